@@ -0,0 +1,15 @@
+//! Fuzz the character definition and character manifest parsers
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use narrative_core::{CharacterDef, CharacterManifest};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let _ = CharacterDef::from_ron_str(content);
+    let _ = CharacterManifest::from_ron_str(content);
+});
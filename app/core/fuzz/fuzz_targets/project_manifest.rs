@@ -0,0 +1,18 @@
+//! Fuzz the project manifest parser (`assets/config/project.ron`)
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use narrative_core::config::ProjectManifest;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(manifest) = ProjectManifest::from_ron_str(content)
+        && let Some(start) = manifest.start_scenario()
+    {
+        let _ = manifest.next_scenario(&start.id);
+    }
+});
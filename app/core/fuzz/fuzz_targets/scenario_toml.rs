@@ -0,0 +1,21 @@
+//! Fuzz the scenario TOML parser
+//!
+//! Malformed scenarios must produce an error. Scenarios that parse are also
+//! started, which validates the start scene.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use narrative_engine::asset::parse_scenario_toml;
+use narrative_engine::runtime::ScenarioRuntime;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(scenario) = parse_scenario_toml(content) {
+        let mut runtime = ScenarioRuntime::new(scenario);
+        let _ = runtime.start();
+    }
+});
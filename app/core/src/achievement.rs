@@ -0,0 +1,320 @@
+//! Achievement definitions and registry
+//!
+//! Achievements are declared in a RON manifest. Each one is unlocked by a
+//! `UnlockAchievement` scenario command, or automatically once all of its
+//! conditions hold. Unlocked achievements are kept in the global
+//! [`UnlockData`](crate::UnlockData), so they are shared by all save slots.
+//!
+//! # Example RON format
+//!
+//! ```ron
+//! AchievementManifest(
+//!     achievements: [
+//!         AchievementDef(
+//!             id: "first_steps",
+//!             title: "First Steps",
+//!             description: "Finish the prologue.",
+//!             unlock: [Scene("chapter_01")],
+//!         ),
+//!         AchievementDef(
+//!             id: "true_friend",
+//!             title: "True Friend",
+//!             description: "Keep every promise to Sakura.",
+//!             hidden: true,
+//!             unlock: [Flag("kept_promise"), Flag("sakura_route")],
+//!         ),
+//!     ],
+//! )
+//! ```
+
+use crate::error::EngineError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Condition for unlocking an achievement automatically
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AchievementCondition {
+    /// The flag is set
+    Flag(String),
+    /// The scene is being played
+    Scene(String),
+}
+
+/// Achievement definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementDef {
+    /// Unique achievement identifier
+    pub id: String,
+
+    /// Display title
+    pub title: String,
+
+    /// What the player has to do, shown in the achievement list
+    #[serde(default)]
+    pub description: String,
+
+    /// Hide the title and description until the achievement is unlocked
+    #[serde(default)]
+    pub hidden: bool,
+
+    /// Conditions that unlock the achievement once all of them hold
+    ///
+    /// Achievements without conditions are only unlocked by the
+    /// `UnlockAchievement` command.
+    #[serde(default)]
+    pub unlock: Vec<AchievementCondition>,
+
+    /// Sort order for the achievement list (lower = earlier)
+    #[serde(default)]
+    pub sort_order: u32,
+}
+
+impl AchievementDef {
+    /// Create a new achievement definition
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: String::new(),
+            hidden: false,
+            unlock: Vec::new(),
+            sort_order: 0,
+        }
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Hide the achievement until it is unlocked
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Add an unlock condition
+    pub fn with_condition(mut self, condition: AchievementCondition) -> Self {
+        self.unlock.push(condition);
+        self
+    }
+
+    /// Set the sort order
+    pub fn with_sort_order(mut self, order: u32) -> Self {
+        self.sort_order = order;
+        self
+    }
+
+    /// Check whether all unlock conditions hold
+    ///
+    /// Returns false for achievements without conditions, which are only
+    /// unlocked by command.
+    pub fn conditions_met(
+        &self,
+        is_flag_set: impl Fn(&str) -> bool,
+        is_in_scene: impl Fn(&str) -> bool,
+    ) -> bool {
+        !self.unlock.is_empty()
+            && self.unlock.iter().all(|condition| match condition {
+                AchievementCondition::Flag(flag) => is_flag_set(flag),
+                AchievementCondition::Scene(scene) => is_in_scene(scene),
+            })
+    }
+}
+
+/// Achievement manifest, as stored in RON
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AchievementManifest {
+    /// Achievement definitions
+    #[serde(default)]
+    pub achievements: Vec<AchievementDef>,
+}
+
+impl AchievementManifest {
+    /// Load manifest from a RON file
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, EngineError> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+        let manifest: Self = ron::from_str(&content).map_err(|e| EngineError::RonSer(e.into()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Validate the manifest: IDs and titles must be set and IDs unique
+    pub fn validate(&self) -> Result<(), EngineError> {
+        let mut ids = std::collections::HashSet::new();
+        for achievement in &self.achievements {
+            if achievement.id.is_empty() {
+                return Err(EngineError::Other(
+                    "Achievement ID cannot be empty".to_string(),
+                ));
+            }
+            if achievement.title.is_empty() {
+                return Err(EngineError::Other(format!(
+                    "Achievement '{}': title cannot be empty",
+                    achievement.id
+                )));
+            }
+            if !ids.insert(achievement.id.as_str()) {
+                return Err(EngineError::Other(format!(
+                    "Duplicate achievement ID '{}'",
+                    achievement.id
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Registry of all achievements in the game
+#[derive(Debug, Clone, Default)]
+pub struct AchievementRegistry {
+    /// Map of achievement ID to definition
+    achievements: HashMap<String, AchievementDef>,
+
+    /// Sorted list of achievement IDs (for ordered iteration)
+    sorted_ids: Vec<String>,
+}
+
+impl AchievementRegistry {
+    /// Create a new empty achievement registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry from a manifest
+    pub fn from_manifest(manifest: AchievementManifest) -> Self {
+        let mut registry = Self::new();
+        registry.register_many(manifest.achievements);
+        registry
+    }
+
+    /// Register an achievement
+    pub fn register(&mut self, achievement: AchievementDef) {
+        self.achievements
+            .insert(achievement.id.clone(), achievement);
+        self.update_sorted_ids();
+    }
+
+    /// Register multiple achievements
+    pub fn register_many(&mut self, achievements: Vec<AchievementDef>) {
+        for achievement in achievements {
+            self.achievements
+                .insert(achievement.id.clone(), achievement);
+        }
+        self.update_sorted_ids();
+    }
+
+    /// Get an achievement by ID
+    pub fn get(&self, id: &str) -> Option<&AchievementDef> {
+        self.achievements.get(id)
+    }
+
+    /// Get all achievements in sorted order
+    pub fn get_all_sorted(&self) -> Vec<&AchievementDef> {
+        self.sorted_ids
+            .iter()
+            .filter_map(|id| self.achievements.get(id))
+            .collect()
+    }
+
+    /// Get the total number of achievements
+    pub fn total_count(&self) -> usize {
+        self.achievements.len()
+    }
+
+    /// Check if the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.achievements.is_empty()
+    }
+
+    /// Update the sorted ID list (by sort order, then ID)
+    fn update_sorted_ids(&mut self) {
+        let mut ids: Vec<_> = self.achievements.keys().cloned().collect();
+        ids.sort_by(|a, b| {
+            let order_a = self.achievements.get(a).map_or(0, |d| d.sort_order);
+            let order_b = self.achievements.get(b).map_or(0, |d| d.sort_order);
+            order_a.cmp(&order_b).then_with(|| a.cmp(b))
+        });
+        self.sorted_ids = ids;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_from_ron() {
+        let ron_str = r#"
+AchievementManifest(
+    achievements: [
+        AchievementDef(
+            id: "first_steps",
+            title: "First Steps",
+            description: "Finish the prologue.",
+            unlock: [Scene("chapter_01")],
+        ),
+        AchievementDef(
+            id: "secret",
+            title: "Secret",
+            hidden: true,
+        ),
+    ],
+)
+"#;
+        let manifest: AchievementManifest = ron::from_str(ron_str).unwrap();
+        assert!(manifest.validate().is_ok());
+        assert_eq!(manifest.achievements.len(), 2);
+        assert_eq!(
+            manifest.achievements[0].unlock,
+            vec![AchievementCondition::Scene("chapter_01".to_string())]
+        );
+        assert!(manifest.achievements[1].hidden);
+        assert!(manifest.achievements[1].unlock.is_empty());
+    }
+
+    #[test]
+    fn test_manifest_rejects_duplicate_ids() {
+        let manifest = AchievementManifest {
+            achievements: vec![
+                AchievementDef::new("a", "A"),
+                AchievementDef::new("a", "Another A"),
+            ],
+        };
+        assert!(manifest.validate().is_err());
+    }
+
+    #[test]
+    fn test_conditions_met() {
+        let achievement = AchievementDef::new("a", "A")
+            .with_condition(AchievementCondition::Flag("kept_promise".to_string()))
+            .with_condition(AchievementCondition::Scene("ending".to_string()));
+
+        assert!(achievement.conditions_met(|f| f == "kept_promise", |s| s == "ending"));
+        assert!(!achievement.conditions_met(|_| false, |s| s == "ending"));
+
+        // Without conditions, only the command unlocks it
+        let manual = AchievementDef::new("b", "B");
+        assert!(!manual.conditions_met(|_| true, |_| true));
+    }
+
+    #[test]
+    fn test_registry_sort_order() {
+        let mut registry = AchievementRegistry::new();
+        registry.register_many(vec![
+            AchievementDef::new("b", "B").with_sort_order(2),
+            AchievementDef::new("a", "A").with_sort_order(2),
+            AchievementDef::new("c", "C").with_sort_order(1),
+        ]);
+
+        let ids: Vec<_> = registry
+            .get_all_sorted()
+            .iter()
+            .map(|d| d.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["c", "a", "b"]);
+        assert_eq!(registry.total_count(), 3);
+    }
+}
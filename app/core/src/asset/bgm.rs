@@ -15,11 +15,12 @@ use std::path::Path;
 ///     name: "School Theme",
 ///     file_path: "assets/audio/music/dailylife/school_theme.ogg",
 ///     loop_enabled: true,
-///     loop_start: Some(4.5),
-///     loop_end: Some(120.0),
+///     loop_start: Some(4.5),             // seconds
+///     loop_end: Some(Samples(5292000)),  // or an exact sample frame
 ///     default_volume: 0.8,
 ///     fade_in_duration: 2.0,
 ///     fade_out_duration: 2.0,
+///     load_mode: Stream,
 ///     meta: Some((
 ///         mood: "cheerful",
 ///         tempo: "moderate",
@@ -43,13 +44,14 @@ pub struct BgmDef {
     #[serde(default = "default_true")]
     pub loop_enabled: bool,
 
-    /// Loop start position in seconds
+    /// Loop start position; playback jumps back here after reaching the loop end.
+    /// Everything before it is an intro that plays once. Defaults to the start of the track.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loop_start: Option<f32>,
+    pub loop_start: Option<LoopPoint>,
 
-    /// Loop end position in seconds
+    /// Loop end position (exclusive). Defaults to the end of the track.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loop_end: Option<f32>,
+    pub loop_end: Option<LoopPoint>,
 
     /// Default volume (0.0 - 1.0)
     #[serde(default = "default_volume")]
@@ -63,6 +65,10 @@ pub struct BgmDef {
     #[serde(default = "default_fade")]
     pub fade_out_duration: f32,
 
+    /// Whether the track is streamed or decoded into memory
+    #[serde(default)]
+    pub load_mode: AudioLoadMode,
+
     /// Optional metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<AudioMeta>,
@@ -85,12 +91,13 @@ impl BgmDef {
             default_volume: default_volume(),
             fade_in_duration: default_fade(),
             fade_out_duration: default_fade(),
+            load_mode: AudioLoadMode::default(),
             meta: None,
         }
     }
 
     /// Set loop points
-    pub fn with_loop(mut self, start: Option<f32>, end: Option<f32>) -> Self {
+    pub fn with_loop(mut self, start: Option<LoopPoint>, end: Option<LoopPoint>) -> Self {
         self.loop_start = start;
         self.loop_end = end;
         self
@@ -109,6 +116,12 @@ impl BgmDef {
         self
     }
 
+    /// Set load mode
+    pub fn with_load_mode(mut self, load_mode: AudioLoadMode) -> Self {
+        self.load_mode = load_mode;
+        self
+    }
+
     /// Set metadata
     pub fn with_meta(mut self, meta: AudioMeta) -> Self {
         self.meta = Some(meta);
@@ -132,8 +145,192 @@ impl BgmDef {
             ));
         }
 
+        let region = LoopRegion::new(self.loop_start, self.loop_end);
+        region.validate_points()?;
+
+        // Without the sample rate only second-based points can be checked
+        // against the declared duration; the player checks the rest on load
+        if let Some(duration) = self.meta.as_ref().and_then(|meta| meta.duration_secs) {
+            for point in [self.loop_start, self.loop_end].into_iter().flatten() {
+                if let LoopPoint::Seconds(seconds) = point
+                    && seconds > f64::from(duration)
+                {
+                    return Err(format!(
+                        "Loop point {} is beyond the track duration ({}s)",
+                        point, duration
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Loop region to use for playback, or `None` if looping is disabled
+    pub fn loop_region(&self) -> Option<LoopRegion> {
+        self.loop_enabled
+            .then(|| LoopRegion::new(self.loop_start, self.loop_end))
+    }
+}
+
+/// Position of a loop point within a track
+///
+/// In RON a bare number is read as seconds (`Some(4.5)`); an exact position
+/// is written as `Some(Samples(198450))`, counted in sample frames.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(from = "LoopPointRepr")]
+pub enum LoopPoint {
+    /// Time in seconds
+    Seconds(f64),
+    /// Sample frame index (one frame covers all channels)
+    Samples(u64),
+}
+
+impl LoopPoint {
+    /// Convert to a sample frame index at the given sample rate
+    pub fn to_samples(self, sample_rate: u32) -> u64 {
+        match self {
+            Self::Seconds(seconds) => (seconds.max(0.0) * f64::from(sample_rate)).round() as u64,
+            Self::Samples(samples) => samples,
+        }
+    }
+}
+
+impl std::fmt::Display for LoopPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Seconds(seconds) => write!(f, "{}s", seconds),
+            Self::Samples(samples) => write!(f, "sample {}", samples),
+        }
+    }
+}
+
+/// Accepts both a bare number (seconds) and the tagged form
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LoopPointRepr {
+    Seconds(f64),
+    Tagged(TaggedLoopPoint),
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LoopPoint")]
+enum TaggedLoopPoint {
+    Seconds(f64),
+    Samples(u64),
+}
+
+impl From<LoopPointRepr> for LoopPoint {
+    fn from(repr: LoopPointRepr) -> Self {
+        match repr {
+            LoopPointRepr::Seconds(seconds)
+            | LoopPointRepr::Tagged(TaggedLoopPoint::Seconds(seconds)) => Self::Seconds(seconds),
+            LoopPointRepr::Tagged(TaggedLoopPoint::Samples(samples)) => Self::Samples(samples),
+        }
+    }
+}
+
+/// Section of a track that repeats
+///
+/// `None` means the start or end of the track respectively.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoopRegion {
+    pub start: Option<LoopPoint>,
+    pub end: Option<LoopPoint>,
+}
+
+impl LoopRegion {
+    /// Create a loop region
+    pub fn new(start: Option<LoopPoint>, end: Option<LoopPoint>) -> Self {
+        Self { start, end }
+    }
+
+    /// Check loop points that can be checked without the audio data
+    pub fn validate_points(&self) -> Result<(), String> {
+        for point in [self.start, self.end].into_iter().flatten() {
+            if let LoopPoint::Seconds(seconds) = point
+                && !(seconds.is_finite() && seconds >= 0.0)
+            {
+                return Err(format!("Loop point {} must be a non-negative time", point));
+            }
+        }
+
+        let ordered = match (self.start, self.end) {
+            (Some(LoopPoint::Seconds(start)), Some(LoopPoint::Seconds(end))) => start < end,
+            (Some(LoopPoint::Samples(start)), Some(LoopPoint::Samples(end))) => start < end,
+            _ => true,
+        };
+        if !ordered {
+            return Err(self.order_error());
+        }
         Ok(())
     }
+
+    /// Check the loop points against a track's length
+    ///
+    /// Returns the region as `(start, end)` sample frames.
+    pub fn resolve(&self, sample_rate: u32, total_frames: u64) -> Result<(u64, u64), String> {
+        self.validate_points()?;
+
+        let start = self.start.map_or(0, |point| point.to_samples(sample_rate));
+        let end = self
+            .end
+            .map_or(total_frames, |point| point.to_samples(sample_rate));
+
+        if end > total_frames {
+            return Err(format!(
+                "Loop end {} is beyond the end of the track ({} samples)",
+                self.end.unwrap_or(LoopPoint::Samples(end)),
+                total_frames
+            ));
+        }
+        if start >= end {
+            return Err(self.order_error());
+        }
+        Ok((start, end))
+    }
+
+    fn order_error(&self) -> String {
+        let describe = |point: Option<LoopPoint>, default: &str| {
+            point.map_or_else(|| default.to_string(), |point| point.to_string())
+        };
+        format!(
+            "Loop start ({}) must come before loop end ({})",
+            describe(self.start, "start of track"),
+            describe(self.end, "end of track")
+        )
+    }
+}
+
+/// How an audio asset is loaded for playback
+///
+/// Streaming decodes on the fly and keeps memory use flat, which suits long
+/// BGM. Preloading decodes the whole file once and keeps it in memory, which
+/// suits short, frequently played sounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AudioLoadMode {
+    /// Decode while playing
+    Stream,
+    /// Decode fully into memory and cache the result
+    Preload,
+    /// Stream files of at least [`AudioLoadMode::AUTO_STREAM_THRESHOLD`] bytes,
+    /// preload smaller ones
+    #[default]
+    Auto,
+}
+
+impl AudioLoadMode {
+    /// File size (encoded, in bytes) from which `Auto` streams
+    pub const AUTO_STREAM_THRESHOLD: u64 = 1024 * 1024;
+
+    /// Whether a file of `file_size` bytes should be streamed
+    pub fn should_stream(self, file_size: u64, threshold: u64) -> bool {
+        match self {
+            Self::Stream => true,
+            Self::Preload => false,
+            Self::Auto => file_size >= threshold,
+        }
+    }
 }
 
 /// Audio metadata for categorization and mood tracking
@@ -279,12 +476,15 @@ mod tests {
     #[test]
     fn test_bgm_def_builder() {
         let bgm = BgmDef::new("bgm.test", "Test", "music.ogg")
-            .with_loop(Some(4.0), Some(120.0))
+            .with_loop(
+                Some(LoopPoint::Seconds(4.0)),
+                Some(LoopPoint::Seconds(120.0)),
+            )
             .with_volume(0.8)
             .with_fade(3.0, 2.5);
 
-        assert_eq!(bgm.loop_start, Some(4.0));
-        assert_eq!(bgm.loop_end, Some(120.0));
+        assert_eq!(bgm.loop_start, Some(LoopPoint::Seconds(4.0)));
+        assert_eq!(bgm.loop_end, Some(LoopPoint::Seconds(120.0)));
         assert_eq!(bgm.default_volume, 0.8);
         assert_eq!(bgm.fade_in_duration, 3.0);
         assert_eq!(bgm.fade_out_duration, 2.5);
@@ -304,6 +504,69 @@ mod tests {
         assert_eq!(invalid_volume.default_volume, 1.0);
     }
 
+    #[test]
+    fn test_loop_point_formats() {
+        let bgm: BgmDef = ron::from_str(
+            r#"(id: "a", name: "A", file_path: "a.ogg", loop_start: Some(4.5), loop_end: Some(Samples(44100)))"#,
+        )
+        .unwrap();
+        assert_eq!(bgm.loop_start, Some(LoopPoint::Seconds(4.5)));
+        assert_eq!(bgm.loop_end, Some(LoopPoint::Samples(44100)));
+
+        let round_trip: BgmDef = ron::from_str(&ron::to_string(&bgm).unwrap()).unwrap();
+        assert_eq!(round_trip, bgm);
+    }
+
+    #[test]
+    fn test_loop_point_validation() {
+        let reversed = BgmDef::new("a", "A", "a.ogg").with_loop(
+            Some(LoopPoint::Seconds(10.0)),
+            Some(LoopPoint::Seconds(5.0)),
+        );
+        assert!(reversed.validate().is_err());
+
+        let negative =
+            BgmDef::new("a", "A", "a.ogg").with_loop(Some(LoopPoint::Seconds(-1.0)), None);
+        assert!(negative.validate().is_err());
+
+        let mut too_long =
+            BgmDef::new("a", "A", "a.ogg").with_loop(None, Some(LoopPoint::Seconds(130.0)));
+        too_long.meta = Some(AudioMeta::new("calm", "slow").with_duration(120.0));
+        assert!(too_long.validate().is_err());
+    }
+
+    #[test]
+    fn test_loop_region_resolve() {
+        let region = LoopRegion::new(Some(LoopPoint::Seconds(1.5)), None);
+        assert_eq!(region.resolve(44100, 441000), Ok((66150, 441000)));
+
+        let region = LoopRegion::new(None, Some(LoopPoint::Samples(500_000)));
+        assert!(region.resolve(44100, 441000).is_err());
+
+        let region = LoopRegion::new(Some(LoopPoint::Seconds(10.0)), None);
+        assert!(region.resolve(44100, 441000).is_err());
+
+        assert_eq!(LoopRegion::default().resolve(48000, 100), Ok((0, 100)));
+    }
+
+    #[test]
+    fn test_audio_load_mode() {
+        let threshold = AudioLoadMode::AUTO_STREAM_THRESHOLD;
+        assert!(AudioLoadMode::Stream.should_stream(10, threshold));
+        assert!(!AudioLoadMode::Preload.should_stream(u64::MAX, threshold));
+        assert!(!AudioLoadMode::Auto.should_stream(threshold - 1, threshold));
+        assert!(AudioLoadMode::Auto.should_stream(threshold, threshold));
+
+        let bgm: BgmDef =
+            ron::from_str(r#"(id: "a", name: "A", file_path: "a.ogg", load_mode: Stream)"#)
+                .unwrap();
+        assert_eq!(bgm.load_mode, AudioLoadMode::Stream);
+        assert_eq!(
+            BgmDef::new("a", "A", "a.ogg").load_mode,
+            AudioLoadMode::Auto
+        );
+    }
+
     #[test]
     fn test_audio_meta() {
         let meta = AudioMeta::new("cheerful", "moderate")
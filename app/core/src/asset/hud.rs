@@ -0,0 +1,350 @@
+use crate::error::EngineError;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Flag or variable a HUD widget shows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HudSource {
+    /// Numeric variable (bools count as 0 or 1, strings as 0)
+    Variable(String),
+    /// Flag, shown as 1 when set and 0 otherwise
+    Flag(String),
+}
+
+/// How a HUD widget draws its value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HudWidgetKind {
+    /// Meter filled by `value / max`
+    Bar {
+        /// Value of a full bar
+        max: f64,
+    },
+    /// The value as a number
+    Number,
+    /// One icon per unit of the value, up to `max`
+    IconRow {
+        /// Icon image path
+        icon: String,
+        /// Most icons shown
+        max: u32,
+    },
+}
+
+/// HUD widget definition
+///
+/// # Example RON format
+///
+/// ```ron
+/// HudWidgetDef(
+///     id: "sakura_affection",
+///     source: Variable("affection.sakura"),
+///     kind: Bar(max: 100.0),
+///     label: Some("Sakura"),
+///     position: (24.0, 24.0),
+///     color: Some((1.0, 0.6, 0.7, 1.0)),
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HudWidgetDef {
+    /// Unique widget identifier, used by `SetHudVisible`
+    pub id: String,
+
+    /// Flag or variable shown
+    pub source: HudSource,
+
+    /// How the value is drawn
+    pub kind: HudWidgetKind,
+
+    /// Text shown before the value
+    #[serde(default)]
+    pub label: Option<String>,
+
+    /// Top-left corner in logical pixels
+    pub position: (f32, f32),
+
+    /// Fill color of bars and numbers (RGBA, 0.0-1.0; theme accent if not set)
+    #[serde(default)]
+    pub color: Option<[f32; 4]>,
+
+    /// Whether the widget is shown before any `SetHudVisible` names it
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+}
+
+impl HudWidgetDef {
+    /// Create a new HUD widget definition
+    pub fn new(
+        id: impl Into<String>,
+        source: HudSource,
+        kind: HudWidgetKind,
+        position: (f32, f32),
+    ) -> Self {
+        Self {
+            id: id.into(),
+            source,
+            kind,
+            label: None,
+            position,
+            color: None,
+            visible: true,
+        }
+    }
+
+    /// Set the label
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the fill color
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Hide the widget until a `SetHudVisible` command shows it
+    pub fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+
+    /// Validate the widget definition
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("Widget ID cannot be empty".to_string());
+        }
+
+        match &self.kind {
+            HudWidgetKind::Bar { max } if *max <= 0.0 => {
+                Err(format!("Bar max must be positive, got {}", max))
+            }
+            HudWidgetKind::IconRow { icon, .. } if icon.is_empty() => {
+                Err("Icon row needs an icon".to_string())
+            }
+            HudWidgetKind::IconRow { max: 0, .. } => {
+                Err("Icon row max must be positive".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// HUD manifest - widgets showing flags and variables during play
+///
+/// # Example RON format
+///
+/// ```ron
+/// HudManifest(
+///     widgets: [
+///         HudWidgetDef(
+///             id: "day",
+///             source: Variable("day"),
+///             kind: Number,
+///             label: Some("Day"),
+///             position: (24.0, 24.0),
+///         ),
+///         HudWidgetDef(
+///             id: "keys",
+///             source: Variable("keys_found"),
+///             kind: IconRow(icon: "assets/ui/icons/key.png", max: 5),
+///             position: (24.0, 64.0),
+///             visible: false,
+///         ),
+///     ],
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct HudManifest {
+    /// Widgets, in drawing order
+    #[serde(default)]
+    pub widgets: Vec<HudWidgetDef>,
+}
+
+impl HudManifest {
+    /// Create a new empty manifest
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a widget
+    pub fn add_widget(mut self, widget: HudWidgetDef) -> Self {
+        self.widgets.push(widget);
+        self
+    }
+
+    /// Load manifest from a RON file
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, EngineError> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+        let manifest: Self = ron::from_str(&content).map_err(|e| EngineError::RonSer(e.into()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Validate all widgets: each must be valid and have a unique ID
+    pub fn validate(&self) -> Result<(), EngineError> {
+        let mut ids = HashSet::new();
+        for widget in &self.widgets {
+            widget
+                .validate()
+                .map_err(|e| EngineError::Other(format!("HUD widget '{}': {}", widget.id, e)))?;
+            if !ids.insert(widget.id.as_str()) {
+                return Err(EngineError::Other(format!(
+                    "Duplicate HUD widget id '{}'",
+                    widget.id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get a widget by ID
+    pub fn get(&self, id: &str) -> Option<&HudWidgetDef> {
+        self.widgets.iter().find(|widget| widget.id == id)
+    }
+
+    /// Check whether the manifest has no widgets
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+}
+
+/// Which HUD widgets are shown, as set by `SetHudVisible` commands
+///
+/// Saved with the game, so a loaded save shows the HUD it was made with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HudVisibility {
+    /// Whether the HUD is shown at all
+    #[serde(default = "default_visible")]
+    pub shown: bool,
+
+    /// Widgets shown or hidden by ID, overriding their `visible` default
+    #[serde(default)]
+    pub widgets: BTreeMap<String, bool>,
+}
+
+impl HudVisibility {
+    /// Show or hide the listed widgets, or the whole HUD if none are listed
+    pub fn set(&mut self, visible: bool, widgets: &[String]) {
+        if widgets.is_empty() {
+            self.shown = visible;
+        } else {
+            for id in widgets {
+                self.widgets.insert(id.clone(), visible);
+            }
+        }
+    }
+
+    /// Check whether a widget is shown
+    pub fn is_visible(&self, widget: &HudWidgetDef) -> bool {
+        self.shown
+            && self
+                .widgets
+                .get(&widget.id)
+                .copied()
+                .unwrap_or(widget.visible)
+    }
+}
+
+impl Default for HudVisibility {
+    fn default() -> Self {
+        Self {
+            shown: true,
+            widgets: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_counter() -> HudWidgetDef {
+        HudWidgetDef::new(
+            "day",
+            HudSource::Variable("day".to_string()),
+            HudWidgetKind::Number,
+            (24.0, 24.0),
+        )
+    }
+
+    #[test]
+    fn test_widget_validation() {
+        assert!(day_counter().validate().is_ok());
+
+        let mut bar = day_counter();
+        bar.kind = HudWidgetKind::Bar { max: 0.0 };
+        assert!(bar.validate().is_err());
+
+        let mut icons = day_counter();
+        icons.kind = HudWidgetKind::IconRow {
+            icon: "key.png".to_string(),
+            max: 0,
+        };
+        assert!(icons.validate().is_err());
+
+        let duplicate = HudManifest::new()
+            .add_widget(day_counter())
+            .add_widget(day_counter());
+        assert!(duplicate.validate().is_err());
+    }
+
+    #[test]
+    fn test_visibility() {
+        let day = day_counter();
+        let keys = HudWidgetDef::new(
+            "keys",
+            HudSource::Flag("has_key".to_string()),
+            HudWidgetKind::Number,
+            (24.0, 64.0),
+        )
+        .hidden();
+        let mut visibility = HudVisibility::default();
+        assert!(visibility.is_visible(&day));
+        assert!(!visibility.is_visible(&keys));
+
+        visibility.set(true, &["keys".to_string()]);
+        assert!(visibility.is_visible(&keys));
+
+        visibility.set(false, &[]);
+        assert!(!visibility.is_visible(&day));
+        assert!(!visibility.is_visible(&keys));
+    }
+
+    #[test]
+    fn test_parse_ron_with_defaults() {
+        let manifest: HudManifest = ron::from_str(
+            r#"
+HudManifest(
+    widgets: [
+        HudWidgetDef(
+            id: "sakura",
+            source: Variable("affection.sakura"),
+            kind: Bar(max: 100.0),
+            label: Some("Sakura"),
+            position: (24.0, 24.0),
+        ),
+        HudWidgetDef(
+            id: "keys",
+            source: Variable("keys_found"),
+            kind: IconRow(icon: "assets/ui/icons/key.png", max: 5),
+            position: (24.0, 64.0),
+            visible: false,
+        ),
+    ],
+)
+"#,
+        )
+        .unwrap();
+        assert!(manifest.validate().is_ok());
+        let sakura = manifest.get("sakura").unwrap();
+        assert_eq!(sakura.label.as_deref(), Some("Sakura"));
+        assert!(sakura.visible);
+        assert!(sakura.color.is_none());
+        assert!(!manifest.get("keys").unwrap().visible);
+    }
+}
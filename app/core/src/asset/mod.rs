@@ -5,10 +5,18 @@
 /// the Veloren project's asset organization pattern.
 pub mod background;
 pub mod bgm;
+pub mod hud;
+pub mod particle;
 pub mod se;
 pub mod ui_theme;
 
 pub use background::{BackgroundDef, BackgroundManifest, BackgroundMeta};
-pub use bgm::{AudioMeta, BgmDef, BgmManifest};
-pub use se::{SeDef, SeManifest};
-pub use ui_theme::{UiThemeDef, UiThemeManifest};
+pub use bgm::{AudioLoadMode, AudioMeta, BgmDef, BgmManifest, LoopPoint, LoopRegion};
+pub use hud::{HudManifest, HudSource, HudVisibility, HudWidgetDef, HudWidgetKind};
+pub use particle::{EmitArea, ParticleEffectDef, ParticleEffectManifest, ParticleShape};
+pub use se::{SeDef, SeManifest, SePool, SePoolMode};
+pub use ui_theme::{
+    ButtonAssets, ChoiceAssets, ColorPalette, DialogueBoxAssets, QuickMenuButtonDef,
+    QuickMenuOrientation, QuickMenuTheme, SubtitleTheme, ThemeFonts, ThemeSounds, UiThemeDef,
+    UiThemeManifest,
+};
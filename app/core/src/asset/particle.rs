@@ -0,0 +1,418 @@
+use crate::error::EngineError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// How a particle is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParticleShape {
+    /// Soft round flake
+    Snow,
+    /// Thin streak stretched along its motion
+    Rain,
+    /// Flat oval petal that flutters as it falls
+    Petal,
+    /// Small bright dot
+    Spark,
+}
+
+/// Where new particles appear
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EmitArea {
+    /// Just above the top edge of the screen
+    #[default]
+    Top,
+    /// Just below the bottom edge of the screen
+    Bottom,
+    /// Anywhere on screen
+    Screen,
+}
+
+/// Particle effect definition
+///
+/// Defines how a `ShowEffect` effect spawns and moves its particles.
+/// Positions and speeds are in logical pixels.
+///
+/// # Example RON format
+///
+/// ```ron
+/// ParticleEffectDef(
+///     id: "snow",
+///     name: "Snow",
+///     shape: Snow,
+///     emit_from: Top,
+///     spawn_rate: 40.0,
+///     max_particles: 400,
+///     lifetime: 12.0,
+///     speed: (40.0, 90.0),
+///     direction: 90.0,
+///     spread: 20.0,
+///     sway: 20.0,
+///     size: (3.0, 7.0),
+///     colors: [(1.0, 1.0, 1.0, 0.9)],
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticleEffectDef {
+    /// Unique effect identifier, used by `ShowEffect`
+    pub id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// How particles are drawn
+    pub shape: ParticleShape,
+
+    /// Where new particles appear
+    #[serde(default)]
+    pub emit_from: EmitArea,
+
+    /// Particles spawned per second at intensity 1.0
+    pub spawn_rate: f32,
+
+    /// Most particles alive at once at intensity 1.0
+    #[serde(default = "default_max_particles")]
+    pub max_particles: usize,
+
+    /// Seconds a particle lives
+    pub lifetime: f32,
+
+    /// Initial speed range (min, max) in pixels per second
+    pub speed: (f32, f32),
+
+    /// Direction of travel in degrees (0 = right, 90 = down)
+    pub direction: f32,
+
+    /// Random deviation from `direction` in degrees, either way
+    #[serde(default)]
+    pub spread: f32,
+
+    /// Downward acceleration in pixels per second squared (negative rises)
+    #[serde(default)]
+    pub gravity: f32,
+
+    /// Amplitude of side-to-side drift in pixels
+    #[serde(default)]
+    pub sway: f32,
+
+    /// Size range (min, max) in pixels
+    pub size: (f32, f32),
+
+    /// Colors picked at random per particle (RGBA, 0.0-1.0)
+    pub colors: Vec<[f32; 4]>,
+}
+
+impl ParticleEffectDef {
+    /// Create a new particle effect definition
+    pub fn new(id: impl Into<String>, name: impl Into<String>, shape: ParticleShape) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            shape,
+            emit_from: EmitArea::default(),
+            spawn_rate: 30.0,
+            max_particles: default_max_particles(),
+            lifetime: 10.0,
+            speed: (50.0, 100.0),
+            direction: 90.0,
+            spread: 0.0,
+            gravity: 0.0,
+            sway: 0.0,
+            size: (2.0, 4.0),
+            colors: vec![[1.0, 1.0, 1.0, 1.0]],
+        }
+    }
+
+    /// Set where particles appear
+    pub fn with_emit_from(mut self, emit_from: EmitArea) -> Self {
+        self.emit_from = emit_from;
+        self
+    }
+
+    /// Set the spawn rate and particle cap
+    pub fn with_rate(mut self, spawn_rate: f32, max_particles: usize) -> Self {
+        self.spawn_rate = spawn_rate;
+        self.max_particles = max_particles;
+        self
+    }
+
+    /// Set how long particles live
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// Set the initial velocity (speed range, direction and spread in degrees)
+    pub fn with_motion(mut self, speed: (f32, f32), direction: f32, spread: f32) -> Self {
+        self.speed = speed;
+        self.direction = direction;
+        self.spread = spread;
+        self
+    }
+
+    /// Set gravity and sway
+    pub fn with_forces(mut self, gravity: f32, sway: f32) -> Self {
+        self.gravity = gravity;
+        self.sway = sway;
+        self
+    }
+
+    /// Set the size range
+    pub fn with_size(mut self, min: f32, max: f32) -> Self {
+        self.size = (min, max);
+        self
+    }
+
+    /// Set the colors
+    pub fn with_colors(mut self, colors: Vec<[f32; 4]>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Validate the effect definition
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("Effect ID cannot be empty".to_string());
+        }
+
+        if self.spawn_rate < 0.0 {
+            return Err(format!(
+                "Spawn rate must not be negative, got {}",
+                self.spawn_rate
+            ));
+        }
+
+        if self.lifetime <= 0.0 {
+            return Err(format!(
+                "Particle lifetime must be positive, got {}",
+                self.lifetime
+            ));
+        }
+
+        let (min_speed, max_speed) = self.speed;
+        if min_speed < 0.0 || min_speed > max_speed {
+            return Err(format!(
+                "Speed range must be 0 <= min <= max, got ({}, {})",
+                min_speed, max_speed
+            ));
+        }
+
+        let (min_size, max_size) = self.size;
+        if min_size <= 0.0 || min_size > max_size {
+            return Err(format!(
+                "Size range must be 0 < min <= max, got ({}, {})",
+                min_size, max_size
+            ));
+        }
+
+        if self.colors.is_empty() {
+            return Err("Effect needs at least one color".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Particle effect manifest - collection of particle effects
+///
+/// # Example RON format
+///
+/// ```ron
+/// ParticleEffectManifest(
+///     effects: {
+///         "snow": ParticleEffectDef(
+///             id: "snow",
+///             name: "Snow",
+///             shape: Snow,
+///             // ... other fields
+///         ),
+///     },
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticleEffectManifest {
+    /// Map of effect IDs to definitions
+    pub effects: HashMap<String, ParticleEffectDef>,
+}
+
+impl ParticleEffectManifest {
+    /// Create a new empty manifest
+    pub fn new() -> Self {
+        Self {
+            effects: HashMap::new(),
+        }
+    }
+
+    /// Manifest with the built-in effects: snow, rain, sakura and sparks
+    pub fn builtin() -> Self {
+        Self::new()
+            .add_effect(
+                ParticleEffectDef::new("snow", "Snow", ParticleShape::Snow)
+                    .with_rate(40.0, 400)
+                    .with_lifetime(12.0)
+                    .with_motion((40.0, 90.0), 90.0, 20.0)
+                    .with_forces(0.0, 20.0)
+                    .with_size(3.0, 7.0)
+                    .with_colors(vec![[1.0, 1.0, 1.0, 0.9], [0.9, 0.95, 1.0, 0.8]]),
+            )
+            .add_effect(
+                ParticleEffectDef::new("rain", "Rain", ParticleShape::Rain)
+                    .with_rate(150.0, 600)
+                    .with_lifetime(1.5)
+                    .with_motion((700.0, 900.0), 100.0, 3.0)
+                    .with_size(1.5, 2.5)
+                    .with_colors(vec![[0.7, 0.75, 0.85, 0.6]]),
+            )
+            .add_effect(
+                ParticleEffectDef::new("sakura", "Sakura Petals", ParticleShape::Petal)
+                    .with_rate(12.0, 150)
+                    .with_lifetime(14.0)
+                    .with_motion((40.0, 80.0), 70.0, 25.0)
+                    .with_forces(0.0, 40.0)
+                    .with_size(6.0, 10.0)
+                    .with_colors(vec![[1.0, 0.8, 0.86, 0.95], [1.0, 0.72, 0.8, 0.95]]),
+            )
+            .add_effect(
+                ParticleEffectDef::new("sparks", "Sparks", ParticleShape::Spark)
+                    .with_emit_from(EmitArea::Bottom)
+                    .with_rate(30.0, 200)
+                    .with_lifetime(3.0)
+                    .with_motion((120.0, 260.0), 270.0, 30.0)
+                    .with_forces(60.0, 10.0)
+                    .with_size(2.0, 4.0)
+                    .with_colors(vec![[1.0, 0.8, 0.3, 1.0], [1.0, 0.5, 0.15, 1.0]]),
+            )
+    }
+
+    /// Add an effect
+    pub fn add_effect(mut self, effect: ParticleEffectDef) -> Self {
+        self.effects.insert(effect.id.clone(), effect);
+        self
+    }
+
+    /// Load manifest from a RON file
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, EngineError> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+        let manifest: Self = ron::from_str(&content).map_err(|e| EngineError::RonSer(e.into()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Validate all effects in the manifest
+    pub fn validate(&self) -> Result<(), EngineError> {
+        for (id, effect) in &self.effects {
+            effect
+                .validate()
+                .map_err(|e| EngineError::Other(format!("Effect '{}': {}", id, e)))?;
+
+            if &effect.id != id {
+                return Err(EngineError::Other(format!(
+                    "Effect map key '{}' does not match effect id '{}'",
+                    id, effect.id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get an effect by ID
+    pub fn get(&self, id: &str) -> Option<&ParticleEffectDef> {
+        self.effects.get(id)
+    }
+
+    /// Get all effect IDs
+    pub fn ids(&self) -> Vec<&str> {
+        self.effects.keys().map(|s| s.as_str()).collect()
+    }
+}
+
+impl Default for ParticleEffectManifest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn default_max_particles() -> usize {
+    500
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_effects_are_valid() {
+        let manifest = ParticleEffectManifest::builtin();
+        assert!(manifest.validate().is_ok());
+        for id in ["snow", "rain", "sakura", "sparks"] {
+            assert!(manifest.get(id).is_some(), "missing built-in '{}'", id);
+        }
+        assert_eq!(
+            manifest.get("sparks").map(|effect| effect.emit_from),
+            Some(EmitArea::Bottom)
+        );
+    }
+
+    #[test]
+    fn test_effect_validation() {
+        let valid = ParticleEffectDef::new("dust", "Dust", ParticleShape::Spark);
+        assert!(valid.validate().is_ok());
+
+        let no_colors = valid.clone().with_colors(Vec::new());
+        assert!(no_colors.validate().is_err());
+
+        let bad_size = valid.clone().with_size(4.0, 2.0);
+        assert!(bad_size.validate().is_err());
+
+        let no_lifetime = valid.with_lifetime(0.0);
+        assert!(no_lifetime.validate().is_err());
+    }
+
+    #[test]
+    fn test_manifest_key_mismatch() {
+        let mut manifest = ParticleEffectManifest::new();
+        manifest.effects.insert(
+            "rain".to_string(),
+            ParticleEffectDef::new("snow", "Snow", ParticleShape::Snow),
+        );
+        assert!(manifest.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_ron_with_defaults() {
+        let manifest: ParticleEffectManifest = ron::from_str(
+            r#"
+ParticleEffectManifest(
+    effects: {
+        "embers": ParticleEffectDef(
+            id: "embers",
+            name: "Embers",
+            shape: Spark,
+            spawn_rate: 10.0,
+            lifetime: 2.0,
+            speed: (20.0, 40.0),
+            direction: 270.0,
+            size: (1.0, 3.0),
+            colors: [(1.0, 0.4, 0.1, 1.0)],
+        ),
+    },
+)
+"#,
+        )
+        .unwrap();
+        assert!(manifest.validate().is_ok());
+
+        let embers = manifest.get("embers").unwrap();
+        assert_eq!(embers.emit_from, EmitArea::Top);
+        assert_eq!(embers.max_particles, 500);
+        assert_eq!(embers.gravity, 0.0);
+    }
+
+    #[test]
+    fn test_manifest_serialization() {
+        let manifest = ParticleEffectManifest::builtin();
+        let ron_str = ron::to_string(&manifest).unwrap();
+        let deserialized: ParticleEffectManifest = ron::from_str(&ron_str).unwrap();
+        assert_eq!(manifest, deserialized);
+    }
+}
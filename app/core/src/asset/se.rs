@@ -1,3 +1,4 @@
+use super::AudioLoadMode;
 use crate::error::EngineError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -15,6 +16,7 @@ use std::path::Path;
 ///     name: "Click Sound",
 ///     file_path: "assets/audio/sounds/click.wav",
 ///     default_volume: 1.0,
+///     load_mode: Preload,
 ///     meta: Some((
 ///         category: "ui",
 ///         tags: ["button", "interaction"],
@@ -37,6 +39,10 @@ pub struct SeDef {
     #[serde(default = "default_volume")]
     pub default_volume: f32,
 
+    /// Whether the sound is streamed or decoded into memory
+    #[serde(default)]
+    pub load_mode: AudioLoadMode,
+
     /// Optional metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<SeMeta>,
@@ -54,6 +60,7 @@ impl SeDef {
             name: name.into(),
             file_path: file_path.into(),
             default_volume: default_volume(),
+            load_mode: AudioLoadMode::default(),
             meta: None,
         }
     }
@@ -64,6 +71,12 @@ impl SeDef {
         self
     }
 
+    /// Set load mode
+    pub fn with_load_mode(mut self, load_mode: AudioLoadMode) -> Self {
+        self.load_mode = load_mode;
+        self
+    }
+
     /// Set metadata
     pub fn with_meta(mut self, meta: SeMeta) -> Self {
         self.meta = Some(meta);
@@ -135,6 +148,111 @@ impl SeMeta {
     }
 }
 
+/// How a sound effect pool picks its next variation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SePoolMode {
+    /// Pick a random file, avoiding the one played last
+    #[default]
+    Random,
+    /// Play the files in order, starting over after the last
+    RoundRobin,
+}
+
+/// Sound effect variation pool
+///
+/// Playing the pool ID (e.g. `PlaySe` with asset `footsteps`) plays one of
+/// its files, so repeated sounds don't feel robotic. Each playback can also
+/// vary in pitch: the playback rate is picked from `pitch_range`.
+///
+/// # Example RON format
+///
+/// ```ron
+/// SePool(
+///     id: "footsteps",
+///     files: [
+///         "assets/audio/se/step1.ogg",
+///         "assets/audio/se/step2.ogg",
+///         "assets/audio/se/step3.ogg",
+///     ],
+///     mode: RoundRobin,
+///     pitch_range: (0.95, 1.05),
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SePool {
+    /// Unique pool identifier, played like a sound file path
+    pub id: String,
+
+    /// Audio file paths (relative to the working directory, like `PlaySe` assets)
+    pub files: Vec<String>,
+
+    /// How the next file is picked
+    #[serde(default)]
+    pub mode: SePoolMode,
+
+    /// Playback rate range (1.0 = original pitch)
+    #[serde(default = "default_pitch_range")]
+    pub pitch_range: (f32, f32),
+}
+
+impl SePool {
+    /// Create a random pool without pitch variation
+    pub fn new(id: impl Into<String>, files: Vec<String>) -> Self {
+        Self {
+            id: id.into(),
+            files,
+            mode: SePoolMode::default(),
+            pitch_range: default_pitch_range(),
+        }
+    }
+
+    /// Set the pick mode
+    pub fn with_mode(mut self, mode: SePoolMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the playback rate range
+    pub fn with_pitch_range(mut self, min: f32, max: f32) -> Self {
+        self.pitch_range = (min, max);
+        self
+    }
+
+    /// Validate the pool
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("SE pool ID cannot be empty".to_string());
+        }
+
+        if self.files.is_empty() {
+            return Err("SE pool must have at least one file".to_string());
+        }
+
+        if self.files.iter().any(|file| file.is_empty()) {
+            return Err("SE pool file path cannot be empty".to_string());
+        }
+
+        let (min, max) = self.pitch_range;
+        if !(MIN_PITCH..=MAX_PITCH).contains(&min)
+            || !(MIN_PITCH..=MAX_PITCH).contains(&max)
+            || min > max
+        {
+            return Err(format!(
+                "SE pool pitch range must be within {}-{} with min <= max, got ({}, {})",
+                MIN_PITCH, MAX_PITCH, min, max
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Lowest playback rate a pool may use
+const MIN_PITCH: f32 = 0.25;
+
+/// Highest playback rate a pool may use
+const MAX_PITCH: f32 = 4.0;
+
 /// Sound effect manifest - collection of sound effects
 ///
 /// # Example RON format
@@ -150,12 +268,22 @@ impl SeMeta {
 ///             // ... other fields
 ///         ),
 ///     },
+///     pools: {
+///         "footsteps": SePool(
+///             id: "footsteps",
+///             files: ["assets/audio/se/step1.ogg", "assets/audio/se/step2.ogg"],
+///         ),
+///     },
 /// )
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SeManifest {
     /// Map of SE IDs to definitions
     pub sounds: HashMap<String, SeDef>,
+
+    /// Map of pool IDs to variation pools
+    #[serde(default)]
+    pub pools: HashMap<String, SePool>,
 }
 
 impl SeManifest {
@@ -163,6 +291,7 @@ impl SeManifest {
     pub fn new() -> Self {
         Self {
             sounds: HashMap::new(),
+            pools: HashMap::new(),
         }
     }
 
@@ -172,6 +301,12 @@ impl SeManifest {
         self
     }
 
+    /// Add a variation pool
+    pub fn add_pool(mut self, pool: SePool) -> Self {
+        self.pools.insert(pool.id.clone(), pool);
+        self
+    }
+
     /// Load manifest from a RON file
     pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, EngineError> {
         let content = std::fs::read_to_string(path.as_ref())?;
@@ -193,9 +328,32 @@ impl SeManifest {
                 )));
             }
         }
+        for (id, pool) in &self.pools {
+            pool.validate()
+                .map_err(|e| EngineError::Other(format!("SE pool '{}': {}", id, e)))?;
+
+            if &pool.id != id {
+                return Err(EngineError::Other(format!(
+                    "SE pool map key '{}' does not match pool id '{}'",
+                    id, pool.id
+                )));
+            }
+
+            if self.sounds.contains_key(id) {
+                return Err(EngineError::Other(format!(
+                    "SE pool '{}' has the same ID as a sound effect",
+                    id
+                )));
+            }
+        }
         Ok(())
     }
 
+    /// Get a variation pool by ID
+    pub fn get_pool(&self, id: &str) -> Option<&SePool> {
+        self.pools.get(id)
+    }
+
     /// Get a sound effect by ID
     pub fn get(&self, id: &str) -> Option<&SeDef> {
         self.sounds.get(id)
@@ -217,6 +375,10 @@ fn default_volume() -> f32 {
     1.0
 }
 
+fn default_pitch_range() -> (f32, f32) {
+    (1.0, 1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,4 +430,52 @@ mod tests {
 
         assert_eq!(manifest, deserialized);
     }
+
+    #[test]
+    fn test_se_pool_roundtrip_and_defaults() {
+        let pool = SePool::new(
+            "footsteps",
+            vec!["step1.ogg".to_string(), "step2.ogg".to_string()],
+        )
+        .with_mode(SePoolMode::RoundRobin)
+        .with_pitch_range(0.9, 1.1);
+        let manifest = SeManifest::new().add_pool(pool);
+        assert!(manifest.validate().is_ok());
+
+        let ron_str = ron::to_string(&manifest).unwrap();
+        let deserialized: SeManifest = ron::from_str(&ron_str).unwrap();
+        assert_eq!(manifest, deserialized);
+
+        // Manifests written before pools existed still load
+        let legacy: SeManifest = ron::from_str("(sounds: {})").unwrap();
+        assert!(legacy.pools.is_empty());
+
+        let minimal: SePool = ron::from_str("(id: \"hit\", files: [\"hit.ogg\"])").unwrap();
+        assert_eq!(minimal.mode, SePoolMode::Random);
+        assert_eq!(minimal.pitch_range, (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_se_pool_validation() {
+        let files = vec!["a.ogg".to_string()];
+        assert!(SePool::new("empty", Vec::new()).validate().is_err());
+        assert!(
+            SePool::new("pitch", files.clone())
+                .with_pitch_range(1.2, 0.8)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            SePool::new("pitch", files.clone())
+                .with_pitch_range(0.1, 1.0)
+                .validate()
+                .is_err()
+        );
+
+        // A pool can't shadow a sound effect
+        let manifest = SeManifest::new()
+            .add_sound(SeDef::new("hit", "Hit", "hit.ogg"))
+            .add_pool(SePool::new("hit", files));
+        assert!(manifest.validate().is_err());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::error::EngineError;
+use crate::types::Color;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -35,6 +36,25 @@ use std::path::Path;
 ///         accent: (100, 150, 255, 255),
 ///         background: (255, 255, 255, 230),
 ///     )),
+///     fonts: Some((
+///         dialogue: Some("assets/fonts/NotoSansJP-Regular.ttf"),
+///     )),
+///     sounds: Some((
+///         click: Some("assets/audio/se/ui/click.ogg"),
+///     )),
+///     quick_menu: Some((
+///         orientation: Vertical,
+///         buttons: [
+///             (id: "save", icon: Some("assets/ui/icons/save.png")),
+///             (id: "load"),
+///             (id: "backlog", label: Some("History")),
+///             (id: "gallery"),
+///         ],
+///     )),
+///     subtitles: Some((
+///         font_size: Some(28.0),
+///         background: Some((0, 0, 0, 160)),
+///     )),
 /// )
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -57,6 +77,22 @@ pub struct UiThemeDef {
     /// Optional color palette
     #[serde(skip_serializing_if = "Option::is_none")]
     pub colors: Option<ColorPalette>,
+
+    /// Optional font overrides
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fonts: Option<ThemeFonts>,
+
+    /// Optional UI sound effects
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sounds: Option<ThemeSounds>,
+
+    /// Optional quick menu layout (`None` uses the built-in buttons)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quick_menu: Option<QuickMenuTheme>,
+
+    /// Optional subtitle caption look (`None` uses the palette and built-in sizes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitles: Option<SubtitleTheme>,
 }
 
 impl UiThemeDef {
@@ -69,6 +105,9 @@ impl UiThemeDef {
         self.dialogue_box.validate()?;
         self.buttons.validate()?;
         self.choices.validate()?;
+        if let Some(quick_menu) = &self.quick_menu {
+            quick_menu.validate()?;
+        }
 
         Ok(())
     }
@@ -180,6 +219,185 @@ pub struct ColorPalette {
     pub background: (u8, u8, u8, u8),
 }
 
+impl ColorPalette {
+    /// Convert an RGBA tuple (0-255) to a [`Color`]
+    pub fn to_color(rgba: (u8, u8, u8, u8)) -> Color {
+        Color::rgba8(rgba.0, rgba.1, rgba.2, rgba.3)
+    }
+}
+
+/// Font files used by a UI theme
+///
+/// Paths are relative to the project root. `None` falls back to the engine default font.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThemeFonts {
+    /// Font for dialogue text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialogue: Option<String>,
+
+    /// Font for speaker names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+
+    /// Font for menus and buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui: Option<String>,
+}
+
+/// Sound effects played by UI theme elements
+///
+/// Paths are relative to the project root. `None` plays no sound.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThemeSounds {
+    /// Button/choice click
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub click: Option<String>,
+
+    /// Button/choice hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hover: Option<String>,
+
+    /// Dialogue advance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advance: Option<String>,
+}
+
+/// Subtitle captions for sound effects, voices and video
+///
+/// Unset colors fall back to the theme palette, then to the built-in look.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubtitleTheme {
+    /// Font size in pixels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<f32>,
+
+    /// Text color (RGBA 0-255)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<(u8, u8, u8, u8)>,
+
+    /// Background box color (RGBA 0-255)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<(u8, u8, u8, u8)>,
+
+    /// Vertical position of the box top (0.0 = top, 1.0 = bottom)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vertical_position: Option<f32>,
+}
+
+/// Quick menu buttons and placement
+///
+/// Button IDs name a built-in action (see [`QuickMenuTheme::BUILT_IN_IDS`]).
+/// Any other ID is a custom entry, passed to the game's quick menu handler.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuickMenuTheme {
+    /// How the buttons are laid out
+    #[serde(default)]
+    pub orientation: QuickMenuOrientation,
+
+    /// Buttons, in display order
+    #[serde(default = "QuickMenuTheme::default_buttons")]
+    pub buttons: Vec<QuickMenuButtonDef>,
+}
+
+impl QuickMenuTheme {
+    /// IDs of the built-in quick menu actions
+    pub const BUILT_IN_IDS: &'static [&'static str] = &[
+        "skip",
+        "skip_to_choice",
+        "auto",
+        "backlog",
+        "quick_save",
+        "quick_load",
+        "save",
+        "load",
+        "menu",
+    ];
+
+    /// The buttons shown without a theme: Skip, Auto, Log, Save (quick save) and Menu
+    pub fn default_buttons() -> Vec<QuickMenuButtonDef> {
+        ["skip", "auto", "backlog", "quick_save", "menu"]
+            .into_iter()
+            .map(QuickMenuButtonDef::new)
+            .collect()
+    }
+
+    /// Check whether a button ID names a built-in action
+    pub fn is_built_in(id: &str) -> bool {
+        Self::BUILT_IN_IDS.contains(&id)
+    }
+
+    /// Validate the quick menu: button IDs must be set and unique
+    pub fn validate(&self) -> Result<(), String> {
+        let mut ids = std::collections::HashSet::new();
+        for button in &self.buttons {
+            if button.id.is_empty() {
+                return Err("Quick menu button ID cannot be empty".to_string());
+            }
+            if !ids.insert(button.id.as_str()) {
+                return Err(format!("Duplicate quick menu button '{}'", button.id));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for QuickMenuTheme {
+    fn default() -> Self {
+        Self {
+            orientation: QuickMenuOrientation::default(),
+            buttons: Self::default_buttons(),
+        }
+    }
+}
+
+/// Quick menu placement
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuickMenuOrientation {
+    /// A row of buttons above the dialogue box, aligned right
+    #[default]
+    Horizontal,
+    /// A column of buttons above the dialogue box's right edge
+    Vertical,
+}
+
+/// Quick menu button
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuickMenuButtonDef {
+    /// Built-in action ID or custom entry ID
+    pub id: String,
+
+    /// Button label (`None` uses the built-in label, or the ID for custom entries)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Icon image drawn before the label, relative to the project root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+}
+
+impl QuickMenuButtonDef {
+    /// Create a button with the default label and no icon
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: None,
+            icon: None,
+        }
+    }
+
+    /// Set the label
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the icon image
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
 /// UI Theme manifest - collection of UI themes
 ///
 /// # Example RON format
@@ -223,6 +441,17 @@ impl UiThemeManifest {
         Ok(manifest)
     }
 
+    /// Validate and save manifest to a RON file
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), EngineError> {
+        self.validate()?;
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path.as_ref(), content)?;
+        Ok(())
+    }
+
     /// Validate all UI themes in the manifest
     pub fn validate(&self) -> Result<(), EngineError> {
         for (id, theme) in &self.themes {
@@ -346,6 +575,10 @@ mod tests {
                 disabled: "choice_disabled.png".to_string(),
             },
             colors: None,
+            fonts: None,
+            sounds: None,
+            quick_menu: None,
+            subtitles: None,
         };
 
         let manifest = UiThemeManifest::new().add_theme(theme);
@@ -355,4 +588,96 @@ mod tests {
 
         assert_eq!(manifest, deserialized);
     }
+
+    #[test]
+    fn test_ui_theme_manifest_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("manifests/ui_themes.ron");
+
+        let theme = UiThemeDef {
+            id: "dark".to_string(),
+            name: "Dark Theme".to_string(),
+            dialogue_box: DialogueBoxAssets {
+                default: "box.png".to_string(),
+                variants: HashMap::new(),
+            },
+            buttons: create_test_button_assets(),
+            choices: ChoiceAssets {
+                idle: "choice_idle.png".to_string(),
+                hover: "choice_hover.png".to_string(),
+                disabled: "choice_disabled.png".to_string(),
+            },
+            colors: Some(ColorPalette {
+                text_primary: (255, 255, 255, 255),
+                text_secondary: (200, 200, 200, 255),
+                accent: (0, 217, 192, 255),
+                background: (0, 0, 0, 200),
+            }),
+            fonts: Some(ThemeFonts {
+                dialogue: Some("fonts/dialogue.ttf".to_string()),
+                ..Default::default()
+            }),
+            sounds: Some(ThemeSounds {
+                click: Some("se/click.ogg".to_string()),
+                ..Default::default()
+            }),
+            quick_menu: Some(QuickMenuTheme {
+                orientation: QuickMenuOrientation::Vertical,
+                buttons: vec![
+                    QuickMenuButtonDef::new("save").with_icon("ui/icons/save.png"),
+                    QuickMenuButtonDef::new("gallery").with_label("Gallery"),
+                ],
+            }),
+            subtitles: Some(SubtitleTheme {
+                font_size: Some(28.0),
+                background: Some((0, 0, 0, 160)),
+                ..Default::default()
+            }),
+        };
+        let manifest = UiThemeManifest::new().add_theme(theme);
+
+        manifest.save_to_file(&path).unwrap();
+        let loaded = UiThemeManifest::load_from_file(&path).unwrap();
+        assert_eq!(manifest, loaded);
+    }
+
+    #[test]
+    fn test_ui_theme_without_fonts_and_sounds_deserializes() {
+        let ron_str = r#"UiThemeDef(
+            id: "light",
+            name: "Light",
+            dialogue_box: (default: "box.png"),
+            buttons: (
+                continue_idle: "a.png", continue_hover: "a.png",
+                history_idle: "a.png", history_hover: "a.png",
+                skip_idle: "a.png", skip_hover: "a.png",
+                options_idle: "a.png", options_hover: "a.png",
+            ),
+            choices: (idle: "a.png", hover: "a.png", disabled: "a.png"),
+        )"#;
+        let theme: UiThemeDef = ron::from_str(ron_str).unwrap();
+        assert!(theme.fonts.is_none());
+        assert!(theme.sounds.is_none());
+        assert!(theme.quick_menu.is_none());
+    }
+
+    #[test]
+    fn test_quick_menu_theme_defaults_and_validation() {
+        let quick_menu: QuickMenuTheme = ron::from_str("(orientation: Vertical)").unwrap();
+        assert_eq!(quick_menu.orientation, QuickMenuOrientation::Vertical);
+        assert_eq!(quick_menu.buttons, QuickMenuTheme::default_buttons());
+        assert!(quick_menu.validate().is_ok());
+
+        assert!(QuickMenuTheme::is_built_in("load"));
+        assert!(!QuickMenuTheme::is_built_in("gallery"));
+
+        let duplicate = QuickMenuTheme {
+            buttons: vec![
+                QuickMenuButtonDef::new("skip"),
+                QuickMenuButtonDef::new("skip"),
+            ],
+            ..Default::default()
+        };
+        assert!(duplicate.validate().is_err());
+    }
 }
@@ -20,6 +20,9 @@ pub struct BacklogEntry {
     pub speaker: Speaker,
     /// Dialogue text
     pub text: String,
+    /// Translation shown with the text in dual-language mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
 }
 
 impl BacklogEntry {
@@ -35,9 +38,16 @@ impl BacklogEntry {
             command_index,
             speaker,
             text: text.into(),
+            translation: None,
         }
     }
 
+    /// Set the translation shown with the text
+    pub fn with_translation(mut self, translation: Option<String>) -> Self {
+        self.translation = translation;
+        self
+    }
+
     /// Get the speaker display name
     pub fn speaker_name(&self) -> &str {
         match &self.speaker {
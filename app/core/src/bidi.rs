@@ -0,0 +1,101 @@
+//! Paragraph direction of bidirectional text
+//!
+//! Text shaping reorders mixed Latin / Arabic / Hebrew runs itself; what it
+//! needs from the game is the paragraph direction, which decides where the
+//! line starts and how neutral characters (spaces, punctuation) attach.
+//! [`TextDirection::Auto`] takes it from the first strong character, as the
+//! Unicode Bidirectional Algorithm does.
+
+use serde::{Deserialize, Serialize};
+
+/// Left-to-right mark, forces a left-to-right paragraph it starts
+pub const LRM: &str = "\u{200E}";
+
+/// Right-to-left mark, forces a right-to-left paragraph it starts
+pub const RLM: &str = "\u{200F}";
+
+/// Paragraph direction of text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TextDirection {
+    /// Follow the first strong character of each paragraph (left-to-right without one)
+    #[default]
+    Auto,
+    /// Left to right (Latin, CJK, ...)
+    Ltr,
+    /// Right to left (Arabic, Hebrew, ...)
+    Rtl,
+}
+
+impl TextDirection {
+    /// Detect the direction of a paragraph from its first strong character
+    ///
+    /// Returns `None` for text without one (digits, punctuation, empty text).
+    pub fn detect(text: &str) -> Option<Self> {
+        match unicode_bidi::get_base_direction(text) {
+            unicode_bidi::Direction::Ltr => Some(Self::Ltr),
+            unicode_bidi::Direction::Rtl => Some(Self::Rtl),
+            unicode_bidi::Direction::Mixed => None,
+        }
+    }
+
+    /// Direction `text` is laid out in, resolving `Auto`
+    pub fn resolve(self, text: &str) -> Self {
+        match self {
+            Self::Auto => Self::detect(text).unwrap_or(Self::Ltr),
+            direction => direction,
+        }
+    }
+
+    /// Check if `text` is laid out right to left
+    pub fn is_rtl(self, text: &str) -> bool {
+        self.resolve(text) == Self::Rtl
+    }
+
+    /// Mark that forces this direction on a paragraph it starts, if not `Auto`
+    pub fn mark(self) -> Option<&'static str> {
+        match self {
+            Self::Auto => None,
+            Self::Ltr => Some(LRM),
+            Self::Rtl => Some(RLM),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_first_strong_character() {
+        assert_eq!(TextDirection::detect("Hello"), Some(TextDirection::Ltr));
+        assert_eq!(TextDirection::detect("שלום"), Some(TextDirection::Rtl));
+        assert_eq!(TextDirection::detect("مرحبا"), Some(TextDirection::Rtl));
+        assert_eq!(
+            TextDirection::detect("こんにちは"),
+            Some(TextDirection::Ltr)
+        );
+
+        // Mixed text follows its first strong character, skipping neutrals
+        assert_eq!(
+            TextDirection::detect("123, שלום Alice"),
+            Some(TextDirection::Rtl)
+        );
+        assert_eq!(
+            TextDirection::detect("Alice said שלום"),
+            Some(TextDirection::Ltr)
+        );
+        assert_eq!(TextDirection::detect("... 42 !"), None);
+        assert_eq!(TextDirection::detect(""), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert!(TextDirection::Auto.is_rtl("مرحبا Bob"));
+        assert!(!TextDirection::Auto.is_rtl("42"));
+        // Forced directions ignore the text
+        assert!(TextDirection::Rtl.is_rtl("Bob مرحبا"));
+        assert!(!TextDirection::Ltr.is_rtl("مرحبا"));
+        assert_eq!(TextDirection::Auto.mark(), None);
+        assert_eq!(TextDirection::Rtl.mark(), Some(RLM));
+    }
+}
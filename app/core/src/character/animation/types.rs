@@ -205,6 +205,19 @@ impl CharacterAnimation {
         }
     }
 
+    /// Limit shake and tremble amplitude to the small preset
+    ///
+    /// Used by comfort mode. Jumps and keyframe-based animations are unchanged.
+    pub fn with_reduced_shake(mut self) -> Self {
+        if let Self::Shake { intensity, .. } | Self::Tremble { intensity, .. } = &mut self {
+            let max_amplitude = IntensityPreset::Small.amplitude();
+            if intensity.amplitude() > max_amplitude {
+                *intensity = AnimationIntensity::custom(max_amplitude, intensity.count());
+            }
+        }
+        self
+    }
+
     /// Get the total duration for keyframe-based animations
     /// Returns None for legacy animations (use timing().total_duration() instead)
     pub fn keyframe_duration(&self) -> Option<f32> {
@@ -596,4 +609,19 @@ preset = "medium"
         assert_eq!(transform.x, 0.0);
         assert_eq!(transform.y, 0.0);
     }
+
+    #[test]
+    fn test_with_reduced_shake() {
+        let shake = CharacterAnimation::shake_with_intensity(AnimationIntensity::large());
+        let reduced = shake.with_reduced_shake();
+        let intensity = reduced.intensity().unwrap();
+        assert_eq!(intensity.amplitude(), IntensityPreset::Small.amplitude());
+        assert_eq!(intensity.count(), IntensityPreset::Large.count());
+
+        // Small shakes and jumps are kept as they are
+        let small = CharacterAnimation::shake_with_intensity(AnimationIntensity::small());
+        assert_eq!(small.clone().with_reduced_shake(), small);
+        let jump = CharacterAnimation::jump_with_intensity(AnimationIntensity::large());
+        assert_eq!(jump.clone().with_reduced_shake(), jump);
+    }
 }
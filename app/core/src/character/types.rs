@@ -1,5 +1,5 @@
 use super::CharacterPosition;
-use crate::types::Color;
+use crate::types::{AssetRef, Color};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -10,17 +10,23 @@ pub enum SpriteMode {
     #[default]
     Integrated,
 
-    /// Layered sprite composition (base + expression overlays)
-    /// NOT YET IMPLEMENTED - Data structure only
+    /// Sprite composited from stacked layers of the same canvas size
     ///
-    /// TODO(layered-sprites): Implement layered rendering
-    /// Required changes:
-    /// - CharacterSpriteElement: Support multiple texture layers
-    /// - Renderer: Add multi-texture compositing in shader
-    /// - Cache: Store Vec<u64> of texture IDs per character
+    /// Layers are drawn bottom to top: `base` (body), the selected outfit,
+    /// the expression image from [`CharacterDef::expressions`] (face), then
+    /// `accessories`. Transparent areas of each layer show the ones below.
     Layered {
-        poses: HashMap<String, String>,
-        expressions: HashMap<String, HashMap<String, Vec<String>>>,
+        /// Body layer every sprite starts from
+        base: String,
+        /// Map of outfit names to clothing layer paths
+        #[serde(default)]
+        outfits: HashMap<String, String>,
+        /// Outfit worn when a command does not name one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        default_outfit: Option<String>,
+        /// Layers drawn over the face, bottom to top
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        accessories: Vec<String>,
     },
 }
 
@@ -96,6 +102,70 @@ pub struct CharacterDef {
     /// Sprite rendering mode (Integrated or Layered)
     #[serde(default)]
     pub sprite_mode: SpriteMode,
+    /// Synthetic voice self-voicing reads this character's lines with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tts_voice: Option<TtsVoice>,
+}
+
+/// Synthetic voice for self-voicing (text-to-speech)
+///
+/// # Example RON format
+///
+/// ```ron
+/// tts_voice: Some((
+///     voice: Some("en-us+f3"),
+///     pitch: 1.3,
+///     rate: 1.1,
+/// )),
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TtsVoice {
+    /// System voice name (e.g. "en-us+f3" for eSpeak NG, "Samantha" on macOS),
+    /// or None for the default voice
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voice: Option<String>,
+    /// Pitch multiplier (1.0 = the voice's normal pitch)
+    #[serde(default = "default_tts_factor")]
+    pub pitch: f32,
+    /// Speaking rate multiplier, applied on top of the player's speech rate
+    #[serde(default = "default_tts_factor")]
+    pub rate: f32,
+}
+
+impl TtsVoice {
+    /// Create a voice using the named system voice
+    pub fn new(voice: impl Into<String>) -> Self {
+        Self {
+            voice: Some(voice.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the pitch multiplier
+    pub fn with_pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Set the speaking rate multiplier
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+}
+
+impl Default for TtsVoice {
+    fn default() -> Self {
+        Self {
+            voice: None,
+            pitch: default_tts_factor(),
+            rate: default_tts_factor(),
+        }
+    }
+}
+
+fn default_tts_factor() -> f32 {
+    1.0
 }
 
 impl CharacterDef {
@@ -117,6 +187,7 @@ impl CharacterDef {
             sprite_offset: None,
             sprite_scale: None,
             sprite_mode: SpriteMode::default(),
+            tts_voice: None,
         }
     }
 
@@ -149,6 +220,12 @@ impl CharacterDef {
         self
     }
 
+    /// Set the synthetic voice used by self-voicing
+    pub fn with_tts_voice(mut self, voice: TtsVoice) -> Self {
+        self.tts_voice = Some(voice);
+        self
+    }
+
     /// Set the description
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -182,16 +259,82 @@ impl CharacterDef {
         self.get_expression_sprite(&self.default_expression)
     }
 
+    /// Layer paths of the sprite for an expression and outfit, bottom to top
+    ///
+    /// Integrated sprites have the single expression image. Layered sprites
+    /// wear `outfit`, or the default outfit when it is None. Returns None if
+    /// the expression or outfit is not defined.
+    pub fn sprite_layers(&self, expression: &str, outfit: Option<&str>) -> Option<Vec<&str>> {
+        let face = self.get_expression_sprite(expression)?;
+        match &self.sprite_mode {
+            SpriteMode::Integrated => Some(vec![face]),
+            SpriteMode::Layered {
+                base,
+                outfits,
+                default_outfit,
+                accessories,
+            } => {
+                let mut layers = vec![base.as_str()];
+                if let Some(outfit) = outfit.or(default_outfit.as_deref()) {
+                    layers.push(outfits.get(outfit)?.as_str());
+                }
+                layers.push(face);
+                layers.extend(accessories.iter().map(String::as_str));
+                Some(layers)
+            }
+        }
+    }
+
+    /// Sprite asset for an expression and outfit
+    ///
+    /// Layered sprites are referenced by [`AssetRef::layered`], which the
+    /// engine composites into one image when loading it.
+    pub fn sprite_for(&self, expression: &str, outfit: Option<&str>) -> Option<AssetRef> {
+        self.sprite_layers(expression, outfit)
+            .map(AssetRef::layered)
+    }
+
+    /// Outfit worn in a layered sprite built by [`sprite_for`](Self::sprite_for)
+    ///
+    /// Returns None for integrated sprites and sprites without an outfit layer.
+    pub fn outfit_of(&self, sprite: &AssetRef) -> Option<&str> {
+        let SpriteMode::Layered { outfits, .. } = &self.sprite_mode else {
+            return None;
+        };
+        let layers: Vec<&str> = sprite.layers().collect();
+        outfits
+            .iter()
+            .find(|(_, path)| layers.contains(&path.as_str()))
+            .map(|(name, _)| name.as_str())
+    }
+
     /// Validate sprite mode configuration
     ///
-    /// Note: Layered sprite mode is accepted but not yet implemented for rendering.
-    /// The validation passes with a warning message returned in the Result.
+    /// Layered sprites need a base layer, and their default outfit must be
+    /// one of the outfits.
     pub fn validate_sprite_mode(&self) -> Result<(), String> {
         match &self.sprite_mode {
             SpriteMode::Integrated => Ok(()),
-            SpriteMode::Layered { .. } => {
-                // Layered mode is structurally valid but rendering not yet implemented
-                // Log this at the application level when loading characters
+            SpriteMode::Layered {
+                base,
+                outfits,
+                default_outfit,
+                ..
+            } => {
+                if base.is_empty() {
+                    return Err(format!(
+                        "Character '{}' layered sprite has no base layer",
+                        self.id
+                    ));
+                }
+                if let Some(outfit) = default_outfit
+                    && !outfits.contains_key(outfit)
+                {
+                    return Err(format!(
+                        "Character '{}' default outfit '{}' not found in outfits map",
+                        self.id, outfit
+                    ));
+                }
                 Ok(())
             }
         }
@@ -238,11 +381,33 @@ impl CharacterDef {
         path: impl AsRef<std::path::Path>,
     ) -> Result<Self, crate::error::EngineError> {
         let content = std::fs::read_to_string(path.as_ref())?;
+        Self::from_ron_str(&content)
+    }
+
+    /// Parse and validate a character definition from RON source
+    pub fn from_ron_str(content: &str) -> Result<Self, crate::error::EngineError> {
         let def: Self =
-            ron::from_str(&content).map_err(|e| crate::error::EngineError::RonSer(e.into()))?;
+            ron::from_str(content).map_err(|e| crate::error::EngineError::RonSer(e.into()))?;
         def.validate().map_err(crate::error::EngineError::Other)?;
         Ok(def)
     }
+
+    /// Save character definition to a RON file
+    ///
+    /// The definition is validated before writing so that a file produced
+    /// by this method can always be read back by [`CharacterDef::load_from_file`].
+    pub fn save_to_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), crate::error::EngineError> {
+        self.validate().map_err(crate::error::EngineError::Other)?;
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path.as_ref(), content)?;
+        Ok(())
+    }
 }
 
 /// Character state in a scene (runtime state)
@@ -356,9 +521,25 @@ impl CharacterManifest {
         path: impl AsRef<std::path::Path>,
     ) -> Result<Self, crate::error::EngineError> {
         let content = std::fs::read_to_string(path.as_ref())?;
-        let manifest: Self =
-            ron::from_str(&content).map_err(|e| crate::error::EngineError::RonSer(e.into()))?;
-        Ok(manifest)
+        Self::from_ron_str(&content)
+    }
+
+    /// Parse a manifest from RON source
+    pub fn from_ron_str(content: &str) -> Result<Self, crate::error::EngineError> {
+        ron::from_str(content).map_err(|e| crate::error::EngineError::RonSer(e.into()))
+    }
+
+    /// Save manifest to a RON file
+    pub fn save_to_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), crate::error::EngineError> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path.as_ref(), content)?;
+        Ok(())
     }
 }
 
@@ -624,6 +805,46 @@ mod tests {
         assert_eq!(manifest, deserialized);
     }
 
+    #[test]
+    fn test_character_def_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("characters/alice.ron");
+
+        let def = CharacterDef::new("alice", "Alice", "normal")
+            .with_expression("normal", "characters/alice/normal.png")
+            .with_color(255, 200, 200)
+            .with_sprite_offset(0.0, 75.0)
+            .with_sprite_scale(1.2);
+
+        def.save_to_file(&path).unwrap();
+        let loaded = CharacterDef::load_from_file(&path).unwrap();
+        assert_eq!(def, loaded);
+    }
+
+    #[test]
+    fn test_character_def_save_rejects_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("invalid.ron");
+
+        let def = CharacterDef::new("bob", "Bob", "normal");
+        assert!(def.save_to_file(&path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_character_manifest_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("manifest.ron");
+
+        let manifest = CharacterManifest::new()
+            .add_character("characters/alice.ron")
+            .add_character("characters/bob.ron");
+
+        manifest.save_to_file(&path).unwrap();
+        let loaded = CharacterManifest::load_from_file(&path).unwrap();
+        assert_eq!(manifest, loaded);
+    }
+
     #[test]
     fn test_sprite_mode_default() {
         assert_eq!(SpriteMode::default(), SpriteMode::Integrated);
@@ -643,19 +864,119 @@ mod tests {
 
         let def: CharacterDef = toml::from_str(toml_str).unwrap();
         assert_eq!(def.sprite_mode, SpriteMode::Integrated);
+        assert_eq!(def.tts_voice, None);
+    }
+
+    #[test]
+    fn test_tts_voice_ron() {
+        let def = CharacterDef::from_ron_str(
+            r#"CharacterDef(
+                id: "alice",
+                name: "Alice",
+                expressions: { "normal": "alice.png" },
+                default_expression: "normal",
+                tts_voice: Some((voice: Some("en-us+f3"), pitch: 1.3)),
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(
+            def.tts_voice,
+            Some(TtsVoice::new("en-us+f3").with_pitch(1.3))
+        );
+        assert_eq!(def.tts_voice.map(|voice| voice.rate), Some(1.0));
+    }
+
+    fn layered_alice() -> CharacterDef {
+        let mut def = CharacterDef::new("alice", "Alice", "normal")
+            .with_expression("normal", "alice/face_normal.png")
+            .with_expression("happy", "alice/face_happy.png");
+        def.sprite_mode = SpriteMode::Layered {
+            base: "alice/body.png".to_string(),
+            outfits: HashMap::from([
+                ("uniform".to_string(), "alice/uniform.png".to_string()),
+                ("casual".to_string(), "alice/casual.png".to_string()),
+            ]),
+            default_outfit: Some("uniform".to_string()),
+            accessories: vec!["alice/ribbon.png".to_string()],
+        };
+        def
     }
 
     #[test]
     fn test_layered_mode_validation() {
-        let mut def =
-            CharacterDef::new("test", "Test", "normal").with_expression("normal", "test.png");
+        let mut def = layered_alice();
+        assert!(def.validate().is_ok());
+
+        if let SpriteMode::Layered { default_outfit, .. } = &mut def.sprite_mode {
+            *default_outfit = Some("swimsuit".to_string());
+        }
+        assert!(def.validate().unwrap_err().contains("swimsuit"));
 
         def.sprite_mode = SpriteMode::Layered {
-            poses: HashMap::new(),
-            expressions: HashMap::new(),
+            base: String::new(),
+            outfits: HashMap::new(),
+            default_outfit: None,
+            accessories: Vec::new(),
         };
+        assert!(def.validate().unwrap_err().contains("base layer"));
+    }
+
+    #[test]
+    fn test_sprite_layers() {
+        let def = layered_alice();
+        assert_eq!(
+            def.sprite_layers("happy", None),
+            Some(vec![
+                "alice/body.png",
+                "alice/uniform.png",
+                "alice/face_happy.png",
+                "alice/ribbon.png",
+            ])
+        );
+        assert_eq!(
+            def.sprite_layers("normal", Some("casual")).map(|l| l[1]),
+            Some("alice/casual.png")
+        );
+        assert_eq!(def.sprite_layers("angry", None), None);
+        assert_eq!(def.sprite_layers("normal", Some("swimsuit")), None);
 
-        // Should validate successfully but with a warning (not tested here)
+        // Integrated sprites are the expression image alone
+        let integrated =
+            CharacterDef::new("bob", "Bob", "normal").with_expression("normal", "bob.png");
+        assert_eq!(
+            integrated.sprite_for("normal", None),
+            Some("bob.png".into())
+        );
+        let sprite = def.sprite_for("normal", Some("casual")).unwrap();
+        assert!(sprite.is_layered());
+        assert_eq!(def.outfit_of(&sprite), Some("casual"));
+        assert_eq!(integrated.outfit_of(&"bob.png".into()), None);
+    }
+
+    #[test]
+    fn test_layered_mode_toml() {
+        let toml_str = r#"
+            id = "alice"
+            name = "Alice"
+            default_expression = "normal"
+
+            [expressions]
+            normal = "alice/face_normal.png"
+
+            [sprite_mode.Layered]
+            base = "alice/body.png"
+            accessories = ["alice/ribbon.png"]
+        "#;
+
+        let def: CharacterDef = toml::from_str(toml_str).unwrap();
         assert!(def.validate().is_ok());
+        assert_eq!(
+            def.sprite_layers("normal", None),
+            Some(vec![
+                "alice/body.png",
+                "alice/face_normal.png",
+                "alice/ribbon.png"
+            ])
+        );
     }
 }
@@ -1,5 +1,7 @@
+use crate::expression::{Expression, ExpressionResult};
 use crate::scenario::VariableValue;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 
 /// Comparison operator for conditions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -80,8 +82,16 @@ impl CompareOp {
 }
 
 /// Condition for branching logic
+///
+/// In scenario files a condition is either a table tagged with `type` or an
+/// expression string (see [`crate::expression`]):
+///
+/// ```toml
+/// condition = { type = "Flag", flag_name = "met_alice" }
+/// condition = "affection >= 5 && !flags.betrayed"
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "type")]
+#[serde(tag = "type", remote = "Self")]
 pub enum Condition {
     /// Flag condition (check if flag is true/false)
     Flag {
@@ -111,6 +121,48 @@ pub enum Condition {
 
     /// Always false
     False,
+
+    /// Expression over flags and variables, true when its value is truthy
+    ///
+    /// Written as a plain string; an expression that fails to evaluate
+    /// (e.g. adds a number to a string) is false.
+    Expression { expression: Expression },
+}
+
+impl Serialize for Condition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Expression { expression } => expression.serialize(serializer),
+            _ => Condition::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Condition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ConditionVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ConditionVisitor {
+            type Value = Condition;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a condition table or expression string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, source: &str) -> Result<Condition, E> {
+                Condition::expression(source).map_err(E::custom)
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Condition, A::Error> {
+                Condition::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+            }
+        }
+
+        deserializer.deserialize_any(ConditionVisitor)
+    }
 }
 
 impl Condition {
@@ -131,6 +183,16 @@ impl Condition {
         }
     }
 
+    /// Parse an expression condition
+    ///
+    /// # Errors
+    /// Returns an error if the expression is malformed.
+    pub fn expression(source: impl Into<String>) -> ExpressionResult<Self> {
+        Ok(Self::Expression {
+            expression: Expression::parse(source)?,
+        })
+    }
+
     /// Create an AND condition
     pub fn and(conditions: Vec<Condition>) -> Self {
         Self::And { conditions }
@@ -193,6 +255,9 @@ impl Condition {
                 .iter()
                 .any(|cond| cond.evaluate(get_flag, get_variable)),
             Self::Not { condition } => !condition.evaluate(get_flag, get_variable),
+            Self::Expression { expression } => expression
+                .evaluate_bool(get_flag, get_variable)
+                .unwrap_or(false),
         }
     }
 }
@@ -394,6 +459,42 @@ mod tests {
         assert_eq!(and_cond, deserialized);
     }
 
+    #[test]
+    fn test_condition_expression_string_form() {
+        #[derive(Debug, Deserialize)]
+        struct Command {
+            condition: Condition,
+        }
+
+        let command: Command = toml::from_str(
+            r#"
+[condition]
+type = "And"
+conditions = ["flags.met_alice", { type = "Flag", flag_name = "trusted" }]
+"#,
+        )
+        .unwrap();
+        let get_flag = |name: &str| name == "met_alice" || name == "trusted";
+        assert!(command.condition.evaluate(&get_flag, &|_| None));
+
+        let cond = Condition::expression("affection >= 5 || flags.trusted").unwrap();
+        let serialized = serde_json::to_string(&cond).unwrap();
+        assert_eq!(serialized, r#""affection >= 5 || flags.trusted""#);
+        assert_eq!(
+            serde_json::from_str::<Condition>(&serialized).unwrap(),
+            cond
+        );
+
+        assert!(toml::from_str::<Command>(r#"condition = "affection = 5""#).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_expression_errors_are_false() {
+        let cond = Condition::expression("10 / count > 1").unwrap();
+        let get_var = |_: &str| Some(VariableValue::Int(0));
+        assert!(!cond.evaluate(&|_| false, &get_var));
+    }
+
     // =============================================================================
     // Condition Evaluation Tests
     // =============================================================================
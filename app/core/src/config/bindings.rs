@@ -0,0 +1,267 @@
+//! Remappable input bindings
+//!
+//! [`Bindings`] maps each logical [`InputAction`] to the keys, mouse buttons
+//! and gamepad buttons that trigger it. Keys are stored by their `KeyCode`
+//! variant name (e.g. "Enter", "PageUp", "F5"), which the engine and GUI key
+//! enums share, so the table serializes readably in `settings.ron`.
+
+use super::GamepadButton;
+use serde::{Deserialize, Serialize};
+
+/// In-game action that can be rebound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputAction {
+    /// Advance text
+    Advance,
+    /// Toggle skip mode
+    Skip,
+    /// Toggle auto mode
+    Auto,
+    /// Open the backlog
+    Backlog,
+    /// Hide the dialogue box and UI
+    HideUi,
+    /// Quick save
+    QuickSave,
+    /// Load the quick save
+    QuickLoad,
+}
+
+impl InputAction {
+    /// All actions, in settings menu order
+    pub const ALL: [Self; 7] = [
+        Self::Advance,
+        Self::Skip,
+        Self::Auto,
+        Self::Backlog,
+        Self::HideUi,
+        Self::QuickSave,
+        Self::QuickLoad,
+    ];
+
+    /// Name shown in the settings menu
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Advance => "Advance",
+            Self::Skip => "Skip",
+            Self::Auto => "Auto",
+            Self::Backlog => "Backlog",
+            Self::HideUi => "Hide UI",
+            Self::QuickSave => "Quick Save",
+            Self::QuickLoad => "Quick Load",
+        }
+    }
+}
+
+/// Mouse button that can be bound to an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MouseBinding {
+    Left,
+    Right,
+    Middle,
+}
+
+/// Input that triggers an action
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Binding {
+    /// Keyboard key, by `KeyCode` variant name
+    Key(String),
+    /// Mouse button
+    Mouse(MouseBinding),
+    /// Gamepad button
+    Gamepad(GamepadButton),
+}
+
+impl Binding {
+    /// Bind a key by its `KeyCode` variant name
+    pub fn key(name: impl Into<String>) -> Self {
+        Self::Key(name.into())
+    }
+
+    /// Check if both bindings are on the same device (keyboard, mouse or gamepad)
+    pub fn same_device(&self, other: &Binding) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Name shown in the settings menu (e.g. "Enter", "Right Click", "Pad South")
+    pub fn label(&self) -> String {
+        match self {
+            Self::Key(name) => name.clone(),
+            Self::Mouse(MouseBinding::Left) => "Left Click".to_string(),
+            Self::Mouse(MouseBinding::Right) => "Right Click".to_string(),
+            Self::Mouse(MouseBinding::Middle) => "Middle Click".to_string(),
+            Self::Gamepad(button) => format!("Pad {:?}", button),
+        }
+    }
+}
+
+/// Inputs bound to each action
+///
+/// Defaults: Enter / Space / left click advance, S skips, A toggles auto mode,
+/// B opens the backlog, H / right click hide the UI, Q quick saves and L loads
+/// the quick save.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bindings {
+    pub advance: Vec<Binding>,
+    pub skip: Vec<Binding>,
+    pub auto: Vec<Binding>,
+    pub backlog: Vec<Binding>,
+    pub hide_ui: Vec<Binding>,
+    pub quick_save: Vec<Binding>,
+    pub quick_load: Vec<Binding>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        Self {
+            advance: vec![
+                Binding::key("Enter"),
+                Binding::key("Space"),
+                Binding::Mouse(MouseBinding::Left),
+                Binding::Gamepad(GamepadButton::South),
+            ],
+            skip: vec![Binding::key("S"), Binding::Gamepad(GamepadButton::North)],
+            auto: vec![Binding::key("A"), Binding::Gamepad(GamepadButton::West)],
+            backlog: vec![
+                Binding::key("B"),
+                Binding::Gamepad(GamepadButton::LeftShoulder),
+            ],
+            hide_ui: vec![
+                Binding::key("H"),
+                Binding::Mouse(MouseBinding::Right),
+                Binding::Gamepad(GamepadButton::East),
+            ],
+            quick_save: vec![
+                Binding::key("Q"),
+                Binding::Gamepad(GamepadButton::RightShoulder),
+            ],
+            quick_load: vec![Binding::key("L")],
+        }
+    }
+}
+
+impl Bindings {
+    /// Inputs bound to an action
+    pub fn get(&self, action: InputAction) -> &[Binding] {
+        match action {
+            InputAction::Advance => &self.advance,
+            InputAction::Skip => &self.skip,
+            InputAction::Auto => &self.auto,
+            InputAction::Backlog => &self.backlog,
+            InputAction::HideUi => &self.hide_ui,
+            InputAction::QuickSave => &self.quick_save,
+            InputAction::QuickLoad => &self.quick_load,
+        }
+    }
+
+    fn get_mut(&mut self, action: InputAction) -> &mut Vec<Binding> {
+        match action {
+            InputAction::Advance => &mut self.advance,
+            InputAction::Skip => &mut self.skip,
+            InputAction::Auto => &mut self.auto,
+            InputAction::Backlog => &mut self.backlog,
+            InputAction::HideUi => &mut self.hide_ui,
+            InputAction::QuickSave => &mut self.quick_save,
+            InputAction::QuickLoad => &mut self.quick_load,
+        }
+    }
+
+    /// Action an input is bound to, if any
+    pub fn action_for(&self, binding: &Binding) -> Option<InputAction> {
+        InputAction::ALL
+            .into_iter()
+            .find(|action| self.get(*action).contains(binding))
+    }
+
+    /// Action a key is bound to, by `KeyCode` variant name
+    pub fn action_for_key(&self, name: &str) -> Option<InputAction> {
+        InputAction::ALL.into_iter().find(|action| {
+            self.get(*action)
+                .iter()
+                .any(|binding| matches!(binding, Binding::Key(key) if key == name))
+        })
+    }
+
+    /// Bind an input to an action
+    ///
+    /// The input replaces the action's other bindings on the same device
+    /// (rebinding a key keeps the mouse and gamepad bindings) and is removed
+    /// from any other action, so one input never triggers two actions.
+    pub fn rebind(&mut self, action: InputAction, binding: Binding) {
+        for other in InputAction::ALL {
+            self.get_mut(other).retain(|bound| *bound != binding);
+        }
+        let bindings = self.get_mut(action);
+        bindings.retain(|bound| !bound.same_device(&binding));
+        bindings.push(binding);
+    }
+
+    /// Bindings of an action for display (e.g. "Enter, Space, Left Click")
+    pub fn describe(&self, action: InputAction) -> String {
+        let labels: Vec<String> = self.get(action).iter().map(Binding::label).collect();
+        if labels.is_empty() {
+            "Unbound".to_string()
+        } else {
+            labels.join(", ")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_bindings() {
+        let bindings = Bindings::default();
+        assert_eq!(bindings.action_for_key("Space"), Some(InputAction::Advance));
+        assert_eq!(bindings.action_for_key("H"), Some(InputAction::HideUi));
+        assert_eq!(
+            bindings.action_for(&Binding::Mouse(MouseBinding::Right)),
+            Some(InputAction::HideUi)
+        );
+        assert_eq!(bindings.action_for_key("L"), Some(InputAction::QuickLoad));
+        assert_eq!(bindings.action_for_key("Escape"), None);
+        assert_eq!(
+            bindings.describe(InputAction::Advance),
+            "Enter, Space, Left Click, Pad South"
+        );
+    }
+
+    #[test]
+    fn test_rebind_replaces_same_device_and_steals_input() {
+        let mut bindings = Bindings::default();
+        bindings.rebind(InputAction::Skip, Binding::key("Space"));
+
+        // The key replaces S but keeps the gamepad binding
+        assert_eq!(
+            bindings.skip,
+            [
+                Binding::Gamepad(GamepadButton::North),
+                Binding::key("Space")
+            ]
+        );
+        // ... and no longer advances
+        assert_eq!(bindings.action_for_key("Space"), Some(InputAction::Skip));
+        assert!(!bindings.advance.contains(&Binding::key("Space")));
+        assert_eq!(bindings.action_for_key("S"), None);
+    }
+
+    #[test]
+    fn test_bindings_roundtrip() {
+        let mut bindings = Bindings::default();
+        bindings.rebind(InputAction::QuickSave, Binding::key("F5"));
+        let serialized = ron::to_string(&bindings).unwrap();
+        let loaded: Bindings = ron::from_str(&serialized).unwrap();
+        assert_eq!(loaded, bindings);
+
+        // Missing actions keep their defaults
+        let partial: Bindings = ron::from_str("(skip: [key(\"K\")])").unwrap();
+        assert_eq!(partial.skip, [Binding::key("K")]);
+        assert_eq!(partial.advance, Bindings::default().advance);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{AudioConfig, GraphicsConfig, PathConfig, TextConfig};
+use super::{AudioConfig, GraphicsConfig, PathConfig, SaveSlotConfig, TextConfig};
 use crate::error::ConfigError;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -21,6 +21,9 @@ pub struct GameConfig {
     /// Path settings
     #[serde(default)]
     pub paths: PathConfig,
+    /// Save slot layout
+    #[serde(default)]
+    pub saves: SaveSlotConfig,
 }
 
 impl GameConfig {
@@ -32,6 +35,7 @@ impl GameConfig {
             audio: AudioConfig::default(),
             text: TextConfig::default(),
             paths: PathConfig::default(),
+            saves: SaveSlotConfig::default(),
         }
     }
 
@@ -42,6 +46,7 @@ impl GameConfig {
 
         // Validate paths configuration (ensures all asset paths are relative and safe)
         config.paths.validate()?;
+        config.saves.validate()?;
 
         Ok(config)
     }
@@ -90,6 +95,9 @@ pub struct GameMetadata {
     /// Window title (defaults to game title if not set)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub window_title: Option<String>,
+    /// Window icon image path (PNG recommended)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
 }
 
 impl GameMetadata {
@@ -100,6 +108,7 @@ impl GameMetadata {
             version: default_version(),
             developer: None,
             window_title: None,
+            icon: None,
         }
     }
 
@@ -155,6 +164,17 @@ mod tests {
         assert_eq!(metadata.get_window_title(), "Custom Window Title");
     }
 
+    #[test]
+    fn test_game_metadata_icon_from_ron() {
+        let metadata: GameMetadata =
+            ron::from_str(r#"(title: "Icon Game", icon: Some("assets/icon.png"))"#).unwrap();
+        assert_eq!(metadata.icon.as_deref(), Some("assets/icon.png"));
+        assert_eq!(metadata.version, "0.1.0");
+
+        let metadata: GameMetadata = ron::from_str(r#"(title: "No Icon")"#).unwrap();
+        assert!(metadata.icon.is_none());
+    }
+
     #[test]
     fn test_game_metadata_with_developer() {
         let mut metadata = GameMetadata::new("My Game");
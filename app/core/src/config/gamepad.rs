@@ -0,0 +1,43 @@
+//! Gamepad buttons
+
+use serde::{Deserialize, Serialize};
+
+/// Gamepad button, named by position (South is A on Xbox, Cross on PlayStation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GamepadButton {
+    /// Bottom face button (A / Cross)
+    South,
+    /// Right face button (B / Circle)
+    East,
+    /// Left face button (X / Square)
+    West,
+    /// Top face button (Y / Triangle)
+    North,
+    LeftShoulder,
+    RightShoulder,
+    /// View / Back / Share
+    Select,
+    /// Menu / Start / Options
+    Start,
+    /// Xbox / PS button
+    Guide,
+    /// Left stick click
+    LeftStick,
+    /// Right stick click
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    /// Check if the button is a D-pad direction
+    pub fn is_dpad(self) -> bool {
+        matches!(
+            self,
+            Self::DPadUp | Self::DPadDown | Self::DPadLeft | Self::DPadRight
+        )
+    }
+}
@@ -1,16 +1,26 @@
 pub mod audio;
+pub mod bindings;
 pub mod game;
+pub mod gamepad;
 pub mod graphics;
 pub mod paths;
+pub mod project;
+pub mod saves;
+pub mod settings_schema;
 pub mod skip;
 pub mod text;
 pub mod ui;
 pub mod user_settings;
 
 pub use audio::*;
+pub use bindings::*;
 pub use game::*;
+pub use gamepad::*;
 pub use graphics::*;
 pub use paths::*;
+pub use project::*;
+pub use saves::*;
+pub use settings_schema::*;
 pub use skip::*;
 pub use text::*;
 pub use ui::*;
@@ -1,4 +1,5 @@
 use crate::error::ConfigError;
+use crate::scenario::VoiceResolver;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -17,6 +18,12 @@ pub struct PathConfig {
     /// Character definitions directory
     #[serde(default = "default_characters_path")]
     pub characters: PathBuf,
+    /// Voice directory, relative to `assets`, for voice files matched by naming convention
+    ///
+    /// When set, character lines without an explicit voice play
+    /// `{voice_dir}/{character}/{scene}_{line}.ogg`. See [`VoiceResolver`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voice_dir: Option<PathBuf>,
 }
 
 impl PathConfig {
@@ -35,11 +42,14 @@ impl PathConfig {
         Self::validate_path(&self.assets, "assets")?;
         Self::validate_path(&self.saves, "saves")?;
         Self::validate_path(&self.characters, "characters")?;
+        if let Some(voice_dir) = &self.voice_dir {
+            Self::validate_path(voice_dir, "voice_dir")?;
+        }
         Ok(())
     }
 
     /// Validate a single path
-    fn validate_path(path: &Path, field_name: &str) -> Result<(), ConfigError> {
+    pub(crate) fn validate_path(path: &Path, field_name: &str) -> Result<(), ConfigError> {
         // Check if path is absolute
         if path.is_absolute() {
             return Err(ConfigError::InvalidValue(
@@ -80,6 +90,13 @@ impl PathConfig {
     pub fn character_path(&self, character: impl AsRef<Path>) -> PathBuf {
         self.characters.join(character)
     }
+
+    /// Get the convention-based voice resolver (`None` when `voice_dir` is not set)
+    pub fn voice_resolver(&self) -> Option<VoiceResolver> {
+        self.voice_dir
+            .as_ref()
+            .map(|dir| VoiceResolver::new(dir.to_string_lossy().replace('\\', "/")))
+    }
 }
 
 impl Default for PathConfig {
@@ -89,6 +106,7 @@ impl Default for PathConfig {
             assets: default_assets_path(),
             saves: default_saves_path(),
             characters: default_characters_path(),
+            voice_dir: None,
         }
     }
 }
@@ -242,4 +260,24 @@ mod tests {
         config.scenarios = PathBuf::from("data/scenarios/chapter01");
         assert!(config.validate().is_ok());
     }
+    #[test]
+    fn test_path_config_voice_dir() {
+        let mut config = PathConfig::new();
+        assert!(config.voice_resolver().is_none());
+
+        // Older configs without voice_dir still load
+        let loaded: PathConfig = ron::from_str("(assets: \"assets/\")").unwrap();
+        assert_eq!(loaded.voice_dir, None);
+
+        config.voice_dir = Some(PathBuf::from("voice"));
+        assert!(config.validate().is_ok());
+        let resolver = config.voice_resolver().unwrap();
+        assert_eq!(
+            resolver.path_for("alice", "park", 3),
+            "voice/alice/park_3.ogg"
+        );
+
+        config.voice_dir = Some(PathBuf::from("../voice"));
+        assert!(config.validate().is_err());
+    }
 }
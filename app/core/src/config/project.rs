@@ -0,0 +1,225 @@
+use super::PathConfig;
+use crate::error::ConfigError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project manifest listing the scenario files of a multi-scenario project
+///
+/// Scenarios are played in the order they are listed. Entries sharing a
+/// `route` form a separate line that is followed in order as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectManifest {
+    /// ID of the scenario a new game starts with (defaults to the first entry)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    /// Scenario files in play order
+    pub scenarios: Vec<ScenarioEntry>,
+}
+
+/// A scenario file referenced by the project manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioEntry {
+    /// Scenario ID (matches the `id` in the scenario's `[chapter]` table)
+    pub id: String,
+    /// Path to the scenario file
+    pub path: PathBuf,
+    /// Route this scenario belongs to (`None` for the common route)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+}
+
+impl ScenarioEntry {
+    /// Create a new scenario entry on the common route
+    pub fn new(id: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            id: id.into(),
+            path: path.into(),
+            route: None,
+        }
+    }
+
+    /// Put the scenario on a route
+    pub fn with_route(mut self, route: impl Into<String>) -> Self {
+        self.route = Some(route.into());
+        self
+    }
+}
+
+impl ProjectManifest {
+    /// Create a manifest from scenario entries
+    pub fn new(scenarios: Vec<ScenarioEntry>) -> Self {
+        Self {
+            start: None,
+            scenarios,
+        }
+    }
+
+    /// Load a project manifest from a RON file
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let contents = fs::read_to_string(path.as_ref())?;
+        Self::from_ron_str(&contents)
+    }
+
+    /// Parse and validate a project manifest from RON source
+    pub fn from_ron_str(contents: &str) -> Result<Self, ConfigError> {
+        let manifest: Self = ron::from_str(contents)?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Validate the manifest
+    ///
+    /// Checks that scenario IDs are unique, that the start scenario exists and
+    /// that all paths are relative without path traversal.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut ids = HashSet::new();
+        for entry in &self.scenarios {
+            if !ids.insert(entry.id.as_str()) {
+                return Err(ConfigError::InvalidValue(
+                    "scenarios".to_string(),
+                    format!("Duplicate scenario ID: {}", entry.id),
+                ));
+            }
+            PathConfig::validate_path(&entry.path, "scenarios")?;
+        }
+
+        if let Some(start) = &self.start
+            && !ids.contains(start.as_str())
+        {
+            return Err(ConfigError::InvalidValue(
+                "start".to_string(),
+                format!("Unknown scenario ID: {}", start),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get a scenario entry by ID
+    pub fn get(&self, id: &str) -> Option<&ScenarioEntry> {
+        self.scenarios.iter().find(|entry| entry.id == id)
+    }
+
+    /// Get the scenario a new game starts with
+    pub fn start_scenario(&self) -> Option<&ScenarioEntry> {
+        match &self.start {
+            Some(id) => self.get(id),
+            None => self.scenarios.first(),
+        }
+    }
+
+    /// Get the scenario following `id` on the same route
+    pub fn next_scenario(&self, id: &str) -> Option<&ScenarioEntry> {
+        let index = self.scenarios.iter().position(|entry| entry.id == id)?;
+        let route = self.scenarios.get(index)?.route.as_deref();
+        self.scenarios
+            .iter()
+            .skip(index.saturating_add(1))
+            .find(|entry| entry.route.as_deref() == route)
+    }
+
+    /// Iterate over the scenarios of a route in play order
+    pub fn route<'a>(&'a self, route: &'a str) -> impl Iterator<Item = &'a ScenarioEntry> + 'a {
+        self.scenarios
+            .iter()
+            .filter(move |entry| entry.route.as_deref() == Some(route))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> ProjectManifest {
+        ProjectManifest::new(vec![
+            ScenarioEntry::new("prologue", "assets/scenarios/prologue.toml"),
+            ScenarioEntry::new("chapter_01", "assets/scenarios/chapter_01.toml"),
+            ScenarioEntry::new("alice_01", "assets/scenarios/alice_01.toml").with_route("alice"),
+            ScenarioEntry::new("bob_01", "assets/scenarios/bob_01.toml").with_route("bob"),
+            ScenarioEntry::new("alice_02", "assets/scenarios/alice_02.toml").with_route("alice"),
+        ])
+    }
+
+    #[test]
+    fn test_project_manifest_order_and_routes() {
+        let manifest = manifest();
+        assert!(manifest.validate().is_ok());
+
+        assert_eq!(
+            manifest.start_scenario().map(|e| e.id.as_str()),
+            Some("prologue")
+        );
+        assert_eq!(
+            manifest.next_scenario("prologue").map(|e| e.id.as_str()),
+            Some("chapter_01")
+        );
+        // The common route does not continue into a character route
+        assert!(manifest.next_scenario("chapter_01").is_none());
+        assert_eq!(
+            manifest.next_scenario("alice_01").map(|e| e.id.as_str()),
+            Some("alice_02")
+        );
+        assert!(manifest.next_scenario("unknown").is_none());
+
+        let alice: Vec<_> = manifest.route("alice").map(|e| e.id.as_str()).collect();
+        assert_eq!(alice, vec!["alice_01", "alice_02"]);
+    }
+
+    #[test]
+    fn test_project_manifest_validate() {
+        let mut manifest = manifest();
+        manifest.start = Some("missing".to_string());
+        assert!(matches!(
+            manifest.validate(),
+            Err(ConfigError::InvalidValue(_, _))
+        ));
+
+        let mut manifest = ProjectManifest::new(vec![
+            ScenarioEntry::new("a", "a.toml"),
+            ScenarioEntry::new("a", "b.toml"),
+        ]);
+        assert!(manifest.validate().is_err());
+
+        manifest.scenarios = vec![ScenarioEntry::new("a", "../a.toml")];
+        assert!(manifest.validate().is_err());
+    }
+
+    #[test]
+    fn test_project_manifest_from_ron() {
+        let manifest: ProjectManifest = ron::from_str(
+            r#"(
+                start: Some("chapter_01"),
+                scenarios: [
+                    (id: "chapter_01", path: "assets/scenarios/chapter_01.toml"),
+                    (id: "alice_01", path: "assets/scenarios/alice_01.toml", route: Some("alice")),
+                ],
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.scenarios.len(), 2);
+        assert_eq!(
+            manifest.start_scenario().map(|e| e.path.clone()),
+            Some(PathBuf::from("assets/scenarios/chapter_01.toml"))
+        );
+        assert_eq!(
+            manifest.get("alice_01").and_then(|e| e.route.as_deref()),
+            Some("alice")
+        );
+    }
+
+    #[test]
+    fn test_project_manifest_from_ron_str_validates() {
+        let manifest =
+            ProjectManifest::from_ron_str(r#"(scenarios: [(id: "a", path: "a.toml")])"#).unwrap();
+        assert_eq!(manifest.scenarios.len(), 1);
+
+        // Syntax errors and invalid manifests are both errors
+        assert!(ProjectManifest::from_ron_str("(scenarios: [").is_err());
+        assert!(
+            ProjectManifest::from_ron_str(r#"(scenarios: [(id: "a", path: "../a.toml")])"#)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! Save slot layout and thumbnail configuration
+
+use crate::error::ConfigError;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::time::Duration;
+
+/// Kind of a save slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SaveSlotKind {
+    /// Written by the game when autosaving
+    Auto,
+    /// Written by quick save
+    Quick,
+    /// Chosen by the player in the save menu
+    Manual,
+}
+
+impl SaveSlotKind {
+    /// Section label shown in the save/load menu
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::Quick => "Quick",
+            Self::Manual => "Manual",
+        }
+    }
+
+    /// Whether the player can't overwrite or delete slots of this kind manually
+    pub fn is_protected(&self) -> bool {
+        !matches!(self, Self::Manual)
+    }
+}
+
+/// Save slot layout
+///
+/// Slot numbers are laid out as autosave slots first, then quick save slots,
+/// then manual slots. With the defaults, quick save keeps using slot 0 and
+/// manual saves use slots 1 to 30. Each save also stores a thumbnail of the
+/// screen at `thumbnail_size`. With `reminder_minutes` set, the game suggests
+/// saving after that much play time without a save.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveSlotConfig {
+    /// Number of reserved autosave slots
+    #[serde(default)]
+    pub autosave_slots: usize,
+    /// Number of reserved quick save slots
+    #[serde(default = "default_quicksave_slots")]
+    pub quicksave_slots: usize,
+    /// Number of slots the player saves to from the save menu
+    #[serde(default = "default_manual_slots")]
+    pub manual_slots: usize,
+    /// Size of save thumbnails and screen previews in pixels (width, height)
+    #[serde(default = "default_thumbnail_size")]
+    pub thumbnail_size: (u32, u32),
+    /// Minutes of play without saving before a save reminder is shown
+    /// (`None` disables the reminder)
+    #[serde(default)]
+    pub reminder_minutes: Option<u32>,
+}
+
+fn default_quicksave_slots() -> usize {
+    1
+}
+
+fn default_manual_slots() -> usize {
+    30
+}
+
+fn default_thumbnail_size() -> (u32, u32) {
+    (320, 180)
+}
+
+impl Default for SaveSlotConfig {
+    fn default() -> Self {
+        Self {
+            autosave_slots: 0,
+            quicksave_slots: default_quicksave_slots(),
+            manual_slots: default_manual_slots(),
+            thumbnail_size: default_thumbnail_size(),
+            reminder_minutes: None,
+        }
+    }
+}
+
+impl SaveSlotConfig {
+    /// Upper bound for the total number of slots
+    pub const MAX_SLOTS: usize = 999;
+
+    /// Upper bound for either thumbnail dimension
+    pub const MAX_THUMBNAIL_SIZE: u32 = 1920;
+
+    /// Validate the slot layout
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.manual_slots == 0 {
+            return Err(ConfigError::InvalidValue(
+                "saves.manual_slots".to_string(),
+                "At least one manual save slot is required".to_string(),
+            ));
+        }
+        if self.total_slots() > Self::MAX_SLOTS {
+            return Err(ConfigError::InvalidValue(
+                "saves".to_string(),
+                format!("At most {} save slots are supported", Self::MAX_SLOTS),
+            ));
+        }
+        let (width, height) = self.thumbnail_size;
+        if !(1..=Self::MAX_THUMBNAIL_SIZE).contains(&width)
+            || !(1..=Self::MAX_THUMBNAIL_SIZE).contains(&height)
+        {
+            return Err(ConfigError::InvalidValue(
+                "saves.thumbnail_size".to_string(),
+                format!(
+                    "Thumbnail size must be between 1 and {} pixels per side",
+                    Self::MAX_THUMBNAIL_SIZE
+                ),
+            ));
+        }
+        if self.reminder_minutes == Some(0) {
+            return Err(ConfigError::InvalidValue(
+                "saves.reminder_minutes".to_string(),
+                "Save reminder interval must be at least one minute".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Save reminder interval, if the reminder is enabled
+    pub fn reminder_interval(&self) -> Option<Duration> {
+        self.reminder_minutes
+            .map(|minutes| Duration::from_secs(u64::from(minutes) * 60))
+    }
+
+    /// Total number of slots of all kinds
+    pub fn total_slots(&self) -> usize {
+        self.autosave_slots
+            .saturating_add(self.quicksave_slots)
+            .saturating_add(self.manual_slots)
+    }
+
+    /// Slot numbers of a kind
+    pub fn slots(&self, kind: SaveSlotKind) -> Range<usize> {
+        let quick_start = self.autosave_slots;
+        let manual_start = quick_start.saturating_add(self.quicksave_slots);
+        match kind {
+            SaveSlotKind::Auto => 0..quick_start,
+            SaveSlotKind::Quick => quick_start..manual_start,
+            SaveSlotKind::Manual => manual_start..self.total_slots(),
+        }
+    }
+
+    /// Kind of a slot (`None` if the slot is out of range)
+    pub fn kind_of(&self, slot: usize) -> Option<SaveSlotKind> {
+        [
+            SaveSlotKind::Auto,
+            SaveSlotKind::Quick,
+            SaveSlotKind::Manual,
+        ]
+        .into_iter()
+        .find(|kind| self.slots(*kind).contains(&slot))
+    }
+
+    /// Whether a slot is reserved for autosave or quick save
+    pub fn is_protected(&self, slot: usize) -> bool {
+        self.kind_of(slot).is_none_or(|kind| kind.is_protected())
+    }
+
+    /// Slot written by quick save (`None` if quick save has no slot)
+    pub fn quicksave_slot(&self) -> Option<usize> {
+        let slots = self.slots(SaveSlotKind::Quick);
+        (!slots.is_empty()).then_some(slots.start)
+    }
+
+    /// Position of a slot within its section (0-indexed)
+    pub fn index_in_section(&self, slot: usize) -> Option<usize> {
+        let kind = self.kind_of(slot)?;
+        Some(slot.saturating_sub(self.slots(kind).start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_layout_keeps_quick_save_in_slot_zero() {
+        let config = SaveSlotConfig::default();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.total_slots(), 31);
+        assert_eq!(config.quicksave_slot(), Some(0));
+        assert!(config.slots(SaveSlotKind::Auto).is_empty());
+        assert_eq!(config.slots(SaveSlotKind::Manual), 1..31);
+        assert!(config.is_protected(0));
+        assert!(!config.is_protected(1));
+        assert!(config.is_protected(31));
+    }
+
+    #[test]
+    fn test_slot_kinds_and_sections() {
+        let config = SaveSlotConfig {
+            autosave_slots: 3,
+            quicksave_slots: 2,
+            manual_slots: 10,
+            ..Default::default()
+        };
+        assert_eq!(config.kind_of(0), Some(SaveSlotKind::Auto));
+        assert_eq!(config.kind_of(3), Some(SaveSlotKind::Quick));
+        assert_eq!(config.kind_of(5), Some(SaveSlotKind::Manual));
+        assert_eq!(config.kind_of(15), None);
+        assert_eq!(config.quicksave_slot(), Some(3));
+        assert_eq!(config.index_in_section(6), Some(1));
+
+        let no_quick = SaveSlotConfig {
+            quicksave_slots: 0,
+            ..config
+        };
+        assert_eq!(no_quick.quicksave_slot(), None);
+    }
+
+    #[test]
+    fn test_validate_and_deserialize() {
+        let config = SaveSlotConfig {
+            manual_slots: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = SaveSlotConfig {
+            manual_slots: SaveSlotConfig::MAX_SLOTS,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config: SaveSlotConfig =
+            ron::from_str("(autosave_slots: 2, manual_slots: 12)").unwrap();
+        assert_eq!(config.quicksave_slots, 1);
+        assert_eq!(config.slots(SaveSlotKind::Manual), 3..15);
+        assert_eq!(config.thumbnail_size, (320, 180));
+
+        let config = SaveSlotConfig {
+            thumbnail_size: (0, 90),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config: SaveSlotConfig = ron::from_str("(thumbnail_size: (480, 270))").unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.thumbnail_size, (480, 270));
+        assert_eq!(config.reminder_interval(), None);
+
+        let config: SaveSlotConfig = ron::from_str("(reminder_minutes: Some(30))").unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.reminder_interval(), Some(Duration::from_secs(1800)));
+
+        let config = SaveSlotConfig {
+            reminder_minutes: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
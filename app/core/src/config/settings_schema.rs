@@ -0,0 +1,536 @@
+//! Declarative schema of the user settings
+//!
+//! Each player-facing setting is declared once in [`SETTINGS_SCHEMA`] with its
+//! name, settings menu tab, value type, range and default, and how it is read
+//! from and written to [`UserSettings`]. The settings menu builds its widgets
+//! from the schema, and [`UserSettings::load`] uses it to bring hand-edited
+//! values back into range, so every setting renders and persists the same way.
+
+use super::user_settings::{SettingsError, UserSettings};
+use super::{SkipMode, TextSpeed};
+
+/// Settings menu tab a setting is shown on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SettingsCategory {
+    #[default]
+    Display,
+    Audio,
+    Text,
+    Controls,
+    Accessibility,
+}
+
+impl SettingsCategory {
+    /// All tabs, in display order
+    pub const ALL: [Self; 5] = [
+        Self::Display,
+        Self::Audio,
+        Self::Text,
+        Self::Controls,
+        Self::Accessibility,
+    ];
+
+    /// Tab label
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Display => "Display",
+            Self::Audio => "Audio",
+            Self::Text => "Text",
+            Self::Controls => "Controls",
+            Self::Accessibility => "Accessibility",
+        }
+    }
+
+    /// Position in [`Self::ALL`]
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|category| *category == self)
+            .unwrap_or(0)
+    }
+
+    /// Tab to the right, wrapping around
+    pub fn next(self) -> Self {
+        let index = (self.index() + 1) % Self::ALL.len();
+        Self::ALL.get(index).copied().unwrap_or_default()
+    }
+
+    /// Tab to the left, wrapping around
+    pub fn previous(self) -> Self {
+        let index = (self.index() + Self::ALL.len() - 1) % Self::ALL.len();
+        Self::ALL.get(index).copied().unwrap_or_default()
+    }
+}
+
+/// Value type of a setting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingType {
+    /// On/off switch
+    Toggle,
+    /// Number in `min..=max`, adjusted in `step` increments
+    Range { min: f32, max: f32, step: f32 },
+    /// Window resolution, picked from the common resolutions
+    Resolution,
+}
+
+/// Value of a setting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingValue {
+    Bool(bool),
+    Number(f32),
+    Resolution(u32, u32),
+}
+
+impl SettingValue {
+    /// The value of a toggle
+    pub fn as_bool(self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value of a range
+    pub fn as_number(self) -> Option<f32> {
+        match self {
+            Self::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Declaration of one user setting
+#[derive(Debug)]
+pub struct SettingSchema {
+    /// Stable identifier (e.g. "audio.master_volume")
+    pub id: &'static str,
+    /// Name shown in the settings menu, also matched by search
+    pub label: &'static str,
+    /// Settings menu tab
+    pub category: SettingsCategory,
+    /// Value type and range
+    pub kind: SettingType,
+    /// Value in [`UserSettings::default`]
+    pub default: SettingValue,
+    get: fn(&UserSettings) -> SettingValue,
+    set: fn(&mut UserSettings, SettingValue),
+}
+
+impl SettingSchema {
+    /// Read the setting
+    pub fn value(&self, settings: &UserSettings) -> SettingValue {
+        (self.get)(settings)
+    }
+
+    /// Write the setting, clamping numbers into range
+    ///
+    /// Returns an error if the value doesn't match the setting's type.
+    pub fn set_value(
+        &self,
+        settings: &mut UserSettings,
+        value: SettingValue,
+    ) -> Result<(), SettingsError> {
+        let value = match (self.kind, value) {
+            (SettingType::Toggle, SettingValue::Bool(_)) => value,
+            (SettingType::Range { min, max, .. }, SettingValue::Number(number)) => {
+                if !number.is_finite() {
+                    return Err(self.invalid(value));
+                }
+                SettingValue::Number(number.clamp(min, max))
+            }
+            (SettingType::Resolution, SettingValue::Resolution(width, height))
+                if width > 0 && height > 0 =>
+            {
+                value
+            }
+            _ => return Err(self.invalid(value)),
+        };
+        (self.set)(settings, value);
+        Ok(())
+    }
+
+    /// Check if the name contains `query`, ignoring case
+    pub fn matches(&self, query: &str) -> bool {
+        self.label.to_lowercase().contains(&query.to_lowercase())
+    }
+
+    fn invalid(&self, value: SettingValue) -> SettingsError {
+        SettingsError::InvalidValue(format!("{:?} for setting '{}'", value, self.id))
+    }
+}
+
+/// Find a setting by its identifier
+pub fn find_setting(id: &str) -> Option<&'static SettingSchema> {
+    SETTINGS_SCHEMA.iter().find(|setting| setting.id == id)
+}
+
+/// Every player-facing setting, in display order within each tab
+pub const SETTINGS_SCHEMA: &[SettingSchema] = &[
+    SettingSchema {
+        id: "display.fullscreen",
+        label: "Fullscreen",
+        category: SettingsCategory::Display,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(false),
+        get: |s| SettingValue::Bool(s.display.fullscreen),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.display.fullscreen = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "display.resolution",
+        label: "Resolution",
+        category: SettingsCategory::Display,
+        kind: SettingType::Resolution,
+        default: SettingValue::Resolution(1280, 720),
+        get: |s| SettingValue::Resolution(s.display.resolution.0, s.display.resolution.1),
+        set: |s, value| {
+            if let SettingValue::Resolution(width, height) = value {
+                s.display.resolution = (width, height);
+            }
+        },
+    },
+    SettingSchema {
+        id: "display.animations",
+        label: "Enable Animations",
+        category: SettingsCategory::Display,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(true),
+        get: |s| SettingValue::Bool(s.animation.enabled),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.animation.enabled = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "display.animation_speed",
+        label: "Animation Speed",
+        category: SettingsCategory::Display,
+        kind: SettingType::Range {
+            min: 0.5,
+            max: 2.0,
+            step: 0.1,
+        },
+        default: SettingValue::Number(1.0),
+        get: |s| SettingValue::Number(s.animation.speed),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.animation.speed = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "audio.master_volume",
+        label: "Master Volume",
+        category: SettingsCategory::Audio,
+        kind: SettingType::Range {
+            min: 0.0,
+            max: 1.0,
+            step: 0.05,
+        },
+        default: SettingValue::Number(1.0),
+        get: |s| SettingValue::Number(s.audio.master_volume),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.audio.master_volume = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "audio.music_volume",
+        label: "Music Volume",
+        category: SettingsCategory::Audio,
+        kind: SettingType::Range {
+            min: 0.0,
+            max: 1.0,
+            step: 0.05,
+        },
+        default: SettingValue::Number(0.7),
+        get: |s| SettingValue::Number(s.audio.bgm_volume),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.audio.bgm_volume = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "audio.sound_volume",
+        label: "Sound Effects Volume",
+        category: SettingsCategory::Audio,
+        kind: SettingType::Range {
+            min: 0.0,
+            max: 1.0,
+            step: 0.05,
+        },
+        default: SettingValue::Number(1.0),
+        get: |s| SettingValue::Number(s.audio.se_volume),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.audio.se_volume = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "audio.voice_volume",
+        label: "Voice Volume",
+        category: SettingsCategory::Audio,
+        kind: SettingType::Range {
+            min: 0.0,
+            max: 1.0,
+            step: 0.05,
+        },
+        default: SettingValue::Number(1.0),
+        get: |s| SettingValue::Number(s.audio.voice_volume),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.audio.voice_volume = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "text.speed",
+        label: "Text Speed (characters/second)",
+        category: SettingsCategory::Text,
+        kind: SettingType::Range {
+            min: 1.0,
+            max: 200.0,
+            step: 1.0,
+        },
+        default: SettingValue::Number(30.0),
+        get: |s| SettingValue::Number(s.text.speed.chars_per_second()),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.text.speed = TextSpeed::from_chars_per_second(value);
+            }
+        },
+    },
+    SettingSchema {
+        id: "text.auto_wait",
+        label: "Auto-Play Speed (seconds)",
+        category: SettingsCategory::Text,
+        kind: SettingType::Range {
+            min: 0.5,
+            max: 10.0,
+            step: 0.5,
+        },
+        default: SettingValue::Number(2.0),
+        get: |s| SettingValue::Number(s.text.auto_wait),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.text.auto_wait = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "text.dual_language",
+        label: "Dual Language (original and translation)",
+        category: SettingsCategory::Text,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(false),
+        get: |s| SettingValue::Bool(s.text.dual_language),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.text.dual_language = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "controls.skip_unread",
+        label: "Skip Unread Text",
+        category: SettingsCategory::Controls,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(false),
+        get: |s| SettingValue::Bool(s.skip.mode == SkipMode::All),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.skip.mode = if value {
+                    SkipMode::All
+                } else {
+                    SkipMode::ReadOnly
+                };
+            }
+        },
+    },
+    SettingSchema {
+        id: "controls.skip_stop_at_choices",
+        label: "Stop Skipping at Choices",
+        category: SettingsCategory::Controls,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(true),
+        get: |s| SettingValue::Bool(s.skip.stop_at_choices),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.skip.stop_at_choices = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "accessibility.comfort_mode",
+        label: "Comfort Mode (instant text, no flashing)",
+        category: SettingsCategory::Accessibility,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(false),
+        get: |s| SettingValue::Bool(s.accessibility.comfort_mode),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.accessibility.comfort_mode = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "accessibility.subtitles",
+        label: "Subtitles (narration and sound effects)",
+        category: SettingsCategory::Accessibility,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(false),
+        get: |s| SettingValue::Bool(s.accessibility.subtitles),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.accessibility.subtitles = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "accessibility.self_voicing",
+        label: "Self-Voicing (read dialogue aloud)",
+        category: SettingsCategory::Accessibility,
+        kind: SettingType::Toggle,
+        default: SettingValue::Bool(false),
+        get: |s| SettingValue::Bool(s.accessibility.self_voicing),
+        set: |s, value| {
+            if let SettingValue::Bool(value) = value {
+                s.accessibility.self_voicing = value;
+            }
+        },
+    },
+    SettingSchema {
+        id: "accessibility.speech_rate",
+        label: "Speech Rate",
+        category: SettingsCategory::Accessibility,
+        kind: SettingType::Range {
+            min: 0.5,
+            max: 2.0,
+            step: 0.1,
+        },
+        default: SettingValue::Number(1.0),
+        get: |s| SettingValue::Number(s.accessibility.speech_rate),
+        set: |s, value| {
+            if let SettingValue::Number(value) = value {
+                s.accessibility.speech_rate = value;
+            }
+        },
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A valid value different from the setting's default
+    fn other_value(setting: &SettingSchema) -> SettingValue {
+        match (setting.kind, setting.default) {
+            (_, SettingValue::Bool(value)) => SettingValue::Bool(!value),
+            (SettingType::Range { min, max, .. }, SettingValue::Number(value)) => {
+                SettingValue::Number(if value > min { min } else { max })
+            }
+            _ => SettingValue::Resolution(1920, 1080),
+        }
+    }
+
+    #[test]
+    fn test_defaults_match_user_settings() {
+        let settings = UserSettings::default();
+        for setting in SETTINGS_SCHEMA {
+            assert_eq!(
+                setting.value(&settings),
+                setting.default,
+                "{} default",
+                setting.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_setting_persists() {
+        let mut settings = UserSettings::default();
+        for setting in SETTINGS_SCHEMA {
+            setting
+                .set_value(&mut settings, other_value(setting))
+                .unwrap();
+            assert_ne!(setting.value(&settings), setting.default, "{}", setting.id);
+        }
+
+        let serialized =
+            ron::ser::to_string_pretty(&settings, ron::ser::PrettyConfig::default()).unwrap();
+        let loaded: UserSettings = ron::from_str(&serialized).unwrap();
+        for setting in SETTINGS_SCHEMA {
+            assert_eq!(
+                setting.value(&loaded),
+                setting.value(&settings),
+                "{} was not saved",
+                setting.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_value_checks_type_and_range() {
+        let mut settings = UserSettings::default();
+        let volume = find_setting("audio.master_volume").unwrap();
+
+        volume
+            .set_value(&mut settings, SettingValue::Number(1.5))
+            .unwrap();
+        assert_eq!(settings.audio.master_volume, 1.0);
+
+        assert!(matches!(
+            volume.set_value(&mut settings, SettingValue::Bool(true)),
+            Err(SettingsError::InvalidValue(_))
+        ));
+        assert!(
+            volume
+                .set_value(&mut settings, SettingValue::Number(f32::NAN))
+                .is_err()
+        );
+        assert!(
+            find_setting("display.resolution")
+                .unwrap()
+                .set_value(&mut settings, SettingValue::Resolution(0, 720))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_ids_are_unique_and_tabs_filled() {
+        let mut ids: Vec<_> = SETTINGS_SCHEMA.iter().map(|setting| setting.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), SETTINGS_SCHEMA.len());
+
+        for category in SettingsCategory::ALL {
+            assert!(
+                SETTINGS_SCHEMA
+                    .iter()
+                    .any(|setting| setting.category == category),
+                "{:?} tab is empty",
+                category
+            );
+        }
+    }
+
+    #[test]
+    fn test_tabs_wrap_around() {
+        assert_eq!(
+            SettingsCategory::Display.previous(),
+            SettingsCategory::Accessibility
+        );
+        assert_eq!(
+            SettingsCategory::Accessibility.next(),
+            SettingsCategory::Display
+        );
+        assert_eq!(SettingsCategory::Audio.next(), SettingsCategory::Text);
+    }
+}
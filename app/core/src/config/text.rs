@@ -14,6 +14,31 @@ pub enum TextSpeed {
     Instant,
 }
 
+impl TextSpeed {
+    /// Typewriter speed used for this preset, in characters per second
+    pub fn chars_per_second(self) -> f32 {
+        match self {
+            TextSpeed::Slow => 15.0,
+            TextSpeed::Normal => 30.0,
+            TextSpeed::Fast => 60.0,
+            TextSpeed::Instant => 200.0,
+        }
+    }
+
+    /// Nearest preset for a typewriter speed in characters per second
+    pub fn from_chars_per_second(chars_per_second: f32) -> Self {
+        if chars_per_second <= 20.0 {
+            TextSpeed::Slow
+        } else if chars_per_second <= 45.0 {
+            TextSpeed::Normal
+        } else if chars_per_second <= 100.0 {
+            TextSpeed::Fast
+        } else {
+            TextSpeed::Instant
+        }
+    }
+}
+
 impl fmt::Display for TextSpeed {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -74,6 +99,15 @@ pub struct TextConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_font: Option<AssetRef>,
 
+    /// Fonts tried in order for characters the default font has no glyph
+    /// for (e.g. a Japanese font, then an emoji font)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_fonts: Vec<AssetRef>,
+
+    /// Fall back to the installed system fonts after `fallback_fonts`
+    #[serde(default = "default_true")]
+    pub system_font_fallback: bool,
+
     /// Default font size in points
     #[serde(default = "default_font_size")]
     pub font_size: f32,
@@ -93,6 +127,14 @@ pub struct TextConfig {
     #[serde(default = "default_line_spacing")]
     pub line_spacing: f32,
 
+    /// Extra space after each letter in pixels (negative tightens the text)
+    #[serde(default)]
+    pub letter_spacing: f32,
+
+    /// Extra space between paragraphs (lines separated by a line break) in pixels
+    #[serde(default)]
+    pub paragraph_spacing: f32,
+
     /// Auto-advance delay in seconds (0 = disabled)
     ///
     /// When set to a value > 0, text will automatically advance after this delay.
@@ -143,6 +185,17 @@ impl TextConfig {
         self
     }
 
+    /// Add a fallback font, tried after the ones added before it
+    pub fn with_fallback_font(mut self, font: impl Into<AssetRef>) -> Self {
+        self.fallback_fonts.push(font.into());
+        self
+    }
+
+    /// Line height in pixels: the font size times the line spacing
+    pub fn line_height(&self) -> f32 {
+        self.font_size * self.line_spacing
+    }
+
     /// Get the character delay in seconds (inverse of speed)
     pub fn character_delay(&self) -> f32 {
         if self.typewriter_speed > 0.0 {
@@ -172,10 +225,14 @@ impl Default for TextConfig {
     fn default() -> Self {
         Self {
             default_font: None,
+            fallback_fonts: Vec::new(),
+            system_font_fallback: true,
             font_size: default_font_size(),
             typewriter_speed: default_typewriter_speed(),
             typewriter_enabled: default_true(),
             line_spacing: default_line_spacing(),
+            letter_spacing: 0.0,
+            paragraph_spacing: 0.0,
             auto_advance_delay: 0.0,
             default_speed: TextSpeed::default(),
             speeds: default_speeds(),
@@ -222,6 +279,24 @@ fn default_auto_wait_per_char() -> f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_text_speed_chars_per_second_round_trip() {
+        for speed in [
+            TextSpeed::Slow,
+            TextSpeed::Normal,
+            TextSpeed::Fast,
+            TextSpeed::Instant,
+        ] {
+            assert_eq!(
+                TextSpeed::from_chars_per_second(speed.chars_per_second()),
+                speed
+            );
+        }
+        assert_eq!(TextSpeed::from_chars_per_second(1.0), TextSpeed::Slow);
+        assert_eq!(TextSpeed::from_chars_per_second(46.0), TextSpeed::Fast);
+        assert_eq!(TextSpeed::from_chars_per_second(500.0), TextSpeed::Instant);
+    }
+
     #[test]
     fn test_text_config_new() {
         let config = TextConfig::new();
@@ -246,6 +321,44 @@ mod tests {
         assert_eq!(config.default_font, Some(AssetRef::from("fonts/main.ttf")));
     }
 
+    #[test]
+    fn test_text_config_spacing() {
+        let config = TextConfig::default();
+        assert_eq!(config.letter_spacing, 0.0);
+        assert_eq!(config.paragraph_spacing, 0.0);
+        assert!((config.line_height() - 28.8).abs() < 1e-4);
+
+        let loaded: TextConfig = ron::from_str(
+            "(font_size: 20.0, line_spacing: 1.5, letter_spacing: -0.5, paragraph_spacing: 8.0)",
+        )
+        .unwrap();
+        assert_eq!(loaded.line_height(), 30.0);
+        assert_eq!(loaded.letter_spacing, -0.5);
+        assert_eq!(loaded.paragraph_spacing, 8.0);
+    }
+
+    #[test]
+    fn test_text_config_fallback_fonts() {
+        let config = TextConfig::new()
+            .with_font("fonts/main.ttf")
+            .with_fallback_font("fonts/jp.otf")
+            .with_fallback_font("fonts/emoji.ttf");
+        assert_eq!(
+            config.fallback_fonts,
+            [
+                AssetRef::from("fonts/jp.otf"),
+                AssetRef::from("fonts/emoji.ttf")
+            ]
+        );
+        assert!(config.system_font_fallback);
+
+        let loaded: TextConfig =
+            ron::from_str("(fallback_fonts: [(\"fonts/jp.otf\")], system_font_fallback: false)")
+                .unwrap();
+        assert_eq!(loaded.fallback_fonts, [AssetRef::from("fonts/jp.otf")]);
+        assert!(!loaded.system_font_fallback);
+    }
+
     #[test]
     fn test_text_config_character_delay() {
         let config = TextConfig::new(); // 30 chars/sec
@@ -1,3 +1,4 @@
+use crate::asset::ColorPalette;
 use crate::types::Color;
 use serde::{Deserialize, Serialize};
 
@@ -43,6 +44,19 @@ pub struct DialogueBoxConfig {
     #[serde(default = "default_line_height")]
     pub line_height: f32,
 
+    /// Dialogue text line height as a multiple of `text_font_size`, used
+    /// instead of `line_height` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_spacing: Option<f32>,
+
+    /// Extra space after each letter of dialogue text in pixels
+    #[serde(default)]
+    pub letter_spacing: f32,
+
+    /// Extra space between paragraphs of dialogue text in pixels
+    #[serde(default)]
+    pub paragraph_spacing: f32,
+
     /// Background color (RGB, alpha is controlled by opacity)
     #[serde(default = "default_background_color")]
     pub background_color: Color,
@@ -66,6 +80,16 @@ pub struct DialogueBoxConfig {
     /// Click indicator blink speed (cycles per second)
     #[serde(default = "default_blink_speed")]
     pub click_indicator_blink_speed: f32,
+
+    /// Vertical position between the bottom of the screen (0.0) and the
+    /// center of the screen (1.0)
+    #[serde(default)]
+    pub vertical_position: f32,
+
+    /// Mirror the layout for right-to-left languages: the speaker name and
+    /// text start at the right edge, the mode and click indicators move left
+    #[serde(default)]
+    pub mirrored: bool,
 }
 
 impl DialogueBoxConfig {
@@ -83,6 +107,35 @@ impl DialogueBoxConfig {
             self.opacity,
         )
     }
+
+    /// Dialogue text line height in pixels
+    ///
+    /// `line_spacing` times the text font size if set, `line_height` otherwise.
+    pub fn text_line_height(&self) -> f32 {
+        self.line_spacing
+            .map_or(self.line_height, |spacing| self.text_font_size * spacing)
+    }
+
+    /// Distance from the bottom of the screen to the bottom of the box
+    ///
+    /// `screen_height` is the height of the area the box is laid out in.
+    pub fn bottom_offset(&self, screen_height: f32) -> f32 {
+        let free_space = (screen_height - self.height).max(0.0);
+        free_space * 0.5 * self.vertical_position.clamp(0.0, 1.0)
+    }
+
+    /// Apply a UI theme color palette
+    ///
+    /// The background alpha becomes the box opacity and the accent color is
+    /// used for speaker names.
+    pub fn with_palette(mut self, palette: &ColorPalette) -> Self {
+        let background = ColorPalette::to_color(palette.background);
+        self.background_color = Color::new(background.r, background.g, background.b, 1.0);
+        self.opacity = background.a;
+        self.text_color = ColorPalette::to_color(palette.text_primary);
+        self.speaker_color = ColorPalette::to_color(palette.accent);
+        self
+    }
 }
 
 impl Default for DialogueBoxConfig {
@@ -94,12 +147,17 @@ impl Default for DialogueBoxConfig {
             speaker_font_size: default_speaker_font_size(),
             text_font_size: default_text_font_size(),
             line_height: default_line_height(),
+            line_spacing: None,
+            letter_spacing: 0.0,
+            paragraph_spacing: 0.0,
             background_color: default_background_color(),
             text_color: default_text_color(),
             speaker_color: default_speaker_color(),
             corner_radius: 0.0,
             show_click_indicator: default_true(),
             click_indicator_blink_speed: default_blink_speed(),
+            vertical_position: 0.0,
+            mirrored: false,
         }
     }
 }
@@ -178,6 +236,41 @@ mod tests {
         assert_eq!(config.click_indicator_blink_speed, 2.0);
     }
 
+    #[test]
+    fn test_dialogue_box_config_bottom_offset() {
+        let mut config = DialogueBoxConfig::new();
+        assert_eq!(config.bottom_offset(720.0), 0.0);
+
+        // Centered: equal space above and below the box
+        config.vertical_position = 1.0;
+        assert_eq!(config.bottom_offset(720.0), 260.0);
+
+        config.vertical_position = 0.5;
+        assert_eq!(config.bottom_offset(720.0), 130.0);
+
+        // Out-of-range positions and small screens are clamped
+        config.vertical_position = 3.0;
+        assert_eq!(config.bottom_offset(720.0), 260.0);
+        assert_eq!(config.bottom_offset(100.0), 0.0);
+    }
+
+    #[test]
+    fn test_dialogue_box_config_text_line_height() {
+        let mut config = DialogueBoxConfig::new();
+        assert_eq!(config.text_line_height(), 32.0);
+
+        config.line_spacing = Some(1.5);
+        assert_eq!(config.text_line_height(), 36.0);
+
+        let loaded: DialogueBoxConfig = ron::from_str(
+            "(line_spacing: Some(1.25), letter_spacing: 1.5, paragraph_spacing: 12.0)",
+        )
+        .unwrap();
+        assert_eq!(loaded.text_line_height(), 30.0);
+        assert_eq!(loaded.letter_spacing, 1.5);
+        assert_eq!(loaded.paragraph_spacing, 12.0);
+    }
+
     #[test]
     fn test_dialogue_box_config_background_color_with_opacity() {
         let config = DialogueBoxConfig::new();
@@ -188,6 +281,21 @@ mod tests {
         assert_eq!(color.a, 0.8);
     }
 
+    #[test]
+    fn test_dialogue_box_config_with_palette() {
+        let palette = ColorPalette {
+            text_primary: (255, 255, 255, 255),
+            text_secondary: (128, 128, 128, 255),
+            accent: (255, 0, 0, 255),
+            background: (0, 0, 255, 51),
+        };
+        let config = DialogueBoxConfig::new().with_palette(&palette);
+        assert_eq!(config.background_color, Color::new(0.0, 0.0, 1.0, 1.0));
+        assert!((config.opacity - 0.2).abs() < 1e-6);
+        assert_eq!(config.text_color, Color::WHITE);
+        assert_eq!(config.speaker_color, Color::new(1.0, 0.0, 0.0, 1.0));
+    }
+
     #[test]
     fn test_dialogue_box_config_custom_opacity() {
         let mut config = DialogueBoxConfig::new();
@@ -3,7 +3,11 @@
 //! This module provides a RON-based settings system for user preferences.
 //! Settings are persisted to `assets/config/settings.ron`.
 
-use super::{AudioConfig, SkipMode, TextSpeed};
+use super::{
+    AudioConfig, Bindings, DialogueBoxConfig, GamepadButton, SETTINGS_SCHEMA, SettingValue,
+    SkipMode, TextSpeed, find_setting,
+};
+use crate::scenario::DialogueLanguages;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -30,6 +34,21 @@ pub struct UserSettings {
     pub skip: SkipSettings,
     /// Animation settings
     pub animation: AnimationSettings,
+    /// Dialogue box (textbox) settings
+    #[serde(default)]
+    pub textbox: TextboxSettings,
+    /// Accessibility settings
+    #[serde(default)]
+    pub accessibility: AccessibilitySettings,
+    /// Gamepad settings
+    #[serde(default)]
+    pub gamepad: GamepadSettings,
+    /// Keys, mouse buttons and gamepad buttons bound to each action
+    #[serde(default)]
+    pub bindings: Bindings,
+    /// Player name and pronouns, shared by every playthrough
+    #[serde(default)]
+    pub profile: ProfileSettings,
 }
 
 impl UserSettings {
@@ -42,11 +61,42 @@ impl UserSettings {
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SettingsError> {
         let content = std::fs::read_to_string(path.as_ref())
             .map_err(|e| SettingsError::IoError(e.to_string()))?;
-        let settings: UserSettings =
+        let mut settings: UserSettings =
             ron::from_str(&content).map_err(|e| SettingsError::ParseError(e.to_string()))?;
+        settings.sanitize();
         Ok(settings)
     }
 
+    /// Read a setting declared in [`SETTINGS_SCHEMA`]
+    pub fn value(&self, id: &str) -> Option<SettingValue> {
+        find_setting(id).map(|setting| setting.value(self))
+    }
+
+    /// Write a setting declared in [`SETTINGS_SCHEMA`], clamping numbers into
+    /// range
+    pub fn set_value(&mut self, id: &str, value: SettingValue) -> Result<(), SettingsError> {
+        let setting =
+            find_setting(id).ok_or_else(|| SettingsError::UnknownSetting(id.to_string()))?;
+        setting.set_value(self, value)
+    }
+
+    /// Bring every schema setting back into range, resetting values that
+    /// can't be used (e.g. a hand-edited NaN volume) to their defaults
+    pub fn sanitize(&mut self) {
+        for setting in SETTINGS_SCHEMA {
+            let value = setting.value(self);
+            if setting.set_value(self, value).is_err() {
+                let _ = setting.set_value(self, setting.default);
+            }
+        }
+        let deadzone = self.gamepad.stick_deadzone;
+        self.gamepad.stick_deadzone = if deadzone.is_finite() {
+            deadzone.clamp(0.05, 0.95)
+        } else {
+            default_stick_deadzone()
+        };
+    }
+
     /// Save settings to RON file
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SettingsError> {
         let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
@@ -128,6 +178,26 @@ pub struct TextSettings {
     /// Auto-advance wait time in seconds
     #[serde(default = "default_auto_wait")]
     pub auto_wait: f32,
+    /// Language code of the dialogue text (None = as written)
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Show a second language under the dialogue text
+    #[serde(default)]
+    pub dual_language: bool,
+    /// Language code of the second text (None = as written)
+    #[serde(default)]
+    pub second_language: Option<String>,
+}
+
+impl TextSettings {
+    /// Languages to display dialogue in
+    pub fn dialogue_languages(&self) -> DialogueLanguages {
+        DialogueLanguages {
+            primary: self.language.clone(),
+            dual: self.dual_language,
+            secondary: self.second_language.clone(),
+        }
+    }
 }
 
 impl Default for TextSettings {
@@ -135,6 +205,9 @@ impl Default for TextSettings {
         Self {
             speed: TextSpeed::default(),
             auto_wait: default_auto_wait(),
+            language: None,
+            dual_language: false,
+            second_language: None,
         }
     }
 }
@@ -248,6 +321,243 @@ fn default_animation_speed() -> f32 {
     1.0
 }
 
+/// Dialogue box (textbox) settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextboxSettings {
+    /// Background opacity (0.0 - 1.0)
+    #[serde(default = "default_textbox_opacity")]
+    pub opacity: f32,
+    /// Vertical position (0.0 = bottom of the screen, 1.0 = center)
+    #[serde(default)]
+    pub vertical_position: f32,
+}
+
+impl Default for TextboxSettings {
+    fn default() -> Self {
+        Self {
+            opacity: default_textbox_opacity(),
+            vertical_position: 0.0,
+        }
+    }
+}
+
+fn default_textbox_opacity() -> f32 {
+    0.8
+}
+
+impl TextboxSettings {
+    /// Apply the player's textbox settings to a dialogue box configuration
+    pub fn apply_to(&self, mut config: DialogueBoxConfig) -> DialogueBoxConfig {
+        config.opacity = self.opacity.clamp(0.0, 1.0);
+        config.vertical_position = self.vertical_position.clamp(0.0, 1.0);
+        config
+    }
+}
+
+/// Accessibility settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessibilitySettings {
+    /// Comfort mode: text is shown instantly, screen flashes become gentle
+    /// fades and shaking is toned down
+    #[serde(default)]
+    pub comfort_mode: bool,
+    /// Show subtitles for narrated audio and sound effects that have a
+    /// subtitle file
+    #[serde(default)]
+    pub subtitles: bool,
+    /// Self-voicing: read dialogue aloud with text-to-speech
+    #[serde(default)]
+    pub self_voicing: bool,
+    /// Text-to-speech rate multiplier (0.5 = half speed, 2.0 = double speed)
+    #[serde(default = "default_speech_rate")]
+    pub speech_rate: f32,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            comfort_mode: false,
+            subtitles: false,
+            self_voicing: false,
+            speech_rate: default_speech_rate(),
+        }
+    }
+}
+
+fn default_speech_rate() -> f32 {
+    1.0
+}
+
+/// Gamepad settings: menu buttons and stick sensitivity
+///
+/// The D-pad and left stick always navigate menus and choices. In-game
+/// actions are mapped in [`Bindings`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamepadSettings {
+    /// Accept gamepad input
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Confirm a menu selection
+    #[serde(default = "default_confirm_button")]
+    pub confirm: GamepadButton,
+    /// Open the pause menu
+    #[serde(default = "default_pause_button")]
+    pub pause: GamepadButton,
+    /// How far the stick must be tilted to navigate (0.0 - 1.0)
+    #[serde(default = "default_stick_deadzone")]
+    pub stick_deadzone: f32,
+}
+
+impl Default for GamepadSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            confirm: default_confirm_button(),
+            pause: default_pause_button(),
+            stick_deadzone: default_stick_deadzone(),
+        }
+    }
+}
+
+fn default_confirm_button() -> GamepadButton {
+    GamepadButton::South
+}
+
+fn default_pause_button() -> GamepadButton {
+    GamepadButton::Start
+}
+
+fn default_stick_deadzone() -> f32 {
+    0.5
+}
+
+/// Prefix of the variables filled in from the player profile
+pub const PLAYER_VARIABLE_PREFIX: &str = "player.";
+
+/// Player profile: name and pronouns
+///
+/// Every new game gets the profile in the reserved `player.*` variables
+/// ([`Self::variables`]), so dialogue can write `{$player.name}` or
+/// `{$player.they}`. An `InputName` command storing into `player.name` or
+/// `player.pronoun` updates the profile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ProfileSettings {
+    /// Player name (empty until entered)
+    #[serde(default)]
+    pub name: String,
+    /// Pronouns used for the player
+    #[serde(default)]
+    pub pronoun: Pronoun,
+}
+
+impl ProfileSettings {
+    /// The `player.*` variables and their values
+    pub fn variables(&self) -> [(&'static str, String); 5] {
+        [
+            ("player.name", self.name.clone()),
+            ("player.pronoun", self.pronoun.label().to_string()),
+            ("player.they", self.pronoun.subject().to_string()),
+            ("player.them", self.pronoun.object().to_string()),
+            ("player.their", self.pronoun.possessive().to_string()),
+        ]
+    }
+
+    /// Store text entered for a `player.*` variable
+    ///
+    /// `player.name` sets the name and `player.pronoun` the pronouns (e.g.
+    /// "she" or "he/him"). Returns false if the variable isn't part of the
+    /// profile or the pronouns aren't recognized.
+    pub fn set_from_input(&mut self, variable: &str, text: &str) -> bool {
+        match variable {
+            "player.name" => {
+                self.name = text.trim().to_string();
+                true
+            }
+            "player.pronoun" => match Pronoun::parse(text) {
+                Some(pronoun) => {
+                    self.pronoun = pronoun;
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Pronouns used for the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Pronoun {
+    /// they/them
+    #[default]
+    They,
+    /// she/her
+    She,
+    /// he/him
+    He,
+}
+
+impl Pronoun {
+    /// All pronouns, in the order the settings menu cycles through them
+    pub const ALL: [Self; 3] = [Self::They, Self::She, Self::He];
+
+    /// Parse typed pronouns such as "she", "He/Him" or "they/them"
+    pub fn parse(text: &str) -> Option<Self> {
+        let first = text.split('/').next()?.trim().to_lowercase();
+        match first.as_str() {
+            "they" | "them" => Some(Self::They),
+            "she" | "her" => Some(Self::She),
+            "he" | "him" => Some(Self::He),
+            _ => None,
+        }
+    }
+
+    /// Label shown in the settings menu
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::They => "they/them",
+            Self::She => "she/her",
+            Self::He => "he/him",
+        }
+    }
+
+    /// Subject form ("they")
+    pub fn subject(self) -> &'static str {
+        match self {
+            Self::They => "they",
+            Self::She => "she",
+            Self::He => "he",
+        }
+    }
+
+    /// Object form ("them")
+    pub fn object(self) -> &'static str {
+        match self {
+            Self::They => "them",
+            Self::She => "her",
+            Self::He => "him",
+        }
+    }
+
+    /// Possessive form ("their")
+    pub fn possessive(self) -> &'static str {
+        match self {
+            Self::They => "their",
+            Self::She => "her",
+            Self::He => "his",
+        }
+    }
+
+    /// The pronouns after this one, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL
+            .get((index + 1) % Self::ALL.len())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
 /// Settings error types
 #[derive(Debug, Clone, PartialEq)]
 pub enum SettingsError {
@@ -257,6 +567,10 @@ pub enum SettingsError {
     ParseError(String),
     /// Serialize error
     SerializeError(String),
+    /// No setting with this identifier in the schema
+    UnknownSetting(String),
+    /// Value doesn't fit the setting's type
+    InvalidValue(String),
 }
 
 impl std::fmt::Display for SettingsError {
@@ -265,6 +579,8 @@ impl std::fmt::Display for SettingsError {
             SettingsError::IoError(msg) => write!(f, "IO error: {}", msg),
             SettingsError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             SettingsError::SerializeError(msg) => write!(f, "Serialize error: {}", msg),
+            SettingsError::UnknownSetting(id) => write!(f, "Unknown setting: {}", id),
+            SettingsError::InvalidValue(msg) => write!(f, "Invalid value: {}", msg),
         }
     }
 }
@@ -274,6 +590,7 @@ impl std::error::Error for SettingsError {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{Binding, InputAction};
 
     #[test]
     fn test_user_settings_default() {
@@ -289,6 +606,10 @@ mod tests {
         assert!(settings.animation.enabled);
         assert_eq!(settings.animation.speed, 1.0);
         assert!(!settings.animation.respect_system_preference);
+        assert_eq!(settings.textbox.opacity, 0.8);
+        assert_eq!(settings.textbox.vertical_position, 0.0);
+        assert!(!settings.accessibility.comfort_mode);
+        assert!(!settings.accessibility.subtitles);
     }
 
     #[test]
@@ -314,6 +635,21 @@ mod tests {
         let text = TextSettings::default();
         assert_eq!(text.speed, TextSpeed::Normal);
         assert_eq!(text.auto_wait, 2.0);
+        assert!(!text.dual_language);
+        assert_eq!(text.dialogue_languages(), DialogueLanguages::default());
+    }
+
+    #[test]
+    fn test_text_settings_dual_language() {
+        let text = TextSettings {
+            dual_language: true,
+            second_language: Some("en".to_string()),
+            ..TextSettings::default()
+        };
+        let languages = text.dialogue_languages();
+        assert!(languages.dual);
+        assert_eq!(languages.primary, None);
+        assert_eq!(languages.secondary.as_deref(), Some("en"));
     }
 
     #[test]
@@ -338,6 +674,86 @@ mod tests {
         assert!(!animation.respect_system_preference);
     }
 
+    #[test]
+    fn test_textbox_settings_apply_to() {
+        let textbox = TextboxSettings {
+            opacity: 1.5,
+            vertical_position: 0.5,
+        };
+        let config = textbox.apply_to(DialogueBoxConfig::default());
+        assert_eq!(config.opacity, 1.0);
+        assert_eq!(config.vertical_position, 0.5);
+        assert_eq!(config.height, DialogueBoxConfig::default().height);
+    }
+
+    #[test]
+    fn test_settings_without_textbox_section() {
+        // Settings saved before the textbox section existed still load
+        let legacy = "(audio: (), text: (), display: (), skip: (), animation: ())";
+        let loaded: UserSettings = ron::from_str(legacy).unwrap();
+        assert_eq!(loaded.textbox, TextboxSettings::default());
+        assert_eq!(loaded.accessibility, AccessibilitySettings::default());
+        assert_eq!(loaded.gamepad, GamepadSettings::default());
+        assert_eq!(loaded.bindings, Bindings::default());
+        assert_eq!(loaded.profile, ProfileSettings::default());
+    }
+
+    #[test]
+    fn test_profile_from_input() {
+        let mut profile = ProfileSettings::default();
+        assert!(profile.set_from_input("player.name", "  Alex "));
+        assert!(profile.set_from_input("player.pronoun", "She/Her"));
+        assert!(!profile.set_from_input("player.pronoun", "xyz"));
+        assert!(!profile.set_from_input("hero_name", "Sam"));
+        assert_eq!(profile.name, "Alex");
+        assert_eq!(profile.pronoun, Pronoun::She);
+
+        let variables = profile.variables();
+        assert!(variables.contains(&("player.name", "Alex".to_string())));
+        assert!(variables.contains(&("player.them", "her".to_string())));
+        assert!(
+            variables
+                .iter()
+                .all(|(name, _)| name.starts_with(PLAYER_VARIABLE_PREFIX))
+        );
+    }
+
+    #[test]
+    fn test_pronoun_cycles() {
+        assert_eq!(Pronoun::They.next(), Pronoun::She);
+        assert_eq!(Pronoun::He.next(), Pronoun::They);
+        assert_eq!(Pronoun::parse(" him "), Some(Pronoun::He));
+    }
+
+    #[test]
+    fn test_gamepad_button_map_roundtrip() {
+        let mut settings = UserSettings::default();
+        settings.gamepad.confirm = GamepadButton::East;
+        settings.gamepad.pause = GamepadButton::Select;
+        settings.bindings.rebind(
+            InputAction::Skip,
+            Binding::Gamepad(GamepadButton::RightStick),
+        );
+        let serialized = ron::to_string(&settings).unwrap();
+        let loaded: UserSettings = ron::from_str(&serialized).unwrap();
+        assert_eq!(loaded.gamepad.confirm, GamepadButton::East);
+        assert_eq!(loaded.gamepad.pause, GamepadButton::Select);
+        assert_eq!(
+            loaded
+                .bindings
+                .action_for(&Binding::Gamepad(GamepadButton::RightStick)),
+            Some(InputAction::Skip)
+        );
+    }
+
+    #[test]
+    fn test_accessibility_without_speech_settings() {
+        let loaded: AccessibilitySettings = ron::from_str("(subtitles: true)").unwrap();
+        assert!(loaded.subtitles);
+        assert!(!loaded.self_voicing);
+        assert_eq!(loaded.speech_rate, 1.0);
+    }
+
     #[test]
     fn test_user_settings_to_audio_config() {
         let settings = UserSettings::default();
@@ -379,4 +795,40 @@ mod tests {
             "Serialize error: cannot serialize"
         );
     }
+
+    #[test]
+    fn test_set_value_by_id() {
+        let mut settings = UserSettings::default();
+        settings
+            .set_value("audio.voice_volume", SettingValue::Number(0.25))
+            .unwrap();
+        assert_eq!(settings.audio.voice_volume, 0.25);
+        assert_eq!(
+            settings.value("audio.voice_volume"),
+            Some(SettingValue::Number(0.25))
+        );
+
+        assert_eq!(
+            settings.set_value("audio.nonexistent", SettingValue::Number(0.5)),
+            Err(SettingsError::UnknownSetting(
+                "audio.nonexistent".to_string()
+            ))
+        );
+        assert_eq!(settings.value("audio.nonexistent"), None);
+    }
+
+    #[test]
+    fn test_sanitize_clamps_and_resets_values() {
+        let mut settings = UserSettings::default();
+        settings.audio.master_volume = 3.0;
+        settings.text.auto_wait = -1.0;
+        settings.accessibility.speech_rate = f32::NAN;
+        settings.display.resolution = (0, 0);
+
+        settings.sanitize();
+        assert_eq!(settings.audio.master_volume, 1.0);
+        assert_eq!(settings.text.auto_wait, 0.5);
+        assert_eq!(settings.accessibility.speech_rate, 1.0);
+        assert_eq!(settings.display.resolution, (1280, 720));
+    }
 }
@@ -0,0 +1,870 @@
+//! Expressions over flags and variables
+//!
+//! Conditions and variable operations can be written as expressions instead
+//! of nested tables:
+//!
+//! ```text
+//! affection >= 5 && !flags.betrayed || route == "mia"
+//! ```
+//!
+//! - Literals: integers, floats, `"strings"` (or `'strings'`), `true`, `false`
+//! - Names: a bare name reads a variable; `flags.name` reads a flag
+//! - Operators, loosest first: `||`, `&&`, `==` `!=`, `<` `<=` `>` `>=`,
+//!   `+` `-`, `*` `/` `%`, then unary `!` and `-`; parentheses group
+//!
+//! A variable that was never set takes the default value of the type it is
+//! combined with (`0`, `0.0`, `""` or `false`), matching table conditions.
+//! Integers mix with floats as floats, `+` joins strings, and `&&`, `||`
+//! and `!` treat zero, empty strings and `false` as false.
+
+use crate::condition::CompareOp;
+use crate::scenario::VariableValue;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Prefix of flag names in expressions
+const FLAG_PREFIX: &str = "flags.";
+
+/// Deepest nesting of parentheses and unary operators accepted, so a
+/// malformed scenario can't overflow the stack
+const MAX_NESTING: usize = 64;
+
+/// Errors parsing or evaluating an expression
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ExpressionError {
+    /// Malformed expression
+    #[error("column {column}: {message}")]
+    Parse {
+        /// 1-based character column of the error
+        column: usize,
+        /// Description of the problem
+        message: String,
+    },
+    /// Operator applied to values it does not support
+    #[error("cannot apply '{operator}' to {left} and {right}")]
+    TypeMismatch {
+        /// Operator symbol
+        operator: &'static str,
+        /// Type of the left operand
+        left: &'static str,
+        /// Type of the right operand
+        right: &'static str,
+    },
+    /// Integer division or remainder by zero
+    #[error("division by zero")]
+    DivisionByZero,
+}
+
+/// Result type for expression operations
+pub type ExpressionResult<T> = Result<T, ExpressionError>;
+
+/// Parsed expression, kept with its source text
+///
+/// Serialized as the source text.
+#[derive(Debug, Clone)]
+pub struct Expression {
+    source: String,
+    root: Expr,
+}
+
+impl Expression {
+    /// Parse an expression
+    ///
+    /// # Errors
+    /// Returns `Parse` with the column of the first problem.
+    pub fn parse(source: impl Into<String>) -> ExpressionResult<Self> {
+        let source = source.into();
+        let root = Parser::new(&source)?.parse()?;
+        Ok(Self { source, root })
+    }
+
+    /// Source text of the expression
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluate the expression using provided lookups
+    ///
+    /// # Errors
+    /// Returns an error if an operator does not apply to its operands or an
+    /// integer is divided by zero.
+    pub fn evaluate(
+        &self,
+        get_flag: &dyn Fn(&str) -> bool,
+        get_variable: &dyn Fn(&str) -> Option<VariableValue>,
+    ) -> ExpressionResult<VariableValue> {
+        let lookup = Lookup {
+            get_flag,
+            get_variable,
+        };
+        Ok(lookup
+            .eval(&self.root)?
+            .unwrap_or(VariableValue::Bool(false)))
+    }
+
+    /// Evaluate the expression as a condition
+    ///
+    /// # Errors
+    /// Same as [`Self::evaluate`].
+    pub fn evaluate_bool(
+        &self,
+        get_flag: &dyn Fn(&str) -> bool,
+        get_variable: &dyn Fn(&str) -> Option<VariableValue>,
+    ) -> ExpressionResult<bool> {
+        self.evaluate(get_flag, get_variable)
+            .map(|value| is_truthy(&value))
+    }
+
+    /// Variables the expression reads, in source order
+    pub fn variables(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.root.visit(&mut |expr| {
+            if let Expr::Variable(name) = expr {
+                names.push(name.as_str());
+            }
+        });
+        names
+    }
+
+    /// Flags the expression reads, in source order
+    pub fn flags(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.root.visit(&mut |expr| {
+            if let Expr::Flag(name) = expr {
+                names.push(name.as_str());
+            }
+        });
+        names
+    }
+
+    /// Source text with every read of a variable renamed
+    ///
+    /// Returns `None` if the expression does not read the variable.
+    pub fn rename_variable(&self, old: &str, new: &str) -> Option<String> {
+        rename_name(&self.source, old, new)
+    }
+
+    /// Source text with every read of a flag renamed
+    ///
+    /// Returns `None` if the expression does not read the flag.
+    pub fn rename_flag(&self, old: &str, new: &str) -> Option<String> {
+        rename_name(
+            &self.source,
+            &format!("{}{}", FLAG_PREFIX, old),
+            &format!("{}{}", FLAG_PREFIX, new),
+        )
+    }
+}
+
+impl PartialEq for Expression {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl std::str::FromStr for Expression {
+    type Err = ExpressionError;
+
+    fn from_str(source: &str) -> ExpressionResult<Self> {
+        Self::parse(source)
+    }
+}
+
+impl Serialize for Expression {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Expression {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Self::parse(source).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Expression syntax tree
+#[derive(Debug, Clone)]
+enum Expr {
+    Literal(VariableValue),
+    Variable(String),
+    Flag(String),
+    Not(Box<Expr>),
+    Negate(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Visit this node and its operands, left to right
+    fn visit<'a>(&'a self, visitor: &mut impl FnMut(&'a Expr)) {
+        visitor(self);
+        match self {
+            Expr::Not(operand) | Expr::Negate(operand) => operand.visit(visitor),
+            Expr::Binary(_, left, right) => {
+                left.visit(visitor);
+                right.visit(visitor);
+            }
+            Expr::Literal(_) | Expr::Variable(_) | Expr::Flag(_) => {}
+        }
+    }
+}
+
+/// Binary operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+    Or,
+    And,
+    Compare(CompareOp),
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl BinaryOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Or => "||",
+            Self::And => "&&",
+            Self::Compare(CompareOp::Equal) => "==",
+            Self::Compare(CompareOp::NotEqual) => "!=",
+            Self::Compare(CompareOp::LessThan) => "<",
+            Self::Compare(CompareOp::LessOrEqual) => "<=",
+            Self::Compare(CompareOp::GreaterThan) => ">",
+            Self::Compare(CompareOp::GreaterOrEqual) => ">=",
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Remainder => "%",
+        }
+    }
+}
+
+/// Lexical token
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Value(VariableValue),
+    Name(String),
+    Operator(&'static str),
+    OpenParen,
+    CloseParen,
+}
+
+/// Operators, longest first so `<=` is not read as `<`
+const OPERATORS: [&str; 15] = [
+    "||", "&&", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "!", "=",
+];
+
+/// Split an expression into tokens with their 1-based columns
+fn tokenize(source: &str) -> ExpressionResult<Vec<(Token, usize)>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while let Some(&c) = chars.get(i) {
+        let column = i + 1;
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '(' || c == ')' {
+            tokens.push((
+                if c == '(' {
+                    Token::OpenParen
+                } else {
+                    Token::CloseParen
+                },
+                column,
+            ));
+            i += 1;
+        } else if c == '"' || c == '\'' {
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    Some(&close) if close == c => break,
+                    Some('\\') => {
+                        i += 1;
+                        match chars.get(i) {
+                            Some(&escaped) => text.push(escaped),
+                            None => break,
+                        }
+                    }
+                    Some(&other) => text.push(other),
+                    None => {
+                        return Err(parse_error(column, "unterminated string"));
+                    }
+                }
+                i += 1;
+            }
+            if chars.get(i).is_none() {
+                return Err(parse_error(column, "unterminated string"));
+            }
+            i += 1;
+            tokens.push((Token::Value(VariableValue::String(text)), column));
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while chars
+                .get(i)
+                .is_some_and(|c| c.is_ascii_digit() || *c == '.' || *c == '_')
+            {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().filter(|c| **c != '_').collect();
+            let value = if text.contains('.') {
+                text.parse().map(VariableValue::Float).ok()
+            } else {
+                text.parse().map(VariableValue::Int).ok()
+            };
+            let value =
+                value.ok_or_else(|| parse_error(column, format!("invalid number '{}'", text)))?;
+            tokens.push((Token::Value(value), column));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while chars
+                .get(i)
+                .is_some_and(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+            {
+                i += 1;
+            }
+            let name: String = chars[start..i].iter().collect();
+            let token = match name.as_str() {
+                "true" => Token::Value(VariableValue::Bool(true)),
+                "false" => Token::Value(VariableValue::Bool(false)),
+                _ => Token::Name(name),
+            };
+            tokens.push((token, column));
+        } else {
+            let rest: String = chars[i..].iter().take(2).collect();
+            let operator = OPERATORS
+                .iter()
+                .find(|operator| rest.starts_with(**operator))
+                .ok_or_else(|| parse_error(column, format!("unexpected character '{}'", c)))?;
+            if *operator == "=" {
+                return Err(parse_error(column, "'=' is not an operator, use '=='"));
+            }
+            tokens.push((Token::Operator(operator), column));
+            i += operator.len();
+        }
+    }
+    Ok(tokens)
+}
+
+/// Replace the name tokens equal to `old` in `source`, keeping everything else
+fn rename_name(source: &str, old: &str, new: &str) -> Option<String> {
+    let starts: Vec<usize> = tokenize(source)
+        .ok()?
+        .into_iter()
+        .filter(|(token, _)| matches!(token, Token::Name(name) if name == old))
+        .map(|(_, column)| column - 1)
+        .collect();
+    if starts.is_empty() {
+        return None;
+    }
+
+    let chars: Vec<char> = source.chars().collect();
+    let old_len = old.chars().count();
+    let mut renamed = String::with_capacity(source.len());
+    let mut i = 0;
+    for start in starts {
+        renamed.extend(&chars[i..start]);
+        renamed.push_str(new);
+        i = start + old_len;
+    }
+    renamed.extend(&chars[i..]);
+    Some(renamed)
+}
+
+fn parse_error(column: usize, message: impl Into<String>) -> ExpressionError {
+    ExpressionError::Parse {
+        column,
+        message: message.into(),
+    }
+}
+
+/// Recursive descent parser over the token list
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    /// Column just past the end of the source
+    end_column: usize,
+    /// Parentheses and unary operators currently open
+    depth: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> ExpressionResult<Self> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            position: 0,
+            end_column: source.chars().count() + 1,
+            depth: 0,
+        })
+    }
+
+    fn parse(mut self) -> ExpressionResult<Expr> {
+        let expr = self.parse_or()?;
+        match self.tokens.get(self.position) {
+            None => Ok(expr),
+            Some((token, column)) => Err(parse_error(
+                *column,
+                format!("unexpected {}", describe(token)),
+            )),
+        }
+    }
+
+    fn column(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.end_column, |(_, column)| *column)
+    }
+
+    /// Consume the next token if it is one of `operators`
+    fn take_operator(&mut self, operators: &[&'static str]) -> Option<&'static str> {
+        match self.tokens.get(self.position) {
+            Some((Token::Operator(operator), _)) if operators.contains(operator) => {
+                self.position += 1;
+                Some(*operator)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a left-associative chain of `operators` over `operand`
+    fn parse_chain(
+        &mut self,
+        operators: &[&'static str],
+        operand: fn(&mut Self) -> ExpressionResult<Expr>,
+    ) -> ExpressionResult<Expr> {
+        let mut left = operand(self)?;
+        while let Some(symbol) = self.take_operator(operators) {
+            let right = operand(self)?;
+            left = Expr::Binary(binary_op(symbol), Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_or(&mut self) -> ExpressionResult<Expr> {
+        self.parse_chain(&["||"], Self::parse_and)
+    }
+
+    fn parse_and(&mut self) -> ExpressionResult<Expr> {
+        self.parse_chain(&["&&"], Self::parse_equality)
+    }
+
+    fn parse_equality(&mut self) -> ExpressionResult<Expr> {
+        self.parse_chain(&["==", "!="], Self::parse_comparison)
+    }
+
+    fn parse_comparison(&mut self) -> ExpressionResult<Expr> {
+        self.parse_chain(&["<", "<=", ">", ">="], Self::parse_additive)
+    }
+
+    fn parse_additive(&mut self) -> ExpressionResult<Expr> {
+        self.parse_chain(&["+", "-"], Self::parse_multiplicative)
+    }
+
+    fn parse_multiplicative(&mut self) -> ExpressionResult<Expr> {
+        self.parse_chain(&["*", "/", "%"], Self::parse_unary)
+    }
+
+    /// Parse `inner` one nesting level deeper, failing past [`MAX_NESTING`]
+    fn parse_nested(
+        &mut self,
+        column: usize,
+        inner: fn(&mut Self) -> ExpressionResult<Expr>,
+    ) -> ExpressionResult<Expr> {
+        if self.depth >= MAX_NESTING {
+            return Err(parse_error(
+                column,
+                format!("nested deeper than {} levels", MAX_NESTING),
+            ));
+        }
+        self.depth += 1;
+        let expr = inner(self);
+        self.depth -= 1;
+        expr
+    }
+
+    fn parse_unary(&mut self) -> ExpressionResult<Expr> {
+        let column = self.column();
+        match self.take_operator(&["!", "-"]) {
+            Some("!") => Ok(Expr::Not(Box::new(
+                self.parse_nested(column, Self::parse_unary)?,
+            ))),
+            Some(_) => Ok(Expr::Negate(Box::new(
+                self.parse_nested(column, Self::parse_unary)?,
+            ))),
+            None => self.parse_primary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> ExpressionResult<Expr> {
+        let column = self.column();
+        let Some((token, _)) = self.tokens.get(self.position).cloned() else {
+            return Err(parse_error(column, "expected a value"));
+        };
+        self.position += 1;
+        match token {
+            Token::Value(value) => Ok(Expr::Literal(value)),
+            Token::Name(name) => match name.strip_prefix(FLAG_PREFIX) {
+                Some("") => Err(parse_error(column, "expected a flag name after 'flags.'")),
+                Some(flag) => Ok(Expr::Flag(flag.to_string())),
+                None => Ok(Expr::Variable(name)),
+            },
+            Token::OpenParen => {
+                let expr = self.parse_nested(column, Self::parse_or)?;
+                match self.tokens.get(self.position) {
+                    Some((Token::CloseParen, _)) => {
+                        self.position += 1;
+                        Ok(expr)
+                    }
+                    _ => Err(parse_error(self.column(), "expected ')'")),
+                }
+            }
+            other => Err(parse_error(
+                column,
+                format!("unexpected {}", describe(&other)),
+            )),
+        }
+    }
+}
+
+fn binary_op(symbol: &str) -> BinaryOp {
+    match symbol {
+        "||" => BinaryOp::Or,
+        "&&" => BinaryOp::And,
+        "==" => BinaryOp::Compare(CompareOp::Equal),
+        "!=" => BinaryOp::Compare(CompareOp::NotEqual),
+        "<" => BinaryOp::Compare(CompareOp::LessThan),
+        "<=" => BinaryOp::Compare(CompareOp::LessOrEqual),
+        ">" => BinaryOp::Compare(CompareOp::GreaterThan),
+        ">=" => BinaryOp::Compare(CompareOp::GreaterOrEqual),
+        "+" => BinaryOp::Add,
+        "-" => BinaryOp::Subtract,
+        "*" => BinaryOp::Multiply,
+        "/" => BinaryOp::Divide,
+        _ => BinaryOp::Remainder,
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Value(VariableValue::String(text)) => format!("string \"{}\"", text),
+        Token::Value(value) => format!("value {:?}", value),
+        Token::Name(name) => format!("name '{}'", name),
+        Token::Operator(operator) => format!("'{}'", operator),
+        Token::OpenParen => "'('".to_string(),
+        Token::CloseParen => "')'".to_string(),
+    }
+}
+
+/// Flag and variable lookups during evaluation
+struct Lookup<'a> {
+    get_flag: &'a dyn Fn(&str) -> bool,
+    get_variable: &'a dyn Fn(&str) -> Option<VariableValue>,
+}
+
+impl Lookup<'_> {
+    /// Evaluate a node; `None` is a variable that was never set
+    fn eval(&self, expr: &Expr) -> ExpressionResult<Option<VariableValue>> {
+        match expr {
+            Expr::Literal(value) => Ok(Some(value.clone())),
+            Expr::Variable(name) => Ok((self.get_variable)(name)),
+            Expr::Flag(name) => Ok(Some(VariableValue::Bool((self.get_flag)(name)))),
+            Expr::Not(operand) => {
+                let value = self.eval(operand)?;
+                Ok(Some(VariableValue::Bool(
+                    !value.as_ref().is_some_and(is_truthy),
+                )))
+            }
+            Expr::Negate(operand) => match self.eval(operand)? {
+                Some(VariableValue::Int(n)) => Ok(Some(VariableValue::Int(n.saturating_neg()))),
+                Some(VariableValue::Float(f)) => Ok(Some(VariableValue::Float(-f))),
+                None => Ok(Some(VariableValue::Int(0))),
+                Some(other) => Err(ExpressionError::TypeMismatch {
+                    operator: "-",
+                    left: type_name(&other),
+                    right: type_name(&other),
+                }),
+            },
+            Expr::Binary(BinaryOp::Or, left, right) => {
+                let value = self.eval(left)?.as_ref().is_some_and(is_truthy)
+                    || self.eval(right)?.as_ref().is_some_and(is_truthy);
+                Ok(Some(VariableValue::Bool(value)))
+            }
+            Expr::Binary(BinaryOp::And, left, right) => {
+                let value = self.eval(left)?.as_ref().is_some_and(is_truthy)
+                    && self.eval(right)?.as_ref().is_some_and(is_truthy);
+                Ok(Some(VariableValue::Bool(value)))
+            }
+            Expr::Binary(op, left, right) => {
+                let (left, right) = match (self.eval(left)?, self.eval(right)?) {
+                    (Some(left), Some(right)) => (left, right),
+                    (Some(left), None) => {
+                        let right = default_like(&left);
+                        (left, right)
+                    }
+                    (None, Some(right)) => (default_like(&right), right),
+                    (None, None) => (VariableValue::Int(0), VariableValue::Int(0)),
+                };
+                apply(*op, &left, &right).map(Some)
+            }
+        }
+    }
+}
+
+/// Apply a comparison or arithmetic operator
+fn apply(
+    op: BinaryOp,
+    left: &VariableValue,
+    right: &VariableValue,
+) -> ExpressionResult<VariableValue> {
+    use VariableValue::{Float, Int, String};
+
+    let mismatch = || ExpressionError::TypeMismatch {
+        operator: op.symbol(),
+        left: type_name(left),
+        right: type_name(right),
+    };
+
+    if let BinaryOp::Compare(compare) = op {
+        // Integers compare with floats as floats
+        let result = match (left, right) {
+            (Int(a), Float(_)) => compare.compare(&Float(*a as f64), right),
+            (Float(_), Int(b)) => compare.compare(left, &Float(*b as f64)),
+            _ => compare.compare(left, right),
+        };
+        return Ok(VariableValue::Bool(result));
+    }
+
+    match (left, right) {
+        (Int(a), Int(b)) => match op {
+            BinaryOp::Add => Ok(Int(a.saturating_add(*b))),
+            BinaryOp::Subtract => Ok(Int(a.saturating_sub(*b))),
+            BinaryOp::Multiply => Ok(Int(a.saturating_mul(*b))),
+            BinaryOp::Divide => a
+                .checked_div(*b)
+                .map(Int)
+                .ok_or(ExpressionError::DivisionByZero),
+            BinaryOp::Remainder => a
+                .checked_rem(*b)
+                .map(Int)
+                .ok_or(ExpressionError::DivisionByZero),
+            _ => Err(mismatch()),
+        },
+        (Int(_) | Float(_), Int(_) | Float(_)) => {
+            let (a, b) = (as_float(left), as_float(right));
+            match op {
+                BinaryOp::Add => Ok(Float(a + b)),
+                BinaryOp::Subtract => Ok(Float(a - b)),
+                BinaryOp::Multiply => Ok(Float(a * b)),
+                BinaryOp::Divide => Ok(Float(a / b)),
+                BinaryOp::Remainder => Ok(Float(a % b)),
+                _ => Err(mismatch()),
+            }
+        }
+        (String(a), String(b)) if op == BinaryOp::Add => Ok(String(format!("{}{}", a, b))),
+        _ => Err(mismatch()),
+    }
+}
+
+fn as_float(value: &VariableValue) -> f64 {
+    match value {
+        VariableValue::Int(n) => *n as f64,
+        VariableValue::Float(f) => *f,
+        _ => 0.0,
+    }
+}
+
+/// Whether a value counts as true in `&&`, `||`, `!` and conditions
+fn is_truthy(value: &VariableValue) -> bool {
+    match value {
+        VariableValue::Bool(b) => *b,
+        VariableValue::Int(n) => *n != 0,
+        VariableValue::Float(f) => *f != 0.0,
+        VariableValue::String(s) => !s.is_empty(),
+    }
+}
+
+/// Default value of the type of `value`, used for variables never set
+fn default_like(value: &VariableValue) -> VariableValue {
+    match value {
+        VariableValue::Bool(_) => VariableValue::Bool(false),
+        VariableValue::Int(_) => VariableValue::Int(0),
+        VariableValue::Float(_) => VariableValue::Float(0.0),
+        VariableValue::String(_) => VariableValue::String(String::new()),
+    }
+}
+
+fn type_name(value: &VariableValue) -> &'static str {
+    match value {
+        VariableValue::Bool(_) => "bool",
+        VariableValue::Int(_) => "int",
+        VariableValue::Float(_) => "float",
+        VariableValue::String(_) => "string",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn eval_with(
+        source: &str,
+        flags: &[&str],
+        variables: &[(&str, VariableValue)],
+    ) -> ExpressionResult<VariableValue> {
+        let variables: HashMap<String, VariableValue> = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        Expression::parse(source)?.evaluate(&|flag| flags.contains(&flag), &|name| {
+            variables.get(name).cloned()
+        })
+    }
+
+    fn eval(source: &str) -> VariableValue {
+        eval_with(source, &[], &[]).unwrap()
+    }
+
+    #[test]
+    fn test_route_condition() {
+        let source = r#"affection >= 5 && !flags.betrayed || route == "mia""#;
+        let check = |flags: &[&str], affection: i64, route: &str| {
+            eval_with(
+                source,
+                flags,
+                &[
+                    ("affection", VariableValue::Int(affection)),
+                    ("route", VariableValue::String(route.to_string())),
+                ],
+            )
+            .unwrap()
+        };
+
+        assert_eq!(check(&[], 5, "yuki"), VariableValue::Bool(true));
+        assert_eq!(check(&["betrayed"], 5, "yuki"), VariableValue::Bool(false));
+        assert_eq!(check(&["betrayed"], 5, "mia"), VariableValue::Bool(true));
+        assert_eq!(check(&[], 4, "yuki"), VariableValue::Bool(false));
+    }
+
+    #[test]
+    fn test_precedence_and_arithmetic() {
+        assert_eq!(eval("1 + 2 * 3"), VariableValue::Int(7));
+        assert_eq!(eval("(1 + 2) * 3"), VariableValue::Int(9));
+        assert_eq!(eval("7 % 4 - -1"), VariableValue::Int(4));
+        assert_eq!(eval("1 + 0.5"), VariableValue::Float(1.5));
+        assert_eq!(eval("'a' + \"b\""), VariableValue::String("ab".to_string()));
+        assert_eq!(eval("1 < 2 == true"), VariableValue::Bool(true));
+        assert_eq!(eval("2 > 1.5"), VariableValue::Bool(true));
+        assert_eq!(eval("!0"), VariableValue::Bool(true));
+    }
+
+    #[test]
+    fn test_unset_variables_take_default_of_other_operand() {
+        assert_eq!(eval("score == 0"), VariableValue::Bool(true));
+        assert_eq!(eval("name == \"\""), VariableValue::Bool(true));
+        assert_eq!(eval("score + 2"), VariableValue::Int(2));
+        assert_eq!(eval("!visited"), VariableValue::Bool(true));
+        assert_eq!(eval("visited"), VariableValue::Bool(false));
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        assert_eq!(
+            eval_with("1 / 0", &[], &[]),
+            Err(ExpressionError::DivisionByZero)
+        );
+        assert!(matches!(
+            eval_with("true + 1", &[], &[]),
+            Err(ExpressionError::TypeMismatch { operator: "+", .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors_report_column() {
+        let column = |source: &str| match Expression::parse(source) {
+            Err(ExpressionError::Parse { column, .. }) => column,
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert_eq!(column("a >= "), 6);
+        assert_eq!(column("a = 1"), 3);
+        assert_eq!(column("(a && b"), 8);
+        assert_eq!(column("a b"), 3);
+        assert_eq!(column("\"open"), 1);
+        assert_eq!(column("flags. == 1"), 1);
+    }
+
+    #[test]
+    fn test_deep_nesting_is_an_error() {
+        let nested = |depth: usize| {
+            format!(
+                "{}{}1{}",
+                "-".repeat(depth),
+                "(".repeat(depth),
+                ")".repeat(depth)
+            )
+        };
+        assert!(Expression::parse(nested(20)).is_ok());
+
+        for source in [
+            nested(10_000),
+            format!("{}true", "!".repeat(10_000)),
+            format!("{}1{}", "(".repeat(10_000), ")".repeat(10_000)),
+        ] {
+            assert!(matches!(
+                Expression::parse(source),
+                Err(ExpressionError::Parse { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rename_names() {
+        let expression = Expression::parse("score + bonus >= 5 && !flags.score").unwrap();
+        assert_eq!(
+            expression.rename_variable("score", "points").as_deref(),
+            Some("points + bonus >= 5 && !flags.score")
+        );
+        assert_eq!(
+            expression.rename_flag("score", "met").as_deref(),
+            Some("score + bonus >= 5 && !flags.met")
+        );
+        assert_eq!(expression.rename_variable("scor", "points"), None);
+        assert_eq!(
+            Expression::parse("\"score\" == name")
+                .unwrap()
+                .rename_variable("score", "points"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_read_names() {
+        let expression =
+            Expression::parse("score + bonus >= 5 && !(flags.met || score == rank)").unwrap();
+        assert_eq!(expression.variables(), ["score", "bonus", "score", "rank"]);
+        assert_eq!(expression.flags(), ["met"]);
+        assert!(Expression::parse("1 + 2").unwrap().variables().is_empty());
+    }
+
+    #[test]
+    fn test_serializes_as_source() {
+        let expression = Expression::parse("score > 10").unwrap();
+        let json = serde_json::to_string(&expression).unwrap();
+        assert_eq!(json, "\"score > 10\"");
+        let parsed: Expression = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, expression);
+        assert!(serde_json::from_str::<Expression>("\"score >\"").is_err());
+    }
+}
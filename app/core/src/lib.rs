@@ -35,43 +35,61 @@
 //! scenario.add_scene("scene_01", scene);
 //! ```
 
+pub mod achievement;
 pub mod asset;
 pub mod backlog;
+pub mod bidi;
 pub mod cg_metadata;
 pub mod character;
 pub mod condition;
 pub mod config;
 pub mod error;
+pub mod expression;
+pub mod markup;
 pub mod read_history;
 pub mod scenario;
+pub mod subtitle;
 pub mod types;
 pub mod unlocks;
 pub mod variable;
 
 // Re-export commonly used types
+pub use achievement::{
+    AchievementCondition, AchievementDef, AchievementManifest, AchievementRegistry,
+};
 pub use asset::{
-    AudioMeta, BackgroundDef, BackgroundManifest, BackgroundMeta, BgmDef, BgmManifest, SeDef,
-    SeManifest, UiThemeDef, UiThemeManifest,
+    AudioLoadMode, AudioMeta, BackgroundDef, BackgroundManifest, BackgroundMeta, BgmDef,
+    BgmManifest, HudManifest, HudSource, HudVisibility, HudWidgetDef, HudWidgetKind, LoopPoint,
+    LoopRegion, ParticleEffectDef, ParticleEffectManifest, QuickMenuButtonDef,
+    QuickMenuOrientation, QuickMenuTheme, SeDef, SeManifest, SePool, SePoolMode, UiThemeDef,
+    UiThemeManifest,
 };
 pub use backlog::{Backlog, BacklogEntry};
+pub use bidi::TextDirection;
 pub use cg_metadata::{CgId, CgMetadata, CgRegistry, CgVariation};
 pub use character::{
     CharacterDef, CharacterManifest, CharacterPosition, CharacterRegistry, CharacterState,
-    Expression,
+    Expression, SpriteMode, TtsVoice,
 };
 pub use condition::{CompareOp, Condition};
 pub use config::{
     AnimationSettings, AudioConfig, DialogueBoxConfig, GameConfig, GameMetadata, GraphicsConfig,
-    PathConfig, SkipMode, TextConfig, TextSpeed, UiConfig, UserSettings,
+    PathConfig, ProjectManifest, SaveSlotConfig, SaveSlotKind, ScenarioEntry, SkipMode, TextConfig,
+    TextSpeed, UiConfig, UserSettings,
 };
 pub use error::{
     ConfigError, ConfigResult, EngineError, EngineResult, ScenarioError, ScenarioResult,
 };
+// `Expression` is taken by character expressions; use `expression::Expression`
+pub use expression::{ExpressionError, ExpressionResult};
+pub use markup::{MarkupBuilder, RichText, SpanStyle, TextEffect, TextSpan};
 pub use read_history::{DialogueId, ReadHistory};
 pub use scenario::{
-    Choice, ChoiceOption, Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene, Speaker,
-    VariableValue,
+    Choice, ChoiceOption, ConventionalVoice, Dialogue, DialogueLanguages, EffectLifetime,
+    EndBehavior, Scenario, ScenarioCommand, ScenarioDocument, ScenarioMetadata, Scene, Speaker,
+    VariableValue, VoiceResolver,
 };
+pub use subtitle::{SubtitleCue, SubtitleError, SubtitleFormat, SubtitleTrack};
 pub use types::{
     AssetRef, AudioId, CharacterId, Color, FlagId, Point, Rect, SceneId, Size, SlideDirection,
     Transition, TransitionKind, VariableId, WipeDirection,
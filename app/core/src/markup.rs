@@ -0,0 +1,484 @@
+//! Inline text markup for dialogue
+//!
+//! Dialogue text can style parts of a line with bracket tags, which are
+//! removed from the displayed text:
+//!
+//! - `[color=#ff0000]...[/color]` colors the text (`#rrggbb` or `#rrggbbaa`)
+//! - `[b]...[/b]` makes the text bold
+//! - `[size=1.5]...[/size]` scales the text relative to the dialogue font size
+//! - `[wave]...[/wave]` makes the characters bob up and down
+//! - `[shake]...[/shake]` makes the characters tremble
+//!
+//! Tags nest; the innermost color, size and effect win. A tag left open
+//! styles the rest of the line. Brackets that don't form a known tag are
+//! displayed as written.
+
+use crate::types::Color;
+use std::ops::Range;
+
+/// Size multiplier range accepted by `[size]`
+const MIN_SIZE: f32 = 0.25;
+const MAX_SIZE: f32 = 4.0;
+
+/// Wave effect: radians per second, radians between neighbouring
+/// characters, and height relative to the font size
+const WAVE_SPEED: f32 = 6.0;
+const WAVE_PHASE_STEP: f32 = 0.5;
+const WAVE_AMPLITUDE: f32 = 0.15;
+
+/// Shake effect: new offsets per second, and distance relative to the font size
+const SHAKE_RATE: f32 = 30.0;
+const SHAKE_AMPLITUDE: f32 = 0.06;
+
+/// Per-character motion of kinetic text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEffect {
+    /// Characters bob up and down in a wave running along the text
+    Wave,
+    /// Characters tremble in place
+    Shake,
+}
+
+impl TextEffect {
+    /// Offset of the character at `char_index` from its resting position,
+    /// `time` seconds into the animation
+    pub fn offset(self, char_index: usize, time: f32, font_size: f32) -> (f32, f32) {
+        match self {
+            Self::Wave => {
+                let phase = time * WAVE_SPEED - char_index as f32 * WAVE_PHASE_STEP;
+                (0.0, phase.sin() * font_size * WAVE_AMPLITUDE)
+            }
+            Self::Shake => {
+                let frame = (time.max(0.0) * SHAKE_RATE) as u64;
+                let seed = (char_index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ frame;
+                let distance = font_size * SHAKE_AMPLITUDE;
+                (
+                    noise(seed) * distance,
+                    noise(seed.wrapping_add(0x5851_F42D)) * distance,
+                )
+            }
+        }
+    }
+}
+
+/// Deterministic pseudo-random value in `-1.0..=1.0`
+fn noise(seed: u64) -> f32 {
+    let mut x = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+    (x >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+/// Styling of a run of text
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpanStyle {
+    /// Text color (None for the dialogue's color)
+    pub color: Option<Color>,
+    /// Bold text
+    pub bold: bool,
+    /// Font size multiplier (None for the dialogue's size)
+    pub size: Option<f32>,
+    /// Kinetic effect
+    pub effect: Option<TextEffect>,
+}
+
+impl SpanStyle {
+    /// Check if the style changes nothing
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Font size for a dialogue font size of `base`
+    pub fn font_size(&self, base: f32) -> f32 {
+        base * self.size.unwrap_or(1.0)
+    }
+}
+
+/// Styled run of text, in characters of the tag-free text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextSpan {
+    /// Index of the first character
+    pub start: usize,
+    /// Index after the last character
+    pub end: usize,
+    /// Style of the run
+    pub style: SpanStyle,
+}
+
+impl TextSpan {
+    /// Style of the character at `char_index` in sorted, non-overlapping spans
+    ///
+    /// Returns None for unstyled characters.
+    pub fn style_at(spans: &[TextSpan], char_index: usize) -> Option<&SpanStyle> {
+        let index = spans.partition_point(|span| span.end <= char_index);
+        spans
+            .get(index)
+            .filter(|span| span.start <= char_index)
+            .map(|span| &span.style)
+    }
+}
+
+/// Dialogue text with its markup extracted
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RichText {
+    /// Text to display (tags removed)
+    pub text: String,
+    /// Styled runs, sorted and non-overlapping
+    pub spans: Vec<TextSpan>,
+}
+
+impl RichText {
+    /// Parse markup tags out of `source`
+    pub fn parse(source: &str) -> Self {
+        let mut text = String::with_capacity(source.len());
+        let mut markup = MarkupBuilder::new();
+        let mut char_index = 0usize;
+        let mut rest = source;
+
+        while let Some(open) = rest.find('[') {
+            let before = &rest[..open];
+            text.push_str(before);
+            char_index = char_index.saturating_add(before.chars().count());
+
+            let after_open = &rest[open + 1..];
+            let tag = after_open
+                .find(']')
+                .map(|close| (&after_open[..close], &after_open[close + 1..]));
+
+            match tag.filter(|(tag, _)| markup.apply_tag(tag, char_index)) {
+                Some((_, remaining)) => rest = remaining,
+                None => {
+                    text.push('[');
+                    char_index = char_index.saturating_add(1);
+                    rest = after_open;
+                }
+            }
+        }
+        text.push_str(rest);
+        char_index = char_index.saturating_add(rest.chars().count());
+
+        Self {
+            text,
+            spans: markup.finish(char_index),
+        }
+    }
+
+    /// Style of the character at `char_index` (None if unstyled)
+    pub fn style_at(&self, char_index: usize) -> Option<&SpanStyle> {
+        TextSpan::style_at(&self.spans, char_index)
+    }
+
+    /// Check if any text moves, so it needs repainting every frame
+    pub fn has_motion(&self) -> bool {
+        has_motion(&self.spans)
+    }
+}
+
+/// Check if any span has a kinetic effect
+pub fn has_motion(spans: &[TextSpan]) -> bool {
+    spans.iter().any(|span| span.style.effect.is_some())
+}
+
+/// Split `text` into byte ranges, each unstyled (None) or styled by the span
+/// at the given index
+///
+/// Spans may reach past the end of `text`, so the spans of a whole line can
+/// style the part revealed so far.
+pub fn styled_runs(text: &str, spans: &[TextSpan]) -> Vec<(Range<usize>, Option<usize>)> {
+    let byte_offset = |char_index: usize| {
+        text.char_indices()
+            .nth(char_index)
+            .map_or(text.len(), |(offset, _)| offset)
+    };
+
+    let mut runs = Vec::with_capacity(spans.len() * 2 + 1);
+    let mut cursor = 0;
+    for (index, span) in spans.iter().enumerate() {
+        let start = byte_offset(span.start).max(cursor);
+        let end = byte_offset(span.end).max(start);
+        if start > cursor {
+            runs.push((cursor..start, None));
+        }
+        if end > start {
+            runs.push((start..end, Some(index)));
+        }
+        cursor = end;
+    }
+    if cursor < text.len() {
+        runs.push((cursor..text.len(), None));
+    }
+    runs
+}
+
+/// Style change made by an open tag
+#[derive(Debug, Clone, Copy)]
+enum StyleChange {
+    Color(Color),
+    Bold,
+    Size(f32),
+    Effect(TextEffect),
+}
+
+impl StyleChange {
+    fn apply(self, style: &mut SpanStyle) {
+        match self {
+            Self::Color(color) => style.color = Some(color),
+            Self::Bold => style.bold = true,
+            Self::Size(size) => style.size = Some(size),
+            Self::Effect(effect) => style.effect = Some(effect),
+        }
+    }
+}
+
+/// Builds styled spans from markup tags met while scanning text
+///
+/// Used by parsers that handle other inline tags in the same pass (like the
+/// typewriter's `{w}` pauses), so character indices stay consistent.
+#[derive(Debug, Default)]
+pub struct MarkupBuilder {
+    /// Open tags, outermost first
+    open: Vec<(&'static str, StyleChange)>,
+    /// Finished spans
+    spans: Vec<TextSpan>,
+    /// Start of the run styled by the open tags
+    run_start: usize,
+}
+
+impl MarkupBuilder {
+    /// Create a builder with no open tags
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply the tag between brackets, met before the character at
+    /// `char_index`
+    ///
+    /// Returns `false` for unknown or malformed tags, which are displayed as
+    /// written.
+    pub fn apply_tag(&mut self, tag: &str, char_index: usize) -> bool {
+        let tag = tag.trim();
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            let Some(position) = self.open.iter().rposition(|(open, _)| *open == name) else {
+                return false;
+            };
+            self.end_run(char_index);
+            self.open.remove(position);
+            return true;
+        }
+
+        let (name, value) = match tag.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (tag, None),
+        };
+        let opened = match (name, value) {
+            ("b", None) => ("b", StyleChange::Bold),
+            ("wave", None) => ("wave", StyleChange::Effect(TextEffect::Wave)),
+            ("shake", None) => ("shake", StyleChange::Effect(TextEffect::Shake)),
+            ("color", Some(value)) => match parse_color(value) {
+                Some(color) => ("color", StyleChange::Color(color)),
+                None => return false,
+            },
+            ("size", Some(value)) => match value.parse::<f32>() {
+                Ok(size) if size.is_finite() && size > 0.0 => {
+                    ("size", StyleChange::Size(size.clamp(MIN_SIZE, MAX_SIZE)))
+                }
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        self.end_run(char_index);
+        self.open.push(opened);
+        true
+    }
+
+    /// Names of the tags still open, outermost first
+    pub fn open_tags(&self) -> Vec<&'static str> {
+        self.open.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Close tags left open at the end of `char_count` characters and
+    /// return the spans
+    pub fn finish(mut self, char_count: usize) -> Vec<TextSpan> {
+        self.end_run(char_count);
+        self.spans
+    }
+
+    /// Style given by the open tags
+    fn style(&self) -> SpanStyle {
+        let mut style = SpanStyle::default();
+        for (_, change) in &self.open {
+            change.apply(&mut style);
+        }
+        style
+    }
+
+    /// End the run styled by the open tags before `char_index`
+    fn end_run(&mut self, char_index: usize) {
+        let start = self.run_start;
+        self.run_start = char_index;
+        if char_index <= start {
+            return;
+        }
+
+        let style = self.style();
+        if style.is_plain() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.end == start && last.style == style => last.end = char_index,
+            _ => self.spans.push(TextSpan {
+                start,
+                end: char_index,
+                style,
+            }),
+        }
+    }
+}
+
+/// Parse `#rrggbb` or `#rrggbbaa`
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let number = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(Color::hex(number)),
+        8 => Some(Color::hex_alpha(number)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_text() {
+        let rich = RichText::parse("Hello, world!");
+        assert_eq!(rich.text, "Hello, world!");
+        assert!(rich.spans.is_empty());
+    }
+
+    #[test]
+    fn test_parse_color_and_bold() {
+        let rich = RichText::parse("I [b]really[/b] like [color=#ff0000]red[/color].");
+        assert_eq!(rich.text, "I really like red.");
+        assert_eq!(
+            rich.spans,
+            [
+                TextSpan {
+                    start: 2,
+                    end: 8,
+                    style: SpanStyle {
+                        bold: true,
+                        ..SpanStyle::default()
+                    },
+                },
+                TextSpan {
+                    start: 14,
+                    end: 17,
+                    style: SpanStyle {
+                        color: Some(Color::hex(0xff0000)),
+                        ..SpanStyle::default()
+                    },
+                },
+            ]
+        );
+        assert!(rich.style_at(0).is_none());
+        assert!(rich.style_at(2).is_some_and(|style| style.bold));
+        assert!(rich.style_at(8).is_none());
+    }
+
+    #[test]
+    fn test_nested_tags_combine() {
+        let rich = RichText::parse("[b]a[size=2]b[wave]c[/wave][/size]d[/b]");
+        assert_eq!(rich.text, "abcd");
+        let styles: Vec<_> = (0..4).map(|i| *rich.style_at(i).unwrap()).collect();
+        assert!(styles.iter().all(|style| style.bold));
+        assert_eq!(styles[0].size, None);
+        assert_eq!(styles[1].size, Some(2.0));
+        assert_eq!(styles[2].effect, Some(TextEffect::Wave));
+        assert_eq!(styles[3], styles[0]);
+        assert!(rich.has_motion());
+    }
+
+    #[test]
+    fn test_unclosed_tag_styles_rest_of_line() {
+        let rich = RichText::parse("calm [shake]scared");
+        assert_eq!(rich.text, "calm scared");
+        assert_eq!(rich.spans.len(), 1);
+        assert_eq!(rich.spans[0].start, 5);
+        assert_eq!(rich.spans[0].end, 11);
+    }
+
+    #[test]
+    fn test_open_tags() {
+        let mut markup = MarkupBuilder::new();
+        assert!(markup.apply_tag("b", 0));
+        assert!(markup.apply_tag("wave", 1));
+        assert!(markup.apply_tag("color=#00ff00", 2));
+        assert!(markup.apply_tag("/wave", 3));
+        assert_eq!(markup.open_tags(), ["b", "color"]);
+    }
+
+    #[test]
+    fn test_unknown_tags_are_displayed() {
+        let rich = RichText::parse("[x] [color=red]a[/color] [/b] [size=-1] [");
+        assert_eq!(rich.text, "[x] [color=red]a[/color] [/b] [size=-1] [");
+        assert!(rich.spans.is_empty());
+    }
+
+    #[test]
+    fn test_indices_count_characters() {
+        let rich = RichText::parse("こんにちは[b]世界[/b]");
+        assert_eq!(rich.text, "こんにちは世界");
+        assert_eq!(rich.spans[0].start, 5);
+        assert_eq!(rich.spans[0].end, 7);
+    }
+
+    #[test]
+    fn test_styled_runs_split_text() {
+        let rich = RichText::parse("ab[b]cd[/b]e");
+        assert_eq!(
+            styled_runs(&rich.text, &rich.spans),
+            [(0..2, None), (2..4, Some(0)), (4..5, None)]
+        );
+        // Revealed part of the line only
+        assert_eq!(
+            styled_runs("abc", &rich.spans),
+            [(0..2, None), (2..3, Some(0))]
+        );
+        assert_eq!(styled_runs("", &rich.spans), []);
+    }
+
+    #[test]
+    fn test_size_is_clamped() {
+        let rich = RichText::parse("[size=100]big");
+        assert_eq!(rich.spans[0].style.size, Some(MAX_SIZE));
+        assert_eq!(rich.spans[0].style.font_size(10.0), 40.0);
+    }
+
+    #[test]
+    fn test_effect_offsets() {
+        let (x, y) = TextEffect::Wave.offset(0, 0.0, 20.0);
+        assert_eq!(x, 0.0);
+        assert_eq!(y, 0.0);
+        let (_, y) = TextEffect::Wave.offset(0, 0.25, 20.0);
+        assert!(y.abs() <= 20.0 * WAVE_AMPLITUDE);
+
+        for char_index in 0..32 {
+            let (x, y) = TextEffect::Shake.offset(char_index, 1.0, 20.0);
+            assert!(x.abs() <= 20.0 * SHAKE_AMPLITUDE);
+            assert!(y.abs() <= 20.0 * SHAKE_AMPLITUDE);
+        }
+        assert_eq!(
+            TextEffect::Shake.offset(3, 0.5, 20.0),
+            TextEffect::Shake.offset(3, 0.5, 20.0)
+        );
+    }
+}
@@ -11,6 +11,12 @@ pub struct ChoiceOption {
     /// Optional conditions that must be met for this option to be available
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub conditions: Vec<Condition>,
+    /// Condition that must hold for this option to be shown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Condition>,
+    /// Condition under which this option is shown but cannot be selected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled_condition: Option<Condition>,
     /// Flags to set when this option is selected
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub flags_to_set: Vec<String>,
@@ -23,6 +29,8 @@ impl ChoiceOption {
             text: text.into(),
             next_scene: next_scene.into(),
             conditions: Vec::new(),
+            condition: None,
+            disabled_condition: None,
             flags_to_set: Vec::new(),
         }
     }
@@ -33,6 +41,18 @@ impl ChoiceOption {
         self
     }
 
+    /// Only show this choice while `condition` holds
+    pub fn visible_if(mut self, condition: Condition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Show this choice greyed out while `condition` holds
+    pub fn disabled_if(mut self, condition: Condition) -> Self {
+        self.disabled_condition = Some(condition);
+        self
+    }
+
     /// Add a flag to set when selected
     pub fn with_flag(mut self, flag: impl Into<String>) -> Self {
         self.flags_to_set.push(flag.into());
@@ -40,8 +60,18 @@ impl ChoiceOption {
     }
 
     /// Check if this choice is available based on conditions
+    ///
+    /// Unavailable choices are hidden from the player.
     pub fn is_available(&self, check_condition: impl Fn(&Condition) -> bool) -> bool {
-        self.conditions.iter().all(check_condition)
+        self.conditions.iter().all(&check_condition)
+            && self.condition.as_ref().is_none_or(&check_condition)
+    }
+
+    /// Check if this choice is shown but cannot be selected
+    pub fn is_disabled(&self, check_condition: impl Fn(&Condition) -> bool) -> bool {
+        self.disabled_condition
+            .as_ref()
+            .is_some_and(check_condition)
     }
 }
 
@@ -53,6 +83,9 @@ pub struct Choice {
     pub prompt: Option<String>,
     /// Available choice options
     pub options: Vec<ChoiceOption>,
+    /// Show the options in a random order (quizzes, interrogations)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub shuffle: bool,
 }
 
 impl Choice {
@@ -61,6 +94,7 @@ impl Choice {
         Self {
             prompt: None,
             options,
+            shuffle: false,
         }
     }
 
@@ -69,9 +103,16 @@ impl Choice {
         Self {
             prompt: Some(prompt.into()),
             options,
+            shuffle: false,
         }
     }
 
+    /// Set whether the options are shown in a random order
+    pub fn with_shuffle(mut self, shuffle: bool) -> Self {
+        self.shuffle = shuffle;
+        self
+    }
+
     /// Add an option to this choice
     pub fn add_option(&mut self, option: ChoiceOption) {
         self.options.push(option);
@@ -87,6 +128,52 @@ impl Choice {
             .filter(|opt| opt.is_available(&check_condition))
             .collect()
     }
+
+    /// Get the display order of the available options
+    ///
+    /// Returns indices into `options`, in the order they are shown. Unavailable
+    /// options are left out. When `shuffle` is set the order is a permutation
+    /// derived from `seed`, so the same seed always gives the same order.
+    pub fn display_order(
+        &self,
+        check_condition: impl Fn(&Condition) -> bool,
+        seed: u64,
+    ) -> Vec<usize> {
+        let mut order: Vec<usize> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, opt)| opt.is_available(&check_condition))
+            .map(|(index, _)| index)
+            .collect();
+
+        if self.shuffle {
+            // Fisher-Yates shuffle
+            let mut rng = SplitMix64(seed);
+            for i in (1..order.len()).rev() {
+                let j = (rng.next() % (i as u64 + 1)) as usize;
+                order.swap(i, j);
+            }
+        }
+
+        order
+    }
+}
+
+/// SplitMix64 generator for deterministic choice shuffling
+///
+/// Kept in-crate so shuffled orders never change with a dependency update,
+/// which would reorder choices in existing saves.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
 }
 
 #[cfg(test)]
@@ -160,6 +247,44 @@ mod tests {
         assert!(!is_available);
     }
 
+    #[test]
+    fn test_choice_option_visible_and_disabled_conditions() {
+        let option = ChoiceOption::new("Use the key", "scene_door")
+            .visible_if(Condition::flag("found_key", true))
+            .disabled_if(Condition::flag("door_jammed", true));
+        let flags = |found_key: bool, door_jammed: bool| {
+            move |condition: &Condition| match condition {
+                Condition::Flag { flag_name, .. } if flag_name == "found_key" => found_key,
+                Condition::Flag { flag_name, .. } if flag_name == "door_jammed" => door_jammed,
+                _ => false,
+            }
+        };
+
+        assert!(!option.is_available(flags(false, false)));
+        assert!(option.is_available(flags(true, false)));
+        assert!(!option.is_disabled(flags(true, false)));
+        assert!(option.is_disabled(flags(true, true)));
+        assert!(!ChoiceOption::new("Leave", "scene_out").is_disabled(|_| true));
+    }
+
+    #[test]
+    fn test_choice_option_conditions_from_toml() {
+        let option: ChoiceOption = toml::from_str(
+            r#"
+text = "Use the key"
+next_scene = "scene_door"
+condition = { type = "Flag", flag_name = "found_key" }
+disabled_condition = { type = "Flag", flag_name = "door_jammed" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(option.condition, Some(Condition::flag("found_key", true)));
+        assert_eq!(
+            option.disabled_condition,
+            Some(Condition::flag("door_jammed", true))
+        );
+    }
+
     #[test]
     fn test_choice_option_serialization() {
         let option = ChoiceOption::new("Test choice", "scene_test");
@@ -240,5 +365,57 @@ mod tests {
         let serialized = serde_json::to_string(&choice).unwrap();
         let deserialized: Choice = serde_json::from_str(&serialized).unwrap();
         assert_eq!(choice, deserialized);
+        assert!(!serialized.contains("shuffle"));
+
+        let shuffled = choice.with_shuffle(true);
+        let serialized = serde_json::to_string(&shuffled).unwrap();
+        let deserialized: Choice = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(shuffled, deserialized);
+    }
+
+    fn quiz_choice() -> Choice {
+        Choice::new(
+            (0..6)
+                .map(|i| ChoiceOption::new(format!("Answer {}", i), format!("scene_{}", i)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_choice_display_order_unshuffled() {
+        let mut choice = quiz_choice();
+        choice.options[2]
+            .conditions
+            .push(Condition::flag("hidden", true));
+
+        assert_eq!(choice.display_order(|_| true, 42), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(choice.display_order(|_| false, 42), vec![0, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_choice_display_order_shuffled_is_deterministic() {
+        let choice = quiz_choice().with_shuffle(true);
+
+        let order = choice.display_order(|_| true, 42);
+        assert_eq!(order, choice.display_order(|_| true, 42));
+
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![0, 1, 2, 3, 4, 5]);
+
+        // Some seed gives a different order
+        assert!((0..16).any(|seed| choice.display_order(|_| true, seed) != order));
+    }
+
+    #[test]
+    fn test_choice_display_order_shuffled_skips_unavailable() {
+        let mut choice = quiz_choice().with_shuffle(true);
+        choice.options[0]
+            .conditions
+            .push(Condition::flag("hidden", true));
+
+        let order = choice.display_order(|_| false, 7);
+        assert_eq!(order.len(), 5);
+        assert!(!order.contains(&0));
     }
 }
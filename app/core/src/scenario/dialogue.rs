@@ -1,5 +1,6 @@
 use crate::character::{CharacterAnimation, Expression};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Speaker in a dialogue
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -72,6 +73,12 @@ pub struct Dialogue {
     /// Optional character animation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub animation: Option<CharacterAnimation>,
+    /// Free-form metadata for plugins and custom commands (ignored by the engine)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tags: HashMap<String, String>,
+    /// Translated text by language code (e.g. `"en"`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub translations: HashMap<String, String>,
 }
 
 impl Dialogue {
@@ -82,6 +89,8 @@ impl Dialogue {
             text: text.into(),
             expression: None,
             animation: None,
+            tags: HashMap::new(),
+            translations: HashMap::new(),
         }
     }
 
@@ -106,12 +115,97 @@ impl Dialogue {
         self.animation = Some(animation);
         self
     }
+
+    /// Add a metadata tag
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get a metadata tag
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+
+    /// Add a translation of the text
+    pub fn with_translation(
+        mut self,
+        language: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        self.translations.insert(language.into(), text.into());
+        self
+    }
+
+    /// Get the text in a language, if the line has a translation for it
+    pub fn translation(&self, language: &str) -> Option<&str> {
+        self.translations.get(language).map(String::as_str)
+    }
+
+    /// Get the text in a language
+    ///
+    /// Falls back to the text as written when `language` is `None` or the
+    /// line has no translation for it.
+    pub fn text_in(&self, language: Option<&str>) -> &str {
+        language
+            .and_then(|language| self.translation(language))
+            .unwrap_or(&self.text)
+    }
+
+    /// Get the text tracks to display: the primary text and, in dual-language
+    /// mode, the secondary text shown with it
+    ///
+    /// The secondary track is omitted when it would repeat the primary one.
+    pub fn text_tracks(&self, languages: &DialogueLanguages) -> (&str, Option<&str>) {
+        let primary = self.text_in(languages.primary.as_deref());
+        let secondary = languages
+            .dual
+            .then(|| self.text_in(languages.secondary.as_deref()))
+            .filter(|secondary| *secondary != primary);
+        (primary, secondary)
+    }
+}
+
+/// Languages dialogue is displayed in
+///
+/// `None` means the text as written in the scenario.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogueLanguages {
+    /// Language of the main text
+    pub primary: Option<String>,
+    /// Show a second language under the main text
+    pub dual: bool,
+    /// Language of the second text
+    pub secondary: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_text_tracks() {
+        let dialogue = Dialogue::narrator("おはよう").with_translation("en", "Good morning");
+
+        assert_eq!(dialogue.text_in(None), "おはよう");
+        assert_eq!(dialogue.text_in(Some("en")), "Good morning");
+        assert_eq!(dialogue.text_in(Some("fr")), "おはよう");
+
+        let dual = DialogueLanguages {
+            primary: None,
+            dual: true,
+            secondary: Some("en".to_string()),
+        };
+        assert_eq!(
+            dialogue.text_tracks(&dual),
+            ("おはよう", Some("Good morning"))
+        );
+
+        // A missing translation would only repeat the main text
+        let untranslated = Dialogue::narrator("はい");
+        assert_eq!(untranslated.text_tracks(&dual), ("はい", None));
+    }
+
     #[test]
     fn test_speaker_default() {
         let speaker = Speaker::default();
@@ -302,4 +396,25 @@ animation = { type = "faint", preset = "medium" }
         assert!(dialogue.animation.is_some());
         assert!(dialogue.animation.as_ref().unwrap().is_keyframe_based());
     }
+
+    #[test]
+    fn test_dialogue_tags_toml() {
+        let toml_str = r#"
+speaker = "Narrator"
+text = "The door creaks."
+tags = { analytics = "door_opened", audio_description = "A heavy wooden door swings open" }
+"#;
+        let dialogue: Dialogue = toml::from_str(toml_str).unwrap();
+        assert_eq!(dialogue.tag("analytics"), Some("door_opened"));
+        assert_eq!(dialogue.tags.len(), 2);
+        assert_eq!(dialogue.tag("missing"), None);
+
+        // Untagged lines don't serialize an empty map
+        let untagged = Dialogue::narrator("Hello");
+        assert!(!toml::to_string(&untagged).unwrap().contains("tags"));
+
+        let tagged = Dialogue::narrator("Hello").with_tag("mood", "calm");
+        let serialized = toml::to_string(&tagged).unwrap();
+        assert_eq!(toml::from_str::<Dialogue>(&serialized).unwrap(), tagged);
+    }
 }
@@ -0,0 +1,760 @@
+//! Format-preserving edits of scenario TOML files
+//!
+//! [`ScenarioDocument`] edits the text of a scenario file in place, for the
+//! editor and refactoring tools. Comments, blank lines, key order and the
+//! inline or table style of everything an edit does not touch stay as they
+//! were, so saving an edit produces a small diff.
+//!
+//! ```
+//! use narrative_core::ScenarioDocument;
+//!
+//! let mut document = ScenarioDocument::parse(
+//!     r#"
+//! [chapter]
+//! id = "chapter_01"
+//! title = "Chapter 1"
+//!
+//! [[scenes]]
+//! id = "park"
+//! title = "Park"
+//!
+//! ## Go to the station
+//! [[scenes.commands]]
+//! type = "JumpToScene"
+//! scene_id = "station"
+//!
+//! [[scenes]]
+//! id = "station"
+//! title = "Station"
+//! "#,
+//! )?;
+//! assert_eq!(document.rename_scene("station", "platform")?, 1);
+//! assert!(document.to_string().contains("# Go to the station\n[[scenes.commands]]"));
+//! # Ok::<(), narrative_core::ScenarioError>(())
+//! ```
+
+use super::ScenarioCommand;
+use crate::error::{ScenarioError, ScenarioResult};
+use crate::expression::Expression;
+use std::fmt;
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, TableLike, Value};
+
+/// A scenario file that can be edited without losing its formatting
+///
+/// Edits work on the `[[scenes]]` of this file only; scenes pulled in with
+/// `include` are edited in their own files. The edited text is returned by
+/// [`ToString::to_string`].
+#[derive(Debug, Clone)]
+pub struct ScenarioDocument {
+    document: DocumentMut,
+}
+
+impl ScenarioDocument {
+    /// Parse the text of a scenario file
+    ///
+    /// # Errors
+    /// Returns an error if the text is not valid TOML.
+    pub fn parse(text: &str) -> ScenarioResult<Self> {
+        let document = text
+            .parse::<DocumentMut>()
+            .map_err(|e| ScenarioError::Other(format!("Invalid scenario TOML: {}", e)))?;
+        Ok(Self { document })
+    }
+
+    /// IDs of the scenes in this file, in file order
+    pub fn scene_ids(&self) -> Vec<String> {
+        self.scenes()
+            .map(|scenes| {
+                scenes
+                    .iter()
+                    .filter_map(|scene| scene.get("id").and_then(Item::as_str))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rename a scene of this file and update the references to it
+    ///
+    /// Returns the number of references updated (see
+    /// [`Self::rename_scene_references`]).
+    ///
+    /// # Errors
+    /// Returns an error if the scene is not in this file or a scene with the
+    /// new ID already is.
+    pub fn rename_scene(&mut self, old_id: &str, new_id: &str) -> ScenarioResult<usize> {
+        let ids = self.scene_ids();
+        if !ids.iter().any(|id| id == old_id) {
+            return Err(ScenarioError::SceneNotFound(old_id.to_string()));
+        }
+        if ids.iter().any(|id| id == new_id) {
+            return Err(ScenarioError::InvalidSceneId(format!(
+                "'{}' is already a scene",
+                new_id
+            )));
+        }
+
+        if let Some(scenes) = self.scenes_mut() {
+            for scene in scenes.iter_mut() {
+                replace_str(scene, "id", old_id, new_id);
+            }
+        }
+        Ok(self.rename_scene_references(old_id, new_id))
+    }
+
+    /// Update the references to a scene, e.g. one defined in another file
+    ///
+    /// Updates `JumpToScene` targets, `Call` scenes and return scenes, choice
+    /// options' `next_scene`, including those inside `If` blocks and the
+    /// scene-level `choices` list. Returns the number of references updated.
+    pub fn rename_scene_references(&mut self, old_id: &str, new_id: &str) -> usize {
+        let Some(scenes) = self.scenes_mut() else {
+            return 0;
+        };
+        let mut renamed = 0;
+        for scene in scenes.iter_mut() {
+            if let Some(choices) = scene.get_mut("choices") {
+                renamed += rename_choice_targets(choices, old_id, new_id);
+            }
+            if let Some(commands) = scene.get_mut("commands") {
+                renamed += rename_command_targets(commands, old_id, new_id);
+            }
+        }
+        renamed
+    }
+
+    /// Update `JumpToScenario` commands continuing at a scene of another file
+    ///
+    /// Returns the number of commands updated.
+    pub fn rename_scenario_jump_targets(&mut self, old_id: &str, new_id: &str) -> usize {
+        let mut renamed = 0;
+        self.visit_tables(&mut |table| {
+            if table.get("type").and_then(Item::as_str) == Some("JumpToScenario") {
+                renamed += usize::from(replace_str(table, "scene", old_id, new_id));
+            }
+        });
+        renamed
+    }
+
+    /// Rename a flag wherever this file sets or reads it
+    ///
+    /// Updates `SetFlag` commands, flag conditions, choice options'
+    /// `flags_to_set` and `flags.` names in expressions. Returns the number
+    /// of values updated.
+    pub fn rename_flag(&mut self, old: &str, new: &str) -> usize {
+        let mut renamed = 0;
+        self.visit_tables(&mut |table| {
+            renamed += usize::from(replace_str(table, "flag_name", old, new));
+            if let Some(Item::Value(Value::Array(flags))) = table.get_mut("flags_to_set") {
+                for flag in flags.iter_mut() {
+                    renamed += usize::from(replace_value(flag, |name| {
+                        (name == old).then(|| new.to_string())
+                    }));
+                }
+            }
+            renamed += rename_expressions(table, &|expression| expression.rename_flag(old, new));
+        });
+        renamed
+    }
+
+    /// Rename a variable wherever this file sets, reads or shows it
+    ///
+    /// Updates `SetVariable`, `ModifyVariable` and `InputName` targets,
+    /// variable conditions, `Call` arguments and results, names in
+    /// expressions and `{$name}` placeholders in text and translations.
+    /// Returns the number of values updated.
+    pub fn rename_variable(&mut self, old: &str, new: &str) -> usize {
+        let old_placeholder = format!("{{${}}}", old);
+        let new_placeholder = format!("{{${}}}", new);
+        let mut renamed = 0;
+        self.visit_tables(&mut |table| {
+            renamed += usize::from(replace_str(table, "variable_name", old, new));
+            match table.get("type").and_then(Item::as_str) {
+                Some("InputName") => {
+                    renamed += usize::from(replace_str(table, "variable", old, new));
+                }
+                Some("Call") => {
+                    renamed += usize::from(replace_str(table, "result", old, new));
+                    if let Some(args) = table.get_mut("args").and_then(Item::as_table_like_mut) {
+                        renamed += usize::from(rename_key(args, old, new));
+                    }
+                }
+                _ => {}
+            }
+            renamed +=
+                rename_expressions(table, &|expression| expression.rename_variable(old, new));
+
+            let mut texts: Vec<&mut Value> = Vec::new();
+            for (key, item) in table.iter_mut() {
+                match (key.get(), item) {
+                    ("text", Item::Value(text)) => texts.push(text),
+                    ("translations", item) => {
+                        if let Some(translations) = item.as_table_like_mut() {
+                            texts.extend(
+                                translations
+                                    .iter_mut()
+                                    .filter_map(|(_, item)| item.as_value_mut()),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            for text in texts {
+                renamed += usize::from(replace_value(text, |text| {
+                    text.contains(&old_placeholder)
+                        .then(|| text.replace(&old_placeholder, &new_placeholder))
+                }));
+            }
+        });
+        renamed
+    }
+
+    /// Rename a character wherever this file defines, shows or voices it
+    ///
+    /// Updates the file's `[[characters]]`, character commands and dialogue
+    /// speakers. Returns the number of values updated.
+    pub fn rename_character(&mut self, old_id: &str, new_id: &str) -> usize {
+        let mut renamed = 0;
+        if let Some(characters) = self.document.get_mut("characters") {
+            for_each_table(characters, &mut |character| {
+                renamed += usize::from(replace_str(character, "id", old_id, new_id));
+            });
+        }
+        self.visit_tables(&mut |table| {
+            // Speakers are written `speaker = "alice"` or
+            // `speaker = { Character = "alice" }`
+            for key in ["character_id", "speaker", "Character"] {
+                renamed += usize::from(replace_str(table, key, old_id, new_id));
+            }
+        });
+        renamed
+    }
+
+    /// Insert a command into a scene's `commands` list
+    ///
+    /// `index` counts the commands listed in the file, with the new command
+    /// placed before the command at that index (or last, if it equals the
+    /// number of commands). Nested tables of the command are written inline,
+    /// except lists of tables such as choice options.
+    ///
+    /// # Errors
+    /// Returns an error if the scene is not in this file, the index is past
+    /// the end of its commands, or the command cannot be serialized.
+    pub fn insert_command(
+        &mut self,
+        scene_id: &str,
+        index: usize,
+        command: &ScenarioCommand,
+    ) -> ScenarioResult<()> {
+        let mut table = command_table(command)?;
+        let scene = self
+            .scenes_mut()
+            .and_then(|scenes| {
+                scenes
+                    .iter_mut()
+                    .find(|scene| scene.get("id").and_then(Item::as_str) == Some(scene_id))
+            })
+            .ok_or_else(|| ScenarioError::SceneNotFound(scene_id.to_string()))?;
+
+        match scene.get_mut("commands") {
+            None => {
+                if index > 0 {
+                    return Err(ScenarioError::InvalidCommandIndex(index, 0));
+                }
+                table.decor_mut().set_prefix("\n");
+                let mut commands = ArrayOfTables::new();
+                commands.push(table);
+                scene.insert("commands", Item::ArrayOfTables(commands));
+            }
+            Some(Item::ArrayOfTables(commands)) => {
+                if index > commands.len() {
+                    return Err(ScenarioError::InvalidCommandIndex(index, commands.len()));
+                }
+                table.decor_mut().set_prefix("\n");
+                let mut tables: Vec<Table> = std::mem::take(commands).into_iter().collect();
+                tables.insert(index, table);
+                *commands = tables.into_iter().collect();
+            }
+            Some(Item::Value(Value::Array(commands))) => {
+                if index > commands.len() {
+                    return Err(ScenarioError::InvalidCommandIndex(index, commands.len()));
+                }
+                commands.insert(index, table.into_inline_table());
+            }
+            Some(_) => {
+                return Err(ScenarioError::InvalidFieldValue(
+                    format!("scenes.{}.commands", scene_id),
+                    "expected a list of commands".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Call `f` with every table of the document, inline or not
+    fn visit_tables(&mut self, f: &mut dyn FnMut(&mut dyn TableLike)) {
+        for (_, item) in self.document.as_table_mut().iter_mut() {
+            visit_tables(item, f);
+        }
+    }
+
+    fn scenes(&self) -> Option<&ArrayOfTables> {
+        self.document.get("scenes")?.as_array_of_tables()
+    }
+
+    fn scenes_mut(&mut self) -> Option<&mut ArrayOfTables> {
+        self.document.get_mut("scenes")?.as_array_of_tables_mut()
+    }
+}
+
+impl fmt::Display for ScenarioDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.document.fmt(f)
+    }
+}
+
+/// Call `f` with each table of a list of tables, inline or not
+fn for_each_table(item: &mut Item, f: &mut dyn FnMut(&mut dyn TableLike)) {
+    match item {
+        Item::ArrayOfTables(tables) => {
+            for table in tables.iter_mut() {
+                f(table);
+            }
+        }
+        Item::Value(Value::Array(values)) => {
+            for value in values.iter_mut() {
+                if let Some(table) = value.as_inline_table_mut() {
+                    f(table);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Call `f` with every table in an item, parents before their children
+fn visit_tables(item: &mut Item, f: &mut dyn FnMut(&mut dyn TableLike)) {
+    match item {
+        Item::Table(table) => visit_table(table, f),
+        Item::ArrayOfTables(tables) => {
+            for table in tables.iter_mut() {
+                visit_table(table, f);
+            }
+        }
+        Item::Value(value) => visit_value_tables(value, f),
+        Item::None => {}
+    }
+}
+
+fn visit_table(table: &mut dyn TableLike, f: &mut dyn FnMut(&mut dyn TableLike)) {
+    f(table);
+    for (_, item) in table.iter_mut() {
+        visit_tables(item, f);
+    }
+}
+
+fn visit_value_tables(value: &mut Value, f: &mut dyn FnMut(&mut dyn TableLike)) {
+    match value {
+        Value::InlineTable(table) => visit_table(table, f),
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                visit_value_tables(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rewrite the expressions of a command, condition or choice option
+///
+/// Expressions are the string forms of conditions and variable operations,
+/// `Expression` conditions and operations, `Call` arguments and `Return`
+/// values.
+fn rename_expressions(
+    table: &mut dyn TableLike,
+    rename: &dyn Fn(&Expression) -> Option<String>,
+) -> usize {
+    let rename_source = |source: &str| {
+        Expression::parse(source)
+            .ok()
+            .and_then(|expression| rename(&expression))
+    };
+    let kind = table.get("type").and_then(Item::as_str).map(str::to_string);
+    let mut renamed = 0;
+    for (key, item) in table.iter_mut() {
+        let Item::Value(value) = item else {
+            if key.get() == "args"
+                && kind.as_deref() == Some("Call")
+                && let Some(args) = item.as_table_like_mut()
+            {
+                for (_, arg) in args.iter_mut() {
+                    if let Some(arg) = arg.as_value_mut() {
+                        renamed += usize::from(replace_value(arg, rename_source));
+                    }
+                }
+            }
+            continue;
+        };
+        match key.get() {
+            "condition" | "disabled_condition" | "operation" | "expression" => {
+                renamed += usize::from(replace_value(value, rename_source));
+            }
+            "value" if kind.as_deref() == Some("Return") => {
+                renamed += usize::from(replace_value(value, rename_source));
+            }
+            "conditions" => {
+                if let Some(conditions) = value.as_array_mut() {
+                    for condition in conditions.iter_mut() {
+                        renamed += usize::from(replace_value(condition, rename_source));
+                    }
+                }
+            }
+            "args" if kind.as_deref() == Some("Call") => {
+                if let Some(args) = value.as_inline_table_mut() {
+                    for (_, arg) in args.iter_mut() {
+                        renamed += usize::from(replace_value(arg, rename_source));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    renamed
+}
+
+/// Rename a key of a table, keeping the order of its entries
+fn rename_key(table: &mut dyn TableLike, old: &str, new: &str) -> bool {
+    if !table.contains_key(old) || table.contains_key(new) {
+        return false;
+    }
+    let entries: Vec<(String, Item)> = table
+        .iter()
+        .map(|(key, item)| (key.to_string(), item.clone()))
+        .collect();
+    table.clear();
+    for (key, item) in entries {
+        table.insert(if key == old { new } else { &key }, item);
+    }
+    true
+}
+
+/// Rename the scene targets of a list of commands, recursing into `If` blocks
+fn rename_command_targets(commands: &mut Item, old_id: &str, new_id: &str) -> usize {
+    let mut renamed = 0;
+    for_each_table(
+        commands,
+        &mut |command| match command.get("type").and_then(Item::as_str) {
+            Some("JumpToScene") => {
+                renamed += usize::from(replace_str(command, "scene_id", old_id, new_id));
+            }
+            Some("Call") => {
+                renamed += usize::from(replace_str(command, "scene_id", old_id, new_id));
+                renamed += usize::from(replace_str(command, "return_scene", old_id, new_id));
+            }
+            Some("ShowChoice") => {
+                if let Some(options) = command
+                    .get_mut("choice")
+                    .and_then(Item::as_table_like_mut)
+                    .and_then(|choice| choice.get_mut("options"))
+                {
+                    renamed += rename_choice_targets(options, old_id, new_id);
+                }
+            }
+            Some("If") => {
+                for key in ["then_commands", "else_commands"] {
+                    if let Some(block) = command.get_mut(key) {
+                        renamed += rename_command_targets(block, old_id, new_id);
+                    }
+                }
+            }
+            _ => {}
+        },
+    );
+    renamed
+}
+
+/// Rename the `next_scene` of a list of choice options
+fn rename_choice_targets(options: &mut Item, old_id: &str, new_id: &str) -> usize {
+    let mut renamed = 0;
+    for_each_table(options, &mut |option| {
+        renamed += usize::from(replace_str(option, "next_scene", old_id, new_id));
+    });
+    renamed
+}
+
+/// Replace a string value equal to `old`, keeping its comments and spacing
+fn replace_str(table: &mut dyn TableLike, key: &str, old: &str, new: &str) -> bool {
+    let Some(Item::Value(value)) = table.get_mut(key) else {
+        return false;
+    };
+    replace_value(value, |value| (value == old).then(|| new.to_string()))
+}
+
+/// Replace a string value with the result of `f`, keeping its comments and
+/// spacing; returns false if the value is not a string or `f` returns `None`
+fn replace_value(value: &mut Value, f: impl FnOnce(&str) -> Option<String>) -> bool {
+    let Some(replaced) = value.as_str().and_then(f) else {
+        return false;
+    };
+    let decor = value.decor().clone();
+    *value = Value::from(replaced);
+    *value.decor_mut() = decor;
+    true
+}
+
+/// A command as a table of a `[[scenes.commands]]` list
+fn command_table(command: &ScenarioCommand) -> ScenarioResult<Table> {
+    let text = toml::to_string(command)
+        .map_err(|e| ScenarioError::Other(format!("Cannot serialize command: {}", e)))?;
+    let mut table = text
+        .parse::<DocumentMut>()
+        .map_err(|e| ScenarioError::Other(format!("Cannot serialize command: {}", e)))?
+        .as_table()
+        .clone();
+    inline_nested_tables(&mut table);
+    table.set_implicit(false);
+    Ok(table)
+}
+
+/// Turn nested tables into inline tables, keeping lists of tables as they are
+///
+/// Tables that contain a list of tables stay tables, with their header left
+/// out when they have no values of their own. The positions the tables had
+/// in the serialized command are dropped so they are written after the
+/// command's header wherever it is inserted.
+fn inline_nested_tables(table: &mut Table) {
+    table.set_position(None);
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Table(nested) => {
+                inline_nested_tables(nested);
+                if nested
+                    .iter()
+                    .any(|(_, item)| item.is_array_of_tables() || item.is_table())
+                {
+                    nested.set_implicit(true);
+                } else {
+                    let inline = std::mem::take(nested).into_inline_table();
+                    *item = Item::Value(Value::InlineTable(inline));
+                }
+            }
+            Item::ArrayOfTables(tables) => {
+                for nested in tables.iter_mut() {
+                    inline_nested_tables(nested);
+                    nested.decor_mut().set_prefix("\n");
+                }
+            }
+            _ => {}
+        }
+    }
+    table.fmt();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::{Choice, ChoiceOption, Dialogue};
+
+    const SCENARIO: &str = r#"# Chapter 1 of the demo
+[chapter]
+id = "chapter_01"
+title = "Chapter 1"
+
+[[scenes]]
+id = "park"
+title = "Park"
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = { speaker = "Narrator", text = "Where to?" }  # first line
+
+[[scenes.commands]]
+type = "ShowChoice"
+
+[[scenes.commands.choice.options]]
+text = "The station"
+next_scene = "station"   # aligned comment
+
+[[scenes.commands.choice.options]]
+text = "Stay"
+next_scene = "park"
+
+[[scenes.commands]]
+type = "If"
+condition = { type = "Flag", flag_name = "late", expected = true }
+then_commands = [
+    { type = "Call", scene_id = "station", return_scene = "park" },
+]
+
+[[scenes]]
+id = "station"
+title = "Station"
+choices = [{ text = "Back", next_scene = "park" }]
+
+[[scenes.commands]]
+type = "JumpToScene"
+scene_id = "station"
+"#;
+
+    #[test]
+    fn test_rename_scene_updates_references() {
+        let mut document = ScenarioDocument::parse(SCENARIO).unwrap();
+        assert_eq!(document.rename_scene("station", "platform").unwrap(), 3);
+
+        let text = document.to_string();
+        assert_eq!(
+            text,
+            SCENARIO
+                .replace("next_scene = \"station\"", "next_scene = \"platform\"")
+                .replace("scene_id = \"station\"", "scene_id = \"platform\"")
+                .replace("id = \"station\"", "id = \"platform\"")
+        );
+        assert_eq!(document.scene_ids(), vec!["park", "platform"]);
+        assert!(text.contains("next_scene = \"platform\"   # aligned comment"));
+
+        assert!(matches!(
+            document.rename_scene("missing", "other"),
+            Err(ScenarioError::SceneNotFound(_))
+        ));
+        assert!(matches!(
+            document.rename_scene("park", "platform"),
+            Err(ScenarioError::InvalidSceneId(_))
+        ));
+    }
+
+    #[test]
+    fn test_rename_scene_references_only() {
+        let mut document = ScenarioDocument::parse(SCENARIO).unwrap();
+        assert_eq!(document.rename_scene_references("park", "home"), 3);
+        assert_eq!(document.scene_ids(), vec!["park", "station"]);
+    }
+
+    #[test]
+    fn test_insert_command_keeps_formatting() {
+        let mut document = ScenarioDocument::parse(SCENARIO).unwrap();
+        let command = ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator("It is getting late."),
+        };
+        document.insert_command("park", 1, &command).unwrap();
+
+        let text = document.to_string();
+        let inserted = "\n[[scenes.commands]]\ntype = \"Dialogue\"\n\
+                        dialogue = { speaker = \"Narrator\", text = \"It is getting late.\" }\n";
+        let first_line = "# first line\n";
+        let at = SCENARIO.find(first_line).unwrap() + first_line.len();
+        assert_eq!(
+            text,
+            format!("{}{}{}", &SCENARIO[..at], inserted, &SCENARIO[at..])
+        );
+
+        // The edited file still parses to the same commands plus the new one
+        let parsed: toml::Value = toml::from_str(&text).unwrap();
+        let commands = parsed["scenes"][0]["commands"].as_array().unwrap();
+        assert_eq!(commands.len(), 4);
+        assert_eq!(
+            commands[1]["dialogue"]["text"].as_str(),
+            Some("It is getting late.")
+        );
+        assert_eq!(commands[2]["type"].as_str(), Some("ShowChoice"));
+
+        assert!(matches!(
+            document.insert_command("park", 9, &command),
+            Err(ScenarioError::InvalidCommandIndex(9, 4))
+        ));
+        assert!(matches!(
+            document.insert_command("missing", 0, &command),
+            Err(ScenarioError::SceneNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_insert_choice_command() {
+        let mut document = ScenarioDocument::parse(SCENARIO).unwrap();
+        let command = ScenarioCommand::ShowChoice {
+            choice: Choice::new(vec![
+                ChoiceOption::new("Left", "park"),
+                ChoiceOption::new("Right", "station"),
+            ]),
+        };
+        document.insert_command("station", 1, &command).unwrap();
+
+        let text = document.to_string();
+        assert!(text.ends_with(
+            "scene_id = \"station\"\n\n[[scenes.commands]]\ntype = \"ShowChoice\"\n\n\
+             [[scenes.commands.choice.options]]\ntext = \"Left\"\nnext_scene = \"park\"\n\n\
+             [[scenes.commands.choice.options]]\ntext = \"Right\"\nnext_scene = \"station\"\n"
+        ));
+        let parsed: toml::Value = toml::from_str(&text).unwrap();
+        let commands = parsed["scenes"][1]["commands"].as_array().unwrap();
+        assert_eq!(
+            commands[1]["choice"]["options"][1]["next_scene"].as_str(),
+            Some("station")
+        );
+    }
+
+    const STATE: &str = r#"[[characters]]
+id = "alice"
+name = "Alice"
+
+[[scenes]]
+id = "shop"
+title = "Shop"
+
+[[scenes.commands]]
+type = "ShowCharacter"
+character_id = "alice"
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = { speaker = { Character = "alice" }, text = "{$gold} left", translations = { ja = "残り{$gold}" } }
+
+[[scenes.commands]]
+type = "ModifyVariable"
+variable_name = "gold"
+operation = "gold - price"  # pay
+
+[[scenes.commands]]
+type = "If"
+condition = "gold >= 5 && !flags.broke"
+then_commands = [{ type = "SetFlag", flag_name = "broke", value = false }]
+
+[[scenes.commands]]
+type = "Call"
+scene_id = "haggle"
+return_scene = "shop"
+args = { gold = "gold", mood = "flags.broke" }
+result = "gold"
+
+[[scenes.commands]]
+type = "ShowChoice"
+
+[[scenes.commands.choice.options]]
+text = "Leave"
+next_scene = "street"
+conditions = [{ type = "Variable", variable_name = "gold", op = "LessThan", value = 1 }]
+flags_to_set = ["broke"]
+"#;
+
+    #[test]
+    fn test_rename_flag_and_variable() {
+        let mut document = ScenarioDocument::parse(STATE).unwrap();
+        assert_eq!(document.rename_flag("broke", "poor"), 4);
+        assert_eq!(document.rename_variable("gold", "coins"), 9);
+        assert_eq!(document.rename_variable("gold", "coins"), 0);
+
+        let text = document.to_string();
+        assert!(!text.contains("gold") && !text.contains("broke"));
+        assert!(text.contains("operation = \"coins - price\"  # pay"));
+        assert!(text.contains("args = { coins = \"coins\", mood = \"flags.poor\" }"));
+        assert!(text.contains("translations = { ja = \"残り{$coins}\" }"));
+        toml::from_str::<toml::Value>(&text).unwrap();
+    }
+
+    #[test]
+    fn test_rename_character() {
+        let mut document = ScenarioDocument::parse(STATE).unwrap();
+        assert_eq!(document.rename_character("alice", "sakura"), 3);
+        assert_eq!(document.to_string(), STATE.replace("alice", "sakura"));
+    }
+}
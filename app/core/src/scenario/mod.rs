@@ -1,7 +1,11 @@
 pub mod choice;
 pub mod dialogue;
+pub mod document;
 pub mod types;
+pub mod voice;
 
 pub use choice::*;
 pub use dialogue::*;
+pub use document::*;
 pub use types::*;
+pub use voice::*;
@@ -1,8 +1,8 @@
 use super::{Choice, Dialogue};
 use crate::character::{CharacterDef, CharacterPosition, Expression};
-use crate::types::{AssetRef, Transition};
+use crate::types::{AssetRef, Color, Transition};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Complete scenario containing all scenes and metadata
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -65,6 +65,9 @@ pub struct ScenarioMetadata {
     /// Version string
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
+    /// What happens when the scenario reaches `End`
+    #[serde(default)]
+    pub on_end: EndBehavior,
 }
 
 impl ScenarioMetadata {
@@ -76,8 +79,54 @@ impl ScenarioMetadata {
             description: None,
             author: None,
             version: None,
+            on_end: EndBehavior::default(),
         }
     }
+
+    /// Set the end-of-content behavior
+    pub fn with_on_end(mut self, on_end: EndBehavior) -> Self {
+        self.on_end = on_end;
+        self
+    }
+}
+
+/// Behavior when a scenario reaches `End`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EndBehavior {
+    /// Return to the title screen
+    #[default]
+    ReturnToTitle,
+    /// Roll credits, then return to the title screen
+    Credits {
+        /// Credit lines, scrolled from bottom to top
+        #[serde(default)]
+        lines: Vec<String>,
+        /// Scroll duration in seconds
+        #[serde(default = "default_credits_duration")]
+        duration: f32,
+    },
+    /// Continue with the next scenario on the same route of the project manifest
+    Continue,
+    /// Continue with another scenario file
+    NextScenario {
+        /// Path to the scenario file
+        path: String,
+    },
+    /// Show an ending card and record the ending as reached
+    EndingCard {
+        /// Ending ID recorded in unlock data
+        ending_id: String,
+        /// Title shown on the card
+        title: String,
+    },
+}
+
+impl EndBehavior {
+    /// Whether the game goes straight back to the title screen
+    pub fn returns_to_title(&self) -> bool {
+        matches!(self, Self::ReturnToTitle)
+    }
 }
 
 /// A scene contains a sequence of commands
@@ -95,6 +144,9 @@ pub struct Scene {
     /// Optional exit transition when leaving this scene
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exit_transition: Option<Transition>,
+    /// Free-form metadata for plugins and custom commands (ignored by the engine)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tags: HashMap<String, String>,
 }
 
 impl Scene {
@@ -106,6 +158,7 @@ impl Scene {
             commands: Vec::new(),
             entry_transition: None,
             exit_transition: None,
+            tags: HashMap::new(),
         }
     }
 
@@ -121,6 +174,17 @@ impl Scene {
         self
     }
 
+    /// Add a metadata tag
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get a metadata tag
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+
     /// Add a command to this scene
     pub fn add_command(&mut self, command: ScenarioCommand) {
         self.commands.push(command);
@@ -166,12 +230,20 @@ pub enum ScenarioCommand {
     },
 
     /// Show a character
+    ///
+    /// Characters defined with a layered sprite are composited from the
+    /// expression (or their default expression) and outfit, and need no
+    /// `sprite`.
     ShowCharacter {
         character_id: String,
+        #[serde(default)]
         sprite: AssetRef,
         position: CharacterPosition,
         #[serde(skip_serializing_if = "Option::is_none")]
         expression: Option<Expression>,
+        /// Outfit of a layered sprite (None for the default outfit)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        outfit: Option<String>,
         #[serde(default)]
         transition: Transition,
     },
@@ -191,6 +263,9 @@ pub enum ScenarioCommand {
     },
 
     /// Change character expression
+    ///
+    /// Swaps the sprite for the character's image of the expression, keeping
+    /// the outfit of a layered sprite.
     ChangeExpression {
         character_id: String,
         expression: Expression,
@@ -231,12 +306,44 @@ pub enum ScenarioCommand {
         volume: f32,
     },
 
+    /// Play a video cutscene (opening, event movie)
+    ///
+    /// The scenario waits until the video ends. Skippable videos end early on
+    /// click or a confirm key.
+    PlayVideo {
+        path: AssetRef,
+        #[serde(default = "default_skippable")]
+        skippable: bool,
+    },
+
     /// Present choices to the player
     ShowChoice { choice: Choice },
 
     /// Jump to another scene
     JumpToScene { scene_id: String },
 
+    /// Mark a position in the scene that `JumpToLabel` can continue at
+    ///
+    /// Labels are local to their scene and do nothing when reached. Only
+    /// labels at the top level of a scene count, not those in `If` blocks.
+    Label { name: String },
+
+    /// Continue at a label of the current scene
+    ///
+    /// Execution resumes after the `Label` command. Allowed in `If` blocks,
+    /// where it skips the rest of the block.
+    JumpToLabel { label: String },
+
+    /// Jump to another scenario of the project
+    ///
+    /// `id` refers to a scenario in the project manifest. Execution starts at
+    /// `scene`, or at the scenario's first scene if not set.
+    JumpToScenario {
+        id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scene: Option<String>,
+    },
+
     /// Set a flag
     SetFlag { flag_name: String, value: bool },
 
@@ -255,21 +362,64 @@ pub enum ScenarioCommand {
     /// Wait for a duration (in seconds)
     Wait { duration: f32 },
 
+    /// Ask the player for a name and store it in a string variable
+    ///
+    /// The scenario waits until the name is confirmed. Dialogue shows the
+    /// name where it has `{$variable}`. An empty entry stores `default`.
+    InputName {
+        variable: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        prompt: Option<String>,
+        #[serde(default)]
+        default: String,
+        #[serde(default = "default_name_max_length")]
+        max_length: usize,
+    },
+
+    /// Unlock an achievement from the achievement manifest
+    ///
+    /// Unlocked achievements are shared by all save slots. Unlocking one
+    /// again has no effect.
+    UnlockAchievement { id: String },
+
+    /// Show or hide HUD widgets from the HUD manifest
+    ///
+    /// Lists widget IDs to toggle, or toggles the whole HUD when `widgets`
+    /// is empty. The HUD's visibility is saved with the game.
+    SetHudVisible {
+        visible: bool,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        widgets: Vec<String>,
+    },
+
     /// Call a scene as a subroutine
     ///
     /// Pushes current scene and next command index to scene_stack,
     /// then jumps to the target scene. When Return is encountered,
     /// execution resumes from return_scene at the next command.
+    ///
+    /// `args` are expressions evaluated before the jump; each is stored in
+    /// the variable it is named after for the duration of the call, and the
+    /// variable's previous value is restored on Return. The value given to
+    /// Return is stored in the caller's `result` variable.
     Call {
         scene_id: String,
         return_scene: String,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        args: BTreeMap<String, crate::expression::Expression>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        result: Option<String>,
     },
 
     /// Return from a subroutine call
     ///
     /// Pops the previous scene and command index from scene_stack
     /// and returns to the saved return_scene. Returns error if stack is empty.
-    Return,
+    /// `value` is evaluated before the call's arguments are restored.
+    Return {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<crate::expression::Expression>,
+    },
 
     /// Conditional branching
     ///
@@ -282,10 +432,74 @@ pub enum ScenarioCommand {
         else_commands: Vec<ScenarioCommand>,
     },
 
+    /// Start an ongoing screen effect such as rain or snow
+    ///
+    /// `effect` names a particle effect from the effect manifest. Showing an
+    /// effect that is already running updates its intensity and lifetime.
+    /// Scene-scoped effects end when the scene changes; persistent ones last
+    /// across scene jumps until `ClearEffects`. With a `duration` (seconds)
+    /// the effect also stops on its own once that time has passed.
+    ShowEffect {
+        effect: String,
+        #[serde(default = "default_intensity")]
+        intensity: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        duration: Option<f32>,
+        #[serde(default)]
+        lifetime: EffectLifetime,
+    },
+
+    /// Stop ongoing screen effects
+    ///
+    /// Stops only `effect` if set, otherwise every running effect.
+    ClearEffects {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effect: Option<String>,
+    },
+
+    /// Shake the screen
+    ///
+    /// Shakes everything drawn for `duration` seconds, dying down towards the
+    /// end. `intensity` ranges from 0.0 to 1.0 (a violent shake); comfort mode
+    /// caps it. Playback waits for the shake to finish.
+    ScreenShake {
+        #[serde(default = "default_shake_intensity")]
+        intensity: f32,
+        #[serde(default = "default_impact_duration")]
+        duration: f32,
+    },
+
+    /// Flash the screen with a color
+    ///
+    /// Covers the screen with `color`, fading out over `duration` seconds.
+    /// Playback waits for the flash to finish.
+    Flash {
+        #[serde(default)]
+        color: Color,
+        #[serde(default = "default_impact_duration")]
+        duration: f32,
+    },
+
+    /// Run a script
+    ///
+    /// `source` is Rhai code that can read and change flags and variables and
+    /// jump to another scene. Requires the engine's `scripting` feature.
+    Script { source: String },
+
     /// End the scenario
     End,
 }
 
+/// How long an effect started by `ShowEffect` lasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EffectLifetime {
+    /// Ends when the scene changes
+    #[default]
+    Scene,
+    /// Lasts across scene jumps until `ClearEffects`
+    Persistent,
+}
+
 /// Variable value types
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -305,6 +519,31 @@ fn default_volume() -> f32 {
     1.0
 }
 
+fn default_intensity() -> f32 {
+    1.0
+}
+
+fn default_shake_intensity() -> f32 {
+    0.5
+}
+
+fn default_impact_duration() -> f32 {
+    0.5
+}
+
+fn default_skippable() -> bool {
+    true
+}
+
+fn default_name_max_length() -> usize {
+    12
+}
+
+// Helper function for default credits duration
+fn default_credits_duration() -> f32 {
+    30.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,6 +557,53 @@ mod tests {
         assert_eq!(metadata.description, None);
         assert_eq!(metadata.author, None);
         assert_eq!(metadata.version, None);
+        assert_eq!(metadata.on_end, EndBehavior::ReturnToTitle);
+    }
+
+    #[test]
+    fn test_end_behavior_deserialize() {
+        let metadata: ScenarioMetadata = toml::from_str(
+            r#"
+            id = "chapter_01"
+            title = "Chapter 1"
+
+            [on_end]
+            type = "NextScenario"
+            path = "assets/scenarios/chapter_02.toml"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            metadata.on_end,
+            EndBehavior::NextScenario {
+                path: "assets/scenarios/chapter_02.toml".to_string()
+            }
+        );
+
+        let credits: EndBehavior = toml::from_str(
+            r#"
+            type = "Credits"
+            lines = ["Story", "Alice"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            credits,
+            EndBehavior::Credits {
+                lines: vec!["Story".to_string(), "Alice".to_string()],
+                duration: 30.0,
+            }
+        );
+        assert!(!credits.returns_to_title());
+
+        let metadata: ScenarioMetadata = toml::from_str(
+            r#"
+            id = "chapter_01"
+            title = "Chapter 1"
+            "#,
+        )
+        .unwrap();
+        assert!(metadata.on_end.returns_to_title());
     }
 
     #[test]
@@ -455,6 +741,7 @@ mod tests {
             sprite: "sprites/alice.png".into(),
             position: CharacterPosition::Center,
             expression: Some(Expression::Happy),
+            outfit: None,
             transition: Transition::instant(),
         };
 
@@ -556,6 +843,254 @@ mod tests {
         assert!(matches!(cmd, ScenarioCommand::End));
     }
 
+    #[test]
+    fn test_effect_commands_toml() {
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "ShowEffect"
+effect = "rain"
+lifetime = "Persistent"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::ShowEffect {
+                effect: "rain".to_string(),
+                intensity: 1.0,
+                duration: None,
+                lifetime: EffectLifetime::Persistent,
+            }
+        );
+
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "ShowEffect"
+effect = "sparks"
+intensity = 0.5
+duration = 3.0
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::ShowEffect {
+                effect: "sparks".to_string(),
+                intensity: 0.5,
+                duration: Some(3.0),
+                lifetime: EffectLifetime::Scene,
+            }
+        );
+
+        let cmd: ScenarioCommand = toml::from_str(r#"type = "ClearEffects""#).unwrap();
+        assert_eq!(cmd, ScenarioCommand::ClearEffects { effect: None });
+    }
+
+    #[test]
+    fn test_impact_commands_toml() {
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "ScreenShake"
+intensity = 0.8
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::ScreenShake {
+                intensity: 0.8,
+                duration: 0.5,
+            }
+        );
+
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "Flash"
+color = { r = 1.0, g = 0.0, b = 0.0, a = 0.8 }
+duration = 0.2
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::Flash {
+                color: Color::new(1.0, 0.0, 0.0, 0.8),
+                duration: 0.2,
+            }
+        );
+
+        let cmd: ScenarioCommand = toml::from_str(r#"type = "Flash""#).unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::Flash {
+                color: Color::WHITE,
+                duration: 0.5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_play_video_command_toml() {
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "PlayVideo"
+path = "movies/opening.y4m"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::PlayVideo {
+                path: "movies/opening.y4m".into(),
+                skippable: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_input_name_command_toml() {
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "InputName"
+variable = "player_name"
+default = "Alex"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::InputName {
+                variable: "player_name".to_string(),
+                prompt: None,
+                default: "Alex".to_string(),
+                max_length: 12,
+            }
+        );
+    }
+
+    #[test]
+    fn test_call_with_args_toml() {
+        let call: ScenarioCommand = toml::from_str(
+            r#"
+type = "Call"
+scene_id = "shop"
+return_scene = "town"
+args = { item = "'potion'", price = "base_price * 2" }
+result = "bought"
+"#,
+        )
+        .unwrap();
+        let ScenarioCommand::Call { args, result, .. } = call else {
+            panic!("Expected Call command");
+        };
+        assert_eq!(args.len(), 2);
+        assert_eq!(args["price"].source(), "base_price * 2");
+        assert_eq!(result.as_deref(), Some("bought"));
+
+        let plain: ScenarioCommand = toml::from_str("type = \"Return\"").unwrap();
+        assert_eq!(plain, ScenarioCommand::Return { value: None });
+        let with_value: ScenarioCommand =
+            toml::from_str("type = \"Return\"\nvalue = \"gold > 0\"").unwrap();
+        assert!(matches!(
+            with_value,
+            ScenarioCommand::Return { value: Some(_) }
+        ));
+    }
+
+    #[test]
+    fn test_unlock_achievement_toml() {
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "UnlockAchievement"
+id = "first_steps"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::UnlockAchievement {
+                id: "first_steps".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_hud_visible_toml() {
+        let cmd: ScenarioCommand = toml::from_str(
+            r#"
+type = "SetHudVisible"
+visible = true
+widgets = ["sakura_affection"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            ScenarioCommand::SetHudVisible {
+                visible: true,
+                widgets: vec!["sakura_affection".to_string()],
+            }
+        );
+
+        let cmd: ScenarioCommand =
+            toml::from_str("type = \"SetHudVisible\"\nvisible = false").unwrap();
+        assert!(matches!(
+            cmd,
+            ScenarioCommand::SetHudVisible { visible: false, widgets } if widgets.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_label_commands_toml() {
+        let label: ScenarioCommand = toml::from_str(
+            r#"
+type = "Label"
+name = "after_fight"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            label,
+            ScenarioCommand::Label {
+                name: "after_fight".to_string(),
+            }
+        );
+
+        let jump: ScenarioCommand = toml::from_str(
+            r#"
+type = "JumpToLabel"
+label = "after_fight"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            jump,
+            ScenarioCommand::JumpToLabel {
+                label: "after_fight".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_script_command_toml() {
+        let toml_str = r#"
+type = "Script"
+source = """
+if game.get_var("gold") >= 100 {
+    game.jump("shop")
+}
+"""
+"#;
+        let cmd: ScenarioCommand = toml::from_str(toml_str).unwrap();
+
+        match cmd {
+            ScenarioCommand::Script { source } => {
+                assert!(source.contains("game.jump(\"shop\")"));
+            }
+            _ => panic!("Expected Script command"),
+        }
+    }
+
     #[test]
     fn test_variable_value_variants() {
         let bool_val = VariableValue::Bool(true);
@@ -587,6 +1122,21 @@ mod tests {
         assert_eq!(scene, deserialized);
     }
 
+    #[test]
+    fn test_scene_tags() {
+        let scene = Scene::new("test", "Test").with_tag("chapter_marker", "act_2");
+        assert_eq!(scene.tag("chapter_marker"), Some("act_2"));
+        assert_eq!(scene.tag("missing"), None);
+
+        let serialized = serde_json::to_string(&scene).unwrap();
+        let deserialized: Scene = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(scene, deserialized);
+
+        // Scenes without tags don't serialize an empty map
+        let serialized = serde_json::to_string(&Scene::new("test", "Test")).unwrap();
+        assert!(!serialized.contains("tags"));
+    }
+
     #[test]
     fn test_dialogue_command_with_escape_animation_toml() {
         use crate::character::CharacterAnimation;
@@ -0,0 +1,190 @@
+//! Convention-based voice file resolution
+
+use super::{ScenarioCommand, Scene, Speaker};
+
+/// Resolves voice files for dialogue lines by naming convention
+///
+/// A line spoken by character `C` that is the `N`th dialogue line of scene `S`
+/// (counting from 1) plays `{voice_dir}/C/S_N.ogg`. Lines with an explicit
+/// voice (a `PlayVoice` command directly before them) and narrator or system
+/// lines are not resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceResolver {
+    /// Voice directory relative to the asset root, without a trailing `/`
+    voice_dir: String,
+    /// Voice file extension
+    extension: String,
+}
+
+/// Dialogue line with a voice file resolved by convention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalVoice {
+    /// Index of the dialogue command within the scene
+    pub command_index: usize,
+    /// Dialogue line number within the scene (from 1)
+    pub line: usize,
+    /// Speaking character ID
+    pub character: String,
+    /// Voice file path, relative to the asset root
+    pub path: String,
+}
+
+impl VoiceResolver {
+    /// Default voice file extension
+    pub const DEFAULT_EXTENSION: &str = "ogg";
+
+    /// Create a resolver for a voice directory (relative to the asset root)
+    pub fn new(voice_dir: impl Into<String>) -> Self {
+        let voice_dir = voice_dir.into().trim_end_matches('/').to_string();
+        Self {
+            voice_dir,
+            extension: Self::DEFAULT_EXTENSION.to_string(),
+        }
+    }
+
+    /// Set the voice file extension
+    pub fn with_extension(mut self, extension: impl Into<String>) -> Self {
+        self.extension = extension.into().trim_start_matches('.').to_string();
+        self
+    }
+
+    /// Get the voice directory
+    pub fn voice_dir(&self) -> &str {
+        &self.voice_dir
+    }
+
+    /// Voice file path for a character's line in a scene
+    pub fn path_for(&self, character: &str, scene_id: &str, line: usize) -> String {
+        let file = format!("{}/{}_{}.{}", character, scene_id, line, self.extension);
+        if self.voice_dir.is_empty() {
+            file
+        } else {
+            format!("{}/{}", self.voice_dir, file)
+        }
+    }
+
+    /// Resolve the voice file of the dialogue command at `command_index`
+    ///
+    /// Returns `None` if the command is not a character's dialogue line or the
+    /// line has an explicit voice.
+    pub fn resolve(&self, scene: &Scene, command_index: usize) -> Option<ConventionalVoice> {
+        self.conventional_lines(scene)
+            .into_iter()
+            .find(|voice| voice.command_index == command_index)
+    }
+
+    /// Resolve every character line of a scene without an explicit voice
+    pub fn conventional_lines(&self, scene: &Scene) -> Vec<ConventionalVoice> {
+        let mut voices = Vec::new();
+        let mut line = 0usize;
+        let mut explicit_voice = false;
+
+        for (command_index, command) in scene.commands.iter().enumerate() {
+            match command {
+                ScenarioCommand::PlayVoice { .. } => {
+                    explicit_voice = true;
+                    continue;
+                }
+                ScenarioCommand::Dialogue { dialogue } => {
+                    line = line.saturating_add(1);
+                    if let Speaker::Character(character) = &dialogue.speaker
+                        && !explicit_voice
+                    {
+                        voices.push(ConventionalVoice {
+                            command_index,
+                            line,
+                            character: character.clone(),
+                            path: self.path_for(character, &scene.id, line),
+                        });
+                    }
+                }
+                _ => {}
+            }
+            explicit_voice = false;
+        }
+
+        voices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::Dialogue;
+    use crate::types::AssetRef;
+
+    fn scene() -> Scene {
+        let mut scene = Scene::new("park", "Park");
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator("It was a quiet morning."),
+        });
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::character("alice", "Good morning!"),
+        });
+        scene.add_command(ScenarioCommand::Wait { duration: 0.5 });
+        scene.add_command(ScenarioCommand::PlayVoice {
+            asset: AssetRef::from("voice/bob/special.ogg"),
+            volume: 1.0,
+        });
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::character("bob", "Morning."),
+        });
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::character("alice", "Shall we go?"),
+        });
+        scene
+    }
+
+    #[test]
+    fn test_voice_resolver_path_for() {
+        let resolver = VoiceResolver::new("voice/");
+        assert_eq!(resolver.voice_dir(), "voice");
+        assert_eq!(
+            resolver.path_for("alice", "park", 2),
+            "voice/alice/park_2.ogg"
+        );
+
+        let resolver = VoiceResolver::new("").with_extension(".wav");
+        assert_eq!(resolver.path_for("alice", "park", 2), "alice/park_2.wav");
+    }
+
+    #[test]
+    fn test_voice_resolver_conventional_lines() {
+        let resolver = VoiceResolver::new("voice");
+        let lines = resolver.conventional_lines(&scene());
+
+        // Narrator lines and lines with an explicit voice are skipped,
+        // but still count towards the line number
+        assert_eq!(
+            lines,
+            vec![
+                ConventionalVoice {
+                    command_index: 1,
+                    line: 2,
+                    character: "alice".to_string(),
+                    path: "voice/alice/park_2.ogg".to_string(),
+                },
+                ConventionalVoice {
+                    command_index: 5,
+                    line: 4,
+                    character: "alice".to_string(),
+                    path: "voice/alice/park_4.ogg".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_voice_resolver_resolve() {
+        let resolver = VoiceResolver::new("voice");
+        let scene = scene();
+
+        assert_eq!(
+            resolver.resolve(&scene, 1).map(|voice| voice.path),
+            Some("voice/alice/park_2.ogg".to_string())
+        );
+        assert_eq!(resolver.resolve(&scene, 0), None); // narrator
+        assert_eq!(resolver.resolve(&scene, 2), None); // not a dialogue
+        assert_eq!(resolver.resolve(&scene, 4), None); // explicit voice
+    }
+}
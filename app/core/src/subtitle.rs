@@ -0,0 +1,380 @@
+//! Subtitle tracks for narrated audio, long sound effects and video
+//!
+//! Subtitles are read from SubRip (`.srt`) or WebVTT (`.vtt`) files. A sound
+//! file can ship with a subtitle file of the same name next to it
+//! (`se/thunder.ogg` → `se/thunder.vtt`), see [`SubtitleTrack::sidecar_path`].
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Errors that can occur when loading subtitles
+#[derive(Debug, Error)]
+pub enum SubtitleError {
+    /// IO error when reading a subtitle file
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Malformed subtitle file
+    #[error("line {line}: {message}")]
+    Parse {
+        /// 1-based line number of the error
+        line: usize,
+        /// Description of the problem
+        message: String,
+    },
+    /// File extension is neither `.srt` nor `.vtt`
+    #[error("unsupported subtitle format: {0}")]
+    UnsupportedFormat(String),
+}
+
+/// Result type for subtitle operations
+pub type SubtitleResult<T> = Result<T, SubtitleError>;
+
+/// Subtitle file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    /// SubRip (`.srt`)
+    Srt,
+    /// WebVTT (`.vtt`)
+    Vtt,
+}
+
+impl SubtitleFormat {
+    /// File extensions checked for sidecar subtitles, in order of preference
+    pub const EXTENSIONS: [&'static str; 2] = ["vtt", "srt"];
+
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "srt" => Some(Self::Srt),
+            "vtt" => Some(Self::Vtt),
+            _ => None,
+        }
+    }
+}
+
+/// A single timed subtitle
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleCue {
+    /// Start time in seconds
+    pub start: f32,
+    /// End time in seconds
+    pub end: f32,
+    /// Text to display (may contain line breaks)
+    pub text: String,
+}
+
+impl SubtitleCue {
+    /// Check if the cue is shown at `time` (seconds)
+    pub fn is_active(&self, time: f32) -> bool {
+        time >= self.start && time < self.end
+    }
+}
+
+/// Timed subtitles, sorted by start time
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubtitleTrack {
+    cues: Vec<SubtitleCue>,
+}
+
+impl SubtitleTrack {
+    /// Create a track from cues (sorted by start time)
+    pub fn new(mut cues: Vec<SubtitleCue>) -> Self {
+        cues.sort_by(|a, b| a.start.total_cmp(&b.start));
+        Self { cues }
+    }
+
+    /// Load a subtitle file, detecting the format from its extension
+    pub fn load(path: impl AsRef<Path>) -> SubtitleResult<Self> {
+        let path = path.as_ref();
+        let format = SubtitleFormat::from_path(path)
+            .ok_or_else(|| SubtitleError::UnsupportedFormat(path.display().to_string()))?;
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content, format)
+    }
+
+    /// Find the subtitle file that accompanies a media file, if any
+    ///
+    /// Looks for a `.vtt`, then a `.srt` file with the same name.
+    pub fn sidecar_path(media_path: impl AsRef<Path>) -> Option<PathBuf> {
+        let media_path = media_path.as_ref();
+        SubtitleFormat::EXTENSIONS
+            .iter()
+            .map(|ext| media_path.with_extension(ext))
+            .find(|path| path.exists())
+    }
+
+    /// Load the subtitle file that accompanies a media file
+    ///
+    /// Returns an empty track when the media file has no subtitle file.
+    pub fn load_sidecar(media_path: impl AsRef<Path>) -> SubtitleResult<Self> {
+        match Self::sidecar_path(media_path) {
+            Some(path) => Self::load(path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Parse subtitles in the given format
+    pub fn parse(content: &str, format: SubtitleFormat) -> SubtitleResult<Self> {
+        match format {
+            SubtitleFormat::Srt => Self::parse_srt(content),
+            SubtitleFormat::Vtt => Self::parse_vtt(content),
+        }
+    }
+
+    /// Parse SubRip (`.srt`) subtitles
+    pub fn parse_srt(content: &str) -> SubtitleResult<Self> {
+        let mut cues = Vec::new();
+        for block in blocks(content) {
+            let Some(timing) = block.lines.iter().position(|l| l.contains("-->")) else {
+                return Err(SubtitleError::Parse {
+                    line: block.line,
+                    message: "expected a '-->' timing line".to_string(),
+                });
+            };
+            cues.push(parse_cue(&block, timing)?);
+        }
+        Ok(Self::new(cues))
+    }
+
+    /// Parse WebVTT (`.vtt`) subtitles
+    ///
+    /// Cue settings (`align:start` etc.), `NOTE`, `STYLE` and `REGION` blocks
+    /// are ignored.
+    pub fn parse_vtt(content: &str) -> SubtitleResult<Self> {
+        let mut blocks = blocks(content.trim_start_matches('\u{feff}'));
+
+        match blocks.next() {
+            Some(header)
+                if header
+                    .lines
+                    .first()
+                    .is_some_and(|line| line.starts_with("WEBVTT")) => {}
+            other => {
+                return Err(SubtitleError::Parse {
+                    line: other.map_or(1, |block| block.line),
+                    message: "missing WEBVTT header".to_string(),
+                });
+            }
+        }
+
+        let mut cues = Vec::new();
+        for block in blocks {
+            let first = block.lines.first().copied().unwrap_or_default();
+            if first.starts_with("NOTE") || first == "STYLE" || first == "REGION" {
+                continue;
+            }
+            let Some(timing) = block.lines.iter().position(|l| l.contains("-->")) else {
+                return Err(SubtitleError::Parse {
+                    line: block.line,
+                    message: "expected a '-->' timing line".to_string(),
+                });
+            };
+            cues.push(parse_cue(&block, timing)?);
+        }
+        Ok(Self::new(cues))
+    }
+
+    /// Get all cues
+    pub fn cues(&self) -> &[SubtitleCue] {
+        &self.cues
+    }
+
+    /// Check if the track has no cues
+    pub fn is_empty(&self) -> bool {
+        self.cues.is_empty()
+    }
+
+    /// End time of the last cue in seconds
+    pub fn duration(&self) -> f32 {
+        self.cues.iter().map(|cue| cue.end).fold(0.0, f32::max)
+    }
+
+    /// Text shown at `time` (seconds); overlapping cues are joined by line breaks
+    pub fn text_at(&self, time: f32) -> Option<String> {
+        let active: Vec<&str> = self
+            .cues
+            .iter()
+            .take_while(|cue| cue.start <= time)
+            .filter(|cue| cue.is_active(time))
+            .map(|cue| cue.text.as_str())
+            .collect();
+
+        (!active.is_empty()).then(|| active.join("\n"))
+    }
+}
+
+/// Consecutive non-blank lines
+struct Block<'a> {
+    /// 1-based line number of the first line
+    line: usize,
+    lines: Vec<&'a str>,
+}
+
+/// Split content into blocks separated by blank lines
+fn blocks(content: &str) -> impl Iterator<Item = Block<'_>> {
+    let mut lines = content.lines().enumerate().peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+
+        let (index, first) = lines.next()?;
+        let mut block = Block {
+            line: index + 1,
+            lines: vec![first.trim_end()],
+        };
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            block.lines.push(line.trim_end());
+        }
+        Some(block)
+    })
+}
+
+/// Parse a cue whose timing line is `block.lines[timing]`
+///
+/// Lines before the timing line are the cue identifier; lines after are text.
+fn parse_cue(block: &Block<'_>, timing: usize) -> SubtitleResult<SubtitleCue> {
+    let line = block.line.saturating_add(timing);
+    let error = |message: String| SubtitleError::Parse { line, message };
+
+    let (start, rest) = block
+        .lines
+        .get(timing)
+        .and_then(|line| line.split_once("-->"))
+        .ok_or_else(|| error("expected a '-->' timing line".to_string()))?;
+    // WebVTT cue settings follow the end time
+    let end = rest.split_whitespace().next().unwrap_or_default();
+
+    let start = parse_timestamp(start.trim())
+        .ok_or_else(|| error(format!("invalid start time '{}'", start.trim())))?;
+    let end = parse_timestamp(end).ok_or_else(|| error(format!("invalid end time '{}'", end)))?;
+    if end < start {
+        return Err(error("end time is before start time".to_string()));
+    }
+
+    let text = block
+        .lines
+        .iter()
+        .skip(timing.saturating_add(1))
+        .map(|line| strip_tags(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ok(SubtitleCue { start, end, text })
+}
+
+/// Parse `hh:mm:ss,mmm`, `hh:mm:ss.mmm` or `mm:ss.mmm` into seconds
+fn parse_timestamp(value: &str) -> Option<f32> {
+    let (clock, millis) = value.split_once([',', '.'])?;
+    if millis.is_empty() || !millis.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let millis: u32 = millis.parse().ok()?;
+
+    let parts: Vec<u32> = clock
+        .split(':')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [h, m, s] => (*h, *m, *s),
+        [m, s] => (0, *m, *s),
+        _ => return None,
+    };
+    if minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+
+    Some((hours * 3600 + minutes * 60 + seconds) as f32 + millis as f32 / 1000.0)
+}
+
+/// Remove markup tags such as `<i>`, `<b>` and `<v Speaker>`
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRT: &str = "1\n00:00:01,000 --> 00:00:03,500\n<i>Thunder rumbles</i>\n\n2\r\n00:00:03,000 --> 00:00:05,000\r\nRain begins\r\nto fall\r\n";
+
+    #[test]
+    fn test_parse_srt() {
+        let track = SubtitleTrack::parse_srt(SRT).unwrap();
+
+        assert_eq!(track.cues().len(), 2);
+        assert_eq!(track.cues()[0].start, 1.0);
+        assert_eq!(track.cues()[0].end, 3.5);
+        assert_eq!(track.cues()[0].text, "Thunder rumbles");
+        assert_eq!(track.cues()[1].text, "Rain begins\nto fall");
+        assert_eq!(track.duration(), 5.0);
+    }
+
+    #[test]
+    fn test_parse_vtt() {
+        let vtt = "WEBVTT - narration\n\nNOTE written by hand\n\nintro\n00:01.000 --> 00:02.500 align:start\n<v Narrator>Long ago\n\n01:00:00.000 --> 01:00:01.000\nLater\n";
+        let track = SubtitleTrack::parse_vtt(vtt).unwrap();
+
+        assert_eq!(track.cues().len(), 2);
+        assert_eq!(track.cues()[0].text, "Long ago");
+        assert_eq!(track.cues()[0].end, 2.5);
+        assert_eq!(track.cues()[1].start, 3600.0);
+    }
+
+    #[test]
+    fn test_parse_vtt_requires_header() {
+        let err = SubtitleTrack::parse_vtt("00:01.000 --> 00:02.000\nHi\n").unwrap_err();
+        assert!(err.to_string().contains("WEBVTT"));
+    }
+
+    #[test]
+    fn test_parse_invalid_timestamp() {
+        let err = SubtitleTrack::parse_srt("1\n00:00:01 --> 00:00:02,000\nHi\n").unwrap_err();
+        assert!(matches!(err, SubtitleError::Parse { line: 2, .. }));
+
+        let err = SubtitleTrack::parse_srt("1\n00:00:03,000 --> 00:00:02,000\nHi\n").unwrap_err();
+        assert!(err.to_string().contains("before start"));
+    }
+
+    #[test]
+    fn test_text_at() {
+        let track = SubtitleTrack::parse_srt(SRT).unwrap();
+
+        assert_eq!(track.text_at(0.5), None);
+        assert_eq!(track.text_at(1.0).as_deref(), Some("Thunder rumbles"));
+        assert_eq!(
+            track.text_at(3.2).as_deref(),
+            Some("Thunder rumbles\nRain begins\nto fall")
+        );
+        assert_eq!(track.text_at(5.0), None);
+    }
+
+    #[test]
+    fn test_load_and_sidecar_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let audio = temp_dir.path().join("thunder.ogg");
+        assert_eq!(SubtitleTrack::sidecar_path(&audio), None);
+        assert!(SubtitleTrack::load_sidecar(&audio).unwrap().is_empty());
+
+        let subtitles = temp_dir.path().join("thunder.srt");
+        fs::write(&subtitles, SRT).unwrap();
+        assert_eq!(SubtitleTrack::sidecar_path(&audio), Some(subtitles.clone()));
+        assert_eq!(SubtitleTrack::load(&subtitles).unwrap().cues().len(), 2);
+        assert_eq!(SubtitleTrack::load_sidecar(&audio).unwrap().cues().len(), 2);
+
+        assert!(matches!(
+            SubtitleTrack::load(&audio),
+            Err(SubtitleError::UnsupportedFormat(_))
+        ));
+    }
+}
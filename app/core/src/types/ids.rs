@@ -57,10 +57,13 @@ impl From<&str> for CharacterId {
 }
 
 /// Asset reference - lightweight path reference
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AssetRef(pub String);
 
 impl AssetRef {
+    /// Separator between the layer paths of a layered reference
+    pub const LAYER_SEPARATOR: &'static str = "|";
+
     /// Create a new asset reference
     pub fn new(path: impl Into<String>) -> Self {
         Self(path.into())
@@ -70,6 +73,32 @@ impl AssetRef {
     pub fn path(&self) -> &str {
         &self.0
     }
+
+    /// Reference an image composited from layers, bottom to top
+    ///
+    /// A single layer is a plain reference to that image.
+    pub fn layered<S: AsRef<str>>(layers: impl IntoIterator<Item = S>) -> Self {
+        let layers: Vec<S> = layers.into_iter().collect();
+        Self(
+            layers
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<_>>()
+                .join(Self::LAYER_SEPARATOR),
+        )
+    }
+
+    /// Check if this references an image composited from several layers
+    pub fn is_layered(&self) -> bool {
+        self.0.contains(Self::LAYER_SEPARATOR)
+    }
+
+    /// Image paths making up the asset, bottom to top
+    ///
+    /// A plain reference has its path as the only layer.
+    pub fn layers(&self) -> impl Iterator<Item = &str> {
+        self.0.split(Self::LAYER_SEPARATOR)
+    }
 }
 
 impl From<String> for AssetRef {
@@ -230,6 +259,19 @@ mod tests {
         assert_eq!(asset.path(), "path/to/asset.png");
     }
 
+    #[test]
+    fn test_asset_ref_layered() {
+        let asset = AssetRef::layered(["body.png", "face.png"]);
+        assert_eq!(asset.path(), "body.png|face.png");
+        assert!(asset.is_layered());
+        assert_eq!(asset.layers().collect::<Vec<_>>(), ["body.png", "face.png"]);
+
+        let plain = AssetRef::layered(["face.png"]);
+        assert_eq!(plain, AssetRef::new("face.png"));
+        assert!(!plain.is_layered());
+        assert_eq!(plain.layers().collect::<Vec<_>>(), ["face.png"]);
+    }
+
     #[test]
     fn test_asset_ref_from_str() {
         let asset: AssetRef = "assets/image.jpg".into();
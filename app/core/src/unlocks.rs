@@ -39,7 +39,7 @@ pub struct UnlockData {
     /// Set of unlocked BGM IDs (for future BGM gallery)
     pub unlocked_bgm: HashSet<String>,
 
-    /// Set of unlocked achievements (for future achievement system)
+    /// Set of unlocked achievement IDs
     pub unlocked_achievements: HashSet<String>,
 
     /// Statistics and counters
@@ -112,6 +112,42 @@ impl UnlockData {
         self.unlocked_bgm.insert(bgm_id.into())
     }
 
+    /// Check if an achievement is unlocked
+    pub fn is_achievement_unlocked(&self, achievement_id: &str) -> bool {
+        self.unlocked_achievements.contains(achievement_id)
+    }
+
+    /// Unlock an achievement
+    ///
+    /// Returns false if it was already unlocked.
+    pub fn unlock_achievement(&mut self, achievement_id: impl Into<String>) -> bool {
+        self.unlocked_achievements.insert(achievement_id.into())
+    }
+
+    /// Get the number of unlocked achievements
+    pub fn unlocked_achievement_count(&self) -> usize {
+        self.unlocked_achievements.len()
+    }
+
+    /// Record that an ending was reached
+    ///
+    /// Also counts as a completion. Returns how many times the ending has been reached.
+    pub fn record_ending(&mut self, ending_id: impl Into<String>) -> u32 {
+        self.statistics.completion_count = self.statistics.completion_count.saturating_add(1);
+        let count = self
+            .statistics
+            .endings_reached
+            .entry(ending_id.into())
+            .or_insert(0);
+        *count = count.saturating_add(1);
+        *count
+    }
+
+    /// Check if an ending has been reached at least once
+    pub fn is_ending_reached(&self, ending_id: &str) -> bool {
+        self.statistics.endings_reached.contains_key(ending_id)
+    }
+
     /// Load unlock data from a file
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> UnlockResult<Self> {
         let path = path.as_ref();
@@ -221,6 +257,29 @@ mod tests {
         assert!(data.is_bgm_unlocked("bgm_01"));
     }
 
+    #[test]
+    fn test_unlock_achievement() {
+        let mut data = UnlockData::new();
+
+        assert!(!data.is_achievement_unlocked("first_steps"));
+        assert!(data.unlock_achievement("first_steps"));
+        assert!(!data.unlock_achievement("first_steps"));
+        assert!(data.is_achievement_unlocked("first_steps"));
+        assert_eq!(data.unlocked_achievement_count(), 1);
+    }
+
+    #[test]
+    fn test_record_ending() {
+        let mut data = UnlockData::new();
+
+        assert!(!data.is_ending_reached("true_end"));
+        assert_eq!(data.record_ending("true_end"), 1);
+        assert_eq!(data.record_ending("true_end"), 2);
+        assert_eq!(data.record_ending("bad_end"), 1);
+        assert!(data.is_ending_reached("true_end"));
+        assert_eq!(data.statistics.completion_count, 3);
+    }
+
     #[test]
     fn test_save_load() {
         let temp_dir = TempDir::new().unwrap();
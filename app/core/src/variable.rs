@@ -1,5 +1,7 @@
+use crate::expression::{Expression, ExpressionError};
 use crate::scenario::VariableValue;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 use thiserror::Error;
 
 /// Errors that can occur during variable operations
@@ -15,11 +17,23 @@ pub enum VariableError {
     /// Division by zero
     #[error("Division by zero")]
     DivisionByZero,
+
+    /// Expression could not be evaluated
+    #[error("Expression failed: {0}")]
+    Expression(#[from] ExpressionError),
 }
 
 /// Variable operation for modifying variables
+///
+/// In scenario files an operation is either a table tagged with `op` or an
+/// expression string whose value is stored (see [`crate::expression`]):
+///
+/// ```toml
+/// operation = { op = "Add", value = 1 }
+/// operation = "affection + trust * 2"
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "op")]
+#[serde(tag = "op", remote = "Self")]
 pub enum VariableOperation {
     /// Set variable to a value
     Set { value: VariableValue },
@@ -53,14 +67,72 @@ pub enum VariableOperation {
 
     /// Toggle boolean variable
     Toggle,
+
+    /// Set variable to the value of an expression over flags and variables
+    Expression { expression: Expression },
+}
+
+impl Serialize for VariableOperation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Expression { expression } => expression.serialize(serializer),
+            _ => VariableOperation::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VariableOperation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OperationVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for OperationVisitor {
+            type Value = VariableOperation;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a variable operation table or expression string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, source: &str) -> Result<VariableOperation, E> {
+                Expression::parse(source)
+                    .map(|expression| VariableOperation::Expression { expression })
+                    .map_err(E::custom)
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<VariableOperation, A::Error> {
+                VariableOperation::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+            }
+        }
+
+        deserializer.deserialize_any(OperationVisitor)
+    }
 }
 
 impl VariableOperation {
     /// Apply this operation to a variable value
+    ///
+    /// Expressions are evaluated with no flags or variables set; use
+    /// [`Self::apply_with`] to give them the game state.
     pub fn apply(&self, current: &VariableValue) -> Result<VariableValue, VariableError> {
+        self.apply_with(current, &|_| false, &|_| None)
+    }
+
+    /// Apply this operation, evaluating expressions with the provided lookups
+    pub fn apply_with(
+        &self,
+        current: &VariableValue,
+        get_flag: &dyn Fn(&str) -> bool,
+        get_variable: &dyn Fn(&str) -> Option<VariableValue>,
+    ) -> Result<VariableValue, VariableError> {
         match (self, current) {
             (Self::Set { value }, _) => Ok(value.clone()),
 
+            (Self::Expression { expression }, _) => {
+                Ok(expression.evaluate(get_flag, get_variable)?)
+            }
+
             (Self::Add { value }, VariableValue::Int(n)) => {
                 Ok(VariableValue::Int(n.saturating_add(*value)))
             }
@@ -367,4 +439,44 @@ mod tests {
             .unwrap();
         assert_eq!(var.value, VariableValue::Float(25.0));
     }
+
+    #[test]
+    fn test_variable_operation_expression() {
+        let op = VariableOperation::Expression {
+            expression: "score * 2 + bonus".parse().unwrap(),
+        };
+        let get_variable = |name: &str| match name {
+            "score" => Some(VariableValue::Int(10)),
+            "bonus" => Some(VariableValue::Int(5)),
+            _ => None,
+        };
+        let result = op.apply_with(&VariableValue::Int(10), &|_| false, &get_variable);
+        assert_eq!(result, Ok(VariableValue::Int(25)));
+
+        // Without lookups every variable is unset
+        assert_eq!(op.apply(&VariableValue::Int(10)), Ok(VariableValue::Int(0)));
+    }
+
+    #[test]
+    fn test_variable_operation_expression_string_form() {
+        #[derive(Debug, Deserialize)]
+        struct Command {
+            operation: VariableOperation,
+        }
+
+        let command: Command = toml::from_str(r#"operation = "score + 1""#).unwrap();
+        assert!(matches!(
+            command.operation,
+            VariableOperation::Expression { .. }
+        ));
+        assert_eq!(
+            toml::to_string(&VariableOperation::Add { value: 1 }).unwrap(),
+            "op = \"Add\"\nvalue = 1\n"
+        );
+
+        let command: Command = toml::from_str(r#"operation = { op = "Add", value = 1 }"#).unwrap();
+        assert_eq!(command.operation, VariableOperation::Add { value: 1 });
+
+        assert!(toml::from_str::<Command>(r#"operation = "score +""#).is_err());
+    }
 }
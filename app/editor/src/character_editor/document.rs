@@ -0,0 +1,530 @@
+//! Editable character manifest document
+//!
+//! Holds the character manifest and every character definition it references
+//! in memory, tracks unsaved changes, and writes everything back to RON once
+//! the document passes validation.
+
+use narrative_core::character::{CharacterDef, CharacterManifest, CharacterPosition};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A character definition loaded from (or destined for) a file in the manifest
+#[derive(Debug, Clone)]
+pub struct CharacterEntry {
+    /// Path of the definition file, relative to the manifest directory
+    pub path: String,
+    /// Character definition being edited
+    pub def: CharacterDef,
+    /// Whether this definition has unsaved changes
+    pub dirty: bool,
+}
+
+/// In-memory editing state for a character manifest
+#[derive(Debug)]
+pub struct CharacterManifestDocument {
+    /// Path to the manifest RON file
+    manifest_path: PathBuf,
+    /// Root directory that sprite paths are relative to
+    asset_root: PathBuf,
+    /// Characters in manifest order
+    entries: Vec<CharacterEntry>,
+    /// Whether the manifest list itself has unsaved changes
+    manifest_dirty: bool,
+}
+
+impl CharacterManifestDocument {
+    /// Create an empty document that will be saved to `manifest_path`
+    pub fn new(manifest_path: impl Into<PathBuf>, asset_root: impl Into<PathBuf>) -> Self {
+        Self {
+            manifest_path: manifest_path.into(),
+            asset_root: asset_root.into(),
+            entries: Vec::new(),
+            manifest_dirty: true,
+        }
+    }
+
+    /// Load a manifest and all character definitions it references
+    ///
+    /// Definitions are parsed without validation so that broken files can
+    /// still be opened and fixed in the editor.
+    pub fn load(
+        manifest_path: impl Into<PathBuf>,
+        asset_root: impl Into<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        let manifest_path = manifest_path.into();
+        let manifest = CharacterManifest::load_from_file(&manifest_path)?;
+        let manifest_dir = manifest_dir(&manifest_path);
+
+        let mut entries = Vec::with_capacity(manifest.characters.len());
+        for char_path in manifest.characters {
+            let full_path = manifest_dir.join(&char_path);
+            let content = std::fs::read_to_string(&full_path)
+                .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", full_path.display(), e))?;
+            let def: CharacterDef = ron::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Failed to parse '{}': {}", full_path.display(), e))?;
+            entries.push(CharacterEntry {
+                path: char_path,
+                def,
+                dirty: false,
+            });
+        }
+
+        Ok(Self {
+            manifest_path,
+            asset_root: asset_root.into(),
+            entries,
+            manifest_dirty: false,
+        })
+    }
+
+    /// Load the manifest if it exists, otherwise start an empty document
+    pub fn open_or_new(
+        manifest_path: impl Into<PathBuf>,
+        asset_root: impl Into<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        let manifest_path = manifest_path.into();
+        if manifest_path.exists() {
+            Self::load(manifest_path, asset_root)
+        } else {
+            Ok(Self::new(manifest_path, asset_root))
+        }
+    }
+
+    /// Path to the manifest file
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+
+    /// Root directory that sprite paths are relative to
+    pub fn asset_root(&self) -> &Path {
+        &self.asset_root
+    }
+
+    /// All characters in manifest order
+    pub fn entries(&self) -> &[CharacterEntry] {
+        &self.entries
+    }
+
+    /// Get a character entry by index
+    pub fn entry(&self, index: usize) -> Option<&CharacterEntry> {
+        self.entries.get(index)
+    }
+
+    /// Whether any part of the document has unsaved changes
+    pub fn is_dirty(&self) -> bool {
+        self.manifest_dirty || self.entries.iter().any(|entry| entry.dirty)
+    }
+
+    /// Resolve a sprite path (relative to the asset root) to a filesystem path
+    pub fn resolve_sprite_path(&self, sprite_path: &str) -> PathBuf {
+        self.asset_root.join(sprite_path)
+    }
+
+    /// Add a new character and return its index
+    ///
+    /// The definition file is placed at `characters/{id}.ron` next to the manifest.
+    pub fn add_character(&mut self, id: &str, name: &str) -> anyhow::Result<usize> {
+        if id.is_empty() {
+            anyhow::bail!("Character ID cannot be empty");
+        }
+        if self.entries.iter().any(|entry| entry.def.id == id) {
+            anyhow::bail!("Character '{}' already exists", id);
+        }
+
+        self.entries.push(CharacterEntry {
+            path: format!("characters/{}.ron", id),
+            def: CharacterDef::new(id, name, "normal"),
+            dirty: true,
+        });
+        self.manifest_dirty = true;
+        Ok(self.entries.len().saturating_sub(1))
+    }
+
+    /// Remove a character from the manifest
+    ///
+    /// The definition file on disk is left untouched.
+    pub fn remove_character(&mut self, index: usize) -> Option<CharacterEntry> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        self.manifest_dirty = true;
+        Some(self.entries.remove(index))
+    }
+
+    /// Add an expression that points at a sprite file
+    ///
+    /// The expression name is derived from the file stem and the sprite path is
+    /// stored relative to the asset root. Returns the expression name.
+    pub fn add_expression_from_file(
+        &mut self,
+        index: usize,
+        sprite_file: &Path,
+    ) -> anyhow::Result<String> {
+        let name = sprite_file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|stem| !stem.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Invalid sprite file name"))?
+            .to_string();
+        let sprite_path = self.relative_asset_path(sprite_file)?;
+        self.set_expression(index, &name, &sprite_path)?;
+        Ok(name)
+    }
+
+    /// Add or replace an expression sprite
+    ///
+    /// If the character's default expression does not exist yet, the new
+    /// expression becomes the default.
+    pub fn set_expression(
+        &mut self,
+        index: usize,
+        name: &str,
+        sprite_path: &str,
+    ) -> anyhow::Result<()> {
+        let entry = self.entry_mut(index)?;
+        entry
+            .def
+            .expressions
+            .insert(name.to_string(), sprite_path.to_string());
+        if !entry
+            .def
+            .expressions
+            .contains_key(&entry.def.default_expression)
+        {
+            entry.def.default_expression = name.to_string();
+        }
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Remove an expression
+    ///
+    /// If the removed expression was the default, the alphabetically first
+    /// remaining expression becomes the new default.
+    pub fn remove_expression(&mut self, index: usize, name: &str) -> anyhow::Result<()> {
+        let entry = self.entry_mut(index)?;
+        if entry.def.expressions.remove(name).is_none() {
+            anyhow::bail!("Expression '{}' not found", name);
+        }
+        if entry.def.default_expression == name
+            && let Some(first) = sorted_expression_names(&entry.def).first()
+        {
+            entry.def.default_expression = first.clone();
+        }
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Set the default expression
+    pub fn set_default_expression(&mut self, index: usize, name: &str) -> anyhow::Result<()> {
+        let entry = self.entry_mut(index)?;
+        if !entry.def.expressions.contains_key(name) {
+            anyhow::bail!("Expression '{}' not found", name);
+        }
+        entry.def.default_expression = name.to_string();
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Set the name text color
+    pub fn set_color(&mut self, index: usize, color: Option<(u8, u8, u8)>) -> anyhow::Result<()> {
+        let entry = self.entry_mut(index)?;
+        entry.def.color = color;
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Set the sprite offset (cleared when both components are zero)
+    pub fn set_sprite_offset(&mut self, index: usize, offset: (f32, f32)) -> anyhow::Result<()> {
+        let entry = self.entry_mut(index)?;
+        entry.def.sprite_offset = if offset == (0.0, 0.0) {
+            None
+        } else {
+            Some(offset)
+        };
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Set the sprite scale (cleared when it equals 1.0)
+    pub fn set_sprite_scale(&mut self, index: usize, scale: f32) -> anyhow::Result<()> {
+        if !scale.is_finite() || scale <= 0.0 {
+            anyhow::bail!("Sprite scale must be a positive number");
+        }
+        let entry = self.entry_mut(index)?;
+        entry.def.sprite_scale = if (scale - 1.0).abs() < f32::EPSILON {
+            None
+        } else {
+            Some(scale)
+        };
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Set the default position
+    pub fn set_default_position(
+        &mut self,
+        index: usize,
+        position: CharacterPosition,
+    ) -> anyhow::Result<()> {
+        let entry = self.entry_mut(index)?;
+        entry.def.default_position = position;
+        entry.dirty = true;
+        Ok(())
+    }
+
+    /// Validate the whole document
+    ///
+    /// Returns a list of human-readable problems; an empty list means the
+    /// document can be saved.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut seen_ids = HashSet::new();
+        let mut seen_paths = HashSet::new();
+
+        for entry in &self.entries {
+            if let Err(e) = entry.def.validate() {
+                problems.push(e);
+            }
+            if !seen_ids.insert(entry.def.id.as_str()) {
+                problems.push(format!("Duplicate character ID '{}'", entry.def.id));
+            }
+            if !seen_paths.insert(entry.path.as_str()) {
+                problems.push(format!("Duplicate definition file '{}'", entry.path));
+            }
+            if let Some(scale) = entry.def.sprite_scale
+                && (!scale.is_finite() || scale <= 0.0)
+            {
+                problems.push(format!(
+                    "Character '{}' sprite scale must be positive",
+                    entry.def.id
+                ));
+            }
+            for name in sorted_expression_names(&entry.def) {
+                if let Some(sprite) = entry.def.expressions.get(&name)
+                    && !self.resolve_sprite_path(sprite).is_file()
+                {
+                    problems.push(format!(
+                        "Character '{}' expression '{}' sprite not found: {}",
+                        entry.def.id, name, sprite
+                    ));
+                }
+            }
+        }
+
+        problems
+    }
+
+    /// Validate and write the manifest and all modified definitions to disk
+    pub fn save(&mut self) -> anyhow::Result<()> {
+        let problems = self.validate();
+        if !problems.is_empty() {
+            anyhow::bail!("Validation failed:\n{}", problems.join("\n"));
+        }
+
+        let manifest_dir = manifest_dir(&self.manifest_path).to_path_buf();
+        for entry in self.entries.iter_mut().filter(|entry| entry.dirty) {
+            entry.def.save_to_file(manifest_dir.join(&entry.path))?;
+            entry.dirty = false;
+        }
+
+        let manifest = CharacterManifest {
+            characters: self
+                .entries
+                .iter()
+                .map(|entry| entry.path.clone())
+                .collect(),
+        };
+        manifest.save_to_file(&self.manifest_path)?;
+        self.manifest_dirty = false;
+
+        tracing::info!(
+            "Saved character manifest with {} characters to {}",
+            self.entries.len(),
+            self.manifest_path.display()
+        );
+        Ok(())
+    }
+
+    /// Convert a sprite file path into a path relative to the asset root
+    fn relative_asset_path(&self, file: &Path) -> anyhow::Result<String> {
+        let relative = if file.is_absolute() {
+            let root = self
+                .asset_root
+                .canonicalize()
+                .unwrap_or_else(|_| self.asset_root.clone());
+            let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+            file.strip_prefix(&root)
+                .map(Path::to_path_buf)
+                .map_err(|_| {
+                    anyhow::anyhow!(
+                        "Sprite '{}' is outside the asset directory '{}'",
+                        file.display(),
+                        self.asset_root.display()
+                    )
+                })?
+        } else {
+            file.strip_prefix(&self.asset_root)
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|_| file.to_path_buf())
+        };
+
+        // Asset paths always use forward slashes regardless of platform
+        let parts: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        Ok(parts.join("/"))
+    }
+
+    fn entry_mut(&mut self, index: usize) -> anyhow::Result<&mut CharacterEntry> {
+        self.entries
+            .get_mut(index)
+            .ok_or_else(|| anyhow::anyhow!("Character index {} out of range", index))
+    }
+}
+
+/// Expression names of a character in stable (alphabetical) order
+pub fn sorted_expression_names(def: &CharacterDef) -> Vec<String> {
+    let mut names: Vec<String> = def.expressions.keys().cloned().collect();
+    names.sort();
+    names
+}
+
+fn manifest_dir(manifest_path: &Path) -> &Path {
+    manifest_path.parent().unwrap_or_else(|| Path::new("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_sprite(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, b"png").unwrap();
+    }
+
+    fn document_with_alice(root: &Path) -> CharacterManifestDocument {
+        write_sprite(root, "characters/alice/normal.png");
+        let mut doc = CharacterManifestDocument::new(
+            root.join("manifests/characters.ron"),
+            root.to_path_buf(),
+        );
+        let index = doc.add_character("alice", "Alice").unwrap();
+        doc.set_expression(index, "normal", "characters/alice/normal.png")
+            .unwrap();
+        doc
+    }
+
+    #[test]
+    fn test_add_character_rejects_duplicates() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = document_with_alice(dir.path());
+        assert!(doc.add_character("alice", "Alice 2").is_err());
+        assert!(doc.add_character("", "Nobody").is_err());
+        assert_eq!(doc.entries().len(), 1);
+    }
+
+    #[test]
+    fn test_remove_expression_reassigns_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = document_with_alice(dir.path());
+        doc.set_expression(0, "happy", "characters/alice/happy.png")
+            .unwrap();
+        doc.remove_expression(0, "normal").unwrap();
+
+        let def = &doc.entry(0).unwrap().def;
+        assert_eq!(def.default_expression, "happy");
+        assert!(doc.remove_expression(0, "missing").is_err());
+    }
+
+    #[test]
+    fn test_add_expression_from_file_uses_stem_and_relative_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = document_with_alice(dir.path());
+        write_sprite(dir.path(), "characters/alice/smile.png");
+
+        let name = doc
+            .add_expression_from_file(0, &dir.path().join("characters/alice/smile.png"))
+            .unwrap();
+        assert_eq!(name, "smile");
+        assert_eq!(
+            doc.entry(0).unwrap().def.get_expression_sprite("smile"),
+            Some("characters/alice/smile.png")
+        );
+    }
+
+    #[test]
+    fn test_add_expression_outside_asset_root_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let other = tempfile::tempdir().unwrap();
+        write_sprite(other.path(), "outside.png");
+        let mut doc = document_with_alice(dir.path());
+
+        assert!(
+            doc.add_expression_from_file(0, &other.path().join("outside.png"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_sprite_offset_and_scale_defaults_are_cleared() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = document_with_alice(dir.path());
+
+        doc.set_sprite_offset(0, (0.0, 40.0)).unwrap();
+        doc.set_sprite_scale(0, 1.5).unwrap();
+        assert_eq!(doc.entry(0).unwrap().def.sprite_offset, Some((0.0, 40.0)));
+        assert_eq!(doc.entry(0).unwrap().def.sprite_scale, Some(1.5));
+
+        doc.set_sprite_offset(0, (0.0, 0.0)).unwrap();
+        doc.set_sprite_scale(0, 1.0).unwrap();
+        assert_eq!(doc.entry(0).unwrap().def.sprite_offset, None);
+        assert_eq!(doc.entry(0).unwrap().def.sprite_scale, None);
+
+        assert!(doc.set_sprite_scale(0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_missing_sprite() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = document_with_alice(dir.path());
+        assert!(doc.validate().is_empty());
+
+        doc.set_expression(0, "sad", "characters/alice/sad.png")
+            .unwrap();
+        let problems = doc.validate();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("sad"));
+        assert!(doc.save().is_err());
+    }
+
+    #[test]
+    fn test_save_and_reload_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = document_with_alice(dir.path());
+        doc.set_color(0, Some((255, 200, 200))).unwrap();
+        doc.set_sprite_offset(0, (0.0, 75.0)).unwrap();
+        doc.save().unwrap();
+        assert!(!doc.is_dirty());
+
+        let loaded =
+            CharacterManifestDocument::load(doc.manifest_path(), dir.path().to_path_buf()).unwrap();
+        assert_eq!(loaded.entries().len(), 1);
+        let entry = loaded.entry(0).unwrap();
+        assert_eq!(entry.path, "characters/alice.ron");
+        assert_eq!(entry.def, doc.entry(0).unwrap().def);
+        assert!(!loaded.is_dirty());
+    }
+
+    #[test]
+    fn test_open_or_new_missing_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc =
+            CharacterManifestDocument::open_or_new(dir.path().join("missing.ron"), dir.path())
+                .unwrap();
+        assert!(doc.entries().is_empty());
+        assert!(doc.is_dirty());
+    }
+}
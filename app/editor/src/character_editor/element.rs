@@ -0,0 +1,1031 @@
+//! Character manifest editor element
+//!
+//! Layout:
+//! - Left: character list with New/Remove buttons
+//! - Center: properties (name color, sprite offset/scale, default position, expressions)
+//! - Right: live sprite preview at each `CharacterPosition`
+//! - Bottom: status line with validation results
+//!
+//! Keyboard shortcuts:
+//! - Up/Down: select character
+//! - Left/Right: move preview position
+//! - Ctrl+S: validate and save
+
+use super::document::{CharacterManifestDocument, sorted_expression_names};
+use crate::widgets::{
+    BUTTON_HEIGHT, EditorButton, StatusMessage, clicked_action, rgba8, step_channel,
+};
+use narrative_core::character::{CharacterDef, CharacterPosition};
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::renderer::Renderer;
+use narrative_gui::theme::{colors, font_size, radius, spacing};
+use narrative_gui::{Color, Point, Size};
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Positions offered in the preview, left to right
+const PREVIEW_POSITIONS: [CharacterPosition; 5] = [
+    CharacterPosition::FarLeft,
+    CharacterPosition::Left,
+    CharacterPosition::Center,
+    CharacterPosition::Right,
+    CharacterPosition::FarRight,
+];
+
+/// Editor action triggered by a button
+#[derive(Debug, Clone, PartialEq)]
+enum EditorAction {
+    SelectCharacter(usize),
+    AddCharacter,
+    RemoveCharacter,
+    AddExpression,
+    RemoveExpression(String),
+    SetDefaultExpression(String),
+    PreviewExpression(String),
+    AdjustColor { channel: usize, delta: i16 },
+    ClearColor,
+    AdjustOffset { dx: f32, dy: f32 },
+    AdjustScale(f32),
+    PreviewPosition(CharacterPosition),
+    UsePreviewAsDefaultPosition,
+    Save,
+}
+
+/// Loaded preview texture
+#[derive(Debug, Clone, Copy)]
+struct PreviewTexture {
+    id: u64,
+    size: (u32, u32),
+}
+
+/// Editor panel for a `CharacterManifest`
+pub struct CharacterEditorElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Document being edited
+    document: CharacterManifestDocument,
+    /// Selected character index
+    selected: usize,
+    /// Expression shown in the preview (None = default expression)
+    preview_expression: Option<String>,
+    /// Position shown in the preview
+    preview_position: CharacterPosition,
+    /// Loaded sprite textures keyed by sprite path
+    textures: HashMap<String, PreviewTexture>,
+    /// Sprite paths that failed to load (not retried)
+    failed_textures: HashSet<String>,
+    /// Status line message
+    status: StatusMessage,
+    /// Dirty flag to track if rendering needs update
+    dirty: bool,
+}
+
+impl CharacterEditorElement {
+    /// Header bar height
+    const HEADER_HEIGHT: f32 = 56.0;
+    /// Status bar height
+    const STATUS_HEIGHT: f32 = 72.0;
+    /// Character list width
+    const LIST_WIDTH: f32 = 240.0;
+    /// Properties panel width
+    const PROPERTIES_WIDTH: f32 = 420.0;
+    /// Row height for lists and property rows
+    const ROW_HEIGHT: f32 = 36.0;
+    /// Small stepper button size
+    const STEP_BUTTON_SIZE: f32 = BUTTON_HEIGHT;
+    /// Regular button width
+    const BUTTON_WIDTH: f32 = 96.0;
+    /// Offset step in reference pixels
+    const OFFSET_STEP: f32 = 5.0;
+    /// Scale step
+    const SCALE_STEP: f32 = 0.05;
+    /// Color channel step
+    const COLOR_STEP: i16 = 15;
+    /// Reference resolution used by the game for sprite layout
+    const REFERENCE_WIDTH: f32 = 1280.0;
+    const REFERENCE_HEIGHT: f32 = 720.0;
+    /// Sprite height at reference resolution
+    const REFERENCE_SPRITE_HEIGHT: f32 = 600.0;
+
+    /// Create a new editor for the given document
+    pub fn new(document: CharacterManifestDocument) -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            document,
+            selected: 0,
+            preview_expression: None,
+            preview_position: CharacterPosition::Center,
+            textures: HashMap::new(),
+            failed_textures: HashSet::new(),
+            status: StatusMessage::Info(
+                "Ctrl+S to save. Click an expression to preview it.".to_string(),
+            ),
+            dirty: true,
+        }
+    }
+
+    fn selected_def(&self) -> Option<&CharacterDef> {
+        self.document.entry(self.selected).map(|entry| &entry.def)
+    }
+
+    /// Sprite path currently shown in the preview
+    fn preview_sprite_path(&self) -> Option<&str> {
+        let def = self.selected_def()?;
+        let expression = self
+            .preview_expression
+            .as_deref()
+            .filter(|name| def.expressions.contains_key(*name))
+            .unwrap_or(&def.default_expression);
+        def.get_expression_sprite(expression)
+    }
+
+    fn select_character(&mut self, index: usize) {
+        if index < self.document.entries().len() {
+            self.selected = index;
+            self.preview_expression = None;
+            if let Some(def) = self.selected_def() {
+                self.preview_position = def.default_position;
+            }
+            self.dirty = true;
+        }
+    }
+
+    fn cycle_preview_position(&mut self, forward: bool) {
+        let current = PREVIEW_POSITIONS
+            .iter()
+            .position(|p| *p == self.preview_position)
+            .unwrap_or(2);
+        let next = if forward {
+            current.saturating_add(1).min(PREVIEW_POSITIONS.len() - 1)
+        } else {
+            current.saturating_sub(1)
+        };
+        if let Some(position) = PREVIEW_POSITIONS.get(next) {
+            self.preview_position = *position;
+            self.dirty = true;
+        }
+    }
+
+    fn report<T>(&mut self, result: anyhow::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.status = StatusMessage::Error(vec![e.to_string()]);
+                None
+            }
+        }
+    }
+
+    fn save(&mut self) {
+        let problems = self.document.validate();
+        if !problems.is_empty() {
+            self.status = StatusMessage::Error(problems);
+            return;
+        }
+        match self.document.save() {
+            Ok(()) => {
+                self.status = StatusMessage::Success(format!(
+                    "Saved {}",
+                    self.document.manifest_path().display()
+                ));
+            }
+            Err(e) => {
+                tracing::error!("Failed to save character manifest: {}", e);
+                self.status = StatusMessage::Error(vec![e.to_string()]);
+            }
+        }
+    }
+
+    fn pick_expression_file(&mut self) {
+        let file = rfd::FileDialog::new()
+            .set_title("Select expression sprite")
+            .add_filter("Images", &["png", "jpg", "jpeg", "webp"])
+            .set_directory(self.document.asset_root())
+            .pick_file();
+
+        if let Some(file) = file {
+            let result = self.document.add_expression_from_file(self.selected, &file);
+            if let Some(name) = self.report(result) {
+                self.status = StatusMessage::Info(format!("Added expression '{}'", name));
+                self.preview_expression = Some(name);
+            }
+        }
+    }
+
+    fn apply_action(&mut self, action: EditorAction) {
+        let index = self.selected;
+        match action {
+            EditorAction::SelectCharacter(i) => self.select_character(i),
+            EditorAction::AddCharacter => {
+                let mut n = self.document.entries().len().saturating_add(1);
+                let mut id = format!("character_{}", n);
+                while self.document.entries().iter().any(|e| e.def.id == id) {
+                    n = n.saturating_add(1);
+                    id = format!("character_{}", n);
+                }
+                let result = self.document.add_character(&id, &id);
+                if let Some(new_index) = self.report(result) {
+                    self.select_character(new_index);
+                    self.status = StatusMessage::Info(format!(
+                        "Added '{}'. Add an expression before saving.",
+                        id
+                    ));
+                }
+            }
+            EditorAction::RemoveCharacter => {
+                if let Some(entry) = self.document.remove_character(index) {
+                    self.status = StatusMessage::Info(format!(
+                        "Removed '{}' from manifest ({} was kept on disk)",
+                        entry.def.id, entry.path
+                    ));
+                    let last = self.document.entries().len().saturating_sub(1);
+                    self.select_character(index.min(last));
+                }
+            }
+            EditorAction::AddExpression => self.pick_expression_file(),
+            EditorAction::RemoveExpression(name) => {
+                let result = self.document.remove_expression(index, &name);
+                self.report(result);
+                if self.preview_expression.as_deref() == Some(name.as_str()) {
+                    self.preview_expression = None;
+                }
+            }
+            EditorAction::SetDefaultExpression(name) => {
+                let result = self.document.set_default_expression(index, &name);
+                self.report(result);
+            }
+            EditorAction::PreviewExpression(name) => self.preview_expression = Some(name),
+            EditorAction::AdjustColor { channel, delta } => {
+                let (r, g, b) = self
+                    .selected_def()
+                    .and_then(|def| def.color)
+                    .unwrap_or((255, 255, 255));
+                let mut rgb = [r, g, b];
+                if let Some(value) = rgb.get_mut(channel) {
+                    *value = step_channel(*value, delta);
+                }
+                let result = self
+                    .document
+                    .set_color(index, Some((rgb[0], rgb[1], rgb[2])));
+                self.report(result);
+            }
+            EditorAction::ClearColor => {
+                let result = self.document.set_color(index, None);
+                self.report(result);
+            }
+            EditorAction::AdjustOffset { dx, dy } => {
+                let (x, y) = self
+                    .selected_def()
+                    .and_then(|def| def.sprite_offset)
+                    .unwrap_or((0.0, 0.0));
+                let result = self.document.set_sprite_offset(index, (x + dx, y + dy));
+                self.report(result);
+            }
+            EditorAction::AdjustScale(delta) => {
+                let scale = self
+                    .selected_def()
+                    .and_then(|def| def.sprite_scale)
+                    .unwrap_or(1.0);
+                // Round to the step to avoid accumulating float error
+                let new_scale = ((scale + delta) / Self::SCALE_STEP).round() * Self::SCALE_STEP;
+                let result = self.document.set_sprite_scale(index, new_scale);
+                self.report(result);
+            }
+            EditorAction::PreviewPosition(position) => self.preview_position = position,
+            EditorAction::UsePreviewAsDefaultPosition => {
+                let result = self
+                    .document
+                    .set_default_position(index, self.preview_position);
+                self.report(result);
+            }
+            EditorAction::Save => self.save(),
+        }
+        self.dirty = true;
+    }
+
+    fn list_bounds(bounds: Bounds) -> Bounds {
+        Bounds {
+            origin: Point::new(bounds.origin.x, bounds.origin.y + Self::HEADER_HEIGHT),
+            size: Size::new(
+                Self::LIST_WIDTH,
+                (bounds.size.height - Self::HEADER_HEIGHT - Self::STATUS_HEIGHT).max(0.0),
+            ),
+        }
+    }
+
+    fn properties_bounds(bounds: Bounds) -> Bounds {
+        let list = Self::list_bounds(bounds);
+        Bounds {
+            origin: Point::new(list.origin.x + list.size.width, list.origin.y),
+            size: Size::new(Self::PROPERTIES_WIDTH, list.size.height),
+        }
+    }
+
+    fn preview_bounds(bounds: Bounds) -> Bounds {
+        let props = Self::properties_bounds(bounds);
+        let x = props.origin.x + props.size.width;
+        Bounds {
+            origin: Point::new(x, props.origin.y),
+            size: Size::new(
+                (bounds.origin.x + bounds.size.width - x).max(0.0),
+                props.size.height,
+            ),
+        }
+    }
+
+    /// Stage area inside the preview panel, fitted to the reference aspect ratio
+    fn stage_bounds(bounds: Bounds) -> Bounds {
+        let preview = Self::preview_bounds(bounds);
+        let top = preview.origin.y + spacing::MD * 2.0 + Self::ROW_HEIGHT;
+        let avail_w = (preview.size.width - spacing::MD * 2.0).max(0.0);
+        let avail_h =
+            (preview.origin.y + preview.size.height - top - spacing::MD * 3.0 - Self::ROW_HEIGHT)
+                .max(0.0);
+        let aspect = Self::REFERENCE_WIDTH / Self::REFERENCE_HEIGHT;
+        let (w, h) = if avail_w / aspect <= avail_h {
+            (avail_w, avail_w / aspect)
+        } else {
+            (avail_h * aspect, avail_h)
+        };
+        Bounds {
+            origin: Point::new(preview.origin.x + (preview.size.width - w) / 2.0, top),
+            size: Size::new(w, h),
+        }
+    }
+
+    /// Sprite bounds inside the stage, matching the game's character layout
+    fn sprite_bounds(&self, stage: Bounds, texture_size: (u32, u32)) -> Option<Bounds> {
+        let def = self.selected_def()?;
+        if texture_size.1 == 0 {
+            return None;
+        }
+        let scale = def.sprite_scale.unwrap_or(1.0);
+        let (offset_x, offset_y) = def.sprite_offset.unwrap_or((0.0, 0.0));
+        let height =
+            stage.size.height * (Self::REFERENCE_SPRITE_HEIGHT / Self::REFERENCE_HEIGHT) * scale;
+        let width = height * (texture_size.0 as f32 / texture_size.1 as f32);
+        let x_scale = stage.size.width / Self::REFERENCE_WIDTH;
+        let y_scale = stage.size.height / Self::REFERENCE_HEIGHT;
+        let x =
+            stage.size.width * self.preview_position.x_percent() - width * 0.5 + offset_x * x_scale;
+        let y = stage.size.height - height + offset_y * y_scale;
+        Some(Bounds {
+            origin: Point::new(stage.origin.x + x, stage.origin.y + y),
+            size: Size::new(width, height),
+        })
+    }
+
+    /// Compute all buttons for the current state and layout
+    fn buttons(&self, bounds: Bounds) -> Vec<EditorButton<EditorAction>> {
+        let mut buttons = Vec::new();
+        let mut push = |x: f32, y: f32, w: f32, label: &str, action: EditorAction, active| {
+            buttons.push(EditorButton::new(x, y, w, label, action).active(active));
+        };
+
+        // Header
+        let save_x = bounds.origin.x + bounds.size.width - Self::BUTTON_WIDTH - spacing::MD;
+        let header_y = bounds.origin.y + (Self::HEADER_HEIGHT - Self::STEP_BUTTON_SIZE) / 2.0;
+        push(
+            save_x,
+            header_y,
+            Self::BUTTON_WIDTH,
+            "Save",
+            EditorAction::Save,
+            self.document.is_dirty(),
+        );
+
+        // Character list
+        let list = Self::list_bounds(bounds);
+        let list_x = list.origin.x + spacing::SM;
+        let list_w = list.size.width - spacing::SM * 2.0;
+        for (i, entry) in self.document.entries().iter().enumerate() {
+            let y = list.origin.y + spacing::SM + i as f32 * Self::ROW_HEIGHT;
+            let marker = if entry.dirty { " *" } else { "" };
+            push(
+                list_x,
+                y,
+                list_w,
+                &format!("{}{}", entry.def.id, marker),
+                EditorAction::SelectCharacter(i),
+                i == self.selected,
+            );
+        }
+        let list_bottom = list.origin.y + list.size.height - Self::STEP_BUTTON_SIZE - spacing::SM;
+        let half = (list_w - spacing::SM) / 2.0;
+        push(
+            list_x,
+            list_bottom,
+            half,
+            "New",
+            EditorAction::AddCharacter,
+            false,
+        );
+        if self.selected_def().is_none() {
+            return buttons;
+        }
+        push(
+            list_x + half + spacing::SM,
+            list_bottom,
+            half,
+            "Remove",
+            EditorAction::RemoveCharacter,
+            false,
+        );
+
+        // Properties
+        let props = Self::properties_bounds(bounds);
+        let step = Self::STEP_BUTTON_SIZE;
+        let controls_x = props.origin.x + 150.0;
+        let row_y = |row: usize| props.origin.y + spacing::MD + row as f32 * Self::ROW_HEIGHT;
+
+        // Rows 2-4: color channels
+        for channel in 0..3 {
+            let y = row_y(2 + channel);
+            let x = controls_x + 20.0;
+            push(
+                x,
+                y,
+                step,
+                "-",
+                EditorAction::AdjustColor {
+                    channel,
+                    delta: -Self::COLOR_STEP,
+                },
+                false,
+            );
+            push(
+                x + step + 56.0,
+                y,
+                step,
+                "+",
+                EditorAction::AdjustColor {
+                    channel,
+                    delta: Self::COLOR_STEP,
+                },
+                false,
+            );
+        }
+        push(
+            controls_x + 160.0,
+            row_y(2),
+            72.0,
+            "Clear",
+            EditorAction::ClearColor,
+            false,
+        );
+
+        // Rows 5-7: offset x/y, scale
+        let steppers = [
+            (
+                row_y(5),
+                EditorAction::AdjustOffset {
+                    dx: -Self::OFFSET_STEP,
+                    dy: 0.0,
+                },
+                EditorAction::AdjustOffset {
+                    dx: Self::OFFSET_STEP,
+                    dy: 0.0,
+                },
+            ),
+            (
+                row_y(6),
+                EditorAction::AdjustOffset {
+                    dx: 0.0,
+                    dy: -Self::OFFSET_STEP,
+                },
+                EditorAction::AdjustOffset {
+                    dx: 0.0,
+                    dy: Self::OFFSET_STEP,
+                },
+            ),
+            (
+                row_y(7),
+                EditorAction::AdjustScale(-Self::SCALE_STEP),
+                EditorAction::AdjustScale(Self::SCALE_STEP),
+            ),
+        ];
+        for (y, minus, plus) in steppers {
+            push(controls_x, y, step, "-", minus, false);
+            push(controls_x + step + 76.0, y, step, "+", plus, false);
+        }
+
+        // Row 8: default position
+        push(
+            controls_x + 110.0,
+            row_y(8),
+            140.0,
+            "Use preview",
+            EditorAction::UsePreviewAsDefaultPosition,
+            false,
+        );
+
+        // Expressions
+        push(
+            props.origin.x + props.size.width - Self::BUTTON_WIDTH - spacing::MD,
+            row_y(9),
+            Self::BUTTON_WIDTH,
+            "Add...",
+            EditorAction::AddExpression,
+            false,
+        );
+        if let Some(def) = self.selected_def() {
+            let preview_name = self
+                .preview_expression
+                .clone()
+                .unwrap_or_else(|| def.default_expression.clone());
+            for (i, name) in sorted_expression_names(def).into_iter().enumerate() {
+                let y = row_y(10 + i);
+                push(
+                    props.origin.x + spacing::MD,
+                    y,
+                    200.0,
+                    &name,
+                    EditorAction::PreviewExpression(name.clone()),
+                    name == preview_name,
+                );
+                push(
+                    props.origin.x + spacing::MD + 208.0,
+                    y,
+                    Self::BUTTON_WIDTH,
+                    "Default",
+                    EditorAction::SetDefaultExpression(name.clone()),
+                    name == def.default_expression,
+                );
+                push(
+                    props.origin.x + spacing::MD + 312.0,
+                    y,
+                    step,
+                    "x",
+                    EditorAction::RemoveExpression(name),
+                    false,
+                );
+            }
+        }
+
+        // Preview position selector
+        let preview = Self::preview_bounds(bounds);
+        let count = PREVIEW_POSITIONS.len() as f32;
+        let pos_w = ((preview.size.width - spacing::MD * 2.0 - spacing::SM * (count - 1.0))
+            / count)
+            .max(0.0);
+        for (i, position) in PREVIEW_POSITIONS.iter().enumerate() {
+            push(
+                preview.origin.x + spacing::MD + i as f32 * (pos_w + spacing::SM),
+                preview.origin.y + spacing::MD,
+                pos_w,
+                position.name(),
+                EditorAction::PreviewPosition(*position),
+                *position == self.preview_position,
+            );
+        }
+
+        buttons
+    }
+
+    fn paint_properties(&self, cx: &mut PaintContext, def: &CharacterDef) {
+        let props = Self::properties_bounds(cx.bounds);
+        let label_x = props.origin.x + spacing::MD;
+        let controls_x = props.origin.x + 150.0;
+        let text_y = |row: usize| {
+            props.origin.y
+                + spacing::MD
+                + row as f32 * Self::ROW_HEIGHT
+                + (Self::STEP_BUTTON_SIZE + font_size::MD * 0.8) / 2.0
+        };
+        let label = |cx: &mut PaintContext, row: usize, text: &str| {
+            cx.draw_text(
+                text,
+                Point::new(label_x, text_y(row)),
+                colors::TEXT_SECONDARY,
+                font_size::MD,
+            );
+        };
+        let value = |cx: &mut PaintContext, row: usize, x: f32, text: &str, color: Color| {
+            cx.draw_text(text, Point::new(x, text_y(row)), color, font_size::MD);
+        };
+
+        label(cx, 0, "ID");
+        value(cx, 0, controls_x, &def.id, colors::TEXT_PRIMARY);
+
+        // Name preview in its dialogue color
+        label(cx, 1, "Name");
+        let display_color = name_color(def).unwrap_or(colors::TEXT_PRIMARY);
+        value(cx, 1, controls_x, &def.name, display_color);
+
+        label(cx, 2, "Name color");
+        let (r, g, b) = def.color.unwrap_or((255, 255, 255));
+        for (channel, (label_text, component)) in [("R", r), ("G", g), ("B", b)].iter().enumerate()
+        {
+            let row = 2 + channel;
+            value(cx, row, controls_x, label_text, colors::TEXT_MUTED);
+            value(
+                cx,
+                row,
+                controls_x + 20.0 + Self::STEP_BUTTON_SIZE + 10.0,
+                &component.to_string(),
+                colors::TEXT_PRIMARY,
+            );
+        }
+        let swatch = Bounds {
+            origin: Point::new(
+                controls_x + 160.0,
+                props.origin.y + spacing::MD + 3.0 * Self::ROW_HEIGHT,
+            ),
+            size: Size::new(72.0, Self::ROW_HEIGHT + Self::STEP_BUTTON_SIZE),
+        };
+        match name_color(def) {
+            Some(color) => cx.fill_rounded_rect(swatch, color, radius::MD),
+            None => cx.stroke_rect(swatch, colors::BORDER_LIGHT, 1.0),
+        }
+
+        let (offset_x, offset_y) = def.sprite_offset.unwrap_or((0.0, 0.0));
+        let number_x = controls_x + Self::STEP_BUTTON_SIZE + 10.0;
+        label(cx, 5, "Offset X");
+        value(
+            cx,
+            5,
+            number_x,
+            &format!("{:.0}", offset_x),
+            colors::TEXT_PRIMARY,
+        );
+        label(cx, 6, "Offset Y");
+        value(
+            cx,
+            6,
+            number_x,
+            &format!("{:.0}", offset_y),
+            colors::TEXT_PRIMARY,
+        );
+        label(cx, 7, "Scale");
+        value(
+            cx,
+            7,
+            number_x,
+            &format!("{:.2}", def.sprite_scale.unwrap_or(1.0)),
+            colors::TEXT_PRIMARY,
+        );
+
+        label(cx, 8, "Default pos.");
+        value(
+            cx,
+            8,
+            controls_x,
+            def.default_position.name(),
+            colors::TEXT_PRIMARY,
+        );
+
+        label(cx, 9, "Expressions");
+    }
+
+    fn paint_preview(&self, cx: &mut PaintContext) {
+        let stage = Self::stage_bounds(cx.bounds);
+        cx.fill_rect(stage, colors::BG_DARKEST);
+        cx.stroke_rect(stage, colors::BORDER, 1.0);
+
+        // Guide lines at each standard position
+        for position in PREVIEW_POSITIONS {
+            let x = stage.origin.x + stage.size.width * position.x_percent();
+            let guide = Bounds {
+                origin: Point::new(x, stage.origin.y),
+                size: Size::new(1.0, stage.size.height),
+            };
+            let color = if position == self.preview_position {
+                colors::BORDER_ACCENT
+            } else {
+                colors::BORDER
+            };
+            cx.fill_rect(guide, color);
+        }
+
+        let caption_y = stage.origin.y + stage.size.height + spacing::MD + font_size::MD;
+        match self.preview_sprite_path() {
+            Some(path) => {
+                if let Some(texture) = self.textures.get(path) {
+                    if let Some(sprite) = self.sprite_bounds(stage, texture.size) {
+                        cx.draw_texture(texture.id, sprite, 1.0);
+                    }
+                } else if self.failed_textures.contains(path) {
+                    cx.draw_text(
+                        &format!("Failed to load {}", path),
+                        Point::new(stage.origin.x + spacing::MD, stage.origin.y + 32.0),
+                        colors::ERROR,
+                        font_size::MD,
+                    );
+                }
+                cx.draw_text(
+                    path,
+                    Point::new(stage.origin.x, caption_y),
+                    colors::TEXT_MUTED,
+                    font_size::SM,
+                );
+            }
+            None => {
+                cx.draw_text(
+                    "No sprite",
+                    Point::new(stage.origin.x + spacing::MD, stage.origin.y + 32.0),
+                    colors::TEXT_MUTED,
+                    font_size::MD,
+                );
+            }
+        }
+    }
+
+    fn paint_status(&self, cx: &mut PaintContext) {
+        let bounds = cx.bounds;
+        let status = Bounds {
+            origin: Point::new(
+                bounds.origin.x,
+                bounds.origin.y + bounds.size.height - Self::STATUS_HEIGHT,
+            ),
+            size: Size::new(bounds.size.width, Self::STATUS_HEIGHT),
+        };
+        self.status.paint(cx, status);
+    }
+}
+
+/// Name color of a character as a GUI color
+fn name_color(def: &CharacterDef) -> Option<Color> {
+    def.color.map(|(r, g, b)| rgba8(r, g, b, 255))
+}
+
+impl Element for CharacterEditorElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        taffy::Style {
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let bounds = cx.bounds;
+        cx.fill_rect(bounds, colors::BG_DARK);
+
+        // Header
+        let header = Bounds {
+            origin: bounds.origin,
+            size: Size::new(bounds.size.width, Self::HEADER_HEIGHT),
+        };
+        cx.fill_rect(header, colors::BG_PANEL);
+        let dirty_marker = if self.document.is_dirty() {
+            " (modified)"
+        } else {
+            ""
+        };
+        cx.draw_text(
+            &format!(
+                "Characters - {}{}",
+                self.document.manifest_path().display(),
+                dirty_marker
+            ),
+            Point::new(
+                bounds.origin.x + spacing::MD,
+                bounds.origin.y + (Self::HEADER_HEIGHT + font_size::LG * 0.8) / 2.0,
+            ),
+            colors::TEXT_PRIMARY,
+            font_size::LG,
+        );
+
+        // Panels
+        cx.fill_rect(Self::list_bounds(bounds), colors::SIDEBAR_BG);
+        let props = Self::properties_bounds(bounds);
+        cx.fill_rect(props, colors::BG_PANEL);
+        cx.stroke_rect(props, colors::BORDER, 1.0);
+
+        if let Some(def) = self.selected_def() {
+            self.paint_properties(cx, def);
+            self.paint_preview(cx);
+        } else {
+            cx.draw_text(
+                "No characters. Click New to add one.",
+                Point::new(props.origin.x + spacing::MD, props.origin.y + 32.0),
+                colors::TEXT_MUTED,
+                font_size::MD,
+            );
+        }
+
+        for button in self.buttons(bounds) {
+            button.paint(cx);
+        }
+
+        self.paint_status(cx);
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        match event {
+            InputEvent::KeyDown { key, modifiers } => match key {
+                KeyCode::S if modifiers.ctrl => {
+                    self.save();
+                    self.dirty = true;
+                    true
+                }
+                KeyCode::Up => {
+                    self.select_character(self.selected.saturating_sub(1));
+                    true
+                }
+                KeyCode::Down => {
+                    self.select_character(self.selected.saturating_add(1));
+                    true
+                }
+                KeyCode::Left => {
+                    self.cycle_preview_position(false);
+                    true
+                }
+                KeyCode::Right => {
+                    self.cycle_preview_position(true);
+                    true
+                }
+                _ => false,
+            },
+            InputEvent::MouseDown { position, .. } => {
+                if let Some(action) = clicked_action(self.buttons(bounds), *position) {
+                    self.apply_action(action);
+                    return true;
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        let _ = delta;
+        let was_dirty = self.dirty;
+        self.dirty = false;
+        was_dirty
+    }
+
+    fn load_pending_background_texture(&mut self, renderer: &mut Renderer) -> bool {
+        let Some(path) = self.preview_sprite_path().map(str::to_string) else {
+            return false;
+        };
+        if self.textures.contains_key(&path) || self.failed_textures.contains(&path) {
+            return false;
+        }
+
+        let full_path = self.document.resolve_sprite_path(&path);
+        match renderer.load_texture_from_path(&full_path) {
+            Ok(id) => {
+                let size = renderer.get_texture_size(id).unwrap_or((1, 1));
+                self.textures.insert(path, PreviewTexture { id, size });
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load sprite '{}': {}", full_path.display(), e);
+                self.failed_textures.insert(path);
+            }
+        }
+        self.dirty = true;
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_bounds() -> Bounds {
+        Bounds {
+            origin: Point::new(0.0, 0.0),
+            size: Size::new(1600.0, 900.0),
+        }
+    }
+
+    fn editor_with_character() -> CharacterEditorElement {
+        let mut doc = CharacterManifestDocument::new("manifest.ron", "assets");
+        doc.add_character("alice", "Alice").unwrap();
+        doc.set_expression(0, "normal", "characters/alice/normal.png")
+            .unwrap();
+        doc.set_expression(0, "happy", "characters/alice/happy.png")
+            .unwrap();
+        CharacterEditorElement::new(doc)
+    }
+
+    #[test]
+    fn test_preview_uses_default_expression() {
+        let mut editor = editor_with_character();
+        assert_eq!(
+            editor.preview_sprite_path(),
+            Some("characters/alice/normal.png")
+        );
+
+        editor.apply_action(EditorAction::PreviewExpression("happy".to_string()));
+        assert_eq!(
+            editor.preview_sprite_path(),
+            Some("characters/alice/happy.png")
+        );
+    }
+
+    #[test]
+    fn test_color_adjustment_clamps() {
+        let mut editor = editor_with_character();
+        editor.apply_action(EditorAction::AdjustColor {
+            channel: 0,
+            delta: 100,
+        });
+        assert_eq!(editor.selected_def().unwrap().color, Some((255, 255, 255)));
+
+        editor.apply_action(EditorAction::AdjustColor {
+            channel: 1,
+            delta: -300,
+        });
+        assert_eq!(editor.selected_def().unwrap().color, Some((255, 0, 255)));
+
+        editor.apply_action(EditorAction::ClearColor);
+        assert_eq!(editor.selected_def().unwrap().color, None);
+    }
+
+    #[test]
+    fn test_scale_steps_are_rounded() {
+        let mut editor = editor_with_character();
+        for _ in 0..3 {
+            editor.apply_action(EditorAction::AdjustScale(
+                CharacterEditorElement::SCALE_STEP,
+            ));
+        }
+        let scale = editor.selected_def().unwrap().sprite_scale.unwrap();
+        assert!((scale - 1.15).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_sprite_bounds_follow_position_and_offset() {
+        let mut editor = editor_with_character();
+        let stage = Bounds {
+            origin: Point::new(0.0, 0.0),
+            size: Size::new(1280.0, 720.0),
+        };
+
+        let center = editor.sprite_bounds(stage, (300, 600)).unwrap();
+        assert_eq!(center.size.height, 600.0);
+        assert_eq!(center.origin.x, 640.0 - 150.0);
+        assert_eq!(center.origin.y, 120.0);
+
+        editor.apply_action(EditorAction::PreviewPosition(CharacterPosition::Left));
+        editor.apply_action(EditorAction::AdjustOffset { dx: 0.0, dy: 10.0 });
+        let left = editor.sprite_bounds(stage, (300, 600)).unwrap();
+        assert_eq!(left.origin.x, 320.0 - 150.0);
+        assert_eq!(left.origin.y, 130.0);
+    }
+
+    #[test]
+    fn test_click_selects_preview_position() {
+        let mut editor = editor_with_character();
+        let bounds = test_bounds();
+        let button = editor
+            .buttons(bounds)
+            .into_iter()
+            .find(|b| b.action == EditorAction::PreviewPosition(CharacterPosition::FarRight))
+            .unwrap();
+        let click = InputEvent::MouseDown {
+            position: Point::new(button.bounds.origin.x + 1.0, button.bounds.origin.y + 1.0),
+            button: narrative_gui::framework::input::MouseButton::Left,
+            modifiers: Default::default(),
+        };
+
+        assert!(editor.handle_event(&click, bounds));
+        assert_eq!(editor.preview_position, CharacterPosition::FarRight);
+    }
+
+    #[test]
+    fn test_add_and_remove_character() {
+        let mut editor = editor_with_character();
+        editor.apply_action(EditorAction::AddCharacter);
+        assert_eq!(editor.document.entries().len(), 2);
+        assert_eq!(editor.selected, 1);
+        assert_eq!(editor.selected_def().unwrap().id, "character_2");
+
+        editor.apply_action(EditorAction::RemoveCharacter);
+        assert_eq!(editor.document.entries().len(), 1);
+        assert_eq!(editor.selected, 0);
+    }
+}
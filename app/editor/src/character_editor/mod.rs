@@ -0,0 +1,12 @@
+//! Character manifest editor
+//!
+//! Lists the characters referenced by a `CharacterManifest`, lets the author
+//! add/remove expressions via image pickers, tweak name color, sprite offset
+//! and scale with a live preview at each `CharacterPosition`, and writes the
+//! manifest and definitions back to RON after validation.
+
+mod document;
+mod element;
+
+pub use document::CharacterManifestDocument;
+pub use element::CharacterEditorElement;
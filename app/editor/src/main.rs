@@ -2,10 +2,21 @@
 //!
 //! GUI authoring tool for creating visual novels without programming.
 //!
-//! ## Status: Placeholder
+//! ## Usage
 //!
-//! This crate is currently a placeholder for future development.
-//! The editor will be implemented in Phase 5 of the roadmap.
+//! ```text
+//! narrative-editor [ASSET_ROOT]
+//! ```
+//!
+//! `ASSET_ROOT` defaults to `assets`. Manifests are read from
+//! `ASSET_ROOT/manifests/` like the engine asset registry does.
+//!
+//! ## Panels
+//!
+//! - Character manifest editor (`manifests/characters.ron`)
+//! - UI theme editor (`manifests/ui_themes.ron`)
+//!
+//! Ctrl+Tab switches between panels.
 //!
 //! ## Planned Features
 //!
@@ -15,11 +26,70 @@
 //! - TOML export/import
 //! - Project management
 
-fn main() {
-    println!("Narrative Editor - Coming in Phase 5");
-    println!("This is a placeholder. The editor is not yet implemented.");
-    println!("\nFor now, please:");
-    println!("  1. Edit TOML files in assets/scenarios/");
-    println!("  2. Run 'cargo run --bin narrative-game' to test");
-    println!("  3. Use 'cargo run --bin scenario-validator' to validate");
+mod character_editor;
+mod root;
+mod theme_editor;
+mod widgets;
+
+use character_editor::{CharacterEditorElement, CharacterManifestDocument};
+use narrative_gui::framework::{App, PresentMode, WindowOptions};
+use root::EditorRootElement;
+use std::path::{Path, PathBuf};
+use theme_editor::{ThemeEditorElement, UiThemeDocument};
+
+/// Default asset root directory
+const DEFAULT_ASSET_ROOT: &str = "assets";
+/// Character manifest path relative to the asset root
+const CHARACTERS_MANIFEST: &str = "manifests/characters.ron";
+/// UI theme manifest path relative to the asset root
+const UI_THEMES_MANIFEST: &str = "manifests/ui_themes.ron";
+
+fn main() -> anyhow::Result<()> {
+    // Initialize logging
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    let asset_root = PathBuf::from(
+        std::env::args()
+            .nth(1)
+            .unwrap_or_else(|| DEFAULT_ASSET_ROOT.to_string()),
+    );
+    // Theme file paths are relative to the project root (e.g. "assets/ui/...")
+    let project_root = asset_root
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf();
+
+    tracing::info!("Starting Narrative Editor ({})", asset_root.display());
+    let characters =
+        CharacterManifestDocument::open_or_new(asset_root.join(CHARACTERS_MANIFEST), &asset_root)?;
+    let themes = UiThemeDocument::open_or_new(asset_root.join(UI_THEMES_MANIFEST), project_root)?;
+
+    App::new(WindowOptions {
+        title: "Narrative Editor".to_string(),
+        width: 1600,
+        height: 900,
+        resizable: true,
+        present_mode: PresentMode::VSync,
+        target_fps: 60,
+        ..Default::default()
+    })
+    .with_root(move || {
+        Box::new(
+            EditorRootElement::new()
+                .with_panel(
+                    "Characters",
+                    Box::new(CharacterEditorElement::new(characters)),
+                )
+                .with_panel("UI Theme", Box::new(ThemeEditorElement::new(themes))),
+        )
+    })
+    .run()?;
+
+    Ok(())
 }
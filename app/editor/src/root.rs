@@ -0,0 +1,195 @@
+//! Editor root element
+//!
+//! Hosts the editor panels behind a tab bar. Only the active panel receives
+//! input, ticks and texture loading; Ctrl+Tab cycles through the panels.
+
+use crate::widgets::{BUTTON_HEIGHT, EditorButton, clicked_action};
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::renderer::Renderer;
+use narrative_gui::theme::{colors, spacing};
+use narrative_gui::{Point, Size};
+use std::any::Any;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// A named editor panel
+struct Panel {
+    title: &'static str,
+    element: Box<dyn Element>,
+}
+
+/// Root element holding all editor panels
+pub struct EditorRootElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Panels in tab order
+    panels: Vec<Panel>,
+    /// Active panel index
+    active: usize,
+    /// Dirty flag to track if rendering needs update
+    dirty: bool,
+}
+
+impl EditorRootElement {
+    /// Tab bar height
+    const TAB_BAR_HEIGHT: f32 = 40.0;
+    /// Tab width
+    const TAB_WIDTH: f32 = 160.0;
+
+    /// Create an empty editor root
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            panels: Vec::new(),
+            active: 0,
+            dirty: true,
+        }
+    }
+
+    /// Add a panel
+    pub fn with_panel(mut self, title: &'static str, element: Box<dyn Element>) -> Self {
+        self.panels.push(Panel { title, element });
+        self
+    }
+
+    fn content_bounds(bounds: Bounds) -> Bounds {
+        Bounds {
+            origin: Point::new(bounds.origin.x, bounds.origin.y + Self::TAB_BAR_HEIGHT),
+            size: Size::new(
+                bounds.size.width,
+                (bounds.size.height - Self::TAB_BAR_HEIGHT).max(0.0),
+            ),
+        }
+    }
+
+    fn tabs(&self, bounds: Bounds) -> Vec<EditorButton<usize>> {
+        let y = bounds.origin.y + (Self::TAB_BAR_HEIGHT - BUTTON_HEIGHT) / 2.0;
+        self.panels
+            .iter()
+            .enumerate()
+            .map(|(i, panel)| {
+                EditorButton::new(
+                    bounds.origin.x + spacing::SM + i as f32 * (Self::TAB_WIDTH + spacing::SM),
+                    y,
+                    Self::TAB_WIDTH,
+                    panel.title,
+                    i,
+                )
+                .active(i == self.active)
+            })
+            .collect()
+    }
+
+    fn activate(&mut self, index: usize) {
+        if index < self.panels.len() && index != self.active {
+            self.active = index;
+            self.dirty = true;
+        }
+    }
+}
+
+impl Default for EditorRootElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for EditorRootElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        taffy::Style {
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let bounds = cx.bounds;
+        let tab_bar = Bounds {
+            origin: bounds.origin,
+            size: Size::new(bounds.size.width, Self::TAB_BAR_HEIGHT),
+        };
+        cx.fill_rect(tab_bar, colors::BG_DARKEST);
+        for tab in self.tabs(bounds) {
+            tab.paint(cx);
+        }
+
+        if let Some(panel) = self.panels.get(self.active) {
+            let mut content_cx = PaintContext {
+                bounds: Self::content_bounds(bounds),
+                clip_bounds: cx.clip_bounds,
+                commands: &mut *cx.commands,
+            };
+            panel.element.paint(&mut content_cx);
+        }
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        match event {
+            InputEvent::KeyDown {
+                key: KeyCode::Tab,
+                modifiers,
+            } if modifiers.ctrl && !self.panels.is_empty() => {
+                self.activate(self.active.saturating_add(1) % self.panels.len());
+                return true;
+            }
+            InputEvent::MouseDown { position, .. } => {
+                if let Some(index) = clicked_action(self.tabs(bounds), *position) {
+                    self.activate(index);
+                    return true;
+                }
+            }
+            _ => {}
+        }
+
+        let content = Self::content_bounds(bounds);
+        self.panels
+            .get_mut(self.active)
+            .is_some_and(|panel| panel.element.handle_event(event, content))
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        let was_dirty = self.dirty;
+        self.dirty = false;
+        let panel_dirty = self
+            .panels
+            .get_mut(self.active)
+            .is_some_and(|panel| panel.element.tick(delta));
+        was_dirty || panel_dirty
+    }
+
+    fn load_pending_background_texture(&mut self, renderer: &mut Renderer) -> bool {
+        self.panels
+            .get_mut(self.active)
+            .is_some_and(|panel| panel.element.load_pending_background_texture(renderer))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,545 @@
+//! Editable UI theme manifest document
+
+use narrative_core::asset::{ColorPalette, ThemeFonts, ThemeSounds, UiThemeDef, UiThemeManifest};
+use std::path::{Path, PathBuf};
+
+/// Color slot in a theme palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteSlot {
+    TextPrimary,
+    TextSecondary,
+    Accent,
+    Background,
+}
+
+impl PaletteSlot {
+    /// All slots in display order
+    pub const ALL: [PaletteSlot; 4] = [
+        PaletteSlot::TextPrimary,
+        PaletteSlot::TextSecondary,
+        PaletteSlot::Accent,
+        PaletteSlot::Background,
+    ];
+
+    /// Display label
+    pub fn label(self) -> &'static str {
+        match self {
+            PaletteSlot::TextPrimary => "Text",
+            PaletteSlot::TextSecondary => "Text (sub)",
+            PaletteSlot::Accent => "Accent",
+            PaletteSlot::Background => "Background",
+        }
+    }
+
+    /// Read the slot from a palette
+    pub fn get(self, palette: &ColorPalette) -> (u8, u8, u8, u8) {
+        match self {
+            PaletteSlot::TextPrimary => palette.text_primary,
+            PaletteSlot::TextSecondary => palette.text_secondary,
+            PaletteSlot::Accent => palette.accent,
+            PaletteSlot::Background => palette.background,
+        }
+    }
+
+    fn get_mut(self, palette: &mut ColorPalette) -> &mut (u8, u8, u8, u8) {
+        match self {
+            PaletteSlot::TextPrimary => &mut palette.text_primary,
+            PaletteSlot::TextSecondary => &mut palette.text_secondary,
+            PaletteSlot::Accent => &mut palette.accent,
+            PaletteSlot::Background => &mut palette.background,
+        }
+    }
+}
+
+/// File reference in a theme (texture, font or sound)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetSlot {
+    DialogueBox,
+    ChoiceIdle,
+    ChoiceHover,
+    ChoiceDisabled,
+    DialogueFont,
+    SpeakerFont,
+    UiFont,
+    ClickSound,
+    HoverSound,
+    AdvanceSound,
+}
+
+impl AssetSlot {
+    /// All slots in display order
+    pub const ALL: [AssetSlot; 10] = [
+        AssetSlot::DialogueBox,
+        AssetSlot::ChoiceIdle,
+        AssetSlot::ChoiceHover,
+        AssetSlot::ChoiceDisabled,
+        AssetSlot::DialogueFont,
+        AssetSlot::SpeakerFont,
+        AssetSlot::UiFont,
+        AssetSlot::ClickSound,
+        AssetSlot::HoverSound,
+        AssetSlot::AdvanceSound,
+    ];
+
+    /// Display label
+    pub fn label(self) -> &'static str {
+        match self {
+            AssetSlot::DialogueBox => "Dialogue box",
+            AssetSlot::ChoiceIdle => "Choice idle",
+            AssetSlot::ChoiceHover => "Choice hover",
+            AssetSlot::ChoiceDisabled => "Choice disabled",
+            AssetSlot::DialogueFont => "Dialogue font",
+            AssetSlot::SpeakerFont => "Speaker font",
+            AssetSlot::UiFont => "UI font",
+            AssetSlot::ClickSound => "Click sound",
+            AssetSlot::HoverSound => "Hover sound",
+            AssetSlot::AdvanceSound => "Advance sound",
+        }
+    }
+
+    /// Whether the theme requires this file (required slots cannot be cleared)
+    pub fn is_required(self) -> bool {
+        matches!(
+            self,
+            AssetSlot::DialogueBox
+                | AssetSlot::ChoiceIdle
+                | AssetSlot::ChoiceHover
+                | AssetSlot::ChoiceDisabled
+        )
+    }
+
+    /// File extensions offered by the file picker
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            AssetSlot::DialogueBox
+            | AssetSlot::ChoiceIdle
+            | AssetSlot::ChoiceHover
+            | AssetSlot::ChoiceDisabled => &["png", "jpg", "jpeg", "webp"],
+            AssetSlot::DialogueFont | AssetSlot::SpeakerFont | AssetSlot::UiFont => {
+                &["ttf", "otf", "ttc"]
+            }
+            AssetSlot::ClickSound | AssetSlot::HoverSound | AssetSlot::AdvanceSound => {
+                &["ogg", "wav", "mp3", "flac"]
+            }
+        }
+    }
+
+    /// Read the slot from a theme
+    pub fn get(self, theme: &UiThemeDef) -> Option<&str> {
+        let fonts = theme.fonts.as_ref();
+        let sounds = theme.sounds.as_ref();
+        match self {
+            AssetSlot::DialogueBox => Some(theme.dialogue_box.default.as_str()),
+            AssetSlot::ChoiceIdle => Some(theme.choices.idle.as_str()),
+            AssetSlot::ChoiceHover => Some(theme.choices.hover.as_str()),
+            AssetSlot::ChoiceDisabled => Some(theme.choices.disabled.as_str()),
+            AssetSlot::DialogueFont => fonts.and_then(|f| f.dialogue.as_deref()),
+            AssetSlot::SpeakerFont => fonts.and_then(|f| f.speaker.as_deref()),
+            AssetSlot::UiFont => fonts.and_then(|f| f.ui.as_deref()),
+            AssetSlot::ClickSound => sounds.and_then(|s| s.click.as_deref()),
+            AssetSlot::HoverSound => sounds.and_then(|s| s.hover.as_deref()),
+            AssetSlot::AdvanceSound => sounds.and_then(|s| s.advance.as_deref()),
+        }
+    }
+
+    fn set(self, theme: &mut UiThemeDef, value: Option<String>) {
+        match self {
+            AssetSlot::DialogueBox => theme.dialogue_box.default = value.unwrap_or_default(),
+            AssetSlot::ChoiceIdle => theme.choices.idle = value.unwrap_or_default(),
+            AssetSlot::ChoiceHover => theme.choices.hover = value.unwrap_or_default(),
+            AssetSlot::ChoiceDisabled => theme.choices.disabled = value.unwrap_or_default(),
+            AssetSlot::DialogueFont | AssetSlot::SpeakerFont | AssetSlot::UiFont => {
+                let fonts = theme.fonts.get_or_insert_with(ThemeFonts::default);
+                match self {
+                    AssetSlot::DialogueFont => fonts.dialogue = value,
+                    AssetSlot::SpeakerFont => fonts.speaker = value,
+                    _ => fonts.ui = value,
+                }
+                if *fonts == ThemeFonts::default() {
+                    theme.fonts = None;
+                }
+            }
+            AssetSlot::ClickSound | AssetSlot::HoverSound | AssetSlot::AdvanceSound => {
+                let sounds = theme.sounds.get_or_insert_with(ThemeSounds::default);
+                match self {
+                    AssetSlot::ClickSound => sounds.click = value,
+                    AssetSlot::HoverSound => sounds.hover = value,
+                    _ => sounds.advance = value,
+                }
+                if *sounds == ThemeSounds::default() {
+                    theme.sounds = None;
+                }
+            }
+        }
+    }
+}
+
+/// Palette used when a theme does not define one (matches the game defaults)
+pub fn default_palette() -> ColorPalette {
+    ColorPalette {
+        text_primary: (255, 255, 255, 255),
+        text_secondary: (179, 179, 184, 255),
+        accent: (255, 230, 153, 255),
+        background: (0, 0, 0, 204),
+    }
+}
+
+/// In-memory editing state for a UI theme manifest
+#[derive(Debug)]
+pub struct UiThemeDocument {
+    /// Path to the theme manifest RON file
+    manifest_path: PathBuf,
+    /// Directory that theme file paths are relative to
+    project_root: PathBuf,
+    /// Manifest being edited
+    manifest: UiThemeManifest,
+    /// Whether the manifest has unsaved changes
+    dirty: bool,
+}
+
+impl UiThemeDocument {
+    /// Load a theme manifest
+    ///
+    /// The manifest is parsed without validation so that broken files can
+    /// still be opened and fixed in the editor.
+    pub fn load(
+        manifest_path: impl Into<PathBuf>,
+        project_root: impl Into<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        let manifest_path = manifest_path.into();
+        let content = std::fs::read_to_string(&manifest_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", manifest_path.display(), e))?;
+        let manifest: UiThemeManifest = ron::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Failed to parse '{}': {}", manifest_path.display(), e))?;
+        Ok(Self {
+            manifest_path,
+            project_root: project_root.into(),
+            manifest,
+            dirty: false,
+        })
+    }
+
+    /// Load the manifest if it exists, otherwise start an empty document
+    pub fn open_or_new(
+        manifest_path: impl Into<PathBuf>,
+        project_root: impl Into<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        let manifest_path = manifest_path.into();
+        if manifest_path.exists() {
+            Self::load(manifest_path, project_root)
+        } else {
+            Ok(Self {
+                manifest_path,
+                project_root: project_root.into(),
+                manifest: UiThemeManifest::new(),
+                dirty: false,
+            })
+        }
+    }
+
+    /// Path to the manifest file
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+
+    /// Directory that theme file paths are relative to
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    /// Whether the manifest has unsaved changes
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Theme IDs in alphabetical order
+    pub fn theme_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.manifest.themes.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Get a theme by ID
+    pub fn theme(&self, id: &str) -> Option<&UiThemeDef> {
+        self.manifest.get(id)
+    }
+
+    /// Palette of a theme, falling back to the default palette
+    pub fn palette(&self, id: &str) -> ColorPalette {
+        self.theme(id)
+            .and_then(|theme| theme.colors.clone())
+            .unwrap_or_else(default_palette)
+    }
+
+    /// Resolve a theme file path to a filesystem path
+    pub fn resolve_path(&self, path: &str) -> PathBuf {
+        self.project_root.join(path)
+    }
+
+    /// Set one RGBA component of a palette color
+    ///
+    /// Creates the palette from the defaults if the theme has none yet.
+    pub fn set_color_channel(
+        &mut self,
+        id: &str,
+        slot: PaletteSlot,
+        channel: usize,
+        value: u8,
+    ) -> anyhow::Result<()> {
+        let theme = self.theme_mut(id)?;
+        let palette = theme.colors.get_or_insert_with(default_palette);
+        let color = slot.get_mut(palette);
+        match channel {
+            0 => color.0 = value,
+            1 => color.1 = value,
+            2 => color.2 = value,
+            3 => color.3 = value,
+            _ => anyhow::bail!("Invalid color channel {}", channel),
+        }
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Set or clear a theme file reference
+    pub fn set_asset(
+        &mut self,
+        id: &str,
+        slot: AssetSlot,
+        path: Option<String>,
+    ) -> anyhow::Result<()> {
+        if path.is_none() && slot.is_required() {
+            anyhow::bail!("{} cannot be cleared", slot.label());
+        }
+        let theme = self.theme_mut(id)?;
+        slot.set(theme, path);
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Set a theme file reference from a picked file
+    ///
+    /// The path is stored relative to the project root.
+    pub fn set_asset_from_file(
+        &mut self,
+        id: &str,
+        slot: AssetSlot,
+        file: &Path,
+    ) -> anyhow::Result<String> {
+        let relative = self.relative_path(file)?;
+        self.set_asset(id, slot, Some(relative.clone()))?;
+        Ok(relative)
+    }
+
+    /// Validate the manifest and check that all referenced files exist
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Err(e) = self.manifest.validate() {
+            problems.push(e.to_string());
+        }
+        for id in self.theme_ids() {
+            let Some(theme) = self.theme(&id) else {
+                continue;
+            };
+            for slot in AssetSlot::ALL {
+                if let Some(path) = slot.get(theme)
+                    && !path.is_empty()
+                    && !self.resolve_path(path).is_file()
+                {
+                    problems.push(format!(
+                        "Theme '{}' {} not found: {}",
+                        id,
+                        slot.label().to_lowercase(),
+                        path
+                    ));
+                }
+            }
+        }
+        problems
+    }
+
+    /// Validate and write the manifest to disk
+    pub fn save(&mut self) -> anyhow::Result<()> {
+        let problems = self.validate();
+        if !problems.is_empty() {
+            anyhow::bail!("Validation failed:\n{}", problems.join("\n"));
+        }
+        self.manifest.save_to_file(&self.manifest_path)?;
+        self.dirty = false;
+        tracing::info!("Saved UI themes to {}", self.manifest_path.display());
+        Ok(())
+    }
+
+    /// Convert a file path into a path relative to the project root
+    fn relative_path(&self, file: &Path) -> anyhow::Result<String> {
+        let relative = if file.is_absolute() {
+            let root = self
+                .project_root
+                .canonicalize()
+                .unwrap_or_else(|_| self.project_root.clone());
+            let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+            file.strip_prefix(&root)
+                .map(Path::to_path_buf)
+                .map_err(|_| {
+                    anyhow::anyhow!(
+                        "'{}' is outside the project directory '{}'",
+                        file.display(),
+                        self.project_root.display()
+                    )
+                })?
+        } else {
+            file.to_path_buf()
+        };
+
+        // Asset paths always use forward slashes regardless of platform
+        let parts: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        Ok(parts.join("/"))
+    }
+
+    fn theme_mut(&mut self, id: &str) -> anyhow::Result<&mut UiThemeDef> {
+        self.manifest
+            .themes
+            .get_mut(id)
+            .ok_or_else(|| anyhow::anyhow!("Theme '{}' not found", id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::asset::{ButtonAssets, ChoiceAssets, DialogueBoxAssets};
+    use std::collections::HashMap;
+
+    const THEME_FILES: [&str; 5] = [
+        "ui/box.png",
+        "ui/choice_idle.png",
+        "ui/choice_hover.png",
+        "ui/choice_disabled.png",
+        "ui/button.png",
+    ];
+
+    fn write_theme(root: &Path) -> PathBuf {
+        for file in THEME_FILES {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"png").unwrap();
+        }
+        let button = "ui/button.png".to_string();
+        let theme = UiThemeDef {
+            id: "light".to_string(),
+            name: "Light".to_string(),
+            dialogue_box: DialogueBoxAssets {
+                default: "ui/box.png".to_string(),
+                variants: HashMap::new(),
+            },
+            buttons: ButtonAssets {
+                continue_idle: button.clone(),
+                continue_hover: button.clone(),
+                history_idle: button.clone(),
+                history_hover: button.clone(),
+                skip_idle: button.clone(),
+                skip_hover: button.clone(),
+                options_idle: button.clone(),
+                options_hover: button,
+            },
+            choices: ChoiceAssets {
+                idle: "ui/choice_idle.png".to_string(),
+                hover: "ui/choice_hover.png".to_string(),
+                disabled: "ui/choice_disabled.png".to_string(),
+            },
+            colors: None,
+            fonts: None,
+            sounds: None,
+            quick_menu: None,
+            subtitles: None,
+        };
+        let path = root.join("manifests/ui_themes.ron");
+        UiThemeManifest::new()
+            .add_theme(theme)
+            .save_to_file(&path)
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_set_color_channel_creates_palette() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = UiThemeDocument::load(write_theme(dir.path()), dir.path()).unwrap();
+        assert!(doc.theme("light").unwrap().colors.is_none());
+
+        doc.set_color_channel("light", PaletteSlot::Accent, 0, 10)
+            .unwrap();
+        let palette = doc.theme("light").unwrap().colors.clone().unwrap();
+        assert_eq!(palette.accent.0, 10);
+        assert_eq!(palette.text_primary, default_palette().text_primary);
+        assert!(doc.is_dirty());
+        assert!(
+            doc.set_color_channel("light", PaletteSlot::Accent, 4, 0)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_optional_assets_are_cleared() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = UiThemeDocument::load(write_theme(dir.path()), dir.path()).unwrap();
+
+        doc.set_asset("light", AssetSlot::ClickSound, Some("se/click.ogg".into()))
+            .unwrap();
+        assert_eq!(
+            AssetSlot::ClickSound.get(doc.theme("light").unwrap()),
+            Some("se/click.ogg")
+        );
+
+        doc.set_asset("light", AssetSlot::ClickSound, None).unwrap();
+        assert!(doc.theme("light").unwrap().sounds.is_none());
+        assert!(
+            doc.set_asset("light", AssetSlot::DialogueBox, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut doc = UiThemeDocument::load(write_theme(dir.path()), dir.path()).unwrap();
+        assert!(doc.validate().is_empty());
+
+        doc.set_asset(
+            "light",
+            AssetSlot::DialogueFont,
+            Some("fonts/missing.ttf".into()),
+        )
+        .unwrap();
+        let problems = doc.validate();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("missing.ttf"));
+        assert!(doc.save().is_err());
+    }
+
+    #[test]
+    fn test_save_and_reload_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_theme(dir.path());
+        let mut doc = UiThemeDocument::load(&path, dir.path()).unwrap();
+
+        doc.set_color_channel("light", PaletteSlot::Background, 3, 128)
+            .unwrap();
+        let relative = doc
+            .set_asset_from_file(
+                "light",
+                AssetSlot::ChoiceHover,
+                &dir.path().join("ui/box.png"),
+            )
+            .unwrap();
+        assert_eq!(relative, "ui/box.png");
+        doc.save().unwrap();
+        assert!(!doc.is_dirty());
+
+        let loaded = UiThemeDocument::load(&path, dir.path()).unwrap();
+        assert_eq!(loaded.palette("light").background.3, 128);
+        assert_eq!(loaded.theme("light").unwrap().choices.hover, "ui/box.png");
+    }
+}
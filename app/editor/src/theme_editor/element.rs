@@ -0,0 +1,745 @@
+//! UI theme editor element
+//!
+//! Layout:
+//! - Left: theme list
+//! - Center: palette color steppers and file pickers (textures, fonts, sounds)
+//! - Right: dialogue box and choice preview drawn by the game components
+//! - Bottom: status line with validation results
+//!
+//! Keyboard shortcuts:
+//! - Up/Down: select theme
+//! - Ctrl+S: validate and save
+
+use super::document::{AssetSlot, PaletteSlot, UiThemeDocument};
+use crate::widgets::{
+    BUTTON_HEIGHT, EditorButton, StatusMessage, clicked_action, rgba8, step_channel,
+};
+use narrative_core::asset::ColorPalette;
+use narrative_core::config::DialogueBoxConfig;
+use narrative_game::components::{ChoiceMenuElement, ChoiceMenuStyle, DialogueBoxElement};
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::renderer::Renderer;
+use narrative_gui::theme::{colors, font_size, spacing};
+use narrative_gui::{Point, Size};
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Sample speaker shown in the preview
+const PREVIEW_SPEAKER: &str = "Alice";
+/// Sample dialogue shown in the preview
+const PREVIEW_TEXT: &str = "The quick brown fox jumps over the lazy dog.";
+/// Sample choices shown in the preview
+const PREVIEW_CHOICES: [&str; 3] = ["Go to the library", "Stay home", "Call a friend"];
+
+/// Editor action triggered by a button
+#[derive(Debug, Clone, PartialEq)]
+enum ThemeAction {
+    SelectTheme(usize),
+    AdjustColor {
+        slot: PaletteSlot,
+        channel: usize,
+        delta: i16,
+    },
+    PickAsset(AssetSlot),
+    ClearAsset(AssetSlot),
+    Save,
+}
+
+/// Editor panel for a `UiThemeManifest`
+pub struct ThemeEditorElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Document being edited
+    document: UiThemeDocument,
+    /// Selected theme index (into the sorted theme IDs)
+    selected: usize,
+    /// Dialogue box preview
+    dialogue_preview: DialogueBoxElement,
+    /// Choice menu preview
+    choice_preview: ChoiceMenuElement,
+    /// Loaded textures keyed by path
+    textures: HashMap<String, u64>,
+    /// Paths that failed to load (not retried)
+    failed_textures: HashSet<String>,
+    /// Status line message
+    status: StatusMessage,
+    /// Dirty flag to track if rendering needs update
+    dirty: bool,
+}
+
+impl ThemeEditorElement {
+    /// Header bar height
+    const HEADER_HEIGHT: f32 = 56.0;
+    /// Status bar height
+    const STATUS_HEIGHT: f32 = 72.0;
+    /// Theme list width
+    const LIST_WIDTH: f32 = 200.0;
+    /// Properties panel width
+    const PROPERTIES_WIDTH: f32 = 600.0;
+    /// Row height
+    const ROW_HEIGHT: f32 = 36.0;
+    /// Stepper button width
+    const STEP_WIDTH: f32 = 24.0;
+    /// Width of one color channel stepper (minus, value, plus, gap)
+    const CHANNEL_WIDTH: f32 = 100.0;
+    /// Color channel step
+    const COLOR_STEP: i16 = 5;
+    /// Dialogue box height in the preview
+    const PREVIEW_BOX_HEIGHT: f32 = 160.0;
+
+    /// Create a new editor for the given document
+    pub fn new(document: UiThemeDocument) -> Self {
+        let mut editor = Self {
+            id: ElementId::new(),
+            layout_node: None,
+            document,
+            selected: 0,
+            dialogue_preview: DialogueBoxElement::new(DialogueBoxConfig::default()),
+            choice_preview: ChoiceMenuElement::new(PREVIEW_CHOICES.to_vec()),
+            textures: HashMap::new(),
+            failed_textures: HashSet::new(),
+            status: StatusMessage::Info("Ctrl+S to save.".to_string()),
+            dirty: true,
+        };
+        editor.refresh_preview();
+        editor
+    }
+
+    fn selected_id(&self) -> Option<String> {
+        self.document.theme_ids().get(self.selected).cloned()
+    }
+
+    /// Rebuild the preview components from the selected theme palette
+    fn refresh_preview(&mut self) {
+        let Some(id) = self.selected_id() else {
+            return;
+        };
+        let palette = self.document.palette(&id);
+        let config = DialogueBoxConfig {
+            height: Self::PREVIEW_BOX_HEIGHT,
+            ..DialogueBoxConfig::default()
+        }
+        .with_palette(&palette);
+        self.dialogue_preview = DialogueBoxElement::new(config)
+            .with_speaker(PREVIEW_SPEAKER)
+            .with_text(PREVIEW_TEXT)
+            .with_visible_chars(PREVIEW_TEXT.chars().count())
+            .with_animations_enabled(false);
+
+        let mut choices = ChoiceMenuElement::new(PREVIEW_CHOICES.to_vec())
+            .with_style(choice_style(&palette))
+            .with_animations_enabled(false);
+        choices.set_selected_index(self.choice_preview.selected_index());
+        self.choice_preview = choices;
+        self.dirty = true;
+    }
+
+    fn select_theme(&mut self, index: usize) {
+        if index < self.document.theme_ids().len() {
+            self.selected = index;
+            self.refresh_preview();
+        }
+    }
+
+    fn report<T>(&mut self, result: anyhow::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.status = StatusMessage::Error(vec![e.to_string()]);
+                None
+            }
+        }
+    }
+
+    fn save(&mut self) {
+        let problems = self.document.validate();
+        if !problems.is_empty() {
+            self.status = StatusMessage::Error(problems);
+            return;
+        }
+        match self.document.save() {
+            Ok(()) => {
+                self.status = StatusMessage::Success(format!(
+                    "Saved {}",
+                    self.document.manifest_path().display()
+                ));
+            }
+            Err(e) => {
+                tracing::error!("Failed to save UI themes: {}", e);
+                self.status = StatusMessage::Error(vec![e.to_string()]);
+            }
+        }
+    }
+
+    fn pick_asset(&mut self, id: &str, slot: AssetSlot) {
+        let file = rfd::FileDialog::new()
+            .set_title(format!("Select {}", slot.label().to_lowercase()))
+            .add_filter(slot.label(), slot.extensions())
+            .set_directory(self.document.project_root())
+            .pick_file();
+
+        if let Some(file) = file {
+            let result = self.document.set_asset_from_file(id, slot, &file);
+            if let Some(path) = self.report(result) {
+                self.status = StatusMessage::Info(format!("{} set to {}", slot.label(), path));
+            }
+        }
+    }
+
+    fn apply_action(&mut self, action: ThemeAction) {
+        match (action, self.selected_id()) {
+            (ThemeAction::SelectTheme(index), _) => self.select_theme(index),
+            (ThemeAction::Save, _) => self.save(),
+            (
+                ThemeAction::AdjustColor {
+                    slot,
+                    channel,
+                    delta,
+                },
+                Some(id),
+            ) => {
+                let rgba = slot.get(&self.document.palette(&id));
+                let current = [rgba.0, rgba.1, rgba.2, rgba.3]
+                    .get(channel)
+                    .copied()
+                    .unwrap_or(0);
+                let result = self.document.set_color_channel(
+                    &id,
+                    slot,
+                    channel,
+                    step_channel(current, delta),
+                );
+                self.report(result);
+            }
+            (ThemeAction::PickAsset(slot), Some(id)) => self.pick_asset(&id, slot),
+            (ThemeAction::ClearAsset(slot), Some(id)) => {
+                let result = self.document.set_asset(&id, slot, None);
+                self.report(result);
+            }
+            _ => {}
+        }
+        self.refresh_preview();
+        self.dirty = true;
+    }
+
+    fn list_bounds(bounds: Bounds) -> Bounds {
+        Bounds {
+            origin: Point::new(bounds.origin.x, bounds.origin.y + Self::HEADER_HEIGHT),
+            size: Size::new(
+                Self::LIST_WIDTH,
+                (bounds.size.height - Self::HEADER_HEIGHT - Self::STATUS_HEIGHT).max(0.0),
+            ),
+        }
+    }
+
+    fn properties_bounds(bounds: Bounds) -> Bounds {
+        let list = Self::list_bounds(bounds);
+        Bounds {
+            origin: Point::new(list.origin.x + list.size.width, list.origin.y),
+            size: Size::new(Self::PROPERTIES_WIDTH, list.size.height),
+        }
+    }
+
+    /// Stage area of the preview, fitted to 16:9
+    fn stage_bounds(bounds: Bounds) -> Bounds {
+        let props = Self::properties_bounds(bounds);
+        let x = props.origin.x + props.size.width + spacing::MD;
+        let avail_w = (bounds.origin.x + bounds.size.width - x - spacing::MD).max(0.0);
+        let avail_h = (props.size.height - spacing::MD * 2.0).max(0.0);
+        let aspect = 16.0 / 9.0;
+        let (w, h) = if avail_w / aspect <= avail_h {
+            (avail_w, avail_w / aspect)
+        } else {
+            (avail_h * aspect, avail_h)
+        };
+        Bounds {
+            origin: Point::new(x, props.origin.y + spacing::MD),
+            size: Size::new(w, h),
+        }
+    }
+
+    /// Dialogue box area at the bottom of the stage
+    fn dialogue_bounds(stage: Bounds) -> Bounds {
+        let height = Self::PREVIEW_BOX_HEIGHT.min(stage.size.height * 0.4);
+        Bounds {
+            origin: Point::new(
+                stage.origin.x + spacing::MD,
+                stage.origin.y + stage.size.height - height - spacing::MD,
+            ),
+            size: Size::new((stage.size.width - spacing::MD * 2.0).max(0.0), height),
+        }
+    }
+
+    /// Choice menu area above the dialogue box
+    fn choices_bounds(stage: Bounds) -> Bounds {
+        let dialogue = Self::dialogue_bounds(stage);
+        Bounds {
+            origin: stage.origin,
+            size: Size::new(
+                stage.size.width,
+                (dialogue.origin.y - stage.origin.y).max(0.0),
+            ),
+        }
+    }
+
+    fn row_y(props: Bounds, row: usize) -> f32 {
+        props.origin.y + spacing::MD + row as f32 * Self::ROW_HEIGHT
+    }
+
+    fn channel_x(props: Bounds, channel: usize) -> f32 {
+        props.origin.x + 180.0 + channel as f32 * Self::CHANNEL_WIDTH
+    }
+
+    /// First row of the file section
+    fn asset_row(index: usize) -> usize {
+        PaletteSlot::ALL.len() + 2 + index
+    }
+
+    /// Compute all buttons for the current state and layout
+    fn buttons(&self, bounds: Bounds) -> Vec<EditorButton<ThemeAction>> {
+        let mut buttons = Vec::new();
+
+        let save_x = bounds.origin.x + bounds.size.width - 96.0 - spacing::MD;
+        let header_y = bounds.origin.y + (Self::HEADER_HEIGHT - BUTTON_HEIGHT) / 2.0;
+        buttons.push(
+            EditorButton::new(save_x, header_y, 96.0, "Save", ThemeAction::Save)
+                .active(self.document.is_dirty()),
+        );
+
+        let list = Self::list_bounds(bounds);
+        for (i, id) in self.document.theme_ids().into_iter().enumerate() {
+            buttons.push(
+                EditorButton::new(
+                    list.origin.x + spacing::SM,
+                    list.origin.y + spacing::SM + i as f32 * Self::ROW_HEIGHT,
+                    list.size.width - spacing::SM * 2.0,
+                    id,
+                    ThemeAction::SelectTheme(i),
+                )
+                .active(i == self.selected),
+            );
+        }
+
+        let Some(id) = self.selected_id() else {
+            return buttons;
+        };
+        let Some(theme) = self.document.theme(&id) else {
+            return buttons;
+        };
+
+        let props = Self::properties_bounds(bounds);
+        for (row, slot) in PaletteSlot::ALL.iter().enumerate() {
+            let y = Self::row_y(props, row + 1);
+            for channel in 0..4 {
+                let x = Self::channel_x(props, channel);
+                buttons.push(EditorButton::new(
+                    x,
+                    y,
+                    Self::STEP_WIDTH,
+                    "-",
+                    ThemeAction::AdjustColor {
+                        slot: *slot,
+                        channel,
+                        delta: -Self::COLOR_STEP,
+                    },
+                ));
+                buttons.push(EditorButton::new(
+                    x + Self::STEP_WIDTH + 40.0,
+                    y,
+                    Self::STEP_WIDTH,
+                    "+",
+                    ThemeAction::AdjustColor {
+                        slot: *slot,
+                        channel,
+                        delta: Self::COLOR_STEP,
+                    },
+                ));
+            }
+        }
+
+        let right = props.origin.x + props.size.width - spacing::MD;
+        for (i, slot) in AssetSlot::ALL.iter().enumerate() {
+            let y = Self::row_y(props, Self::asset_row(i));
+            buttons.push(EditorButton::new(
+                right - 140.0,
+                y,
+                72.0,
+                "Browse",
+                ThemeAction::PickAsset(*slot),
+            ));
+            if !slot.is_required() && slot.get(theme).is_some() {
+                buttons.push(EditorButton::new(
+                    right - 60.0,
+                    y,
+                    60.0,
+                    "Clear",
+                    ThemeAction::ClearAsset(*slot),
+                ));
+            }
+        }
+
+        buttons
+    }
+
+    fn paint_properties(&self, cx: &mut PaintContext, id: &str) {
+        let props = Self::properties_bounds(cx.bounds);
+        let Some(theme) = self.document.theme(id) else {
+            return;
+        };
+        let palette = self.document.palette(id);
+        let text_y =
+            |row: usize| Self::row_y(props, row) + (BUTTON_HEIGHT + font_size::MD * 0.8) / 2.0;
+        let label_x = props.origin.x + spacing::MD;
+
+        cx.draw_text(
+            &format!("Colors - {}", theme.name),
+            Point::new(label_x, text_y(0)),
+            colors::TEXT_PRIMARY,
+            font_size::MD,
+        );
+        for (channel, name) in ["R", "G", "B", "A"].iter().enumerate() {
+            cx.draw_text(
+                name,
+                Point::new(
+                    Self::channel_x(props, channel) + Self::STEP_WIDTH + 14.0,
+                    text_y(0),
+                ),
+                colors::TEXT_MUTED,
+                font_size::MD,
+            );
+        }
+
+        for (row, slot) in PaletteSlot::ALL.iter().enumerate() {
+            let row = row + 1;
+            let rgba = slot.get(&palette);
+            cx.draw_text(
+                slot.label(),
+                Point::new(label_x, text_y(row)),
+                colors::TEXT_SECONDARY,
+                font_size::MD,
+            );
+            let swatch = Bounds {
+                origin: Point::new(props.origin.x + 124.0, Self::row_y(props, row)),
+                size: Size::new(40.0, BUTTON_HEIGHT),
+            };
+            cx.fill_rect(swatch, colors::BG_DARKEST);
+            cx.fill_rect(swatch, rgba8(rgba.0, rgba.1, rgba.2, rgba.3));
+            cx.stroke_rect(swatch, colors::BORDER_LIGHT, 1.0);
+            for (channel, value) in [rgba.0, rgba.1, rgba.2, rgba.3].iter().enumerate() {
+                cx.draw_text(
+                    &value.to_string(),
+                    Point::new(
+                        Self::channel_x(props, channel) + Self::STEP_WIDTH + 6.0,
+                        text_y(row),
+                    ),
+                    colors::TEXT_PRIMARY,
+                    font_size::MD,
+                );
+            }
+        }
+
+        let files_row = PaletteSlot::ALL.len() + 1;
+        cx.draw_text(
+            "Files",
+            Point::new(label_x, text_y(files_row)),
+            colors::TEXT_PRIMARY,
+            font_size::MD,
+        );
+        for (i, slot) in AssetSlot::ALL.iter().enumerate() {
+            let row = Self::asset_row(i);
+            cx.draw_text(
+                slot.label(),
+                Point::new(label_x, text_y(row)),
+                colors::TEXT_SECONDARY,
+                font_size::SM,
+            );
+            let (path, color) = match slot.get(theme) {
+                Some(path) => (ellipsize_start(path, 32), colors::TEXT_PRIMARY),
+                None => ("(default)".to_string(), colors::TEXT_MUTED),
+            };
+            cx.draw_text(
+                &path,
+                Point::new(props.origin.x + 140.0, text_y(row)),
+                color,
+                font_size::SM,
+            );
+        }
+    }
+
+    fn paint_preview(&self, cx: &mut PaintContext, id: &str) {
+        let stage = Self::stage_bounds(cx.bounds);
+        cx.fill_rect(stage, colors::BG_DARKEST);
+        cx.stroke_rect(stage, colors::BORDER, 1.0);
+
+        let dialogue = Self::dialogue_bounds(stage);
+        if let Some(texture_id) = self
+            .document
+            .theme(id)
+            .and_then(|theme| self.textures.get(&theme.dialogue_box.default))
+        {
+            cx.draw_texture(*texture_id, dialogue, 1.0);
+        }
+
+        // Paint the game components into sub-regions of the stage
+        let mut choices_cx = PaintContext {
+            bounds: Self::choices_bounds(stage),
+            clip_bounds: Some(stage),
+            commands: &mut *cx.commands,
+        };
+        self.choice_preview.paint(&mut choices_cx);
+
+        let mut dialogue_cx = PaintContext {
+            bounds: dialogue,
+            clip_bounds: Some(stage),
+            commands: &mut *cx.commands,
+        };
+        self.dialogue_preview.paint(&mut dialogue_cx);
+    }
+}
+
+/// Choice button colors derived from a theme palette
+fn choice_style(palette: &ColorPalette) -> ChoiceMenuStyle {
+    let (r, g, b, a) = palette.background;
+    let (tr, tg, tb, ta) = palette.text_primary;
+    let (ar, ag, ab, aa) = palette.accent;
+    let (sr, sg, sb, sa) = palette.text_secondary;
+    ChoiceMenuStyle {
+        selected_background: rgba8(ar, ag, ab, aa),
+        background: rgba8(r, g, b, a),
+        selected_text: rgba8(r, g, b, 255),
+        text: rgba8(tr, tg, tb, ta),
+        border: rgba8(sr, sg, sb, sa),
+        disabled_background: rgba8(r, g, b, a / 2),
+        disabled_text: rgba8(sr, sg, sb, sa / 2),
+    }
+}
+
+/// Shorten a path from the left so the file name stays visible
+fn ellipsize_start(text: &str, max_chars: usize) -> String {
+    let count = text.chars().count();
+    if count <= max_chars {
+        return text.to_string();
+    }
+    let tail: String = text
+        .chars()
+        .skip(count.saturating_sub(max_chars.saturating_sub(3)))
+        .collect();
+    format!("...{}", tail)
+}
+
+impl Element for ThemeEditorElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        taffy::Style {
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let bounds = cx.bounds;
+        cx.fill_rect(bounds, colors::BG_DARK);
+
+        let header = Bounds {
+            origin: bounds.origin,
+            size: Size::new(bounds.size.width, Self::HEADER_HEIGHT),
+        };
+        cx.fill_rect(header, colors::BG_PANEL);
+        let dirty_marker = if self.document.is_dirty() {
+            " (modified)"
+        } else {
+            ""
+        };
+        cx.draw_text(
+            &format!(
+                "UI Themes - {}{}",
+                self.document.manifest_path().display(),
+                dirty_marker
+            ),
+            Point::new(
+                bounds.origin.x + spacing::MD,
+                bounds.origin.y + (Self::HEADER_HEIGHT + font_size::LG * 0.8) / 2.0,
+            ),
+            colors::TEXT_PRIMARY,
+            font_size::LG,
+        );
+
+        cx.fill_rect(Self::list_bounds(bounds), colors::SIDEBAR_BG);
+        let props = Self::properties_bounds(bounds);
+        cx.fill_rect(props, colors::BG_PANEL);
+        cx.stroke_rect(props, colors::BORDER, 1.0);
+
+        match self.selected_id() {
+            Some(id) => {
+                self.paint_properties(cx, &id);
+                self.paint_preview(cx, &id);
+            }
+            None => {
+                cx.draw_text(
+                    "No themes in manifest.",
+                    Point::new(props.origin.x + spacing::MD, props.origin.y + 32.0),
+                    colors::TEXT_MUTED,
+                    font_size::MD,
+                );
+            }
+        }
+
+        for button in self.buttons(bounds) {
+            button.paint(cx);
+        }
+
+        let status = Bounds {
+            origin: Point::new(
+                bounds.origin.x,
+                bounds.origin.y + bounds.size.height - Self::STATUS_HEIGHT,
+            ),
+            size: Size::new(bounds.size.width, Self::STATUS_HEIGHT),
+        };
+        self.status.paint(cx, status);
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        match event {
+            InputEvent::KeyDown { key, modifiers } => match key {
+                KeyCode::S if modifiers.ctrl => {
+                    self.apply_action(ThemeAction::Save);
+                    true
+                }
+                KeyCode::Up => {
+                    self.select_theme(self.selected.saturating_sub(1));
+                    true
+                }
+                KeyCode::Down => {
+                    self.select_theme(self.selected.saturating_add(1));
+                    true
+                }
+                _ => false,
+            },
+            InputEvent::MouseDown { position, .. } => {
+                if let Some(action) = clicked_action(self.buttons(bounds), *position) {
+                    self.apply_action(action);
+                    return true;
+                }
+                // Clicking a preview choice highlights it
+                let choices = Self::choices_bounds(Self::stage_bounds(bounds));
+                if choices.contains(*position) && self.choice_preview.handle_event(event, choices) {
+                    self.choice_preview.reset_confirmation();
+                    self.dirty = true;
+                    return true;
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        let _ = delta;
+        let was_dirty = self.dirty;
+        self.dirty = false;
+        was_dirty
+    }
+
+    fn load_pending_background_texture(&mut self, renderer: &mut Renderer) -> bool {
+        let Some(path) = self.selected_id().and_then(|id| {
+            self.document
+                .theme(&id)
+                .map(|t| t.dialogue_box.default.clone())
+        }) else {
+            return false;
+        };
+        if path.is_empty()
+            || self.textures.contains_key(&path)
+            || self.failed_textures.contains(&path)
+        {
+            return false;
+        }
+
+        let full_path = self.document.resolve_path(&path);
+        match renderer.load_texture_from_path(&full_path) {
+            Ok(id) => {
+                self.textures.insert(path, id);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load texture '{}': {}", full_path.display(), e);
+                self.failed_textures.insert(path);
+            }
+        }
+        self.dirty = true;
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_gui::Color;
+
+    #[test]
+    fn test_ellipsize_start() {
+        assert_eq!(ellipsize_start("short.png", 32), "short.png");
+        assert_eq!(
+            ellipsize_start("assets/ui/dialogue.png", 12),
+            "...logue.png"
+        );
+    }
+
+    #[test]
+    fn test_choice_style_uses_palette() {
+        let palette = ColorPalette {
+            text_primary: (255, 255, 255, 255),
+            text_secondary: (128, 128, 128, 255),
+            accent: (255, 0, 0, 255),
+            background: (0, 0, 0, 128),
+        };
+        let style = choice_style(&palette);
+        assert_eq!(style.selected_background, Color::RED);
+        assert_eq!(style.text, Color::WHITE);
+        assert_eq!(style.selected_text, Color::BLACK);
+    }
+
+    #[test]
+    fn test_empty_document_has_only_save_button() {
+        let dir = tempfile::tempdir().unwrap();
+        let doc =
+            UiThemeDocument::open_or_new(dir.path().join("ui_themes.ron"), dir.path()).unwrap();
+        let editor = ThemeEditorElement::new(doc);
+        let bounds = Bounds {
+            origin: Point::new(0.0, 0.0),
+            size: Size::new(1600.0, 900.0),
+        };
+        let buttons = editor.buttons(bounds);
+        assert_eq!(buttons.len(), 1);
+        assert_eq!(buttons[0].action, ThemeAction::Save);
+    }
+}
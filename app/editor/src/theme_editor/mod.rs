@@ -0,0 +1,12 @@
+//! UI theme editor
+//!
+//! Edits `UiThemeDef` values (palette colors, dialogue box and choice
+//! textures, fonts, sounds) with a live preview of the dialogue box and
+//! choice menu drawn by the game components, and writes the theme manifest
+//! back to RON after validation.
+
+mod document;
+mod element;
+
+pub use document::UiThemeDocument;
+pub use element::ThemeEditorElement;
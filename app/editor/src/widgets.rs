@@ -0,0 +1,159 @@
+//! Small drawing helpers shared by editor panels
+//!
+//! Panels compute their clickable buttons from the current bounds on every
+//! paint and event, so the same list drives both drawing and hit testing.
+
+use narrative_gui::framework::element::PaintContext;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, font_size, radius, spacing};
+use narrative_gui::{Color, Point, Size};
+
+/// Height of editor buttons
+pub const BUTTON_HEIGHT: f32 = 28.0;
+
+/// Clickable button computed from the current layout
+#[derive(Debug, Clone)]
+pub struct EditorButton<A> {
+    /// Button bounds
+    pub bounds: Bounds,
+    /// Button label
+    pub label: String,
+    /// Action triggered on click
+    pub action: A,
+    /// Whether the button is drawn highlighted
+    pub active: bool,
+}
+
+impl<A> EditorButton<A> {
+    /// Create a button with the standard height
+    pub fn new(x: f32, y: f32, width: f32, label: impl Into<String>, action: A) -> Self {
+        Self {
+            bounds: Bounds {
+                origin: Point::new(x, y),
+                size: Size::new(width, BUTTON_HEIGHT),
+            },
+            label: label.into(),
+            action,
+            active: false,
+        }
+    }
+
+    /// Set the highlighted state
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Draw the button
+    pub fn paint(&self, cx: &mut PaintContext) {
+        let (bg, fg) = if self.active {
+            (colors::ACCENT_PRIMARY, colors::BG_DARKEST)
+        } else {
+            (colors::BUTTON_SECONDARY, colors::TEXT_PRIMARY)
+        };
+        cx.fill_rounded_rect(self.bounds, bg, radius::MD);
+        if !self.active {
+            cx.stroke_rect(self.bounds, colors::BORDER_LIGHT, 1.0);
+        }
+        let text_width = self.label.chars().count() as f32 * font_size::SM * 0.6;
+        let text_x = self.bounds.origin.x + ((self.bounds.size.width - text_width) / 2.0).max(4.0);
+        let text_y = self.bounds.origin.y + (self.bounds.size.height + font_size::SM * 0.8) / 2.0;
+        cx.draw_text(&self.label, Point::new(text_x, text_y), fg, font_size::SM);
+    }
+}
+
+/// Find the action of the button under `position`
+pub fn clicked_action<A>(buttons: Vec<EditorButton<A>>, position: Point) -> Option<A> {
+    buttons
+        .into_iter()
+        .find(|button| button.bounds.contains(position))
+        .map(|button| button.action)
+}
+
+/// Status line message
+#[derive(Debug, Clone)]
+pub enum StatusMessage {
+    /// Neutral hint
+    Info(String),
+    /// Successful operation
+    Success(String),
+    /// One or more problems
+    Error(Vec<String>),
+}
+
+impl StatusMessage {
+    /// Maximum number of problem lines shown at once
+    const MAX_LINES: usize = 2;
+
+    /// Draw the status line into `bounds`
+    pub fn paint(&self, cx: &mut PaintContext, bounds: Bounds) {
+        cx.fill_rect(bounds, colors::BG_DARK);
+
+        let (lines, color): (Vec<String>, Color) = match self {
+            StatusMessage::Info(msg) => (vec![msg.clone()], colors::TEXT_SECONDARY),
+            StatusMessage::Success(msg) => (vec![msg.clone()], colors::SUCCESS),
+            StatusMessage::Error(problems) => {
+                let mut lines: Vec<String> =
+                    problems.iter().take(Self::MAX_LINES).cloned().collect();
+                let remaining = problems.len().saturating_sub(Self::MAX_LINES);
+                if remaining > 0
+                    && let Some(last) = lines.last_mut()
+                {
+                    last.push_str(&format!(" (+{} more)", remaining));
+                }
+                (lines, colors::ERROR)
+            }
+        };
+        for (i, line) in lines.iter().enumerate() {
+            cx.draw_text(
+                line,
+                Point::new(
+                    bounds.origin.x + spacing::MD,
+                    bounds.origin.y + spacing::MD + font_size::MD + i as f32 * 24.0,
+                ),
+                color,
+                font_size::MD,
+            );
+        }
+    }
+}
+
+/// Convert 8-bit RGBA components to a GUI color
+pub fn rgba8(r: u8, g: u8, b: u8, a: u8) -> Color {
+    Color::new(
+        f32::from(r) / 255.0,
+        f32::from(g) / 255.0,
+        f32::from(b) / 255.0,
+        f32::from(a) / 255.0,
+    )
+}
+
+/// Apply a signed step to an 8-bit color channel, clamping to 0-255
+pub fn step_channel(value: u8, delta: i16) -> u8 {
+    i16::from(value).saturating_add(delta).clamp(0, 255) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_channel_clamps() {
+        assert_eq!(step_channel(250, 15), 255);
+        assert_eq!(step_channel(5, -15), 0);
+        assert_eq!(step_channel(100, 15), 115);
+    }
+
+    #[test]
+    fn test_clicked_action() {
+        let buttons = vec![
+            EditorButton::new(0.0, 0.0, 50.0, "A", 1),
+            EditorButton::new(60.0, 0.0, 50.0, "B", 2),
+        ];
+        assert_eq!(
+            clicked_action(buttons.clone(), Point::new(70.0, 10.0)),
+            Some(2)
+        );
+        assert_eq!(clicked_action(buttons, Point::new(55.0, 10.0)), None);
+    }
+}
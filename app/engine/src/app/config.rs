@@ -1,11 +1,13 @@
 //! Engine configuration
 
+use crate::save::SaveBackendKind;
 use narrative_core::EngineResult;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 /// Engine configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct EngineConfig {
     /// Window configuration
     #[serde(default)]
@@ -31,6 +33,9 @@ pub struct EngineConfig {
     /// Save directory
     #[serde(default = "default_save_path")]
     pub save_path: PathBuf,
+    /// Save storage backend
+    #[serde(default)]
+    pub save_backend: SaveBackendKind,
     /// Start scenario path
     #[serde(default = "default_start_scenario")]
     pub start_scenario: PathBuf,
@@ -48,8 +53,15 @@ fn default_start_scenario() -> PathBuf {
     PathBuf::from("assets/scenarios/chapter_01.toml")
 }
 
+/// Whether a config path uses RON (by extension); anything else is TOML
+fn is_ron_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ron"))
+}
+
 /// Window configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct WindowConfig {
     /// Window title
     #[serde(default = "default_window_title")]
@@ -156,6 +168,7 @@ pub enum AntiAliasing {
 
 /// Graphics configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct GraphicsConfig {
     /// VSync enabled
     #[serde(default = "default_true")]
@@ -169,6 +182,23 @@ pub struct GraphicsConfig {
     /// Character texture cache capacity (number of textures)
     #[serde(default = "default_character_cache_capacity")]
     pub character_cache_capacity: usize,
+    /// Background texture cache capacity (number of GPU textures)
+    ///
+    /// Evicted backgrounds are re-uploaded from the decoded image cache.
+    #[serde(default = "default_background_cache_capacity")]
+    pub background_cache_capacity: usize,
+    /// Budget of the decoded image cache in MiB (0 disables it)
+    #[serde(default = "default_image_cache_budget_mb")]
+    pub image_cache_budget_mb: usize,
+    /// Memory cap in MiB of assets preloaded for upcoming scenes (0 disables preloading)
+    #[serde(default = "default_preload_budget_mb")]
+    pub preload_budget_mb: usize,
+    /// Fixed logical resolution [width, height] for render commands
+    ///
+    /// Scaled to fit the window with letterboxing. When unset, the logical
+    /// resolution is the window size divided by the DPI scale factor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub virtual_resolution: Option<[u32; 2]>,
 }
 
 fn default_target_fps() -> u32 {
@@ -179,6 +209,18 @@ fn default_character_cache_capacity() -> usize {
     75 // 50-100 range, using middle value
 }
 
+fn default_background_cache_capacity() -> usize {
+    16
+}
+
+fn default_image_cache_budget_mb() -> usize {
+    256
+}
+
+fn default_preload_budget_mb() -> usize {
+    128
+}
+
 impl Default for GraphicsConfig {
     fn default() -> Self {
         Self {
@@ -186,6 +228,10 @@ impl Default for GraphicsConfig {
             target_fps: 60,
             anti_aliasing: AntiAliasing::default(),
             character_cache_capacity: default_character_cache_capacity(),
+            background_cache_capacity: default_background_cache_capacity(),
+            image_cache_budget_mb: default_image_cache_budget_mb(),
+            preload_budget_mb: default_preload_budget_mb(),
+            virtual_resolution: None,
         }
     }
 }
@@ -197,6 +243,8 @@ impl GraphicsConfig {
         const MAX_FPS: u32 = 240;
         const MIN_CACHE_CAPACITY: usize = 10;
         const MAX_CACHE_CAPACITY: usize = 500;
+        const MAX_BACKGROUND_CACHE_CAPACITY: usize = 128;
+        const MAX_IMAGE_CACHE_BUDGET_MB: usize = 4096;
 
         if self.target_fps < MIN_FPS || self.target_fps > MAX_FPS {
             return Err(format!(
@@ -214,12 +262,45 @@ impl GraphicsConfig {
             ));
         }
 
+        if self.background_cache_capacity == 0
+            || self.background_cache_capacity > MAX_BACKGROUND_CACHE_CAPACITY
+        {
+            return Err(format!(
+                "graphics.background_cache_capacity must be 1-{}, got {}",
+                MAX_BACKGROUND_CACHE_CAPACITY, self.background_cache_capacity
+            ));
+        }
+
+        if self.image_cache_budget_mb > MAX_IMAGE_CACHE_BUDGET_MB {
+            return Err(format!(
+                "graphics.image_cache_budget_mb must be 0-{}, got {}",
+                MAX_IMAGE_CACHE_BUDGET_MB, self.image_cache_budget_mb
+            ));
+        }
+
+        if self.preload_budget_mb > MAX_IMAGE_CACHE_BUDGET_MB {
+            return Err(format!(
+                "graphics.preload_budget_mb must be 0-{}, got {}",
+                MAX_IMAGE_CACHE_BUDGET_MB, self.preload_budget_mb
+            ));
+        }
+
+        if let Some([width, height]) = self.virtual_resolution
+            && (width == 0 || height == 0)
+        {
+            return Err(format!(
+                "graphics.virtual_resolution must be non-zero, got {}x{}",
+                width, height
+            ));
+        }
+
         Ok(())
     }
 }
 
 /// Audio configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct AudioConfig {
     /// Master volume (0.0-1.0)
     #[serde(default = "default_volume")]
@@ -236,12 +317,33 @@ pub struct AudioConfig {
     /// Audio enabled (mute when false)
     #[serde(default = "default_true")]
     pub enabled: bool,
+    /// Seconds BGM tracks crossfade when the music changes (0 = hard cut)
+    #[serde(default = "default_bgm_crossfade")]
+    pub bgm_crossfade: f32,
+    /// Decibels BGM is lowered by while a voice line plays (0 = no ducking)
+    #[serde(default = "default_voice_ducking_db")]
+    pub voice_ducking_db: f32,
+    /// Seconds BGM takes to return to its volume after a voice line ends
+    #[serde(default = "default_voice_ducking_release")]
+    pub voice_ducking_release: f32,
 }
 
 fn default_volume() -> f32 {
     1.0
 }
 
+fn default_bgm_crossfade() -> f32 {
+    1.0
+}
+
+fn default_voice_ducking_db() -> f32 {
+    8.0
+}
+
+fn default_voice_ducking_release() -> f32 {
+    0.5
+}
+
 fn default_music_volume() -> f32 {
     0.8
 }
@@ -254,6 +356,9 @@ impl Default for AudioConfig {
             sound_volume: 1.0,
             voice_volume: 1.0,
             enabled: true,
+            bgm_crossfade: 1.0,
+            voice_ducking_db: 8.0,
+            voice_ducking_release: 0.5,
         }
     }
 }
@@ -285,6 +390,24 @@ impl AudioConfig {
                 self.voice_volume
             ));
         }
+        if !(0.0..=10.0).contains(&self.bgm_crossfade) {
+            return Err(format!(
+                "audio.bgm_crossfade must be 0.0-10.0 seconds, got {}",
+                self.bgm_crossfade
+            ));
+        }
+        if !(0.0..=60.0).contains(&self.voice_ducking_db) {
+            return Err(format!(
+                "audio.voice_ducking_db must be 0.0-60.0 dB, got {}",
+                self.voice_ducking_db
+            ));
+        }
+        if !(0.0..=10.0).contains(&self.voice_ducking_release) {
+            return Err(format!(
+                "audio.voice_ducking_release must be 0.0-10.0 seconds, got {}",
+                self.voice_ducking_release
+            ));
+        }
         Ok(())
     }
 
@@ -353,6 +476,7 @@ impl AudioConfig {
 
 /// Gameplay configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct GameplayConfig {
     /// Text speed (characters per second)
     #[serde(default = "default_text_speed")]
@@ -378,9 +502,18 @@ pub struct GameplayConfig {
     /// Enable quick save
     #[serde(default = "default_true")]
     pub enable_quick_save: bool,
+    /// Ask for confirmation before quick load discards unsaved progress
+    #[serde(default = "default_true")]
+    pub confirm_quick_load: bool,
     /// Maximum save slots
     #[serde(default = "default_max_save_slots")]
     pub max_save_slots: usize,
+    /// Offer "return to last choice" in the pause menu (false = choices are final)
+    #[serde(default = "default_true")]
+    pub allow_return_to_choice: bool,
+    /// Compiled `Script` commands kept for reuse (least recently run dropped first)
+    #[serde(default = "default_script_cache_capacity")]
+    pub script_cache_capacity: usize,
 }
 
 fn default_text_speed() -> f32 {
@@ -395,6 +528,10 @@ fn default_max_save_slots() -> usize {
     20
 }
 
+fn default_script_cache_capacity() -> usize {
+    64
+}
+
 impl Default for GameplayConfig {
     fn default() -> Self {
         Self {
@@ -406,7 +543,10 @@ impl Default for GameplayConfig {
             skip_mode_enabled: false,
             skip_stop_at_choices: true,
             enable_quick_save: true,
+            confirm_quick_load: true,
             max_save_slots: 20,
+            allow_return_to_choice: true,
+            script_cache_capacity: default_script_cache_capacity(),
         }
     }
 }
@@ -420,6 +560,7 @@ impl GameplayConfig {
         const MAX_AUTO_SPEED: f32 = 10.0;
         const MIN_SAVE_SLOTS: usize = 1;
         const MAX_SAVE_SLOTS: usize = 100;
+        const MAX_SCRIPT_CACHE_CAPACITY: usize = 1024;
 
         if self.text_speed < MIN_TEXT_SPEED || self.text_speed > MAX_TEXT_SPEED {
             return Err(format!(
@@ -442,12 +583,21 @@ impl GameplayConfig {
             ));
         }
 
+        if self.script_cache_capacity == 0 || self.script_cache_capacity > MAX_SCRIPT_CACHE_CAPACITY
+        {
+            return Err(format!(
+                "gameplay.script_cache_capacity must be 1-{}, got {}",
+                MAX_SCRIPT_CACHE_CAPACITY, self.script_cache_capacity
+            ));
+        }
+
         Ok(())
     }
 }
 
 /// UI configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct UiConfig {
     /// Dialogue font size
     #[serde(default = "default_dialogue_font_size")]
@@ -464,6 +614,33 @@ pub struct UiConfig {
     /// Title screen BGM path
     #[serde(default)]
     pub title_bgm: Option<String>,
+    /// ID of the UI theme in `manifests/ui_themes.ron` (None = built-in look)
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Mirror the dialogue box for right-to-left languages
+    #[serde(default)]
+    pub mirror_dialogue_box: bool,
+    /// Demo scenario played when the title screen is left idle (None = disabled)
+    #[serde(default)]
+    pub attract_mode: Option<AttractModeConfig>,
+}
+
+/// Title screen attract mode configuration
+///
+/// The demo plays in auto mode, picks the first available option at choices
+/// and returns to the title screen on any key or mouse button.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AttractModeConfig {
+    /// Scenario file played as the demo
+    pub scenario: PathBuf,
+    /// Seconds without input on the title screen before the demo starts
+    #[serde(default = "default_attract_idle_secs")]
+    pub idle_secs: f32,
+}
+
+fn default_attract_idle_secs() -> f32 {
+    60.0
 }
 
 fn default_dialogue_font_size() -> u32 {
@@ -490,6 +667,9 @@ impl Default for UiConfig {
             dialogue_box_opacity: 0.8,
             choice_highlight_color: [1.0, 1.0, 0.0, 1.0],
             title_bgm: None,
+            theme: None,
+            mirror_dialogue_box: false,
+            attract_mode: None,
         }
     }
 }
@@ -513,12 +693,22 @@ impl UiConfig {
             }
         }
 
+        if let Some(attract_mode) = &self.attract_mode
+            && attract_mode.idle_secs <= 0.0
+        {
+            return Err(format!(
+                "ui.attract_mode.idle_secs must be positive, got {}",
+                attract_mode.idle_secs
+            ));
+        }
+
         Ok(())
     }
 }
 
 /// Development configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct DevelopmentConfig {
     /// Debug mode enabled
     #[serde(default)]
@@ -537,10 +727,18 @@ impl EngineConfig {
         Self::default()
     }
 
-    /// Load configuration from a TOML file
+    /// Load configuration from a RON (`.ron`) or TOML file
+    ///
+    /// Fields missing from the file use their defaults; unknown fields are
+    /// rejected. The loaded configuration is validated.
     pub fn load<P: AsRef<Path>>(path: P) -> EngineResult<Self> {
-        let content = std::fs::read_to_string(path.as_ref())?;
-        let config: EngineConfig = toml::from_str(&content)?;
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let config: EngineConfig = if is_ron_path(path) {
+            ron::from_str(&content).map_err(narrative_core::ConfigError::from)?
+        } else {
+            toml::from_str(&content)?
+        };
 
         // Validate all configuration sections
         config.validate()?;
@@ -548,9 +746,22 @@ impl EngineConfig {
         Ok(config)
     }
 
-    /// Save configuration to a TOML file
+    /// Load configuration from a file if it exists, otherwise use defaults
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> EngineResult<Self> {
+        if path.as_ref().exists() {
+            Self::load(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save configuration to a RON (`.ron`) or TOML file
     pub fn save<P: AsRef<Path>>(&self, path: P) -> EngineResult<()> {
-        let content = toml::to_string_pretty(self)?;
+        let content = if is_ron_path(path.as_ref()) {
+            self.to_ron_string()?
+        } else {
+            toml::to_string_pretty(self)?
+        };
 
         // Ensure parent directory exists
         if let Some(parent) = path.as_ref().parent() {
@@ -562,6 +773,14 @@ impl EngineConfig {
         Ok(())
     }
 
+    /// Format the configuration as pretty-printed RON (e.g. for `--print-config`)
+    pub fn to_ron_string(&self) -> EngineResult<String> {
+        Ok(ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::new(),
+        )?)
+    }
+
     /// Validate all configuration sections
     pub fn validate(&self) -> EngineResult<()> {
         self.window
@@ -615,6 +834,7 @@ impl Default for EngineConfig {
             development: DevelopmentConfig::default(),
             asset_path: default_asset_path(),
             save_path: default_save_path(),
+            save_backend: SaveBackendKind::default(),
             start_scenario: default_start_scenario(),
         }
     }
@@ -716,6 +936,43 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_config_ron_roundtrip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("engine.ron");
+
+        let mut config = EngineConfig::default();
+        config.window.title = "RON Game".to_string();
+        config.graphics.anti_aliasing = AntiAliasing::X8;
+        config.graphics.virtual_resolution = Some([1280, 720]);
+        config.save(&path).unwrap();
+
+        let loaded = EngineConfig::load(&path).unwrap();
+        assert_eq!(loaded.window_title(), "RON Game");
+        assert_eq!(loaded.graphics.anti_aliasing, AntiAliasing::X8);
+        assert_eq!(loaded.graphics.virtual_resolution, Some([1280, 720]));
+    }
+
+    #[test]
+    fn test_config_ron_partial_and_unknown_fields() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("engine.ron");
+
+        std::fs::write(&path, "(window: (width: 1920, height: 1080))").unwrap();
+        let loaded = EngineConfig::load(&path).unwrap();
+        assert_eq!(loaded.window_width(), 1920);
+        assert_eq!(loaded.target_fps(), 60);
+
+        std::fs::write(&path, "(window: (widht: 1920))").unwrap();
+        assert!(EngineConfig::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_config_load_or_default_missing_file() {
+        let config = EngineConfig::load_or_default("nonexistent_file.ron").unwrap();
+        assert_eq!(config.window_width(), 1280);
+    }
+
     #[test]
     fn test_config_aspect_ratio() {
         let config = EngineConfig::default();
@@ -770,6 +1027,45 @@ mod tests {
         assert!(graphics.validate().is_err());
     }
 
+    #[test]
+    fn test_graphics_config_background_caches() {
+        let mut graphics = GraphicsConfig::default();
+        assert_eq!(graphics.background_cache_capacity, 16);
+        assert_eq!(graphics.image_cache_budget_mb, 256);
+
+        graphics.image_cache_budget_mb = 0;
+        assert!(graphics.validate().is_ok());
+        graphics.image_cache_budget_mb = 4097;
+        assert!(graphics.validate().is_err());
+
+        graphics.image_cache_budget_mb = 256;
+        graphics.background_cache_capacity = 0;
+        assert!(graphics.validate().is_err());
+    }
+
+    #[test]
+    fn test_graphics_config_preload_budget() {
+        let mut graphics = GraphicsConfig::default();
+        assert_eq!(graphics.preload_budget_mb, 128);
+
+        graphics.preload_budget_mb = 0;
+        assert!(graphics.validate().is_ok());
+        graphics.preload_budget_mb = 4097;
+        assert!(graphics.validate().is_err());
+    }
+
+    #[test]
+    fn test_graphics_config_virtual_resolution() {
+        let mut graphics = GraphicsConfig::default();
+        assert_eq!(graphics.virtual_resolution, None);
+
+        graphics.virtual_resolution = Some([1280, 720]);
+        assert!(graphics.validate().is_ok());
+
+        graphics.virtual_resolution = Some([1280, 0]);
+        assert!(graphics.validate().is_err());
+    }
+
     #[test]
     fn test_audio_config() {
         let audio = AudioConfig::default();
@@ -778,6 +1074,37 @@ mod tests {
         assert_eq!(audio.sound_volume, 1.0);
         assert_eq!(audio.voice_volume, 1.0);
         assert!(audio.enabled);
+        assert_eq!(audio.bgm_crossfade, 1.0);
+        assert_eq!(audio.voice_ducking_db, 8.0);
+        assert_eq!(audio.voice_ducking_release, 0.5);
+    }
+
+    #[test]
+    fn test_audio_config_bgm_crossfade_validation() {
+        let mut audio = AudioConfig {
+            bgm_crossfade: 0.0,
+            ..Default::default()
+        };
+        assert!(audio.validate().is_ok());
+        audio.bgm_crossfade = 12.0;
+        assert!(audio.validate().is_err());
+        audio.bgm_crossfade = -1.0;
+        assert!(audio.validate().is_err());
+    }
+
+    #[test]
+    fn test_audio_config_voice_ducking_validation() {
+        let mut audio = AudioConfig {
+            voice_ducking_db: 0.0,
+            voice_ducking_release: 0.0,
+            ..Default::default()
+        };
+        assert!(audio.validate().is_ok());
+        audio.voice_ducking_db = 80.0;
+        assert!(audio.validate().is_err());
+        audio.voice_ducking_db = 12.0;
+        audio.voice_ducking_release = -0.5;
+        assert!(audio.validate().is_err());
     }
 
     #[test]
@@ -787,6 +1114,7 @@ mod tests {
         assert_eq!(gameplay.auto_advance_speed, 2.0);
         assert_eq!(gameplay.skip_mode, narrative_core::SkipMode::ReadOnly);
         assert!(gameplay.enable_quick_save);
+        assert!(gameplay.confirm_quick_load);
         assert_eq!(gameplay.max_save_slots, 20);
     }
 
@@ -887,6 +1215,15 @@ mod tests {
         assert!(gameplay.validate().is_err());
     }
 
+    #[test]
+    fn test_gameplay_validation_script_cache_capacity() {
+        let mut gameplay = GameplayConfig::default();
+        gameplay.script_cache_capacity = 0;
+        assert!(gameplay.validate().is_err());
+        gameplay.script_cache_capacity = 2000;
+        assert!(gameplay.validate().is_err());
+    }
+
     #[test]
     fn test_ui_validation_success() {
         let ui = UiConfig::default();
@@ -907,6 +1244,26 @@ mod tests {
         assert!(ui.validate().is_err());
     }
 
+    #[test]
+    fn test_ui_attract_mode() {
+        let ui: UiConfig =
+            ron::from_str(r#"(attract_mode: Some((scenario: "assets/scenarios/demo.toml")))"#)
+                .unwrap();
+        let attract_mode = ui.attract_mode.clone().unwrap();
+        assert_eq!(
+            attract_mode.scenario,
+            PathBuf::from("assets/scenarios/demo.toml")
+        );
+        assert_eq!(attract_mode.idle_secs, 60.0);
+        assert!(ui.validate().is_ok());
+
+        let mut ui = ui;
+        if let Some(attract_mode) = &mut ui.attract_mode {
+            attract_mode.idle_secs = 0.0;
+        }
+        assert!(ui.validate().is_err());
+    }
+
     #[test]
     fn test_engine_config_validation() {
         let config = EngineConfig::default();
@@ -0,0 +1,204 @@
+//! Engine subsystem initialization
+
+use crate::app::EngineConfig;
+use crate::asset::AssetLoader;
+use crate::audio::AudioManager;
+use crate::error::EngineResult;
+use crate::input::InputHandler;
+use crate::render::Renderer;
+use crate::save::SaveManager;
+use std::sync::Arc;
+use winit::window::Window;
+
+/// Main engine struct combining all subsystems
+///
+/// Created with [`EngineBuilder`], which applies the [`EngineConfig`] to each
+/// subsystem.
+pub struct Engine {
+    /// Configuration the engine was built with
+    pub config: EngineConfig,
+    /// Render subsystem (`None` when built without a window, e.g. when a GUI
+    /// framework owns the surface)
+    pub renderer: Option<Renderer>,
+    /// Audio subsystem
+    pub audio: AudioManager,
+    /// Input subsystem
+    pub input: InputHandler,
+    /// Asset subsystem
+    pub assets: AssetLoader,
+    /// Save subsystem
+    pub save: SaveManager,
+}
+
+impl Engine {
+    /// Create an engine without a window (no renderer)
+    pub fn new(config: EngineConfig) -> EngineResult<Self> {
+        Ok(Self::headless(config))
+    }
+
+    /// Create an engine without a renderer
+    ///
+    /// For hosts that own the window and surface themselves (e.g. the GUI
+    /// framework). This cannot fail: audio falls back to a disabled manager.
+    pub fn headless(config: EngineConfig) -> Self {
+        Self::with_renderer(config, None)
+    }
+
+    /// Start building an engine
+    pub fn builder(config: EngineConfig) -> EngineBuilder {
+        EngineBuilder::new(config)
+    }
+
+    /// Initialize the non-render subsystems in order: Audio → Input → Assets → Save
+    fn with_renderer(config: EngineConfig, renderer: Option<Renderer>) -> Self {
+        // 1. Audio
+        let audio = match AudioManager::with_config(config.audio.clone()) {
+            Ok(manager) => {
+                tracing::info!("AudioManager initialized");
+                manager
+            }
+            Err(e) => {
+                tracing::error!("Failed to initialize AudioManager: {}", e);
+                tracing::warn!("Running in audio-disabled mode - audio will not play");
+                AudioManager::disabled()
+            }
+        };
+
+        // 2. Input
+        let mut input = InputHandler::new();
+        input.enable_gamepads();
+
+        // 3. Assets
+        let assets = AssetLoader::new(config.asset_path.clone());
+
+        // 4. Save
+        let save = SaveManager::from_config(&config);
+
+        Self {
+            config,
+            renderer,
+            audio,
+            input,
+            assets,
+            save,
+        }
+    }
+}
+
+/// Builder that initializes engine subsystems in order
+///
+/// Subsystems are created as Renderer → Audio → Input → Assets → Save.
+/// The renderer is only created when a window is supplied.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # fn example(window: std::sync::Arc<winit::window::Window>) -> narrative_engine::EngineResult<()> {
+/// use narrative_engine::{EngineBuilder, EngineConfig};
+///
+/// let engine = EngineBuilder::new(EngineConfig::default())
+///     .with_window(window)
+///     .build()?;
+/// assert!(engine.renderer.is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub struct EngineBuilder {
+    config: EngineConfig,
+    window: Option<Arc<Window>>,
+}
+
+impl EngineBuilder {
+    /// Create a builder for the given configuration
+    pub fn new(config: EngineConfig) -> Self {
+        Self {
+            config,
+            window: None,
+        }
+    }
+
+    /// Render into this window
+    pub fn with_window(mut self, window: Arc<Window>) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Initialize all subsystems, blocking on renderer creation
+    ///
+    /// # Errors
+    /// Returns an error if the renderer cannot be created. Audio failures are
+    /// not fatal: the engine falls back to a disabled audio manager.
+    pub fn build(self) -> EngineResult<Engine> {
+        pollster::block_on(self.build_async())
+    }
+
+    /// Initialize all subsystems
+    pub async fn build_async(self) -> EngineResult<Engine> {
+        // The renderer comes first so a missing GPU fails before audio starts
+        let renderer = match self.window {
+            Some(window) => Some(create_renderer(window, &self.config).await?),
+            None => None,
+        };
+
+        Ok(Engine::with_renderer(self.config, renderer))
+    }
+}
+
+/// Create a renderer for a window and apply the graphics configuration
+pub(crate) async fn create_renderer(
+    window: Arc<Window>,
+    config: &EngineConfig,
+) -> EngineResult<Renderer> {
+    let mut renderer = Renderer::new(window).await?;
+
+    renderer.set_vsync(config.graphics.vsync);
+    // Render commands use logical pixels (optionally a fixed virtual resolution)
+    renderer.set_virtual_resolution(
+        config
+            .graphics
+            .virtual_resolution
+            .map(|[width, height]| (width, height)),
+    );
+
+    Ok(renderer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::SaveBackendKind;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_engine_builder_without_window() {
+        let engine = EngineBuilder::new(EngineConfig::default()).build().unwrap();
+        assert!(engine.renderer.is_none());
+    }
+
+    #[test]
+    fn test_engine_builder_applies_config() {
+        let mut config = EngineConfig {
+            save_path: PathBuf::from("custom_saves"),
+            ..Default::default()
+        };
+        config.audio.music_volume = 0.25;
+
+        let engine = Engine::builder(config).build().unwrap();
+        assert_eq!(
+            engine.save.save_directory(),
+            Some(Path::new("custom_saves"))
+        );
+        assert_eq!(engine.config.audio.music_volume, 0.25);
+    }
+
+    #[test]
+    fn test_engine_builder_memory_save_backend() {
+        let config = EngineConfig {
+            save_backend: SaveBackendKind::Memory,
+            ..Default::default()
+        };
+
+        let engine = Engine::new(config).unwrap();
+        assert!(engine.save.save_directory().is_none());
+    }
+}
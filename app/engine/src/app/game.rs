@@ -0,0 +1,93 @@
+//! Game callbacks driven by the game loop
+
+use crate::app::{Engine, EngineConfig};
+use crate::error::EngineResult;
+use crate::input::InputState;
+use crate::render::{RenderCommand, Renderer};
+use std::sync::Arc;
+use winit::window::Window;
+
+/// Callbacks for a game run by [`GameLoop::run_game`](super::GameLoop::run_game)
+///
+/// Per frame, the loop calls `update` zero or more times with a fixed timestep
+/// (`1 / target_fps`), then `render` once.
+pub trait Game {
+    /// Called once after the window and engine are created
+    fn init(&mut self, _ctx: &mut GameContext) -> EngineResult<()> {
+        Ok(())
+    }
+
+    /// Advance the game by one fixed timestep of `dt` seconds
+    fn update(&mut self, ctx: &mut GameContext, dt: f32);
+
+    /// Build the render commands for this frame
+    ///
+    /// `alpha` (0.0-1.0) is how far the frame is between the last update and
+    /// the next one, for interpolating movement.
+    fn render(&mut self, ctx: &mut GameContext, alpha: f32) -> Vec<RenderCommand>;
+
+    /// Called when the window is asked to close; return `false` to keep running
+    fn on_close_requested(&mut self, _ctx: &mut GameContext) -> bool {
+        true
+    }
+
+    /// Called once before the loop exits
+    fn shutdown(&mut self, _ctx: &mut GameContext) {}
+}
+
+/// Engine state handed to [`Game`] callbacks
+pub struct GameContext {
+    /// Engine subsystems (the renderer is moved out into `renderer`)
+    pub engine: Engine,
+    /// Renderer for the game window
+    pub renderer: Renderer,
+    window: Arc<Window>,
+    fps: f32,
+    exit_requested: bool,
+}
+
+impl GameContext {
+    pub(super) fn new(engine: Engine, renderer: Renderer, window: Arc<Window>) -> Self {
+        Self {
+            engine,
+            renderer,
+            window,
+            fps: 0.0,
+            exit_requested: false,
+        }
+    }
+
+    /// Engine configuration
+    pub fn config(&self) -> &EngineConfig {
+        &self.engine.config
+    }
+
+    /// Input state for the current frame
+    pub fn input(&self) -> &InputState {
+        self.engine.input.state()
+    }
+
+    /// The game window
+    pub fn window(&self) -> &Window {
+        &self.window
+    }
+
+    /// Frames per second, averaged over the last second
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+
+    pub(super) fn set_fps(&mut self, fps: f32) {
+        self.fps = fps;
+    }
+
+    /// Exit the game loop after the current frame
+    pub fn exit(&mut self) {
+        self.exit_requested = true;
+    }
+
+    /// Whether `exit` has been called
+    pub fn is_exit_requested(&self) -> bool {
+        self.exit_requested
+    }
+}
@@ -1,11 +1,25 @@
 //! Game loop
 //!
+//! [`GameLoop`] owns the winit event loop and drives a [`Game`]: it creates the
+//! window and [`Engine`](super::Engine), forwards window events to the input
+//! handler, and calls the game's update and render callbacks every frame.
+//! [`GameLoop::run`] plays the configured start scenario with a minimal
+//! dialogue renderer; [`GameLoop::headless`] plays it without a window for
+//! automated tests.
+//!
+//! # Update Strategy
+//!
+//! Updates run with a fixed timestep of `1 / target_fps` seconds. Each frame
+//! runs as many updates as the elapsed time allows (at most
+//! `MAX_UPDATES_PER_FRAME`, so a long stall doesn't freeze the game catching
+//! up), then renders once.
+//!
 //! # Frame Rate Control Strategy
 //!
 //! The game loop uses a dual-layer approach for 60 FPS stability:
 //!
 //! 1. **Primary: VSync (PresentMode::Fifo)**
-//!    - Enabled in renderer configuration (see `renderer.rs:130`)
+//!    - Enabled in renderer configuration (`GraphicsConfig::vsync`)
 //!    - Provides hardware-level frame synchronization
 //!    - Ensures stable 60 FPS on most displays
 //!
@@ -16,9 +30,10 @@
 //!
 //! This combination provides both stability (VSync) and efficiency (sleep).
 
-use crate::app::EngineConfig;
+use super::game::{Game, GameContext};
+use super::headless::{HeadlessRunner, ScriptedInput};
+use crate::app::{EngineBuilder, EngineConfig};
 use crate::error::EngineResult;
-use crate::input::InputHandler;
 use crate::render::{RenderCommand, Renderer};
 use crate::runtime::{AppState, InGameState, ScenarioRuntime};
 use narrative_core::{Color, Point};
@@ -34,27 +49,148 @@ use winit::{
 // Game loop configuration constants
 const LOADING_DURATION: f32 = 1.0; // seconds
 
+/// Maximum fixed updates per frame before the remaining time is dropped
+const MAX_UPDATES_PER_FRAME: u32 = 5;
+
+/// Fixed-timestep accumulator
+#[derive(Debug, Clone, Copy)]
+struct FixedTimestep {
+    /// Update step in seconds
+    step: f32,
+    /// Time not yet consumed by updates
+    accumulator: f32,
+}
+
+impl FixedTimestep {
+    fn new(step: f32) -> Self {
+        Self {
+            step,
+            accumulator: 0.0,
+        }
+    }
+
+    /// Add frame time and return how many updates to run
+    fn advance(&mut self, frame_time: f32) -> u32 {
+        self.accumulator += frame_time;
+
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < MAX_UPDATES_PER_FRAME {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+
+        // Drop time we couldn't catch up on
+        if steps == MAX_UPDATES_PER_FRAME {
+            self.accumulator = self.accumulator.min(self.step);
+        }
+        steps
+    }
+
+    /// Interpolation factor between the last update and the next one
+    fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
+
 /// Game loop state
 struct GameLoopState {
-    window: Arc<Window>,
-    renderer: Renderer,
-    input: InputHandler,
-    app_state: AppState,
-    scenario_runtime: Option<ScenarioRuntime>,
+    ctx: GameContext,
+    timestep: FixedTimestep,
     last_frame_time: Instant,
-    delta_time: f32,
     frame_count: u64,
     fps_update_timer: f32,
-    current_fps: f32,
 }
 
 /// Game loop application handler
-struct GameLoopApp {
+struct GameLoopApp<G: Game> {
     config: EngineConfig,
+    game: G,
     state: Option<GameLoopState>,
 }
 
-impl ApplicationHandler for GameLoopApp {
+impl<G: Game> GameLoopApp<G> {
+    /// Call the game's shutdown hook and stop the event loop
+    fn exit(&mut self, event_loop: &ActiveEventLoop) {
+        if let Some(state) = &mut self.state {
+            self.game.shutdown(&mut state.ctx);
+        }
+        self.state = None;
+        event_loop.exit();
+    }
+
+    /// Run updates and render one frame
+    fn frame(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(state) = &mut self.state else {
+            return;
+        };
+
+        // ========== Frame Timing ==========
+        let now = Instant::now();
+        let frame_time = now.duration_since(state.last_frame_time).as_secs_f32();
+        state.last_frame_time = now;
+
+        // Update FPS counter
+        state.frame_count = state.frame_count.saturating_add(1);
+        state.fps_update_timer += frame_time;
+        if state.fps_update_timer >= 1.0 {
+            let fps = state.frame_count as f32 / state.fps_update_timer;
+            state.ctx.set_fps(fps);
+            state.frame_count = 0;
+            state.fps_update_timer = 0.0;
+            tracing::trace!(
+                "FPS: {:.1} | {}",
+                fps,
+                state.ctx.renderer.last_stats().display_string()
+            );
+        }
+
+        // ========== Update Phase ==========
+        state.ctx.engine.input.poll_gamepads();
+        let steps = state.timestep.advance(frame_time);
+        for _ in 0..steps {
+            self.game.update(&mut state.ctx, state.timestep.step);
+        }
+        // Clear frame-specific input state (just_pressed, just_released) only
+        // once an update has seen it
+        if steps > 0 {
+            state.ctx.engine.input.update();
+        }
+        state.ctx.engine.audio.process_commands();
+
+        // ========== Render Phase ==========
+        let commands = self.game.render(&mut state.ctx, state.timestep.alpha());
+        if let Err(e) = state.ctx.renderer.render_commands(&commands) {
+            tracing::error!("Render error: {}", e);
+        }
+
+        if state.ctx.is_exit_requested() {
+            self.exit(event_loop);
+            return;
+        }
+
+        // ========== Frame Rate Control ==========
+        // Primary frame rate control: VSync (PresentMode::Fifo) in renderer
+        // Secondary control: sleep for fine-tuning if frame finishes early
+        let target_frame_time = Duration::from_secs_f32(1.0 / self.config.target_fps() as f32);
+        let elapsed = now.elapsed();
+
+        if elapsed < target_frame_time {
+            let sleep_duration = target_frame_time.saturating_sub(elapsed);
+            // Note: std::thread::sleep() precision varies by OS:
+            // - Windows: ~1-15ms (can be improved with timeBeginPeriod)
+            // - Linux: ~1-2ms (depends on kernel timer resolution)
+            // - macOS: ~1ms (mach_wait_until provides nanosecond precision)
+            // For 60FPS (16.67ms), VSync provides the primary stability,
+            // and sleep() prevents CPU busy-waiting between frames.
+            std::thread::sleep(sleep_duration);
+        }
+
+        // Request next frame
+        state.ctx.window().request_redraw();
+    }
+}
+
+impl<G: Game> ApplicationHandler for GameLoopApp<G> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.state.is_some() {
             return;
@@ -77,37 +213,37 @@ impl ApplicationHandler for GameLoopApp {
             }
         };
 
-        // Initialize renderer (async)
-        // Use pollster to block on async wgpu initialization in synchronous resumed() callback
-        let renderer = match pollster::block_on(Renderer::new(window.clone())) {
-            Ok(r) => r,
+        // Initialize engine subsystems, including the renderer for this window
+        let mut engine = match EngineBuilder::new(self.config.clone())
+            .with_window(window.clone())
+            .build()
+        {
+            Ok(engine) => engine,
             Err(e) => {
-                tracing::error!("Failed to initialize renderer: {}", e);
+                tracing::error!("Failed to initialize engine: {}", e);
                 event_loop.exit();
                 return;
             }
         };
+        let Some(renderer) = engine.renderer.take() else {
+            tracing::error!("Engine was built without a renderer");
+            event_loop.exit();
+            return;
+        };
 
-        // Initialize input handler
-        let input = InputHandler::new();
-
-        // Initialize application state (starts in Loading)
-        let app_state = AppState::default();
-
-        // Initialize frame timing
-        let now = Instant::now();
+        let mut ctx = GameContext::new(engine, renderer, window);
+        if let Err(e) = self.game.init(&mut ctx) {
+            tracing::error!("Failed to initialize game: {}", e);
+            event_loop.exit();
+            return;
+        }
 
         self.state = Some(GameLoopState {
-            window,
-            renderer,
-            input,
-            app_state,
-            scenario_runtime: None,
-            last_frame_time: now,
-            delta_time: 0.0,
+            ctx,
+            timestep: FixedTimestep::new(1.0 / self.config.target_fps().max(1) as f32),
+            last_frame_time: Instant::now(),
             frame_count: 0,
             fps_update_timer: 0.0,
-            current_fps: 0.0,
         });
     }
 
@@ -115,18 +251,24 @@ impl ApplicationHandler for GameLoopApp {
         let Some(state) = &mut self.state else {
             return;
         };
+        let ctx = &mut state.ctx;
 
         match event {
-            WindowEvent::CloseRequested => {
-                event_loop.exit();
+            WindowEvent::CloseRequested if self.game.on_close_requested(ctx) => {
+                self.exit(event_loop);
             }
             WindowEvent::Resized(physical_size) => {
-                state.renderer.resize(physical_size);
+                ctx.renderer.resize(physical_size);
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                ctx.renderer.set_scale_factor(scale_factor);
             }
             WindowEvent::KeyboardInput { event, .. } => {
                 // Extract KeyCode from PhysicalKey
                 if let winit::keyboard::PhysicalKey::Code(key_code) = event.physical_key {
-                    state.input.process_keyboard_event(key_code, event.state);
+                    ctx.engine
+                        .input
+                        .process_keyboard_event(key_code, event.state);
                 }
             }
             WindowEvent::MouseInput {
@@ -134,94 +276,43 @@ impl ApplicationHandler for GameLoopApp {
                 button,
                 ..
             } => {
-                state.input.process_mouse_button_event(button, button_state);
+                ctx.engine
+                    .input
+                    .process_mouse_button_event(button, button_state);
             }
             WindowEvent::CursorMoved { position, .. } => {
-                state.input.process_mouse_motion(position.x, position.y);
+                // Game code works in logical pixels
+                let logical = ctx
+                    .renderer
+                    .viewport()
+                    .to_logical(Point::new(position.x as f32, position.y as f32));
+                ctx.engine
+                    .input
+                    .process_mouse_motion(f64::from(logical.x), f64::from(logical.y));
             }
             WindowEvent::ModifiersChanged(modifiers) => {
-                state.input.process_modifiers(modifiers.state());
+                ctx.engine.input.process_modifiers(modifiers.state());
             }
             WindowEvent::RedrawRequested => {
-                // ========== Frame Timing ==========
-                let now = Instant::now();
-                let frame_duration = now.duration_since(state.last_frame_time);
-                state.delta_time = frame_duration.as_secs_f32();
-                state.last_frame_time = now;
-
-                // Update FPS counter
-                state.frame_count = state.frame_count.saturating_add(1);
-                state.fps_update_timer += state.delta_time;
-                if state.fps_update_timer >= 1.0 {
-                    state.current_fps = state.frame_count as f32 / state.fps_update_timer;
-                    state.frame_count = 0;
-                    state.fps_update_timer = 0.0;
-                    tracing::trace!("FPS: {:.1}", state.current_fps);
-                }
-
-                // ========== Update Phase ==========
-                // Clear frame-specific input state (just_pressed, just_released)
-                state.input.update();
-
-                // Update application state
-                update_app_state(
-                    &mut state.app_state,
-                    &mut state.scenario_runtime,
-                    state.input.state(),
-                    state.delta_time,
-                    &self.config,
-                );
-
-                // ========== Render Phase ==========
-                // Render dialogue with typewriter effect
-                let result = render_dialogue(&mut state.renderer, &state.app_state).map_err(|e| {
-                    tracing::error!("Render error: {}", e);
-                    // Convert to a generic surface error for consistent handling
-                    wgpu::SurfaceError::Timeout
-                });
-
-                match result {
-                    Ok(_) => {}
-                    Err(wgpu::SurfaceError::Lost) => {
-                        // Reconfigure surface on lost
-                        state.renderer.resize(state.window.inner_size());
-                    }
-                    Err(wgpu::SurfaceError::OutOfMemory) => {
-                        tracing::error!("Out of memory!");
-                        event_loop.exit();
-                    }
-                    Err(e) => {
-                        tracing::error!("Render error: {:?}", e);
-                    }
-                }
-
-                // ========== Frame Rate Control ==========
-                // Primary frame rate control: VSync (PresentMode::Fifo) in renderer
-                // Secondary control: sleep for fine-tuning if frame finishes early
-                let target_frame_time =
-                    Duration::from_secs_f32(1.0 / self.config.target_fps() as f32);
-                let elapsed = now.elapsed();
-
-                if elapsed < target_frame_time {
-                    let sleep_duration = target_frame_time.saturating_sub(elapsed);
-                    // Note: std::thread::sleep() precision varies by OS:
-                    // - Windows: ~1-15ms (can be improved with timeBeginPeriod)
-                    // - Linux: ~1-2ms (depends on kernel timer resolution)
-                    // - macOS: ~1ms (mach_wait_until provides nanosecond precision)
-                    // For 60FPS (16.67ms), VSync provides the primary stability,
-                    // and sleep() prevents CPU busy-waiting between frames.
-                    std::thread::sleep(sleep_duration);
-                }
-
-                // Request next frame
-                state.window.request_redraw();
+                self.frame(event_loop);
             }
             _ => {}
         }
     }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        // Exits not initiated by the loop itself (e.g. platform shutdown)
+        if let Some(state) = &mut self.state {
+            self.game.shutdown(&mut state.ctx);
+        }
+        self.state = None;
+    }
 }
 
 /// Game loop
+///
+/// Owns the winit event loop; see the [module docs](self) for the update and
+/// frame rate strategy.
 pub struct GameLoop {
     config: EngineConfig,
 }
@@ -239,8 +330,29 @@ impl GameLoop {
         Self { config }
     }
 
-    /// Run the game loop
+    /// Run the configured start scenario with the built-in dialogue renderer
     pub fn run(self) -> EngineResult<()> {
+        self.run_game(ScenarioPlayer::default())
+    }
+
+    /// Create a headless runner for the configured start scenario
+    ///
+    /// The runner plays without a window, renderer or audio, taking choices
+    /// and names from `input`; see [`HeadlessRunner`].
+    ///
+    /// # Errors
+    /// Returns an error if the start scenario can't be loaded or started.
+    pub fn headless(
+        self,
+        input: impl IntoIterator<Item = ScriptedInput>,
+    ) -> EngineResult<HeadlessRunner> {
+        let mut runtime = ScenarioRuntime::from_toml(&self.config.start_scenario)?;
+        runtime.start()?;
+        Ok(HeadlessRunner::new(runtime).with_input(input))
+    }
+
+    /// Run a game until it exits or its window is closed
+    pub fn run_game<G: Game>(self, game: G) -> EngineResult<()> {
         let event_loop = EventLoop::new().map_err(|e| {
             crate::error::EngineError::GameLoop(format!("Failed to create event loop: {}", e))
         })?;
@@ -249,6 +361,7 @@ impl GameLoop {
 
         let mut app = GameLoopApp {
             config: self.config,
+            game,
             state: None,
         };
 
@@ -266,6 +379,29 @@ impl Default for GameLoop {
     }
 }
 
+/// Built-in game that plays the start scenario with a minimal dialogue box
+#[derive(Default)]
+struct ScenarioPlayer {
+    app_state: AppState,
+    scenario_runtime: Option<ScenarioRuntime>,
+}
+
+impl Game for ScenarioPlayer {
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) {
+        update_app_state(
+            &mut self.app_state,
+            &mut self.scenario_runtime,
+            ctx.engine.input.state(),
+            dt,
+            &ctx.engine.config,
+        );
+    }
+
+    fn render(&mut self, ctx: &mut GameContext, _alpha: f32) -> Vec<RenderCommand> {
+        dialogue_commands(&ctx.renderer, &self.app_state)
+    }
+}
+
 // =============================================================================
 // UI Layout Constants
 // =============================================================================
@@ -292,17 +428,15 @@ const DIALOGUE_BOX_CORNER_RADIUS: f32 = 0.0;
 // Render Functions
 // =============================================================================
 
-/// Render dialogue with typewriter effect
-fn render_dialogue(renderer: &mut Renderer, app_state: &AppState) -> EngineResult<()> {
+/// Build dialogue render commands with typewriter effect
+fn dialogue_commands(renderer: &Renderer, app_state: &AppState) -> Vec<RenderCommand> {
     // Build render commands based on app state
     let mut commands = Vec::new();
 
     // Only add dialogue if in InGame state with Typing
     if let AppState::InGame(InGameState::Typing(typing)) = app_state {
-        // Get screen dimensions for positioning
-        let config = renderer.surface_config();
-        let screen_w = config.width as f32;
-        let screen_h = config.height as f32;
+        // Get screen dimensions for positioning (logical pixels)
+        let (screen_w, screen_h) = renderer.logical_size();
 
         // Dialogue box position (bottom of screen)
         let dialogue_box_y = screen_h - DIALOGUE_BOX_HEIGHT;
@@ -327,12 +461,11 @@ fn render_dialogue(renderer: &mut Renderer, app_state: &AppState) -> EngineResul
             font_size: DIALOGUE_FONT_SIZE,
             line_height: DIALOGUE_LINE_HEIGHT,
             color: Color::WHITE,
-            visible_chars: Some(typing.char_index), // Typewriter effect!
+            visible_chars: Some(typing.visible_chars()), // Typewriter effect!
         });
     }
 
-    // Render all commands
-    renderer.render_commands(&commands)
+    commands
 }
 
 /// Update application state
@@ -408,32 +541,22 @@ fn update_app_state(
             match in_game_state {
                 InGameState::Typing(typing) => {
                     // Typewriter effect: display characters one by one
-                    typing.elapsed += delta;
+                    typing
+                        .typewriter
+                        .set_chars_per_second(config.gameplay.text_speed);
+                    typing
+                        .typewriter
+                        .update(Duration::try_from_secs_f32(delta).unwrap_or_default());
 
-                    let text_len = typing.text.chars().count();
-
-                    // Calculate character delay from text speed (chars/sec -> seconds/char)
-                    let char_delay = if config.gameplay.text_speed > 0.0 {
-                        1.0 / config.gameplay.text_speed
-                    } else {
-                        0.0 // Instant display if speed is 0
-                    };
-
-                    // Progress typewriter
-                    while typing.elapsed >= char_delay && typing.char_index < text_len {
-                        typing.char_index = typing.char_index.saturating_add(1);
-                        typing.elapsed -= char_delay;
+                    // Handle input - skip to end
+                    if input.clicked() && !typing.is_complete() {
+                        typing.typewriter.skip();
+                        return; // Early return to avoid transition this frame
                     }
 
                     // Check if we should transition
                     let should_transition =
-                        (!typing.auto_mode || input.clicked()) && typing.char_index >= text_len;
-
-                    // Handle input - skip to end
-                    if input.clicked() && typing.char_index < text_len {
-                        typing.char_index = text_len;
-                        return; // Early return to avoid transition this frame
-                    }
+                        (!typing.auto_mode || input.clicked()) && typing.is_complete();
 
                     // Transition to WaitingInput if needed
                     if should_transition {
@@ -527,7 +650,10 @@ fn update_app_state(
                 InGameState::PlayingEffect(effect) => {
                     if effect.update(delta) {
                         // Effect complete, move to next command
-                        if let Some(new_state) = execute_and_transition(runtime) {
+                        if !runtime.advance_command() {
+                            tracing::info!("Scenario ended after effect");
+                            *app_state = AppState::MainMenu(MainMenuState::default());
+                        } else if let Some(new_state) = execute_and_transition(runtime) {
                             *in_game_state = new_state;
                         } else {
                             // End of scenario or error
@@ -550,6 +676,16 @@ fn update_app_state(
                     }
                 }
 
+                InGameState::PlayingVideo(_video) => {
+                    // Video playback is handled in the GUI layer (GameRootElement)
+                    // No game loop logic needed here
+                }
+
+                InGameState::InputtingName(_name_input) => {
+                    // Name entry is handled in the GUI layer (GameRootElement)
+                    // No game loop logic needed here
+                }
+
                 InGameState::PauseMenu(_pause) => {
                     // Pause menu handling would go here
                     if input.pause_pressed() {
@@ -578,6 +714,16 @@ fn update_app_state(
                     // CG Viewer UI is handled in the GUI layer (GameRootElement)
                     // No game loop logic needed here
                 }
+
+                InGameState::Achievements(_achievements) => {
+                    // Achievement list is handled in the GUI layer (GameRootElement)
+                    // No game loop logic needed here
+                }
+
+                InGameState::Ending(_ending) => {
+                    // Ending screen is handled in the GUI layer (GameRootElement)
+                    // No game loop logic needed here
+                }
             }
         }
         AppState::Settings(_settings) => {
@@ -588,7 +734,7 @@ fn update_app_state(
 
 /// Create InGameState from the current command in the runtime
 fn create_state_from_command(runtime: &ScenarioRuntime) -> Option<InGameState> {
-    use crate::runtime::{ChoiceState, InGameState, TypingState, WaitState};
+    use crate::runtime::{ChoiceState, EffectState, InGameState, TypingState, WaitState};
     use narrative_core::ScenarioCommand;
 
     let command = runtime.get_current_command()?;
@@ -605,28 +751,29 @@ fn create_state_from_command(runtime: &ScenarioRuntime) -> Option<InGameState> {
                 Speaker::Narrator | Speaker::System => None,
             };
 
-            Some(InGameState::Typing(TypingState {
-                scene_id,
-                command_index,
-                speaker,
-                text: Arc::from(dialogue.text.clone()),
-                char_index: 0,
-                elapsed: 0.0,
-                auto_mode: false,
-                skip_mode: false,
-            }))
+            let (text, translation) = dialogue.text_tracks(runtime.dialogue_languages());
+            let variables = runtime.variables();
+            let translation = translation.map(|translation| variables.substitute(translation));
+            Some(InGameState::Typing(
+                TypingState::new(scene_id, command_index, speaker, variables.substitute(text))
+                    .with_translation(translation.as_deref()),
+            ))
         }
 
-        ScenarioCommand::ShowChoice { choice } => Some(InGameState::ShowingChoices(ChoiceState {
+        ScenarioCommand::ShowChoice { .. } => Some(InGameState::ShowingChoices(ChoiceState {
             scene_id,
             command_index,
-            choices: choice.options.clone(),
+            choices: runtime.displayed_choices(),
             selected: 0,
             confirmed: false,
         })),
 
         ScenarioCommand::Wait { duration } => Some(InGameState::Waiting(WaitState::new(*duration))),
 
+        ScenarioCommand::ScreenShake { .. } | ScenarioCommand::Flash { .. } => {
+            EffectState::from_command(command).map(InGameState::PlayingEffect)
+        }
+
         // Other commands don't create waiting states, they execute immediately
         _ => None,
     }
@@ -693,9 +840,64 @@ fn execute_and_transition(runtime: &mut ScenarioRuntime) -> Option<InGameState>
             Some(InGameState::Waiting(WaitState::new(duration)))
         }
 
+        CommandExecutionResult::PlayEffect(effect) => Some(InGameState::PlayingEffect(effect)),
+
+        CommandExecutionResult::InputName(name_input) => {
+            // This loop has no text entry; the default name is used
+            if let Err(e) = runtime.submit_name(&name_input.default) {
+                tracing::error!("Failed to store name: {}", e);
+                return None;
+            }
+            if !runtime.advance_command() {
+                return None;
+            }
+            create_state_from_command(runtime)
+        }
+
+        CommandExecutionResult::PlayVideo { path, .. } => {
+            // This loop has no video output; the GUI layer plays videos
+            tracing::info!("Skipping video '{}'", path.path());
+            if !runtime.advance_command() {
+                return None;
+            }
+            create_state_from_command(runtime)
+        }
+
+        CommandExecutionResult::JumpToScenario(jump) => {
+            tracing::info!("Jump to scenario '{}' is not supported here", jump.id);
+            None
+        }
+
         CommandExecutionResult::End => {
             tracing::info!("Scenario ended");
             None
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_timestep_accumulates() {
+        let mut timestep = FixedTimestep::new(0.1);
+
+        assert_eq!(timestep.advance(0.05), 0);
+        assert!((timestep.alpha() - 0.5).abs() < 1e-5);
+
+        assert_eq!(timestep.advance(0.26), 3);
+        assert!((timestep.alpha() - 0.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_fixed_timestep_caps_updates_per_frame() {
+        let mut timestep = FixedTimestep::new(0.1);
+
+        // A 2 second stall runs at most MAX_UPDATES_PER_FRAME updates
+        assert_eq!(timestep.advance(2.0), MAX_UPDATES_PER_FRAME);
+        // and doesn't keep catching up on the following frames
+        assert!(timestep.advance(0.0) <= 1);
+        assert_eq!(timestep.advance(0.0), 0);
+    }
+}
@@ -0,0 +1,304 @@
+//! Headless scenario playback
+//!
+//! [`HeadlessRunner`] plays a scenario without a window, renderer or audio,
+//! so integration tests can play through a route in CI and check the flags,
+//! variables and ending it reaches. Dialogue advances by itself, waits,
+//! effects and videos finish at once, and choices and name entries are taken
+//! from a script of [`ScriptedInput`]s.
+//!
+//! ```no_run
+//! use narrative_engine::app::{HeadlessEnd, HeadlessRunner, ScriptedInput};
+//! use narrative_engine::ScenarioRuntime;
+//!
+//! let mut runtime = ScenarioRuntime::from_toml("assets/scenarios/chapter_01.toml")?;
+//! runtime.start()?;
+//! let mut runner = HeadlessRunner::new(runtime).with_input([
+//!     ScriptedInput::Name("Alice".to_string()),
+//!     ScriptedInput::ChooseText("Go to the festival".to_string()),
+//! ]);
+//! let end = runner.run()?;
+//! assert!(matches!(end, HeadlessEnd::Ended { .. }));
+//! assert!(runner.runtime().flags().is_set(&"went_to_festival".into()));
+//! # Ok::<(), narrative_engine::EngineError>(())
+//! ```
+
+use crate::error::{EngineError, EngineResult};
+use crate::runtime::{CommandExecutionResult, ScenarioJump, ScenarioRuntime};
+use crate::text::TypewriterEffect;
+use narrative_core::{ChoiceOption, EndBehavior, ScenarioCommand};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// One scripted player input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScriptedInput {
+    /// Pick the displayed choice at this index
+    Choose(usize),
+    /// Pick the displayed choice with this text
+    ChooseText(String),
+    /// Enter this name at a name input
+    Name(String),
+}
+
+/// How a headless run stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadlessEnd {
+    /// The scenario ended, with the ending its `on_end` records, if any
+    Ended { ending_id: Option<String> },
+    /// The scenario jumped to another scenario of the project
+    ScenarioJump(ScenarioJump),
+}
+
+/// Plays a scenario without a window, renderer or audio
+///
+/// See the [module docs](self).
+pub struct HeadlessRunner {
+    runtime: ScenarioRuntime,
+    input: VecDeque<ScriptedInput>,
+    max_steps: usize,
+    steps: usize,
+}
+
+impl HeadlessRunner {
+    /// Default upper bound for executed commands, to catch endless loops
+    pub const DEFAULT_MAX_STEPS: usize = 100_000;
+
+    /// Create a runner for a started runtime
+    pub fn new(runtime: ScenarioRuntime) -> Self {
+        Self {
+            runtime,
+            input: VecDeque::new(),
+            max_steps: Self::DEFAULT_MAX_STEPS,
+            steps: 0,
+        }
+    }
+
+    /// Queue scripted inputs, used in order
+    pub fn with_input(mut self, input: impl IntoIterator<Item = ScriptedInput>) -> Self {
+        self.input.extend(input);
+        self
+    }
+
+    /// Set the upper bound for executed commands
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Runtime being played (flags, variables, backlog, ...)
+    pub fn runtime(&self) -> &ScenarioRuntime {
+        &self.runtime
+    }
+
+    /// Take the runtime, e.g. to continue with another runner
+    pub fn into_runtime(self) -> ScenarioRuntime {
+        self.runtime
+    }
+
+    /// Scripted inputs not used yet
+    pub fn remaining_input(&self) -> impl Iterator<Item = &ScriptedInput> {
+        self.input.iter()
+    }
+
+    /// Number of commands executed so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Play until the scenario ends or jumps to another scenario
+    ///
+    /// # Errors
+    /// Returns an error if a command fails, a choice is reached without a
+    /// matching scripted input, or the run exceeds the step limit.
+    pub fn run(&mut self) -> EngineResult<HeadlessEnd> {
+        loop {
+            if self.runtime.is_ended() {
+                return Ok(self.ended());
+            }
+            if self.steps >= self.max_steps {
+                return Err(EngineError::ScenarioExecution(format!(
+                    "Scenario did not end within {} commands (stopped in scene '{}')",
+                    self.max_steps,
+                    self.scene_name()
+                )));
+            }
+            self.steps += 1;
+
+            self.record_dialogue();
+            match self.runtime.execute_current_command()? {
+                CommandExecutionResult::Continue
+                | CommandExecutionResult::Wait(_)
+                | CommandExecutionResult::PlayEffect(_)
+                | CommandExecutionResult::PlayVideo { .. } => {
+                    self.runtime.advance_command();
+                }
+                // The new scene starts at its first command
+                CommandExecutionResult::SceneChanged { .. } => {}
+                CommandExecutionResult::ShowChoices(choices) => {
+                    let index = self.next_choice(&choices)?;
+                    self.runtime.select_choice(index)?;
+                }
+                CommandExecutionResult::InputName(name_input) => {
+                    // Without a scripted name the default is used
+                    let name = match self.input.front() {
+                        Some(ScriptedInput::Name(name)) => {
+                            let name = name.clone();
+                            self.input.pop_front();
+                            name
+                        }
+                        _ => name_input.default,
+                    };
+                    self.runtime.submit_name(&name)?;
+                    self.runtime.advance_command();
+                }
+                CommandExecutionResult::JumpToScenario(jump) => {
+                    return Ok(HeadlessEnd::ScenarioJump(jump));
+                }
+                CommandExecutionResult::End => return Ok(self.ended()),
+            }
+        }
+    }
+
+    /// Add the current command's dialogue to the backlog, like the game does
+    /// when it shows the line
+    fn record_dialogue(&mut self) {
+        if let Some(ScenarioCommand::Dialogue { dialogue }) = self.runtime.get_current_command()
+            && let Some(scene_id) = self.runtime.current_scene()
+        {
+            let (text, _) = dialogue.text_tracks(self.runtime.dialogue_languages());
+            let text = TypewriterEffect::strip_tags(&self.runtime.variables().substitute(text));
+            let speaker = dialogue.speaker.clone();
+            let scene_id = scene_id.clone();
+            let command_index = self.runtime.command_index();
+            self.runtime
+                .add_to_backlog(scene_id, command_index, speaker, text);
+        }
+    }
+
+    /// Index of the displayed choice picked by the next scripted input
+    fn next_choice(&mut self, choices: &[ChoiceOption]) -> EngineResult<usize> {
+        let index = match self.input.front() {
+            Some(ScriptedInput::Choose(index)) if *index < choices.len() => Some(*index),
+            Some(ScriptedInput::ChooseText(text)) => {
+                choices.iter().position(|choice| choice.text == *text)
+            }
+            _ => None,
+        };
+        let Some(index) = index else {
+            let options: Vec<&str> = choices.iter().map(|choice| choice.text.as_str()).collect();
+            return Err(EngineError::ScenarioExecution(format!(
+                "No scripted input for the choice in scene '{}' (next input: {:?}, options: {:?})",
+                self.scene_name(),
+                self.input.front(),
+                options
+            )));
+        };
+        self.input.pop_front();
+        Ok(index)
+    }
+
+    fn ended(&self) -> HeadlessEnd {
+        let ending_id = match &self.runtime.scenario().metadata.on_end {
+            EndBehavior::EndingCard { ending_id, .. } => Some(ending_id.clone()),
+            _ => None,
+        };
+        HeadlessEnd::Ended { ending_id }
+    }
+
+    fn scene_name(&self) -> &str {
+        self.runtime
+            .current_scene()
+            .map_or("<none>", |scene| scene.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{Choice, Dialogue, Scenario, ScenarioMetadata, Scene};
+
+    fn route_scenario() -> Scenario {
+        let mut scenario = Scenario::new(
+            ScenarioMetadata::new("test", "Test").with_on_end(EndBehavior::EndingCard {
+                ending_id: "festival".to_string(),
+                title: "Festival".to_string(),
+            }),
+            "start",
+        );
+
+        let mut start = Scene::new("start", "Start");
+        start.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator("Where to?"),
+        });
+        start.add_command(ScenarioCommand::Wait { duration: 2.0 });
+        start.add_command(ScenarioCommand::ShowChoice {
+            choice: Choice::new(vec![
+                ChoiceOption::new("Stay home", "home"),
+                ChoiceOption::new("Go to the festival", "festival").with_flag("went_to_festival"),
+            ]),
+        });
+        scenario.add_scene("start", start);
+
+        for (id, line) in [("home", "A quiet night."), ("festival", "Fireworks!")] {
+            let mut scene = Scene::new(id, id);
+            scene.add_command(ScenarioCommand::Dialogue {
+                dialogue: Dialogue::narrator(line),
+            });
+            scene.add_command(ScenarioCommand::End);
+            scenario.add_scene(id, scene);
+        }
+        scenario
+    }
+
+    fn runner(input: Vec<ScriptedInput>) -> HeadlessRunner {
+        let mut runtime = ScenarioRuntime::new(route_scenario());
+        runtime.start().unwrap();
+        HeadlessRunner::new(runtime).with_input(input)
+    }
+
+    #[test]
+    fn test_headless_plays_scripted_route() {
+        let mut runner = runner(vec![ScriptedInput::ChooseText(
+            "Go to the festival".to_string(),
+        )]);
+        let end = runner.run().unwrap();
+
+        assert_eq!(
+            end,
+            HeadlessEnd::Ended {
+                ending_id: Some("festival".to_string())
+            }
+        );
+        let runtime = runner.runtime();
+        assert!(runtime.flags().is_set(&"went_to_festival".into()));
+        assert_eq!(runtime.current_scene().unwrap().as_str(), "festival");
+        let lines: Vec<&str> = runtime
+            .backlog()
+            .entries()
+            .iter()
+            .map(|entry| entry.text.as_str())
+            .collect();
+        assert_eq!(lines, ["Where to?", "Fireworks!"]);
+        assert_eq!(runner.remaining_input().count(), 0);
+
+        let mut runner = self::runner(vec![ScriptedInput::Choose(0)]);
+        runner.run().unwrap();
+        assert!(!runner.runtime().flags().is_set(&"went_to_festival".into()));
+    }
+
+    #[test]
+    fn test_headless_errors_without_choice_input() {
+        let mut runner = runner(Vec::new());
+        assert!(runner.run().is_err());
+
+        let mut runner = self::runner(vec![ScriptedInput::ChooseText("Sleep".to_string())]);
+        assert!(runner.run().is_err());
+    }
+
+    #[test]
+    fn test_headless_step_limit() {
+        let mut runner = runner(vec![ScriptedInput::Choose(1)]).with_max_steps(2);
+        assert!(runner.run().is_err());
+        assert_eq!(runner.steps(), 2);
+    }
+}
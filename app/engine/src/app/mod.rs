@@ -1,9 +1,20 @@
 //! Application integration module
 //!
-//! This module provides the game loop and engine configuration.
+//! This module provides engine initialization, the game loop (windowed or
+//! headless), replays and engine configuration.
 
 mod config;
+mod engine;
+mod game;
 mod game_loop;
+mod headless;
+mod overrides;
+mod replay;
 
-pub use config::{AudioConfig, EngineConfig};
+pub use config::{AttractModeConfig, AudioConfig, EngineConfig};
+pub use engine::{Engine, EngineBuilder};
+pub use game::{Game, GameContext};
 pub use game_loop::GameLoop;
+pub use headless::{HeadlessEnd, HeadlessRunner, ScriptedInput};
+pub use overrides::{ConfigArgs, ConfigOverride, ConfigOverrides, OverrideSource};
+pub use replay::{REPLAY_FORMAT_VERSION, Replay, ReplayEvent, ReplayOutcome, scenario_hash};
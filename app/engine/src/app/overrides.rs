@@ -0,0 +1,429 @@
+//! Configuration overrides from environment variables and the command line
+//!
+//! Overrides are applied on top of the loaded [`EngineConfig`] in order, so the
+//! layering is: config file < environment variables < command-line flags.
+//!
+//! Keys use the dotted field path of the config, e.g. `window.width` or
+//! `save_path`. Values are TOML literals (`1920`, `0.5`, `true`,
+//! `[1280, 720]`); anything else is taken as a string.
+//!
+//! Environment variables are `NARRATIVE_` followed by the upper-cased key with
+//! dots replaced by underscores, e.g. `NARRATIVE_WINDOW_WIDTH=1920` or
+//! `NARRATIVE_SAVE_PATH=/tmp/saves`.
+
+use super::EngineConfig;
+use narrative_core::{ConfigError, EngineResult};
+use std::path::PathBuf;
+
+/// Prefix of environment variables that override config fields
+pub const ENV_PREFIX: &str = "NARRATIVE_";
+
+/// Where an override came from (for error messages)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverrideSource {
+    /// Environment variable with this name
+    Env(String),
+    /// Command-line flag
+    CommandLine,
+}
+
+/// A single `key = value` override
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverride {
+    /// Dotted field path, e.g. `window.width`
+    pub key: String,
+    /// Value as written by the user
+    pub value: String,
+    /// Where the override came from
+    pub source: OverrideSource,
+}
+
+/// Ordered list of config overrides; later entries win
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigOverrides {
+    entries: Vec<ConfigOverride>,
+}
+
+impl ConfigOverrides {
+    /// Create an empty override list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect overrides from `NARRATIVE_*` environment variables
+    pub fn from_env() -> Self {
+        Self::from_env_vars(std::env::vars())
+    }
+
+    /// Collect overrides from `NARRATIVE_*` variables in `vars`
+    ///
+    /// Variables are sorted by name so the result doesn't depend on the
+    /// environment's iteration order.
+    pub fn from_env_vars(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        let sections = config_sections();
+        let mut vars: Vec<_> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+            .collect();
+        vars.sort();
+
+        let mut overrides = Self::new();
+        for (name, value) in vars {
+            let key = env_var_key(&name[ENV_PREFIX.len()..], &sections);
+            overrides.entries.push(ConfigOverride {
+                key,
+                value,
+                source: OverrideSource::Env(name),
+            });
+        }
+        overrides
+    }
+
+    /// Add a command-line override
+    pub fn push(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.entries.push(ConfigOverride {
+            key: key.into(),
+            value: value.into(),
+            source: OverrideSource::CommandLine,
+        });
+    }
+
+    /// Append all overrides from `other` (they take precedence)
+    pub fn extend(&mut self, other: ConfigOverrides) {
+        self.entries.extend(other.entries);
+    }
+
+    /// Get the overrides in application order
+    pub fn entries(&self) -> &[ConfigOverride] {
+        &self.entries
+    }
+
+    /// Check if there are no overrides
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl EngineConfig {
+    /// Apply overrides in order and validate the result
+    ///
+    /// # Errors
+    /// Returns an error naming the override if its key isn't a config field,
+    /// its value has the wrong type, or the resulting config fails validation.
+    pub fn apply_overrides(&mut self, overrides: &ConfigOverrides) -> EngineResult<()> {
+        if overrides.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = toml::Table::try_from(&*self)?;
+        for entry in overrides.entries() {
+            let origin = match &entry.source {
+                OverrideSource::Env(name) => format!("{} (from {})", entry.key, name),
+                OverrideSource::CommandLine => entry.key.clone(),
+            };
+
+            set_key(&mut table, &entry.key, &entry.value)
+                .and_then(|()| {
+                    table
+                        .clone()
+                        .try_into::<EngineConfig>()
+                        .map(|_| ())
+                        .map_err(|e| e.message().to_string())
+                })
+                .map_err(|e| ConfigError::InvalidValue(origin, e))?;
+        }
+
+        let config: EngineConfig = table.try_into()?;
+        config.validate()?;
+        *self = config;
+        Ok(())
+    }
+}
+
+/// Engine command-line options
+///
+/// Recognized flags:
+/// - `--config <path>`: load the engine config from this file
+/// - `--set <key>=<value>` or `--<key>=<value>` (key containing a `.`): override a field
+/// - `--print-config`: print the effective config as RON and exit
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigArgs {
+    /// Config file given with `--config`
+    pub config_path: Option<PathBuf>,
+    /// Overrides given on the command line, in order
+    pub overrides: ConfigOverrides,
+    /// Whether `--print-config` was given
+    pub print_config: bool,
+}
+
+impl ConfigArgs {
+    /// Parse the process arguments (skipping the program name)
+    pub fn from_env_args() -> EngineResult<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Parse arguments (without the program name)
+    pub fn parse(args: impl IntoIterator<Item = String>) -> EngineResult<Self> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--print-config" => parsed.print_config = true,
+                "--config" => {
+                    let path = args.next().ok_or_else(|| {
+                        ConfigError::MissingField("path after --config".to_string())
+                    })?;
+                    parsed.config_path = Some(PathBuf::from(path));
+                }
+                "--set" => {
+                    let assignment = args.next().ok_or_else(|| {
+                        ConfigError::MissingField("key=value after --set".to_string())
+                    })?;
+                    let (key, value) = split_assignment(&assignment)?;
+                    parsed.overrides.push(key, value);
+                }
+                _ => match arg.strip_prefix("--") {
+                    Some(assignment) if assignment.contains('.') => {
+                        let (key, value) = match assignment.split_once('=') {
+                            Some((key, value)) => (key.to_string(), value.to_string()),
+                            None => {
+                                let value = args.next().ok_or_else(|| {
+                                    ConfigError::MissingField(format!("value after --{}", arg))
+                                })?;
+                                (assignment.to_string(), value)
+                            }
+                        };
+                        parsed.overrides.push(key, value);
+                    }
+                    _ => {
+                        return Err(ConfigError::Other(format!(
+                            "Unknown argument '{}' (expected --config, --set, --print-config or --<key>=<value>)",
+                            arg
+                        ))
+                        .into());
+                    }
+                },
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Split `key=value`
+fn split_assignment(assignment: &str) -> EngineResult<(String, String)> {
+    assignment
+        .split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or_else(|| {
+            ConfigError::InvalidValue(assignment.to_string(), "expected key=value".to_string())
+                .into()
+        })
+}
+
+/// Names of the config sections (nested tables), e.g. `window`
+fn config_sections() -> Vec<String> {
+    toml::Table::try_from(EngineConfig::default())
+        .map(|table| {
+            table
+                .into_iter()
+                .filter(|(_, value)| value.is_table())
+                .map(|(name, _)| name)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Map an environment variable name (without prefix) to a dotted key
+///
+/// `WINDOW_WIDTH` becomes `window.width`; names that don't start with a
+/// section are top-level fields (`SAVE_PATH` becomes `save_path`).
+fn env_var_key(name: &str, sections: &[String]) -> String {
+    let name = name.to_ascii_lowercase();
+    sections
+        .iter()
+        .find_map(|section| {
+            name.strip_prefix(section.as_str())
+                .and_then(|rest| rest.strip_prefix('_'))
+                .map(|field| format!("{}.{}", section, field))
+        })
+        .unwrap_or(name)
+}
+
+/// Parse a user-supplied value as a TOML literal, falling back to a string
+fn parse_value(value: &str) -> toml::Value {
+    format!("value = {}", value)
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()))
+}
+
+/// Set a dotted key in a TOML table
+fn set_key(table: &mut toml::Table, key: &str, value: &str) -> Result<(), String> {
+    let mut parts = key.split('.').peekable();
+    let mut current = table;
+
+    while let Some(part) = parts.next() {
+        if part.is_empty() {
+            return Err("empty key segment".to_string());
+        }
+
+        if parts.peek().is_none() {
+            let mut new_value = parse_value(value);
+            // Allow `1` for float fields
+            if let (Some(toml::Value::Float(_)), toml::Value::Integer(i)) =
+                (current.get(part), &new_value)
+            {
+                new_value = toml::Value::Float(*i as f64);
+            }
+            // Keep string fields strings even if the value looks like a number
+            if let (Some(toml::Value::String(_)), false) = (current.get(part), new_value.is_str()) {
+                new_value = toml::Value::String(value.to_string());
+            }
+            current.insert(part.to_string(), new_value);
+            return Ok(());
+        }
+
+        current = current
+            .entry(part.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| format!("'{}' is not a config section", part))?;
+    }
+
+    Err("empty key".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_env_var_mapping() {
+        let overrides = ConfigOverrides::from_env_vars(vars(&[
+            ("NARRATIVE_WINDOW_WIDTH", "1920"),
+            ("NARRATIVE_SAVE_PATH", "/tmp/saves"),
+            ("NARRATIVE_GRAPHICS_TARGET_FPS", "144"),
+            ("PATH", "/usr/bin"),
+        ]));
+
+        let keys: Vec<_> = overrides.entries().iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["graphics.target_fps", "save_path", "window.width"]
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let mut overrides = ConfigOverrides::new();
+        overrides.push("window.width", "1920");
+        overrides.push("window.title", "123");
+        overrides.push("audio.music_volume", "1");
+        overrides.push("graphics.virtual_resolution", "[1280, 720]");
+        overrides.push("save_path", "/tmp/saves");
+
+        let mut config = EngineConfig::default();
+        config.apply_overrides(&overrides).unwrap();
+
+        assert_eq!(config.window.width, 1920);
+        assert_eq!(config.window.title, "123");
+        assert_eq!(config.audio.music_volume, 1.0);
+        assert_eq!(config.graphics.virtual_resolution, Some([1280, 720]));
+        assert_eq!(config.save_path, PathBuf::from("/tmp/saves"));
+    }
+
+    #[test]
+    fn test_command_line_overrides_env() {
+        let mut overrides =
+            ConfigOverrides::from_env_vars(vars(&[("NARRATIVE_WINDOW_WIDTH", "1024")]));
+        let mut cli = ConfigOverrides::new();
+        cli.push("window.width", "1920");
+        overrides.extend(cli);
+
+        let mut config = EngineConfig::default();
+        config.apply_overrides(&overrides).unwrap();
+        assert_eq!(config.window.width, 1920);
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_unknown_key() {
+        let overrides = ConfigOverrides::from_env_vars(vars(&[("NARRATIVE_WINDOW_WIDHT", "1")]));
+
+        let mut config = EngineConfig::default();
+        let err = config.apply_overrides(&overrides).unwrap_err().to_string();
+        assert!(err.contains("window.widht"));
+        assert!(err.contains("NARRATIVE_WINDOW_WIDHT"));
+        assert_eq!(config.window.width, 1280);
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_wrong_type() {
+        let mut overrides = ConfigOverrides::new();
+        overrides.push("window.width", "wide");
+
+        let mut config = EngineConfig::default();
+        assert!(config.apply_overrides(&overrides).is_err());
+    }
+
+    #[test]
+    fn test_apply_overrides_validates() {
+        let mut overrides = ConfigOverrides::new();
+        overrides.push("audio.master_volume", "2.0");
+
+        let mut config = EngineConfig::default();
+        assert!(config.apply_overrides(&overrides).is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = ConfigArgs::parse(
+            [
+                "--config",
+                "game.ron",
+                "--set",
+                "window.width=1920",
+                "--audio.enabled=false",
+                "--window.height",
+                "1080",
+                "--print-config",
+            ]
+            .map(String::from),
+        )
+        .unwrap();
+
+        assert_eq!(args.config_path, Some(PathBuf::from("game.ron")));
+        assert!(args.print_config);
+        let overrides: Vec<_> = args
+            .overrides
+            .entries()
+            .iter()
+            .map(|e| (e.key.as_str(), e.value.as_str()))
+            .collect();
+        assert_eq!(
+            overrides,
+            vec![
+                ("window.width", "1920"),
+                ("audio.enabled", "false"),
+                ("window.height", "1080"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(ConfigArgs::parse(["--fullscreen".to_string()]).is_err());
+        assert!(ConfigArgs::parse(["--config".to_string()]).is_err());
+        assert!(ConfigArgs::parse(["--set".to_string(), "width".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,426 @@
+//! Replay files
+//!
+//! A [`Replay`] records the choices and names entered during one playthrough
+//! of a scenario, with the play time each was made at, so the playthrough can
+//! be attached to a bug report or checked for a speedrun or score. Another
+//! copy of the game plays it back headlessly (see [`HeadlessRunner`]) and gets
+//! the same result: the replay keeps the choice shuffle seed and the variables
+//! the scenario started with, and a hash of the scenario content rejects
+//! replays recorded against a different version of it.
+//!
+//! ```no_run
+//! use narrative_engine::app::Replay;
+//! use narrative_engine::runtime::ScenarioRuntime;
+//!
+//! let replay = Replay::load("saves/replays/chapter_01.ron")?;
+//! let runtime = ScenarioRuntime::from_toml("assets/scenarios/chapter_01.toml")?;
+//! let outcome = replay.verify(runtime.scenario().clone())?;
+//! println!("Reached {:?} in {:.0}s", outcome.scene, replay.play_time_secs);
+//! # Ok::<(), narrative_engine::EngineError>(())
+//! ```
+
+use super::headless::{HeadlessRunner, ScriptedInput};
+use crate::error::{EngineError, EngineResult};
+use crate::runtime::ScenarioRuntime;
+use narrative_core::{Scenario, VariableId, VariableValue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Version of the replay file format
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+/// One recorded player input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    /// Play time in seconds when the input was made
+    pub time_secs: f64,
+    /// Scene the input was made in
+    pub scene: String,
+    /// The input
+    pub input: ScriptedInput,
+}
+
+/// State a playthrough ended in, compared on playback
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayOutcome {
+    /// Scene the scenario ended in
+    pub scene: Option<String>,
+    /// Flags that were set, with their values
+    pub flags: BTreeMap<String, bool>,
+    /// Variables and their values
+    pub variables: BTreeMap<String, VariableValue>,
+}
+
+impl ReplayOutcome {
+    /// Outcome of the runtime's playthrough so far
+    pub fn of(runtime: &ScenarioRuntime) -> Self {
+        Self {
+            scene: runtime
+                .current_scene()
+                .map(|scene| scene.as_str().to_string()),
+            flags: runtime
+                .flags()
+                .iter()
+                .map(|(flag, value)| (flag.0.clone(), value))
+                .collect(),
+            variables: variable_map(runtime),
+        }
+    }
+}
+
+/// Recorded playthrough of a scenario
+///
+/// See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    /// Replay file format version
+    pub format_version: u32,
+    /// Version of the engine that recorded the replay
+    pub engine_version: String,
+    /// ID of the played scenario
+    pub scenario_id: String,
+    /// Hash of the scenario content (see [`scenario_hash`])
+    pub scenario_hash: u64,
+    /// Seed choices were shuffled with
+    pub choice_seed: u64,
+    /// Variables set before the first command ran (e.g. from the player profile)
+    #[serde(default)]
+    pub start_variables: BTreeMap<String, VariableValue>,
+    /// Recorded inputs, in order
+    #[serde(default)]
+    pub events: Vec<ReplayEvent>,
+    /// Play time in seconds, up to the end once finished
+    #[serde(default)]
+    pub play_time_secs: f64,
+    /// State the playthrough ended in, set by [`Self::finish`]
+    #[serde(default)]
+    pub outcome: Option<ReplayOutcome>,
+}
+
+impl Replay {
+    /// Start recording a playthrough of the runtime's scenario
+    ///
+    /// Call this after [`ScenarioRuntime::start`], before the first command
+    /// runs, so the recorded variables are the ones the scenario started with.
+    ///
+    /// # Errors
+    /// Returns an error if the scenario cannot be hashed.
+    pub fn new(runtime: &ScenarioRuntime) -> EngineResult<Self> {
+        let scenario = runtime.scenario();
+        Ok(Self {
+            format_version: REPLAY_FORMAT_VERSION,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            scenario_id: scenario.metadata.id.clone(),
+            scenario_hash: scenario_hash(scenario)?,
+            choice_seed: runtime.choice_seed(),
+            start_variables: variable_map(runtime),
+            events: Vec::new(),
+            play_time_secs: 0.0,
+            outcome: None,
+        })
+    }
+
+    /// Add play time
+    pub fn tick(&mut self, delta: f32) {
+        self.play_time_secs += f64::from(delta);
+    }
+
+    /// Record an input made at the runtime's current position
+    pub fn record(&mut self, input: ScriptedInput, runtime: &ScenarioRuntime) {
+        let scene = runtime
+            .current_scene()
+            .map(|scene| scene.as_str().to_string())
+            .unwrap_or_default();
+        self.events.push(ReplayEvent {
+            time_secs: self.play_time_secs,
+            scene,
+            input,
+        });
+    }
+
+    /// Record the state the playthrough ended in
+    pub fn finish(&mut self, runtime: &ScenarioRuntime) {
+        self.outcome = Some(ReplayOutcome::of(runtime));
+    }
+
+    /// Whether [`Self::finish`] has been called
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Write the replay to a RON file, creating its directory
+    pub fn save(&self, path: impl AsRef<Path>) -> EngineResult<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let serialized = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| {
+                EngineError::SaveOperation(format!("Failed to serialize replay: {}", e))
+            })?;
+        std::fs::write(path, serialized)?;
+        Ok(())
+    }
+
+    /// Read a replay from a RON file
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed, or was written
+    /// in a newer format.
+    pub fn load(path: impl AsRef<Path>) -> EngineResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        let replay: Self = ron::from_str(&contents).map_err(|e| {
+            EngineError::LoadOperation(format!(
+                "Failed to parse replay '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        if replay.format_version > REPLAY_FORMAT_VERSION {
+            return Err(EngineError::LoadOperation(format!(
+                "Replay '{}' has format version {}, newest supported is {}",
+                path.display(),
+                replay.format_version,
+                REPLAY_FORMAT_VERSION
+            )));
+        }
+        Ok(replay)
+    }
+
+    /// Create a headless runner that plays the replay on a scenario
+    ///
+    /// # Errors
+    /// Returns an error if the scenario is not the one the replay was
+    /// recorded on, or it cannot be started.
+    pub fn runner(&self, scenario: Scenario) -> EngineResult<HeadlessRunner> {
+        if scenario.metadata.id != self.scenario_id {
+            return Err(EngineError::ScenarioExecution(format!(
+                "Replay is for scenario '{}', not '{}'",
+                self.scenario_id, scenario.metadata.id
+            )));
+        }
+        let hash = scenario_hash(&scenario)?;
+        if hash != self.scenario_hash {
+            return Err(EngineError::ScenarioExecution(format!(
+                "Scenario '{}' changed since the replay was recorded (hash {:016x}, expected {:016x})",
+                self.scenario_id, hash, self.scenario_hash
+            )));
+        }
+
+        let mut runtime = ScenarioRuntime::new(scenario);
+        runtime.set_choice_seed(self.choice_seed);
+        for (name, value) in &self.start_variables {
+            runtime
+                .variables_mut()
+                .set(VariableId::new(name.clone()), value.clone());
+        }
+        runtime.start()?;
+        Ok(HeadlessRunner::new(runtime)
+            .with_input(self.events.iter().map(|event| event.input.clone())))
+    }
+
+    /// Play the replay on a scenario and return the state it ends in
+    ///
+    /// # Errors
+    /// Returns an error if the runner cannot be created (see
+    /// [`Self::runner`]) or the playthrough fails.
+    pub fn play(&self, scenario: Scenario) -> EngineResult<ReplayOutcome> {
+        let mut runner = self.runner(scenario)?;
+        runner.run()?;
+        Ok(ReplayOutcome::of(runner.runtime()))
+    }
+
+    /// Play the replay and check it ends in the recorded state
+    ///
+    /// # Errors
+    /// Returns an error if playback fails (see [`Self::play`]), the replay
+    /// was never finished, or playback ends in a different state.
+    pub fn verify(&self, scenario: Scenario) -> EngineResult<ReplayOutcome> {
+        let Some(expected) = &self.outcome else {
+            return Err(EngineError::ScenarioExecution(
+                "Replay was not recorded to the end".to_string(),
+            ));
+        };
+        let outcome = self.play(scenario)?;
+        if outcome != *expected {
+            return Err(EngineError::ScenarioExecution(format!(
+                "Replay ended in a different state: expected {:?}, got {:?}",
+                expected, outcome
+            )));
+        }
+        Ok(outcome)
+    }
+}
+
+/// Hash of a scenario's content (FNV-1a)
+///
+/// Tables are hashed with their keys sorted, so the hash does not depend on
+/// map order and is the same in every copy of the game.
+///
+/// # Errors
+/// Returns an error if the scenario cannot be serialized.
+pub fn scenario_hash(scenario: &Scenario) -> EngineResult<u64> {
+    let value = toml::Value::try_from(scenario)
+        .map_err(|e| EngineError::Other(format!("Failed to serialize scenario: {}", e)))?;
+    let mut hash = 0xCBF2_9CE4_8422_2325;
+    hash_value(&value, &mut hash);
+    Ok(hash)
+}
+
+fn hash_value(value: &toml::Value, hash: &mut u64) {
+    match value {
+        toml::Value::Table(table) => {
+            let mut entries: Vec<_> = table.iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+            hash_bytes(hash, b"{");
+            for (key, value) in entries {
+                hash_bytes(hash, format!("{:?}=", key).as_bytes());
+                hash_value(value, hash);
+                hash_bytes(hash, b",");
+            }
+            hash_bytes(hash, b"}");
+        }
+        toml::Value::Array(items) => {
+            hash_bytes(hash, b"[");
+            for item in items {
+                hash_value(item, hash);
+                hash_bytes(hash, b",");
+            }
+            hash_bytes(hash, b"]");
+        }
+        scalar => hash_bytes(hash, scalar.to_string().as_bytes()),
+    }
+}
+
+fn hash_bytes(hash: &mut u64, bytes: &[u8]) {
+    for byte in bytes {
+        *hash = (*hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01B3);
+    }
+}
+
+fn variable_map(runtime: &ScenarioRuntime) -> BTreeMap<String, VariableValue> {
+    runtime
+        .variables()
+        .iter()
+        .map(|(variable, value)| (variable.0.clone(), value.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{
+        Choice, ChoiceOption, Dialogue, ScenarioCommand, ScenarioMetadata, Scene,
+    };
+
+    fn route_scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+
+        let mut start = Scene::new("start", "Start");
+        start.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator("Where to?"),
+        });
+        start.add_command(ScenarioCommand::ShowChoice {
+            choice: Choice::new(vec![
+                ChoiceOption::new("Stay home", "home"),
+                ChoiceOption::new("Go to the festival", "festival").with_flag("went_to_festival"),
+                ChoiceOption::new("Go to the beach", "home").with_flag("went_to_beach"),
+            ])
+            .with_shuffle(true),
+        });
+        scenario.add_scene("start", start);
+
+        for (id, line) in [("home", "A quiet night."), ("festival", "Fireworks!")] {
+            let mut scene = Scene::new(id, id);
+            scene.add_command(ScenarioCommand::Dialogue {
+                dialogue: Dialogue::narrator(line),
+            });
+            scene.add_command(ScenarioCommand::End);
+            scenario.add_scene(id, scene);
+        }
+        scenario
+    }
+
+    /// Play the route like the game, recording the festival choice
+    fn record(seed: u64) -> Replay {
+        let mut runtime = ScenarioRuntime::new(route_scenario());
+        runtime.set_choice_seed(seed);
+        runtime.variables_mut().set(
+            VariableId::new("player.name"),
+            VariableValue::String("Alice".to_string()),
+        );
+        runtime.start().unwrap();
+        let mut replay = Replay::new(&runtime).unwrap();
+
+        runtime.advance_command();
+        runtime.execute_current_command().unwrap();
+        replay.tick(2.5);
+        let index = runtime
+            .displayed_choices()
+            .iter()
+            .position(|option| option.text == "Go to the festival")
+            .unwrap();
+        replay.record(ScriptedInput::Choose(index), &runtime);
+        runtime.select_choice(index).unwrap();
+        replay.tick(1.0);
+        replay.finish(&runtime);
+        replay
+    }
+
+    #[test]
+    fn test_replay_plays_back_recorded_choices() {
+        for seed in [1, 7, 42] {
+            let replay = record(seed);
+            assert_eq!(replay.events.len(), 1);
+            assert_eq!(replay.events[0].scene, "start");
+            assert!((replay.events[0].time_secs - 2.5).abs() < f64::EPSILON);
+
+            let outcome = replay.verify(route_scenario()).unwrap();
+            assert_eq!(outcome.scene.as_deref(), Some("festival"));
+            assert_eq!(outcome.flags.get("went_to_festival"), Some(&true));
+            assert_eq!(
+                outcome.variables.get("player.name"),
+                Some(&VariableValue::String("Alice".to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_replay_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("replays").join("test.ron");
+        let replay = record(3);
+        replay.save(&path).unwrap();
+
+        let loaded = Replay::load(&path).unwrap();
+        assert_eq!(loaded, replay);
+        assert!(loaded.verify(route_scenario()).is_ok());
+    }
+
+    #[test]
+    fn test_replay_rejects_changed_scenario() {
+        let replay = record(5);
+        let mut changed = route_scenario();
+        if let Some(scene) = changed.scenes.get_mut("home") {
+            scene.add_command(ScenarioCommand::End);
+        }
+
+        let err = replay.play(changed).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("changed since the replay was recorded")
+        );
+    }
+
+    #[test]
+    fn test_scenario_hash_is_stable() {
+        let hash = scenario_hash(&route_scenario()).unwrap();
+        for _ in 0..5 {
+            assert_eq!(scenario_hash(&route_scenario()).unwrap(), hash);
+        }
+    }
+}
@@ -32,8 +32,16 @@ impl TextureCache {
     }
 
     /// Insert a texture into the cache
-    pub fn insert(&mut self, asset_ref: AssetRef, handle: TextureHandle) {
-        self.cache.put(asset_ref, handle);
+    ///
+    /// Returns the entry the texture displaced: the least recently used one
+    /// when the cache was full, or the previous texture of `asset_ref`. Its
+    /// GPU texture can be released by the caller.
+    pub fn insert(
+        &mut self,
+        asset_ref: AssetRef,
+        handle: TextureHandle,
+    ) -> Option<(AssetRef, TextureHandle)> {
+        self.cache.push(asset_ref, handle)
     }
 
     /// Clear the cache
@@ -41,6 +49,16 @@ impl TextureCache {
         self.cache.clear();
     }
 
+    /// Number of cached textures
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
     /// Get cache capacity
     pub fn capacity(&self) -> usize {
         self.cache.cap().get()
@@ -127,7 +145,9 @@ mod tests {
         cache.insert(asset2.clone(), TextureHandle::new(2));
 
         // Insert third item - should evict first
-        cache.insert(asset3.clone(), TextureHandle::new(3));
+        let evicted = cache.insert(asset3.clone(), TextureHandle::new(3));
+        assert_eq!(evicted, Some((asset1.clone(), TextureHandle::new(1))));
+        assert_eq!(cache.len(), 2);
 
         assert_eq!(cache.get(&asset1), None); // Evicted
         assert!(cache.get(&asset2).is_some());
@@ -139,8 +159,9 @@ mod tests {
         let mut cache = TextureCache::new().unwrap();
         let asset = AssetRef::new("texture.png");
 
-        cache.insert(asset.clone(), TextureHandle::new(1));
-        cache.insert(asset.clone(), TextureHandle::new(2));
+        assert_eq!(cache.insert(asset.clone(), TextureHandle::new(1)), None);
+        let replaced = cache.insert(asset.clone(), TextureHandle::new(2));
+        assert_eq!(replaced, Some((asset.clone(), TextureHandle::new(1))));
 
         assert_eq!(cache.get(&asset), Some(&TextureHandle::new(2)));
     }
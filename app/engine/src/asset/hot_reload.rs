@@ -1,7 +1,8 @@
-//! Hot-reload system for asset manifests
+//! Hot-reload system for asset manifests and scenarios
 //!
 //! This module provides file watching and automatic reloading of RON manifest files
-//! when they change on disk. This is only available with the `hot-reload` feature.
+//! and scenario TOML files when they change on disk. This is only available with the
+//! `hot-reload` feature.
 //!
 //! # Example
 //!
@@ -220,6 +221,83 @@ impl HotReloadWatcher {
     }
 }
 
+/// Watcher for a scenario file and the files it includes
+///
+/// Included files usually sit next to or below the scenario file, so its
+/// whole directory is watched for changed `.toml` files.
+pub struct ScenarioWatcher {
+    _watcher: RecommendedWatcher,
+    scenario_path: PathBuf,
+    changes: Receiver<PathBuf>,
+}
+
+impl ScenarioWatcher {
+    /// Start watching the directory of a scenario file
+    pub fn new(scenario_path: impl AsRef<Path>) -> Result<Self, HotReloadError> {
+        let scenario_path = scenario_path.as_ref().to_path_buf();
+        let dir = match scenario_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            Some(_) => PathBuf::from("."),
+            None => return Err(HotReloadError::InvalidPath(scenario_path)),
+        };
+        let (tx, rx): (Sender<PathBuf>, Receiver<PathBuf>) = unbounded();
+        let debouncer = Mutex::new(DebounceTracker::new(Duration::from_millis(500)));
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res: Result<Event, notify::Error>| {
+                let Ok(event) = res else {
+                    return;
+                };
+                if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                    return;
+                }
+                for path in event.paths {
+                    if !is_scenario_file(&path) {
+                        continue;
+                    }
+                    let Ok(mut debouncer) = debouncer.lock() else {
+                        tracing::error!("Debouncer mutex poisoned");
+                        return;
+                    };
+                    if debouncer.should_reload(&path) {
+                        debouncer.mark_reloaded(path.clone());
+                        tracing::info!("🔥 Detected change in {}", path.display());
+                        let _ = tx.send(path);
+                    }
+                }
+            },
+            Config::default(),
+        )?;
+        watcher
+            .watch(&dir, RecursiveMode::Recursive)
+            .map_err(|e| HotReloadError::WatchFailed(e.to_string()))?;
+        tracing::info!("🔥 Watching scenario files in: {}", dir.display());
+
+        Ok(Self {
+            _watcher: watcher,
+            scenario_path,
+            changes: rx,
+        })
+    }
+
+    /// Scenario file this watcher was created for
+    pub fn scenario_path(&self) -> &Path {
+        &self.scenario_path
+    }
+
+    /// Take the changes since the last call
+    ///
+    /// Returns the last changed file, or `None` if nothing changed.
+    pub fn take_change(&self) -> Option<PathBuf> {
+        self.changes.try_iter().last()
+    }
+}
+
+/// Whether a changed file can be part of a scenario
+fn is_scenario_file(path: &Path) -> bool {
+    path.extension().and_then(|s| s.to_str()) == Some("toml")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,6 +348,17 @@ mod tests {
         assert!(tracker.should_reload(&path));
     }
 
+    #[test]
+    fn test_is_scenario_file() {
+        assert!(is_scenario_file(Path::new(
+            "assets/scenarios/chapter_01.toml"
+        )));
+        assert!(!is_scenario_file(Path::new("assets/manifests/bgm.ron")));
+        assert!(!is_scenario_file(Path::new(
+            "assets/scenarios/.chapter_01.toml.swp"
+        )));
+    }
+
     #[test]
     fn test_reload_event_filename() {
         assert_eq!(ReloadEvent::Characters.filename(), "characters.ron");
@@ -0,0 +1,296 @@
+//! Decoded image cache
+//!
+//! GPU textures are released when their cache evicts them, after which a
+//! background shown again would be read and decoded from disk once more.
+//! [`DecodedImageCache`] keeps the decoded RGBA pixels of recently used
+//! images within a byte budget, so re-uploading them skips the file system
+//! and the decoder.
+
+use crate::error::{EngineError, EngineResult};
+use image::RgbaImage;
+use lru::LruCache;
+use narrative_core::AssetRef;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Default byte budget of [`DecodedImageCache`] (256 MiB)
+pub const DEFAULT_IMAGE_CACHE_BUDGET: usize = 256 * 1024 * 1024;
+
+/// Cache of decoded images with LRU eviction and a byte budget
+///
+/// Images are shared as `Arc<RgbaImage>`, so a cached image stays valid for
+/// its holders after being evicted. Images larger than the whole budget are
+/// not cached.
+#[derive(Debug)]
+pub struct DecodedImageCache {
+    cache: LruCache<AssetRef, Arc<RgbaImage>>,
+    budget: usize,
+    used_bytes: usize,
+}
+
+impl DecodedImageCache {
+    /// Create a new image cache with the default budget
+    pub fn new() -> Self {
+        Self::with_budget(DEFAULT_IMAGE_CACHE_BUDGET)
+    }
+
+    /// Create a new image cache holding at most `budget` bytes of pixels
+    ///
+    /// A budget of zero disables caching.
+    pub fn with_budget(budget: usize) -> Self {
+        Self {
+            cache: LruCache::unbounded(),
+            budget,
+            used_bytes: 0,
+        }
+    }
+
+    /// Get a cached image, marking it as recently used
+    pub fn get(&mut self, asset_ref: &AssetRef) -> Option<Arc<RgbaImage>> {
+        self.cache.get(asset_ref).cloned()
+    }
+
+    /// Insert an image, evicting least recently used images to stay in budget
+    pub fn insert(&mut self, asset_ref: AssetRef, image: Arc<RgbaImage>) {
+        self.remove(&asset_ref);
+        let size = image_bytes(&image);
+        if size > self.budget {
+            return;
+        }
+        while self.used_bytes + size > self.budget {
+            match self.cache.pop_lru() {
+                Some((_, evicted)) => self.used_bytes -= image_bytes(&evicted),
+                None => break,
+            }
+        }
+        self.used_bytes += size;
+        self.cache.put(asset_ref, image);
+    }
+
+    /// Get a cached image, or load and cache it
+    ///
+    /// # Errors
+    /// Returns the error of `load` when the image is not cached.
+    pub fn get_or_load(
+        &mut self,
+        asset_ref: &AssetRef,
+        load: impl FnOnce(&AssetRef) -> EngineResult<RgbaImage>,
+    ) -> EngineResult<Arc<RgbaImage>> {
+        if let Some(image) = self.get(asset_ref) {
+            return Ok(image);
+        }
+        let image = Arc::new(load(asset_ref)?);
+        self.insert(asset_ref.clone(), Arc::clone(&image));
+        Ok(image)
+    }
+
+    /// Remove an image from the cache
+    pub fn remove(&mut self, asset_ref: &AssetRef) -> Option<Arc<RgbaImage>> {
+        let image = self.cache.pop(asset_ref)?;
+        self.used_bytes -= image_bytes(&image);
+        Some(image)
+    }
+
+    /// Clear the cache
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.used_bytes = 0;
+    }
+
+    /// Number of cached images
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Bytes of pixel data currently cached
+    pub fn used_bytes(&self) -> usize {
+        self.used_bytes
+    }
+
+    /// Byte budget of the cache
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Change the byte budget, evicting images if the cache is over it
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        while self.used_bytes > self.budget {
+            match self.cache.pop_lru() {
+                Some((_, evicted)) => self.used_bytes -= image_bytes(&evicted),
+                None => break,
+            }
+        }
+    }
+}
+
+impl Default for DecodedImageCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Size of an image's pixel data in bytes
+fn image_bytes(image: &RgbaImage) -> usize {
+    image.as_raw().len()
+}
+
+/// Read and decode an image file as RGBA
+///
+/// With the `mmap` feature the file is memory-mapped and decoded in place
+/// instead of being copied into a buffer first.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or decoded.
+pub fn load_image_file(path: impl AsRef<Path>) -> EngineResult<RgbaImage> {
+    let path = path.as_ref();
+    let decode = |bytes: &[u8]| {
+        image::load_from_memory(bytes)
+            .map(|image| image.to_rgba8())
+            .map_err(|e| {
+                EngineError::AssetLoad(format!("Failed to decode {}: {}", path.display(), e))
+            })
+    };
+    let read_error = |e: std::io::Error| {
+        EngineError::AssetLoad(format!("Failed to read {}: {}", path.display(), e))
+    };
+
+    #[cfg(feature = "mmap")]
+    {
+        let file = std::fs::File::open(path).map_err(read_error)?;
+        // Safety: the map is only read while decoding, and assets are not
+        // expected to be truncated while the game is running. If one is,
+        // the decoder sees the changed bytes and may fail, like a read would.
+        let map = unsafe { memmap2::Mmap::map(&file) }.map_err(read_error)?;
+        decode(&map)
+    }
+    #[cfg(not(feature = "mmap"))]
+    {
+        let bytes = std::fs::read(path).map_err(read_error)?;
+        decode(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+    use tempfile::TempDir;
+
+    /// A `size`x`size` image, 4 * size * size bytes
+    fn image(size: u32) -> Arc<RgbaImage> {
+        Arc::new(RgbaImage::new(size, size))
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut cache = DecodedImageCache::with_budget(1024);
+        let asset = AssetRef::new("bg/room.png");
+        cache.insert(asset.clone(), image(4));
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.used_bytes(), 64);
+        assert_eq!(cache.get(&asset).map(|image| image.width()), Some(4));
+        assert!(cache.get(&AssetRef::new("bg/street.png")).is_none());
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_over_budget() {
+        // Room for two 4x4 images
+        let mut cache = DecodedImageCache::with_budget(128);
+        let room = AssetRef::new("bg/room.png");
+        let street = AssetRef::new("bg/street.png");
+        let park = AssetRef::new("bg/park.png");
+        cache.insert(room.clone(), image(4));
+        cache.insert(street.clone(), image(4));
+
+        // Using the room makes the street the least recently used
+        assert!(cache.get(&room).is_some());
+        cache.insert(park.clone(), image(4));
+
+        assert!(cache.get(&street).is_none());
+        assert!(cache.get(&room).is_some());
+        assert!(cache.get(&park).is_some());
+        assert_eq!(cache.used_bytes(), 128);
+    }
+
+    #[test]
+    fn test_image_over_budget_is_not_cached() {
+        let mut cache = DecodedImageCache::with_budget(100);
+        let small = AssetRef::new("small.png");
+        cache.insert(small.clone(), image(2));
+        cache.insert(AssetRef::new("large.png"), image(8));
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&small).is_some());
+
+        let mut disabled = DecodedImageCache::with_budget(0);
+        disabled.insert(small, image(1));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_replace_and_remove_track_bytes() {
+        let mut cache = DecodedImageCache::with_budget(1024);
+        let asset = AssetRef::new("bg/room.png");
+        cache.insert(asset.clone(), image(4));
+        cache.insert(asset.clone(), image(2));
+        assert_eq!(cache.used_bytes(), 16);
+
+        assert!(cache.remove(&asset).is_some());
+        assert_eq!(cache.used_bytes(), 0);
+
+        cache.insert(asset, image(4));
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.used_bytes(), 0);
+    }
+
+    #[test]
+    fn test_set_budget_evicts() {
+        let mut cache = DecodedImageCache::with_budget(1024);
+        cache.insert(AssetRef::new("a.png"), image(4));
+        cache.insert(AssetRef::new("b.png"), image(4));
+
+        cache.set_budget(64);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&AssetRef::new("b.png")).is_some());
+    }
+
+    #[test]
+    fn test_get_or_load_loads_once() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bg.png");
+        RgbaImage::from_pixel(3, 2, Rgba([10, 20, 30, 255]))
+            .save(&path)
+            .unwrap();
+        let asset = AssetRef::new(path.to_string_lossy());
+
+        let mut cache = DecodedImageCache::new();
+        let mut loads = 0;
+        for _ in 0..2 {
+            let image = cache
+                .get_or_load(&asset, |asset| {
+                    loads += 1;
+                    load_image_file(asset.path())
+                })
+                .unwrap();
+            assert_eq!(image.dimensions(), (3, 2));
+            assert_eq!(image.get_pixel(0, 0), &Rgba([10, 20, 30, 255]));
+        }
+        assert_eq!(loads, 1);
+
+        let missing = AssetRef::new("missing/bg.png");
+        assert!(
+            cache
+                .get_or_load(&missing, |asset| load_image_file(asset.path()))
+                .is_err()
+        );
+        assert_eq!(cache.len(), 1);
+    }
+}
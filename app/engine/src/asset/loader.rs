@@ -5,17 +5,23 @@
 //! - Scenarios (TOML files)
 //! - Textures (images)
 //! - Audio (BGM, SE)
+//! - Preloading of the assets of upcoming scenes
 
-use super::{AssetRegistry, TextureCache, TextureHandle};
+use super::{
+    AssetPreloader, AssetRegistry, DEFAULT_PRELOAD_BUDGET, DecodedImageCache, LoadProgress,
+    PrefetchScope, TextureCache, TextureHandle, load_image_file, upcoming_assets,
+};
 use crate::error::{EngineError, EngineResult};
+use image::RgbaImage;
 use narrative_core::{
     AssetRef, BackgroundDef, BgmDef, CharacterDef, CharacterPosition, CharacterRegistry, Choice,
-    ChoiceOption, Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene, SeDef, Speaker,
-    Transition, UiThemeDef,
+    ChoiceOption, Dialogue, EndBehavior, Scenario, ScenarioCommand, ScenarioMetadata, Scene, SeDef,
+    Speaker, Transition, UiThemeDef,
 };
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Unified asset loader
 ///
@@ -42,8 +48,13 @@ use std::path::{Path, PathBuf};
 pub struct AssetLoader {
     base_path: PathBuf,
     texture_cache: TextureCache,
+    image_cache: DecodedImageCache,
     registry: AssetRegistry,
     scenarios: HashMap<String, Scenario>,
+    /// Started by the first prefetch
+    preloader: Option<AssetPreloader>,
+    prefetch_scope: PrefetchScope,
+    preload_budget: usize,
 }
 
 impl AssetLoader {
@@ -54,7 +65,11 @@ impl AssetLoader {
             registry: AssetRegistry::new(&base_path),
             base_path,
             texture_cache: TextureCache::default(),
+            image_cache: DecodedImageCache::default(),
             scenarios: HashMap::new(),
+            preloader: None,
+            prefetch_scope: PrefetchScope::default(),
+            preload_budget: DEFAULT_PRELOAD_BUDGET,
         }
     }
 
@@ -100,6 +115,78 @@ impl AssetLoader {
         Ok(TextureHandle::default())
     }
 
+    /// Load a decoded image, keeping it in the decoded image cache
+    ///
+    /// The path of `asset_ref` is relative to the base path. An image shown
+    /// again is returned from the cache without touching the disk, even if
+    /// its GPU texture has been released in the meantime.
+    pub fn load_image(&mut self, asset_ref: &AssetRef) -> EngineResult<Arc<RgbaImage>> {
+        if let Some(preloader) = &mut self.preloader {
+            preloader.update();
+            if let Some(image) = preloader.take_image(asset_ref) {
+                self.image_cache
+                    .insert(asset_ref.clone(), Arc::clone(&image));
+                return Ok(image);
+            }
+        }
+        let base_path = &self.base_path;
+        self.image_cache.get_or_load(asset_ref, |asset_ref| {
+            load_image_file(base_path.join(asset_ref.path()))
+        })
+    }
+
+    /// Start loading the assets of the commands ahead of a playback position
+    ///
+    /// Scans the scene from `index` on and the scenes it can lead to (see
+    /// [`upcoming_assets`]) and loads their images and sounds on background
+    /// threads, replacing the previous prefetch. Preloaded images are served by
+    /// [`Self::load_image`]; sounds are taken from [`Self::preloader_mut`].
+    /// Returns the number of assets queued for loading.
+    pub fn prefetch(&mut self, scenario: &Scenario, scene_id: &str, index: usize) -> usize {
+        let requests = upcoming_assets(scenario, scene_id, index, self.prefetch_scope);
+        let (base_path, budget) = (&self.base_path, self.preload_budget);
+        self.preloader
+            .get_or_insert_with(|| AssetPreloader::new(base_path, budget))
+            .preload(requests)
+    }
+
+    /// Progress of the assets the next few commands of the last prefetch need
+    ///
+    /// Collects finished loads first, so a loading screen can poll it once
+    /// per frame. Complete when nothing was prefetched.
+    pub fn load_progress(&mut self) -> LoadProgress {
+        match &mut self.preloader {
+            Some(preloader) => {
+                preloader.update();
+                preloader.progress()
+            }
+            None => LoadProgress::default(),
+        }
+    }
+
+    /// How far ahead [`Self::prefetch`] looks
+    pub fn prefetch_scope(&self) -> PrefetchScope {
+        self.prefetch_scope
+    }
+
+    /// Set how far ahead [`Self::prefetch`] looks
+    pub fn set_prefetch_scope(&mut self, scope: PrefetchScope) {
+        self.prefetch_scope = scope;
+    }
+
+    /// Set the memory cap of prefetched assets in bytes (0 turns prefetching off)
+    pub fn set_preload_budget(&mut self, budget: usize) {
+        self.preload_budget = budget;
+        if let Some(preloader) = &mut self.preloader {
+            preloader.set_budget(budget);
+        }
+    }
+
+    /// Get the preloader, once [`Self::prefetch`] has started it
+    pub fn preloader_mut(&mut self) -> Option<&mut AssetPreloader> {
+        self.preloader.as_mut()
+    }
+
     /// Get the asset registry
     pub fn registry(&self) -> &AssetRegistry {
         &self.registry
@@ -145,6 +232,16 @@ impl AssetLoader {
         &mut self.texture_cache
     }
 
+    /// Get the decoded image cache
+    pub fn image_cache(&self) -> &DecodedImageCache {
+        &self.image_cache
+    }
+
+    /// Get mutable decoded image cache
+    pub fn image_cache_mut(&mut self) -> &mut DecodedImageCache {
+        &mut self.image_cache
+    }
+
     /// Get base path
     pub fn base_path(&self) -> &Path {
         &self.base_path
@@ -211,6 +308,23 @@ struct TomlScenario {
     chapter: ChapterInfo,
     #[serde(default)]
     settings: Option<ScenarioSettings>,
+    /// Files whose characters and scenes are added to this scenario
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    characters: Vec<CharacterDef>,
+    #[serde(default)]
+    scenes: Vec<TomlScene>,
+}
+
+/// TOML structure of an included file
+///
+/// Only includes, characters and scenes are read, so a full scenario file can
+/// be included as well as a file of shared scenes.
+#[derive(Debug, Deserialize)]
+struct TomlInclude {
+    #[serde(default)]
+    include: Vec<String>,
     #[serde(default)]
     characters: Vec<CharacterDef>,
     #[serde(default)]
@@ -229,6 +343,8 @@ struct TomlScene {
     #[serde(default)]
     choices: Vec<ChoiceOption>,
     #[serde(default)]
+    shuffle_choices: bool,
+    #[serde(default)]
     commands: Vec<ScenarioCommand>,
     #[serde(default)]
     entry_transition: Option<FlexibleTransition>,
@@ -236,6 +352,8 @@ struct TomlScene {
     exit_transition: Option<FlexibleTransition>,
     #[serde(default)]
     transition_duration: Option<f32>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
 }
 
 /// TOML sound effect entry
@@ -269,6 +387,10 @@ struct TomlDialogue {
     sound_effects: Vec<TomlSoundEffect>,
     #[serde(default)]
     animation: Option<narrative_core::character::CharacterAnimation>,
+    #[serde(default)]
+    tags: HashMap<String, String>,
+    #[serde(default)]
+    translations: HashMap<String, String>,
 }
 
 /// Chapter metadata
@@ -278,6 +400,8 @@ struct ChapterInfo {
     title: String,
     #[serde(default)]
     description: Option<String>,
+    #[serde(default)]
+    on_end: EndBehavior,
 }
 
 /// Scenario settings
@@ -355,6 +479,7 @@ impl TomlScene {
                         sprite: AssetRef::from(sprite),
                         position,
                         expression: None,
+                        outfit: None,
                         transition,
                     });
                     displayed_characters.push(char_key);
@@ -377,13 +502,15 @@ impl TomlScene {
                     text,
                     expression: None,
                     animation: dialogue_entry.animation,
+                    tags: dialogue_entry.tags,
+                    translations: dialogue_entry.translations,
                 },
             });
         }
 
         if !self.choices.is_empty() {
             commands.push(ScenarioCommand::ShowChoice {
-                choice: Choice::new(self.choices),
+                choice: Choice::new(self.choices).with_shuffle(self.shuffle_choices),
             });
         }
 
@@ -407,6 +534,7 @@ impl TomlScene {
             commands,
             entry_transition,
             exit_transition,
+            tags: self.tags,
         })
     }
 }
@@ -441,6 +569,7 @@ impl TomlScenario {
                 description: self.chapter.description,
                 author: None,
                 version: None,
+                on_end: self.chapter.on_end,
             },
             characters: self.characters,
             scenes,
@@ -449,6 +578,25 @@ impl TomlScenario {
     }
 }
 
+/// Parse a scenario from TOML source
+///
+/// Character manifests referenced in `[settings]` are not loaded, since they are
+/// resolved relative to the scenario file; use [`AssetLoader::load_scenario`] for that.
+///
+/// # Errors
+/// Returns an error if the scenario has `include`s, which are also resolved
+/// relative to the scenario file.
+pub fn parse_scenario_toml(content: &str) -> EngineResult<Scenario> {
+    let toml_scenario: TomlScenario = toml::from_str(content)
+        .map_err(|e| EngineError::ScenarioExecution(format!("Failed to parse TOML: {}", e)))?;
+    if !toml_scenario.include.is_empty() {
+        return Err(EngineError::ScenarioExecution(
+            "Scenario includes need a file path; load the scenario from its file".to_string(),
+        ));
+    }
+    toml_scenario.into_scenario()
+}
+
 /// Load a scenario from a TOML file (private)
 fn load_scenario_from_toml<P: AsRef<Path>>(path: P) -> EngineResult<Scenario> {
     let scenario_path = path.as_ref();
@@ -492,10 +640,161 @@ fn load_scenario_from_toml<P: AsRef<Path>>(path: P) -> EngineResult<Scenario> {
         toml_scenario.characters = characters;
     }
 
+    let mut includes = IncludeResolver::new(scenario_path, &toml_scenario)?;
+    let scenario_dir = scenario_path.parent().unwrap_or_else(|| Path::new("."));
+    for include in std::mem::take(&mut toml_scenario.include) {
+        includes.include(&scenario_dir.join(include))?;
+    }
+    toml_scenario.characters.append(&mut includes.characters);
+    toml_scenario.scenes.append(&mut includes.scenes);
+
     let scenario = toml_scenario.into_scenario()?;
     Ok(scenario)
 }
 
+/// Collects the characters and scenes of included scenario files
+///
+/// Each file is read once, even if included from several files. Including a
+/// file that is still being read is a cycle, and scene or character IDs
+/// defined in two files are reported with both file names.
+struct IncludeResolver {
+    /// Files currently being read, outermost first
+    stack: Vec<PathBuf>,
+    /// Files already read
+    included: HashSet<PathBuf>,
+    /// File each scene ID was defined in
+    scene_origins: HashMap<String, PathBuf>,
+    /// File each character ID was defined in
+    character_origins: HashMap<String, PathBuf>,
+    characters: Vec<CharacterDef>,
+    scenes: Vec<TomlScene>,
+}
+
+impl IncludeResolver {
+    /// Start resolving the includes of the scenario at `root_path`
+    fn new(root_path: &Path, root: &TomlScenario) -> EngineResult<Self> {
+        let root_path = canonical_path(root_path);
+        let mut resolver = Self {
+            stack: vec![root_path.clone()],
+            included: HashSet::from([root_path.clone()]),
+            scene_origins: HashMap::new(),
+            character_origins: HashMap::new(),
+            characters: Vec::new(),
+            scenes: Vec::new(),
+        };
+        resolver.record_ids(&root_path, &root.characters, &root.scenes)?;
+        Ok(resolver)
+    }
+
+    /// Read an included file and, recursively, the files it includes
+    fn include(&mut self, path: &Path) -> EngineResult<()> {
+        let path = canonical_path(path);
+        if self.stack.contains(&path) {
+            let cycle: Vec<String> = self
+                .stack
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|path| path.display().to_string())
+                .collect();
+            return Err(EngineError::ScenarioExecution(format!(
+                "Scenario include cycle: {}",
+                cycle.join(" -> ")
+            )));
+        }
+        if !self.included.insert(path.clone()) {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            EngineError::ScenarioExecution(format!(
+                "Failed to read included scenario '{}' (included from '{}'): {}",
+                path.display(),
+                self.including_file().display(),
+                e
+            ))
+        })?;
+        let file: TomlInclude = toml::from_str(&content).map_err(|e| {
+            EngineError::ScenarioExecution(format!(
+                "Failed to parse TOML '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        self.record_ids(&path, &file.characters, &file.scenes)?;
+        self.characters.extend(file.characters);
+        self.scenes.extend(file.scenes);
+
+        self.stack.push(path.clone());
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        for include in &file.include {
+            self.include(&dir.join(include))?;
+        }
+        self.stack.pop();
+        Ok(())
+    }
+
+    /// File whose includes are being read
+    fn including_file(&self) -> &Path {
+        self.stack
+            .last()
+            .map_or(Path::new(""), |path| path.as_path())
+    }
+
+    /// Record where IDs are defined, failing on IDs defined twice
+    fn record_ids(
+        &mut self,
+        path: &Path,
+        characters: &[CharacterDef],
+        scenes: &[TomlScene],
+    ) -> EngineResult<()> {
+        for character in characters {
+            record_id(
+                &mut self.character_origins,
+                "character",
+                &character.id,
+                path,
+            )?;
+        }
+        for scene in scenes {
+            record_id(&mut self.scene_origins, "scene", &scene.id, path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Record the file an ID is defined in
+fn record_id(
+    origins: &mut HashMap<String, PathBuf>,
+    kind: &str,
+    id: &str,
+    path: &Path,
+) -> EngineResult<()> {
+    if let Some(origin) = origins.get(id) {
+        let message = if origin == path {
+            format!("Duplicate {} ID '{}' in '{}'", kind, id, path.display())
+        } else {
+            format!(
+                "Duplicate {} ID '{}' in '{}' (already defined in '{}')",
+                kind,
+                id,
+                path.display(),
+                origin.display()
+            )
+        };
+        return Err(EngineError::ScenarioExecution(message));
+    }
+    origins.insert(id.to_string(), path.to_path_buf());
+    Ok(())
+}
+
+/// Path used to recognize a file included more than once
+///
+/// Falls back to the path as given if it cannot be resolved; reading the file
+/// then reports the error.
+fn canonical_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -512,6 +811,28 @@ mod tests {
         assert_eq!(loader.base_path(), Path::new("assets"));
     }
 
+    #[test]
+    fn test_load_image_is_cached() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("bg")).unwrap();
+        let path = dir.path().join("bg/room.png");
+        RgbaImage::new(4, 2).save(&path).unwrap();
+
+        let mut loader = AssetLoader::new(dir.path());
+        let asset = AssetRef::new("bg/room.png");
+        let first = loader.load_image(&asset).unwrap();
+        assert_eq!(first.dimensions(), (4, 2));
+        assert_eq!(loader.image_cache().len(), 1);
+
+        // Served from the cache once the file is gone
+        std::fs::remove_file(&path).unwrap();
+        let second = loader.load_image(&asset).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        loader.image_cache_mut().clear();
+        assert!(loader.load_image(&asset).is_err());
+    }
+
     #[test]
     fn test_asset_stats() {
         let loader = AssetLoader::new("assets");
@@ -565,4 +886,228 @@ dialogue = {
             _ => panic!("Expected Dialogue command"),
         }
     }
+
+    #[test]
+    fn test_parse_scenario_toml() {
+        let scenario = parse_scenario_toml(
+            r#"
+[chapter]
+id = "chapter_01"
+title = "Chapter 1"
+
+[[scenes]]
+id = "opening"
+title = "Opening"
+
+[[scenes.dialogue]]
+speaker = "Narrator"
+text = "Hello"
+"#,
+        )
+        .unwrap();
+        assert_eq!(scenario.metadata.id, "chapter_01");
+        assert_eq!(scenario.start_scene, "opening");
+        assert_eq!(scenario.scenes["opening"].commands.len(), 1);
+
+        // Malformed TOML and scenarios without scenes are errors
+        assert!(parse_scenario_toml("[chapter").is_err());
+        assert!(parse_scenario_toml("[chapter]\nid = \"a\"\ntitle = \"A\"").is_err());
+    }
+    #[test]
+    fn test_parse_scenario_toml_shuffle_choices() {
+        let scenario = parse_scenario_toml(
+            r#"
+[chapter]
+id = "quiz"
+title = "Quiz"
+
+[[scenes]]
+id = "question"
+title = "Question"
+shuffle_choices = true
+
+[[scenes.choices]]
+text = "Paris"
+next_scene = "correct"
+
+[[scenes.choices]]
+text = "Lyon"
+next_scene = "wrong"
+"#,
+        )
+        .unwrap();
+
+        match scenario.scenes["question"].commands.last() {
+            Some(ScenarioCommand::ShowChoice { choice }) => {
+                assert!(choice.shuffle);
+                assert_eq!(choice.options.len(), 2);
+            }
+            other => panic!("Expected ShowChoice, got {:?}", other),
+        }
+    }
+    #[test]
+    fn test_parse_scenario_toml_tags() {
+        let scenario = parse_scenario_toml(
+            r#"
+[chapter]
+id = "chapter_01"
+title = "Chapter 1"
+
+[[scenes]]
+id = "opening"
+title = "Opening"
+tags = { act = "1" }
+
+[[scenes.dialogue]]
+speaker = "Narrator"
+text = "Hello"
+tags = { analytics = "opening_line" }
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = { speaker = "Narrator", text = "Bye", tags = { note = "fade music" } }
+"#,
+        )
+        .unwrap();
+
+        let scene = &scenario.scenes["opening"];
+        assert_eq!(scene.tag("act"), Some("1"));
+        let tags: Vec<Option<&str>> = scene
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                ScenarioCommand::Dialogue { dialogue } => {
+                    Some(dialogue.tag("analytics").or(dialogue.tag("note")))
+                }
+                _ => None,
+            })
+            .collect();
+        // `commands` come before the `dialogue` entries
+        assert_eq!(tags, vec![Some("fade music"), Some("opening_line")]);
+    }
+
+    #[test]
+    fn test_parse_scenario_toml_translations() {
+        let scenario = parse_scenario_toml(
+            r#"
+[chapter]
+id = "chapter_01"
+title = "Chapter 1"
+
+[[scenes]]
+id = "opening"
+title = "Opening"
+
+[[scenes.dialogue]]
+speaker = "Narrator"
+text = "おはよう"
+translations = { en = "Good morning" }
+"#,
+        )
+        .unwrap();
+
+        let dialogue = scenario.scenes["opening"]
+            .commands
+            .iter()
+            .find_map(|command| match command {
+                ScenarioCommand::Dialogue { dialogue } => Some(dialogue),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(dialogue.translation("en"), Some("Good morning"));
+    }
+
+    /// Scene file with a single scene, optionally including other files
+    fn scene_file(scene_id: &str, includes: &[&str]) -> String {
+        let includes: Vec<String> = includes.iter().map(|path| format!("{:?}", path)).collect();
+        format!(
+            "include = [{}]\n\n[[scenes]]\nid = \"{}\"\ntitle = \"{}\"\n",
+            includes.join(", "),
+            scene_id,
+            scene_id
+        )
+    }
+
+    fn chapter_file(includes: &[&str]) -> String {
+        let includes: Vec<String> = includes.iter().map(|path| format!("{:?}", path)).collect();
+        format!(
+            "include = [{}]\n\n[chapter]\nid = \"main\"\ntitle = \"Main\"\n\n[[scenes]]\nid = \"opening\"\ntitle = \"Opening\"\n",
+            includes.join(", ")
+        )
+    }
+
+    #[test]
+    fn test_load_scenario_with_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("routes")).unwrap();
+        std::fs::write(
+            dir.path().join("main.toml"),
+            chapter_file(&["routes/alice.toml", "common.toml"]),
+        )
+        .unwrap();
+        // Paths are relative to the including file; common.toml is read once
+        std::fs::write(
+            dir.path().join("routes/alice.toml"),
+            scene_file("alice_route", &["../common.toml"]),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("common.toml"),
+            format!(
+                "{}\n[[characters]]\nid = \"alice\"\nname = \"Alice\"\ndefault_expression = \"normal\"\nexpressions = {{}}\n",
+                scene_file("epilogue", &[])
+            ),
+        )
+        .unwrap();
+
+        let mut loader = AssetLoader::new(dir.path());
+        let scenario = loader.load_scenario("main.toml").unwrap();
+        assert_eq!(scenario.start_scene, "opening");
+        let mut scene_ids: Vec<&str> = scenario.scenes.keys().map(String::as_str).collect();
+        scene_ids.sort_unstable();
+        assert_eq!(scene_ids, vec!["alice_route", "epilogue", "opening"]);
+        assert_eq!(scenario.characters.len(), 1);
+
+        assert!(
+            parse_scenario_toml(&chapter_file(&["common.toml"]))
+                .unwrap_err()
+                .to_string()
+                .contains("need a file path")
+        );
+    }
+
+    #[test]
+    fn test_load_scenario_include_cycle() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("main.toml"), chapter_file(&["a.toml"])).unwrap();
+        std::fs::write(dir.path().join("a.toml"), scene_file("a", &["b.toml"])).unwrap();
+        std::fs::write(dir.path().join("b.toml"), scene_file("b", &["a.toml"])).unwrap();
+
+        let mut loader = AssetLoader::new(dir.path());
+        let error = loader.load_scenario("main.toml").unwrap_err().to_string();
+        assert!(error.contains("include cycle"), "{}", error);
+        assert!(error.contains("a.toml -> "), "{}", error);
+        assert!(error.ends_with("a.toml"), "{}", error);
+    }
+
+    #[test]
+    fn test_load_scenario_include_id_collisions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("main.toml"), chapter_file(&["shared.toml"])).unwrap();
+        std::fs::write(dir.path().join("shared.toml"), scene_file("opening", &[])).unwrap();
+
+        let mut loader = AssetLoader::new(dir.path());
+        let error = loader.load_scenario("main.toml").unwrap_err().to_string();
+        assert!(error.contains("Duplicate scene ID 'opening'"), "{}", error);
+        assert!(error.contains("shared.toml"), "{}", error);
+        assert!(error.contains("already defined in"), "{}", error);
+
+        std::fs::write(
+            dir.path().join("main.toml"),
+            chapter_file(&["missing.toml"]),
+        )
+        .unwrap();
+        let error = loader.load_scenario("main.toml").unwrap_err().to_string();
+        assert!(error.contains("included from"), "{}", error);
+    }
 }
@@ -4,18 +4,28 @@
 
 mod cache;
 mod handle;
+mod image_cache;
 mod loader;
+mod preload;
 mod registry;
+mod sprite;
 
 #[cfg(feature = "hot-reload")]
 mod hot_reload;
 
 pub use cache::TextureCache;
 pub use handle::TextureHandle;
-pub use loader::{AssetLoader, AssetStats};
+pub use image_cache::{DEFAULT_IMAGE_CACHE_BUDGET, DecodedImageCache, load_image_file};
+pub use loader::{AssetLoader, AssetStats, parse_scenario_toml};
+pub use preload::{
+    AssetPreloader, DEFAULT_PRELOAD_BUDGET, LoadProgress, PrefetchScope, PreloadKind,
+    PreloadPriority, PreloadRequest, PreloadedAsset, upcoming_assets,
+};
 pub use registry::{
-    AssetRegistry, BackgroundRegistry, BgmRegistry, RegistryStats, SeRegistry, UiThemeRegistry,
+    AssetRegistry, BackgroundRegistry, BgmRegistry, ParticleEffectRegistry, RegistryStats,
+    SeRegistry, UiThemeRegistry,
 };
+pub use sprite::load_sprite_image;
 
 #[cfg(feature = "hot-reload")]
-pub use hot_reload::{HotReloadWatcher, ReloadEvent};
+pub use hot_reload::{HotReloadWatcher, ReloadEvent, ScenarioWatcher};
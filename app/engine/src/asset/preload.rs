@@ -0,0 +1,966 @@
+//! Asset preloading for upcoming scenes
+//!
+//! Backgrounds, sprites and sounds are read and decoded when their command
+//! runs, so a scene transition can stall on disk I/O. [`upcoming_assets`]
+//! scans the commands ahead of the playback position, and the scenes they
+//! can lead to, for the assets they use. [`AssetPreloader`] loads those on a
+//! pool of background threads and keeps them, within a memory cap, until they
+//! are taken for display or playback. Its [`LoadProgress`] lets a loading
+//! screen wait for the assets the next commands need. Sounds and videos come
+//! with their sidecar subtitles, so playback never looks for subtitle files.
+
+use super::{load_image_file, load_sprite_image};
+use crate::audio::decoded_size;
+use crate::error::{EngineError, EngineResult};
+use image::RgbaImage;
+use kira::sound::static_sound::StaticSoundData;
+use narrative_core::{
+    AssetRef, AudioLoadMode, Scenario, ScenarioCommand, SpriteMode, SubtitleTrack,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+
+/// Default memory cap of [`AssetPreloader`] (128 MiB)
+pub const DEFAULT_PRELOAD_BUDGET: usize = 128 * 1024 * 1024;
+
+/// Upcoming commands whose assets are preloaded with [`PreloadPriority::High`]
+const IMMEDIATE_COMMANDS: usize = 5;
+
+/// Most preload threads, whatever the number of CPUs
+const MAX_WORKERS: usize = 4;
+
+/// How soon a preloaded asset is expected to be needed
+///
+/// Assets are loaded in priority order, and a full preloader makes room for
+/// an asset by dropping preloaded assets of lower priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PreloadPriority {
+    /// Used by a scene the current one can lead to
+    Low,
+    /// Used later in the current scene
+    Normal,
+    /// Used by one of the next few commands
+    High,
+}
+
+/// How a preloaded asset is loaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreloadKind {
+    /// Background, CG or sprite, decoded to RGBA (layered sprites composited)
+    Image,
+    /// BGM or sound effect, decoded into memory unless it would be streamed
+    Sound,
+    /// Voice line, decoded like a sound
+    ///
+    /// Kept apart because the game resolves voice paths against the asset
+    /// directory, unlike other sound paths.
+    Voice,
+    /// Video cutscene; only its sidecar subtitles are loaded ahead
+    Video,
+}
+
+/// An asset to preload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadRequest {
+    pub asset: AssetRef,
+    pub kind: PreloadKind,
+    pub priority: PreloadPriority,
+}
+
+impl PreloadRequest {
+    /// Create a request
+    pub fn new(asset: impl Into<AssetRef>, kind: PreloadKind, priority: PreloadPriority) -> Self {
+        Self {
+            asset: asset.into(),
+            kind,
+            priority,
+        }
+    }
+}
+
+/// How far ahead [`upcoming_assets`] looks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchScope {
+    /// Commands scanned in the current scene and in each reachable scene
+    pub commands: usize,
+    /// Scene jumps followed from the current scene (0 scans only the current scene)
+    pub scenes: usize,
+}
+
+impl Default for PrefetchScope {
+    fn default() -> Self {
+        Self {
+            commands: 20,
+            scenes: 1,
+        }
+    }
+}
+
+/// Assets used by the commands ahead of a playback position
+///
+/// Scans the next `scope.commands` commands of the scene from `index` on,
+/// both branches of `If` blocks included, then the first commands of the
+/// scenes those can jump, call or choose to, following up to `scope.scenes`
+/// jumps. Each asset is listed once with its highest priority, most urgent
+/// first.
+pub fn upcoming_assets(
+    scenario: &Scenario,
+    scene_id: &str,
+    index: usize,
+    scope: PrefetchScope,
+) -> Vec<PreloadRequest> {
+    let mut scan = AssetScan {
+        scenario,
+        requests: Vec::new(),
+        seen: HashSet::new(),
+        next_scenes: Vec::new(),
+    };
+    let mut visited = HashSet::from([scene_id.to_string()]);
+
+    if let Some(scene) = scenario.get_scene(scene_id) {
+        let upcoming = scene.commands.iter().skip(index).take(scope.commands);
+        for (offset, command) in upcoming.enumerate() {
+            let priority = if offset < IMMEDIATE_COMMANDS {
+                PreloadPriority::High
+            } else {
+                PreloadPriority::Normal
+            };
+            scan.command(command, priority);
+        }
+    }
+
+    for _ in 0..scope.scenes {
+        let scenes = std::mem::take(&mut scan.next_scenes);
+        for scene_id in scenes {
+            if !visited.insert(scene_id.clone()) {
+                continue;
+            }
+            if let Some(scene) = scenario.get_scene(&scene_id) {
+                for command in scene.commands.iter().take(scope.commands) {
+                    scan.command(command, PreloadPriority::Low);
+                }
+            }
+        }
+    }
+
+    scan.requests
+}
+
+/// State of an [`upcoming_assets`] scan
+struct AssetScan<'a> {
+    scenario: &'a Scenario,
+    requests: Vec<PreloadRequest>,
+    seen: HashSet<AssetRef>,
+    /// Scenes the scanned commands lead to, in the order found
+    next_scenes: Vec<String>,
+}
+
+impl AssetScan<'_> {
+    fn command(&mut self, command: &ScenarioCommand, priority: PreloadPriority) {
+        match command {
+            ScenarioCommand::ShowBackground { asset, .. }
+            | ScenarioCommand::ShowCG { asset, .. } => {
+                self.add(asset, PreloadKind::Image, priority)
+            }
+            ScenarioCommand::ShowCharacter {
+                character_id,
+                sprite,
+                expression,
+                outfit,
+                ..
+            } => {
+                if sprite.path().is_empty() {
+                    let expression = expression.as_ref().map(|expression| expression.name());
+                    if let Some(sprite) =
+                        self.layered_sprite(character_id, expression, outfit.as_deref())
+                    {
+                        self.add(&sprite, PreloadKind::Image, priority);
+                    }
+                } else {
+                    self.add(sprite, PreloadKind::Image, priority);
+                }
+            }
+            ScenarioCommand::ChangeSprite { sprite, .. } => {
+                self.add(sprite, PreloadKind::Image, priority)
+            }
+            ScenarioCommand::ChangeExpression {
+                character_id,
+                expression,
+            } => {
+                let sprite = self
+                    .character_def(character_id)
+                    .and_then(|def| def.sprite_for(expression.name(), None));
+                if let Some(sprite) = sprite {
+                    self.add(&sprite, PreloadKind::Image, priority);
+                }
+            }
+            ScenarioCommand::PlayBgm { asset, .. } | ScenarioCommand::PlaySe { asset, .. } => {
+                self.add(asset, PreloadKind::Sound, priority)
+            }
+            ScenarioCommand::PlayVoice { asset, .. } => {
+                self.add(asset, PreloadKind::Voice, priority)
+            }
+            ScenarioCommand::PlayVideo { path, .. } => self.add(path, PreloadKind::Video, priority),
+            ScenarioCommand::JumpToScene { scene_id } => self.next_scenes.push(scene_id.clone()),
+            ScenarioCommand::Call {
+                scene_id,
+                return_scene,
+                ..
+            } => {
+                self.next_scenes.push(scene_id.clone());
+                self.next_scenes.push(return_scene.clone());
+            }
+            ScenarioCommand::ShowChoice { choice } => self.next_scenes.extend(
+                choice
+                    .options
+                    .iter()
+                    .map(|option| option.next_scene.clone()),
+            ),
+            ScenarioCommand::If {
+                then_commands,
+                else_commands,
+                ..
+            } => {
+                for command in then_commands.iter().chain(else_commands) {
+                    self.command(command, priority);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, asset: &AssetRef, kind: PreloadKind, priority: PreloadPriority) {
+        if !asset.path().is_empty() && self.seen.insert(asset.clone()) {
+            self.requests
+                .push(PreloadRequest::new(asset.clone(), kind, priority));
+        }
+    }
+
+    fn character_def(&self, character_id: &str) -> Option<&narrative_core::CharacterDef> {
+        self.scenario
+            .characters
+            .iter()
+            .find(|character| character.id == character_id)
+    }
+
+    /// Sprite the runtime builds for a character defined with a layered sprite
+    fn layered_sprite(
+        &self,
+        character_id: &str,
+        expression: Option<&str>,
+        outfit: Option<&str>,
+    ) -> Option<AssetRef> {
+        let def = self
+            .character_def(character_id)
+            .filter(|def| matches!(def.sprite_mode, SpriteMode::Layered { .. }))?;
+        def.sprite_for(expression.unwrap_or(&def.default_expression), outfit)
+    }
+}
+
+/// A preloaded asset
+#[derive(Debug, Clone)]
+pub enum PreloadedAsset {
+    Image(Arc<RgbaImage>),
+    Sound(Box<StaticSoundData>),
+}
+
+impl PreloadedAsset {
+    /// Memory used by the decoded data
+    pub fn bytes(&self) -> usize {
+        match self {
+            Self::Image(image) => image.as_raw().len(),
+            Self::Sound(sound) => usize::try_from(decoded_size(sound)).unwrap_or(usize::MAX),
+        }
+    }
+}
+
+/// Progress of the [`PreloadPriority::High`] loads of the latest preload pass
+///
+/// Failed loads count as completed, since their commands load the asset
+/// themselves when they run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Assets finished loading
+    pub completed: usize,
+    /// Assets requested
+    pub total: usize,
+    /// Path of an asset being loaded
+    pub current: Option<String>,
+}
+
+impl LoadProgress {
+    /// Completed share of the loads, from 0.0 to 1.0 (1.0 when there are none)
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.completed as f32 / self.total as f32).min(1.0)
+        }
+    }
+
+    /// Whether every requested asset finished loading
+    pub fn is_complete(&self) -> bool {
+        self.completed >= self.total
+    }
+}
+
+/// Work shared with the preload threads
+#[derive(Default)]
+struct Queue {
+    /// Requests not started yet, most urgent first
+    requests: VecDeque<PreloadRequest>,
+    /// Assets being loaded
+    loading: Vec<AssetRef>,
+    /// Loads finished since the last update
+    finished: Vec<Loaded>,
+    /// Set when the preloader is dropped
+    closed: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    queue: Mutex<Queue>,
+    wake: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A load finished by a preload thread
+struct Loaded {
+    request: PreloadRequest,
+    result: EngineResult<Option<PreloadedAsset>>,
+    /// Sidecar subtitles of a sound (empty if it has none), `None` for images
+    subtitles: Option<SubtitleTrack>,
+}
+
+/// Loads upcoming assets on a pool of background threads
+///
+/// Each [`preload`](Self::preload) call replaces the wanted assets: requests
+/// not started yet are dropped, and preloaded assets no longer wanted are
+/// released. Finished loads are collected by [`update`](Self::update), once
+/// per frame, and handed out by [`take`](Self::take). Preloaded assets are
+/// kept within a byte budget; when it is full, assets of lower priority make
+/// room or the new asset is dropped.
+///
+/// Sounds at least as large as the auto-stream threshold are streamed when
+/// played, so they are not preloaded.
+pub struct AssetPreloader {
+    shared: Arc<Shared>,
+    /// Priority of each asset of the latest preload pass
+    wanted: HashMap<AssetRef, PreloadPriority>,
+    loaded: HashMap<AssetRef, (PreloadPriority, PreloadedAsset)>,
+    /// High-priority assets of the latest preload pass still loading
+    pending: HashSet<AssetRef>,
+    /// Number of high-priority assets the latest preload pass had to load
+    pending_total: usize,
+    budget: usize,
+    used_bytes: usize,
+    /// Sidecar subtitles of the sounds loaded since the last take
+    subtitles: Vec<(AssetRef, SubtitleTrack)>,
+}
+
+impl AssetPreloader {
+    /// Start a preloader for asset paths relative to `base_path`
+    ///
+    /// Assets are loaded on one thread per CPU, up to four. Preloaded assets
+    /// use at most `budget` bytes; a budget of zero turns preloading off.
+    pub fn new(base_path: impl Into<PathBuf>, budget: usize) -> Self {
+        let shared = Arc::new(Shared::default());
+        let base_path = base_path.into();
+        let workers = std::thread::available_parallelism()
+            .map_or(1, |count| count.get())
+            .clamp(1, MAX_WORKERS);
+        for index in 0..workers {
+            let worker = Arc::clone(&shared);
+            let base_path = base_path.clone();
+            if let Err(e) = std::thread::Builder::new()
+                .name(format!("asset-preload-{}", index))
+                .spawn(move || run_worker(&worker, &base_path))
+            {
+                tracing::warn!("Cannot start asset preload thread {}: {}", index, e);
+            }
+        }
+        Self {
+            shared,
+            wanted: HashMap::new(),
+            loaded: HashMap::new(),
+            pending: HashSet::new(),
+            pending_total: 0,
+            budget,
+            used_bytes: 0,
+            subtitles: Vec::new(),
+        }
+    }
+
+    /// Preload these assets, replacing the previous requests
+    ///
+    /// Returns the number of assets queued for loading; assets already
+    /// preloaded or being loaded are not loaded again.
+    pub fn preload(&mut self, requests: impl IntoIterator<Item = PreloadRequest>) -> usize {
+        let mut requests: Vec<PreloadRequest> = requests.into_iter().collect();
+        if self.budget == 0 {
+            requests.clear();
+        }
+        requests.sort_by_key(|request| std::cmp::Reverse(request.priority));
+        self.wanted = requests
+            .iter()
+            .map(|request| (request.asset.clone(), request.priority))
+            .collect();
+
+        let unwanted: Vec<AssetRef> = self
+            .loaded
+            .keys()
+            .filter(|asset| !self.wanted.contains_key(*asset))
+            .cloned()
+            .collect();
+        for asset in unwanted {
+            self.remove(&asset);
+        }
+        for (asset, (priority, _)) in &mut self.loaded {
+            if let Some(&wanted) = self.wanted.get(asset) {
+                *priority = wanted;
+            }
+        }
+        self.pending = requests
+            .iter()
+            .filter(|request| {
+                request.priority == PreloadPriority::High
+                    && !self.loaded.contains_key(&request.asset)
+            })
+            .map(|request| request.asset.clone())
+            .collect();
+        self.pending_total = self.pending.len();
+
+        let mut queue = self.shared.lock();
+        queue.requests = requests
+            .into_iter()
+            .filter(|request| {
+                !self.loaded.contains_key(&request.asset) && !queue.loading.contains(&request.asset)
+            })
+            .collect();
+        let queued = queue.requests.len();
+        drop(queue);
+        self.shared.wake.notify_all();
+        queued
+    }
+
+    /// Collect finished loads
+    ///
+    /// Returns the number of assets that were preloaded.
+    pub fn update(&mut self) -> usize {
+        let finished = std::mem::take(&mut self.shared.lock().finished);
+        let mut preloaded: usize = 0;
+        for Loaded {
+            request,
+            result,
+            subtitles,
+        } in finished
+        {
+            self.pending.remove(&request.asset);
+            if let Some(subtitles) = subtitles {
+                self.subtitles.push((request.asset.clone(), subtitles));
+            }
+            match result {
+                Ok(Some(asset)) => {
+                    if let Some(&priority) = self.wanted.get(&request.asset)
+                        && self.insert(request.asset, priority, asset)
+                    {
+                        preloaded = preloaded.saturating_add(1);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Failed to preload '{}': {}", request.asset.path(), e),
+            }
+        }
+        preloaded
+    }
+
+    /// Take a preloaded asset, leaving it to the caller's caches
+    pub fn take(&mut self, asset: &AssetRef) -> Option<PreloadedAsset> {
+        self.wanted.remove(asset);
+        self.remove(asset)
+    }
+
+    /// Take a preloaded image
+    pub fn take_image(&mut self, asset: &AssetRef) -> Option<Arc<RgbaImage>> {
+        match self.loaded.get(asset) {
+            Some((_, PreloadedAsset::Image(_))) => match self.take(asset) {
+                Some(PreloadedAsset::Image(image)) => Some(image),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Take all preloaded sounds and voice lines
+    pub fn take_sounds(&mut self) -> Vec<(AssetRef, StaticSoundData)> {
+        let sounds: Vec<AssetRef> = self
+            .loaded
+            .iter()
+            .filter(|(_, (_, asset))| matches!(asset, PreloadedAsset::Sound(_)))
+            .map(|(asset, _)| asset.clone())
+            .collect();
+        sounds
+            .into_iter()
+            .filter_map(|asset| match self.take(&asset) {
+                Some(PreloadedAsset::Sound(sound)) => Some((asset, *sound)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Take the sidecar subtitles of the sounds and voice lines loaded so far
+    ///
+    /// Every sound request gets an entry, with an empty track when the sound
+    /// has no subtitle file, including sounds too large to preload.
+    pub fn take_subtitles(&mut self) -> Vec<(AssetRef, SubtitleTrack)> {
+        std::mem::take(&mut self.subtitles)
+    }
+
+    /// Whether an asset is preloaded
+    pub fn is_loaded(&self, asset: &AssetRef) -> bool {
+        self.loaded.contains_key(asset)
+    }
+
+    /// Whether the preload threads have nothing left to load
+    pub fn is_idle(&self) -> bool {
+        let queue = self.shared.lock();
+        queue.requests.is_empty() && queue.loading.is_empty()
+    }
+
+    /// Progress of the high-priority loads of the latest preload pass,
+    /// as of the last [`update`](Self::update)
+    pub fn progress(&self) -> LoadProgress {
+        let current = self
+            .shared
+            .lock()
+            .loading
+            .iter()
+            .find(|asset| self.pending.contains(*asset))
+            .map(|asset| asset.path().to_string());
+        LoadProgress {
+            completed: self.pending_total.saturating_sub(self.pending.len()),
+            total: self.pending_total,
+            current,
+        }
+    }
+
+    /// Number of preloaded assets
+    pub fn len(&self) -> usize {
+        self.loaded.len()
+    }
+
+    /// Whether no asset is preloaded
+    pub fn is_empty(&self) -> bool {
+        self.loaded.is_empty()
+    }
+
+    /// Bytes of preloaded data
+    pub fn used_bytes(&self) -> usize {
+        self.used_bytes
+    }
+
+    /// Memory cap of preloaded data in bytes
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Change the memory cap, dropping preloaded assets if over it
+    ///
+    /// A budget of zero turns preloading off.
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        if budget == 0 {
+            self.clear();
+        }
+        while self.used_bytes > self.budget {
+            let Some(asset) = self.lowest_priority(PreloadPriority::High) else {
+                break;
+            };
+            self.remove(&asset);
+        }
+    }
+
+    /// Drop all requests and preloaded assets
+    pub fn clear(&mut self) {
+        self.shared.lock().requests.clear();
+        self.wanted.clear();
+        self.loaded.clear();
+        self.pending.clear();
+        self.pending_total = 0;
+        self.used_bytes = 0;
+        self.subtitles.clear();
+    }
+
+    /// Keep a loaded asset if it fits, dropping assets of lower priority
+    fn insert(
+        &mut self,
+        asset: AssetRef,
+        priority: PreloadPriority,
+        loaded: PreloadedAsset,
+    ) -> bool {
+        self.remove(&asset);
+        let size = loaded.bytes();
+        if size > self.budget {
+            return false;
+        }
+        while self.used_bytes.saturating_add(size) > self.budget {
+            let Some(evicted) = self.lowest_priority(priority) else {
+                tracing::debug!("Preload budget full, dropped '{}'", asset.path());
+                return false;
+            };
+            self.remove(&evicted);
+        }
+        self.used_bytes = self.used_bytes.saturating_add(size);
+        self.loaded.insert(asset, (priority, loaded));
+        true
+    }
+
+    /// Preloaded asset of the lowest priority below `below`, if any
+    fn lowest_priority(&self, below: PreloadPriority) -> Option<AssetRef> {
+        self.loaded
+            .iter()
+            .filter(|(_, (priority, _))| *priority < below)
+            .min_by_key(|(_, (priority, _))| *priority)
+            .map(|(asset, _)| asset.clone())
+    }
+
+    fn remove(&mut self, asset: &AssetRef) -> Option<PreloadedAsset> {
+        let (_, loaded) = self.loaded.remove(asset)?;
+        self.used_bytes = self.used_bytes.saturating_sub(loaded.bytes());
+        Some(loaded)
+    }
+}
+
+impl Drop for AssetPreloader {
+    fn drop(&mut self) {
+        let mut queue = self.shared.lock();
+        queue.closed = true;
+        queue.requests.clear();
+        drop(queue);
+        self.shared.wake.notify_all();
+    }
+}
+
+/// Body of a preload thread: load requests until the preloader is dropped
+fn run_worker(shared: &Shared, base_path: &Path) {
+    loop {
+        let request = {
+            let mut queue = shared.lock();
+            loop {
+                if queue.closed {
+                    return;
+                }
+                if let Some(request) = queue.requests.pop_front() {
+                    queue.loading.push(request.asset.clone());
+                    break request;
+                }
+                queue = shared.wake.wait(queue).unwrap_or_else(|e| e.into_inner());
+            }
+        };
+        let result = load_asset(base_path, &request);
+        let subtitles = load_subtitles(base_path, &request);
+        let mut queue = shared.lock();
+        if let Some(index) = queue
+            .loading
+            .iter()
+            .position(|asset| *asset == request.asset)
+        {
+            queue.loading.swap_remove(index);
+        }
+        queue.finished.push(Loaded {
+            request,
+            result,
+            subtitles,
+        });
+    }
+}
+
+/// Load one requested asset; `Ok(None)` for sounds that would be streamed
+/// and for videos, which are decoded as they play
+fn load_asset(base_path: &Path, request: &PreloadRequest) -> EngineResult<Option<PreloadedAsset>> {
+    let path = |layer: &str| base_path.join(layer).to_string_lossy().into_owned();
+    match request.kind {
+        PreloadKind::Image if request.asset.is_layered() => {
+            let layers = AssetRef::layered(request.asset.layers().map(path));
+            Ok(Some(PreloadedAsset::Image(Arc::new(load_sprite_image(
+                &layers,
+            )?))))
+        }
+        PreloadKind::Image => {
+            let image = load_image_file(path(request.asset.path()))?;
+            Ok(Some(PreloadedAsset::Image(Arc::new(image))))
+        }
+        PreloadKind::Sound | PreloadKind::Voice => {
+            let path = path(request.asset.path());
+            let file_size = std::fs::metadata(&path)
+                .map_err(|e| EngineError::AudioLoad(format!("Failed to read '{}': {}", path, e)))?
+                .len();
+            if file_size >= AudioLoadMode::AUTO_STREAM_THRESHOLD {
+                return Ok(None);
+            }
+            let sound = StaticSoundData::from_file(&path).map_err(|e| {
+                EngineError::AudioLoad(format!("Failed to load '{}': {:?}", path, e))
+            })?;
+            Ok(Some(PreloadedAsset::Sound(Box::new(sound))))
+        }
+        PreloadKind::Video => Ok(None),
+    }
+}
+
+/// Sidecar subtitles of a requested sound or video (empty if it has none or
+/// they can't be read), `None` for images
+fn load_subtitles(base_path: &Path, request: &PreloadRequest) -> Option<SubtitleTrack> {
+    if request.kind == PreloadKind::Image {
+        return None;
+    }
+    let path = base_path.join(request.asset.path());
+    Some(SubtitleTrack::load_sidecar(&path).unwrap_or_else(|e| {
+        tracing::warn!("Failed to load subtitles for '{}': {}", path.display(), e);
+        SubtitleTrack::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+    use narrative_core::{Choice, ChoiceOption, ScenarioMetadata, Scene, Transition};
+    use std::time::{Duration, Instant};
+    use tempfile::TempDir;
+
+    fn background(path: &str) -> ScenarioCommand {
+        ScenarioCommand::ShowBackground {
+            asset: AssetRef::new(path),
+            transition: Transition::default(),
+        }
+    }
+
+    fn scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "park");
+        let mut park = Scene::new("park", "Park");
+        park.add_command(background("bg/park.png"));
+        park.add_command(ScenarioCommand::PlaySe {
+            asset: AssetRef::new("se/birds.ogg"),
+            volume: 1.0,
+        });
+        for _ in 0..4 {
+            park.add_command(ScenarioCommand::Wait { duration: 1.0 });
+        }
+        park.add_command(ScenarioCommand::PlayVoice {
+            asset: AssetRef::new("voice/bye.ogg"),
+            volume: 1.0,
+        });
+        park.add_command(ScenarioCommand::ShowChoice {
+            choice: Choice::new(vec![
+                ChoiceOption::new("Station", "station"),
+                ChoiceOption::new("Stay", "park"),
+            ]),
+        });
+        scenario.add_scene("park", park);
+
+        let mut station = Scene::new("station", "Station");
+        station.add_command(background("bg/station.png"));
+        station.add_command(background("bg/park.png"));
+        station.add_command(ScenarioCommand::JumpToScene {
+            scene_id: "train".to_string(),
+        });
+        scenario.add_scene("station", station);
+
+        let mut train = Scene::new("train", "Train");
+        train.add_command(background("bg/train.png"));
+        scenario.add_scene("train", train);
+        scenario
+    }
+
+    fn paths(requests: &[PreloadRequest]) -> Vec<(&str, PreloadPriority)> {
+        requests
+            .iter()
+            .map(|request| (request.asset.path(), request.priority))
+            .collect()
+    }
+
+    #[test]
+    fn test_upcoming_assets_by_distance() {
+        let scenario = scenario();
+        let requests = upcoming_assets(&scenario, "park", 1, PrefetchScope::default());
+        assert_eq!(
+            paths(&requests),
+            vec![
+                ("se/birds.ogg", PreloadPriority::High),
+                ("voice/bye.ogg", PreloadPriority::Normal),
+                ("bg/station.png", PreloadPriority::Low),
+                ("bg/park.png", PreloadPriority::Low),
+            ]
+        );
+        assert_eq!(requests[1].kind, PreloadKind::Voice);
+
+        // Two jumps ahead reaches the train
+        let scope = PrefetchScope {
+            commands: 2,
+            scenes: 2,
+        };
+        let requests = upcoming_assets(&scenario, "station", 0, scope);
+        assert_eq!(
+            paths(&requests),
+            vec![
+                ("bg/station.png", PreloadPriority::High),
+                ("bg/park.png", PreloadPriority::High),
+            ]
+        );
+        let requests = upcoming_assets(&scenario, "park", 7, scope);
+        assert_eq!(
+            paths(&requests),
+            vec![
+                ("bg/station.png", PreloadPriority::Low),
+                ("bg/park.png", PreloadPriority::Low),
+            ]
+        );
+        let scope = PrefetchScope {
+            commands: 3,
+            scenes: 2,
+        };
+        let requests = upcoming_assets(&scenario, "park", 7, scope);
+        assert_eq!(requests.last().unwrap().asset.path(), "bg/train.png");
+    }
+
+    fn write_image(dir: &TempDir, name: &str, size: u32) -> AssetRef {
+        RgbaImage::from_pixel(size, size, Rgba([1, 2, 3, 255]))
+            .save(dir.path().join(name))
+            .unwrap();
+        AssetRef::new(name)
+    }
+
+    fn wait_idle(preloader: &mut AssetPreloader) {
+        let start = Instant::now();
+        while !preloader.is_idle() {
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "preload timed out"
+            );
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        preloader.update();
+    }
+
+    #[test]
+    fn test_preloader_loads_in_background() {
+        let dir = TempDir::new().unwrap();
+        let room = write_image(&dir, "room.png", 4);
+        let mut preloader = AssetPreloader::new(dir.path(), 1024);
+
+        let requests = vec![
+            PreloadRequest::new(room.clone(), PreloadKind::Image, PreloadPriority::High),
+            PreloadRequest::new("missing.png", PreloadKind::Image, PreloadPriority::Low),
+        ];
+        assert_eq!(preloader.preload(requests.clone()), 2);
+        // Only the high-priority load counts towards the progress
+        let progress = preloader.progress();
+        assert_eq!((progress.completed, progress.total), (0, 1));
+        assert!(!progress.is_complete());
+        wait_idle(&mut preloader);
+        assert_eq!(
+            preloader.progress(),
+            LoadProgress {
+                completed: 1,
+                total: 1,
+                current: None,
+            }
+        );
+
+        assert!(preloader.is_loaded(&room));
+        assert_eq!(preloader.len(), 1);
+        assert_eq!(preloader.used_bytes(), 64);
+        // Preloaded assets are not loaded again
+        assert_eq!(preloader.preload(requests), 1);
+        assert_eq!(preloader.progress().fraction(), 1.0);
+        wait_idle(&mut preloader);
+
+        let image = preloader.take_image(&room).unwrap();
+        assert_eq!(image.dimensions(), (4, 4));
+        assert!(preloader.is_empty());
+        assert_eq!(preloader.used_bytes(), 0);
+    }
+
+    #[test]
+    fn test_preloader_loads_sound_subtitles() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("thunder.srt"),
+            "1\n00:00:00,000 --> 00:00:01,000\nThunder rumbles\n",
+        )
+        .unwrap();
+        let mut preloader = AssetPreloader::new(dir.path(), 1024);
+
+        preloader.preload([
+            PreloadRequest::new("thunder.ogg", PreloadKind::Sound, PreloadPriority::High),
+            PreloadRequest::new("rain.ogg", PreloadKind::Sound, PreloadPriority::High),
+            PreloadRequest::new("room.png", PreloadKind::Image, PreloadPriority::High),
+        ]);
+        wait_idle(&mut preloader);
+
+        // Subtitles are found even when the sound itself fails to load
+        let mut subtitles = preloader.take_subtitles();
+        subtitles.sort_by(|a, b| a.0.path().cmp(b.0.path()));
+        let cues: Vec<(&str, usize)> = subtitles
+            .iter()
+            .map(|(asset, track)| (asset.path(), track.cues().len()))
+            .collect();
+        assert_eq!(cues, [("rain.ogg", 0), ("thunder.ogg", 1)]);
+        assert!(preloader.take_subtitles().is_empty());
+    }
+
+    #[test]
+    fn test_preloader_memory_cap_prefers_priority() {
+        let dir = TempDir::new().unwrap();
+        let near = write_image(&dir, "near.png", 4);
+        let far = write_image(&dir, "far.png", 4);
+        let later = write_image(&dir, "later.png", 4);
+        // Room for two 4x4 images
+        let mut preloader = AssetPreloader::new(dir.path(), 128);
+
+        preloader.preload([
+            PreloadRequest::new(far.clone(), PreloadKind::Image, PreloadPriority::Low),
+            PreloadRequest::new(later.clone(), PreloadKind::Image, PreloadPriority::Normal),
+        ]);
+        wait_idle(&mut preloader);
+        assert_eq!(preloader.len(), 2);
+
+        // A more urgent asset replaces the least urgent one
+        preloader.preload([
+            PreloadRequest::new(near.clone(), PreloadKind::Image, PreloadPriority::High),
+            PreloadRequest::new(far.clone(), PreloadKind::Image, PreloadPriority::Low),
+            PreloadRequest::new(later.clone(), PreloadKind::Image, PreloadPriority::Normal),
+        ]);
+        wait_idle(&mut preloader);
+        assert!(preloader.is_loaded(&near));
+        assert!(preloader.is_loaded(&later));
+        assert!(!preloader.is_loaded(&far));
+        assert_eq!(preloader.used_bytes(), 128);
+
+        // Assets no longer wanted are released
+        preloader.preload([PreloadRequest::new(
+            near.clone(),
+            PreloadKind::Image,
+            PreloadPriority::High,
+        )]);
+        assert_eq!(preloader.len(), 1);
+        assert_eq!(preloader.used_bytes(), 64);
+
+        preloader.set_budget(0);
+        assert!(preloader.is_empty());
+        assert_eq!(
+            preloader.preload([PreloadRequest::new(
+                near,
+                PreloadKind::Image,
+                PreloadPriority::High,
+            )]),
+            0
+        );
+    }
+}
@@ -3,7 +3,8 @@
 /// Provides centralized management of all asset types defined in RON manifests.
 use narrative_core::{
     BackgroundDef, BackgroundManifest, BgmDef, BgmManifest, CharacterDef, CharacterRegistry,
-    EngineError, EngineResult, SeDef, SeManifest, UiThemeDef, UiThemeManifest,
+    EngineError, EngineResult, ParticleEffectDef, ParticleEffectManifest, SeDef, SeManifest,
+    UiThemeDef, UiThemeManifest,
 };
 use std::path::{Path, PathBuf};
 
@@ -13,6 +14,7 @@ const BACKGROUNDS_MANIFEST: &str = "manifests/backgrounds.ron";
 const BGM_MANIFEST: &str = "manifests/bgm.ron";
 const SE_MANIFEST: &str = "manifests/se.ron";
 const UI_THEMES_MANIFEST: &str = "manifests/ui_themes.ron";
+const EFFECTS_MANIFEST: &str = "manifests/effects.ron";
 
 /// Background registry - manages background definitions from manifest
 pub struct BackgroundRegistry {
@@ -190,6 +192,54 @@ impl Default for UiThemeRegistry {
     }
 }
 
+/// Particle effect registry - built-in effects plus those from a manifest
+///
+/// Starts with the built-in snow, rain, sakura and sparks effects. Effects in
+/// a loaded manifest are added to them, replacing built-ins with the same ID.
+pub struct ParticleEffectRegistry {
+    effects: ParticleEffectManifest,
+    base_dir: PathBuf,
+}
+
+impl ParticleEffectRegistry {
+    /// Create a new particle effect registry with the built-in effects
+    pub fn new(base_dir: PathBuf) -> Self {
+        Self {
+            effects: ParticleEffectManifest::builtin(),
+            base_dir,
+        }
+    }
+
+    /// Load manifest from file, on top of the effects already registered
+    pub fn load_manifest(&mut self, manifest_path: impl AsRef<Path>) -> EngineResult<()> {
+        let full_path = self.base_dir.join(manifest_path.as_ref());
+        let manifest = ParticleEffectManifest::load_from_file(&full_path)?;
+        self.effects.effects.extend(manifest.effects);
+        Ok(())
+    }
+
+    /// Get a particle effect by ID
+    pub fn get(&self, id: &str) -> Option<&ParticleEffectDef> {
+        self.effects.get(id)
+    }
+
+    /// Get all particle effect IDs
+    pub fn ids(&self) -> Vec<&str> {
+        self.effects.ids()
+    }
+
+    /// Check if a particle effect exists
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+}
+
+impl Default for ParticleEffectRegistry {
+    fn default() -> Self {
+        Self::new(PathBuf::from("assets"))
+    }
+}
+
 /// Unified asset registry - manages all asset types from manifests
 ///
 /// This registry provides centralized access to all asset definitions
@@ -220,6 +270,8 @@ pub struct AssetRegistry {
     pub se: SeRegistry,
     /// UI theme registry
     pub ui_themes: UiThemeRegistry,
+    /// Particle effect registry
+    pub particle_effects: ParticleEffectRegistry,
 
     base_dir: PathBuf,
 }
@@ -234,6 +286,7 @@ impl AssetRegistry {
             bgm: BgmRegistry::new(base_dir.clone()),
             se: SeRegistry::new(base_dir.clone()),
             ui_themes: UiThemeRegistry::new(base_dir.clone()),
+            particle_effects: ParticleEffectRegistry::new(base_dir.clone()),
             base_dir,
         }
     }
@@ -246,6 +299,7 @@ impl AssetRegistry {
     /// - `manifests/bgm.ron`
     /// - `manifests/se.ron`
     /// - `manifests/ui_themes.ron`
+    /// - `manifests/effects.ron`
     pub fn load_all_manifests(&mut self) -> EngineResult<()> {
         // Load character manifest
         self.characters
@@ -268,6 +322,11 @@ impl AssetRegistry {
         // Load UI theme manifest
         self.ui_themes.load_manifest(UI_THEMES_MANIFEST)?;
 
+        // Load particle effect manifest (built-in effects remain without one)
+        if let Err(e) = self.particle_effects.load_manifest(EFFECTS_MANIFEST) {
+            tracing::warn!("Failed to load particle effect manifest: {}", e);
+        }
+
         Ok(())
     }
 
@@ -296,6 +355,11 @@ impl AssetRegistry {
         self.ui_themes.get(id)
     }
 
+    /// Get a particle effect by ID
+    pub fn particle_effect(&self, id: &str) -> Option<&ParticleEffectDef> {
+        self.particle_effects.get(id)
+    }
+
     /// Get base directory
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
@@ -364,6 +428,57 @@ mod tests {
         assert!(registry.ids().is_empty());
     }
 
+    #[test]
+    fn test_particle_effect_registry_manifest_overrides_builtin() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("manifests")).unwrap();
+        std::fs::write(
+            dir.path().join(EFFECTS_MANIFEST),
+            r#"
+ParticleEffectManifest(
+    effects: {
+        "snow": ParticleEffectDef(
+            id: "snow",
+            name: "Blizzard",
+            shape: Snow,
+            spawn_rate: 200.0,
+            lifetime: 4.0,
+            speed: (200.0, 300.0),
+            direction: 60.0,
+            size: (2.0, 5.0),
+            colors: [(1.0, 1.0, 1.0, 1.0)],
+        ),
+        "fireflies": ParticleEffectDef(
+            id: "fireflies",
+            name: "Fireflies",
+            shape: Spark,
+            emit_from: Screen,
+            spawn_rate: 5.0,
+            lifetime: 6.0,
+            speed: (5.0, 15.0),
+            direction: 270.0,
+            spread: 180.0,
+            size: (2.0, 3.0),
+            colors: [(0.8, 1.0, 0.4, 0.9)],
+        ),
+    },
+)
+"#,
+        )
+        .unwrap();
+
+        let mut registry = ParticleEffectRegistry::new(dir.path().to_path_buf());
+        assert!(registry.contains("rain"));
+        registry.load_manifest(EFFECTS_MANIFEST).unwrap();
+
+        assert_eq!(
+            registry.get("snow").map(|e| e.name.as_str()),
+            Some("Blizzard")
+        );
+        assert!(registry.contains("fireflies"));
+        assert!(registry.contains("sakura"));
+    }
+
     #[test]
     fn test_asset_registry_new() {
         let registry = AssetRegistry::new("assets");
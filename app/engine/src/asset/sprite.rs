@@ -0,0 +1,81 @@
+//! Layered sprite compositing
+//!
+//! Layered character sprites are referenced by [`AssetRef::layered`]: a stack
+//! of images authored on the same canvas (body, outfit, face, accessories).
+//! They are composited into one image when loaded, so the renderer, texture
+//! cache and saves treat them like any other sprite.
+
+use super::load_image_file;
+use crate::error::{EngineError, EngineResult};
+use image::{RgbaImage, imageops};
+use narrative_core::AssetRef;
+
+/// Load a sprite image, compositing the layers of a layered reference
+///
+/// Layers are drawn bottom to top onto a canvas the size of the bottom
+/// layer, aligned at the top-left corner; anything outside it is clipped.
+///
+/// # Errors
+/// Returns an error if any layer cannot be loaded.
+pub fn load_sprite_image(sprite: &AssetRef) -> EngineResult<RgbaImage> {
+    let mut layers = sprite.layers();
+    let mut canvas = load_layer(layers.next().unwrap_or_default())?;
+    for path in layers {
+        imageops::overlay(&mut canvas, &load_layer(path)?, 0, 0);
+    }
+    Ok(canvas)
+}
+
+/// Load one layer as RGBA
+fn load_layer(path: &str) -> EngineResult<RgbaImage> {
+    load_image_file(path)
+        .map_err(|e| EngineError::AssetLoad(format!("Failed to load sprite layer {}: {}", path, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+    use tempfile::TempDir;
+
+    fn write_layer(dir: &TempDir, name: &str, image: RgbaImage) -> String {
+        let path = dir.path().join(name);
+        image.save(&path).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_load_sprite_image_composites_layers() {
+        let dir = TempDir::new().unwrap();
+        let body = write_layer(
+            &dir,
+            "body.png",
+            RgbaImage::from_pixel(4, 4, Rgba([0, 0, 255, 255])),
+        );
+        // Face covers the top half only; the rest is transparent
+        let mut face_image = RgbaImage::new(4, 4);
+        for x in 0..4 {
+            for y in 0..2 {
+                face_image.put_pixel(x, y, Rgba([255, 0, 0, 255]));
+            }
+        }
+        let face = write_layer(&dir, "face.png", face_image);
+
+        let image = load_sprite_image(&AssetRef::layered([&body, &face])).unwrap();
+        assert_eq!(image.dimensions(), (4, 4));
+        assert_eq!(image.get_pixel(1, 0), &Rgba([255, 0, 0, 255]));
+        assert_eq!(image.get_pixel(1, 3), &Rgba([0, 0, 255, 255]));
+
+        // A plain reference loads the single image
+        let image = load_sprite_image(&AssetRef::new(&body)).unwrap();
+        assert_eq!(image.get_pixel(1, 0), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_load_sprite_image_missing_layer() {
+        let dir = TempDir::new().unwrap();
+        let body = write_layer(&dir, "body.png", RgbaImage::new(2, 2));
+        let sprite = AssetRef::layered([body.as_str(), "missing/face.png"]);
+        assert!(load_sprite_image(&sprite).is_err());
+    }
+}
@@ -1,16 +1,23 @@
 //! BGM player
 
+use super::sound::{SoundHandle, SoundSource};
 use crate::error::{EngineError, EngineResult};
 use kira::{
-    AudioManager, Decibels, Tween, Value,
-    sound::static_sound::{StaticSoundData, StaticSoundHandle, StaticSoundSettings},
+    AudioManager, Decibels, Tween,
+    sound::{EndPosition, PlaybackPosition, Region},
 };
+use narrative_core::LoopRegion;
 use std::{path::Path, time::Duration};
 
 /// BGM player with kira integration
+///
+/// Starting a track while another plays crossfades between them over the
+/// crossfade duration; a duration of zero cuts straight to the new track.
 pub struct BgmPlayer {
-    current_handle: Option<StaticSoundHandle>,
+    current_handle: Option<SoundHandle>,
     current_volume: f64,
+    /// Crossfade duration in seconds
+    crossfade: f64,
 }
 
 impl BgmPlayer {
@@ -19,9 +26,20 @@ impl BgmPlayer {
         Self {
             current_handle: None,
             current_volume: 0.0, // 0 dB = unity gain
+            crossfade: 0.0,
         }
     }
 
+    /// Set how long track changes crossfade, in seconds (0 = hard cut)
+    pub fn set_crossfade(&mut self, seconds: f64) {
+        self.crossfade = seconds.max(0.0);
+    }
+
+    /// Crossfade duration in seconds
+    pub fn crossfade(&self) -> f64 {
+        self.crossfade
+    }
+
     /// Play BGM from file path
     ///
     /// # Arguments
@@ -36,13 +54,30 @@ impl BgmPlayer {
         loop_enabled: bool,
         fade_in_duration: Option<f64>,
     ) -> EngineResult<()> {
-        // Stop current BGM if playing
-        if self.current_handle.is_some() {
-            self.stop(None)?;
-        }
+        let loop_region = loop_enabled.then(LoopRegion::default);
+        self.play_with_loop(manager, path, loop_region, fade_in_duration)
+    }
 
+    /// Play BGM from file path, looping the given region
+    ///
+    /// The part before the loop start plays once as an intro; playback then
+    /// repeats the region sample-accurately. Loop points outside the track
+    /// are rejected.
+    ///
+    /// # Arguments
+    /// * `manager` - Kira audio manager
+    /// * `path` - Path to the audio file
+    /// * `loop_region` - Region to loop, or `None` to play once
+    /// * `fade_in_duration` - Optional fade-in duration in seconds
+    pub fn play_with_loop(
+        &mut self,
+        manager: &mut AudioManager,
+        path: impl AsRef<Path>,
+        loop_region: Option<LoopRegion>,
+        fade_in_duration: Option<f64>,
+    ) -> EngineResult<()> {
         // Load audio file
-        let sound_data = StaticSoundData::from_file(path.as_ref()).map_err(|e| {
+        let source = SoundSource::from_file(path.as_ref(), false).map_err(|e| {
             EngineError::BgmPlayback(format!(
                 "Failed to load BGM file '{}': {:?}",
                 path.as_ref().display(),
@@ -50,34 +85,55 @@ impl BgmPlayer {
             ))
         })?;
 
-        // Configure playback settings
-        let mut settings = StaticSoundSettings::default();
+        self.play_source(manager, source, loop_region, fade_in_duration)
+    }
 
+    /// Play already loaded BGM, looping the given region
+    ///
+    /// A track already playing fades out over the crossfade duration while
+    /// the new one fades in; without an explicit fade-in the new track uses
+    /// the crossfade duration too.
+    ///
+    /// # Arguments
+    /// * `manager` - Kira audio manager
+    /// * `source` - Streamed or decoded audio
+    /// * `loop_region` - Region to loop, or `None` to play once
+    /// * `fade_in_duration` - Optional fade-in duration in seconds
+    pub fn play_source(
+        &mut self,
+        manager: &mut AudioManager,
+        source: SoundSource,
+        loop_region: Option<LoopRegion>,
+        fade_in_duration: Option<f64>,
+    ) -> EngineResult<()> {
         // Enable looping if requested
-        if loop_enabled {
-            settings = settings.loop_region(..);
-        }
+        let region = match loop_region {
+            Some(region) => Some(
+                kira_region(&region, &source)
+                    .map_err(|e| EngineError::BgmPlayback(format!("Invalid loop points: {}", e)))?,
+            ),
+            None => None,
+        };
 
-        // Set volume with fade-in if requested
-        if let Some(duration) = fade_in_duration {
-            // Use fade_in_tween for volume fade-in
-            settings.fade_in_tween = Some(Tween {
-                start_time: kira::StartTime::Immediate,
-                duration: Duration::from_secs_f64(duration),
-                easing: kira::Easing::Linear,
-            });
-            // Set target volume (fade_in_tween will fade from 0 to this)
-            settings.volume = Value::Fixed(Decibels(self.current_volume as f32));
-        } else {
-            // No fade-in, use current volume directly
-            settings.volume = Value::Fixed(Decibels(self.current_volume as f32));
-        }
+        // Crossfade out of the current BGM, or cut it
+        let crossfading = self.current_handle.is_some() && self.crossfade > 0.0;
+        let fade_out = crossfading.then_some(self.crossfade);
+        self.stop(fade_out)?;
+
+        // Fade in from silence to the current volume if requested
+        let fade_in_tween = fade_in_duration.or(fade_out).map(linear_tween);
 
         // Play the sound
-        let handle = manager
-            .play(sound_data.with_settings(settings))
+        let handle = source
+            .play(
+                manager,
+                Decibels(self.current_volume as f32),
+                fade_in_tween,
+                region,
+                1.0,
+            )
             .map_err(|e| {
-                EngineError::BgmPlayback(format!("Failed to start BGM playback: {:?}", e))
+                EngineError::BgmPlayback(format!("Failed to start BGM playback: {}", e))
             })?;
 
         self.current_handle = Some(handle);
@@ -90,20 +146,8 @@ impl BgmPlayer {
     /// * `fade_out_duration` - Optional fade-out duration in seconds
     pub fn stop(&mut self, fade_out_duration: Option<f64>) -> EngineResult<()> {
         if let Some(mut handle) = self.current_handle.take() {
-            if let Some(duration) = fade_out_duration {
-                // Fade out then stop
-                let tween = Tween {
-                    start_time: kira::StartTime::Immediate,
-                    duration: Duration::from_secs_f64(duration),
-                    easing: kira::Easing::Linear,
-                };
-                handle.set_volume(Decibels(-60.0), tween);
-                // Note: In kira 0.11, we can't wait for the tween to complete easily
-                // The handle will be dropped and sound will stop when it goes out of scope
-            } else {
-                // Immediate stop
-                handle.stop(Tween::default());
-            }
+            // kira fades the sound out before stopping it
+            handle.stop(fade_out_duration.map(linear_tween).unwrap_or_default());
         }
         Ok(())
     }
@@ -114,32 +158,26 @@ impl BgmPlayer {
     /// * `volume` - Volume level (0.0 - 1.0, where 1.0 = unity gain)
     /// * `tween_duration` - Optional duration for volume change in seconds
     pub fn set_volume(&mut self, volume: f32, tween_duration: Option<f64>) -> EngineResult<()> {
-        // Convert 0.0-1.0 range to decibels
-        // 0.0 -> -60dB (very quiet), 1.0 -> 0dB (unity)
-        let db = if volume <= 0.0 {
-            -60.0
-        } else {
-            20.0 * (volume as f64).log10()
-        };
-        self.current_volume = db;
+        self.set_next_volume(volume);
 
         if let Some(handle) = &mut self.current_handle {
-            let tween = if let Some(duration) = tween_duration {
-                Tween {
-                    start_time: kira::StartTime::Immediate,
-                    duration: Duration::from_secs_f64(duration),
-                    easing: kira::Easing::Linear,
-                }
-            } else {
-                Tween::default()
-            };
-
-            handle.set_volume(Decibels(db as f32), tween);
+            let tween = tween_duration.map(linear_tween).unwrap_or_default();
+            handle.set_volume(Decibels(self.current_volume as f32), tween);
         }
 
         Ok(())
     }
 
+    /// Set the volume the next track starts at, leaving the playing one as is
+    ///
+    /// Used before starting a track so a crossfading track keeps its volume.
+    ///
+    /// # Arguments
+    /// * `volume` - Volume level (0.0 - 1.0, where 1.0 = unity gain)
+    pub fn set_next_volume(&mut self, volume: f32) {
+        self.current_volume = volume_to_db(volume);
+    }
+
     /// Check if BGM is currently playing
     pub fn is_playing(&self) -> bool {
         self.current_handle.is_some()
@@ -148,15 +186,7 @@ impl BgmPlayer {
     /// Pause BGM playback
     pub fn pause(&mut self, fade_out_duration: Option<f64>) -> EngineResult<()> {
         if let Some(handle) = &mut self.current_handle {
-            let tween = if let Some(duration) = fade_out_duration {
-                Tween {
-                    start_time: kira::StartTime::Immediate,
-                    duration: Duration::from_secs_f64(duration),
-                    easing: kira::Easing::Linear,
-                }
-            } else {
-                Tween::default()
-            };
+            let tween = fade_out_duration.map(linear_tween).unwrap_or_default();
 
             handle.pause(tween);
         }
@@ -166,15 +196,7 @@ impl BgmPlayer {
     /// Resume BGM playback
     pub fn resume(&mut self, fade_in_duration: Option<f64>) -> EngineResult<()> {
         if let Some(handle) = &mut self.current_handle {
-            let tween = if let Some(duration) = fade_in_duration {
-                Tween {
-                    start_time: kira::StartTime::Immediate,
-                    duration: Duration::from_secs_f64(duration),
-                    easing: kira::Easing::Linear,
-                }
-            } else {
-                Tween::default()
-            };
+            let tween = fade_in_duration.map(linear_tween).unwrap_or_default();
 
             handle.resume(tween);
         }
@@ -182,6 +204,43 @@ impl BgmPlayer {
     }
 }
 
+/// Convert a 0.0-1.0 volume to decibels
+///
+/// 0.0 -> -60dB (very quiet), 1.0 -> 0dB (unity)
+pub(super) fn volume_to_db(volume: f32) -> f64 {
+    if volume <= 0.0 {
+        -60.0
+    } else {
+        20.0 * (volume as f64).log10()
+    }
+}
+
+/// Linear tween starting now and lasting `duration` seconds
+pub(super) fn linear_tween(duration: f64) -> Tween {
+    Tween {
+        start_time: kira::StartTime::Immediate,
+        duration: Duration::from_secs_f64(duration),
+        easing: kira::Easing::Linear,
+    }
+}
+
+/// Convert a loop region into kira's region, checked against the track length
+fn kira_region(region: &LoopRegion, source: &SoundSource) -> Result<Region, String> {
+    let total_frames = u64::try_from(source.num_frames()).unwrap_or(u64::MAX);
+    let (start, end) = region.resolve(source.sample_rate(), total_frames)?;
+    let to_position =
+        |frame: u64| PlaybackPosition::Samples(usize::try_from(frame).unwrap_or(usize::MAX));
+
+    Ok(Region {
+        start: to_position(start),
+        end: if region.end.is_some() {
+            EndPosition::Custom(to_position(end))
+        } else {
+            EndPosition::EndOfAudio
+        },
+    })
+}
+
 impl Default for BgmPlayer {
     fn default() -> Self {
         Self::new()
@@ -243,6 +302,54 @@ mod tests {
         assert!((player.current_volume - 0.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_bgm_player_crossfade() {
+        let mut player = BgmPlayer::new();
+        assert_eq!(player.crossfade(), 0.0);
+        player.set_crossfade(1.5);
+        assert_eq!(player.crossfade(), 1.5);
+        player.set_crossfade(-1.0);
+        assert_eq!(player.crossfade(), 0.0);
+    }
+
+    #[test]
+    fn test_bgm_player_set_next_volume() {
+        let mut player = BgmPlayer::new();
+        player.set_next_volume(0.5);
+        assert!((player.current_volume - (-6.020599)).abs() < 0.001);
+        assert!(!player.is_playing());
+    }
+
+    #[test]
+    fn test_kira_region() {
+        use kira::Frame;
+        use kira::sound::static_sound::{StaticSoundData, StaticSoundSettings};
+        use narrative_core::LoopPoint;
+        use std::sync::Arc;
+
+        let sound_data = SoundSource::Static(StaticSoundData {
+            sample_rate: 100,
+            frames: Arc::from(vec![Frame::ZERO; 1000]),
+            settings: StaticSoundSettings::default(),
+            slice: None,
+        });
+
+        let intro = LoopRegion::new(Some(LoopPoint::Seconds(2.5)), None);
+        let region = kira_region(&intro, &sound_data).unwrap();
+        assert_eq!(region.start, PlaybackPosition::Samples(250));
+        assert_eq!(region.end, EndPosition::EndOfAudio);
+
+        let custom = LoopRegion::new(None, Some(LoopPoint::Samples(900)));
+        let region = kira_region(&custom, &sound_data).unwrap();
+        assert_eq!(
+            region.end,
+            EndPosition::Custom(PlaybackPosition::Samples(900))
+        );
+
+        let beyond = LoopRegion::new(None, Some(LoopPoint::Seconds(11.0)));
+        assert!(kira_region(&beyond, &sound_data).is_err());
+    }
+
     #[test]
     fn test_bgm_player_pause_when_not_playing() {
         let mut player = BgmPlayer::new();
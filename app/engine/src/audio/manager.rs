@@ -1,9 +1,20 @@
 //! Audio manager
 
-use super::{BgmPlayer, SePlayer, VoicePlayer};
+use super::{
+    AudioCommand, AudioMemoryStats, AudioQueue, BgmPlayer, SePlayer, SoundLoader, SoundtrackPlayer,
+    VoicePlayer,
+};
 use crate::app::AudioConfig;
 use crate::error::{EngineError, EngineResult};
 use kira::AudioManager as KiraAudioManager;
+use kira::sound::static_sound::StaticSoundData;
+use narrative_core::{AudioLoadMode, BgmDef, BgmManifest, SeDef, SeManifest};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+
+/// Seconds BGM takes to fade when ducking starts or ends
+const BGM_DUCKING_FADE: f64 = 0.25;
 
 /// Central audio manager
 pub struct AudioManager {
@@ -11,7 +22,21 @@ pub struct AudioManager {
     bgm: BgmPlayer,
     se: SePlayer,
     voice: VoicePlayer,
+    soundtrack: SoundtrackPlayer,
+    loader: SoundLoader,
     config: AudioConfig,
+    /// BGM volume multiplier while ducked under speech (1.0 = not ducked)
+    bgm_ducking: f32,
+    /// BGM is lowered by `voice_ducking_db` while a voice line plays
+    voice_ducked: bool,
+    /// BGM manifest tracks by ID, played by `play_bgm` in place of a file
+    bgm_tracks: HashMap<String, BgmDef>,
+    /// Directory the manifest's file paths are relative to
+    bgm_base_dir: PathBuf,
+    /// Handle given out by `queue()`
+    queue: AudioQueue,
+    /// Commands waiting for `process_commands()`
+    commands: Receiver<AudioCommand>,
 }
 
 impl AudioManager {
@@ -24,16 +49,25 @@ impl AudioManager {
     pub fn with_config(config: AudioConfig) -> EngineResult<Self> {
         let kira_manager = KiraAudioManager::new(Default::default())
             .map_err(|e| EngineError::AudioInit(format!("Failed to initialize kira: {:?}", e)))?;
+        let (queue, commands) = AudioQueue::channel();
 
         let mut manager = Self {
             kira_manager: Some(kira_manager),
             bgm: BgmPlayer::new(),
             se: SePlayer::new(),
             voice: VoicePlayer::new(),
+            soundtrack: SoundtrackPlayer::new(),
+            loader: SoundLoader::new(),
             config: config.clone(),
+            bgm_ducking: 1.0,
+            voice_ducked: false,
+            bgm_tracks: HashMap::new(),
+            bgm_base_dir: PathBuf::new(),
+            queue,
+            commands,
         };
 
-        // Apply initial volumes from config
+        // Apply initial volumes and crossfade from config
         manager.apply_volumes()?;
 
         Ok(manager)
@@ -44,12 +78,81 @@ impl AudioManager {
     /// This is useful when audio initialization fails but the application
     /// should continue running without audio.
     pub fn disabled() -> Self {
+        let (queue, commands) = AudioQueue::channel();
         Self {
             kira_manager: None,
             bgm: BgmPlayer::new(),
             se: SePlayer::new(),
             voice: VoicePlayer::new(),
+            soundtrack: SoundtrackPlayer::new(),
+            loader: SoundLoader::new(),
             config: AudioConfig::default(),
+            bgm_ducking: 1.0,
+            voice_ducked: false,
+            bgm_tracks: HashMap::new(),
+            bgm_base_dir: PathBuf::new(),
+            queue,
+            commands,
+        }
+    }
+
+    /// Get a handle for queueing audio commands
+    ///
+    /// Lets UI code request playback and volume changes without holding
+    /// the manager; see [`process_commands`](Self::process_commands).
+    pub fn queue(&self) -> AudioQueue {
+        self.queue.clone()
+    }
+
+    /// Apply all queued audio commands
+    ///
+    /// Call once per frame. Failed commands are logged and skipped.
+    /// Returns the number of commands processed.
+    pub fn process_commands(&mut self) -> usize {
+        self.process_commands_with(|_| {})
+    }
+
+    /// Apply all queued audio commands, passing each one to `on_processed`
+    ///
+    /// Lets the caller react to playback requests as they are applied (e.g. to
+    /// show subtitles for a sound effect). `on_processed` is called even when
+    /// the command fails, so subtitles still work without an audio device.
+    /// Also brings BGM back up once a voice line has finished.
+    /// Returns the number of commands processed.
+    pub fn process_commands_with(&mut self, mut on_processed: impl FnMut(&AudioCommand)) -> usize {
+        let mut count = 0;
+        while let Ok(command) = self.commands.try_recv() {
+            if let Err(e) = self.apply_command(&command) {
+                tracing::warn!("Audio command {:?} failed: {}", command, e);
+            }
+            on_processed(&command);
+            count += 1;
+        }
+        if let Err(e) = self.update_voice_ducking() {
+            tracing::warn!("Failed to update BGM ducking: {}", e);
+        }
+        count
+    }
+
+    /// Apply a single audio command immediately
+    pub fn apply_command(&mut self, command: &AudioCommand) -> EngineResult<()> {
+        match command {
+            AudioCommand::PlayBgm {
+                path,
+                looped,
+                fade_in,
+                volume,
+            } => self.play_bgm(path, *looped, *fade_in, *volume),
+            AudioCommand::StopBgm { fade_out } => self.stop_bgm(*fade_out),
+            AudioCommand::PlaySe { path, volume } => self.play_se(path, *volume),
+            AudioCommand::StopAllSe => self.stop_all_se(),
+            AudioCommand::PlayVoice { path, volume } => self.play_voice(path, *volume),
+            AudioCommand::StopVoice => self.stop_voice(),
+            AudioCommand::SetMasterVolume(volume) => self.set_master_volume(*volume),
+            AudioCommand::SetMusicVolume(volume) => self.set_music_volume(*volume),
+            AudioCommand::SetSoundVolume(volume) => self.set_sound_volume(*volume),
+            AudioCommand::SetVoiceVolume(volume) => self.set_voice_volume(*volume),
+            AudioCommand::SetBgmDucking(level) => self.set_bgm_ducking(*level),
         }
     }
 
@@ -79,10 +182,34 @@ impl AudioManager {
         &mut self.voice
     }
 
+    /// Get video soundtrack player
+    pub fn soundtrack(&mut self) -> &mut SoundtrackPlayer {
+        &mut self.soundtrack
+    }
+
+    /// Get sound loader (load modes and decoded sound cache)
+    pub fn loader(&mut self) -> &mut SoundLoader {
+        &mut self.loader
+    }
+
+    /// Decoded memory and streaming statistics
+    pub fn memory_stats(&self) -> AudioMemoryStats {
+        self.loader.stats()
+    }
+
+    /// Decode a sound into memory ahead of playback
+    pub fn preload(&mut self, path: impl AsRef<std::path::Path>) -> EngineResult<()> {
+        self.loader.preload(path)
+    }
+
     /// Play BGM with direct access to both player and manager
     ///
+    /// The file is streamed or preloaded depending on its size
+    /// (`AudioLoadMode::Auto`). A `path` naming a track of the BGM manifest
+    /// plays that track with its loop points instead.
+    ///
     /// # Arguments
-    /// * `path` - Path to the audio file
+    /// * `path` - Path to the audio file, or a BGM manifest ID
     /// * `loop_enabled` - Whether to loop the BGM
     /// * `fade_in_duration` - Optional fade-in duration in seconds
     /// * `volume_multiplier` - Volume multiplier for this playback (1.0 = use config volume)
@@ -93,17 +220,89 @@ impl AudioManager {
         fade_in_duration: Option<f64>,
         volume_multiplier: f32,
     ) -> EngineResult<()> {
+        if let Some(def) = path
+            .as_ref()
+            .to_str()
+            .and_then(|id| self.bgm_tracks.get(id))
+            .cloned()
+        {
+            let base_dir = self.bgm_base_dir.clone();
+            return self.play_bgm_track(&def, &base_dir, fade_in_duration, volume_multiplier);
+        }
+
+        // Calculate effective volume (config volume * ducking * multiplier)
+        let effective_volume = self.bgm_volume() * volume_multiplier;
+
         let kira = self.kira_manager.as_mut().ok_or_else(|| {
             EngineError::AudioInit("Audio is disabled - cannot play BGM".to_string())
         })?;
 
-        // Calculate effective volume (config volume * multiplier)
-        let effective_volume = self.config.effective_music_volume() * volume_multiplier;
+        // Set the volume before playing; a crossfading track keeps its own
+        self.bgm.set_next_volume(effective_volume);
 
-        // Set the volume before playing
-        self.bgm.set_volume(effective_volume, None)?;
+        let source = self.loader.load(path, AudioLoadMode::Auto)?;
+        let loop_region = loop_enabled.then(narrative_core::LoopRegion::default);
+        self.bgm
+            .play_source(kira, source, loop_region, fade_in_duration)
+    }
+
+    /// Play a BGM track from its manifest definition
+    ///
+    /// Uses the definition's loop points, load mode, default volume and fade-in.
+    ///
+    /// # Arguments
+    /// * `def` - BGM definition
+    /// * `base_dir` - Directory `def.file_path` is relative to
+    /// * `volume_multiplier` - Volume multiplier for this playback (1.0 = use config volume)
+    pub fn play_bgm_def(
+        &mut self,
+        def: &BgmDef,
+        base_dir: impl AsRef<std::path::Path>,
+        volume_multiplier: f32,
+    ) -> EngineResult<()> {
+        self.play_bgm_track(def, base_dir.as_ref(), None, volume_multiplier)
+    }
+
+    /// Play a manifest track, with `fade_in` overriding the definition's fade-in
+    fn play_bgm_track(
+        &mut self,
+        def: &BgmDef,
+        base_dir: &Path,
+        fade_in: Option<f64>,
+        volume_multiplier: f32,
+    ) -> EngineResult<()> {
+        let effective_volume = self.bgm_volume() * def.default_volume * volume_multiplier;
+
+        let kira = self.kira_manager.as_mut().ok_or_else(|| {
+            EngineError::AudioInit("Audio is disabled - cannot play BGM".to_string())
+        })?;
+        self.bgm.set_next_volume(effective_volume);
 
-        self.bgm.play(kira, path, loop_enabled, fade_in_duration)
+        let fade_in =
+            fade_in.or_else(|| (def.fade_in_duration > 0.0).then_some(def.fade_in_duration as f64));
+        let source = self
+            .loader
+            .load(base_dir.join(&def.file_path), def.load_mode)?;
+        self.bgm
+            .play_source(kira, source, def.loop_region(), fade_in)
+    }
+
+    /// Register the tracks of a BGM manifest
+    ///
+    /// `play_bgm` then accepts their IDs, playing them with their loop points.
+    /// Replaces previously registered tracks.
+    ///
+    /// # Arguments
+    /// * `manifest` - BGM manifest
+    /// * `base_dir` - Directory the tracks' file paths are relative to
+    pub fn set_bgm_manifest(&mut self, manifest: &BgmManifest, base_dir: impl Into<PathBuf>) {
+        self.bgm_tracks = manifest.tracks.clone();
+        self.bgm_base_dir = base_dir.into();
+    }
+
+    /// Check if an ID names a track of the BGM manifest
+    pub fn is_bgm_track(&self, id: &str) -> bool {
+        self.bgm_tracks.contains_key(id)
     }
 
     /// Stop BGM playback
@@ -131,8 +330,13 @@ impl AudioManager {
 
     /// Play SE with direct access to both player and manager
     ///
+    /// The file is streamed or preloaded depending on its size
+    /// (`AudioLoadMode::Auto`); preloaded SE are decoded only once. If `path`
+    /// is the ID of a variation pool (see [`set_se_pools`](Self::set_se_pools)),
+    /// one of the pool's files is played instead.
+    ///
     /// # Arguments
-    /// * `path` - Path to the audio file
+    /// * `path` - Path to the audio file, or a pool ID
     /// * `volume_multiplier` - Volume multiplier for this playback (1.0 = use config volume)
     pub fn play_se(
         &mut self,
@@ -149,7 +353,59 @@ impl AudioManager {
         // Set the volume before playing
         self.se.set_volume(effective_volume)?;
 
-        self.se.play(kira, path)
+        let variation = path
+            .as_ref()
+            .to_str()
+            .and_then(|id| self.se.next_variation(id));
+        match variation {
+            Some((file, playback_rate)) => {
+                let source = self.loader.load(file, AudioLoadMode::Auto)?;
+                self.se.play_source_at_rate(kira, source, playback_rate)
+            }
+            None => {
+                let source = self.loader.load(path, AudioLoadMode::Auto)?;
+                self.se.play_source(kira, source)
+            }
+        }
+    }
+
+    /// Use the variation pools of an SE manifest
+    ///
+    /// Afterwards, playing a pool ID with [`play_se`](Self::play_se) picks one
+    /// of the pool's files.
+    pub fn set_se_pools(&mut self, manifest: &SeManifest) {
+        self.se.set_pools(manifest.pools.values().cloned());
+    }
+
+    /// Check if an SE path is the ID of a variation pool
+    pub fn is_se_pool(&self, id: &str) -> bool {
+        self.se.has_pool(id)
+    }
+
+    /// Play a sound effect from its manifest definition
+    ///
+    /// # Arguments
+    /// * `def` - SE definition
+    /// * `base_dir` - Directory `def.file_path` is relative to
+    /// * `volume_multiplier` - Volume multiplier for this playback (1.0 = use config volume)
+    pub fn play_se_def(
+        &mut self,
+        def: &SeDef,
+        base_dir: impl AsRef<std::path::Path>,
+        volume_multiplier: f32,
+    ) -> EngineResult<()> {
+        let kira = self.kira_manager.as_mut().ok_or_else(|| {
+            EngineError::AudioInit("Audio is disabled - cannot play SE".to_string())
+        })?;
+
+        let effective_volume =
+            self.config.effective_sound_volume() * def.default_volume * volume_multiplier;
+        self.se.set_volume(effective_volume)?;
+
+        let source = self
+            .loader
+            .load(base_dir.as_ref().join(&def.file_path), def.load_mode)?;
+        self.se.play_source(kira, source)
     }
 
     /// Stop all currently playing SE
@@ -162,6 +418,59 @@ impl AudioManager {
         self.se.active_count()
     }
 
+    /// Play a voice line, cutting off the current one
+    ///
+    /// Voice lines are streamed: each plays about once, so caching them
+    /// decoded would only hold memory.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the audio file
+    /// * `volume_multiplier` - Volume multiplier for this line (1.0 = use config volume)
+    pub fn play_voice(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+        volume_multiplier: f32,
+    ) -> EngineResult<()> {
+        let kira = self.kira_manager.as_mut().ok_or_else(|| {
+            EngineError::AudioInit("Audio is disabled - cannot play voice".to_string())
+        })?;
+
+        let source = self.loader.load(path, AudioLoadMode::Stream)?;
+        self.voice.play_source(kira, source, volume_multiplier)?;
+        self.update_voice_ducking()
+    }
+
+    /// Stop the current voice line
+    pub fn stop_voice(&mut self) -> EngineResult<()> {
+        self.voice.stop();
+        self.update_voice_ducking()
+    }
+
+    /// Check if a voice line is playing
+    pub fn is_voice_playing(&self) -> bool {
+        self.voice.is_playing()
+    }
+
+    /// Play the soundtrack of a video cutscene at music volume
+    pub fn play_soundtrack(&mut self, data: StaticSoundData) -> EngineResult<()> {
+        let volume = self.soundtrack_volume();
+        let kira = self.kira_manager.as_mut().ok_or_else(|| {
+            EngineError::AudioInit("Audio is disabled - cannot play soundtrack".to_string())
+        })?;
+        self.soundtrack.set_volume(volume);
+        self.soundtrack.play(kira, data)
+    }
+
+    /// Stop the video soundtrack
+    pub fn stop_soundtrack(&mut self) {
+        self.soundtrack.stop();
+    }
+
+    /// Playback position of the video soundtrack in seconds, `None` if none is playing
+    pub fn soundtrack_position(&self) -> Option<f64> {
+        self.soundtrack.position()
+    }
+
     /// Get the current audio configuration
     pub fn config(&self) -> &AudioConfig {
         &self.config
@@ -173,12 +482,51 @@ impl AudioManager {
         self.apply_volumes()
     }
 
-    /// Apply volumes from config to all players
+    /// BGM volume from config with speech and voice ducking applied
+    fn bgm_volume(&self) -> f32 {
+        let voice_ducking = if self.voice_ducked {
+            10f32.powf(-self.config.voice_ducking_db / 20.0)
+        } else {
+            1.0
+        };
+        self.config.effective_music_volume() * self.bgm_ducking * voice_ducking
+    }
+
+    /// Video soundtrack volume: music volume with speech ducking applied
+    fn soundtrack_volume(&self) -> f32 {
+        self.config.effective_music_volume() * self.bgm_ducking
+    }
+
+    /// Lower BGM when a voice line starts and bring it back when it ends
+    ///
+    /// BGM goes down over a short fade and comes back up over
+    /// `voice_ducking_release` seconds.
+    fn update_voice_ducking(&mut self) -> EngineResult<()> {
+        let ducked = self.voice.is_playing() && self.config.voice_ducking_db > 0.0;
+        if ducked == self.voice_ducked {
+            return Ok(());
+        }
+        self.voice_ducked = ducked;
+        let fade = if ducked {
+            BGM_DUCKING_FADE
+        } else {
+            f64::from(self.config.voice_ducking_release)
+        };
+        self.bgm.set_volume(self.bgm_volume(), Some(fade))
+    }
+
+    /// Check if BGM is lowered for a playing voice line
+    pub fn is_ducked_for_voice(&self) -> bool {
+        self.voice_ducked
+    }
+
+    /// Apply volumes and the BGM crossfade from config to all players
     fn apply_volumes(&mut self) -> EngineResult<()> {
-        self.bgm
-            .set_volume(self.config.effective_music_volume(), None)?;
+        self.bgm.set_crossfade(f64::from(self.config.bgm_crossfade));
+        self.bgm.set_volume(self.bgm_volume(), None)?;
+        self.soundtrack.set_volume(self.soundtrack_volume());
         self.se.set_volume(self.config.effective_sound_volume())?;
-        // Voice player volumes will be applied when voice playback is implemented
+        self.voice.set_volume(self.config.effective_voice_volume());
         Ok(())
     }
 
@@ -191,8 +539,24 @@ impl AudioManager {
     /// Set music volume (0.0-1.0) and apply
     pub fn set_music_volume(&mut self, volume: f32) -> EngineResult<()> {
         self.config.set_music_volume(volume);
+        self.soundtrack.set_volume(self.soundtrack_volume());
+        self.bgm.set_volume(self.bgm_volume(), None)
+    }
+
+    /// Duck BGM to `level` (0.0-1.0) of its volume, e.g. while text-to-speech
+    /// is talking; 1.0 restores it
+    ///
+    /// The change ramps over a short fade so the music doesn't jump.
+    pub fn set_bgm_ducking(&mut self, level: f32) -> EngineResult<()> {
+        self.bgm_ducking = level.clamp(0.0, 1.0);
+        self.soundtrack.set_volume(self.soundtrack_volume());
         self.bgm
-            .set_volume(self.config.effective_music_volume(), None)
+            .set_volume(self.bgm_volume(), Some(BGM_DUCKING_FADE))
+    }
+
+    /// BGM volume multiplier currently applied by ducking
+    pub fn bgm_ducking(&self) -> f32 {
+        self.bgm_ducking
     }
 
     /// Set sound effects volume (0.0-1.0) and apply
@@ -204,7 +568,7 @@ impl AudioManager {
     /// Set voice volume (0.0-1.0)
     pub fn set_voice_volume(&mut self, volume: f32) -> EngineResult<()> {
         self.config.set_voice_volume(volume);
-        // Voice player volumes will be applied when voice playback is implemented
+        self.voice.set_volume(self.config.effective_voice_volume());
         Ok(())
     }
 
@@ -230,6 +594,82 @@ impl AudioManager {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_audio_manager_process_queued_commands() {
+        let mut manager = AudioManager::disabled();
+        let queue = manager.queue();
+
+        queue.set_music_volume(0.25);
+        queue.set_voice_volume(0.5);
+        // Playback fails while disabled, but is still consumed
+        queue.play_se("missing.ogg", 1.0);
+
+        assert_eq!(manager.process_commands(), 3);
+        assert_eq!(manager.config().music_volume, 0.25);
+        assert_eq!(manager.config().voice_volume, 0.5);
+        assert_eq!(manager.process_commands(), 0);
+    }
+
+    #[test]
+    fn test_audio_manager_process_commands_with_callback() {
+        let mut manager = AudioManager::disabled();
+        let queue = manager.queue();
+
+        queue.play_se("missing.ogg", 1.0);
+        queue.stop_all_se();
+
+        let mut processed = Vec::new();
+        assert_eq!(
+            manager.process_commands_with(|command| processed.push(command.clone())),
+            2
+        );
+        assert_eq!(
+            processed,
+            vec![
+                AudioCommand::PlaySe {
+                    path: "missing.ogg".into(),
+                    volume: 1.0,
+                },
+                AudioCommand::StopAllSe,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audio_manager_bgm_ducking() {
+        let mut manager = AudioManager::disabled();
+        let queue = manager.queue();
+
+        queue.set_bgm_ducking(0.3);
+        manager.process_commands();
+        assert_eq!(manager.bgm_ducking(), 0.3);
+        // Ducking scales BGM without changing the configured volume
+        assert_eq!(
+            manager.config().music_volume,
+            AudioConfig::default().music_volume
+        );
+
+        queue.set_bgm_ducking(1.5);
+        manager.process_commands();
+        assert_eq!(manager.bgm_ducking(), 1.0);
+    }
+
+    #[test]
+    fn test_audio_manager_bgm_manifest() {
+        let mut manager = AudioManager::disabled();
+        let manifest = BgmManifest::new().add_track(BgmDef::new(
+            "bgm.title",
+            "Title",
+            "audio/music/title.ogg",
+        ));
+        manager.set_bgm_manifest(&manifest, "assets");
+
+        assert!(manager.is_bgm_track("bgm.title"));
+        assert!(!manager.is_bgm_track("assets/audio/music/title.ogg"));
+        // Track IDs resolve to the manifest entry, which still needs audio
+        assert!(manager.play_bgm("bgm.title", true, None, 1.0).is_err());
+    }
+
     #[test]
     fn test_audio_manager_new() {
         let manager = AudioManager::new();
@@ -243,6 +683,28 @@ mod tests {
         assert!(!bgm.is_playing());
     }
 
+    #[test]
+    fn test_audio_manager_voice_ducking() {
+        let mut manager = AudioManager::disabled();
+        manager
+            .update_config(AudioConfig {
+                music_volume: 1.0,
+                voice_ducking_db: 20.0,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(manager.bgm_volume(), 1.0);
+
+        // -20 dB while a voice line plays
+        manager.voice_ducked = true;
+        assert!((manager.bgm_volume() - 0.1).abs() < 1e-6);
+
+        // Without a playing voice, BGM comes back up on the next frame
+        manager.process_commands();
+        assert!(!manager.is_ducked_for_voice());
+        assert_eq!(manager.bgm_volume(), 1.0);
+    }
+
     #[test]
     fn test_audio_manager_is_bgm_playing_initial() {
         let manager = AudioManager::new().unwrap();
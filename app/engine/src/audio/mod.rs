@@ -1,13 +1,23 @@
 //! Audio module
 //!
-//! This module provides audio playback using kira, including BGM, SE, and voice.
+//! This module provides audio playback using kira, including BGM, SE, voice
+//! and video soundtracks, and text-to-speech for self-voicing.
 
 mod bgm;
 mod manager;
+mod queue;
 mod se;
+mod sound;
+mod soundtrack;
+mod tts;
 mod voice;
 
 pub use bgm::BgmPlayer;
 pub use manager::AudioManager;
+pub use queue::{AudioCommand, AudioQueue};
 pub use se::SePlayer;
+pub(crate) use sound::decoded_size;
+pub use sound::{AudioMemoryStats, SoundLoader, SoundSource};
+pub use soundtrack::SoundtrackPlayer;
+pub use tts::{CommandSpeechBackend, SpeechBackend, SpeechParams, TextToSpeech};
 pub use voice::VoicePlayer;
@@ -0,0 +1,196 @@
+//! Audio command queue
+//!
+//! UI code sends [`AudioCommand`]s through a cloneable [`AudioQueue`] instead of
+//! locking the [`AudioManager`](super::AudioManager). The owner of the manager
+//! applies them once per frame with
+//! [`AudioManager::process_commands`](super::AudioManager::process_commands).
+
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+/// Audio operation queued for the next frame
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioCommand {
+    /// Play BGM, replacing the current track
+    PlayBgm {
+        path: PathBuf,
+        looped: bool,
+        /// Fade-in duration in seconds
+        fade_in: Option<f64>,
+        /// Volume multiplier (1.0 = use config volume)
+        volume: f32,
+    },
+    /// Stop BGM playback
+    StopBgm {
+        /// Fade-out duration in seconds
+        fade_out: Option<f64>,
+    },
+    /// Play a sound effect
+    PlaySe {
+        path: PathBuf,
+        /// Volume multiplier (1.0 = use config volume)
+        volume: f32,
+    },
+    /// Stop all currently playing SE
+    StopAllSe,
+    /// Play a voice line, cutting off the current one
+    PlayVoice {
+        path: PathBuf,
+        /// Volume multiplier (1.0 = use config volume)
+        volume: f32,
+    },
+    /// Stop the current voice line
+    StopVoice,
+    /// Set master volume (0.0-1.0)
+    SetMasterVolume(f32),
+    /// Set music volume (0.0-1.0)
+    SetMusicVolume(f32),
+    /// Set sound effects volume (0.0-1.0)
+    SetSoundVolume(f32),
+    /// Set voice volume (0.0-1.0)
+    SetVoiceVolume(f32),
+    /// Duck BGM to a fraction of its volume (1.0 = not ducked)
+    SetBgmDucking(f32),
+}
+
+/// Cloneable handle for sending audio commands
+///
+/// Sending never blocks; commands sent after the manager is dropped are discarded.
+#[derive(Debug, Clone)]
+pub struct AudioQueue {
+    sender: Sender<AudioCommand>,
+}
+
+impl AudioQueue {
+    /// Create a queue and the receiver its commands arrive on
+    pub(super) fn channel() -> (Self, Receiver<AudioCommand>) {
+        let (sender, receiver) = channel();
+        (Self { sender }, receiver)
+    }
+
+    /// Queue a command
+    pub fn send(&self, command: AudioCommand) {
+        if let Err(e) = self.sender.send(command) {
+            tracing::debug!("Audio command dropped, manager is gone: {:?}", e.0);
+        }
+    }
+
+    /// Queue BGM playback
+    ///
+    /// # Arguments
+    /// * `path` - Path to the audio file
+    /// * `looped` - Whether to loop the BGM
+    /// * `fade_in` - Optional fade-in duration in seconds
+    /// * `volume` - Volume multiplier (1.0 = use config volume)
+    pub fn play_bgm(
+        &self,
+        path: impl Into<PathBuf>,
+        looped: bool,
+        fade_in: Option<f64>,
+        volume: f32,
+    ) {
+        self.send(AudioCommand::PlayBgm {
+            path: path.into(),
+            looped,
+            fade_in,
+            volume,
+        });
+    }
+
+    /// Queue stopping BGM with an optional fade-out in seconds
+    pub fn stop_bgm(&self, fade_out: Option<f64>) {
+        self.send(AudioCommand::StopBgm { fade_out });
+    }
+
+    /// Queue a sound effect
+    pub fn play_se(&self, path: impl Into<PathBuf>, volume: f32) {
+        self.send(AudioCommand::PlaySe {
+            path: path.into(),
+            volume,
+        });
+    }
+
+    /// Queue stopping all sound effects
+    pub fn stop_all_se(&self) {
+        self.send(AudioCommand::StopAllSe);
+    }
+
+    /// Queue a voice line
+    pub fn play_voice(&self, path: impl Into<PathBuf>, volume: f32) {
+        self.send(AudioCommand::PlayVoice {
+            path: path.into(),
+            volume,
+        });
+    }
+
+    /// Queue stopping the current voice line
+    pub fn stop_voice(&self) {
+        self.send(AudioCommand::StopVoice);
+    }
+
+    /// Queue a master volume change
+    pub fn set_master_volume(&self, volume: f32) {
+        self.send(AudioCommand::SetMasterVolume(volume));
+    }
+
+    /// Queue a music volume change
+    pub fn set_music_volume(&self, volume: f32) {
+        self.send(AudioCommand::SetMusicVolume(volume));
+    }
+
+    /// Queue a sound effects volume change
+    pub fn set_sound_volume(&self, volume: f32) {
+        self.send(AudioCommand::SetSoundVolume(volume));
+    }
+
+    /// Queue a voice volume change
+    pub fn set_voice_volume(&self, volume: f32) {
+        self.send(AudioCommand::SetVoiceVolume(volume));
+    }
+
+    /// Queue ducking BGM to `level` of its volume (1.0 restores it)
+    pub fn set_bgm_ducking(&self, level: f32) {
+        self.send(AudioCommand::SetBgmDucking(level));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_queue_preserves_order() {
+        let (queue, receiver) = AudioQueue::channel();
+        let clone = queue.clone();
+
+        queue.stop_bgm(Some(0.5));
+        clone.play_bgm("title.ogg", true, None, 1.0);
+        queue.set_music_volume(0.3);
+
+        let commands: Vec<_> = receiver.try_iter().collect();
+        assert_eq!(
+            commands,
+            vec![
+                AudioCommand::StopBgm {
+                    fade_out: Some(0.5)
+                },
+                AudioCommand::PlayBgm {
+                    path: PathBuf::from("title.ogg"),
+                    looped: true,
+                    fade_in: None,
+                    volume: 1.0,
+                },
+                AudioCommand::SetMusicVolume(0.3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audio_queue_send_after_receiver_dropped() {
+        let (queue, receiver) = AudioQueue::channel();
+        drop(receiver);
+
+        // Must not panic
+        queue.play_se("click.ogg", 1.0);
+    }
+}
@@ -1,39 +1,107 @@
 //! Sound effect player
 
+use super::sound::{SoundHandle, SoundSource};
 use crate::error::{EngineError, EngineResult};
-use kira::{
-    AudioManager, Decibels, Value,
-    sound::static_sound::{StaticSoundData, StaticSoundHandle, StaticSoundSettings},
-};
+use kira::{AudioManager, Decibels};
+use narrative_core::{SePool, SePoolMode};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Default maximum number of simultaneous SE playback
 const DEFAULT_MAX_SIMULTANEOUS: usize = 32;
 
 /// SE player with support for multiple simultaneous playback
+///
+/// Also resolves variation pools: see [`next_variation`](Self::next_variation).
 pub struct SePlayer {
-    active_handles: Vec<StaticSoundHandle>,
+    active_handles: Vec<SoundHandle>,
     current_volume: f64,
     max_simultaneous: usize,
+    /// Variation pools by ID
+    pools: HashMap<String, SePool>,
+    /// Index of the file each pool played last
+    last_variation: HashMap<String, usize>,
+    /// Xorshift state for random picks and pitch jitter
+    rng: u64,
 }
 
 impl SePlayer {
     /// Create a new SE player
     pub fn new() -> Self {
-        Self {
-            active_handles: Vec::new(),
-            current_volume: 0.0, // 0 dB = unity gain
-            max_simultaneous: DEFAULT_MAX_SIMULTANEOUS,
-        }
+        Self::with_capacity(DEFAULT_MAX_SIMULTANEOUS)
     }
 
     /// Create a new SE player with custom max simultaneous sounds
     pub fn with_capacity(max_simultaneous: usize) -> Self {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
         Self {
             active_handles: Vec::new(),
-            current_volume: 0.0,
+            current_volume: 0.0, // 0 dB = unity gain
             max_simultaneous,
+            pools: HashMap::new(),
+            last_variation: HashMap::new(),
+            // Xorshift must not start at zero
+            rng: seed | 1,
+        }
+    }
+
+    /// Replace the variation pools
+    pub fn set_pools(&mut self, pools: impl IntoIterator<Item = SePool>) {
+        self.pools = pools
+            .into_iter()
+            .map(|pool| (pool.id.clone(), pool))
+            .collect();
+        self.last_variation.clear();
+    }
+
+    /// Check if a variation pool exists
+    pub fn has_pool(&self, id: &str) -> bool {
+        self.pools.contains_key(id)
+    }
+
+    /// Pick the next file of a variation pool and its playback rate
+    ///
+    /// Random pools never repeat the file played last (unless it is the only
+    /// one); round-robin pools cycle through their files in order. Returns
+    /// `None` if `id` isn't a pool.
+    pub fn next_variation(&mut self, id: &str) -> Option<(String, f64)> {
+        let pool = self.pools.get(id)?;
+        let count = pool.files.len();
+        if count == 0 {
+            return None;
         }
+        let (mode, (min_pitch, max_pitch)) = (pool.mode, pool.pitch_range);
+        let last = self.last_variation.get(id).copied();
+
+        let index = match (mode, last) {
+            (SePoolMode::RoundRobin, Some(last)) => (last + 1) % count,
+            (SePoolMode::RoundRobin, None) => 0,
+            (SePoolMode::Random, Some(last)) if count > 1 => {
+                // Pick among the other files
+                let index = (self.next_random() % (count as u64 - 1)) as usize;
+                if index >= last { index + 1 } else { index }
+            }
+            (SePoolMode::Random, _) => (self.next_random() % count as u64) as usize,
+        };
+        self.last_variation.insert(id.to_string(), index);
+
+        let fraction = (self.next_random() >> 11) as f64 / (1u64 << 53) as f64;
+        let rate = min_pitch as f64 + (max_pitch as f64 - min_pitch as f64) * fraction;
+        let file = self.pools.get(id)?.files.get(index)?.clone();
+        Some((file, rate))
+    }
+
+    /// Next pseudo-random number (xorshift64)
+    fn next_random(&mut self) -> u64 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng = x;
+        x
     }
 
     /// Play SE from file path
@@ -44,6 +112,38 @@ impl SePlayer {
     ///
     /// Note: This method automatically cleans up finished sound handles
     pub fn play(&mut self, manager: &mut AudioManager, path: impl AsRef<Path>) -> EngineResult<()> {
+        // Load audio file
+        let source = SoundSource::from_file(path.as_ref(), false).map_err(|e| {
+            EngineError::SePlayback(format!(
+                "Failed to load SE file '{}': {:?}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+
+        self.play_source(manager, source)
+    }
+
+    /// Play already loaded SE
+    ///
+    /// # Arguments
+    /// * `manager` - Kira audio manager
+    /// * `source` - Streamed or decoded audio
+    pub fn play_source(
+        &mut self,
+        manager: &mut AudioManager,
+        source: SoundSource,
+    ) -> EngineResult<()> {
+        self.play_source_at_rate(manager, source, 1.0)
+    }
+
+    /// Play already loaded SE at a playback rate (1.0 = original pitch)
+    pub fn play_source_at_rate(
+        &mut self,
+        manager: &mut AudioManager,
+        source: SoundSource,
+        playback_rate: f64,
+    ) -> EngineResult<()> {
         // Clean up finished sounds before playing new one
         self.cleanup_finished();
 
@@ -54,25 +154,16 @@ impl SePlayer {
             handle.stop(kira::Tween::default());
         }
 
-        // Load audio file
-        let sound_data = StaticSoundData::from_file(path.as_ref()).map_err(|e| {
-            EngineError::SePlayback(format!(
-                "Failed to load SE file '{}': {:?}",
-                path.as_ref().display(),
-                e
-            ))
-        })?;
-
-        // Configure playback settings
-        let settings = StaticSoundSettings::default()
-            .volume(Value::Fixed(Decibels(self.current_volume as f32)));
-
         // Play the sound
-        let handle = manager
-            .play(sound_data.with_settings(settings))
-            .map_err(|e| {
-                EngineError::SePlayback(format!("Failed to start SE playback: {:?}", e))
-            })?;
+        let handle = source
+            .play(
+                manager,
+                Decibels(self.current_volume as f32),
+                None,
+                None,
+                playback_rate,
+            )
+            .map_err(|e| EngineError::SePlayback(format!("Failed to start SE playback: {}", e)))?;
 
         // Store the handle
         self.active_handles.push(handle);
@@ -188,6 +279,47 @@ mod tests {
         assert_eq!(player.active_count(), 0);
     }
 
+    fn pool(mode: SePoolMode) -> SePool {
+        SePool::new(
+            "footsteps",
+            vec![
+                "a.ogg".to_string(),
+                "b.ogg".to_string(),
+                "c.ogg".to_string(),
+            ],
+        )
+        .with_mode(mode)
+        .with_pitch_range(0.9, 1.1)
+    }
+
+    #[test]
+    fn test_se_pool_round_robin() {
+        let mut player = SePlayer::new();
+        player.set_pools([pool(SePoolMode::RoundRobin)]);
+        assert!(player.has_pool("footsteps"));
+        assert!(player.next_variation("a.ogg").is_none());
+
+        let files: Vec<String> = (0..4)
+            .filter_map(|_| player.next_variation("footsteps"))
+            .map(|(file, _)| file)
+            .collect();
+        assert_eq!(files, ["a.ogg", "b.ogg", "c.ogg", "a.ogg"]);
+    }
+
+    #[test]
+    fn test_se_pool_random_never_repeats_and_jitters_pitch() {
+        let mut player = SePlayer::new();
+        player.set_pools([pool(SePoolMode::Random)]);
+
+        let mut last = None;
+        for _ in 0..50 {
+            let (file, rate) = player.next_variation("footsteps").unwrap();
+            assert_ne!(Some(file.clone()), last);
+            assert!((0.9..=1.1).contains(&rate), "rate {}", rate);
+            last = Some(file);
+        }
+    }
+
     #[test]
     fn test_se_player_active_count_initial() {
         let player = SePlayer::new();
@@ -0,0 +1,340 @@
+//! Sound loading shared by the audio players
+//!
+//! Decides per asset whether to stream or decode into memory, caches decoded
+//! sounds and tracks how much memory they use.
+
+use crate::error::{EngineError, EngineResult};
+use kira::{
+    AudioManager, Decibels, Frame, PlaybackRate, Tween, Value,
+    sound::{
+        FromFileError, PlaybackState, Region,
+        static_sound::{StaticSoundData, StaticSoundHandle, StaticSoundSettings},
+        streaming::{StreamingSoundData, StreamingSoundHandle, StreamingSoundSettings},
+    },
+};
+use narrative_core::AudioLoadMode;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Audio data ready to be played
+pub enum SoundSource {
+    /// Fully decoded audio
+    Static(StaticSoundData),
+    /// Audio decoded while playing
+    Streaming(StreamingSoundData<FromFileError>),
+}
+
+impl SoundSource {
+    /// Open an audio file, either streaming or fully decoded
+    pub fn from_file(path: impl AsRef<Path>, stream: bool) -> Result<Self, FromFileError> {
+        if stream {
+            Ok(Self::Streaming(StreamingSoundData::from_file(path)?))
+        } else {
+            Ok(Self::Static(StaticSoundData::from_file(path)?))
+        }
+    }
+
+    /// Whether the audio is streamed
+    pub fn is_streaming(&self) -> bool {
+        matches!(self, Self::Streaming(_))
+    }
+
+    /// Length in sample frames
+    pub fn num_frames(&self) -> usize {
+        match self {
+            Self::Static(data) => data.num_frames(),
+            Self::Streaming(data) => data.num_frames(),
+        }
+    }
+
+    /// Sample rate in Hz
+    pub fn sample_rate(&self) -> u32 {
+        match self {
+            Self::Static(data) => data.sample_rate,
+            // The streaming decoder does not expose its sample rate directly
+            Self::Streaming(data) => {
+                let seconds = data.duration().as_secs_f64();
+                if seconds > 0.0 {
+                    (data.num_frames() as f64 / seconds).round() as u32
+                } else {
+                    0
+                }
+            }
+        }
+    }
+
+    /// Start playback
+    pub(crate) fn play(
+        self,
+        manager: &mut AudioManager,
+        volume: Decibels,
+        fade_in_tween: Option<Tween>,
+        loop_region: Option<Region>,
+        playback_rate: f64,
+    ) -> Result<SoundHandle, String> {
+        match self {
+            Self::Static(data) => {
+                let settings = StaticSoundSettings {
+                    volume: Value::Fixed(volume),
+                    fade_in_tween,
+                    loop_region,
+                    playback_rate: Value::Fixed(PlaybackRate(playback_rate)),
+                    ..Default::default()
+                };
+                manager
+                    .play(data.with_settings(settings))
+                    .map(SoundHandle::Static)
+                    .map_err(|e| format!("{:?}", e))
+            }
+            Self::Streaming(data) => {
+                let settings = StreamingSoundSettings {
+                    volume: Value::Fixed(volume),
+                    fade_in_tween,
+                    loop_region,
+                    playback_rate: Value::Fixed(PlaybackRate(playback_rate)),
+                    ..Default::default()
+                };
+                manager
+                    .play(data.with_settings(settings))
+                    .map(SoundHandle::Streaming)
+                    .map_err(|e| format!("{:?}", e))
+            }
+        }
+    }
+}
+
+/// Handle to a playing sound of either kind
+pub(crate) enum SoundHandle {
+    Static(StaticSoundHandle),
+    Streaming(StreamingSoundHandle<FromFileError>),
+}
+
+impl SoundHandle {
+    pub(crate) fn set_volume(&mut self, volume: Decibels, tween: Tween) {
+        match self {
+            Self::Static(handle) => handle.set_volume(volume, tween),
+            Self::Streaming(handle) => handle.set_volume(volume, tween),
+        }
+    }
+
+    pub(crate) fn pause(&mut self, tween: Tween) {
+        match self {
+            Self::Static(handle) => handle.pause(tween),
+            Self::Streaming(handle) => handle.pause(tween),
+        }
+    }
+
+    pub(crate) fn resume(&mut self, tween: Tween) {
+        match self {
+            Self::Static(handle) => handle.resume(tween),
+            Self::Streaming(handle) => handle.resume(tween),
+        }
+    }
+
+    pub(crate) fn stop(&mut self, tween: Tween) {
+        match self {
+            Self::Static(handle) => handle.stop(tween),
+            Self::Streaming(handle) => handle.stop(tween),
+        }
+    }
+
+    pub(crate) fn state(&self) -> PlaybackState {
+        match self {
+            Self::Static(handle) => handle.state(),
+            Self::Streaming(handle) => handle.state(),
+        }
+    }
+
+    /// Playback position in seconds
+    pub(crate) fn position(&self) -> f64 {
+        match self {
+            Self::Static(handle) => handle.position(),
+            Self::Streaming(handle) => handle.position(),
+        }
+    }
+}
+
+/// Decoded memory and streaming statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AudioMemoryStats {
+    /// Number of decoded sounds held in the cache
+    pub cached_sounds: usize,
+    /// Memory used by decoded sample data in the cache
+    pub decoded_bytes: u64,
+    /// Loads served from the cache
+    pub cache_hits: u64,
+    /// Files decoded into memory
+    pub decodes: u64,
+    /// Streams opened
+    pub streams_opened: u64,
+}
+
+/// Loads sounds according to their load mode and caches decoded audio
+pub struct SoundLoader {
+    cache: HashMap<PathBuf, StaticSoundData>,
+    stream_threshold: u64,
+    stats: AudioMemoryStats,
+}
+
+impl SoundLoader {
+    /// Create a loader with the default auto-stream threshold
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stream_threshold: AudioLoadMode::AUTO_STREAM_THRESHOLD,
+            stats: AudioMemoryStats::default(),
+        }
+    }
+
+    /// File size (in bytes) from which `AudioLoadMode::Auto` streams
+    pub fn stream_threshold(&self) -> u64 {
+        self.stream_threshold
+    }
+
+    /// Set the file size from which `AudioLoadMode::Auto` streams
+    pub fn set_stream_threshold(&mut self, bytes: u64) {
+        self.stream_threshold = bytes;
+    }
+
+    /// Load a sound for playback
+    ///
+    /// Preloaded sounds are decoded once and served from the cache afterwards.
+    /// A sound already in the cache is always served from it, whatever the mode.
+    pub fn load(
+        &mut self,
+        path: impl AsRef<Path>,
+        mode: AudioLoadMode,
+    ) -> EngineResult<SoundSource> {
+        let path = path.as_ref();
+        if let Some(data) = self.cache.get(path) {
+            self.stats.cache_hits = self.stats.cache_hits.saturating_add(1);
+            return Ok(SoundSource::Static(data.clone()));
+        }
+
+        let file_size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        if mode.should_stream(file_size, self.stream_threshold) {
+            let source = SoundSource::from_file(path, true).map_err(|e| load_error(path, e))?;
+            self.stats.streams_opened = self.stats.streams_opened.saturating_add(1);
+            return Ok(source);
+        }
+
+        Ok(SoundSource::Static(self.decode(path)?))
+    }
+
+    /// Decode a sound into the cache ahead of playback
+    pub fn preload(&mut self, path: impl AsRef<Path>) -> EngineResult<()> {
+        let path = path.as_ref();
+        if !self.cache.contains_key(path) {
+            self.decode(path)?;
+        }
+        Ok(())
+    }
+
+    /// Add a sound decoded elsewhere, such as by the asset preloader
+    pub fn insert_decoded(&mut self, path: impl Into<PathBuf>, data: StaticSoundData) {
+        self.insert(path.into(), data);
+    }
+
+    /// Drop a decoded sound from the cache
+    pub fn evict(&mut self, path: impl AsRef<Path>) {
+        if let Some(data) = self.cache.remove(path.as_ref()) {
+            self.stats.cached_sounds = self.cache.len();
+            self.stats.decoded_bytes = self.stats.decoded_bytes.saturating_sub(decoded_size(&data));
+        }
+    }
+
+    /// Drop all decoded sounds from the cache
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats.cached_sounds = 0;
+        self.stats.decoded_bytes = 0;
+    }
+
+    /// Current statistics
+    pub fn stats(&self) -> AudioMemoryStats {
+        self.stats
+    }
+
+    fn decode(&mut self, path: &Path) -> EngineResult<StaticSoundData> {
+        let data = StaticSoundData::from_file(path).map_err(|e| load_error(path, e))?;
+        self.stats.decodes = self.stats.decodes.saturating_add(1);
+        self.insert(path.to_path_buf(), data.clone());
+        Ok(data)
+    }
+
+    fn insert(&mut self, path: PathBuf, data: StaticSoundData) {
+        let size = decoded_size(&data);
+        if let Some(previous) = self.cache.insert(path, data) {
+            self.stats.decoded_bytes = self
+                .stats
+                .decoded_bytes
+                .saturating_sub(decoded_size(&previous));
+        }
+        self.stats.cached_sounds = self.cache.len();
+        self.stats.decoded_bytes = self.stats.decoded_bytes.saturating_add(size);
+    }
+}
+
+impl Default for SoundLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Memory used by decoded sample data
+pub(crate) fn decoded_size(data: &StaticSoundData) -> u64 {
+    let bytes = data
+        .frames
+        .len()
+        .saturating_mul(std::mem::size_of::<Frame>());
+    u64::try_from(bytes).unwrap_or(u64::MAX)
+}
+
+fn load_error(path: &Path, error: FromFileError) -> EngineError {
+    EngineError::AudioLoad(format!("Failed to load '{}': {:?}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn sound(frames: usize) -> StaticSoundData {
+        StaticSoundData {
+            sample_rate: 100,
+            frames: Arc::from(vec![Frame::ZERO; frames]),
+            settings: StaticSoundSettings::default(),
+            slice: None,
+        }
+    }
+
+    #[test]
+    fn test_sound_loader_cache_stats() {
+        let mut loader = SoundLoader::new();
+        loader.insert(PathBuf::from("a.ogg"), sound(100));
+        loader.insert(PathBuf::from("b.ogg"), sound(50));
+
+        let frame_size = std::mem::size_of::<Frame>() as u64;
+        assert_eq!(loader.stats().cached_sounds, 2);
+        assert_eq!(loader.stats().decoded_bytes, 150 * frame_size);
+
+        let source = loader.load("a.ogg", AudioLoadMode::Stream).unwrap();
+        assert!(!source.is_streaming());
+        assert_eq!(source.num_frames(), 100);
+        assert_eq!(loader.stats().cache_hits, 1);
+
+        loader.evict("a.ogg");
+        assert_eq!(loader.stats().decoded_bytes, 50 * frame_size);
+        loader.clear();
+        assert_eq!(loader.stats().cached_sounds, 0);
+        assert_eq!(loader.stats().decoded_bytes, 0);
+    }
+
+    #[test]
+    fn test_sound_loader_missing_file() {
+        let mut loader = SoundLoader::new();
+        assert!(loader.load("missing.ogg", AudioLoadMode::Preload).is_err());
+        assert!(loader.load("missing.ogg", AudioLoadMode::Stream).is_err());
+        assert_eq!(loader.stats(), AudioMemoryStats::default());
+    }
+}
@@ -0,0 +1,75 @@
+//! Video soundtrack player
+
+use super::bgm::{linear_tween, volume_to_db};
+use super::sound::{SoundHandle, SoundSource};
+use crate::error::{EngineError, EngineResult};
+use kira::sound::PlaybackState;
+use kira::sound::static_sound::StaticSoundData;
+use kira::{AudioManager, Decibels};
+
+/// Seconds the soundtrack fades out when a video is skipped
+const SOUNDTRACK_STOP_FADE: f64 = 0.1;
+
+/// Plays the audio track of a video cutscene
+///
+/// The video follows the soundtrack's playback position, so the two stay in
+/// sync even when frames are dropped.
+pub struct SoundtrackPlayer {
+    current_handle: Option<SoundHandle>,
+    /// Music volume from config (0.0 - 1.0)
+    volume: f32,
+}
+
+impl SoundtrackPlayer {
+    /// Create a new soundtrack player
+    pub fn new() -> Self {
+        Self {
+            current_handle: None,
+            volume: 1.0,
+        }
+    }
+
+    /// Play a decoded soundtrack from the start, stopping the current one
+    pub fn play(&mut self, manager: &mut AudioManager, data: StaticSoundData) -> EngineResult<()> {
+        self.stop();
+        let handle = SoundSource::Static(data)
+            .play(manager, self.decibels(), None, None, 1.0)
+            .map_err(|e| EngineError::Video(format!("Failed to start video soundtrack: {}", e)))?;
+        self.current_handle = Some(handle);
+        Ok(())
+    }
+
+    /// Stop the soundtrack
+    pub fn stop(&mut self) {
+        if let Some(mut handle) = self.current_handle.take() {
+            handle.stop(linear_tween(SOUNDTRACK_STOP_FADE));
+        }
+    }
+
+    /// Playback position in seconds, or `None` once the soundtrack is over
+    pub fn position(&self) -> Option<f64> {
+        self.current_handle
+            .as_ref()
+            .filter(|handle| handle.state() != PlaybackState::Stopped)
+            .map(SoundHandle::position)
+    }
+
+    /// Set volume (0.0 - 1.0), applied to the playing soundtrack as well
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+        let decibels = self.decibels();
+        if let Some(handle) = &mut self.current_handle {
+            handle.set_volume(decibels, Default::default());
+        }
+    }
+
+    fn decibels(&self) -> Decibels {
+        Decibels(volume_to_db(self.volume) as f32)
+    }
+}
+
+impl Default for SoundtrackPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
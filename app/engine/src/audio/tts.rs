@@ -0,0 +1,429 @@
+//! Text-to-speech for self-voicing
+//!
+//! [`TextToSpeech`] reads dialogue aloud through a [`SpeechBackend`]. The
+//! system backend runs eSpeak NG (`espeak-ng` / `espeak`) or macOS `say`,
+//! whichever is on the `PATH`; without either, self-voicing stays silent.
+//! Characters get distinct voices through their [`TtsVoice`].
+
+use crate::error::{EngineError, EngineResult};
+use narrative_core::TtsVoice;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+
+/// Slowest player speech rate
+const MIN_RATE: f32 = 0.5;
+
+/// Fastest player speech rate
+const MAX_RATE: f32 = 2.0;
+
+/// eSpeak's default pitch (0-99)
+const ESPEAK_PITCH: f32 = 50.0;
+
+/// eSpeak's default speed in words per minute
+const ESPEAK_WPM: f32 = 175.0;
+
+/// `say`'s default speed in words per minute
+const SAY_WPM: f32 = 175.0;
+
+/// How one utterance should sound
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeechParams {
+    /// System voice name, or None for the default voice
+    pub voice: Option<String>,
+    /// Pitch multiplier (1.0 = the voice's normal pitch)
+    pub pitch: f32,
+    /// Rate multiplier (1.0 = the voice's normal speed)
+    pub rate: f32,
+}
+
+impl SpeechParams {
+    /// Parameters for a character's voice at the player's speech rate
+    pub fn new(voice: Option<&TtsVoice>, player_rate: f32) -> Self {
+        match voice {
+            Some(voice) => Self {
+                voice: voice.voice.clone(),
+                pitch: voice.pitch,
+                rate: voice.rate * player_rate,
+            },
+            None => Self {
+                voice: None,
+                pitch: 1.0,
+                rate: player_rate,
+            },
+        }
+    }
+}
+
+/// Speech synthesizer
+pub trait SpeechBackend: Send + Sync {
+    /// Start speaking `text`, interrupting any utterance in progress
+    fn speak(&mut self, text: &str, params: &SpeechParams) -> EngineResult<()>;
+
+    /// Stop speaking
+    fn stop(&mut self);
+
+    /// Check if an utterance is still being spoken
+    fn is_speaking(&mut self) -> bool;
+}
+
+/// Speech program run by [`CommandSpeechBackend`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpeechProgram {
+    /// eSpeak NG or eSpeak
+    Espeak,
+    /// macOS `say`
+    Say,
+}
+
+/// Speaks by running a system speech program
+#[derive(Debug)]
+pub struct CommandSpeechBackend {
+    program: SpeechProgram,
+    path: PathBuf,
+    /// Utterance being spoken
+    child: Option<Child>,
+}
+
+impl CommandSpeechBackend {
+    /// Find a speech program on the `PATH`
+    ///
+    /// Returns None if none is installed.
+    pub fn detect() -> Option<Self> {
+        let candidates = [
+            ("espeak-ng", SpeechProgram::Espeak),
+            ("espeak", SpeechProgram::Espeak),
+            ("say", SpeechProgram::Say),
+        ];
+        candidates.into_iter().find_map(|(name, program)| {
+            find_in_path(name).map(|path| Self {
+                program,
+                path,
+                child: None,
+            })
+        })
+    }
+
+    /// Path of the speech program
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Command-line arguments for an utterance; the text is written to stdin
+    fn args(&self, params: &SpeechParams) -> Vec<String> {
+        let mut args = Vec::new();
+        match self.program {
+            SpeechProgram::Espeak => {
+                if let Some(voice) = &params.voice {
+                    args.extend(["-v".to_string(), voice.clone()]);
+                }
+                let pitch = (ESPEAK_PITCH * params.pitch).clamp(0.0, 99.0);
+                let speed = (ESPEAK_WPM * params.rate).clamp(80.0, 450.0);
+                args.extend([
+                    "-p".to_string(),
+                    format!("{:.0}", pitch),
+                    "-s".to_string(),
+                    format!("{:.0}", speed),
+                    "--stdin".to_string(),
+                ]);
+            }
+            SpeechProgram::Say => {
+                // `say` has no pitch option; voices differ by name and rate
+                if let Some(voice) = &params.voice {
+                    args.extend(["-v".to_string(), voice.clone()]);
+                }
+                let speed = (SAY_WPM * params.rate).clamp(80.0, 450.0);
+                args.extend([
+                    "-r".to_string(),
+                    format!("{:.0}", speed),
+                    "-f".to_string(),
+                    "-".to_string(),
+                ]);
+            }
+        }
+        args
+    }
+}
+
+impl SpeechBackend for CommandSpeechBackend {
+    fn speak(&mut self, text: &str, params: &SpeechParams) -> EngineResult<()> {
+        self.stop();
+
+        let mut child = Command::new(&self.path)
+            .args(self.args(params))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| {
+                EngineError::Speech(format!("Failed to run '{}': {}", self.path.display(), e))
+            })?;
+
+        // Dropping stdin after writing ends the input, so the program speaks it
+        if let Some(mut stdin) = child.stdin.take()
+            && let Err(e) = stdin.write_all(text.as_bytes())
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(EngineError::Speech(format!(
+                "Failed to send text to '{}': {}",
+                self.path.display(),
+                e
+            )));
+        }
+
+        self.child = Some(child);
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            // The program may have exited already
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    fn is_speaking(&mut self) -> bool {
+        let Some(child) = &mut self.child else {
+            return false;
+        };
+        match child.try_wait() {
+            Ok(None) => true,
+            Ok(Some(_)) | Err(_) => {
+                self.child = None;
+                false
+            }
+        }
+    }
+}
+
+impl Drop for CommandSpeechBackend {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Find an executable in the directories of the `PATH`
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(format!("{}{}", name, std::env::consts::EXE_SUFFIX)))
+        .find(|path| path.is_file())
+}
+
+/// Reads text aloud with per-character voices
+pub struct TextToSpeech {
+    backend: Option<Box<dyn SpeechBackend>>,
+    /// Player speech rate multiplier
+    rate: f32,
+}
+
+impl TextToSpeech {
+    /// Use the system speech program, or stay silent if there is none
+    pub fn system() -> Self {
+        match CommandSpeechBackend::detect() {
+            Some(backend) => {
+                tracing::info!("Text-to-speech using {}", backend.path().display());
+                Self::with_backend(Box::new(backend))
+            }
+            None => {
+                tracing::info!("No speech program found (espeak-ng, espeak or say)");
+                Self::disabled()
+            }
+        }
+    }
+
+    /// Speak through the given backend
+    pub fn with_backend(backend: Box<dyn SpeechBackend>) -> Self {
+        Self {
+            backend: Some(backend),
+            rate: 1.0,
+        }
+    }
+
+    /// Text-to-speech that never speaks
+    pub fn disabled() -> Self {
+        Self {
+            backend: None,
+            rate: 1.0,
+        }
+    }
+
+    /// Check if a speech backend is available
+    pub fn is_available(&self) -> bool {
+        self.backend.is_some()
+    }
+
+    /// Player speech rate multiplier
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Set the player speech rate multiplier (clamped to 0.5-2.0)
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate = rate.clamp(MIN_RATE, MAX_RATE);
+    }
+
+    /// Start reading `text` in a character's voice (None for the default
+    /// voice), interrupting the previous utterance
+    ///
+    /// Blank text just stops speaking.
+    pub fn speak(&mut self, text: &str, voice: Option<&TtsVoice>) -> EngineResult<()> {
+        let Some(backend) = &mut self.backend else {
+            return Ok(());
+        };
+        let text = text.trim();
+        if text.is_empty() {
+            backend.stop();
+            return Ok(());
+        }
+        backend.speak(text, &SpeechParams::new(voice, self.rate))
+    }
+
+    /// Stop speaking
+    pub fn stop(&mut self) {
+        if let Some(backend) = &mut self.backend {
+            backend.stop();
+        }
+    }
+
+    /// Check if an utterance is still being spoken
+    pub fn is_speaking(&mut self) -> bool {
+        self.backend
+            .as_mut()
+            .is_some_and(|backend| backend.is_speaking())
+    }
+}
+
+impl Default for TextToSpeech {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl std::fmt::Debug for TextToSpeech {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextToSpeech")
+            .field("available", &self.is_available())
+            .field("rate", &self.rate)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Backend recording what it's asked to say
+    #[derive(Default)]
+    struct RecordingBackend {
+        spoken: Arc<Mutex<Vec<(String, SpeechParams)>>>,
+        speaking: bool,
+    }
+
+    impl SpeechBackend for RecordingBackend {
+        fn speak(&mut self, text: &str, params: &SpeechParams) -> EngineResult<()> {
+            self.spoken
+                .lock()
+                .unwrap()
+                .push((text.to_string(), params.clone()));
+            self.speaking = true;
+            Ok(())
+        }
+
+        fn stop(&mut self) {
+            self.speaking = false;
+        }
+
+        fn is_speaking(&mut self) -> bool {
+            self.speaking
+        }
+    }
+
+    #[test]
+    fn test_speaks_with_character_voice() {
+        let backend = RecordingBackend::default();
+        let spoken = Arc::clone(&backend.spoken);
+        let mut tts = TextToSpeech::with_backend(Box::new(backend));
+        tts.set_rate(1.5);
+
+        let voice = TtsVoice::new("en-us+f3").with_pitch(1.3).with_rate(0.8);
+        tts.speak("  Hello.  ", Some(&voice)).unwrap();
+        tts.speak("Narration.", None).unwrap();
+        assert!(tts.is_speaking());
+
+        let spoken = spoken.lock().unwrap();
+        let (text, params) = &spoken[0];
+        assert_eq!(text, "Hello.");
+        assert_eq!(params.voice.as_deref(), Some("en-us+f3"));
+        assert_eq!(params.pitch, 1.3);
+        assert!((params.rate - 1.2).abs() < 1e-6);
+        assert_eq!(
+            spoken[1].1,
+            SpeechParams {
+                voice: None,
+                pitch: 1.0,
+                rate: 1.5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_blank_text_stops_speaking() {
+        let backend = RecordingBackend::default();
+        let spoken = Arc::clone(&backend.spoken);
+        let mut tts = TextToSpeech::with_backend(Box::new(backend));
+
+        tts.speak("Hello.", None).unwrap();
+        tts.speak(" ", None).unwrap();
+        assert!(!tts.is_speaking());
+        assert_eq!(spoken.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_rate_is_clamped() {
+        let mut tts = TextToSpeech::disabled();
+        tts.set_rate(10.0);
+        assert_eq!(tts.rate(), MAX_RATE);
+        tts.set_rate(0.0);
+        assert_eq!(tts.rate(), MIN_RATE);
+    }
+
+    #[test]
+    fn test_disabled_is_silent() {
+        let mut tts = TextToSpeech::disabled();
+        assert!(!tts.is_available());
+        assert!(tts.speak("Hello.", None).is_ok());
+        assert!(!tts.is_speaking());
+    }
+
+    #[test]
+    fn test_command_args() {
+        let espeak = CommandSpeechBackend {
+            program: SpeechProgram::Espeak,
+            path: PathBuf::from("espeak-ng"),
+            child: None,
+        };
+        let params = SpeechParams {
+            voice: Some("en-us+f3".to_string()),
+            pitch: 1.5,
+            rate: 2.0,
+        };
+        assert_eq!(
+            espeak.args(&params),
+            ["-v", "en-us+f3", "-p", "75", "-s", "350", "--stdin"]
+        );
+
+        let say = CommandSpeechBackend {
+            program: SpeechProgram::Say,
+            path: PathBuf::from("say"),
+            child: None,
+        };
+        assert_eq!(
+            say.args(&SpeechParams::new(None, 0.1)),
+            ["-r", "80", "-f", "-"]
+        );
+    }
+}
@@ -1,31 +1,103 @@
 //! Voice player
 
-use narrative_core::AudioId;
+use super::bgm::{linear_tween, volume_to_db};
+use super::sound::{SoundHandle, SoundSource};
+use crate::error::{EngineError, EngineResult};
+use kira::{AudioManager, Decibels, sound::PlaybackState};
+use std::path::Path;
+
+/// Seconds a voice line fades out when it is cut off
+const VOICE_STOP_FADE: f64 = 0.05;
 
 /// Voice player
+///
+/// Plays one voice line at a time: starting a line cuts off the previous one.
 pub struct VoicePlayer {
-    // Will be populated with kira in Phase 3.3
+    current_handle: Option<SoundHandle>,
+    /// Voice volume from config (0.0 - 1.0)
+    volume: f32,
+    /// Volume multiplier of the playing line
+    line_volume: f32,
 }
 
 impl VoicePlayer {
-    /// Create a new voice player (stub implementation)
+    /// Create a new voice player
     pub fn new() -> Self {
-        Self {}
+        Self {
+            current_handle: None,
+            volume: 1.0,
+            line_volume: 1.0,
+        }
+    }
+
+    /// Play a voice line from file path
+    ///
+    /// # Arguments
+    /// * `manager` - Kira audio manager
+    /// * `path` - Path to the audio file
+    pub fn play(&mut self, manager: &mut AudioManager, path: impl AsRef<Path>) -> EngineResult<()> {
+        let source = SoundSource::from_file(path.as_ref(), false).map_err(|e| {
+            EngineError::VoicePlayback(format!(
+                "Failed to load voice file '{}': {:?}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+
+        self.play_source(manager, source, 1.0)
     }
 
-    /// Play voice
-    pub fn play(&mut self, _audio_id: &AudioId) {
-        // TODO: Phase 3.3 - voice support
+    /// Play an already loaded voice line, stopping the current one
+    ///
+    /// # Arguments
+    /// * `manager` - Kira audio manager
+    /// * `source` - Streamed or decoded audio
+    /// * `volume_multiplier` - Volume multiplier for this line (1.0 = use config volume)
+    pub fn play_source(
+        &mut self,
+        manager: &mut AudioManager,
+        source: SoundSource,
+        volume_multiplier: f32,
+    ) -> EngineResult<()> {
+        self.stop();
+        self.line_volume = volume_multiplier;
+
+        let handle = source
+            .play(manager, self.decibels(), None, None, 1.0)
+            .map_err(|e| {
+                EngineError::VoicePlayback(format!("Failed to start voice playback: {}", e))
+            })?;
+        self.current_handle = Some(handle);
+
+        Ok(())
     }
 
     /// Stop voice
     pub fn stop(&mut self) {
-        // TODO: Phase 3.3 - voice support
+        if let Some(mut handle) = self.current_handle.take() {
+            handle.stop(linear_tween(VOICE_STOP_FADE));
+        }
+    }
+
+    /// Check if a voice line is playing (not yet finished or stopped)
+    pub fn is_playing(&self) -> bool {
+        self.current_handle
+            .as_ref()
+            .is_some_and(|handle| handle.state() != PlaybackState::Stopped)
     }
 
-    /// Set volume (0.0 - 1.0)
-    pub fn set_volume(&mut self, _volume: f32) {
-        // TODO: Phase 3.3 - voice support
+    /// Set volume (0.0 - 1.0), applied to the playing line as well
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+        let decibels = self.decibels();
+        if let Some(handle) = &mut self.current_handle {
+            handle.set_volume(decibels, Default::default());
+        }
+    }
+
+    /// Volume of the playing line in decibels
+    fn decibels(&self) -> Decibels {
+        Decibels(volume_to_db(self.volume * self.line_volume) as f32)
     }
 }
 
@@ -34,3 +106,23 @@ impl Default for VoicePlayer {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voice_player_volume() {
+        let mut player = VoicePlayer::new();
+        assert!(!player.is_playing());
+        assert_eq!(player.decibels(), Decibels(0.0));
+
+        player.set_volume(0.1);
+        player.line_volume = 0.5;
+        assert!((player.decibels().0 - volume_to_db(0.05) as f32).abs() < 1e-4);
+
+        // Stopping without a line is a no-op
+        player.stop();
+        assert!(!player.is_playing());
+    }
+}
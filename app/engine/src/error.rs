@@ -37,6 +37,10 @@ pub enum EngineError {
     #[error("Audio initialization failed: {0}")]
     AudioInit(String),
 
+    /// Audio file load error
+    #[error("Audio load error: {0}")]
+    AudioLoad(String),
+
     /// BGM playback error
     #[error("BGM playback error: {0}")]
     BgmPlayback(String),
@@ -49,6 +53,10 @@ pub enum EngineError {
     #[error("Voice playback error: {0}")]
     VoicePlayback(String),
 
+    /// Text-to-speech error
+    #[error("Text-to-speech error: {0}")]
+    Speech(String),
+
     // === Render Module Errors ===
     /// Renderer initialization error
     #[error("Renderer initialization failed: {0}")]
@@ -92,6 +100,15 @@ pub enum EngineError {
     #[error("Variable/Flag operation error: {0}")]
     VariableFlag(String),
 
+    /// Scenario script error
+    #[error("Script error: {0}")]
+    Script(String),
+
+    // === Video Module Errors ===
+    /// Video decoding error
+    #[error("Video error: {0}")]
+    Video(String),
+
     // === Save Module Errors ===
     /// Save operation error
     #[error("Save operation failed: {0}")]
@@ -183,6 +200,9 @@ mod tests {
         let err = EngineError::AudioInit("Failed to initialize kira".to_string());
         assert!(format!("{}", err).contains("Audio initialization failed"));
 
+        let err = EngineError::AudioLoad("bad file".to_string());
+        assert!(format!("{}", err).contains("Audio load error"));
+
         let err = EngineError::BgmPlayback("BGM error".to_string());
         assert!(format!("{}", err).contains("BGM playback error"));
 
@@ -230,6 +250,9 @@ mod tests {
 
         let err = EngineError::VariableFlag("Invalid variable".to_string());
         assert!(format!("{}", err).contains("Variable/Flag operation error"));
+
+        let err = EngineError::Script("Unknown function".to_string());
+        assert!(format!("{}", err).contains("Script error"));
     }
 
     #[test]
@@ -0,0 +1,190 @@
+//! Gamepad events and the gilrs backend
+//!
+//! winit has no gamepad support, so pads are read through gilrs, which maps
+//! every supported controller to a common layout on Linux, Windows and macOS
+//! and reports pads plugged in or out while the game runs. Events are drained
+//! once per frame by [`InputHandler::poll_gamepads`](super::InputHandler::poll_gamepads).
+//! Where gilrs has no backend, a warning is logged and events can still be
+//! fed in through [`InputHandler::process_gamepad_event`](super::InputHandler::process_gamepad_event).
+
+use gilrs::{Axis, Button, EventType, Gilrs};
+use narrative_core::config::GamepadButton;
+use std::sync::Mutex;
+
+/// Gamepad analog axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    LeftStickX,
+    /// Negative is up
+    LeftStickY,
+    RightStickX,
+    /// Negative is up
+    RightStickY,
+    /// D-pad reported as an axis (-1.0 left, 1.0 right)
+    DPadX,
+    /// D-pad reported as an axis (-1.0 up, 1.0 down)
+    DPadY,
+}
+
+/// Gamepad input event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamepadEvent {
+    /// Button pressed or released
+    Button {
+        button: GamepadButton,
+        pressed: bool,
+    },
+    /// Axis moved, value in -1.0..=1.0
+    Axis { axis: GamepadAxis, value: f32 },
+    /// A gamepad was unplugged; everything it held is released
+    Disconnected,
+}
+
+/// Button for a gilrs button (analog triggers and extra buttons are unmapped)
+fn map_button(button: Button) -> Option<GamepadButton> {
+    Some(match button {
+        Button::South => GamepadButton::South,
+        Button::East => GamepadButton::East,
+        Button::West => GamepadButton::West,
+        Button::North => GamepadButton::North,
+        Button::LeftTrigger => GamepadButton::LeftShoulder,
+        Button::RightTrigger => GamepadButton::RightShoulder,
+        Button::Select => GamepadButton::Select,
+        Button::Start => GamepadButton::Start,
+        Button::Mode => GamepadButton::Guide,
+        Button::LeftThumb => GamepadButton::LeftStick,
+        Button::RightThumb => GamepadButton::RightStick,
+        Button::DPadUp => GamepadButton::DPadUp,
+        Button::DPadDown => GamepadButton::DPadDown,
+        Button::DPadLeft => GamepadButton::DPadLeft,
+        Button::DPadRight => GamepadButton::DPadRight,
+        _ => return None,
+    })
+}
+
+/// Axis and value for a gilrs axis event
+///
+/// gilrs reports up as positive, so vertical axes are flipped.
+fn map_axis(axis: Axis, value: f32) -> Option<GamepadEvent> {
+    let (axis, value) = match axis {
+        Axis::LeftStickX => (GamepadAxis::LeftStickX, value),
+        Axis::LeftStickY => (GamepadAxis::LeftStickY, -value),
+        Axis::RightStickX => (GamepadAxis::RightStickX, value),
+        Axis::RightStickY => (GamepadAxis::RightStickY, -value),
+        Axis::DPadX => (GamepadAxis::DPadX, value),
+        Axis::DPadY => (GamepadAxis::DPadY, -value),
+        _ => return None,
+    };
+    Some(GamepadEvent::Axis {
+        axis,
+        value: value.clamp(-1.0, 1.0),
+    })
+}
+
+/// Event for a gilrs event, if it is one the game reacts to
+fn map_event(event: EventType) -> Option<GamepadEvent> {
+    match event {
+        EventType::ButtonPressed(button, _) => Some(GamepadEvent::Button {
+            button: map_button(button)?,
+            pressed: true,
+        }),
+        EventType::ButtonReleased(button, _) => Some(GamepadEvent::Button {
+            button: map_button(button)?,
+            pressed: false,
+        }),
+        EventType::AxisChanged(axis, value, _) => map_axis(axis, value),
+        EventType::Disconnected => Some(GamepadEvent::Disconnected),
+        _ => None,
+    }
+}
+
+/// Reads events from every gamepad, including ones plugged in later
+pub(crate) struct GamepadReader {
+    /// Gilrs is not `Sync`; the mutex lets the reader live in `Sync` UI elements
+    gilrs: Mutex<Gilrs>,
+}
+
+impl GamepadReader {
+    /// Start reading gamepads
+    ///
+    /// Returns None, with a warning, if gamepads are not supported on this
+    /// platform or the backend fails to start.
+    pub(crate) fn open() -> Option<Self> {
+        match Gilrs::new() {
+            Ok(gilrs) => {
+                for (_id, gamepad) in gilrs.gamepads() {
+                    tracing::info!("Gamepad connected: {}", gamepad.name());
+                }
+                Some(Self {
+                    gilrs: Mutex::new(gilrs),
+                })
+            }
+            Err(gilrs::Error::NotImplemented(_)) => {
+                tracing::warn!("Gamepads are not supported on this platform");
+                None
+            }
+            Err(e) => {
+                tracing::warn!("Failed to start gamepad input: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Next pending event, if any
+    pub(crate) fn next_event(&mut self) -> Option<GamepadEvent> {
+        let gilrs = self.gilrs.get_mut().unwrap_or_else(|e| e.into_inner());
+        while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+            match event {
+                EventType::Connected => {
+                    tracing::info!("Gamepad connected: {}", gilrs.gamepad(id).name());
+                }
+                EventType::Disconnected => {
+                    tracing::info!("Gamepad disconnected: {}", gilrs.gamepad(id).name());
+                }
+                _ => {}
+            }
+            if let Some(event) = map_event(event) {
+                return Some(event);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_button() {
+        assert_eq!(map_button(Button::South), Some(GamepadButton::South));
+        assert_eq!(
+            map_button(Button::LeftTrigger),
+            Some(GamepadButton::LeftShoulder)
+        );
+        assert_eq!(map_button(Button::Mode), Some(GamepadButton::Guide));
+        // Analog triggers are not mapped
+        assert_eq!(map_button(Button::LeftTrigger2), None);
+        assert_eq!(map_button(Button::Unknown), None);
+    }
+
+    #[test]
+    fn test_map_axis() {
+        // Up is negative
+        assert_eq!(
+            map_axis(Axis::LeftStickY, 1.0),
+            Some(GamepadEvent::Axis {
+                axis: GamepadAxis::LeftStickY,
+                value: -1.0
+            })
+        );
+        assert_eq!(
+            map_axis(Axis::DPadX, 1.0),
+            Some(GamepadEvent::Axis {
+                axis: GamepadAxis::DPadX,
+                value: 1.0
+            })
+        );
+        assert_eq!(map_axis(Axis::LeftZ, 0.5), None);
+    }
+}
@@ -1,6 +1,10 @@
 //! Input handler and state management
 
+use super::gamepad::{GamepadAxis, GamepadEvent, GamepadReader};
 use super::{KeyCode, MouseButton};
+use narrative_core::config::{
+    Binding, Bindings, GamepadButton, GamepadSettings, InputAction, MouseBinding,
+};
 use std::collections::HashSet;
 
 /// Modifier key state
@@ -35,7 +39,7 @@ impl From<winit::keyboard::ModifiersState> for Modifiers {
 
 /// Input state
 ///
-/// Tracks the current state of keyboard and mouse input.
+/// Tracks the current state of keyboard, mouse and gamepad input.
 /// Provides both low-level input queries and high-level game actions.
 #[derive(Debug, Clone, Default)]
 pub struct InputState {
@@ -53,6 +57,19 @@ pub struct InputState {
     // Modifiers
     modifiers: Modifiers,
 
+    // Gamepad state
+    pressed_gamepad_buttons: HashSet<GamepadButton>,
+    just_pressed_gamepad_buttons: HashSet<GamepadButton>,
+    just_released_gamepad_buttons: HashSet<GamepadButton>,
+    left_stick: (f32, f32),
+    /// Direction the left stick is tilted past the deadzone, as a D-pad button
+    stick_direction: Option<GamepadButton>,
+    stick_just_tilted: bool,
+    gamepad: GamepadSettings,
+
+    // Inputs bound to each game action
+    bindings: Bindings,
+
     // High-level game state
     pub choice_hover_index: Option<usize>,
 }
@@ -106,55 +123,146 @@ impl InputState {
         self.just_released_mouse_buttons.contains(&button)
     }
 
+    // ========================================================================
+    // Low-level gamepad queries
+    // ========================================================================
+
+    /// Check if a gamepad button is pressed
+    pub fn is_gamepad_button_pressed(&self, button: GamepadButton) -> bool {
+        self.pressed_gamepad_buttons.contains(&button)
+    }
+
+    /// Check if a gamepad button was just pressed this frame
+    pub fn is_gamepad_button_just_pressed(&self, button: GamepadButton) -> bool {
+        self.just_pressed_gamepad_buttons.contains(&button)
+    }
+
+    /// Check if a gamepad button was just released this frame
+    pub fn is_gamepad_button_just_released(&self, button: GamepadButton) -> bool {
+        self.just_released_gamepad_buttons.contains(&button)
+    }
+
+    /// Gamepad buttons just pressed this frame
+    pub fn just_pressed_gamepad_buttons(&self) -> impl Iterator<Item = GamepadButton> + '_ {
+        self.just_pressed_gamepad_buttons.iter().copied()
+    }
+
+    /// Get the left stick position (-1.0..=1.0, negative y is up)
+    pub fn left_stick(&self) -> (f32, f32) {
+        self.left_stick
+    }
+
+    /// Get the gamepad button map
+    pub fn gamepad_settings(&self) -> &GamepadSettings {
+        &self.gamepad
+    }
+
     // ========================================================================
     // High-level game actions (as used in runtime design)
     // ========================================================================
 
-    /// Check if the confirm action was triggered (left click, space, or enter)
+    /// Check if the advance action was triggered (by default left click,
+    /// Space, Enter or the gamepad South button)
     pub fn clicked(&self) -> bool {
-        self.is_mouse_button_just_pressed(MouseButton::Left)
-            || self.is_key_just_pressed(KeyCode::Space)
-            || self.is_key_just_pressed(KeyCode::Enter)
+        self.action_pressed(InputAction::Advance)
     }
 
-    /// Check if the pause button was pressed (Escape)
+    /// Check if the pause button was pressed (Escape or the gamepad pause button)
     pub fn pause_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::Escape)
+        self.is_key_just_pressed(KeyCode::Escape) || self.gamepad_action(self.gamepad.pause)
     }
 
-    /// Check if the confirm button was pressed (Enter or Space)
+    /// Check if the confirm button was pressed (Enter, Space, or the gamepad
+    /// confirm button)
     pub fn confirm_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::Enter) || self.is_key_just_pressed(KeyCode::Space)
+        self.is_key_just_pressed(KeyCode::Enter)
+            || self.is_key_just_pressed(KeyCode::Space)
+            || self.gamepad_action(self.gamepad.confirm)
     }
 
-    /// Check if the up key was pressed (for navigation)
+    /// Check if up was pressed (for navigation): arrow key, D-pad or left stick
     pub fn up_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::Up)
+        self.is_key_just_pressed(KeyCode::Up) || self.gamepad_direction(GamepadButton::DPadUp)
     }
 
-    /// Check if the down key was pressed (for navigation)
+    /// Check if down was pressed (for navigation): arrow key, D-pad or left stick
     pub fn down_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::Down)
+        self.is_key_just_pressed(KeyCode::Down) || self.gamepad_direction(GamepadButton::DPadDown)
     }
 
-    /// Check if the left key was pressed (for navigation)
+    /// Check if left was pressed (for navigation): arrow key, D-pad or left stick
     pub fn left_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::Left)
+        self.is_key_just_pressed(KeyCode::Left) || self.gamepad_direction(GamepadButton::DPadLeft)
     }
 
-    /// Check if the right key was pressed (for navigation)
+    /// Check if right was pressed (for navigation): arrow key, D-pad or left stick
     pub fn right_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::Right)
+        self.is_key_just_pressed(KeyCode::Right) || self.gamepad_direction(GamepadButton::DPadRight)
     }
 
-    /// Check if auto mode toggle was pressed (A key)
+    /// Check if auto mode toggle was pressed (A key by default)
     pub fn auto_mode_toggle_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::A)
+        self.action_pressed(InputAction::Auto)
     }
 
-    /// Check if skip mode toggle was pressed (S key)
+    /// Check if skip mode toggle was pressed (S key by default)
     pub fn skip_mode_toggle_pressed(&self) -> bool {
-        self.is_key_just_pressed(KeyCode::S)
+        self.action_pressed(InputAction::Skip)
+    }
+
+    /// Check if the backlog was requested (B key by default)
+    pub fn backlog_pressed(&self) -> bool {
+        self.action_pressed(InputAction::Backlog)
+    }
+
+    /// Check if hiding the UI was requested (H key or right click by default)
+    pub fn hide_ui_pressed(&self) -> bool {
+        self.action_pressed(InputAction::HideUi)
+    }
+
+    /// Check if a quick save was requested (Q key by default)
+    pub fn quick_save_pressed(&self) -> bool {
+        self.action_pressed(InputAction::QuickSave)
+    }
+
+    /// Check if loading the quick save was requested (L key by default)
+    pub fn quick_load_pressed(&self) -> bool {
+        self.action_pressed(InputAction::QuickLoad)
+    }
+
+    /// Check if any input bound to an action was just pressed
+    pub fn action_pressed(&self, action: InputAction) -> bool {
+        self.bindings
+            .get(action)
+            .iter()
+            .any(|binding| match binding {
+                Binding::Key(name) => self.just_pressed_keys.iter().any(|key| key.name() == *name),
+                Binding::Mouse(button) => self.is_mouse_button_just_pressed(match button {
+                    MouseBinding::Left => MouseButton::Left,
+                    MouseBinding::Right => MouseButton::Right,
+                    MouseBinding::Middle => MouseButton::Middle,
+                }),
+                Binding::Gamepad(button) => self.gamepad_action(*button),
+            })
+    }
+
+    /// Get the action bindings
+    pub fn bindings(&self) -> &Bindings {
+        &self.bindings
+    }
+
+    /// Check if a mapped gamepad button was just pressed
+    fn gamepad_action(&self, button: GamepadButton) -> bool {
+        self.gamepad.enabled && self.is_gamepad_button_just_pressed(button)
+    }
+
+    /// Check if the D-pad was just pressed, or the left stick just tilted,
+    /// in a direction
+    fn gamepad_direction(&self, direction: GamepadButton) -> bool {
+        self.gamepad_action(direction)
+            || (self.gamepad.enabled
+                && self.stick_just_tilted
+                && self.stick_direction == Some(direction))
     }
 
     /// Get modifiers state
@@ -172,6 +280,9 @@ impl InputState {
         self.just_released_keys.clear();
         self.just_pressed_mouse_buttons.clear();
         self.just_released_mouse_buttons.clear();
+        self.just_pressed_gamepad_buttons.clear();
+        self.just_released_gamepad_buttons.clear();
+        self.stick_just_tilted = false;
     }
 
     /// Press a key
@@ -212,6 +323,100 @@ impl InputState {
         self.modifiers = modifiers;
     }
 
+    /// Press gamepad button
+    pub(super) fn press_gamepad_button(&mut self, button: GamepadButton) {
+        if self.pressed_gamepad_buttons.insert(button) {
+            self.just_pressed_gamepad_buttons.insert(button);
+        }
+    }
+
+    /// Release gamepad button
+    pub(super) fn release_gamepad_button(&mut self, button: GamepadButton) {
+        if self.pressed_gamepad_buttons.remove(&button) {
+            self.just_released_gamepad_buttons.insert(button);
+        }
+    }
+
+    /// Release every gamepad button and center the left stick
+    pub(super) fn release_gamepad(&mut self) {
+        let held: Vec<GamepadButton> = self.pressed_gamepad_buttons.iter().copied().collect();
+        for button in held {
+            self.release_gamepad_button(button);
+        }
+        self.left_stick = (0.0, 0.0);
+        self.stick_direction = None;
+    }
+
+    /// Move a gamepad axis
+    ///
+    /// D-pad axes press and release the D-pad buttons. The left stick
+    /// navigates like the D-pad: a direction fires once when the stick is
+    /// tilted past the deadzone, and again only after it returns or turns.
+    pub(super) fn set_gamepad_axis(&mut self, axis: GamepadAxis, value: f32) {
+        match axis {
+            GamepadAxis::DPadX => {
+                self.set_dpad_axis(value, GamepadButton::DPadLeft, GamepadButton::DPadRight)
+            }
+            GamepadAxis::DPadY => {
+                self.set_dpad_axis(value, GamepadButton::DPadUp, GamepadButton::DPadDown)
+            }
+            GamepadAxis::LeftStickX | GamepadAxis::LeftStickY => {
+                if axis == GamepadAxis::LeftStickX {
+                    self.left_stick.0 = value;
+                } else {
+                    self.left_stick.1 = value;
+                }
+                let direction = self.stick_direction_for(self.left_stick);
+                if direction != self.stick_direction {
+                    self.stick_direction = direction;
+                    self.stick_just_tilted |= direction.is_some();
+                }
+            }
+            GamepadAxis::RightStickX | GamepadAxis::RightStickY => {}
+        }
+    }
+
+    /// Press the D-pad button on the side an axis points to, releasing the other
+    fn set_dpad_axis(&mut self, value: f32, negative: GamepadButton, positive: GamepadButton) {
+        for (button, held) in [(negative, value < -0.5), (positive, value > 0.5)] {
+            if held {
+                self.press_gamepad_button(button);
+            } else {
+                self.release_gamepad_button(button);
+            }
+        }
+    }
+
+    /// Direction of the dominant stick axis, if past the deadzone
+    fn stick_direction_for(&self, (x, y): (f32, f32)) -> Option<GamepadButton> {
+        let deadzone = self.gamepad.stick_deadzone;
+        if x.abs().max(y.abs()) < deadzone {
+            None
+        } else if y.abs() >= x.abs() {
+            Some(if y < 0.0 {
+                GamepadButton::DPadUp
+            } else {
+                GamepadButton::DPadDown
+            })
+        } else {
+            Some(if x < 0.0 {
+                GamepadButton::DPadLeft
+            } else {
+                GamepadButton::DPadRight
+            })
+        }
+    }
+
+    /// Set the gamepad button map
+    pub fn set_gamepad_settings(&mut self, settings: GamepadSettings) {
+        self.gamepad = settings;
+    }
+
+    /// Set the action bindings
+    pub fn set_bindings(&mut self, bindings: Bindings) {
+        self.bindings = bindings;
+    }
+
     /// Set choice hover index
     pub fn set_choice_hover_index(&mut self, index: Option<usize>) {
         self.choice_hover_index = index;
@@ -220,19 +425,66 @@ impl InputState {
 
 /// Input handler
 ///
-/// Manages input state and provides winit event processing.
+/// Manages input state and provides winit and gamepad event processing.
 pub struct InputHandler {
     state: InputState,
+    gamepads: Option<GamepadReader>,
 }
 
 impl InputHandler {
     /// Create a new input handler
+    ///
+    /// Gamepads are not read until [`enable_gamepads`](Self::enable_gamepads)
+    /// is called.
     pub fn new() -> Self {
         Self {
             state: InputState::new(),
+            gamepads: None,
         }
     }
 
+    /// Start reading gamepads, including ones plugged in later
+    ///
+    /// Returns false, after logging a warning, if gamepads are not
+    /// supported on this platform.
+    pub fn enable_gamepads(&mut self) -> bool {
+        self.gamepads = GamepadReader::open();
+        self.gamepads.is_some()
+    }
+
+    /// Apply pending gamepad events (call once per frame, before game update)
+    pub fn poll_gamepads(&mut self) {
+        while let Some(event) = self.gamepads.as_mut().and_then(GamepadReader::next_event) {
+            self.process_gamepad_event(event);
+        }
+    }
+
+    /// Process a gamepad event
+    pub fn process_gamepad_event(&mut self, event: GamepadEvent) {
+        match event {
+            GamepadEvent::Button {
+                button,
+                pressed: true,
+            } => self.state.press_gamepad_button(button),
+            GamepadEvent::Button {
+                button,
+                pressed: false,
+            } => self.state.release_gamepad_button(button),
+            GamepadEvent::Axis { axis, value } => self.state.set_gamepad_axis(axis, value),
+            GamepadEvent::Disconnected => self.state.release_gamepad(),
+        }
+    }
+
+    /// Set the gamepad button map (e.g. from `UserSettings::gamepad`)
+    pub fn set_gamepad_settings(&mut self, settings: GamepadSettings) {
+        self.state.set_gamepad_settings(settings);
+    }
+
+    /// Set the action bindings (e.g. from `UserSettings::bindings`)
+    pub fn set_bindings(&mut self, bindings: Bindings) {
+        self.state.set_bindings(bindings);
+    }
+
     /// Get the current input state
     pub fn state(&self) -> &InputState {
         &self.state
@@ -479,6 +731,28 @@ mod tests {
         assert!(state.clicked());
     }
 
+    #[test]
+    fn test_rebound_actions() {
+        let mut state = InputState::new();
+        let mut bindings = Bindings::default();
+        bindings.rebind(InputAction::QuickSave, Binding::key("F5"));
+        bindings.rebind(InputAction::Backlog, Binding::Mouse(MouseBinding::Middle));
+        state.set_bindings(bindings);
+
+        state.press_key(KeyCode::F5);
+        assert!(state.quick_save_pressed());
+        state.press_mouse_button(MouseButton::Middle);
+        assert!(state.backlog_pressed());
+
+        // The replaced key no longer quick saves; binding the mouse kept B
+        state.clear_frame_state();
+        state.press_key(KeyCode::Q);
+        state.press_key(KeyCode::B);
+        assert!(!state.quick_save_pressed());
+        assert!(state.backlog_pressed());
+        assert!(!state.clicked());
+    }
+
     #[test]
     fn test_high_level_navigation() {
         let mut state = InputState::new();
@@ -563,6 +837,121 @@ mod tests {
         assert!(!state.is_key_just_pressed(KeyCode::Unknown));
     }
 
+    #[test]
+    fn test_gamepad_buttons_map_to_actions() {
+        let mut handler = InputHandler::new();
+        handler.process_gamepad_event(GamepadEvent::Button {
+            button: GamepadButton::South,
+            pressed: true,
+        });
+        assert!(handler.state().clicked());
+        assert!(handler.state().confirm_pressed());
+        assert!(!handler.state().pause_pressed());
+
+        handler.update();
+        assert!(!handler.state().confirm_pressed());
+        assert!(
+            handler
+                .state()
+                .is_gamepad_button_pressed(GamepadButton::South)
+        );
+
+        // Remapped and disabled buttons
+        let mut settings = GamepadSettings {
+            confirm: GamepadButton::East,
+            ..GamepadSettings::default()
+        };
+        handler.set_gamepad_settings(settings.clone());
+        handler.process_gamepad_event(GamepadEvent::Button {
+            button: GamepadButton::East,
+            pressed: true,
+        });
+        assert!(handler.state().confirm_pressed());
+        settings.enabled = false;
+        handler.set_gamepad_settings(settings);
+        assert!(!handler.state().confirm_pressed());
+    }
+
+    #[test]
+    fn test_dpad_axis_navigation() {
+        let mut handler = InputHandler::new();
+        handler.process_gamepad_event(GamepadEvent::Axis {
+            axis: GamepadAxis::DPadY,
+            value: 1.0,
+        });
+        assert!(handler.state().down_pressed());
+        assert!(!handler.state().up_pressed());
+
+        handler.update();
+        handler.process_gamepad_event(GamepadEvent::Axis {
+            axis: GamepadAxis::DPadY,
+            value: -1.0,
+        });
+        assert!(handler.state().up_pressed());
+        assert!(
+            handler
+                .state()
+                .is_gamepad_button_just_released(GamepadButton::DPadDown)
+        );
+    }
+
+    #[test]
+    fn test_stick_navigation_fires_once_per_tilt() {
+        let mut handler = InputHandler::new();
+        let stick_y = |value| GamepadEvent::Axis {
+            axis: GamepadAxis::LeftStickY,
+            value,
+        };
+
+        // Within the deadzone
+        handler.process_gamepad_event(stick_y(0.3));
+        assert!(!handler.state().down_pressed());
+
+        handler.process_gamepad_event(stick_y(0.9));
+        assert!(handler.state().down_pressed());
+
+        // Held: no repeat
+        handler.update();
+        handler.process_gamepad_event(stick_y(1.0));
+        assert!(!handler.state().down_pressed());
+
+        // Released and tilted again
+        handler.process_gamepad_event(stick_y(0.0));
+        handler.update();
+        handler.process_gamepad_event(stick_y(-0.8));
+        assert!(handler.state().up_pressed());
+        assert_eq!(handler.state().left_stick(), (0.0, -0.8));
+    }
+
+    #[test]
+    fn test_disconnect_releases_gamepad() {
+        let mut handler = InputHandler::new();
+        handler.process_gamepad_event(GamepadEvent::Button {
+            button: GamepadButton::South,
+            pressed: true,
+        });
+        handler.process_gamepad_event(GamepadEvent::Axis {
+            axis: GamepadAxis::LeftStickY,
+            value: 1.0,
+        });
+        handler.update();
+
+        handler.process_gamepad_event(GamepadEvent::Disconnected);
+        assert!(
+            handler
+                .state()
+                .is_gamepad_button_just_released(GamepadButton::South)
+        );
+        assert_eq!(handler.state().left_stick(), (0.0, 0.0));
+
+        // The stick fires again once a pad reports a tilt
+        handler.process_gamepad_event(GamepadEvent::Axis {
+            axis: GamepadAxis::LeftStickY,
+            value: 1.0,
+        });
+        assert!(handler.state().down_pressed());
+    }
+
     #[test]
     fn test_mouse_button_just_pressed_cleared() {
         let mut state = InputState::new();
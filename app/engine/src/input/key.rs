@@ -91,6 +91,13 @@ pub enum KeyCode {
     Unknown,
 }
 
+impl KeyCode {
+    /// Variant name (e.g. "Enter", "F5"), as stored in key bindings
+    pub fn name(self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 impl From<winit::keyboard::KeyCode> for KeyCode {
     fn from(key: winit::keyboard::KeyCode) -> Self {
         use winit::keyboard::KeyCode as WK;
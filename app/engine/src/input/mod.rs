@@ -6,19 +6,22 @@
 //!
 //! The input system consists of three main components:
 //!
-//! - `InputHandler`: Manages input state and processes winit events
-//! - `InputState`: Tracks current input state (keyboard, mouse, modifiers)
-//! - `KeyCode`/`MouseButton`: Type-safe key and button identifiers
+//! - `InputHandler`: Manages input state and processes winit and gamepad events
+//! - `InputState`: Tracks current input state (keyboard, mouse, gamepad, modifiers)
+//! - `KeyCode`/`MouseButton`/`GamepadButton`: Type-safe key and button identifiers
+//!
+//! Gamepads are read through gilrs, with hotplug (see `gamepad`);
+//! which button triggers each action is configured by `GamepadSettings`.
 //!
 //! # High-Level Game Actions
 //!
 //! `InputState` provides high-level game action queries that abstract
 //! over multiple input methods:
 //!
-//! - `clicked()`: Left mouse button, Space, or Enter
-//! - `pause_pressed()`: Escape key
-//! - `confirm_pressed()`: Enter or Space
-//! - `up_pressed()`, `down_pressed()`: Arrow key navigation
+//! - `clicked()`: Left mouse button, Space, Enter, or gamepad confirm (A)
+//! - `pause_pressed()`: Escape key or gamepad pause (Start)
+//! - `confirm_pressed()`: Enter, Space, or gamepad confirm (A)
+//! - `up_pressed()`, `down_pressed()`: Arrow keys, D-pad, or left stick
 //! - `choice_hover_index`: Mouse hover over choice options
 //!
 //! These are used by the runtime state machine (see `docs/design/engine/runtime.md`).
@@ -34,7 +37,10 @@
 //! // input.process_keyboard_event(key, state);
 //! // input.process_mouse_button_event(button, state);
 //!
-//! // At the start of each frame:
+//! // Before updating the game each frame:
+//! input.poll_gamepads();
+//!
+//! // After the frame's input has been handled:
 //! input.update();
 //!
 //! // Query input state:
@@ -47,10 +53,13 @@
 //! }
 //! ```
 
+mod gamepad;
 mod handler;
 mod key;
 mod mouse;
 
+pub use gamepad::{GamepadAxis, GamepadEvent};
 pub use handler::{InputHandler, InputState, Modifiers};
 pub use key::KeyCode;
 pub use mouse::MouseButton;
+pub use narrative_core::config::{GamepadButton, GamepadSettings};
@@ -9,6 +9,7 @@
 //! - **Rendering**: 2D GPU-accelerated rendering with wgpu
 //! - **Text**: Text layout and rendering with cosmic-text
 //! - **Audio**: Audio playback with kira (BGM, SE, voice)
+//! - **Video**: Video cutscene decoding
 //! - **Input**: Keyboard, mouse, and gamepad input handling
 //! - **Save/Load**: Game state persistence
 //! - **Assets**: Asset loading and caching
@@ -26,6 +27,7 @@
 //!   ├── render     - 2D rendering (wgpu)
 //!   ├── text       - Text rendering (cosmic-text)
 //!   ├── audio      - Audio (kira)
+//!   ├── video      - Video cutscenes
 //!   ├── input      - Input handling
 //!   ├── save       - Save/load
 //!   ├── asset      - Asset management
@@ -63,69 +65,46 @@ pub mod input;
 pub mod render;
 pub mod runtime;
 pub mod save;
+#[cfg(feature = "steam")]
+pub mod steam;
 pub mod text;
 pub mod ui;
+pub mod video;
 
 // Re-export commonly used types
-pub use app::{EngineConfig, GameLoop};
+pub use app::{
+    ConfigArgs, ConfigOverrides, Engine, EngineBuilder, EngineConfig, Game, GameContext, GameLoop,
+};
 pub use asset::{AssetLoader, TextureCache, TextureHandle};
-pub use audio::{AudioManager, BgmPlayer, SePlayer, VoicePlayer};
+pub use audio::{
+    AudioCommand, AudioManager, AudioQueue, BgmPlayer, SePlayer, TextToSpeech, VoicePlayer,
+};
 pub use error::{EngineError, EngineResult};
 pub use input::{InputHandler, InputState, KeyCode, Modifiers, MouseButton};
-pub use render::{RenderBatch, RenderCommand, Renderer, SpritePipeline, SpriteVertex};
+pub use render::{
+    RenderBatch, RenderCommand, RenderStats, Renderer, SpriteInstance, SpritePipeline,
+};
 pub use runtime::{
     AppState, ChoiceState, EffectKind, EffectState, FlagStore, InGameState, LoadingState,
     MainMenuState, PauseMenuState, ReadHistory, SaveLoadState, ScenarioRuntime, SettingsState,
-    TransitionKind, TransitionState, TypingState, VariableStore, WaitState, WaitingInputState,
+    TextLog, TextLogFormat, TextLogSource, TransitionKind, TransitionState, TypingState,
+    VariableStore, WaitState, WaitingInputState,
+};
+pub use save::{
+    DisplaySnapshot, SAVE_VERSION, SaveBackend, SaveData, SaveManager, SavedCharacterDisplay,
+    render_thumbnail,
 };
-pub use save::{SAVE_VERSION, SaveData, SaveManager, SavedCharacterDisplay, generate_thumbnail};
 pub use text::{GlyphCache, TextLayout, TextureAtlas, TypewriterEffect};
 pub use ui::UiComponent;
 
 // Re-export narrative-core for convenience
 pub use narrative_core;
 
-/// Engine initialization helper
-///
-/// This function will be implemented in Phase 0.5 to initialize all engine
-/// subsystems in the correct order.
-pub fn init(_config: EngineConfig) -> narrative_core::EngineResult<Engine> {
-    // TODO: Phase 0.5 - engine initialization
-    Ok(Engine {
-        audio: AudioManager::new()
-            .map_err(|e| narrative_core::EngineError::Other(e.to_string()))?,
-        input: InputHandler::default(),
-        assets: AssetLoader::default(),
-        save: SaveManager::default(),
-    })
-}
-
-/// Main engine struct combining all subsystems
+/// Initialize all engine subsystems without a window
 ///
-/// This will be properly initialized in Phase 0.5.
-/// Note: Renderer is not included here as it requires window context
-pub struct Engine {
-    /// Audio subsystem
-    pub audio: AudioManager,
-    /// Input subsystem
-    pub input: InputHandler,
-    /// Asset subsystem
-    pub assets: AssetLoader,
-    /// Save subsystem
-    pub save: SaveManager,
-}
-
-impl Engine {
-    /// Create a new engine instance (stub implementation)
-    pub fn new(_config: EngineConfig) -> narrative_core::EngineResult<Self> {
-        Ok(Self {
-            audio: AudioManager::new()
-                .map_err(|e| narrative_core::EngineError::Other(e.to_string()))?,
-            input: InputHandler::default(),
-            assets: AssetLoader::default(),
-            save: SaveManager::default(),
-        })
-    }
+/// Use [`EngineBuilder::with_window`] to also create the renderer.
+pub fn init(config: EngineConfig) -> EngineResult<Engine> {
+    EngineBuilder::new(config).build()
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 //! Render batching
 
-use super::{RenderLayer, SpriteVertex, TextureId};
+use super::{RenderLayer, SpriteInstance, TextureId};
 
 /// Maximum number of sprites per batch
 const MAX_BATCH_SPRITES: usize = 10000;
@@ -14,10 +14,8 @@ pub struct RenderBatch {
     layer: RenderLayer,
     /// Z-order within the layer
     z_order: i32,
-    /// Vertex data
-    vertices: Vec<SpriteVertex>,
-    /// Index data
-    indices: Vec<u16>,
+    /// Sprite instance data
+    instances: Vec<SpriteInstance>,
 }
 
 impl RenderBatch {
@@ -27,8 +25,7 @@ impl RenderBatch {
             texture_id: None,
             layer: RenderLayer::default(),
             z_order: 0,
-            vertices: Vec::new(),
-            indices: Vec::new(),
+            instances: Vec::new(),
         }
     }
 
@@ -38,32 +35,20 @@ impl RenderBatch {
             texture_id: Some(texture_id),
             layer,
             z_order,
-            vertices: Vec::new(),
-            indices: Vec::new(),
+            instances: Vec::new(),
         }
     }
 
     /// Check if a sprite with the given texture can be added to this batch
     pub fn can_add(&self, texture_id: TextureId) -> bool {
         // Check if batch is not full and texture matches
-        self.vertices.len() / 4 < MAX_BATCH_SPRITES
+        self.instances.len() < MAX_BATCH_SPRITES
             && self.texture_id.is_none_or(|id| id == texture_id)
     }
 
-    /// Add a sprite quad to the batch
-    pub fn add_quad(&mut self, vertices: [SpriteVertex; 4]) {
-        let base_index = self.vertices.len() as u16;
-        self.vertices.extend_from_slice(&vertices);
-
-        // Two triangles per quad
-        self.indices.extend_from_slice(&[
-            base_index,
-            base_index + 1,
-            base_index + 2,
-            base_index + 2,
-            base_index + 3,
-            base_index,
-        ]);
+    /// Add a sprite to the batch
+    pub fn add_sprite(&mut self, instance: SpriteInstance) {
+        self.instances.push(instance);
     }
 
     /// Clear the batch completely (resets all parameters and data)
@@ -71,16 +56,14 @@ impl RenderBatch {
         self.texture_id = None;
         self.layer = RenderLayer::default();
         self.z_order = 0;
-        self.vertices.clear();
-        self.indices.clear();
+        self.instances.clear();
     }
 
-    /// Clear only vertex and index data, preserving texture, layer, and z-order
+    /// Clear only instance data, preserving texture, layer, and z-order
     ///
     /// This is useful for reusing batches with the same parameters across frames
     pub fn clear_data(&mut self) {
-        self.vertices.clear();
-        self.indices.clear();
+        self.instances.clear();
     }
 
     /// Get the texture ID
@@ -113,24 +96,19 @@ impl RenderBatch {
         self.z_order = z_order;
     }
 
-    /// Get vertices
-    pub fn vertices(&self) -> &[SpriteVertex] {
-        &self.vertices
-    }
-
-    /// Get indices
-    pub fn indices(&self) -> &[u16] {
-        &self.indices
+    /// Get sprite instances
+    pub fn instances(&self) -> &[SpriteInstance] {
+        &self.instances
     }
 
     /// Get the number of quads in this batch
     pub fn quad_count(&self) -> usize {
-        self.indices.len() / 6
+        self.instances.len()
     }
 
     /// Check if the batch is empty
     pub fn is_empty(&self) -> bool {
-        self.vertices.is_empty()
+        self.instances.is_empty()
     }
 }
 
@@ -143,81 +121,51 @@ impl Default for RenderBatch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use narrative_core::Rect;
 
-    fn create_test_quad() -> [SpriteVertex; 4] {
-        [
-            SpriteVertex {
-                position: [0.0, 0.0],
-                tex_coords: [0.0, 0.0],
-                color: [1.0, 1.0, 1.0, 1.0],
-            },
-            SpriteVertex {
-                position: [1.0, 0.0],
-                tex_coords: [1.0, 0.0],
-                color: [1.0, 1.0, 1.0, 1.0],
-            },
-            SpriteVertex {
-                position: [1.0, 1.0],
-                tex_coords: [1.0, 1.0],
-                color: [1.0, 1.0, 1.0, 1.0],
-            },
-            SpriteVertex {
-                position: [0.0, 1.0],
-                tex_coords: [0.0, 1.0],
-                color: [1.0, 1.0, 1.0, 1.0],
-            },
-        ]
+    fn create_test_sprite() -> SpriteInstance {
+        SpriteInstance::new(Rect::new(0.0, 0.0, 1.0, 1.0), None, 1.0, None)
     }
 
     #[test]
     fn test_batch_new() {
         let batch = RenderBatch::new();
-        assert_eq!(batch.vertices().len(), 0);
-        assert_eq!(batch.indices().len(), 0);
+        assert_eq!(batch.instances().len(), 0);
         assert!(batch.is_empty());
     }
 
     #[test]
     fn test_batch_add_single_quad() {
         let mut batch = RenderBatch::new();
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
 
-        assert_eq!(batch.vertices().len(), 4);
-        assert_eq!(batch.indices().len(), 6);
+        assert_eq!(batch.instances().len(), 1);
         assert_eq!(batch.quad_count(), 1);
         assert!(!batch.is_empty());
-
-        // Verify indices form two triangles
-        assert_eq!(batch.indices(), &[0, 1, 2, 2, 3, 0]);
     }
 
     #[test]
     fn test_batch_add_multiple_quads() {
         let mut batch = RenderBatch::new();
-        batch.add_quad(create_test_quad());
-        batch.add_quad(create_test_quad());
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
+        batch.add_sprite(create_test_sprite());
+        batch.add_sprite(create_test_sprite());
 
-        assert_eq!(batch.vertices().len(), 12);
-        assert_eq!(batch.indices().len(), 18);
+        assert_eq!(batch.instances().len(), 3);
         assert_eq!(batch.quad_count(), 3);
-
-        // Verify second quad indices
-        assert_eq!(&batch.indices()[6..12], &[4, 5, 6, 6, 7, 4]);
     }
 
     #[test]
     fn test_batch_clear() {
         let mut batch = RenderBatch::new();
-        batch.add_quad(create_test_quad());
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
+        batch.add_sprite(create_test_sprite());
 
         assert!(!batch.is_empty());
 
         batch.clear();
 
-        assert_eq!(batch.vertices().len(), 0);
-        assert_eq!(batch.indices().len(), 0);
+        assert_eq!(batch.instances().len(), 0);
         assert!(batch.is_empty());
         assert_eq!(batch.quad_count(), 0);
     }
@@ -225,16 +173,12 @@ mod tests {
     #[test]
     fn test_batch_clear_and_reuse() {
         let mut batch = RenderBatch::new();
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
         batch.clear();
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
 
-        assert_eq!(batch.vertices().len(), 4);
-        assert_eq!(batch.indices().len(), 6);
+        assert_eq!(batch.instances().len(), 1);
         assert_eq!(batch.quad_count(), 1);
-
-        // Indices should restart from 0
-        assert_eq!(batch.indices(), &[0, 1, 2, 2, 3, 0]);
     }
 
     #[test]
@@ -242,11 +186,10 @@ mod tests {
         let mut batch = RenderBatch::new();
 
         for _ in 0..100 {
-            batch.add_quad(create_test_quad());
+            batch.add_sprite(create_test_sprite());
         }
 
-        assert_eq!(batch.vertices().len(), 400);
-        assert_eq!(batch.indices().len(), 600);
+        assert_eq!(batch.instances().len(), 100);
         assert_eq!(batch.quad_count(), 100);
     }
 
@@ -258,16 +201,20 @@ mod tests {
     }
 
     #[test]
-    fn test_vertex_data_preserved() {
+    fn test_instance_data_preserved() {
         let mut batch = RenderBatch::new();
-        let quad = create_test_quad();
-        batch.add_quad(quad);
+        batch.add_sprite(create_test_sprite());
+        batch.add_sprite(SpriteInstance::new(
+            Rect::new(10.0, 20.0, 30.0, 40.0),
+            None,
+            0.5,
+            None,
+        ));
 
-        let vertices = batch.vertices();
-        assert_eq!(vertices[0].position, [0.0, 0.0]);
-        assert_eq!(vertices[1].position, [1.0, 0.0]);
-        assert_eq!(vertices[2].position, [1.0, 1.0]);
-        assert_eq!(vertices[3].position, [0.0, 1.0]);
+        let instances = batch.instances();
+        assert_eq!(instances[0].rect, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(instances[1].rect, [10.0, 20.0, 30.0, 40.0]);
+        assert_eq!(instances[1].opacity, 0.5);
     }
 
     #[test]
@@ -316,7 +263,7 @@ mod tests {
 
         // Set texture and add a quad
         batch.set_texture_id(1);
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
 
         // Can add same texture
         assert!(batch.can_add(1));
@@ -331,7 +278,7 @@ mod tests {
         // Add MAX_BATCH_SPRITES quads
         for _ in 0..MAX_BATCH_SPRITES {
             assert!(batch.can_add(1));
-            batch.add_quad(create_test_quad());
+            batch.add_sprite(create_test_sprite());
         }
 
         // Should not be able to add more
@@ -341,7 +288,7 @@ mod tests {
     #[test]
     fn test_batch_clear_resets_params() {
         let mut batch = RenderBatch::with_params(42, RenderLayer::UI, 10);
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
 
         batch.clear();
 
@@ -354,8 +301,8 @@ mod tests {
     #[test]
     fn test_batch_clear_data_preserves_params() {
         let mut batch = RenderBatch::with_params(42, RenderLayer::UI, 10);
-        batch.add_quad(create_test_quad());
-        batch.add_quad(create_test_quad());
+        batch.add_sprite(create_test_sprite());
+        batch.add_sprite(create_test_sprite());
 
         assert!(!batch.is_empty());
         assert_eq!(batch.quad_count(), 2);
@@ -23,6 +23,19 @@ pub enum RenderLayer {
     Overlay = 4,
 }
 
+impl RenderLayer {
+    /// Name of the layer in GPU debug groups
+    pub fn debug_name(&self) -> &'static str {
+        match self {
+            Self::Background => "Background Layer",
+            Self::CG => "CG Layer",
+            Self::Characters => "Character Layer",
+            Self::UI => "UI Layer",
+            Self::Overlay => "Overlay Layer",
+        }
+    }
+}
+
 /// Rendering commands
 #[derive(Debug, Clone)]
 pub enum RenderCommand {
@@ -1,17 +1,26 @@
 //! 2D rendering module
 //!
 //! This module provides GPU-accelerated 2D rendering using wgpu,
-//! including sprite rendering, batching, and render commands.
+//! including sprite rendering, batching, render commands, render statistics,
+//! particle effects, and the logical coordinate space render commands are
+//! positioned in.
 
 mod batch;
 mod commands;
+mod debug;
+mod particles;
 mod pipeline;
 mod renderer;
 mod sprite;
+mod stats;
 mod transition;
+mod viewport;
 
 pub use batch::RenderBatch;
 pub use commands::{RenderCommand, RenderLayer, TransitionKind};
+pub use particles::{Particle, ParticleSystem};
 pub use renderer::{LoadedTexture, Renderer, TextureId};
-pub use sprite::{SpritePipeline, SpriteVertex};
+pub use sprite::{SpriteInstance, SpritePipeline};
+pub use stats::RenderStats;
 pub use transition::{TransitionPipeline, TransitionVertex};
+pub use viewport::Viewport;
@@ -0,0 +1,453 @@
+//! Particle effects for scene weather and ambience
+//!
+//! [`ParticleSystem`] runs one emitter per effect started by `ShowEffect`
+//! (snow, rain, sakura petals, sparks, or any effect from the effect
+//! manifest). Particles are simulated on the CPU and handed to the renderer
+//! as [`Particle`] quads, so an effect costs one instanced draw however many
+//! particles it has.
+//!
+//! When an effect is cleared its emitter stops spawning and the particles
+//! already on screen play out their lifetime, so effects trail off instead of
+//! vanishing.
+
+use crate::asset::ParticleEffectRegistry;
+use crate::runtime::ActiveEffect;
+use narrative_core::asset::{EmitArea, ParticleEffectDef, ParticleShape};
+use std::collections::HashSet;
+use std::f32::consts::TAU;
+
+/// Most particles one emitter spawns in a single update, so a long hitch
+/// doesn't produce a burst
+const MAX_SPAWN_PER_UPDATE: usize = 64;
+
+/// Distance outside the screen edge new particles start at
+const SPAWN_MARGIN: f32 = 16.0;
+
+/// Distance outside the screen past which particles are dropped
+const CULL_MARGIN: f32 = 128.0;
+
+/// Extra width, as a fraction of the screen width, on each side of the screen
+/// that edge-emitted particles start in, so slanted rain covers the corners
+const OVERSCAN: f32 = 0.2;
+
+/// Fraction of its lifetime a particle spends fading in, and fading out
+const FADE_FRACTION: f32 = 0.15;
+
+/// Side-to-side drift cycles per second
+const SWAY_FREQUENCY: f32 = 0.5;
+
+/// Petal flutter (apparent rotation) cycles per second
+const FLUTTER_FREQUENCY: f32 = 0.8;
+
+/// Length of a rain streak relative to its width
+const RAIN_STREAK_LENGTH: f32 = 12.0;
+
+/// Particle ready to draw
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    /// How the particle is drawn
+    pub shape: ParticleShape,
+    /// Center position in logical pixels
+    pub position: [f32; 2],
+    /// Drawn width and height in logical pixels
+    pub size: [f32; 2],
+    /// RGBA color with the fade applied to alpha
+    pub color: [f32; 4],
+}
+
+/// Simulated particle
+#[derive(Debug, Clone)]
+struct LiveParticle {
+    position: [f32; 2],
+    velocity: [f32; 2],
+    size: f32,
+    color: [f32; 4],
+    age: f32,
+    lifetime: f32,
+    /// Phase offset for sway and flutter, so particles don't move in lockstep
+    phase: f32,
+}
+
+impl LiveParticle {
+    fn is_alive(&self, screen_size: (f32, f32)) -> bool {
+        let [x, y] = self.position;
+        let side_margin = screen_size.0 * OVERSCAN + CULL_MARGIN;
+        self.age < self.lifetime
+            && x > -side_margin
+            && x < screen_size.0 + side_margin
+            && y > -CULL_MARGIN
+            && y < screen_size.1 + CULL_MARGIN
+    }
+
+    /// Opacity from fading in at birth and out before death
+    fn fade(&self) -> f32 {
+        let fade_time = self.lifetime * FADE_FRACTION;
+        if fade_time <= 0.0 {
+            return 1.0;
+        }
+        let fade_in = self.age / fade_time;
+        let fade_out = (self.lifetime - self.age) / fade_time;
+        fade_in.min(fade_out).clamp(0.0, 1.0)
+    }
+}
+
+/// Particles of one effect
+#[derive(Debug)]
+struct Emitter {
+    def: ParticleEffectDef,
+    intensity: f32,
+    /// False once the effect is cleared; live particles still play out
+    spawning: bool,
+    particles: Vec<LiveParticle>,
+    /// Fractional particles carried over between updates
+    spawn_accumulator: f32,
+}
+
+impl Emitter {
+    fn new(def: ParticleEffectDef, intensity: f32) -> Self {
+        Self {
+            def,
+            intensity,
+            spawning: true,
+            particles: Vec::new(),
+            spawn_accumulator: 0.0,
+        }
+    }
+
+    /// Most live particles at the current intensity
+    fn particle_cap(&self) -> usize {
+        (self.def.max_particles as f32 * self.intensity.max(0.0)).ceil() as usize
+    }
+
+    fn update(&mut self, delta: f32, screen_size: (f32, f32), rng: &mut Rng) {
+        let gravity = self.def.gravity;
+        for particle in &mut self.particles {
+            particle.velocity[1] += gravity * delta;
+            particle.position[0] += particle.velocity[0] * delta;
+            particle.position[1] += particle.velocity[1] * delta;
+            particle.age += delta;
+        }
+        self.particles
+            .retain(|particle| particle.is_alive(screen_size));
+
+        if !self.spawning {
+            return;
+        }
+        self.spawn_accumulator += self.def.spawn_rate * self.intensity.max(0.0) * delta;
+        let due = self.spawn_accumulator.floor() as usize;
+        let room = self.particle_cap().saturating_sub(self.particles.len());
+        let count = due.min(room).min(MAX_SPAWN_PER_UPDATE);
+        self.spawn_accumulator = if due > MAX_SPAWN_PER_UPDATE {
+            0.0
+        } else {
+            self.spawn_accumulator.fract()
+        };
+        for _ in 0..count {
+            let particle = self.spawn(screen_size, rng);
+            self.particles.push(particle);
+        }
+    }
+
+    fn spawn(&self, (width, height): (f32, f32), rng: &mut Rng) -> LiveParticle {
+        let def = &self.def;
+        let overscan = width * OVERSCAN;
+        let position = match def.emit_from {
+            EmitArea::Top => [rng.range(-overscan, width + overscan), -SPAWN_MARGIN],
+            EmitArea::Bottom => [
+                rng.range(-overscan, width + overscan),
+                height + SPAWN_MARGIN,
+            ],
+            EmitArea::Screen => [rng.range(0.0, width), rng.range(0.0, height)],
+        };
+
+        let angle = (def.direction + rng.range(-def.spread, def.spread)).to_radians();
+        let speed = rng.range(def.speed.0, def.speed.1);
+        let color_index = (rng.next_f32() * def.colors.len() as f32) as usize;
+
+        LiveParticle {
+            position,
+            velocity: [angle.cos() * speed, angle.sin() * speed],
+            size: rng.range(def.size.0, def.size.1),
+            color: def
+                .colors
+                .get(color_index)
+                .or(def.colors.first())
+                .copied()
+                .unwrap_or([1.0; 4]),
+            age: 0.0,
+            lifetime: def.lifetime,
+            phase: rng.range(0.0, TAU),
+        }
+    }
+
+    fn particles(&self) -> impl Iterator<Item = Particle> + '_ {
+        let shape = self.def.shape;
+        let sway = self.def.sway;
+        self.particles.iter().map(move |particle| {
+            let drift = sway * (particle.age * SWAY_FREQUENCY * TAU + particle.phase).sin();
+            let size = match shape {
+                ParticleShape::Rain => [particle.size, particle.size * RAIN_STREAK_LENGTH],
+                ParticleShape::Petal => {
+                    // Narrowing and widening reads as the petal turning over
+                    let turn = (particle.age * FLUTTER_FREQUENCY * TAU + particle.phase).cos();
+                    [particle.size * turn.abs().max(0.3), particle.size * 0.6]
+                }
+                ParticleShape::Snow | ParticleShape::Spark => [particle.size, particle.size],
+            };
+            let [r, g, b, a] = particle.color;
+            Particle {
+                shape,
+                position: [particle.position[0] + drift, particle.position[1]],
+                size,
+                color: [r, g, b, a * particle.fade()],
+            }
+        })
+    }
+}
+
+/// Runs the particle emitters of the running effects
+#[derive(Debug)]
+pub struct ParticleSystem {
+    emitters: Vec<Emitter>,
+    rng: Rng,
+    /// Effects already reported as missing from the registry
+    unknown_effects: HashSet<String>,
+}
+
+impl ParticleSystem {
+    /// Create an empty particle system
+    pub fn new() -> Self {
+        Self::with_seed(0x9E37_79B9)
+    }
+
+    /// Create an empty particle system with a fixed random seed
+    pub fn with_seed(seed: u32) -> Self {
+        Self {
+            emitters: Vec::new(),
+            rng: Rng::new(seed),
+            unknown_effects: HashSet::new(),
+        }
+    }
+
+    /// Match the emitters to the effects the scenario has running
+    ///
+    /// Starts emitters for new effects, updates intensities, and stops
+    /// spawning for effects that are no longer running. Effects missing from
+    /// the registry are logged once and ignored.
+    pub fn sync(&mut self, effects: &[ActiveEffect], registry: &ParticleEffectRegistry) {
+        for emitter in &mut self.emitters {
+            emitter.spawning = false;
+        }
+
+        for effect in effects {
+            if let Some(emitter) = self
+                .emitters
+                .iter_mut()
+                .find(|emitter| emitter.def.id == effect.effect)
+            {
+                emitter.intensity = effect.intensity;
+                emitter.spawning = true;
+                continue;
+            }
+
+            match registry.get(&effect.effect) {
+                Some(def) => self
+                    .emitters
+                    .push(Emitter::new(def.clone(), effect.intensity)),
+                None => {
+                    if self.unknown_effects.insert(effect.effect.clone()) {
+                        tracing::warn!("Unknown particle effect '{}'", effect.effect);
+                    }
+                }
+            }
+        }
+
+        self.emitters
+            .retain(|emitter| emitter.spawning || !emitter.particles.is_empty());
+    }
+
+    /// Advance the simulation by `delta` seconds on a screen of `screen_size`
+    ///
+    /// Returns: true if there are particles or emitters to draw
+    pub fn update(&mut self, delta: f32, screen_size: (f32, f32)) -> bool {
+        for emitter in &mut self.emitters {
+            emitter.update(delta, screen_size, &mut self.rng);
+        }
+        self.emitters
+            .retain(|emitter| emitter.spawning || !emitter.particles.is_empty());
+        !self.is_idle()
+    }
+
+    /// Remove every emitter and particle at once
+    pub fn clear(&mut self) {
+        self.emitters.clear();
+    }
+
+    /// Check if nothing is running or left on screen
+    pub fn is_idle(&self) -> bool {
+        self.emitters.is_empty()
+    }
+
+    /// Number of live particles
+    pub fn particle_count(&self) -> usize {
+        self.emitters
+            .iter()
+            .map(|emitter| emitter.particles.len())
+            .sum()
+    }
+
+    /// Particles to draw, in the order their effects were started
+    pub fn particles(&self) -> impl Iterator<Item = Particle> + '_ {
+        self.emitters.iter().flat_map(Emitter::particles)
+    }
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Small xorshift generator for particle variation
+#[derive(Debug, Clone)]
+struct Rng(u32);
+
+impl Rng {
+    fn new(seed: u32) -> Self {
+        // Xorshift gets stuck at zero
+        Self(seed.max(1))
+    }
+
+    /// Uniform value in [0, 1)
+    fn next_f32(&mut self) -> f32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Uniform value in [min, max)
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::EffectLifetime;
+
+    const SCREEN: (f32, f32) = (1280.0, 720.0);
+
+    fn effect(name: &str, intensity: f32) -> ActiveEffect {
+        ActiveEffect {
+            effect: name.to_string(),
+            intensity,
+            duration: None,
+            elapsed: 0.0,
+            lifetime: EffectLifetime::Scene,
+        }
+    }
+
+    #[test]
+    fn test_spawns_at_rate() {
+        let registry = ParticleEffectRegistry::default();
+        let mut system = ParticleSystem::new();
+        system.sync(&[effect("snow", 1.0)], &registry);
+
+        // Snow spawns 40 per second at intensity 1.0
+        for _ in 0..10 {
+            system.update(0.1, SCREEN);
+        }
+        assert_eq!(system.particle_count(), 40);
+
+        let particle = system.particles().next().unwrap();
+        assert_eq!(particle.shape, ParticleShape::Snow);
+        assert!(particle.color[3] > 0.0);
+    }
+
+    #[test]
+    fn test_intensity_scales_spawning() {
+        let registry = ParticleEffectRegistry::default();
+        let mut system = ParticleSystem::new();
+        system.sync(&[effect("snow", 0.5)], &registry);
+        for _ in 0..10 {
+            system.update(0.1, SCREEN);
+        }
+        assert_eq!(system.particle_count(), 20);
+
+        system.sync(&[effect("snow", 0.0)], &registry);
+        let count = system.particle_count();
+        system.update(0.1, SCREEN);
+        assert_eq!(system.particle_count(), count);
+    }
+
+    #[test]
+    fn test_cleared_effect_plays_out() {
+        let registry = ParticleEffectRegistry::default();
+        let mut system = ParticleSystem::new();
+        system.sync(&[effect("sparks", 1.0)], &registry);
+        system.update(0.5, SCREEN);
+        assert!(system.particle_count() > 0);
+
+        // Particles stay after the effect is cleared, then expire
+        system.sync(&[], &registry);
+        assert!(!system.is_idle());
+        let count = system.particle_count();
+        system.update(0.1, SCREEN);
+        assert!(system.particle_count() <= count);
+
+        for _ in 0..40 {
+            system.update(0.1, SCREEN);
+        }
+        assert!(system.is_idle());
+        assert!(!system.update(0.1, SCREEN));
+    }
+
+    #[test]
+    fn test_unknown_effect_is_ignored() {
+        let registry = ParticleEffectRegistry::default();
+        let mut system = ParticleSystem::new();
+        system.sync(&[effect("lightning", 1.0)], &registry);
+        assert!(system.is_idle());
+        assert!(!system.update(0.1, SCREEN));
+    }
+
+    #[test]
+    fn test_particle_cap() {
+        let registry = ParticleEffectRegistry::default();
+        let mut system = ParticleSystem::new();
+        system.sync(&[effect("sakura", 1.0)], &registry);
+        // A huge hitch spawns at most one batch
+        system.update(100.0, SCREEN);
+        assert!(system.particle_count() <= MAX_SPAWN_PER_UPDATE);
+
+        // Sakura allows 150 petals at intensity 1.0
+        for _ in 0..200 {
+            system.update(1.0, SCREEN);
+        }
+        assert!(system.particle_count() <= 150);
+    }
+
+    #[test]
+    fn test_rain_draws_streaks() {
+        let registry = ParticleEffectRegistry::default();
+        let mut system = ParticleSystem::new();
+        system.sync(&[effect("rain", 1.0)], &registry);
+        system.update(0.1, SCREEN);
+        let drop = system.particles().next().unwrap();
+        assert_eq!(drop.shape, ParticleShape::Rain);
+        assert!(drop.size[1] > drop.size[0] * 10.0);
+    }
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            let value = rng.range(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+    }
+}
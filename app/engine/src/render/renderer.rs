@@ -1,14 +1,15 @@
 //! Core renderer
 
+use super::debug::{DebugMarkers, texture_label};
 use crate::error::EngineResult;
 use crate::render::{
-    RenderBatch, RenderCommand, RenderLayer, SpritePipeline, SpriteVertex, TransitionKind,
-    TransitionPipeline,
+    RenderBatch, RenderCommand, RenderLayer, RenderStats, SpriteInstance, SpritePipeline,
+    TransitionKind, TransitionPipeline, Viewport,
 };
 use crate::text::{FontManager, GlyphCache, TextLayout, TextStyle, TextureAtlas};
 use narrative_core::{AssetRef, Rect};
+use std::borrow::Cow;
 use std::collections::HashMap;
-use wgpu::util::DeviceExt;
 use winit::window::Window;
 
 /// Texture handle for referencing loaded textures
@@ -21,6 +22,8 @@ pub struct LoadedTexture {
     pub sampler: wgpu::Sampler,
     pub bind_group: wgpu::BindGroup,
     pub size: (u32, u32),
+    /// GPU debug label (the asset path with the `gpu-debug` feature)
+    pub label: Cow<'static, str>,
 }
 
 /// Main renderer struct
@@ -43,6 +46,12 @@ pub struct Renderer {
     surface_config: wgpu::SurfaceConfiguration,
     /// Window size
     size: winit::dpi::PhysicalSize<u32>,
+    /// DPI scale factor of the window
+    scale_factor: f64,
+    /// Optional fixed logical resolution
+    virtual_resolution: Option<(u32, u32)>,
+    /// Logical coordinate space of render commands
+    viewport: Viewport,
     /// Sprite rendering pipeline
     sprite_pipeline: SpritePipeline,
     /// Transition rendering pipeline
@@ -69,12 +78,15 @@ pub struct Renderer {
     text_atlas: TextureAtlas,
     /// Texture ID for the text atlas
     text_atlas_id: Option<TextureId>,
+    /// Statistics of the last `render_commands()` call
+    last_stats: RenderStats,
 }
 
 impl Renderer {
     /// Create a new renderer with wgpu initialization
     pub async fn new(window: std::sync::Arc<Window>) -> EngineResult<Self> {
         let size = window.inner_size();
+        let scale_factor = window.scale_factor();
 
         // 1. Create wgpu instance
         let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
@@ -147,8 +159,9 @@ impl Renderer {
         let transition_pipeline = TransitionPipeline::new(&device, surface_format)?;
 
         // Create orthographic projection matrix for 2D rendering
-        // Using screen coordinates: (0, 0) at top-left, (width, height) at bottom-right
-        let projection = create_orthographic_projection(size.width, size.height);
+        // Using logical coordinates: (0, 0) at top-left, (width, height) at bottom-right
+        let viewport = Viewport::new((size.width, size.height), scale_factor, None);
+        let projection = create_orthographic_projection(&viewport);
         let projection_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Projection Buffer"),
             size: std::mem::size_of::<[[f32; 4]; 4]>() as u64,
@@ -189,6 +202,9 @@ impl Renderer {
             queue,
             surface_config,
             size,
+            scale_factor,
+            virtual_resolution: None,
+            viewport,
             sprite_pipeline,
             transition_pipeline,
             projection_buffer,
@@ -202,6 +218,7 @@ impl Renderer {
             glyph_cache,
             text_atlas,
             text_atlas_id: None,
+            last_stats: RenderStats::default(),
         };
 
         // Create 1x1 white texture for solid color rendering
@@ -223,15 +240,72 @@ impl Renderer {
             self.surface.configure(&self.device, &self.surface_config);
 
             // Update projection matrix for new size
-            let projection = create_orthographic_projection(new_size.width, new_size.height);
-            self.queue.write_buffer(
-                &self.projection_buffer,
-                0,
-                bytemuck::cast_slice(&projection),
-            );
+            self.update_viewport();
         }
     }
 
+    /// Enable or disable VSync
+    ///
+    /// Disabling uses `AutoNoVsync`, which falls back to `Fifo` where
+    /// unsynchronized presentation isn't supported.
+    pub fn set_vsync(&mut self, enabled: bool) {
+        let present_mode = if enabled {
+            wgpu::PresentMode::Fifo
+        } else {
+            wgpu::PresentMode::AutoNoVsync
+        };
+        if self.surface_config.present_mode != present_mode {
+            self.surface_config.present_mode = present_mode;
+            self.surface.configure(&self.device, &self.surface_config);
+        }
+    }
+
+    /// Set the DPI scale factor (e.g. on `WindowEvent::ScaleFactorChanged`)
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.update_viewport();
+    }
+
+    /// Set a fixed logical resolution for render commands
+    ///
+    /// The logical space is scaled uniformly to fit the surface. `None` uses the
+    /// surface size divided by the DPI scale factor.
+    pub fn set_virtual_resolution(&mut self, virtual_resolution: Option<(u32, u32)>) {
+        self.virtual_resolution = virtual_resolution;
+        self.update_viewport();
+    }
+
+    /// Get the logical coordinate space of render commands
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Get the logical size render commands are positioned in
+    pub fn logical_size(&self) -> (f32, f32) {
+        self.viewport.logical_size
+    }
+
+    /// Recompute the viewport and projection matrix
+    fn update_viewport(&mut self) {
+        self.viewport = Viewport::new(
+            (self.size.width, self.size.height),
+            self.scale_factor,
+            self.virtual_resolution,
+        );
+        let projection = create_orthographic_projection(&self.viewport);
+        self.queue.write_buffer(
+            &self.projection_buffer,
+            0,
+            bytemuck::cast_slice(&projection),
+        );
+    }
+
+    /// Restrict a render pass to the viewport's physical rect
+    fn apply_viewport(&self, render_pass: &mut wgpu::RenderPass<'_>) {
+        let rect = self.viewport.physical_rect;
+        render_pass.set_viewport(rect.x, rect.y, rect.width, rect.height, 0.0, 1.0);
+    }
+
     /// Render a frame with clear color
     pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         // Get current surface texture
@@ -281,11 +355,10 @@ impl Renderer {
 
     /// Render sprites using the sprite pipeline
     ///
-    /// This method renders a batch of sprites to the screen
+    /// This method renders a batch of sprite instances to the screen
     pub fn render_sprites(
         &mut self,
-        vertices: &[super::SpriteVertex],
-        indices: &[u16],
+        instances: &[SpriteInstance],
         texture_id: TextureId,
     ) -> EngineResult<()> {
         // Get texture
@@ -301,23 +374,9 @@ impl Renderer {
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Create vertex buffer
-        let vertex_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Sprite Vertex Buffer"),
-                contents: bytemuck::cast_slice(vertices),
-                usage: wgpu::BufferUsages::VERTEX,
-            });
-
-        // Create index buffer
-        let index_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Sprite Index Buffer"),
-                contents: bytemuck::cast_slice(indices),
-                usage: wgpu::BufferUsages::INDEX,
-            });
+        // Upload instances
+        self.sprite_pipeline
+            .prepare(&self.device, &self.queue, instances);
 
         // Create command encoder
         let mut encoder = self
@@ -350,12 +409,13 @@ impl Renderer {
                 multiview_mask: None,
             });
 
+            self.apply_viewport(&mut render_pass);
             render_pass.set_pipeline(self.sprite_pipeline.pipeline());
             render_pass.set_bind_group(0, &self.projection_bind_group, &[]);
             render_pass.set_bind_group(1, &texture.bind_group, &[]);
-            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-            render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+            render_pass.debug_marker(&texture.label);
+            self.sprite_pipeline
+                .draw(&mut render_pass, 0..instances.len() as u32);
         }
 
         // Submit commands
@@ -403,6 +463,17 @@ impl Renderer {
         width: u32,
         height: u32,
         rgba_data: &[u8],
+    ) -> EngineResult<TextureId> {
+        self.upload_texture(width, height, rgba_data, texture_label("raw RGBA"))
+    }
+
+    /// Create a texture from RGBA bytes and add it to the cache
+    fn upload_texture(
+        &mut self,
+        width: u32,
+        height: u32,
+        rgba_data: &[u8],
+        label: Cow<'static, str>,
     ) -> EngineResult<TextureId> {
         // Validate data size
         let expected_size = (width * height * 4) as usize;
@@ -416,7 +487,7 @@ impl Renderer {
 
         // Create texture
         let texture = self.device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("Loaded Texture"),
+            label: Some(&label),
             size: wgpu::Extent3d {
                 width,
                 height,
@@ -463,7 +534,7 @@ impl Renderer {
 
         // Create bind group
         let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Texture Bind Group"),
+            label: Some(&label),
             layout: self.sprite_pipeline.texture_bind_group_layout(),
             entries: &[
                 wgpu::BindGroupEntry {
@@ -496,6 +567,7 @@ impl Renderer {
                 sampler,
                 bind_group,
                 size: (width, height),
+                label,
             },
         );
 
@@ -514,7 +586,7 @@ impl Renderer {
         let rgba = img.to_rgba8();
         let (width, height) = img.dimensions();
 
-        self.load_texture_from_bytes(width, height, &rgba)
+        self.upload_texture(width, height, &rgba, texture_label(path))
     }
 
     /// Get a loaded texture by ID
@@ -584,8 +656,8 @@ impl Renderer {
                     // Load texture if needed
                     let texture_id = self.get_or_load_texture(texture)?;
 
-                    // Create sprite vertices
-                    let vertices = self.create_sprite_vertices(*dest, *source, *opacity, *tint);
+                    // Create sprite instance
+                    let sprite = SpriteInstance::new(*dest, *source, *opacity, *tint);
 
                     // Check if we can add to current batch
                     let can_batch = current_batch.as_ref().is_some_and(|b| {
@@ -612,7 +684,7 @@ impl Renderer {
 
                     // Add to batch
                     if let Some(ref mut batch) = current_batch {
-                        batch.add_quad(vertices);
+                        batch.add_sprite(sprite);
                     }
                 }
 
@@ -632,13 +704,14 @@ impl Renderer {
                     let texture_id = self.get_or_load_texture(texture)?;
 
                     // Full screen quad
-                    let dest = Rect::new(0.0, 0.0, self.size.width as f32, self.size.height as f32);
-                    let vertices = self.create_sprite_vertices(dest, None, *opacity, *tint);
+                    let (screen_w, screen_h) = self.viewport.logical_size;
+                    let dest = Rect::new(0.0, 0.0, screen_w, screen_h);
+                    let sprite = SpriteInstance::new(dest, None, *opacity, *tint);
 
                     // Create batch for background
                     let mut batch =
                         RenderBatch::with_params(texture_id, RenderLayer::Background, 0);
-                    batch.add_quad(vertices);
+                    batch.add_sprite(sprite);
                     self.batches.push(batch);
                 }
 
@@ -658,12 +731,13 @@ impl Renderer {
                     let texture_id = self.get_or_load_texture(texture)?;
 
                     // Full screen quad (same as background)
-                    let dest = Rect::new(0.0, 0.0, self.size.width as f32, self.size.height as f32);
-                    let vertices = self.create_sprite_vertices(dest, None, *opacity, *tint);
+                    let (screen_w, screen_h) = self.viewport.logical_size;
+                    let dest = Rect::new(0.0, 0.0, screen_w, screen_h);
+                    let sprite = SpriteInstance::new(dest, None, *opacity, *tint);
 
                     // Create batch for CG (layer CG, z_order 0)
                     let mut batch = RenderBatch::with_params(texture_id, RenderLayer::CG, 0);
-                    batch.add_quad(vertices);
+                    batch.add_sprite(sprite);
                     self.batches.push(batch);
                 }
 
@@ -697,9 +771,10 @@ impl Renderer {
                     let char_height = tex_size.1 as f32 * scale;
 
                     // Center character horizontally at the position
-                    let x = (self.size.width as f32 * x_percent) - (char_width / 2.0);
+                    let (screen_w, screen_h) = self.viewport.logical_size;
+                    let x = (screen_w * x_percent) - (char_width / 2.0);
                     // Position at bottom of screen
-                    let y = self.size.height as f32 - char_height;
+                    let y = screen_h - char_height;
 
                     let dest = Rect::new(x, y, char_width, char_height);
 
@@ -710,8 +785,8 @@ impl Renderer {
                         None // Normal UVs
                     };
 
-                    // Create sprite vertices
-                    let vertices = self.create_sprite_vertices(dest, source, *opacity, None);
+                    // Create sprite instance
+                    let sprite = SpriteInstance::new(dest, source, *opacity, None);
 
                     // Check if we can add to current batch
                     let can_batch = current_batch.as_ref().is_some_and(|b| {
@@ -738,7 +813,7 @@ impl Renderer {
 
                     // Add to batch
                     if let Some(ref mut batch) = current_batch {
-                        batch.add_quad(vertices);
+                        batch.add_sprite(sprite);
                     }
                 }
 
@@ -763,6 +838,7 @@ impl Renderer {
                         line_height: *line_height,
                         color: *color,
                         family: cosmic_text::Family::SansSerif,
+                        ..TextStyle::default()
                     };
 
                     // Create text layout
@@ -810,6 +886,7 @@ impl Renderer {
                                 sampler: self.text_atlas.sampler().clone(),
                                 bind_group,
                                 size: (width, height),
+                                label: Cow::Borrowed("Glyph Atlas Texture"),
                             },
                         );
 
@@ -874,9 +951,8 @@ impl Renderer {
 
                                 let source = Some(Rect::new(u, v, u_width, v_height));
 
-                                // Create sprite vertices for this glyph
-                                let vertices =
-                                    self.create_sprite_vertices(dest, source, 1.0, Some(*color));
+                                // Create sprite instance for this glyph
+                                let sprite = SpriteInstance::new(dest, source, 1.0, Some(*color));
 
                                 // Check if we can add to current batch
                                 let can_batch = current_batch.as_ref().is_some_and(|b| {
@@ -898,7 +974,7 @@ impl Renderer {
 
                                 // Add glyph to batch
                                 if let Some(ref mut batch) = current_batch {
-                                    batch.add_quad(vertices);
+                                    batch.add_sprite(sprite);
                                 }
                             }
 
@@ -922,11 +998,10 @@ impl Renderer {
                     }
 
                     // Draw background rectangle
-                    let bg_vertices =
-                        self.create_sprite_vertices(*rect, None, 1.0, Some(*background));
+                    let bg_sprite = SpriteInstance::new(*rect, None, 1.0, Some(*background));
                     let mut bg_batch =
                         RenderBatch::with_params(self.white_texture_id, current_layer, 0);
-                    bg_batch.add_quad(bg_vertices);
+                    bg_batch.add_sprite(bg_sprite);
                     self.batches.push(bg_batch);
 
                     // Draw border if specified
@@ -942,15 +1017,11 @@ impl Renderer {
                         let right = Rect::new(rect.x + rect.width - w, rect.y, w, rect.height);
 
                         for border_rect in [top, bottom, left, right] {
-                            let border_vertices = self.create_sprite_vertices(
-                                border_rect,
-                                None,
-                                1.0,
-                                Some(*border_color),
-                            );
+                            let border_sprite =
+                                SpriteInstance::new(border_rect, None, 1.0, Some(*border_color));
                             let mut border_batch =
                                 RenderBatch::with_params(self.white_texture_id, current_layer, 1);
-                            border_batch.add_quad(border_vertices);
+                            border_batch.add_sprite(border_sprite);
                             self.batches.push(border_batch);
                         }
                     }
@@ -969,10 +1040,10 @@ impl Renderer {
                     }
 
                     // Draw solid color rectangle using white texture with color tint
-                    let vertices = self.create_sprite_vertices(*rect, None, 1.0, Some(*color));
+                    let sprite = SpriteInstance::new(*rect, None, 1.0, Some(*color));
                     let mut rect_batch =
                         RenderBatch::with_params(self.white_texture_id, current_layer, 0);
-                    rect_batch.add_quad(vertices);
+                    rect_batch.add_sprite(sprite);
                     self.batches.push(rect_batch);
                 }
 
@@ -1001,11 +1072,10 @@ impl Renderer {
                     let right = Rect::new(rect.x + rect.width - w, rect.y, w, rect.height);
 
                     for border_rect in [top, bottom, left, right] {
-                        let vertices =
-                            self.create_sprite_vertices(border_rect, None, 1.0, Some(*color));
+                        let sprite = SpriteInstance::new(border_rect, None, 1.0, Some(*color));
                         let mut border_batch =
                             RenderBatch::with_params(self.white_texture_id, current_layer, 0);
-                        border_batch.add_quad(vertices);
+                        border_batch.add_sprite(sprite);
                         self.batches.push(border_batch);
                     }
                 }
@@ -1046,75 +1116,30 @@ impl Renderer {
         Ok(())
     }
 
-    /// Create sprite vertices from rectangle and parameters
-    ///
-    /// Creates a quad with CCW (Counter-Clockwise) winding order:
-    /// ```text
-    /// 0-------1
-    /// |     / |
-    /// |   /   |
-    /// | /     |
-    /// 3-------2
-    /// ```
-    /// Triangle 1: 0→1→2 (top-left to top-right to bottom-right)
-    /// Triangle 2: 2→3→0 (bottom-right to bottom-left to top-left)
-    ///
-    /// # Arguments
-    /// * `dest` - Destination rectangle in screen coordinates
-    /// * `source` - Optional source UV coordinates (None = full texture 0.0-1.0)
-    /// * `opacity` - Alpha multiplier (0.0 = transparent, 1.0 = opaque)
-    /// * `tint` - Optional color tint (None = white, no tinting)
-    fn create_sprite_vertices(
-        &self,
-        dest: Rect,
-        source: Option<Rect>,
-        opacity: f32,
-        tint: Option<narrative_core::Color>,
-    ) -> [SpriteVertex; 4] {
-        // UV coordinates (default to full texture)
-        let uv = source.unwrap_or_else(|| Rect::new(0.0, 0.0, 1.0, 1.0));
-
-        // Calculate color with opacity and tint
-        let base_color = tint.unwrap_or_else(|| narrative_core::Color::new(1.0, 1.0, 1.0, 1.0));
-        let color = [
-            base_color.r,
-            base_color.g,
-            base_color.b,
-            base_color.a * opacity,
-        ];
-
-        // Create quad vertices (CCW winding)
-        [
-            SpriteVertex {
-                position: [dest.x, dest.y],
-                tex_coords: [uv.x, uv.y],
-                color,
-            },
-            SpriteVertex {
-                position: [dest.x + dest.width, dest.y],
-                tex_coords: [uv.x + uv.width, uv.y],
-                color,
-            },
-            SpriteVertex {
-                position: [dest.x + dest.width, dest.y + dest.height],
-                tex_coords: [uv.x + uv.width, uv.y + uv.height],
-                color,
-            },
-            SpriteVertex {
-                position: [dest.x, dest.y + dest.height],
-                tex_coords: [uv.x, uv.y + uv.height],
-                color,
-            },
-        ]
+    /// Get the statistics of the last `render_commands()` call
+    pub fn last_stats(&self) -> RenderStats {
+        self.last_stats
     }
 
     /// Render using render commands
     ///
-    /// This is the main rendering method that processes commands and renders batches
-    pub fn render_commands(&mut self, commands: &[RenderCommand]) -> EngineResult<()> {
+    /// This is the main rendering method that processes commands and renders batches.
+    /// Returns the frame's render statistics (also kept in [`last_stats`](Self::last_stats)).
+    pub fn render_commands(&mut self, commands: &[RenderCommand]) -> EngineResult<RenderStats> {
+        let mut stats = RenderStats::default();
+
         // Build batches from commands
         self.build_batches(commands)?;
 
+        // Upload all batches' sprites into one instance buffer
+        let instances: Vec<SpriteInstance> = self
+            .batches
+            .iter()
+            .flat_map(|batch| batch.instances().iter().copied())
+            .collect();
+        self.sprite_pipeline
+            .prepare(&self.device, &self.queue, &instances);
+
         // Extract transition commands (overlay layer)
         let transition_commands: Vec<_> = commands
             .iter()
@@ -1169,16 +1194,34 @@ impl Renderer {
                 multiview_mask: None,
             });
 
+            // Draw into the logical viewport (letterboxed for virtual resolutions)
+            self.apply_viewport(&mut render_pass);
+
             // Set pipeline
             render_pass.set_pipeline(self.sprite_pipeline.pipeline());
             render_pass.set_bind_group(0, &self.projection_bind_group, &[]);
 
-            // Render each batch
+            // Render each batch as a range of the uploaded instances,
+            // grouped by layer in GPU captures
+            let mut first_instance = 0;
+            let mut bound_texture: Option<TextureId> = None;
+            let mut debug_layer: Option<RenderLayer> = None;
             for batch in &self.batches {
+                let instances = first_instance..first_instance + batch.quad_count() as u32;
+                first_instance = instances.end;
+
                 if batch.is_empty() {
                     continue;
                 }
 
+                if debug_layer != Some(batch.layer()) {
+                    if debug_layer.is_some() {
+                        render_pass.end_debug_group();
+                    }
+                    render_pass.begin_debug_group(batch.layer().debug_name());
+                    debug_layer = Some(batch.layer());
+                }
+
                 // Get texture for this batch
                 let texture_id = match batch.texture_id() {
                     Some(id) => id,
@@ -1190,36 +1233,28 @@ impl Renderer {
                     None => continue,
                 };
 
-                // Create vertex buffer for this batch
-                let vertex_buffer =
-                    self.device
-                        .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                            label: Some("Batch Vertex Buffer"),
-                            contents: bytemuck::cast_slice(batch.vertices()),
-                            usage: wgpu::BufferUsages::VERTEX,
-                        });
-
-                // Create index buffer for this batch
-                let index_buffer =
-                    self.device
-                        .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                            label: Some("Batch Index Buffer"),
-                            contents: bytemuck::cast_slice(batch.indices()),
-                            usage: wgpu::BufferUsages::INDEX,
-                        });
-
-                // Draw this batch
-                render_pass.set_bind_group(1, &texture.bind_group, &[]);
-                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-                render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-                render_pass.draw_indexed(0..batch.indices().len() as u32, 0, 0..1);
+                // Draw this batch (consecutive batches may share a texture)
+                if bound_texture != Some(texture_id) {
+                    render_pass.set_bind_group(1, &texture.bind_group, &[]);
+                    bound_texture = Some(texture_id);
+                    stats.textures_bound += 1;
+                }
+                stats.batches += 1;
+                stats.draw_calls += 1;
+                stats.vertices += batch.quad_count() as u32 * 4;
+                render_pass.debug_marker(&texture.label);
+                self.sprite_pipeline.draw(&mut render_pass, instances);
+            }
+            if debug_layer.is_some() {
+                render_pass.end_debug_group();
             }
 
             // Render transitions (overlay layer)
+            render_pass.begin_debug_group("Transitions");
             for (kind, progress, fade_color_opt, from_texture_opt, to_texture_opt) in
                 transition_commands
             {
-                self.render_transition(
+                let rendered = self.render_transition(
                     &mut render_pass,
                     kind,
                     progress,
@@ -1227,17 +1262,26 @@ impl Renderer {
                     from_texture_opt,
                     to_texture_opt,
                 )?;
+                if rendered {
+                    stats.transitions += 1;
+                    stats.draw_calls += 1;
+                    stats.vertices += 4;
+                }
             }
+            render_pass.end_debug_group();
         }
 
         // Submit commands and present
         self.queue.submit(std::iter::once(encoder.finish()));
         output.present();
 
-        Ok(())
+        self.last_stats = stats;
+        Ok(stats)
     }
 
     /// Render a transition effect
+    ///
+    /// Returns whether the effect was drawn (cross-dissolve is not implemented yet).
     fn render_transition(
         &self,
         render_pass: &mut wgpu::RenderPass,
@@ -1246,7 +1290,7 @@ impl Renderer {
         fade_color_opt: Option<narrative_core::Color>,
         _from_texture_opt: &Option<AssetRef>,
         _to_texture_opt: &Option<AssetRef>,
-    ) -> EngineResult<()> {
+    ) -> EngineResult<bool> {
         match kind {
             TransitionKind::FadeBlack => {
                 let fade_color = narrative_core::Color::BLACK;
@@ -1309,19 +1353,22 @@ impl Renderer {
                 // TODO: Implement cross-dissolve with texture blending
                 // This requires loading both from_texture and to_texture
                 // For now, this is a placeholder
+                return Ok(false);
             }
         }
 
-        Ok(())
+        Ok(true)
     }
 }
 
 /// Create an orthographic projection matrix for 2D rendering
-/// Maps screen coordinates (0, 0) at top-left to (-1, 1) in clip space
+/// Maps logical coordinates (0, 0) at top-left to (-1, 1) in clip space
 /// and (width, height) at bottom-right to (1, -1) in clip space
-fn create_orthographic_projection(width: u32, height: u32) -> [[f32; 4]; 4] {
-    let w = width as f32;
-    let h = height as f32;
+///
+/// Clip space covers the viewport's physical rect (see `Renderer::apply_viewport`),
+/// so one logical pixel spans `viewport.scale()` physical pixels.
+fn create_orthographic_projection(viewport: &Viewport) -> [[f32; 4]; 4] {
+    let (w, h) = viewport.logical_size;
 
     // Orthographic projection matrix for 2D screen-space coordinates
     // Maps (0, 0) to (-1, 1) and (width, height) to (1, -1)
@@ -1337,3 +1384,28 @@ fn create_orthographic_projection(width: u32, height: u32) -> [[f32; 4]; 4] {
         [-1.0, 1.0, 0.0, 1.0],
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::Point;
+
+    /// Apply the column-major projection to a 2D point
+    fn project(projection: &[[f32; 4]; 4], point: Point) -> (f32, f32) {
+        (
+            projection[0][0] * point.x + projection[1][0] * point.y + projection[3][0],
+            projection[0][1] * point.x + projection[1][1] * point.y + projection[3][1],
+        )
+    }
+
+    #[test]
+    fn test_projection_maps_logical_space_to_clip_space() {
+        // 2x DPI: logical 1280x720 on a 2560x1440 surface
+        let viewport = Viewport::new((2560, 1440), 2.0, None);
+        let projection = create_orthographic_projection(&viewport);
+
+        assert_eq!(project(&projection, Point::new(0.0, 0.0)), (-1.0, 1.0));
+        assert_eq!(project(&projection, Point::new(1280.0, 720.0)), (1.0, -1.0));
+        assert_eq!(project(&projection, Point::new(640.0, 360.0)), (0.0, 0.0));
+    }
+}
@@ -1,9 +1,17 @@
-// Sprite rendering shader
+// Sprite rendering shader (instanced unit quads)
 
 struct VertexInput {
-    @location(0) position: vec2<f32>,
-    @location(1) tex_coords: vec2<f32>,
-    @location(2) color: vec4<f32>,
+    // Corner of the unit quad (0..1)
+    @location(0) corner: vec2<f32>,
+}
+
+struct InstanceInput {
+    // Destination rect (x, y, width, height) in screen coordinates
+    @location(1) rect: vec4<f32>,
+    // Source UV rect (u, v, width, height)
+    @location(2) uv_rect: vec4<f32>,
+    @location(3) color: vec4<f32>,
+    @location(4) opacity: f32,
 }
 
 struct VertexOutput {
@@ -21,11 +29,13 @@ struct Uniforms {
 @group(1) @binding(1) var sprite_sampler: sampler;
 
 @vertex
-fn vs_main(in: VertexInput) -> VertexOutput {
+fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let position = instance.rect.xy + vertex.corner * instance.rect.zw;
+
     var out: VertexOutput;
-    out.clip_position = uniforms.projection * vec4<f32>(in.position, 0.0, 1.0);
-    out.tex_coords = in.tex_coords;
-    out.color = in.color;
+    out.clip_position = uniforms.projection * vec4<f32>(position, 0.0, 1.0);
+    out.tex_coords = instance.uv_rect.xy + vertex.corner * instance.uv_rect.zw;
+    out.color = vec4<f32>(instance.color.rgb, instance.color.a * instance.opacity);
     return out;
 }
 
@@ -1,62 +1,144 @@
 //! Sprite rendering pipeline
 
 use bytemuck::{Pod, Zeroable};
+use narrative_core::{Color, Rect};
+use std::ops::Range;
+use wgpu::util::DeviceExt;
 
-/// Sprite vertex for GPU
+/// Unit quad corners shared by every sprite instance
+///
+/// Creates a quad with CCW (Counter-Clockwise) winding order:
+/// ```text
+/// 0-------1
+/// |     / |
+/// |   /   |
+/// | /     |
+/// 3-------2
+/// ```
+const QUAD_CORNERS: &[[f32; 2]] = &[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+/// Two triangles per quad: 0→1→2 and 2→3→0
+const QUAD_INDICES: &[u16] = &[0, 1, 2, 2, 3, 0];
+
+/// Initial instance buffer capacity in sprites
+const INITIAL_INSTANCE_CAPACITY: usize = 256;
+
+/// Per-sprite instance data for GPU
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
-pub struct SpriteVertex {
-    /// Position [x, y]
-    pub position: [f32; 2],
-    /// Texture coordinates [u, v]
-    pub tex_coords: [f32; 2],
+pub struct SpriteInstance {
+    /// Destination rectangle [x, y, width, height] in screen coordinates
+    pub rect: [f32; 4],
+    /// Source UV rectangle [u, v, width, height] (negative width flips)
+    pub uv_rect: [f32; 4],
     /// Color [r, g, b, a]
     pub color: [f32; 4],
+    /// Alpha multiplier (0.0 = transparent, 1.0 = opaque)
+    pub opacity: f32,
+    /// Padding to keep the stride a multiple of 16 bytes
+    pub _padding: [f32; 3],
 }
 
-impl SpriteVertex {
-    /// Get the vertex buffer layout descriptor
+impl SpriteInstance {
+    /// Create an instance from a rectangle and parameters
+    ///
+    /// # Arguments
+    /// * `dest` - Destination rectangle in screen coordinates
+    /// * `source` - Optional source UV coordinates (None = full texture 0.0-1.0)
+    /// * `opacity` - Alpha multiplier (0.0 = transparent, 1.0 = opaque)
+    /// * `tint` - Optional color tint (None = white, no tinting)
+    pub fn new(dest: Rect, source: Option<Rect>, opacity: f32, tint: Option<Color>) -> Self {
+        let uv = source.unwrap_or_else(|| Rect::new(0.0, 0.0, 1.0, 1.0));
+        let color = tint.unwrap_or(Color::WHITE);
+
+        Self {
+            rect: [dest.x, dest.y, dest.width, dest.height],
+            uv_rect: [uv.x, uv.y, uv.width, uv.height],
+            color: [color.r, color.g, color.b, color.a],
+            opacity,
+            _padding: [0.0; 3],
+        }
+    }
+
+    /// Get the instance buffer layout descriptor
     pub fn layout() -> wgpu::VertexBufferLayout<'static> {
-        // Calculate offsets for vertex attributes
-        // position: [f32; 2] at offset 0
-        // tex_coords: [f32; 2] at offset 8 (after position)
-        // color: [f32; 4] at offset 16 (after position + tex_coords)
-        const POSITION_OFFSET: u64 = 0;
-        const TEX_COORDS_OFFSET: u64 = POSITION_OFFSET + std::mem::size_of::<[f32; 2]>() as u64;
-        const COLOR_OFFSET: u64 = TEX_COORDS_OFFSET + std::mem::size_of::<[f32; 2]>() as u64;
+        // rect: [f32; 4] at offset 0
+        // uv_rect: [f32; 4] at offset 16 (after rect)
+        // color: [f32; 4] at offset 32 (after rect + uv_rect)
+        // opacity: f32 at offset 48 (after rect + uv_rect + color)
+        const RECT_OFFSET: u64 = 0;
+        const UV_RECT_OFFSET: u64 = RECT_OFFSET + std::mem::size_of::<[f32; 4]>() as u64;
+        const COLOR_OFFSET: u64 = UV_RECT_OFFSET + std::mem::size_of::<[f32; 4]>() as u64;
+        const OPACITY_OFFSET: u64 = COLOR_OFFSET + std::mem::size_of::<[f32; 4]>() as u64;
 
         wgpu::VertexBufferLayout {
-            array_stride: std::mem::size_of::<SpriteVertex>() as wgpu::BufferAddress,
-            step_mode: wgpu::VertexStepMode::Vertex,
+            array_stride: std::mem::size_of::<SpriteInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
             attributes: &[
-                // Position
+                // Destination rectangle
                 wgpu::VertexAttribute {
-                    offset: POSITION_OFFSET,
-                    shader_location: 0,
-                    format: wgpu::VertexFormat::Float32x2,
+                    offset: RECT_OFFSET,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float32x4,
                 },
-                // Texture coordinates
+                // UV rectangle
                 wgpu::VertexAttribute {
-                    offset: TEX_COORDS_OFFSET,
-                    shader_location: 1,
-                    format: wgpu::VertexFormat::Float32x2,
+                    offset: UV_RECT_OFFSET,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x4,
                 },
                 // Color
                 wgpu::VertexAttribute {
                     offset: COLOR_OFFSET,
-                    shader_location: 2,
+                    shader_location: 3,
                     format: wgpu::VertexFormat::Float32x4,
                 },
+                // Opacity
+                wgpu::VertexAttribute {
+                    offset: OPACITY_OFFSET,
+                    shader_location: 4,
+                    format: wgpu::VertexFormat::Float32,
+                },
             ],
         }
     }
 }
 
+/// Get the unit quad vertex buffer layout descriptor
+fn quad_layout() -> wgpu::VertexBufferLayout<'static> {
+    wgpu::VertexBufferLayout {
+        array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+        step_mode: wgpu::VertexStepMode::Vertex,
+        attributes: &[
+            // Corner in unit quad space
+            wgpu::VertexAttribute {
+                offset: 0,
+                shader_location: 0,
+                format: wgpu::VertexFormat::Float32x2,
+            },
+        ],
+    }
+}
+
 /// Sprite rendering pipeline
+///
+/// Sprites are drawn as instances of a shared unit quad. All sprites of a
+/// frame are uploaded to one instance buffer with [`prepare`](Self::prepare),
+/// then each batch draws its range of instances with [`draw`](Self::draw).
 pub struct SpritePipeline {
     pipeline: wgpu::RenderPipeline,
     uniform_bind_group_layout: wgpu::BindGroupLayout,
     texture_bind_group_layout: wgpu::BindGroupLayout,
+    /// Unit quad corners
+    quad_vertex_buffer: wgpu::Buffer,
+    /// Unit quad indices
+    quad_index_buffer: wgpu::Buffer,
+    /// Instance buffer (reused across frames, grows as needed)
+    instance_buffer: wgpu::Buffer,
+    /// Instance buffer capacity in sprites
+    instance_capacity: usize,
+    /// Number of instances uploaded by the last `prepare()`
+    instance_count: u32,
 }
 
 impl SpritePipeline {
@@ -122,7 +204,7 @@ impl SpritePipeline {
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: Some("vs_main"),
-                buffers: &[SpriteVertex::layout()],
+                buffers: &[quad_layout(), SpriteInstance::layout()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
@@ -154,11 +236,75 @@ impl SpritePipeline {
             cache: None,
         });
 
+        let quad_vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sprite Quad Vertex Buffer"),
+            contents: bytemuck::cast_slice(QUAD_CORNERS),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let quad_index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sprite Quad Index Buffer"),
+            contents: bytemuck::cast_slice(QUAD_INDICES),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let instance_capacity = INITIAL_INSTANCE_CAPACITY;
+        let instance_buffer = Self::create_instance_buffer(device, instance_capacity);
+
         Self {
             pipeline,
             uniform_bind_group_layout,
             texture_bind_group_layout,
+            quad_vertex_buffer,
+            quad_index_buffer,
+            instance_buffer,
+            instance_capacity,
+            instance_count: 0,
+        }
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sprite Instance Buffer"),
+            size: (capacity * std::mem::size_of::<SpriteInstance>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Upload this frame's sprite instances
+    ///
+    /// Must be called before the render pass; the buffer grows to the next
+    /// power of two when it is too small.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        instances: &[SpriteInstance],
+    ) {
+        if instances.len() > self.instance_capacity {
+            self.instance_capacity = instances.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.instance_capacity);
         }
+
+        if !instances.is_empty() {
+            queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(instances));
+        }
+        self.instance_count = instances.len() as u32;
+    }
+
+    /// Draw a range of the prepared instances
+    ///
+    /// The pipeline and bind groups must already be set on the render pass.
+    pub fn draw(&self, render_pass: &mut wgpu::RenderPass<'_>, instances: Range<u32>) {
+        if instances.is_empty() || instances.end > self.instance_count {
+            return;
+        }
+
+        render_pass.set_vertex_buffer(0, self.quad_vertex_buffer.slice(..));
+        render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+        render_pass.set_index_buffer(self.quad_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+        render_pass.draw_indexed(0..QUAD_INDICES.len() as u32, 0, instances);
     }
 
     /// Get the render pipeline
@@ -182,85 +328,82 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_sprite_vertex_creation() {
-        let vertex = SpriteVertex {
-            position: [1.0, 2.0],
-            tex_coords: [0.5, 0.5],
-            color: [1.0, 0.0, 0.0, 1.0],
-        };
-
-        assert_eq!(vertex.position, [1.0, 2.0]);
-        assert_eq!(vertex.tex_coords, [0.5, 0.5]);
-        assert_eq!(vertex.color, [1.0, 0.0, 0.0, 1.0]);
+    fn test_sprite_instance_creation() {
+        let instance = SpriteInstance::new(
+            Rect::new(1.0, 2.0, 3.0, 4.0),
+            Some(Rect::new(0.5, 0.5, 0.25, 0.25)),
+            0.5,
+            Some(Color::new(1.0, 0.0, 0.0, 1.0)),
+        );
+
+        assert_eq!(instance.rect, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(instance.uv_rect, [0.5, 0.5, 0.25, 0.25]);
+        assert_eq!(instance.color, [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(instance.opacity, 0.5);
     }
 
     #[test]
-    fn test_sprite_vertex_copy() {
-        let v1 = SpriteVertex {
-            position: [1.0, 2.0],
-            tex_coords: [0.5, 0.5],
-            color: [1.0, 0.0, 0.0, 1.0],
-        };
-
-        let v2 = v1;
-
-        assert_eq!(v1.position, v2.position);
-        assert_eq!(v1.tex_coords, v2.tex_coords);
-        assert_eq!(v1.color, v2.color);
+    fn test_sprite_instance_defaults() {
+        let instance = SpriteInstance::new(Rect::new(0.0, 0.0, 10.0, 10.0), None, 1.0, None);
+
+        // Full texture, white, opaque
+        assert_eq!(instance.uv_rect, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(instance.color, [1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(instance.opacity, 1.0);
     }
 
     #[test]
-    fn test_sprite_vertex_debug() {
-        let vertex = SpriteVertex {
-            position: [1.0, 2.0],
-            tex_coords: [0.5, 0.5],
-            color: [1.0, 0.0, 0.0, 1.0],
-        };
-
-        let debug_str = format!("{:?}", vertex);
-        assert!(debug_str.contains("SpriteVertex"));
+    fn test_sprite_instance_copy() {
+        let i1 = SpriteInstance::new(Rect::new(1.0, 2.0, 3.0, 4.0), None, 1.0, None);
+        let i2 = i1;
+
+        assert_eq!(i1.rect, i2.rect);
+        assert_eq!(i1.uv_rect, i2.uv_rect);
+        assert_eq!(i1.color, i2.color);
     }
 
     #[test]
-    fn test_sprite_vertex_zero() {
-        let vertex: SpriteVertex = bytemuck::Zeroable::zeroed();
+    fn test_sprite_instance_debug() {
+        let instance = SpriteInstance::new(Rect::new(1.0, 2.0, 3.0, 4.0), None, 1.0, None);
 
-        assert_eq!(vertex.position, [0.0, 0.0]);
-        assert_eq!(vertex.tex_coords, [0.0, 0.0]);
-        assert_eq!(vertex.color, [0.0, 0.0, 0.0, 0.0]);
+        let debug_str = format!("{:?}", instance);
+        assert!(debug_str.contains("SpriteInstance"));
     }
 
     #[test]
-    fn test_sprite_vertex_bytemuck_cast() {
-        let vertex = SpriteVertex {
-            position: [1.0, 2.0],
-            tex_coords: [0.5, 0.5],
-            color: [1.0, 0.0, 0.0, 1.0],
-        };
+    fn test_sprite_instance_zero() {
+        let instance: SpriteInstance = bytemuck::Zeroable::zeroed();
+
+        assert_eq!(instance.rect, [0.0; 4]);
+        assert_eq!(instance.uv_rect, [0.0; 4]);
+        assert_eq!(instance.color, [0.0; 4]);
+        assert_eq!(instance.opacity, 0.0);
+    }
+
+    #[test]
+    fn test_sprite_instance_bytemuck_cast() {
+        let instance = SpriteInstance::new(Rect::new(1.0, 2.0, 3.0, 4.0), None, 1.0, None);
 
         // Test Pod/Zeroable traits work for bytemuck
-        let bytes: &[u8] = bytemuck::bytes_of(&vertex);
-        assert_eq!(bytes.len(), std::mem::size_of::<SpriteVertex>());
+        let bytes: &[u8] = bytemuck::bytes_of(&instance);
+        assert_eq!(bytes.len(), std::mem::size_of::<SpriteInstance>());
     }
 
     #[test]
-    fn test_sprite_vertex_array() {
-        let vertices = [
-            SpriteVertex {
-                position: [0.0, 0.0],
-                tex_coords: [0.0, 0.0],
-                color: [1.0, 1.0, 1.0, 1.0],
-            },
-            SpriteVertex {
-                position: [1.0, 1.0],
-                tex_coords: [1.0, 1.0],
-                color: [1.0, 1.0, 1.0, 1.0],
-            },
-        ];
+    fn test_sprite_instance_layout() {
+        // 16-byte aligned stride, one attribute per shader input
+        assert_eq!(std::mem::size_of::<SpriteInstance>(), 64);
+        let layout = SpriteInstance::layout();
+        assert_eq!(layout.array_stride, 64);
+        assert_eq!(layout.step_mode, wgpu::VertexStepMode::Instance);
+        assert_eq!(layout.attributes.len(), 4);
+        assert_eq!(layout.attributes[3].offset, 48);
+    }
 
-        assert_eq!(vertices.len(), 2);
-        assert_eq!(vertices[0].position, [0.0, 0.0]);
-        assert_eq!(vertices[1].position, [1.0, 1.0]);
+    #[test]
+    fn test_quad_geometry() {
+        assert_eq!(QUAD_CORNERS.len(), 4);
+        assert_eq!(QUAD_INDICES, &[0, 1, 2, 2, 3, 0]);
     }
 
     // Pipeline tests require wgpu device, which is async and integration-test scope
@@ -0,0 +1,56 @@
+//! Render statistics
+
+/// Per-frame statistics reported by `Renderer::render_commands`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Number of GPU draw calls issued
+    pub draw_calls: u32,
+    /// Number of sprite batches drawn
+    pub batches: u32,
+    /// Number of texture bind group changes
+    pub textures_bound: u32,
+    /// Number of vertices processed (4 per sprite instance)
+    pub vertices: u32,
+    /// Number of transition effects rendered
+    pub transitions: u32,
+}
+
+impl RenderStats {
+    /// Format the stats for a debug overlay or log line
+    pub fn display_string(&self) -> String {
+        format!(
+            "Draw calls: {} | Batches: {} | Textures: {} | Vertices: {} | Transitions: {}",
+            self.draw_calls, self.batches, self.textures_bound, self.vertices, self.transitions
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_stats_default() {
+        let stats = RenderStats::default();
+        assert_eq!(stats.draw_calls, 0);
+        assert_eq!(stats.batches, 0);
+        assert_eq!(stats.textures_bound, 0);
+        assert_eq!(stats.vertices, 0);
+        assert_eq!(stats.transitions, 0);
+    }
+
+    #[test]
+    fn test_render_stats_display_string() {
+        let stats = RenderStats {
+            draw_calls: 3,
+            batches: 2,
+            textures_bound: 2,
+            vertices: 40,
+            transitions: 1,
+        };
+        assert_eq!(
+            stats.display_string(),
+            "Draw calls: 3 | Batches: 2 | Textures: 2 | Vertices: 40 | Transitions: 1"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Logical coordinate space for render commands
+
+use narrative_core::{Point, Rect};
+
+/// Mapping between logical pixels used by render commands and the physical surface
+///
+/// Game code positions everything in logical pixels so layouts look the same on
+/// every monitor:
+///
+/// - Without a virtual resolution, the logical size is the surface size divided
+///   by the DPI scale factor (a 2560x1440 surface at 2.0x is 1280x720 logical).
+/// - With a virtual resolution, the logical size is fixed and scaled uniformly
+///   to fit the surface, centered with letterbox or pillarbox bars.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// Logical size render commands are positioned in
+    pub logical_size: (f32, f32),
+    /// Physical pixel rect the logical space is drawn into
+    pub physical_rect: Rect,
+}
+
+impl Viewport {
+    /// Compute the viewport for a surface
+    ///
+    /// # Arguments
+    /// * `physical_size` - Surface size in physical pixels
+    /// * `scale_factor` - DPI scale factor of the window (physical per logical pixel)
+    /// * `virtual_resolution` - Optional fixed logical resolution
+    pub fn new(
+        physical_size: (u32, u32),
+        scale_factor: f64,
+        virtual_resolution: Option<(u32, u32)>,
+    ) -> Self {
+        let physical_w = physical_size.0.max(1) as f32;
+        let physical_h = physical_size.1.max(1) as f32;
+
+        match virtual_resolution {
+            Some((virtual_w, virtual_h)) if virtual_w > 0 && virtual_h > 0 => {
+                let (virtual_w, virtual_h) = (virtual_w as f32, virtual_h as f32);
+                let scale = (physical_w / virtual_w).min(physical_h / virtual_h);
+                let width = virtual_w * scale;
+                let height = virtual_h * scale;
+
+                Self {
+                    logical_size: (virtual_w, virtual_h),
+                    physical_rect: Rect::new(
+                        ((physical_w - width) / 2.0).floor(),
+                        ((physical_h - height) / 2.0).floor(),
+                        width,
+                        height,
+                    ),
+                }
+            }
+            _ => {
+                let scale = if scale_factor > 0.0 {
+                    scale_factor as f32
+                } else {
+                    1.0
+                };
+
+                Self {
+                    logical_size: (physical_w / scale, physical_h / scale),
+                    physical_rect: Rect::new(0.0, 0.0, physical_w, physical_h),
+                }
+            }
+        }
+    }
+
+    /// Physical pixels per logical pixel
+    pub fn scale(&self) -> f32 {
+        self.physical_rect.width / self.logical_size.0
+    }
+
+    /// Convert a physical surface position (e.g. the mouse cursor) to logical pixels
+    pub fn to_logical(&self, physical: Point) -> Point {
+        let scale = self.scale();
+        Point::new(
+            (physical.x - self.physical_rect.x) / scale,
+            (physical.y - self.physical_rect.y) / scale,
+        )
+    }
+
+    /// Convert a logical position to physical surface pixels
+    pub fn to_physical(&self, logical: Point) -> Point {
+        let scale = self.scale();
+        Point::new(
+            logical.x * scale + self.physical_rect.x,
+            logical.y * scale + self.physical_rect.y,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport_dpi_scale() {
+        let viewport = Viewport::new((2560, 1440), 2.0, None);
+
+        assert_eq!(viewport.logical_size, (1280.0, 720.0));
+        assert_eq!(viewport.physical_rect, Rect::new(0.0, 0.0, 2560.0, 1440.0));
+        assert_eq!(viewport.scale(), 2.0);
+    }
+
+    #[test]
+    fn test_viewport_invalid_scale_factor() {
+        let viewport = Viewport::new((800, 600), 0.0, None);
+        assert_eq!(viewport.logical_size, (800.0, 600.0));
+    }
+
+    #[test]
+    fn test_viewport_virtual_resolution_letterbox() {
+        // 16:9 virtual resolution on a 4:3 surface: bars above and below
+        let viewport = Viewport::new((1600, 1200), 1.0, Some((1280, 720)));
+
+        assert_eq!(viewport.logical_size, (1280.0, 720.0));
+        assert_eq!(viewport.physical_rect, Rect::new(0.0, 150.0, 1600.0, 900.0));
+        assert_eq!(viewport.scale(), 1.25);
+    }
+
+    #[test]
+    fn test_viewport_virtual_resolution_ignores_scale_factor() {
+        let viewport = Viewport::new((2560, 1440), 2.0, Some((1280, 720)));
+
+        assert_eq!(viewport.logical_size, (1280.0, 720.0));
+        assert_eq!(viewport.scale(), 2.0);
+    }
+
+    #[test]
+    fn test_viewport_point_conversion() {
+        let viewport = Viewport::new((1600, 1200), 1.0, Some((1280, 720)));
+
+        let logical = viewport.to_logical(Point::new(800.0, 600.0));
+        assert_eq!(logical, Point::new(640.0, 360.0));
+        assert_eq!(viewport.to_physical(logical), Point::new(800.0, 600.0));
+    }
+}
@@ -3,13 +3,19 @@
 //! This module provides the main execution engine for scenarios, handling
 //! command execution, state management, and scene transitions.
 
-use super::{FlagStore, ReadHistory, VariableStore};
+#[cfg(feature = "scripting")]
+use super::ScriptHost;
+use super::{EffectState, FlagStore, NameInputState, ReadHistory, VariableStore};
 use crate::asset::AssetLoader;
 use crate::error::{EngineError, EngineResult};
+use crate::save::{DisplaySnapshot, SceneCheckpoint};
 use narrative_core::{
-    AssetRef, Backlog, BacklogEntry, CharacterPosition, ChoiceOption, FlagId, Scenario,
-    ScenarioCommand, Scene, SceneId, Transition, UnlockData, VariableId,
+    AchievementRegistry, AssetRef, Backlog, BacklogEntry, CharacterPosition, ChoiceOption,
+    DialogueLanguages, EffectLifetime, FlagId, HudVisibility, Scenario, ScenarioCommand, Scene,
+    SceneId, Transition, UnlockData, VariableId, VariableValue, VoiceResolver,
 };
+use rollback::RollbackHistory;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
@@ -17,6 +23,9 @@ use std::sync::{Arc, Mutex};
 /// Maximum call stack depth to prevent infinite recursion
 const MAX_CALL_STACK_DEPTH: usize = 100;
 
+/// Maximum number of dialogue lines kept for rollback
+const MAX_ROLLBACK_STEPS: usize = 100;
+
 /// Scenario runtime for executing scenarios
 ///
 /// This is the main execution engine that manages scenario state,
@@ -28,6 +37,8 @@ pub struct ScenarioRuntime {
     current_scene: Option<SceneId>,
     /// Current command index within the scene
     command_index: usize,
+    /// Command index of each `Label` command, per scene
+    labels: HashMap<SceneId, HashMap<String, usize>>,
     /// Flag storage for boolean flags
     flag_store: FlagStore,
     /// Variable storage for typed variables
@@ -36,6 +47,12 @@ pub struct ScenarioRuntime {
     read_history: ReadHistory,
     /// Backlog of displayed dialogues
     backlog: Backlog,
+    /// Screen shown with each backlog entry, keyed like the backlog
+    ///
+    /// Consecutive entries on an unchanged screen share one snapshot.
+    display_history: HashMap<(SceneId, usize), Arc<DisplaySnapshot>>,
+    /// Snapshot recorded for the most recent backlog entry
+    last_display_snapshot: Option<Arc<DisplaySnapshot>>,
     /// Scene navigation stack for Call/Return commands
     ///
     /// - Call: Push current scene and position to stack, jump to target scene
@@ -43,6 +60,8 @@ pub struct ScenarioRuntime {
     ///
     /// This enables subroutine-like scene navigation patterns.
     scene_stack: Vec<(SceneId, usize)>,
+    /// Arguments and result variable of each call, indexed like `scene_stack`
+    call_frames: Vec<CallFrame>,
     /// Currently displayed characters (for UI rendering)
     displayed_characters: HashMap<String, DisplayedCharacter>,
     /// Dirty flag for displayed characters changes
@@ -51,8 +70,73 @@ pub struct ScenarioRuntime {
     current_background: Option<AssetRef>,
     /// Current CG (event graphics) asset
     current_cg: Option<AssetRef>,
+    /// Background music started by the last PlayBgm command
+    current_bgm: Option<PlayingBgm>,
+    /// Screen effects started by ShowEffect, in the order they were started
+    active_effects: Vec<ActiveEffect>,
+    /// HUD widgets shown or hidden by SetHudVisible commands
+    hud_visibility: HudVisibility,
+    /// States at recent dialogue lines, for rollback
+    rollback_history: RollbackHistory,
     /// Global unlock data (shared across saves)
     unlock_data: Option<Arc<Mutex<UnlockData>>>,
+    /// Achievements whose conditions are checked as commands are reached
+    achievements: Option<Arc<AchievementRegistry>>,
+    /// Achievements unlocked since they were last taken
+    unlocked_achievements: Vec<String>,
+    /// Scenario jump requested by a JumpToScenario command
+    pending_scenario_jump: Option<ScenarioJump>,
+    /// State snapshot taken at the last scene entry
+    checkpoint: Option<SceneCheckpoint>,
+    /// Seed for shuffled choice orders
+    choice_seed: u64,
+    /// Choice display order restored from a save, for the choice at (scene, command index)
+    saved_choice_order: Option<(SceneId, usize, Vec<usize>)>,
+    /// Hook called with each command the runtime reaches
+    on_command: Option<CommandHook>,
+    /// Convention-based voice resolution (`None` when `voice_dir` is not configured)
+    voice_resolver: Option<VoiceResolver>,
+    /// Languages dialogue is displayed and logged in
+    dialogue_languages: DialogueLanguages,
+    /// Engine running `Script` commands
+    #[cfg(feature = "scripting")]
+    script_host: ScriptHost,
+}
+
+/// Hook called with each command the runtime reaches
+///
+/// See [`ScenarioRuntime::set_on_command`].
+pub type CommandHook = Box<dyn FnMut(&CommandEvent<'_>) + Send + Sync>;
+
+/// Command reached by the runtime, passed to the `on_command` hook
+#[derive(Debug, Clone, Copy)]
+pub struct CommandEvent<'a> {
+    /// Scene containing the command
+    pub scene: &'a Scene,
+    /// Index of the command within the scene
+    pub command_index: usize,
+    /// The command
+    pub command: &'a ScenarioCommand,
+}
+
+impl<'a> CommandEvent<'a> {
+    /// Get a metadata tag of the command, falling back to the scene's tags
+    pub fn tag(&self, key: &str) -> Option<&'a str> {
+        let command_tag = match self.command {
+            ScenarioCommand::Dialogue { dialogue } => dialogue.tag(key),
+            _ => None,
+        };
+        command_tag.or_else(|| self.scene.tag(key))
+    }
+}
+
+/// Jump to another scenario of the project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioJump {
+    /// Scenario ID in the project manifest
+    pub id: String,
+    /// Scene to start at (`None` for the scenario's start scene)
+    pub scene: Option<String>,
 }
 
 /// Information about a displayed character
@@ -68,6 +152,89 @@ pub struct DisplayedCharacter {
     pub transition: Transition,
 }
 
+/// Background music started by a PlayBgm command
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayingBgm {
+    /// Music asset
+    pub asset: AssetRef,
+    /// Playback volume (0.0-1.0)
+    pub volume: f32,
+}
+
+/// Scope of a scene entered with a Call command
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CallFrame {
+    /// Argument variables and their values before the call (None = unset)
+    #[serde(default)]
+    pub shadowed: Vec<(String, Option<VariableValue>)>,
+    /// Caller variable that receives the value given to Return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+}
+
+/// Screen effect started by a ShowEffect command
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEffect {
+    /// Effect name (e.g. "rain")
+    pub effect: String,
+    /// Effect intensity (1.0 = normal)
+    pub intensity: f32,
+    /// Seconds the effect runs for, or None to run until it is cleared
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f32>,
+    /// Seconds the effect has been running
+    #[serde(default)]
+    pub elapsed: f32,
+    /// Whether the effect ends with the scene or lasts until ClearEffects
+    #[serde(default)]
+    pub lifetime: EffectLifetime,
+}
+
+/// Branch of a choice or If command, evaluated without running it
+///
+/// See [`ScenarioRuntime::preview_branches`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchPreview {
+    /// Index of the branching command in its scene
+    pub command_index: usize,
+    /// Which branch of the command this is
+    pub branch: BranchKind,
+    /// Scene the branch continues in
+    ///
+    /// A choice option jumps to its `next_scene`; If blocks run inline, so
+    /// both arms continue in the scene of the command.
+    pub destination: SceneId,
+    /// Whether the branch would be taken (for a choice option: whether it is offered)
+    pub taken: bool,
+    /// Conditions guarding the branch and whether each one passed
+    pub conditions: Vec<ConditionCheck>,
+}
+
+/// Branch of a branching command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BranchKind {
+    /// Option of a ShowChoice command
+    ChoiceOption {
+        /// Index of the option as authored (not the shuffled display order)
+        index: usize,
+        /// Option text
+        text: String,
+    },
+    /// `then_commands` of an If command
+    Then,
+    /// `else_commands` of an If command
+    Else,
+}
+
+/// Condition evaluated against the current runtime state
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionCheck {
+    /// The condition
+    pub condition: narrative_core::Condition,
+    /// Whether it held
+    pub passed: bool,
+}
+
 /// Result of executing a command
 #[derive(Debug, Clone, PartialEq)]
 pub enum CommandExecutionResult {
@@ -82,17 +249,73 @@ pub enum CommandExecutionResult {
     ShowChoices(Vec<ChoiceOption>),
     /// Wait for a duration (in seconds)
     Wait(f32),
+    /// Play a screen effect (shake, flash) and wait until it ends
+    PlayEffect(EffectState),
+    /// Play a video cutscene and wait until it ends
+    PlayVideo { path: AssetRef, skippable: bool },
+    /// Ask the player for a name and wait until it is submitted
+    InputName(NameInputState),
+    /// Jump to another scenario (resolved by the caller)
+    JumpToScenario(ScenarioJump),
     /// Scenario has ended
     End,
 }
 
+/// Where [`ScenarioRuntime::skip_to_next_stop`] stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipStop {
+    /// At a choice
+    Choice,
+    /// At a dialogue line the player has not read
+    UnreadDialogue,
+    /// At a command that waits for the player: a name input or an
+    /// unskippable video
+    PlayerInput,
+    /// The scenario ended or jumped to another scenario
+    End,
+    /// The command limit was reached before any of the above
+    Limit,
+}
+
+/// Where playback continues after [`ScenarioRuntime::reload_scenario`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioReload {
+    /// The current command is unchanged at the same position
+    Unchanged,
+    /// The current command moved within its scene (lines were added or removed before it)
+    Moved {
+        /// Previous command index
+        from: usize,
+        /// New command index
+        to: usize,
+    },
+    /// The current command was edited or removed; playback continues at the
+    /// same position of the scene, or its last command
+    Edited {
+        /// New command index
+        index: usize,
+    },
+    /// The current scene was removed or emptied; playback restarts at the
+    /// start scene
+    SceneRemoved,
+}
+
+mod achievements;
+mod branch_preview;
+#[cfg(any(test, feature = "test-util"))]
+mod builder;
 mod command_execution;
 mod display_state;
 mod execution_support;
+mod fast_forward;
 mod flow_control;
 mod lifecycle;
 mod persistence;
+mod rollback;
 mod state;
 
+#[cfg(any(test, feature = "test-util"))]
+pub use builder::ScenarioRuntimeBuilder;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+impl ScenarioRuntime {
+    /// Set the achievements whose conditions are checked as commands are reached
+    pub fn set_achievements(&mut self, achievements: Arc<AchievementRegistry>) {
+        self.achievements = Some(achievements);
+    }
+
+    /// Take the achievements unlocked since the last call, in unlock order
+    pub fn take_unlocked_achievements(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.unlocked_achievements)
+    }
+
+    /// Unlock an achievement in the unlock data and save it
+    ///
+    /// Does nothing without unlock data or if the achievement is already
+    /// unlocked. IDs missing from the achievement manifest are still
+    /// unlocked, with a warning.
+    pub(super) fn unlock_achievement(&mut self, id: &str) {
+        let Some(unlock_data_arc) = &self.unlock_data else {
+            return;
+        };
+        if let Some(achievements) = &self.achievements
+            && achievements.get(id).is_none()
+        {
+            tracing::warn!("Achievement '{}' is not in the achievement manifest", id);
+        }
+
+        match unlock_data_arc.lock() {
+            Ok(mut data) => {
+                if data.unlock_achievement(id) {
+                    tracing::info!("Achievement unlocked: {}", id);
+                    if let Err(e) = data.save_default() {
+                        tracing::warn!("Failed to save unlock data: {}", e);
+                    }
+                    self.unlocked_achievements.push(id.to_string());
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to lock unlock_data: {}", e);
+            }
+        }
+    }
+
+    /// Unlock the achievements whose conditions hold
+    pub(super) fn check_achievements(&mut self) {
+        let (Some(achievements), Some(unlock_data_arc)) = (&self.achievements, &self.unlock_data)
+        else {
+            return;
+        };
+
+        let is_flag_set = |flag: &str| self.flag_store.get(&FlagId::new(flag.to_string()));
+        let is_in_scene = |scene: &str| {
+            self.current_scene
+                .as_ref()
+                .is_some_and(|id| id.as_str() == scene)
+        };
+        let met: Vec<String> = match unlock_data_arc.lock() {
+            Ok(data) => achievements
+                .get_all_sorted()
+                .into_iter()
+                .filter(|achievement| !data.is_achievement_unlocked(&achievement.id))
+                .filter(|achievement| achievement.conditions_met(is_flag_set, is_in_scene))
+                .map(|achievement| achievement.id.clone())
+                .collect(),
+            Err(e) => {
+                tracing::error!("Failed to lock unlock_data: {}", e);
+                return;
+            }
+        };
+
+        for id in met {
+            self.unlock_achievement(&id);
+        }
+    }
+}
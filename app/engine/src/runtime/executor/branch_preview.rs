@@ -0,0 +1,89 @@
+use super::*;
+use narrative_core::Condition;
+
+impl ScenarioRuntime {
+    /// Preview the branches of every choice and If command in a scene
+    ///
+    /// Conditions are evaluated against the current flags and variables, but
+    /// nothing is executed: no flags are set and the position does not change.
+    /// Only commands at the top level of the scene are previewed.
+    ///
+    /// # Errors
+    /// Returns an error if the scene does not exist
+    pub fn preview_branches(&self, scene_id: &SceneId) -> EngineResult<Vec<BranchPreview>> {
+        let scene = self.scenario.scenes.get(scene_id.as_str()).ok_or_else(|| {
+            EngineError::ScenarioExecution(format!("Scene '{}' not found", scene_id.as_str()))
+        })?;
+
+        Ok(scene
+            .commands
+            .iter()
+            .enumerate()
+            .flat_map(|(index, command)| self.preview_command(scene_id, index, command))
+            .collect())
+    }
+
+    /// Preview the branches of the command at the current position
+    ///
+    /// Returns an empty list if the current command is not a choice or an If.
+    pub fn preview_current_branches(&self) -> Vec<BranchPreview> {
+        let (Some(scene_id), Some(command)) = (&self.current_scene, self.get_current_command())
+        else {
+            return Vec::new();
+        };
+        self.preview_command(scene_id, self.command_index, command)
+    }
+
+    fn preview_command(
+        &self,
+        scene_id: &SceneId,
+        command_index: usize,
+        command: &ScenarioCommand,
+    ) -> Vec<BranchPreview> {
+        match command {
+            ScenarioCommand::ShowChoice { choice } => choice
+                .options
+                .iter()
+                .enumerate()
+                .map(|(index, option)| {
+                    let conditions = self.check_conditions(&option.conditions);
+                    BranchPreview {
+                        command_index,
+                        branch: BranchKind::ChoiceOption {
+                            index,
+                            text: option.text.clone(),
+                        },
+                        destination: SceneId::new(option.next_scene.clone()),
+                        taken: conditions.iter().all(|check| check.passed),
+                        conditions,
+                    }
+                })
+                .collect(),
+            ScenarioCommand::If { condition, .. } => {
+                let conditions = self.check_conditions(std::slice::from_ref(condition));
+                let passed = conditions.iter().all(|check| check.passed);
+                [(BranchKind::Then, passed), (BranchKind::Else, !passed)]
+                    .into_iter()
+                    .map(|(branch, taken)| BranchPreview {
+                        command_index,
+                        branch,
+                        destination: scene_id.clone(),
+                        taken,
+                        conditions: conditions.clone(),
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn check_conditions(&self, conditions: &[Condition]) -> Vec<ConditionCheck> {
+        conditions
+            .iter()
+            .map(|condition| ConditionCheck {
+                condition: condition.clone(),
+                passed: self.evaluate_condition(condition),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,138 @@
+use super::*;
+use narrative_core::VariableValue;
+
+/// Builder for a runtime that starts partway through a scenario
+///
+/// Lets tests of scenario logic start at a scene with flags and variables
+/// already set, instead of replaying the scenario from its start scene.
+///
+/// Available with the `test-util` feature.
+pub struct ScenarioRuntimeBuilder {
+    scenario: Scenario,
+    flags: Vec<(FlagId, bool)>,
+    variables: Vec<(VariableId, VariableValue)>,
+    scene: Option<SceneId>,
+    command_index: usize,
+    choice_seed: Option<u64>,
+}
+
+impl ScenarioRuntimeBuilder {
+    /// Create a builder that starts at the scenario's start scene
+    pub fn new(scenario: Scenario) -> Self {
+        Self {
+            scenario,
+            flags: Vec::new(),
+            variables: Vec::new(),
+            scene: None,
+            command_index: 0,
+            choice_seed: None,
+        }
+    }
+
+    /// Set a flag before starting
+    pub fn with_flag(mut self, flag: impl Into<FlagId>, value: bool) -> Self {
+        self.flags.push((flag.into(), value));
+        self
+    }
+
+    /// Set several flags before starting
+    pub fn with_flags<F: Into<FlagId>>(
+        mut self,
+        flags: impl IntoIterator<Item = (F, bool)>,
+    ) -> Self {
+        self.flags
+            .extend(flags.into_iter().map(|(flag, value)| (flag.into(), value)));
+        self
+    }
+
+    /// Set a variable before starting
+    pub fn with_variable(mut self, variable: impl Into<VariableId>, value: VariableValue) -> Self {
+        self.variables.push((variable.into(), value));
+        self
+    }
+
+    /// Set several variables before starting
+    pub fn with_variables<V: Into<VariableId>>(
+        mut self,
+        variables: impl IntoIterator<Item = (V, VariableValue)>,
+    ) -> Self {
+        self.variables.extend(
+            variables
+                .into_iter()
+                .map(|(variable, value)| (variable.into(), value)),
+        );
+        self
+    }
+
+    /// Start at a scene instead of the start scene
+    pub fn at_scene(mut self, scene: impl Into<String>) -> Self {
+        self.scene = Some(SceneId::new(scene));
+        self
+    }
+
+    /// Start at a command of the scene instead of its first one
+    pub fn at_command(mut self, command_index: usize) -> Self {
+        self.command_index = command_index;
+        self
+    }
+
+    /// Use a fixed seed for shuffled choices
+    pub fn with_choice_seed(mut self, seed: u64) -> Self {
+        self.choice_seed = Some(seed);
+        self
+    }
+
+    /// Build the runtime, positioned at the chosen scene and command
+    ///
+    /// The scene-entry checkpoint is taken with the flags and variables set.
+    ///
+    /// # Errors
+    /// Returns an error if the scene does not exist or the command index is
+    /// past the end of the scene
+    pub fn build(self) -> EngineResult<ScenarioRuntime> {
+        let mut runtime = ScenarioRuntime::new(self.scenario);
+        if let Some(seed) = self.choice_seed {
+            runtime.set_choice_seed(seed);
+        }
+        for (flag, value) in self.flags {
+            runtime.flag_store.set(flag, value);
+        }
+        for (variable, value) in self.variables {
+            runtime.variable_store.set(variable, value);
+        }
+
+        match &self.scene {
+            Some(scene) => {
+                runtime.jump_to_scene(scene)?;
+            }
+            None => runtime.start()?,
+        }
+
+        if self.command_index > 0 {
+            let command_count = runtime
+                .get_current_scene_data()
+                .map_or(0, |scene| scene.commands.len());
+            if self.command_index > command_count {
+                return Err(EngineError::ScenarioExecution(format!(
+                    "Command index {} is past the end of scene '{}' ({} commands)",
+                    self.command_index,
+                    runtime
+                        .current_scene
+                        .as_ref()
+                        .map_or("", |scene| scene.as_str()),
+                    command_count
+                )));
+            }
+            runtime.command_index = self.command_index;
+        }
+
+        Ok(runtime)
+    }
+}
+
+impl ScenarioRuntime {
+    /// Builder for a runtime that starts partway through `scenario`
+    pub fn builder(scenario: Scenario) -> ScenarioRuntimeBuilder {
+        ScenarioRuntimeBuilder::new(scenario)
+    }
+}
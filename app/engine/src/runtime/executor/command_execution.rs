@@ -1,4 +1,5 @@
 use super::*;
+use narrative_core::Expression;
 
 impl ScenarioRuntime {
     /// Execute the current command
@@ -76,9 +77,17 @@ impl ScenarioRuntime {
                 character_id,
                 sprite,
                 position,
-                expression: _,
+                expression,
+                outfit,
                 transition,
             } => {
+                let sprite = self
+                    .layered_sprite(
+                        character_id,
+                        expression.as_ref().map(Expression::name),
+                        outfit.as_deref(),
+                    )
+                    .unwrap_or_else(|| sprite.clone());
                 tracing::info!(
                     "ShowCharacter: id={}, sprite={}, position={:?}, transition={:?}",
                     character_id,
@@ -92,7 +101,7 @@ impl ScenarioRuntime {
                     character_id.to_string(),
                     DisplayedCharacter {
                         character_id: character_id.to_string(),
-                        sprite: sprite.clone(),
+                        sprite,
                         position: *position,
                         transition: *transition,
                     },
@@ -142,7 +151,40 @@ impl ScenarioRuntime {
                 // The animation will run asynchronously in the rendering layer
                 Ok(CommandExecutionResult::Continue)
             }
-            ScenarioCommand::ChangeExpression { .. } => Ok(CommandExecutionResult::Continue),
+            ScenarioCommand::ChangeExpression {
+                character_id,
+                expression,
+            } => {
+                // Clone needed to release immutable borrow from get_current_command()
+                let char_id = character_id.to_string();
+                let expression = expression.name().to_string();
+                let sprite = self
+                    .displayed_characters
+                    .get(&char_id)
+                    .and_then(|character| {
+                        let def = self.character_def(&char_id)?;
+                        def.sprite_for(&expression, def.outfit_of(&character.sprite))
+                    });
+
+                match (self.displayed_characters.get_mut(&char_id), sprite) {
+                    (Some(character), Some(sprite)) => {
+                        tracing::info!("ChangeExpression: id={}, sprite={}", char_id, sprite.0);
+                        character.sprite = sprite;
+                        self.displayed_characters_dirty = true;
+                    }
+                    (Some(_), None) => tracing::warn!(
+                        "ChangeExpression: Character '{}' has no sprite for expression '{}', ignoring",
+                        char_id,
+                        expression
+                    ),
+                    (None, _) => tracing::warn!(
+                        "ChangeExpression: Character '{}' not currently displayed, ignoring",
+                        char_id
+                    ),
+                }
+
+                Ok(CommandExecutionResult::Continue)
+            }
             ScenarioCommand::ChangeSprite {
                 character_id,
                 sprite,
@@ -173,26 +215,62 @@ impl ScenarioRuntime {
             }
 
             // Audio commands
-            ScenarioCommand::PlayBgm { .. } => Ok(CommandExecutionResult::Continue),
-            ScenarioCommand::StopBgm { .. } => Ok(CommandExecutionResult::Continue),
+            ScenarioCommand::PlayBgm { asset, volume, .. } => {
+                self.current_bgm = Some(PlayingBgm {
+                    asset: asset.clone(),
+                    volume: *volume,
+                });
+                Ok(CommandExecutionResult::Continue)
+            }
+            ScenarioCommand::StopBgm { .. } => {
+                self.current_bgm = None;
+                Ok(CommandExecutionResult::Continue)
+            }
+            ScenarioCommand::ShowEffect {
+                effect,
+                intensity,
+                duration,
+                lifetime,
+            } => {
+                let active = ActiveEffect {
+                    effect: effect.clone(),
+                    intensity: *intensity,
+                    duration: *duration,
+                    elapsed: 0.0,
+                    lifetime: *lifetime,
+                };
+                match self
+                    .active_effects
+                    .iter_mut()
+                    .find(|running| running.effect == active.effect)
+                {
+                    Some(running) => *running = active,
+                    None => self.active_effects.push(active),
+                }
+                Ok(CommandExecutionResult::Continue)
+            }
+            ScenarioCommand::ClearEffects { effect } => {
+                match effect.clone() {
+                    Some(effect) => self
+                        .active_effects
+                        .retain(|running| running.effect != effect),
+                    None => self.active_effects.clear(),
+                }
+                Ok(CommandExecutionResult::Continue)
+            }
             ScenarioCommand::PlaySe { .. } => Ok(CommandExecutionResult::Continue),
+            ScenarioCommand::PlayVideo { path, skippable } => {
+                Ok(CommandExecutionResult::PlayVideo {
+                    path: path.clone(),
+                    skippable: *skippable,
+                })
+            }
             ScenarioCommand::PlayVoice { .. } => Ok(CommandExecutionResult::Continue),
 
             // Choice - returns the choices for the game loop to display
-            ScenarioCommand::ShowChoice { choice } => {
-                // Filter choices based on conditions
-                let available_choices: Vec<ChoiceOption> = choice
-                    .options
-                    .iter()
-                    .filter(|option| {
-                        // Check if all conditions for this option are satisfied
-                        option
-                            .conditions
-                            .iter()
-                            .all(|cond| self.evaluate_condition(cond))
-                    })
-                    .cloned()
-                    .collect();
+            ScenarioCommand::ShowChoice { .. } => {
+                // Filter choices based on conditions, in display order
+                let available_choices = self.displayed_choices();
 
                 // If no choices are available after filtering, this is an error
                 if available_choices.is_empty() {
@@ -202,7 +280,20 @@ impl ScenarioRuntime {
                             .to_string(),
                     ));
                 }
+                // Nor can the player continue if every shown choice is disabled
+                if self
+                    .displayed_choices_disabled()
+                    .iter()
+                    .all(|&disabled| disabled)
+                {
+                    return Err(EngineError::ScenarioExecution(
+                        "All available choices are disabled. \
+                         At least one choice must be selectable."
+                            .to_string(),
+                    ));
+                }
 
+                self.record_choice_point();
                 Ok(CommandExecutionResult::ShowChoices(available_choices))
             }
 
@@ -216,12 +307,44 @@ impl ScenarioRuntime {
                 })
             }
 
+            // Labels only mark a position
+            ScenarioCommand::Label { .. } => Ok(CommandExecutionResult::Continue),
+
+            // Continue at a label of this scene, after the Label command
+            ScenarioCommand::JumpToLabel { label } => {
+                let label = label.clone();
+                self.jump_to_label(&label)?;
+                Ok(CommandExecutionResult::Continue)
+            }
+
+            ScenarioCommand::JumpToScenario { id, scene } => {
+                tracing::info!("JumpToScenario: id={}, scene={:?}", id, scene);
+                let jump = ScenarioJump {
+                    id: id.clone(),
+                    scene: scene.clone(),
+                };
+                self.pending_scenario_jump = Some(jump.clone());
+                Ok(CommandExecutionResult::JumpToScenario(jump))
+            }
+
             // Flag operations
             ScenarioCommand::SetFlag { flag_name, value } => {
                 self.flag_store.set(FlagId::new(flag_name.clone()), *value);
                 Ok(CommandExecutionResult::Continue)
             }
 
+            ScenarioCommand::UnlockAchievement { id } => {
+                let id = id.clone();
+                self.unlock_achievement(&id);
+                Ok(CommandExecutionResult::Continue)
+            }
+
+            ScenarioCommand::SetHudVisible { visible, widgets } => {
+                let (visible, widgets) = (*visible, widgets.clone());
+                self.hud_visibility.set(visible, &widgets);
+                Ok(CommandExecutionResult::Continue)
+            }
+
             // Variable operations
             ScenarioCommand::SetVariable {
                 variable_name,
@@ -245,14 +368,30 @@ impl ScenarioRuntime {
             // Wait command
             ScenarioCommand::Wait { duration } => Ok(CommandExecutionResult::Wait(*duration)),
 
+            // Name input: wait for the player to submit a name
+            ScenarioCommand::InputName { .. } => match NameInputState::from_command(command) {
+                Some(state) => Ok(CommandExecutionResult::InputName(state)),
+                None => Ok(CommandExecutionResult::Continue),
+            },
+
             // Call command: Push to stack and jump to target scene
             ScenarioCommand::Call {
                 scene_id,
                 return_scene,
+                args,
+                result,
             } => {
                 // Clone values to avoid borrow issues
                 let scene_id = scene_id.clone();
                 let return_scene = return_scene.clone();
+                let result = result.clone();
+                // Arguments are evaluated in the caller's scope, before any is bound
+                let args = args
+                    .iter()
+                    .map(|(name, expression)| {
+                        Ok((name.clone(), self.evaluate_expression(expression)?))
+                    })
+                    .collect::<EngineResult<Vec<_>>>()?;
 
                 // Check stack depth limit
                 if self.scene_stack.len() >= MAX_CALL_STACK_DEPTH {
@@ -275,7 +414,21 @@ impl ScenarioRuntime {
                 if self.current_scene.is_some() {
                     let next_index = self.command_index.saturating_add(1);
                     let return_scene_id = SceneId::new(return_scene);
+                    self.call_frames.truncate(self.scene_stack.len());
                     self.scene_stack.push((return_scene_id, next_index));
+
+                    let mut frame = CallFrame {
+                        shadowed: Vec::with_capacity(args.len()),
+                        result,
+                    };
+                    for (name, value) in args {
+                        let variable = VariableId::new(name.clone());
+                        frame
+                            .shadowed
+                            .push((name, self.variable_store.get(&variable).cloned()));
+                        self.variable_store.set(variable, value);
+                    }
+                    self.call_frames.push(frame);
                 }
 
                 // Jump to target scene
@@ -288,7 +441,13 @@ impl ScenarioRuntime {
             }
 
             // Return command: Pop from stack and return
-            ScenarioCommand::Return => {
+            ScenarioCommand::Return { value } => {
+                // The value is evaluated in the called scene's scope
+                let value = value
+                    .as_ref()
+                    .map(|expression| self.evaluate_expression(expression))
+                    .transpose()?;
+
                 // Pop return destination
                 let (return_scene, command_index) = self.scene_stack.pop().ok_or_else(|| {
                     EngineError::ScenarioExecution(
@@ -298,11 +457,35 @@ impl ScenarioRuntime {
                     )
                 })?;
 
+                // Restore the variables the arguments were bound to, then hand
+                // the value to the caller
+                let frame = if self.call_frames.len() > self.scene_stack.len() {
+                    self.call_frames.truncate(self.scene_stack.len() + 1);
+                    self.call_frames.pop()
+                } else {
+                    None
+                };
+                if let Some(frame) = frame {
+                    for (name, previous) in frame.shadowed.into_iter().rev() {
+                        let variable = VariableId::new(name);
+                        match previous {
+                            Some(previous) => self.variable_store.set(variable, previous),
+                            None => {
+                                self.variable_store.remove(&variable);
+                            }
+                        }
+                    }
+                    if let (Some(result), Some(value)) = (frame.result, value) {
+                        self.variable_store.set(VariableId::new(result), value);
+                    }
+                }
+
                 // Jump to return scene
-                let (exit_transition, entry_transition) = self.jump_to_scene(&return_scene)?;
+                let (exit_transition, entry_transition) = self.change_scene(&return_scene)?;
 
                 // Restore saved command position
                 self.command_index = command_index;
+                self.notify_command_reached();
 
                 Ok(CommandExecutionResult::SceneChanged {
                     exit_transition,
@@ -329,21 +512,49 @@ impl ScenarioRuntime {
 
                 // Execute all commands in the chosen branch
                 // Note: These are executed inline, not as a scene jump
+                let command_index = self.command_index;
                 for cmd in &commands_to_execute {
                     // Recursively execute each command
                     // We need to be careful not to advance the command index here
                     // since these are inline commands
                     self.execute_command_inline(cmd)?;
+                    // JumpToLabel moved to a label: skip the rest of the block
+                    if self.command_index != command_index {
+                        break;
+                    }
                 }
 
                 Ok(CommandExecutionResult::Continue)
             }
 
+            // Script: run it, then jump if it asked to
+            ScenarioCommand::Script { source } => {
+                let source = source.clone();
+                match self.run_script(&source)? {
+                    Some(scene_id) => {
+                        let (exit_transition, entry_transition) =
+                            self.jump_to_scene(&SceneId::new(scene_id))?;
+                        Ok(CommandExecutionResult::SceneChanged {
+                            exit_transition,
+                            entry_transition,
+                        })
+                    }
+                    None => Ok(CommandExecutionResult::Continue),
+                }
+            }
+
+            // Screen effects: played by the game loop, which then advances
+            ScenarioCommand::ScreenShake { .. } | ScenarioCommand::Flash { .. } => {
+                match EffectState::from_command(command) {
+                    Some(effect) => Ok(CommandExecutionResult::PlayEffect(effect)),
+                    None => Ok(CommandExecutionResult::Continue),
+                }
+            }
+
             // End scenario
             ScenarioCommand::End => Ok(CommandExecutionResult::End),
             // TODO: Implement additional commands for future phases
-            // - Camera: Camera control commands (zoom, pan, shake)
-            // - Effect: Visual effects (flash, fade, etc.)
+            // - Camera: Camera control commands (zoom, pan)
         }
     }
 }
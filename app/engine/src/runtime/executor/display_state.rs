@@ -1,4 +1,6 @@
 use super::*;
+use crate::save::SavedCharacterDisplay;
+use narrative_core::{CharacterDef, SpriteMode};
 
 impl ScenarioRuntime {
     /// Get the currently displayed characters
@@ -6,6 +8,121 @@ impl ScenarioRuntime {
         &self.displayed_characters
     }
 
+    /// Execute the display commands at the current position
+    ///
+    /// Runs background, CG and character commands up to the first other
+    /// command, so the opening picture of a scene is on screen while a
+    /// transition into it plays, whatever order those commands come in.
+    /// Returns how many commands were executed.
+    pub fn execute_display_commands(&mut self) -> EngineResult<usize> {
+        let mut executed = 0;
+        while self.get_current_command().is_some_and(|command| {
+            matches!(
+                command,
+                ScenarioCommand::ShowBackground { .. }
+                    | ScenarioCommand::HideBackground { .. }
+                    | ScenarioCommand::ShowCG { .. }
+                    | ScenarioCommand::HideCG { .. }
+                    | ScenarioCommand::ShowCharacter { .. }
+                    | ScenarioCommand::HideCharacter { .. }
+                    | ScenarioCommand::ChangeExpression { .. }
+                    | ScenarioCommand::ChangeSprite { .. }
+            )
+        }) {
+            self.execute_current_command()?;
+            executed += 1;
+            if !self.advance_command() {
+                break;
+            }
+        }
+        Ok(executed)
+    }
+
+    /// Definition of a character of the scenario
+    pub(super) fn character_def(&self, character_id: &str) -> Option<&CharacterDef> {
+        self.scenario
+            .characters
+            .iter()
+            .find(|character| character.id == character_id)
+    }
+
+    /// Sprite of a character defined with a layered sprite
+    ///
+    /// Uses the default expression when `expression` is None. Returns None
+    /// for other characters, and with a warning if the expression or outfit
+    /// is not defined.
+    pub(super) fn layered_sprite(
+        &self,
+        character_id: &str,
+        expression: Option<&str>,
+        outfit: Option<&str>,
+    ) -> Option<AssetRef> {
+        let def = self
+            .character_def(character_id)
+            .filter(|def| matches!(def.sprite_mode, SpriteMode::Layered { .. }))?;
+        let expression = expression.unwrap_or(&def.default_expression);
+        let sprite = def.sprite_for(expression, outfit);
+        if sprite.is_none() {
+            tracing::warn!(
+                "Character '{}' has no layered sprite for expression '{}' and outfit {:?}",
+                character_id,
+                expression,
+                outfit
+            );
+        }
+        sprite
+    }
+
+    /// What is on screen now: background, CG and characters
+    ///
+    /// Characters are ordered by ID so the snapshot is deterministic.
+    pub fn display_snapshot(&self) -> DisplaySnapshot {
+        let mut characters: Vec<SavedCharacterDisplay> = self
+            .displayed_characters
+            .values()
+            .map(|character| SavedCharacterDisplay {
+                character_id: character.character_id.clone(),
+                sprite: character.sprite.0.to_string(),
+                position: character.position,
+            })
+            .collect();
+        characters.sort_by(|a, b| a.character_id.cmp(&b.character_id));
+
+        DisplaySnapshot {
+            background: self.current_background.as_ref().map(|bg| bg.0.to_string()),
+            cg: self.current_cg.as_ref().map(|cg| cg.0.to_string()),
+            characters,
+        }
+    }
+
+    /// Screen that was shown with a backlog entry
+    pub fn display_snapshot_at(
+        &self,
+        scene_id: &SceneId,
+        command_index: usize,
+    ) -> Option<&DisplaySnapshot> {
+        self.display_history
+            .get(&(scene_id.clone(), command_index))
+            .map(Arc::as_ref)
+    }
+
+    /// Record the current screen for a backlog entry (first display wins,
+    /// like the backlog itself)
+    pub(super) fn record_display_snapshot(&mut self, scene_id: SceneId, command_index: usize) {
+        let key = (scene_id, command_index);
+        if self.display_history.contains_key(&key) {
+            return;
+        }
+
+        let snapshot = self.display_snapshot();
+        let snapshot = match &self.last_display_snapshot {
+            Some(last) if **last == snapshot => Arc::clone(last),
+            _ => Arc::new(snapshot),
+        };
+        self.last_display_snapshot = Some(Arc::clone(&snapshot));
+        self.display_history.insert(key, snapshot);
+    }
+
     /// Check if displayed characters have changed since last check
     ///
     /// This method consumes the dirty flag (one-shot read).
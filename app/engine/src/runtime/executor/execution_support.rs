@@ -1,6 +1,32 @@
 use super::*;
 
 impl ScenarioRuntime {
+    /// Call the `on_command` hook with the command at the current position
+    ///
+    /// Achievement conditions are checked first.
+    pub(super) fn notify_command_reached(&mut self) {
+        self.check_achievements();
+        let Some(hook) = self.on_command.as_mut() else {
+            return;
+        };
+        let Some(scene) = self
+            .current_scene
+            .as_ref()
+            .and_then(|id| self.scenario.scenes.get(id.as_str()))
+        else {
+            return;
+        };
+        let Some(command) = scene.commands.get(self.command_index) else {
+            return;
+        };
+
+        hook(&CommandEvent {
+            scene,
+            command_index: self.command_index,
+            command,
+        });
+    }
+
     /// Evaluate a condition using current runtime state
     ///
     /// This checks flags and variables to determine if a condition is satisfied.
@@ -18,6 +44,26 @@ impl ScenarioRuntime {
         condition.evaluate(&get_flag, &get_variable)
     }
 
+    /// Evaluate an expression over the current flags and variables
+    pub(super) fn evaluate_expression(
+        &self,
+        expression: &narrative_core::expression::Expression,
+    ) -> EngineResult<narrative_core::VariableValue> {
+        let get_flag = |flag_name: &str| {
+            let flag_id = narrative_core::FlagId::new(flag_name.to_string());
+            self.flag_store.get(&flag_id)
+        };
+
+        let get_variable = |variable_name: &str| {
+            let variable_id = narrative_core::VariableId::new(variable_name.to_string());
+            self.variable_store.get(&variable_id).cloned()
+        };
+
+        expression.evaluate(&get_flag, &get_variable).map_err(|e| {
+            EngineError::ScenarioExecution(format!("Failed to evaluate '{}': {}", expression, e))
+        })
+    }
+
     /// Apply a variable modification operation
     ///
     /// This method handles the common logic for applying variable operations,
@@ -54,22 +100,63 @@ impl ScenarioRuntime {
                         VariableValue::String(String::new())
                     }
                     narrative_core::VariableOperation::Toggle => VariableValue::Bool(false),
+                    // Expressions read variables themselves and ignore the current value
+                    narrative_core::VariableOperation::Expression { .. } => VariableValue::Int(0),
                 }
             });
 
-        // Apply operation
-        let new_value = operation.apply(&current_value).map_err(|e| {
-            EngineError::ScenarioExecution(format!(
-                "Failed to apply operation to variable '{}': {}",
-                variable_name, e
-            ))
-        })?;
+        // Apply operation, giving expressions access to flags and variables
+        let get_flag = |flag_name: &str| {
+            let flag_id = narrative_core::FlagId::new(flag_name.to_string());
+            self.flag_store.get(&flag_id)
+        };
+        let get_variable = |name: &str| {
+            let variable_id = VariableId::new(name.to_string());
+            self.variable_store.get(&variable_id).cloned()
+        };
+        let new_value = operation
+            .apply_with(&current_value, &get_flag, &get_variable)
+            .map_err(|e| {
+                EngineError::ScenarioExecution(format!(
+                    "Failed to apply operation to variable '{}': {}",
+                    variable_name, e
+                ))
+            })?;
 
         // Store the result
         self.variable_store.set(var_id, new_value);
         Ok(())
     }
 
+    /// Run the source of a `Script` command
+    ///
+    /// Returns the scene the script asked to jump to.
+    #[cfg(feature = "scripting")]
+    pub(super) fn run_script(&mut self, source: &str) -> EngineResult<Option<String>> {
+        let scene = self
+            .current_scene
+            .as_ref()
+            .map(|id| id.as_str())
+            .unwrap_or_default();
+        let outcome = self.script_host.run(
+            source,
+            scene,
+            &mut self.flag_store,
+            &mut self.variable_store,
+        )?;
+        Ok(outcome.jump)
+    }
+
+    /// Run the source of a `Script` command
+    ///
+    /// Always fails: the engine was built without the `scripting` feature.
+    #[cfg(not(feature = "scripting"))]
+    pub(super) fn run_script(&mut self, _source: &str) -> EngineResult<Option<String>> {
+        Err(EngineError::Script(
+            "Script commands need narrative-engine's `scripting` feature".to_string(),
+        ))
+    }
+
     /// Execute a command inline (used for If/Else command blocks)
     ///
     /// This executes a command without affecting the main command index.
@@ -99,6 +186,16 @@ impl ScenarioRuntime {
                 self.apply_variable_modification(&variable_name, &operation)
             }
 
+            ScenarioCommand::UnlockAchievement { id } => {
+                self.unlock_achievement(id);
+                Ok(())
+            }
+
+            ScenarioCommand::SetHudVisible { visible, widgets } => {
+                self.hud_visibility.set(*visible, widgets);
+                Ok(())
+            }
+
             // Nested If commands
             ScenarioCommand::If {
                 condition,
@@ -112,19 +209,36 @@ impl ScenarioRuntime {
                     else_commands
                 };
 
+                let command_index = self.command_index;
                 for cmd in commands_to_execute {
                     self.execute_command_inline(cmd)?;
+                    if self.command_index != command_index {
+                        break;
+                    }
                 }
                 Ok(())
             }
 
+            // Continue at a label of the scene once the block is left
+            ScenarioCommand::JumpToLabel { label } => self.jump_to_label(label),
+
+            // Scripts can change state, but not jump out of the block
+            ScenarioCommand::Script { source } => match self.run_script(source)? {
+                Some(scene_id) => Err(EngineError::ScenarioExecution(format!(
+                    "Script inside If/Else block cannot jump to scene '{}'",
+                    scene_id
+                ))),
+                None => Ok(()),
+            },
+
             // Commands that cannot be executed inline should return an error
             ScenarioCommand::JumpToScene { .. }
+            | ScenarioCommand::JumpToScenario { .. }
             | ScenarioCommand::Call { .. }
-            | ScenarioCommand::Return
+            | ScenarioCommand::Return { .. }
             | ScenarioCommand::End => Err(EngineError::ScenarioExecution(format!(
                 "Command {:?} cannot be executed inside If/Else block. \
-                 Only SetFlag, SetVariable, ModifyVariable, and nested If commands are allowed.",
+                 Only SetFlag, SetVariable, ModifyVariable, UnlockAchievement, SetHudVisible, Script, JumpToLabel, and nested If commands are allowed.",
                 command
             ))),
 
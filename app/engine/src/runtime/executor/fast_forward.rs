@@ -0,0 +1,81 @@
+use super::*;
+use crate::text::TypewriterEffect;
+
+impl ScenarioRuntime {
+    /// Most commands [`Self::skip_to_next_stop`] runs in one call, to stop
+    /// endless loops of read lines
+    pub const SKIP_COMMAND_LIMIT: usize = 10_000;
+
+    /// Skip to the next choice or unread dialogue line
+    ///
+    /// Runs the commands in between at once: read lines go to the backlog
+    /// without being typed out, waits, screen effects and skippable videos
+    /// end immediately, and scene changes skip their transitions. Sound
+    /// effects are not played; the BGM playing afterwards is
+    /// [`Self::current_bgm`].
+    ///
+    /// The current command counts as done, so this is called while a line is
+    /// shown. Skipping stops *at* the next choice, unread line, name input or
+    /// unskippable video, before it runs, so the caller enters it as usual.
+    ///
+    /// # Errors
+    /// Returns an error if a command fails
+    pub fn skip_to_next_stop(&mut self) -> EngineResult<SkipStop> {
+        self.advance_command();
+        for _ in 0..Self::SKIP_COMMAND_LIMIT {
+            let Some(command) = self.get_current_command() else {
+                return Ok(SkipStop::End);
+            };
+            match command {
+                ScenarioCommand::ShowChoice { .. } => return Ok(SkipStop::Choice),
+                ScenarioCommand::InputName { .. }
+                | ScenarioCommand::PlayVideo {
+                    skippable: false, ..
+                } => return Ok(SkipStop::PlayerInput),
+                ScenarioCommand::End => return Ok(SkipStop::End),
+                ScenarioCommand::Dialogue { .. } => {
+                    let scene_id = self
+                        .current_scene
+                        .clone()
+                        .unwrap_or_else(|| SceneId::new(""));
+                    if !self.read_history.is_read(&scene_id, self.command_index) {
+                        return Ok(SkipStop::UnreadDialogue);
+                    }
+                    self.record_skipped_line(scene_id);
+                    self.advance_command();
+                }
+                _ => match self.execute_current_command()? {
+                    CommandExecutionResult::Continue
+                    | CommandExecutionResult::Wait(_)
+                    | CommandExecutionResult::PlayEffect(_)
+                    | CommandExecutionResult::PlayVideo { .. } => {
+                        self.advance_command();
+                    }
+                    // The new scene starts at its first command
+                    CommandExecutionResult::SceneChanged { .. } => {}
+                    CommandExecutionResult::ShowChoices(_) => return Ok(SkipStop::Choice),
+                    CommandExecutionResult::InputName(_) => return Ok(SkipStop::PlayerInput),
+                    CommandExecutionResult::JumpToScenario(_) | CommandExecutionResult::End => {
+                        return Ok(SkipStop::End);
+                    }
+                },
+            }
+        }
+        Ok(SkipStop::Limit)
+    }
+
+    /// Add the current (read) dialogue line to the backlog, as if it was shown
+    fn record_skipped_line(&mut self, scene_id: SceneId) {
+        let Some(ScenarioCommand::Dialogue { dialogue }) = self.get_current_command() else {
+            return;
+        };
+        let speaker = dialogue.speaker.clone();
+        let (text, translation) = dialogue.text_tracks(&self.dialogue_languages);
+        let text = TypewriterEffect::strip_tags(&self.variable_store.substitute(text));
+        let translation = translation.map(|translation| {
+            TypewriterEffect::strip_tags(&self.variable_store.substitute(translation))
+        });
+        let command_index = self.command_index;
+        self.add_to_backlog_with_translation(scene_id, command_index, speaker, text, translation);
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use narrative_core::VariableValue;
 
 impl ScenarioRuntime {
     /// Advance to the next command in the current scene
@@ -18,12 +19,66 @@ impl ScenarioRuntime {
         // Check if we can advance to next command
         if self.command_index < scene.command_count() {
             self.command_index = self.command_index.saturating_add(1);
+            self.notify_command_reached();
             true
         } else {
             false
         }
     }
 
+    /// Continue at a label of the current scene
+    ///
+    /// The current command becomes the `Label` command, so advancing moves to
+    /// the command after it.
+    ///
+    /// # Errors
+    /// Returns an error if the current scene has no such label
+    pub fn jump_to_label(&mut self, label: &str) -> EngineResult<()> {
+        let scene = self.current_scene.as_ref().ok_or_else(|| {
+            EngineError::ScenarioExecution(format!(
+                "Cannot jump to label '{}' outside a scene",
+                label
+            ))
+        })?;
+        let index = self
+            .labels
+            .get(scene)
+            .and_then(|labels| labels.get(label))
+            .copied()
+            .ok_or_else(|| {
+                EngineError::ScenarioExecution(format!(
+                    "Label '{}' not found in scene '{}'",
+                    label,
+                    scene.as_str()
+                ))
+            })?;
+
+        tracing::info!("JumpToLabel: {}:{}", scene.as_str(), label);
+        self.command_index = index;
+        self.notify_command_reached();
+        Ok(())
+    }
+
+    /// Command index of each label in the scenes of `scenario`
+    ///
+    /// Only top-level `Label` commands are indexed. When a scene repeats a
+    /// label, the first one is used.
+    pub(super) fn index_labels(scenario: &Scenario) -> HashMap<SceneId, HashMap<String, usize>> {
+        scenario
+            .scenes
+            .iter()
+            .map(|(id, scene)| {
+                let mut labels = HashMap::new();
+                for (index, command) in scene.commands.iter().enumerate() {
+                    if let ScenarioCommand::Label { name } = command {
+                        labels.entry(name.clone()).or_insert(index);
+                    }
+                }
+                (SceneId::new(id.clone()), labels)
+            })
+            .collect()
+    }
+
     /// Get the current scene data
     pub fn get_current_scene_data(&self) -> Option<&Scene> {
         self.current_scene
@@ -37,16 +92,94 @@ impl ScenarioRuntime {
             .and_then(|scene| scene.commands.get(self.command_index))
     }
 
+    /// Seed used to shuffle choices
+    pub fn choice_seed(&self) -> u64 {
+        self.choice_seed
+    }
+
+    /// Set the seed used to shuffle choices
+    ///
+    /// A shuffled choice shown at the same position with the same seed is
+    /// always in the same order. Saves record the seed.
+    pub fn set_choice_seed(&mut self, seed: u64) {
+        self.choice_seed = seed;
+    }
+
+    /// Display order of the choice at the current position
+    ///
+    /// Returns indices into the authored options in the order they are shown,
+    /// with unavailable options left out. Returns `None` if the current command
+    /// is not a choice.
+    pub fn choice_display_order(&self) -> Option<Vec<usize>> {
+        let scene = self.current_scene.as_ref()?;
+        let ScenarioCommand::ShowChoice { choice } = self.get_current_command()? else {
+            return None;
+        };
+
+        // Keep the order the player saw before saving
+        if let Some((saved_scene, saved_index, order)) = &self.saved_choice_order
+            && saved_scene == scene
+            && *saved_index == self.command_index
+            && order.iter().all(|&index| index < choice.options.len())
+        {
+            return Some(order.clone());
+        }
+
+        let seed = self.choice_seed_at(scene, self.command_index);
+        Some(choice.display_order(|cond| self.evaluate_condition(cond), seed))
+    }
+
+    /// Options of the choice at the current position, in display order
+    ///
+    /// Returns an empty list if the current command is not a choice.
+    pub fn displayed_choices(&self) -> Vec<ChoiceOption> {
+        let Some(ScenarioCommand::ShowChoice { choice }) = self.get_current_command() else {
+            return Vec::new();
+        };
+        self.choice_display_order()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|index| choice.options.get(index).cloned())
+            .collect()
+    }
+
+    /// Whether each option of [`Self::displayed_choices`] is disabled
+    ///
+    /// Disabled options are shown greyed out and cannot be selected.
+    pub fn displayed_choices_disabled(&self) -> Vec<bool> {
+        self.displayed_choices()
+            .iter()
+            .map(|option| option.is_disabled(|cond| self.evaluate_condition(cond)))
+            .collect()
+    }
+
+    /// Shuffle seed for the choice at a scene position
+    ///
+    /// Mixes the runtime seed with the position (FNV-1a) so each choice of a
+    /// playthrough gets its own order.
+    fn choice_seed_at(&self, scene: &SceneId, command_index: usize) -> u64 {
+        let position = (command_index as u64).to_le_bytes();
+        scene
+            .as_str()
+            .bytes()
+            .chain(position)
+            .fold(0xCBF2_9CE4_8422_2325 ^ self.choice_seed, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+            })
+    }
+
     /// Handle choice selection
     ///
     /// # Arguments
-    /// * `choice_index` - The index of the selected choice
+    /// * `choice_index` - The index of the selected choice, in display order
+    ///   (see [`Self::choice_display_order`])
     ///
     /// # Returns
     /// Returns (exit_transition, entry_transition) for the scene change
     ///
     /// # Errors
-    /// Returns an error if the choice index is invalid
+    /// Returns an error if the choice index is invalid or the option is
+    /// disabled
     pub fn select_choice(
         &mut self,
         choice_index: usize,
@@ -60,13 +193,24 @@ impl ScenarioRuntime {
             .clone();
 
         if let ScenarioCommand::ShowChoice { choice } = command {
-            let selected_option = choice.options.get(choice_index).ok_or_else(|| {
-                EngineError::ScenarioExecution(format!(
-                    "Invalid choice index: {} (max: {})",
-                    choice_index,
-                    choice.options.len()
-                ))
-            })?;
+            let order = self.choice_display_order().unwrap_or_default();
+            let selected_option = order
+                .get(choice_index)
+                .and_then(|&index| choice.options.get(index))
+                .ok_or_else(|| {
+                    EngineError::ScenarioExecution(format!(
+                        "Invalid choice index: {} (max: {})",
+                        choice_index,
+                        order.len()
+                    ))
+                })?;
+            if selected_option.is_disabled(|cond| self.evaluate_condition(cond)) {
+                return Err(EngineError::ScenarioExecution(format!(
+                    "Choice '{}' is disabled",
+                    selected_option.text
+                )));
+            }
+            self.saved_choice_order = None;
 
             // Set flags associated with this choice
             for flag_name in &selected_option.flags_to_set {
@@ -84,4 +228,27 @@ impl ScenarioRuntime {
             ))
         }
     }
+
+    /// Store the name entered for the current `InputName` command
+    ///
+    /// The name is trimmed and cut to the command's maximum length; an empty
+    /// name falls back to the command's default. Does not advance.
+    ///
+    /// # Errors
+    /// Returns an error if the current command is not `InputName`
+    pub fn submit_name(&mut self, name: &str) -> EngineResult<()> {
+        let state = self
+            .get_current_command()
+            .and_then(NameInputState::from_command)
+            .ok_or_else(|| {
+                EngineError::ScenarioExecution("Current command is not a name input".to_string())
+            })?;
+
+        let name = state.resolve(name);
+        self.variable_store.set(
+            VariableId::new(state.variable.clone()),
+            VariableValue::String(name),
+        );
+        Ok(())
+    }
 }
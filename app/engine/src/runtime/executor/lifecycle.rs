@@ -4,6 +4,7 @@ impl ScenarioRuntime {
     /// Create a new scenario runtime from a scenario
     pub fn new(scenario: Scenario) -> Self {
         Self {
+            labels: Self::index_labels(&scenario),
             scenario,
             current_scene: None,
             command_index: 0,
@@ -11,15 +12,41 @@ impl ScenarioRuntime {
             variable_store: VariableStore::default(),
             read_history: ReadHistory::default(),
             backlog: Backlog::new(),
+            display_history: HashMap::new(),
+            last_display_snapshot: None,
             scene_stack: Vec::new(),
+            call_frames: Vec::new(),
             displayed_characters: HashMap::new(),
             displayed_characters_dirty: false,
             current_background: None,
             current_cg: None,
+            current_bgm: None,
+            active_effects: Vec::new(),
+            hud_visibility: HudVisibility::default(),
+            rollback_history: RollbackHistory::default(),
             unlock_data: None,
+            achievements: None,
+            unlocked_achievements: Vec::new(),
+            pending_scenario_jump: None,
+            checkpoint: None,
+            choice_seed: Self::fresh_choice_seed(),
+            saved_choice_order: None,
+            on_command: None,
+            voice_resolver: None,
+            dialogue_languages: DialogueLanguages::default(),
+            #[cfg(feature = "scripting")]
+            script_host: ScriptHost::new(),
         }
     }
 
+    /// Seed for a new playthrough, taken from the clock
+    fn fresh_choice_seed() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or_default()
+    }
+
     /// Load a scenario from a TOML file using AssetLoader
     ///
     /// # Arguments
@@ -57,10 +84,153 @@ impl ScenarioRuntime {
         let scene_id = SceneId::new(start_scene_id);
         self.current_scene = Some(scene_id.clone());
         self.command_index = 0;
+        self.active_effects.clear();
+        self.rollback_history.clear();
+        self.record_checkpoint();
+        self.notify_command_reached();
+
+        Ok(())
+    }
+
+    /// Replace the running scenario, keeping flags, variables and history
+    ///
+    /// Used to continue in another scenario file of the project. Read history,
+    /// backlog, unlock data and persistent effects carry over; the call stack,
+    /// characters, CG and scene-scoped effects are cleared. Execution starts at `scene`, or at the new scenario's start scene.
+    ///
+    /// # Errors
+    /// Returns an error if the scene doesn't exist in the new scenario. The
+    /// runtime is left unchanged in that case.
+    pub fn switch_scenario(&mut self, scenario: Scenario, scene: Option<&str>) -> EngineResult<()> {
+        let scene_id = scene.unwrap_or(&scenario.start_scene).to_string();
+        if !scenario.scenes.contains_key(&scene_id) {
+            return Err(EngineError::ScenarioExecution(format!(
+                "Scene '{}' not found in scenario '{}'",
+                scene_id, scenario.metadata.id
+            )));
+        }
+
+        self.labels = Self::index_labels(&scenario);
+        self.scenario = scenario;
+        self.current_scene = Some(SceneId::new(scene_id));
+        self.command_index = 0;
+        self.scene_stack.clear();
+        self.call_frames.clear();
+        self.displayed_characters.clear();
+        self.displayed_characters_dirty = true;
+        self.current_cg = None;
+        self.end_scene_effects();
+        self.pending_scenario_jump = None;
+        self.rollback_history.clear();
+        self.record_checkpoint();
+        self.notify_command_reached();
 
         Ok(())
     }
 
+    /// Replace the running scenario with one loaded from a TOML file
+    ///
+    /// See [`Self::switch_scenario`] for what carries over.
+    pub fn switch_scenario_from_toml<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        scene: Option<&str>,
+    ) -> EngineResult<()> {
+        let mut loader = AssetLoader::new("");
+        let scenario = loader.load_scenario(path)?.clone();
+        self.switch_scenario(scenario, scene)
+    }
+
+    /// Replace the running scenario with an edited version of it
+    ///
+    /// Used to hot reload the scenario file while playing. All state carries
+    /// over; playback stays at the current command if it is unchanged, follows
+    /// it if it moved within its scene, and otherwise continues at the same
+    /// index (see [`ScenarioReload`]). Rollback history is cleared, as its
+    /// positions may no longer exist.
+    ///
+    /// # Errors
+    /// Returns an error if the current scene was removed and the new
+    /// scenario's start scene does not exist. The runtime is left unchanged
+    /// in that case.
+    pub fn reload_scenario(&mut self, scenario: Scenario) -> EngineResult<ScenarioReload> {
+        let current = self.get_current_command().cloned();
+        let new_commands = self
+            .current_scene
+            .as_ref()
+            .and_then(|scene| scenario.scenes.get(scene.as_str()))
+            .map(|scene| &scene.commands)
+            .filter(|commands| !commands.is_empty());
+
+        let index = self.command_index;
+        let (reload, new_index) = match new_commands {
+            Some(commands) if current.as_ref() == commands.get(index) => {
+                (ScenarioReload::Unchanged, index)
+            }
+            Some(commands) => {
+                // The nearest copy of the current command, if it still exists
+                let moved = current.as_ref().and_then(|current| {
+                    commands
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, command)| *command == current)
+                        .map(|(position, _)| position)
+                        .min_by_key(|position| position.abs_diff(index))
+                });
+                match moved {
+                    Some(to) => (ScenarioReload::Moved { from: index, to }, to),
+                    None => {
+                        let to = index.min(commands.len() - 1);
+                        (ScenarioReload::Edited { index: to }, to)
+                    }
+                }
+            }
+            None => {
+                if !scenario.scenes.contains_key(&scenario.start_scene) {
+                    return Err(EngineError::ScenarioExecution(format!(
+                        "Start scene '{}' not found in reloaded scenario",
+                        scenario.start_scene
+                    )));
+                }
+                self.current_scene = Some(SceneId::new(scenario.start_scene.clone()));
+                self.scene_stack.clear();
+                self.call_frames.clear();
+                (ScenarioReload::SceneRemoved, 0)
+            }
+        };
+
+        self.labels = Self::index_labels(&scenario);
+        self.scenario = scenario;
+        self.command_index = new_index;
+        if reload != ScenarioReload::Unchanged {
+            self.saved_choice_order = None;
+        }
+        self.rollback_history.clear();
+        Ok(reload)
+    }
+
+    /// Replace the running scenario with an edited version loaded from a TOML file
+    ///
+    /// See [`Self::reload_scenario`].
+    pub fn reload_scenario_from_toml<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> EngineResult<ScenarioReload> {
+        let mut loader = AssetLoader::new("");
+        let scenario = loader.load_scenario(path)?.clone();
+        self.reload_scenario(scenario)
+    }
+
+    /// Take the scenario jump requested by the last JumpToScenario command
+    pub fn take_scenario_jump(&mut self) -> Option<ScenarioJump> {
+        self.pending_scenario_jump.take()
+    }
+
+    /// Check if a JumpToScenario command is waiting to be resolved
+    pub fn has_scenario_jump(&self) -> bool {
+        self.pending_scenario_jump.is_some()
+    }
+
     /// Jump to a specific scene
     ///
     /// # Arguments
@@ -74,6 +244,19 @@ impl ScenarioRuntime {
     pub fn jump_to_scene(
         &mut self,
         scene_id: &SceneId,
+    ) -> EngineResult<(Option<Transition>, Option<Transition>)> {
+        let transitions = self.change_scene(scene_id)?;
+        self.record_checkpoint();
+        self.notify_command_reached();
+        Ok(transitions)
+    }
+
+    /// Move to the start of a scene without recording a checkpoint
+    ///
+    /// Used by Return, which resumes a scene instead of entering it.
+    pub(super) fn change_scene(
+        &mut self,
+        scene_id: &SceneId,
     ) -> EngineResult<(Option<Transition>, Option<Transition>)> {
         // Validate scene exists
         if !self.scenario.scenes.contains_key(scene_id.as_str()) {
@@ -99,7 +282,14 @@ impl ScenarioRuntime {
 
         self.current_scene = Some(scene_id.clone());
         self.command_index = 0;
+        self.end_scene_effects();
 
         Ok((exit_transition, entry_transition))
     }
+
+    /// Stop the effects scoped to the scene being left
+    fn end_scene_effects(&mut self) {
+        self.active_effects
+            .retain(|effect| effect.lifetime == EffectLifetime::Persistent);
+    }
 }
@@ -29,6 +29,7 @@ impl ScenarioRuntime {
             slot,
             timestamp: 0,      // Caller should set this
             play_time_secs: 0, // Caller should set this
+            scenario_id: Some(self.scenario.metadata.id.clone()),
             current_scene: self
                 .current_scene
                 .clone()
@@ -36,13 +37,20 @@ impl ScenarioRuntime {
             command_index: self.command_index,
             flags: self.flag_store.to_save_format(),
             variables: self.variable_store.to_save_format(),
+            text_variables: self.variable_store.to_text_save_format(),
             read_scenes: vec![], // Deprecated field
             read_history: self.read_history.clone(),
             scene_stack: self.scene_stack.clone(),
+            call_frames: self.call_frames.clone(),
             current_background: self.current_background.as_ref().map(|bg| bg.0.to_string()),
             current_cg: self.current_cg.as_ref().map(|cg| cg.0.to_string()),
             displayed_characters,
-            thumbnail_path: None, // Thumbnail will be added later during save
+            thumbnail_path: None,
+            checkpoint: self.checkpoint.clone(), // Thumbnail will be added later during save
+            choice_seed: self.choice_seed,
+            choice_order: self.choice_display_order(),
+            active_effects: self.active_effects.clone(),
+            hud_visibility: self.hud_visibility.clone(),
         }
     }
 
@@ -51,6 +59,16 @@ impl ScenarioRuntime {
     /// This restores the runtime state from saved data.
     /// The scenario must already be loaded before calling this.
     pub fn from_save_data(&mut self, save_data: &crate::save::SaveData) -> EngineResult<()> {
+        // Validate that the save data belongs to this scenario
+        if let Some(scenario_id) = &save_data.scenario_id
+            && *scenario_id != self.scenario.metadata.id
+        {
+            return Err(EngineError::ScenarioExecution(format!(
+                "Save data belongs to scenario '{}', but '{}' is loaded",
+                scenario_id, self.scenario.metadata.id
+            )));
+        }
+
         // Validate that the save data's current scene exists
         if !save_data.current_scene.as_str().is_empty()
             && !self
@@ -77,12 +95,29 @@ impl ScenarioRuntime {
 
         // Restore variables
         self.variable_store = VariableStore::from_save_format(&save_data.variables);
+        self.variable_store
+            .load_text_save_format(&save_data.text_variables);
 
         // Restore read history
         self.read_history = save_data.read_history.clone();
 
         // Restore scene stack
         self.scene_stack = save_data.scene_stack.clone();
+        self.call_frames = save_data.call_frames.clone();
+        self.call_frames.truncate(self.scene_stack.len());
+
+        // Restore the scene-entry checkpoint
+        self.checkpoint = save_data.checkpoint.clone();
+
+        // Lines played before the save can't be rolled back to
+        self.rollback_history.clear();
+
+        // Restore choice shuffling, keeping the order of a choice on screen
+        self.choice_seed = save_data.choice_seed;
+        self.saved_choice_order = match (&self.current_scene, &save_data.choice_order) {
+            (Some(scene), Some(order)) => Some((scene.clone(), self.command_index, order.clone())),
+            _ => None,
+        };
 
         // Restore display state: background
         self.current_background = save_data
@@ -96,6 +131,12 @@ impl ScenarioRuntime {
             .as_ref()
             .map(|cg| AssetRef::from(cg.clone()));
 
+        // Restore display state: screen effects (a save made mid-storm keeps its rain)
+        self.active_effects = save_data.active_effects.clone();
+
+        // Restore display state: HUD widgets shown by SetHudVisible
+        self.hud_visibility = save_data.hud_visibility.clone();
+
         // Restore display state: displayed characters
         self.displayed_characters = save_data
             .displayed_characters
@@ -115,4 +156,55 @@ impl ScenarioRuntime {
 
         Ok(())
     }
+
+    /// State snapshot taken at the last scene entry
+    pub fn checkpoint(&self) -> Option<&SceneCheckpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Snapshot the current state as the scene-entry checkpoint
+    pub(super) fn record_checkpoint(&mut self) {
+        self.checkpoint = self.current_scene.as_ref().map(|scene| SceneCheckpoint {
+            scene: scene.clone(),
+            flags: self.flag_store.to_save_format(),
+            variables: self.variable_store.to_save_format(),
+            text_variables: self.variable_store.to_text_save_format(),
+            scene_stack: self.scene_stack.clone(),
+            current_background: self.current_background.as_ref().map(|bg| bg.0.to_string()),
+        });
+    }
+
+    /// Restart from the scene-entry checkpoint of a save
+    ///
+    /// Used when the saved command index can't be restored after a scenario
+    /// update. Flags, variables, the call stack and the background are reset
+    /// to their state when the scene was entered; read history is kept.
+    ///
+    /// # Errors
+    /// Returns an error if the save has no checkpoint or its scene no longer exists.
+    pub fn restart_from_checkpoint(
+        &mut self,
+        save_data: &crate::save::SaveData,
+    ) -> EngineResult<()> {
+        let restarted = save_data.at_checkpoint(&self.scenario).ok_or_else(|| {
+            EngineError::ScenarioExecution(
+                "Save data has no usable scene-entry checkpoint".to_string(),
+            )
+        })?;
+        self.from_save_data(&restarted)
+    }
+
+    /// Restore runtime state at the start of the nearest scene that exists
+    ///
+    /// Best-effort load for saves made with an older version of the scenario
+    /// (see [`crate::save::SaveData::compatibility_issues`]). Flags, variables
+    /// and history are restored; playback restarts at the beginning of the
+    /// saved scene, or of the nearest scene that still exists.
+    pub fn from_save_data_at_scene_start(
+        &mut self,
+        save_data: &crate::save::SaveData,
+    ) -> EngineResult<()> {
+        let repaired = save_data.at_nearest_scene_start(&self.scenario);
+        self.from_save_data(&repaired)
+    }
 }
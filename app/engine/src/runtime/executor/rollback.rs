@@ -0,0 +1,208 @@
+use super::*;
+use std::collections::VecDeque;
+
+/// Runtime state at a displayed dialogue line
+#[derive(Debug, Clone)]
+pub(super) struct RollbackSnapshot {
+    scene: SceneId,
+    command_index: usize,
+    flags: FlagStore,
+    variables: VariableStore,
+    scene_stack: Vec<(SceneId, usize)>,
+    call_frames: Vec<CallFrame>,
+    displayed_characters: HashMap<String, DisplayedCharacter>,
+    background: Option<AssetRef>,
+    cg: Option<AssetRef>,
+    bgm: Option<PlayingBgm>,
+    effects: Vec<ActiveEffect>,
+    hud_visibility: HudVisibility,
+}
+
+/// Dialogue lines the player can roll back to, oldest first
+///
+/// `cursor` is the index of the snapshot on screen while rolled back, and
+/// `None` while playing the newest line. `last_choice` is the state at the
+/// most recent choice, with the number of line snapshots recorded before it.
+#[derive(Debug, Clone, Default)]
+pub(super) struct RollbackHistory {
+    snapshots: VecDeque<RollbackSnapshot>,
+    cursor: Option<usize>,
+    last_choice: Option<(RollbackSnapshot, usize)>,
+}
+
+impl RollbackHistory {
+    /// Index of the snapshot on screen
+    fn position(&self) -> Option<usize> {
+        self.cursor.or_else(|| self.snapshots.len().checked_sub(1))
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.snapshots.clear();
+        self.cursor = None;
+        self.last_choice = None;
+    }
+}
+
+impl ScenarioRuntime {
+    /// Check if there is an earlier dialogue line to roll back to
+    pub fn can_rollback(&self) -> bool {
+        self.rollback_history
+            .position()
+            .is_some_and(|position| position > 0)
+    }
+
+    /// Check if playback is rolled back and can move forward again
+    pub fn can_roll_forward(&self) -> bool {
+        self.rollback_history.cursor.is_some()
+    }
+
+    /// Roll back to the previous dialogue line
+    ///
+    /// Restores flags, variables, the call stack, displayed characters,
+    /// background, CG, BGM and screen effects as they were when that line was
+    /// displayed.
+    /// Read history and the backlog are kept. Advancing normally from a
+    /// rolled-back line discards the lines after it and plays on from there.
+    ///
+    /// Returns `false` if there is no earlier line.
+    pub fn rollback(&mut self) -> bool {
+        let Some(target) = self
+            .rollback_history
+            .position()
+            .and_then(|position| position.checked_sub(1))
+        else {
+            return false;
+        };
+        self.restore_rollback_snapshot(target)
+    }
+
+    /// Roll forward to the next recorded dialogue line
+    ///
+    /// Returns `false` if playback is not rolled back.
+    pub fn roll_forward(&mut self) -> bool {
+        let Some(target) = self
+            .rollback_history
+            .cursor
+            .map(|cursor| cursor.saturating_add(1))
+        else {
+            return false;
+        };
+        self.restore_rollback_snapshot(target)
+    }
+
+    /// Check if a choice has been reached that [`Self::return_to_last_choice`] can go back to
+    pub fn can_return_to_choice(&self) -> bool {
+        self.rollback_history.last_choice.is_some()
+    }
+
+    /// Go back to the most recent choice so it can be picked again
+    ///
+    /// Restores the state as it was when the choice was shown, like
+    /// [`Self::rollback`]; the current command becomes the choice. Rollback
+    /// points recorded after the choice are discarded.
+    ///
+    /// Returns `false` if no choice has been reached since the scenario started.
+    pub fn return_to_last_choice(&mut self) -> bool {
+        let Some((snapshot, line_count)) = self.rollback_history.last_choice.clone() else {
+            return false;
+        };
+        let history = &mut self.rollback_history;
+        history.snapshots.truncate(line_count);
+        history.cursor = None;
+        self.restore_snapshot(snapshot);
+        self.notify_command_reached();
+        true
+    }
+
+    /// Snapshot the state at the choice being displayed
+    pub(super) fn record_choice_point(&mut self) {
+        let Some(snapshot) = self.snapshot() else {
+            return;
+        };
+        let history = &mut self.rollback_history;
+        let line_count = history
+            .cursor
+            .map_or(history.snapshots.len(), |cursor| cursor.saturating_add(1));
+        history.last_choice = Some((snapshot, line_count));
+    }
+
+    /// State at the current command
+    fn snapshot(&self) -> Option<RollbackSnapshot> {
+        Some(RollbackSnapshot {
+            scene: self.current_scene.clone()?,
+            command_index: self.command_index,
+            flags: self.flag_store.clone(),
+            variables: self.variable_store.clone(),
+            scene_stack: self.scene_stack.clone(),
+            call_frames: self.call_frames.clone(),
+            displayed_characters: self.displayed_characters.clone(),
+            background: self.current_background.clone(),
+            cg: self.current_cg.clone(),
+            bgm: self.current_bgm.clone(),
+            effects: self.active_effects.clone(),
+            hud_visibility: self.hud_visibility.clone(),
+        })
+    }
+
+    /// Snapshot the state at the dialogue line being displayed
+    pub(super) fn record_rollback_point(&mut self) {
+        let Some(snapshot) = self.snapshot() else {
+            return;
+        };
+
+        let history = &mut self.rollback_history;
+        if let Some(cursor) = history.cursor.take() {
+            // Playing on from a rolled-back line replaces the lines after it
+            history.snapshots.truncate(cursor.saturating_add(1));
+        }
+        if history.snapshots.back().is_some_and(|last| {
+            last.scene == snapshot.scene && last.command_index == snapshot.command_index
+        }) {
+            history.snapshots.pop_back();
+        }
+
+        history.snapshots.push_back(snapshot);
+        while history.snapshots.len() > MAX_ROLLBACK_STEPS {
+            history.snapshots.pop_front();
+            // Lines dropped from the front also precede the last choice
+            if let Some((_, line_count)) = &mut history.last_choice {
+                *line_count = line_count.saturating_sub(1);
+            }
+        }
+    }
+
+    fn restore_rollback_snapshot(&mut self, index: usize) -> bool {
+        let history = &mut self.rollback_history;
+        let Some(snapshot) = history.snapshots.get(index).cloned() else {
+            return false;
+        };
+        history.cursor = (index.saturating_add(1) < history.snapshots.len()).then_some(index);
+        self.restore_snapshot(snapshot);
+        true
+    }
+
+    /// Put the runtime back into a snapshot's state
+    fn restore_snapshot(&mut self, snapshot: RollbackSnapshot) {
+        self.current_scene = Some(snapshot.scene);
+        self.command_index = snapshot.command_index;
+        self.flag_store = snapshot.flags;
+        self.variable_store = snapshot.variables;
+        self.scene_stack = snapshot.scene_stack;
+        self.call_frames = snapshot.call_frames;
+        self.displayed_characters = snapshot
+            .displayed_characters
+            .into_iter()
+            .map(|(id, mut character)| {
+                character.transition = Transition::instant();
+                (id, character)
+            })
+            .collect();
+        self.displayed_characters_dirty = true;
+        self.current_background = snapshot.background;
+        self.current_cg = snapshot.cg;
+        self.current_bgm = snapshot.bgm;
+        self.active_effects = snapshot.effects;
+        self.hud_visibility = snapshot.hud_visibility;
+        self.pending_scenario_jump = None;
+    }
+}
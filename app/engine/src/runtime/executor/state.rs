@@ -26,11 +26,134 @@ impl ScenarioRuntime {
         self.current_cg.as_ref()
     }
 
+    /// Get the background music started by the last PlayBgm command
+    pub fn current_bgm(&self) -> Option<&PlayingBgm> {
+        self.current_bgm.as_ref()
+    }
+
+    /// Get the screen effects that are running, in the order they were started
+    pub fn active_effects(&self) -> &[ActiveEffect] {
+        &self.active_effects
+    }
+
+    /// Get which HUD widgets are shown
+    pub fn hud_visibility(&self) -> &HudVisibility {
+        &self.hud_visibility
+    }
+
+    /// Advance running effects by `delta` seconds, stopping those whose
+    /// duration has passed
+    ///
+    /// Returns: true if any effect stopped
+    pub fn update_effects(&mut self, delta: f32) -> bool {
+        let running = self.active_effects.len();
+        for effect in &mut self.active_effects {
+            effect.elapsed += delta;
+        }
+        self.active_effects.retain(|effect| {
+            effect
+                .duration
+                .is_none_or(|duration| effect.elapsed < duration)
+        });
+        self.active_effects.len() != running
+    }
+
+    /// Set the languages dialogue is displayed and logged in
+    pub fn set_dialogue_languages(&mut self, languages: DialogueLanguages) {
+        self.dialogue_languages = languages;
+    }
+
+    /// Get the languages dialogue is displayed and logged in
+    pub fn dialogue_languages(&self) -> &DialogueLanguages {
+        &self.dialogue_languages
+    }
+
     /// Set the unlock data reference
     pub fn set_unlock_data(&mut self, unlock_data: Arc<Mutex<UnlockData>>) {
         self.unlock_data = Some(unlock_data);
     }
 
+    /// Set the resolver used for lines without an explicit voice
+    pub fn set_voice_resolver(&mut self, resolver: VoiceResolver) {
+        self.voice_resolver = Some(resolver);
+    }
+
+    /// Set how many compiled `Script` commands are kept for reuse
+    #[cfg(feature = "scripting")]
+    pub fn set_script_cache_capacity(&mut self, capacity: usize) {
+        self.script_host.set_capacity(capacity);
+    }
+
+    /// Get the voice of the current dialogue line
+    ///
+    /// Returns the asset of a `PlayVoice` command directly before the line, or
+    /// the file matched by the voice resolver's naming convention. Returns
+    /// `None` when the current command is not a dialogue line or no voice applies.
+    pub fn current_voice(&self) -> Option<AssetRef> {
+        self.current_voice_with_volume().map(|(asset, _)| asset)
+    }
+
+    /// Get the voice of the current dialogue line with its volume multiplier
+    ///
+    /// Same as [`current_voice`](Self::current_voice); the volume comes from
+    /// the `PlayVoice` command, voices matched by convention play at 1.0.
+    pub fn current_voice_with_volume(&self) -> Option<(AssetRef, f32)> {
+        let scene = self.get_current_scene_data()?;
+        if !matches!(
+            scene.commands.get(self.command_index),
+            Some(ScenarioCommand::Dialogue { .. })
+        ) {
+            return None;
+        }
+
+        if let Some(ScenarioCommand::PlayVoice { asset, volume }) = self
+            .command_index
+            .checked_sub(1)
+            .and_then(|index| scene.commands.get(index))
+        {
+            return Some((asset.clone(), *volume));
+        }
+
+        let voice = self
+            .voice_resolver
+            .as_ref()?
+            .resolve(scene, self.command_index)?;
+        Some((AssetRef::from(voice.path), 1.0))
+    }
+
+    /// Assets of the commands ahead of the current position
+    ///
+    /// See [`crate::asset::upcoming_assets`]; empty when no scene is playing.
+    pub fn upcoming_assets(
+        &self,
+        scope: crate::asset::PrefetchScope,
+    ) -> Vec<crate::asset::PreloadRequest> {
+        match &self.current_scene {
+            Some(scene) => crate::asset::upcoming_assets(
+                &self.scenario,
+                scene.as_str(),
+                self.command_index,
+                scope,
+            ),
+            None => Vec::new(),
+        }
+    }
+
+    /// Set a hook called with each command the runtime reaches
+    ///
+    /// The hook runs when playback moves onto a command (start, advance, scene
+    /// jumps, Call/Return and scenario switches), before the command executes.
+    /// Plugins use it to act on dialogue and scene `tags` without engine changes.
+    /// Loading a save does not call it.
+    pub fn set_on_command(&mut self, hook: impl FnMut(&CommandEvent<'_>) + Send + Sync + 'static) {
+        self.on_command = Some(Box::new(hook));
+    }
+
+    /// Remove the `on_command` hook
+    pub fn clear_on_command(&mut self) {
+        self.on_command = None;
+    }
+
     /// Get the current command index
     pub fn command_index(&self) -> usize {
         self.command_index
@@ -83,7 +206,8 @@ impl ScenarioRuntime {
 
     /// Add a dialogue to the backlog
     ///
-    /// This should be called when a dialogue is displayed to the player.
+    /// This should be called when a dialogue is displayed to the player. It
+    /// also records the line as a rollback point (see [`Self::rollback`]).
     pub fn add_to_backlog(
         &mut self,
         scene_id: SceneId,
@@ -91,7 +215,24 @@ impl ScenarioRuntime {
         speaker: narrative_core::Speaker,
         text: impl Into<String>,
     ) {
-        let entry = BacklogEntry::new(scene_id, command_index, speaker, text);
+        self.add_to_backlog_with_translation(scene_id, command_index, speaker, text, None);
+    }
+
+    /// Add a dialogue to the backlog with the translation shown beside it
+    ///
+    /// Used in dual-language mode so the backlog keeps both text tracks.
+    pub fn add_to_backlog_with_translation(
+        &mut self,
+        scene_id: SceneId,
+        command_index: usize,
+        speaker: narrative_core::Speaker,
+        text: impl Into<String>,
+        translation: Option<String>,
+    ) {
+        self.record_display_snapshot(scene_id.clone(), command_index);
+        self.record_rollback_point();
+        let entry =
+            BacklogEntry::new(scene_id, command_index, speaker, text).with_translation(translation);
         self.backlog.add_entry(entry);
     }
 }
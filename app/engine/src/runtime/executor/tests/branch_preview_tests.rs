@@ -0,0 +1,101 @@
+//! Tests for previewing choice and If branches
+
+use super::*;
+use narrative_core::{CompareOp, Condition};
+
+fn create_branching_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "start");
+
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::If {
+        condition: Condition::flag("met_alice", true),
+        then_commands: vec![ScenarioCommand::SetFlag {
+            flag_name: "greeted".to_string(),
+            value: true,
+        }],
+        else_commands: vec![],
+    });
+    scene.add_command(ScenarioCommand::ShowChoice {
+        choice: Choice::new(vec![
+            ChoiceOption::new("Go home", "home").with_flag("went_home"),
+            ChoiceOption::new("Visit Alice", "alice")
+                .with_condition(Condition::flag("met_alice", true))
+                .with_condition(Condition::variable(
+                    "affection",
+                    CompareOp::GreaterOrEqual,
+                    VariableValue::Int(5),
+                )),
+        ]),
+    });
+    scenario.add_scene("start", scene);
+    scenario.add_scene("home", Scene::new("home", "Home"));
+    scenario.add_scene("alice", Scene::new("alice", "Alice"));
+
+    scenario
+}
+
+#[test]
+fn test_preview_branches_reports_destinations_and_conditions() {
+    let mut runtime = ScenarioRuntime::new(create_branching_scenario());
+    runtime.start().unwrap();
+    runtime.flags_mut().set(FlagId::new("met_alice"), true);
+    runtime
+        .variables_mut()
+        .set(VariableId::new("affection"), VariableValue::Int(3));
+
+    let previews = runtime.preview_branches(&SceneId::new("start")).unwrap();
+    assert_eq!(previews.len(), 4);
+
+    let [then_arm, else_arm, home, alice] = previews.as_slice() else {
+        panic!("Expected four branches, got {previews:?}");
+    };
+    assert_eq!(then_arm.branch, BranchKind::Then);
+    assert!(then_arm.taken);
+    assert_eq!(then_arm.destination, SceneId::new("start"));
+    assert_eq!(else_arm.branch, BranchKind::Else);
+    assert!(!else_arm.taken);
+
+    assert_eq!(home.command_index, 1);
+    assert_eq!(home.destination, SceneId::new("home"));
+    assert!(home.taken);
+    assert!(home.conditions.is_empty());
+
+    assert_eq!(alice.destination, SceneId::new("alice"));
+    assert!(!alice.taken);
+    let passed: Vec<bool> = alice.conditions.iter().map(|check| check.passed).collect();
+    assert_eq!(passed, vec![true, false]);
+}
+
+#[test]
+fn test_preview_branches_has_no_side_effects() {
+    let mut runtime = ScenarioRuntime::new(create_branching_scenario());
+    runtime.start().unwrap();
+    runtime.advance_command();
+
+    let previews = runtime.preview_current_branches();
+    assert_eq!(previews.len(), 2);
+    assert!(
+        previews
+            .iter()
+            .all(|preview| matches!(preview.branch, BranchKind::ChoiceOption { .. }))
+    );
+
+    assert!(!runtime.flags().is_set(&FlagId::new("went_home")));
+    assert!(!runtime.flags().is_set(&FlagId::new("greeted")));
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("start")));
+    assert_eq!(runtime.command_index(), 1);
+}
+
+#[test]
+fn test_preview_branches_unknown_scene() {
+    let runtime = ScenarioRuntime::new(create_branching_scenario());
+    assert!(runtime.preview_branches(&SceneId::new("missing")).is_err());
+}
+
+#[test]
+fn test_preview_current_branches_on_other_command() {
+    let mut runtime = ScenarioRuntime::new(create_test_scenario());
+    runtime.start().unwrap();
+    assert!(runtime.preview_current_branches().is_empty());
+}
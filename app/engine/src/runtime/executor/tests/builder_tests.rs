@@ -0,0 +1,62 @@
+//! Tests for starting a runtime partway through a scenario
+
+use super::*;
+
+#[test]
+fn test_builder_starts_at_scene_with_state() {
+    let runtime = ScenarioRuntime::builder(create_test_scenario())
+        .with_flags([("met_alice", true), ("left_town", false)])
+        .with_variable("affection", VariableValue::Int(7))
+        .at_scene("scene2")
+        .build()
+        .expect("scene2 exists");
+
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene2")));
+    assert_eq!(runtime.command_index(), 0);
+    assert!(runtime.flags().get(&FlagId::new("met_alice")));
+    assert!(!runtime.flags().get(&FlagId::new("left_town")));
+    assert_eq!(
+        runtime.variables().get(&VariableId::new("affection")),
+        Some(&VariableValue::Int(7))
+    );
+}
+
+#[test]
+fn test_builder_defaults_to_start_scene() {
+    let runtime = ScenarioRuntime::builder(create_test_scenario())
+        .with_choice_seed(42)
+        .build()
+        .expect("start scene exists");
+
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene1")));
+    assert_eq!(runtime.choice_seed(), 42);
+}
+
+#[test]
+fn test_builder_starts_at_command() {
+    let mut runtime = ScenarioRuntime::builder(create_test_scenario())
+        .at_command(1)
+        .build()
+        .expect("scene1 has two commands");
+
+    assert!(matches!(
+        runtime.get_current_command(),
+        Some(ScenarioCommand::SetFlag { .. })
+    ));
+    runtime.execute_current_command().expect("SetFlag runs");
+    assert!(runtime.flags().get(&FlagId::new("test_flag")));
+}
+
+#[test]
+fn test_builder_rejects_invalid_position() {
+    let missing = ScenarioRuntime::builder(create_test_scenario())
+        .at_scene("missing")
+        .build();
+    assert!(missing.is_err());
+
+    let past_end = ScenarioRuntime::builder(create_test_scenario())
+        .at_scene("scene2")
+        .at_command(10)
+        .build();
+    assert!(past_end.is_err());
+}
@@ -74,6 +74,8 @@ fn test_call_with_invalid_return_scene() {
     main_scene.add_command(ScenarioCommand::Call {
         scene_id: "sub".to_string(),
         return_scene: "nonexistent".to_string(), // Invalid return scene
+        args: BTreeMap::new(),
+        result: None,
     });
 
     let mut sub_scene = Scene::new("sub", "Sub");
@@ -102,7 +104,7 @@ fn test_return_on_empty_stack() {
     let mut scenario = Scenario::new(metadata, "main");
 
     let mut main_scene = Scene::new("main", "Main");
-    main_scene.add_command(ScenarioCommand::Return); // Return without Call
+    main_scene.add_command(ScenarioCommand::Return { value: None }); // Return without Call
 
     scenario.add_scene("main", main_scene);
 
@@ -132,6 +134,8 @@ fn test_nested_call_sequence() {
     scene_a.add_command(ScenarioCommand::Call {
         scene_id: "b".to_string(),
         return_scene: "a".to_string(),
+        args: BTreeMap::new(),
+        result: None,
     });
     scene_a.add_command(ScenarioCommand::Dialogue {
         dialogue: Dialogue::narrator("A after B"),
@@ -146,18 +150,20 @@ fn test_nested_call_sequence() {
     scene_b.add_command(ScenarioCommand::Call {
         scene_id: "c".to_string(),
         return_scene: "b".to_string(),
+        args: BTreeMap::new(),
+        result: None,
     });
     scene_b.add_command(ScenarioCommand::Dialogue {
         dialogue: Dialogue::narrator("B after C"),
     });
-    scene_b.add_command(ScenarioCommand::Return);
+    scene_b.add_command(ScenarioCommand::Return { value: None });
 
     // Scene C: Just return
     let mut scene_c = Scene::new("c", "C");
     scene_c.add_command(ScenarioCommand::Dialogue {
         dialogue: Dialogue::narrator("C"),
     });
-    scene_c.add_command(ScenarioCommand::Return);
+    scene_c.add_command(ScenarioCommand::Return { value: None });
 
     scenario.add_scene("a", scene_a);
     scenario.add_scene("b", scene_b);
@@ -227,6 +233,8 @@ fn test_call_stack_depth_limit() {
     recursive_scene.add_command(ScenarioCommand::Call {
         scene_id: "recursive".to_string(),
         return_scene: "recursive".to_string(),
+        args: BTreeMap::new(),
+        result: None,
     });
 
     scenario.add_scene("recursive", recursive_scene);
@@ -261,6 +269,8 @@ fn test_call_with_invalid_target_scene() {
     main_scene.add_command(ScenarioCommand::Call {
         scene_id: "nonexistent".to_string(), // Invalid target scene
         return_scene: "main".to_string(),
+        args: BTreeMap::new(),
+        result: None,
     });
 
     scenario.add_scene("main", main_scene);
@@ -278,3 +288,85 @@ fn test_call_with_invalid_target_scene() {
         assert!(error_msg.contains("Scene") && error_msg.contains("nonexistent"));
     }
 }
+
+fn create_shop_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "town");
+
+    let mut town = Scene::new("town", "Town");
+    town.add_command(ScenarioCommand::Call {
+        scene_id: "shop".to_string(),
+        return_scene: "town".to_string(),
+        args: BTreeMap::from([
+            ("item".to_string(), "'potion'".parse().unwrap()),
+            ("price".to_string(), "base_price * 2".parse().unwrap()),
+        ]),
+        result: Some("receipt".to_string()),
+    });
+    town.add_command(ScenarioCommand::End);
+    scenario.add_scene("town", town);
+
+    let mut shop = Scene::new("shop", "Shop");
+    shop.add_command(ScenarioCommand::Return {
+        value: Some("price - 5".parse().unwrap()),
+    });
+    scenario.add_scene("shop", shop);
+    scenario
+}
+
+#[test]
+fn test_call_binds_args_and_return_sets_result() {
+    let mut runtime = ScenarioRuntime::new(create_shop_scenario());
+    runtime.start().unwrap();
+    runtime
+        .variables_mut()
+        .set(VariableId::new("base_price"), VariableValue::Int(25));
+    runtime.variables_mut().set(
+        VariableId::new("item"),
+        VariableValue::String("sword".to_string()),
+    );
+
+    runtime.execute_current_command().unwrap(); // Call
+    assert_eq!(
+        runtime.variables().get(&VariableId::new("item")),
+        Some(&VariableValue::String("potion".to_string()))
+    );
+    assert_eq!(
+        runtime.variables().get(&VariableId::new("price")),
+        Some(&VariableValue::Int(50))
+    );
+
+    runtime.execute_current_command().unwrap(); // Return
+    assert_eq!(
+        runtime.current_scene(),
+        Some(&SceneId::new("town".to_string()))
+    );
+    // Arguments go back to their values before the call
+    assert_eq!(
+        runtime.variables().get(&VariableId::new("item")),
+        Some(&VariableValue::String("sword".to_string()))
+    );
+    assert_eq!(runtime.variables().get(&VariableId::new("price")), None);
+    assert_eq!(
+        runtime.variables().get(&VariableId::new("receipt")),
+        Some(&VariableValue::Int(45))
+    );
+    assert!(runtime.call_frames.is_empty());
+}
+
+#[test]
+fn test_return_value_error_keeps_call() {
+    let mut scenario = create_shop_scenario();
+    if let Some(shop) = scenario.scenes.get_mut("shop") {
+        shop.commands = vec![ScenarioCommand::Return {
+            value: Some("1 / 0".parse().unwrap()),
+        }];
+    }
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    runtime.execute_current_command().unwrap(); // Call
+
+    let error = runtime.execute_current_command().unwrap_err();
+    assert!(error.to_string().contains("division by zero"));
+    assert_eq!(runtime.scene_stack.len(), 1);
+}
@@ -158,3 +158,179 @@ fn test_conditional_choice_no_available_choices() {
         assert!(error_msg.contains("No available choices"));
     }
 }
+
+/// Scenario with a shuffled four-answer choice, each answer leading to its own scene
+fn create_quiz_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("quiz", "Quiz");
+    let mut scenario = Scenario::new(metadata, "question");
+
+    let answers = ["a", "b", "c", "d"];
+    let mut question = Scene::new("question", "Question");
+    let choice = Choice::new(
+        answers
+            .iter()
+            .map(|answer| ChoiceOption::new(answer.to_uppercase(), format!("answer_{}", answer)))
+            .collect(),
+    )
+    .with_shuffle(true);
+    question.add_command(ScenarioCommand::ShowChoice { choice });
+    scenario.add_scene("question", question);
+
+    for answer in answers {
+        let id = format!("answer_{}", answer);
+        scenario.add_scene(id.clone(), Scene::new(id, "Answer"));
+    }
+
+    scenario
+}
+
+#[test]
+fn test_shuffled_choice_is_deterministic() {
+    let mut runtime = ScenarioRuntime::new(create_quiz_scenario());
+    runtime.set_choice_seed(1234);
+    runtime.start().unwrap();
+    let order = runtime.choice_display_order().unwrap();
+
+    let mut other = ScenarioRuntime::new(create_quiz_scenario());
+    other.set_choice_seed(1234);
+    other.start().unwrap();
+    assert_eq!(other.choice_display_order().unwrap(), order);
+
+    let mut sorted = order.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, vec![0, 1, 2, 3]);
+
+    // ShowChoices lists the options in display order
+    let result = runtime.execute_current_command().unwrap();
+    let CommandExecutionResult::ShowChoices(choices) = result else {
+        panic!("Expected ShowChoices result");
+    };
+    assert_eq!(choices, runtime.displayed_choices());
+    for (choice, index) in choices.iter().zip(&order) {
+        assert_eq!(choice.text, ["A", "B", "C", "D"][*index]);
+    }
+}
+
+#[test]
+fn test_select_shuffled_choice_maps_to_authored_option() {
+    // Find a seed that moves the first answer
+    let seed = (0..64)
+        .find(|&seed| {
+            let mut runtime = ScenarioRuntime::new(create_quiz_scenario());
+            runtime.set_choice_seed(seed);
+            runtime.start().unwrap();
+            runtime.choice_display_order().unwrap()[0] != 0
+        })
+        .unwrap();
+
+    let mut runtime = ScenarioRuntime::new(create_quiz_scenario());
+    runtime.set_choice_seed(seed);
+    runtime.start().unwrap();
+    let first_shown = runtime.displayed_choices()[0].next_scene.clone();
+
+    runtime.select_choice(0).unwrap();
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new(first_shown)));
+    assert_ne!(runtime.current_scene(), Some(&SceneId::new("answer_a")));
+}
+
+#[test]
+fn test_select_choice_uses_display_index_after_filtering() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene1 = Scene::new("scene1", "Scene 1");
+    let choice = Choice::new(vec![
+        ChoiceOption::new("Use key", "scene_unlock")
+            .with_condition(Condition::flag("has_key", true)),
+        ChoiceOption::new("Break door", "scene_break"),
+    ]);
+    scene1.add_command(ScenarioCommand::ShowChoice { choice });
+    scenario.add_scene("scene1", scene1);
+    scenario.add_scene("scene_unlock", Scene::new("scene_unlock", "Unlocked"));
+    scenario.add_scene("scene_break", Scene::new("scene_break", "Broken"));
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    // "Use key" is hidden, so the only option shown is "Break door"
+    assert_eq!(runtime.choice_display_order(), Some(vec![1]));
+    runtime.select_choice(0).unwrap();
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene_break")));
+}
+
+/// Scene with a hidden, a disabled and a plain option
+fn create_door_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene1 = Scene::new("scene1", "Scene 1");
+    let choice = Choice::new(vec![
+        ChoiceOption::new("Pick the lock", "scene_unlock")
+            .visible_if(Condition::flag("has_lockpick", true)),
+        ChoiceOption::new("Use key", "scene_unlock").disabled_if(Condition::flag("has_key", false)),
+        ChoiceOption::new("Break door", "scene_break"),
+    ]);
+    scene1.add_command(ScenarioCommand::ShowChoice { choice });
+    scenario.add_scene("scene1", scene1);
+    scenario.add_scene("scene_unlock", Scene::new("scene_unlock", "Unlocked"));
+    scenario.add_scene("scene_break", Scene::new("scene_break", "Broken"));
+    scenario
+}
+
+#[test]
+fn test_visible_and_disabled_conditions() {
+    let mut runtime = ScenarioRuntime::new(create_door_scenario());
+    runtime.start().unwrap();
+
+    // "Pick the lock" is hidden, "Use key" is shown greyed out
+    let texts: Vec<String> = runtime
+        .displayed_choices()
+        .into_iter()
+        .map(|option| option.text)
+        .collect();
+    assert_eq!(texts, vec!["Use key", "Break door"]);
+    assert_eq!(runtime.displayed_choices_disabled(), vec![true, false]);
+    assert!(runtime.execute_current_command().is_ok());
+
+    let result = runtime.select_choice(0);
+    assert!(result.is_err_and(|e| e.to_string().contains("disabled")));
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene1")));
+
+    runtime.select_choice(1).unwrap();
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene_break")));
+}
+
+#[test]
+fn test_disabled_condition_lifts_with_flag() {
+    let mut runtime = ScenarioRuntime::builder(create_door_scenario())
+        .with_flag("has_key", true)
+        .with_flag("has_lockpick", true)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        runtime.displayed_choices_disabled(),
+        vec![false, false, false]
+    );
+    runtime.select_choice(1).unwrap();
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene_unlock")));
+}
+
+#[test]
+fn test_all_choices_disabled_is_an_error() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene1 = Scene::new("scene1", "Scene 1");
+    let choice = Choice::new(vec![
+        ChoiceOption::new("Use key", "scene1").disabled_if(Condition::flag("has_key", false)),
+    ]);
+    scene1.add_command(ScenarioCommand::ShowChoice { choice });
+    scenario.add_scene("scene1", scene1);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let result = runtime.execute_current_command();
+    assert!(result.is_err_and(|e| e.to_string().contains("All available choices are disabled")));
+}
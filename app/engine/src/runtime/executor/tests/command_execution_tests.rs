@@ -1,6 +1,7 @@
 //! Tests for basic command execution
 
 use super::*;
+use crate::runtime::EffectKind;
 
 #[test]
 fn test_execute_dialogue_command() {
@@ -94,6 +95,45 @@ fn test_execute_wait_command() {
     assert_eq!(result, CommandExecutionResult::Wait(2.5));
 }
 
+#[test]
+fn test_execute_impact_commands() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::ScreenShake {
+        intensity: 0.5,
+        duration: 0.3,
+    });
+    scene.add_command(ScenarioCommand::Flash {
+        color: narrative_core::Color::WHITE,
+        duration: 0.2,
+    });
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let result = runtime.execute_current_command().unwrap();
+    assert_eq!(
+        result,
+        CommandExecutionResult::PlayEffect(EffectState::new(
+            EffectKind::Shake { intensity: 0.5 },
+            0.3
+        ))
+    );
+
+    assert!(runtime.advance_command());
+    let result = runtime.execute_current_command().unwrap();
+    assert_eq!(
+        result,
+        CommandExecutionResult::PlayEffect(EffectState::new(
+            EffectKind::Flash { color: [1.0; 4] },
+            0.2
+        ))
+    );
+}
+
 #[test]
 fn test_execute_end_command() {
     let scenario = create_test_scenario();
@@ -109,3 +149,189 @@ fn test_execute_end_command() {
     let result = runtime.execute_current_command().unwrap();
     assert_eq!(result, CommandExecutionResult::End);
 }
+
+/// Scenario whose start scene runs a script before a dialogue line
+#[cfg(feature = "scripting")]
+fn create_script_scenario(source: &str) -> Scenario {
+    let mut scenario = create_test_scenario();
+    let mut shop = Scene::new("shop", "Shop");
+    shop.add_command(ScenarioCommand::Script {
+        source: source.to_string(),
+    });
+    shop.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Come again"),
+    });
+    scenario.add_scene("shop", shop);
+    scenario.start_scene = "shop".to_string();
+    scenario
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn test_execute_script_command() {
+    let mut runtime = ScenarioRuntime::new(create_script_scenario(
+        r#"
+        game.set_flag("visited_shop", true);
+        game.set_var("gold", 100);
+        "#,
+    ));
+    runtime.start().unwrap();
+
+    let result = runtime.execute_current_command().unwrap();
+    assert_eq!(result, CommandExecutionResult::Continue);
+    assert!(runtime.flags().is_set(&FlagId::new("visited_shop")));
+    assert_eq!(
+        runtime.variables().get(&VariableId::new("gold")),
+        Some(&VariableValue::Int(100))
+    );
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn test_execute_script_command_jump() {
+    let mut runtime = ScenarioRuntime::new(create_script_scenario(r#"game.jump("scene2")"#));
+    runtime.start().unwrap();
+
+    let result = runtime.execute_current_command().unwrap();
+    assert!(matches!(
+        result,
+        CommandExecutionResult::SceneChanged { .. }
+    ));
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene2")));
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn test_execute_script_command_error() {
+    let mut runtime = ScenarioRuntime::new(create_script_scenario(r#"game.jump("missing")"#));
+    runtime.start().unwrap();
+
+    assert!(runtime.execute_current_command().is_err());
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("shop")));
+}
+
+/// Scenario whose start scene starts a persistent and a scene-scoped effect
+fn create_effect_scenario() -> Scenario {
+    let mut scenario = create_test_scenario();
+    let mut storm = Scene::new("storm", "Storm");
+    storm.add_command(ScenarioCommand::ShowEffect {
+        effect: "rain".to_string(),
+        intensity: 0.5,
+        duration: None,
+        lifetime: EffectLifetime::Persistent,
+    });
+    storm.add_command(ScenarioCommand::ShowEffect {
+        effect: "lightning".to_string(),
+        intensity: 1.0,
+        duration: None,
+        lifetime: EffectLifetime::Scene,
+    });
+    storm.add_command(ScenarioCommand::ShowEffect {
+        effect: "rain".to_string(),
+        intensity: 2.0,
+        duration: None,
+        lifetime: EffectLifetime::Persistent,
+    });
+    storm.add_command(ScenarioCommand::JumpToScene {
+        scene_id: "shelter".to_string(),
+    });
+    let mut shelter = Scene::new("shelter", "Shelter");
+    shelter.add_command(ScenarioCommand::ClearEffects { effect: None });
+    scenario.add_scene("storm", storm);
+    scenario.add_scene("shelter", shelter);
+    scenario.start_scene = "storm".to_string();
+    scenario
+}
+
+fn effect_names(runtime: &ScenarioRuntime) -> Vec<&str> {
+    runtime
+        .active_effects()
+        .iter()
+        .map(|effect| effect.effect.as_str())
+        .collect()
+}
+
+#[test]
+fn test_persistent_effects_outlive_scene_jumps() {
+    let mut runtime = ScenarioRuntime::new(create_effect_scenario());
+    runtime.start().unwrap();
+
+    for _ in 0..3 {
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+    }
+    assert_eq!(effect_names(&runtime), vec!["rain", "lightning"]);
+    // Showing a running effect again updates it in place
+    assert_eq!(runtime.active_effects()[0].intensity, 2.0);
+
+    runtime.execute_current_command().unwrap();
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("shelter")));
+    assert_eq!(effect_names(&runtime), vec!["rain"]);
+
+    runtime.execute_current_command().unwrap();
+    assert!(runtime.active_effects().is_empty());
+}
+
+#[test]
+fn test_timed_effect_stops_after_duration() {
+    let mut scenario = create_effect_scenario();
+    if let Some(storm) = scenario.scenes.get_mut("storm") {
+        storm.commands.insert(
+            0,
+            ScenarioCommand::ShowEffect {
+                effect: "sparks".to_string(),
+                intensity: 1.0,
+                duration: Some(1.0),
+                lifetime: EffectLifetime::Persistent,
+            },
+        );
+    }
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    for _ in 0..2 {
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+    }
+    assert_eq!(effect_names(&runtime), vec!["sparks", "rain"]);
+
+    assert!(!runtime.update_effects(0.6));
+    assert_eq!(effect_names(&runtime), vec!["sparks", "rain"]);
+
+    assert!(runtime.update_effects(0.6));
+    assert_eq!(effect_names(&runtime), vec!["rain"]);
+}
+
+#[test]
+fn test_clear_single_effect() {
+    let mut scenario = create_effect_scenario();
+    if let Some(shelter) = scenario.scenes.get_mut("shelter") {
+        shelter.commands = vec![
+            ScenarioCommand::ShowEffect {
+                effect: "fog".to_string(),
+                intensity: 1.0,
+                duration: None,
+                lifetime: EffectLifetime::Scene,
+            },
+            ScenarioCommand::ClearEffects {
+                effect: Some("rain".to_string()),
+            },
+        ];
+    }
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    for _ in 0..2 {
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+    }
+
+    runtime.jump_to_scene(&SceneId::new("shelter")).unwrap();
+    for _ in 0..2 {
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+    }
+    assert_eq!(effect_names(&runtime), vec!["fog"]);
+
+    // A new playthrough starts without effects
+    runtime.start().unwrap();
+    assert!(runtime.active_effects().is_empty());
+}
@@ -114,6 +114,44 @@ fn test_if_command_with_variable_condition() {
     assert!(runtime.flags().is_set(&FlagId::new("high_score")));
 }
 
+#[test]
+fn test_if_command_with_expression() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+
+    scene.add_command(ScenarioCommand::SetFlag {
+        flag_name: "met_alice".to_string(),
+        value: true,
+    });
+    scene.add_command(ScenarioCommand::SetVariable {
+        variable_name: "affection".to_string(),
+        value: VariableValue::Int(4),
+    });
+    scene.add_command(ScenarioCommand::If {
+        condition: Condition::expression("flags.met_alice && affection + 2 >= 5").unwrap(),
+        then_commands: vec![ScenarioCommand::SetFlag {
+            flag_name: "good_ending".to_string(),
+            value: true,
+        }],
+        else_commands: vec![],
+    });
+
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    for _ in 0..2 {
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+    }
+    runtime.execute_current_command().unwrap();
+
+    assert!(runtime.flags().is_set(&FlagId::new("good_ending")));
+}
+
 #[test]
 fn test_if_command_nested() {
     let metadata = ScenarioMetadata::new("test", "Test");
@@ -200,3 +238,44 @@ fn test_if_command_complex_condition() {
 
     assert!(runtime.flags().is_set(&FlagId::new("door_unlocked")));
 }
+
+#[test]
+fn test_if_command_jump_to_label() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::If {
+        condition: Condition::flag("has_item", false),
+        then_commands: vec![
+            ScenarioCommand::JumpToLabel {
+                label: "no_item".to_string(),
+            },
+            ScenarioCommand::SetFlag {
+                flag_name: "after_jump".to_string(),
+                value: true,
+            },
+        ],
+        else_commands: vec![],
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("You have the item"),
+    });
+    scene.add_command(ScenarioCommand::Label {
+        name: "no_item".to_string(),
+    });
+    scene.add_command(ScenarioCommand::End);
+
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    runtime.execute_current_command().unwrap();
+    assert_eq!(runtime.command_index(), 2);
+    // The rest of the block is skipped
+    assert!(!runtime.flags().is_set(&FlagId::new("after_jump")));
+
+    runtime.advance_command();
+    assert!(runtime.is_ended());
+}
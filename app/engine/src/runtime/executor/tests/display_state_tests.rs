@@ -1,6 +1,7 @@
 //! Tests for display state management (background, characters, dirty flags) and save/load
 
 use super::*;
+use narrative_core::{CharacterDef, Expression, SpriteMode};
 
 #[test]
 fn test_save_load_display_state_background() {
@@ -53,6 +54,7 @@ fn test_save_load_display_state_characters() {
         sprite: AssetRef::from("alice_happy"),
         position: CharacterPosition::Left,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::ShowCharacter {
@@ -60,6 +62,7 @@ fn test_save_load_display_state_characters() {
         sprite: AssetRef::from("bob_normal"),
         position: CharacterPosition::Right,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::Dialogue {
@@ -139,6 +142,7 @@ fn test_save_load_display_state_full_scene() {
         sprite: AssetRef::from("teacher_normal"),
         position: CharacterPosition::Center,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::Dialogue {
@@ -251,6 +255,7 @@ fn test_dirty_flag_show_character() {
         sprite: AssetRef::from("alice_normal"),
         position: CharacterPosition::Center,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::Dialogue {
@@ -286,6 +291,7 @@ fn test_dirty_flag_hide_character() {
         sprite: AssetRef::from("bob_normal"),
         position: CharacterPosition::Left,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::HideCharacter {
@@ -325,6 +331,7 @@ fn test_dirty_flag_move_character() {
         sprite: AssetRef::from("charlie_normal"),
         position: CharacterPosition::Left,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::MoveCharacter {
@@ -365,6 +372,7 @@ fn test_dirty_flag_change_sprite() {
         sprite: AssetRef::from("dave_normal"),
         position: CharacterPosition::Center,
         expression: None,
+        outfit: None,
         transition: Transition::instant(),
     });
     scene.add_command(ScenarioCommand::ChangeSprite {
@@ -420,3 +428,231 @@ fn test_dirty_flag_unchanged() {
     runtime.execute_current_command().unwrap(); // ShowBackground
     assert!(!runtime.displayed_characters_changed());
 }
+
+#[test]
+fn test_current_voice() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "park");
+
+    let mut scene = Scene::new("park", "Park");
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::character("alice", "Good morning!"),
+    });
+    scene.add_command(ScenarioCommand::PlayVoice {
+        asset: AssetRef::from("voice/bob/special.ogg"),
+        volume: 0.8,
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::character("bob", "Morning."),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("They walked on."),
+    });
+    scenario.add_scene("park", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    // Without a resolver only explicit voices play
+    assert_eq!(runtime.current_voice(), None);
+
+    runtime.set_voice_resolver(VoiceResolver::new("voice"));
+    assert_eq!(
+        runtime.current_voice(),
+        Some(AssetRef::from("voice/alice/park_1.ogg"))
+    );
+    assert_eq!(
+        runtime.current_voice_with_volume(),
+        Some((AssetRef::from("voice/alice/park_1.ogg"), 1.0))
+    );
+
+    runtime.advance_command(); // PlayVoice
+    assert_eq!(runtime.current_voice(), None);
+
+    runtime.advance_command(); // Explicit voice wins
+    assert_eq!(
+        runtime.current_voice(),
+        Some(AssetRef::from("voice/bob/special.ogg"))
+    );
+    assert_eq!(
+        runtime.current_voice_with_volume(),
+        Some((AssetRef::from("voice/bob/special.ogg"), 0.8))
+    );
+
+    runtime.advance_command(); // Narrator lines have no voice
+    assert_eq!(runtime.current_voice(), None);
+}
+
+#[test]
+fn test_display_snapshot_history() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::ShowBackground {
+        asset: AssetRef::from("bg_room"),
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::ShowCharacter {
+        character_id: "alice".to_string(),
+        sprite: AssetRef::from("alice_happy"),
+        position: CharacterPosition::Left,
+        expression: None,
+        outfit: None,
+        transition: Transition::instant(),
+    });
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    let scene_id = SceneId::new("scene1");
+
+    runtime.execute_current_command().unwrap();
+    runtime.add_to_backlog(
+        scene_id.clone(),
+        0,
+        narrative_core::Speaker::Narrator,
+        "Background only",
+    );
+    runtime.add_to_backlog(
+        scene_id.clone(),
+        1,
+        narrative_core::Speaker::Narrator,
+        "Still background only",
+    );
+
+    runtime.advance_command();
+    runtime.execute_current_command().unwrap();
+    runtime.add_to_backlog(
+        scene_id.clone(),
+        2,
+        narrative_core::Speaker::Narrator,
+        "With Alice",
+    );
+
+    let first = runtime.display_snapshot_at(&scene_id, 0).unwrap();
+    assert_eq!(first.background.as_deref(), Some("bg_room"));
+    assert!(first.characters.is_empty());
+    // Unchanged screens share one snapshot
+    assert!(std::ptr::eq(
+        first,
+        runtime.display_snapshot_at(&scene_id, 1).unwrap()
+    ));
+
+    let second = runtime.display_snapshot_at(&scene_id, 2).unwrap();
+    assert_eq!(second.characters.len(), 1);
+    assert_eq!(second.characters[0].character_id, "alice");
+    assert_eq!(second, &runtime.display_snapshot());
+
+    assert!(runtime.display_snapshot_at(&scene_id, 3).is_none());
+}
+
+#[test]
+fn test_layered_character_sprites() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+    let mut alice = CharacterDef::new("alice", "Alice", "normal")
+        .with_expression("normal", "alice/normal.png")
+        .with_expression("happy", "alice/happy.png");
+    alice.sprite_mode = SpriteMode::Layered {
+        base: "alice/body.png".to_string(),
+        outfits: HashMap::from([
+            ("uniform".to_string(), "alice/uniform.png".to_string()),
+            ("casual".to_string(), "alice/casual.png".to_string()),
+        ]),
+        default_outfit: Some("uniform".to_string()),
+        accessories: Vec::new(),
+    };
+    scenario.characters.push(alice);
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::ShowCharacter {
+        character_id: "alice".to_string(),
+        sprite: AssetRef::default(),
+        position: CharacterPosition::Center,
+        expression: None,
+        outfit: None,
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::ShowCharacter {
+        character_id: "alice".to_string(),
+        sprite: AssetRef::default(),
+        position: CharacterPosition::Center,
+        expression: Some(Expression::Happy),
+        outfit: Some("casual".to_string()),
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::ChangeExpression {
+        character_id: "alice".to_string(),
+        expression: Expression::Normal,
+    });
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    let mut sprites = Vec::new();
+    for _ in 0..3 {
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+        sprites.push(runtime.displayed_characters()["alice"].sprite.clone());
+    }
+
+    // Default expression and outfit, then the requested ones
+    assert_eq!(
+        sprites[0],
+        AssetRef::layered(["alice/body.png", "alice/uniform.png", "alice/normal.png"])
+    );
+    assert_eq!(
+        sprites[1],
+        AssetRef::layered(["alice/body.png", "alice/casual.png", "alice/happy.png"])
+    );
+    // A new expression keeps the outfit
+    assert_eq!(
+        sprites[2],
+        AssetRef::layered(["alice/body.png", "alice/casual.png", "alice/normal.png"])
+    );
+}
+
+#[test]
+fn test_execute_display_commands() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::ShowCharacter {
+        character_id: "alice".to_string(),
+        sprite: AssetRef::from("alice.png"),
+        position: CharacterPosition::Left,
+        expression: None,
+        outfit: None,
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::ShowBackground {
+        asset: AssetRef::from("bg_room"),
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Test"),
+    });
+    scene.add_command(ScenarioCommand::ShowCG {
+        asset: AssetRef::from("cg_ending"),
+        transition: Transition::instant(),
+    });
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    // Stops at the dialogue, with the background and character set up
+    assert_eq!(runtime.execute_display_commands().unwrap(), 2);
+    assert_eq!(runtime.command_index(), 2);
+    assert_eq!(
+        runtime.current_background(),
+        Some(&AssetRef::from("bg_room"))
+    );
+    assert!(runtime.displayed_characters().contains_key("alice"));
+    assert_eq!(runtime.current_cg(), None);
+
+    assert_eq!(runtime.execute_display_commands().unwrap(), 0);
+    assert_eq!(runtime.command_index(), 2);
+}
@@ -0,0 +1,96 @@
+//! Tests for skipping to the next choice or unread line
+
+use super::*;
+
+fn create_skip_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("skip", "Skip");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene1 = Scene::new("scene1", "Scene 1");
+    for line in ["One", "Two"] {
+        scene1.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator(line),
+        });
+    }
+    scene1.add_command(ScenarioCommand::Wait { duration: 3.0 });
+    scene1.add_command(ScenarioCommand::PlayBgm {
+        asset: AssetRef::from("bgm_night"),
+        volume: 1.0,
+        fade_in: 2.0,
+    });
+    scene1.add_command(ScenarioCommand::JumpToScene {
+        scene_id: "scene2".to_string(),
+    });
+    scenario.add_scene("scene1", scene1);
+
+    let mut scene2 = Scene::new("scene2", "Scene 2");
+    scene2.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Three"),
+    });
+    scene2.add_command(ScenarioCommand::ShowChoice {
+        choice: Choice::new(vec![
+            ChoiceOption::new("Stay", "scene2"),
+            ChoiceOption::new("Leave", "scene1"),
+        ]),
+    });
+    scenario.add_scene("scene2", scene2);
+    scenario
+}
+
+fn backlog_lines(runtime: &ScenarioRuntime) -> Vec<&str> {
+    runtime
+        .backlog()
+        .entries()
+        .iter()
+        .map(|entry| entry.text.as_str())
+        .collect()
+}
+
+#[test]
+fn test_skip_stops_at_unread_line() {
+    let mut runtime = ScenarioRuntime::new(create_skip_scenario());
+    runtime.start().unwrap();
+
+    let stop = runtime.skip_to_next_stop().unwrap();
+    assert_eq!(stop, SkipStop::UnreadDialogue);
+    assert_eq!(runtime.command_index(), 1);
+    assert!(runtime.backlog().entries().is_empty());
+}
+
+#[test]
+fn test_skip_runs_read_lines_to_choice() {
+    let mut runtime = ScenarioRuntime::new(create_skip_scenario());
+    runtime.start().unwrap();
+    let scene1 = SceneId::new("scene1");
+    let scene2 = SceneId::new("scene2");
+    runtime.read_history_mut().mark_read(scene1.clone(), 1);
+    runtime.read_history_mut().mark_read(scene2.clone(), 0);
+
+    let stop = runtime.skip_to_next_stop().unwrap();
+    assert_eq!(stop, SkipStop::Choice);
+    assert_eq!(runtime.current_scene(), Some(&scene2));
+    assert_eq!(runtime.command_index(), 1);
+    // Read lines reach the backlog, the wait ends at once and the BGM is tracked
+    assert_eq!(backlog_lines(&runtime), ["Two", "Three"]);
+    assert_eq!(
+        runtime.current_bgm().map(|bgm| bgm.asset.path()),
+        Some("bgm_night")
+    );
+}
+
+#[test]
+fn test_skip_ends_with_scenario() {
+    let metadata = ScenarioMetadata::new("skip", "Skip");
+    let mut scenario = Scenario::new(metadata, "scene1");
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Bye"),
+    });
+    scene.add_command(ScenarioCommand::End);
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    assert_eq!(runtime.skip_to_next_stop().unwrap(), SkipStop::End);
+    assert!(runtime.is_ended());
+}
@@ -89,3 +89,143 @@ fn test_dialogue_level_read_history_tracking() {
     assert!(runtime.read_history().is_read(&scene1_id, 1));
     assert!(runtime.read_history().is_read(&scene2_id, 0));
 }
+
+#[test]
+fn test_on_command_hook_receives_tags() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene1 = Scene::new("scene1", "Scene 1").with_tag("act", "1");
+    scene1.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Tagged").with_tag("analytics", "intro_seen"),
+    });
+    scene1.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Untagged"),
+    });
+    scene1.add_command(ScenarioCommand::JumpToScene {
+        scene_id: "scene2".to_string(),
+    });
+    let mut scene2 = Scene::new("scene2", "Scene 2");
+    scene2.add_command(ScenarioCommand::End);
+    scenario.add_scene("scene1", scene1);
+    scenario.add_scene("scene2", scene2);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&seen);
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.set_on_command(move |event| {
+        log.lock().unwrap().push((
+            event.scene.id.clone(),
+            event.command_index,
+            event.tag("analytics").map(str::to_string),
+            event.tag("act").map(str::to_string),
+        ));
+    });
+
+    runtime.start().unwrap();
+    runtime.advance_command();
+    runtime.advance_command();
+    runtime.execute_current_command().unwrap();
+
+    let seen = seen.lock().unwrap();
+    let some = |s: &str| Some(s.to_string());
+    assert_eq!(
+        *seen,
+        vec![
+            ("scene1".to_string(), 0, some("intro_seen"), some("1")),
+            ("scene1".to_string(), 1, None, some("1")),
+            ("scene1".to_string(), 2, None, some("1")),
+            ("scene2".to_string(), 0, None, None),
+        ]
+    );
+}
+
+#[test]
+fn test_on_command_hook_call_return_and_clear() {
+    let mut runtime = ScenarioRuntime::new(create_call_return_scenario());
+    let reached = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&reached);
+    runtime.set_on_command(move |event| {
+        log.lock()
+            .unwrap()
+            .push(format!("{}:{}", event.scene.id, event.command_index));
+    });
+
+    runtime.start().unwrap();
+    runtime.advance_command(); // Call
+    runtime.execute_current_command().unwrap(); // -> subroutine
+    runtime.advance_command(); // Return
+    runtime.execute_current_command().unwrap(); // -> main, after Call
+
+    // Moving past the last command reaches nothing
+    runtime.clear_on_command();
+    runtime.advance_command();
+
+    assert_eq!(
+        *reached.lock().unwrap(),
+        vec!["main:0", "main:1", "subroutine:0", "subroutine:1", "main:2"]
+    );
+}
+
+/// Scene that skips its second line by jumping to a label
+fn create_label_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("test_labels", "Test Labels");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::JumpToLabel {
+        label: "later".to_string(),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Skipped"),
+    });
+    scene.add_command(ScenarioCommand::Label {
+        name: "later".to_string(),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("After the label"),
+    });
+    scene.add_command(ScenarioCommand::JumpToLabel {
+        label: "missing".to_string(),
+    });
+
+    scenario.add_scene("scene1", scene);
+    scenario
+}
+
+#[test]
+fn test_jump_to_label() {
+    let mut runtime = ScenarioRuntime::new(create_label_scenario());
+    runtime.start().unwrap();
+
+    let result = runtime.execute_current_command().unwrap();
+    assert_eq!(result, CommandExecutionResult::Continue);
+    assert_eq!(runtime.command_index(), 2);
+
+    // The label itself does nothing; execution continues after it
+    runtime.advance_command();
+    assert_eq!(runtime.command_index(), 3);
+    assert!(matches!(
+        runtime.get_current_command(),
+        Some(ScenarioCommand::Dialogue { .. })
+    ));
+
+    // Unknown labels are an error
+    runtime.advance_command();
+    assert!(runtime.execute_current_command().is_err());
+    assert_eq!(runtime.command_index(), 4);
+}
+
+#[test]
+fn test_labels_are_scene_local() {
+    let mut scenario = create_label_scenario();
+    let mut other = Scene::new("scene2", "Scene 2");
+    other.add_command(ScenarioCommand::JumpToLabel {
+        label: "later".to_string(),
+    });
+    scenario.add_scene("scene2", other);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.jump_to_scene(&SceneId::new("scene2")).unwrap();
+    assert!(runtime.jump_to_label("later").is_err());
+}
@@ -34,3 +34,118 @@ fn test_scenario_runtime_start_invalid_scene() {
     let result = runtime.start();
     assert!(result.is_err());
 }
+
+#[test]
+fn test_jump_to_scenario_and_switch() {
+    let metadata = ScenarioMetadata::new("chapter_01", "Chapter 1");
+    let mut scenario = Scenario::new(metadata, "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::SetFlag {
+        flag_name: "met_alice".to_string(),
+        value: true,
+    });
+    scene.add_command(ScenarioCommand::JumpToScenario {
+        id: "alice_01".to_string(),
+        scene: Some("scene2".to_string()),
+    });
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    runtime.execute_current_command().unwrap();
+    runtime.advance_command();
+
+    let result = runtime.execute_current_command().unwrap();
+    let expected = ScenarioJump {
+        id: "alice_01".to_string(),
+        scene: Some("scene2".to_string()),
+    };
+    assert_eq!(
+        result,
+        CommandExecutionResult::JumpToScenario(expected.clone())
+    );
+    assert!(runtime.has_scenario_jump());
+    assert_eq!(runtime.take_scenario_jump(), Some(expected));
+    assert!(!runtime.has_scenario_jump());
+
+    // Unknown scenes leave the runtime untouched
+    assert!(
+        runtime
+            .switch_scenario(create_test_scenario(), Some("missing"))
+            .is_err()
+    );
+    assert_eq!(runtime.scenario().metadata.id, "chapter_01");
+
+    runtime
+        .switch_scenario(create_test_scenario(), Some("scene2"))
+        .unwrap();
+    assert_eq!(runtime.scenario().metadata.id, "test");
+    assert_eq!(
+        runtime.current_scene(),
+        Some(&SceneId::new("scene2".to_string()))
+    );
+    assert_eq!(runtime.command_index(), 0);
+    // Flags carry over to the new scenario
+    assert!(runtime.flags().get(&FlagId::new("met_alice")));
+}
+
+#[test]
+fn test_reload_scenario_maps_position() {
+    let mut runtime = ScenarioRuntime::new(create_test_scenario());
+    runtime.start().unwrap();
+    runtime.jump_to_scene(&SceneId::new("scene2")).unwrap();
+    runtime.flags_mut().set(FlagId::new("met_alice"), true);
+
+    // Unrelated edits keep the position
+    let mut edited = create_test_scenario();
+    if let Some(scene) = edited.scenes.get_mut("scene1") {
+        scene.add_command(ScenarioCommand::End);
+    }
+    assert_eq!(
+        runtime.reload_scenario(edited.clone()).unwrap(),
+        ScenarioReload::Unchanged
+    );
+    assert_eq!(runtime.command_index(), 0);
+
+    // A line inserted before the current one moves it
+    if let Some(scene) = edited.scenes.get_mut("scene2") {
+        scene.commands.insert(
+            0,
+            ScenarioCommand::Dialogue {
+                dialogue: Dialogue::narrator("New opening line"),
+            },
+        );
+    }
+    assert_eq!(
+        runtime.reload_scenario(edited.clone()).unwrap(),
+        ScenarioReload::Moved { from: 0, to: 1 }
+    );
+    assert_eq!(runtime.command_index(), 1);
+
+    // An edited line is shown in place
+    if let Some(scene) = edited.scenes.get_mut("scene2") {
+        scene.commands[1] = ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator("Scene 2 dialogue, revised"),
+        };
+    }
+    assert_eq!(
+        runtime.reload_scenario(edited.clone()).unwrap(),
+        ScenarioReload::Edited { index: 1 }
+    );
+    assert!(matches!(
+        runtime.get_current_command(),
+        Some(ScenarioCommand::Dialogue { dialogue }) if dialogue.text == "Scene 2 dialogue, revised"
+    ));
+
+    // A removed scene restarts at the start scene
+    edited.scenes.remove("scene2");
+    assert_eq!(
+        runtime.reload_scenario(edited).unwrap(),
+        ScenarioReload::SceneRemoved
+    );
+    assert_eq!(runtime.current_scene(), Some(&SceneId::new("scene1")));
+    assert_eq!(runtime.command_index(), 0);
+
+    // State carries over
+    assert!(runtime.flags().get(&FlagId::new("met_alice")));
+}
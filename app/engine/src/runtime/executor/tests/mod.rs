@@ -4,7 +4,10 @@
 //! Tests are organized by functionality.
 
 use super::*;
-use narrative_core::{Choice, ChoiceOption, Dialogue, ScenarioMetadata, VariableValue};
+use narrative_core::{
+    Choice, ChoiceOption, Dialogue, EffectLifetime, ScenarioMetadata, VariableValue,
+};
+use std::collections::BTreeMap;
 
 /// Helper function to create a basic test scenario with two scenes
 pub(super) fn create_test_scenario() -> Scenario {
@@ -48,6 +51,8 @@ pub(super) fn create_call_return_scenario() -> Scenario {
     main_scene.add_command(ScenarioCommand::Call {
         scene_id: "subroutine".to_string(),
         return_scene: "main".to_string(),
+        args: BTreeMap::new(),
+        result: None,
     });
     main_scene.add_command(ScenarioCommand::Dialogue {
         dialogue: Dialogue::narrator("After return"),
@@ -59,14 +64,14 @@ pub(super) fn create_call_return_scenario() -> Scenario {
     subroutine.add_command(ScenarioCommand::Dialogue {
         dialogue: Dialogue::narrator("In subroutine"),
     });
-    subroutine.add_command(ScenarioCommand::Return);
+    subroutine.add_command(ScenarioCommand::Return { value: None });
 
     // Nested subroutine scene
     let mut nested = Scene::new("nested", "Nested Subroutine");
     nested.add_command(ScenarioCommand::Dialogue {
         dialogue: Dialogue::narrator("In nested"),
     });
-    nested.add_command(ScenarioCommand::Return);
+    nested.add_command(ScenarioCommand::Return { value: None });
 
     scenario.add_scene("main", main_scene);
     scenario.add_scene("subroutine", subroutine);
@@ -75,12 +80,16 @@ pub(super) fn create_call_return_scenario() -> Scenario {
     scenario
 }
 
+mod branch_preview_tests;
+mod builder_tests;
 mod call_return_tests;
 mod choice_tests;
 mod command_execution_tests;
 mod conditional_tests;
 mod display_state_tests;
+mod fast_forward_tests;
 mod flow_control_tests;
 mod lifecycle_tests;
 mod persistence_tests;
+mod rollback_tests;
 mod variable_tests;
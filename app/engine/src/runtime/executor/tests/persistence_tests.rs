@@ -148,3 +148,174 @@ fn test_from_save_data_with_scene_stack() {
     assert_eq!(runtime.scene_stack[0].0, SceneId::new("main".to_string()));
     assert_eq!(runtime.scene_stack[0].1, 2);
 }
+
+#[test]
+fn test_save_data_scenario_id() {
+    let mut runtime = ScenarioRuntime::new(create_test_scenario());
+    runtime.start().unwrap();
+
+    let save_data = runtime.to_save_data(1);
+    assert_eq!(save_data.scenario_id.as_deref(), Some("test"));
+    assert!(runtime.from_save_data(&save_data).is_ok());
+
+    // Saves from another scenario are rejected
+    let mut other = save_data.clone();
+    other.scenario_id = Some("chapter_02".to_string());
+    assert!(runtime.from_save_data(&other).is_err());
+
+    // Saves without a scenario ID are accepted
+    other.scenario_id = None;
+    assert!(runtime.from_save_data(&other).is_ok());
+}
+
+#[test]
+fn test_from_save_data_at_scene_start() {
+    let mut runtime = ScenarioRuntime::new(create_test_scenario());
+
+    // Save made before "scene3" was removed from the scenario
+    let mut save_data = SaveData::new(1);
+    save_data.scenario_id = Some("test".to_string());
+    save_data.current_scene = SceneId::new("scene3".to_string());
+    save_data.command_index = 4;
+    save_data.flags.insert("completed_intro".to_string(), true);
+    assert!(
+        !save_data
+            .compatibility_issues(runtime.scenario())
+            .is_empty()
+    );
+
+    runtime.from_save_data_at_scene_start(&save_data).unwrap();
+    assert_eq!(
+        runtime.current_scene(),
+        Some(&SceneId::new("scene1".to_string()))
+    );
+    assert_eq!(runtime.command_index(), 0);
+    assert!(runtime.flags().is_set(&FlagId::new("completed_intro")));
+}
+
+#[test]
+fn test_restart_from_checkpoint() {
+    let mut runtime = ScenarioRuntime::new(create_test_scenario());
+    runtime.start().unwrap();
+    assert_eq!(
+        runtime.checkpoint().map(|c| c.scene.clone()),
+        Some(SceneId::new("scene1".to_string()))
+    );
+
+    // Entering scene2 records a checkpoint with the flags of that moment
+    runtime.flags_mut().set(FlagId::new("before_scene2"), true);
+    runtime
+        .jump_to_scene(&SceneId::new("scene2".to_string()))
+        .unwrap();
+    runtime.flags_mut().set(FlagId::new("inside_scene2"), true);
+    runtime.advance_command();
+
+    // The scenario was updated and scene2 got shorter than the saved position
+    let mut save_data = runtime.to_save_data(1);
+    save_data.command_index = 10;
+    let checkpoint = save_data.checkpoint.clone().unwrap();
+    assert_eq!(checkpoint.scene, SceneId::new("scene2".to_string()));
+    assert_eq!(checkpoint.flags.get("before_scene2"), Some(&true));
+    assert!(!checkpoint.flags.contains_key("inside_scene2"));
+
+    let mut loaded = ScenarioRuntime::new(create_test_scenario());
+    loaded.restart_from_checkpoint(&save_data).unwrap();
+    assert_eq!(
+        loaded.current_scene(),
+        Some(&SceneId::new("scene2".to_string()))
+    );
+    assert_eq!(loaded.command_index(), 0);
+    assert!(loaded.flags().is_set(&FlagId::new("before_scene2")));
+    assert!(!loaded.flags().is_set(&FlagId::new("inside_scene2")));
+
+    // Saves without a checkpoint can't restart from one
+    save_data.checkpoint = None;
+    assert!(loaded.restart_from_checkpoint(&save_data).is_err());
+}
+
+#[test]
+fn test_save_records_choice_order() {
+    let metadata = ScenarioMetadata::new("quiz", "Quiz");
+    let mut scenario = Scenario::new(metadata, "question");
+    let mut question = Scene::new("question", "Question");
+    let choice = Choice::new(
+        (0..5)
+            .map(|i| ChoiceOption::new(format!("Answer {}", i), "question"))
+            .collect(),
+    )
+    .with_shuffle(true);
+    question.add_command(ScenarioCommand::ShowChoice { choice });
+    scenario.add_scene("question", question);
+
+    let mut runtime = ScenarioRuntime::new(scenario.clone());
+    runtime.set_choice_seed(99);
+    runtime.start().unwrap();
+    let order = runtime.choice_display_order().unwrap();
+
+    let mut save_data = runtime.to_save_data(1);
+    assert_eq!(save_data.choice_seed, 99);
+    assert_eq!(save_data.choice_order, Some(order.clone()));
+
+    // The recorded order wins over the seed when loading
+    save_data.choice_seed = 100;
+    let mut loaded = ScenarioRuntime::new(scenario);
+    loaded.from_save_data(&save_data).unwrap();
+    assert_eq!(loaded.choice_seed(), 100);
+    assert_eq!(loaded.choice_display_order(), Some(order));
+
+    // Saves away from a choice don't record an order
+    let mut runtime = ScenarioRuntime::new(create_test_scenario());
+    runtime.start().unwrap();
+    assert_eq!(runtime.to_save_data(1).choice_order, None);
+}
+
+#[test]
+fn test_save_load_restores_active_effects() {
+    let mut scenario = create_test_scenario();
+    if let Some(scene) = scenario.scenes.get_mut("scene1") {
+        scene.commands.insert(
+            0,
+            ScenarioCommand::ShowEffect {
+                effect: "rain".to_string(),
+                intensity: 0.8,
+                duration: None,
+                lifetime: EffectLifetime::Persistent,
+            },
+        );
+    }
+    let mut runtime = ScenarioRuntime::new(scenario.clone());
+    runtime.start().unwrap();
+    runtime.execute_current_command().unwrap();
+    runtime.advance_command();
+
+    let save_data = runtime.to_save_data(1);
+    assert_eq!(save_data.active_effects.len(), 1);
+
+    let mut loaded = ScenarioRuntime::new(scenario);
+    loaded.from_save_data(&save_data).unwrap();
+    assert_eq!(loaded.active_effects(), runtime.active_effects());
+}
+
+#[test]
+fn test_save_load_restores_hud_visibility() {
+    let mut scenario = create_test_scenario();
+    if let Some(scene) = scenario.scenes.get_mut("scene1") {
+        scene.commands.insert(
+            0,
+            ScenarioCommand::SetHudVisible {
+                visible: false,
+                widgets: vec!["day".to_string()],
+            },
+        );
+    }
+    let mut runtime = ScenarioRuntime::new(scenario.clone());
+    runtime.start().unwrap();
+    runtime.execute_current_command().unwrap();
+    runtime.advance_command();
+    assert_eq!(runtime.hud_visibility().widgets.get("day"), Some(&false));
+
+    let save_data = runtime.to_save_data(1);
+    let mut loaded = ScenarioRuntime::new(scenario);
+    loaded.from_save_data(&save_data).unwrap();
+    assert_eq!(loaded.hud_visibility(), runtime.hud_visibility());
+}
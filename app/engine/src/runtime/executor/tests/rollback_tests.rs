@@ -0,0 +1,270 @@
+//! Tests for rollback to earlier dialogue lines
+
+use super::*;
+
+fn create_rollback_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("rollback", "Rollback");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::PlayBgm {
+        asset: AssetRef::from("bgm_day"),
+        volume: 0.8,
+        fade_in: 0.0,
+    });
+    scene.add_command(ScenarioCommand::ShowBackground {
+        asset: AssetRef::from("bg_room"),
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("One"),
+    });
+    scene.add_command(ScenarioCommand::SetFlag {
+        flag_name: "met_alice".to_string(),
+        value: true,
+    });
+    scene.add_command(ScenarioCommand::ShowCharacter {
+        character_id: "alice".to_string(),
+        sprite: AssetRef::from("alice_happy"),
+        position: CharacterPosition::Center,
+        expression: None,
+        outfit: None,
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Two"),
+    });
+    scene.add_command(ScenarioCommand::StopBgm { fade_out: 0.0 });
+    scene.add_command(ScenarioCommand::ShowBackground {
+        asset: AssetRef::from("bg_street"),
+        transition: Transition::instant(),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Three"),
+    });
+    scene.add_command(ScenarioCommand::End);
+
+    scenario.add_scene("scene1", scene);
+    scenario
+}
+
+/// Execute commands up to the next dialogue line and display it, like the game loop
+fn play_to_next_line(runtime: &mut ScenarioRuntime) {
+    loop {
+        if let Some(ScenarioCommand::Dialogue { dialogue }) = runtime.get_current_command() {
+            let speaker = dialogue.speaker.clone();
+            let text = dialogue.text.clone();
+            let scene = runtime.current_scene().unwrap().clone();
+            let index = runtime.command_index();
+            runtime.add_to_backlog(scene, index, speaker, text);
+            return;
+        }
+        runtime.execute_current_command().unwrap();
+        assert!(runtime.advance_command());
+    }
+}
+
+/// Advance past the displayed line to the next one
+fn advance_line(runtime: &mut ScenarioRuntime) {
+    runtime.execute_current_command().unwrap();
+    assert!(runtime.advance_command());
+    play_to_next_line(runtime);
+}
+
+fn started_runtime() -> ScenarioRuntime {
+    let mut runtime = ScenarioRuntime::new(create_rollback_scenario());
+    runtime.start().unwrap();
+    play_to_next_line(&mut runtime);
+    runtime
+}
+
+#[test]
+fn test_rollback_restores_state_of_previous_line() {
+    let mut runtime = started_runtime();
+    advance_line(&mut runtime);
+    advance_line(&mut runtime);
+
+    assert_eq!(runtime.command_index(), 8);
+    assert_eq!(runtime.current_bgm(), None);
+    assert_eq!(
+        runtime.current_background(),
+        Some(&AssetRef::from("bg_street"))
+    );
+
+    assert!(runtime.rollback());
+    assert_eq!(runtime.command_index(), 5);
+    assert!(runtime.flags().get(&FlagId::new("met_alice")));
+    assert!(runtime.displayed_characters().contains_key("alice"));
+    assert_eq!(
+        runtime.current_background(),
+        Some(&AssetRef::from("bg_room"))
+    );
+    assert_eq!(
+        runtime.current_bgm().map(|bgm| &bgm.asset),
+        Some(&AssetRef::from("bgm_day"))
+    );
+
+    assert!(runtime.rollback());
+    assert_eq!(runtime.command_index(), 2);
+    assert!(!runtime.flags().get(&FlagId::new("met_alice")));
+    assert!(runtime.displayed_characters().is_empty());
+    assert!(!runtime.can_rollback());
+    assert!(!runtime.rollback());
+}
+
+#[test]
+fn test_roll_forward_returns_to_newest_line() {
+    let mut runtime = started_runtime();
+    advance_line(&mut runtime);
+    advance_line(&mut runtime);
+    assert!(!runtime.can_roll_forward());
+
+    assert!(runtime.rollback());
+    assert!(runtime.rollback());
+    assert!(runtime.can_roll_forward());
+
+    assert!(runtime.roll_forward());
+    assert_eq!(runtime.command_index(), 5);
+    assert!(runtime.roll_forward());
+    assert_eq!(runtime.command_index(), 8);
+    assert_eq!(runtime.current_bgm(), None);
+    assert!(!runtime.can_roll_forward());
+    assert!(!runtime.roll_forward());
+}
+
+#[test]
+fn test_playing_on_after_rollback_replaces_later_lines() {
+    let mut runtime = started_runtime();
+    advance_line(&mut runtime);
+    advance_line(&mut runtime);
+
+    assert!(runtime.rollback());
+    assert!(runtime.rollback());
+
+    // Change the outcome of the replayed lines
+    runtime.flags_mut().set(FlagId::new("changed"), true);
+    advance_line(&mut runtime);
+
+    assert_eq!(runtime.command_index(), 5);
+    assert!(!runtime.can_roll_forward());
+    assert!(runtime.flags().get(&FlagId::new("changed")));
+
+    assert!(runtime.rollback());
+    assert_eq!(runtime.command_index(), 2);
+    assert!(runtime.roll_forward());
+    assert!(runtime.flags().get(&FlagId::new("changed")));
+    assert!(!runtime.roll_forward());
+}
+
+#[test]
+fn test_rollback_history_is_bounded() {
+    let metadata = ScenarioMetadata::new("long", "Long");
+    let mut scenario = Scenario::new(metadata, "scene1");
+    let mut scene = Scene::new("scene1", "Scene 1");
+    for i in 0..MAX_ROLLBACK_STEPS + 10 {
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator(format!("Line {}", i)),
+        });
+    }
+    scene.add_command(ScenarioCommand::End);
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    play_to_next_line(&mut runtime);
+    for _ in 1..MAX_ROLLBACK_STEPS + 10 {
+        advance_line(&mut runtime);
+    }
+
+    let mut steps = 0;
+    while runtime.rollback() {
+        steps += 1;
+    }
+    assert_eq!(steps, MAX_ROLLBACK_STEPS - 1);
+    assert_eq!(runtime.command_index(), 10);
+}
+
+#[test]
+fn test_loading_save_clears_rollback_history() {
+    let mut runtime = started_runtime();
+    advance_line(&mut runtime);
+    assert!(runtime.can_rollback());
+
+    let save_data = runtime.to_save_data(1);
+    runtime.from_save_data(&save_data).unwrap();
+    assert!(!runtime.can_rollback());
+}
+
+fn create_choice_scenario() -> Scenario {
+    let metadata = ScenarioMetadata::new("choice_rollback", "Choice Rollback");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene1 = Scene::new("scene1", "Scene 1");
+    scene1.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Before"),
+    });
+    scene1.add_command(ScenarioCommand::ShowChoice {
+        choice: Choice::new(vec![
+            ChoiceOption::new("Go", "scene2").with_flag("went"),
+            ChoiceOption::new("Stay", "scene2"),
+        ]),
+    });
+    scenario.add_scene("scene1", scene1);
+
+    let mut scene2 = Scene::new("scene2", "Scene 2");
+    scene2.add_command(ScenarioCommand::ShowBackground {
+        asset: AssetRef::from("bg_street"),
+        transition: Transition::instant(),
+    });
+    scene2.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("After"),
+    });
+    scene2.add_command(ScenarioCommand::End);
+    scenario.add_scene("scene2", scene2);
+    scenario
+}
+
+#[test]
+fn test_return_to_last_choice() {
+    let mut runtime = ScenarioRuntime::new(create_choice_scenario());
+    runtime.start().unwrap();
+    play_to_next_line(&mut runtime);
+    assert!(!runtime.can_return_to_choice());
+    assert!(!runtime.return_to_last_choice());
+
+    runtime.execute_current_command().unwrap();
+    assert!(runtime.advance_command());
+    runtime.execute_current_command().unwrap();
+    assert!(runtime.can_return_to_choice());
+    runtime.select_choice(0).unwrap();
+    play_to_next_line(&mut runtime);
+    assert!(runtime.flags().get(&FlagId::new("went")));
+
+    assert!(runtime.return_to_last_choice());
+    assert_eq!(
+        runtime.current_scene(),
+        Some(&SceneId::new("scene1".to_string()))
+    );
+    assert_eq!(runtime.command_index(), 1);
+    assert!(!runtime.flags().get(&FlagId::new("went")));
+    assert_eq!(runtime.current_background(), None);
+    assert!(!runtime.can_roll_forward());
+
+    // The choice can be picked again
+    runtime.select_choice(1).unwrap();
+    assert!(!runtime.flags().get(&FlagId::new("went")));
+}
+
+#[test]
+fn test_starting_clears_last_choice() {
+    let mut runtime = ScenarioRuntime::new(create_choice_scenario());
+    runtime.start().unwrap();
+    play_to_next_line(&mut runtime);
+    runtime.execute_current_command().unwrap();
+    assert!(runtime.advance_command());
+    runtime.execute_current_command().unwrap();
+    assert!(runtime.can_return_to_choice());
+
+    runtime.start().unwrap();
+    assert!(!runtime.can_return_to_choice());
+}
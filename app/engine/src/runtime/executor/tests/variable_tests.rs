@@ -586,3 +586,108 @@ fn test_modify_variable_in_if_block() {
         Some(&VariableValue::Int(110))
     );
 }
+
+#[test]
+fn test_input_name_stores_string_variable() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::InputName {
+        variable: "player_name".to_string(),
+        prompt: Some("Your name?".to_string()),
+        default: "Alex".to_string(),
+        max_length: 5,
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Welcome, {$player_name}."),
+    });
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let Ok(CommandExecutionResult::InputName(state)) = runtime.execute_current_command() else {
+        panic!("Expected a name input");
+    };
+    assert_eq!(state.variable, "player_name");
+    assert_eq!(state.prompt.as_deref(), Some("Your name?"));
+
+    // Trimmed and cut to the maximum length
+    runtime.submit_name("  Alexandra ").unwrap();
+    let var_id = VariableId::new("player_name");
+    assert_eq!(
+        runtime.variables().get(&var_id),
+        Some(&VariableValue::String("Alexa".to_string()))
+    );
+    assert_eq!(
+        runtime.variables().substitute("Welcome, {$player_name}."),
+        "Welcome, Alexa."
+    );
+
+    // An empty name falls back to the default
+    runtime.submit_name("   ").unwrap();
+    assert_eq!(
+        runtime.variables().get(&var_id),
+        Some(&VariableValue::String("Alex".to_string()))
+    );
+
+    // The name survives a save
+    let save = runtime.to_save_data(0);
+    let mut loaded = ScenarioRuntime::new(runtime.scenario().clone());
+    loaded.from_save_data(&save).unwrap();
+    assert_eq!(
+        loaded.variables().get(&var_id),
+        Some(&VariableValue::String("Alex".to_string()))
+    );
+
+    // Only the name input command accepts a name
+    runtime.advance_command();
+    assert!(runtime.submit_name("Sakura").is_err());
+}
+
+#[test]
+fn test_modify_variable_expression() {
+    let metadata = ScenarioMetadata::new("test", "Test");
+    let mut scenario = Scenario::new(metadata, "scene1");
+
+    let mut scene = Scene::new("scene1", "Scene 1");
+    scene.add_command(ScenarioCommand::SetVariable {
+        variable_name: "trust".to_string(),
+        value: VariableValue::Int(3),
+    });
+    // `affection` is unset and reads as 0
+    scene.add_command(ScenarioCommand::ModifyVariable {
+        variable_name: "affection".to_string(),
+        operation: VariableOperation::Expression {
+            expression: "affection + trust * 2".parse().unwrap(),
+        },
+    });
+    scene.add_command(ScenarioCommand::ModifyVariable {
+        variable_name: "affection".to_string(),
+        operation: VariableOperation::Expression {
+            expression: "affection / 0".parse().unwrap(),
+        },
+    });
+    scenario.add_scene("scene1", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    runtime.execute_current_command().unwrap();
+    runtime.advance_command();
+    runtime.execute_current_command().unwrap();
+    runtime.advance_command();
+
+    let var_id = VariableId::new("affection");
+    assert_eq!(
+        runtime.variables().get(&var_id),
+        Some(&VariableValue::Int(6))
+    );
+
+    assert!(runtime.execute_current_command().is_err());
+    assert_eq!(
+        runtime.variables().get(&var_id),
+        Some(&VariableValue::Int(6))
+    );
+}
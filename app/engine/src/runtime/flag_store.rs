@@ -36,6 +36,11 @@ impl FlagStore {
         self.set(flag.clone(), !current);
     }
 
+    /// Iterate over the flags that have been set (true or false), in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&FlagId, bool)> {
+        self.flags.iter().map(|(flag, value)| (flag, *value))
+    }
+
     /// Clear all flags
     pub fn clear(&mut self) {
         self.flags.clear();
@@ -5,15 +5,28 @@
 
 mod executor;
 mod flag_store;
+#[cfg(feature = "scripting")]
+mod script;
 mod state_machine;
+mod text_log;
 mod variable_store;
 
-pub use executor::{CommandExecutionResult, DisplayedCharacter, ScenarioRuntime};
+#[cfg(any(test, feature = "test-util"))]
+pub use executor::ScenarioRuntimeBuilder;
+pub use executor::{
+    ActiveEffect, BranchKind, BranchPreview, CallFrame, CommandEvent, CommandExecutionResult,
+    CommandHook, ConditionCheck, DisplayedCharacter, PlayingBgm, ScenarioJump, ScenarioReload,
+    ScenarioRuntime, SkipStop,
+};
 pub use flag_store::FlagStore;
 pub use narrative_core::{ReadHistory, TransitionKind};
+#[cfg(feature = "scripting")]
+pub use script::{ScriptHost, ScriptOutcome};
 pub use state_machine::{
-    AppState, BacklogState, CgGalleryState, CgViewerState, ChoiceState, EffectKind, EffectState,
-    InGameState, LayoutMode, LoadingState, MainMenuState, PauseMenuState, SaveLoadState,
-    SettingsState, TransitionState, TypingState, WaitState, WaitingInputState,
+    AchievementsState, AppState, BacklogState, CgGalleryState, CgViewerState, ChoiceState,
+    EffectKind, EffectState, EndingState, InGameState, LayoutMode, LoadingState, MainMenuState,
+    NameInputState, PauseMenuState, SaveLoadState, SettingsState, TransitionState, TypingState,
+    VideoState, WaitState, WaitingInputState,
 };
+pub use text_log::{TextLog, TextLogFormat, TextLogLine, TextLogSource, default_export_dir};
 pub use variable_store::VariableStore;
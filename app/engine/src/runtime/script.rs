@@ -0,0 +1,331 @@
+//! Scenario scripting
+//!
+//! `Script` commands run [Rhai](https://rhai.rs) code against the runtime
+//! state, for game logic that has no dedicated command (shops, stat checks,
+//! mini-game results). Scripts see the running game as the `game` object:
+//!
+//! ```text
+//! let gold = game.get_var("gold");
+//! if gold >= 100 {
+//!     game.set_var("gold", gold - 100);
+//!     game.set_flag("bought_sword", true);
+//!     game.jump("shop_thanks");
+//! }
+//! ```
+//!
+//! | Method | Description |
+//! |--------|-------------|
+//! | `game.scene` | ID of the current scene |
+//! | `game.get_flag(name)` | Flag value (`false` if unset) |
+//! | `game.set_flag(name, value)` | Set a flag |
+//! | `game.get_var(name)` | Variable value (`()` if unset) |
+//! | `game.has_var(name)` | Whether a variable is set |
+//! | `game.set_var(name, value)` | Set a bool, int, float or string variable |
+//! | `game.jump(scene)` | Jump to a scene after the script finishes |
+//!
+//! Scripts run against a copy of the flags and variables, so a script that
+//! fails leaves the runtime unchanged.
+
+use super::{FlagStore, VariableStore};
+use crate::error::{EngineError, EngineResult};
+use lru::LruCache;
+use narrative_core::{FlagId, VariableId, VariableValue};
+use rhai::{AST, Dynamic, Engine, EvalAltResult, Scope};
+use std::num::NonZeroUsize;
+
+/// Name of the game object in script scope
+const GAME_OBJECT: &str = "game";
+
+/// Maximum operations per script run, so a runaway loop can't hang the game
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// Default number of compiled scripts kept by [`ScriptHost`]
+const DEFAULT_SCRIPT_CACHE_CAPACITY: usize = 64;
+
+/// Runtime state exposed to a script as the `game` object
+#[derive(Debug, Clone)]
+struct ScriptContext {
+    scene: String,
+    flags: FlagStore,
+    variables: VariableStore,
+    jump: Option<String>,
+}
+
+impl ScriptContext {
+    fn get_flag(&mut self, name: &str) -> bool {
+        self.flags.get(&FlagId::new(name))
+    }
+
+    fn set_flag(&mut self, name: &str, value: bool) {
+        self.flags.set(FlagId::new(name), value);
+    }
+
+    fn get_var(&mut self, name: &str) -> Dynamic {
+        match self.variables.get(&VariableId::new(name)) {
+            Some(VariableValue::Bool(value)) => Dynamic::from(*value),
+            Some(VariableValue::Int(value)) => Dynamic::from(*value),
+            Some(VariableValue::Float(value)) => Dynamic::from(*value),
+            Some(VariableValue::String(value)) => Dynamic::from(value.clone()),
+            None => Dynamic::UNIT,
+        }
+    }
+
+    fn has_var(&mut self, name: &str) -> bool {
+        self.variables.get(&VariableId::new(name)).is_some()
+    }
+
+    fn set_var(&mut self, name: &str, value: Dynamic) -> Result<(), Box<EvalAltResult>> {
+        let type_name = value.type_name();
+        let value = if let Ok(value) = value.as_bool() {
+            VariableValue::Bool(value)
+        } else if let Ok(value) = value.as_int() {
+            VariableValue::Int(value)
+        } else if let Ok(value) = value.as_float() {
+            VariableValue::Float(value)
+        } else if let Ok(value) = value.into_string() {
+            VariableValue::String(value)
+        } else {
+            return Err(format!(
+                "Variable '{}' can't hold a value of type {}",
+                name, type_name
+            )
+            .into());
+        };
+        self.variables.set(VariableId::new(name), value);
+        Ok(())
+    }
+
+    fn jump(&mut self, scene: &str) {
+        self.jump = Some(scene.to_string());
+    }
+}
+
+/// Result of a script run
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ScriptOutcome {
+    /// Scene the script asked to jump to
+    pub jump: Option<String>,
+}
+
+/// Script engine of a scenario runtime
+///
+/// Compiled scripts are cached by source, so a script inside a loop or a
+/// revisited scene is parsed once. The cache holds a fixed number of
+/// scripts and drops the least recently run one when full.
+pub struct ScriptHost {
+    engine: Engine,
+    compiled: LruCache<String, AST>,
+}
+
+impl ScriptHost {
+    /// Create a script host with the `game` API registered, caching up to
+    /// 64 compiled scripts
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_SCRIPT_CACHE_CAPACITY)
+    }
+
+    /// Create a script host caching up to `capacity` compiled scripts (at least 1)
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine
+            .register_type_with_name::<ScriptContext>("Game")
+            .register_get("scene", |context: &mut ScriptContext| context.scene.clone())
+            .register_fn("get_flag", ScriptContext::get_flag)
+            .register_fn("set_flag", ScriptContext::set_flag)
+            .register_fn("get_var", ScriptContext::get_var)
+            .register_fn("has_var", ScriptContext::has_var)
+            .register_fn("set_var", ScriptContext::set_var)
+            .register_fn("jump", ScriptContext::jump);
+
+        Self {
+            engine,
+            compiled: LruCache::new(cache_capacity(capacity)),
+        }
+    }
+
+    /// Change how many compiled scripts are kept, dropping the least
+    /// recently run ones beyond the new capacity
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.compiled.resize(cache_capacity(capacity));
+    }
+
+    /// Number of compiled scripts currently cached
+    pub fn cached_count(&self) -> usize {
+        self.compiled.len()
+    }
+
+    /// Run a script against the given flags and variables
+    ///
+    /// `scene` is the current scene ID. On success the stores hold the values
+    /// the script set; on error they are left unchanged.
+    pub fn run(
+        &mut self,
+        source: &str,
+        scene: &str,
+        flags: &mut FlagStore,
+        variables: &mut VariableStore,
+    ) -> EngineResult<ScriptOutcome> {
+        let engine = &self.engine;
+        let ast = self.compiled.try_get_or_insert_ref(source, || {
+            engine
+                .compile(source)
+                .map_err(|e| EngineError::Script(e.to_string()))
+        })?;
+
+        let mut scope = Scope::new();
+        scope.push(
+            GAME_OBJECT,
+            ScriptContext {
+                scene: scene.to_string(),
+                flags: flags.clone(),
+                variables: variables.clone(),
+                jump: None,
+            },
+        );
+
+        self.engine
+            .run_ast_with_scope(&mut scope, ast)
+            .map_err(|e| EngineError::Script(e.to_string()))?;
+
+        let context = scope.remove::<ScriptContext>(GAME_OBJECT).ok_or_else(|| {
+            EngineError::Script(format!("Script replaced the `{}` object", GAME_OBJECT))
+        })?;
+        *flags = context.flags;
+        *variables = context.variables;
+
+        Ok(ScriptOutcome { jump: context.jump })
+    }
+}
+
+impl Default for ScriptHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn cache_capacity(capacity: usize) -> NonZeroUsize {
+    NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(
+        source: &str,
+        flags: &mut FlagStore,
+        variables: &mut VariableStore,
+    ) -> EngineResult<ScriptOutcome> {
+        ScriptHost::new().run(source, "start", flags, variables)
+    }
+
+    #[test]
+    fn test_script_reads_and_sets_state() {
+        let mut flags = FlagStore::new();
+        let mut variables = VariableStore::new();
+        flags.set(FlagId::new("met_mia"), true);
+        variables.set(VariableId::new("gold"), VariableValue::Int(150));
+
+        let outcome = run(
+            r#"
+            if game.get_flag("met_mia") && game.get_var("gold") >= 100 {
+                game.set_var("gold", game.get_var("gold") - 100);
+                game.set_var("title", "Knight");
+                game.set_var("ratio", 0.5);
+                game.set_flag("bought_sword", true);
+            }
+            "#,
+            &mut flags,
+            &mut variables,
+        )
+        .unwrap();
+
+        assert_eq!(outcome, ScriptOutcome::default());
+        assert!(flags.get(&FlagId::new("bought_sword")));
+        assert_eq!(
+            variables.get(&VariableId::new("gold")),
+            Some(&VariableValue::Int(50))
+        );
+        assert_eq!(
+            variables.get(&VariableId::new("title")),
+            Some(&VariableValue::String("Knight".to_string()))
+        );
+        assert_eq!(
+            variables.get(&VariableId::new("ratio")),
+            Some(&VariableValue::Float(0.5))
+        );
+    }
+
+    #[test]
+    fn test_script_jump_and_scene() {
+        let mut flags = FlagStore::new();
+        let mut variables = VariableStore::new();
+
+        let outcome = run(
+            r#"
+            if game.scene == "start" && !game.has_var("gold") {
+                game.jump("shop");
+            }
+            "#,
+            &mut flags,
+            &mut variables,
+        )
+        .unwrap();
+
+        assert_eq!(outcome.jump.as_deref(), Some("shop"));
+    }
+
+    #[test]
+    fn test_failed_script_leaves_state_unchanged() {
+        let mut flags = FlagStore::new();
+        let mut variables = VariableStore::new();
+
+        let result = run(
+            r#"
+            game.set_flag("partial", true);
+            game.set_var("items", [1, 2]);
+            "#,
+            &mut flags,
+            &mut variables,
+        );
+
+        assert!(matches!(result, Err(EngineError::Script(_))));
+        assert!(!flags.get(&FlagId::new("partial")));
+    }
+
+    #[test]
+    fn test_syntax_error_is_reported() {
+        let result = run(
+            "game.set_flag(",
+            &mut FlagStore::new(),
+            &mut VariableStore::new(),
+        );
+        assert!(matches!(result, Err(EngineError::Script(_))));
+    }
+
+    #[test]
+    fn test_runaway_script_is_stopped() {
+        let result = run("loop { }", &mut FlagStore::new(), &mut VariableStore::new());
+        assert!(matches!(result, Err(EngineError::Script(_))));
+    }
+
+    #[test]
+    fn test_compiled_scripts_are_bounded() {
+        let mut host = ScriptHost::with_capacity(2);
+        let (mut flags, mut variables) = (FlagStore::new(), VariableStore::new());
+        for i in 0..5 {
+            let source = format!("game.set_var(\"step\", {})", i);
+            host.run(&source, "start", &mut flags, &mut variables)
+                .unwrap();
+        }
+        assert_eq!(host.cached_count(), 2);
+
+        host.set_capacity(1);
+        assert_eq!(host.cached_count(), 1);
+        assert_eq!(
+            variables.get(&VariableId::new("step")),
+            Some(&VariableValue::Int(4))
+        );
+    }
+}
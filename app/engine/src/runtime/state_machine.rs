@@ -25,7 +25,11 @@
 //!
 //! See `docs/design/engine/runtime.md` for full design details.
 
-use narrative_core::{CharacterId, ChoiceOption, SceneId, TransitionKind};
+use crate::asset::LoadProgress;
+use crate::text::TypewriterEffect;
+use narrative_core::{
+    AssetRef, CharacterId, ChoiceOption, EndBehavior, ScenarioCommand, SceneId, TransitionKind,
+};
 use std::sync::Arc;
 
 // =============================================================================
@@ -62,6 +66,10 @@ pub struct LoadingState {
     pub total_tasks: usize,
     /// Number of completed tasks
     pub completed_tasks: usize,
+    /// Game state entered when loading completes
+    ///
+    /// `None` for the startup load, which ends on the main menu.
+    pub next: Option<Box<InGameState>>,
 }
 
 /// Main menu state
@@ -99,6 +107,10 @@ pub enum InGameState {
     PlayingEffect(EffectState),
     /// Waiting for duration (Wait command)
     Waiting(WaitState),
+    /// Playing a video cutscene (PlayVideo command)
+    PlayingVideo(VideoState),
+    /// Asking the player for a name (InputName command)
+    InputtingName(NameInputState),
     /// Pause menu
     PauseMenu(PauseMenuState),
     /// Save/load menu
@@ -109,6 +121,10 @@ pub enum InGameState {
     CgGallery(CgGalleryState),
     /// CG viewer (full-size CG display)
     CgViewer(CgViewerState),
+    /// Achievement list
+    Achievements(AchievementsState),
+    /// End-of-content screen (credits or ending card)
+    Ending(EndingState),
 }
 
 /// Typewriter text display state
@@ -120,12 +136,12 @@ pub struct TypingState {
     pub command_index: usize,
     /// Speaker name (None for narrator)
     pub speaker: Option<String>,
-    /// Dialogue text to display (Arc<str> for efficient cloning during rendering)
+    /// Dialogue text to display, typewriter tags removed (Arc<str> for efficient cloning during rendering)
     pub text: Arc<str>,
-    /// Current character index being displayed
-    pub char_index: usize,
-    /// Elapsed time since last character
-    pub elapsed: f32,
+    /// Second-language text shown under the dialogue (dual-language mode), tags removed
+    pub translation: Option<Arc<str>>,
+    /// Typewriter reveal progress
+    pub typewriter: TypewriterEffect,
     /// Auto mode enabled
     pub auto_mode: bool,
     /// Skip mode enabled
@@ -193,6 +209,8 @@ pub enum EffectKind {
     Shake { intensity: f32 },
     /// Screen flash
     Flash { color: [f32; 4] },
+    /// Gentle fade in and out of a color (comfort mode replacement for flashes)
+    Fade { color: [f32; 4] },
     /// Character animation
     CharacterAnimation { character_id: CharacterId },
 }
@@ -210,6 +228,30 @@ impl Default for WaitState {
     }
 }
 
+/// Video cutscene state (for PlayVideo command)
+///
+/// The decoder lives with the renderer; this only tracks what is playing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoState {
+    /// Video file
+    pub path: AssetRef,
+    /// Whether the player can end the video early
+    pub skippable: bool,
+}
+
+/// Name entry state (for InputName command)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameInputState {
+    /// Variable the name is stored in
+    pub variable: String,
+    /// Text shown above the entry field
+    pub prompt: Option<String>,
+    /// Name stored when the entry is left empty
+    pub default: String,
+    /// Maximum name length in characters
+    pub max_length: usize,
+}
+
 /// Pause menu state
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct PauseMenuState {
@@ -249,6 +291,13 @@ pub struct BacklogState {
     pub hovered_index: Option<usize>,
 }
 
+/// Achievement list state
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AchievementsState {
+    /// Currently selected achievement index
+    pub selected: usize,
+}
+
 /// CG Gallery state
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct CgGalleryState {
@@ -354,6 +403,46 @@ impl CgViewerState {
     }
 }
 
+/// End-of-content screen state
+#[derive(Debug, Clone, PartialEq)]
+pub enum EndingState {
+    /// Scrolling credits
+    Credits {
+        /// Credit lines
+        lines: Vec<String>,
+        /// Scroll duration in seconds
+        duration: f32,
+    },
+    /// Ending card
+    Card {
+        /// Ending ID
+        ending_id: String,
+        /// Title shown on the card
+        title: String,
+    },
+}
+
+impl EndingState {
+    /// Create the ending state for a scenario's end behavior
+    ///
+    /// Returns `None` for behaviors that do not show an ending screen.
+    pub fn from_end_behavior(behavior: &EndBehavior) -> Option<Self> {
+        match behavior {
+            EndBehavior::Credits { lines, duration } => Some(Self::Credits {
+                lines: lines.clone(),
+                duration: *duration,
+            }),
+            EndBehavior::EndingCard { ending_id, title } => Some(Self::Card {
+                ending_id: ending_id.clone(),
+                title: title.clone(),
+            }),
+            EndBehavior::ReturnToTitle
+            | EndBehavior::Continue
+            | EndBehavior::NextScenario { .. } => None,
+        }
+    }
+}
+
 // =============================================================================
 // AppState Implementation
 // =============================================================================
@@ -413,6 +502,29 @@ impl LoadingState {
         self.set_progress(progress);
         self
     }
+
+    /// Loading screen shown until the assets of a game state are loaded
+    pub fn entering(next: InGameState) -> Self {
+        Self {
+            next: Some(Box::new(next)),
+            ..Self::default()
+        }
+    }
+
+    /// Show asset loading progress
+    ///
+    /// Returns the game state to enter once the loads are complete.
+    pub fn update_progress(&mut self, progress: &LoadProgress) -> Option<InGameState> {
+        self.total_tasks = progress.total;
+        self.completed_tasks = progress.completed;
+        self.current_task = progress.current.clone().unwrap_or_default();
+        self.set_progress(progress.fraction());
+        if progress.is_complete() {
+            self.next.take().map(|next| *next)
+        } else {
+            None
+        }
+    }
 }
 
 impl TypingState {
@@ -423,26 +535,39 @@ impl TypingState {
         speaker: Option<String>,
         text: String,
     ) -> Self {
+        let typewriter = TypewriterEffect::new(text, TypewriterEffect::DEFAULT_CHARS_PER_SECOND);
         Self {
             scene_id,
             command_index,
             speaker,
-            text: Arc::from(text),
-            char_index: 0,
-            elapsed: 0.0,
+            text: Arc::from(typewriter.full_text()),
+            translation: None,
+            typewriter,
             auto_mode: false,
             skip_mode: false,
         }
     }
 
+    /// Set the second-language text shown under the dialogue
+    pub fn with_translation(mut self, translation: Option<&str>) -> Self {
+        self.translation =
+            translation.map(|translation| Arc::from(TypewriterEffect::strip_tags(translation)));
+        self
+    }
+
     /// Get the total character count of the dialogue text
     pub fn text_length(&self) -> usize {
         self.text.chars().count()
     }
 
-    /// Check if all text has been displayed
+    /// Get the number of characters currently revealed
+    pub fn visible_chars(&self) -> usize {
+        self.typewriter.visible_chars()
+    }
+
+    /// Check if all text has been displayed (including trailing pauses)
     pub fn is_complete(&self) -> bool {
-        self.char_index >= self.text_length()
+        self.typewriter.is_complete()
     }
 }
 
@@ -472,6 +597,15 @@ impl TransitionState {
 }
 
 impl EffectState {
+    /// Shortest fade used in place of a flash in comfort mode (seconds)
+    pub const COMFORT_MIN_FADE_DURATION: f32 = 0.6;
+    /// Strongest screen shake allowed in comfort mode
+    pub const COMFORT_MAX_SHAKE_INTENSITY: f32 = 0.25;
+    /// Horizontal shake oscillations per second
+    const SHAKE_FREQUENCY_X: f32 = 23.0;
+    /// Vertical shake oscillations per second
+    const SHAKE_FREQUENCY_Y: f32 = 17.0;
+
     /// Create a new effect state
     pub fn new(kind: EffectKind, duration: f32) -> Self {
         Self {
@@ -503,6 +637,82 @@ impl EffectState {
             (self.elapsed / self.duration).clamp(0.0, 1.0)
         }
     }
+
+    /// Adjust the effect for comfort mode
+    ///
+    /// Flashes become fades lasting at least `COMFORT_MIN_FADE_DURATION`, and
+    /// screen shake intensity is capped at `COMFORT_MAX_SHAKE_INTENSITY`.
+    /// Applying it more than once has no further effect.
+    pub fn apply_comfort_mode(&mut self) {
+        match self.kind {
+            EffectKind::Flash { color } => {
+                self.kind = EffectKind::Fade { color };
+                self.duration = self.duration.max(Self::COMFORT_MIN_FADE_DURATION);
+            }
+            EffectKind::Shake { intensity } => {
+                self.kind = EffectKind::Shake {
+                    intensity: intensity.min(Self::COMFORT_MAX_SHAKE_INTENSITY),
+                };
+            }
+            EffectKind::Fade { .. } | EffectKind::CharacterAnimation { .. } => {}
+        }
+    }
+
+    /// Screen offset of a shake at the current time, in pixels
+    ///
+    /// `max_offset` is the offset of a full-intensity shake. The shake
+    /// starts at rest, oscillates on both axes and dies down linearly; other
+    /// effects don't move the screen.
+    pub fn shake_offset(&self, max_offset: f32) -> (f32, f32) {
+        let EffectKind::Shake { intensity } = self.kind else {
+            return (0.0, 0.0);
+        };
+        let amplitude = intensity * max_offset * (1.0 - self.progress_ratio());
+        let phase = self.elapsed * std::f32::consts::TAU;
+        (
+            amplitude * (phase * Self::SHAKE_FREQUENCY_X).sin(),
+            amplitude * (phase * Self::SHAKE_FREQUENCY_Y).sin(),
+        )
+    }
+
+    /// Color drawn over the whole screen at the current time
+    ///
+    /// Flashes start at their color's alpha and fade out; comfort mode fades
+    /// rise to it and back. Other effects draw nothing.
+    pub fn overlay_color(&self) -> Option<[f32; 4]> {
+        let progress = self.progress_ratio();
+        let (color, strength) = match self.kind {
+            EffectKind::Flash { color } => (color, 1.0 - progress),
+            EffectKind::Fade { color } => (color, 1.0 - (2.0 * progress - 1.0).abs()),
+            EffectKind::Shake { .. } | EffectKind::CharacterAnimation { .. } => return None,
+        };
+        let [r, g, b, a] = color;
+        Some([r, g, b, a * strength])
+    }
+
+    /// Effect played by a `ScreenShake` or `Flash` command
+    ///
+    /// Returns `None` for other commands.
+    pub fn from_command(command: &ScenarioCommand) -> Option<Self> {
+        match command {
+            ScenarioCommand::ScreenShake {
+                intensity,
+                duration,
+            } => Some(Self::new(
+                EffectKind::Shake {
+                    intensity: intensity.clamp(0.0, 1.0),
+                },
+                duration.max(0.0),
+            )),
+            ScenarioCommand::Flash { color, duration } => Some(Self::new(
+                EffectKind::Flash {
+                    color: [color.r, color.g, color.b, color.a],
+                },
+                duration.max(0.0),
+            )),
+            _ => None,
+        }
+    }
 }
 
 impl WaitState {
@@ -527,6 +737,49 @@ impl WaitState {
     }
 }
 
+impl VideoState {
+    /// Create a new video state
+    pub fn new(path: AssetRef, skippable: bool) -> Self {
+        Self { path, skippable }
+    }
+}
+
+impl NameInputState {
+    /// Name entry asked for by an `InputName` command
+    ///
+    /// Returns `None` for other commands.
+    pub fn from_command(command: &ScenarioCommand) -> Option<Self> {
+        match command {
+            ScenarioCommand::InputName {
+                variable,
+                prompt,
+                default,
+                max_length,
+            } => Some(Self {
+                variable: variable.clone(),
+                prompt: prompt.clone(),
+                default: default.clone(),
+                max_length: (*max_length).max(1),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Name to store for what the player entered
+    ///
+    /// Surrounding whitespace is removed, the name is cut to `max_length`
+    /// characters, and an empty entry becomes `default`.
+    pub fn resolve(&self, entered: &str) -> String {
+        let name: String = entered.trim().chars().take(self.max_length).collect();
+        let name = name.trim_end();
+        if name.is_empty() {
+            self.default.clone()
+        } else {
+            name.to_string()
+        }
+    }
+}
+
 impl ChoiceState {
     /// Check if the current selection is valid
     pub fn is_valid_selection(&self) -> bool {
@@ -650,16 +903,8 @@ mod tests {
 
     #[test]
     fn test_app_state_is_in_game() {
-        let typing_state = TypingState {
-            scene_id: SceneId::new("test_scene"),
-            command_index: 0,
-            speaker: None,
-            text: Arc::from("Hello"),
-            char_index: 0,
-            elapsed: 0.0,
-            auto_mode: false,
-            skip_mode: false,
-        };
+        let typing_state =
+            TypingState::new(SceneId::new("test_scene"), 0, None, "Hello".to_string());
         let state = AppState::InGame(InGameState::Typing(typing_state));
         assert!(!state.is_loading());
         assert!(!state.is_main_menu());
@@ -678,16 +923,12 @@ mod tests {
 
     #[test]
     fn test_app_state_in_game_state() {
-        let typing_state = TypingState {
-            scene_id: SceneId::new("test"),
-            command_index: 0,
-            speaker: Some("Alice".to_string()),
-            text: Arc::from("Test"),
-            char_index: 0,
-            elapsed: 0.0,
-            auto_mode: false,
-            skip_mode: false,
-        };
+        let typing_state = TypingState::new(
+            SceneId::new("test"),
+            0,
+            Some("Alice".to_string()),
+            "Test".to_string(),
+        );
         let state = AppState::InGame(InGameState::Typing(typing_state.clone()));
 
         assert!(state.in_game_state().is_some());
@@ -706,16 +947,12 @@ mod tests {
 
     #[test]
     fn test_in_game_state_is_typing() {
-        let state = InGameState::Typing(TypingState {
-            scene_id: SceneId::new("test"),
-            command_index: 0,
-            speaker: None,
-            text: Arc::from("Test"),
-            char_index: 0,
-            elapsed: 0.0,
-            auto_mode: false,
-            skip_mode: false,
-        });
+        let state = InGameState::Typing(TypingState::new(
+            SceneId::new("test"),
+            0,
+            None,
+            "Test".to_string(),
+        ));
         assert!(state.is_typing());
         assert!(!state.is_waiting_input());
         assert!(!state.is_showing_choices());
@@ -802,16 +1039,12 @@ mod tests {
     fn test_in_game_state_current_scene() {
         let scene_id = SceneId::new("test_scene");
 
-        let typing_state = InGameState::Typing(TypingState {
-            scene_id: scene_id.clone(),
-            command_index: 0,
-            speaker: None,
-            text: Arc::from("Test"),
-            char_index: 0,
-            elapsed: 0.0,
-            auto_mode: false,
-            skip_mode: false,
-        });
+        let typing_state = InGameState::Typing(TypingState::new(
+            scene_id.clone(),
+            0,
+            None,
+            "Test".to_string(),
+        ));
         assert_eq!(typing_state.current_scene(), Some(&scene_id));
 
         let waiting_state = InGameState::WaitingInput(WaitingInputState {
@@ -828,16 +1061,12 @@ mod tests {
 
     #[test]
     fn test_in_game_state_command_index() {
-        let typing_state = InGameState::Typing(TypingState {
-            scene_id: SceneId::new("test"),
-            command_index: 42,
-            speaker: None,
-            text: Arc::from("Test"),
-            char_index: 0,
-            elapsed: 0.0,
-            auto_mode: false,
-            skip_mode: false,
-        });
+        let typing_state = InGameState::Typing(TypingState::new(
+            SceneId::new("test"),
+            42,
+            None,
+            "Test".to_string(),
+        ));
         assert_eq!(typing_state.command_index(), Some(42));
 
         let pause_state = InGameState::PauseMenu(PauseMenuState::default());
@@ -883,6 +1112,32 @@ mod tests {
         assert_eq!(state.progress, 1.0);
     }
 
+    #[test]
+    fn test_loading_state_enters_game_when_complete() {
+        let next = InGameState::WaitingInput(WaitingInputState {
+            scene_id: SceneId::new("intro"),
+            command_index: 2,
+            auto_wait_elapsed: 0.0,
+            skip_mode: false,
+        });
+        let mut state = LoadingState::entering(next.clone());
+
+        let mut progress = LoadProgress {
+            completed: 1,
+            total: 4,
+            current: Some("bg/room.png".to_string()),
+        };
+        assert_eq!(state.update_progress(&progress), None);
+        assert_eq!(state.progress, 0.25);
+        assert_eq!(state.current_task, "bg/room.png");
+
+        progress.completed = 4;
+        progress.current = None;
+        assert_eq!(state.update_progress(&progress), Some(next));
+        assert_eq!(state.progress, 1.0);
+        assert_eq!(state.next, None);
+    }
+
     #[test]
     fn test_main_menu_state_default() {
         let state = MainMenuState::default();
@@ -946,8 +1201,7 @@ mod tests {
         assert_eq!(state.command_index, 5);
         assert_eq!(state.speaker, Some("Alice".to_string()));
         assert_eq!(&*state.text, "Hello, world!");
-        assert_eq!(state.char_index, 0);
-        assert_eq!(state.elapsed, 0.0);
+        assert_eq!(state.visible_chars(), 0);
         assert!(!state.auto_mode);
     }
 
@@ -957,7 +1211,8 @@ mod tests {
 
         assert!(!state.is_complete());
 
-        state.char_index = 4; // "Test" has 4 characters
+        state.typewriter.skip();
+        assert_eq!(state.visible_chars(), 4);
         assert!(state.is_complete());
     }
 
@@ -1168,6 +1423,121 @@ mod tests {
         assert_eq!(state.progress_ratio(), 0.5);
     }
 
+    #[test]
+    fn test_effect_state_comfort_mode() {
+        let color = [1.0, 1.0, 1.0, 1.0];
+        let mut flash = EffectState::new(EffectKind::Flash { color }, 0.1);
+        flash.apply_comfort_mode();
+        assert_eq!(flash.kind, EffectKind::Fade { color });
+        assert_eq!(flash.duration, EffectState::COMFORT_MIN_FADE_DURATION);
+
+        let mut shake = EffectState::new(EffectKind::Shake { intensity: 2.0 }, 0.5);
+        shake.apply_comfort_mode();
+        shake.apply_comfort_mode();
+        assert_eq!(
+            shake.kind,
+            EffectKind::Shake {
+                intensity: EffectState::COMFORT_MAX_SHAKE_INTENSITY
+            }
+        );
+        assert_eq!(shake.duration, 0.5);
+    }
+
+    #[test]
+    fn test_effect_state_from_command() {
+        let shake = EffectState::from_command(&ScenarioCommand::ScreenShake {
+            intensity: 3.0,
+            duration: 0.4,
+        });
+        assert_eq!(
+            shake,
+            Some(EffectState::new(EffectKind::Shake { intensity: 1.0 }, 0.4))
+        );
+
+        let flash = EffectState::from_command(&ScenarioCommand::Flash {
+            color: narrative_core::Color::new(1.0, 0.5, 0.0, 0.8),
+            duration: 0.2,
+        });
+        assert_eq!(
+            flash,
+            Some(EffectState::new(
+                EffectKind::Flash {
+                    color: [1.0, 0.5, 0.0, 0.8]
+                },
+                0.2
+            ))
+        );
+
+        assert_eq!(
+            EffectState::from_command(&ScenarioCommand::Wait { duration: 1.0 }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_name_input_state_resolve() {
+        let state = NameInputState::from_command(&ScenarioCommand::InputName {
+            variable: "player_name".to_string(),
+            prompt: None,
+            default: "Alex".to_string(),
+            max_length: 5,
+        });
+        let Some(state) = state else {
+            panic!("InputName should ask for a name");
+        };
+
+        assert_eq!(state.resolve("  Kai "), "Kai");
+        assert_eq!(state.resolve("Christopher"), "Chris");
+        assert_eq!(state.resolve("   "), "Alex");
+        // Cut by characters, not bytes
+        assert_eq!(state.resolve("さくらこさん"), "さくらこさ");
+        assert_eq!(
+            NameInputState::from_command(&ScenarioCommand::Wait { duration: 1.0 }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_effect_state_shake_offset() {
+        let mut shake = EffectState::new(EffectKind::Shake { intensity: 0.5 }, 1.0);
+        assert_eq!(shake.shake_offset(20.0), (0.0, 0.0));
+        shake.update(0.3);
+        let (x, y) = shake.shake_offset(20.0);
+        assert!(x != 0.0 || y != 0.0);
+        // Dies down: at most intensity * max_offset * (1 - progress)
+        assert!(x.abs() <= 7.0 + 1e-4 && y.abs() <= 7.0 + 1e-4);
+
+        shake.update(1.0);
+        assert_eq!(shake.shake_offset(20.0), (0.0, 0.0));
+
+        let flash = EffectState::new(EffectKind::Flash { color: [1.0; 4] }, 1.0);
+        assert_eq!(flash.shake_offset(20.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_effect_state_overlay_color() {
+        let mut flash = EffectState::new(
+            EffectKind::Flash {
+                color: [1.0, 0.0, 0.0, 0.8],
+            },
+            1.0,
+        );
+        assert_eq!(flash.overlay_color(), Some([1.0, 0.0, 0.0, 0.8]));
+        flash.update(0.5);
+        assert_eq!(flash.overlay_color(), Some([1.0, 0.0, 0.0, 0.4]));
+        flash.update(0.5);
+        assert_eq!(flash.overlay_color(), Some([1.0, 0.0, 0.0, 0.0]));
+
+        // Comfort mode fades rise and fall
+        let mut fade = EffectState::new(EffectKind::Fade { color: [1.0; 4] }, 1.0);
+        assert_eq!(fade.overlay_color(), Some([1.0, 1.0, 1.0, 0.0]));
+        fade.update(0.5);
+        assert_eq!(fade.overlay_color(), Some([1.0; 4]));
+
+        let shake = EffectState::new(EffectKind::Shake { intensity: 1.0 }, 1.0);
+        assert_eq!(shake.overlay_color(), None);
+    }
+
     #[test]
     fn test_save_load_state_default() {
         let state = SaveLoadState::default();
@@ -1197,4 +1567,30 @@ mod tests {
         assert!(!state.is_save_mode);
         assert_eq!(state.selected_slot, 1);
     }
+
+    #[test]
+    fn test_ending_state_from_end_behavior() {
+        assert_eq!(
+            EndingState::from_end_behavior(&EndBehavior::ReturnToTitle),
+            None
+        );
+        assert_eq!(
+            EndingState::from_end_behavior(&EndBehavior::NextScenario {
+                path: "next.toml".to_string()
+            }),
+            None
+        );
+
+        let state = EndingState::from_end_behavior(&EndBehavior::EndingCard {
+            ending_id: "true_end".to_string(),
+            title: "True End".to_string(),
+        });
+        assert_eq!(
+            state,
+            Some(EndingState::Card {
+                ending_id: "true_end".to_string(),
+                title: "True End".to_string(),
+            })
+        );
+    }
 }
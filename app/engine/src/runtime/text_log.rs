@@ -0,0 +1,409 @@
+//! Text log export
+//!
+//! Builds a readable transcript of the backlog or of every dialogue line the
+//! player has read, and writes it as plain text or HTML. Speaker IDs are
+//! resolved to the character names defined in the scenario.
+
+use crate::error::EngineResult;
+use crate::text::TypewriterEffect;
+use narrative_core::{Backlog, ReadHistory, Scenario, ScenarioCommand, SceneId, Speaker};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File format of an exported text log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextLogFormat {
+    /// Plain text (`.txt`)
+    PlainText,
+    /// Standalone HTML page (`.html`)
+    Html,
+}
+
+impl TextLogFormat {
+    /// File extension without the leading dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::PlainText => "txt",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// Which lines an exported text log contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextLogSource {
+    /// The current backlog, oldest line first
+    Backlog,
+    /// Every dialogue line marked as read, in scenario order
+    ReadHistory,
+}
+
+impl TextLogSource {
+    /// Short name used in exported file names
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            Self::Backlog => "backlog",
+            Self::ReadHistory => "read_history",
+        }
+    }
+}
+
+/// A single line of a text log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextLogLine {
+    /// Title of the scene the line belongs to
+    pub scene: String,
+    /// Speaker display name (`None` for the narrator)
+    pub speaker: Option<String>,
+    /// Dialogue text
+    pub text: String,
+}
+
+/// Transcript of dialogue lines ready to be exported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextLog {
+    title: String,
+    lines: Vec<TextLogLine>,
+}
+
+impl TextLog {
+    /// Build a text log from the backlog (oldest line first)
+    pub fn from_backlog(backlog: &Backlog, scenario: &Scenario) -> Self {
+        let lines = backlog
+            .entries()
+            .iter()
+            .map(|entry| TextLogLine {
+                scene: scene_title(scenario, &entry.scene_id),
+                speaker: speaker_display_name(scenario, &entry.speaker),
+                text: entry.text.clone(),
+            })
+            .collect();
+
+        Self {
+            title: scenario.metadata.title.clone(),
+            lines,
+        }
+    }
+
+    /// Build a text log from every read dialogue line of a scenario
+    ///
+    /// Read history doesn't keep the order lines were read in, so lines are
+    /// listed by scene (the start scene first, then by scene ID) and by their
+    /// position in the scene. Read lines of other scenarios are skipped.
+    pub fn from_read_history(history: &ReadHistory, scenario: &Scenario) -> Self {
+        let mut read: Vec<_> = history
+            .iter()
+            .filter(|id| scenario.get_scene(id.scene_id.as_str()).is_some())
+            .collect();
+        read.sort_by(|a, b| {
+            let a_start = a.scene_id.as_str() == scenario.start_scene;
+            let b_start = b.scene_id.as_str() == scenario.start_scene;
+            b_start
+                .cmp(&a_start)
+                .then_with(|| a.scene_id.as_str().cmp(b.scene_id.as_str()))
+                .then_with(|| a.command_index.cmp(&b.command_index))
+        });
+
+        let lines = read
+            .into_iter()
+            .filter_map(|id| {
+                let scene = scenario.get_scene(id.scene_id.as_str())?;
+                match scene.commands.get(id.command_index)? {
+                    ScenarioCommand::Dialogue { dialogue } => Some(TextLogLine {
+                        scene: scene.title.clone(),
+                        speaker: speaker_display_name(scenario, &dialogue.speaker),
+                        text: TypewriterEffect::strip_tags(&dialogue.text),
+                    }),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        Self {
+            title: scenario.metadata.title.clone(),
+            lines,
+        }
+    }
+
+    /// Build a text log from the given source
+    pub fn from_source(
+        source: TextLogSource,
+        backlog: &Backlog,
+        history: &ReadHistory,
+        scenario: &Scenario,
+    ) -> Self {
+        match source {
+            TextLogSource::Backlog => Self::from_backlog(backlog, scenario),
+            TextLogSource::ReadHistory => Self::from_read_history(history, scenario),
+        }
+    }
+
+    /// Title of the log (the scenario title)
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Lines of the log
+    pub fn lines(&self) -> &[TextLogLine] {
+        &self.lines
+    }
+
+    /// Check if the log has no lines
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Render the log in a format
+    ///
+    /// A scene heading is inserted whenever the scene changes.
+    pub fn render(&self, format: TextLogFormat) -> String {
+        match format {
+            TextLogFormat::PlainText => self.render_plain_text(),
+            TextLogFormat::Html => self.render_html(),
+        }
+    }
+
+    fn render_plain_text(&self) -> String {
+        let mut out = format!("{}\n", self.title);
+        let mut current_scene: Option<&str> = None;
+        for line in &self.lines {
+            if current_scene != Some(line.scene.as_str()) {
+                out.push_str(&format!("\n== {} ==\n\n", line.scene));
+                current_scene = Some(line.scene.as_str());
+            }
+            match &line.speaker {
+                Some(speaker) => out.push_str(&format!("{}: {}\n", speaker, line.text)),
+                None => out.push_str(&format!("{}\n", line.text)),
+            }
+        }
+        out
+    }
+
+    fn render_html(&self) -> String {
+        let title = escape_html(&self.title);
+        let mut out = String::new();
+        out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        out.push_str(&format!("<title>{}</title>\n", title));
+        out.push_str(
+            "<style>\
+             body { font-family: sans-serif; max-width: 48em; margin: 2em auto; line-height: 1.6; }\
+             .speaker { font-weight: bold; }\
+             .narration { font-style: italic; }\
+             </style>\n",
+        );
+        out.push_str("</head>\n<body>\n");
+        out.push_str(&format!("<h1>{}</h1>\n", title));
+
+        let mut current_scene: Option<&str> = None;
+        for line in &self.lines {
+            if current_scene != Some(line.scene.as_str()) {
+                out.push_str(&format!("<h2>{}</h2>\n", escape_html(&line.scene)));
+                current_scene = Some(line.scene.as_str());
+            }
+            match &line.speaker {
+                Some(speaker) => out.push_str(&format!(
+                    "<p><span class=\"speaker\">{}</span>: {}</p>\n",
+                    escape_html(speaker),
+                    escape_html(&line.text)
+                )),
+                None => out.push_str(&format!(
+                    "<p class=\"narration\">{}</p>\n",
+                    escape_html(&line.text)
+                )),
+            }
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Write the log to a new file in a directory
+    ///
+    /// The directory is created if needed. The file name contains the source
+    /// and the local time, e.g. `backlog_20250101_120000.txt`. Returns the
+    /// path of the written file.
+    pub fn write_to_dir(
+        &self,
+        dir: &Path,
+        source: TextLogSource,
+        format: TextLogFormat,
+    ) -> EngineResult<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let path = dir.join(format!(
+            "{}_{}.{}",
+            source.file_stem(),
+            timestamp,
+            format.extension()
+        ));
+        fs::write(&path, self.render(format))?;
+        Ok(path)
+    }
+}
+
+/// Default directory for exported text logs
+///
+/// Uses the user's `Documents` folder, falling back to the home directory and
+/// then to an `exports` directory next to the game.
+pub fn default_export_dir() -> PathBuf {
+    let home = std::env::var_os("USERPROFILE")
+        .or_else(|| std::env::var_os("HOME"))
+        .map(PathBuf::from);
+    match home {
+        Some(home) => {
+            let documents = home.join("Documents");
+            if documents.is_dir() { documents } else { home }
+        }
+        None => PathBuf::from("exports"),
+    }
+}
+
+/// Scene title, or the scene ID if the scene isn't in the scenario
+fn scene_title(scenario: &Scenario, scene_id: &SceneId) -> String {
+    scenario
+        .get_scene(scene_id.as_str())
+        .map(|scene| scene.title.clone())
+        .unwrap_or_else(|| scene_id.as_str().to_string())
+}
+
+/// Display name of a speaker (`None` for the narrator)
+fn speaker_display_name(scenario: &Scenario, speaker: &Speaker) -> Option<String> {
+    match speaker {
+        Speaker::Character(id) => Some(
+            scenario
+                .characters
+                .iter()
+                .find(|character| character.id == *id)
+                .map(|character| character.name.clone())
+                .unwrap_or_else(|| id.clone()),
+        ),
+        Speaker::Narrator => None,
+        Speaker::System => Some("System".to_string()),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{BacklogEntry, CharacterDef, Dialogue, ScenarioMetadata, Scene};
+
+    fn scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("main", "Main Story"), "intro");
+        scenario.add_character(CharacterDef::new("alice", "Alice", "normal"));
+
+        let mut intro = Scene::new("intro", "Prologue");
+        intro.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator("It was raining."),
+        });
+        intro.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::character("alice", "Hello <there> & welcome!"),
+        });
+        intro.add_command(ScenarioCommand::End);
+        scenario.add_scene("intro", intro);
+
+        let mut aftermath = Scene::new("aftermath", "Aftermath");
+        aftermath.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::character("bob", "Who are you?"),
+        });
+        scenario.add_scene("aftermath", aftermath);
+        scenario
+    }
+
+    #[test]
+    fn test_from_backlog_resolves_names() {
+        let scenario = scenario();
+        let mut backlog = Backlog::new();
+        backlog.add_entry(BacklogEntry::new(
+            SceneId::new("intro"),
+            0,
+            Speaker::Narrator,
+            "It was raining.",
+        ));
+        backlog.add_entry(BacklogEntry::new(
+            SceneId::new("intro"),
+            1,
+            Speaker::character("alice"),
+            "Hello!",
+        ));
+
+        let log = TextLog::from_backlog(&backlog, &scenario);
+        assert_eq!(log.title(), "Main Story");
+        assert_eq!(log.lines().len(), 2);
+        assert_eq!(log.lines()[0].speaker, None);
+        assert_eq!(log.lines()[1].speaker.as_deref(), Some("Alice"));
+        assert_eq!(log.lines()[1].scene, "Prologue");
+    }
+
+    #[test]
+    fn test_from_read_history_orders_by_scenario() {
+        let scenario = scenario();
+        let mut history = ReadHistory::new();
+        history.mark_read(SceneId::new("aftermath"), 0);
+        history.mark_read(SceneId::new("intro"), 1);
+        history.mark_read(SceneId::new("intro"), 0);
+        // Non-dialogue commands and unknown scenes are skipped
+        history.mark_read(SceneId::new("intro"), 2);
+        history.mark_read(SceneId::new("removed"), 0);
+
+        let log = TextLog::from_read_history(&history, &scenario);
+        let texts: Vec<_> = log.lines().iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "It was raining.",
+                "Hello <there> & welcome!",
+                "Who are you?"
+            ]
+        );
+        // Speakers without a character definition keep their ID
+        assert_eq!(log.lines()[2].speaker.as_deref(), Some("bob"));
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let scenario = scenario();
+        let mut history = ReadHistory::new();
+        history.mark_read(SceneId::new("intro"), 0);
+        history.mark_read(SceneId::new("intro"), 1);
+        let log = TextLog::from_read_history(&history, &scenario);
+
+        let text = log.render(TextLogFormat::PlainText);
+        assert!(text.contains("== Prologue =="));
+        assert!(text.contains("\nIt was raining.\n"));
+        assert!(text.contains("Alice: Hello <there> & welcome!"));
+
+        let html = log.render(TextLogFormat::Html);
+        assert!(html.contains("<h2>Prologue</h2>"));
+        assert!(html.contains("Hello &lt;there&gt; &amp; welcome!"));
+        assert!(!html.contains("<there>"));
+    }
+
+    #[test]
+    fn test_write_to_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = TextLog::from_backlog(&Backlog::new(), &scenario());
+        let path = log
+            .write_to_dir(dir.path(), TextLogSource::Backlog, TextLogFormat::Html)
+            .unwrap();
+
+        assert!(path.starts_with(dir.path()));
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("html"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("<h1>Main Story</h1>"));
+    }
+}
@@ -1,5 +1,6 @@
 //! Variable storage and management
 
+use narrative_core::config::ProfileSettings;
 use narrative_core::{VariableId, VariableValue};
 use std::collections::HashMap;
 
@@ -30,6 +31,11 @@ impl VariableStore {
         self.variables.remove(variable)
     }
 
+    /// Iterate over the variables, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&VariableId, &VariableValue)> {
+        self.variables.iter()
+    }
+
     /// Clear all variables
     pub fn clear(&mut self) {
         self.variables.clear();
@@ -60,6 +66,71 @@ impl VariableStore {
 
         Self { variables }
     }
+
+    /// Convert string variables to save data format (HashMap<String, String>)
+    pub fn to_text_save_format(&self) -> HashMap<String, String> {
+        self.variables
+            .iter()
+            .filter_map(|(var_id, value)| {
+                if let VariableValue::String(text) = value {
+                    Some((var_id.name().to_string(), text.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Add string variables from save data format (HashMap<String, String>)
+    pub fn load_text_save_format(&mut self, data: &HashMap<String, String>) {
+        self.variables.extend(data.iter().map(|(name, text)| {
+            (
+                VariableId::new(name.clone()),
+                VariableValue::String(text.clone()),
+            )
+        }));
+    }
+
+    /// Fill the reserved `player.*` variables from the player profile
+    pub fn set_player_profile(&mut self, profile: &ProfileSettings) {
+        for (name, value) in profile.variables() {
+            self.set(VariableId::new(name), VariableValue::String(value));
+        }
+    }
+
+    /// Replace `{$name}` placeholders in `text` with variable values
+    ///
+    /// Placeholders naming an undefined variable are left as written.
+    pub fn substitute(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(open) = rest.find("{$") {
+            result.push_str(&rest[..open]);
+            let after_open = &rest[open + 2..];
+            let value = after_open.find('}').and_then(|close| {
+                self.get(&VariableId::new(&after_open[..close]))
+                    .map(|value| (value, &after_open[close + 1..]))
+            });
+            match value {
+                Some((value, remaining)) => {
+                    match value {
+                        VariableValue::Bool(b) => result.push_str(&b.to_string()),
+                        VariableValue::Int(n) => result.push_str(&n.to_string()),
+                        VariableValue::Float(f) => result.push_str(&f.to_string()),
+                        VariableValue::String(s) => result.push_str(s),
+                    }
+                    rest = remaining;
+                }
+                None => {
+                    result.push_str("{$");
+                    rest = after_open;
+                }
+            }
+        }
+        result.push_str(rest);
+        result
+    }
 }
 
 #[cfg(test)]
@@ -207,4 +278,63 @@ mod tests {
         store.set(var.clone(), VariableValue::String(String::new()));
         assert_eq!(store.get(&var), Some(&VariableValue::String(String::new())));
     }
+
+    #[test]
+    fn test_substitute() {
+        let mut store = VariableStore::new();
+        store.set(
+            VariableId::new("player_name"),
+            VariableValue::String("Sakura".to_string()),
+        );
+        store.set(VariableId::new("score"), VariableValue::Int(3));
+
+        assert_eq!(
+            store.substitute("Hello, {$player_name}! Score: {$score}"),
+            "Hello, Sakura! Score: 3"
+        );
+        // Undefined variables, unclosed placeholders and other tags are kept
+        assert_eq!(store.substitute("{$missing} {w=0.5}"), "{$missing} {w=0.5}");
+        assert_eq!(store.substitute("{$player_name"), "{$player_name");
+        assert_eq!(store.substitute("[b]{$player_name}[/b]"), "[b]Sakura[/b]");
+    }
+
+    #[test]
+    fn test_text_save_format() {
+        let mut store = VariableStore::new();
+        store.set(VariableId::new("score"), VariableValue::Int(3));
+        store.set(
+            VariableId::new("player_name"),
+            VariableValue::String("Sakura".to_string()),
+        );
+
+        let ints = store.to_save_format();
+        let texts = store.to_text_save_format();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts.get("player_name").map(String::as_str), Some("Sakura"));
+
+        let mut restored = VariableStore::from_save_format(&ints);
+        restored.load_text_save_format(&texts);
+        assert_eq!(
+            restored.get(&VariableId::new("player_name")),
+            Some(&VariableValue::String("Sakura".to_string()))
+        );
+        assert_eq!(
+            restored.get(&VariableId::new("score")),
+            Some(&VariableValue::Int(3))
+        );
+    }
+
+    #[test]
+    fn test_set_player_profile() {
+        let mut store = VariableStore::new();
+        let profile = ProfileSettings {
+            name: "Alex".to_string(),
+            pronoun: narrative_core::config::Pronoun::He,
+        };
+        store.set_player_profile(&profile);
+        assert_eq!(
+            store.substitute("{$player.name} said {$player.they} lost {$player.their} key"),
+            "Alex said he lost his key"
+        );
+    }
 }
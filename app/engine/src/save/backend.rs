@@ -0,0 +1,382 @@
+//! Save storage backends
+//!
+//! [`SaveManager`](super::SaveManager) handles serialization and delegates
+//! storage of the serialized slots to a [`SaveBackend`]. The file backend is the
+//! default; platforms can plug in their own (e.g. Steam Cloud or browser
+//! storage) with [`SaveManager::with_backend`](super::SaveManager::with_backend).
+
+use narrative_core::{EngineError, EngineResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Storage for serialized save slots
+pub trait SaveBackend: Send + Sync {
+    /// Write serialized save data to a slot, replacing any existing data
+    fn write(&self, slot: usize, contents: &str) -> EngineResult<()>;
+
+    /// Read serialized save data from a slot
+    ///
+    /// # Errors
+    /// Returns an `Io` error of kind `NotFound` if the slot is empty
+    fn read(&self, slot: usize) -> EngineResult<String>;
+
+    /// Check if a slot has save data
+    fn exists(&self, slot: usize) -> bool;
+
+    /// Delete a slot (deleting an empty slot succeeds)
+    fn delete(&self, slot: usize) -> EngineResult<()>;
+
+    /// Human-readable location of a slot, for logging
+    fn location(&self, slot: usize) -> String;
+
+    /// Directory the slots are stored in, if the backend uses the file system
+    fn directory(&self) -> Option<&Path> {
+        None
+    }
+
+    /// Write a slot's PNG thumbnail, stored apart from the save data so
+    /// listing slots never reads image bytes
+    ///
+    /// Backends that can't store thumbnails return an error; the slot still
+    /// previews from its display snapshot.
+    fn write_thumbnail(&self, slot: usize, _png: &[u8]) -> EngineResult<()> {
+        Err(EngineError::Other(format!(
+            "Save backend can't store the thumbnail of slot {}",
+            slot
+        )))
+    }
+
+    /// Read a slot's PNG thumbnail
+    ///
+    /// # Errors
+    /// Returns an `Io` error of kind `NotFound` if the slot has no thumbnail
+    fn read_thumbnail(&self, slot: usize) -> EngineResult<Vec<u8>> {
+        Err(EngineError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("No thumbnail for save slot {}", slot),
+        )))
+    }
+
+    /// Name the thumbnail of a slot is stored under, if the backend stores
+    /// thumbnails (recorded in `SaveData::thumbnail_path`)
+    fn thumbnail_name(&self, _slot: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Save backend selection for [`EngineConfig`](crate::EngineConfig)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SaveBackendKind {
+    /// RON files in the save directory (default)
+    #[default]
+    File,
+    /// In-memory slots, lost on exit (for tests and demos)
+    Memory,
+}
+
+/// Stores each slot as a RON file (`slot_XX.ron`) in a directory
+#[derive(Debug, Clone)]
+pub struct FileSaveBackend {
+    directory: PathBuf,
+}
+
+impl FileSaveBackend {
+    /// Create a file backend for a save directory
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Get the file path for a save slot
+    pub fn slot_path(&self, slot: usize) -> PathBuf {
+        self.directory.join(format!("slot_{:02}.ron", slot))
+    }
+
+    /// Get the file path for a save slot's thumbnail
+    pub fn thumbnail_path(&self, slot: usize) -> PathBuf {
+        self.slot_path(slot).with_extension("png")
+    }
+
+    /// Ensure save directory exists
+    fn ensure_directory(&self) -> EngineResult<()> {
+        if !self.directory.exists() {
+            fs::create_dir_all(&self.directory).map_err(|e| {
+                EngineError::Io(std::io::Error::new(
+                    e.kind(),
+                    format!(
+                        "Failed to create save directory '{}': {}",
+                        self.directory.display(),
+                        e
+                    ),
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Write a file atomically
+    ///
+    /// To prevent data corruption during save (e.g., crash while writing):
+    /// 1. Write to a temporary file (e.g. slot_XX.ron.tmp)
+    /// 2. Atomically rename the temp file to the final file
+    ///
+    /// The directory will be created if it doesn't exist.
+    fn write_atomic(&self, final_path: &Path, contents: &[u8]) -> EngineResult<()> {
+        self.ensure_directory()?;
+
+        let mut temp_name = final_path.as_os_str().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = PathBuf::from(temp_name);
+
+        // Write to temporary file
+        fs::write(&temp_path, contents).map_err(|e| {
+            EngineError::Io(std::io::Error::new(
+                e.kind(),
+                format!(
+                    "Failed to write temporary save file '{}': {}",
+                    temp_path.display(),
+                    e
+                ),
+            ))
+        })?;
+
+        // Atomic rename to final location
+        fs::rename(&temp_path, final_path).map_err(|e| {
+            // Clean up temp file on error
+            let _ = fs::remove_file(&temp_path);
+            EngineError::Io(std::io::Error::new(
+                e.kind(),
+                format!(
+                    "Failed to rename save file from '{}' to '{}': {}",
+                    temp_path.display(),
+                    final_path.display(),
+                    e
+                ),
+            ))
+        })
+    }
+
+    /// Remove a file if it exists
+    fn remove_if_exists(path: &Path) -> EngineResult<()> {
+        if path.exists() {
+            fs::remove_file(path).map_err(|e| {
+                EngineError::Io(std::io::Error::new(
+                    e.kind(),
+                    format!("Failed to delete save file '{}': {}", path.display(), e),
+                ))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl SaveBackend for FileSaveBackend {
+    /// Write a slot atomically (temp file + rename)
+    fn write(&self, slot: usize, contents: &str) -> EngineResult<()> {
+        self.write_atomic(&self.slot_path(slot), contents.as_bytes())
+    }
+
+    fn read(&self, slot: usize) -> EngineResult<String> {
+        let path = self.slot_path(slot);
+
+        if !path.exists() {
+            return Err(EngineError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Save slot {} not found at '{}'", slot, path.display()),
+            )));
+        }
+
+        fs::read_to_string(&path).map_err(|e| {
+            EngineError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to read save file '{}': {}", path.display(), e),
+            ))
+        })
+    }
+
+    fn exists(&self, slot: usize) -> bool {
+        self.slot_path(slot).exists()
+    }
+
+    /// Delete a slot and its thumbnail
+    fn delete(&self, slot: usize) -> EngineResult<()> {
+        Self::remove_if_exists(&self.slot_path(slot))?;
+        Self::remove_if_exists(&self.thumbnail_path(slot))
+    }
+
+    fn location(&self, slot: usize) -> String {
+        self.slot_path(slot).display().to_string()
+    }
+
+    fn directory(&self) -> Option<&Path> {
+        Some(&self.directory)
+    }
+
+    fn write_thumbnail(&self, slot: usize, png: &[u8]) -> EngineResult<()> {
+        self.write_atomic(&self.thumbnail_path(slot), png)
+    }
+
+    fn read_thumbnail(&self, slot: usize) -> EngineResult<Vec<u8>> {
+        let path = self.thumbnail_path(slot);
+        fs::read(&path).map_err(|e| {
+            EngineError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to read thumbnail '{}': {}", path.display(), e),
+            ))
+        })
+    }
+
+    fn thumbnail_name(&self, slot: usize) -> Option<String> {
+        self.thumbnail_path(slot)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    }
+}
+
+/// Keeps slots in memory; nothing is persisted
+#[derive(Debug, Default)]
+pub struct MemorySaveBackend {
+    slots: Mutex<HashMap<usize, String>>,
+    thumbnails: Mutex<HashMap<usize, Vec<u8>>>,
+}
+
+impl MemorySaveBackend {
+    /// Create an empty in-memory backend
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn slots(&self) -> std::sync::MutexGuard<'_, HashMap<usize, String>> {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn thumbnails(&self) -> std::sync::MutexGuard<'_, HashMap<usize, Vec<u8>>> {
+        self.thumbnails.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl SaveBackend for MemorySaveBackend {
+    fn write(&self, slot: usize, contents: &str) -> EngineResult<()> {
+        self.slots().insert(slot, contents.to_string());
+        Ok(())
+    }
+
+    fn read(&self, slot: usize) -> EngineResult<String> {
+        self.slots().get(&slot).cloned().ok_or_else(|| {
+            EngineError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Save slot {} not found in memory", slot),
+            ))
+        })
+    }
+
+    fn exists(&self, slot: usize) -> bool {
+        self.slots().contains_key(&slot)
+    }
+
+    fn delete(&self, slot: usize) -> EngineResult<()> {
+        self.slots().remove(&slot);
+        self.thumbnails().remove(&slot);
+        Ok(())
+    }
+
+    fn location(&self, slot: usize) -> String {
+        format!("memory slot {}", slot)
+    }
+
+    fn write_thumbnail(&self, slot: usize, png: &[u8]) -> EngineResult<()> {
+        self.thumbnails().insert(slot, png.to_vec());
+        Ok(())
+    }
+
+    fn read_thumbnail(&self, slot: usize) -> EngineResult<Vec<u8>> {
+        self.thumbnails().get(&slot).cloned().ok_or_else(|| {
+            EngineError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("No thumbnail for memory slot {}", slot),
+            ))
+        })
+    }
+
+    fn thumbnail_name(&self, slot: usize) -> Option<String> {
+        Some(format!("memory thumbnail {}", slot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_slot_path_formatting() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = FileSaveBackend::new(temp_dir.path().to_path_buf());
+
+        assert!(
+            backend
+                .slot_path(1)
+                .to_string_lossy()
+                .ends_with("slot_01.ron")
+        );
+        assert!(
+            backend
+                .slot_path(5)
+                .to_string_lossy()
+                .ends_with("slot_05.ron")
+        );
+        assert!(
+            backend
+                .slot_path(99)
+                .to_string_lossy()
+                .ends_with("slot_99.ron")
+        );
+    }
+
+    #[test]
+    fn test_memory_backend_roundtrip() {
+        let backend = MemorySaveBackend::new();
+        assert!(!backend.exists(2));
+        assert!(backend.directory().is_none());
+
+        backend.write(2, "data").unwrap();
+        assert!(backend.exists(2));
+        assert_eq!(backend.read(2).unwrap(), "data");
+
+        backend.delete(2).unwrap();
+        assert!(!backend.exists(2));
+        assert!(backend.delete(2).is_ok());
+    }
+
+    #[test]
+    fn test_file_backend_thumbnail_beside_slot() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = FileSaveBackend::new(temp_dir.path().to_path_buf());
+        assert!(backend.read_thumbnail(3).is_err());
+
+        backend.write(3, "data").unwrap();
+        backend.write_thumbnail(3, &[1, 2, 3]).unwrap();
+        assert_eq!(backend.thumbnail_name(3).as_deref(), Some("slot_03.png"));
+        assert_eq!(backend.read_thumbnail(3).unwrap(), [1, 2, 3]);
+        assert_eq!(backend.read(3).unwrap(), "data");
+
+        // Deleting the slot deletes its thumbnail
+        backend.delete(3).unwrap();
+        assert!(!backend.thumbnail_path(3).exists());
+    }
+
+    #[test]
+    fn test_memory_backend_read_empty_slot() {
+        let backend = MemorySaveBackend::new();
+        let err = backend.read(1).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_save_backend_kind_default() {
+        assert_eq!(SaveBackendKind::default(), SaveBackendKind::File);
+    }
+}
@@ -0,0 +1,267 @@
+//! Save data compatibility with the loaded scenario
+//!
+//! Saves keep a scene ID and a command index. After a scenario update the
+//! scene may be gone or shorter than the saved position. Such saves are
+//! reported when listing slots and can still be loaded at the start of the
+//! nearest scene that exists, or restarted from their scene-entry checkpoint.
+
+use super::SaveData;
+use narrative_core::{Scenario, SceneId};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A mismatch between a save and the current scenario
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SaveIssue {
+    /// The saved scene no longer exists
+    MissingScene(SceneId),
+    /// The saved command index is past the end of the scene
+    CommandIndexOutOfRange {
+        scene: SceneId,
+        index: usize,
+        len: usize,
+    },
+    /// A scene on the saved call stack no longer exists
+    MissingStackScene(SceneId),
+}
+
+impl fmt::Display for SaveIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingScene(scene) => {
+                write!(f, "Scene '{}' no longer exists", scene.as_str())
+            }
+            Self::CommandIndexOutOfRange { scene, index, len } => write!(
+                f,
+                "Scene '{}' now has {} commands, but the save is at command {}",
+                scene.as_str(),
+                len,
+                index
+            ),
+            Self::MissingStackScene(scene) => {
+                write!(f, "Calling scene '{}' no longer exists", scene.as_str())
+            }
+        }
+    }
+}
+
+impl SaveData {
+    /// Check the save against a scenario
+    ///
+    /// Returns an empty list if the save can be restored exactly. Saves that
+    /// belong to another scenario of the project are not checked.
+    pub fn compatibility_issues(&self, scenario: &Scenario) -> Vec<SaveIssue> {
+        let mut issues = Vec::new();
+        if self
+            .scenario_id
+            .as_ref()
+            .is_some_and(|id| *id != scenario.metadata.id)
+        {
+            return issues;
+        }
+
+        if !self.current_scene.as_str().is_empty() {
+            match scenario.get_scene(self.current_scene.as_str()) {
+                None => issues.push(SaveIssue::MissingScene(self.current_scene.clone())),
+                Some(scene) if self.command_index > scene.commands.len() => {
+                    issues.push(SaveIssue::CommandIndexOutOfRange {
+                        scene: self.current_scene.clone(),
+                        index: self.command_index,
+                        len: scene.commands.len(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (scene, _) in &self.scene_stack {
+            if scenario.get_scene(scene.as_str()).is_none() {
+                issues.push(SaveIssue::MissingStackScene(scene.clone()));
+            }
+        }
+
+        issues
+    }
+
+    /// Whether the save's scene-entry checkpoint can be restored in a scenario
+    pub fn has_usable_checkpoint(&self, scenario: &Scenario) -> bool {
+        self.at_checkpoint(scenario).is_some()
+    }
+
+    /// Copy of the save restarted from its scene-entry checkpoint
+    ///
+    /// Returns `None` if the save has no checkpoint or the checkpoint's scene
+    /// (or a scene on its call stack) no longer exists.
+    pub fn at_checkpoint(&self, scenario: &Scenario) -> Option<SaveData> {
+        let checkpoint = self.checkpoint.as_ref()?;
+        scenario.get_scene(checkpoint.scene.as_str())?;
+        let stack_valid = checkpoint.scene_stack.iter().all(|(scene, index)| {
+            scenario
+                .get_scene(scene.as_str())
+                .is_some_and(|s| *index <= s.commands.len())
+        });
+        if !stack_valid {
+            return None;
+        }
+
+        let mut save = self.clone();
+        save.current_scene = checkpoint.scene.clone();
+        save.command_index = 0;
+        save.flags = checkpoint.flags.clone();
+        save.variables = checkpoint.variables.clone();
+        save.text_variables = checkpoint.text_variables.clone();
+        save.scene_stack = checkpoint.scene_stack.clone();
+        save.call_frames.truncate(save.scene_stack.len());
+        save.current_background = checkpoint.current_background.clone();
+        save.choice_order = None;
+        Some(save)
+    }
+
+    /// Copy of the save moved to the start of the nearest scene that exists
+    ///
+    /// The saved scene is kept if it still exists; otherwise the innermost
+    /// calling scene is used, falling back to the scenario's start scene.
+    /// Call stack entries that no longer resolve are dropped. Flags,
+    /// variables and history are kept as they are.
+    pub fn at_nearest_scene_start(&self, scenario: &Scenario) -> SaveData {
+        let mut save = self.clone();
+
+        // Call frames are dropped along with their stack entries
+        let frames = std::mem::take(&mut save.call_frames)
+            .into_iter()
+            .map(Some)
+            .chain(std::iter::repeat_with(|| None));
+        let (scene_stack, call_frames): (Vec<_>, Vec<_>) = std::mem::take(&mut save.scene_stack)
+            .into_iter()
+            .zip(frames)
+            .filter(|((scene, index), _)| {
+                scenario
+                    .get_scene(scene.as_str())
+                    .is_some_and(|s| *index <= s.commands.len())
+            })
+            .unzip();
+        save.scene_stack = scene_stack;
+        save.call_frames = call_frames.into_iter().flatten().collect();
+
+        let scene_exists = scenario.get_scene(save.current_scene.as_str()).is_some();
+        if !scene_exists {
+            save.current_scene = match save.scene_stack.pop() {
+                Some((scene, _)) => scene,
+                None => SceneId::new(scenario.start_scene.clone()),
+            };
+            save.call_frames.truncate(save.scene_stack.len());
+        }
+        save.command_index = 0;
+        save.choice_order = None;
+        save
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::SceneCheckpoint;
+    use narrative_core::{ScenarioCommand, ScenarioMetadata, Scene};
+    use std::collections::HashMap;
+
+    fn scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("main", "Main"), "intro");
+        let mut intro = Scene::new("intro", "Intro");
+        intro.add_command(ScenarioCommand::End);
+        scenario.add_scene("intro", intro);
+        scenario.add_scene("chapter_01", Scene::new("chapter_01", "Chapter 1"));
+        scenario
+    }
+
+    fn save(scene: &str, index: usize) -> SaveData {
+        let mut save = SaveData::new(1);
+        save.scenario_id = Some("main".to_string());
+        save.current_scene = SceneId::new(scene);
+        save.command_index = index;
+        save
+    }
+
+    #[test]
+    fn test_compatibility_issues() {
+        let scenario = scenario();
+        assert!(save("intro", 1).compatibility_issues(&scenario).is_empty());
+
+        assert_eq!(
+            save("removed", 0).compatibility_issues(&scenario),
+            vec![SaveIssue::MissingScene(SceneId::new("removed"))]
+        );
+        assert_eq!(
+            save("intro", 5).compatibility_issues(&scenario),
+            vec![SaveIssue::CommandIndexOutOfRange {
+                scene: SceneId::new("intro"),
+                index: 5,
+                len: 1,
+            }]
+        );
+
+        let mut called = save("intro", 0);
+        called.scene_stack.push((SceneId::new("old_caller"), 2));
+        assert_eq!(
+            called.compatibility_issues(&scenario),
+            vec![SaveIssue::MissingStackScene(SceneId::new("old_caller"))]
+        );
+
+        // Saves of other scenarios are not checked against this one
+        let mut other = save("removed", 0);
+        other.scenario_id = Some("other".to_string());
+        assert!(other.compatibility_issues(&scenario).is_empty());
+    }
+
+    #[test]
+    fn test_at_nearest_scene_start() {
+        let scenario = scenario();
+
+        let repaired = save("intro", 5).at_nearest_scene_start(&scenario);
+        assert_eq!(repaired.current_scene, SceneId::new("intro"));
+        assert_eq!(repaired.command_index, 0);
+
+        let mut called = save("removed", 3);
+        called.flags.insert("met_alice".to_string(), true);
+        called.scene_stack.push((SceneId::new("chapter_01"), 0));
+        called.scene_stack.push((SceneId::new("old_caller"), 2));
+        let repaired = called.at_nearest_scene_start(&scenario);
+        assert_eq!(repaired.current_scene, SceneId::new("chapter_01"));
+        assert!(repaired.scene_stack.is_empty());
+        assert_eq!(repaired.flags.get("met_alice"), Some(&true));
+        assert!(repaired.compatibility_issues(&scenario).is_empty());
+
+        let repaired = save("removed", 3).at_nearest_scene_start(&scenario);
+        assert_eq!(repaired.current_scene, SceneId::new("intro"));
+    }
+
+    #[test]
+    fn test_at_checkpoint() {
+        let scenario = scenario();
+        let mut save = save("chapter_01", 7);
+        assert!(!save.has_usable_checkpoint(&scenario));
+
+        save.flags.insert("late_flag".to_string(), true);
+        save.checkpoint = Some(SceneCheckpoint {
+            scene: SceneId::new("chapter_01"),
+            flags: HashMap::from([("early_flag".to_string(), true)]),
+            variables: HashMap::new(),
+            text_variables: HashMap::new(),
+            scene_stack: Vec::new(),
+            current_background: Some("bg/school.png".to_string()),
+        });
+        let restarted = save.at_checkpoint(&scenario).unwrap();
+        assert_eq!(restarted.command_index, 0);
+        assert_eq!(restarted.flags.get("early_flag"), Some(&true));
+        assert!(!restarted.flags.contains_key("late_flag"));
+        assert_eq!(
+            restarted.current_background.as_deref(),
+            Some("bg/school.png")
+        );
+
+        // A checkpoint in a removed scene can't be used
+        if let Some(checkpoint) = save.checkpoint.as_mut() {
+            checkpoint.scene = SceneId::new("removed");
+        }
+        assert!(!save.has_usable_checkpoint(&scenario));
+    }
+}
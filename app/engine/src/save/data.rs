@@ -1,6 +1,8 @@
 //! Save data
 
-use narrative_core::{CharacterPosition, ReadHistory, SceneId};
+use super::DisplaySnapshot;
+use crate::runtime::{ActiveEffect, CallFrame};
+use narrative_core::{CharacterPosition, HudVisibility, ReadHistory, SceneId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -8,7 +10,7 @@ use std::collections::HashMap;
 pub const SAVE_VERSION: u32 = 1;
 
 /// Save data structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SaveData {
     /// Save data format version
     #[serde(default = "default_version")]
@@ -20,6 +22,9 @@ pub struct SaveData {
     /// Play time in seconds
     #[serde(default)]
     pub play_time_secs: u64,
+    /// ID of the scenario being played (`None` for saves from single-scenario builds)
+    #[serde(default)]
+    pub scenario_id: Option<String>,
     /// Current scene
     pub current_scene: SceneId,
     /// Current command index
@@ -28,6 +33,9 @@ pub struct SaveData {
     pub flags: HashMap<String, bool>,
     /// Variable states
     pub variables: HashMap<String, i64>,
+    /// String variable states (e.g. the player's name)
+    #[serde(default)]
+    pub text_variables: HashMap<String, String>,
     /// Read history (deprecated, use read_history instead)
     #[serde(default)]
     pub read_scenes: Vec<SceneId>,
@@ -37,6 +45,9 @@ pub struct SaveData {
     /// Call/Return stack for subroutine tracking
     #[serde(default)]
     pub scene_stack: Vec<(SceneId, usize)>,
+    /// Arguments and result variable of each call on the stack
+    #[serde(default)]
+    pub call_frames: Vec<CallFrame>,
     /// Display state: current background
     #[serde(default)]
     pub current_background: Option<String>,
@@ -49,6 +60,44 @@ pub struct SaveData {
     /// Thumbnail file path (relative to save directory)
     #[serde(default)]
     pub thumbnail_path: Option<String>,
+    /// State at the entry of the last scene (`None` for older saves)
+    #[serde(default)]
+    pub checkpoint: Option<SceneCheckpoint>,
+    /// Seed for shuffled choice orders
+    #[serde(default)]
+    pub choice_seed: u64,
+    /// Display order of the choice on screen when saved (indices into its authored options)
+    #[serde(default)]
+    pub choice_order: Option<Vec<usize>>,
+    /// Display state: running screen effects
+    #[serde(default)]
+    pub active_effects: Vec<ActiveEffect>,
+    /// Display state: HUD widgets shown or hidden by SetHudVisible
+    #[serde(default)]
+    pub hud_visibility: HudVisibility,
+}
+
+/// Snapshot taken when the runtime enters a scene
+///
+/// Lets a save restart from the beginning of its scene with the state the
+/// scene started with, when the saved command index can't be restored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneCheckpoint {
+    /// Scene that was entered
+    pub scene: SceneId,
+    /// Flag states at scene entry
+    pub flags: HashMap<String, bool>,
+    /// Variable states at scene entry
+    pub variables: HashMap<String, i64>,
+    /// String variable states at scene entry
+    #[serde(default)]
+    pub text_variables: HashMap<String, String>,
+    /// Call/Return stack at scene entry
+    #[serde(default)]
+    pub scene_stack: Vec<(SceneId, usize)>,
+    /// Background at scene entry
+    #[serde(default)]
+    pub current_background: Option<String>,
 }
 
 /// Serialized character display state
@@ -68,6 +117,21 @@ fn default_version() -> u32 {
 }
 
 impl SaveData {
+    /// What was on screen when the game was saved
+    ///
+    /// Characters are ordered by ID so the snapshot is deterministic.
+    pub fn display_snapshot(&self) -> DisplaySnapshot {
+        let mut characters: Vec<SavedCharacterDisplay> =
+            self.displayed_characters.values().cloned().collect();
+        characters.sort_by(|a, b| a.character_id.cmp(&b.character_id));
+
+        DisplaySnapshot {
+            background: self.current_background.clone(),
+            cg: self.current_cg.clone(),
+            characters,
+        }
+    }
+
     /// Create a new save data
     pub fn new(slot: usize) -> Self {
         Self {
@@ -75,17 +139,25 @@ impl SaveData {
             slot,
             timestamp: 0,
             play_time_secs: 0,
+            scenario_id: None,
             current_scene: SceneId::new(""),
             command_index: 0,
             flags: HashMap::new(),
             variables: HashMap::new(),
+            text_variables: HashMap::new(),
             read_scenes: Vec::new(),
             read_history: ReadHistory::new(),
             scene_stack: Vec::new(),
+            call_frames: Vec::new(),
             current_background: None,
             current_cg: None,
             displayed_characters: HashMap::new(),
             thumbnail_path: None,
+            checkpoint: None,
+            choice_seed: 0,
+            choice_order: None,
+            active_effects: Vec::new(),
+            hud_visibility: HudVisibility::default(),
         }
     }
 }
@@ -142,6 +214,22 @@ mod tests {
         assert_eq!(deserialized.read_scenes.len(), 1);
     }
 
+    #[test]
+    fn test_save_data_scenario_id() {
+        let mut save = SaveData::new(1);
+        save.scenario_id = Some("chapter_02".to_string());
+
+        let serialized = ron::to_string(&save).unwrap();
+        let deserialized: SaveData = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.scenario_id.as_deref(), Some("chapter_02"));
+
+        // Saves written before scenario IDs were recorded still load
+        let legacy = serialized.replace("scenario_id:Some(\"chapter_02\"),", "");
+        assert_ne!(legacy, serialized);
+        let deserialized: SaveData = ron::from_str(&legacy).unwrap();
+        assert_eq!(deserialized.scenario_id, None);
+    }
+
     #[test]
     fn test_save_data_with_multiple_flags() {
         let mut save = SaveData::new(1);
@@ -209,6 +297,32 @@ mod tests {
         assert_eq!(deserialized.scene_stack[0].1, 5);
     }
 
+    #[test]
+    fn test_save_data_display_snapshot() {
+        let mut save = SaveData::new(1);
+        save.current_background = Some("bg/room.png".to_string());
+        for id in ["bob", "alice"] {
+            save.displayed_characters.insert(
+                id.to_string(),
+                SavedCharacterDisplay {
+                    character_id: id.to_string(),
+                    sprite: format!("{}.png", id),
+                    position: CharacterPosition::Center,
+                },
+            );
+        }
+
+        let snapshot = save.display_snapshot();
+        assert_eq!(snapshot.background.as_deref(), Some("bg/room.png"));
+        assert_eq!(snapshot.cg, None);
+        let ids: Vec<_> = snapshot
+            .characters
+            .iter()
+            .map(|c| c.character_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["alice", "bob"]);
+    }
+
     #[test]
     fn test_save_data_version() {
         let save = SaveData::new(1);
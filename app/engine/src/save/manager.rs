@@ -1,54 +1,47 @@
 //! Save manager
 
-use super::SaveData;
-use narrative_core::EngineResult;
-use std::fs;
-use std::path::PathBuf;
-
-/// Save file manager
+use super::{FileSaveBackend, MemorySaveBackend, SaveBackend, SaveBackendKind, SaveData};
+use crate::EngineConfig;
+use image::RgbaImage;
+use narrative_core::{EngineError, EngineResult};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+/// Save manager
+///
+/// Serializes [`SaveData`] to RON and stores it through a [`SaveBackend`]
+/// (files in a save directory by default). Slot thumbnails are stored as
+/// separate PNGs, so listing slots never decodes image data.
 pub struct SaveManager {
-    save_directory: PathBuf,
+    backend: Box<dyn SaveBackend>,
 }
 
 impl SaveManager {
-    /// Create a new save manager
+    /// Create a save manager that stores slots as files in `save_directory`
     pub fn new(save_directory: PathBuf) -> Self {
-        Self { save_directory }
+        Self::with_backend(FileSaveBackend::new(save_directory))
     }
 
-    /// Get the file path for a save slot
-    fn slot_path(&self, slot: usize) -> PathBuf {
-        self.save_directory.join(format!("slot_{:02}.ron", slot))
+    /// Create a save manager with a custom storage backend
+    pub fn with_backend(backend: impl SaveBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+        }
     }
 
-    /// Ensure save directory exists
-    fn ensure_save_directory(&self) -> EngineResult<()> {
-        if !self.save_directory.exists() {
-            fs::create_dir_all(&self.save_directory).map_err(|e| {
-                narrative_core::EngineError::Io(std::io::Error::new(
-                    e.kind(),
-                    format!(
-                        "Failed to create save directory '{}': {}",
-                        self.save_directory.display(),
-                        e
-                    ),
-                ))
-            })?;
+    /// Create a save manager with the backend selected in the engine config
+    pub fn from_config(config: &EngineConfig) -> Self {
+        match config.save_backend {
+            SaveBackendKind::File => Self::new(config.save_path.clone()),
+            SaveBackendKind::Memory => Self::with_backend(MemorySaveBackend::new()),
         }
-        Ok(())
     }
 
     /// Save game state to a slot
     ///
-    /// This saves the game state to a RON file in the save directory using atomic write.
-    /// The directory will be created if it doesn't exist.
-    ///
-    /// # Atomic Write Process
-    /// To prevent data corruption during save (e.g., crash while writing):
-    /// 1. Write to a temporary file (slot_XX.ron.tmp)
-    /// 2. Atomically rename the temp file to the final file
-    ///
-    /// This ensures that the save file is either fully written or not changed at all.
+    /// This serializes the game state to RON and writes it through the backend.
+    /// The file backend writes atomically (temp file + rename) and creates the
+    /// save directory if it doesn't exist.
     ///
     /// # Arguments
     /// * `slot` - The save slot number (e.g., 1 for slot_01.ron)
@@ -56,14 +49,9 @@ impl SaveManager {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The save directory cannot be created
     /// - The save data cannot be serialized
-    /// - The temporary file cannot be written
-    /// - The atomic rename fails
+    /// - The backend fails to store the data
     pub fn save(&self, slot: usize, data: &SaveData) -> EngineResult<()> {
-        // Ensure save directory exists
-        self.ensure_save_directory()?;
-
         // Serialize to RON format with pretty printing
         let ron_config = ron::ser::PrettyConfig::new()
             .depth_limit(4)
@@ -74,38 +62,13 @@ impl SaveManager {
             narrative_core::EngineError::Other(format!("Failed to serialize save data: {}", e))
         })?;
 
-        // Atomic write: Write to temp file, then rename
-        let final_path = self.slot_path(slot);
-        let temp_path = final_path.with_extension("ron.tmp");
-
-        // Write to temporary file
-        fs::write(&temp_path, &serialized).map_err(|e| {
-            narrative_core::EngineError::Io(std::io::Error::new(
-                e.kind(),
-                format!(
-                    "Failed to write temporary save file '{}': {}",
-                    temp_path.display(),
-                    e
-                ),
-            ))
-        })?;
-
-        // Atomic rename to final location
-        fs::rename(&temp_path, &final_path).map_err(|e| {
-            // Clean up temp file on error
-            let _ = fs::remove_file(&temp_path);
-            narrative_core::EngineError::Io(std::io::Error::new(
-                e.kind(),
-                format!(
-                    "Failed to rename save file from '{}' to '{}': {}",
-                    temp_path.display(),
-                    final_path.display(),
-                    e
-                ),
-            ))
-        })?;
+        self.backend.write(slot, &serialized)?;
 
-        tracing::info!("Saved game to slot {} ({})", slot, final_path.display());
+        tracing::info!(
+            "Saved game to slot {} ({})",
+            slot,
+            self.backend.location(slot)
+        );
         Ok(())
     }
 
@@ -116,50 +79,82 @@ impl SaveManager {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The save file doesn't exist
-    /// - The file cannot be read
+    /// - The save slot doesn't exist
+    /// - The slot cannot be read
     /// - The save data cannot be deserialized
     pub fn load(&self, slot: usize) -> EngineResult<SaveData> {
-        let path = self.slot_path(slot);
-
-        // Check if file exists
-        if !path.exists() {
-            return Err(narrative_core::EngineError::Io(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("Save slot {} not found at '{}'", slot, path.display()),
-            )));
-        }
-
-        // Read file contents
-        let contents = fs::read_to_string(&path).map_err(|e| {
-            narrative_core::EngineError::Io(std::io::Error::new(
-                e.kind(),
-                format!("Failed to read save file '{}': {}", path.display(), e),
-            ))
-        })?;
+        let contents = self.backend.read(slot)?;
 
         // Deserialize from RON
         let save_data = ron::from_str::<SaveData>(&contents).map_err(|e| {
             narrative_core::EngineError::Other(format!(
                 "Failed to deserialize save file '{}': {}",
-                path.display(),
+                self.backend.location(slot),
                 e
             ))
         })?;
 
-        tracing::info!("Loaded game from slot {} ({})", slot, path.display());
+        tracing::info!(
+            "Loaded game from slot {} ({})",
+            slot,
+            self.backend.location(slot)
+        );
         Ok(save_data)
     }
 
+    /// Store a slot's thumbnail as a PNG
+    ///
+    /// # Errors
+    /// Returns an error if the image can't be encoded or the backend can't
+    /// store thumbnails.
+    pub fn save_thumbnail(&self, slot: usize, thumbnail: &RgbaImage) -> EngineResult<()> {
+        let mut png = Vec::new();
+        thumbnail
+            .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+            .map_err(|e| EngineError::Other(format!("Failed to encode thumbnail: {}", e)))?;
+        self.backend.write_thumbnail(slot, &png)?;
+
+        tracing::debug!(
+            "Saved {}x{} thumbnail for slot {}",
+            thumbnail.width(),
+            thumbnail.height(),
+            slot
+        );
+        Ok(())
+    }
+
+    /// Load a slot's thumbnail
+    ///
+    /// # Errors
+    /// Returns an error if the slot has no thumbnail or it can't be decoded
+    pub fn load_thumbnail(&self, slot: usize) -> EngineResult<RgbaImage> {
+        let png = self.backend.read_thumbnail(slot)?;
+        image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+            .map(|image| image.to_rgba8())
+            .map_err(|e| {
+                EngineError::Other(format!(
+                    "Failed to decode thumbnail of slot {}: {}",
+                    slot, e
+                ))
+            })
+    }
+
+    /// Name a slot's thumbnail is stored under, for `SaveData::thumbnail_path`
+    ///
+    /// None if the backend doesn't store thumbnails.
+    pub fn thumbnail_name(&self, slot: usize) -> Option<String> {
+        self.backend.thumbnail_name(slot)
+    }
+
     /// Check if a save slot exists
     ///
     /// # Arguments
     /// * `slot` - The save slot number to check
     ///
     /// # Returns
-    /// `true` if the save file exists, `false` otherwise
+    /// `true` if the slot has save data, `false` otherwise
     pub fn slot_exists(&self, slot: usize) -> bool {
-        self.slot_path(slot).exists()
+        self.backend.exists(slot)
     }
 
     /// Delete a save slot
@@ -168,27 +163,23 @@ impl SaveManager {
     /// * `slot` - The save slot number to delete
     ///
     /// # Errors
-    /// Returns an error if the file exists but cannot be deleted
+    /// Returns an error if the slot exists but cannot be deleted
     pub fn delete_slot(&self, slot: usize) -> EngineResult<()> {
-        let path = self.slot_path(slot);
-
-        // Only try to delete if file exists
-        if path.exists() {
-            fs::remove_file(&path).map_err(|e| {
-                narrative_core::EngineError::Io(std::io::Error::new(
-                    e.kind(),
-                    format!("Failed to delete save file '{}': {}", path.display(), e),
-                ))
-            })?;
-            tracing::info!("Deleted save slot {} ({})", slot, path.display());
+        if self.backend.exists(slot) {
+            self.backend.delete(slot)?;
+            tracing::info!(
+                "Deleted save slot {} ({})",
+                slot,
+                self.backend.location(slot)
+            );
         }
 
         Ok(())
     }
 
-    /// Get save directory
-    pub fn save_directory(&self) -> &PathBuf {
-        &self.save_directory
+    /// Get save directory (None for backends that don't use the file system)
+    pub fn save_directory(&self) -> Option<&Path> {
+        self.backend.directory()
     }
 }
 
@@ -223,27 +214,29 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let manager = SaveManager::new(temp_dir.path().to_path_buf());
 
-        assert_eq!(manager.save_directory(), temp_dir.path());
+        assert_eq!(manager.save_directory(), Some(temp_dir.path()));
     }
 
     #[test]
     fn test_save_manager_default() {
         let manager = SaveManager::default();
-        assert_eq!(manager.save_directory(), &PathBuf::from("saves"));
+        assert_eq!(manager.save_directory(), Some(Path::new("saves")));
     }
 
     #[test]
-    fn test_slot_path_formatting() {
+    fn test_thumbnail_roundtrip_and_delete() {
         let temp_dir = TempDir::new().unwrap();
         let manager = SaveManager::new(temp_dir.path().to_path_buf());
+        assert!(manager.load_thumbnail(2).is_err());
 
-        let path1 = manager.slot_path(1);
-        let path5 = manager.slot_path(5);
-        let path99 = manager.slot_path(99);
+        let thumbnail = RgbaImage::from_pixel(16, 9, image::Rgba([10, 20, 30, 255]));
+        manager.save(2, &create_test_save_data(2)).unwrap();
+        manager.save_thumbnail(2, &thumbnail).unwrap();
+        assert_eq!(manager.thumbnail_name(2).as_deref(), Some("slot_02.png"));
+        assert_eq!(manager.load_thumbnail(2).unwrap(), thumbnail);
 
-        assert!(path1.to_string_lossy().ends_with("slot_01.ron"));
-        assert!(path5.to_string_lossy().ends_with("slot_05.ron"));
-        assert!(path99.to_string_lossy().ends_with("slot_99.ron"));
+        manager.delete_slot(2).unwrap();
+        assert!(manager.load_thumbnail(2).is_err());
     }
 
     #[test]
@@ -476,7 +469,7 @@ mod tests {
         manager.save(1, &save_data).unwrap();
 
         // Read the raw file content
-        let file_path = manager.slot_path(1);
+        let file_path = FileSaveBackend::new(temp_dir.path().to_path_buf()).slot_path(1);
         let content = std::fs::read_to_string(file_path).unwrap();
 
         // Verify it's RON format and readable
@@ -512,7 +505,9 @@ mod tests {
         manager.save(1, &save_data).unwrap();
 
         // Verify temporary file was cleaned up
-        let temp_path = manager.slot_path(1).with_extension("ron.tmp");
+        let temp_path = FileSaveBackend::new(temp_dir.path().to_path_buf())
+            .slot_path(1)
+            .with_extension("ron.tmp");
         assert!(
             !temp_path.exists(),
             "Temporary file should not exist after successful save"
@@ -554,4 +549,31 @@ mod tests {
         assert_eq!(loaded.flags.get("final_boss_defeated"), Some(&true));
         assert_eq!(loaded.variables.get("completion_rate"), Some(&95));
     }
+
+    #[test]
+    fn test_memory_backend_save_and_load() {
+        let manager = SaveManager::with_backend(MemorySaveBackend::new());
+        assert!(manager.save_directory().is_none());
+
+        manager.save(2, &create_test_save_data(2)).unwrap();
+        assert!(manager.slot_exists(2));
+        assert_eq!(manager.load(2).unwrap().command_index, 42);
+
+        manager.delete_slot(2).unwrap();
+        assert!(!manager.slot_exists(2));
+    }
+
+    #[test]
+    fn test_save_manager_from_config() {
+        let mut config = EngineConfig {
+            save_path: PathBuf::from("custom_saves"),
+            ..Default::default()
+        };
+        let manager = SaveManager::from_config(&config);
+        assert_eq!(manager.save_directory(), Some(Path::new("custom_saves")));
+
+        config.save_backend = SaveBackendKind::Memory;
+        let manager = SaveManager::from_config(&config);
+        assert!(manager.save_directory().is_none());
+    }
 }
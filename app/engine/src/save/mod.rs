@@ -2,12 +2,16 @@
 //!
 //! This module provides save and load functionality with thumbnails.
 
+mod backend;
+mod compatibility;
 mod data;
 mod manager;
 mod slot_info;
 mod thumbnail;
 
-pub use data::{SAVE_VERSION, SaveData, SavedCharacterDisplay};
+pub use backend::{FileSaveBackend, MemorySaveBackend, SaveBackend, SaveBackendKind};
+pub use compatibility::SaveIssue;
+pub use data::{SAVE_VERSION, SaveData, SavedCharacterDisplay, SceneCheckpoint};
 pub use manager::SaveManager;
-pub use slot_info::{SlotInfo, list_all_slots};
-pub use thumbnail::generate_thumbnail;
+pub use slot_info::{SlotInfo, list_all_slots, list_all_slots_checked};
+pub use thumbnail::{DisplaySnapshot, ThumbnailLayer, render_thumbnail, thumbnail_layers};
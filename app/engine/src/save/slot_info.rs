@@ -1,8 +1,8 @@
 //! Slot metadata for UI display
 
-use super::SaveManager;
+use super::{DisplaySnapshot, SaveIssue, SaveManager};
 use chrono::{TimeZone, Utc};
-use narrative_core::{EngineResult, SceneId};
+use narrative_core::{EngineResult, Scenario, SceneId};
 use serde::{Deserialize, Serialize};
 
 /// Slot metadata for UI display
@@ -22,6 +22,15 @@ pub struct SlotInfo {
     pub play_time_secs: u64,
     /// Thumbnail file path (relative to saves directory)
     pub thumbnail_path: Option<String>,
+    /// Mismatches with the current scenario (empty if not checked or compatible)
+    #[serde(default)]
+    pub issues: Vec<SaveIssue>,
+    /// Whether the save can restart from its scene-entry checkpoint
+    #[serde(default)]
+    pub has_checkpoint: bool,
+    /// What was on screen when the game was saved (for previews)
+    #[serde(default)]
+    pub display: DisplaySnapshot,
 }
 
 impl SlotInfo {
@@ -35,16 +44,35 @@ impl SlotInfo {
             scene_name: String::new(),
             play_time_secs: 0,
             thumbnail_path: None,
+            issues: Vec::new(),
+            has_checkpoint: false,
+            display: DisplaySnapshot::default(),
         }
     }
 
     /// Load slot info from SaveManager
     pub fn load(save_manager: &SaveManager, slot: usize) -> EngineResult<Self> {
+        Self::load_checked(save_manager, slot, None)
+    }
+
+    /// Load slot info and check the save against a scenario
+    pub fn load_checked(
+        save_manager: &SaveManager,
+        slot: usize,
+        scenario: Option<&Scenario>,
+    ) -> EngineResult<Self> {
         if !save_manager.slot_exists(slot) {
             return Ok(Self::empty(slot));
         }
 
         let save_data = save_manager.load(slot)?;
+        let issues = scenario
+            .map(|scenario| save_data.compatibility_issues(scenario))
+            .unwrap_or_default();
+        let has_checkpoint = match scenario {
+            Some(scenario) => save_data.has_usable_checkpoint(scenario),
+            None => save_data.checkpoint.is_some(),
+        };
 
         Ok(Self {
             slot,
@@ -54,9 +82,17 @@ impl SlotInfo {
             scene_name: save_data.current_scene.as_str().to_string(),
             play_time_secs: save_data.play_time_secs,
             thumbnail_path: save_data.thumbnail_path.clone(),
+            issues,
+            has_checkpoint,
+            display: save_data.display_snapshot(),
         })
     }
 
+    /// Whether the save can't be restored exactly in the current scenario
+    pub fn has_issues(&self) -> bool {
+        !self.issues.is_empty()
+    }
+
     /// Format timestamp as date/time string
     pub fn formatted_date(&self) -> String {
         if self.timestamp == 0 {
@@ -107,9 +143,18 @@ impl SlotInfo {
 
 /// List all slot information (0 to max_slots)
 pub fn list_all_slots(save_manager: &SaveManager, max_slots: usize) -> Vec<SlotInfo> {
+    list_all_slots_checked(save_manager, max_slots, None)
+}
+
+/// List all slot information, checking each save against a scenario
+pub fn list_all_slots_checked(
+    save_manager: &SaveManager,
+    max_slots: usize,
+    scenario: Option<&Scenario>,
+) -> Vec<SlotInfo> {
     (0..max_slots)
         .map(|slot| {
-            SlotInfo::load(save_manager, slot).unwrap_or_else(|e| {
+            SlotInfo::load_checked(save_manager, slot, scenario).unwrap_or_else(|e| {
                 tracing::warn!("Failed to load slot {}: {}", slot, e);
                 SlotInfo::empty(slot)
             })
@@ -140,6 +185,9 @@ mod tests {
             scene_name: "Test Scene".to_string(),
             play_time_secs: 3661, // 1 hour, 1 minute, 1 second
             thumbnail_path: None,
+            issues: Vec::new(),
+            has_checkpoint: false,
+            display: DisplaySnapshot::default(),
         };
 
         assert_eq!(slot.formatted_play_time(), "01:01:01");
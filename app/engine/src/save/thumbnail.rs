@@ -1,9 +1,278 @@
 //! Thumbnail generation
+//!
+//! Thumbnails are composed from a [`DisplaySnapshot`] (background, CG and
+//! character sprites) rather than read back from the live scene, so any saved
+//! or past screen can be previewed on demand. [`thumbnail_layers`] places the
+//! layers; the game draws them on the GPU, and [`render_thumbnail`] composes
+//! them on the CPU where no renderer is available.
 
-use narrative_core::EngineResult;
+use super::SavedCharacterDisplay;
+use crate::asset::load_sprite_image;
+use image::imageops::{self, FilterType};
+use image::{Rgba, RgbaImage};
+use narrative_core::{AssetRef, CharacterPosition, EngineError, EngineResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-/// Generate a thumbnail from the current screen
-pub fn generate_thumbnail(_width: u32, _height: u32) -> EngineResult<Vec<u8>> {
-    // TODO: Phase 2.4 - thumbnail implementation
-    Ok(Vec::new())
+/// Reference resolution the scene layout is specified for
+const REFERENCE_WIDTH: f32 = 1280.0;
+/// Reference resolution the scene layout is specified for
+const REFERENCE_HEIGHT: f32 = 720.0;
+/// Character sprite height at the reference resolution (matches the live scene)
+const REFERENCE_SPRITE_HEIGHT: f32 = 600.0;
+
+/// What was on screen at one point: background, CG and characters
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DisplaySnapshot {
+    /// Background image path
+    pub background: Option<String>,
+    /// CG image path
+    pub cg: Option<String>,
+    /// Displayed characters, in drawing order
+    pub characters: Vec<SavedCharacterDisplay>,
+}
+
+impl DisplaySnapshot {
+    /// Check if nothing is displayed
+    pub fn is_empty(&self) -> bool {
+        self.background.is_none() && self.cg.is_none() && self.characters.is_empty()
+    }
+}
+
+/// One image of a thumbnail, placed in thumbnail pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThumbnailLayer<'a> {
+    /// Image path
+    pub path: &'a str,
+    /// Left edge (may be negative for sprites partly off screen)
+    pub x: i64,
+    /// Top edge
+    pub y: i64,
+    /// Drawn width (at least 1)
+    pub width: u32,
+    /// Drawn height (at least 1)
+    pub height: u32,
+}
+
+/// Place the layers of a snapshot in a `width`x`height` thumbnail
+///
+/// Layers are placed like the live scene, in drawing order: the background
+/// stretched to the thumbnail, the CG fitted with its aspect ratio, then
+/// character sprites anchored to the bottom edge. `image_size` returns the
+/// pixel size of a layer's image, or None to leave the layer out.
+pub fn thumbnail_layers<'a>(
+    snapshot: &'a DisplaySnapshot,
+    width: u32,
+    height: u32,
+    mut image_size: impl FnMut(&str) -> Option<(u32, u32)>,
+) -> Vec<ThumbnailLayer<'a>> {
+    let mut layers = Vec::new();
+
+    if let Some(background) = snapshot.background.as_deref()
+        && image_size(background).is_some()
+    {
+        layers.push(ThumbnailLayer {
+            path: background,
+            x: 0,
+            y: 0,
+            width,
+            height,
+        });
+    }
+
+    if let Some(cg) = snapshot.cg.as_deref()
+        && let Some(size) = image_size(cg).filter(|&(w, h)| w > 0 && h > 0)
+    {
+        let scale = (width as f32 / size.0 as f32).min(height as f32 / size.1 as f32);
+        let (cg_width, cg_height) = scaled_size(size, scale);
+        layers.push(ThumbnailLayer {
+            path: cg,
+            x: i64::from(width.saturating_sub(cg_width) / 2),
+            y: i64::from(height.saturating_sub(cg_height) / 2),
+            width: cg_width,
+            height: cg_height,
+        });
+    }
+
+    for character in &snapshot.characters {
+        let Some(size) = image_size(&character.sprite).filter(|&(w, h)| w > 0 && h > 0) else {
+            continue;
+        };
+        let scale = height as f32 * (REFERENCE_SPRITE_HEIGHT / REFERENCE_HEIGHT) / size.1 as f32;
+        let (sprite_width, sprite_height) = scaled_size(size, scale);
+        let x = match character.position {
+            CharacterPosition::Fixed(x) => x * width as f32 / REFERENCE_WIDTH,
+            position => width as f32 * position.x_percent() - sprite_width as f32 / 2.0,
+        };
+        layers.push(ThumbnailLayer {
+            path: &character.sprite,
+            x: x.round() as i64,
+            y: i64::from(height) - i64::from(sprite_height),
+            width: sprite_width,
+            height: sprite_height,
+        });
+    }
+
+    layers
+}
+
+/// Render a snapshot into an RGBA thumbnail on the CPU
+///
+/// Layers are placed by [`thumbnail_layers`]. Images that fail to load are
+/// skipped with a warning, so a missing asset leaves a gap rather than
+/// failing the preview.
+///
+/// # Errors
+/// Returns an error if `width` or `height` is zero.
+pub fn render_thumbnail(
+    snapshot: &DisplaySnapshot,
+    width: u32,
+    height: u32,
+) -> EngineResult<RgbaImage> {
+    if width == 0 || height == 0 {
+        return Err(EngineError::Other(format!(
+            "Invalid thumbnail size {}x{}",
+            width, height
+        )));
+    }
+
+    let mut images = HashMap::new();
+    let layers = thumbnail_layers(snapshot, width, height, |path| {
+        let image = load_layer(path)?;
+        let size = image.dimensions();
+        images.insert(path.to_string(), image);
+        Some(size)
+    });
+
+    let mut thumbnail = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]));
+    for layer in layers {
+        if let Some(image) = images.get(layer.path) {
+            let image = imageops::resize(image, layer.width, layer.height, FilterType::Triangle);
+            imageops::overlay(&mut thumbnail, &image, layer.x, layer.y);
+        }
+    }
+
+    Ok(thumbnail)
+}
+
+/// Size of an image scaled by `scale` (at least 1x1)
+fn scaled_size((width, height): (u32, u32), scale: f32) -> (u32, u32) {
+    (
+        ((width as f32 * scale).round() as u32).max(1),
+        ((height as f32 * scale).round() as u32).max(1),
+    )
+}
+
+/// Load an image layer, logging failures
+///
+/// Layered character sprites are composited first.
+fn load_layer(path: &str) -> Option<RgbaImage> {
+    match load_sprite_image(&AssetRef::new(path)) {
+        Ok(image) => Some(image),
+        Err(e) => {
+            tracing::warn!("Thumbnail layer '{}' could not be loaded: {}", path, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_image(dir: &TempDir, name: &str, width: u32, height: u32, color: [u8; 4]) -> String {
+        let path = dir.path().join(name);
+        RgbaImage::from_pixel(width, height, Rgba(color))
+            .save(&path)
+            .unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_render_thumbnail_layers() {
+        let dir = TempDir::new().unwrap();
+        let background = write_image(&dir, "bg.png", 64, 36, [0, 0, 255, 255]);
+        let sprite = write_image(&dir, "alice.png", 10, 20, [255, 0, 0, 255]);
+
+        let snapshot = DisplaySnapshot {
+            background: Some(background),
+            cg: None,
+            characters: vec![SavedCharacterDisplay {
+                character_id: "alice".to_string(),
+                sprite,
+                position: CharacterPosition::Center,
+            }],
+        };
+        let thumbnail = render_thumbnail(&snapshot, 128, 72).unwrap();
+
+        assert_eq!(thumbnail.dimensions(), (128, 72));
+        // Background in the corner, character at the bottom center
+        assert_eq!(thumbnail.get_pixel(2, 2), &Rgba([0, 0, 255, 255]));
+        assert_eq!(thumbnail.get_pixel(64, 70), &Rgba([255, 0, 0, 255]));
+        // Sprites are 600/720 of the height, so the top center is background
+        assert_eq!(thumbnail.get_pixel(64, 2), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_render_thumbnail_cg_is_letterboxed() {
+        let dir = TempDir::new().unwrap();
+        let cg = write_image(&dir, "cg.png", 10, 10, [0, 255, 0, 255]);
+
+        let snapshot = DisplaySnapshot {
+            cg: Some(cg),
+            ..Default::default()
+        };
+        let thumbnail = render_thumbnail(&snapshot, 160, 90).unwrap();
+
+        assert_eq!(thumbnail.get_pixel(80, 45), &Rgba([0, 255, 0, 255]));
+        assert_eq!(thumbnail.get_pixel(5, 45), &Rgba([0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_render_thumbnail_skips_missing_layers() {
+        let snapshot = DisplaySnapshot {
+            background: Some("missing/bg.png".to_string()),
+            ..Default::default()
+        };
+        let thumbnail = render_thumbnail(&snapshot, 16, 9).unwrap();
+        assert_eq!(thumbnail.get_pixel(0, 0), &Rgba([0, 0, 0, 255]));
+
+        assert!(render_thumbnail(&snapshot, 0, 9).is_err());
+    }
+
+    #[test]
+    fn test_thumbnail_layers_placement() {
+        let snapshot = DisplaySnapshot {
+            background: Some("bg.png".to_string()),
+            cg: Some("cg.png".to_string()),
+            characters: vec![SavedCharacterDisplay {
+                character_id: "alice".to_string(),
+                sprite: "alice.png".to_string(),
+                position: CharacterPosition::Center,
+            }],
+        };
+        let layers = thumbnail_layers(&snapshot, 320, 180, |path| match path {
+            "bg.png" => Some((1920, 1080)),
+            "cg.png" => Some((100, 100)),
+            "alice.png" => Some((200, 600)),
+            _ => None,
+        });
+
+        let placement: Vec<_> = layers
+            .iter()
+            .map(|layer| (layer.path, layer.x, layer.y, layer.width, layer.height))
+            .collect();
+        assert_eq!(
+            placement,
+            [
+                ("bg.png", 0, 0, 320, 180),
+                ("cg.png", 70, 0, 180, 180),
+                ("alice.png", 135, 30, 50, 150),
+            ]
+        );
+
+        // Layers without an image are left out
+        assert!(thumbnail_layers(&snapshot, 320, 180, |_| None).is_empty());
+    }
 }
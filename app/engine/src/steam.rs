@@ -0,0 +1,276 @@
+//! Steamworks integration (`steam` feature)
+//!
+//! Bridges engine subsystems to Steam:
+//!
+//! - **Achievements**: achievement IDs from the achievement manifest are set
+//!   as Steam achievements with the same API name
+//! - **Cloud saves**: [`SteamCloudSaveBackend`] keeps the save files in the
+//!   save directory and mirrors them to Steam Cloud
+//! - **Rich presence**: the friends list shows the current scene, from its
+//!   `rich_presence` tag or its title
+//!
+//! The Steam client must be running. Outside of Steam, put a
+//! `steam_appid.txt` with the app ID in the working directory.
+
+use crate::EngineConfig;
+use crate::save::{FileSaveBackend, MemorySaveBackend, SaveBackend, SaveBackendKind, SaveManager};
+use narrative_core::{EngineError, EngineResult, Scene};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use steamworks::Client;
+
+/// Scene tag with the rich presence text (falls back to the scene title)
+pub const RICH_PRESENCE_TAG: &str = "rich_presence";
+
+/// Rich presence key shown in the Steam friends list
+const RICH_PRESENCE_STATUS_KEY: &str = "status";
+
+/// Rich presence text for a scene: its `rich_presence` tag, or its title
+pub fn rich_presence_text(scene: &Scene) -> &str {
+    scene.tag(RICH_PRESENCE_TAG).unwrap_or(&scene.title)
+}
+
+/// Connection to the running Steam client
+#[derive(Clone)]
+pub struct Steam {
+    client: Client,
+}
+
+impl Steam {
+    /// Connect to the Steam client
+    ///
+    /// # Errors
+    /// Returns an error if Steam isn't running, the app ID can't be
+    /// determined or the user doesn't own the game.
+    pub fn init() -> EngineResult<Self> {
+        let client = Client::init()
+            .map_err(|e| EngineError::Other(format!("Failed to initialize Steam: {}", e)))?;
+        tracing::info!("Connected to Steam (app {})", client.utils().app_id().0);
+        Ok(Self { client })
+    }
+
+    /// Run pending Steam callbacks (call once per frame)
+    pub fn run_callbacks(&self) {
+        self.client.run_callbacks();
+    }
+
+    /// Set a Steam achievement and upload it
+    pub fn unlock_achievement(&self, id: &str) {
+        self.set_achievements(std::iter::once(id));
+    }
+
+    /// Set the Steam achievements of locally unlocked achievements
+    ///
+    /// Used at startup so achievements unlocked while offline (or before the
+    /// game shipped on Steam) show up in Steam too.
+    pub fn sync_achievements<'a>(&self, unlocked: impl IntoIterator<Item = &'a str>) {
+        self.set_achievements(unlocked);
+    }
+
+    fn set_achievements<'a>(&self, ids: impl IntoIterator<Item = &'a str>) {
+        let user_stats = self.client.user_stats();
+        let mut changed = false;
+        for id in ids {
+            let achievement = user_stats.achievement(id);
+            if achievement.get() == Ok(true) {
+                continue;
+            }
+            match achievement.set() {
+                Ok(()) => changed = true,
+                Err(()) => tracing::warn!("Steam has no achievement '{}'", id),
+            }
+        }
+        if changed && user_stats.store_stats().is_err() {
+            tracing::warn!("Failed to upload Steam achievements");
+        }
+    }
+
+    /// Show the scene in the Steam friends list
+    pub fn set_rich_presence(&self, scene: &Scene) {
+        let text = rich_presence_text(scene);
+        if !self
+            .client
+            .friends()
+            .set_rich_presence(RICH_PRESENCE_STATUS_KEY, Some(text))
+        {
+            tracing::warn!("Failed to set Steam rich presence to '{}'", text);
+        }
+    }
+
+    /// Clear the rich presence (e.g. back on the title screen)
+    pub fn clear_rich_presence(&self) {
+        self.client.friends().clear_rich_presence();
+    }
+
+    /// Create a save manager for the engine config's save backend
+    ///
+    /// File saves are mirrored to Steam Cloud; newer cloud saves are
+    /// downloaded first.
+    pub fn save_manager(&self, config: &EngineConfig) -> SaveManager {
+        match config.save_backend {
+            SaveBackendKind::File => {
+                let backend = SteamCloudSaveBackend::new(self.clone(), config.save_path.clone());
+                backend.sync_from_cloud();
+                SaveManager::with_backend(backend)
+            }
+            SaveBackendKind::Memory => SaveManager::with_backend(MemorySaveBackend::new()),
+        }
+    }
+}
+
+/// Save backend that mirrors save files to Steam Cloud
+///
+/// Slots are read from and written to the save directory like
+/// [`FileSaveBackend`]; every write and delete is also applied to the cloud
+/// file of the same name.
+pub struct SteamCloudSaveBackend {
+    steam: Steam,
+    local: FileSaveBackend,
+}
+
+impl SteamCloudSaveBackend {
+    /// Create a backend for a save directory
+    pub fn new(steam: Steam, directory: PathBuf) -> Self {
+        Self {
+            steam,
+            local: FileSaveBackend::new(directory),
+        }
+    }
+
+    /// Download cloud save files that are newer than the local ones
+    pub fn sync_from_cloud(&self) {
+        let remote_storage = self.steam.client.remote_storage();
+        if !remote_storage.is_cloud_enabled_for_account()
+            || !remote_storage.is_cloud_enabled_for_app()
+        {
+            tracing::info!("Steam Cloud is disabled, using local saves only");
+            return;
+        }
+        let Some(directory) = self.local.directory() else {
+            return;
+        };
+
+        for info in remote_storage.files() {
+            let file = remote_storage.file(&info.name);
+            let local_path = directory.join(&info.name);
+            if modified_secs(&local_path).is_some_and(|local| local >= file.timestamp()) {
+                continue;
+            }
+
+            let mut contents = Vec::new();
+            if let Err(e) = file.read().read_to_end(&mut contents) {
+                tracing::warn!("Failed to download '{}' from Steam Cloud: {}", info.name, e);
+                continue;
+            }
+            let written = std::fs::create_dir_all(directory)
+                .and_then(|()| std::fs::write(&local_path, &contents));
+            match written {
+                Ok(()) => tracing::info!("Downloaded '{}' from Steam Cloud", info.name),
+                Err(e) => tracing::warn!("Failed to write '{}': {}", local_path.display(), e),
+            }
+        }
+    }
+
+    /// Upload a file of the save directory to Steam Cloud
+    fn upload(&self, path: &Path, contents: &[u8]) {
+        let Some(name) = cloud_name(path) else {
+            return;
+        };
+        let mut writer = self.steam.client.remote_storage().file(name).write();
+        if let Err(e) = writer.write_all(contents) {
+            tracing::warn!("Failed to upload '{}' to Steam Cloud: {}", name, e);
+        }
+    }
+
+    /// Delete a file from Steam Cloud
+    fn delete_remote(&self, path: &Path) {
+        if let Some(name) = cloud_name(path) {
+            let file = self.steam.client.remote_storage().file(name);
+            if file.exists() && !file.delete() {
+                tracing::warn!("Failed to delete '{}' from Steam Cloud", name);
+            }
+        }
+    }
+}
+
+impl SaveBackend for SteamCloudSaveBackend {
+    fn write(&self, slot: usize, contents: &str) -> EngineResult<()> {
+        self.local.write(slot, contents)?;
+        self.upload(&self.local.slot_path(slot), contents.as_bytes());
+        Ok(())
+    }
+
+    fn read(&self, slot: usize) -> EngineResult<String> {
+        self.local.read(slot)
+    }
+
+    fn exists(&self, slot: usize) -> bool {
+        self.local.exists(slot)
+    }
+
+    fn delete(&self, slot: usize) -> EngineResult<()> {
+        self.local.delete(slot)?;
+        self.delete_remote(&self.local.slot_path(slot));
+        self.delete_remote(&self.local.thumbnail_path(slot));
+        Ok(())
+    }
+
+    fn location(&self, slot: usize) -> String {
+        format!("{} (Steam Cloud)", self.local.location(slot))
+    }
+
+    fn directory(&self) -> Option<&Path> {
+        self.local.directory()
+    }
+
+    fn write_thumbnail(&self, slot: usize, png: &[u8]) -> EngineResult<()> {
+        self.local.write_thumbnail(slot, png)?;
+        self.upload(&self.local.thumbnail_path(slot), png);
+        Ok(())
+    }
+
+    fn read_thumbnail(&self, slot: usize) -> EngineResult<Vec<u8>> {
+        self.local.read_thumbnail(slot)
+    }
+
+    fn thumbnail_name(&self, slot: usize) -> Option<String> {
+        self.local.thumbnail_name(slot)
+    }
+}
+
+/// Cloud file name of a save file (its file name)
+fn cloud_name(path: &Path) -> Option<&str> {
+    path.file_name().and_then(|name| name.to_str())
+}
+
+/// Modification time of a local file, in seconds since the Unix epoch
+fn modified_secs(path: &Path) -> Option<i64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let secs = modified.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(secs).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rich_presence_text() {
+        let mut scene = Scene::new("chapter_02", "Chapter 2: The Festival");
+        assert_eq!(rich_presence_text(&scene), "Chapter 2: The Festival");
+
+        scene.tags.insert(
+            RICH_PRESENCE_TAG.to_string(),
+            "At the summer festival".to_string(),
+        );
+        assert_eq!(rich_presence_text(&scene), "At the summer festival");
+    }
+
+    #[test]
+    fn test_cloud_name() {
+        let backend = FileSaveBackend::new(PathBuf::from("saves"));
+        assert_eq!(cloud_name(&backend.slot_path(3)), Some("slot_03.ron"));
+        assert_eq!(cloud_name(&backend.thumbnail_path(3)), Some("slot_03.png"));
+    }
+}
@@ -1,12 +1,121 @@
 //! Font management with cosmic-text integration
 
+use super::TypewriterEffect;
 use crate::error::{EngineError, EngineResult};
-use cosmic_text::{Attrs, Family, FontSystem, Metrics, fontdb};
-use std::path::Path;
+use cosmic_text::{Attrs, Fallback, Family, FontSystem, Metrics, PlatformFallback, fontdb};
+use narrative_core::{Scenario, ScenarioCommand, TextConfig};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use unicode_script::Script;
+
+/// Fonts tried in order for characters the main font has no glyph for
+///
+/// The first font is the main font, followed by its fallbacks (e.g. a
+/// Japanese font, then an emoji font). With `system_fonts`, the installed
+/// system fonts come last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontFallbackChain {
+    /// Font files, main font first
+    pub fonts: Vec<PathBuf>,
+    /// Fall back to the system fonts after `fonts`
+    pub system_fonts: bool,
+}
+
+impl Default for FontFallbackChain {
+    fn default() -> Self {
+        Self {
+            fonts: Vec::new(),
+            system_fonts: true,
+        }
+    }
+}
+
+impl FontFallbackChain {
+    /// Chain of a text config: its default font, then its fallback fonts
+    ///
+    /// Font assets are relative to `asset_root`.
+    pub fn from_text_config(config: &TextConfig, asset_root: &Path) -> Self {
+        let fonts = config
+            .default_font
+            .iter()
+            .chain(&config.fallback_fonts)
+            .map(|font| asset_root.join(font.path()))
+            .collect();
+        Self {
+            fonts,
+            system_fonts: config.system_font_fallback,
+        }
+    }
+}
+
+/// Fallback order of a chain: its fonts for every script, then the
+/// platform's common fallbacks
+///
+/// cosmic-text wants `'static` family names, so the chain's names are
+/// leaked; font managers are created once per run.
+struct ChainFallback {
+    /// Chain families, tried first for every script
+    chain: Vec<&'static str>,
+    /// Chain families followed by the platform's common fallbacks
+    common: Vec<&'static str>,
+    /// Platform fallbacks never to use
+    forbidden: Vec<&'static str>,
+}
+
+impl ChainFallback {
+    fn new(families: &[String]) -> Self {
+        let chain: Vec<&'static str> = families
+            .iter()
+            .map(|family| &*Box::leak(family.clone().into_boxed_str()))
+            .collect();
+        let platform = PlatformFallback;
+        let common = chain
+            .iter()
+            .chain(platform.common_fallback())
+            .copied()
+            .collect();
+        Self {
+            chain,
+            common,
+            forbidden: platform.forbidden_fallback().to_vec(),
+        }
+    }
+}
+
+impl Fallback for ChainFallback {
+    fn common_fallback(&self) -> &[&'static str] {
+        &self.common
+    }
+
+    fn forbidden_fallback(&self) -> &[&'static str] {
+        &self.forbidden
+    }
+
+    fn script_fallback(&self, _script: Script, _locale: &str) -> &[&'static str] {
+        &self.chain
+    }
+}
+
+/// Check if a character draws nothing (joiners, direction marks, variation selectors)
+fn is_invisible(c: char) -> bool {
+    c.is_whitespace()
+        || c.is_control()
+        || matches!(
+            c,
+            '\u{200B}'..='\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{FE00}'..='\u{FE0F}'
+                | '\u{FEFF}'
+        )
+}
 
 /// Font manager wrapping cosmic-text's FontSystem
 pub struct FontManager {
     font_system: FontSystem,
+    /// Family names of the fallback chain's fonts, main font first
+    fallback_families: Vec<String>,
 }
 
 impl FontManager {
@@ -17,7 +126,94 @@ impl FontManager {
         // Load system fonts as fallback
         font_system.db_mut().load_system_fonts();
 
-        Ok(Self { font_system })
+        Ok(Self {
+            font_system,
+            fallback_families: Vec::new(),
+        })
+    }
+
+    /// Create a font manager using a fallback chain
+    ///
+    /// The chain's first font becomes the sans-serif family used for all
+    /// text; characters it lacks are looked up in the following fonts, then
+    /// in the system fonts if the chain allows them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FontLoad` if a font file can't be read or holds no font face.
+    pub fn with_fallback_chain(chain: &FontFallbackChain) -> EngineResult<Self> {
+        let (locale, system_db) = FontSystem::new().into_locale_and_db();
+        let mut db = if chain.system_fonts {
+            system_db
+        } else {
+            fontdb::Database::new()
+        };
+
+        let mut families = Vec::with_capacity(chain.fonts.len());
+        for path in &chain.fonts {
+            let data = std::fs::read(path).map_err(|e| {
+                EngineError::FontLoad(format!(
+                    "Failed to read font file '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            let faces = db.load_font_source(fontdb::Source::Binary(Arc::new(data)));
+            let family = faces
+                .iter()
+                .find_map(|id| db.face(*id)?.families.first())
+                .map(|(name, _)| name.clone())
+                .ok_or_else(|| {
+                    EngineError::FontLoad(format!(
+                        "Font file '{}' holds no usable font face",
+                        path.display()
+                    ))
+                })?;
+            families.push(family);
+        }
+        if let Some(main) = families.first() {
+            db.set_sans_serif_family(main.clone());
+        }
+
+        let font_system = FontSystem::new_with_locale_and_db_and_fallback(
+            locale,
+            db,
+            ChainFallback::new(&families),
+        );
+        Ok(Self {
+            font_system,
+            fallback_families: families,
+        })
+    }
+
+    /// Family names of the fallback chain's fonts, main font first
+    pub fn fallback_families(&self) -> &[String] {
+        &self.fallback_families
+    }
+
+    /// Characters of `text` no loaded font has a glyph for
+    ///
+    /// These render as empty boxes ("tofu"). Whitespace and invisible
+    /// characters are skipped; each character is listed once, in order of
+    /// first appearance.
+    pub fn missing_glyphs(&mut self, text: &str) -> Vec<char> {
+        let faces: Vec<fontdb::ID> = self.font_system.db().faces().map(|face| face.id).collect();
+        let mut seen = HashSet::new();
+        let mut missing = Vec::new();
+        for c in text.chars() {
+            if is_invisible(c) || !seen.insert(c) {
+                continue;
+            }
+            let covered = faces.iter().any(|id| {
+                self.font_system
+                    .get_font(*id, fontdb::Weight::NORMAL)
+                    .is_some_and(|font| font.as_swash().charmap().map(c) != 0)
+            });
+            if !covered {
+                missing.push(c);
+            }
+        }
+        missing
     }
 
     /// Load a Japanese font from file
@@ -88,6 +284,54 @@ impl FontManager {
         families
     }
 
+    /// Characters shown by a scenario that no loaded font has a glyph for
+    ///
+    /// Covers character names, scene titles, dialogue text (without markup
+    /// tags) and its translations, and choice prompts and options.
+    pub fn missing_scenario_glyphs(&mut self, scenario: &Scenario) -> Vec<char> {
+        let mut text = String::new();
+        let mut push = |line: &str| {
+            text.push_str(line);
+            text.push('\n');
+        };
+        for character in &scenario.characters {
+            push(&character.name);
+        }
+        let mut scene_ids: Vec<&String> = scenario.scenes.keys().collect();
+        scene_ids.sort();
+        for scene in scene_ids
+            .into_iter()
+            .filter_map(|id| scenario.scenes.get(id))
+        {
+            push(&scene.title);
+            for command in &scene.commands {
+                match command {
+                    ScenarioCommand::Dialogue { dialogue } => {
+                        push(&TypewriterEffect::strip_tags(&dialogue.text));
+                        let mut languages: Vec<&String> = dialogue.translations.keys().collect();
+                        languages.sort();
+                        for translation in languages
+                            .into_iter()
+                            .filter_map(|language| dialogue.translations.get(language))
+                        {
+                            push(&TypewriterEffect::strip_tags(translation));
+                        }
+                    }
+                    ScenarioCommand::ShowChoice { choice } => {
+                        if let Some(prompt) = &choice.prompt {
+                            push(prompt);
+                        }
+                        for option in &choice.options {
+                            push(&option.text);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.missing_glyphs(&text)
+    }
+
     /// Get default metrics for a given font size
     pub fn default_metrics(font_size: f32) -> Metrics {
         Metrics::new(font_size, font_size * 1.4) // 1.4 line height multiplier
@@ -168,4 +412,88 @@ mod tests {
         // Non-existent family should return false
         assert!(!manager.has_font_family("NonExistentFontFamily12345"));
     }
+
+    #[test]
+    fn test_fallback_chain_from_text_config() {
+        let config = TextConfig::default()
+            .with_font("fonts/main.ttf")
+            .with_fallback_font("fonts/jp.otf")
+            .with_fallback_font("fonts/emoji.ttf");
+        let chain = FontFallbackChain::from_text_config(&config, Path::new("assets"));
+        assert_eq!(
+            chain.fonts,
+            [
+                PathBuf::from("assets/fonts/main.ttf"),
+                PathBuf::from("assets/fonts/jp.otf"),
+                PathBuf::from("assets/fonts/emoji.ttf"),
+            ]
+        );
+        assert!(chain.system_fonts);
+    }
+
+    #[test]
+    fn test_fallback_chain_missing_font_file() {
+        let chain = FontFallbackChain {
+            fonts: vec![PathBuf::from("non_existent_font.ttf")],
+            system_fonts: false,
+        };
+        let result = FontManager::with_fallback_chain(&chain);
+        assert!(matches!(result, Err(EngineError::FontLoad(_))));
+    }
+
+    #[test]
+    fn test_missing_glyphs_without_fonts() {
+        let chain = FontFallbackChain {
+            fonts: Vec::new(),
+            system_fonts: false,
+        };
+        let mut manager = FontManager::with_fallback_chain(&chain).unwrap();
+        assert!(manager.fallback_families().is_empty());
+
+        // Every visible character is missing, listed once in order
+        assert_eq!(
+            manager.missing_glyphs("Hi, hi\n\u{200B}あ"),
+            ['H', 'i', ',', 'h', 'あ']
+        );
+        assert!(manager.missing_glyphs(" \t\u{FE0F}").is_empty());
+    }
+
+    #[test]
+    fn test_missing_scenario_glyphs() {
+        use narrative_core::{Choice, ChoiceOption, Dialogue, ScenarioMetadata, Scene};
+
+        let mut scene = Scene::new("intro", "A");
+        let mut dialogue = Dialogue::new("narrator", "[b]b[/b]{w=1}");
+        dialogue
+            .translations
+            .insert("ja".to_string(), "c".to_string());
+        scene.commands.push(ScenarioCommand::Dialogue { dialogue });
+        scene.commands.push(ScenarioCommand::ShowChoice {
+            choice: Choice::new(vec![ChoiceOption::new("d", "intro")]),
+        });
+        let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "intro");
+        scenario.scenes.insert("intro".to_string(), scene);
+
+        let chain = FontFallbackChain {
+            fonts: Vec::new(),
+            system_fonts: false,
+        };
+        let mut manager = FontManager::with_fallback_chain(&chain).unwrap();
+        // Markup tags are not shown, so they are not checked
+        assert_eq!(
+            manager.missing_scenario_glyphs(&scenario),
+            ['A', 'b', 'c', 'd']
+        );
+    }
+
+    #[test]
+    fn test_missing_glyphs_with_system_fonts() {
+        let mut manager = FontManager::with_fallback_chain(&FontFallbackChain::default()).unwrap();
+        if manager.list_font_families().is_empty() {
+            return;
+        }
+        // Basic Latin is covered by any system font; private use characters aren't
+        assert!(manager.missing_glyphs("Hello").is_empty());
+        assert_eq!(manager.missing_glyphs("A\u{10FFFD}"), ['\u{10FFFD}']);
+    }
 }
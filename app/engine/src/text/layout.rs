@@ -20,8 +20,11 @@
 //! ```
 
 use crate::text::FontManager;
-use cosmic_text::{Attrs, Buffer, Family, Metrics, Shaping};
-use narrative_core::{Color, EngineResult, Point, Size};
+use cosmic_text::{Attrs, Buffer, Family, Metrics, Shaping, Weight};
+use narrative_core::markup::styled_runs;
+use narrative_core::{
+    Color, EngineResult, Point, Size, SpanStyle, TextConfig, TextDirection, TextEffect, TextSpan,
+};
 use std::sync::Arc;
 
 /// Text style configuration
@@ -35,6 +38,12 @@ pub struct TextStyle {
     pub color: Color,
     /// Font family
     pub family: Family<'static>,
+    /// Paragraph direction; lines of right-to-left paragraphs are aligned right
+    pub direction: TextDirection,
+    /// Extra space after each letter in pixels
+    pub letter_spacing: f32,
+    /// Extra space between paragraphs in pixels
+    pub paragraph_spacing: f32,
     // TODO(Phase 0.4+): Add font_id field for explicit font specification
     // pub font_id: Option<fontdb::ID>,
 }
@@ -46,17 +55,68 @@ impl Default for TextStyle {
             line_height: 16.0 * 1.4, // 1.4 line height multiplier
             color: Color::WHITE,
             family: Family::SansSerif,
+            direction: TextDirection::Auto,
+            letter_spacing: 0.0,
+            paragraph_spacing: 0.0,
         }
     }
 }
 
 impl TextStyle {
+    /// Style with the size and spacing of a text config
+    pub fn from_text_config(config: &TextConfig) -> Self {
+        Self {
+            font_size: config.font_size,
+            line_height: config.line_height(),
+            letter_spacing: config.letter_spacing,
+            paragraph_spacing: config.paragraph_spacing,
+            ..Self::default()
+        }
+    }
+
     /// Create attributes for cosmic-text
     pub fn attrs(&self) -> Attrs<'static> {
-        Attrs::new().family(self.family)
+        let attrs = Attrs::new().family(self.family);
+        if self.letter_spacing != 0.0 && self.font_size > 0.0 {
+            // cosmic-text tracks in ems, so larger spans get wider spacing
+            attrs.letter_spacing(self.letter_spacing / self.font_size)
+        } else {
+            attrs
+        }
+    }
+
+    /// Create attributes for a styled span of text
+    ///
+    /// `metadata` tags the span's glyphs so their style can be looked up after layout.
+    pub fn span_attrs(&self, style: &SpanStyle, metadata: usize) -> Attrs<'static> {
+        let mut attrs = self.attrs().metadata(metadata);
+        if let Some(color) = style.color {
+            attrs = attrs.color(to_cosmic_color(color));
+        }
+        if style.bold {
+            attrs = attrs.weight(Weight::BOLD);
+        }
+        if let Some(scale) = style.size {
+            attrs = attrs.metrics(Metrics::new(
+                self.font_size * scale,
+                self.line_height * scale,
+            ));
+        }
+        attrs
     }
 }
 
+/// Convert a color to cosmic-text's 8-bit RGBA
+fn to_cosmic_color(color: Color) -> cosmic_text::Color {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    cosmic_text::Color::rgba(
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        channel(color.a),
+    )
+}
+
 /// Positioned glyph for rendering
 #[derive(Debug, Clone)]
 pub struct LayoutGlyph {
@@ -70,6 +130,12 @@ pub struct LayoutGlyph {
     pub width: f32,
     /// Font size
     pub font_size: f32,
+    /// Index of the character the glyph was shaped from
+    pub char_index: usize,
+    /// Glyph color (the span's color, or the style's)
+    pub color: Color,
+    /// Kinetic effect of the glyph's span
+    pub effect: Option<TextEffect>,
 }
 
 /// Text layout line
@@ -83,6 +149,10 @@ pub struct LayoutLine {
     pub height: f32,
     /// Baseline Y position
     pub baseline_y: f32,
+    /// Whether the line's paragraph runs right to left
+    pub rtl: bool,
+    /// Index of the paragraph (text between line breaks) the line belongs to
+    pub paragraph: usize,
 }
 
 /// Text layout using cosmic-text
@@ -95,6 +165,8 @@ pub struct TextLayout {
     position: Point,
     /// Text style
     style: TextStyle,
+    /// Styled runs of the text, in characters
+    spans: Vec<TextSpan>,
     /// Cached layout lines
     lines: Vec<LayoutLine>,
 }
@@ -108,24 +180,18 @@ impl TextLayout {
         style: TextStyle,
     ) -> Self {
         let metrics = Metrics::new(style.font_size, style.line_height);
-        let mut buffer = Buffer::new(font_manager.font_system_mut(), metrics);
-
-        buffer.set_text(
-            font_manager.font_system_mut(),
-            &text,
-            &style.attrs(),
-            Shaping::Advanced,
-            None, // alignment
-        );
+        let buffer = Buffer::new(font_manager.font_system_mut(), metrics);
 
         let mut layout = Self {
             buffer,
             text,
             position,
             style,
+            spans: Vec::new(),
             lines: Vec::new(),
         };
 
+        layout.shape_text(font_manager);
         layout.update_layout(font_manager);
         layout
     }
@@ -140,42 +206,148 @@ impl TextLayout {
     ) -> Self {
         let metrics = Metrics::new(style.font_size, style.line_height);
         let mut buffer = Buffer::new(font_manager.font_system_mut(), metrics);
-
         buffer.set_size(font_manager.font_system_mut(), Some(max_width), None);
-        buffer.set_text(
-            font_manager.font_system_mut(),
-            &text,
-            &style.attrs(),
-            Shaping::Advanced,
-            None, // alignment
-        );
 
         let mut layout = Self {
             buffer,
             text,
             position,
             style,
+            spans: Vec::new(),
             lines: Vec::new(),
         };
 
+        layout.shape_text(font_manager);
         layout.update_layout(font_manager);
         layout
     }
 
+    /// Style runs of the text with markup spans (see [`narrative_core::markup`])
+    ///
+    /// Span indices are characters of the layout's text.
+    pub fn with_spans(mut self, font_manager: &mut FontManager, spans: Vec<TextSpan>) -> Self {
+        self.set_spans(font_manager, spans);
+        self
+    }
+
+    /// Replace the markup spans styling the text
+    pub fn set_spans(&mut self, font_manager: &mut FontManager, spans: Vec<TextSpan>) {
+        self.spans = spans;
+        self.shape_text(font_manager);
+        self.update_layout(font_manager);
+    }
+
+    /// Get the markup spans styling the text
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Set the buffer's text, with an attribute run per span
+    ///
+    /// A forced direction starts each paragraph with a directional mark,
+    /// which `update_layout` leaves out of the glyphs and character indices.
+    fn shape_text(&mut self, font_manager: &mut FontManager) {
+        let default_attrs = self.style.attrs();
+        let runs: Vec<(&str, Attrs<'static>)> = if self.spans.is_empty() {
+            vec![(&self.text, default_attrs.clone())]
+        } else {
+            styled_runs(&self.text, &self.spans)
+                .into_iter()
+                .map(|(range, span_index)| {
+                    // Metadata 0 marks unstyled glyphs
+                    let attrs = span_index
+                        .and_then(|index| {
+                            let span = self.spans.get(index)?;
+                            Some(self.style.span_attrs(&span.style, index + 1))
+                        })
+                        .unwrap_or_else(|| default_attrs.clone());
+                    (&self.text[range], attrs)
+                })
+                .collect()
+        };
+
+        let mark = self.direction_mark();
+        // Marks are invisible, so they don't take letter spacing
+        let mark_attrs = Attrs::new().family(self.style.family);
+        let mut marked = Vec::with_capacity(runs.len() + 1);
+        let mut paragraph_start = true;
+        for (text, attrs) in runs {
+            for piece in text.split_inclusive('\n') {
+                if paragraph_start && let Some(mark) = mark {
+                    marked.push((mark, mark_attrs.clone()));
+                }
+                paragraph_start = piece.ends_with('\n');
+                marked.push((piece, attrs.clone()));
+            }
+        }
+        if paragraph_start && let Some(mark) = mark {
+            // Trailing empty paragraph
+            marked.push((mark, mark_attrs.clone()));
+        }
+
+        self.buffer.set_rich_text(
+            font_manager.font_system_mut(),
+            marked,
+            &default_attrs,
+            Shaping::Advanced,
+            None, // alignment follows each paragraph's direction
+        );
+    }
+
+    /// Directional mark starting each paragraph, if the direction is forced
+    fn direction_mark(&self) -> Option<&'static str> {
+        self.style.direction.mark()
+    }
+
     /// Update the layout (should be called after buffer changes)
     fn update_layout(&mut self, _font_manager: &mut FontManager) {
         self.lines.clear();
 
+        // Bytes of the directional mark starting each buffer line
+        let mark_len = self.direction_mark().map_or(0, str::len);
+
+        // Character index where each buffer line starts
+        let mut line_starts = Vec::with_capacity(self.buffer.lines.len());
+        let mut line_start = 0usize;
+        for line in &self.buffer.lines {
+            line_starts.push(line_start);
+            let text = line.text().get(mark_len..).unwrap_or_default();
+            line_start = line_start
+                .saturating_add(text.chars().count())
+                .saturating_add(line.ending().as_str().chars().count());
+        }
+
         for run in self.buffer.layout_runs() {
             let mut glyphs = Vec::new();
+            let line_start = line_starts.get(run.line_i).copied().unwrap_or(0);
+            let line_y = run.line_y + self.position.y + self.paragraph_offset(run.line_i);
 
             for glyph in run.glyphs.iter() {
+                if glyph.start < mark_len {
+                    // The directional mark itself
+                    continue;
+                }
+                let span_style = glyph
+                    .metadata
+                    .checked_sub(1)
+                    .and_then(|index| self.spans.get(index))
+                    .map(|span| span.style);
+                let char_index = line_start.saturating_add(
+                    run.text
+                        .get(mark_len..glyph.start)
+                        .map_or(0, |before| before.chars().count()),
+                );
                 glyphs.push(LayoutGlyph {
                     glyph_id: glyph.glyph_id,
                     x: glyph.x + self.position.x,
-                    y: run.line_y + self.position.y,
+                    y: line_y,
                     width: glyph.w,
                     font_size: glyph.font_size,
+                    char_index,
+                    color: span_style
+                        .and_then(|style| style.color)
+                        .unwrap_or(self.style.color),
+                    effect: span_style.and_then(|style| style.effect),
                 });
             }
 
@@ -183,11 +355,18 @@ impl TextLayout {
                 glyphs,
                 width: run.line_w,
                 height: self.style.line_height,
-                baseline_y: run.line_y + self.position.y,
+                baseline_y: line_y,
+                rtl: run.rtl,
+                paragraph: run.line_i,
             });
         }
     }
 
+    /// Vertical offset of a paragraph from the paragraph spacing above it
+    fn paragraph_offset(&self, paragraph: usize) -> f32 {
+        paragraph as f32 * self.style.paragraph_spacing
+    }
+
     /// Get the text content
     pub fn text(&self) -> &str {
         &self.text
@@ -213,9 +392,10 @@ impl TextLayout {
         self.lines.iter().flat_map(|line| line.glyphs.iter())
     }
 
-    /// Get visible glyphs for typewriter effect (up to char_count characters)
+    /// Get visible glyphs for typewriter effect (glyphs of the first char_count characters)
     pub fn visible_glyphs(&self, char_count: usize) -> impl Iterator<Item = &LayoutGlyph> {
-        self.glyphs().take(char_count)
+        self.glyphs()
+            .filter(move |glyph| glyph.char_index < char_count)
     }
 
     /// Calculate the total size of the layout
@@ -227,7 +407,9 @@ impl TextLayout {
             .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
             .unwrap_or(0.0);
 
-        let height = self.lines.iter().map(|line| line.height).sum::<f32>();
+        let paragraphs = self.lines.last().map_or(0, |line| line.paragraph);
+        let height = self.lines.iter().map(|line| line.height).sum::<f32>()
+            + self.paragraph_offset(paragraphs);
 
         Ok(Size::new(width, height))
     }
@@ -243,15 +425,12 @@ impl TextLayout {
     }
 
     /// Update text content
+    ///
+    /// Clears the markup spans, which index the old text.
     pub fn set_text(&mut self, font_manager: &mut FontManager, text: String) {
         self.text = Arc::from(text);
-        self.buffer.set_text(
-            font_manager.font_system_mut(),
-            self.text.as_ref(),
-            &self.style.attrs(),
-            Shaping::Advanced,
-            None, // alignment
-        );
+        self.spans.clear();
+        self.shape_text(font_manager);
         self.update_layout(font_manager);
     }
 
@@ -382,4 +561,124 @@ mod tests {
         // Should have at least one line
         assert!(!layout.lines().is_empty());
     }
+
+    #[test]
+    fn test_spans_style_glyphs() {
+        let mut font_manager = FontManager::new().unwrap();
+        let rich = narrative_core::RichText::parse("a[color=#ff0000]b[/color]\n[wave]c");
+        let style = TextStyle::default();
+
+        let layout = TextLayout::new(
+            &mut font_manager,
+            Arc::from(rich.text.as_str()),
+            Point::new(0.0, 0.0),
+            style,
+        )
+        .with_spans(&mut font_manager, rich.spans);
+
+        let glyphs: Vec<_> = layout.glyphs().collect();
+        if glyphs.len() < 3 {
+            // No system font to shape with
+            return;
+        }
+        let indices: Vec<_> = glyphs.iter().map(|glyph| glyph.char_index).collect();
+        assert_eq!(indices, [0, 1, 3]);
+        assert_eq!(glyphs[0].color, Color::WHITE);
+        assert_eq!(glyphs[1].color, Color::hex(0xff0000));
+        assert_eq!(glyphs[2].effect, Some(TextEffect::Wave));
+        assert_eq!(layout.visible_glyphs(2).count(), 2);
+    }
+
+    #[test]
+    fn test_letter_and_paragraph_spacing() {
+        let mut font_manager = FontManager::new().unwrap();
+        let text: Arc<str> = Arc::from("Hello\nWorld");
+        let layout = TextLayout::new(
+            &mut font_manager,
+            Arc::clone(&text),
+            Point::new(0.0, 0.0),
+            TextStyle::default(),
+        );
+        let spaced = TextLayout::new(
+            &mut font_manager,
+            text,
+            Point::new(0.0, 0.0),
+            TextStyle {
+                letter_spacing: 2.0,
+                paragraph_spacing: 10.0,
+                ..TextStyle::default()
+            },
+        );
+
+        let lines = layout.lines();
+        let spaced_lines = spaced.lines();
+        assert_eq!(spaced_lines.len(), 2);
+        assert_eq!(spaced_lines[1].paragraph, 1);
+        // Each of the five letters gets 2 pixels more
+        assert!((spaced_lines[0].width - lines[0].width - 10.0).abs() < 0.01);
+        // The second paragraph moves down by the paragraph spacing
+        assert_eq!(spaced_lines[0].baseline_y, lines[0].baseline_y);
+        assert!((spaced_lines[1].baseline_y - lines[1].baseline_y - 10.0).abs() < 0.01);
+
+        let size = layout.calculate().unwrap();
+        let spaced_size = spaced.calculate().unwrap();
+        assert!((spaced_size.height - size.height - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_text_style_from_text_config() {
+        let config = TextConfig {
+            font_size: 20.0,
+            line_spacing: 1.5,
+            letter_spacing: 1.0,
+            paragraph_spacing: 6.0,
+            ..TextConfig::default()
+        };
+        let style = TextStyle::from_text_config(&config);
+        assert_eq!(style.font_size, 20.0);
+        assert_eq!(style.line_height, 30.0);
+        assert_eq!(style.letter_spacing, 1.0);
+        assert_eq!(style.paragraph_spacing, 6.0);
+    }
+
+    #[test]
+    fn test_paragraph_direction() {
+        let mut font_manager = FontManager::new().unwrap();
+        let text = "Hello שלום\nworld";
+        let char_count = text.chars().count();
+        let style = TextStyle {
+            direction: TextDirection::Rtl,
+            ..TextStyle::default()
+        };
+
+        let layout = TextLayout::new(
+            &mut font_manager,
+            Arc::from(text),
+            Point::new(0.0, 0.0),
+            style,
+        );
+        let indices: Vec<_> = layout.glyphs().map(|glyph| glyph.char_index).collect();
+        if indices.is_empty() {
+            // No system font to shape with
+            return;
+        }
+
+        // Both paragraphs run right to left, though they start with Latin text
+        assert!(layout.lines().iter().all(|line| line.rtl));
+        // The directional marks are not glyphs and don't shift character indices
+        assert!(indices.contains(&0));
+        assert!(indices.contains(&(char_count - 1)));
+        assert!(indices.iter().all(|&index| index < char_count));
+        assert_eq!(layout.visible_glyphs(5).count(), 5);
+
+        // Auto follows the first strong character of each paragraph
+        let auto = TextLayout::new(
+            &mut font_manager,
+            Arc::from("Hello שלום\nשלום world"),
+            Point::new(0.0, 0.0),
+            TextStyle::default(),
+        );
+        let directions: Vec<_> = auto.lines().iter().map(|line| line.rtl).collect();
+        assert_eq!(directions, [false, true]);
+    }
 }
@@ -7,7 +7,6 @@
 //!
 //! - Vertical text support (Phase 0.5+)
 //! - Performance metrics tracking (Phase 0.4+)
-//! - Advanced font fallback strategies (Phase 0.5+)
 
 mod atlas;
 mod font_manager;
@@ -16,7 +15,7 @@ mod layout;
 mod typewriter;
 
 pub use atlas::TextureAtlas;
-pub use font_manager::FontManager;
+pub use font_manager::{FontFallbackChain, FontManager};
 pub use glyph_cache::{GlyphCache, GlyphInfo, GlyphKey};
 pub use layout::{LayoutGlyph, LayoutLine, TextLayout, TextStyle};
-pub use typewriter::TypewriterEffect;
+pub use typewriter::{TagProblem, TypewriterEffect};
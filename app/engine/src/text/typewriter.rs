@@ -1,55 +1,176 @@
 //! Typewriter effect for dialogue
-
+//!
+//! Reveals dialogue text one character at a time. Inline tags control the reveal
+//! and are removed from the displayed text:
+//!
+//! - `{w=0.5}` (or `{wait=0.5}`) pauses for 0.5 seconds before the next character
+//! - `{speed=60}` reveals the following characters at 60 characters per second
+//! - `{/speed}` returns to the player's text speed
+//!
+//! Braces that don't form a known tag are displayed as written.
+//!
+//! Bracket markup (`[color=#ff0000]`, `[b]`, `[size=1.5]`, `[wave]`, `[shake]`,
+//! see [`narrative_core::markup`]) is parsed in the same pass, so its styled
+//! spans line up with the revealed characters.
+
+use narrative_core::{MarkupBuilder, RichText, TextSpan};
+use std::fmt;
 use std::time::Duration;
 
+/// Problem with an inline tag in dialogue text, found by
+/// [`TypewriterEffect::check_tags`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagProblem {
+    /// Tag-like text that is not a known tag or has an invalid value, so it
+    /// is displayed as written
+    Unknown(String),
+    /// Closing markup tag without an open tag of the same name
+    UnmatchedClose(String),
+    /// Markup tag left open at the end of the line
+    Unclosed(String),
+}
+
+impl fmt::Display for TagProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(tag) => write!(f, "Unknown or malformed tag {}", tag),
+            Self::UnmatchedClose(tag) => write!(f, "Closing tag {} has no opening tag", tag),
+            Self::Unclosed(name) => write!(f, "Tag [{}] is not closed", name),
+        }
+    }
+}
+
 /// Typewriter effect state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TypewriterEffect {
-    /// Full text to display
+    /// Full text to display (tags removed)
     full_text: String,
+    /// Character count of the full text
+    char_count: usize,
+    /// Pauses before revealing the character at each index, in order
+    pauses: Vec<(usize, Duration)>,
+    /// Speed overrides starting at each character index (`None` restores the base speed)
+    speed_changes: Vec<(usize, Option<f32>)>,
+    /// Styled runs of the full text
+    spans: Vec<TextSpan>,
+    /// Base speed in characters per second (0 or less shows the text instantly)
+    chars_per_second: f32,
     /// Current visible character count
     visible_chars: usize,
     /// Time elapsed since last character
     elapsed: Duration,
-    /// Time between characters
-    char_delay: Duration,
+    /// Index of the next pause in `pauses`
+    next_pause: usize,
     /// Whether the effect is complete
     complete: bool,
 }
 
 impl TypewriterEffect {
+    /// Text speed used until [`set_chars_per_second`](Self::set_chars_per_second) is called
+    pub const DEFAULT_CHARS_PER_SECOND: f32 = 30.0;
+
     /// Create a new typewriter effect
     pub fn new(text: String, chars_per_second: f32) -> Self {
-        let millis_per_char = (1000.0 / chars_per_second) as u64;
-        let char_delay = Duration::from_millis(millis_per_char);
-        let complete = text.is_empty();
+        let parsed = ParsedText::parse(&text);
+        let char_count = parsed.text.chars().count();
         Self {
-            full_text: text,
+            full_text: parsed.text,
+            char_count,
+            pauses: parsed.pauses,
+            speed_changes: parsed.speed_changes,
+            spans: parsed.spans,
+            chars_per_second,
             visible_chars: 0,
             elapsed: Duration::ZERO,
-            char_delay,
-            complete,
+            next_pause: 0,
+            complete: char_count == 0,
+        }
+    }
+
+    /// Remove typewriter tags and markup from dialogue text
+    ///
+    /// Use for places that show the whole line at once (backlog, text log).
+    pub fn strip_tags(text: &str) -> String {
+        ParsedText::parse(text).text
+    }
+
+    /// Remove typewriter tags from dialogue text, keeping its styled spans
+    ///
+    /// Use for places that show the whole line at once with its styling.
+    pub fn rich_text(text: &str) -> RichText {
+        let parsed = ParsedText::parse(text);
+        RichText {
+            text: parsed.text,
+            spans: parsed.spans,
         }
     }
 
+    /// Find unknown, malformed, unmatched and unclosed tags in dialogue text
+    ///
+    /// Only brackets and braces around a tag-like name (`[colour=#fff]`,
+    /// `{wait}`) count as tags; other bracketed text and `{$variable}`
+    /// placeholders are left alone.
+    pub fn check_tags(text: &str) -> Vec<TagProblem> {
+        ParsedText::parse(text).problems
+    }
+
+    /// Change the base speed (characters per second, 0 or less for instant text)
+    pub fn set_chars_per_second(&mut self, chars_per_second: f32) {
+        self.chars_per_second = chars_per_second;
+    }
+
     /// Update the typewriter effect
     pub fn update(&mut self, delta: Duration) {
         if self.complete {
             return;
         }
 
+        if self.chars_per_second.is_nan() || self.chars_per_second <= 0.0 {
+            self.skip();
+            return;
+        }
+
         self.elapsed = self.elapsed.saturating_add(delta);
 
-        while self.elapsed >= self.char_delay && !self.complete {
-            self.elapsed = self.elapsed.saturating_sub(self.char_delay);
-            self.visible_chars = self.visible_chars.saturating_add(1);
+        loop {
+            if let Some(&(index, pause)) = self.pauses.get(self.next_pause)
+                && index <= self.visible_chars
+            {
+                if self.elapsed < pause {
+                    break;
+                }
+                self.elapsed = self.elapsed.saturating_sub(pause);
+                self.next_pause = self.next_pause.saturating_add(1);
+                continue;
+            }
 
-            if self.visible_chars >= self.full_text.chars().count() {
+            if self.visible_chars >= self.char_count {
                 self.complete = true;
+                break;
+            }
+
+            let char_delay = self.char_delay();
+            if self.elapsed < char_delay {
+                break;
             }
+            self.elapsed = self.elapsed.saturating_sub(char_delay);
+            self.visible_chars = self.visible_chars.saturating_add(1);
         }
     }
 
+    /// Delay before revealing the next character
+    fn char_delay(&self) -> Duration {
+        let chars_per_second = self
+            .speed_changes
+            .iter()
+            .rev()
+            .find(|(index, _)| *index <= self.visible_chars)
+            .and_then(|(_, speed)| *speed)
+            .unwrap_or(self.chars_per_second);
+
+        Duration::try_from_secs_f64(1.0 / f64::from(chars_per_second)).unwrap_or_default()
+    }
+
     /// Get the currently visible text
     pub fn visible_text(&self) -> String {
         if self.complete {
@@ -62,9 +183,20 @@ impl TypewriterEffect {
         }
     }
 
+    /// Get the number of visible characters
+    pub fn visible_chars(&self) -> usize {
+        self.visible_chars
+    }
+
+    /// Get the character count of the full text
+    pub fn char_count(&self) -> usize {
+        self.char_count
+    }
+
     /// Skip to the end
     pub fn skip(&mut self) {
-        self.visible_chars = self.full_text.chars().count();
+        self.visible_chars = self.char_count;
+        self.next_pause = self.pauses.len();
         self.complete = true;
     }
 
@@ -77,6 +209,129 @@ impl TypewriterEffect {
     pub fn full_text(&self) -> &str {
         &self.full_text
     }
+
+    /// Get the styled runs of the full text, in characters
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+}
+
+/// Dialogue text with typewriter tags and markup extracted
+struct ParsedText {
+    text: String,
+    pauses: Vec<(usize, Duration)>,
+    speed_changes: Vec<(usize, Option<f32>)>,
+    spans: Vec<TextSpan>,
+    problems: Vec<TagProblem>,
+}
+
+impl ParsedText {
+    fn parse(source: &str) -> Self {
+        let mut parsed = Self {
+            text: String::with_capacity(source.len()),
+            pauses: Vec::new(),
+            speed_changes: Vec::new(),
+            spans: Vec::new(),
+            problems: Vec::new(),
+        };
+        let mut markup = MarkupBuilder::new();
+        let mut char_index = 0usize;
+        let mut rest = source;
+
+        while let Some(open) = rest.find(['{', '[']) {
+            let before = &rest[..open];
+            parsed.text.push_str(before);
+            char_index = char_index.saturating_add(before.chars().count());
+
+            let is_typewriter_tag = rest[open..].starts_with('{');
+            let (open_char, close_char) = if is_typewriter_tag {
+                ('{', '}')
+            } else {
+                ('[', ']')
+            };
+            let after_open = &rest[open + 1..];
+            let tag = after_open
+                .find(close_char)
+                .map(|close| (&after_open[..close], &after_open[close + 1..]));
+
+            let applied = tag.filter(|(tag, _)| {
+                if is_typewriter_tag {
+                    parsed.apply_tag(tag, char_index)
+                } else {
+                    markup.apply_tag(tag, char_index)
+                }
+            });
+            match applied {
+                Some((_, remaining)) => rest = remaining,
+                None => {
+                    if let Some((tag, _)) = tag
+                        && looks_like_tag(tag)
+                    {
+                        let written = format!("{}{}{}", open_char, tag, close_char);
+                        parsed.problems.push(if tag.trim().starts_with('/') {
+                            TagProblem::UnmatchedClose(written)
+                        } else {
+                            TagProblem::Unknown(written)
+                        });
+                    }
+                    parsed.text.push(open_char);
+                    char_index = char_index.saturating_add(1);
+                    rest = after_open;
+                }
+            }
+        }
+        parsed.text.push_str(rest);
+        char_index = char_index.saturating_add(rest.chars().count());
+        parsed.problems.extend(
+            markup
+                .open_tags()
+                .into_iter()
+                .map(|name| TagProblem::Unclosed(name.to_string())),
+        );
+        parsed.spans = markup.finish(char_index);
+
+        parsed
+    }
+
+    /// Record a tag at `char_index`, returning `false` for unknown or malformed tags
+    fn apply_tag(&mut self, tag: &str, char_index: usize) -> bool {
+        let tag = tag.trim();
+        if tag == "/speed" {
+            self.speed_changes.push((char_index, None));
+            return true;
+        }
+
+        let Some((name, value)) = tag.split_once('=') else {
+            return false;
+        };
+        let Ok(value) = value.trim().parse::<f32>() else {
+            return false;
+        };
+
+        match name.trim() {
+            "w" | "wait" => match Duration::try_from_secs_f32(value) {
+                Ok(pause) => {
+                    self.pauses.push((char_index, pause));
+                    true
+                }
+                Err(_) => false,
+            },
+            "speed" if value > 0.0 && value.is_finite() => {
+                self.speed_changes.push((char_index, Some(value)));
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Check if the text between brackets or braces reads as a tag name, with an
+/// optional leading `/` and `=value`
+fn looks_like_tag(tag: &str) -> bool {
+    let tag = tag.trim();
+    let name = tag.strip_prefix('/').unwrap_or(tag);
+    let name = name.split_once('=').map_or(name, |(name, _)| name).trim();
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic() || c == '_')
 }
 
 #[cfg(test)]
@@ -192,4 +447,129 @@ mod tests {
         effect.update(Duration::from_millis(1000));
         assert_eq!(effect.visible_chars, chars_before); // Should not change
     }
+
+    #[test]
+    fn test_typewriter_tags_are_removed() {
+        let effect =
+            TypewriterEffect::new("Wait{w=0.5}... {speed=5}slow{/speed}!".to_string(), 10.0);
+        assert_eq!(effect.full_text(), "Wait... slow!");
+        assert_eq!(effect.char_count(), 13);
+
+        // Unknown tags and stray braces are kept as text
+        assert_eq!(
+            TypewriterEffect::strip_tags("{name} {w=x} {"),
+            "{name} {w=x} {"
+        );
+        assert_eq!(TypewriterEffect::strip_tags("a{w=1}b{wait=2}c"), "abc");
+    }
+
+    #[test]
+    fn test_typewriter_pause() {
+        let mut effect = TypewriterEffect::new("AB{w=0.5}C".to_string(), 10.0);
+
+        effect.update(Duration::from_millis(200));
+        assert_eq!(effect.visible_chars(), 2);
+
+        // Pause holds the next character for 500ms
+        effect.update(Duration::from_millis(400));
+        assert_eq!(effect.visible_chars(), 2);
+
+        effect.update(Duration::from_millis(200));
+        assert_eq!(effect.visible_chars(), 3);
+        assert!(effect.is_complete());
+    }
+
+    #[test]
+    fn test_typewriter_trailing_pause_delays_completion() {
+        let mut effect = TypewriterEffect::new("Hi{w=1}".to_string(), 10.0);
+
+        effect.update(Duration::from_millis(200));
+        assert_eq!(effect.visible_text(), "Hi");
+        assert!(!effect.is_complete());
+
+        effect.update(Duration::from_millis(1000));
+        assert!(effect.is_complete());
+    }
+
+    #[test]
+    fn test_typewriter_speed_tag() {
+        let mut effect = TypewriterEffect::new("A{speed=2}B{/speed}C".to_string(), 10.0);
+
+        effect.update(Duration::from_millis(100));
+        assert_eq!(effect.visible_chars(), 1);
+
+        // "B" at 2 chars/sec takes 500ms
+        effect.update(Duration::from_millis(400));
+        assert_eq!(effect.visible_chars(), 1);
+        effect.update(Duration::from_millis(100));
+        assert_eq!(effect.visible_chars(), 2);
+
+        // Back to the base speed for "C"
+        effect.update(Duration::from_millis(100));
+        assert_eq!(effect.visible_chars(), 3);
+        assert!(effect.is_complete());
+    }
+
+    #[test]
+    fn test_typewriter_instant_speed_and_skip() {
+        let mut effect = TypewriterEffect::new("Hello{w=5}".to_string(), 0.0);
+        effect.update(Duration::ZERO);
+        assert!(effect.is_complete());
+        assert_eq!(effect.visible_chars(), 5);
+
+        let mut effect = TypewriterEffect::new("Hello{w=5} there".to_string(), 10.0);
+        effect.skip();
+        assert!(effect.is_complete());
+        assert_eq!(effect.visible_text(), "Hello there");
+    }
+
+    #[test]
+    fn test_typewriter_set_speed() {
+        let mut effect = TypewriterEffect::new("ABCD".to_string(), 10.0);
+        effect.update(Duration::from_millis(100));
+        assert_eq!(effect.visible_chars(), 1);
+
+        effect.set_chars_per_second(100.0);
+        effect.update(Duration::from_millis(30));
+        assert_eq!(effect.visible_chars(), 4);
+    }
+
+    #[test]
+    fn test_typewriter_markup_spans_follow_reveal() {
+        let mut effect =
+            TypewriterEffect::new("Hi {w=1}[color=#00ff00]there[/color]!".to_string(), 10.0);
+        assert_eq!(effect.full_text(), "Hi there!");
+        assert_eq!(effect.spans().len(), 1);
+        assert_eq!(effect.spans()[0].start, 3);
+        assert_eq!(effect.spans()[0].end, 8);
+
+        effect.update(Duration::from_millis(300));
+        assert_eq!(effect.visible_text(), "Hi ");
+        assert_eq!(TypewriterEffect::strip_tags("[b]Hi[/b] {w=1}[x]"), "Hi [x]");
+
+        let rich = TypewriterEffect::rich_text("[wave]Hi[/wave]{w=1}");
+        assert_eq!(rich.text, "Hi");
+        assert!(rich.has_motion());
+    }
+
+    #[test]
+    fn test_check_tags() {
+        assert!(
+            TypewriterEffect::check_tags(
+                "[b]Hi[/b] {w=1}[wave]{speed=5}x{/speed}[/wave] {$name} [1] (a)"
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            TypewriterEffect::check_tags("[b]bold [colour=#fff]x[/colour] [/i] {wait} {w=x}"),
+            [
+                TagProblem::Unknown("[colour=#fff]".to_string()),
+                TagProblem::UnmatchedClose("[/colour]".to_string()),
+                TagProblem::UnmatchedClose("[/i]".to_string()),
+                TagProblem::Unknown("{wait}".to_string()),
+                TagProblem::Unknown("{w=x}".to_string()),
+                TagProblem::Unclosed("b".to_string()),
+            ]
+        );
+    }
 }
@@ -0,0 +1,251 @@
+//! FFmpeg decoder for compressed video (`video-ffmpeg` feature)
+//!
+//! Decodes VP9, AV1 and whatever else the system FFmpeg libraries support,
+//! in WebM, Matroska or MP4 containers. Frames are decoded and converted to
+//! RGBA on a background thread a few frames ahead of playback. The audio
+//! track is decoded in full when the video opens, as stereo at its own
+//! sample rate.
+
+use super::{VideoDecoder, VideoFrame, VideoInfo};
+use crate::error::{EngineError, EngineResult};
+use ffmpeg_next::format::context::Input;
+use ffmpeg_next::format::{Pixel, Sample, sample};
+use ffmpeg_next::media::Type;
+use ffmpeg_next::software::scaling::{self, Flags};
+use ffmpeg_next::{ChannelLayout, Rational, codec, decoder, frame};
+use kira::Frame;
+use kira::sound::static_sound::{StaticSoundData, StaticSoundSettings};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
+
+/// Frames decoded ahead of playback
+const FRAMES_AHEAD: usize = 4;
+
+/// Frame rate assumed when the container doesn't declare one
+const DEFAULT_FRAME_RATE: f32 = 30.0;
+
+/// Decoder for any container and codec FFmpeg can read
+pub struct FfmpegDecoder {
+    info: VideoInfo,
+    /// Frames from the decode thread; disconnected at the end of the stream
+    frames: Mutex<Receiver<EngineResult<VideoFrame>>>,
+    soundtrack: Option<StaticSoundData>,
+}
+
+impl FfmpegDecoder {
+    /// Open a video file, decode its audio track and start decoding frames
+    pub fn open(path: &Path) -> EngineResult<Self> {
+        let error =
+            |e: ffmpeg_next::Error| EngineError::Video(format!("'{}': {}", path.display(), e));
+        ffmpeg_next::init().map_err(error)?;
+
+        let mut input = ffmpeg_next::format::input(path).map_err(error)?;
+        let soundtrack = decode_soundtrack(&mut input).map_err(error)?;
+
+        let mut input = ffmpeg_next::format::input(path).map_err(error)?;
+        let stream = input.streams().best(Type::Video).ok_or_else(|| {
+            EngineError::Video(format!("'{}' has no video stream", path.display()))
+        })?;
+        let index = stream.index();
+        let frame_rate = frame_rate(stream.avg_frame_rate())
+            .or_else(|| frame_rate(stream.rate()))
+            .unwrap_or(DEFAULT_FRAME_RATE);
+        let decoder = codec::Context::from_parameters(stream.parameters())
+            .and_then(|context| context.decoder().video())
+            .map_err(error)?;
+        let info = VideoInfo {
+            width: decoder.width(),
+            height: decoder.height(),
+            frame_rate,
+        };
+
+        let (sender, frames) = sync_channel(FRAMES_AHEAD);
+        std::thread::Builder::new()
+            .name("video-decode".to_string())
+            .spawn(move || decode_video(&mut input, index, decoder, &sender))
+            .map_err(|e| EngineError::Video(format!("Cannot start video decoding: {}", e)))?;
+
+        Ok(Self {
+            info,
+            frames: Mutex::new(frames),
+            soundtrack,
+        })
+    }
+}
+
+impl VideoDecoder for FfmpegDecoder {
+    fn info(&self) -> VideoInfo {
+        self.info
+    }
+
+    fn next_frame(&mut self) -> EngineResult<Option<VideoFrame>> {
+        let frames = self.frames.get_mut().unwrap_or_else(|e| e.into_inner());
+        // The decode thread hangs up once every frame has been sent
+        frames.recv().map_or(Ok(None), |frame| frame.map(Some))
+    }
+
+    fn take_soundtrack(&mut self) -> Option<StaticSoundData> {
+        self.soundtrack.take()
+    }
+}
+
+/// Frame rate of a stream, if it declares a usable one
+fn frame_rate(rate: Rational) -> Option<f32> {
+    (rate.numerator() > 0 && rate.denominator() > 0).then(|| f64::from(rate) as f32)
+}
+
+/// Decode the best audio stream to stereo, or `None` for a silent video
+fn decode_soundtrack(input: &mut Input) -> Result<Option<StaticSoundData>, ffmpeg_next::Error> {
+    let Some(stream) = input.streams().best(Type::Audio) else {
+        return Ok(None);
+    };
+    let index = stream.index();
+    let mut decoder = codec::Context::from_parameters(stream.parameters())?
+        .decoder()
+        .audio()?;
+    let sample_rate = decoder.rate();
+    let mut resampler = decoder.resampler(
+        Sample::F32(sample::Type::Planar),
+        ChannelLayout::STEREO,
+        sample_rate,
+    )?;
+
+    let mut frames = Vec::new();
+    let mut decoded = frame::Audio::empty();
+    let mut receive = |decoder: &mut decoder::Audio| -> Result<(), ffmpeg_next::Error> {
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let mut stereo = frame::Audio::empty();
+            resampler.run(&decoded, &mut stereo)?;
+            let left = stereo.plane::<f32>(0);
+            let right = stereo.plane::<f32>(1);
+            frames.extend(
+                left.iter()
+                    .zip(right)
+                    .map(|(&left, &right)| Frame { left, right }),
+            );
+        }
+        Ok(())
+    };
+
+    for (stream, packet) in input.packets() {
+        if stream.index() == index {
+            decoder.send_packet(&packet)?;
+            receive(&mut decoder)?;
+        }
+    }
+    decoder.send_eof()?;
+    receive(&mut decoder)?;
+
+    Ok(Some(StaticSoundData {
+        sample_rate,
+        frames: frames.into(),
+        settings: StaticSoundSettings::default(),
+        slice: None,
+    }))
+}
+
+/// Body of the decode thread; runs until the stream ends or the player is dropped
+fn decode_video(
+    input: &mut Input,
+    index: usize,
+    mut decoder: decoder::Video,
+    frames: &SyncSender<EngineResult<VideoFrame>>,
+) {
+    if let Err(e) = send_frames(input, index, &mut decoder, frames) {
+        frames
+            .send(Err(EngineError::Video(format!(
+                "Failed to decode video: {}",
+                e
+            ))))
+            .ok();
+    }
+}
+
+/// Decode every frame of the stream and send it as RGBA
+fn send_frames(
+    input: &mut Input,
+    index: usize,
+    decoder: &mut decoder::Video,
+    frames: &SyncSender<EngineResult<VideoFrame>>,
+) -> Result<(), ffmpeg_next::Error> {
+    let mut converter = RgbaConverter::default();
+    let mut decoded = frame::Video::empty();
+    // Returns false once the receiving player is gone
+    let mut receive = |decoder: &mut decoder::Video| -> Result<bool, ffmpeg_next::Error> {
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let frame = converter.convert(&decoded)?;
+            if frames.send(Ok(frame)).is_err() {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    };
+
+    for (stream, packet) in input.packets() {
+        if stream.index() == index {
+            decoder.send_packet(&packet)?;
+            if !receive(decoder)? {
+                return Ok(());
+            }
+        }
+    }
+    decoder.send_eof()?;
+    receive(decoder)?;
+    Ok(())
+}
+
+/// Converts decoded frames to RGBA, keeping the scaler while the input format holds
+#[derive(Default)]
+struct RgbaConverter {
+    scaler: Option<scaling::Context>,
+}
+
+impl RgbaConverter {
+    fn convert(&mut self, decoded: &frame::Video) -> Result<VideoFrame, ffmpeg_next::Error> {
+        let (width, height) = (decoded.width(), decoded.height());
+        let mut scaler = match self.scaler.take() {
+            Some(scaler)
+                if *scaler.input()
+                    == (scaling::context::Definition {
+                        format: decoded.format(),
+                        width,
+                        height,
+                    }) =>
+            {
+                scaler
+            }
+            _ => scaling::Context::get(
+                decoded.format(),
+                width,
+                height,
+                Pixel::RGBA,
+                width,
+                height,
+                Flags::BILINEAR,
+            )?,
+        };
+        let mut converted = frame::Video::empty();
+        scaler.run(decoded, &mut converted)?;
+        self.scaler = Some(scaler);
+
+        // Rows may be padded past the visible width
+        let row_len = (width as usize).saturating_mul(4);
+        let stride = converted.stride(0);
+        let mut rgba = Vec::with_capacity(row_len.saturating_mul(height as usize));
+        for row in converted
+            .data(0)
+            .chunks(stride.max(1))
+            .take(height as usize)
+        {
+            rgba.extend_from_slice(row.get(..row_len).unwrap_or(row));
+        }
+        rgba.resize(row_len.saturating_mul(height as usize), 255);
+
+        Ok(VideoFrame {
+            width,
+            height,
+            rgba,
+        })
+    }
+}
@@ -0,0 +1,325 @@
+//! Video cutscene decoding
+//!
+//! [`VideoPlayer`] paces decoded frames to the video's frame rate for the
+//! `PlayVideo` scenario command. Frames are RGBA, ready to upload as a
+//! texture each time a new one is due.
+//!
+//! Decoders implement [`VideoDecoder`]. With the `video-ffmpeg` feature,
+//! [`FfmpegDecoder`] plays VP9 and AV1 video (WebM, MKV, MP4) through the
+//! system FFmpeg libraries, along with the container's audio track. Without
+//! it, or for `.y4m` files, [`Y4mDecoder`] reads uncompressed YUV4MPEG2
+//! streams (`ffmpeg -i movie.webm movie.y4m`), which carry no audio.
+//!
+//! A video's soundtrack is handed out by [`VideoPlayer::take_soundtrack`]
+//! for the audio manager to play; frames then follow its playback position
+//! (see [`VideoPlayer::update_to`]). Subtitles are sidecar files next to the
+//! video, like for sound effects (see `narrative_core::SubtitleTrack`).
+
+#[cfg(feature = "video-ffmpeg")]
+mod ffmpeg;
+mod y4m;
+
+#[cfg(feature = "video-ffmpeg")]
+pub use ffmpeg::FfmpegDecoder;
+pub use y4m::Y4mDecoder;
+
+use crate::error::{EngineError, EngineResult};
+use kira::sound::static_sound::StaticSoundData;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Maximum frames decoded in one update, so a long hitch drops frames
+/// instead of stalling the game
+const MAX_FRAMES_PER_UPDATE: usize = 8;
+
+/// Stream properties of a video
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VideoInfo {
+    /// Frame width in pixels
+    pub width: u32,
+    /// Frame height in pixels
+    pub height: u32,
+    /// Frames per second
+    pub frame_rate: f32,
+}
+
+/// Decoded video frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoFrame {
+    /// Frame width in pixels
+    pub width: u32,
+    /// Frame height in pixels
+    pub height: u32,
+    /// RGBA pixels, row by row
+    pub rgba: Vec<u8>,
+}
+
+/// Source of decoded video frames
+pub trait VideoDecoder: Send + Sync {
+    /// Stream properties
+    fn info(&self) -> VideoInfo;
+
+    /// Decode the next frame, or `None` at the end of the stream
+    fn next_frame(&mut self) -> EngineResult<Option<VideoFrame>>;
+
+    /// Take the decoded audio track, if the video has one
+    fn take_soundtrack(&mut self) -> Option<StaticSoundData> {
+        None
+    }
+}
+
+/// Plays a video at its frame rate
+pub struct VideoPlayer {
+    decoder: Box<dyn VideoDecoder>,
+    /// Seconds per frame
+    frame_duration: f32,
+    /// Playback position in seconds
+    elapsed: f32,
+    /// Playback position at which the next frame is due
+    next_frame_at: f32,
+    /// Frame on screen
+    frame: Option<VideoFrame>,
+    /// The frame on screen hasn't been taken for upload yet
+    frame_pending: bool,
+    finished: bool,
+}
+
+impl VideoPlayer {
+    /// Play frames from a decoder
+    pub fn new(decoder: Box<dyn VideoDecoder>) -> Self {
+        let frame_rate = decoder.info().frame_rate;
+        let frame_duration = if frame_rate > 0.0 {
+            frame_rate.recip()
+        } else {
+            0.0
+        };
+        Self {
+            decoder,
+            frame_duration,
+            elapsed: 0.0,
+            next_frame_at: 0.0,
+            frame: None,
+            frame_pending: false,
+            finished: false,
+        }
+    }
+
+    /// Open a video file, choosing the decoder by extension
+    ///
+    /// `.y4m` files are always read by [`Y4mDecoder`]; anything else needs
+    /// the `video-ffmpeg` feature.
+    pub fn open(path: &Path) -> EngineResult<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("y4m") => {
+                let file = File::open(path).map_err(|e| {
+                    EngineError::Video(format!("Failed to open '{}': {}", path.display(), e))
+                })?;
+                let decoder = Y4mDecoder::new(BufReader::new(file))?;
+                Ok(Self::new(Box::new(decoder)))
+            }
+            #[cfg(feature = "video-ffmpeg")]
+            _ => Ok(Self::new(Box::new(FfmpegDecoder::open(path)?))),
+            #[cfg(not(feature = "video-ffmpeg"))]
+            _ => Err(EngineError::Video(format!(
+                "Unsupported video format '{}' (build with the `video-ffmpeg` feature, or convert it to .y4m)",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Take the video's audio track to play alongside it
+    ///
+    /// Returns `None` after the first call, or if the video has no audio.
+    pub fn take_soundtrack(&mut self) -> Option<StaticSoundData> {
+        self.decoder.take_soundtrack()
+    }
+
+    /// Stream properties
+    pub fn info(&self) -> VideoInfo {
+        self.decoder.info()
+    }
+
+    /// Advance playback by `delta` seconds, decoding the frames that came due
+    ///
+    /// Returns true if a new frame is ready (see [`Self::take_new_frame`]).
+    pub fn update(&mut self, delta: f32) -> EngineResult<bool> {
+        self.update_to(self.elapsed + delta)
+    }
+
+    /// Move playback to `position` seconds, decoding the frames that came due
+    ///
+    /// Used to follow the soundtrack's playback position, so frames stay in
+    /// sync with the audio. Returns true if a new frame is ready.
+    pub fn update_to(&mut self, position: f32) -> EngineResult<bool> {
+        if self.finished {
+            return Ok(false);
+        }
+        self.elapsed = position;
+
+        let mut decoded = 0;
+        while self.elapsed >= self.next_frame_at && decoded < MAX_FRAMES_PER_UPDATE {
+            match self.decoder.next_frame()? {
+                Some(frame) => {
+                    self.frame = Some(frame);
+                    self.frame_pending = true;
+                    self.next_frame_at += self.frame_duration;
+                    decoded += 1;
+                }
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+        if decoded == MAX_FRAMES_PER_UPDATE {
+            // Too far behind; continue from now rather than catching up
+            self.next_frame_at = self.elapsed + self.frame_duration;
+        }
+        Ok(self.frame_pending)
+    }
+
+    /// Take the newest frame if it hasn't been taken yet
+    pub fn take_new_frame(&mut self) -> Option<&VideoFrame> {
+        if std::mem::take(&mut self.frame_pending) {
+            self.frame.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Frame on screen
+    pub fn current_frame(&self) -> Option<&VideoFrame> {
+        self.frame.as_ref()
+    }
+
+    /// Check if the last frame has been shown for its full duration
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl std::fmt::Debug for VideoPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VideoPlayer")
+            .field("info", &self.info())
+            .field("elapsed", &self.elapsed)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decoder producing `remaining` 1x1 frames, numbered in the red channel
+    struct CountingDecoder {
+        remaining: u8,
+        decoded: u8,
+    }
+
+    impl VideoDecoder for CountingDecoder {
+        fn info(&self) -> VideoInfo {
+            VideoInfo {
+                width: 1,
+                height: 1,
+                frame_rate: 10.0,
+            }
+        }
+
+        fn next_frame(&mut self) -> EngineResult<Option<VideoFrame>> {
+            if self.remaining == 0 {
+                return Ok(None);
+            }
+            self.remaining -= 1;
+            self.decoded += 1;
+            Ok(Some(VideoFrame {
+                width: 1,
+                height: 1,
+                rgba: vec![self.decoded, 0, 0, 255],
+            }))
+        }
+    }
+
+    fn player(frames: u8) -> VideoPlayer {
+        VideoPlayer::new(Box::new(CountingDecoder {
+            remaining: frames,
+            decoded: 0,
+        }))
+    }
+
+    #[test]
+    fn test_frames_follow_frame_rate() {
+        let mut player = player(3);
+
+        // The first frame is shown immediately
+        assert!(player.update(0.0).unwrap());
+        assert_eq!(player.take_new_frame().map(|frame| frame.rgba[0]), Some(1));
+        assert!(player.take_new_frame().is_none());
+
+        // 10 fps: the second frame is due at 0.1s
+        assert!(!player.update(0.05).unwrap());
+        assert!(player.update(0.05).unwrap());
+        assert_eq!(player.take_new_frame().map(|frame| frame.rgba[0]), Some(2));
+        assert!(!player.is_finished());
+    }
+
+    #[test]
+    fn test_finishes_after_last_frame() {
+        let mut player = player(2);
+        player.update(0.0).unwrap();
+        player.update(0.1).unwrap();
+        assert!(!player.is_finished());
+
+        // The last frame stays up for its duration before the video ends
+        player.update(0.1).unwrap();
+        assert!(player.is_finished());
+        assert_eq!(player.current_frame().map(|frame| frame.rgba[0]), Some(2));
+    }
+
+    #[test]
+    fn test_long_hitch_drops_frames() {
+        let mut player = player(100);
+        player.update(5.0).unwrap();
+        assert_eq!(
+            player.take_new_frame().map(|frame| frame.rgba[0]),
+            Some(MAX_FRAMES_PER_UPDATE as u8)
+        );
+        assert!(!player.is_finished());
+    }
+
+    #[test]
+    fn test_frames_follow_soundtrack_position() {
+        let mut player = player(10);
+        player.update_to(0.0).unwrap();
+        assert_eq!(player.take_new_frame().map(|frame| frame.rgba[0]), Some(1));
+
+        // The audio clock jumped ahead: skip to the frame it is on
+        assert!(player.update_to(0.35).unwrap());
+        assert_eq!(player.take_new_frame().map(|frame| frame.rgba[0]), Some(4));
+
+        // Falling back to frame deltas continues from the audio position
+        assert!(player.update(0.1).unwrap());
+        assert_eq!(player.take_new_frame().map(|frame| frame.rgba[0]), Some(5));
+        assert!(player.take_soundtrack().is_none());
+    }
+
+    #[cfg(not(feature = "video-ffmpeg"))]
+    #[test]
+    fn test_open_rejects_unknown_format() {
+        let result = VideoPlayer::open(Path::new("movies/opening.webm"));
+        assert!(matches!(result, Err(EngineError::Video(_))));
+    }
+
+    #[cfg(feature = "video-ffmpeg")]
+    #[test]
+    fn test_open_reports_missing_file() {
+        let result = VideoPlayer::open(Path::new("movies/missing.webm"));
+        assert!(matches!(result, Err(EngineError::Video(_))));
+    }
+}
@@ -0,0 +1,322 @@
+//! YUV4MPEG2 (`.y4m`) decoder
+//!
+//! A Y4M stream is a text header (`YUV4MPEG2 W640 H360 F30:1 C420jpeg`)
+//! followed by frames, each a `FRAME` line and the raw Y, Cb and Cr planes.
+//! 8-bit 4:2:0, 4:2:2, 4:4:4 and monochrome streams are supported.
+
+use super::{VideoDecoder, VideoFrame, VideoInfo};
+use crate::error::{EngineError, EngineResult};
+use std::io::{BufRead, ErrorKind, Read};
+
+/// Stream signature at the start of the header
+const SIGNATURE: &str = "YUV4MPEG2";
+
+/// Frame marker at the start of each frame header
+const FRAME_MARKER: &str = "FRAME";
+
+/// Longest header line accepted, so a corrupt file can't exhaust memory
+const MAX_HEADER_LEN: u64 = 1024;
+
+/// Largest frame dimension accepted
+const MAX_DIMENSION: u32 = 8192;
+
+/// Chroma subsampling of a stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Chroma {
+    /// Half width, half height
+    Yuv420,
+    /// Half width, full height
+    Yuv422,
+    /// Full resolution
+    Yuv444,
+    /// Luma only
+    Mono,
+}
+
+impl Chroma {
+    fn parse(tag: &str) -> EngineResult<Self> {
+        match tag {
+            "420" | "420jpeg" | "420paldv" | "420mpeg2" => Ok(Self::Yuv420),
+            "422" => Ok(Self::Yuv422),
+            "444" => Ok(Self::Yuv444),
+            "mono" => Ok(Self::Mono),
+            other => Err(EngineError::Video(format!(
+                "Unsupported Y4M colorspace '{}' (8-bit 420, 422, 444 or mono expected)",
+                other
+            ))),
+        }
+    }
+
+    /// Horizontal and vertical subsampling factors
+    fn subsampling(self) -> (usize, usize) {
+        match self {
+            Self::Yuv420 => (2, 2),
+            Self::Yuv422 => (2, 1),
+            Self::Yuv444 | Self::Mono => (1, 1),
+        }
+    }
+}
+
+/// Decoder for YUV4MPEG2 streams
+pub struct Y4mDecoder<R> {
+    reader: R,
+    info: VideoInfo,
+    chroma: Chroma,
+    /// Y, Cb and Cr planes of the frame being decoded
+    planes: Vec<u8>,
+}
+
+impl<R: BufRead> Y4mDecoder<R> {
+    /// Read the stream header
+    pub fn new(mut reader: R) -> EngineResult<Self> {
+        let header = read_line(&mut reader)?
+            .ok_or_else(|| EngineError::Video("Empty Y4M stream".to_string()))?;
+        let mut params = header.split_ascii_whitespace();
+        if params.next() != Some(SIGNATURE) {
+            return Err(EngineError::Video("Not a Y4M stream".to_string()));
+        }
+
+        let mut width = None;
+        let mut height = None;
+        let mut frame_rate = 25.0;
+        let mut chroma = Chroma::Yuv420;
+        for param in params {
+            let (tag, value) = param.split_at(1);
+            match tag {
+                "W" => width = value.parse::<u32>().ok(),
+                "H" => height = value.parse::<u32>().ok(),
+                "F" => frame_rate = parse_ratio(value)?,
+                "C" => chroma = Chroma::parse(value)?,
+                // Interlacing, pixel aspect and comments don't affect decoding
+                _ => {}
+            }
+        }
+
+        let (width, height) = match (width, height) {
+            (Some(width), Some(height))
+                if (1..=MAX_DIMENSION).contains(&width)
+                    && (1..=MAX_DIMENSION).contains(&height) =>
+            {
+                (width, height)
+            }
+            _ => {
+                return Err(EngineError::Video(
+                    "Y4M header has missing or invalid frame size".to_string(),
+                ));
+            }
+        };
+
+        let mut decoder = Self {
+            reader,
+            info: VideoInfo {
+                width,
+                height,
+                frame_rate,
+            },
+            chroma,
+            planes: Vec::new(),
+        };
+        decoder.planes = vec![0; decoder.frame_len()];
+        Ok(decoder)
+    }
+
+    /// Size of the chroma planes (width, height), zero for monochrome
+    fn chroma_size(&self) -> (usize, usize) {
+        if self.chroma == Chroma::Mono {
+            return (0, 0);
+        }
+        let (sub_x, sub_y) = self.chroma.subsampling();
+        (
+            (self.info.width as usize).div_ceil(sub_x),
+            (self.info.height as usize).div_ceil(sub_y),
+        )
+    }
+
+    /// Bytes of plane data in a frame
+    fn frame_len(&self) -> usize {
+        let luma = self.info.width as usize * self.info.height as usize;
+        let (chroma_width, chroma_height) = self.chroma_size();
+        luma + 2 * chroma_width * chroma_height
+    }
+
+    /// Convert the planes read into RGBA (BT.601, limited range)
+    fn to_rgba(&self) -> Vec<u8> {
+        let width = self.info.width as usize;
+        let height = self.info.height as usize;
+        let (sub_x, sub_y) = self.chroma.subsampling();
+        let (chroma_width, chroma_height) = self.chroma_size();
+        let chroma_len = chroma_width * chroma_height;
+
+        let (luma, chroma) = self.planes.split_at(width * height);
+        let (cb_plane, cr_plane) = chroma.split_at(chroma_len);
+
+        let mut rgba = Vec::with_capacity(width * height * 4);
+        for (row, luma_row) in luma.chunks_exact(width).enumerate() {
+            let chroma_start = (row / sub_y) * chroma_width;
+            let cb_row = cb_plane.get(chroma_start..).unwrap_or_default();
+            let cr_row = cr_plane.get(chroma_start..).unwrap_or_default();
+            for (column, &y) in luma_row.iter().enumerate() {
+                let cb = cb_row.get(column / sub_x).copied().unwrap_or(128);
+                let cr = cr_row.get(column / sub_x).copied().unwrap_or(128);
+                let [r, g, b] = yuv_to_rgb(y, cb, cr);
+                rgba.extend_from_slice(&[r, g, b, 255]);
+            }
+        }
+        rgba.resize(width * height * 4, 255);
+        rgba
+    }
+}
+
+impl<R: BufRead + Send + Sync> VideoDecoder for Y4mDecoder<R> {
+    fn info(&self) -> VideoInfo {
+        self.info
+    }
+
+    fn next_frame(&mut self) -> EngineResult<Option<VideoFrame>> {
+        let Some(header) = read_line(&mut self.reader)? else {
+            return Ok(None);
+        };
+        if !header.starts_with(FRAME_MARKER) {
+            return Err(EngineError::Video(format!(
+                "Expected a Y4M frame header, found '{}'",
+                header
+            )));
+        }
+
+        self.reader.read_exact(&mut self.planes).map_err(|e| {
+            if e.kind() == ErrorKind::UnexpectedEof {
+                EngineError::Video("Y4M stream ends in the middle of a frame".to_string())
+            } else {
+                EngineError::Video(format!("Failed to read Y4M frame: {}", e))
+            }
+        })?;
+
+        Ok(Some(VideoFrame {
+            width: self.info.width,
+            height: self.info.height,
+            rgba: self.to_rgba(),
+        }))
+    }
+}
+
+/// Read a header line without its newline, or `None` at the end of the stream
+fn read_line(reader: &mut impl BufRead) -> EngineResult<Option<String>> {
+    let mut line = Vec::new();
+    reader
+        .take(MAX_HEADER_LEN)
+        .read_until(b'\n', &mut line)
+        .map_err(|e| EngineError::Video(format!("Failed to read Y4M header: {}", e)))?;
+    if line.is_empty() {
+        return Ok(None);
+    }
+    if line.pop() != Some(b'\n') {
+        return Err(EngineError::Video("Y4M header line too long".to_string()));
+    }
+    String::from_utf8(line)
+        .map(Some)
+        .map_err(|_| EngineError::Video("Y4M header is not valid text".to_string()))
+}
+
+/// Parse a frame rate such as `30000:1001`
+fn parse_ratio(value: &str) -> EngineResult<f32> {
+    let ratio = value
+        .split_once(':')
+        .and_then(|(numerator, denominator)| {
+            Some((
+                numerator.parse::<f32>().ok()?,
+                denominator.parse::<f32>().ok()?,
+            ))
+        })
+        .filter(|(numerator, denominator)| *numerator > 0.0 && *denominator > 0.0);
+    match ratio {
+        Some((numerator, denominator)) => Ok(numerator / denominator),
+        None => Err(EngineError::Video(format!(
+            "Invalid Y4M frame rate '{}'",
+            value
+        ))),
+    }
+}
+
+/// Convert one limited-range BT.601 YCbCr sample to RGB
+fn yuv_to_rgb(y: u8, cb: u8, cr: u8) -> [u8; 3] {
+    let c = 298 * (i32::from(y) - 16);
+    let d = i32::from(cb) - 128;
+    let e = i32::from(cr) - 128;
+    let channel = |value: i32| ((value + 128) >> 8).clamp(0, 255) as u8;
+    [
+        channel(c + 409 * e),
+        channel(c - 100 * d - 208 * e),
+        channel(c + 516 * d),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// A 2x2 4:2:0 stream with the given frames (Y planes; chroma is neutral)
+    fn stream(header: &str, frames: &[[u8; 4]]) -> Vec<u8> {
+        let mut data = format!("{}\n", header).into_bytes();
+        for luma in frames {
+            data.extend_from_slice(b"FRAME\n");
+            data.extend_from_slice(luma);
+            data.extend_from_slice(&[128, 128]);
+        }
+        data
+    }
+
+    #[test]
+    fn test_decode_frames() {
+        let data = stream(
+            "YUV4MPEG2 W2 H2 F30000:1001 Ip A1:1 C420jpeg XYSCSS=420JPEG",
+            &[[16, 235, 16, 235], [235, 235, 235, 235]],
+        );
+        let mut decoder = Y4mDecoder::new(Cursor::new(data)).unwrap();
+        let info = decoder.info();
+        assert_eq!((info.width, info.height), (2, 2));
+        assert!((info.frame_rate - 29.97).abs() < 0.01);
+
+        let first = decoder.next_frame().unwrap().unwrap();
+        assert_eq!(first.rgba.len(), 16);
+        assert_eq!(&first.rgba[..8], &[0, 0, 0, 255, 255, 255, 255, 255]);
+
+        let second = decoder.next_frame().unwrap().unwrap();
+        assert!(second.rgba.iter().all(|&channel| channel == 255));
+
+        assert_eq!(decoder.next_frame().unwrap(), None);
+    }
+
+    #[test]
+    fn test_yuv_to_rgb_colors() {
+        // Pure red in limited-range BT.601
+        assert_eq!(yuv_to_rgb(81, 90, 240), [255, 0, 0]);
+        // Mid gray
+        assert_eq!(yuv_to_rgb(126, 128, 128), [128, 128, 128]);
+    }
+
+    #[test]
+    fn test_rejects_bad_headers() {
+        for header in [
+            "MPEG4 W2 H2",
+            "YUV4MPEG2 H2 F30:1",
+            "YUV4MPEG2 W2 H2 F30:0",
+            "YUV4MPEG2 W2 H2 C420p10",
+        ] {
+            let result = Y4mDecoder::new(Cursor::new(stream(header, &[])));
+            assert!(
+                matches!(result, Err(EngineError::Video(_))),
+                "{} should be rejected",
+                header
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncated_frame_is_an_error() {
+        let mut data = stream("YUV4MPEG2 W2 H2 F30:1", &[[16, 16, 16, 16]]);
+        data.truncate(data.len() - 1);
+        let mut decoder = Y4mDecoder::new(Cursor::new(data)).unwrap();
+        assert!(matches!(decoder.next_frame(), Err(EngineError::Video(_))));
+    }
+}
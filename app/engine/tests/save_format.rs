@@ -0,0 +1,239 @@
+//! Property-based round-trip tests for the save format
+//!
+//! Save files are RON. These tests check that flags, variables, read history and
+//! whole `SaveData` values survive serialization unchanged, and that saves written
+//! by a newer build (with fields this build doesn't know) still load. A failure here
+//! means the save format changed; bump `SAVE_VERSION` or add a migration on purpose.
+
+use narrative_core::{CharacterPosition, ReadHistory, SceneId, VariableId, VariableValue};
+use narrative_engine::runtime::{FlagStore, VariableStore};
+use narrative_engine::save::{SaveData, SavedCharacterDisplay, SceneCheckpoint};
+use proptest::collection::{hash_map, vec};
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+/// Serialize to RON and back
+fn ron_round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let serialized = ron::to_string(value).expect("serialize");
+    ron::from_str(&serialized)
+        .unwrap_or_else(|e| panic!("deserialize failed: {e}\nRON: {serialized}"))
+}
+
+/// Any printable string, including quotes, escapes and non-ASCII text
+fn any_name() -> impl Strategy<Value = String> {
+    "\\PC{0,16}"
+}
+
+fn scene_id() -> impl Strategy<Value = SceneId> {
+    any_name().prop_map(SceneId::new)
+}
+
+fn finite_f64() -> impl Strategy<Value = f64> {
+    prop::num::f64::NORMAL | prop::num::f64::SUBNORMAL | prop::num::f64::ZERO
+}
+
+fn variable_value() -> impl Strategy<Value = VariableValue> {
+    prop_oneof![
+        any::<bool>().prop_map(VariableValue::Bool),
+        any::<i64>().prop_map(VariableValue::Int),
+        finite_f64().prop_map(VariableValue::Float),
+        any_name().prop_map(VariableValue::String),
+    ]
+}
+
+fn read_history() -> impl Strategy<Value = ReadHistory> {
+    vec((scene_id(), any::<usize>()), 0..16).prop_map(|entries| {
+        let mut history = ReadHistory::new();
+        for (scene, index) in entries {
+            history.mark_read(scene, index);
+        }
+        history
+    })
+}
+
+fn character_position() -> impl Strategy<Value = CharacterPosition> {
+    prop_oneof![
+        Just(CharacterPosition::FarLeft),
+        Just(CharacterPosition::Left),
+        Just(CharacterPosition::Center),
+        Just(CharacterPosition::Right),
+        Just(CharacterPosition::FarRight),
+        (0u8..=100).prop_map(CharacterPosition::Custom),
+        (-4096.0f32..4096.0).prop_map(CharacterPosition::Fixed),
+    ]
+}
+
+fn character_display() -> impl Strategy<Value = SavedCharacterDisplay> {
+    (any_name(), any_name(), character_position()).prop_map(|(character_id, sprite, position)| {
+        SavedCharacterDisplay {
+            character_id,
+            sprite,
+            position,
+        }
+    })
+}
+
+fn checkpoint() -> impl Strategy<Value = SceneCheckpoint> {
+    (
+        scene_id(),
+        hash_map(any_name(), any::<bool>(), 0..8),
+        hash_map(any_name(), any::<i64>(), 0..8),
+        hash_map(any_name(), any_name(), 0..4),
+        vec((scene_id(), any::<usize>()), 0..4),
+        proptest::option::of(any_name()),
+    )
+        .prop_map(
+            |(scene, flags, variables, text_variables, scene_stack, current_background)| {
+                SceneCheckpoint {
+                    scene,
+                    flags,
+                    variables,
+                    text_variables,
+                    scene_stack,
+                    current_background,
+                }
+            },
+        )
+}
+
+fn save_data() -> impl Strategy<Value = SaveData> {
+    let header = (
+        any::<usize>(),
+        any::<u64>(),
+        any::<u64>(),
+        proptest::option::of(any_name()),
+        scene_id(),
+        any::<usize>(),
+    );
+    let state = (
+        hash_map(any_name(), any::<bool>(), 0..8),
+        hash_map(any_name(), any::<i64>(), 0..8),
+        hash_map(any_name(), any_name(), 0..4),
+        vec(scene_id(), 0..4),
+        read_history(),
+        vec((scene_id(), any::<usize>()), 0..4),
+    );
+    let display = (
+        proptest::option::of(any_name()),
+        proptest::option::of(any_name()),
+        hash_map(any_name(), character_display(), 0..4),
+        proptest::option::of(any_name()),
+        proptest::option::of(checkpoint()),
+    );
+    let choices = (
+        any::<u64>(),
+        proptest::option::of(vec(any::<usize>(), 0..8)),
+    );
+
+    (header, state, display, choices).prop_map(
+        |(
+            (slot, timestamp, play_time_secs, scenario_id, current_scene, command_index),
+            (flags, variables, text_variables, read_scenes, read_history, scene_stack),
+            (current_background, current_cg, displayed_characters, thumbnail_path, checkpoint),
+            (choice_seed, choice_order),
+        )| {
+            let mut save = SaveData::new(slot);
+            save.timestamp = timestamp;
+            save.play_time_secs = play_time_secs;
+            save.scenario_id = scenario_id;
+            save.current_scene = current_scene;
+            save.command_index = command_index;
+            save.flags = flags;
+            save.variables = variables;
+            save.text_variables = text_variables;
+            save.read_scenes = read_scenes;
+            save.read_history = read_history;
+            save.scene_stack = scene_stack;
+            save.current_background = current_background;
+            save.current_cg = current_cg;
+            save.displayed_characters = displayed_characters;
+            save.thumbnail_path = thumbnail_path;
+            save.checkpoint = checkpoint;
+            save.choice_seed = choice_seed;
+            save.choice_order = choice_order;
+            save
+        },
+    )
+}
+
+/// RON value for an unknown field written by a newer build
+fn unknown_value() -> impl Strategy<Value = String> {
+    prop_oneof![
+        any::<i64>().prop_map(|n| n.to_string()),
+        any::<bool>().prop_map(|b| b.to_string()),
+        any_name().prop_map(|s| format!("{s:?}")),
+        vec(any::<u32>(), 0..4).prop_map(|v| format!("{v:?}")),
+        any_name().prop_map(|s| format!("Some((id: {s:?}, level: 2))")),
+        Just("None".to_string()),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn flag_store_round_trip(flags in hash_map(any_name(), any::<bool>(), 0..32)) {
+        let store = FlagStore::from_save_format(&flags);
+        prop_assert_eq!(store.to_save_format(), flags.clone());
+        prop_assert_eq!(ron_round_trip(&flags), flags);
+    }
+
+    #[test]
+    fn variable_store_round_trip(variables in hash_map(any_name(), any::<i64>(), 0..32)) {
+        let store = VariableStore::from_save_format(&variables);
+        prop_assert_eq!(store.to_save_format(), variables.clone());
+        prop_assert_eq!(ron_round_trip(&variables), variables);
+    }
+
+    #[test]
+    fn variable_store_saves_only_integers(
+        variables in hash_map(any_name(), variable_value(), 0..32)
+    ) {
+        let mut store = VariableStore::new();
+        for (name, value) in &variables {
+            store.set(VariableId::new(name.clone()), value.clone());
+        }
+
+        let expected: HashMap<String, i64> = variables
+            .iter()
+            .filter_map(|(name, value)| match value {
+                VariableValue::Int(n) => Some((name.clone(), *n)),
+                _ => None,
+            })
+            .collect();
+        prop_assert_eq!(store.to_save_format(), expected);
+    }
+
+    #[test]
+    fn variable_value_round_trip(value in variable_value()) {
+        prop_assert_eq!(ron_round_trip(&value), value);
+    }
+
+    #[test]
+    fn read_history_round_trip(history in read_history()) {
+        prop_assert_eq!(ron_round_trip(&history), history);
+    }
+
+    #[test]
+    fn save_data_round_trip(save in save_data()) {
+        prop_assert_eq!(ron_round_trip(&save), save);
+    }
+
+    #[test]
+    fn save_data_ignores_unknown_fields(
+        save in save_data(),
+        field in "zz_[a-z_]{1,12}",
+        value in unknown_value(),
+    ) {
+        let serialized = ron::to_string(&save).unwrap();
+        let body = serialized
+            .strip_prefix('(')
+            .expect("SaveData serializes as an unnamed RON struct");
+
+        let newer = format!("({field}: {value}, {body}");
+        let loaded: SaveData = ron::from_str(&newer)
+            .unwrap_or_else(|e| panic!("unknown field rejected: {e}\nRON: {newer}"));
+        prop_assert_eq!(loaded, save);
+    }
+}
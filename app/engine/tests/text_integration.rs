@@ -86,6 +86,7 @@ fn test_japanese_text_layout() {
         line_height: 22.4,
         color: Color::WHITE,
         family: cosmic_text::Family::Name("DotGothic16"),
+        ..TextStyle::default()
     };
 
     let layout = TextLayout::new(
@@ -128,6 +129,7 @@ fn test_text_wrapping() {
         line_height: 22.4,
         color: Color::WHITE,
         family: cosmic_text::Family::Name("DotGothic16"),
+        ..TextStyle::default()
     };
 
     // Create layout with max width for wrapping
@@ -170,6 +172,7 @@ fn test_mixed_text_layout() {
         line_height: 25.2,
         color: Color::new(1.0, 1.0, 1.0, 1.0),
         family: cosmic_text::Family::Name("DotGothic16"),
+        ..TextStyle::default()
     };
 
     let layout = TextLayout::new(
@@ -257,6 +260,7 @@ fn test_multiline_japanese_text() {
         line_height: 22.4,
         color: Color::WHITE,
         family: cosmic_text::Family::Name("DotGothic16"),
+        ..TextStyle::default()
     };
 
     let layout = TextLayout::new(
@@ -373,6 +377,7 @@ fn test_large_text_performance() {
         line_height: 20.0,
         color: Color::WHITE,
         family: cosmic_text::Family::Name("DotGothic16"),
+        ..TextStyle::default()
     };
 
     // This should complete without panic or excessive time
@@ -0,0 +1,399 @@
+//! Achievement list UI component
+//!
+//! Lists every achievement of the manifest with its unlock status.
+//! Features:
+//! - Scrolling list with keyboard selection and mouse wheel / scrollbar scrolling
+//! - Hidden achievements show "???" until they are unlocked
+//! - Unlock count in the header
+
+use narrative_core::{AchievementRegistry, UnlockData};
+use narrative_engine::runtime::AchievementsState;
+use narrative_gui::framework::animation::AnimationContext;
+use narrative_gui::framework::element::{
+    Element, ElementId, LayoutContext, PaintContext, ScrollView,
+};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, font_size, radius};
+use narrative_gui::{Color, Point, Size};
+use std::any::Any;
+use std::sync::Arc;
+use std::time::Duration;
+use taffy::{NodeId, Style};
+
+/// Actions that can be confirmed by the achievement list
+#[derive(Debug, Clone, PartialEq)]
+pub enum AchievementListAction {
+    /// Back to previous screen
+    Back,
+}
+
+/// Achievement list UI element
+pub struct AchievementListElement {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    state: AchievementsState,
+    registry: Arc<AchievementRegistry>,
+    unlock_data: Arc<UnlockData>,
+    confirmed_action: Option<AchievementListAction>,
+    /// Dirty flag for re-rendering
+    dirty: bool,
+    /// Animation context (reserved for future animation support)
+    #[allow(dead_code)]
+    animation_context: AnimationContext,
+    /// Scroll position of the list
+    scroll: ScrollView,
+}
+
+impl AchievementListElement {
+    // Row dimensions
+    const ROW_WIDTH: f32 = 800.0;
+    const ROW_HEIGHT: f32 = 72.0;
+    const ROW_SPACING: f32 = 12.0;
+    /// Distance between the tops of two rows
+    const ROW_STRIDE: f32 = Self::ROW_HEIGHT + Self::ROW_SPACING;
+    /// Number of rows in view
+    const VISIBLE_ROWS: usize = 6;
+
+    // UI constants
+    const HEADER_HEIGHT: f32 = 100.0;
+    const TITLE_FONT_SIZE: f32 = 36.0;
+    const INFO_FONT_SIZE: f32 = 18.0;
+    const HINT_FONT_SIZE: f32 = 16.0;
+    const TEXT_PADDING: f32 = 16.0;
+
+    /// Title and description shown for hidden achievements until unlocked
+    const HIDDEN_TITLE: &'static str = "???";
+    const HIDDEN_DESCRIPTION: &'static str = "Hidden achievement";
+
+    pub fn new(
+        state: AchievementsState,
+        registry: Arc<AchievementRegistry>,
+        unlock_data: Arc<UnlockData>,
+    ) -> Self {
+        let mut scroll = ScrollView::new()
+            .with_gap(Self::ROW_SPACING)
+            .with_step(Self::ROW_STRIDE / 2.0);
+        scroll.set_content_height(
+            (registry.total_count() as f32 * Self::ROW_STRIDE - Self::ROW_SPACING).max(0.0),
+        );
+
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            state,
+            registry,
+            unlock_data,
+            confirmed_action: None,
+            dirty: true,
+            animation_context: AnimationContext::default(),
+            scroll,
+        }
+    }
+
+    pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
+        self.animation_context = context;
+        self
+    }
+
+    pub fn confirmed_action(&self) -> Option<AchievementListAction> {
+        self.confirmed_action.clone()
+    }
+
+    pub fn reset_confirmation(&mut self) {
+        self.confirmed_action = None;
+    }
+
+    /// Number of unlocked achievements of the manifest
+    fn unlocked_count(&self) -> usize {
+        self.registry
+            .get_all_sorted()
+            .iter()
+            .filter(|achievement| self.unlock_data.is_achievement_unlocked(&achievement.id))
+            .count()
+    }
+
+    /// Region the list is scrolled in, below the header
+    fn list_viewport(bounds: Bounds) -> Bounds {
+        let height = Self::VISIBLE_ROWS as f32 * Self::ROW_STRIDE - Self::ROW_SPACING;
+        Bounds::new(
+            bounds.origin.x + (bounds.size.width - Self::ROW_WIDTH) / 2.0,
+            bounds.origin.y + Self::HEADER_HEIGHT,
+            Self::ROW_WIDTH,
+            height,
+        )
+    }
+
+    /// Select an achievement, keeping its row in view
+    fn select(&mut self, index: usize) {
+        if index == self.state.selected || index >= self.registry.total_count() {
+            return;
+        }
+        self.state.selected = index;
+        let top = index as f32 * Self::ROW_STRIDE;
+        self.scroll.scroll_to_show(top, top + Self::ROW_HEIGHT);
+        self.dirty = true;
+    }
+}
+
+impl Element for AchievementListElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> Style {
+        use taffy::prelude::*;
+
+        Style {
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        // Draw semi-transparent background overlay
+        cx.fill_rect(cx.bounds, Color::new(0.0, 0.0, 0.0, 0.85));
+
+        // Draw header
+        let title_x = cx.bounds.origin.x + 50.0;
+        let title_y = cx.bounds.origin.y + 40.0;
+        cx.draw_text(
+            "Achievements",
+            Point::new(title_x, title_y),
+            colors::TEXT_PRIMARY,
+            Self::TITLE_FONT_SIZE,
+        );
+
+        let unlock_text = format!(
+            "Unlocked: {}/{}",
+            self.unlocked_count(),
+            self.registry.total_count()
+        );
+        cx.draw_text(
+            &unlock_text,
+            Point::new(title_x, title_y + Self::TITLE_FONT_SIZE + 10.0),
+            colors::TEXT_SECONDARY,
+            Self::INFO_FONT_SIZE,
+        );
+
+        // Draw achievement rows
+        let achievements = self.registry.get_all_sorted();
+        let viewport = Self::list_viewport(cx.bounds);
+        let rows = self
+            .scroll
+            .visible_range(viewport, Self::ROW_HEIGHT, achievements.len());
+
+        self.scroll.paint_content(cx, viewport, |cx, offset| {
+            for index in rows {
+                let Some(achievement) = achievements.get(index) else {
+                    break;
+                };
+                let row_bounds = Bounds {
+                    origin: Point::new(
+                        viewport.origin.x,
+                        viewport.origin.y + index as f32 * Self::ROW_STRIDE - offset,
+                    ),
+                    size: Size::new(Self::ROW_WIDTH, Self::ROW_HEIGHT),
+                };
+                let is_unlocked = self.unlock_data.is_achievement_unlocked(&achievement.id);
+                let is_selected = index == self.state.selected;
+
+                let bg_color = if is_selected {
+                    colors::BG_SELECTED
+                } else if is_unlocked {
+                    colors::CARD_BG
+                } else {
+                    Color::new(0.12, 0.12, 0.12, 1.0)
+                };
+                cx.fill_rounded_rect(row_bounds, bg_color, radius::MD);
+                let border = if is_selected {
+                    colors::BORDER_ACCENT
+                } else {
+                    colors::BORDER_LIGHT
+                };
+                cx.stroke_rect(row_bounds, border, 1.0);
+
+                let (title, description) = if achievement.hidden && !is_unlocked {
+                    (Self::HIDDEN_TITLE, Self::HIDDEN_DESCRIPTION)
+                } else {
+                    (achievement.title.as_str(), achievement.description.as_str())
+                };
+                let (title_color, status, status_color) = if is_unlocked {
+                    (colors::TEXT_PRIMARY, "Unlocked", colors::SUCCESS)
+                } else {
+                    (colors::TEXT_MUTED, "Locked", colors::TEXT_MUTED)
+                };
+
+                let text_x = row_bounds.origin.x + Self::TEXT_PADDING;
+                let title_y = row_bounds.origin.y + Self::TEXT_PADDING + font_size::LG;
+                cx.draw_text(
+                    title,
+                    Point::new(text_x, title_y),
+                    title_color,
+                    font_size::LG,
+                );
+                cx.draw_text(
+                    description,
+                    Point::new(text_x, title_y + font_size::SM + 8.0),
+                    colors::TEXT_SECONDARY,
+                    font_size::SM,
+                );
+                cx.draw_text(
+                    status,
+                    Point::new(row_bounds.origin.x + Self::ROW_WIDTH - 110.0, title_y),
+                    status_color,
+                    font_size::MD,
+                );
+            }
+        });
+        self.scroll.paint_scrollbar(cx, viewport);
+
+        // Draw footer with hints
+        let hint_text = "Up/Down: Select | ESC: Back | Wheel: Scroll";
+        let hint_x = cx.bounds.origin.x + (cx.bounds.size.width / 2.0) - 160.0;
+        let hint_y = cx.bounds.origin.y + cx.bounds.size.height - 30.0;
+        cx.draw_text(
+            hint_text,
+            Point::new(hint_x, hint_y),
+            colors::TEXT_SECONDARY,
+            Self::HINT_FONT_SIZE,
+        );
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        self.scroll.set_viewport(Self::list_viewport(bounds));
+
+        match event {
+            InputEvent::KeyDown { key, .. } => match key {
+                KeyCode::Escape => {
+                    self.confirmed_action = Some(AchievementListAction::Back);
+                    self.dirty = true;
+                    true
+                }
+                KeyCode::Up => {
+                    if let Some(index) = self.state.selected.checked_sub(1) {
+                        self.select(index);
+                    }
+                    true
+                }
+                KeyCode::Down => {
+                    self.select(self.state.selected + 1);
+                    true
+                }
+                _ => false,
+            },
+            InputEvent::MouseScroll { .. }
+            | InputEvent::MouseDown { .. }
+            | InputEvent::MouseMove { .. }
+            | InputEvent::MouseUp { .. } => {
+                let handled = self.scroll.handle_scroll_event(event);
+                self.dirty |= handled;
+                handled
+            }
+            _ => false,
+        }
+    }
+
+    fn children(&self) -> &[Box<dyn Element>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Element>] {
+        &mut []
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn tick(&mut self, _delta: Duration) -> bool {
+        let was_dirty = self.dirty;
+        self.dirty = false;
+        was_dirty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::AchievementDef;
+    use narrative_gui::framework::input::Modifiers;
+
+    fn create_list(count: usize, unlocked: &[usize]) -> AchievementListElement {
+        let mut registry = AchievementRegistry::new();
+        let mut unlock_data = UnlockData::new();
+        for i in 0..count {
+            let id = format!("achievement_{i:02}");
+            if unlocked.contains(&i) {
+                unlock_data.unlock_achievement(id.clone());
+            }
+            registry.register(AchievementDef::new(id, format!("Achievement {i}")));
+        }
+        AchievementListElement::new(
+            AchievementsState::default(),
+            Arc::new(registry),
+            Arc::new(unlock_data),
+        )
+    }
+
+    fn key(key: KeyCode) -> InputEvent {
+        InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        }
+    }
+
+    const BOUNDS: Bounds = Bounds {
+        origin: Point { x: 0.0, y: 0.0 },
+        size: Size {
+            width: 1280.0,
+            height: 720.0,
+        },
+    };
+
+    #[test]
+    fn test_selection_scrolls_list() {
+        let mut list = create_list(10, &[]);
+
+        for _ in 0..AchievementListElement::VISIBLE_ROWS {
+            assert!(list.handle_event(&key(KeyCode::Down), BOUNDS));
+        }
+        assert_eq!(list.state.selected, AchievementListElement::VISIBLE_ROWS);
+        // The seventh row is brought into view at the bottom
+        assert_eq!(list.scroll.offset(), AchievementListElement::ROW_STRIDE);
+
+        // Selection stops at the last achievement
+        for _ in 0..10 {
+            list.handle_event(&key(KeyCode::Down), BOUNDS);
+        }
+        assert_eq!(list.state.selected, 9);
+    }
+
+    #[test]
+    fn test_escape_goes_back() {
+        let mut list = create_list(3, &[1]);
+        assert_eq!(list.unlocked_count(), 1);
+
+        list.handle_event(&key(KeyCode::Escape), BOUNDS);
+        assert_eq!(list.confirmed_action(), Some(AchievementListAction::Back));
+        list.reset_confirmation();
+        assert_eq!(list.confirmed_action(), None);
+    }
+}
@@ -1,13 +1,19 @@
 //! Backlog UI component for viewing dialogue history
 //!
 //! This component displays a scrollable list of past dialogues, allowing
-//! players to review previous conversations.
+//! players to review previous conversations. The log can also be exported to
+//! a text or HTML file. Hovering an entry previews the screen it was shown on.
 
 use narrative_core::BacklogEntry;
+use narrative_engine::runtime::{TextLogFormat, TextLogSource};
+use narrative_engine::save::DisplaySnapshot;
 use narrative_gui::framework::animation::AnimationContext;
-use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::element::{
+    Element, ElementId, LayoutContext, PaintContext, ScrollView,
+};
 use narrative_gui::framework::input::{InputEvent, KeyCode};
 use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::renderer::TextAlign;
 use narrative_gui::theme::colors;
 use narrative_gui::{Point, Size};
 use std::any::Any;
@@ -21,18 +27,22 @@ pub struct BacklogElement {
     layout_node: Option<NodeId>,
     /// Backlog entries (newest first)
     entries: Vec<BacklogEntry>,
-    /// Current scroll offset (in pixels)
-    scroll_offset: f32,
-    /// Maximum scroll offset
-    max_scroll: f32,
+    /// Screen shown with each entry (same order as `entries`)
+    snapshots: Vec<Option<DisplaySnapshot>>,
+    /// Entry under the mouse cursor
+    hovered_entry: Option<usize>,
+    /// Rendered preview of the hovered entry's screen
+    preview_texture: Option<u64>,
+    /// Scroll position and scrollbar of the entry list
+    scroll: ScrollView,
     /// Dirty flag for repainting
     dirty: bool,
     /// Whether close was requested (Escape key)
     close_requested: bool,
-    /// Whether the scrollbar is being dragged
-    is_dragging_scrollbar: bool,
-    /// Y offset when drag started
-    drag_start_offset: f32,
+    /// Pending export request (E/H keys), taken by the game root
+    export_request: Option<(TextLogSource, TextLogFormat)>,
+    /// Result of the last export, shown below the title
+    status_message: Option<String>,
     /// Animation context for global settings
     animation_context: AnimationContext,
     /// Component-specific animation override (None = follow global)
@@ -54,29 +64,38 @@ impl BacklogElement {
     const SPEAKER_FONT_SIZE: f32 = 16.0;
     /// Dialogue text font size
     const TEXT_FONT_SIZE: f32 = 14.0;
-    /// Scroll speed (pixels per wheel tick)
+    /// Scroll speed (pixels per arrow key press)
     const SCROLL_SPEED: f32 = 40.0;
+    /// Pixels scrolled per wheel step
+    const WHEEL_SCROLL_STEP: f32 = 20.0;
     /// Background overlay opacity
     const OVERLAY_ALPHA: f32 = 0.92;
     /// Maximum characters per line (approximate)
     const MAX_CHARS_PER_LINE: usize = 60;
-    /// Maximum number of visible entries at once
-    const MAX_VISIBLE_ENTRIES: usize = 8;
-    /// Scrollbar width in pixels
-    const SCROLLBAR_WIDTH: f32 = 8.0;
+    /// Screen preview width
+    const PREVIEW_WIDTH: f32 = 240.0;
+    /// Screen preview height
+    const PREVIEW_HEIGHT: f32 = 135.0;
 
     /// Create a new backlog element
     pub fn new(entries: Vec<BacklogEntry>) -> Self {
+        let mut scroll = ScrollView::new()
+            .with_gap(Self::ENTRY_SPACING)
+            .with_step(Self::WHEEL_SCROLL_STEP);
+        scroll.set_content_height(Self::total_content_height(&entries));
+
         Self {
             id: ElementId::new(),
             layout_node: None,
             entries,
-            scroll_offset: 0.0,
-            max_scroll: 0.0, // Will be calculated in update_max_scroll
+            snapshots: Vec::new(),
+            hovered_entry: None,
+            preview_texture: None,
+            scroll,
             dirty: true,
             close_requested: false,
-            is_dragging_scrollbar: false,
-            drag_start_offset: 0.0,
+            export_request: None,
+            status_message: None,
             animation_context: AnimationContext::default(),
             animations_enabled: None,
         }
@@ -94,36 +113,68 @@ impl BacklogElement {
         self
     }
 
+    /// Set the screen shown with each entry, for hover previews
+    pub fn with_snapshots(mut self, snapshots: Vec<Option<DisplaySnapshot>>) -> Self {
+        self.snapshots = snapshots;
+        self
+    }
+
+    /// Screen of the hovered entry, if it should be previewed
+    pub fn preview_snapshot(&self) -> Option<&DisplaySnapshot> {
+        self.hovered_entry
+            .and_then(|index| self.snapshots.get(index)?.as_ref())
+            .filter(|snapshot| !snapshot.is_empty())
+    }
+
+    /// Set the rendered preview of the hovered entry
+    ///
+    /// Returns true if the texture changed.
+    pub fn set_preview_texture(&mut self, texture_id: Option<u64>) -> bool {
+        if self.preview_texture == texture_id {
+            return false;
+        }
+        self.preview_texture = texture_id;
+        self.dirty = true;
+        true
+    }
+
     /// Check if close was requested
     pub fn is_close_requested(&self) -> bool {
         self.close_requested
     }
 
+    /// Take the pending export request
+    ///
+    /// E exports the backlog as text and H as HTML. With Shift held, the full
+    /// read history is exported instead of the backlog.
+    pub fn take_export_request(&mut self) -> Option<(TextLogSource, TextLogFormat)> {
+        self.export_request.take()
+    }
+
+    /// Show the result of an export
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+        self.dirty = true;
+    }
+
     /// Calculate total content height based on actual entry heights
-    fn calculate_total_content_height(&self) -> f32 {
+    fn total_content_height(entries: &[BacklogEntry]) -> f32 {
         let mut total_height = Self::PADDING * 2.0;
 
-        if self.entries.is_empty() {
+        if entries.is_empty() {
             return total_height;
         }
 
-        for entry in &self.entries {
-            total_height += Self::calculate_entry_height(&entry.text) + Self::ENTRY_SPACING;
+        for entry in entries {
+            total_height += Self::calculate_entry_height(entry) + Self::ENTRY_SPACING;
         }
         // Remove the last spacing
         total_height - Self::ENTRY_SPACING
     }
 
-    /// Update max scroll based on viewport height
-    fn update_max_scroll(&mut self, viewport_height: f32) {
-        let content_height = self.calculate_total_content_height();
-        self.max_scroll = (content_height - viewport_height).max(0.0);
-    }
-
     /// Scroll by a delta amount
     fn scroll(&mut self, delta: f32) {
-        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, self.max_scroll);
-        self.dirty = true;
+        self.dirty |= self.scroll.scroll_by(delta);
     }
 
     /// Scroll up (towards newer entries)
@@ -196,12 +247,106 @@ impl BacklogElement {
         lines
     }
 
+    /// Wrapped lines of an entry: the text, then its translation (max 4 lines each)
+    fn entry_lines(entry: &BacklogEntry) -> (Vec<String>, Vec<String>) {
+        let mut lines = Self::split_text_into_lines(&entry.text, Self::MAX_CHARS_PER_LINE);
+        lines.truncate(4);
+        let mut translation_lines = entry
+            .translation
+            .as_deref()
+            .map(|translation| Self::split_text_into_lines(translation, Self::MAX_CHARS_PER_LINE))
+            .unwrap_or_default();
+        translation_lines.truncate(4);
+        (lines, translation_lines)
+    }
+
     /// Calculate the height of a single entry based on text content
-    fn calculate_entry_height(text: &str) -> f32 {
-        let lines = Self::split_text_into_lines(text, Self::MAX_CHARS_PER_LINE);
-        let line_count = lines.len().min(4); // Max 4 lines
+    fn calculate_entry_height(entry: &BacklogEntry) -> f32 {
+        let (lines, translation_lines) = Self::entry_lines(entry);
+        let line_count = lines.len() + translation_lines.len();
         Self::BASE_ENTRY_HEIGHT + (line_count as f32 * Self::TEXT_LINE_HEIGHT)
     }
+
+    /// Container bounds within the element bounds
+    fn container_bounds(bounds: Bounds) -> Bounds {
+        Bounds {
+            origin: Point::new(
+                bounds.origin.x + Self::CONTAINER_MARGIN,
+                bounds.origin.y + Self::CONTAINER_MARGIN,
+            ),
+            size: Size::new(
+                bounds.size.width - (Self::CONTAINER_MARGIN * 2.0),
+                bounds.size.height - (Self::CONTAINER_MARGIN * 2.0),
+            ),
+        }
+    }
+
+    /// Area the entries scroll in, below the title
+    fn entries_viewport(container_bounds: Bounds) -> Bounds {
+        Bounds {
+            origin: Point::new(
+                container_bounds.origin.x + Self::PADDING,
+                container_bounds.origin.y + Self::PADDING + 60.0,
+            ),
+            size: Size::new(
+                container_bounds.size.width - (Self::PADDING * 2.0),
+                container_bounds.size.height - 100.0,
+            ),
+        }
+    }
+
+    /// Bounds of the entries in view in a container (index, bounds)
+    ///
+    /// Entries partly in view are included; they are cut to the viewport
+    /// when painted.
+    fn visible_entries(&self, container_bounds: Bounds) -> Vec<(usize, Bounds)> {
+        self.scroll.visible_items(
+            Self::entries_viewport(container_bounds),
+            self.entries.iter().map(Self::calculate_entry_height),
+        )
+    }
+
+    /// Update the hovered entry; returns true if it changed
+    fn update_hover(&mut self, position: Point, bounds: Bounds) -> bool {
+        let hovered = self
+            .visible_entries(Self::container_bounds(bounds))
+            .into_iter()
+            .find(|(_, entry_bounds)| entry_bounds.contains(position))
+            .map(|(index, _)| index);
+
+        if hovered == self.hovered_entry {
+            return false;
+        }
+        self.hovered_entry = hovered;
+        // The previous preview belongs to another entry
+        self.preview_texture = None;
+        self.dirty = true;
+        true
+    }
+
+    /// Paint the preview of the hovered entry beside it
+    fn paint_preview(&self, cx: &mut PaintContext, entry_bounds: Bounds, container_bounds: Bounds) {
+        let Some(texture_id) = self.preview_texture else {
+            return;
+        };
+
+        // Right-aligned next to the scrollbar, centered on the entry and kept
+        // inside the container
+        let x = entry_bounds.origin.x + entry_bounds.size.width - Self::PREVIEW_WIDTH - 12.0;
+        let min_y = container_bounds.origin.y + Self::PADDING;
+        let max_y = container_bounds.origin.y + container_bounds.size.height
+            - Self::PADDING
+            - Self::PREVIEW_HEIGHT;
+        let y = (entry_bounds.origin.y + (entry_bounds.size.height - Self::PREVIEW_HEIGHT) / 2.0)
+            .clamp(min_y, max_y.max(min_y));
+
+        let preview_bounds = Bounds {
+            origin: Point::new(x, y),
+            size: Size::new(Self::PREVIEW_WIDTH, Self::PREVIEW_HEIGHT),
+        };
+        cx.draw_texture(texture_id, preview_bounds, 1.0);
+        cx.stroke_rect(preview_bounds, colors::ACCENT_PRIMARY, 2.0);
+    }
 }
 
 impl Element for BacklogElement {
@@ -238,16 +383,7 @@ impl Element for BacklogElement {
         cx.fill_rect(cx.bounds, overlay_color);
 
         // Draw backlog container
-        let container_bounds = Bounds {
-            origin: Point::new(
-                cx.bounds.origin.x + Self::CONTAINER_MARGIN,
-                cx.bounds.origin.y + Self::CONTAINER_MARGIN,
-            ),
-            size: Size::new(
-                cx.bounds.size.width - (Self::CONTAINER_MARGIN * 2.0),
-                cx.bounds.size.height - (Self::CONTAINER_MARGIN * 2.0),
-            ),
-        };
+        let container_bounds = Self::container_bounds(cx.bounds);
 
         // Draw container background
         cx.fill_rounded_rect(container_bounds, colors::CARD_BG, 12.0);
@@ -275,174 +411,130 @@ impl Element for BacklogElement {
             14.0,
         );
 
-        // Calculate content area (below title)
-        let content_start_y = title_y + 60.0;
-        let content_height = container_bounds.size.height - 100.0;
-
-        // Draw entries (newest first, scrollable)
-        let mut current_y = content_start_y - self.scroll_offset;
-        let mut visible_count = 0;
-        let content_end_y = content_start_y + content_height;
-
-        for entry in &self.entries {
-            let entry_height = Self::calculate_entry_height(&entry.text);
-
-            // Skip entries that are above the visible area
-            if current_y + entry_height < content_start_y {
-                current_y += entry_height + Self::ENTRY_SPACING;
-                continue;
-            }
-
-            // Stop if we've reached the maximum number of visible entries
-            if visible_count >= Self::MAX_VISIBLE_ENTRIES {
-                break;
-            }
-
-            // Stop if the entry would extend beyond the visible area (prevents partial display)
-            if current_y + entry_height > content_end_y {
-                break;
-            }
+        // Draw export hint, or the result of the last export
+        let (export_text, export_color) = match &self.status_message {
+            Some(message) => (message.as_str(), colors::INFO),
+            None => (
+                "E: Export text  H: Export HTML  (Shift: all read lines)",
+                colors::TEXT_SECONDARY,
+            ),
+        };
+        cx.draw_text(
+            export_text,
+            Point::new(title_x + 140.0, title_y + 20.0),
+            export_color,
+            13.0,
+        );
 
-            // Draw entry background (subtle distinction)
-            let entry_bounds = Bounds {
-                origin: Point::new(container_bounds.origin.x + Self::PADDING, current_y),
-                size: Size::new(
-                    container_bounds.size.width - (Self::PADDING * 2.0),
-                    entry_height,
-                ),
-            };
-
-            // Draw entry background
-            cx.fill_rounded_rect(entry_bounds, colors::BG_DARK, 4.0);
-
-            // Draw speaker name
-            let speaker_name = entry.speaker_name();
-            let speaker_x = entry_bounds.origin.x + 12.0;
-            let speaker_y = entry_bounds.origin.y + 20.0;
-            cx.draw_text(
-                speaker_name,
-                Point::new(speaker_x, speaker_y),
-                colors::ACCENT_PRIMARY,
-                Self::SPEAKER_FONT_SIZE,
-            );
-
-            // Draw dialogue text (with line wrapping)
-            let text_x = speaker_x;
-            let mut text_y = speaker_y + 26.0;
-            let lines = Self::split_text_into_lines(&entry.text, Self::MAX_CHARS_PER_LINE);
-
-            for line in lines.iter().take(4) {
-                // Limit to 4 lines to fit in entry height
-                cx.draw_text(
-                    line,
-                    Point::new(text_x, text_y),
-                    colors::TEXT_PRIMARY,
-                    Self::TEXT_FONT_SIZE,
+        // Draw entries (newest first, scrollable), cut to the entry area
+        let viewport = Self::entries_viewport(container_bounds);
+        let visible_entries = self.visible_entries(container_bounds);
+        self.scroll.paint_content(cx, viewport, |cx, _| {
+            for (index, entry_bounds) in &visible_entries {
+                let entry = &self.entries[*index];
+
+                // Draw entry background (subtle distinction)
+                let entry_color = if self.hovered_entry == Some(*index) {
+                    colors::BG_HOVER
+                } else {
+                    colors::BG_DARK
+                };
+                cx.fill_rounded_rect(*entry_bounds, entry_color, 4.0);
+
+                // Right-to-left entries start at the right edge, speaker name included
+                let (lines, translation_lines) = Self::entry_lines(entry);
+                let start_x = |align: TextAlign| match align {
+                    TextAlign::Right => entry_bounds.origin.x + entry_bounds.size.width - 12.0,
+                    _ => entry_bounds.origin.x + 12.0,
+                };
+                let text_align = TextAlign::start_of(&entry.text);
+
+                // Draw speaker name
+                let speaker_name = entry.speaker_name();
+                let speaker_y = entry_bounds.origin.y + 20.0;
+                cx.draw_text_aligned(
+                    speaker_name,
+                    Point::new(start_x(text_align), speaker_y),
+                    colors::ACCENT_PRIMARY,
+                    Self::SPEAKER_FONT_SIZE,
+                    text_align,
                 );
-                text_y += Self::TEXT_LINE_HEIGHT;
-            }
-
-            current_y += entry_height + Self::ENTRY_SPACING;
-            visible_count += 1;
-        }
-
-        // Draw scrollbar if content is scrollable
-        if self.max_scroll > 0.0 {
-            let scrollbar_width = Self::SCROLLBAR_WIDTH;
-
-            // Calculate scrollbar height based on viewport to total content ratio
-            let total_content_height = content_height + self.max_scroll;
-            let scrollbar_height = (content_height / total_content_height) * content_height;
 
-            // Calculate scrollbar position within the available travel distance
-            let scrollbar_travel_distance = content_height - scrollbar_height;
-            let scrollbar_y = content_start_y
-                + (self.scroll_offset / self.max_scroll) * scrollbar_travel_distance;
-
-            let scrollbar_bounds = Bounds {
-                origin: Point::new(
-                    container_bounds.origin.x + container_bounds.size.width - 20.0,
-                    scrollbar_y,
-                ),
-                size: Size::new(scrollbar_width, scrollbar_height),
-            };
-
-            cx.fill_rounded_rect(scrollbar_bounds, colors::ACCENT_PRIMARY, 4.0);
+                // Draw dialogue text (with line wrapping)
+                let mut text_y = speaker_y + 26.0;
+                for line in &lines {
+                    cx.draw_text_aligned(
+                        line,
+                        Point::new(start_x(text_align), text_y),
+                        colors::TEXT_PRIMARY,
+                        Self::TEXT_FONT_SIZE,
+                        text_align,
+                    );
+                    text_y += Self::TEXT_LINE_HEIGHT;
+                }
+                let translation_align = entry
+                    .translation
+                    .as_deref()
+                    .map_or(TextAlign::Left, TextAlign::start_of);
+                for line in &translation_lines {
+                    cx.draw_text_aligned(
+                        line,
+                        Point::new(start_x(translation_align), text_y),
+                        colors::TEXT_SECONDARY,
+                        Self::TEXT_FONT_SIZE,
+                        translation_align,
+                    );
+                    text_y += Self::TEXT_LINE_HEIGHT;
+                }
+            }
+        });
+        self.scroll.paint_scrollbar(cx, viewport);
+
+        // Draw the screen preview of the hovered entry on top
+        if let Some((_, entry_bounds)) = visible_entries
+            .iter()
+            .find(|(index, _)| self.hovered_entry == Some(*index))
+        {
+            self.paint_preview(cx, *entry_bounds, container_bounds);
         }
     }
 
     fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
-        // Update max scroll based on current viewport
-        // Calculate content height same as in paint():
-        // container_bounds.height = bounds.height - (margin * 2)
-        // content_height = container_bounds.height - 100
-        let container_bounds_width = bounds.size.width - (Self::CONTAINER_MARGIN * 2.0);
-        let content_height = bounds.size.height - (Self::CONTAINER_MARGIN * 2.0) - 100.0;
-        let content_start_y = bounds.origin.y + Self::CONTAINER_MARGIN + 60.0 + Self::PADDING;
-        self.update_max_scroll(content_height);
-
-        // Calculate scrollbar bounds for hit testing
-        let scrollbar_x = bounds.origin.x + Self::CONTAINER_MARGIN + container_bounds_width - 20.0;
-        let scrollbar_width = Self::SCROLLBAR_WIDTH;
+        self.scroll
+            .set_viewport(Self::entries_viewport(Self::container_bounds(bounds)));
 
         match event {
-            InputEvent::MouseDown { position, .. } => {
-                // Check if clicking on scrollbar
-                if self.max_scroll > 0.0 {
-                    // Calculate scrollbar height based on viewport to total content ratio
-                    let total_content_height = content_height + self.max_scroll;
-                    let scrollbar_height = (content_height / total_content_height) * content_height;
-
-                    // Calculate scrollbar position within the available travel distance
-                    let scrollbar_travel_distance = content_height - scrollbar_height;
-                    let scrollbar_y = content_start_y
-                        + (self.scroll_offset / self.max_scroll) * scrollbar_travel_distance;
-
-                    let scrollbar_bounds = Bounds {
-                        origin: Point::new(scrollbar_x, scrollbar_y),
-                        size: Size::new(scrollbar_width, scrollbar_height),
-                    };
-
-                    if scrollbar_bounds.contains(*position) {
-                        self.is_dragging_scrollbar = true;
-                        self.drag_start_offset = position.y - scrollbar_y;
-                        self.dirty = true;
-                        return true;
-                    }
-                }
-                false
-            }
-            InputEvent::MouseUp { .. } => {
-                if self.is_dragging_scrollbar {
-                    self.is_dragging_scrollbar = false;
-                    self.dirty = true;
-                    return true;
-                }
-                false
+            InputEvent::MouseDown { .. } | InputEvent::MouseUp { .. } => {
+                let handled = self.scroll.handle_scroll_event(event);
+                self.dirty |= handled;
+                handled
             }
             InputEvent::MouseMove { position, .. } => {
-                if self.is_dragging_scrollbar {
-                    // Calculate scrollbar dimensions
-                    let total_content_height = content_height + self.max_scroll;
-                    let scrollbar_height = (content_height / total_content_height) * content_height;
-                    let scrollbar_travel_distance = content_height - scrollbar_height;
-
-                    // Calculate new scroll position based on mouse Y
-                    let scrollbar_y = position.y - self.drag_start_offset;
-                    let relative_y = scrollbar_y - content_start_y;
-                    let scroll_ratio = relative_y / scrollbar_travel_distance;
-                    self.scroll_offset =
-                        (scroll_ratio * self.max_scroll).clamp(0.0, self.max_scroll);
-                    self.dirty = true;
+                if self.scroll.is_dragging() {
+                    self.dirty |= self.scroll.handle_scroll_event(event);
                     return true;
                 }
-                false
+                self.update_hover(*position, bounds)
             }
-            InputEvent::KeyDown { key, .. } => match key {
+            InputEvent::KeyDown { key, modifiers } => match key {
                 KeyCode::Escape => {
                     self.close_requested = true;
                     true
                 }
+                KeyCode::E | KeyCode::H => {
+                    let source = if modifiers.shift {
+                        TextLogSource::ReadHistory
+                    } else {
+                        TextLogSource::Backlog
+                    };
+                    let format = if *key == KeyCode::H {
+                        TextLogFormat::Html
+                    } else {
+                        TextLogFormat::PlainText
+                    };
+                    self.export_request = Some((source, format));
+                    true
+                }
                 KeyCode::Up => {
                     self.scroll_up();
                     true
@@ -460,22 +552,18 @@ impl Element for BacklogElement {
                     true
                 }
                 KeyCode::Home => {
-                    self.scroll_offset = 0.0;
-                    self.dirty = true;
+                    self.dirty |= self.scroll.scroll_to(0.0);
                     true
                 }
                 KeyCode::End => {
-                    self.scroll_offset = self.max_scroll;
-                    self.dirty = true;
+                    self.dirty |= self.scroll.scroll_to_end();
                     true
                 }
                 _ => false,
             },
-            InputEvent::MouseScroll { delta, .. } => {
-                // Scroll based on wheel delta (Y axis)
+            InputEvent::MouseScroll { .. } => {
                 // Positive delta = scroll up (towards newer entries)
-                // Negative delta = scroll down (towards older entries)
-                self.scroll(-delta.y * 20.0);
+                self.dirty |= self.scroll.handle_scroll_event(event);
                 true
             }
             _ => false,
@@ -521,82 +609,159 @@ mod tests {
 
         let backlog = BacklogElement::new(entries);
         assert_eq!(backlog.entries.len(), 2);
-        assert_eq!(backlog.scroll_offset, 0.0);
+        assert_eq!(backlog.scroll.offset(), 0.0);
         assert!(!backlog.is_close_requested());
     }
 
     #[test]
-    fn test_empty_backlog() {
-        let backlog = BacklogElement::new(vec![]);
-        assert_eq!(backlog.entries.len(), 0);
-        assert_eq!(backlog.max_scroll, 0.0);
-    }
+    fn test_hover_previews_entry_screen() {
+        use narrative_gui::framework::input::Modifiers;
 
-    #[test]
-    fn test_scroll_up() {
         let entries = vec![
             create_test_entry("alice", "Entry 1", 0),
             create_test_entry("bob", "Entry 2", 1),
-            create_test_entry("alice", "Entry 3", 2),
         ];
+        let snapshot = DisplaySnapshot {
+            background: Some("assets/backgrounds/room.png".to_string()),
+            ..Default::default()
+        };
+        let mut backlog =
+            BacklogElement::new(entries).with_snapshots(vec![Some(snapshot.clone()), None]);
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        let hover = |backlog: &mut BacklogElement, y: f32| {
+            backlog.handle_event(
+                &InputEvent::MouseMove {
+                    position: Point::new(200.0, y),
+                    modifiers: Modifiers::none(),
+                },
+                bounds,
+            )
+        };
+
+        // First entry spans y = 124..208 at this size
+        assert!(hover(&mut backlog, 150.0));
+        assert_eq!(backlog.preview_snapshot(), Some(&snapshot));
+        assert!(backlog.set_preview_texture(Some(7)));
+        assert!(!backlog.set_preview_texture(Some(7)));
+
+        // Second entry has no recorded screen
+        assert!(hover(&mut backlog, 250.0));
+        assert_eq!(backlog.preview_snapshot(), None);
+        assert_eq!(backlog.preview_texture, None);
+
+        // Between entries
+        assert!(hover(&mut backlog, 215.0));
+        assert!(!hover(&mut backlog, 216.0));
+    }
 
+    /// Backlog with enough entries to scroll, viewport set as for a 1280x720 window
+    fn scrollable_backlog() -> BacklogElement {
+        let entries = (0..20)
+            .map(|i| create_test_entry("alice", &format!("Entry {i}"), i))
+            .collect();
         let mut backlog = BacklogElement::new(entries);
-        backlog.max_scroll = 200.0;
-        backlog.scroll_offset = 100.0;
+        let container = BacklogElement::container_bounds(Bounds::new(0.0, 0.0, 1280.0, 720.0));
+        backlog
+            .scroll
+            .set_viewport(BacklogElement::entries_viewport(container));
+        backlog
+    }
+
+    #[test]
+    fn test_empty_backlog() {
+        let mut backlog = BacklogElement::new(vec![]);
+        backlog
+            .scroll
+            .set_viewport(Bounds::new(0.0, 0.0, 1000.0, 600.0));
+        assert_eq!(backlog.entries.len(), 0);
+        assert_eq!(backlog.scroll.max_offset(), 0.0);
+        assert!(!backlog.scroll.is_scrollable());
+    }
+
+    #[test]
+    fn test_scroll_up() {
+        let mut backlog = scrollable_backlog();
+        backlog.scroll.scroll_to(100.0);
 
         backlog.scroll_up();
-        assert!(backlog.scroll_offset < 100.0);
+        assert!(backlog.scroll.offset() < 100.0);
     }
 
     #[test]
     fn test_scroll_down() {
-        let entries = vec![
-            create_test_entry("alice", "Entry 1", 0),
-            create_test_entry("bob", "Entry 2", 1),
-        ];
-
-        let mut backlog = BacklogElement::new(entries);
-        backlog.max_scroll = 200.0;
+        let mut backlog = scrollable_backlog();
 
         backlog.scroll_down();
-        assert!(backlog.scroll_offset > 0.0);
+        assert!(backlog.scroll.offset() > 0.0);
     }
 
     #[test]
     fn test_scroll_clamping() {
-        let entries = vec![create_test_entry("alice", "Entry", 0)];
-        let mut backlog = BacklogElement::new(entries);
-        backlog.max_scroll = 100.0;
+        let mut backlog = scrollable_backlog();
+        let max = backlog.scroll.max_offset();
+        assert!(max > 0.0);
 
         // Scroll beyond max
-        backlog.scroll(200.0);
-        assert_eq!(backlog.scroll_offset, 100.0);
+        backlog.scroll(max + 200.0);
+        assert_eq!(backlog.scroll.offset(), max);
 
         // Scroll below min
-        backlog.scroll(-200.0);
-        assert_eq!(backlog.scroll_offset, 0.0);
+        backlog.scroll(-(max + 200.0));
+        assert_eq!(backlog.scroll.offset(), 0.0);
+    }
+
+    #[test]
+    fn test_end_key_scrolls_to_last_entry() {
+        use narrative_gui::framework::input::Modifiers;
+
+        let mut backlog = scrollable_backlog();
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        let key = |key| InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        };
+
+        assert!(backlog.handle_event(&key(KeyCode::End), bounds));
+        assert_eq!(backlog.scroll.offset(), backlog.scroll.max_offset());
+        let visible = backlog.visible_entries(BacklogElement::container_bounds(bounds));
+        assert_eq!(visible.last().map(|(index, _)| *index), Some(19));
+
+        assert!(backlog.handle_event(&key(KeyCode::Home), bounds));
+        assert_eq!(backlog.scroll.offset(), 0.0);
     }
 
     #[test]
     fn test_content_height_calculation() {
         // Empty backlog should have minimal height (just padding)
-        let backlog_0 = BacklogElement::new(vec![]);
-        let height_0 = backlog_0.calculate_total_content_height();
+        let height_0 = BacklogElement::total_content_height(&[]);
         assert_eq!(height_0, BacklogElement::PADDING * 2.0);
 
         // Backlog with 1 entry should have height > 0
-        let entry1 = create_test_entry("alice", "Hello!", 0);
-        let backlog_1 = BacklogElement::new(vec![entry1]);
-        let height_1 = backlog_1.calculate_total_content_height();
+        let height_1 =
+            BacklogElement::total_content_height(&[create_test_entry("alice", "Hello!", 0)]);
         assert!(height_1 > height_0);
 
         // Backlog with 2 entries should be taller
-        let entry2 = create_test_entry("bob", "Hi there!", 1);
-        let backlog_2 = BacklogElement::new(vec![create_test_entry("alice", "Hello!", 0), entry2]);
-        let height_2 = backlog_2.calculate_total_content_height();
+        let height_2 = BacklogElement::total_content_height(&[
+            create_test_entry("alice", "Hello!", 0),
+            create_test_entry("bob", "Hi there!", 1),
+        ]);
         assert!(height_2 > height_1);
     }
 
+    #[test]
+    fn test_translation_adds_lines() {
+        let entry = create_test_entry("alice", "おはよう", 0);
+        let translated = entry
+            .clone()
+            .with_translation(Some("Good morning".to_string()));
+
+        assert_eq!(
+            BacklogElement::calculate_entry_height(&translated),
+            BacklogElement::calculate_entry_height(&entry) + BacklogElement::TEXT_LINE_HEIGHT
+        );
+    }
+
     #[test]
     fn test_close_requested() {
         let mut backlog = BacklogElement::new(vec![]);
@@ -605,4 +770,45 @@ mod tests {
         backlog.close_requested = true;
         assert!(backlog.is_close_requested());
     }
+
+    #[test]
+    fn test_export_keys() {
+        use narrative_gui::framework::input::Modifiers;
+
+        let mut backlog = BacklogElement::new(vec![]);
+        let bounds = Bounds {
+            origin: Point::new(0.0, 0.0),
+            size: Size::new(1280.0, 720.0),
+        };
+        assert_eq!(backlog.take_export_request(), None);
+
+        backlog.handle_event(
+            &InputEvent::KeyDown {
+                key: KeyCode::E,
+                modifiers: Modifiers::none(),
+            },
+            bounds,
+        );
+        assert_eq!(
+            backlog.take_export_request(),
+            Some((TextLogSource::Backlog, TextLogFormat::PlainText))
+        );
+        assert_eq!(backlog.take_export_request(), None);
+
+        let shift = Modifiers {
+            shift: true,
+            ..Modifiers::none()
+        };
+        backlog.handle_event(
+            &InputEvent::KeyDown {
+                key: KeyCode::H,
+                modifiers: shift,
+            },
+            bounds,
+        );
+        assert_eq!(
+            backlog.take_export_request(),
+            Some((TextLogSource::ReadHistory, TextLogFormat::Html))
+        );
+    }
 }
@@ -2,8 +2,8 @@
 //!
 //! Displays a grid of CG thumbnails with unlock status.
 //! Features:
-//! - 3x3 grid layout (9 CGs per page)
-//! - Pagination with keyboard navigation
+//! - Scrolling 3-column grid, three rows in view (9 CGs per page)
+//! - Keyboard navigation, page jumps and mouse wheel / scrollbar scrolling
 //! - Lock/unlock status display
 //! - Unlock rate statistics
 
@@ -11,7 +11,9 @@ use narrative_core::{CgRegistry, UnlockData};
 use narrative_engine::runtime::CgGalleryState;
 use narrative_gui::Point;
 use narrative_gui::framework::animation::AnimationContext;
-use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::element::{
+    Element, ElementId, LayoutContext, PaintContext, ScrollView,
+};
 use narrative_gui::framework::input::{InputEvent, KeyCode};
 use narrative_gui::framework::layout::Bounds;
 use narrative_gui::theme::colors;
@@ -45,6 +47,8 @@ pub struct CgGalleryElement {
     animation_context: AnimationContext,
     /// Thumbnail textures (CgId -> TextureId)
     thumbnail_textures: HashMap<String, u64>,
+    /// Scroll position of the grid
+    scroll: ScrollView,
 }
 
 impl CgGalleryElement {
@@ -58,6 +62,8 @@ impl CgGalleryElement {
     const CARD_HEIGHT: f32 = 180.0;
     const CARD_SPACING: f32 = 20.0;
     const CORNER_RADIUS: f32 = 8.0;
+    /// Distance between the tops of two grid rows
+    const ROW_STRIDE: f32 = Self::CARD_HEIGHT + Self::CARD_SPACING;
 
     // UI constants
     const HEADER_HEIGHT: f32 = 100.0;
@@ -72,6 +78,12 @@ impl CgGalleryElement {
         unlock_data: Arc<UnlockData>,
         thumbnail_textures: HashMap<String, u64>,
     ) -> Self {
+        let rows = state.total_cgs.div_ceil(Self::GRID_COLS);
+        let mut scroll = ScrollView::new()
+            .with_gap(Self::CARD_SPACING)
+            .with_step(Self::ROW_STRIDE / 2.0);
+        scroll.set_content_height((rows as f32 * Self::ROW_STRIDE - Self::CARD_SPACING).max(0.0));
+
         Self {
             id: ElementId::new(),
             layout_node: None,
@@ -82,6 +94,7 @@ impl CgGalleryElement {
             dirty: true,
             animation_context: AnimationContext::default(),
             thumbnail_textures,
+            scroll,
         }
     }
 
@@ -104,74 +117,84 @@ impl CgGalleryElement {
         total.div_ceil(Self::ITEMS_PER_PAGE)
     }
 
-    /// Get grid position from selected CG index
-    fn grid_position(&self) -> (usize, usize) {
-        let index_in_page = self.state.selected_cg % Self::ITEMS_PER_PAGE;
-        let row = index_in_page / Self::GRID_COLS;
-        let col = index_in_page % Self::GRID_COLS;
-        (row, col)
+    /// Number of grid rows over all CGs
+    fn total_rows(&self) -> usize {
+        self.state.total_cgs.div_ceil(Self::GRID_COLS)
+    }
+
+    /// Region the grid is scrolled in, three rows tall below the header
+    fn grid_viewport(bounds: Bounds) -> Bounds {
+        let width = (Self::CARD_WIDTH * Self::GRID_COLS as f32)
+            + (Self::CARD_SPACING * (Self::GRID_COLS - 1) as f32);
+        let height = Self::GRID_ROWS as f32 * Self::ROW_STRIDE - Self::CARD_SPACING;
+        Bounds::new(
+            bounds.origin.x + (bounds.size.width - width) / 2.0,
+            bounds.origin.y + Self::HEADER_HEIGHT,
+            width,
+            height,
+        )
+    }
+
+    /// Select a CG, keeping its row in view and the page indicator in step
+    fn select(&mut self, index: usize) {
+        if index == self.state.selected_cg || index >= self.state.total_cgs {
+            return;
+        }
+        self.state.selected_cg = index;
+        self.state.current_page = index / Self::ITEMS_PER_PAGE;
+        let top = (index / Self::GRID_COLS) as f32 * Self::ROW_STRIDE;
+        self.scroll.scroll_to_show(top, top + Self::CARD_HEIGHT);
+        self.dirty = true;
     }
 
     /// Move selection up
     fn select_up(&mut self) {
-        let (row, _col) = self.grid_position();
-        if row > 0 {
-            self.state.selected_cg = self.state.selected_cg.saturating_sub(Self::GRID_COLS);
-            self.dirty = true;
+        if let Some(index) = self.state.selected_cg.checked_sub(Self::GRID_COLS) {
+            self.select(index);
         }
     }
 
     /// Move selection down
     fn select_down(&mut self) {
-        let (row, _col) = self.grid_position();
-        if row < Self::GRID_ROWS - 1 {
-            let new_index = self.state.selected_cg + Self::GRID_COLS;
-            if new_index < self.state.total_cgs {
-                self.state.selected_cg = new_index;
-                self.dirty = true;
-            }
-        }
+        self.select(self.state.selected_cg + Self::GRID_COLS);
     }
 
     /// Move selection left
     fn select_left(&mut self) {
-        let (_row, col) = self.grid_position();
-        if col > 0 {
-            self.state.selected_cg = self.state.selected_cg.saturating_sub(1);
-            self.dirty = true;
+        if !self.state.selected_cg.is_multiple_of(Self::GRID_COLS) {
+            self.select(self.state.selected_cg - 1);
         }
     }
 
     /// Move selection right
     fn select_right(&mut self) {
-        let (_row, col) = self.grid_position();
-        if col < Self::GRID_COLS - 1 {
-            let new_index = self.state.selected_cg + 1;
-            if new_index < self.state.total_cgs {
-                self.state.selected_cg = new_index;
-                self.dirty = true;
-            }
+        if self.state.selected_cg % Self::GRID_COLS < Self::GRID_COLS - 1 {
+            self.select(self.state.selected_cg + 1);
         }
     }
 
-    /// Go to previous page
+    /// Go to previous page, scrolling its first row to the top
     fn prev_page(&mut self) {
         if self.state.current_page > 0 {
-            self.state.current_page -= 1;
-            self.state.selected_cg = self.state.current_page * Self::ITEMS_PER_PAGE;
-            self.dirty = true;
+            self.go_to_page(self.state.current_page - 1);
         }
     }
 
-    /// Go to next page
+    /// Go to next page, scrolling its first row to the top
     fn next_page(&mut self) {
         if self.state.current_page < self.total_pages().saturating_sub(1) {
-            self.state.current_page += 1;
-            self.state.selected_cg = self.state.current_page * Self::ITEMS_PER_PAGE;
-            self.dirty = true;
+            self.go_to_page(self.state.current_page + 1);
         }
     }
 
+    fn go_to_page(&mut self, page: usize) {
+        self.state.current_page = page;
+        self.state.selected_cg = page * Self::ITEMS_PER_PAGE;
+        self.scroll
+            .scroll_to((page * Self::GRID_ROWS) as f32 * Self::ROW_STRIDE);
+        self.dirty = true;
+    }
+
     /// Confirm current selection
     fn confirm_selection(&mut self) {
         let sorted_cgs = self.cg_registry.get_all_sorted();
@@ -217,13 +240,6 @@ impl Element for CgGalleryElement {
         // Draw semi-transparent background overlay
         cx.fill_rect(cx.bounds, narrative_gui::Color::new(0.0, 0.0, 0.0, 0.85));
 
-        // Calculate layout
-        let content_width = (Self::CARD_WIDTH * Self::GRID_COLS as f32)
-            + (Self::CARD_SPACING * (Self::GRID_COLS - 1) as f32);
-
-        let start_x = cx.bounds.origin.x + (cx.bounds.size.width - content_width) / 2.0;
-        let start_y = cx.bounds.origin.y + Self::HEADER_HEIGHT;
-
         // Draw header
         let title = "CG Gallery";
         let title_x = cx.bounds.origin.x + 50.0;
@@ -269,86 +285,93 @@ impl Element for CgGalleryElement {
 
         // Draw CG grid
         let sorted_cgs = self.cg_registry.get_all_sorted();
-        let start_index = self.state.current_page * Self::ITEMS_PER_PAGE;
+        let viewport = Self::grid_viewport(cx.bounds);
+        let rows = self
+            .scroll
+            .visible_range(viewport, Self::CARD_HEIGHT, self.total_rows());
+
+        self.scroll.paint_content(cx, viewport, |cx, offset| {
+            for row in rows {
+                for col in 0..Self::GRID_COLS {
+                    let cg_index = row * Self::GRID_COLS + col;
+                    if cg_index >= sorted_cgs.len() {
+                        break;
+                    }
 
-        for row in 0..Self::GRID_ROWS {
-            for col in 0..Self::GRID_COLS {
-                let cg_index = start_index + row * Self::GRID_COLS + col;
-                if cg_index >= sorted_cgs.len() {
-                    break;
-                }
+                    let x =
+                        viewport.origin.x + (col as f32 * (Self::CARD_WIDTH + Self::CARD_SPACING));
+                    let y = viewport.origin.y + row as f32 * Self::ROW_STRIDE - offset;
 
-                let x = start_x + (col as f32 * (Self::CARD_WIDTH + Self::CARD_SPACING));
-                let y = start_y + (row as f32 * (Self::CARD_HEIGHT + Self::CARD_SPACING));
-
-                let card_bounds = Bounds {
-                    origin: Point::new(x, y),
-                    size: narrative_gui::Size::new(Self::CARD_WIDTH, Self::CARD_HEIGHT),
-                };
-
-                let cg = &sorted_cgs[cg_index];
-                let is_unlocked = self.unlock_data.is_cg_unlocked(&cg.id);
-                let is_selected = cg_index == self.state.selected_cg;
-
-                // Card background
-                let bg_color = if is_selected {
-                    colors::ACCENT_PRIMARY
-                } else if is_unlocked {
-                    colors::CARD_BG
-                } else {
-                    narrative_gui::Color::new(0.2, 0.2, 0.2, 1.0)
-                };
-
-                cx.fill_rounded_rect(card_bounds, bg_color, Self::CORNER_RADIUS);
-
-                // Card border
-                if is_selected {
-                    cx.stroke_rect(
-                        card_bounds,
-                        narrative_gui::Color::new(1.0, 1.0, 1.0, 1.0),
-                        2.0,
-                    );
-                } else {
-                    cx.stroke_rect(card_bounds, colors::BORDER_LIGHT, 1.0);
-                }
+                    let card_bounds = Bounds {
+                        origin: Point::new(x, y),
+                        size: narrative_gui::Size::new(Self::CARD_WIDTH, Self::CARD_HEIGHT),
+                    };
+
+                    let cg = &sorted_cgs[cg_index];
+                    let is_unlocked = self.unlock_data.is_cg_unlocked(&cg.id);
+                    let is_selected = cg_index == self.state.selected_cg;
 
-                // Content
-                if is_unlocked {
-                    // Draw thumbnail texture if available
-                    if let Some(&texture_id) = self.thumbnail_textures.get(&cg.id) {
-                        cx.draw_texture(texture_id, card_bounds, 1.0);
+                    // Card background
+                    let bg_color = if is_selected {
+                        colors::ACCENT_PRIMARY
+                    } else if is_unlocked {
+                        colors::CARD_BG
                     } else {
-                        // Fallback: Show CG title if thumbnail not loaded
-                        let text_x = x + Self::CARD_WIDTH / 2.0 - 60.0;
-                        let text_y = y + Self::CARD_HEIGHT / 2.0;
-                        let text_color = if is_selected {
-                            colors::BG_DARKEST
+                        narrative_gui::Color::new(0.2, 0.2, 0.2, 1.0)
+                    };
+
+                    cx.fill_rounded_rect(card_bounds, bg_color, Self::CORNER_RADIUS);
+
+                    // Card border
+                    if is_selected {
+                        cx.stroke_rect(
+                            card_bounds,
+                            narrative_gui::Color::new(1.0, 1.0, 1.0, 1.0),
+                            2.0,
+                        );
+                    } else {
+                        cx.stroke_rect(card_bounds, colors::BORDER_LIGHT, 1.0);
+                    }
+
+                    // Content
+                    if is_unlocked {
+                        // Draw thumbnail texture if available
+                        if let Some(&texture_id) = self.thumbnail_textures.get(&cg.id) {
+                            cx.draw_texture(texture_id, card_bounds, 1.0);
                         } else {
-                            colors::TEXT_PRIMARY
-                        };
+                            // Fallback: Show CG title if thumbnail not loaded
+                            let text_x = x + Self::CARD_WIDTH / 2.0 - 60.0;
+                            let text_y = y + Self::CARD_HEIGHT / 2.0;
+                            let text_color = if is_selected {
+                                colors::BG_DARKEST
+                            } else {
+                                colors::TEXT_PRIMARY
+                            };
+                            cx.draw_text(
+                                &cg.title,
+                                Point::new(text_x, text_y),
+                                text_color,
+                                Self::CG_TITLE_FONT_SIZE,
+                            );
+                        }
+                    } else {
+                        // Show lock icon (placeholder: "LOCKED" text)
+                        let lock_x = x + Self::CARD_WIDTH / 2.0 - 30.0;
+                        let lock_y = y + Self::CARD_HEIGHT / 2.0;
                         cx.draw_text(
-                            &cg.title,
-                            Point::new(text_x, text_y),
-                            text_color,
+                            "LOCKED",
+                            Point::new(lock_x, lock_y),
+                            narrative_gui::Color::new(0.4, 0.4, 0.4, 1.0),
                             Self::CG_TITLE_FONT_SIZE,
                         );
                     }
-                } else {
-                    // Show lock icon (placeholder: "LOCKED" text)
-                    let lock_x = x + Self::CARD_WIDTH / 2.0 - 30.0;
-                    let lock_y = y + Self::CARD_HEIGHT / 2.0;
-                    cx.draw_text(
-                        "LOCKED",
-                        Point::new(lock_x, lock_y),
-                        narrative_gui::Color::new(0.4, 0.4, 0.4, 1.0),
-                        Self::CG_TITLE_FONT_SIZE,
-                    );
                 }
             }
-        }
+        });
+        self.scroll.paint_scrollbar(cx, viewport);
 
         // Draw footer with hints
-        let hint_text = "Arrow Keys: Select | Enter: View | ESC: Back | Q/E: Page";
+        let hint_text = "Arrow Keys: Select | Enter: View | ESC: Back | Q/E: Page | Wheel: Scroll";
         let hint_x = cx.bounds.origin.x + (cx.bounds.size.width / 2.0) - 250.0;
         let hint_y = cx.bounds.origin.y + cx.bounds.size.height - 30.0;
         cx.draw_text(
@@ -359,7 +382,9 @@ impl Element for CgGalleryElement {
         );
     }
 
-    fn handle_event(&mut self, event: &InputEvent, _bounds: Bounds) -> bool {
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        self.scroll.set_viewport(Self::grid_viewport(bounds));
+
         match event {
             InputEvent::KeyDown { key, .. } => match key {
                 KeyCode::Escape => {
@@ -397,6 +422,14 @@ impl Element for CgGalleryElement {
                 }
                 _ => false,
             },
+            InputEvent::MouseScroll { .. }
+            | InputEvent::MouseDown { .. }
+            | InputEvent::MouseMove { .. }
+            | InputEvent::MouseUp { .. } => {
+                let handled = self.scroll.handle_scroll_event(event);
+                self.dirty |= handled;
+                handled
+            }
             _ => false,
         }
     }
@@ -423,3 +456,100 @@ impl Element for CgGalleryElement {
         was_dirty
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::CgMetadata;
+    use narrative_gui::framework::input::Modifiers;
+
+    fn create_gallery(count: usize) -> CgGalleryElement {
+        let mut registry = CgRegistry::new();
+        for i in 0..count {
+            registry.register(CgMetadata::new(
+                format!("cg_{i:02}"),
+                format!("CG {i}"),
+                format!("cg/{i}.png"),
+            ));
+        }
+        CgGalleryElement::new(
+            CgGalleryState::new(count),
+            Arc::new(registry),
+            Arc::new(UnlockData::new()),
+            HashMap::new(),
+        )
+    }
+
+    fn key(key: KeyCode) -> InputEvent {
+        InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        }
+    }
+
+    const BOUNDS: Bounds = Bounds {
+        origin: Point { x: 0.0, y: 0.0 },
+        size: narrative_gui::Size {
+            width: 1280.0,
+            height: 720.0,
+        },
+    };
+
+    #[test]
+    fn test_selection_scrolls_past_first_page() {
+        let mut gallery = create_gallery(20);
+
+        for _ in 0..3 {
+            assert!(gallery.handle_event(&key(KeyCode::Down), BOUNDS));
+        }
+        assert_eq!(gallery.state.selected_cg, 9);
+        assert_eq!(gallery.state.current_page, 1);
+        // Fourth row is brought into view at the bottom
+        assert_eq!(gallery.scroll.offset(), CgGalleryElement::ROW_STRIDE);
+
+        for _ in 0..3 {
+            gallery.handle_event(&key(KeyCode::Up), BOUNDS);
+        }
+        assert_eq!(gallery.state.selected_cg, 0);
+        assert_eq!(gallery.state.current_page, 0);
+        assert_eq!(gallery.scroll.offset(), 0.0);
+    }
+
+    #[test]
+    fn test_page_keys_scroll_page_to_top() {
+        let mut gallery = create_gallery(20);
+
+        gallery.handle_event(&key(KeyCode::E), BOUNDS);
+        assert_eq!(gallery.state.selected_cg, 9);
+        assert_eq!(gallery.scroll.offset(), 3.0 * CgGalleryElement::ROW_STRIDE);
+
+        // The last page only has one row, so it cannot reach the top
+        gallery.handle_event(&key(KeyCode::E), BOUNDS);
+        assert_eq!(gallery.state.selected_cg, 18);
+        assert_eq!(gallery.scroll.offset(), gallery.scroll.max_offset());
+
+        gallery.handle_event(&key(KeyCode::Q), BOUNDS);
+
+        gallery.handle_event(&key(KeyCode::Q), BOUNDS);
+        assert_eq!(gallery.state.selected_cg, 0);
+        assert_eq!(gallery.scroll.offset(), 0.0);
+    }
+
+    #[test]
+    fn test_wheel_scrolls_grid() {
+        let mut gallery = create_gallery(20);
+        let wheel = InputEvent::MouseScroll {
+            delta: Point::new(0.0, -1.0),
+            position: Point::new(640.0, 360.0),
+            modifiers: Modifiers::none(),
+        };
+
+        assert!(gallery.handle_event(&wheel, BOUNDS));
+        assert!(gallery.scroll.offset() > 0.0);
+
+        // A single page does not scroll
+        let mut small = create_gallery(9);
+        small.handle_event(&wheel, BOUNDS);
+        assert_eq!(small.scroll.offset(), 0.0);
+    }
+}
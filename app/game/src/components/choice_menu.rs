@@ -5,18 +5,53 @@
 //! - Enter key confirmation
 //! - Mouse click support
 //! - Visual highlight for selected choice
+//! - Greyed out choices that cannot be selected
 
-use narrative_gui::Point;
 use narrative_gui::framework::animation::AnimationContext;
 use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
 use narrative_gui::framework::input::{InputEvent, KeyCode};
 use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::renderer::TextAlign;
 use narrative_gui::theme::colors;
+use narrative_gui::{Color, Point};
 use std::any::Any;
 use std::sync::Arc;
 use std::time::Duration;
 use taffy::NodeId;
 
+/// Colors used to draw choice buttons
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChoiceMenuStyle {
+    /// Background of the selected choice
+    pub selected_background: Color,
+    /// Background of other choices
+    pub background: Color,
+    /// Text color of the selected choice
+    pub selected_text: Color,
+    /// Text color of other choices
+    pub text: Color,
+    /// Border of non-selected choices
+    pub border: Color,
+    /// Background of disabled choices
+    pub disabled_background: Color,
+    /// Text color of disabled choices
+    pub disabled_text: Color,
+}
+
+impl Default for ChoiceMenuStyle {
+    fn default() -> Self {
+        Self {
+            selected_background: colors::ACCENT_PRIMARY,
+            background: colors::CARD_BG,
+            selected_text: colors::BG_DARKEST,
+            text: colors::TEXT_PRIMARY,
+            border: colors::BORDER_LIGHT,
+            disabled_background: colors::BG_DARKEST,
+            disabled_text: colors::TEXT_MUTED,
+        }
+    }
+}
+
 /// Choice menu element that displays a list of selectable options
 pub struct ChoiceMenuElement {
     /// Unique element ID
@@ -25,6 +60,8 @@ pub struct ChoiceMenuElement {
     layout_node: Option<NodeId>,
     /// List of choice texts
     choices: Vec<Arc<str>>,
+    /// Whether each choice is disabled (missing entries are enabled)
+    disabled: Vec<bool>,
     /// Currently selected choice index
     selected_index: usize,
     /// Whether a choice has been confirmed (Enter or click)
@@ -33,6 +70,8 @@ pub struct ChoiceMenuElement {
     dirty: bool,
     /// Cached button bounds for click detection
     button_bounds: Vec<Bounds>,
+    /// Button colors
+    style: ChoiceMenuStyle,
     /// Animation context for global settings
     animation_context: AnimationContext,
     /// Component-specific animation override (None = follow global)
@@ -41,7 +80,7 @@ pub struct ChoiceMenuElement {
 
 impl ChoiceMenuElement {
     /// Default button width
-    const BUTTON_WIDTH: f32 = 600.0;
+    pub const BUTTON_WIDTH: f32 = 600.0;
     /// Default button height
     const BUTTON_HEIGHT: f32 = 60.0;
     /// Spacing between buttons
@@ -49,7 +88,7 @@ impl ChoiceMenuElement {
     /// Button corner radius
     const CORNER_RADIUS: f32 = 8.0;
     /// Button font size
-    const FONT_SIZE: f32 = 18.0;
+    pub const FONT_SIZE: f32 = 18.0;
 
     /// Create a new choice menu element
     pub fn new(choices: Vec<impl Into<Arc<str>>>) -> Self {
@@ -60,15 +99,53 @@ impl ChoiceMenuElement {
             id: ElementId::new(),
             layout_node: None,
             choices,
+            disabled: Vec::new(),
             selected_index: 0,
             choice_confirmed: false,
             dirty: true,
             button_bounds,
+            style: ChoiceMenuStyle::default(),
             animation_context: AnimationContext::default(),
             animations_enabled: None,
         }
     }
 
+    /// Mark choices that are shown but cannot be selected
+    ///
+    /// The selection moves to the first enabled choice.
+    pub fn with_disabled(mut self, disabled: Vec<bool>) -> Self {
+        self.set_disabled(disabled);
+        self
+    }
+
+    /// Update which choices are disabled (mutable)
+    pub fn set_disabled(&mut self, disabled: Vec<bool>) {
+        self.disabled = disabled;
+        if self.is_disabled(self.selected_index)
+            && let Some(index) = (0..self.choices.len()).find(|&i| !self.is_disabled(i))
+        {
+            self.selected_index = index;
+        }
+        self.dirty = true;
+    }
+
+    /// Check if a choice is disabled
+    pub fn is_disabled(&self, index: usize) -> bool {
+        self.disabled.get(index).copied().unwrap_or(false)
+    }
+
+    /// Set the button colors
+    pub fn with_style(mut self, style: ChoiceMenuStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Update the button colors (mutable)
+    pub fn set_style(&mut self, style: ChoiceMenuStyle) {
+        self.style = style;
+        self.dirty = true;
+    }
+
     /// Set the animation context
     pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
         self.animation_context = context;
@@ -82,8 +159,11 @@ impl ChoiceMenuElement {
     }
 
     /// Set the list of choices
+    ///
+    /// Clears the disabled choices.
     pub fn set_choices(&mut self, choices: Vec<Arc<str>>) {
         self.choices = choices;
+        self.disabled.clear();
         self.selected_index = 0;
         self.choice_confirmed = false;
         self.button_bounds = vec![Bounds::default(); self.choices.len()];
@@ -91,8 +171,10 @@ impl ChoiceMenuElement {
     }
 
     /// Set the selected choice index
+    ///
+    /// Out of range and disabled choices are ignored.
     pub fn set_selected_index(&mut self, index: usize) {
-        if index < self.choices.len() {
+        if index < self.choices.len() && !self.is_disabled(index) {
             self.selected_index = index;
             self.dirty = true;
         }
@@ -113,25 +195,32 @@ impl ChoiceMenuElement {
         self.choice_confirmed = false;
     }
 
-    /// Move selection up
+    /// Move selection up, skipping disabled choices
     fn select_previous(&mut self) {
-        if self.selected_index > 0 {
-            self.selected_index = self.selected_index.saturating_sub(1);
+        if let Some(index) = (0..self.selected_index)
+            .rev()
+            .find(|&i| !self.is_disabled(i))
+        {
+            self.selected_index = index;
             self.dirty = true;
         }
     }
 
-    /// Move selection down
+    /// Move selection down, skipping disabled choices
     fn select_next(&mut self) {
-        if self.selected_index < self.choices.len().saturating_sub(1) {
-            self.selected_index = self.selected_index.saturating_add(1);
+        if let Some(index) =
+            (self.selected_index + 1..self.choices.len()).find(|&i| !self.is_disabled(i))
+        {
+            self.selected_index = index;
             self.dirty = true;
         }
     }
 
-    /// Confirm the current selection
+    /// Confirm the current selection, unless it is disabled
     fn confirm_selection(&mut self) {
-        self.choice_confirmed = true;
+        if !self.is_disabled(self.selected_index) {
+            self.choice_confirmed = true;
+        }
     }
 
     /// Calculate button bounds for layout
@@ -204,16 +293,14 @@ impl Element for ChoiceMenuElement {
             };
 
             // Determine if this button should appear hovered (for selected item)
-            let is_selected = i == self.selected_index;
-            let bg_color = if is_selected {
-                colors::ACCENT_PRIMARY
-            } else {
-                colors::CARD_BG
-            };
-            let text_color = if is_selected {
-                colors::BG_DARKEST
+            let is_disabled = self.is_disabled(i);
+            let is_selected = i == self.selected_index && !is_disabled;
+            let (bg_color, text_color) = if is_disabled {
+                (self.style.disabled_background, self.style.disabled_text)
+            } else if is_selected {
+                (self.style.selected_background, self.style.selected_text)
             } else {
-                colors::TEXT_PRIMARY
+                (self.style.background, self.style.text)
             };
 
             // Draw button background
@@ -221,24 +308,20 @@ impl Element for ChoiceMenuElement {
 
             // Draw button border for non-selected items
             if !is_selected {
-                cx.stroke_rect(button_bounds, colors::BORDER_LIGHT, 1.0);
+                cx.stroke_rect(button_bounds, self.style.border, 1.0);
             }
 
-            // Draw choice text (centered)
-            // TODO: Use cosmic-text's Buffer::shape() for accurate text width measurement
-            // Current implementation assumes fixed-width characters, which is inaccurate
-            // for proportional fonts and Japanese full-width/half-width character mixes.
-            // For better centering accuracy, measure actual glyph widths.
-            let text_width = choice.chars().count() as f32 * Self::FONT_SIZE * 0.6;
-            let text_x = button_bounds.origin.x + (Self::BUTTON_WIDTH - text_width) / 2.0;
+            // Draw choice text centered on the shaped width, whatever its direction
+            let text_x = button_bounds.origin.x + Self::BUTTON_WIDTH / 2.0;
             let text_y =
                 button_bounds.origin.y + (Self::BUTTON_HEIGHT + Self::FONT_SIZE * 0.8) / 2.0;
 
-            cx.draw_text(
+            cx.draw_text_aligned(
                 choice.as_ref(),
                 Point::new(text_x, text_y),
                 text_color,
                 Self::FONT_SIZE,
+                TextAlign::Center,
             );
         }
     }
@@ -267,6 +350,10 @@ impl Element for ChoiceMenuElement {
                 // Check if click is on any button
                 for (i, button_bound) in self.button_bounds.iter().enumerate() {
                     if button_bound.contains(*position) {
+                        // Clicks on disabled choices are swallowed
+                        if self.is_disabled(i) {
+                            return true;
+                        }
                         self.selected_index = i;
                         self.confirm_selection();
                         self.dirty = true;
@@ -310,6 +397,17 @@ mod tests {
         assert!(!menu.is_choice_confirmed());
     }
 
+    #[test]
+    fn test_choice_menu_style() {
+        let style = ChoiceMenuStyle {
+            selected_background: Color::RED,
+            ..Default::default()
+        };
+        let menu = ChoiceMenuElement::new(vec!["A"]).with_style(style);
+        assert_eq!(menu.style.selected_background, Color::RED);
+        assert_eq!(menu.style.background, colors::CARD_BG);
+    }
+
     #[test]
     fn test_selection_navigation() {
         let choices = vec!["Choice 1", "Choice 2", "Choice 3"];
@@ -368,6 +466,47 @@ mod tests {
         assert!(!menu.is_choice_confirmed());
     }
 
+    #[test]
+    fn test_disabled_choices_are_skipped() {
+        let mut menu = ChoiceMenuElement::new(vec!["A", "B", "C", "D"])
+            .with_disabled(vec![true, false, true, false]);
+        // Selection starts on the first enabled choice
+        assert_eq!(menu.selected_index(), 1);
+
+        menu.select_next();
+        assert_eq!(menu.selected_index(), 3);
+        menu.select_previous();
+        assert_eq!(menu.selected_index(), 1);
+        menu.select_previous();
+        assert_eq!(menu.selected_index(), 1);
+
+        menu.set_selected_index(2);
+        assert_eq!(menu.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_disabled_choice_cannot_be_confirmed() {
+        let mut menu = ChoiceMenuElement::new(vec!["A", "B"]).with_disabled(vec![false, true]);
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        menu.calculate_button_bounds(bounds);
+        let disabled_button = menu.button_bounds[1];
+        let click = InputEvent::MouseDown {
+            position: Point::new(
+                disabled_button.origin.x + 10.0,
+                disabled_button.origin.y + 10.0,
+            ),
+            button: narrative_gui::framework::input::MouseButton::Left,
+            modifiers: Default::default(),
+        };
+
+        assert!(menu.handle_event(&click, bounds));
+        assert_eq!(menu.selected_index(), 0);
+        assert!(!menu.is_choice_confirmed());
+
+        menu.confirm_selection();
+        assert!(menu.is_choice_confirmed());
+    }
+
     #[test]
     fn test_set_selected_index() {
         let choices = vec!["Choice 1", "Choice 2", "Choice 3"];
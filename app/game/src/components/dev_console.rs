@@ -0,0 +1,662 @@
+//! Developer console
+//!
+//! A debug overlay for testing late scenes without replaying from the start.
+//! The "Scenes" tab lists the scenes of the loaded scenario and jumps to the
+//! selected one; the "State" tab lists the current flags and variables, where
+//! Enter toggles a flag or edits a variable in place. Like the help overlay it
+//! is modal and drawn over everything else; the game applies the chosen
+//! [`DevConsoleAction`]s to the runtime.
+
+use narrative_core::{SceneId, VariableValue};
+use narrative_engine::runtime::ScenarioRuntime;
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, font_size, radius, spacing};
+use narrative_gui::{Color, Point, Size};
+use std::any::Any;
+use taffy::NodeId;
+
+/// Tab of the developer console
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DevConsoleTab {
+    /// Scenes of the scenario, to jump to
+    #[default]
+    Scenes,
+    /// Flags and variables, to edit
+    State,
+}
+
+/// Change requested from the developer console
+#[derive(Debug, Clone, PartialEq)]
+pub enum DevConsoleAction {
+    /// Continue at the start of a scene
+    JumpToScene(SceneId),
+    /// Set a flag
+    SetFlag(String, bool),
+    /// Set a variable
+    SetVariable(String, VariableValue),
+}
+
+/// Scene listed in the console
+#[derive(Debug, Clone, PartialEq)]
+struct SceneRow {
+    id: SceneId,
+    title: String,
+    current: bool,
+}
+
+/// Flag or variable listed in the console
+#[derive(Debug, Clone, PartialEq)]
+enum StateRow {
+    Flag(String, bool),
+    Variable(String, VariableValue),
+}
+
+impl StateRow {
+    fn name(&self) -> &str {
+        match self {
+            Self::Flag(name, _) | Self::Variable(name, _) => name,
+        }
+    }
+
+    fn value_text(&self) -> String {
+        match self {
+            Self::Flag(_, value) => value.to_string(),
+            Self::Variable(_, value) => value_text(value),
+        }
+    }
+}
+
+/// Developer console element
+///
+/// Hidden until [`show`](Self::show)n with a runtime to list. While visible it
+/// takes all input and closes on F12 or Escape.
+pub struct DevConsoleElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Whether the console is shown
+    visible: bool,
+    /// Tab shown
+    tab: DevConsoleTab,
+    /// Scenes, sorted by ID
+    scenes: Vec<SceneRow>,
+    /// Flags, then variables, each sorted by name
+    state: Vec<StateRow>,
+    /// Selected row of each tab
+    selected: [usize; 2],
+    /// Text typed for the selected variable while it is edited
+    editing: Option<String>,
+    /// Result of the last edit (e.g. a value that didn't parse)
+    message: Option<String>,
+    /// Action to apply (set on Enter)
+    pending_action: Option<DevConsoleAction>,
+}
+
+impl DevConsoleElement {
+    /// Width of the console panel
+    const PANEL_WIDTH: f32 = 720.0;
+    /// Height of one row
+    const ROW_HEIGHT: f32 = 26.0;
+    /// Rows shown at once; the list scrolls with the selection
+    const VISIBLE_ROWS: usize = 16;
+    /// Background dimming alpha
+    const OVERLAY_ALPHA: f32 = 0.6;
+    /// Hints shown under the list
+    const SCENES_HINT: &'static str = "Enter: jump to scene   Tab: flags & variables   F12: close";
+    const STATE_HINT: &'static str =
+        "Enter: toggle flag / edit variable   Tab: scenes   F12: close";
+
+    /// Create a hidden developer console
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            visible: false,
+            tab: DevConsoleTab::default(),
+            scenes: Vec::new(),
+            state: Vec::new(),
+            selected: [0; 2],
+            editing: None,
+            message: None,
+            pending_action: None,
+        }
+    }
+
+    /// Show the console with the scenes and state of a runtime
+    ///
+    /// The current scene is selected in the scene list.
+    pub fn show(&mut self, runtime: &ScenarioRuntime) {
+        self.refresh(runtime);
+        if let Some(index) = self.scenes.iter().position(|scene| scene.current) {
+            self.selected[0] = index;
+        }
+        self.editing = None;
+        self.message = None;
+        self.visible = true;
+    }
+
+    /// Hide the console
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.editing = None;
+    }
+
+    /// Whether the console is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Tab shown
+    pub fn tab(&self) -> DevConsoleTab {
+        self.tab
+    }
+
+    /// Re-read the scenes, flags and variables of a runtime
+    pub fn refresh(&mut self, runtime: &ScenarioRuntime) {
+        let current = runtime.current_scene();
+        self.scenes = runtime
+            .scenario()
+            .scenes
+            .iter()
+            .map(|(id, scene)| {
+                let id = SceneId::new(id.clone());
+                SceneRow {
+                    current: current == Some(&id),
+                    id,
+                    title: scene.title.clone(),
+                }
+            })
+            .collect();
+        self.scenes.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+
+        let mut flags: Vec<StateRow> = runtime
+            .flags()
+            .iter()
+            .map(|(flag, value)| StateRow::Flag(flag.name().to_string(), value))
+            .collect();
+        flags.sort_by(|a, b| a.name().cmp(b.name()));
+        let mut variables: Vec<StateRow> = runtime
+            .variables()
+            .iter()
+            .map(|(variable, value)| StateRow::Variable(variable.name().to_string(), value.clone()))
+            .collect();
+        variables.sort_by(|a, b| a.name().cmp(b.name()));
+        self.state = flags;
+        self.state.extend(variables);
+
+        self.selected[0] = self.selected[0].min(self.scenes.len().saturating_sub(1));
+        self.selected[1] = self.selected[1].min(self.state.len().saturating_sub(1));
+    }
+
+    /// IDs of the listed scenes, in display order
+    pub fn scene_ids(&self) -> impl Iterator<Item = &SceneId> {
+        self.scenes.iter().map(|scene| &scene.id)
+    }
+
+    /// Names and values of the listed flags and variables, in display order
+    pub fn state_rows(&self) -> impl Iterator<Item = (&str, String)> {
+        self.state.iter().map(|row| (row.name(), row.value_text()))
+    }
+
+    /// Take the action chosen in the console, if any
+    pub fn take_action(&mut self) -> Option<DevConsoleAction> {
+        self.pending_action.take()
+    }
+
+    /// Check if an event opens or closes the developer console (F12)
+    pub fn is_toggle_event(event: &InputEvent) -> bool {
+        matches!(
+            event,
+            InputEvent::KeyDown {
+                key: KeyCode::F12,
+                ..
+            }
+        )
+    }
+
+    fn tab_index(&self) -> usize {
+        match self.tab {
+            DevConsoleTab::Scenes => 0,
+            DevConsoleTab::State => 1,
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        match self.tab {
+            DevConsoleTab::Scenes => self.scenes.len(),
+            DevConsoleTab::State => self.state.len(),
+        }
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        let count = self.row_count();
+        if count == 0 {
+            return;
+        }
+        let selected = &mut self.selected[self.tab_index()];
+        *selected = if down {
+            selected.saturating_add(1) % count
+        } else {
+            selected.checked_sub(1).unwrap_or(count.saturating_sub(1))
+        };
+    }
+
+    /// Act on the selected row
+    fn confirm(&mut self) {
+        match self.tab {
+            DevConsoleTab::Scenes => {
+                if let Some(scene) = self.scenes.get(self.selected[0]) {
+                    self.pending_action = Some(DevConsoleAction::JumpToScene(scene.id.clone()));
+                    self.hide();
+                }
+            }
+            DevConsoleTab::State => match (self.state.get(self.selected[1]), self.editing.take()) {
+                (Some(StateRow::Flag(name, value)), _) => {
+                    self.pending_action = Some(DevConsoleAction::SetFlag(name.clone(), !value));
+                }
+                (Some(row @ StateRow::Variable(name, _)), None) => {
+                    self.editing = Some(row.value_text());
+                    self.message = Some(format!("Editing '{}'", name));
+                }
+                (Some(StateRow::Variable(name, value)), Some(text)) => {
+                    match parse_like(value, &text) {
+                        Some(new_value) => {
+                            self.pending_action =
+                                Some(DevConsoleAction::SetVariable(name.clone(), new_value));
+                            self.message = None;
+                        }
+                        None => {
+                            self.message = Some(format!("'{}' is not a valid value", text));
+                            self.editing = Some(text);
+                        }
+                    }
+                }
+                (None, _) => {}
+            },
+        }
+    }
+
+    /// Handle a key while a variable is edited
+    fn handle_editing_event(&mut self, event: &InputEvent) {
+        let Some(text) = self.editing.as_mut() else {
+            return;
+        };
+        match event {
+            InputEvent::CharInput { character } if !character.is_control() => {
+                text.push(*character);
+            }
+            InputEvent::KeyDown { key, .. } => match key {
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Enter => self.confirm(),
+                KeyCode::Escape => {
+                    self.editing = None;
+                    self.message = None;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn paint_rows(&self, cx: &mut PaintContext, origin: Point, width: f32) {
+        let selected = self.selected[self.tab_index()];
+        let first = selected
+            .saturating_add(1)
+            .saturating_sub(Self::VISIBLE_ROWS)
+            .min(self.row_count().saturating_sub(Self::VISIBLE_ROWS));
+        // (index, name, value, name color) of each visible row
+        let rows: Vec<(usize, String, String, Color)> = match self.tab {
+            DevConsoleTab::Scenes => self
+                .scenes
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(Self::VISIBLE_ROWS)
+                .map(|(index, scene)| {
+                    let marker = if scene.current { " (current)" } else { "" };
+                    (
+                        index,
+                        format!("{}{}", scene.id.as_str(), marker),
+                        scene.title.clone(),
+                        if scene.current {
+                            colors::TEXT_ACCENT
+                        } else {
+                            colors::TEXT_PRIMARY
+                        },
+                    )
+                })
+                .collect(),
+            DevConsoleTab::State => self
+                .state
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(Self::VISIBLE_ROWS)
+                .map(|(index, row)| {
+                    let value = match &self.editing {
+                        Some(text) if index == selected => format!("{}_", text),
+                        _ => row.value_text(),
+                    };
+                    (index, row.name().to_string(), value, colors::TEXT_PRIMARY)
+                })
+                .collect(),
+        };
+        for (row, (index, name, value, name_color)) in rows.into_iter().enumerate() {
+            let y = origin.y + row as f32 * Self::ROW_HEIGHT;
+            if index == selected {
+                cx.fill_rect(
+                    Bounds {
+                        origin: Point::new(origin.x - spacing::XS, y),
+                        size: Size::new(width + spacing::XS * 2.0, Self::ROW_HEIGHT),
+                    },
+                    colors::BG_SELECTED,
+                );
+            }
+            let text_y = y + (Self::ROW_HEIGHT + font_size::MD * 0.7) / 2.0;
+            cx.draw_text(
+                &name,
+                Point::new(origin.x, text_y),
+                name_color,
+                font_size::MD,
+            );
+            cx.draw_text(
+                &value,
+                Point::new(origin.x + width / 2.0, text_y),
+                colors::TEXT_SECONDARY,
+                font_size::MD,
+            );
+        }
+        if self.row_count() == 0 {
+            let empty = match self.tab {
+                DevConsoleTab::Scenes => "No scenes",
+                DevConsoleTab::State => "No flags or variables set yet",
+            };
+            cx.draw_text(
+                empty,
+                Point::new(origin.x, origin.y + font_size::MD),
+                colors::TEXT_MUTED,
+                font_size::MD,
+            );
+        }
+    }
+}
+
+impl Default for DevConsoleElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for DevConsoleElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Overlay the whole screen
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, _cx: &mut PaintContext) {
+        // Drawn at the overlay layer, over menus and dialogs
+    }
+
+    fn paint_overlay(&self, cx: &mut PaintContext) {
+        if !self.visible {
+            return;
+        }
+
+        let mut dim = colors::BG_DARKEST;
+        dim.a = Self::OVERLAY_ALPHA;
+        cx.fill_rect(cx.bounds, dim);
+
+        let tabs_height = font_size::LG + spacing::MD;
+        let list_height = Self::VISIBLE_ROWS as f32 * Self::ROW_HEIGHT;
+        let panel_size = Size::new(
+            Self::PANEL_WIDTH.min(cx.bounds.size.width),
+            (tabs_height + list_height + (font_size::SM + spacing::MD) * 2.0 + spacing::XL * 2.0)
+                .min(cx.bounds.size.height),
+        );
+        let panel = Bounds {
+            origin: Point::new(
+                cx.bounds.origin.x + (cx.bounds.size.width - panel_size.width) / 2.0,
+                cx.bounds.origin.y + (cx.bounds.size.height - panel_size.height) / 2.0,
+            ),
+            size: panel_size,
+        };
+        cx.fill_rounded_rect(panel, colors::CARD_BG, radius::LG);
+        cx.stroke_rect(panel, colors::BORDER_LIGHT, 2.0);
+
+        let left = panel.origin.x + spacing::XL;
+        let top = panel.origin.y + spacing::XL;
+        let width = panel.size.width - spacing::XL * 2.0;
+        for (index, (tab, label)) in [
+            (DevConsoleTab::Scenes, "Scenes"),
+            (DevConsoleTab::State, "Flags & Variables"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let color = if tab == self.tab {
+                colors::TEXT_ACCENT
+            } else {
+                colors::TEXT_MUTED
+            };
+            cx.draw_text(
+                label,
+                Point::new(left + index as f32 * width / 2.0, top + font_size::LG),
+                color,
+                font_size::LG,
+            );
+        }
+
+        self.paint_rows(cx, Point::new(left, top + tabs_height), width);
+
+        let footer_y = top + tabs_height + list_height + spacing::MD + font_size::SM;
+        if let Some(message) = &self.message {
+            cx.draw_text(
+                message,
+                Point::new(left, footer_y),
+                colors::WARNING,
+                font_size::SM,
+            );
+        }
+        let hint = match self.tab {
+            DevConsoleTab::Scenes => Self::SCENES_HINT,
+            DevConsoleTab::State => Self::STATE_HINT,
+        };
+        cx.draw_text(
+            hint,
+            Point::new(left, footer_y + font_size::SM + spacing::MD),
+            colors::TEXT_MUTED,
+            font_size::SM,
+        );
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, _bounds: Bounds) -> bool {
+        if !self.visible {
+            return false;
+        }
+        if self.editing.is_some() {
+            self.handle_editing_event(event);
+            return true;
+        }
+        if Self::is_toggle_event(event) {
+            self.hide();
+            return true;
+        }
+        if let InputEvent::KeyDown { key, .. } = event {
+            match key {
+                KeyCode::Escape => self.hide(),
+                KeyCode::Tab => {
+                    self.tab = match self.tab {
+                        DevConsoleTab::Scenes => DevConsoleTab::State,
+                        DevConsoleTab::State => DevConsoleTab::Scenes,
+                    };
+                    self.message = None;
+                }
+                KeyCode::Up => self.move_selection(false),
+                KeyCode::Down => self.move_selection(true),
+                KeyCode::Enter => self.confirm(),
+                _ => {}
+            }
+        }
+        // Modal: nothing reaches the screen underneath
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Text of a variable value, as typed to edit it
+fn value_text(value: &VariableValue) -> String {
+    match value {
+        VariableValue::Bool(b) => b.to_string(),
+        VariableValue::Int(n) => n.to_string(),
+        VariableValue::Float(f) => f.to_string(),
+        VariableValue::String(s) => s.clone(),
+    }
+}
+
+/// Parse typed text as a value of the same type as `value`
+fn parse_like(value: &VariableValue, text: &str) -> Option<VariableValue> {
+    let trimmed = text.trim();
+    match value {
+        VariableValue::Bool(_) => trimmed.parse().ok().map(VariableValue::Bool),
+        VariableValue::Int(_) => trimmed.parse().ok().map(VariableValue::Int),
+        VariableValue::Float(_) => trimmed.parse().ok().map(VariableValue::Float),
+        VariableValue::String(_) => Some(VariableValue::String(text.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{
+        Dialogue, FlagId, Scenario, ScenarioCommand, ScenarioMetadata, Scene, VariableId,
+    };
+    use narrative_gui::framework::input::Modifiers;
+
+    fn key_down(key: KeyCode) -> InputEvent {
+        InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        }
+    }
+
+    fn runtime() -> ScenarioRuntime {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+        for (id, title) in [("start", "Start"), ("ending", "Ending")] {
+            let mut scene = Scene::new(id, title);
+            scene.add_command(ScenarioCommand::Dialogue {
+                dialogue: Dialogue::narrator("Hello"),
+            });
+            scenario.add_scene(id, scene);
+        }
+        let mut runtime = ScenarioRuntime::new(scenario);
+        runtime.start().unwrap();
+        runtime.flags_mut().set(FlagId::new("met_sakura"), true);
+        runtime
+            .variables_mut()
+            .set(VariableId::new("affection"), VariableValue::Int(10));
+        runtime
+    }
+
+    #[test]
+    fn test_jump_to_scene() {
+        let mut console = DevConsoleElement::new();
+        let bounds = Bounds::default();
+        assert!(!console.handle_event(&key_down(KeyCode::Enter), bounds));
+
+        console.show(&runtime());
+        let ids: Vec<&str> = console.scene_ids().map(SceneId::as_str).collect();
+        assert_eq!(ids, ["ending", "start"]);
+
+        // The current scene is selected; Down wraps around to the first
+        console.handle_event(&key_down(KeyCode::Down), bounds);
+        assert!(console.handle_event(&key_down(KeyCode::Enter), bounds));
+        assert_eq!(
+            console.take_action(),
+            Some(DevConsoleAction::JumpToScene(SceneId::new("ending")))
+        );
+        assert!(!console.is_visible());
+    }
+
+    #[test]
+    fn test_edit_flags_and_variables() {
+        let mut console = DevConsoleElement::new();
+        let bounds = Bounds::default();
+        console.show(&runtime());
+        console.handle_event(&key_down(KeyCode::Tab), bounds);
+        assert_eq!(console.tab(), DevConsoleTab::State);
+        let rows: Vec<(&str, String)> = console.state_rows().collect();
+        assert_eq!(
+            rows,
+            [
+                ("met_sakura", "true".to_string()),
+                ("affection", "10".to_string())
+            ]
+        );
+
+        console.handle_event(&key_down(KeyCode::Enter), bounds);
+        assert_eq!(
+            console.take_action(),
+            Some(DevConsoleAction::SetFlag("met_sakura".to_string(), false))
+        );
+
+        // Edit the variable: typed text must parse as its type
+        console.handle_event(&key_down(KeyCode::Down), bounds);
+        console.handle_event(&key_down(KeyCode::Enter), bounds);
+        console.handle_event(&key_down(KeyCode::Backspace), bounds);
+        console.handle_event(&key_down(KeyCode::Backspace), bounds);
+        for character in "5x".chars() {
+            console.handle_event(&InputEvent::CharInput { character }, bounds);
+        }
+        console.handle_event(&key_down(KeyCode::Enter), bounds);
+        assert_eq!(console.take_action(), None);
+        console.handle_event(&key_down(KeyCode::Backspace), bounds);
+        console.handle_event(&key_down(KeyCode::Enter), bounds);
+        assert_eq!(
+            console.take_action(),
+            Some(DevConsoleAction::SetVariable(
+                "affection".to_string(),
+                VariableValue::Int(5)
+            ))
+        );
+
+        // Escape stops editing before it closes the console
+        console.handle_event(&key_down(KeyCode::Enter), bounds);
+        console.handle_event(&key_down(KeyCode::Escape), bounds);
+        assert!(console.is_visible());
+        console.handle_event(&key_down(KeyCode::F12), bounds);
+        assert!(!console.is_visible());
+    }
+}
@@ -3,11 +3,17 @@
 //! This component displays dialogue text with:
 //! - Speaker name (optional)
 //! - Typewriter effect (controlled by visible_chars)
+//! - Markup styling (color, bold, size) and kinetic text (wave, shake)
+//! - Second-language text under the dialogue (dual-language mode)
 //! - Blinking click indicator when text is complete
+//! - Mirrored layout for right-to-left languages
+//! - Configurable line, letter and paragraph spacing
 //! - Configurable styling via DialogueBoxConfig
 
+use narrative_core::TextSpan;
 use narrative_core::config::DialogueBoxConfig;
 use narrative_gui::framework::animation::AnimationContext;
+use narrative_gui::framework::renderer::{DrawCommand, TextAlign, TextMarkup, TextSpacing};
 use narrative_gui::{Bounds, Color, Element, ElementId, InputEvent, Point, Size};
 use std::any::Any;
 use std::sync::Arc;
@@ -26,6 +32,10 @@ pub struct DialogueBoxElement {
     speaker: Option<Arc<str>>,
     /// Full dialogue text
     text: Arc<str>,
+    /// Markup spans styling the dialogue text, in characters
+    spans: Arc<[TextSpan]>,
+    /// Second-language text stacked under the dialogue text
+    translation: Option<Arc<str>>,
     /// Number of characters currently visible (for typewriter effect)
     visible_chars: usize,
     /// Whether all text has been displayed
@@ -44,6 +54,8 @@ pub struct DialogueBoxElement {
     animation_context: AnimationContext,
     /// Component-specific animation override (None = follow global)
     animations_enabled: Option<bool>,
+    /// UI theme box image drawn instead of the background color
+    background_texture_id: Option<u64>,
 }
 
 impl DialogueBoxElement {
@@ -52,6 +64,10 @@ impl DialogueBoxElement {
     const BLINK_ALPHA_MIN: f32 = 0.3;
     const BLINK_ALPHA_MAX: f32 = 1.0;
     const BLINK_ALPHA_SCALE: f32 = 0.5;
+    /// Translation font size relative to the dialogue text
+    const TRANSLATION_FONT_SCALE: f32 = 0.8;
+    /// Lines reserved for the translation at the bottom of the box
+    const TRANSLATION_LINES: f32 = 2.0;
     /// Assumed frame delta time for 60 FPS
     const ASSUMED_FRAME_DELTA: f32 = 1.0 / 60.0;
 
@@ -63,6 +79,8 @@ impl DialogueBoxElement {
             config,
             speaker: None,
             text: Arc::from(""),
+            spans: Arc::from([]),
+            translation: None,
             visible_chars: 0,
             text_complete: false,
             elapsed: 0.0,
@@ -72,6 +90,7 @@ impl DialogueBoxElement {
             skip_mode: narrative_core::SkipMode::default(),
             animation_context: AnimationContext::default(),
             animations_enabled: None,
+            background_texture_id: None,
         }
     }
 
@@ -81,6 +100,12 @@ impl DialogueBoxElement {
         self
     }
 
+    /// Draw a texture (the UI theme's box image) instead of the background color
+    pub fn with_background_texture(mut self, texture_id: Option<u64>) -> Self {
+        self.background_texture_id = texture_id;
+        self
+    }
+
     /// Set component-specific animation override
     pub fn with_animations_enabled(mut self, enabled: impl Into<Option<bool>>) -> Self {
         self.animations_enabled = enabled.into();
@@ -96,11 +121,18 @@ impl DialogueBoxElement {
     /// Set the dialogue text
     pub fn with_text(mut self, text: impl Into<Arc<str>>) -> Self {
         self.text = text.into();
+        self.spans = Arc::from([]);
         self.visible_chars = 0;
         self.text_complete = false;
         self
     }
 
+    /// Set the markup spans styling the dialogue text (after the text)
+    pub fn with_spans(mut self, spans: impl Into<Arc<[TextSpan]>>) -> Self {
+        self.spans = spans.into();
+        self
+    }
+
     /// Set the number of visible characters (for typewriter effect)
     pub fn with_visible_chars(mut self, count: usize) -> Self {
         self.visible_chars = count;
@@ -114,12 +146,25 @@ impl DialogueBoxElement {
     }
 
     /// Update the dialogue text (mutable)
+    ///
+    /// Clears the markup spans, which belong to the previous text.
     pub fn set_text(&mut self, text: Arc<str>) {
         self.text = text;
+        self.spans = Arc::from([]);
         self.visible_chars = 0;
         self.text_complete = false;
     }
 
+    /// Update the markup spans styling the dialogue text (mutable)
+    pub fn set_spans(&mut self, spans: Arc<[TextSpan]>) {
+        self.spans = spans;
+    }
+
+    /// Update the second-language text (mutable)
+    pub fn set_translation(&mut self, translation: Option<Arc<str>>) {
+        self.translation = translation;
+    }
+
     /// Update the number of visible characters (mutable)
     pub fn set_visible_chars(&mut self, count: usize) {
         self.visible_chars = count;
@@ -142,11 +187,44 @@ impl DialogueBoxElement {
     }
 
     /// Get truncated text for typewriter effect
-    fn get_visible_text(&self) -> String {
-        if self.visible_chars >= self.text.chars().count() {
-            self.text.to_string()
-        } else {
-            self.text.chars().take(self.visible_chars).collect()
+    ///
+    /// Borrows from the full text, so painting doesn't allocate.
+    fn get_visible_text(&self) -> &str {
+        match self.text.char_indices().nth(self.visible_chars) {
+            Some((end, _)) => &self.text[..end],
+            None => &self.text,
+        }
+    }
+
+    /// Check if kinetic text (wave, shake) is moving
+    fn has_moving_text(&self) -> bool {
+        narrative_core::markup::has_motion(&self.spans)
+            && self
+                .animation_context
+                .should_animate(self.animations_enabled)
+    }
+
+    /// Spans to paint: kinetic effects are dropped while animations are off
+    fn paint_spans(&self) -> Arc<[TextSpan]> {
+        if self.has_moving_text() || !narrative_core::markup::has_motion(&self.spans) {
+            return Arc::clone(&self.spans);
+        }
+        self.spans
+            .iter()
+            .map(|span| {
+                let mut span = *span;
+                span.style.effect = None;
+                span
+            })
+            .collect()
+    }
+
+    /// Line, letter and paragraph spacing of the dialogue text
+    fn text_spacing(&self) -> TextSpacing {
+        TextSpacing {
+            line_height: Some(self.config.text_line_height()),
+            letter_spacing: self.config.letter_spacing,
+            paragraph_spacing: self.config.paragraph_spacing,
         }
     }
 
@@ -180,11 +258,12 @@ impl Element for DialogueBoxElement {
 
     fn layout(
         &mut self,
-        _cx: &mut narrative_gui::framework::element::LayoutContext,
+        cx: &mut narrative_gui::framework::element::LayoutContext,
     ) -> taffy::Style {
         use taffy::prelude::*;
 
-        // Fixed height, 100% width at bottom of screen
+        // Fixed height, 100% width, raised from the bottom by the player's position setting
+        let bottom = self.config.bottom_offset(cx.available_size.height);
         taffy::Style {
             size: taffy::geometry::Size {
                 width: Dimension::percent(1.0), // 100% width
@@ -194,7 +273,7 @@ impl Element for DialogueBoxElement {
             inset: taffy::geometry::Rect {
                 left: LengthPercentageAuto::length(0.0),
                 right: LengthPercentageAuto::length(0.0),
-                bottom: LengthPercentageAuto::length(0.0), // Pin to bottom
+                bottom: LengthPercentageAuto::length(bottom),
                 top: LengthPercentageAuto::auto(),
             },
             padding: taffy::geometry::Rect {
@@ -208,22 +287,35 @@ impl Element for DialogueBoxElement {
     }
 
     fn paint(&self, cx: &mut narrative_gui::framework::element::PaintContext) {
-        // 1. Draw background with rounded corners
-        let bg_color = Self::to_gui_color(&self.config.background_color_with_opacity());
-        cx.fill_rounded_rect(cx.bounds, bg_color, self.config.corner_radius);
+        // 1. Draw background (theme texture, or color with rounded corners)
+        if let Some(texture_id) = self.background_texture_id {
+            cx.draw_texture(texture_id, cx.bounds, self.config.opacity);
+        } else {
+            let bg_color = Self::to_gui_color(&self.config.background_color_with_opacity());
+            cx.fill_rounded_rect(cx.bounds, bg_color, self.config.corner_radius);
+        }
 
         let mut current_y = cx.bounds.origin.y + self.config.padding;
 
+        // Text starts at the right edge of a mirrored box
+        let left = cx.bounds.origin.x;
+        let right = cx.bounds.origin.x + cx.bounds.size.width;
+        let (text_x, text_align) = if self.config.mirrored {
+            (right - self.config.padding, TextAlign::Right)
+        } else {
+            (left + self.config.padding, TextAlign::Left)
+        };
+
         // 2. Draw speaker name if present
         if let Some(speaker) = &self.speaker {
             let speaker_color = Self::to_gui_color(&self.config.speaker_color);
-            let speaker_pos = Point::new(cx.bounds.origin.x + self.config.padding, current_y);
 
-            cx.draw_text(
+            cx.draw_text_aligned(
                 speaker.as_ref(),
-                speaker_pos,
+                Point::new(text_x, current_y),
                 speaker_color,
                 self.config.speaker_font_size,
+                text_align,
             );
 
             // Move down for dialogue text (speaker height + small gap)
@@ -233,19 +325,60 @@ impl Element for DialogueBoxElement {
         // 3. Draw dialogue text (with typewriter effect)
         let visible_text = self.get_visible_text();
         let text_color = Self::to_gui_color(&self.config.text_color);
-        let text_pos = Point::new(cx.bounds.origin.x + self.config.padding, current_y);
 
-        cx.draw_text(
-            &visible_text,
-            text_pos,
-            text_color,
-            self.config.text_font_size,
-        );
+        // Pushed as a command to carry the configured spacing
+        let spans = self.paint_spans();
+        let text = cx.commands.intern(visible_text);
+        cx.commands.push(DrawCommand::Text {
+            text,
+            position: Point::new(text_x, current_y),
+            color: text_color,
+            font_size: self.config.text_font_size,
+            align: text_align,
+            markup: (!spans.is_empty()).then_some(TextMarkup {
+                spans,
+                time: self.elapsed,
+            }),
+            spacing: self.text_spacing(),
+            clip: None,
+        });
+
+        // Second language, stacked in the lower part of the box in a dimmer color
+        if let Some(translation) = &self.translation {
+            let font_size = self.config.text_font_size * Self::TRANSLATION_FONT_SCALE;
+            let translation_y = cx.bounds.origin.y + cx.bounds.size.height
+                - self.config.padding
+                - font_size * Self::TRANSLATION_LINES;
+            let translation_color = Color::new(
+                self.config.text_color.r,
+                self.config.text_color.g,
+                self.config.text_color.b,
+                self.config.text_color.a * 0.7,
+            );
+            cx.draw_text_aligned(
+                translation.as_ref(),
+                Point::new(
+                    text_x,
+                    translation_y.max(current_y + self.config.text_font_size),
+                ),
+                translation_color,
+                font_size,
+                text_align,
+            );
+        }
 
         // 4. Draw mode indicators (SKIP and AUTO can be shown simultaneously)
         let indicator_font_size = self.config.text_font_size * 0.8;
         let indicator_padding = self.config.padding * 0.5;
         let mut indicator_y = cx.bounds.origin.y + self.config.padding;
+        // Indicators sit in the top corner opposite the speaker name
+        let indicator_x = |text_width: f32| {
+            if self.config.mirrored {
+                left + self.config.padding + indicator_padding * 2.0
+            } else {
+                right - self.config.padding - text_width - indicator_padding * 2.0
+            }
+        };
 
         // Draw skip mode indicator when skip mode is enabled
         if self.skip_mode_enabled {
@@ -258,10 +391,7 @@ impl Element for DialogueBoxElement {
             // Approximate text width (conservative estimate to ensure text fits)
             let text_width = skip_text.len() as f32 * indicator_font_size * 0.7;
 
-            let skip_x = cx.bounds.origin.x + cx.bounds.size.width
-                - self.config.padding
-                - text_width
-                - indicator_padding * 2.0;
+            let skip_x = indicator_x(text_width);
 
             // Draw background rectangle for SKIP indicator
             let skip_bg_bounds = Bounds {
@@ -300,10 +430,7 @@ impl Element for DialogueBoxElement {
             // Approximate text width (conservative estimate to ensure text fits)
             let text_width = auto_text.len() as f32 * indicator_font_size * 0.7;
 
-            let auto_x = cx.bounds.origin.x + cx.bounds.size.width
-                - self.config.padding
-                - text_width
-                - indicator_padding * 2.0;
+            let auto_x = indicator_x(text_width);
 
             // Draw background rectangle for AUTO indicator
             let auto_bg_bounds = Bounds {
@@ -336,10 +463,12 @@ impl Element for DialogueBoxElement {
         if self.text_complete && self.config.show_click_indicator {
             let blink_alpha = self.calculate_blink_alpha();
 
-            // Position at bottom-right corner
-            let indicator_x = cx.bounds.origin.x + cx.bounds.size.width
-                - self.config.padding
-                - Self::CLICK_INDICATOR_SIZE;
+            // Position at the bottom corner the text ends at
+            let indicator_x = if self.config.mirrored {
+                left + self.config.padding
+            } else {
+                right - self.config.padding - Self::CLICK_INDICATOR_SIZE
+            };
             let indicator_y = cx.bounds.origin.y + cx.bounds.size.height
                 - self.config.padding
                 - Self::CLICK_INDICATOR_SIZE;
@@ -378,8 +507,8 @@ impl Element for DialogueBoxElement {
         self.blink_phase =
             self.elapsed * self.config.click_indicator_blink_speed * std::f32::consts::TAU;
 
-        // Request repaint if text is complete (for blinking indicator)
-        self.text_complete && self.config.show_click_indicator
+        // Request repaint if text is complete (for blinking indicator) or moving
+        (self.text_complete && self.config.show_click_indicator) || self.has_moving_text()
     }
 
     fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
@@ -585,4 +714,101 @@ mod tests {
         assert_eq!(gui_color.b, 0.7);
         assert_eq!(gui_color.a, 0.8);
     }
+
+    #[test]
+    fn test_kinetic_text_spans() {
+        let rich = narrative_core::RichText::parse("[wave]Hi[/wave] there");
+        let mut dialogue_box = DialogueBoxElement::default()
+            .with_text(rich.text.as_str())
+            .with_spans(rich.spans)
+            .with_visible_chars(1);
+
+        // Moving text repaints every frame, even while typing
+        assert!(dialogue_box.tick(Duration::from_millis(16)));
+        assert!(dialogue_box.paint_spans()[0].style.effect.is_some());
+
+        let mut still_box = DialogueBoxElement::default()
+            .with_animations_enabled(false)
+            .with_text("Hi there")
+            .with_spans(dialogue_box.spans.clone())
+            .with_visible_chars(1);
+        assert!(!still_box.tick(Duration::from_millis(16)));
+        assert!(still_box.paint_spans()[0].style.effect.is_none());
+
+        // New text drops the old spans
+        dialogue_box.set_text(Arc::from("Plain"));
+        assert!(dialogue_box.spans.is_empty());
+    }
+
+    #[test]
+    fn test_text_spacing() {
+        use narrative_gui::framework::HeadlessWindow;
+
+        let config = DialogueBoxConfig {
+            line_spacing: Some(1.5),
+            letter_spacing: 2.0,
+            paragraph_spacing: 12.0,
+            ..Default::default()
+        };
+        let dialogue_box = DialogueBoxElement::new(config)
+            .with_text("Hello\nWorld")
+            .with_visible_chars(11);
+        let mut window = HeadlessWindow::new(1280, 720, Box::new(dialogue_box));
+        window.frame().unwrap();
+
+        let spacing = window
+            .draw_commands()
+            .iter()
+            .find_map(|command| match command {
+                DrawCommand::Text { text, spacing, .. } if text.as_str() == "Hello\nWorld" => {
+                    Some(*spacing)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            spacing,
+            TextSpacing {
+                line_height: Some(36.0),
+                letter_spacing: 2.0,
+                paragraph_spacing: 12.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_mirrored_layout() {
+        use narrative_gui::framework::HeadlessWindow;
+
+        let text = "שלום, Alice!";
+        let config = DialogueBoxConfig {
+            mirrored: true,
+            ..Default::default()
+        };
+        let dialogue_box = DialogueBoxElement::new(config)
+            .with_speaker("אליס")
+            .with_text(text)
+            .with_visible_chars(text.chars().count());
+        let mut window = HeadlessWindow::new(1280, 720, Box::new(dialogue_box));
+        window.frame().unwrap();
+
+        // Speaker and text are anchored at the right edge
+        for painted in ["אליס", text] {
+            let (x, align) = window
+                .draw_commands()
+                .iter()
+                .find_map(|command| match command {
+                    DrawCommand::Text {
+                        text,
+                        position,
+                        align,
+                        ..
+                    } if text.as_str() == painted => Some((position.x, *align)),
+                    _ => None,
+                })
+                .unwrap();
+            assert_eq!(align, TextAlign::Right);
+            assert!(x > 640.0, "{} drawn at {}", painted, x);
+        }
+    }
 }
@@ -0,0 +1,280 @@
+//! Ending screen UI component
+//!
+//! Shown when a scenario ends with credits or an ending card:
+//! - Credits scroll from bottom to top, then finish automatically
+//! - Ending cards stay until the player dismisses them
+//!
+//! Input is ignored for a short moment so a held key does not skip the screen.
+
+use narrative_engine::runtime::EndingState;
+use narrative_gui::framework::animation::AnimationContext;
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, font_size, spacing};
+use narrative_gui::{Color, Point};
+use std::any::Any;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Ending screen UI element
+pub struct EndingScreenElement {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    state: EndingState,
+    /// Seconds since the screen was shown
+    elapsed: f32,
+    /// Set once the credits finished or the player dismissed the screen
+    finished: bool,
+    animation_context: AnimationContext,
+}
+
+impl EndingScreenElement {
+    /// Seconds before input can dismiss the screen
+    const MIN_DISPLAY_TIME: f32 = 1.0;
+    /// Duration of the ending card fade-in
+    const CARD_FADE_IN: f32 = 1.5;
+    const CARD_TITLE_FONT_SIZE: f32 = 48.0;
+    const CREDITS_LINE_HEIGHT: f32 = 40.0;
+    const HINT_BOTTOM_MARGIN: f32 = 30.0;
+
+    pub fn new(state: EndingState) -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            state,
+            elapsed: 0.0,
+            finished: false,
+            animation_context: AnimationContext::default(),
+        }
+    }
+
+    pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
+        self.animation_context = context;
+        self
+    }
+
+    /// Whether the ending screen is done and the game can return to the title
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn can_dismiss(&self) -> bool {
+        self.elapsed >= Self::MIN_DISPLAY_TIME
+    }
+
+    /// Scroll progress of the credits (0.0 to 1.0)
+    fn credits_progress(&self, duration: f32) -> f32 {
+        if duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Opacity of the ending card
+    fn card_alpha(&self) -> f32 {
+        let fade_in = self
+            .animation_context
+            .adjust_duration(Duration::from_secs_f32(Self::CARD_FADE_IN), None)
+            .as_secs_f32();
+        if fade_in <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / fade_in).clamp(0.0, 1.0)
+    }
+
+    fn paint_credits(&self, cx: &mut PaintContext, lines: &[String], duration: f32) {
+        let total_height = lines.len() as f32 * Self::CREDITS_LINE_HEIGHT;
+        // Scroll from just below the bottom edge until the last line leaves the top
+        let travel = cx.bounds.size.height + total_height;
+        let offset = self.credits_progress(duration) * travel;
+        let start_y = cx.bounds.origin.y + cx.bounds.size.height - offset;
+
+        for (i, line) in lines.iter().enumerate() {
+            let y = start_y + i as f32 * Self::CREDITS_LINE_HEIGHT;
+            if y < cx.bounds.origin.y || y > cx.bounds.origin.y + cx.bounds.size.height {
+                continue;
+            }
+            // Rough estimate for centering (TODO: use proper text measurement)
+            let text_width = line.len() as f32 * font_size::LG * 0.6;
+            let x = cx.bounds.origin.x + (cx.bounds.size.width - text_width) / 2.0;
+            cx.draw_text(line, Point::new(x, y), colors::TEXT_PRIMARY, font_size::LG);
+        }
+    }
+
+    fn paint_card(&self, cx: &mut PaintContext, title: &str) {
+        let alpha = self.card_alpha();
+        let text_color = Color::new(
+            colors::TEXT_PRIMARY.r,
+            colors::TEXT_PRIMARY.g,
+            colors::TEXT_PRIMARY.b,
+            colors::TEXT_PRIMARY.a * alpha,
+        );
+
+        // Rough estimate for centering (TODO: use proper text measurement)
+        let title_width = title.len() as f32 * Self::CARD_TITLE_FONT_SIZE * 0.6;
+        let title_x = cx.bounds.origin.x + (cx.bounds.size.width - title_width) / 2.0;
+        let title_y = cx.bounds.origin.y + cx.bounds.size.height / 2.0;
+        cx.draw_text(
+            title,
+            Point::new(title_x, title_y),
+            text_color,
+            Self::CARD_TITLE_FONT_SIZE,
+        );
+
+        if self.can_dismiss() {
+            let hint = "Press Enter to continue";
+            let hint_width = hint.len() as f32 * font_size::SM * 0.6;
+            let hint_x = cx.bounds.origin.x + (cx.bounds.size.width - hint_width) / 2.0;
+            let hint_y =
+                cx.bounds.origin.y + cx.bounds.size.height - Self::HINT_BOTTOM_MARGIN - spacing::MD;
+            cx.draw_text(
+                hint,
+                Point::new(hint_x, hint_y),
+                colors::TEXT_SECONDARY,
+                font_size::SM,
+            );
+        }
+    }
+}
+
+impl Element for EndingScreenElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        taffy::Style {
+            size: Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            position: Position::Absolute,
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        cx.fill_rect(cx.bounds, Color::BLACK);
+
+        match &self.state {
+            EndingState::Credits { lines, duration } => {
+                self.paint_credits(cx, lines, *duration);
+            }
+            EndingState::Card { title, .. } => {
+                self.paint_card(cx, title);
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, _bounds: Bounds) -> bool {
+        let dismiss = match event {
+            InputEvent::KeyDown { key, .. } => {
+                matches!(key, KeyCode::Enter | KeyCode::Space | KeyCode::Escape)
+            }
+            InputEvent::MouseDown { .. } => true,
+            _ => false,
+        };
+
+        if dismiss && self.can_dismiss() {
+            self.finished = true;
+        }
+
+        // The ending screen is modal
+        matches!(
+            event,
+            InputEvent::KeyDown { .. } | InputEvent::MouseDown { .. }
+        )
+    }
+
+    fn children(&self) -> &[Box<dyn Element>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Element>] {
+        &mut []
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        if self.finished {
+            return false;
+        }
+        self.elapsed += delta.as_secs_f32();
+
+        if let EndingState::Credits { duration, .. } = &self.state
+            && self.elapsed >= *duration
+        {
+            self.finished = true;
+        }
+
+        // Credits scroll and the card fades in every frame
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_gui::framework::input::Modifiers;
+
+    fn card() -> EndingState {
+        EndingState::Card {
+            ending_id: "true_end".to_string(),
+            title: "True End".to_string(),
+        }
+    }
+
+    fn enter() -> InputEvent {
+        InputEvent::KeyDown {
+            key: KeyCode::Enter,
+            modifiers: Modifiers::none(),
+        }
+    }
+
+    #[test]
+    fn test_ending_card_requires_min_display_time() {
+        let mut screen = EndingScreenElement::new(card());
+        let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+
+        assert!(screen.handle_event(&enter(), bounds));
+        assert!(!screen.is_finished());
+
+        screen.tick(Duration::from_secs_f32(
+            EndingScreenElement::MIN_DISPLAY_TIME,
+        ));
+        screen.handle_event(&enter(), bounds);
+        assert!(screen.is_finished());
+    }
+
+    #[test]
+    fn test_credits_finish_after_duration() {
+        let mut screen = EndingScreenElement::new(EndingState::Credits {
+            lines: vec!["Thanks for playing".to_string()],
+            duration: 2.0,
+        });
+
+        screen.tick(Duration::from_secs(1));
+        assert!(!screen.is_finished());
+        screen.tick(Duration::from_secs(1));
+        assert!(screen.is_finished());
+    }
+}
@@ -0,0 +1,65 @@
+//! Achievements for GameRootElement
+
+use super::element::GameRootElement;
+use crate::components::ToastMessage;
+use narrative_core::{AchievementManifest, AchievementRegistry};
+use narrative_engine::EngineConfig;
+use narrative_gui::components::IconType;
+
+/// Achievement manifest, relative to the asset directory
+const ACHIEVEMENTS_MANIFEST: &str = "manifests/achievements.ron";
+
+/// Heading of achievement toasts
+const ACHIEVEMENT_TOAST_HEADING: &str = "Achievement Unlocked";
+
+/// Load the achievements in the achievement manifest
+///
+/// A missing manifest leaves the game without achievements.
+pub(super) fn load_achievements(config: &EngineConfig) -> AchievementRegistry {
+    let path = config.asset_path.join(ACHIEVEMENTS_MANIFEST);
+    if !path.exists() {
+        return AchievementRegistry::new();
+    }
+    match AchievementManifest::load_from_file(&path) {
+        Ok(manifest) => {
+            tracing::debug!("Loaded {} achievements", manifest.achievements.len());
+            AchievementRegistry::from_manifest(manifest)
+        }
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load achievements from '{}': {}",
+                path.display(),
+                e
+            );
+            AchievementRegistry::new()
+        }
+    }
+}
+
+impl GameRootElement {
+    /// Show a toast for each achievement the runtime unlocked (setting it in
+    /// Steam with the `steam` feature)
+    pub(super) fn update_achievement_toasts(&mut self) {
+        if let Some(runtime) = self.scenario_runtime.as_mut() {
+            for id in runtime.take_unlocked_achievements() {
+                // The demo unlocks into throwaway unlock data
+                if self.attract_mode {
+                    continue;
+                }
+                #[cfg(feature = "steam")]
+                if let Some(steam) = &self.steam {
+                    steam.unlock_achievement(&id);
+                }
+                let title = self
+                    .achievements
+                    .get(&id)
+                    .map_or(id.clone(), |achievement| achievement.title.clone());
+                self.toasts.push(
+                    ToastMessage::new(title)
+                        .with_icon(IconType::Star)
+                        .with_heading(ACHIEVEMENT_TOAST_HEADING),
+                );
+            }
+        }
+    }
+}
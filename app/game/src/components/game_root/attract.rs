@@ -0,0 +1,123 @@
+//! Title screen attract mode (demo playback after idle) for GameRootElement
+
+use super::element::GameRootElement;
+use narrative_core::UnlockData;
+use narrative_engine::runtime::{AppState, MainMenuState};
+use narrative_gui::framework::element::PaintContext;
+use narrative_gui::framework::input::InputEvent;
+use narrative_gui::framework::renderer::TextAlign;
+use narrative_gui::{Color, Point};
+use std::sync::{Arc, Mutex};
+
+/// Prompt shown at the bottom of the screen while the demo plays
+const ATTRACT_PROMPT: &str = "Press any key";
+/// Prompt font size in pixels
+const ATTRACT_PROMPT_SIZE: f32 = 24.0;
+
+impl GameRootElement {
+    /// Start the demo once the title screen has been idle long enough, and
+    /// end it when it leaves the game (e.g. its scenario ended)
+    pub(super) fn update_attract_mode(&mut self, delta: f32) {
+        if self.attract_mode && !matches!(self.app_state, AppState::InGame(_)) {
+            self.stop_attract_mode();
+        }
+
+        if !matches!(self.app_state, AppState::MainMenu(_)) || self.help_overlay.is_visible() {
+            self.title_idle_secs = 0.0;
+            return;
+        }
+
+        let Some(attract_mode) = &self.config.ui.attract_mode else {
+            return;
+        };
+        self.title_idle_secs += delta;
+        if self.title_idle_secs >= attract_mode.idle_secs {
+            let path = attract_mode.scenario.clone();
+            self.title_idle_secs = 0.0;
+            self.start_attract_mode(&path);
+        }
+    }
+
+    /// Play the demo scenario in auto mode
+    ///
+    /// CGs seen in the demo are not unlocked. Returns false (staying on the
+    /// title screen) if the scenario could not be started.
+    fn start_attract_mode(&mut self, path: &std::path::Path) -> bool {
+        tracing::info!("Title screen idle, starting demo: {}", path.display());
+        self.auto_mode_before_attract = self.config.gameplay.auto_mode_enabled;
+        self.config.gameplay.auto_mode_enabled = true;
+        self.config.gameplay.skip_mode_enabled = false;
+
+        let unlock_data = std::mem::replace(
+            &mut self.unlock_data,
+            Arc::new(Mutex::new(UnlockData::new())),
+        );
+        let started = self.start_scenario(path);
+        self.unlock_data = unlock_data;
+
+        if started {
+            self.attract_mode = true;
+            self.replay = None;
+            self.attract_choice_elapsed = 0.0;
+        } else {
+            self.config.gameplay.auto_mode_enabled = self.auto_mode_before_attract;
+            tracing::warn!("Failed to start demo, staying on the title screen");
+        }
+        started
+    }
+
+    /// End the demo and return to the title screen
+    pub(super) fn stop_attract_mode(&mut self) {
+        tracing::info!("Demo ended, returning to title");
+        self.attract_mode = false;
+        self.config.gameplay.auto_mode_enabled = self.auto_mode_before_attract;
+        self.scenario_runtime = None;
+        self.previous_in_game_state = None;
+        self.ui_hidden = false;
+        if !matches!(self.app_state, AppState::MainMenu(_)) {
+            self.app_state = AppState::MainMenu(MainMenuState::default());
+        }
+        self.title_idle_secs = 0.0;
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+    }
+
+    /// Input while the demo plays: any key or mouse button returns to the
+    /// title screen, everything else is ignored
+    pub(super) fn handle_attract_mode_event(&mut self, event: &InputEvent) -> bool {
+        if matches!(
+            event,
+            InputEvent::KeyDown { .. } | InputEvent::MouseDown { .. }
+        ) {
+            self.stop_attract_mode();
+            // Input arrives outside update_state(), which starts it on state changes
+            self.start_title_bgm();
+        }
+        true
+    }
+
+    /// Draw the "press any key" prompt over the demo
+    pub(super) fn paint_attract_prompt(&self, cx: &mut PaintContext) {
+        if !self.attract_mode {
+            return;
+        }
+        let position = Point::new(
+            cx.bounds.origin.x + cx.bounds.size.width / 2.0,
+            cx.bounds.origin.y + cx.bounds.size.height - ATTRACT_PROMPT_SIZE * 2.0,
+        );
+        cx.draw_text_aligned(
+            ATTRACT_PROMPT,
+            position,
+            Color::new(1.0, 1.0, 1.0, 0.8),
+            ATTRACT_PROMPT_SIZE,
+            TextAlign::Center,
+        );
+    }
+}
+
+/// Index of the option the demo picks: the first one that is not disabled
+pub(super) fn attract_choice(disabled: &[bool], count: usize) -> usize {
+    (0..count)
+        .find(|&i| !disabled.get(i).copied().unwrap_or(false))
+        .unwrap_or(0)
+}
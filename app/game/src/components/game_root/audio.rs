@@ -1,14 +1,153 @@
 //! Audio management for GameRootElement
 
 use super::element::GameRootElement;
+use crate::components::SubtitleElement;
+use narrative_core::{BgmManifest, SeManifest, SubtitleTrack};
+use narrative_engine::runtime::{AppState, InGameState};
+use narrative_engine::{AudioCommand, AudioManager, EngineConfig};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Sound effect manifest, relative to the asset directory
+const SE_MANIFEST: &str = "manifests/se.ron";
+
+/// BGM manifest, relative to the asset directory
+const BGM_MANIFEST: &str = "manifests/bgm.ron";
+
+/// Load the sound effect variation pools from the SE manifest
+///
+/// A missing manifest leaves no pools, so every `PlaySe` asset is a file.
+pub(super) fn load_se_pools(config: &EngineConfig, audio: &mut AudioManager) {
+    let path = config.asset_path.join(SE_MANIFEST);
+    if !path.exists() {
+        return;
+    }
+    match SeManifest::load_from_file(&path) {
+        Ok(manifest) => {
+            tracing::debug!("Loaded {} SE pools", manifest.pools.len());
+            audio.set_se_pools(&manifest);
+        }
+        Err(e) => tracing::warn!("Failed to load SE pools from '{}': {}", path.display(), e),
+    }
+}
+
+/// Load the BGM manifest so `PlayBgm` can name tracks by ID
+///
+/// Tracks play with the loop points declared in the manifest. A missing
+/// manifest leaves every `PlayBgm` asset a file.
+pub(super) fn load_bgm_manifest(config: &EngineConfig, audio: &mut AudioManager) {
+    let path = config.asset_path.join(BGM_MANIFEST);
+    if !path.exists() {
+        return;
+    }
+    match BgmManifest::load_from_file(&path) {
+        Ok(manifest) => {
+            tracing::debug!("Loaded {} BGM tracks", manifest.tracks.len());
+            audio.set_bgm_manifest(&manifest, &config.asset_path);
+        }
+        Err(e) => tracing::warn!("Failed to load BGM manifest '{}': {}", path.display(), e),
+    }
+}
+
+/// Start the sidecar subtitles of a sound or video, if subtitles are on
+///
+/// A path missing from `subtitle_tracks` has its subtitle file looked up
+/// once and the result cached, empty if there is none.
+pub(super) fn play_subtitles(
+    subtitles: &mut SubtitleElement,
+    subtitle_tracks: &mut HashMap<PathBuf, SubtitleTrack>,
+    path: &Path,
+) {
+    if !subtitles.is_enabled() {
+        return;
+    }
+    let track = subtitle_tracks
+        .entry(path.to_path_buf())
+        .or_insert_with(|| {
+            SubtitleTrack::load_sidecar(path).unwrap_or_else(|e| {
+                tracing::warn!("Failed to load subtitles for '{}': {}", path.display(), e);
+                SubtitleTrack::default()
+            })
+        });
+    subtitles.play(track.clone());
+}
 
 impl GameRootElement {
+    /// Apply queued audio commands to the audio manager
+    ///
+    /// Called once per frame from `tick`; UI code only ever sends commands
+    /// through `audio_queue`. Preloaded sounds are handed to the audio loader
+    /// first, and their sidecar subtitles cached. Sound effects with a subtitle
+    /// file next to them start their subtitles here; a sound the preloader
+    /// never saw has its subtitle file looked up once, on its first play.
+    pub(super) fn process_audio_commands(&mut self) {
+        let audio = self.audio_manager.get_mut().unwrap_or_else(|e| {
+            tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
+            e.into_inner()
+        });
+        // Sounds decoded ahead of their commands are played from memory
+        for (asset, sound) in self.asset_preloader.take_sounds() {
+            audio.loader().insert_decoded(asset.path(), sound);
+        }
+        for (asset, track) in self.asset_preloader.take_subtitles() {
+            self.subtitle_tracks.insert(asset.path().into(), track);
+        }
+        let subtitles = &mut self.subtitles;
+        let subtitle_tracks = &mut self.subtitle_tracks;
+        audio.process_commands_with(|command| match command {
+            AudioCommand::PlaySe { path, .. } | AudioCommand::PlayVoice { path, .. } => {
+                play_subtitles(subtitles, subtitle_tracks, path)
+            }
+            AudioCommand::StopAllSe => subtitles.stop(),
+            _ => {}
+        });
+        self.voice_playing = audio.is_voice_playing();
+    }
+
+    /// Play the voice of each new dialogue line
+    ///
+    /// A line's voice starts when the line starts typing, cutting off the
+    /// previous line's. Leaving the game stops it. Voice paths are relative
+    /// to the asset directory.
+    pub(super) fn update_voice(&mut self) {
+        match (&self.app_state, &self.scenario_runtime) {
+            (AppState::InGame(InGameState::Typing(typing)), Some(runtime)) => {
+                let line = (typing.scene_id.clone(), typing.command_index);
+                if self.voiced_line.as_ref() != Some(&line) {
+                    match runtime.current_voice_with_volume() {
+                        Some((voice, volume)) => {
+                            let path = self.config.asset_path.join(voice.path());
+                            tracing::debug!("Playing voice: {}", path.display());
+                            self.audio_queue.play_voice(path, volume);
+                        }
+                        None => self.audio_queue.stop_voice(),
+                    }
+                    self.voiced_line = Some(line);
+                }
+            }
+            (AppState::InGame(_) | AppState::Settings(_), Some(_)) => {}
+            _ => {
+                if self.voiced_line.take().is_some() {
+                    self.audio_queue.stop_voice();
+                }
+            }
+        }
+    }
+
     /// Start title screen BGM playback
     ///
     /// This should be called when entering the main menu.
     pub(super) fn start_title_bgm(&mut self) {
         // Reset BGM started flag when returning to title
         self.bgm_started = false;
+        self.subtitles.stop();
+        // A video left mid-way stops with its audio track
+        if self.video_player.take().is_some() {
+            self.audio_manager
+                .get_mut()
+                .unwrap_or_else(|e| e.into_inner())
+                .stop_soundtrack();
+        }
 
         // Use title BGM path from config, or default to "assets/audio/music/title.ogg"
         let title_bgm_path = self
@@ -18,28 +157,19 @@ impl GameRootElement {
             .as_deref()
             .unwrap_or("assets/audio/music/title.ogg");
 
-        let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-            tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-            e.into_inner()
-        });
-
         // Stop any currently playing BGM first
-        if audio.is_bgm_playing()
-            && let Err(e) = audio.stop_bgm(Some(0.5))
-        {
-            tracing::warn!("Failed to stop previous BGM: {}", e);
+        self.audio_queue.stop_bgm(Some(0.5));
+
+        // Title BGM is optional, so skip it quietly when the file is missing
+        if !Path::new(title_bgm_path).exists() {
+            tracing::debug!("Title BGM not available (optional): {}", title_bgm_path);
+            return;
         }
 
         // Play title BGM with looping, fade-in, normal volume
-        match audio.play_bgm(title_bgm_path, true, Some(1.0), 1.0) {
-            Ok(_) => {
-                tracing::info!("Title BGM playback started: {}", title_bgm_path);
-            }
-            Err(e) => {
-                // Don't log error for missing title BGM - it's optional
-                tracing::debug!("Title BGM not available (optional): {}", e);
-            }
-        }
+        self.audio_queue
+            .play_bgm(title_bgm_path, true, Some(1.0), 1.0);
+        tracing::info!("Title BGM playback queued: {}", title_bgm_path);
     }
 
     /// Start BGM playback
@@ -56,27 +186,11 @@ impl GameRootElement {
         // Currently hardcoded to match assets/scenarios/chapter_01.toml
         let bgm_path = "assets/audio/music/dailylife/schooldays.ogg";
 
-        let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-            tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-            e.into_inner()
-        });
-
-        // Stop any title BGM that might be playing
-        if audio.is_bgm_playing()
-            && let Err(e) = audio.stop_bgm(Some(0.5))
-        {
-            tracing::warn!("Failed to stop previous BGM: {}", e);
-        }
-
-        // Play BGM with looping, no fade-in, normal volume
-        match audio.play_bgm(bgm_path, true, None, 1.0) {
-            Ok(_) => {
-                tracing::info!("BGM playback started: {}", bgm_path);
-                self.bgm_started = true;
-            }
-            Err(e) => {
-                tracing::error!("Failed to start BGM playback: {}", e);
-            }
-        }
+        // Stop any title BGM that might be playing, then play BGM with
+        // looping, no fade-in, normal volume
+        self.audio_queue.stop_bgm(Some(0.5));
+        self.audio_queue.play_bgm(bgm_path, true, None, 1.0);
+        tracing::info!("BGM playback queued: {}", bgm_path);
+        self.bgm_started = true;
     }
 }
@@ -1,14 +1,18 @@
 //! UI children building logic for GameRootElement
 
 use super::element::GameRootElement;
+use super::quick_save::QUICK_LOAD_CONFIRM_MESSAGE;
 use crate::components::{
-    BacklogElement, CgGalleryElement, CgViewerElement, CharacterSpriteElement, ChoiceMenuElement,
-    ConfirmDialogElement, DialogueBoxElement, PauseMenuElement, QuickMenuElement,
-    SaveLoadMenuElement, SettingsMenuElement, TitleScreenElement,
+    AchievementListElement, BacklogElement, CgGalleryElement, CgViewerElement,
+    CharacterSpriteElement, ChoiceMenuElement, ConfirmDialogElement, DialogueBoxElement,
+    EndingScreenElement, LoadingScreenElement, NameInputElement, PauseMenuElement,
+    QuickMenuElement, SaveLoadMenuElement, SettingsMenuElement, TitleScreenElement,
 };
-use narrative_core::config::DialogueBoxConfig;
+use narrative_core::config::UserSettings;
 use narrative_core::{AssetRef, UnlockData};
 use narrative_engine::runtime::{AppState, InGameState};
+use narrative_engine::text::TypewriterEffect;
+use narrative_gui::framework::animation::AnimationContext;
 use std::sync::Arc;
 
 impl GameRootElement {
@@ -18,6 +22,8 @@ impl GameRootElement {
 
         // Get animation context from settings (loaded once per rebuild)
         let anim_ctx = self.animation_context();
+        self.toasts.set_animation_context(anim_ctx);
+        self.hud.set_animation_context(anim_ctx);
 
         // NOTE: Debug logging is controlled by RUST_LOG environment variable at runtime
         // Set RUST_LOG=narrative_app=debug to see these logs, or RUST_LOG=info to hide them
@@ -27,14 +33,26 @@ impl GameRootElement {
         );
 
         match &self.app_state {
+            AppState::Loading(loading) if loading.next.is_some() => {
+                tracing::debug!("Loading state - showing loading screen");
+                let mut loading_screen = LoadingScreenElement::new();
+                loading_screen.set_progress(loading.progress, &loading.current_task);
+                self.children.push(Box::new(loading_screen));
+            }
             AppState::Loading(_loading) => {
-                // TODO: Add loading screen UI (Phase 1.5 or later)
+                // TODO: Add startup loading screen UI (Phase 1.5 or later)
                 tracing::debug!("Loading state - no UI");
             }
             AppState::MainMenu(menu) => {
                 tracing::debug!("MainMenu state - showing title screen");
-                let title_screen =
-                    TitleScreenElement::new(menu.has_continue).with_animation_context(anim_ctx);
+                let mut title_screen = TitleScreenElement::new(menu.has_continue)
+                    .with_achievements(!self.achievements.is_empty())
+                    .with_animation_context(anim_ctx);
+                if let Some(metadata) = &self.game_metadata {
+                    title_screen = title_screen
+                        .with_title(metadata.title.clone())
+                        .with_version(metadata.version.clone());
+                }
                 self.children.push(Box::new(title_screen));
             }
             AppState::InGame(in_game_state) => {
@@ -153,9 +171,7 @@ impl GameRootElement {
                         }
 
                         // Set texture from cache or fallback to development texture
-                        // TODO(layered-sprites): Add support for layered sprite rendering
-                        // Current implementation only supports single texture per character.
-                        // For layered sprites, need to render multiple textures with blending.
+                        // Layered sprites are composited into a single texture at load time
                         if let Some(handle) = self.character_texture_cache.get(&char_info.sprite) {
                             let texture_id = handle.id();
                             sprite = sprite.with_texture(texture_id);
@@ -185,11 +201,20 @@ impl GameRootElement {
 
                         // Apply animation from current dialogue if the character is the speaker
                         if let Some(command) = runtime.get_current_command() {
-                            if let narrative_core::ScenarioCommand::Dialogue { dialogue } = command {
-                                if let narrative_core::Speaker::Character(speaker_id) = &dialogue.speaker {
+                            if let narrative_core::ScenarioCommand::Dialogue { dialogue } = command
+                            {
+                                if let narrative_core::Speaker::Character(speaker_id) =
+                                    &dialogue.speaker
+                                {
                                     if speaker_id == &char_info.character_id {
                                         if let Some(ref animation) = dialogue.animation {
-                                            sprite.start_animation(animation.clone());
+                                            if self.comfort_mode {
+                                                sprite.start_animation(
+                                                    animation.clone().with_reduced_shake(),
+                                                );
+                                            } else {
+                                                sprite.start_animation(animation.clone());
+                                            }
                                             tracing::info!(
                                                 "Started animation for '{}': {:?}",
                                                 char_info.character_id,
@@ -216,22 +241,24 @@ impl GameRootElement {
                 match in_game_state {
                     InGameState::Typing(typing) => {
                         tracing::debug!(
-                            "Typing state - creating DialogueBox (char_index: {}/{})",
-                            typing.char_index,
+                            "Typing state - creating DialogueBox (visible_chars: {}/{})",
+                            typing.visible_chars(),
                             typing.text.chars().count()
                         );
                         // Create dialogue box with typewriter effect
-                        // Use default DialogueBoxConfig
                         let mut dialogue_box =
-                            DialogueBoxElement::new(DialogueBoxConfig::default())
-                                .with_animation_context(anim_ctx);
+                            DialogueBoxElement::new(self.dialogue_box_config.clone())
+                                .with_animation_context(anim_ctx)
+                                .with_background_texture(self.dialogue_box_texture_id);
 
                         if let Some(speaker) = &typing.speaker {
                             dialogue_box.set_speaker(Some(Arc::from(speaker.as_str())));
                         }
 
                         dialogue_box.set_text(typing.text.clone());
-                        dialogue_box.set_visible_chars(typing.char_index);
+                        dialogue_box.set_spans(Arc::from(typing.typewriter.spans()));
+                        dialogue_box.set_translation(typing.translation.clone());
+                        dialogue_box.set_visible_chars(typing.visible_chars());
                         dialogue_box.set_auto_mode_enabled(self.config.gameplay.auto_mode_enabled);
                         dialogue_box.set_skip_mode_enabled(
                             self.config.gameplay.skip_mode_enabled,
@@ -239,10 +266,7 @@ impl GameRootElement {
                         );
 
                         // Add quick menu
-                        let mut quick_menu =
-                            QuickMenuElement::new().with_animation_context(anim_ctx);
-                        quick_menu.set_skip_active(self.config.gameplay.skip_mode_enabled);
-                        quick_menu.set_auto_active(self.config.gameplay.auto_mode_enabled);
+                        let quick_menu = self.quick_menu(anim_ctx);
 
                         // Only add UI elements if not hidden
                         if !self.ui_hidden {
@@ -259,17 +283,27 @@ impl GameRootElement {
                             && let Some(command) = runtime.get_current_command()
                             && let narrative_core::ScenarioCommand::Dialogue { dialogue } = command
                         {
-                            // Use default DialogueBoxConfig
                             let mut dialogue_box =
-                                DialogueBoxElement::new(DialogueBoxConfig::default())
-                                    .with_animation_context(anim_ctx);
+                                DialogueBoxElement::new(self.dialogue_box_config.clone())
+                                    .with_animation_context(anim_ctx)
+                                    .with_background_texture(self.dialogue_box_texture_id);
 
                             if let narrative_core::Speaker::Character(name) = &dialogue.speaker {
                                 dialogue_box.set_speaker(Some(Arc::from(name.as_str())));
                             }
 
-                            dialogue_box.set_text(Arc::from(dialogue.text.clone()));
-                            dialogue_box.set_visible_chars(dialogue.text.chars().count());
+                            let (text, translation) =
+                                dialogue.text_tracks(runtime.dialogue_languages());
+                            let variables = runtime.variables();
+                            let rich = TypewriterEffect::rich_text(&variables.substitute(text));
+                            dialogue_box.set_visible_chars(rich.text.chars().count());
+                            dialogue_box.set_text(Arc::from(rich.text));
+                            dialogue_box.set_spans(Arc::from(rich.spans));
+                            dialogue_box.set_translation(translation.map(|translation| {
+                                Arc::from(TypewriterEffect::strip_tags(
+                                    &variables.substitute(translation),
+                                ))
+                            }));
                             dialogue_box.set_text_complete(true);
                             dialogue_box
                                 .set_auto_mode_enabled(self.config.gameplay.auto_mode_enabled);
@@ -279,10 +313,7 @@ impl GameRootElement {
                             );
 
                             // Add quick menu
-                            let mut quick_menu =
-                                QuickMenuElement::new().with_animation_context(anim_ctx);
-                            quick_menu.set_skip_active(self.config.gameplay.skip_mode_enabled);
-                            quick_menu.set_auto_active(self.config.gameplay.auto_mode_enabled);
+                            let quick_menu = self.quick_menu(anim_ctx);
 
                             // Only add UI elements if not hidden
                             if !self.ui_hidden {
@@ -309,6 +340,9 @@ impl GameRootElement {
                                 .collect(),
                         )
                         .with_animation_context(anim_ctx);
+                        if let Some(runtime) = &self.scenario_runtime {
+                            choice_menu.set_disabled(runtime.displayed_choices_disabled());
+                        }
                         choice_menu.set_selected_index(choice_state.selected);
                         self.children.push(Box::new(choice_menu));
                     }
@@ -316,11 +350,19 @@ impl GameRootElement {
                         // TODO: Add transition effects (Phase 1.5 or later)
                     }
                     InGameState::PlayingEffect(_effect) => {
-                        // TODO: Add visual effects (Phase 1.5 or later)
+                        // Shakes and flashes are drawn by GameRootElement
                     }
                     InGameState::Waiting(_wait) => {
                         // Wait state typically doesn't show UI
                     }
+                    InGameState::PlayingVideo(_video) => {
+                        // The video is painted by GameRootElement, without UI
+                    }
+                    InGameState::InputtingName(name_input) => {
+                        tracing::debug!("InputtingName state - showing name entry");
+                        self.children
+                            .push(Box::new(NameInputElement::new(name_input)));
+                    }
                     InGameState::PauseMenu(_pause) => {
                         // If showing confirmation dialog, only show the dialog
                         if self.showing_title_confirm {
@@ -333,8 +375,18 @@ impl GameRootElement {
                         } else {
                             // Show pause menu normally
                             tracing::debug!("PauseMenu state - showing pause menu");
-                            let pause_menu =
-                                PauseMenuElement::new().with_animation_context(anim_ctx);
+                            let can_return_to_choice = self.config.gameplay.allow_return_to_choice
+                                && self
+                                    .scenario_runtime
+                                    .as_ref()
+                                    .is_some_and(|runtime| runtime.can_return_to_choice());
+                            let pause_menu = PauseMenuElement::new()
+                                .with_return_to_choice(can_return_to_choice)
+                                .with_animation_context(anim_ctx)
+                                .with_quick_settings(
+                                    self.current_user_settings(),
+                                    self.audio_queue.clone(),
+                                );
                             self.children.push(Box::new(pause_menu));
                         }
                     }
@@ -349,7 +401,15 @@ impl GameRootElement {
                             save_load_state.is_save_mode,
                             save_load_state.layout_mode,
                         )
+                        .with_slot_config(self.save_slots)
                         .with_animation_context(anim_ctx);
+                        // Flag saves that don't match the running scenario
+                        let save_load_menu = match &self.scenario_runtime {
+                            Some(runtime) if !save_load_state.is_save_mode => {
+                                save_load_menu.with_compatibility_check(runtime.scenario())
+                            }
+                            _ => save_load_menu,
+                        };
                         self.children.push(Box::new(save_load_menu));
                     }
                     InGameState::Backlog(_backlog) => {
@@ -358,9 +418,19 @@ impl GameRootElement {
                             // Get backlog entries (newest first)
                             let entries: Vec<_> =
                                 runtime.backlog().entries_reversed().cloned().collect();
-
-                            let backlog_element =
-                                BacklogElement::new(entries).with_animation_context(anim_ctx);
+                            // Screen shown with each entry, for hover previews
+                            let snapshots = entries
+                                .iter()
+                                .map(|entry| {
+                                    runtime
+                                        .display_snapshot_at(&entry.scene_id, entry.command_index)
+                                        .cloned()
+                                })
+                                .collect();
+
+                            let backlog_element = BacklogElement::new(entries)
+                                .with_snapshots(snapshots)
+                                .with_animation_context(anim_ctx);
                             self.children.push(Box::new(backlog_element));
                         }
                     }
@@ -428,34 +498,90 @@ impl GameRootElement {
 
                         self.children.push(Box::new(viewer));
                     }
+                    InGameState::Achievements(achievements_state) => {
+                        tracing::debug!("Achievements state - creating achievement list");
+                        let unlock_data_arc = self
+                            .unlock_data
+                            .lock()
+                            .map(|data| Arc::new((*data).clone()))
+                            .unwrap_or_else(|_| Arc::new(UnlockData::new()));
+
+                        let list = AchievementListElement::new(
+                            achievements_state.clone(),
+                            Arc::clone(&self.achievements),
+                            unlock_data_arc,
+                        )
+                        .with_animation_context(anim_ctx);
+
+                        self.children.push(Box::new(list));
+                    }
+                    InGameState::Ending(ending_state) => {
+                        tracing::debug!("Ending state - showing ending screen");
+                        let ending_screen = EndingScreenElement::new(ending_state.clone())
+                            .with_animation_context(anim_ctx);
+                        self.children.push(Box::new(ending_screen));
+                    }
+                }
+
+                // The quick load confirmation covers the dialogue
+                if self.showing_quick_load_confirm {
+                    tracing::debug!("Showing confirmation dialog for quick load");
+                    let confirm_dialog = ConfirmDialogElement::new(QUICK_LOAD_CONFIRM_MESSAGE)
+                        .with_labels("Load", "Cancel")
+                        .with_animation_context(anim_ctx);
+                    self.children.push(Box::new(confirm_dialog));
                 }
             }
             AppState::Settings(_settings) => {
                 tracing::debug!("Settings state - creating settings menu");
 
-                // Load user settings from RON file, or create from current config
-                let user_settings =
-                    narrative_core::config::UserSettings::load("assets/config/settings.ron")
-                        .unwrap_or_else(|e| {
-                            tracing::debug!("Failed to load settings.ron, using defaults: {}", e);
-                            // Create default settings from current engine config
-                            let mut settings = narrative_core::config::UserSettings::default();
-                            settings.audio.master_volume = self.config.audio.master_volume;
-                            settings.audio.bgm_volume = self.config.audio.music_volume;
-                            settings.audio.se_volume = self.config.audio.sound_volume;
-                            settings.audio.voice_volume = self.config.audio.voice_volume;
-                            settings.display.fullscreen = self.config.window.fullscreen;
-                            settings.display.resolution =
-                                (self.config.window.width, self.config.window.height);
-                            settings
-                        });
-
-                let settings_menu =
-                    SettingsMenuElement::new(user_settings, Arc::clone(&self.audio_manager))
-                        .with_animation_context(anim_ctx);
+                let settings_menu = SettingsMenuElement::new(
+                    self.current_user_settings(),
+                    self.audio_queue.clone(),
+                )
+                .with_animation_context(anim_ctx);
 
                 self.children.push(Box::new(settings_menu));
             }
         }
     }
+
+    /// Quick menu with the theme's buttons and the current skip/auto state
+    fn quick_menu(&self, anim_ctx: AnimationContext) -> QuickMenuElement {
+        let layout = self
+            .ui_theme
+            .as_ref()
+            .and_then(|theme| theme.quick_menu.clone())
+            .unwrap_or_default();
+        let mut quick_menu = QuickMenuElement::new()
+            .with_layout(&layout, &self.quick_menu_icon_textures)
+            .with_animation_context(anim_ctx)
+            .with_dialogue_box_config(self.dialogue_box_config.clone());
+        quick_menu.set_skip_active(self.config.gameplay.skip_mode_enabled);
+        quick_menu.set_auto_active(self.config.gameplay.auto_mode_enabled);
+        quick_menu
+    }
+
+    /// Current user settings
+    ///
+    /// Unsaved quick settings take precedence, then `settings.ron`; if that
+    /// cannot be loaded, settings are created from the current engine config.
+    fn current_user_settings(&self) -> UserSettings {
+        if let Some(settings) = &self.unsaved_quick_settings {
+            return settings.clone();
+        }
+
+        UserSettings::load("assets/config/settings.ron").unwrap_or_else(|e| {
+            tracing::debug!("Failed to load settings.ron, using defaults: {}", e);
+            // Create default settings from current engine config
+            let mut settings = UserSettings::default();
+            settings.audio.master_volume = self.config.audio.master_volume;
+            settings.audio.bgm_volume = self.config.audio.music_volume;
+            settings.audio.se_volume = self.config.audio.sound_volume;
+            settings.audio.voice_volume = self.config.audio.voice_volume;
+            settings.display.fullscreen = self.config.window.fullscreen;
+            settings.display.resolution = (self.config.window.width, self.config.window.height);
+            settings
+        })
+    }
 }
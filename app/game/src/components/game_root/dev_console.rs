@@ -0,0 +1,88 @@
+//! Developer console for GameRootElement
+
+use super::element::GameRootElement;
+use crate::components::DevConsoleAction;
+use narrative_core::{FlagId, SceneId, VariableId};
+use narrative_engine::runtime::AppState;
+
+impl GameRootElement {
+    /// Whether the developer console can be opened: in debug builds, or with
+    /// `development.debug_mode` set
+    pub(super) fn dev_console_allowed(&self) -> bool {
+        cfg!(debug_assertions) || self.config.development.debug_mode
+    }
+
+    /// Open the developer console (F12)
+    ///
+    /// Returns false outside the game, where there is no scenario to list.
+    pub(super) fn show_dev_console(&mut self) -> bool {
+        if !self.dev_console_allowed() || !matches!(self.app_state, AppState::InGame(_)) {
+            return false;
+        }
+        let Some(runtime) = &self.scenario_runtime else {
+            return false;
+        };
+        self.dev_console.show(runtime);
+        true
+    }
+
+    /// Apply the action chosen in the developer console
+    pub(super) fn apply_dev_console_action(&mut self) {
+        let Some(action) = self.dev_console.take_action() else {
+            return;
+        };
+        self.stop_replay("the developer console changed the game");
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return;
+        };
+        tracing::info!("Developer console: {:?}", action);
+        match action {
+            DevConsoleAction::SetFlag(name, value) => {
+                runtime.flags_mut().set(FlagId::new(name), value);
+            }
+            DevConsoleAction::SetVariable(name, value) => {
+                runtime.variables_mut().set(VariableId::new(name), value);
+            }
+            DevConsoleAction::JumpToScene(scene_id) => {
+                self.jump_to_scene(&scene_id);
+                return;
+            }
+        }
+        self.dev_console.refresh(runtime);
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+    }
+
+    /// Continue at the start of a scene, leaving any menu open over the game
+    fn jump_to_scene(&mut self, scene_id: &SceneId) {
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return;
+        };
+        if let Err(e) = runtime.jump_to_scene(scene_id) {
+            tracing::error!("Failed to jump to scene '{}': {}", scene_id.as_str(), e);
+            return;
+        }
+        let new_state = Self::enter_current_command(runtime)
+            .or_else(|| Self::execute_and_transition(runtime, &self.audio_queue));
+
+        self.config.gameplay.skip_mode_enabled = false;
+        self.previous_in_game_state = None;
+        if self.video_player.take().is_some() {
+            self.audio_manager
+                .get_mut()
+                .unwrap_or_else(|e| e.into_inner())
+                .stop_soundtrack();
+            self.subtitles.stop();
+        }
+        match new_state {
+            Some(state) => {
+                if let Some(in_game_state) = self.app_state.in_game_state_mut() {
+                    *in_game_state = state;
+                }
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            None => self.handle_scenario_end(),
+        }
+    }
+}
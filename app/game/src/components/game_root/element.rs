@@ -1,13 +1,34 @@
 //! GameRootElement struct definition and constructors
 
-use narrative_core::config::UserSettings;
-use narrative_core::{AssetRef, CgRegistry, UnlockData};
-use narrative_engine::asset::TextureCache;
+use super::{achievements, audio, hud, particles, theme};
+use crate::components::{
+    DevConsoleElement, HelpOverlayElement, HudElement, QuickMenuHandler, SubtitleElement,
+    SubtitleStyle, ToastManagerElement, ToastQueue,
+};
+use narrative_core::config::{
+    AccessibilitySettings, Bindings, DialogueBoxConfig, GamepadSettings, ProfileSettings,
+    UserSettings,
+};
+use narrative_core::{
+    AchievementRegistry, AssetRef, CgRegistry, DialogueLanguages, GameMetadata, ProjectManifest,
+    SaveSlotConfig, SceneId, SubtitleTrack, UiThemeDef, UnlockData, VoiceResolver,
+};
+use narrative_engine::app::Replay;
+use narrative_engine::asset::{
+    AssetPreloader, DecodedImageCache, ParticleEffectRegistry, TextureCache,
+};
+use narrative_engine::render::ParticleSystem;
 use narrative_engine::runtime::{AppState, InGameState, MainMenuState, ScenarioRuntime};
-use narrative_engine::save::SaveManager;
-use narrative_engine::{AudioManager, EngineConfig};
+use narrative_engine::save::{DisplaySnapshot, SaveManager};
+use narrative_engine::text::FontFallbackChain;
+use narrative_engine::video::VideoPlayer;
+use narrative_engine::{
+    AudioManager, AudioQueue, Engine, EngineConfig, InputHandler, TextToSpeech,
+};
+use narrative_gui::framework::MenuId;
 use narrative_gui::framework::element::{Element, ElementId, WindowOperation};
 use std::collections::HashMap;
+use std::sync::mpsc::Receiver;
 use std::sync::{Arc, Mutex};
 use taffy::NodeId;
 
@@ -45,16 +66,32 @@ pub struct GameRootElement {
     pub(super) skip_mode_toggle_pressed: bool,
     /// Backlog key pressed this frame
     pub(super) backlog_pressed: bool,
+    /// Quick save key or quick menu button pressed this frame
+    pub(super) quick_save_pressed: bool,
+    /// Quick load key or quick menu button pressed this frame
+    pub(super) quick_load_pressed: bool,
+    /// Rollback (mouse wheel up / Page Up) requested this frame
+    pub(super) rollback_pressed: bool,
+    /// Roll forward (mouse wheel down / Page Down) requested this frame
+    pub(super) roll_forward_pressed: bool,
     /// Background texture ID (set from Window after loading)
     pub(super) background_texture_id: Option<u64>,
     /// Character texture ID (set from Window after loading)
     pub(super) character_texture_id: Option<u64>,
     /// Currently displayed background texture ID (dynamically updated)
     pub(super) current_background_texture_id: Option<u64>,
-    /// Previous background texture ID (used for crossfade transitions)
-    pub(super) previous_background_texture_id: Option<u64>,
     /// Background texture cache (AssetRef -> TextureId)
-    pub(super) background_texture_cache: HashMap<AssetRef, u64>,
+    ///
+    /// Capacity is configured via EngineConfig.graphics.background_cache_capacity
+    pub(super) background_texture_cache: TextureCache,
+    /// Background textures evicted from the cache, released on the next texture update
+    pub(super) evicted_background_textures: Vec<u64>,
+    /// Decoded background images, re-uploaded when their texture was evicted
+    pub(super) decoded_image_cache: DecodedImageCache,
+    /// Images and sounds of upcoming commands, loaded on a background thread
+    pub(super) asset_preloader: AssetPreloader,
+    /// Playback position the preloader last scanned ahead of
+    pub(super) preloaded_position: Option<(String, usize)>,
     /// Currently displayed background AssetRef (for change detection)
     pub(super) displayed_background: Option<AssetRef>,
     /// Pending background to load in next frame
@@ -63,10 +100,12 @@ pub struct GameRootElement {
     pub(super) current_cg_texture_id: Option<u64>,
     /// Size of current CG texture (width, height) for aspect ratio calculation
     pub(super) current_cg_texture_size: Option<(u32, u32)>,
-    /// Previous CG texture ID (used for crossfade transitions)
-    pub(super) previous_cg_texture_id: Option<u64>,
-    /// Size of previous CG texture for crossfade transitions
-    pub(super) previous_cg_texture_size: Option<(u32, u32)>,
+    /// Whether the scene transition being played has captured its outgoing frame
+    pub(super) transition_started: bool,
+    /// Outgoing frame of a scene transition, composed at the next texture update
+    pub(super) pending_transition_snapshot: Option<DisplaySnapshot>,
+    /// Outgoing frame the scene transition blends from (window-sized texture)
+    pub(super) transition_texture_id: Option<u64>,
     /// CG texture cache (AssetRef -> (TextureId, Size))
     pub(super) cg_texture_cache: HashMap<AssetRef, (u64, (u32, u32))>,
     /// Currently displayed CG AssetRef (for change detection)
@@ -75,20 +114,114 @@ pub struct GameRootElement {
     pub(super) pending_cg: Option<AssetRef>,
     /// CG thumbnail texture cache (CgId -> TextureId) for gallery display
     pub(super) cg_thumbnail_cache: HashMap<String, u64>,
-    /// Audio manager for BGM/SE/Voice playback
-    pub(super) audio_manager: Arc<Mutex<AudioManager>>,
+    /// Preview thumbnail for the hovered backlog entry or selected save slot
+    pub(super) preview_thumbnail: Option<(DisplaySnapshot, u64)>,
+    /// Saved slots whose thumbnail is rendered on the next texture update
+    pub(super) pending_save_thumbnails: Vec<(usize, DisplaySnapshot)>,
+    /// Quick save slot whose toast waits for its thumbnail to be rendered
+    pub(super) pending_quick_save_toast: Option<usize>,
+    /// Thumbnail texture of the last quick save, shown in its toast
+    pub(super) quick_save_thumbnail: Option<u64>,
+    /// Audio manager for BGM/SE/Voice playback (accessed only in `tick`)
+    pub(super) audio_manager: Mutex<AudioManager>,
+    /// Queue for audio commands, applied to `audio_manager` once per frame
+    pub(super) audio_queue: AudioQueue,
     /// Save manager for save/load operations
     pub(super) save_manager: Arc<Mutex<SaveManager>>,
     /// Total play time in seconds (accumulated across sessions)
     pub(super) total_play_time_secs: u64,
     /// Accumulator for fractional seconds (for accurate play time tracking)
     pub(super) play_time_accumulator: f32,
+    /// Play time in seconds since the player last saved or loaded
+    pub(super) unsaved_play_secs: f32,
     /// Flag to track if BGM has been started
     pub(super) bgm_started: bool,
     /// Pending window operations (e.g., close window)
     pub(super) window_operations: Vec<WindowOperation>,
     /// Flag to track if showing confirmation dialog for returning to title
     pub(super) showing_title_confirm: bool,
+    /// Flag to track if showing confirmation dialog for loading the quick save
+    pub(super) showing_quick_load_confirm: bool,
+    /// Settings changed through the pause menu quick settings, saved when the menu closes
+    pub(super) unsaved_quick_settings: Option<UserSettings>,
+    /// Game metadata (title and version shown on the title screen)
+    pub(super) game_metadata: Option<GameMetadata>,
+    /// Project manifest for multi-scenario projects
+    pub(super) project: Option<ProjectManifest>,
+    /// Save slot layout (autosave, quick save and manual slots)
+    pub(super) save_slots: SaveSlotConfig,
+    /// Dialogue box configuration with the player's textbox settings applied
+    pub(super) dialogue_box_config: DialogueBoxConfig,
+    /// UI theme selected by `config.ui.theme`
+    pub(super) ui_theme: Option<UiThemeDef>,
+    /// Theme dialogue box texture ID (set after loading)
+    pub(super) dialogue_box_texture_id: Option<u64>,
+    /// Theme quick menu icon texture IDs by icon path (set after loading)
+    pub(super) quick_menu_icon_textures: HashMap<String, u64>,
+    /// Handler for the theme's custom quick menu entries
+    pub(super) quick_menu_handler: Option<QuickMenuHandler>,
+    /// Steam connection (achievements, cloud saves and rich presence)
+    #[cfg(feature = "steam")]
+    pub(super) steam: Option<narrative_engine::steam::Steam>,
+    /// Scene shown as Steam rich presence
+    #[cfg(feature = "steam")]
+    pub(super) rich_presence_scene: Option<String>,
+    /// Scenario file being played, reloaded when it changes (hot reload)
+    pub(super) scenario_file: Option<std::path::PathBuf>,
+    /// Watcher for the scenario file (`development.hot_reload`)
+    #[cfg(feature = "hot-reload")]
+    pub(super) scenario_watcher: Option<narrative_engine::asset::ScenarioWatcher>,
+    /// The scenario file changed; it is reloaded once play resumes
+    pub(super) scenario_reload_pending: bool,
+    /// Theme texture and font need (re)loading in the next frame
+    pub(super) theme_assets_pending: bool,
+    /// Comfort mode (instant text, fades instead of flashes, reduced shaking)
+    pub(super) comfort_mode: bool,
+    /// Subtitles for sound effects and videos that ship with a subtitle file
+    pub(super) subtitles: SubtitleElement,
+    /// Sidecar subtitles of each sound or video preloaded or played so far,
+    /// by path (empty for those without a subtitle file)
+    pub(super) subtitle_tracks: HashMap<std::path::PathBuf, SubtitleTrack>,
+    /// Keyboard shortcut help (F1 or `?`)
+    pub(super) help_overlay: HelpOverlayElement,
+    /// Developer console to jump to scenes and edit flags and variables (F12)
+    pub(super) dev_console: DevConsoleElement,
+    /// Replay of the playthrough being recorded, written when the scenario ends
+    pub(super) replay: Option<Replay>,
+    /// Self-voicing: dialogue is read aloud with text-to-speech
+    pub(super) self_voicing: bool,
+    /// Text-to-speech for self-voicing
+    pub(super) tts: TextToSpeech,
+    /// Dialogue line last read aloud (scene, command index)
+    pub(super) spoken_line: Option<(SceneId, usize)>,
+    /// BGM is ducked while text-to-speech speaks
+    pub(super) bgm_ducked_for_speech: bool,
+    /// Dialogue line whose voice was last started (scene, command index)
+    pub(super) voiced_line: Option<(SceneId, usize)>,
+    /// A voice line is playing (updated after audio commands are applied)
+    pub(super) voice_playing: bool,
+    /// Languages dialogue is shown in, passed to each scenario runtime
+    pub(super) dialogue_languages: DialogueLanguages,
+    /// Voice file naming convention, passed to each scenario runtime
+    pub(super) voice_resolver: Option<VoiceResolver>,
+    /// Fonts text is drawn with, checked against each scenario in dev builds
+    pub(super) font_fallback: FontFallbackChain,
+    /// Keys and mouse buttons bound to each in-game action
+    pub(super) bindings: Bindings,
+    /// Gamepad state, fed into the keyboard input path each frame
+    pub(super) gamepad: InputHandler,
+    /// Player name and pronouns, given to each new game as `player.*` variables
+    pub(super) player_profile: ProfileSettings,
+    /// Video of the PlayingVideo state (opened on its first update)
+    pub(super) video_player: Option<VideoPlayer>,
+    /// Texture holding the video frame on screen
+    pub(super) video_texture_id: Option<u64>,
+    /// Particle effects that ShowEffect can start
+    pub(super) particle_effects: ParticleEffectRegistry,
+    /// Particles of the running effects
+    pub(super) particles: ParticleSystem,
+    /// Commands from the native menu bar
+    pub(super) menu_commands: Option<Mutex<Receiver<MenuId>>>,
     /// Flag to track if UI is hidden (for background appreciation)
     ///
     /// This is only active during Typing/WaitingInput states and automatically
@@ -99,6 +232,14 @@ pub struct GameRootElement {
     pub(super) cg_registry: Arc<CgRegistry>,
     /// Global unlock data (persists across saves)
     pub(super) unlock_data: Arc<Mutex<UnlockData>>,
+    /// Achievements of the game, from the achievement manifest
+    pub(super) achievements: Arc<AchievementRegistry>,
+    /// Toast notifications (achievements, save reminder, ...)
+    pub(super) toasts: ToastManagerElement,
+    /// HUD widgets showing flags and variables, from the HUD manifest
+    pub(super) hud: HudElement,
+    /// Whether the HUD's icons still need to be loaded
+    pub(super) hud_icons_pending: bool,
     /// Current window size (width, height) for responsive layout
     pub(super) window_size: (f32, f32),
     /// Last seen character states for transition optimization
@@ -106,10 +247,18 @@ pub struct GameRootElement {
     pub(super) last_seen_characters: HashMap<String, (AssetRef, narrative_core::CharacterPosition)>,
     /// Character texture cache with LRU eviction
     /// Capacity is configured via EngineConfig.graphics.character_cache_capacity
-    /// TODO(layered-sprites): Change value type to Vec<TextureHandle> for layered sprites
+    /// Layered sprites are composited into one texture, keyed by their layered reference
     pub(super) character_texture_cache: TextureCache,
     /// Pending character textures to load in next frame
     pub(super) pending_character_textures: Vec<(String, AssetRef)>,
+    /// Seconds the title screen has gone without input
+    pub(super) title_idle_secs: f32,
+    /// The attract mode demo is playing
+    pub(super) attract_mode: bool,
+    /// Player's auto mode setting, restored when the demo ends
+    pub(super) auto_mode_before_attract: bool,
+    /// Seconds the demo has shown the current choice
+    pub(super) attract_choice_elapsed: f32,
 }
 
 impl GameRootElement {
@@ -122,40 +271,69 @@ impl GameRootElement {
     pub(super) const FRAME_TIME: f32 = 1.0 / 60.0;
 
     /// Create a new game root element
-    pub fn new(config: EngineConfig) -> Self {
-        // Load user settings to get audio configuration
-        let audio_config = match UserSettings::load("assets/config/settings.ron") {
+    pub fn new(mut config: EngineConfig) -> Self {
+        let ui_theme = theme::load_ui_theme(&config);
+        let themed_dialogue_box_config =
+            theme::themed_dialogue_box_config(&config, ui_theme.as_ref());
+        let subtitle_style = SubtitleStyle::from_theme(ui_theme.as_ref());
+
+        // Load user settings to get audio, textbox and accessibility configuration
+        let (
+            dialogue_box_config,
+            accessibility,
+            dialogue_languages,
+            bindings,
+            gamepad_settings,
+            player_profile,
+        ) = match UserSettings::load("assets/config/settings.ron") {
             Ok(settings) => {
                 tracing::info!("Loaded user settings from assets/config/settings.ron");
                 let core_config = settings.to_audio_config();
                 // Convert narrative_core::AudioConfig to narrative_engine::app::AudioConfig
-                narrative_engine::app::AudioConfig {
+                config.audio = narrative_engine::app::AudioConfig {
                     master_volume: core_config.master_volume,
                     music_volume: core_config.bgm_volume,
                     sound_volume: core_config.se_volume,
                     voice_volume: core_config.voice_volume,
                     enabled: core_config.enabled,
-                }
+                    ..config.audio
+                };
+                let dialogue_box_config = settings.textbox.apply_to(themed_dialogue_box_config);
+                (
+                    dialogue_box_config,
+                    settings.accessibility,
+                    settings.text.dialogue_languages(),
+                    settings.bindings,
+                    settings.gamepad,
+                    settings.profile,
+                )
             }
             Err(e) => {
                 tracing::debug!("Could not load user settings, using defaults: {}", e);
-                narrative_engine::app::AudioConfig::default()
+                (
+                    themed_dialogue_box_config,
+                    AccessibilitySettings::default(),
+                    DialogueLanguages::default(),
+                    Bindings::default(),
+                    GamepadSettings::default(),
+                    ProfileSettings::default(),
+                )
             }
         };
 
-        // Initialize audio manager with user-configured volumes
-        let audio_manager = match AudioManager::with_config(audio_config) {
-            Ok(manager) => {
-                tracing::info!("AudioManager initialized successfully with user settings");
-                Arc::new(Mutex::new(manager))
-            }
-            Err(e) => {
-                tracing::error!("Failed to initialize AudioManager: {}", e);
-                tracing::warn!("Running in audio-disabled mode - audio will not play");
-                // Create a disabled audio manager that will continue to work without audio
-                Arc::new(Mutex::new(AudioManager::disabled()))
-            }
-        };
+        // Initialize engine subsystems with user-configured volumes. The GUI
+        // framework owns the window, so the engine is built without a renderer.
+        let Engine {
+            config,
+            audio: mut audio_manager,
+            input: mut gamepad,
+            save: save_manager,
+            ..
+        } = Engine::headless(config);
+        gamepad.set_gamepad_settings(gamepad_settings);
+        audio::load_se_pools(&config, &mut audio_manager);
+        audio::load_bgm_manifest(&config, &mut audio_manager);
+        let audio_queue = audio_manager.queue();
 
         // Load CG definitions from TOML
         // TODO: Add load_cg_definitions to AssetLoader
@@ -219,6 +397,15 @@ impl GameRootElement {
 
         // Cache capacity before moving config
         let character_cache_capacity = config.graphics.character_cache_capacity;
+        let background_cache_capacity = config.graphics.background_cache_capacity;
+        let image_cache_budget = config.graphics.image_cache_budget_mb * 1024 * 1024;
+        let preload_budget = config.graphics.preload_budget_mb * 1024 * 1024;
+        let particle_effects = particles::load_particle_effects(&config);
+        let achievements = Arc::new(achievements::load_achievements(&config));
+        let hud = HudElement::new(&hud::load_hud(&config));
+        let hud_icons_pending = hud.icon_paths().next().is_some();
+        let mut tts = TextToSpeech::system();
+        tts.set_rate(accessibility.speech_rate);
 
         Self {
             id: ElementId::new(),
@@ -235,38 +422,103 @@ impl GameRootElement {
             auto_mode_toggle_pressed: false,
             skip_mode_toggle_pressed: false,
             backlog_pressed: false,
+            quick_save_pressed: false,
+            quick_load_pressed: false,
+            rollback_pressed: false,
+            roll_forward_pressed: false,
             background_texture_id: None,
             character_texture_id: None,
             current_background_texture_id: None,
-            previous_background_texture_id: None,
-            background_texture_cache: HashMap::new(),
+            background_texture_cache: TextureCache::with_capacity(background_cache_capacity)
+                .expect("Invalid background cache capacity"),
+            evicted_background_textures: Vec::new(),
+            decoded_image_cache: DecodedImageCache::with_budget(image_cache_budget),
+            asset_preloader: AssetPreloader::new("", preload_budget),
+            preloaded_position: None,
             displayed_background: None,
             pending_background: None,
             current_cg_texture_id: None,
             current_cg_texture_size: None,
-            previous_cg_texture_id: None,
-            previous_cg_texture_size: None,
+            transition_started: false,
+            pending_transition_snapshot: None,
+            transition_texture_id: None,
             cg_texture_cache: HashMap::new(),
             displayed_cg: None,
             pending_cg: None,
             cg_thumbnail_cache: HashMap::new(),
-            audio_manager,
-            save_manager: Arc::new(Mutex::new(SaveManager::new(std::path::PathBuf::from(
-                "saves",
-            )))),
+            preview_thumbnail: None,
+            pending_save_thumbnails: Vec::new(),
+            pending_quick_save_toast: None,
+            quick_save_thumbnail: None,
+            audio_manager: Mutex::new(audio_manager),
+            audio_queue,
+            save_manager: Arc::new(Mutex::new(save_manager)),
             total_play_time_secs: 0,
             play_time_accumulator: 0.0,
+            unsaved_play_secs: 0.0,
             bgm_started: false,
             window_operations: Vec::new(),
             showing_title_confirm: false,
+            showing_quick_load_confirm: false,
+            unsaved_quick_settings: None,
+            game_metadata: None,
+            project: None,
+            save_slots: SaveSlotConfig::default(),
+            dialogue_box_config,
+            theme_assets_pending: ui_theme.is_some(),
+            ui_theme,
+            dialogue_box_texture_id: None,
+            quick_menu_icon_textures: HashMap::new(),
+            quick_menu_handler: None,
+            #[cfg(feature = "steam")]
+            steam: None,
+            #[cfg(feature = "steam")]
+            rich_presence_scene: None,
+            scenario_file: None,
+            #[cfg(feature = "hot-reload")]
+            scenario_watcher: None,
+            scenario_reload_pending: false,
+            comfort_mode: accessibility.comfort_mode,
+            subtitles: SubtitleElement::new()
+                .with_enabled(accessibility.subtitles)
+                .with_style(subtitle_style),
+            subtitle_tracks: HashMap::new(),
+            help_overlay: HelpOverlayElement::new(),
+            dev_console: DevConsoleElement::new(),
+            replay: None,
+            self_voicing: accessibility.self_voicing,
+            tts,
+            spoken_line: None,
+            bgm_ducked_for_speech: false,
+            voiced_line: None,
+            voice_playing: false,
+            dialogue_languages,
+            voice_resolver: None,
+            font_fallback: FontFallbackChain::default(),
+            bindings,
+            gamepad,
+            player_profile,
+            video_player: None,
+            video_texture_id: None,
+            particle_effects,
+            particles: ParticleSystem::new(),
+            menu_commands: None,
             ui_hidden: false,
             cg_registry,
             unlock_data,
+            achievements,
+            toasts: ToastManagerElement::new(),
+            hud_icons_pending,
+            hud,
             window_size: (1280.0, 720.0), // Default, updated in layout()
             last_seen_characters: HashMap::new(),
             character_texture_cache: TextureCache::with_capacity(character_cache_capacity)
                 .expect("Invalid character cache capacity"),
             pending_character_textures: Vec::new(),
+            title_idle_secs: 0.0,
+            attract_mode: false,
+            auto_mode_before_attract: false,
+            attract_choice_elapsed: 0.0,
         }
     }
 
@@ -299,6 +551,69 @@ impl GameRootElement {
         Self::new(config)
     }
 
+    /// Set the game metadata shown on the title screen
+    pub fn with_game_metadata(mut self, metadata: GameMetadata) -> Self {
+        self.game_metadata = Some(metadata);
+        self.children_dirty = true;
+        self
+    }
+
+    /// Use a project manifest to resolve scenarios by ID
+    ///
+    /// New games start with the manifest's start scenario instead of
+    /// `EngineConfig::start_scenario`.
+    pub fn with_project(mut self, project: ProjectManifest) -> Self {
+        self.project = Some(project);
+        self
+    }
+
+    /// Match voice files to dialogue lines by name
+    ///
+    /// Lines without a `PlayVoice` command play the file the resolver names.
+    pub fn with_voice_resolver(mut self, resolver: VoiceResolver) -> Self {
+        self.voice_resolver = Some(resolver);
+        self
+    }
+
+    /// Set the font fallback chain
+    ///
+    /// With the `dev` feature, characters of a started scenario that no font
+    /// of the chain can render are logged as warnings.
+    pub fn with_font_fallback(mut self, chain: FontFallbackChain) -> Self {
+        self.font_fallback = chain;
+        self
+    }
+
+    /// Set the save slot layout
+    ///
+    /// Quick save writes the first quick save slot; reserved slots can't be
+    /// overwritten or deleted from the save menu.
+    pub fn with_save_slots(mut self, save_slots: SaveSlotConfig) -> Self {
+        self.save_slots = save_slots;
+        self
+    }
+
+    /// Receive native menu commands (Save, Load, Preferences, Toggle Fullscreen)
+    ///
+    /// Pair with `App::with_menu_handler` forwarding each `MenuId` into the sender.
+    pub fn with_menu_commands(mut self, receiver: Receiver<MenuId>) -> Self {
+        self.menu_commands = Some(Mutex::new(receiver));
+        self
+    }
+
+    /// Handle the UI theme's custom quick menu entries
+    ///
+    /// The handler is called with the entry's ID when its button is clicked,
+    /// and can act on the scenario runtime (set flags, jump to scenes, ...).
+    /// Without a handler, custom entries do nothing.
+    pub fn with_quick_menu_handler(
+        mut self,
+        handler: impl FnMut(&str, &mut ScenarioRuntime) + Send + Sync + 'static,
+    ) -> Self {
+        self.quick_menu_handler = Some(Box::new(handler));
+        self
+    }
+
     /// Load or reload a scenario at runtime
     ///
     /// This method allows loading a new scenario while the game is running.
@@ -329,6 +644,15 @@ impl GameRootElement {
 
         // Load the scenario runtime
         let mut runtime = ScenarioRuntime::from_toml(path.as_ref())?;
+        runtime.set_dialogue_languages(self.dialogue_languages.clone());
+        if let Some(resolver) = &self.voice_resolver {
+            runtime.set_voice_resolver(resolver.clone());
+        }
+        #[cfg(feature = "scripting")]
+        runtime.set_script_cache_capacity(self.config.gameplay.script_cache_capacity);
+        runtime
+            .variables_mut()
+            .set_player_profile(&self.player_profile);
 
         // Start the runtime
         runtime.start()?;
@@ -336,6 +660,7 @@ impl GameRootElement {
         // Reset to main menu state with the new scenario
         self.app_state = AppState::MainMenu(MainMenuState::default());
         self.scenario_runtime = Some(runtime);
+        self.set_scenario_file(path.as_ref());
         self.previous_in_game_state = None;
         tracing::debug!("children_dirty set at line {}", line!());
         self.children_dirty = true;
@@ -345,6 +670,17 @@ impl GameRootElement {
         Ok(())
     }
 
+    /// Current application state (title, settings, in-game, ...)
+    pub fn app_state(&self) -> &AppState {
+        &self.app_state
+    }
+
+    /// Handle for showing toast notifications, e.g. from a quick menu handler
+    /// or another thread
+    pub fn toast_queue(&self) -> ToastQueue {
+        self.toasts.queue()
+    }
+
     /// Set texture IDs for default game assets
     ///
     /// This should be called after the Window loads the default assets
@@ -1,6 +1,7 @@
 //! Tests for GameRootElement public API
 
 use super::element::GameRootElement;
+use narrative_core::types::Color;
 use narrative_engine::EngineConfig;
 use narrative_engine::runtime::AppState;
 
@@ -71,3 +72,1081 @@ fn test_load_scenario_error_handling() {
     // Should return an error, not panic
     assert!(result.is_err());
 }
+
+#[test]
+fn test_menu_commands_on_title_screen() {
+    use narrative_engine::runtime::MainMenuState;
+    use narrative_gui::framework::MenuId;
+    use narrative_gui::framework::element::WindowOperation;
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut root = GameRootElement::new(EngineConfig::default()).with_menu_commands(receiver);
+    root.app_state = AppState::MainMenu(MainMenuState::default());
+
+    // Save is not available on the title screen
+    sender.send(MenuId::Save).unwrap();
+    root.update_menu_commands();
+    assert!(root.app_state.is_main_menu());
+
+    // Fullscreen toggles in any state
+    sender.send(MenuId::ToggleFullscreen).unwrap();
+    root.update_menu_commands();
+    assert!(root.config.window.fullscreen);
+    assert!(matches!(
+        root.window_operations.last(),
+        Some(WindowOperation::ToggleFullscreen)
+    ));
+
+    // Preferences opens settings like the title screen button
+    sender.send(MenuId::Settings).unwrap();
+    root.update_menu_commands();
+    assert!(matches!(root.app_state, AppState::Settings(_)));
+    assert!(matches!(
+        root.previous_app_state.as_deref(),
+        Some(AppState::MainMenu(_))
+    ));
+}
+
+#[test]
+fn test_scenario_end_behavior() {
+    use narrative_core::{EndBehavior, Scenario, ScenarioMetadata};
+    use narrative_engine::runtime::{EndingState, InGameState, ScenarioRuntime};
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+
+    // Credits show the ending screen
+    let metadata = ScenarioMetadata::new("test", "Test").with_on_end(EndBehavior::Credits {
+        lines: vec!["Thanks for playing".to_string()],
+        duration: 5.0,
+    });
+    root.scenario_runtime = Some(ScenarioRuntime::new(Scenario::new(metadata, "start")));
+    root.handle_scenario_end();
+    assert!(matches!(
+        root.app_state,
+        AppState::InGame(InGameState::Ending(EndingState::Credits { .. }))
+    ));
+
+    // A missing next scenario falls back to the title screen
+    let metadata = ScenarioMetadata::new("test", "Test").with_on_end(EndBehavior::NextScenario {
+        path: "nonexistent/scenario.toml".to_string(),
+    });
+    root.scenario_runtime = Some(ScenarioRuntime::new(Scenario::new(metadata, "start")));
+    root.handle_scenario_end();
+    assert!(root.app_state.is_main_menu());
+}
+
+#[test]
+fn test_jump_to_unknown_scenario_returns_to_title() {
+    use narrative_core::{
+        ProjectManifest, Scenario, ScenarioCommand, ScenarioEntry, ScenarioMetadata, Scene,
+    };
+    use narrative_engine::runtime::ScenarioRuntime;
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("chapter_01", "Chapter 1"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::JumpToScenario {
+        id: "missing".to_string(),
+        scene: None,
+    });
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    runtime.execute_current_command().unwrap();
+
+    let project = ProjectManifest::new(vec![ScenarioEntry::new(
+        "chapter_01",
+        "assets/scenarios/chapter_01.toml",
+    )]);
+    let mut root = GameRootElement::new(EngineConfig::default()).with_project(project);
+    root.scenario_runtime = Some(runtime);
+    root.handle_scenario_end();
+
+    assert!(root.app_state.is_main_menu());
+    assert!(
+        root.scenario_runtime
+            .as_ref()
+            .is_some_and(|runtime| !runtime.has_scenario_jump())
+    );
+}
+
+#[test]
+fn test_step_rollback_shows_previous_line() {
+    use narrative_core::{Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene, SceneId};
+    use narrative_engine::runtime::{InGameState, ScenarioRuntime, WaitingInputState};
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    for text in ["First", "Second"] {
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator(text),
+        });
+    }
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    let scene_id = SceneId::new("start");
+    runtime.add_to_backlog(
+        scene_id.clone(),
+        0,
+        narrative_core::Speaker::Narrator,
+        "First",
+    );
+    runtime.advance_command();
+    runtime.add_to_backlog(
+        scene_id.clone(),
+        1,
+        narrative_core::Speaker::Narrator,
+        "Second",
+    );
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(InGameState::WaitingInput(WaitingInputState {
+        scene_id,
+        command_index: 1,
+        auto_wait_elapsed: 0.0,
+        skip_mode: false,
+    }));
+
+    root.step_rollback(false);
+    assert!(matches!(
+        &root.app_state,
+        AppState::InGame(InGameState::WaitingInput(waiting)) if waiting.command_index == 0
+    ));
+
+    root.step_rollback(true);
+    assert!(matches!(
+        &root.app_state,
+        AppState::InGame(InGameState::WaitingInput(waiting)) if waiting.command_index == 1
+    ));
+}
+
+fn write_ui_theme_manifest(asset_dir: &std::path::Path, accent: &str) {
+    let manifest = format!(
+        r#"UiThemeManifest(
+    themes: {{
+        "dark": UiThemeDef(
+            id: "dark",
+            name: "Dark",
+            dialogue_box: (default: "assets/ui/dialoguebox_dark.png"),
+            buttons: (
+                continue_idle: "c.png", continue_hover: "c.png",
+                history_idle: "h.png", history_hover: "h.png",
+                skip_idle: "s.png", skip_hover: "s.png",
+                options_idle: "o.png", options_hover: "o.png",
+            ),
+            choices: (idle: "i.png", hover: "i.png", disabled: "i.png"),
+            colors: Some((
+                text_primary: (255, 255, 255, 255),
+                text_secondary: (128, 128, 128, 255),
+                accent: {accent},
+                background: (0, 0, 0, 200),
+            )),
+        ),
+    }},
+)"#
+    );
+    std::fs::create_dir_all(asset_dir.join("manifests")).unwrap();
+    std::fs::write(asset_dir.join("manifests/ui_themes.ron"), manifest).unwrap();
+}
+
+#[test]
+fn test_ui_theme_applies_and_reloads() {
+    let asset_dir =
+        std::env::temp_dir().join(format!("narrative_ui_theme_test_{}", std::process::id()));
+    write_ui_theme_manifest(&asset_dir, "(255, 0, 0, 255)");
+
+    let mut config = EngineConfig {
+        asset_path: asset_dir.clone(),
+        ..Default::default()
+    };
+    config.ui.theme = Some("dark".to_string());
+    let mut root = GameRootElement::new(config);
+
+    assert_eq!(root.ui_theme.as_ref().map(|t| t.id.as_str()), Some("dark"));
+    assert!(root.theme_assets_pending);
+    assert_eq!(root.dialogue_box_config.text_color, Color::WHITE);
+    assert_eq!(
+        root.dialogue_box_config.speaker_color,
+        Color::new(1.0, 0.0, 0.0, 1.0)
+    );
+
+    // Edit the theme on disk and reload it
+    write_ui_theme_manifest(&asset_dir, "(0, 0, 255, 255)");
+    root.theme_assets_pending = false;
+    root.children_dirty = false;
+    root.reload_ui_theme_and_settings();
+
+    assert_eq!(
+        root.dialogue_box_config.speaker_color,
+        Color::new(0.0, 0.0, 1.0, 1.0)
+    );
+    assert!(root.theme_assets_pending);
+    assert!(root.children_dirty);
+
+    let _ = std::fs::remove_dir_all(&asset_dir);
+}
+
+#[test]
+fn test_dual_language_shows_translation() {
+    use narrative_core::config::UserSettings;
+    use narrative_core::{Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::{InGameState, ScenarioRuntime};
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("おはよう").with_translation("en", "Good morning"),
+    });
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.scenario_runtime = Some(runtime);
+
+    let mut settings = UserSettings::default();
+    settings.text.dual_language = true;
+    settings.text.second_language = Some("en".to_string());
+    root.apply_user_settings(&settings);
+
+    let runtime = root.scenario_runtime.as_ref().unwrap();
+    let Some(InGameState::Typing(typing)) = GameRootElement::create_state_from_command(runtime)
+    else {
+        panic!("Expected a typing state");
+    };
+    assert_eq!(typing.text.as_ref(), "おはよう");
+    assert_eq!(typing.translation.as_deref(), Some("Good morning"));
+
+    settings.text.dual_language = false;
+    root.apply_user_settings(&settings);
+    let runtime = root.scenario_runtime.as_ref().unwrap();
+    let Some(InGameState::Typing(typing)) = GameRootElement::create_state_from_command(runtime)
+    else {
+        panic!("Expected a typing state");
+    };
+    assert_eq!(typing.translation, None);
+}
+
+#[test]
+fn test_play_video_state_and_playback() {
+    use narrative_core::{AssetRef, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::{InGameState, ScenarioRuntime, VideoState};
+
+    let dir = std::env::temp_dir().join(format!("narrative_video_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    // Two 2x2 frames at 10 fps
+    let path = dir.join("opening.y4m");
+    let mut data = b"YUV4MPEG2 W2 H2 F10:1 C420jpeg\n".to_vec();
+    for _ in 0..2 {
+        data.extend_from_slice(b"FRAME\n");
+        data.extend_from_slice(&[16, 16, 16, 16, 128, 128]);
+    }
+    std::fs::write(&path, data).unwrap();
+    std::fs::write(
+        dir.join("opening.srt"),
+        "1\n00:00:00,000 --> 00:00:05,000\nOpening titles\n",
+    )
+    .unwrap();
+    let path = path.to_string_lossy().into_owned();
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::PlayVideo {
+        path: AssetRef::from(path.as_str()),
+        skippable: false,
+    });
+    scenario.add_scene("start", scene);
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let Some(InGameState::PlayingVideo(video)) =
+        GameRootElement::create_state_from_command(&runtime)
+    else {
+        panic!("Expected a video state");
+    };
+    assert!(!video.skippable);
+
+    fn update(root: &mut GameRootElement, video: &VideoState, delta: f32, skip: bool) -> bool {
+        GameRootElement::update_video(
+            &mut root.video_player,
+            video,
+            delta,
+            skip,
+            root.audio_manager.get_mut().unwrap(),
+            &mut root.subtitles,
+            &mut root.subtitle_tracks,
+        )
+    }
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.subtitles.set_enabled(true);
+
+    // An unskippable video ignores skip input and ends after its last frame;
+    // its sidecar subtitles show while it plays
+    assert!(!update(&mut root, &video, 0.0, true));
+    assert!(root.video_player.is_some());
+    assert_eq!(root.subtitles.current_text(), Some("Opening titles"));
+    assert!(!update(&mut root, &video, 0.1, true));
+    assert!(update(&mut root, &video, 0.1, false));
+    assert_eq!(root.subtitles.current_text(), None);
+
+    // A skippable video ends on skip input
+    root.video_player = None;
+    let skippable = VideoState::new(video.path.clone(), true);
+    assert!(update(&mut root, &skippable, 0.0, true));
+
+    // A missing video is skipped
+    let missing = VideoState::new(AssetRef::from("missing/opening.y4m"), false);
+    assert!(update(&mut root, &missing, 0.0, false));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_show_effect_spawns_particles() {
+    use narrative_core::{EffectLifetime, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::{
+        InGameState, MainMenuState, PauseMenuState, ScenarioRuntime, WaitState,
+    };
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::ShowEffect {
+        effect: "snow".to_string(),
+        intensity: 1.0,
+        duration: Some(2.0),
+        lifetime: EffectLifetime::Scene,
+    });
+    scene.add_command(ScenarioCommand::End);
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    runtime.execute_current_command().unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(InGameState::Waiting(WaitState { remaining: 5.0 }));
+
+    assert!(root.update_particles(0.5));
+    let count = root.particles.particle_count();
+    assert!(count > 0);
+
+    // Particles freeze under the pause menu
+    root.app_state = AppState::InGame(InGameState::PauseMenu(PauseMenuState::default()));
+    assert!(!root.update_particles(0.5));
+    assert_eq!(root.particles.particle_count(), count);
+
+    // The effect ends after its duration
+    root.app_state = AppState::InGame(InGameState::Waiting(WaitState { remaining: 5.0 }));
+    root.update_particles(2.0);
+    assert!(
+        root.scenario_runtime
+            .as_ref()
+            .unwrap()
+            .active_effects()
+            .is_empty()
+    );
+
+    // Leaving the game clears the particles
+    root.app_state = AppState::MainMenu(MainMenuState::default());
+    assert!(root.update_particles(0.1));
+    assert!(root.particles.is_idle());
+}
+
+#[test]
+fn test_self_voicing_reads_lines_in_character_voice() {
+    use narrative_core::config::UserSettings;
+    use narrative_core::{
+        CharacterDef, Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene, TtsVoice,
+    };
+    use narrative_engine::EngineResult;
+    use narrative_engine::TextToSpeech;
+    use narrative_engine::audio::{SpeechBackend, SpeechParams};
+    use narrative_engine::runtime::{MainMenuState, ScenarioRuntime};
+    use std::sync::{Arc, Mutex};
+
+    /// Backend recording what it's asked to say
+    struct RecordingBackend {
+        spoken: Arc<Mutex<Vec<(String, SpeechParams)>>>,
+        speaking: bool,
+    }
+
+    impl SpeechBackend for RecordingBackend {
+        fn speak(&mut self, text: &str, params: &SpeechParams) -> EngineResult<()> {
+            self.spoken
+                .lock()
+                .unwrap()
+                .push((text.to_string(), params.clone()));
+            self.speaking = true;
+            Ok(())
+        }
+
+        fn stop(&mut self) {
+            self.speaking = false;
+        }
+
+        fn is_speaking(&mut self) -> bool {
+            self.speaking
+        }
+    }
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    scenario.add_character(
+        CharacterDef::new("alice", "Alice", "alice.png")
+            .with_tts_voice(TtsVoice::new("en-us+f3").with_pitch(1.3)),
+    );
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::character("alice", "Hello!"),
+    });
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let spoken = Arc::new(Mutex::new(Vec::new()));
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.tts = TextToSpeech::with_backend(Box::new(RecordingBackend {
+        spoken: Arc::clone(&spoken),
+        speaking: false,
+    }));
+    let mut settings = UserSettings::default();
+    settings.accessibility.self_voicing = true;
+    settings.accessibility.speech_rate = 1.5;
+    root.apply_user_settings(&settings);
+
+    let state = GameRootElement::create_state_from_command(&runtime).unwrap();
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(state);
+
+    // Each line is read once, in the speaker's voice, with BGM ducked
+    root.update_self_voicing();
+    root.update_self_voicing();
+    {
+        let spoken = spoken.lock().unwrap();
+        assert_eq!(spoken.len(), 1);
+        let (text, params) = &spoken[0];
+        assert_eq!(text, "Hello!");
+        assert_eq!(params.voice.as_deref(), Some("en-us+f3"));
+        assert_eq!(params.pitch, 1.3);
+        assert_eq!(params.rate, 1.5);
+    }
+    assert!(root.bgm_ducked_for_speech);
+
+    // Leaving the game stops speech and restores BGM
+    root.app_state = AppState::MainMenu(MainMenuState::default());
+    root.update_self_voicing();
+    assert!(!root.tts.is_speaking());
+    assert!(!root.bgm_ducked_for_speech);
+}
+
+#[test]
+fn test_voice_plays_with_each_line() {
+    use narrative_core::{AssetRef, Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::AudioCommand;
+    use narrative_engine::runtime::{MainMenuState, ScenarioRuntime};
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::PlayVoice {
+        asset: AssetRef::from("voice/alice/hello.ogg"),
+        volume: 0.5,
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::character("alice", "Hello!"),
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Silence."),
+    });
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    runtime.advance_command();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    let state = GameRootElement::create_state_from_command(&runtime).unwrap();
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(state);
+
+    let take_commands = |root: &mut GameRootElement| {
+        let mut commands = Vec::new();
+        root.audio_manager
+            .get_mut()
+            .unwrap()
+            .process_commands_with(|command| commands.push(command.clone()));
+        commands
+    };
+
+    // A line's voice starts once, relative to the asset directory
+    root.update_voice();
+    root.update_voice();
+    assert_eq!(
+        take_commands(&mut root),
+        [AudioCommand::PlayVoice {
+            path: root.config.asset_path.join("voice/alice/hello.ogg"),
+            volume: 0.5,
+        }]
+    );
+
+    // A line without a voice cuts off the previous one
+    let runtime = root.scenario_runtime.as_mut().unwrap();
+    runtime.advance_command();
+    let state = GameRootElement::create_state_from_command(runtime).unwrap();
+    root.app_state = AppState::InGame(state);
+    root.update_voice();
+    assert_eq!(take_commands(&mut root), [AudioCommand::StopVoice]);
+
+    // Leaving the game stops the voice once
+    root.app_state = AppState::MainMenu(MainMenuState::default());
+    root.update_voice();
+    root.update_voice();
+    assert_eq!(take_commands(&mut root), [AudioCommand::StopVoice]);
+}
+
+#[test]
+fn test_save_records_thumbnail_for_displayed_screen() {
+    use super::textures::save_with_thumbnail;
+    use narrative_engine::save::{MemorySaveBackend, SaveData, SaveManager};
+
+    let manager = SaveManager::with_backend(MemorySaveBackend::new());
+    let mut pending = Vec::new();
+
+    // Nothing on screen: no thumbnail
+    let mut save_data = SaveData::new(1);
+    save_with_thumbnail(&manager, 1, &mut save_data, &mut pending).unwrap();
+    assert!(save_data.thumbnail_path.is_none());
+    assert!(pending.is_empty());
+
+    let mut save_data = SaveData::new(2);
+    save_data.current_background = Some("bg/room.png".to_string());
+    save_with_thumbnail(&manager, 2, &mut save_data, &mut pending).unwrap();
+    assert!(save_data.thumbnail_path.is_some());
+    assert_eq!(
+        manager.load(2).unwrap().thumbnail_path,
+        save_data.thumbnail_path
+    );
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].0, 2);
+    assert_eq!(pending[0].1.background.as_deref(), Some("bg/room.png"));
+}
+
+#[test]
+fn test_screen_shake_and_flash_play_in_order() {
+    use narrative_core::{Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::{EffectKind, InGameState, ScenarioRuntime};
+    use narrative_gui::framework::layout::Point;
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::ScreenShake {
+        intensity: 1.0,
+        duration: 0.5,
+    });
+    scene.add_command(ScenarioCommand::Flash {
+        color: Color::WHITE,
+        duration: 0.2,
+    });
+    scene.add_command(ScenarioCommand::End);
+    scenario.add_scene("start", scene);
+
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    let state = GameRootElement::create_state_from_command(&runtime).unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(state);
+    assert_eq!(root.screen_shake_offset(), Point::ZERO);
+
+    // The shake moves the camera while it plays
+    root.update_state(0.1);
+    assert_ne!(root.screen_shake_offset(), Point::ZERO);
+
+    // ... then the flash plays
+    root.update_state(0.5);
+    let AppState::InGame(InGameState::PlayingEffect(effect)) = &root.app_state else {
+        panic!("Expected the flash to play, got {:?}", root.app_state);
+    };
+    assert_eq!(effect.kind, EffectKind::Flash { color: [1.0; 4] });
+    assert_eq!(root.screen_shake_offset(), Point::ZERO);
+    assert_eq!(root.scenario_runtime.as_ref().unwrap().command_index(), 1);
+
+    // ... and playback moves on once it ends
+    root.update_state(0.3);
+    assert!(!matches!(
+        root.app_state,
+        AppState::InGame(InGameState::PlayingEffect(_))
+    ));
+}
+
+#[test]
+fn test_scene_transition_captures_outgoing_frame() {
+    use narrative_core::types::Transition;
+    use narrative_core::{Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::{InGameState, ScenarioRuntime};
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut start = Scene::new("start", "Start");
+    start.add_command(ScenarioCommand::ShowBackground {
+        asset: "bg_a".into(),
+        transition: Transition::instant(),
+    });
+    start.add_command(ScenarioCommand::JumpToScene {
+        scene_id: "next".to_string(),
+    });
+    scenario.add_scene("start", start);
+    let mut next = Scene::new("next", "Next").with_entry_transition(Transition::crossfade());
+    next.add_command(ScenarioCommand::ShowBackground {
+        asset: "bg_b".into(),
+        transition: Transition::instant(),
+    });
+    next.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Hello"),
+    });
+    scenario.add_scene("next", next);
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    let state = GameRootElement::execute_and_transition(&mut runtime, &root.audio_queue).unwrap();
+    assert!(matches!(state, InGameState::Transition(_)));
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(state);
+
+    // Starting the transition captures the old frame and builds the new scene under it
+    root.update_scene_transition();
+    assert!(root.transition_started);
+    let snapshot = root.pending_transition_snapshot.as_ref().unwrap();
+    assert_eq!(snapshot.background.as_deref(), Some("bg_a"));
+    let runtime = root.scenario_runtime.as_ref().unwrap();
+    assert_eq!(
+        runtime.current_background().map(|asset| asset.path()),
+        Some("bg_b")
+    );
+    assert_eq!(runtime.command_index(), 1);
+
+    // Once it finishes, the first non-display command starts
+    root.update_state(1.0);
+    assert!(
+        matches!(root.app_state, AppState::InGame(InGameState::Typing(_))),
+        "Expected typing, got {:?}",
+        root.app_state
+    );
+    assert!(!root.transition_started);
+}
+
+#[test]
+fn test_name_entry_fills_in_later_dialogue() {
+    use narrative_core::{Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::{InGameState, ScenarioRuntime};
+    use narrative_gui::framework::element::WindowOperation;
+    use narrative_gui::framework::input::{InputEvent, KeyCode, Modifiers};
+    use narrative_gui::framework::layout::Bounds;
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    scene.add_command(ScenarioCommand::InputName {
+        variable: "player_name".to_string(),
+        prompt: None,
+        default: "Alex".to_string(),
+        max_length: 12,
+    });
+    scene.add_command(ScenarioCommand::Dialogue {
+        dialogue: Dialogue::narrator("Nice to meet you, {$player_name}."),
+    });
+    scenario.add_scene("start", scene);
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    let state = GameRootElement::execute_and_transition(&mut runtime, &root.audio_queue);
+    assert!(matches!(state, Some(InGameState::InputtingName(_))));
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(state.unwrap());
+    root.rebuild_children();
+
+    // Typed keys go to the name entry, not to key bindings
+    let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+    for character in "Sakura".chars() {
+        assert!(root.handle_event_impl(&InputEvent::CharInput { character }, bounds));
+    }
+    assert!(root.handle_event_impl(
+        &InputEvent::KeyDown {
+            key: KeyCode::Enter,
+            modifiers: Modifiers::default(),
+        },
+        bounds
+    ));
+    root.update_in_game_state_wrapper(0.016);
+
+    let AppState::InGame(InGameState::Typing(typing)) = &root.app_state else {
+        panic!("Expected the next dialogue line, got {:?}", root.app_state);
+    };
+    assert_eq!(typing.text.as_ref(), "Nice to meet you, Sakura.");
+    assert_eq!(
+        root.window_operations.first(),
+        Some(&WindowOperation::SetImeAllowed(true))
+    );
+    assert!(
+        root.window_operations
+            .contains(&WindowOperation::SetImeAllowed(false))
+    );
+}
+
+#[test]
+fn test_save_reminder_waits_for_manual_play() {
+    use narrative_core::SceneId;
+    use narrative_engine::runtime::{InGameState, WaitingInputState};
+
+    let mut root = GameRootElement::new(EngineConfig::default()).with_save_slots(
+        narrative_core::SaveSlotConfig {
+            reminder_minutes: Some(1),
+            ..Default::default()
+        },
+    );
+    root.unsaved_play_secs = 61.0;
+    root.app_state = AppState::InGame(InGameState::WaitingInput(WaitingInputState {
+        scene_id: SceneId::new("start"),
+        command_index: 0,
+        auto_wait_elapsed: 0.0,
+        skip_mode: false,
+    }));
+
+    // Not shown while the game plays itself
+    root.config.gameplay.auto_mode_enabled = true;
+    root.update_save_reminder();
+    assert!(root.toasts.is_empty());
+
+    root.config.gameplay.auto_mode_enabled = false;
+    root.config.gameplay.skip_mode_enabled = false;
+    root.update_save_reminder();
+    assert_eq!(root.toasts.shown().count(), 1);
+    assert_eq!(root.unsaved_play_secs, 0.0);
+}
+
+#[test]
+fn test_quick_load_asks_for_confirmation() {
+    use narrative_core::SceneId;
+    use narrative_engine::runtime::{InGameState, WaitingInputState};
+    use narrative_engine::save::{MemorySaveBackend, SaveData, SaveManager};
+    use narrative_gui::framework::input::{InputEvent, KeyCode, Modifiers};
+    use narrative_gui::framework::layout::Bounds;
+    use std::sync::{Arc, Mutex};
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.save_manager = Arc::new(Mutex::new(SaveManager::with_backend(
+        MemorySaveBackend::new(),
+    )));
+    root.app_state = AppState::InGame(InGameState::WaitingInput(WaitingInputState {
+        scene_id: SceneId::new("start"),
+        command_index: 0,
+        auto_wait_elapsed: 0.0,
+        skip_mode: false,
+    }));
+
+    // Nothing to load yet
+    root.quick_load();
+    assert!(!root.showing_quick_load_confirm);
+    assert_eq!(root.toasts.shown().count(), 1);
+
+    let slot = root.save_slots.quicksave_slot().unwrap();
+    root.save_manager
+        .lock()
+        .unwrap()
+        .save(slot, &SaveData::new(slot))
+        .unwrap();
+    root.quick_load();
+    assert!(root.showing_quick_load_confirm);
+    root.rebuild_children();
+
+    // The dialog takes all input until answered
+    let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+    let key = |key| InputEvent::KeyDown {
+        key,
+        modifiers: Modifiers::default(),
+    };
+    assert!(root.handle_event_impl(&key(KeyCode::Q), bounds));
+    assert!(!root.quick_save_pressed);
+    assert!(root.update_quick_load_confirm());
+
+    root.handle_event_impl(&key(KeyCode::Escape), bounds);
+    assert!(!root.update_quick_load_confirm());
+    assert!(!root.showing_quick_load_confirm);
+    assert!(matches!(
+        root.app_state,
+        AppState::InGame(InGameState::WaitingInput(_))
+    ));
+}
+
+#[test]
+fn test_skip_to_next_choice() {
+    use narrative_core::{
+        Choice, ChoiceOption, Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene, SceneId,
+    };
+    use narrative_engine::runtime::{InGameState, ScenarioRuntime};
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    let mut scene = Scene::new("start", "Start");
+    for line in ["One", "Two"] {
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator(line),
+        });
+    }
+    scene.add_command(ScenarioCommand::Wait { duration: 5.0 });
+    scene.add_command(ScenarioCommand::ShowChoice {
+        choice: Choice::new(vec![
+            ChoiceOption::new("Stay", "start"),
+            ChoiceOption::new("Leave", "start"),
+        ]),
+    });
+    scenario.add_scene("start", scene);
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+    runtime
+        .read_history_mut()
+        .mark_read(SceneId::new("start"), 1);
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    let state = GameRootElement::enter_current_command(&mut runtime);
+    root.scenario_runtime = Some(runtime);
+    root.app_state = AppState::InGame(state.unwrap());
+    root.config.gameplay.skip_mode_enabled = true;
+
+    // The read line and the wait are skipped at once
+    root.skip_to_next_stop();
+    assert!(
+        matches!(
+            &root.app_state,
+            AppState::InGame(InGameState::ShowingChoices(choices)) if choices.choices.len() == 2
+        ),
+        "Expected the choice, got {:?}",
+        root.app_state
+    );
+    assert!(!root.config.gameplay.skip_mode_enabled);
+    let runtime = root.scenario_runtime.as_ref().unwrap();
+    assert_eq!(runtime.backlog().entries().len(), 2);
+    assert!(runtime.read_history().is_read(&SceneId::new("start"), 0));
+
+    // Nothing happens without a line on screen
+    root.skip_to_next_stop();
+    assert!(matches!(
+        root.app_state,
+        AppState::InGame(InGameState::ShowingChoices(_))
+    ));
+}
+
+#[test]
+fn test_replay_saved_at_scenario_end() {
+    use narrative_core::{
+        Choice, ChoiceOption, Scenario, ScenarioCommand, ScenarioMetadata, Scene,
+    };
+    use narrative_engine::app::{Replay, ScriptedInput};
+    use narrative_engine::runtime::ScenarioRuntime;
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("replay_test", "Replay"), "start");
+    let mut start = Scene::new("start", "Start");
+    start.add_command(ScenarioCommand::ShowChoice {
+        choice: Choice::new(vec![
+            ChoiceOption::new("Stay", "end"),
+            ChoiceOption::new("Leave", "end").with_flag("left"),
+        ]),
+    });
+    scenario.add_scene("start", start);
+    let mut end = Scene::new("end", "End");
+    end.add_command(ScenarioCommand::End);
+    scenario.add_scene("end", end);
+
+    let save_dir =
+        std::env::temp_dir().join(format!("narrative_replay_test_{}", std::process::id()));
+    let mut root = GameRootElement::new(EngineConfig {
+        save_path: save_dir.clone(),
+        ..Default::default()
+    });
+
+    let mut runtime = ScenarioRuntime::new(scenario.clone());
+    runtime.start().unwrap();
+    root.replay = GameRootElement::new_replay(&runtime);
+    runtime.execute_current_command().unwrap();
+    if let Some(replay) = root.replay.as_mut() {
+        replay.record(ScriptedInput::Choose(1), &runtime);
+    }
+    runtime.select_choice(1).unwrap();
+    runtime.execute_current_command().unwrap();
+    root.scenario_runtime = Some(runtime);
+    root.handle_scenario_end();
+
+    assert!(root.replay.is_none());
+    let replay = Replay::load(root.replay_dir().join("replay_test.ron")).unwrap();
+    assert_eq!(replay.events.len(), 1);
+    let outcome = replay.verify(scenario).unwrap();
+    assert_eq!(outcome.flags.get("left"), Some(&true));
+
+    let _ = std::fs::remove_dir_all(&save_dir);
+}
+
+#[test]
+fn test_scenario_reload_shows_edited_line() {
+    use narrative_engine::runtime::InGameState;
+
+    let dir = std::env::temp_dir().join(format!("narrative_reload_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("chapter.toml");
+    let scenario = |line: &str| {
+        format!(
+            r#"
+[chapter]
+id = "chapter"
+title = "Chapter"
+
+[[scenes]]
+id = "start"
+title = "Start"
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = {{ speaker = "Narrator", text = "First line." }}
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = {{ speaker = "Narrator", text = "{line}" }}
+"#
+        )
+    };
+    std::fs::write(&path, scenario("Second line.")).unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    assert!(root.start_scenario(&path));
+    let backlog_len = |root: &GameRootElement| {
+        root.scenario_runtime
+            .as_ref()
+            .map_or(0, |runtime| runtime.backlog().entries().len())
+    };
+    let shown_lines = backlog_len(&root);
+
+    // The watcher marks the reload pending when the file is saved
+    std::fs::write(&path, scenario("Second line, revised.")).unwrap();
+    root.scenario_reload_pending = true;
+    root.update_scenario_hot_reload();
+
+    let AppState::InGame(InGameState::Typing(typing)) = &root.app_state else {
+        panic!("Expected the edited line, got {:?}", root.app_state);
+    };
+    assert_eq!(&*typing.text, "Second line, revised.");
+    assert_eq!(typing.command_index, 1);
+    assert!(!root.scenario_reload_pending);
+    assert_eq!(backlog_len(&root), shown_lines);
+
+    // A broken file keeps the scenario playing
+    std::fs::write(&path, "[[scenes").unwrap();
+    assert!(!root.reload_scenario());
+    assert_eq!(
+        root.scenario_runtime
+            .as_ref()
+            .unwrap()
+            .scenario()
+            .metadata
+            .id,
+        "chapter"
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_start_scenario_waits_for_assets() {
+    use narrative_engine::runtime::InGameState;
+    use std::time::{Duration, Instant};
+
+    let dir = std::env::temp_dir().join(format!("narrative_loading_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let background = dir.join("room.png");
+    image::RgbaImage::new(4, 4).save(&background).unwrap();
+    let path = dir.join("chapter.toml");
+    std::fs::write(
+        &path,
+        format!(
+            r#"
+[chapter]
+id = "chapter"
+title = "Chapter"
+
+[[scenes]]
+id = "start"
+title = "Start"
+
+[[scenes.commands]]
+type = "ShowBackground"
+asset = "{}"
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = {{ speaker = "Narrator", text = "A quiet room." }}
+"#,
+            background.display()
+        ),
+    )
+    .unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    assert!(root.start_scenario(&path));
+    let AppState::Loading(loading) = &root.app_state else {
+        panic!("Expected the loading screen, got {:?}", root.app_state);
+    };
+    assert!(matches!(
+        loading.next.as_deref(),
+        Some(InGameState::Typing(_))
+    ));
+
+    let start = Instant::now();
+    while root.app_state.is_loading() {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "loading timed out"
+        );
+        std::thread::sleep(Duration::from_millis(5));
+        root.update_state(GameRootElement::FRAME_TIME);
+    }
+    assert!(matches!(
+        root.app_state,
+        AppState::InGame(InGameState::Typing(_))
+    ));
+    assert!(
+        root.asset_preloader
+            .is_loaded(&background.to_string_lossy().into_owned().into())
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_se_subtitles_are_looked_up_once() {
+    let dir = std::env::temp_dir().join(format!("narrative_subtitle_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let sound = dir.join("thunder.ogg");
+    let subtitles = dir.join("thunder.srt");
+    std::fs::write(
+        &subtitles,
+        "1\n00:00:00,000 --> 00:00:01,000\nThunder rumbles\n",
+    )
+    .unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.subtitles.set_enabled(true);
+    root.audio_queue.play_se(&sound, 1.0);
+    root.process_audio_commands();
+    assert_eq!(root.subtitles.current_text(), Some("Thunder rumbles"));
+
+    // Later plays use the cached track instead of reading the file again
+    root.subtitles.stop();
+    std::fs::remove_file(&subtitles).unwrap();
+    root.audio_queue.play_se(&sound, 1.0);
+    root.process_audio_commands();
+    assert_eq!(root.subtitles.current_text(), Some("Thunder rumbles"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
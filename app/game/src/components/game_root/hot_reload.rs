@@ -0,0 +1,118 @@
+//! Scenario hot reload for GameRootElement
+
+use super::element::GameRootElement;
+use crate::components::ToastMessage;
+use narrative_engine::runtime::{InGameState, ScenarioReload};
+use std::path::Path;
+
+impl GameRootElement {
+    /// Remember the scenario file being played, and watch it when hot reload is on
+    pub(super) fn set_scenario_file(&mut self, path: &Path) {
+        self.scenario_file = Some(path.to_path_buf());
+        self.scenario_reload_pending = false;
+        #[cfg(feature = "hot-reload")]
+        self.watch_scenario_file();
+    }
+
+    /// Watch the scenario file (`development.hot_reload`)
+    #[cfg(feature = "hot-reload")]
+    fn watch_scenario_file(&mut self) {
+        use narrative_engine::asset::ScenarioWatcher;
+
+        let Some(path) = &self.scenario_file else {
+            return;
+        };
+        if !self.config.development.hot_reload
+            || self
+                .scenario_watcher
+                .as_ref()
+                .is_some_and(|watcher| watcher.scenario_path() == path)
+        {
+            return;
+        }
+        self.scenario_watcher = match ScenarioWatcher::new(path) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                tracing::warn!("Cannot watch scenario '{}': {}", path.display(), e);
+                None
+            }
+        };
+    }
+
+    /// Reload the scenario when its files changed on disk
+    ///
+    /// The reload waits until play resumes, so it is not applied under a menu
+    /// or during a transition.
+    pub(super) fn update_scenario_hot_reload(&mut self) {
+        #[cfg(feature = "hot-reload")]
+        if let Some(path) = self
+            .scenario_watcher
+            .as_ref()
+            .and_then(|watcher| watcher.take_change())
+        {
+            tracing::debug!("Scenario file changed: {}", path.display());
+            self.scenario_reload_pending = true;
+        }
+
+        if self.scenario_reload_pending
+            && matches!(
+                self.app_state.in_game_state(),
+                Some(
+                    InGameState::Typing(_)
+                        | InGameState::WaitingInput(_)
+                        | InGameState::ShowingChoices(_)
+                        | InGameState::Waiting(_)
+                        | InGameState::InputtingName(_)
+                )
+            )
+        {
+            self.scenario_reload_pending = false;
+            self.reload_scenario();
+        }
+    }
+
+    /// Reload the scenario file being played and continue at the same position
+    ///
+    /// Flags, variables and history carry over (see
+    /// [`narrative_engine::runtime::ScenarioRuntime::reload_scenario`]). The
+    /// current line is shown again, with any edits. A scenario that fails to
+    /// parse is reported in a toast and playback continues unchanged.
+    pub(super) fn reload_scenario(&mut self) -> bool {
+        let (Some(path), Some(runtime)) = (&self.scenario_file, self.scenario_runtime.as_mut())
+        else {
+            return false;
+        };
+        let reload = match runtime.reload_scenario_from_toml(path) {
+            Ok(reload) => reload,
+            Err(e) => {
+                tracing::warn!("Scenario '{}' was not reloaded: {}", path.display(), e);
+                self.toasts
+                    .push(ToastMessage::new(e.to_string()).with_heading("Scenario reload failed"));
+                return false;
+            }
+        };
+        tracing::info!("Reloaded scenario '{}': {:?}", path.display(), reload);
+        if reload == ScenarioReload::SceneRemoved {
+            self.toasts.push(
+                ToastMessage::new("The current scene was removed")
+                    .with_heading("Scenario reloaded"),
+            );
+        }
+
+        // Shown without adding the line to the backlog again
+        let new_state = Self::create_state_from_command(runtime)
+            .or_else(|| Self::execute_and_transition(runtime, &self.audio_queue));
+        self.stop_replay("the scenario was reloaded");
+        match new_state {
+            Some(new_state) => {
+                if let Some(in_game_state) = self.app_state.in_game_state_mut() {
+                    *in_game_state = new_state;
+                }
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            None => self.handle_scenario_end(),
+        }
+        true
+    }
+}
@@ -0,0 +1,89 @@
+//! HUD widgets for GameRootElement
+
+use super::element::GameRootElement;
+use narrative_core::HudManifest;
+use narrative_engine::EngineConfig;
+use narrative_engine::runtime::{AppState, InGameState};
+use narrative_gui::framework::element::Element;
+use narrative_gui::framework::renderer::Renderer;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// HUD manifest, relative to the asset directory
+const HUD_MANIFEST: &str = "manifests/hud.ron";
+
+/// Load the widgets in the HUD manifest
+///
+/// A missing manifest leaves the game without a HUD.
+pub(super) fn load_hud(config: &EngineConfig) -> HudManifest {
+    let path = config.asset_path.join(HUD_MANIFEST);
+    if !path.exists() {
+        return HudManifest::new();
+    }
+    match HudManifest::load_from_file(&path) {
+        Ok(manifest) => {
+            tracing::debug!("Loaded {} HUD widgets", manifest.widgets.len());
+            manifest
+        }
+        Err(e) => {
+            tracing::warn!("Failed to load HUD from '{}': {}", path.display(), e);
+            HudManifest::new()
+        }
+    }
+}
+
+impl GameRootElement {
+    /// Whether the HUD is drawn: while the scene plays, not under menus,
+    /// cutscenes or with the UI hidden
+    pub(super) fn hud_shown(&self) -> bool {
+        !self.ui_hidden
+            && matches!(
+                self.app_state,
+                AppState::InGame(
+                    InGameState::Typing(_)
+                        | InGameState::WaitingInput(_)
+                        | InGameState::ShowingChoices(_)
+                        | InGameState::Transition(_)
+                        | InGameState::PlayingEffect(_)
+                        | InGameState::Waiting(_)
+                        | InGameState::InputtingName(_)
+                )
+            )
+    }
+
+    /// Sync the HUD widgets with the runtime and ease their bars
+    ///
+    /// Returns: true if the HUD needs to be redrawn
+    pub(super) fn update_hud(&mut self, delta: f32) -> bool {
+        if self.hud.is_empty() {
+            return false;
+        }
+        let changed = match &self.scenario_runtime {
+            Some(runtime) => self.hud.sync(runtime),
+            None => false,
+        };
+        let moving = self.hud.tick(Duration::from_secs_f32(delta));
+        (changed || moving) && self.hud_shown()
+    }
+
+    /// Load the icons of the HUD's icon rows, once
+    pub(super) fn load_hud_icons(&mut self, renderer: &mut Renderer) {
+        if !std::mem::take(&mut self.hud_icons_pending) {
+            return;
+        }
+        let mut textures = HashMap::new();
+        for path in self.hud.icon_paths() {
+            if textures.contains_key(path) {
+                continue;
+            }
+            match renderer.load_texture_from_path(Path::new(path)) {
+                Ok(texture_id) => {
+                    textures.insert(path.to_string(), texture_id);
+                }
+                Err(e) => tracing::warn!("Failed to load HUD icon '{}': {}", path, e),
+            }
+        }
+        self.hud.set_icon_textures(textures);
+    }
+}
@@ -1,16 +1,25 @@
 //! In-game state update logic for GameRootElement
 
+use super::attract::attract_choice;
 use super::element::GameRootElement;
+use super::textures::save_with_thumbnail;
 use crate::components::{
-    BacklogElement, CgGalleryAction, CgGalleryElement, CgViewerAction, CgViewerElement,
-    ChoiceMenuElement, ConfirmDialogElement, DialogueBoxElement, QuickMenuAction, QuickMenuElement,
+    AchievementListAction, AchievementListElement, BacklogElement, CgGalleryAction,
+    CgGalleryElement, CgViewerAction, CgViewerElement, ChoiceMenuElement, ConfirmDialogElement,
+    DialogueBoxElement, EndingScreenElement, NameInputElement, QuickMenuAction, QuickMenuElement,
     SaveLoadMenuAction, SaveLoadMenuElement,
 };
-use narrative_core::ScenarioCommand;
+use narrative_core::config::PLAYER_VARIABLE_PREFIX;
+use narrative_core::{VariableId, VariableValue};
+use narrative_engine::app::ScriptedInput;
 use narrative_engine::runtime::{
-    AppState, InGameState, MainMenuState, ScenarioRuntime, WaitingInputState,
+    AppState, InGameState, MainMenuState, ScenarioRuntime, TextLog, WaitingInputState,
+    default_export_dir,
 };
+use narrative_gui::framework::MenuId;
+use narrative_gui::framework::element::{Element, WindowOperation};
 use std::sync::Arc;
+use std::time::Duration;
 
 impl GameRootElement {
     pub(super) fn update_in_game_state_wrapper(&mut self, delta: f32) {
@@ -24,74 +33,87 @@ impl GameRootElement {
                     | InGameState::Backlog(_)
                     | InGameState::CgGallery(_)
                     | InGameState::CgViewer(_)
+                    | InGameState::Achievements(_)
+                    | InGameState::Ending(_)
             )
         {
             tracing::error!("InGame state without runtime!");
             return;
         }
 
+        // The game waits while the quick load confirmation is shown
+        if self.update_quick_load_confirm() {
+            return;
+        }
+
         // Handle quick menu actions FIRST (before checking flags)
         // This ensures that actions set flags in the same frame they will be processed
-        for child in &mut self.children {
-            if let Some(quick_menu) = child.as_any_mut().downcast_mut::<QuickMenuElement>()
-                && let Some(action) = quick_menu.pending_action()
-            {
-                match action {
-                    QuickMenuAction::ToggleSkip => {
-                        self.skip_mode_toggle_pressed = true;
-                    }
-                    QuickMenuAction::ToggleAuto => {
-                        self.auto_mode_toggle_pressed = true;
-                    }
-                    QuickMenuAction::OpenBacklog => {
-                        self.backlog_pressed = true;
-                    }
-                    QuickMenuAction::QuickSave => {
-                        // Quick save to slot 0
-                        if let Some(runtime) = &self.scenario_runtime {
-                            let mut save_data = runtime.to_save_data(0);
-
-                            // Set timestamp and play time
-                            save_data.timestamp = std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .map(|d| d.as_secs())
-                                .unwrap_or_else(|e| {
-                                    tracing::error!(
-                                        "Failed to get system time for quick save: {:?}",
-                                        e
-                                    );
-                                    // Fallback: use 0 (will be logged as error above)
-                                    0
-                                });
-                            save_data.play_time_secs = self.total_play_time_secs;
-
-                            // Save to file
-                            match self.save_manager.lock() {
-                                Ok(manager) => match manager.save(0, &save_data) {
-                                    Ok(_) => {
-                                        tracing::info!("Quick save successful (slot 0)");
-                                    }
-                                    Err(e) => {
-                                        tracing::error!("Quick save failed: {:?}", e);
-                                    }
-                                },
-                                Err(e) => {
-                                    tracing::error!(
-                                        "Failed to lock save_manager for quick save: {:?}",
-                                        e
-                                    );
-                                }
-                            }
+        let quick_menu_action = self.children.iter_mut().find_map(|child| {
+            child
+                .as_any_mut()
+                .downcast_mut::<QuickMenuElement>()
+                .and_then(QuickMenuElement::take_pending_action)
+        });
+        if let Some(action) = quick_menu_action {
+            match action {
+                QuickMenuAction::ToggleSkip => {
+                    self.skip_mode_toggle_pressed = true;
+                }
+                QuickMenuAction::SkipToChoice => {
+                    self.skip_to_next_stop();
+                    return;
+                }
+                QuickMenuAction::ToggleAuto => {
+                    self.auto_mode_toggle_pressed = true;
+                }
+                QuickMenuAction::OpenBacklog => {
+                    self.backlog_pressed = true;
+                }
+                QuickMenuAction::QuickSave => {
+                    self.quick_save_pressed = true;
+                }
+                QuickMenuAction::QuickLoad => {
+                    self.quick_load_pressed = true;
+                }
+                QuickMenuAction::OpenMenu => {
+                    self.pause_pressed = true;
+                }
+                QuickMenuAction::Save => {
+                    // Opens like the native menu command, over the pause menu
+                    self.handle_menu_command(MenuId::Save);
+                    return;
+                }
+                QuickMenuAction::Load => {
+                    self.handle_menu_command(MenuId::Load);
+                    return;
+                }
+                QuickMenuAction::Custom(id) => {
+                    match (
+                        self.quick_menu_handler.as_mut(),
+                        self.scenario_runtime.as_mut(),
+                    ) {
+                        (Some(handler), Some(runtime)) => {
+                            handler(&id, runtime);
+                            tracing::debug!("children_dirty set at line {}", line!());
+                            self.children_dirty = true;
                         }
-                    }
-                    QuickMenuAction::OpenMenu => {
-                        self.pause_pressed = true;
+                        _ => tracing::warn!("No handler for quick menu entry '{}'", id),
                     }
                 }
-                quick_menu.clear_pending_action();
             }
         }
 
+        // Handle quick save (quick menu button or bound key)
+        if self.quick_save_pressed {
+            self.quick_save();
+        }
+
+        // Handle quick load (quick menu button or bound key)
+        if self.quick_load_pressed {
+            self.quick_load();
+            return;
+        }
+
         // Handle auto mode toggle
         if self.auto_mode_toggle_pressed {
             self.config.gameplay.auto_mode_enabled = !self.config.gameplay.auto_mode_enabled;
@@ -115,6 +137,17 @@ impl GameRootElement {
             self.children_dirty = true;
         }
 
+        // Handle rollback / roll forward on dialogue lines
+        if (self.rollback_pressed || self.roll_forward_pressed)
+            && matches!(
+                self.app_state,
+                AppState::InGame(InGameState::Typing(_) | InGameState::WaitingInput(_))
+            )
+        {
+            self.step_rollback(self.roll_forward_pressed);
+            return;
+        }
+
         if let AppState::InGame(in_game_state) = &mut self.app_state {
             match in_game_state {
                 InGameState::Typing(typing) => {
@@ -157,46 +190,30 @@ impl GameRootElement {
                             }
                         };
 
-                    // Inline typewriter logic to avoid borrow checker issues
-                    typing.elapsed += delta;
-
-                    let text_len = typing.text.chars().count();
-                    let old_char_index = typing.char_index;
+                    let old_visible_chars = typing.visible_chars();
 
-                    // Calculate character delay from text speed
-                    let char_delay = if typing.skip_mode {
-                        // In skip mode, show text instantly
-                        0.0
-                    } else if self.config.gameplay.text_speed > 0.0 {
-                        1.0 / self.config.gameplay.text_speed
+                    if typing.skip_mode || self.comfort_mode || self.clicked_last_frame {
+                        // In skip mode and comfort mode, show text instantly;
+                        // a click skips to the end
+                        typing.typewriter.skip();
                     } else {
-                        0.0
-                    };
-
-                    // Progress typewriter
-                    while typing.elapsed >= char_delay && typing.char_index < text_len {
-                        typing.char_index = typing.char_index.saturating_add(1);
-                        typing.elapsed -= char_delay;
-                    }
-
-                    // In skip mode, immediately show all text
-                    if typing.skip_mode && typing.char_index < text_len {
-                        typing.char_index = text_len;
+                        typing
+                            .typewriter
+                            .set_chars_per_second(self.config.gameplay.text_speed);
+                        typing
+                            .typewriter
+                            .update(Duration::try_from_secs_f32(delta).unwrap_or_default());
                     }
 
-                    // Handle input - skip to end
-                    if self.clicked_last_frame && typing.char_index < text_len {
-                        typing.char_index = text_len;
-                    }
-
-                    // Update DialogueBoxElement's visible_chars if char_index changed
+                    // Update DialogueBoxElement's visible_chars if the typewriter advanced
                     // This avoids rebuilding all children (which would restart character sprite transitions)
-                    if typing.char_index != old_char_index {
+                    let visible_chars = typing.visible_chars();
+                    if visible_chars != old_visible_chars {
                         for child in &mut self.children {
                             if let Some(dialogue_box) =
                                 child.as_any_mut().downcast_mut::<DialogueBoxElement>()
                             {
-                                dialogue_box.set_visible_chars(typing.char_index);
+                                dialogue_box.set_visible_chars(visible_chars);
                                 break;
                             }
                         }
@@ -205,7 +222,7 @@ impl GameRootElement {
                     // Check if we should transition
                     let should_transition =
                         (typing.skip_mode || !typing.auto_mode || self.clicked_last_frame)
-                            && typing.char_index >= text_len;
+                            && typing.is_complete();
 
                     // Transition to WaitingInput if needed
                     if should_transition {
@@ -261,9 +278,10 @@ impl GameRootElement {
                         // Calculate wait duration based on auto_advance_speed
                         let wait_duration = self.config.gameplay.auto_advance_speed;
 
-                        // Check if we should auto-advance
-                        // Note: voice waiting is not implemented yet (voice player is stub)
-                        if waiting.auto_wait_elapsed >= wait_duration {
+                        // Check if we should auto-advance, letting the voice finish first
+                        let voice_pending =
+                            self.config.gameplay.auto_wait_for_voice && self.voice_playing;
+                        if waiting.auto_wait_elapsed >= wait_duration && !voice_pending {
                             tracing::debug!(
                                 "Auto-advancing after {:.2}s (wait_duration={:.2}s)",
                                 waiting.auto_wait_elapsed,
@@ -327,17 +345,41 @@ impl GameRootElement {
                         }
                     }
 
+                    // The demo picks the first available option once it has been shown
+                    // as long as a line in auto mode
+                    if self.attract_mode && !choice_state.confirmed {
+                        self.attract_choice_elapsed += delta;
+                        if self.attract_choice_elapsed >= self.config.gameplay.auto_advance_speed {
+                            self.attract_choice_elapsed = 0.0;
+                            let disabled = self
+                                .scenario_runtime
+                                .as_ref()
+                                .map(|runtime| runtime.displayed_choices_disabled())
+                                .unwrap_or_default();
+                            selected_index = attract_choice(&disabled, choice_state.choices.len());
+                            choice_state.selected = selected_index;
+                            choice_confirmed = true;
+                        }
+                    }
+
                     // Execute choice if confirmed (choice_confirmed already includes !confirmed check)
                     if choice_confirmed {
                         tracing::debug!("Executing choice: index={}", selected_index);
                         // Execute choice in runtime
                         if let Some(runtime) = self.scenario_runtime.as_mut() {
+                            if let Some(replay) = self.replay.as_mut() {
+                                replay.record(ScriptedInput::Choose(selected_index), runtime);
+                            }
                             // select_choice() jumps to the next scene and returns transitions
                             let (exit_transition, entry_transition) =
                                 match runtime.select_choice(selected_index) {
                                     Ok(transitions) => transitions,
                                     Err(e) => {
                                         tracing::error!("Failed to select choice: {}", e);
+                                        // The choice was not made, so it is not replayed
+                                        if let Some(replay) = self.replay.as_mut() {
+                                            replay.events.pop();
+                                        }
                                         return;
                                     }
                                 };
@@ -363,18 +405,6 @@ impl GameRootElement {
 
                             // If there's an entry transition, create a TransitionState
                             if let Some(entry) = entry_transition {
-                                // Execute ShowBackground command BEFORE creating transition
-                                // This ensures the background is changed before transition starts
-                                if let Some(ScenarioCommand::ShowBackground { .. }) =
-                                    runtime.get_current_command()
-                                {
-                                    tracing::debug!("Executing ShowBackground before transition");
-                                    if let Err(e) = runtime.execute_current_command() {
-                                        tracing::error!("Failed to execute ShowBackground: {}", e);
-                                    }
-                                    runtime.advance_command();
-                                }
-
                                 // Get current scene for transition
                                 if let Some(to_scene) = runtime.current_scene() {
                                     let to_scene = to_scene.clone();
@@ -389,10 +419,6 @@ impl GameRootElement {
                                             duration: entry.duration,
                                         },
                                     );
-
-                                    // Now detect background change and schedule load
-                                    // The background has already changed, so this will schedule the load
-                                    self.update_background_if_changed();
                                     tracing::debug!("children_dirty set at line {}", line!());
                                     self.children_dirty = true;
                                 } else {
@@ -400,15 +426,8 @@ impl GameRootElement {
                                         "Failed to create transition: no current scene"
                                     );
                                     // Fall back to executing next command without transition
-                                    let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                                        tracing::warn!(
-                                            "AudioManager mutex poisoned, recovering: {}",
-                                            e
-                                        );
-                                        e.into_inner()
-                                    });
                                     if let Some(new_state) =
-                                        Self::execute_and_transition(runtime, &mut audio)
+                                        Self::execute_and_transition(runtime, &self.audio_queue)
                                     {
                                         *in_game_state = new_state;
                                         tracing::debug!("children_dirty set at line {}", line!());
@@ -417,20 +436,15 @@ impl GameRootElement {
                                 }
                             } else {
                                 // No entry transition, execute the first command of the new scene
-                                let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                                    tracing::warn!(
-                                        "AudioManager mutex poisoned, recovering: {}",
-                                        e
-                                    );
-                                    e.into_inner()
-                                });
                                 if let Some(new_state) =
-                                    Self::execute_and_transition(runtime, &mut audio)
+                                    Self::execute_and_transition(runtime, &self.audio_queue)
                                 {
                                     tracing::debug!("Choice confirmed, transitioning to new state");
                                     *in_game_state = new_state;
                                     tracing::debug!("children_dirty set at line {}", line!());
                                     self.children_dirty = true;
+                                } else if runtime.is_ended() || runtime.has_scenario_jump() {
+                                    self.handle_scenario_end();
                                 } else {
                                     tracing::error!("Failed to create state after choice");
                                 }
@@ -443,47 +457,42 @@ impl GameRootElement {
                     if transition.is_complete()
                         && let Some(runtime) = self.scenario_runtime.as_mut()
                     {
-                        let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                            tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-                            e.into_inner()
-                        });
-                        if let Some(new_state) = Self::execute_and_transition(runtime, &mut audio) {
+                        // The scene's display commands ran when the transition started;
+                        // pick up from the command after them
+                        if let Some(new_state) = Self::enter_current_command(runtime)
+                            .or_else(|| Self::execute_and_transition(runtime, &self.audio_queue))
+                        {
                             *in_game_state = new_state;
-                            // Clear previous background and CG after transition completes
-                            self.previous_background_texture_id = None;
-                            self.previous_cg_texture_id = None;
-                            self.previous_cg_texture_size = None;
+                            // A transition that follows right away captures its own frame
+                            self.transition_started = false;
                             tracing::debug!("children_dirty set at line {}", line!());
                             self.children_dirty = true;
                         } else {
                             tracing::debug!("Scenario ended after transition");
-                            self.app_state = AppState::MainMenu(MainMenuState::default());
-                            // Clear previous background and CG when scenario ends
-                            self.previous_background_texture_id = None;
-                            self.previous_cg_texture_id = None;
-                            self.previous_cg_texture_size = None;
-                            tracing::debug!("children_dirty set at line {}", line!());
-                            self.children_dirty = true;
+                            self.transition_started = false;
+                            self.handle_scenario_end();
                         }
                     }
                 }
                 InGameState::PlayingEffect(effect) => {
+                    if self.comfort_mode {
+                        effect.apply_comfort_mode();
+                    }
                     if effect.update(delta)
                         && let Some(runtime) = self.scenario_runtime.as_mut()
                     {
-                        let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                            tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-                            e.into_inner()
-                        });
-                        if let Some(new_state) = Self::execute_and_transition(runtime, &mut audio) {
+                        // Effect finished, advance to next command
+                        runtime.advance_command();
+
+                        if let Some(new_state) =
+                            Self::execute_and_transition(runtime, &self.audio_queue)
+                        {
                             *in_game_state = new_state;
                             tracing::debug!("children_dirty set at line {}", line!());
                             self.children_dirty = true;
                         } else {
                             tracing::debug!("Scenario ended after effect");
-                            self.app_state = AppState::MainMenu(MainMenuState::default());
-                            tracing::debug!("children_dirty set at line {}", line!());
-                            self.children_dirty = true;
+                            self.handle_scenario_end();
                         }
                     }
                 }
@@ -494,19 +503,122 @@ impl GameRootElement {
                         // Wait completed, advance to next command
                         runtime.advance_command();
 
-                        let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                            tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-                            e.into_inner()
-                        });
-                        if let Some(new_state) = Self::execute_and_transition(runtime, &mut audio) {
+                        if let Some(new_state) =
+                            Self::execute_and_transition(runtime, &self.audio_queue)
+                        {
                             *in_game_state = new_state;
                             tracing::debug!("children_dirty set at line {}", line!());
                             self.children_dirty = true;
                         } else {
                             tracing::debug!("Scenario ended after wait");
-                            self.app_state = AppState::MainMenu(MainMenuState::default());
+                            self.handle_scenario_end();
+                        }
+                    }
+                }
+                InGameState::PlayingVideo(video) => {
+                    let skip = self.clicked_last_frame || self.pause_pressed;
+                    let audio = self
+                        .audio_manager
+                        .get_mut()
+                        .unwrap_or_else(|e| e.into_inner());
+                    if Self::update_video(
+                        &mut self.video_player,
+                        video,
+                        delta,
+                        skip,
+                        audio,
+                        &mut self.subtitles,
+                        &mut self.subtitle_tracks,
+                    ) && let Some(runtime) = self.scenario_runtime.as_mut()
+                    {
+                        self.video_player = None;
+                        runtime.advance_command();
+
+                        if let Some(new_state) =
+                            Self::execute_and_transition(runtime, &self.audio_queue)
+                        {
+                            *in_game_state = new_state;
                             tracing::debug!("children_dirty set at line {}", line!());
                             self.children_dirty = true;
+                        } else {
+                            tracing::debug!("Scenario ended after video");
+                            self.handle_scenario_end();
+                        }
+                    }
+                }
+                InGameState::InputtingName(_) => {
+                    if self.pause_pressed {
+                        self.previous_in_game_state = Some(Box::new(in_game_state.clone()));
+                        *in_game_state = InGameState::PauseMenu(Default::default());
+                        // The name entry is rebuilt (and IME turned on again) on return
+                        self.window_operations
+                            .push(WindowOperation::SetImeAllowed(false));
+                        tracing::debug!("children_dirty set at line {}", line!());
+                        self.children_dirty = true;
+                        return;
+                    }
+
+                    // The demo enters the default name
+                    let mut entered = match in_game_state {
+                        InGameState::InputtingName(name_input) if self.attract_mode => {
+                            Some(name_input.default.clone())
+                        }
+                        _ => None,
+                    };
+                    // Names entered for `player.*` also go into the profile (not in the demo)
+                    let profile_variable = match in_game_state {
+                        InGameState::InputtingName(name_input)
+                            if !self.attract_mode
+                                && name_input.variable.starts_with(PLAYER_VARIABLE_PREFIX) =>
+                        {
+                            Some(name_input.variable.clone())
+                        }
+                        _ => None,
+                    };
+                    for child in &mut self.children {
+                        if let Some(name_input) =
+                            child.as_any_mut().downcast_mut::<NameInputElement>()
+                        {
+                            entered = name_input.take_confirmed();
+                            self.window_operations
+                                .extend(name_input.take_window_operations());
+                        }
+                    }
+
+                    if let Some(name) = entered
+                        && let Some(runtime) = self.scenario_runtime.as_mut()
+                    {
+                        if let Err(e) = runtime.submit_name(&name) {
+                            tracing::error!("Failed to store entered name: {}", e);
+                        } else if let Some(replay) = self.replay.as_mut() {
+                            replay.record(ScriptedInput::Name(name.clone()), runtime);
+                        }
+                        if let Some(variable) = profile_variable
+                            && let Some(VariableValue::String(value)) =
+                                runtime.variables().get(&VariableId::new(variable.clone()))
+                            && self.player_profile.set_from_input(&variable, value)
+                        {
+                            runtime
+                                .variables_mut()
+                                .set_player_profile(&self.player_profile);
+                            Self::save_player_profile(&self.player_profile);
+                        }
+                        // The next command may be a line that uses the name
+                        let new_state = if runtime.advance_command() {
+                            Self::enter_current_command(runtime).or_else(|| {
+                                Self::execute_and_transition(runtime, &self.audio_queue)
+                            })
+                        } else {
+                            None
+                        };
+
+                        if let Some(new_state) = new_state {
+                            *in_game_state = new_state;
+                            tracing::debug!("children_dirty set at line {}", line!());
+                            self.children_dirty = true;
+                        } else {
+                            tracing::debug!("Scenario ended after name entry");
+                            self.handle_scenario_end();
                         }
                     }
                 }
@@ -601,7 +713,12 @@ impl GameRootElement {
 
                                     // Save to file
                                     match self.save_manager.lock() {
-                                        Ok(manager) => manager.save(slot, &save_data),
+                                        Ok(manager) => save_with_thumbnail(
+                                            &manager,
+                                            slot,
+                                            &mut save_data,
+                                            &mut self.pending_save_thumbnails,
+                                        ),
                                         Err(e) => {
                                             tracing::error!("Failed to lock save_manager: {:?}", e);
                                             Err(narrative_core::EngineError::Other(
@@ -619,6 +736,7 @@ impl GameRootElement {
                                 match save_result {
                                     Ok(_) => {
                                         tracing::info!("Successfully saved to slot {}", slot);
+                                        self.unsaved_play_secs = 0.0;
                                         // Return to previous state
                                         if let Some(prev_state) = self.previous_in_game_state.take()
                                         {
@@ -646,133 +764,11 @@ impl GameRootElement {
                                     }
                                 }
                             }
-                            SaveLoadMenuAction::LoadFromSlot(slot) => {
-                                tracing::debug!("Loading from slot {}", slot);
-
-                                // Perform load operation
-                                let load_result = match self.save_manager.lock() {
-                                    Ok(manager) => manager.load(slot),
-                                    Err(e) => {
-                                        tracing::error!("Failed to lock save_manager: {:?}", e);
-                                        Err(narrative_core::EngineError::Other(
-                                            "SaveManager lock poisoned".to_string(),
-                                        ))
-                                    }
-                                };
-
-                                match load_result {
-                                    Ok(save_data) => {
-                                        tracing::info!("Successfully loaded from slot {}", slot);
-
-                                        // Update play time
-                                        self.total_play_time_secs = save_data.play_time_secs;
-
-                                        // Create or get runtime
-                                        let runtime = if let Some(runtime) =
-                                            &mut self.scenario_runtime
-                                        {
-                                            runtime
-                                        } else {
-                                            // Create new runtime from scenario file (e.g., when loading from title screen)
-                                            tracing::debug!(
-                                                "Creating new scenario runtime for load"
-                                            );
-                                            match ScenarioRuntime::from_toml(
-                                                &self.config.start_scenario,
-                                            ) {
-                                                Ok(new_runtime) => {
-                                                    self.scenario_runtime = Some(new_runtime);
-                                                }
-                                                Err(e) => {
-                                                    tracing::error!(
-                                                        "Failed to create scenario runtime: {}",
-                                                        e
-                                                    );
-                                                    self.app_state = AppState::MainMenu(
-                                                        MainMenuState::default(),
-                                                    );
-                                                    tracing::debug!(
-                                                        "children_dirty set at line {}",
-                                                        line!()
-                                                    );
-                                                    self.children_dirty = true;
-                                                    return;
-                                                }
-                                            }
-                                            // scenario_runtime is now guaranteed to be Some
-                                            if let Some(runtime) = &mut self.scenario_runtime {
-                                                runtime
-                                            } else {
-                                                // This should never happen since we just set it above
-                                                tracing::error!(
-                                                    "Critical error: scenario_runtime is None after creation"
-                                                );
-                                                self.app_state =
-                                                    AppState::MainMenu(MainMenuState::default());
-                                                tracing::debug!(
-                                                    "children_dirty set at line {}",
-                                                    line!()
-                                                );
-                                                self.children_dirty = true;
-                                                return;
-                                            }
-                                        };
-
-                                        // Set unlock data for CG tracking
-                                        runtime.set_unlock_data(Arc::clone(&self.unlock_data));
-
-                                        // Restore runtime state from save data
-                                        match runtime.from_save_data(&save_data) {
-                                            Ok(_) => {
-                                                tracing::debug!("Runtime state restored");
-                                                // Transition to gameplay - use the restored scene/index from runtime
-                                                let current_scene = runtime
-                                                    .current_scene()
-                                                    .cloned()
-                                                    .unwrap_or_else(|| {
-                                                        narrative_core::SceneId::new("")
-                                                    });
-                                                let command_index = runtime.command_index();
-
-                                                *in_game_state =
-                                                    InGameState::WaitingInput(WaitingInputState {
-                                                        scene_id: current_scene,
-                                                        command_index,
-                                                        auto_wait_elapsed: 0.0,
-                                                        skip_mode: false,
-                                                    });
-                                                tracing::debug!(
-                                                    "children_dirty set at line {}",
-                                                    line!()
-                                                );
-                                                self.children_dirty = true;
-                                            }
-                                            Err(e) => {
-                                                tracing::error!(
-                                                    "Failed to restore runtime state: {}",
-                                                    e
-                                                );
-                                                // TODO: Show error dialog
-                                                self.app_state =
-                                                    AppState::MainMenu(MainMenuState::default());
-                                                tracing::debug!(
-                                                    "children_dirty set at line {}",
-                                                    line!()
-                                                );
-                                                self.children_dirty = true;
-                                            }
-                                        }
-                                    }
-                                    Err(e) => {
-                                        tracing::error!("Failed to load from slot {}: {}", slot, e);
-                                        // TODO: Show error dialog to user
-                                        // Go back to main menu
-                                        self.app_state =
-                                            AppState::MainMenu(MainMenuState::default());
-                                        tracing::debug!("children_dirty set at line {}", line!());
-                                        self.children_dirty = true;
-                                    }
-                                }
+                            SaveLoadMenuAction::LoadFromSlot(slot)
+                            | SaveLoadMenuAction::RestartFromCheckpoint(slot) => {
+                                let from_checkpoint =
+                                    matches!(action, SaveLoadMenuAction::RestartFromCheckpoint(_));
+                                self.load_from_slot(slot, from_checkpoint);
                             }
                             SaveLoadMenuAction::DeleteSlot(slot) => {
                                 tracing::debug!("Deleting slot {}", slot);
@@ -852,6 +848,36 @@ impl GameRootElement {
                         }
                     }
 
+                    // Export the backlog or read history if requested
+                    if let Some(backlog_element) = self.children.first_mut()
+                        && let Some(backlog) = backlog_element
+                            .as_any_mut()
+                            .downcast_mut::<BacklogElement>()
+                        && let Some((source, format)) = backlog.take_export_request()
+                        && let Some(runtime) = &self.scenario_runtime
+                    {
+                        let log = TextLog::from_source(
+                            source,
+                            runtime.backlog(),
+                            runtime.read_history(),
+                            runtime.scenario(),
+                        );
+                        match log.write_to_dir(&default_export_dir(), source, format) {
+                            Ok(path) => {
+                                tracing::info!("Exported text log to {}", path.display());
+                                backlog.set_status_message(format!(
+                                    "Exported {} lines to {}",
+                                    log.lines().len(),
+                                    path.display()
+                                ));
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to export text log: {}", e);
+                                backlog.set_status_message(format!("Export failed: {}", e));
+                            }
+                        }
+                    }
+
                     // Check if BacklogElement requested close
                     if let Some(backlog_element) = self.children.first()
                         && let Some(backlog) =
@@ -1004,6 +1030,175 @@ impl GameRootElement {
                         }
                     }
                 }
+                InGameState::Achievements(_) => {
+                    let confirmed_action = self.children.iter().find_map(|child| {
+                        child
+                            .as_any()
+                            .downcast_ref::<AchievementListElement>()
+                            .and_then(|list| list.confirmed_action())
+                    });
+
+                    if let Some(AchievementListAction::Back) = confirmed_action {
+                        tracing::debug!("Returning to main menu from achievements");
+                        self.app_state = AppState::MainMenu(MainMenuState::default());
+                        tracing::debug!("children_dirty set at line {}", line!());
+                        self.children_dirty = true;
+                    }
+                }
+                InGameState::Ending(_) => {
+                    let finished = self.children.iter().any(|child| {
+                        child
+                            .as_any()
+                            .downcast_ref::<EndingScreenElement>()
+                            .is_some_and(|screen| screen.is_finished())
+                    });
+
+                    if finished {
+                        tracing::debug!("Ending screen finished, returning to title");
+                        self.app_state = AppState::MainMenu(MainMenuState::default());
+                        tracing::debug!("children_dirty set at line {}", line!());
+                        self.children_dirty = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Load a save slot and continue playing from it
+    ///
+    /// With `from_checkpoint`, restarts the scene the save was made in from its
+    /// checkpoint. Returns to the title screen if the save can't be restored.
+    pub(super) fn load_from_slot(&mut self, slot: usize, from_checkpoint: bool) {
+        tracing::debug!("Loading from slot {}", slot);
+
+        // Perform load operation
+        let load_result = match self.save_manager.lock() {
+            Ok(manager) => manager.load(slot),
+            Err(e) => {
+                tracing::error!("Failed to lock save_manager: {:?}", e);
+                Err(narrative_core::EngineError::Other(
+                    "SaveManager lock poisoned".to_string(),
+                ))
+            }
+        };
+
+        match load_result {
+            Ok(save_data) => {
+                tracing::info!("Successfully loaded from slot {}", slot);
+                self.stop_replay("a save was loaded");
+
+                // Update play time
+                self.total_play_time_secs = save_data.play_time_secs;
+                self.unsaved_play_secs = 0.0;
+
+                // Reuse the runtime if it plays the saved scenario
+                let runtime = if let Some(runtime) =
+                    self.scenario_runtime.as_mut().filter(|runtime| {
+                        save_data
+                            .scenario_id
+                            .as_ref()
+                            .is_none_or(|id| *id == runtime.scenario().metadata.id)
+                    }) {
+                    runtime
+                } else {
+                    // Create new runtime from the saved scenario's file
+                    // (e.g., when loading from title screen)
+                    let scenario_path = save_data
+                        .scenario_id
+                        .as_deref()
+                        .zip(self.project.as_ref())
+                        .and_then(|(id, project)| project.get(id))
+                        .map(|entry| entry.path.clone())
+                        .unwrap_or_else(|| self.config.start_scenario.clone());
+                    tracing::debug!(
+                        "Creating new scenario runtime for load: {}",
+                        scenario_path.display()
+                    );
+                    match ScenarioRuntime::from_toml(&scenario_path) {
+                        Ok(mut new_runtime) => {
+                            new_runtime.set_dialogue_languages(self.dialogue_languages.clone());
+                            if let Some(resolver) = &self.voice_resolver {
+                                new_runtime.set_voice_resolver(resolver.clone());
+                            }
+                            #[cfg(feature = "scripting")]
+                            new_runtime.set_script_cache_capacity(
+                                self.config.gameplay.script_cache_capacity,
+                            );
+                            self.scenario_runtime = Some(new_runtime);
+                            self.set_scenario_file(&scenario_path);
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to create scenario runtime: {}", e);
+                            self.app_state = AppState::MainMenu(MainMenuState::default());
+                            tracing::debug!("children_dirty set at line {}", line!());
+                            self.children_dirty = true;
+                            return;
+                        }
+                    }
+                    // scenario_runtime is now guaranteed to be Some
+                    if let Some(runtime) = &mut self.scenario_runtime {
+                        runtime
+                    } else {
+                        // This should never happen since we just set it above
+                        tracing::error!("Critical error: scenario_runtime is None after creation");
+                        self.app_state = AppState::MainMenu(MainMenuState::default());
+                        tracing::debug!("children_dirty set at line {}", line!());
+                        self.children_dirty = true;
+                        return;
+                    }
+                };
+
+                // Set unlock data for CG tracking
+                runtime.set_unlock_data(Arc::clone(&self.unlock_data));
+                runtime.set_achievements(Arc::clone(&self.achievements));
+
+                // Restore runtime state from save data. Saves made with
+                // another version of the scenario restart at the
+                // beginning of the nearest scene instead of failing.
+                let issues = save_data.compatibility_issues(runtime.scenario());
+                let restore_result = if from_checkpoint {
+                    runtime.restart_from_checkpoint(&save_data)
+                } else if issues.is_empty() {
+                    runtime.from_save_data(&save_data)
+                } else {
+                    for issue in &issues {
+                        tracing::warn!("Save slot {} does not match the scenario: {}", slot, issue);
+                    }
+                    runtime.from_save_data_at_scene_start(&save_data)
+                };
+                match restore_result {
+                    Ok(_) => {
+                        tracing::debug!("Runtime state restored");
+                        // Transition to gameplay - use the restored scene/index from runtime
+                        let current_scene = runtime
+                            .current_scene()
+                            .cloned()
+                            .unwrap_or_else(|| narrative_core::SceneId::new(""));
+                        let command_index = runtime.command_index();
+
+                        self.enter_in_game(InGameState::WaitingInput(WaitingInputState {
+                            scene_id: current_scene,
+                            command_index,
+                            auto_wait_elapsed: 0.0,
+                            skip_mode: false,
+                        }));
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to restore runtime state: {}", e);
+                        // TODO: Show error dialog
+                        self.app_state = AppState::MainMenu(MainMenuState::default());
+                        tracing::debug!("children_dirty set at line {}", line!());
+                        self.children_dirty = true;
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to load from slot {}: {}", slot, e);
+                // TODO: Show error dialog to user
+                // Go back to main menu
+                self.app_state = AppState::MainMenu(MainMenuState::default());
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
             }
         }
     }
@@ -1,16 +1,47 @@
 //! Input handling for GameRootElement (handle_event implementation)
 
 use super::element::GameRootElement;
-use crate::components::QuickMenuElement;
+use crate::components::{ConfirmDialogElement, HelpOverlayElement, QuickMenuElement};
+use narrative_core::config::{Binding, GamepadButton, InputAction, MouseBinding};
 use narrative_engine::runtime::{AppState, InGameState};
 use narrative_gui::framework::element::Element;
-use narrative_gui::framework::input::{InputEvent, KeyCode, MouseButton};
+use narrative_gui::framework::input::{InputEvent, KeyCode, Modifiers, MouseButton};
 use narrative_gui::framework::layout::Bounds;
 
 impl GameRootElement {
     pub(super) fn handle_event_impl(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        // The attract mode demo only listens for "press any key"
+        if self.attract_mode {
+            return self.handle_attract_mode_event(event);
+        }
+
+        // The help overlay is modal: it takes all input while shown
+        if self.help_overlay.is_visible() {
+            return self.help_overlay.handle_event(event, bounds);
+        }
+
+        // So is the developer console
+        if self.dev_console.is_visible() {
+            let handled = self.dev_console.handle_event(event, bounds);
+            self.apply_dev_console_action();
+            return handled;
+        }
+
+        // The quick load confirmation is modal as well
+        if self.showing_quick_load_confirm {
+            if let Some(dialog) = self
+                .children
+                .iter_mut()
+                .find_map(|child| child.as_any_mut().downcast_mut::<ConfirmDialogElement>())
+            {
+                dialog.handle_event(event, bounds);
+            }
+            return true;
+        }
+
         // In MainMenu state, let the TitleScreenElement handle input first
         if let AppState::MainMenu(_) = &self.app_state {
+            self.title_idle_secs = 0.0;
             // Forward event to children (TitleScreenElement)
             for child in &mut self.children {
                 if child.handle_event(event, bounds) {
@@ -31,6 +62,35 @@ impl GameRootElement {
             }
         }
 
+        // While entering a name, the NameInputElement takes all input except
+        // Escape (pause), F1 (help) and F12 (developer console), so typed keys
+        // don't trigger bindings
+        if let AppState::InGame(InGameState::InputtingName(_)) = &self.app_state {
+            for child in &mut self.children {
+                if child.handle_event(event, bounds) {
+                    return true;
+                }
+            }
+            if !matches!(
+                event,
+                InputEvent::KeyDown {
+                    key: KeyCode::Escape | KeyCode::F1 | KeyCode::F12,
+                    ..
+                }
+            ) {
+                return true;
+            }
+        }
+
+        // In Ending state, the EndingScreenElement takes all input
+        if let AppState::InGame(InGameState::Ending(_)) = &self.app_state {
+            for child in &mut self.children {
+                if child.handle_event(event, bounds) {
+                    return true;
+                }
+            }
+        }
+
         // In Backlog state, let the BacklogElement handle input first
         if let AppState::InGame(InGameState::Backlog(_)) = &self.app_state {
             // Forward event to children (BacklogElement)
@@ -52,7 +112,7 @@ impl GameRootElement {
                 }
             }
             // For Settings state, don't process default GameRoot input handling
-            // Only process keyboard shortcuts (Escape, F1) which are handled below
+            // Only process keyboard shortcuts (Escape, F1, ?) which are handled below
             // This prevents mouse events from being consumed by GameRoot
         }
 
@@ -89,6 +149,16 @@ impl GameRootElement {
             }
         }
 
+        // In Achievements state, let the AchievementListElement handle input first
+        if let AppState::InGame(InGameState::Achievements(_)) = &self.app_state {
+            for child in &mut self.children {
+                if child.handle_event(event, bounds) {
+                    tracing::debug!("Achievements: Event handled by child element");
+                    return true;
+                }
+            }
+        }
+
         // In CgViewer state, let the CgViewerElement handle input first
         if let AppState::InGame(InGameState::CgViewer(_)) = &self.app_state {
             // Forward event to children (CgViewerElement)
@@ -127,45 +197,42 @@ impl GameRootElement {
             }
         }
 
-        // Handle input events at GameRoot level (not in Settings state)
+        // Mouse buttons bound to actions (not in Settings state)
         if !matches!(self.app_state, AppState::Settings(_))
             && let InputEvent::MouseDown { button, .. } = event
+            && let Some(binding) = mouse_binding(*button)
+            && let Some(action) = self.bindings.action_for(&binding)
+            && self.trigger_action(action)
         {
-            match button {
-                MouseButton::Left => {
-                    tracing::debug!("GameRootElement: Left mouse button pressed");
-                    self.clicked_last_frame = true;
-                    return true;
-                }
-                MouseButton::Right => {
-                    // Right click - toggle UI visibility (only in Typing/WaitingInput states)
-                    if matches!(
-                        self.app_state,
-                        AppState::InGame(InGameState::Typing(_) | InGameState::WaitingInput(_))
-                    ) {
-                        self.ui_hidden = !self.ui_hidden;
-                        tracing::debug!("children_dirty set at line {}", line!());
-                        self.children_dirty = true; // Force rebuild to hide/show UI
-                        tracing::debug!(
-                            "UI visibility toggled by right-click: {}",
-                            if self.ui_hidden { "hidden" } else { "visible" }
-                        );
-                        return true;
-                    }
-                }
-                _ => {}
+            return true;
+        }
+
+        // Mouse wheel rolls dialogue back (up) or forward (down)
+        if let InputEvent::MouseScroll { delta, .. } = event
+            && matches!(
+                self.app_state,
+                AppState::InGame(InGameState::Typing(_) | InGameState::WaitingInput(_))
+            )
+        {
+            if delta.y > 0.0 {
+                self.rollback_pressed = true;
+            } else if delta.y < 0.0 {
+                self.roll_forward_pressed = true;
             }
+            return true;
         }
 
-        // Handle keyboard shortcuts (works in all states)
+        // Keys bound to actions (see `UserSettings::bindings`)
+        if let InputEvent::KeyDown { key, .. } = event
+            && let Some(action) = self.bindings.action_for_key(&key.name())
+            && self.trigger_action(action)
+        {
+            return true;
+        }
+
+        // Handle fixed keyboard shortcuts (works in all states)
         match event {
             InputEvent::KeyDown { key, .. } => match key {
-                KeyCode::Enter | KeyCode::Space => {
-                    // Enter/Space key acts as click for dialogue progression
-                    tracing::debug!("GameRootElement: Enter/Space key pressed (acts as click)");
-                    self.clicked_last_frame = true;
-                    true
-                }
                 KeyCode::Escape => {
                     // Escape key - open settings from main menu, or go back if already in settings
                     if matches!(self.app_state, AppState::Settings(_))
@@ -178,53 +245,29 @@ impl GameRootElement {
                     true
                 }
                 KeyCode::F1 => {
-                    // F1 key - toggle settings from anywhere (except loading)
-                    if !matches!(self.app_state, AppState::Loading(_)) {
-                        self.toggle_settings_menu();
-                        true
-                    } else {
-                        false
-                    }
+                    // F1 key - show keyboard shortcuts from anywhere (except loading)
+                    self.show_help()
                 }
-                KeyCode::A => {
-                    // A key - toggle auto mode (only in game)
-                    if matches!(self.app_state, AppState::InGame(_)) {
-                        self.auto_mode_toggle_pressed = true;
-                        true
-                    } else {
-                        false
-                    }
+                KeyCode::F12 => {
+                    // F12 key - open the developer console (debug builds only)
+                    self.show_dev_console()
                 }
-                KeyCode::S => {
-                    // S key - toggle skip mode (only in game)
-                    if matches!(self.app_state, AppState::InGame(_)) {
-                        self.skip_mode_toggle_pressed = true;
-                        true
-                    } else {
-                        false
-                    }
-                }
-                KeyCode::B => {
-                    // B key - toggle backlog (open or close)
-                    if matches!(self.app_state, AppState::InGame(_)) {
-                        self.backlog_pressed = true;
-                        return true;
-                    }
-                    false
+                KeyCode::F5 if self.config.development.debug_mode => {
+                    // F5 key - reload UI theme and user settings (debug mode only)
+                    self.reload_ui_theme_and_settings();
+                    true
                 }
-                KeyCode::H => {
-                    // H key - toggle UI visibility (only in Typing/WaitingInput states)
+                KeyCode::PageUp | KeyCode::PageDown => {
+                    // Page Up / Page Down - roll dialogue back or forward
                     if matches!(
                         self.app_state,
                         AppState::InGame(InGameState::Typing(_) | InGameState::WaitingInput(_))
                     ) {
-                        self.ui_hidden = !self.ui_hidden;
-                        tracing::debug!("children_dirty set at line {}", line!());
-                        self.children_dirty = true; // Force rebuild to hide/show UI
-                        tracing::debug!(
-                            "UI visibility toggled: {}",
-                            if self.ui_hidden { "hidden" } else { "visible" }
-                        );
+                        if *key == KeyCode::PageUp {
+                            self.rollback_pressed = true;
+                        } else {
+                            self.roll_forward_pressed = true;
+                        }
                         true
                     } else {
                         false
@@ -232,7 +275,152 @@ impl GameRootElement {
                 }
                 _ => false,
             },
+            // ? - show keyboard shortcuts, like F1
+            InputEvent::CharInput { .. } if HelpOverlayElement::is_toggle_event(event) => {
+                self.show_help()
+            }
+            _ => false,
+        }
+    }
+
+    /// Feed gamepad input into the keyboard input path
+    ///
+    /// Called once per frame from `tick`. The D-pad and left stick press the
+    /// arrow keys, the confirm button Enter and the pause button Escape, so
+    /// the menus and choices navigate as they do from the keyboard. Other
+    /// buttons trigger the action they are bound to, except while an overlay
+    /// or the settings menu is open. Any button leaves the attract mode demo.
+    ///
+    /// Returns true if any input was handled.
+    pub(super) fn update_gamepad(&mut self) -> bool {
+        self.gamepad.poll_gamepads();
+        let state = self.gamepad.state();
+        let settings = state.gamepad_settings();
+        let keys: Vec<KeyCode> = [
+            (state.up_pressed(), KeyCode::Up),
+            (state.down_pressed(), KeyCode::Down),
+            (state.left_pressed(), KeyCode::Left),
+            (state.right_pressed(), KeyCode::Right),
+            (state.confirm_pressed(), KeyCode::Enter),
+            (state.pause_pressed(), KeyCode::Escape),
+        ]
+        .into_iter()
+        .filter_map(|(pressed, key)| pressed.then_some(key))
+        .collect();
+        let buttons: Vec<GamepadButton> = state
+            .just_pressed_gamepad_buttons()
+            .filter(|_| settings.enabled)
+            .collect();
+        let actions: Vec<InputAction> = buttons
+            .iter()
+            .filter(|&&button| {
+                !button.is_dpad() && button != settings.confirm && button != settings.pause
+            })
+            .filter_map(|&button| self.bindings.action_for(&Binding::Gamepad(button)))
+            .collect();
+        self.gamepad.update();
+
+        if self.attract_mode {
+            if buttons.is_empty() && keys.is_empty() {
+                return false;
+            }
+            self.stop_attract_mode();
+            self.start_title_bgm();
+            return true;
+        }
+
+        let bounds = Bounds::new(0.0, 0.0, self.window_size.0, self.window_size.1);
+        let mut handled = false;
+        for key in keys {
+            let event = InputEvent::KeyDown {
+                key,
+                modifiers: Modifiers::none(),
+            };
+            handled |= self.handle_event_impl(&event, bounds);
+        }
+        for action in actions {
+            if self.help_overlay.is_visible()
+                || self.dev_console.is_visible()
+                || self.showing_quick_load_confirm
+                || matches!(self.app_state, AppState::Settings(_))
+            {
+                break;
+            }
+            handled |= self.trigger_action(action);
+        }
+        handled
+    }
+
+    /// Show the keyboard shortcut help for the current bindings
+    ///
+    /// Returns false while loading, when there's nothing to help with yet.
+    fn show_help(&mut self) -> bool {
+        if matches!(self.app_state, AppState::Loading(_)) {
+            return false;
+        }
+        self.help_overlay.show(&self.bindings);
+        true
+    }
+
+    /// Perform a bound action
+    ///
+    /// Returns false if the action doesn't apply in the current state, so the
+    /// key can still be handled as a fixed shortcut.
+    fn trigger_action(&mut self, action: InputAction) -> bool {
+        let in_game = matches!(self.app_state, AppState::InGame(_));
+        let in_dialogue = matches!(
+            self.app_state,
+            AppState::InGame(InGameState::Typing(_) | InGameState::WaitingInput(_))
+        );
+        match action {
+            InputAction::Advance => {
+                // Advances dialogue; menus handle their own clicks first
+                tracing::debug!("GameRootElement: advance pressed (acts as click)");
+                self.clicked_last_frame = true;
+                true
+            }
+            InputAction::Skip if in_game => {
+                self.skip_mode_toggle_pressed = true;
+                true
+            }
+            InputAction::Auto if in_game => {
+                self.auto_mode_toggle_pressed = true;
+                true
+            }
+            InputAction::Backlog if in_game => {
+                // Toggles the backlog (open or close)
+                self.backlog_pressed = true;
+                true
+            }
+            InputAction::HideUi if in_dialogue => {
+                self.ui_hidden = !self.ui_hidden;
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true; // Force rebuild to hide/show UI
+                tracing::debug!(
+                    "UI visibility toggled: {}",
+                    if self.ui_hidden { "hidden" } else { "visible" }
+                );
+                true
+            }
+            InputAction::QuickSave if in_dialogue => {
+                self.quick_save_pressed = true;
+                true
+            }
+            InputAction::QuickLoad if in_dialogue => {
+                self.quick_load_pressed = true;
+                true
+            }
             _ => false,
         }
     }
 }
+
+/// Mouse button as a binding, for the buttons that can be bound
+fn mouse_binding(button: MouseButton) -> Option<Binding> {
+    match button {
+        MouseButton::Left => Some(Binding::Mouse(MouseBinding::Left)),
+        MouseButton::Right => Some(Binding::Mouse(MouseBinding::Right)),
+        MouseButton::Middle => Some(Binding::Mouse(MouseBinding::Middle)),
+        _ => None,
+    }
+}
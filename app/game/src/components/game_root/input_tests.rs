@@ -1,14 +1,17 @@
 //! Tests for input handling (input.rs)
 
+use super::attract::attract_choice;
 use super::element::GameRootElement;
+use narrative_core::config::{Binding, Bindings, GamepadButton, InputAction, MouseBinding};
 use narrative_core::types::SceneId;
 use narrative_engine::EngineConfig;
+use narrative_engine::app::AttractModeConfig;
+use narrative_engine::input::GamepadEvent;
 use narrative_engine::runtime::{
-    AppState, ChoiceState, InGameState, TypingState, WaitingInputState,
+    AppState, ChoiceState, InGameState, MainMenuState, TypingState, WaitingInputState,
 };
 use narrative_gui::framework::input::{InputEvent, KeyCode, Modifiers, MouseButton};
 use narrative_gui::framework::layout::{Bounds, Point};
-use std::sync::Arc;
 
 #[test]
 fn test_input_handling() {
@@ -33,16 +36,12 @@ fn test_ui_hidden_toggle_in_typing_state() {
     let mut root = GameRootElement::new(config);
 
     // Setup: Transition to InGame with Typing state
-    root.app_state = AppState::InGame(InGameState::Typing(TypingState {
-        scene_id: SceneId::new("test_scene"),
-        command_index: 0,
-        speaker: None,
-        text: Arc::from("Test dialogue"),
-        char_index: 0,
-        elapsed: 0.0,
-        auto_mode: false,
-        skip_mode: false,
-    }));
+    root.app_state = AppState::InGame(InGameState::Typing(TypingState::new(
+        SceneId::new("test_scene"),
+        0,
+        None,
+        "Test dialogue".to_string(),
+    )));
 
     // Initially ui_hidden should be false
     assert!(!root.ui_hidden);
@@ -145,3 +144,314 @@ fn test_ui_hidden_not_allowed_in_other_states() {
     // ui_hidden should still be false
     assert!(!root.ui_hidden);
 }
+
+#[test]
+fn test_mouse_wheel_requests_rollback_on_dialogue() {
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.app_state = AppState::InGame(InGameState::WaitingInput(WaitingInputState {
+        scene_id: SceneId::new("test_scene"),
+        command_index: 0,
+        auto_wait_elapsed: 0.0,
+        skip_mode: false,
+    }));
+    let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+    let scroll = |y: f32| InputEvent::MouseScroll {
+        delta: Point::new(0.0, y),
+        position: Point::new(50.0, 50.0),
+        modifiers: Modifiers::none(),
+    };
+
+    assert!(root.handle_event_impl(&scroll(1.0), bounds));
+    assert!(root.rollback_pressed);
+    assert!(!root.roll_forward_pressed);
+
+    assert!(root.handle_event_impl(&scroll(-1.0), bounds));
+    assert!(root.roll_forward_pressed);
+
+    root.rollback_pressed = false;
+    let page_up = InputEvent::KeyDown {
+        key: KeyCode::PageUp,
+        modifiers: Modifiers::none(),
+    };
+    assert!(root.handle_event_impl(&page_up, bounds));
+    assert!(root.rollback_pressed);
+}
+
+#[test]
+fn test_f5_reloads_theme_only_in_debug_mode() {
+    let f5 = InputEvent::KeyDown {
+        key: KeyCode::F5,
+        modifiers: Modifiers::none(),
+    };
+    let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.children_dirty = false;
+    assert!(!root.handle_event_impl(&f5, bounds));
+    assert!(!root.children_dirty);
+    assert!(!root.theme_assets_pending);
+
+    let mut config = EngineConfig::default();
+    config.development.debug_mode = true;
+    let mut root = GameRootElement::new(config);
+    root.children_dirty = false;
+    assert!(root.handle_event_impl(&f5, bounds));
+    assert!(root.children_dirty);
+    assert!(root.theme_assets_pending);
+}
+
+#[test]
+fn test_rebound_keys_trigger_actions() {
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.app_state = AppState::InGame(InGameState::WaitingInput(WaitingInputState {
+        scene_id: SceneId::new("test_scene"),
+        command_index: 0,
+        auto_wait_elapsed: 0.0,
+        skip_mode: false,
+    }));
+    root.bindings = Bindings::default();
+    root.bindings
+        .rebind(InputAction::QuickSave, Binding::key("F6"));
+    root.bindings
+        .rebind(InputAction::Skip, Binding::Mouse(MouseBinding::Middle));
+    let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+    let key = |key| InputEvent::KeyDown {
+        key,
+        modifiers: Modifiers::none(),
+    };
+
+    assert!(root.handle_event_impl(&key(KeyCode::F6), bounds));
+    assert!(root.quick_save_pressed);
+    assert!(!root.handle_event_impl(&key(KeyCode::Q), bounds));
+    assert!(root.handle_event_impl(&key(KeyCode::L), bounds));
+    assert!(root.quick_load_pressed);
+
+    let middle_click = InputEvent::MouseDown {
+        position: Point::new(50.0, 50.0),
+        button: MouseButton::Middle,
+        modifiers: Modifiers::none(),
+    };
+    assert!(root.handle_event_impl(&middle_click, bounds));
+    assert!(root.skip_mode_toggle_pressed);
+
+    // Binding Space to backlog stops it from advancing
+    root.bindings
+        .rebind(InputAction::Backlog, Binding::key("Space"));
+    assert!(root.handle_event_impl(&key(KeyCode::Space), bounds));
+    assert!(root.backlog_pressed);
+    assert!(!root.clicked_last_frame);
+}
+
+#[test]
+fn test_help_overlay_takes_input_while_shown() {
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.app_state = AppState::InGame(InGameState::Typing(TypingState::new(
+        SceneId::new("test_scene"),
+        0,
+        None,
+        "Test dialogue".to_string(),
+    )));
+    let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+    let key = |key| InputEvent::KeyDown {
+        key,
+        modifiers: Modifiers::none(),
+    };
+
+    // Remapped bindings are listed
+    root.bindings.rebind(InputAction::Skip, Binding::key("K"));
+    assert!(root.handle_event_impl(&key(KeyCode::F1), bounds));
+    assert!(root.help_overlay.is_visible());
+    assert!(
+        root.help_overlay.sections()[0]
+            .entries
+            .iter()
+            .any(|entry| entry.label == "Skip" && entry.keys.contains('K'))
+    );
+
+    // Bound keys don't reach the game underneath
+    assert!(root.handle_event_impl(&key(KeyCode::K), bounds));
+    assert!(!root.skip_mode_toggle_pressed);
+
+    assert!(root.handle_event_impl(&key(KeyCode::Escape), bounds));
+    assert!(!root.help_overlay.is_visible());
+    assert!(!root.pause_pressed);
+
+    // ? opens it too
+    assert!(root.handle_event_impl(&InputEvent::CharInput { character: '?' }, bounds));
+    assert!(root.help_overlay.is_visible());
+}
+
+#[test]
+fn test_attract_mode_returns_to_title_on_any_key() {
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.app_state = AppState::InGame(InGameState::Typing(TypingState::new(
+        SceneId::new("demo"),
+        0,
+        None,
+        "Demo dialogue".to_string(),
+    )));
+    root.attract_mode = true;
+    root.config.gameplay.auto_mode_enabled = true;
+    let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+
+    // Moving the mouse doesn't end the demo, and nothing reaches the game
+    let mouse_move = InputEvent::MouseMove {
+        position: Point::new(10.0, 10.0),
+        modifiers: Modifiers::none(),
+    };
+    assert!(root.handle_event_impl(&mouse_move, bounds));
+    assert!(root.attract_mode);
+
+    let event = InputEvent::KeyDown {
+        key: KeyCode::A,
+        modifiers: Modifiers::none(),
+    };
+    assert!(root.handle_event_impl(&event, bounds));
+    assert!(!root.attract_mode);
+    assert!(matches!(root.app_state, AppState::MainMenu(_)));
+    assert!(!root.config.gameplay.auto_mode_enabled);
+    assert!(!root.skip_mode_toggle_pressed);
+}
+
+fn press(root: &mut GameRootElement, button: GamepadButton) {
+    root.gamepad.process_gamepad_event(GamepadEvent::Button {
+        button,
+        pressed: true,
+    });
+    root.gamepad.process_gamepad_event(GamepadEvent::Button {
+        button,
+        pressed: false,
+    });
+}
+
+#[test]
+fn test_gamepad_buttons_act_like_keys() {
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.bindings = Bindings::default();
+    root.app_state = AppState::InGame(InGameState::Typing(TypingState::new(
+        SceneId::new("test_scene"),
+        0,
+        None,
+        "Test dialogue".to_string(),
+    )));
+
+    // Nothing pressed
+    assert!(!root.update_gamepad());
+
+    // Confirm presses Enter, which advances
+    press(&mut root, GamepadButton::South);
+    assert!(root.update_gamepad());
+    assert!(root.clicked_last_frame);
+
+    // Other buttons trigger their bound action
+    press(&mut root, GamepadButton::East);
+    assert!(root.update_gamepad());
+    assert!(root.ui_hidden);
+    press(&mut root, GamepadButton::LeftShoulder);
+    root.update_gamepad();
+    assert!(root.backlog_pressed);
+
+    // Pause presses Escape
+    press(&mut root, GamepadButton::Start);
+    root.update_gamepad();
+    assert!(root.pause_pressed);
+
+    // The help overlay takes no bound actions
+    root.help_overlay.show(&root.bindings);
+    press(&mut root, GamepadButton::East);
+    root.update_gamepad();
+    assert!(root.ui_hidden);
+}
+
+#[test]
+fn test_gamepad_ends_attract_mode() {
+    let mut root = GameRootElement::new(EngineConfig::default());
+    root.app_state = AppState::InGame(InGameState::Typing(TypingState::new(
+        SceneId::new("demo"),
+        0,
+        None,
+        "Demo dialogue".to_string(),
+    )));
+    root.attract_mode = true;
+
+    press(&mut root, GamepadButton::Select);
+    assert!(root.update_gamepad());
+    assert!(!root.attract_mode);
+    assert!(matches!(root.app_state, AppState::MainMenu(_)));
+}
+
+#[test]
+fn test_attract_mode_stays_on_title_when_demo_missing() {
+    let mut config = EngineConfig::default();
+    config.ui.attract_mode = Some(AttractModeConfig {
+        scenario: "nonexistent/demo.toml".into(),
+        idle_secs: 1.0,
+    });
+    let mut root = GameRootElement::new(config);
+    root.app_state = AppState::MainMenu(MainMenuState::default());
+
+    root.update_attract_mode(0.5);
+    assert_eq!(root.title_idle_secs, 0.5);
+
+    root.update_attract_mode(0.6);
+    assert!(!root.attract_mode);
+    assert!(matches!(root.app_state, AppState::MainMenu(_)));
+    assert_eq!(root.title_idle_secs, 0.0);
+    assert!(!root.config.gameplay.auto_mode_enabled);
+}
+
+#[test]
+fn test_attract_choice_skips_disabled_options() {
+    assert_eq!(attract_choice(&[true, false, false], 3), 1);
+    assert_eq!(attract_choice(&[], 2), 0);
+    assert_eq!(attract_choice(&[true, true], 2), 0);
+}
+
+#[test]
+fn test_dev_console_jumps_to_scene() {
+    use narrative_core::{Dialogue, Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+    use narrative_engine::runtime::ScenarioRuntime;
+
+    let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+    for (id, line) in [("ending", "The end."), ("start", "Hello.")] {
+        let mut scene = Scene::new(id, id);
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::narrator(line),
+        });
+        scenario.add_scene(id, scene);
+    }
+    let mut runtime = ScenarioRuntime::new(scenario);
+    runtime.start().unwrap();
+
+    let mut root = GameRootElement::new(EngineConfig::default());
+    let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+    let key = |key| InputEvent::KeyDown {
+        key,
+        modifiers: Modifiers::none(),
+    };
+
+    // Nothing to list on the title screen
+    root.app_state = AppState::MainMenu(MainMenuState::default());
+    assert!(!root.handle_event_impl(&key(KeyCode::F12), bounds));
+
+    root.app_state =
+        AppState::InGame(GameRootElement::enter_current_command(&mut runtime).unwrap());
+    root.scenario_runtime = Some(runtime);
+    assert!(root.handle_event_impl(&key(KeyCode::F12), bounds));
+    assert!(root.dev_console.is_visible());
+
+    // Keys go to the console; "ending" is listed before the current scene
+    assert!(root.handle_event_impl(&key(KeyCode::Up), bounds));
+    assert!(root.handle_event_impl(&key(KeyCode::Enter), bounds));
+    assert!(!root.dev_console.is_visible());
+    assert!(!root.clicked_last_frame);
+
+    let AppState::InGame(InGameState::Typing(typing)) = &root.app_state else {
+        panic!(
+            "Expected the first line of the scene, got {:?}",
+            root.app_state
+        );
+    };
+    assert_eq!(typing.scene_id.as_str(), "ending");
+    assert_eq!(typing.text.as_ref(), "The end.");
+}
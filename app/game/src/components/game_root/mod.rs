@@ -1,14 +1,29 @@
 //! Game root element - main game UI container
 
+mod achievements;
+mod attract;
 mod audio;
 mod children;
+mod dev_console;
 mod element;
+mod hot_reload;
+mod hud;
 mod in_game;
 mod input;
+mod particles;
+mod preload;
+mod quick_save;
 mod rendering;
+mod replay;
+mod save_reminder;
+mod speech;
 mod state;
+#[cfg(feature = "steam")]
+mod steam;
 mod textures;
+mod theme;
 mod transitions;
+mod video;
 
 #[cfg(test)]
 mod element_tests;
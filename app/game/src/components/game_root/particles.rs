@@ -0,0 +1,90 @@
+//! Particle effects (ShowEffect) for GameRootElement
+
+use super::element::GameRootElement;
+use narrative_core::asset::ParticleShape;
+use narrative_engine::EngineConfig;
+use narrative_engine::asset::ParticleEffectRegistry;
+use narrative_engine::runtime::{AppState, InGameState};
+use narrative_gui::framework::element::PaintContext;
+
+/// Particle effect manifest, relative to the asset directory
+const EFFECTS_MANIFEST: &str = "manifests/effects.ron";
+
+/// Load the built-in particle effects and those in the effect manifest
+///
+/// A missing manifest leaves just the built-in effects.
+pub(super) fn load_particle_effects(config: &EngineConfig) -> ParticleEffectRegistry {
+    let mut registry = ParticleEffectRegistry::new(config.asset_path.clone());
+    if config.asset_path.join(EFFECTS_MANIFEST).exists()
+        && let Err(e) = registry.load_manifest(EFFECTS_MANIFEST)
+    {
+        tracing::warn!(
+            "Failed to load particle effects, using built-in ones: {}",
+            e
+        );
+    }
+    registry
+}
+
+impl GameRootElement {
+    /// Advance running effects and their particles
+    ///
+    /// Particles move while the scene plays and freeze under in-game menus.
+    /// Leaving the game clears them.
+    ///
+    /// Returns: true if particles need to be redrawn
+    pub(super) fn update_particles(&mut self, delta: f32) -> bool {
+        let playing = matches!(
+            self.app_state,
+            AppState::InGame(
+                InGameState::Typing(_)
+                    | InGameState::WaitingInput(_)
+                    | InGameState::ShowingChoices(_)
+                    | InGameState::Transition(_)
+                    | InGameState::PlayingEffect(_)
+                    | InGameState::Waiting(_)
+                    | InGameState::PlayingVideo(_)
+                    | InGameState::InputtingName(_)
+            )
+        );
+        let in_game = matches!(self.app_state, AppState::InGame(_) | AppState::Settings(_));
+
+        match &mut self.scenario_runtime {
+            Some(runtime) if playing => {
+                runtime.update_effects(delta);
+                self.particles
+                    .sync(runtime.active_effects(), &self.particle_effects);
+                self.particles.update(delta, self.window_size)
+            }
+            Some(_) if in_game => false,
+            _ => {
+                let had_particles = !self.particles.is_idle();
+                self.particles.clear();
+                had_particles
+            }
+        }
+    }
+
+    /// Draw the particles of running effects over the scene
+    pub(super) fn paint_particles(&self, cx: &mut PaintContext) {
+        let origin = cx.bounds.origin;
+        for particle in self.particles.particles() {
+            let [width, height] = particle.size;
+            let bounds = narrative_gui::Bounds {
+                origin: narrative_gui::Point::new(
+                    origin.x + particle.position[0] - width / 2.0,
+                    origin.y + particle.position[1] - height / 2.0,
+                ),
+                size: narrative_gui::Size::new(width, height),
+            };
+            let [r, g, b, a] = particle.color;
+            let color = narrative_gui::Color::new(r, g, b, a);
+            // Flakes and sparks are round; streaks and petals have rounded ends
+            let corner_radius = match particle.shape {
+                ParticleShape::Snow | ParticleShape::Spark => width / 2.0,
+                ParticleShape::Rain | ParticleShape::Petal => width.min(height) / 2.0,
+            };
+            cx.fill_rounded_rect(bounds, color, corner_radius);
+        }
+    }
+}
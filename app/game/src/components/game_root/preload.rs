@@ -0,0 +1,133 @@
+//! Asset preloading for GameRootElement
+
+use super::element::GameRootElement;
+use crate::components::LoadingScreenElement;
+use narrative_engine::asset::{PrefetchScope, PreloadKind, PreloadPriority, PreloadRequest};
+use narrative_engine::runtime::{AppState, InGameState, LoadingState};
+
+impl GameRootElement {
+    /// Preload the assets of the commands ahead of the playback position
+    ///
+    /// Scans again whenever the position moves, so assets of the next lines
+    /// and the scenes they lead to are decoded before their commands run.
+    pub(super) fn update_asset_preload(&mut self) {
+        self.asset_preloader.update();
+
+        let playing = match &self.app_state {
+            AppState::InGame(_) => true,
+            AppState::Loading(loading) => loading.next.is_some(),
+            _ => false,
+        };
+        let position = self
+            .scenario_runtime
+            .as_ref()
+            .filter(|_| playing)
+            .and_then(|runtime| {
+                runtime
+                    .current_scene()
+                    .map(|scene| (scene.as_str().to_string(), runtime.command_index()))
+            });
+        if position == self.preloaded_position {
+            return;
+        }
+        let requests = if position.is_some() {
+            self.upcoming_asset_requests()
+        } else {
+            Vec::new()
+        };
+        let queued = self.asset_preloader.preload(requests);
+        if queued > 0 {
+            tracing::debug!("Preloading {} assets ahead of {:?}", queued, position);
+        }
+        self.preloaded_position = position;
+    }
+
+    /// Enter a game state once the assets it shows are loaded
+    ///
+    /// Preloads the displayed background, CG and characters along with the
+    /// assets of the commands ahead, and shows the loading screen until the
+    /// urgent ones are decoded. Enters the state right away if they already
+    /// are.
+    pub(super) fn enter_in_game(&mut self, state: InGameState) {
+        let mut requests = Vec::new();
+        if let Some(runtime) = &self.scenario_runtime {
+            let shown = runtime
+                .current_background()
+                .into_iter()
+                .chain(runtime.current_cg())
+                .chain(
+                    runtime
+                        .displayed_characters()
+                        .values()
+                        .map(|character| &character.sprite),
+                );
+            requests.extend(shown.map(|asset| {
+                PreloadRequest::new(asset.clone(), PreloadKind::Image, PreloadPriority::High)
+            }));
+            self.preloaded_position = runtime
+                .current_scene()
+                .map(|scene| (scene.as_str().to_string(), runtime.command_index()));
+        }
+        requests.extend(self.upcoming_asset_requests());
+        self.asset_preloader.preload(requests);
+
+        self.app_state = if self.asset_preloader.progress().is_complete() {
+            AppState::InGame(state)
+        } else {
+            tracing::debug!("Loading assets before entering the game");
+            AppState::Loading(LoadingState::entering(state))
+        };
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+    }
+
+    /// Show the loading progress, entering the game when it completes
+    pub(super) fn update_asset_loading(&mut self) {
+        self.asset_preloader.update();
+        let progress = self.asset_preloader.progress();
+        let AppState::Loading(loading) = &mut self.app_state else {
+            return;
+        };
+        match loading.update_progress(&progress) {
+            Some(state) => {
+                tracing::debug!("Assets loaded, entering the game");
+                self.app_state = AppState::InGame(state);
+            }
+            None => {
+                let (progress, task) = (loading.progress, loading.current_task.clone());
+                for child in &mut self.children {
+                    if let Some(screen) = child.as_any_mut().downcast_mut::<LoadingScreenElement>()
+                    {
+                        screen.set_progress(progress, &task);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Assets of the commands ahead of the playback position
+    ///
+    /// Voice paths are joined to the asset directory, like when they play.
+    fn upcoming_asset_requests(&self) -> Vec<PreloadRequest> {
+        let Some(runtime) = &self.scenario_runtime else {
+            return Vec::new();
+        };
+        runtime
+            .upcoming_assets(PrefetchScope::default())
+            .into_iter()
+            .map(|request| match request.kind {
+                PreloadKind::Voice => PreloadRequest {
+                    asset: self
+                        .config
+                        .asset_path
+                        .join(request.asset.path())
+                        .to_string_lossy()
+                        .into_owned()
+                        .into(),
+                    ..request
+                },
+                _ => request,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,178 @@
+//! Quick save and quick load for GameRootElement
+
+use super::element::GameRootElement;
+use super::textures::save_with_thumbnail;
+use crate::components::{
+    ConfirmDialogElement, DialogResponse, QuickMenuAction, QuickMenuElement, ToastMessage,
+};
+use narrative_core::SaveSlotKind;
+use narrative_engine::runtime::AppState;
+use narrative_gui::components::IconType;
+
+/// Message of the quick load confirmation dialog
+pub(super) const QUICK_LOAD_CONFIRM_MESSAGE: &str =
+    "Load the quick save? Unsaved progress will be lost.";
+
+/// Heading of the quick save toast
+const QUICK_SAVE_TOAST_HEADING: &str = "Quick Saved";
+
+impl GameRootElement {
+    /// Save to the reserved quick save slot
+    ///
+    /// On success the quick menu's quick save button flashes and a toast
+    /// names the slot. When the save has a thumbnail, the toast waits until
+    /// the thumbnail is rendered so it can show it.
+    pub(super) fn quick_save(&mut self) {
+        let quicksave_slot = self.save_slots.quicksave_slot();
+        if quicksave_slot.is_none() {
+            tracing::warn!("Quick save ignored: no quick save slot configured");
+        }
+        if let (Some(runtime), Some(slot)) = (&self.scenario_runtime, quicksave_slot) {
+            let mut save_data = runtime.to_save_data(slot);
+
+            // Set timestamp and play time
+            save_data.timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_else(|e| {
+                    tracing::error!("Failed to get system time for quick save: {:?}", e);
+                    // Fallback: use 0 (will be logged as error above)
+                    0
+                });
+            save_data.play_time_secs = self.total_play_time_secs;
+
+            // Save to file
+            let saved = match self.save_manager.lock() {
+                Ok(manager) => match save_with_thumbnail(
+                    &manager,
+                    slot,
+                    &mut save_data,
+                    &mut self.pending_save_thumbnails,
+                ) {
+                    Ok(_) => {
+                        tracing::info!("Quick save successful (slot {})", slot);
+                        true
+                    }
+                    Err(e) => {
+                        tracing::error!("Quick save failed: {:?}", e);
+                        false
+                    }
+                },
+                Err(e) => {
+                    tracing::error!("Failed to lock save_manager for quick save: {:?}", e);
+                    false
+                }
+            };
+
+            if saved {
+                self.unsaved_play_secs = 0.0;
+                self.flash_quick_menu(QuickMenuAction::QuickSave);
+                if save_data.thumbnail_path.is_some() {
+                    self.pending_quick_save_toast = Some(slot);
+                } else {
+                    self.show_quick_save_toast(slot, None);
+                }
+            }
+        }
+    }
+
+    /// Show the toast for a quick save, with its thumbnail texture if any
+    pub(super) fn show_quick_save_toast(&mut self, slot: usize, thumbnail: Option<u64>) {
+        let number = self
+            .save_slots
+            .index_in_section(slot)
+            .unwrap_or_default()
+            .saturating_add(1);
+        let mut toast = ToastMessage::new(format!("{} {:02}", SaveSlotKind::Quick.label(), number))
+            .with_icon(IconType::Save)
+            .with_heading(QUICK_SAVE_TOAST_HEADING);
+        if let Some(texture_id) = thumbnail {
+            toast = toast.with_image(texture_id);
+        }
+        self.toasts.push(toast);
+    }
+
+    /// Load the quick save, asking first unless `confirm_quick_load` is off
+    pub(super) fn quick_load(&mut self) {
+        let Some(slot) = self.save_slots.quicksave_slot() else {
+            tracing::warn!("Quick load ignored: no quick save slot configured");
+            return;
+        };
+        let exists = match self.save_manager.lock() {
+            Ok(manager) => manager.slot_exists(slot),
+            Err(e) => {
+                tracing::error!("Failed to lock save_manager for quick load: {:?}", e);
+                false
+            }
+        };
+        if !exists {
+            self.toasts
+                .push(ToastMessage::new("No quick save yet").with_icon(IconType::Save));
+            return;
+        }
+
+        if self.config.gameplay.confirm_quick_load {
+            self.showing_quick_load_confirm = true;
+            tracing::debug!("children_dirty set at line {}", line!());
+            self.children_dirty = true;
+        } else {
+            self.load_quick_save(slot);
+        }
+    }
+
+    /// Apply the answer to the quick load confirmation
+    ///
+    /// Returns true while the confirmation is shown, so the game waits for it.
+    pub(super) fn update_quick_load_confirm(&mut self) -> bool {
+        if !self.showing_quick_load_confirm {
+            return false;
+        }
+        let response = self.children.iter().find_map(|child| {
+            child
+                .as_any()
+                .downcast_ref::<ConfirmDialogElement>()
+                .map(ConfirmDialogElement::response)
+        });
+        match response {
+            Some(DialogResponse::Confirmed) => {
+                self.showing_quick_load_confirm = false;
+                if let Some(slot) = self.save_slots.quicksave_slot() {
+                    self.load_quick_save(slot);
+                }
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+                false
+            }
+            Some(DialogResponse::Cancelled) => {
+                tracing::debug!("Cancelled quick load");
+                self.showing_quick_load_confirm = false;
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Load the quick save slot, showing a toast when it worked
+    fn load_quick_save(&mut self, slot: usize) {
+        self.load_from_slot(slot, false);
+        if matches!(self.app_state, AppState::InGame(_))
+            || matches!(&self.app_state, AppState::Loading(loading) if loading.next.is_some())
+        {
+            self.toasts
+                .push(ToastMessage::new("Quick save loaded").with_icon(IconType::Save));
+        }
+    }
+
+    /// Flash the quick menu buttons for an action
+    fn flash_quick_menu(&mut self, action: QuickMenuAction) {
+        if let Some(quick_menu) = self
+            .children
+            .iter_mut()
+            .find_map(|child| child.as_any_mut().downcast_mut::<QuickMenuElement>())
+        {
+            quick_menu.flash(action);
+        }
+    }
+}
@@ -2,63 +2,148 @@
 
 use super::element::GameRootElement;
 use crate::components::SettingsMenuElement;
+use narrative_core::types::transition::{SlideDirection, TransitionKind, WipeDirection};
 use narrative_engine::runtime::{AppState, InGameState};
 use narrative_gui::framework::element::{
     Element, ElementId, LayoutContext, PaintContext, WindowOperation,
 };
-use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::layout::{Bounds, Point};
 use std::any::Any;
 use std::time::Duration;
 use taffy::NodeId;
 
 impl GameRootElement {
-    /// Render dissolve transition overlay effect
+    /// Draw the outgoing frame of the scene transition being played
     ///
-    /// Creates a pixelated block pattern that masks the new background progressively
-    fn render_dissolve_overlay(
-        &self,
-        cx: &mut narrative_gui::framework::element::PaintContext,
-        progress: f32,
-    ) {
+    /// The new scene is already drawn underneath; the frame captured when the
+    /// transition started leaves it according to the transition kind. Fades
+    /// go through the fade color halfway. Without a capture (it failed to
+    /// compose), the new scene shows through from the start.
+    fn paint_transition(&self, cx: &mut PaintContext, kind: TransitionKind, progress: f32) {
+        let from = self.transition_texture_id;
+        match kind {
+            TransitionKind::Fade | TransitionKind::FadeWhite => {
+                let mut color = if kind == TransitionKind::Fade {
+                    narrative_gui::Color::new(0.0, 0.0, 0.0, 1.0)
+                } else {
+                    narrative_gui::Color::new(1.0, 1.0, 1.0, 1.0)
+                };
+                // Out of the old frame over the first half, into the new one over the second
+                color.a = if progress < 0.5 {
+                    if let Some(from) = from {
+                        cx.draw_texture(from, cx.bounds, 1.0);
+                        progress * 2.0
+                    } else {
+                        1.0
+                    }
+                } else {
+                    (1.0 - progress) * 2.0
+                };
+                cx.fill_rect(cx.bounds, color);
+            }
+            TransitionKind::Crossfade => {
+                if let Some(from) = from {
+                    cx.draw_texture(from, cx.bounds, 1.0 - progress);
+                }
+            }
+            TransitionKind::Slide(direction) => {
+                // The old frame slides out, uncovering the new scene
+                let Some(from) = from else {
+                    return;
+                };
+                let (x, y) = match direction {
+                    SlideDirection::Left => (-progress, 0.0),
+                    SlideDirection::Right => (progress, 0.0),
+                    SlideDirection::Up => (0.0, -progress),
+                    SlideDirection::Down => (0.0, progress),
+                };
+                let bounds = Bounds {
+                    origin: Point::new(
+                        cx.bounds.origin.x + x * cx.bounds.size.width,
+                        cx.bounds.origin.y + y * cx.bounds.size.height,
+                    ),
+                    size: cx.bounds.size,
+                };
+                cx.draw_texture(from, bounds, 1.0);
+            }
+            TransitionKind::Wipe(direction) => {
+                // The part of the old frame the wipe has not reached yet
+                let Some(from) = from else {
+                    return;
+                };
+                let remaining = 1.0 - progress;
+                let source = match direction {
+                    WipeDirection::Left => Bounds::new(0.0, 0.0, remaining, 1.0),
+                    WipeDirection::Right => Bounds::new(progress, 0.0, remaining, 1.0),
+                    WipeDirection::Up => Bounds::new(0.0, 0.0, 1.0, remaining),
+                    WipeDirection::Down => Bounds::new(0.0, progress, 1.0, remaining),
+                };
+                cx.draw_texture_region(from, region_of(cx.bounds, source), source, 1.0);
+            }
+            TransitionKind::Dissolve => {
+                if let Some(from) = from {
+                    Self::paint_dissolve(cx, from, progress);
+                }
+            }
+            TransitionKind::None => {}
+        }
+    }
+
+    /// Draw the blocks of the old frame that have not dissolved yet
+    fn paint_dissolve(cx: &mut PaintContext, from: u64, progress: f32) {
         // Grid size for dissolve blocks (larger = chunkier effect)
         const BLOCK_SIZE: f32 = 20.0;
 
         let width = cx.bounds.size.width;
         let height = cx.bounds.size.height;
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
 
         // Calculate number of blocks
         let cols = (width / BLOCK_SIZE).ceil() as i32;
         let rows = (height / BLOCK_SIZE).ceil() as i32;
 
-        // Draw old background blocks that haven't dissolved yet
-        if let Some(old_bg_id) = self.previous_background_texture_id {
-            // Use a pseudo-random but deterministic pattern
-            // We want the same blocks to dissolve in the same order
-            for row in 0..rows {
-                for col in 0..cols {
-                    // Create a deterministic "random" value based on position
-                    // Using a simple hash-like function for determinism
-                    let seed = ((row * 73) + (col * 151)) % 256;
-                    let threshold = (seed as f32) / 256.0;
-
-                    // If this block's threshold is greater than progress, draw old background block
-                    // (i.e., mask the new background to reveal old background underneath)
-                    if threshold > progress {
-                        let block_x = cx.bounds.origin.x + (col as f32 * BLOCK_SIZE);
-                        let block_y = cx.bounds.origin.y + (row as f32 * BLOCK_SIZE);
-
-                        let block_bounds = narrative_gui::Bounds {
-                            origin: narrative_gui::Point::new(block_x, block_y),
-                            size: narrative_gui::Size::new(BLOCK_SIZE, BLOCK_SIZE),
-                        };
-
-                        // Draw old background texture for this block
-                        cx.draw_texture(old_bg_id, block_bounds, 1.0);
-                    }
+        // Use a pseudo-random but deterministic pattern
+        // We want the same blocks to dissolve in the same order
+        for row in 0..rows {
+            for col in 0..cols {
+                // Create a deterministic "random" value based on position
+                // Using a simple hash-like function for determinism
+                let seed = ((row * 73) + (col * 151)) % 256;
+                let threshold = (seed as f32) / 256.0;
+                if threshold <= progress {
+                    continue;
                 }
+
+                // Blocks on the right and bottom edges may be cut short
+                let x = col as f32 * BLOCK_SIZE;
+                let y = row as f32 * BLOCK_SIZE;
+                let source = Bounds::new(
+                    x / width,
+                    y / height,
+                    BLOCK_SIZE.min(width - x) / width,
+                    BLOCK_SIZE.min(height - y) / height,
+                );
+                cx.draw_texture_region(from, region_of(cx.bounds, source), source, 1.0);
             }
         }
     }
+
+    /// Camera offset of the screen shake being played, if any
+    pub(super) fn screen_shake_offset(&self) -> Point {
+        // Offset of a full-intensity shake, as a fraction of the window height
+        const MAX_OFFSET_RATIO: f32 = 0.04;
+
+        match &self.app_state {
+            AppState::InGame(InGameState::PlayingEffect(effect)) => {
+                let (x, y) = effect.shake_offset(self.window_size.1 * MAX_OFFSET_RATIO);
+                Point::new(x, y)
+            }
+            _ => Point::ZERO,
+        }
+    }
+
     /// Calculate bounds that fit the given texture size within the container bounds
     /// while preserving aspect ratio. The result is centered and letterboxed/pillarboxed as needed.
     fn calculate_aspect_ratio_fit(
@@ -153,269 +238,18 @@ impl Element for GameRootElement {
     }
 
     fn paint(&self, cx: &mut PaintContext) {
-        use narrative_core::types::transition::{SlideDirection, TransitionKind, WipeDirection};
-
-        // Check if we're in a special transition that needs custom rendering
-        let transition_kind =
-            if let AppState::InGame(InGameState::Transition(transition)) = &self.app_state {
-                Some((transition.kind, transition.progress_ratio()))
-            } else {
-                None
-            };
-
-        match transition_kind {
-            // Crossfade: blend old and new backgrounds and CGs
-            Some((TransitionKind::Crossfade, progress)) => {
-                // Draw old background at full opacity
-                if let Some(old_bg_id) = self.previous_background_texture_id {
-                    cx.draw_texture(old_bg_id, cx.bounds, 1.0);
-                }
-
-                // Draw new background on top with increasing opacity
-                if let Some(new_bg_id) = self.current_background_texture_id {
-                    cx.draw_texture(new_bg_id, cx.bounds, progress);
-                }
-
-                // Draw old CG at full opacity (with aspect ratio preserved)
-                if let Some(old_cg_id) = self.previous_cg_texture_id {
-                    if let Some((width, height)) = self.previous_cg_texture_size {
-                        let cg_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        cx.draw_texture(old_cg_id, cg_bounds, 1.0);
-                    } else {
-                        cx.draw_texture(old_cg_id, cx.bounds, 1.0);
-                    }
-                }
-
-                // Draw new CG on top with increasing opacity (with aspect ratio preserved)
-                if let Some(new_cg_id) = self.current_cg_texture_id {
-                    if let Some((width, height)) = self.current_cg_texture_size {
-                        let cg_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        cx.draw_texture(new_cg_id, cg_bounds, progress);
-                    } else {
-                        cx.draw_texture(new_cg_id, cx.bounds, progress);
-                    }
-                }
-            }
-
-            // Slide transitions: move backgrounds in/out
-            Some((TransitionKind::Slide(direction), progress)) => {
-                let width = cx.bounds.size.width;
-                let height = cx.bounds.size.height;
-
-                // Calculate positions based on slide direction
-                let (old_offset, new_offset) = match direction {
-                    SlideDirection::Left => (
-                        narrative_gui::Point::new(-width * progress, 0.0),
-                        narrative_gui::Point::new(width * (1.0 - progress), 0.0),
-                    ),
-                    SlideDirection::Right => (
-                        narrative_gui::Point::new(width * progress, 0.0),
-                        narrative_gui::Point::new(-width * (1.0 - progress), 0.0),
-                    ),
-                    SlideDirection::Up => (
-                        narrative_gui::Point::new(0.0, -height * progress),
-                        narrative_gui::Point::new(0.0, height * (1.0 - progress)),
-                    ),
-                    SlideDirection::Down => (
-                        narrative_gui::Point::new(0.0, height * progress),
-                        narrative_gui::Point::new(0.0, -height * (1.0 - progress)),
-                    ),
-                };
-
-                // Draw old background sliding out
-                if let Some(old_bg_id) = self.previous_background_texture_id {
-                    let old_bounds = narrative_gui::Bounds {
-                        origin: narrative_gui::Point::new(
-                            cx.bounds.origin.x + old_offset.x,
-                            cx.bounds.origin.y + old_offset.y,
-                        ),
-                        size: cx.bounds.size,
-                    };
-                    cx.draw_texture(old_bg_id, old_bounds, 1.0);
-                }
-
-                // Draw new background sliding in
-                if let Some(new_bg_id) = self.current_background_texture_id {
-                    let new_bounds = narrative_gui::Bounds {
-                        origin: narrative_gui::Point::new(
-                            cx.bounds.origin.x + new_offset.x,
-                            cx.bounds.origin.y + new_offset.y,
-                        ),
-                        size: cx.bounds.size,
-                    };
-                    cx.draw_texture(new_bg_id, new_bounds, 1.0);
-                }
-
-                // Draw old CG sliding out (with aspect ratio preserved)
-                if let Some(old_cg_id) = self.previous_cg_texture_id {
-                    if let Some((width, height)) = self.previous_cg_texture_size {
-                        let cg_fitted_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        let old_cg_bounds = narrative_gui::Bounds {
-                            origin: narrative_gui::Point::new(
-                                cg_fitted_bounds.origin.x + old_offset.x,
-                                cg_fitted_bounds.origin.y + old_offset.y,
-                            ),
-                            size: cg_fitted_bounds.size,
-                        };
-                        cx.draw_texture(old_cg_id, old_cg_bounds, 1.0);
-                    } else {
-                        let old_cg_bounds = narrative_gui::Bounds {
-                            origin: narrative_gui::Point::new(
-                                cx.bounds.origin.x + old_offset.x,
-                                cx.bounds.origin.y + old_offset.y,
-                            ),
-                            size: cx.bounds.size,
-                        };
-                        cx.draw_texture(old_cg_id, old_cg_bounds, 1.0);
-                    }
-                }
-
-                // Draw new CG sliding in (with aspect ratio preserved)
-                if let Some(new_cg_id) = self.current_cg_texture_id {
-                    if let Some((width, height)) = self.current_cg_texture_size {
-                        let cg_fitted_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        let new_cg_bounds = narrative_gui::Bounds {
-                            origin: narrative_gui::Point::new(
-                                cg_fitted_bounds.origin.x + new_offset.x,
-                                cg_fitted_bounds.origin.y + new_offset.y,
-                            ),
-                            size: cg_fitted_bounds.size,
-                        };
-                        cx.draw_texture(new_cg_id, new_cg_bounds, 1.0);
-                    } else {
-                        let new_cg_bounds = narrative_gui::Bounds {
-                            origin: narrative_gui::Point::new(
-                                cx.bounds.origin.x + new_offset.x,
-                                cx.bounds.origin.y + new_offset.y,
-                            ),
-                            size: cx.bounds.size,
-                        };
-                        cx.draw_texture(new_cg_id, new_cg_bounds, 1.0);
-                    }
-                }
-            }
-
-            // Wipe transitions: reveal new background progressively
-            Some((TransitionKind::Wipe(direction), progress)) => {
-                let width = cx.bounds.size.width;
-                let height = cx.bounds.size.height;
-
-                // Draw old background at full size
-                if let Some(old_bg_id) = self.previous_background_texture_id {
-                    cx.draw_texture(old_bg_id, cx.bounds, 1.0);
-                }
-
-                // Calculate new background bounds based on wipe direction
-                let new_bounds = match direction {
-                    WipeDirection::Left => narrative_gui::Bounds {
-                        origin: narrative_gui::Point::new(
-                            cx.bounds.origin.x + width * (1.0 - progress),
-                            cx.bounds.origin.y,
-                        ),
-                        size: narrative_gui::Size::new(width * progress, height),
-                    },
-                    WipeDirection::Right => narrative_gui::Bounds {
-                        origin: cx.bounds.origin,
-                        size: narrative_gui::Size::new(width * progress, height),
-                    },
-                    WipeDirection::Up => narrative_gui::Bounds {
-                        origin: narrative_gui::Point::new(
-                            cx.bounds.origin.x,
-                            cx.bounds.origin.y + height * (1.0 - progress),
-                        ),
-                        size: narrative_gui::Size::new(width, height * progress),
-                    },
-                    WipeDirection::Down => narrative_gui::Bounds {
-                        origin: cx.bounds.origin,
-                        size: narrative_gui::Size::new(width, height * progress),
-                    },
-                };
-
-                // Draw new background with wipe effect
-                if let Some(new_bg_id) = self.current_background_texture_id {
-                    cx.draw_texture(new_bg_id, new_bounds, 1.0);
-                }
-
-                // Draw old CG at full opacity (with aspect ratio preserved)
-                // Note: CG uses crossfade during wipe transitions since partial texture
-                // rendering with aspect ratio preservation is complex
-                if let Some(old_cg_id) = self.previous_cg_texture_id {
-                    if let Some((width, height)) = self.previous_cg_texture_size {
-                        let cg_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        cx.draw_texture(old_cg_id, cg_bounds, 1.0 - progress);
-                    } else {
-                        cx.draw_texture(old_cg_id, cx.bounds, 1.0 - progress);
-                    }
-                }
-
-                // Draw new CG with increasing opacity (with aspect ratio preserved)
-                if let Some(new_cg_id) = self.current_cg_texture_id {
-                    if let Some((width, height)) = self.current_cg_texture_size {
-                        let cg_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        cx.draw_texture(new_cg_id, cg_bounds, progress);
-                    } else {
-                        cx.draw_texture(new_cg_id, cx.bounds, progress);
-                    }
-                }
-            }
-
-            // Dissolve transition: draw both backgrounds, mask with blocks in overlay
-            Some((TransitionKind::Dissolve, _progress)) => {
-                // Draw old background first
-                if let Some(old_bg_id) = self.previous_background_texture_id {
-                    cx.draw_texture(old_bg_id, cx.bounds, 1.0);
-                }
-
-                // Draw new background on top
-                if let Some(new_bg_id) = self.current_background_texture_id {
-                    cx.draw_texture(new_bg_id, cx.bounds, 1.0);
-                }
+        // Scene transitions draw the outgoing frame over this in paint_overlay()
 
-                // Draw old CG first (with aspect ratio preserved)
-                if let Some(old_cg_id) = self.previous_cg_texture_id {
-                    if let Some((width, height)) = self.previous_cg_texture_size {
-                        let cg_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        cx.draw_texture(old_cg_id, cg_bounds, 1.0);
-                    } else {
-                        cx.draw_texture(old_cg_id, cx.bounds, 1.0);
-                    }
-                }
-
-                // Draw new CG on top (with aspect ratio preserved)
-                if let Some(new_cg_id) = self.current_cg_texture_id {
-                    if let Some((width, height)) = self.current_cg_texture_size {
-                        let cg_bounds =
-                            self.calculate_aspect_ratio_fit(cx.bounds, width as f32, height as f32);
-                        cx.draw_texture(new_cg_id, cg_bounds, 1.0);
-                    } else {
-                        cx.draw_texture(new_cg_id, cx.bounds, 1.0);
-                    }
-                }
-
-                // The dissolve pattern mask will be applied in paint_overlay()
-            }
-
-            // Normal rendering or other transition types
-            _ => {
-                // Normal rendering: Draw current background texture if loaded, otherwise use solid color
-                // current_background_texture_id is dynamically updated when background changes
-                if let Some(bg_texture_id) = self.current_background_texture_id {
-                    // Draw background image to fill the entire window
-                    cx.draw_texture(bg_texture_id, cx.bounds, 1.0);
-                } else {
-                    // Fallback: Draw background with a visible color
-                    // Shown when: (1) background not loaded, (2) HideBackground command, (3) texture load failed
-                    let bg_color = narrative_gui::Color::new(0.1, 0.15, 0.2, 1.0); // Dark blue-gray
-                    cx.fill_rect(cx.bounds, bg_color);
-                }
-            }
+        // Draw current background texture if loaded, otherwise use solid color
+        // current_background_texture_id is dynamically updated when background changes
+        if let Some(bg_texture_id) = self.current_background_texture_id {
+            // Draw background image to fill the entire window
+            cx.draw_texture(bg_texture_id, cx.bounds, 1.0);
+        } else {
+            // Fallback: Draw background with a visible color
+            // Shown when: (1) background not loaded, (2) HideBackground command, (3) texture load failed
+            let bg_color = narrative_gui::Color::new(0.1, 0.15, 0.2, 1.0); // Dark blue-gray
+            cx.fill_rect(cx.bounds, bg_color);
         }
 
         // Draw CG (event graphics) if present
@@ -432,9 +266,29 @@ impl Element for GameRootElement {
             }
         }
 
+        // Particle effects (rain, snow, ...) over the scene
+        self.paint_particles(cx);
+
+        // Video cutscenes cover the whole scene, letterboxed on black
+        if let AppState::InGame(InGameState::PlayingVideo(_)) = &self.app_state {
+            cx.fill_rect(cx.bounds, narrative_gui::Color::new(0.0, 0.0, 0.0, 1.0));
+            if let (Some(texture_id), Some(player)) = (self.video_texture_id, &self.video_player) {
+                let info = player.info();
+                let video_bounds = self.calculate_aspect_ratio_fit(
+                    cx.bounds,
+                    info.width as f32,
+                    info.height as f32,
+                );
+                cx.draw_texture(texture_id, video_bounds, 1.0);
+            }
+        }
+
         // Character sprites are now managed by CharacterSpriteElement children
         // (removed fixed character rendering)
 
+        // Subtitles for sound effects and videos, over the scene but under the UI
+        self.subtitles.paint(cx);
+
         // Draw debug visual indicators (only in debug builds)
         // NOTE: These indicators are always visible in debug builds to help with development.
         // For a cleaner view during testing, use --release build or implement a runtime toggle.
@@ -526,10 +380,16 @@ impl Element for GameRootElement {
                     | InGameState::Transition(_)
                     | InGameState::PlayingEffect(_)
                     | InGameState::Waiting(_)
+                    | InGameState::PlayingVideo(_)
+                    | InGameState::InputtingName(_)
             )
         ) {
             // Accumulate fractional seconds for accurate time tracking
             self.play_time_accumulator += frame_time;
+            self.unsaved_play_secs += frame_time;
+            if let Some(replay) = self.replay.as_mut() {
+                replay.tick(frame_time);
+            }
             if self.play_time_accumulator >= 1.0 {
                 let whole_seconds = self.play_time_accumulator as u64;
                 self.total_play_time_secs = self.total_play_time_secs.saturating_add(whole_seconds);
@@ -537,8 +397,21 @@ impl Element for GameRootElement {
             }
         }
 
+        // Gamepad buttons act like their keys, ahead of the state update
+        if self.update_gamepad() {
+            needs_update = true;
+        }
+
+        // Handle native menu commands before the regular state update
+        self.update_menu_commands();
+        self.update_scenario_hot_reload();
+
         // Update game state
         self.update_state(frame_time);
+        self.update_scene_transition();
+
+        // Load the assets of upcoming commands in the background
+        self.update_asset_preload();
 
         // Detect background changes (InGame state only)
         if matches!(self.app_state, AppState::InGame(_)) {
@@ -562,6 +435,9 @@ impl Element for GameRootElement {
             }
         }
 
+        // Apply pause menu quick settings (saved once the menu has closed)
+        self.update_quick_settings();
+
         // Rebuild children only if state changed
         if self.children_dirty {
             tracing::debug!("tick(): Rebuilding children (children_dirty=true)");
@@ -575,6 +451,35 @@ impl Element for GameRootElement {
             );
         }
 
+        // Advance effects and their particles
+        if self.update_particles(frame_time) {
+            needs_update = true;
+        }
+
+        // Follow the flags and variables shown on the HUD
+        if self.update_hud(frame_time) {
+            needs_update = true;
+        }
+
+        // Voice new dialogue lines and read them aloud
+        self.update_voice();
+        self.update_self_voicing();
+
+        // Advance subtitles for playing sound effects
+        if self.subtitles.tick(Duration::from_secs_f32(frame_time)) {
+            needs_update = true;
+        }
+
+        // Toasts for achievements unlocked this frame and the save reminder
+        self.update_achievement_toasts();
+        self.update_save_reminder();
+        if self.toasts.tick(Duration::from_secs_f32(frame_time)) {
+            needs_update = true;
+        }
+
+        #[cfg(feature = "steam")]
+        self.update_steam();
+
         // Check if any child needs update (e.g., typewriter effect, animations)
         for child in &mut self.children {
             if child.tick(delta) {
@@ -615,6 +520,20 @@ impl Element for GameRootElement {
                         self.config.audio.sound_volume = user_settings.audio.se_volume;
                         self.config.audio.voice_volume = user_settings.audio.voice_volume;
                         self.config.window.fullscreen = user_settings.display.fullscreen;
+                        self.config.gameplay.skip_mode = user_settings.skip.mode;
+                        self.config.gameplay.skip_stop_at_choices =
+                            user_settings.skip.stop_at_choices;
+                        self.dialogue_box_config = user_settings
+                            .textbox
+                            .apply_to(self.dialogue_box_config.clone());
+                        self.comfort_mode = user_settings.accessibility.comfort_mode;
+                        self.subtitles
+                            .set_enabled(user_settings.accessibility.subtitles);
+                        self.self_voicing = user_settings.accessibility.self_voicing;
+                        self.tts.set_rate(user_settings.accessibility.speech_rate);
+                        self.bindings = user_settings.bindings.clone();
+                        self.gamepad
+                            .set_gamepad_settings(user_settings.gamepad.clone());
 
                         needs_update = true;
                     }
@@ -628,7 +547,7 @@ impl Element for GameRootElement {
 
                     // Check if back button was pressed
                     if settings_menu.take_back_pressed() {
-                        // Restore previous state like F1/ESC do
+                        // Restore previous state like Escape does
                         if let Some(prev_state) = self.previous_app_state.take() {
                             tracing::debug!(
                                 "Settings back button pressed, restoring previous state"
@@ -648,6 +567,9 @@ impl Element for GameRootElement {
             }
         }
 
+        // Apply audio commands queued by state updates and menus this frame
+        self.process_audio_commands();
+
         // Reset frame-specific input flags
         if self.clicked_last_frame {
             tracing::trace!("Resetting clicked_last_frame");
@@ -657,6 +579,10 @@ impl Element for GameRootElement {
         self.auto_mode_toggle_pressed = false;
         self.skip_mode_toggle_pressed = false;
         self.backlog_pressed = false;
+        self.quick_save_pressed = false;
+        self.quick_load_pressed = false;
+        self.rollback_pressed = false;
+        self.roll_forward_pressed = false;
 
         // Only repaint/relayout if something actually changed
         needs_update
@@ -675,45 +601,34 @@ impl Element for GameRootElement {
     }
 
     fn paint_overlay(&self, cx: &mut PaintContext) {
-        // Render transition overlay if in Transition state
+        // Outgoing frame of a scene transition, over the new scene
         if let AppState::InGame(InGameState::Transition(transition)) = &self.app_state {
-            use narrative_core::types::TransitionKind;
-
-            // Calculate transition progress ratio (0.0 to 1.0)
-            let progress = transition.progress_ratio();
-
-            // Render based on transition kind
-            match transition.kind {
-                TransitionKind::Fade => {
-                    // Fade to/from black
-                    // For fade-in: start with alpha=1.0 (fully black), end with alpha=0.0 (transparent)
-                    // This creates a fade-in effect from black
-                    let alpha = 1.0 - progress;
-                    let fade_color = narrative_gui::Color::new(0.0, 0.0, 0.0, alpha);
-                    cx.fill_rect(cx.bounds, fade_color);
-                }
-                TransitionKind::FadeWhite => {
-                    // Fade to/from white
-                    // For fade-in: start with alpha=1.0 (fully white), end with alpha=0.0 (transparent)
-                    // This creates a fade-in effect from white
-                    let alpha = 1.0 - progress;
-                    let fade_color = narrative_gui::Color::new(1.0, 1.0, 1.0, alpha);
-                    cx.fill_rect(cx.bounds, fade_color);
-                }
-                TransitionKind::Crossfade | TransitionKind::Slide(_) | TransitionKind::Wipe(_) => {
-                    // These transitions are handled entirely in paint()
-                    // No overlay needed
-                }
-                TransitionKind::Dissolve => {
-                    // Dissolve effect: create a pixelated/block transition effect
-                    // Show old background through a decreasing grid pattern
-                    self.render_dissolve_overlay(cx, progress);
-                }
-                TransitionKind::None => {
-                    // No transition
-                }
-            }
+            self.paint_transition(cx, transition.kind, transition.progress_ratio());
+        }
+
+        // Flash (or its comfort mode fade) over the whole screen
+        if let AppState::InGame(InGameState::PlayingEffect(effect)) = &self.app_state
+            && let Some([r, g, b, a]) = effect.overlay_color()
+        {
+            cx.fill_rect(cx.bounds, narrative_gui::Color::new(r, g, b, a));
         }
+
+        // "Press any key" over the attract mode demo
+        self.paint_attract_prompt(cx);
+
+        // HUD widgets, over the scene and dialogue box
+        if self.hud_shown() {
+            self.hud.paint(cx);
+        }
+
+        // Toasts, over the scene and menus
+        self.toasts.paint(cx);
+
+        // Developer console, over the game and menus
+        self.dev_console.paint_overlay(cx);
+
+        // Keyboard shortcut help, over everything else
+        self.help_overlay.paint_overlay(cx);
     }
 
     fn load_pending_background_texture(
@@ -725,3 +640,13 @@ impl Element for GameRootElement {
         GameRootElement::load_pending_background_texture(self, renderer)
     }
 }
+
+/// Part of `bounds` covered by `region`, given in fractions of it (0.0-1.0)
+fn region_of(bounds: Bounds, region: Bounds) -> Bounds {
+    Bounds::new(
+        bounds.origin.x + region.origin.x * bounds.size.width,
+        bounds.origin.y + region.origin.y * bounds.size.height,
+        region.size.width * bounds.size.width,
+        region.size.height * bounds.size.height,
+    )
+}
@@ -0,0 +1,55 @@
+//! Replay recording for GameRootElement
+
+use super::element::GameRootElement;
+use narrative_engine::app::Replay;
+use narrative_engine::runtime::ScenarioRuntime;
+use std::path::PathBuf;
+
+impl GameRootElement {
+    /// Directory finished replays are written to
+    pub(super) fn replay_dir(&self) -> PathBuf {
+        self.config.save_path.join("replays")
+    }
+
+    /// Start recording the playthrough of a started scenario
+    ///
+    /// Must be called before the scenario's first command runs.
+    pub(super) fn new_replay(runtime: &ScenarioRuntime) -> Option<Replay> {
+        match Replay::new(runtime) {
+            Ok(replay) => Some(replay),
+            Err(e) => {
+                tracing::warn!("Not recording a replay: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Stop recording without writing the replay
+    ///
+    /// Used when the playthrough can no longer be reproduced from its inputs
+    /// (a save was loaded, playback was rolled back, or the developer
+    /// console changed the state).
+    pub(super) fn stop_replay(&mut self, reason: &str) {
+        if self.replay.take().is_some() {
+            tracing::info!("Stopped recording the replay: {}", reason);
+        }
+    }
+
+    /// Finish the recording at the end of the scenario and write it
+    ///
+    /// Each scenario keeps the replay of its last finished playthrough, as
+    /// `<scenario id>.ron` in [`Self::replay_dir`].
+    pub(super) fn save_replay(&mut self) {
+        let (Some(mut replay), Some(runtime)) = (self.replay.take(), &self.scenario_runtime) else {
+            return;
+        };
+        replay.finish(runtime);
+        let path = self
+            .replay_dir()
+            .join(format!("{}.ron", replay.scenario_id));
+        match replay.save(&path) {
+            Ok(()) => tracing::info!("Replay saved to {}", path.display()),
+            Err(e) => tracing::warn!("Failed to save replay '{}': {}", path.display(), e),
+        }
+    }
+}
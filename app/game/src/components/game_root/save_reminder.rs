@@ -0,0 +1,44 @@
+//! Save reminder for GameRootElement
+
+use super::element::GameRootElement;
+use crate::components::ToastMessage;
+use narrative_engine::runtime::{AppState, InGameState};
+use narrative_gui::components::IconType;
+
+/// Message of the save reminder toast
+const SAVE_REMINDER_MESSAGE: &str = "It's been a while since you last saved";
+
+impl GameRootElement {
+    /// Remind the player to save once the play time since the last save
+    /// reaches the configured interval
+    ///
+    /// The reminder waits while auto or skip mode is on, during cutscenes and
+    /// outside of dialogue and choices, so it never covers a menu.
+    pub(super) fn update_save_reminder(&mut self) {
+        if let Some(interval) = self.save_slots.reminder_interval()
+            && self.unsaved_play_secs >= interval.as_secs_f32()
+            && self.can_show_save_reminder()
+        {
+            tracing::debug!("Showing save reminder");
+            self.toasts
+                .push(ToastMessage::new(SAVE_REMINDER_MESSAGE).with_icon(IconType::Save));
+            self.unsaved_play_secs = 0.0;
+        }
+    }
+
+    /// Whether the reminder can be shown without getting in the way
+    fn can_show_save_reminder(&self) -> bool {
+        let gameplay = &self.config.gameplay;
+        !self.attract_mode
+            && !gameplay.auto_mode_enabled
+            && !gameplay.skip_mode_enabled
+            && matches!(
+                self.app_state,
+                AppState::InGame(
+                    InGameState::Typing(_)
+                        | InGameState::WaitingInput(_)
+                        | InGameState::ShowingChoices(_)
+                )
+            )
+    }
+}
@@ -0,0 +1,75 @@
+//! Self-voicing (text-to-speech) for GameRootElement
+
+use super::element::GameRootElement;
+use narrative_core::config::AccessibilitySettings;
+use narrative_core::{Scenario, ScenarioCommand, SceneId, Speaker, TtsVoice};
+use narrative_engine::runtime::{AppState, InGameState};
+
+/// BGM volume, relative to the player's setting, while a line is read aloud
+const SPEECH_DUCKING: f32 = 0.3;
+
+impl GameRootElement {
+    /// Apply the player's self-voicing settings
+    pub(super) fn apply_speech_settings(&mut self, settings: &AccessibilitySettings) {
+        self.self_voicing = settings.self_voicing;
+        self.tts.set_rate(settings.speech_rate);
+    }
+
+    /// Read each new dialogue line aloud in its speaker's voice, ducking BGM
+    /// while speech plays
+    ///
+    /// A line is read once, when it starts typing. Leaving the game or
+    /// turning self-voicing off stops speech.
+    pub(super) fn update_self_voicing(&mut self) {
+        match (&self.app_state, &self.scenario_runtime) {
+            (AppState::InGame(InGameState::Typing(typing)), Some(runtime)) if self.self_voicing => {
+                let line = (typing.scene_id.clone(), typing.command_index);
+                if self.spoken_line.as_ref() != Some(&line) {
+                    let voice = speaker_voice(runtime.scenario(), &line.0, line.1);
+                    if let Err(e) = self.tts.speak(&typing.text, voice) {
+                        tracing::warn!("Failed to read dialogue aloud: {}", e);
+                    }
+                    self.spoken_line = Some(line);
+                }
+            }
+            (AppState::InGame(_) | AppState::Settings(_), Some(_)) if self.self_voicing => {}
+            _ => {
+                self.tts.stop();
+                self.spoken_line = None;
+            }
+        }
+
+        let speaking = self.tts.is_speaking();
+        if speaking != self.bgm_ducked_for_speech {
+            self.bgm_ducked_for_speech = speaking;
+            let level = if speaking { SPEECH_DUCKING } else { 1.0 };
+            self.audio_queue.set_bgm_ducking(level);
+        }
+    }
+}
+
+/// Synthetic voice of the character speaking a dialogue command
+///
+/// Returns None for the narrator and characters without a voice.
+fn speaker_voice<'a>(
+    scenario: &'a Scenario,
+    scene_id: &SceneId,
+    command_index: usize,
+) -> Option<&'a TtsVoice> {
+    let command = scenario
+        .get_scene(scene_id.as_str())?
+        .commands
+        .get(command_index)?;
+    let ScenarioCommand::Dialogue { dialogue } = command else {
+        return None;
+    };
+    let Speaker::Character(id) = &dialogue.speaker else {
+        return None;
+    };
+    scenario
+        .characters
+        .iter()
+        .find(|character| character.id == *id)?
+        .tts_voice
+        .as_ref()
+}
@@ -2,10 +2,14 @@
 
 use super::element::GameRootElement;
 use crate::components::{PauseMenuAction, PauseMenuElement, TitleScreenAction, TitleScreenElement};
-use narrative_core::config::UserSettings;
+use narrative_core::config::{ProfileSettings, UserSettings};
 use narrative_engine::runtime::{
-    AppState, InGameState, LayoutMode, MainMenuState, SaveLoadState, ScenarioRuntime,
+    AchievementsState, AppState, InGameState, LayoutMode, MainMenuState, SaveLoadState,
+    ScenarioRuntime,
 };
+use narrative_gui::framework::MenuId;
+use narrative_gui::framework::element::WindowOperation;
+use std::path::Path;
 use std::sync::Arc;
 
 // Constants
@@ -26,6 +30,9 @@ impl GameRootElement {
 
         // Match and extract values to avoid borrowing conflicts
         match &self.app_state {
+            AppState::Loading(loading) if loading.next.is_some() => {
+                self.update_asset_loading();
+            }
             AppState::Loading(_) => {
                 let mut should_transition = false;
                 if let AppState::Loading(loading) = &mut self.app_state {
@@ -43,6 +50,11 @@ impl GameRootElement {
             AppState::MainMenu(_) => {
                 self.update_main_menu_state();
             }
+            AppState::InGame(_)
+                if self.help_overlay.is_visible() || self.dev_console.is_visible() =>
+            {
+                // Playback pauses under the help overlay and developer console
+            }
             AppState::InGame(_) => {
                 // Extract state data to avoid borrow conflicts
                 self.update_in_game_state_wrapper(delta);
@@ -52,6 +64,8 @@ impl GameRootElement {
             }
         }
 
+        self.update_attract_mode(delta);
+
         // Mark children as dirty if state changed
         let new_state_discriminant = std::mem::discriminant(&self.app_state);
         if old_state_discriminant != new_state_discriminant {
@@ -111,69 +125,220 @@ impl GameRootElement {
                 }
             }
 
-            match action {
-                TitleScreenAction::NewGame => {
-                    self.start_new_game();
-                }
-                TitleScreenAction::Continue => {
-                    // TODO: Implement continue from last save
-                    tracing::warn!("Continue not yet implemented, starting new game");
-                    self.start_new_game();
-                }
-                TitleScreenAction::Load => {
-                    // Transition to Save/Load menu in load mode
-                    tracing::debug!("Opening load menu from title screen");
-
-                    // Load user settings to get layout preference
-                    let layout_mode = UserSettings::load("assets/config/settings.ron")
-                        .map(|s| match s.display.save_menu_layout {
-                            narrative_core::config::SaveMenuLayoutMode::List => LayoutMode::List,
-                            narrative_core::config::SaveMenuLayoutMode::Grid => LayoutMode::Grid,
-                        })
-                        .inspect_err(|e| {
-                            tracing::warn!(
-                                "Failed to load user settings, using default layout (List): {}",
-                                e
-                            );
-                        })
-                        .unwrap_or(LayoutMode::List);
-
-                    self.app_state = AppState::InGame(InGameState::SaveLoadMenu(SaveLoadState {
-                        is_save_mode: false,
-                        selected_slot: 0,
-                        current_page: 0,
-                        layout_mode,
-                    }));
-                    tracing::debug!("children_dirty set at line {}", line!());
-                    self.children_dirty = true;
-                }
-                TitleScreenAction::CgGallery => {
-                    // Transition to CG Gallery
-                    tracing::debug!("Opening CG Gallery from title screen");
-                    let total_cgs = self.cg_registry.total_count();
-                    self.app_state = AppState::InGame(InGameState::CgGallery(
-                        narrative_engine::runtime::CgGalleryState::new(total_cgs),
-                    ));
-                    tracing::debug!("children_dirty set at line {}", line!());
-                    self.children_dirty = true;
-                }
-                TitleScreenAction::Settings => {
-                    // Transition to settings menu
-                    tracing::debug!("Opening settings from title screen");
-                    self.previous_app_state = Some(Box::new(self.app_state.clone()));
-                    self.app_state = AppState::Settings(Default::default());
-                    tracing::debug!("children_dirty set at line {}", line!());
-                    self.children_dirty = true;
+            self.apply_title_screen_action(action);
+        }
+    }
+
+    /// Perform a title screen action
+    pub(super) fn apply_title_screen_action(&mut self, action: TitleScreenAction) {
+        match action {
+            TitleScreenAction::NewGame => {
+                self.start_new_game();
+            }
+            TitleScreenAction::Continue => {
+                // TODO: Implement continue from last save
+                tracing::warn!("Continue not yet implemented, starting new game");
+                self.start_new_game();
+            }
+            TitleScreenAction::Load => {
+                // Transition to Save/Load menu in load mode
+                tracing::debug!("Opening load menu from title screen");
+
+                // Load user settings to get layout preference
+                let layout_mode = UserSettings::load("assets/config/settings.ron")
+                    .map(|s| match s.display.save_menu_layout {
+                        narrative_core::config::SaveMenuLayoutMode::List => LayoutMode::List,
+                        narrative_core::config::SaveMenuLayoutMode::Grid => LayoutMode::Grid,
+                    })
+                    .inspect_err(|e| {
+                        tracing::warn!(
+                            "Failed to load user settings, using default layout (List): {}",
+                            e
+                        );
+                    })
+                    .unwrap_or(LayoutMode::List);
+
+                self.app_state = AppState::InGame(InGameState::SaveLoadMenu(SaveLoadState {
+                    is_save_mode: false,
+                    selected_slot: 0,
+                    current_page: 0,
+                    layout_mode,
+                }));
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            TitleScreenAction::CgGallery => {
+                // Transition to CG Gallery
+                tracing::debug!("Opening CG Gallery from title screen");
+                let total_cgs = self.cg_registry.total_count();
+                self.app_state = AppState::InGame(InGameState::CgGallery(
+                    narrative_engine::runtime::CgGalleryState::new(total_cgs),
+                ));
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            TitleScreenAction::Achievements => {
+                tracing::debug!("Opening achievements from title screen");
+                self.app_state =
+                    AppState::InGame(InGameState::Achievements(AchievementsState::default()));
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            TitleScreenAction::Settings => {
+                // Transition to settings menu
+                tracing::debug!("Opening settings from title screen");
+                self.previous_app_state = Some(Box::new(self.app_state.clone()));
+                self.app_state = AppState::Settings(Default::default());
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            TitleScreenAction::Exit => {
+                tracing::info!("Exit requested - closing application");
+                self.window_operations
+                    .push(narrative_gui::framework::element::WindowOperation::Close);
+            }
+        }
+    }
+
+    /// Handle commands sent from the native menu bar
+    pub(super) fn update_menu_commands(&mut self) {
+        let commands: Vec<MenuId> = match &self.menu_commands {
+            Some(receiver) => match receiver.lock() {
+                Ok(receiver) => receiver.try_iter().collect(),
+                Err(e) => {
+                    tracing::warn!("Menu command receiver poisoned: {}", e);
+                    return;
                 }
-                TitleScreenAction::Exit => {
-                    tracing::info!("Exit requested - closing application");
-                    self.window_operations
-                        .push(narrative_gui::framework::element::WindowOperation::Close);
+            },
+            None => return,
+        };
+
+        for command in commands {
+            self.handle_menu_command(command);
+        }
+    }
+
+    /// Handle a native menu command
+    ///
+    /// Commands go through the same actions as the title screen and pause menu,
+    /// so the resulting screens behave exactly as if opened from the in-game UI.
+    pub(super) fn handle_menu_command(&mut self, command: MenuId) {
+        tracing::debug!("Menu command: {:?}", command);
+
+        if command == MenuId::ToggleFullscreen {
+            self.config.window.fullscreen = !self.config.window.fullscreen;
+            self.window_operations
+                .push(WindowOperation::ToggleFullscreen);
+            return;
+        }
+
+        match &mut self.app_state {
+            AppState::MainMenu(_) => {
+                let action = match command {
+                    MenuId::Load => TitleScreenAction::Load,
+                    MenuId::Settings => TitleScreenAction::Settings,
+                    _ => {
+                        tracing::debug!("Menu command {:?} unavailable on title screen", command);
+                        return;
+                    }
+                };
+                self.apply_title_screen_action(action);
+            }
+            AppState::InGame(in_game_state) => {
+                let action = match command {
+                    MenuId::Save => PauseMenuAction::Save,
+                    MenuId::Load => PauseMenuAction::Load,
+                    MenuId::Settings => PauseMenuAction::Settings,
+                    _ => {
+                        tracing::debug!("Menu command {:?} unavailable in game", command);
+                        return;
+                    }
+                };
+
+                match in_game_state {
+                    InGameState::Typing(_) | InGameState::WaitingInput(_) => {
+                        // Pause first so closing the opened screen returns to the pause menu
+                        self.previous_in_game_state = Some(Box::new(in_game_state.clone()));
+                        *in_game_state = InGameState::PauseMenu(Default::default());
+                    }
+                    InGameState::PauseMenu(_) if !self.showing_title_confirm => {}
+                    _ => {
+                        tracing::debug!(
+                            "Menu command {:?} unavailable in the current screen",
+                            command
+                        );
+                        return;
+                    }
                 }
+
+                self.apply_pause_menu_action(action);
+            }
+            _ => {
+                tracing::debug!(
+                    "Menu command {:?} unavailable in the current screen",
+                    command
+                );
+            }
+        }
+    }
+
+    /// Apply quick settings changed in the pause menu and save them once the menu closes
+    pub(super) fn update_quick_settings(&mut self) {
+        let changed = self.children.iter().find_map(|child| {
+            child
+                .as_any()
+                .downcast_ref::<PauseMenuElement>()
+                .and_then(|pause_menu| pause_menu.take_settings_if_changed())
+        });
+        if let Some(settings) = changed {
+            self.apply_user_settings(&settings);
+            self.unsaved_quick_settings = Some(settings);
+        }
+
+        if !matches!(self.app_state, AppState::InGame(InGameState::PauseMenu(_)))
+            && let Some(settings) = self.unsaved_quick_settings.take()
+        {
+            match settings.save("assets/config/settings.ron") {
+                Ok(_) => tracing::info!("Quick settings saved to assets/config/settings.ron"),
+                Err(e) => tracing::error!("Failed to save quick settings: {}", e),
             }
         }
     }
 
+    /// Save the player profile to `settings.ron`, keeping the other settings
+    pub(super) fn save_player_profile(profile: &ProfileSettings) {
+        let mut settings = UserSettings::load("assets/config/settings.ron").unwrap_or_default();
+        settings.profile = profile.clone();
+        match settings.save("assets/config/settings.ron") {
+            Ok(_) => tracing::info!("Player profile saved to assets/config/settings.ron"),
+            Err(e) => tracing::error!("Failed to save player profile: {}", e),
+        }
+    }
+
+    /// Update engine config from user settings
+    pub(super) fn apply_user_settings(&mut self, settings: &UserSettings) {
+        self.config.audio.master_volume = settings.audio.master_volume;
+        self.config.audio.music_volume = settings.audio.bgm_volume;
+        self.config.audio.sound_volume = settings.audio.se_volume;
+        self.config.audio.voice_volume = settings.audio.voice_volume;
+        self.config.gameplay.text_speed = settings.text.speed.chars_per_second();
+        self.config.gameplay.auto_advance_speed = settings.text.auto_wait;
+        self.config.gameplay.skip_mode = settings.skip.mode;
+        self.config.gameplay.skip_stop_at_choices = settings.skip.stop_at_choices;
+        // Picked up by the dialogue box when the game view is rebuilt
+        self.dialogue_box_config = settings.textbox.apply_to(self.dialogue_box_config.clone());
+        self.comfort_mode = settings.accessibility.comfort_mode;
+        self.subtitles.set_enabled(settings.accessibility.subtitles);
+        self.apply_speech_settings(&settings.accessibility);
+        self.dialogue_languages = settings.text.dialogue_languages();
+        self.bindings = settings.bindings.clone();
+        self.gamepad.set_gamepad_settings(settings.gamepad.clone());
+        self.player_profile = settings.profile.clone();
+        if let Some(runtime) = &mut self.scenario_runtime {
+            runtime.set_dialogue_languages(self.dialogue_languages.clone());
+        }
+    }
+
     /// Update pause menu state
     pub(super) fn update_pause_menu_state(&mut self) {
         // Check if pause menu has a confirmed action
@@ -195,97 +360,106 @@ impl GameRootElement {
                 }
             }
 
-            match action {
-                PauseMenuAction::Resume => {
-                    // Resume game - restore previous state
-                    if let Some(prev_state) = self.previous_in_game_state.take()
-                        && let AppState::InGame(in_game_state) = &mut self.app_state
-                    {
-                        *in_game_state = *prev_state;
-                        tracing::debug!("children_dirty set at line {}", line!());
-                        self.children_dirty = true;
-                    }
-                }
-                PauseMenuAction::Save => {
-                    // Transition to Save/Load menu in save mode
-                    tracing::debug!("Opening save menu from pause menu");
-
-                    // Load user settings to get layout preference
-                    let layout_mode = UserSettings::load("assets/config/settings.ron")
-                        .map(|s| match s.display.save_menu_layout {
-                            narrative_core::config::SaveMenuLayoutMode::List => LayoutMode::List,
-                            narrative_core::config::SaveMenuLayoutMode::Grid => LayoutMode::Grid,
-                        })
-                        .inspect_err(|e| {
-                            tracing::warn!(
-                                "Failed to load user settings, using default layout (List): {}",
-                                e
-                            );
-                        })
-                        .unwrap_or(LayoutMode::List);
-
-                    if let AppState::InGame(in_game_state) = &mut self.app_state {
-                        // Save current pause menu state so we can return to it
-                        self.previous_in_game_state = Some(Box::new(in_game_state.clone()));
+            self.apply_pause_menu_action(action);
+        }
+    }
 
-                        *in_game_state = InGameState::SaveLoadMenu(SaveLoadState {
-                            is_save_mode: true,
-                            selected_slot: 0,
-                            current_page: 0,
-                            layout_mode,
-                        });
-                        tracing::debug!("children_dirty set at line {}", line!());
-                        self.children_dirty = true;
-                    }
+    /// Perform a pause menu action
+    pub(super) fn apply_pause_menu_action(&mut self, action: PauseMenuAction) {
+        match action {
+            PauseMenuAction::Resume => {
+                // Resume game - restore previous state
+                if let Some(prev_state) = self.previous_in_game_state.take()
+                    && let AppState::InGame(in_game_state) = &mut self.app_state
+                {
+                    *in_game_state = *prev_state;
+                    tracing::debug!("children_dirty set at line {}", line!());
+                    self.children_dirty = true;
                 }
-                PauseMenuAction::Load => {
-                    // Transition to Save/Load menu in load mode
-                    tracing::debug!("Opening load menu from pause menu");
-
-                    // Load user settings to get layout preference
-                    let layout_mode = UserSettings::load("assets/config/settings.ron")
-                        .map(|s| match s.display.save_menu_layout {
-                            narrative_core::config::SaveMenuLayoutMode::List => LayoutMode::List,
-                            narrative_core::config::SaveMenuLayoutMode::Grid => LayoutMode::Grid,
-                        })
-                        .inspect_err(|e| {
-                            tracing::warn!(
-                                "Failed to load user settings, using default layout (List): {}",
-                                e
-                            );
-                        })
-                        .unwrap_or(LayoutMode::List);
-
-                    if let AppState::InGame(in_game_state) = &mut self.app_state {
-                        // Save current pause menu state so we can return to it
-                        self.previous_in_game_state = Some(Box::new(in_game_state.clone()));
+            }
+            PauseMenuAction::Save => {
+                // Transition to Save/Load menu in save mode
+                tracing::debug!("Opening save menu from pause menu");
 
-                        *in_game_state = InGameState::SaveLoadMenu(SaveLoadState {
-                            is_save_mode: false,
-                            selected_slot: 0,
-                            current_page: 0,
-                            layout_mode,
-                        });
-                        tracing::debug!("children_dirty set at line {}", line!());
-                        self.children_dirty = true;
-                    }
-                }
-                PauseMenuAction::Settings => {
-                    // Open settings from pause menu
-                    tracing::debug!("Opening settings from pause menu");
-                    self.previous_app_state = Some(Box::new(self.app_state.clone()));
-                    self.app_state = AppState::Settings(Default::default());
+                // Load user settings to get layout preference
+                let layout_mode = UserSettings::load("assets/config/settings.ron")
+                    .map(|s| match s.display.save_menu_layout {
+                        narrative_core::config::SaveMenuLayoutMode::List => LayoutMode::List,
+                        narrative_core::config::SaveMenuLayoutMode::Grid => LayoutMode::Grid,
+                    })
+                    .inspect_err(|e| {
+                        tracing::warn!(
+                            "Failed to load user settings, using default layout (List): {}",
+                            e
+                        );
+                    })
+                    .unwrap_or(LayoutMode::List);
+
+                if let AppState::InGame(in_game_state) = &mut self.app_state {
+                    // Save current pause menu state so we can return to it
+                    self.previous_in_game_state = Some(Box::new(in_game_state.clone()));
+
+                    *in_game_state = InGameState::SaveLoadMenu(SaveLoadState {
+                        is_save_mode: true,
+                        selected_slot: 0,
+                        current_page: 0,
+                        layout_mode,
+                    });
                     tracing::debug!("children_dirty set at line {}", line!());
                     self.children_dirty = true;
                 }
-                PauseMenuAction::Title => {
-                    // Show confirmation dialog
-                    tracing::debug!("Showing confirmation dialog for return to title");
-                    self.showing_title_confirm = true;
+            }
+            PauseMenuAction::Load => {
+                // Transition to Save/Load menu in load mode
+                tracing::debug!("Opening load menu from pause menu");
+
+                // Load user settings to get layout preference
+                let layout_mode = UserSettings::load("assets/config/settings.ron")
+                    .map(|s| match s.display.save_menu_layout {
+                        narrative_core::config::SaveMenuLayoutMode::List => LayoutMode::List,
+                        narrative_core::config::SaveMenuLayoutMode::Grid => LayoutMode::Grid,
+                    })
+                    .inspect_err(|e| {
+                        tracing::warn!(
+                            "Failed to load user settings, using default layout (List): {}",
+                            e
+                        );
+                    })
+                    .unwrap_or(LayoutMode::List);
+
+                if let AppState::InGame(in_game_state) = &mut self.app_state {
+                    // Save current pause menu state so we can return to it
+                    self.previous_in_game_state = Some(Box::new(in_game_state.clone()));
+
+                    *in_game_state = InGameState::SaveLoadMenu(SaveLoadState {
+                        is_save_mode: false,
+                        selected_slot: 0,
+                        current_page: 0,
+                        layout_mode,
+                    });
                     tracing::debug!("children_dirty set at line {}", line!());
                     self.children_dirty = true;
                 }
             }
+            PauseMenuAction::ReturnToChoice => {
+                tracing::debug!("Returning to the last choice from pause menu");
+                self.return_to_last_choice();
+            }
+            PauseMenuAction::Settings => {
+                // Open settings from pause menu
+                tracing::debug!("Opening settings from pause menu");
+                self.previous_app_state = Some(Box::new(self.app_state.clone()));
+                self.app_state = AppState::Settings(Default::default());
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            PauseMenuAction::Title => {
+                // Show confirmation dialog
+                tracing::debug!("Showing confirmation dialog for return to title");
+                self.showing_title_confirm = true;
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
         }
     }
 
@@ -295,50 +469,98 @@ impl GameRootElement {
             tracing::debug!("Scenario already loaded, starting new game will reset it");
         }
 
-        tracing::info!(
-            "Starting new game: {}",
-            self.config.start_scenario.display()
-        );
-        match ScenarioRuntime::from_toml(&self.config.start_scenario) {
-            Ok(mut runtime) => {
-                // Set unlock data for CG tracking
-                runtime.set_unlock_data(Arc::clone(&self.unlock_data));
-
-                if let Err(e) = runtime.start() {
-                    tracing::error!("Failed to start scenario: {}", e);
-                    tracing::warn!("Staying in MainMenu due to scenario start failure");
-                    return;
-                }
+        let path = self
+            .project
+            .as_ref()
+            .and_then(|project| project.start_scenario())
+            .map(|entry| entry.path.clone())
+            .unwrap_or_else(|| self.config.start_scenario.clone());
+        tracing::info!("Starting new game: {}", path.display());
+        if !self.start_scenario(&path) {
+            tracing::warn!("Staying in MainMenu - please check scenario file path");
+        }
+    }
 
-                // Execute commands until we reach a waiting state
-                let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                    tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-                    e.into_inner()
-                });
-                if let Some(initial_state) = Self::execute_and_transition(&mut runtime, &mut audio)
-                {
-                    self.scenario_runtime = Some(runtime);
-                    self.app_state = AppState::InGame(initial_state);
-                    tracing::debug!("children_dirty set at line {}", line!());
-                    self.children_dirty = true;
-                    tracing::debug!("Scenario started successfully");
-                } else {
-                    tracing::error!("Failed to create initial state from command");
-                    tracing::warn!("Staying in MainMenu - scenario has no valid initial command");
-                }
-            }
+    /// Load a scenario file and run it until the first waiting state
+    ///
+    /// Returns false (leaving the current state untouched) if the scenario
+    /// could not be loaded or has no valid initial command.
+    pub(super) fn start_scenario(&mut self, path: &Path) -> bool {
+        let mut runtime = match ScenarioRuntime::from_toml(path) {
+            Ok(runtime) => runtime,
             Err(e) => {
-                tracing::error!(
-                    "Failed to load scenario file '{}': {}",
-                    self.config.start_scenario.display(),
-                    e
-                );
-                tracing::warn!("Staying in MainMenu - please check scenario file path");
+                tracing::error!("Failed to load scenario file '{}': {}", path.display(), e);
+                return false;
             }
+        };
+
+        #[cfg(feature = "dev")]
+        self.report_missing_glyphs(path, runtime.scenario());
+
+        // Set unlock data for CG tracking
+        runtime.set_unlock_data(Arc::clone(&self.unlock_data));
+        runtime.set_achievements(Arc::clone(&self.achievements));
+        runtime.set_dialogue_languages(self.dialogue_languages.clone());
+        if let Some(resolver) = &self.voice_resolver {
+            runtime.set_voice_resolver(resolver.clone());
+        }
+        #[cfg(feature = "scripting")]
+        runtime.set_script_cache_capacity(self.config.gameplay.script_cache_capacity);
+        runtime
+            .variables_mut()
+            .set_player_profile(&self.player_profile);
+
+        if let Err(e) = runtime.start() {
+            tracing::error!("Failed to start scenario: {}", e);
+            return false;
+        }
+        let replay = Self::new_replay(&runtime);
+
+        // Execute commands until we reach a waiting state
+        let initial_state = Self::execute_and_transition(&mut runtime, &self.audio_queue);
+        let Some(initial_state) = initial_state else {
+            tracing::error!("Failed to create initial state from command");
+            return false;
+        };
+
+        self.scenario_runtime = Some(runtime);
+        self.enter_in_game(initial_state);
+        self.unsaved_play_secs = 0.0;
+        self.replay = replay;
+        self.set_scenario_file(path);
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+        tracing::debug!("Scenario started successfully");
+        true
+    }
+
+    /// Warn about characters of a scenario no font of the fallback chain has
+    #[cfg(feature = "dev")]
+    fn report_missing_glyphs(&self, path: &Path, scenario: &narrative_core::Scenario) {
+        let mut fonts =
+            match narrative_engine::text::FontManager::with_fallback_chain(&self.font_fallback) {
+                Ok(fonts) => fonts,
+                Err(e) => {
+                    tracing::warn!("Could not check scenario glyphs: {}", e);
+                    return;
+                }
+            };
+        let missing = fonts.missing_scenario_glyphs(scenario);
+        if !missing.is_empty() {
+            let list: Vec<String> = missing
+                .iter()
+                .map(|c| format!("'{}' (U+{:04X})", c, u32::from(*c)))
+                .collect();
+            tracing::warn!(
+                "Scenario '{}' uses {} characters no configured font can render: {}",
+                path.display(),
+                missing.len(),
+                list.join(", ")
+            );
         }
     }
 
-    /// Toggle settings menu (shared logic for F1 and ESC keys)
+    /// Toggle settings menu (Escape key)
     pub(super) fn toggle_settings_menu(&mut self) {
         if matches!(self.app_state, AppState::Settings(_)) {
             // Exiting settings - restore previous state
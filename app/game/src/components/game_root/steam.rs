@@ -0,0 +1,53 @@
+//! Steam integration for GameRootElement (`steam` feature)
+
+use super::element::GameRootElement;
+use narrative_engine::runtime::AppState;
+use narrative_engine::steam::Steam;
+
+impl GameRootElement {
+    /// Connect the game to Steam
+    ///
+    /// Saves are mirrored to Steam Cloud (newer cloud saves are downloaded
+    /// now), achievements unlocked so far are set in Steam, and the friends
+    /// list shows the scene being played.
+    pub fn with_steam(mut self, steam: Steam) -> Self {
+        match self.save_manager.lock() {
+            Ok(mut save_manager) => *save_manager = steam.save_manager(&self.config),
+            Err(e) => tracing::error!("Failed to lock save_manager: {}", e),
+        }
+        match self.unlock_data.lock() {
+            Ok(data) => {
+                steam.sync_achievements(data.unlocked_achievements.iter().map(String::as_str))
+            }
+            Err(e) => tracing::error!("Failed to lock unlock_data: {}", e),
+        }
+        self.steam = Some(steam);
+        self
+    }
+
+    /// Run Steam callbacks and show the current scene as rich presence
+    pub(super) fn update_steam(&mut self) {
+        let Some(steam) = &self.steam else {
+            return;
+        };
+        steam.run_callbacks();
+
+        // The demo doesn't count as playing
+        let scene = match (&self.app_state, &self.scenario_runtime) {
+            (AppState::InGame(_), Some(runtime)) if !self.attract_mode => runtime
+                .current_scene()
+                .and_then(|id| runtime.scenario().get_scene(id.as_str())),
+            _ => None,
+        };
+        let scene_id = scene.map(|scene| scene.id.as_str());
+        if self.rich_presence_scene.as_deref() == scene_id {
+            return;
+        }
+
+        match scene {
+            Some(scene) => steam.set_rich_presence(scene),
+            None => steam.clear_rich_presence(),
+        }
+        self.rich_presence_scene = scene_id.map(str::to_string);
+    }
+}
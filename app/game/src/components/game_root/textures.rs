@@ -1,7 +1,17 @@
 //! Texture loading and management for GameRootElement
 
 use super::element::GameRootElement;
-use narrative_engine::asset::TextureHandle;
+use crate::components::{BacklogElement, SaveLoadMenuElement};
+use image::RgbaImage;
+use narrative_core::{AssetRef, EngineResult};
+use narrative_engine::asset::{TextureHandle, load_image_file, load_sprite_image};
+use narrative_engine::save::{DisplaySnapshot, SaveData, SaveManager, thumbnail_layers};
+use narrative_gui::framework::Color;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::framework::renderer::{DrawCommand, Renderer, RendererError};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 
 impl GameRootElement {
     /// Check if background has changed and schedule loading if necessary
@@ -32,9 +42,6 @@ impl GameRootElement {
             runtime_background.map(|bg| bg.path())
         );
 
-        // Save current background as previous for crossfade transitions
-        self.previous_background_texture_id = self.current_background_texture_id;
-
         // Update displayed background
         self.displayed_background = runtime_background.cloned();
 
@@ -63,13 +70,21 @@ impl GameRootElement {
                     new_bg.path(),
                     initial_bg_id
                 );
-                self.background_texture_cache
-                    .insert(new_bg.clone(), initial_bg_id);
+                if let Some((_, evicted)) = self
+                    .background_texture_cache
+                    .insert(new_bg.clone(), TextureHandle::new(initial_bg_id))
+                {
+                    self.evicted_background_textures.push(evicted.id());
+                }
                 // Background is already displayed, no need to load
             }
 
             // Check cache
-            if let Some(&cached_id) = self.background_texture_cache.get(new_bg) {
+            if let Some(cached_id) = self
+                .background_texture_cache
+                .get(new_bg)
+                .map(TextureHandle::id)
+            {
                 tracing::debug!(
                     "Using cached background: {} (id: {})",
                     new_bg.path(),
@@ -120,10 +135,6 @@ impl GameRootElement {
             runtime_cg.map(|cg| cg.path())
         );
 
-        // Save current CG as previous for crossfade transitions
-        self.previous_cg_texture_id = self.current_cg_texture_id;
-        self.previous_cg_texture_size = self.current_cg_texture_size;
-
         // Update displayed CG
         self.displayed_cg = runtime_cg.cloned();
 
@@ -157,7 +168,9 @@ impl GameRootElement {
         &mut self,
         renderer: &mut narrative_gui::framework::renderer::Renderer,
     ) -> bool {
-        let mut needs_redraw = false;
+        let mut needs_redraw = self.load_pending_theme_assets(renderer);
+        needs_redraw |= self.upload_video_frame(renderer);
+        renderer.set_camera_offset(self.screen_shake_offset());
 
         // Load pending background texture
         if let Some(pending_bg) = self.pending_background.clone() {
@@ -166,14 +179,34 @@ impl GameRootElement {
                 pending_bg.path()
             );
 
-            match renderer.load_texture_from_path(std::path::Path::new(pending_bg.path())) {
+            // Decoded images outlive their textures, so a background evicted
+            // from the GPU is re-uploaded without reading the file again
+            let preloader = &mut self.asset_preloader;
+            let texture = self
+                .decoded_image_cache
+                .get_or_load(&pending_bg, |bg| match preloader.take_image(bg) {
+                    Some(image) => Ok(Arc::unwrap_or_clone(image)),
+                    None => load_image_file(bg.path()),
+                })
+                .map_err(|e| e.to_string())
+                .and_then(|image| {
+                    renderer
+                        .load_texture_from_bytes(image.as_raw(), image.width(), image.height())
+                        .map_err(|e| e.to_string())
+                });
+            match texture {
                 Ok(texture_id) => {
                     tracing::debug!(
                         "Loaded background texture: {} (id: {})",
                         pending_bg.path(),
                         texture_id
                     );
-                    self.background_texture_cache.insert(pending_bg, texture_id);
+                    if let Some((_, evicted)) = self
+                        .background_texture_cache
+                        .insert(pending_bg, TextureHandle::new(texture_id))
+                    {
+                        self.evicted_background_textures.push(evicted.id());
+                    }
                     self.current_background_texture_id = Some(texture_id);
                     self.pending_background = None;
                     needs_redraw = true;
@@ -189,11 +222,19 @@ impl GameRootElement {
             }
         }
 
+        self.release_evicted_background_textures(renderer);
+
         // Load pending CG texture
         if let Some(pending_cg) = self.pending_cg.clone() {
             tracing::debug!("Loading pending CG texture: {}", pending_cg.path());
 
-            match renderer.load_texture_from_path(std::path::Path::new(pending_cg.path())) {
+            let texture = match self.asset_preloader.take_image(&pending_cg) {
+                Some(image) => {
+                    renderer.load_texture_from_bytes(image.as_raw(), image.width(), image.height())
+                }
+                None => renderer.load_texture_from_path(std::path::Path::new(pending_cg.path())),
+            };
+            match texture {
                 Ok(texture_id) => {
                     tracing::debug!(
                         "Loaded CG texture: {} (id: {})",
@@ -229,7 +270,8 @@ impl GameRootElement {
 
         // Load pending character textures
         while let Some((character_id, sprite_ref)) = self.pending_character_textures.pop() {
-            match renderer.load_texture_from_path(std::path::Path::new(sprite_ref.path())) {
+            let preloaded = self.asset_preloader.take_image(&sprite_ref);
+            match load_sprite_texture(renderer, &sprite_ref, preloaded) {
                 Ok(texture_id) => {
                     tracing::info!(
                         "Loaded character texture: character='{}', sprite='{}', texture_id={}",
@@ -340,6 +382,279 @@ impl GameRootElement {
             }
         }
 
+        self.write_save_thumbnails(renderer);
+        self.load_hud_icons(renderer);
+        if self.update_preview_thumbnail(renderer) {
+            needs_redraw = true;
+        }
+        needs_redraw |= self.update_transition_texture(renderer);
+
         needs_redraw
     }
+
+    /// Release the GPU textures of backgrounds evicted from the texture cache
+    ///
+    /// The displayed background and the one loaded by the Window are kept.
+    fn release_evicted_background_textures(&mut self, renderer: &mut Renderer) {
+        for texture_id in std::mem::take(&mut self.evicted_background_textures) {
+            if Some(texture_id) != self.current_background_texture_id
+                && Some(texture_id) != self.background_texture_id
+            {
+                renderer.remove_texture(texture_id);
+            }
+        }
+    }
+
+    /// Compose the outgoing frame of a starting scene transition
+    ///
+    /// The frame is composed at window size on the GPU, like save
+    /// thumbnails, and released once the transition has finished.
+    ///
+    /// Returns: true if the transition texture changed
+    fn update_transition_texture(&mut self, renderer: &mut Renderer) -> bool {
+        let Some(snapshot) = self.pending_transition_snapshot.take() else {
+            if !self.transition_started
+                && let Some(texture_id) = self.transition_texture_id.take()
+            {
+                renderer.remove_texture(texture_id);
+            }
+            return false;
+        };
+
+        if let Some(texture_id) = self.transition_texture_id.take() {
+            renderer.remove_texture(texture_id);
+        }
+        let size = (
+            self.window_size.0.round().max(1.0) as u32,
+            self.window_size.1.round().max(1.0) as u32,
+        );
+        match compose_thumbnail(renderer, &snapshot, size) {
+            Ok(texture_id) => self.transition_texture_id = Some(texture_id),
+            Err(e) => tracing::error!("Failed to capture the scene before a transition: {}", e),
+        }
+        true
+    }
+
+    /// Render the preview of the hovered backlog entry or selected save slot
+    ///
+    /// Saved slots show the thumbnail stored with the save; otherwise the
+    /// entry's display snapshot is composed on the GPU when it changes. The
+    /// result is handed to the menu as a texture. Only the latest preview is
+    /// kept on the GPU.
+    ///
+    /// Returns: true if the menu received a new texture
+    fn update_preview_thumbnail(&mut self, renderer: &mut Renderer) -> bool {
+        let preview = self.children.iter().find_map(|child| {
+            let child = child.as_any();
+            if let Some(backlog) = child.downcast_ref::<BacklogElement>() {
+                backlog
+                    .preview_snapshot()
+                    .map(|snapshot| (snapshot.clone(), None))
+            } else {
+                child
+                    .downcast_ref::<SaveLoadMenuElement>()
+                    .and_then(|menu| {
+                        menu.preview_snapshot()
+                            .map(|snapshot| (snapshot.clone(), menu.preview_thumbnail_slot()))
+                    })
+            }
+        });
+        let Some((snapshot, stored_slot)) = preview else {
+            return false;
+        };
+
+        let texture_id = match &self.preview_thumbnail {
+            Some((cached, texture_id)) if *cached == snapshot => *texture_id,
+            _ => {
+                let stored =
+                    stored_slot.and_then(|slot| self.load_stored_thumbnail(renderer, slot));
+                let texture_id = match stored {
+                    Some(texture_id) => texture_id,
+                    None => {
+                        match compose_thumbnail(renderer, &snapshot, self.save_slots.thumbnail_size)
+                        {
+                            Ok(texture_id) => texture_id,
+                            Err(e) => {
+                                tracing::error!("Failed to render preview thumbnail: {}", e);
+                                return false;
+                            }
+                        }
+                    }
+                };
+                if let Some((_, old_texture_id)) = self.preview_thumbnail.take() {
+                    renderer.remove_texture(old_texture_id);
+                }
+                self.preview_thumbnail = Some((snapshot, texture_id));
+                texture_id
+            }
+        };
+
+        let mut updated = false;
+        for child in &mut self.children {
+            let child = child.as_any_mut();
+            if let Some(backlog) = child.downcast_mut::<BacklogElement>() {
+                updated |= backlog.set_preview_texture(Some(texture_id));
+            } else if let Some(menu) = child.downcast_mut::<SaveLoadMenuElement>() {
+                updated |= menu.set_preview_texture(Some(texture_id));
+            }
+        }
+        updated
+    }
+
+    /// Upload the thumbnail stored with a save slot
+    ///
+    /// Returns None if the slot has no readable thumbnail.
+    fn load_stored_thumbnail(&self, renderer: &mut Renderer, slot: usize) -> Option<u64> {
+        let image = match self.save_manager.lock() {
+            Ok(manager) => manager.load_thumbnail(slot),
+            Err(e) => {
+                tracing::error!("Failed to lock save_manager for thumbnail: {:?}", e);
+                return None;
+            }
+        };
+        let image = image
+            .map_err(|e| tracing::debug!("Composing preview of slot {}: {}", slot, e))
+            .ok()?;
+        renderer
+            .load_texture_from_bytes(image.as_raw(), image.width(), image.height())
+            .map_err(|e| tracing::error!("Failed to upload thumbnail of slot {}: {}", slot, e))
+            .ok()
+    }
+
+    /// Render and store the thumbnails of slots saved since the last update
+    fn write_save_thumbnails(&mut self, renderer: &mut Renderer) {
+        let (width, height) = self.save_slots.thumbnail_size;
+        for (slot, snapshot) in std::mem::take(&mut self.pending_save_thumbnails) {
+            // A quick save's thumbnail stays uploaded for its toast
+            let quick_save = self.pending_quick_save_toast == Some(slot);
+            let mut kept_texture = None;
+            let image =
+                compose_thumbnail(renderer, &snapshot, (width, height)).and_then(|texture_id| {
+                    let pixels = renderer.read_texture(texture_id);
+                    if quick_save {
+                        kept_texture = Some(texture_id);
+                    } else {
+                        renderer.remove_texture(texture_id);
+                    }
+                    pixels
+                });
+            let result = match image {
+                Ok(pixels) => match RgbaImage::from_raw(width, height, pixels) {
+                    Some(image) => match self.save_manager.lock() {
+                        Ok(manager) => manager
+                            .save_thumbnail(slot, &image)
+                            .map_err(|e| e.to_string()),
+                        Err(e) => Err(format!("Failed to lock save_manager: {:?}", e)),
+                    },
+                    None => Err("Thumbnail size mismatch".to_string()),
+                },
+                Err(e) => Err(e.to_string()),
+            };
+            if let Err(e) = result {
+                tracing::error!("Failed to write thumbnail of slot {}: {}", slot, e);
+            }
+
+            if quick_save {
+                self.pending_quick_save_toast = None;
+                if let Some(old) = std::mem::replace(&mut self.quick_save_thumbnail, kept_texture) {
+                    renderer.remove_texture(old);
+                }
+                self.show_quick_save_toast(slot, kept_texture);
+            }
+        }
+    }
+}
+
+/// Write a save, recording where its thumbnail will be stored
+///
+/// On success the slot is queued in `pending` so its thumbnail is rendered
+/// on the GPU at the next texture update, then stored beside the save.
+pub(super) fn save_with_thumbnail(
+    manager: &SaveManager,
+    slot: usize,
+    save_data: &mut SaveData,
+    pending: &mut Vec<(usize, DisplaySnapshot)>,
+) -> EngineResult<()> {
+    let snapshot = Some(save_data.display_snapshot()).filter(|snapshot| !snapshot.is_empty());
+    save_data.thumbnail_path = snapshot.as_ref().and_then(|_| manager.thumbnail_name(slot));
+    manager.save(slot, save_data)?;
+    if let Some(snapshot) = snapshot
+        && save_data.thumbnail_path.is_some()
+    {
+        pending.push((slot, snapshot));
+    }
+    Ok(())
+}
+
+/// Upload a sprite, compositing the layers of a layered sprite first
+///
+/// A `preloaded` image of the sprite is uploaded without reading its files.
+fn load_sprite_texture(
+    renderer: &mut Renderer,
+    sprite: &AssetRef,
+    preloaded: Option<Arc<RgbaImage>>,
+) -> Result<u64, String> {
+    if let Some(image) = preloaded {
+        return renderer
+            .load_texture_from_bytes(image.as_raw(), image.width(), image.height())
+            .map_err(|e| e.to_string());
+    }
+    if !sprite.is_layered() {
+        return renderer
+            .load_texture_from_path(Path::new(sprite.path()))
+            .map_err(|e| e.to_string());
+    }
+    let image = load_sprite_image(sprite).map_err(|e| e.to_string())?;
+    renderer
+        .load_texture_from_bytes(image.as_raw(), image.width(), image.height())
+        .map_err(|e| e.to_string())
+}
+
+/// Compose a display snapshot into a texture of `size` on the GPU
+///
+/// Layer images are uploaded at full size, sampled down into the target by
+/// the GPU, then released.
+fn compose_thumbnail(
+    renderer: &mut Renderer,
+    snapshot: &DisplaySnapshot,
+    (width, height): (u32, u32),
+) -> Result<u64, RendererError> {
+    let mut layer_textures: HashMap<String, u64> = HashMap::new();
+    let layers = thumbnail_layers(snapshot, width, height, |path| {
+        if let Some(&texture_id) = layer_textures.get(path) {
+            return renderer.get_texture_size(texture_id);
+        }
+        match load_sprite_texture(renderer, &AssetRef::new(path), None) {
+            Ok(texture_id) => {
+                layer_textures.insert(path.to_string(), texture_id);
+                renderer.get_texture_size(texture_id)
+            }
+            Err(e) => {
+                tracing::warn!("Thumbnail layer '{}' could not be loaded: {}", path, e);
+                None
+            }
+        }
+    });
+
+    let commands: Vec<DrawCommand> = layers
+        .iter()
+        .filter_map(|layer| {
+            Some(DrawCommand::Texture {
+                texture_id: *layer_textures.get(layer.path)?,
+                bounds: Bounds::new(
+                    layer.x as f32,
+                    layer.y as f32,
+                    layer.width as f32,
+                    layer.height as f32,
+                ),
+                opacity: 1.0,
+            })
+        })
+        .collect();
+    let result = renderer.render_to_texture(&commands, width, height, Color::BLACK);
+
+    for texture_id in layer_textures.into_values() {
+        renderer.remove_texture(texture_id);
+    }
+    result
 }
@@ -0,0 +1,144 @@
+//! UI theme loading and reloading for GameRootElement
+
+use super::element::GameRootElement;
+use crate::components::SubtitleStyle;
+use narrative_core::UiThemeDef;
+use narrative_core::config::{DialogueBoxConfig, UserSettings};
+use narrative_engine::EngineConfig;
+use narrative_engine::asset::UiThemeRegistry;
+use narrative_gui::framework::renderer::Renderer;
+use std::path::Path;
+
+/// UI theme manifest, relative to the asset directory
+const UI_THEMES_MANIFEST: &str = "manifests/ui_themes.ron";
+
+/// Load the UI theme selected by `config.ui.theme`
+///
+/// Returns `None` if no theme is selected or it can't be loaded.
+pub(super) fn load_ui_theme(config: &EngineConfig) -> Option<UiThemeDef> {
+    let id = config.ui.theme.as_deref()?;
+
+    let mut registry = UiThemeRegistry::new(config.asset_path.clone());
+    if let Err(e) = registry.load_manifest(UI_THEMES_MANIFEST) {
+        tracing::warn!("Failed to load UI themes, using built-in look: {}", e);
+        return None;
+    }
+
+    let theme = registry.get(id).cloned();
+    if theme.is_none() {
+        tracing::warn!("UI theme '{}' not found in {}", id, UI_THEMES_MANIFEST);
+    }
+    theme
+}
+
+/// Dialogue box configuration with the theme's colors
+///
+/// The player's textbox settings are applied on top of this, so their
+/// opacity wins over the theme palette's.
+pub(super) fn themed_dialogue_box_config(
+    config: &EngineConfig,
+    theme: Option<&UiThemeDef>,
+) -> DialogueBoxConfig {
+    let dialogue_box_config = DialogueBoxConfig {
+        mirrored: config.ui.mirror_dialogue_box,
+        ..DialogueBoxConfig::default()
+    };
+    match theme.and_then(|theme| theme.colors.as_ref()) {
+        Some(palette) => dialogue_box_config.with_palette(palette),
+        None => dialogue_box_config,
+    }
+}
+
+impl GameRootElement {
+    /// Reload the UI theme and user settings from disk
+    ///
+    /// Bound to F5 in debug mode so designers can iterate on theme RON while
+    /// the game runs. Colors, settings and the quick menu layout apply when the
+    /// UI is rebuilt; the dialogue box texture, icons and font are loaded on the
+    /// next frame.
+    pub(super) fn reload_ui_theme_and_settings(&mut self) {
+        self.ui_theme = load_ui_theme(&self.config);
+
+        let settings = UserSettings::load("assets/config/settings.ron").unwrap_or_else(|e| {
+            tracing::warn!("Could not reload user settings, using defaults: {}", e);
+            UserSettings::default()
+        });
+        self.dialogue_box_config = themed_dialogue_box_config(&self.config, self.ui_theme.as_ref());
+        self.subtitles
+            .set_style(SubtitleStyle::from_theme(self.ui_theme.as_ref()));
+        self.apply_user_settings(&settings);
+
+        self.theme_assets_pending = true;
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+        tracing::info!(
+            "Reloaded UI theme ({}) and user settings",
+            self.ui_theme
+                .as_ref()
+                .map_or("built-in", |theme| theme.id.as_str())
+        );
+    }
+
+    /// Load the current theme's dialogue box texture, quick menu icons and dialogue font
+    ///
+    /// Returns: true if theme assets were (re)loaded
+    pub(super) fn load_pending_theme_assets(&mut self, renderer: &mut Renderer) -> bool {
+        if !std::mem::take(&mut self.theme_assets_pending) {
+            return false;
+        }
+
+        if let Some(texture_id) = self.dialogue_box_texture_id.take() {
+            renderer.remove_texture(texture_id);
+        }
+        if let Some(theme) = &self.ui_theme {
+            let path = &theme.dialogue_box.default;
+            match renderer.load_texture_from_path(Path::new(path)) {
+                Ok(texture_id) => self.dialogue_box_texture_id = Some(texture_id),
+                Err(e) => tracing::warn!("Failed to load dialogue box '{}': {}", path, e),
+            }
+        }
+
+        for (_, texture_id) in self.quick_menu_icon_textures.drain() {
+            renderer.remove_texture(texture_id);
+        }
+        let icons = self
+            .ui_theme
+            .as_ref()
+            .and_then(|theme| theme.quick_menu.as_ref())
+            .into_iter()
+            .flat_map(|quick_menu| &quick_menu.buttons)
+            .filter_map(|button| button.icon.as_ref());
+        for path in icons {
+            if self.quick_menu_icon_textures.contains_key(path) {
+                continue;
+            }
+            match renderer.load_texture_from_path(Path::new(path)) {
+                Ok(texture_id) => {
+                    self.quick_menu_icon_textures
+                        .insert(path.clone(), texture_id);
+                }
+                Err(e) => tracing::warn!("Failed to load quick menu icon '{}': {}", path, e),
+            }
+        }
+
+        let font = self
+            .ui_theme
+            .as_ref()
+            .and_then(|theme| theme.fonts.as_ref())
+            .and_then(|fonts| fonts.dialogue.as_deref());
+        match font {
+            Some(path) => match renderer.set_default_font(Path::new(path)) {
+                Ok(family) => tracing::debug!("Using theme font '{}' from {}", family, path),
+                Err(e) => {
+                    tracing::warn!("Failed to load theme font '{}': {}", path, e);
+                    renderer.reset_default_font();
+                }
+            },
+            None => renderer.reset_default_font(),
+        }
+
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+        true
+    }
+}
@@ -2,14 +2,16 @@
 
 use super::element::GameRootElement;
 use narrative_core::config::UserSettings;
-use narrative_core::{ScenarioCommand, Speaker};
-use narrative_engine::AudioManager;
+use narrative_core::{EndBehavior, ScenarioCommand, Speaker};
+use narrative_engine::AudioQueue;
 use narrative_engine::runtime::{
-    AppState, ChoiceState, CommandExecutionResult, InGameState, MainMenuState, ScenarioRuntime,
-    TypingState, WaitState,
+    AppState, ChoiceState, CommandExecutionResult, EffectState, EndingState, InGameState,
+    MainMenuState, NameInputState, PlayingBgm, ScenarioRuntime, SkipStop, TypingState, VideoState,
+    WaitState, WaitingInputState,
 };
+use narrative_engine::text::TypewriterEffect;
 use narrative_gui::framework::animation::AnimationContext;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
 
 impl GameRootElement {
     /// Advance after waiting input state
@@ -24,13 +26,7 @@ impl GameRootElement {
         if runtime.advance_command() {
             tracing::debug!("Successfully advanced to next command");
             // Successfully advanced, execute new command
-            let new_state = {
-                let mut audio = self.audio_manager.lock().unwrap_or_else(|e| {
-                    tracing::warn!("AudioManager mutex poisoned, recovering: {}", e);
-                    e.into_inner()
-                });
-                Self::execute_and_transition(runtime, &mut audio)
-            }; // audio lock is dropped here
+            let new_state = Self::execute_and_transition(runtime, &self.audio_queue);
 
             if let Some(new_state) = new_state {
                 tracing::debug!(
@@ -38,11 +34,6 @@ impl GameRootElement {
                     std::mem::discriminant(&new_state)
                 );
 
-                // If transitioning to TransitionState, immediately schedule background load
-                if matches!(new_state, InGameState::Transition(_)) {
-                    self.update_background_if_changed();
-                }
-
                 if let Some(in_game_state) = self.app_state.in_game_state_mut() {
                     *in_game_state = new_state;
                     tracing::debug!("children_dirty set at line {}", line!());
@@ -51,11 +42,8 @@ impl GameRootElement {
             } else {
                 // No next state after command execution
                 tracing::warn!("No next state after command execution");
-                if runtime.is_ended() {
-                    tracing::debug!("Scenario ended");
-                    self.app_state = AppState::MainMenu(MainMenuState::default());
-                    tracing::debug!("children_dirty set at line {}", line!());
-                    self.children_dirty = true;
+                if runtime.is_ended() || runtime.has_scenario_jump() {
+                    self.handle_scenario_end();
                 } else {
                     // Unexpected: not ended but no next state
                     tracing::error!("Runtime not ended but no next state available");
@@ -67,14 +55,370 @@ impl GameRootElement {
         } else {
             // At end of scene/scenario
             if runtime.is_ended() {
-                tracing::debug!("Scenario ended");
-                self.app_state = AppState::MainMenu(MainMenuState::default());
+                self.handle_scenario_end();
+            }
+        }
+    }
+
+    /// Start or finish the outgoing frame of a scene transition
+    ///
+    /// When a transition starts, what is on screen is captured for it to
+    /// blend from, then the new scene's display commands run so it blends
+    /// into the new picture whatever order they come in. The capture is let
+    /// go once play moves on; menus opened over the transition keep it.
+    pub(super) fn update_scene_transition(&mut self) {
+        match &self.app_state {
+            AppState::InGame(InGameState::Transition(_)) if !self.transition_started => {
+                self.transition_started = true;
+                let Some(runtime) = self.scenario_runtime.as_mut() else {
+                    return;
+                };
+                self.pending_transition_snapshot = Some(runtime.display_snapshot());
+                if let Err(e) = runtime.execute_display_commands() {
+                    tracing::error!("Failed to set up the scene of a transition: {}", e);
+                }
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            AppState::InGame(
+                InGameState::Transition(_)
+                | InGameState::PauseMenu(_)
+                | InGameState::SaveLoadMenu(_)
+                | InGameState::Backlog(_)
+                | InGameState::CgGallery(_)
+                | InGameState::CgViewer(_)
+                | InGameState::Achievements(_),
+            ) => {}
+            _ => self.transition_started = false,
+        }
+    }
+
+    /// Roll dialogue back to the previous line, or forward again
+    ///
+    /// The restored line is shown fully typed. BGM is switched when the
+    /// restored line played different music, and skip mode is turned off so
+    /// the rolled-back line stays on screen.
+    pub(super) fn step_rollback(&mut self, forward: bool) {
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return;
+        };
+
+        let previous_bgm = runtime.current_bgm().cloned();
+        let moved = if forward {
+            runtime.roll_forward()
+        } else {
+            runtime.rollback()
+        };
+        if !moved {
+            tracing::debug!(
+                "Nothing to roll {}",
+                if forward { "forward" } else { "back" }
+            );
+            return;
+        }
+        self.stop_replay("playback was rolled back");
+        self.sync_bgm_after_rewind(previous_bgm);
+
+        let Some(runtime) = self.scenario_runtime.as_ref() else {
+            return;
+        };
+        let Some(scene_id) = runtime.current_scene().cloned() else {
+            return;
+        };
+        let command_index = runtime.command_index();
+        tracing::debug!(
+            "Rolled {} to {}:{}",
+            if forward { "forward" } else { "back" },
+            scene_id.as_str(),
+            command_index
+        );
+
+        self.config.gameplay.skip_mode_enabled = false;
+        if let Some(in_game_state) = self.app_state.in_game_state_mut() {
+            *in_game_state = InGameState::WaitingInput(WaitingInputState {
+                scene_id,
+                command_index,
+                auto_wait_elapsed: 0.0,
+                skip_mode: false,
+            });
+            tracing::debug!("children_dirty set at line {}", line!());
+            self.children_dirty = true;
+        }
+    }
+
+    /// Rewind to the most recent choice from the pause menu so it can be picked again
+    pub(super) fn return_to_last_choice(&mut self) {
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return;
+        };
+
+        let previous_bgm = runtime.current_bgm().cloned();
+        if !runtime.return_to_last_choice() {
+            tracing::debug!("No choice to return to");
+            return;
+        }
+        self.stop_replay("returned to the last choice");
+        self.sync_bgm_after_rewind(previous_bgm);
+
+        let Some(runtime) = self.scenario_runtime.as_ref() else {
+            return;
+        };
+        let Some(state) = Self::create_state_from_command(runtime) else {
+            tracing::warn!("Returned to the last choice, but no choice is shown");
+            return;
+        };
+        tracing::debug!("Returned to the last choice");
+
+        self.config.gameplay.skip_mode_enabled = false;
+        self.previous_in_game_state = None;
+        if let Some(in_game_state) = self.app_state.in_game_state_mut() {
+            *in_game_state = state;
+            tracing::debug!("children_dirty set at line {}", line!());
+            self.children_dirty = true;
+        }
+    }
+
+    /// Skip straight to the next choice or unread line (quick menu)
+    ///
+    /// Unlike skip mode, which types out each read line quickly, everything
+    /// up to the stop runs at once, including waits, effects and scene
+    /// transitions. Only works while a line is shown.
+    pub(super) fn skip_to_next_stop(&mut self) {
+        let AppState::InGame(
+            InGameState::Typing(TypingState {
+                scene_id,
+                command_index,
+                ..
+            })
+            | InGameState::WaitingInput(WaitingInputState {
+                scene_id,
+                command_index,
+                ..
+            }),
+        ) = &self.app_state
+        else {
+            tracing::debug!("Skip to next choice ignored: no line shown");
+            return;
+        };
+        let (scene_id, command_index) = (scene_id.clone(), *command_index);
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return;
+        };
+
+        // The line on screen has been seen
+        runtime
+            .read_history_mut()
+            .mark_read(scene_id, command_index);
+        let previous_bgm = runtime.current_bgm().cloned();
+        let stop = match runtime.skip_to_next_stop() {
+            Ok(stop) => stop,
+            Err(e) => {
+                tracing::error!("Failed to skip to the next choice: {}", e);
+                return;
+            }
+        };
+        tracing::debug!("Skipped to {:?}", stop);
+        self.sync_bgm_after_rewind(previous_bgm);
+        self.config.gameplay.skip_mode_enabled = false;
+
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return;
+        };
+        let new_state = match stop {
+            SkipStop::End => None,
+            _ => Self::enter_current_command(runtime)
+                .or_else(|| Self::execute_and_transition(runtime, &self.audio_queue)),
+        };
+        match new_state {
+            Some(state) => {
+                if let Some(in_game_state) = self.app_state.in_game_state_mut() {
+                    *in_game_state = state;
+                }
                 tracing::debug!("children_dirty set at line {}", line!());
                 self.children_dirty = true;
             }
+            None => self.handle_scenario_end(),
         }
     }
 
+    /// Play the BGM of the state rolled back or skipped to, if it differs from `previous_bgm`
+    fn sync_bgm_after_rewind(&self, previous_bgm: Option<PlayingBgm>) {
+        let Some(runtime) = self.scenario_runtime.as_ref() else {
+            return;
+        };
+        if runtime.current_bgm() != previous_bgm.as_ref() {
+            match runtime.current_bgm() {
+                Some(bgm) => self
+                    .audio_queue
+                    .play_bgm(bgm.asset.path(), true, None, bgm.volume),
+                None => self.audio_queue.stop_bgm(None),
+            }
+        }
+    }
+
+    /// Leave the current scenario once it reached `End` or a JumpToScenario command
+    ///
+    /// A pending scenario jump is followed; otherwise the scenario's end
+    /// behavior is applied.
+    pub(super) fn handle_scenario_end(&mut self) {
+        // The demo ends on the title screen, without following the scenario's end
+        if self.attract_mode {
+            self.stop_attract_mode();
+            return;
+        }
+        self.save_replay();
+
+        if let Some(jump) = self
+            .scenario_runtime
+            .as_mut()
+            .and_then(|runtime| runtime.take_scenario_jump())
+        {
+            match self.scenario_path(&jump.id) {
+                Some(path) if self.continue_in_scenario(&path, jump.scene.as_deref()) => return,
+                Some(_) => {}
+                None => tracing::error!("Scenario '{}' is not in the project manifest", jump.id),
+            }
+            tracing::warn!(
+                "Failed to jump to scenario '{}', returning to title",
+                jump.id
+            );
+            self.return_to_title_after_end();
+            return;
+        }
+
+        let (scenario_id, on_end) = self
+            .scenario_runtime
+            .as_ref()
+            .map(|runtime| {
+                let metadata = &runtime.scenario().metadata;
+                (metadata.id.clone(), metadata.on_end.clone())
+            })
+            .unwrap_or_default();
+        tracing::debug!("Scenario '{}' ended: {:?}", scenario_id, on_end);
+
+        let next_path = match &on_end {
+            EndBehavior::NextScenario { path } => Some(PathBuf::from(path)),
+            EndBehavior::Continue => self
+                .project
+                .as_ref()
+                .and_then(|project| project.next_scenario(&scenario_id))
+                .map(|entry| entry.path.clone()),
+            _ => None,
+        };
+        if let Some(path) = next_path {
+            if self.continue_in_scenario(&path, None) {
+                return;
+            }
+            tracing::warn!(
+                "Failed to start next scenario '{}', returning to title",
+                path.display()
+            );
+        } else if on_end == EndBehavior::Continue {
+            tracing::warn!("No next scenario after '{}' in the project", scenario_id);
+        }
+
+        if let EndBehavior::EndingCard { ending_id, .. } = &on_end {
+            self.record_ending(ending_id);
+        }
+
+        match EndingState::from_end_behavior(&on_end) {
+            Some(ending) => {
+                self.app_state = AppState::InGame(InGameState::Ending(ending));
+                tracing::debug!("children_dirty set at line {}", line!());
+                self.children_dirty = true;
+            }
+            None => self.return_to_title_after_end(),
+        }
+    }
+
+    fn return_to_title_after_end(&mut self) {
+        self.app_state = AppState::MainMenu(MainMenuState::default());
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+    }
+
+    /// Path of a scenario in the project manifest
+    pub(super) fn scenario_path(&self, id: &str) -> Option<PathBuf> {
+        self.project
+            .as_ref()
+            .and_then(|project| project.get(id))
+            .map(|entry| entry.path.clone())
+    }
+
+    /// Continue playing in another scenario file
+    ///
+    /// Flags, variables and read history carry over from the current runtime.
+    /// Returns false if the scenario could not be loaded or started.
+    fn continue_in_scenario(&mut self, path: &Path, scene: Option<&str>) -> bool {
+        let Some(runtime) = self.scenario_runtime.as_mut() else {
+            return self.start_scenario(path);
+        };
+
+        tracing::info!("Continuing in scenario: {}", path.display());
+        if let Err(e) = runtime.switch_scenario_from_toml(path, scene) {
+            tracing::error!("Failed to load scenario file '{}': {}", path.display(), e);
+            return false;
+        }
+
+        let new_state = Self::execute_and_transition(runtime, &self.audio_queue);
+        let Some(new_state) = new_state else {
+            tracing::error!("Scenario '{}' has no valid initial command", path.display());
+            return false;
+        };
+
+        if matches!(new_state, InGameState::Transition(_)) {
+            self.update_background_if_changed();
+        }
+        self.app_state = AppState::InGame(new_state);
+        self.set_scenario_file(path);
+        tracing::debug!("children_dirty set at line {}", line!());
+        self.children_dirty = true;
+        true
+    }
+
+    /// Record a reached ending in the unlock data and save it
+    fn record_ending(&self, ending_id: &str) {
+        match self.unlock_data.lock() {
+            Ok(mut data) => {
+                let count = data.record_ending(ending_id);
+                tracing::info!("Ending reached: {} ({} times)", ending_id, count);
+                if let Err(e) = data.save_default() {
+                    tracing::warn!("Failed to save unlock data: {}", e);
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to lock unlock_data: {}", e);
+            }
+        }
+    }
+
+    /// Create the state of the current command, if it waits
+    ///
+    /// Dialogue lines are added to the backlog as they are entered.
+    pub(super) fn enter_current_command(runtime: &mut ScenarioRuntime) -> Option<InGameState> {
+        let state = Self::create_state_from_command(runtime)?;
+        // Add dialogue to backlog when creating Typing state from Dialogue command
+        if let Some(ScenarioCommand::Dialogue { dialogue }) = runtime.get_current_command()
+            && let Some(scene_id) = runtime.current_scene()
+        {
+            let command_index = runtime.command_index();
+            let (text, translation) = dialogue.text_tracks(runtime.dialogue_languages());
+            let variables = runtime.variables();
+            let text = TypewriterEffect::strip_tags(&variables.substitute(text));
+            let translation = translation.map(|translation| {
+                TypewriterEffect::strip_tags(&variables.substitute(translation))
+            });
+            runtime.add_to_backlog_with_translation(
+                scene_id.clone(),
+                command_index,
+                dialogue.speaker.clone(),
+                text,
+                translation,
+            );
+        }
+        Some(state)
+    }
+
     /// Create InGameState from the current command in the runtime
     pub(super) fn create_state_from_command(runtime: &ScenarioRuntime) -> Option<InGameState> {
         let command = runtime.get_current_command()?;
@@ -96,16 +440,14 @@ impl GameRootElement {
                     Speaker::Narrator | Speaker::System => None,
                 };
 
-                Some(InGameState::Typing(TypingState {
-                    scene_id,
-                    command_index,
-                    speaker,
-                    text: Arc::from(dialogue.text.clone()),
-                    char_index: 0,
-                    elapsed: 0.0,
-                    auto_mode: false,
-                    skip_mode: false,
-                }))
+                // Player-entered names and other variables are filled in here
+                let (text, translation) = dialogue.text_tracks(runtime.dialogue_languages());
+                let variables = runtime.variables();
+                let translation = translation.map(|translation| variables.substitute(translation));
+                Some(InGameState::Typing(
+                    TypingState::new(scene_id, command_index, speaker, variables.substitute(text))
+                        .with_translation(translation.as_deref()),
+                ))
             }
 
             ScenarioCommand::ShowChoice { choice } => {
@@ -113,7 +455,7 @@ impl GameRootElement {
                 Some(InGameState::ShowingChoices(ChoiceState {
                     scene_id,
                     command_index,
-                    choices: choice.options.clone(),
+                    choices: runtime.displayed_choices(),
                     selected: 0,
                     confirmed: false,
                 }))
@@ -123,6 +465,18 @@ impl GameRootElement {
                 Some(InGameState::Waiting(WaitState::new(*duration)))
             }
 
+            ScenarioCommand::ScreenShake { .. } | ScenarioCommand::Flash { .. } => {
+                EffectState::from_command(command).map(InGameState::PlayingEffect)
+            }
+
+            ScenarioCommand::PlayVideo { path, skippable } => Some(InGameState::PlayingVideo(
+                VideoState::new(path.clone(), *skippable),
+            )),
+
+            ScenarioCommand::InputName { .. } => {
+                NameInputState::from_command(command).map(InGameState::InputtingName)
+            }
+
             // Other commands don't create waiting states, they execute immediately
             _ => None,
         }
@@ -131,7 +485,7 @@ impl GameRootElement {
     /// Execute current command and transition to next state
     pub(super) fn execute_and_transition(
         runtime: &mut ScenarioRuntime,
-        audio_manager: &mut AudioManager,
+        audio: &AudioQueue,
     ) -> Option<InGameState> {
         tracing::debug!("execute_and_transition called");
 
@@ -142,9 +496,7 @@ impl GameRootElement {
                 match command {
                     ScenarioCommand::PlaySe { asset, volume } => {
                         tracing::debug!("Playing SE: {}", asset.path());
-                        if let Err(e) = audio_manager.play_se(asset.path(), *volume) {
-                            tracing::error!("Failed to play SE '{}': {}", asset.path(), e);
-                        }
+                        audio.play_se(asset.path(), *volume);
                     }
                     ScenarioCommand::PlayBgm {
                         asset,
@@ -157,11 +509,7 @@ impl GameRootElement {
                         } else {
                             None
                         };
-                        if let Err(e) =
-                            audio_manager.play_bgm(asset.path(), true, fade_duration, *volume)
-                        {
-                            tracing::error!("Failed to play BGM '{}': {}", asset.path(), e);
-                        }
+                        audio.play_bgm(asset.path(), true, fade_duration, *volume);
                     }
                     ScenarioCommand::StopBgm { fade_out } => {
                         tracing::debug!("Stopping BGM");
@@ -170,9 +518,7 @@ impl GameRootElement {
                         } else {
                             None
                         };
-                        if let Err(e) = audio_manager.stop_bgm(fade_duration) {
-                            tracing::error!("Failed to stop BGM: {}", e);
-                        }
+                        audio.stop_bgm(fade_duration);
                     }
                     _ => {}
                 }
@@ -202,20 +548,7 @@ impl GameRootElement {
                     }
 
                     // Try to create state from new command
-                    if let Some(state) = Self::create_state_from_command(runtime) {
-                        // Add dialogue to backlog when creating Typing state from Dialogue command
-                        if let Some(command) = runtime.get_current_command()
-                            && let ScenarioCommand::Dialogue { dialogue } = command
-                            && let Some(scene_id) = runtime.current_scene()
-                        {
-                            let command_index = runtime.command_index();
-                            runtime.add_to_backlog(
-                                scene_id.clone(),
-                                command_index,
-                                dialogue.speaker.clone(),
-                                dialogue.text.clone(),
-                            );
-                        }
+                    if let Some(state) = Self::enter_current_command(runtime) {
                         return Some(state);
                     }
                     // If no state was created, loop to execute the next command
@@ -257,20 +590,7 @@ impl GameRootElement {
                     }
 
                     // No entry transition, scene changed, try to create state from first command of new scene
-                    if let Some(state) = Self::create_state_from_command(runtime) {
-                        // Add dialogue to backlog when creating Typing state from Dialogue command
-                        if let Some(command) = runtime.get_current_command()
-                            && let ScenarioCommand::Dialogue { dialogue } = command
-                            && let Some(scene_id) = runtime.current_scene()
-                        {
-                            let command_index = runtime.command_index();
-                            runtime.add_to_backlog(
-                                scene_id.clone(),
-                                command_index,
-                                dialogue.speaker.clone(),
-                                dialogue.text.clone(),
-                            );
-                        }
+                    if let Some(state) = Self::enter_current_command(runtime) {
                         return Some(state);
                     }
                     // If no waiting state, continue executing commands
@@ -293,10 +613,28 @@ impl GameRootElement {
                     }));
                 }
 
+                CommandExecutionResult::PlayVideo { path, skippable } => {
+                    return Some(InGameState::PlayingVideo(VideoState::new(path, skippable)));
+                }
+
                 CommandExecutionResult::Wait(duration) => {
                     return Some(InGameState::Waiting(WaitState::new(duration)));
                 }
 
+                CommandExecutionResult::InputName(name_input) => {
+                    return Some(InGameState::InputtingName(name_input));
+                }
+
+                CommandExecutionResult::PlayEffect(effect) => {
+                    return Some(InGameState::PlayingEffect(effect));
+                }
+
+                CommandExecutionResult::JumpToScenario(jump) => {
+                    // Resolved by the caller through handle_scenario_end
+                    tracing::debug!("Jump to scenario '{}'", jump.id);
+                    return None;
+                }
+
                 CommandExecutionResult::End => {
                     tracing::debug!("Scenario ended");
                     return None;
@@ -0,0 +1,118 @@
+//! Video cutscene playback for GameRootElement
+
+use super::audio::play_subtitles;
+use super::element::GameRootElement;
+use crate::components::SubtitleElement;
+use narrative_core::SubtitleTrack;
+use narrative_engine::AudioManager;
+use narrative_engine::runtime::VideoState;
+use narrative_engine::video::VideoPlayer;
+use narrative_gui::framework::renderer::Renderer;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+impl GameRootElement {
+    /// Advance the video of a PlayingVideo state
+    ///
+    /// Opens the video on the first update, starting its audio track and
+    /// sidecar subtitles. While the audio track plays, frames follow its
+    /// position instead of the frame clock. `skip` ends a skippable video.
+    ///
+    /// Returns: true once the video is over (ended, skipped or unplayable),
+    /// with its audio and subtitles stopped
+    pub(super) fn update_video(
+        player: &mut Option<VideoPlayer>,
+        video: &VideoState,
+        delta: f32,
+        skip: bool,
+        audio: &mut AudioManager,
+        subtitles: &mut SubtitleElement,
+        subtitle_tracks: &mut HashMap<PathBuf, SubtitleTrack>,
+    ) -> bool {
+        let finished = Self::advance_video(player, video, delta, skip, audio, |path| {
+            play_subtitles(subtitles, subtitle_tracks, path)
+        });
+        if finished {
+            audio.stop_soundtrack();
+            subtitles.stop();
+        }
+        finished
+    }
+
+    /// Open or advance the video; true once it is over
+    fn advance_video(
+        player: &mut Option<VideoPlayer>,
+        video: &VideoState,
+        delta: f32,
+        skip: bool,
+        audio: &mut AudioManager,
+        start_subtitles: impl FnOnce(&Path),
+    ) -> bool {
+        if skip && video.skippable {
+            tracing::debug!("Video '{}' skipped", video.path.path());
+            return true;
+        }
+
+        let player = match player {
+            Some(player) => player,
+            None => match VideoPlayer::open(Path::new(video.path.path())) {
+                Ok(mut opened) => {
+                    if let Some(soundtrack) = opened.take_soundtrack()
+                        && let Err(e) = audio.play_soundtrack(soundtrack)
+                    {
+                        tracing::warn!("No audio for video '{}': {}", video.path.path(), e);
+                    }
+                    start_subtitles(Path::new(video.path.path()));
+                    player.insert(opened)
+                }
+                Err(e) => {
+                    tracing::warn!("Skipping video '{}': {}", video.path.path(), e);
+                    return true;
+                }
+            },
+        };
+
+        let result = match audio.soundtrack_position() {
+            Some(position) => player.update_to(position as f32),
+            None => player.update(delta),
+        };
+        match result {
+            Ok(_) => player.is_finished(),
+            Err(e) => {
+                tracing::warn!("Stopping video '{}': {}", video.path.path(), e);
+                true
+            }
+        }
+    }
+
+    /// Upload the newest video frame, or free the video texture after playback
+    ///
+    /// Returns: true if a new frame was uploaded
+    pub(super) fn upload_video_frame(&mut self, renderer: &mut Renderer) -> bool {
+        let Some(player) = &mut self.video_player else {
+            if let Some(texture_id) = self.video_texture_id.take() {
+                renderer.remove_texture(texture_id);
+            }
+            return false;
+        };
+        let Some(frame) = player.take_new_frame() else {
+            return false;
+        };
+
+        match renderer.upload_video_frame(
+            self.video_texture_id,
+            &frame.rgba,
+            frame.width,
+            frame.height,
+        ) {
+            Ok(texture_id) => {
+                self.video_texture_id = Some(texture_id);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to upload video frame: {}", e);
+                false
+            }
+        }
+    }
+}
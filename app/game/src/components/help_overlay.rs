@@ -0,0 +1,363 @@
+//! Keyboard shortcut help overlay
+//!
+//! Lists the current bindings grouped by context (reading, choices, menus) in
+//! a modal drawn over everything else. The rebindable actions come from the
+//! player's [`Bindings`], so the list stays accurate after remapping; fixed
+//! shortcuts are listed alongside them.
+
+use narrative_core::config::{Bindings, InputAction};
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::{InputEvent, KeyCode};
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, font_size, radius, spacing};
+use narrative_gui::{Point, Size};
+use std::any::Any;
+use taffy::NodeId;
+
+/// One shortcut in the help overlay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpEntry {
+    /// What the shortcut does
+    pub label: &'static str,
+    /// Inputs that trigger it (e.g. "Enter, Space, Left Click")
+    pub keys: String,
+}
+
+impl HelpEntry {
+    fn new(label: &'static str, keys: impl Into<String>) -> Self {
+        Self {
+            label,
+            keys: keys.into(),
+        }
+    }
+}
+
+/// Shortcuts of one context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpSection {
+    /// Context name
+    pub title: &'static str,
+    /// Shortcuts, in display order
+    pub entries: Vec<HelpEntry>,
+}
+
+/// Shortcuts grouped by context, with the rebindable ones taken from `bindings`
+pub fn help_sections(bindings: &Bindings) -> Vec<HelpSection> {
+    let mut reading: Vec<HelpEntry> = InputAction::ALL
+        .into_iter()
+        .map(|action| HelpEntry::new(action.label(), bindings.describe(action)))
+        .collect();
+    reading.extend([
+        HelpEntry::new("Roll Back", "Page Up, Wheel Up"),
+        HelpEntry::new("Roll Forward", "Page Down, Wheel Down"),
+        HelpEntry::new("Pause Menu", "Escape"),
+    ]);
+
+    vec![
+        HelpSection {
+            title: "Reading",
+            entries: reading,
+        },
+        HelpSection {
+            title: "Choices",
+            entries: vec![
+                HelpEntry::new("Select", "Up, Down"),
+                HelpEntry::new("Choose", "Enter, Left Click"),
+            ],
+        },
+        HelpSection {
+            title: "Menus",
+            entries: vec![
+                HelpEntry::new("Navigate", "Up, Down, Left, Right"),
+                HelpEntry::new("Confirm", "Enter, Space"),
+                HelpEntry::new("Back", "Escape"),
+                HelpEntry::new("Delete Save", "Delete"),
+                HelpEntry::new("Restart Scene", "R"),
+                HelpEntry::new("Export Backlog", "E (text), H (HTML)"),
+            ],
+        },
+    ]
+}
+
+/// Keyboard shortcut help overlay element
+///
+/// Hidden until [`show`](Self::show)n. While visible it takes all input and
+/// closes on F1, `?`, Escape or a click.
+pub struct HelpOverlayElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Sections shown, empty while hidden
+    sections: Vec<HelpSection>,
+    /// Whether the overlay is shown
+    visible: bool,
+}
+
+impl HelpOverlayElement {
+    /// Width of one section column
+    const COLUMN_WIDTH: f32 = 320.0;
+    /// Height of one entry (label and keys lines)
+    const ENTRY_HEIGHT: f32 = 44.0;
+    /// Background dimming alpha
+    const OVERLAY_ALPHA: f32 = 0.8;
+    /// Hint shown under the sections
+    const CLOSE_HINT: &'static str = "Press F1, ? or Escape to close";
+
+    /// Create a hidden help overlay
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            sections: Vec::new(),
+            visible: false,
+        }
+    }
+
+    /// Show the overlay with the current bindings
+    pub fn show(&mut self, bindings: &Bindings) {
+        self.sections = help_sections(bindings);
+        self.visible = true;
+    }
+
+    /// Hide the overlay
+    pub fn hide(&mut self) {
+        self.sections.clear();
+        self.visible = false;
+    }
+
+    /// Show the overlay if hidden, hide it otherwise
+    pub fn toggle(&mut self, bindings: &Bindings) {
+        if self.visible {
+            self.hide();
+        } else {
+            self.show(bindings);
+        }
+    }
+
+    /// Whether the overlay is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Sections shown
+    pub fn sections(&self) -> &[HelpSection] {
+        &self.sections
+    }
+
+    /// Check if an event opens or closes the help overlay (F1 or `?`)
+    pub fn is_toggle_event(event: &InputEvent) -> bool {
+        matches!(
+            event,
+            InputEvent::KeyDown {
+                key: KeyCode::F1,
+                ..
+            } | InputEvent::CharInput { character: '?' }
+        )
+    }
+}
+
+impl Default for HelpOverlayElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for HelpOverlayElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Overlay the whole screen
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, _cx: &mut PaintContext) {
+        // Drawn at the overlay layer, over menus and dialogs
+    }
+
+    fn paint_overlay(&self, cx: &mut PaintContext) {
+        if !self.visible {
+            return;
+        }
+
+        let mut dim = colors::BG_DARKEST;
+        dim.a = Self::OVERLAY_ALPHA;
+        cx.fill_rect(cx.bounds, dim);
+
+        let columns = self.sections.len().max(1) as f32;
+        let rows = self
+            .sections
+            .iter()
+            .map(|section| section.entries.len())
+            .max()
+            .unwrap_or(0) as f32;
+        let title_height = font_size::XL + spacing::LG;
+        let header_height = font_size::LG + spacing::MD;
+        let panel_size = Size::new(
+            (columns * Self::COLUMN_WIDTH + spacing::XL * 2.0).min(cx.bounds.size.width),
+            (title_height
+                + header_height
+                + rows * Self::ENTRY_HEIGHT
+                + font_size::SM
+                + spacing::XL * 2.0
+                + spacing::MD)
+                .min(cx.bounds.size.height),
+        );
+        let panel = Bounds {
+            origin: Point::new(
+                cx.bounds.origin.x + (cx.bounds.size.width - panel_size.width) / 2.0,
+                cx.bounds.origin.y + (cx.bounds.size.height - panel_size.height) / 2.0,
+            ),
+            size: panel_size,
+        };
+        cx.fill_rounded_rect(panel, colors::CARD_BG, radius::LG);
+        cx.stroke_rect(panel, colors::BORDER_LIGHT, 2.0);
+
+        let left = panel.origin.x + spacing::XL;
+        let top = panel.origin.y + spacing::XL;
+        cx.draw_text(
+            "Controls",
+            Point::new(left, top + font_size::XL),
+            colors::TEXT_PRIMARY,
+            font_size::XL,
+        );
+
+        let sections_top = top + title_height;
+        for (column, section) in self.sections.iter().enumerate() {
+            let x = left + column as f32 * Self::COLUMN_WIDTH;
+            cx.draw_text(
+                section.title,
+                Point::new(x, sections_top + font_size::LG),
+                colors::TEXT_ACCENT,
+                font_size::LG,
+            );
+            for (row, entry) in section.entries.iter().enumerate() {
+                let y = sections_top + header_height + row as f32 * Self::ENTRY_HEIGHT;
+                cx.draw_text(
+                    entry.label,
+                    Point::new(x, y + font_size::MD),
+                    colors::TEXT_PRIMARY,
+                    font_size::MD,
+                );
+                cx.draw_text(
+                    &entry.keys,
+                    Point::new(x, y + font_size::MD + font_size::SM + spacing::XS),
+                    colors::TEXT_SECONDARY,
+                    font_size::SM,
+                );
+            }
+        }
+
+        cx.draw_text(
+            Self::CLOSE_HINT,
+            Point::new(left, panel.origin.y + panel.size.height - spacing::XL),
+            colors::TEXT_MUTED,
+            font_size::SM,
+        );
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, _bounds: Bounds) -> bool {
+        if !self.visible {
+            return false;
+        }
+        let close = Self::is_toggle_event(event)
+            || matches!(
+                event,
+                InputEvent::KeyDown {
+                    key: KeyCode::Escape,
+                    ..
+                } | InputEvent::MouseDown { .. }
+            );
+        if close {
+            self.hide();
+        }
+        // Modal: nothing reaches the screen underneath
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::config::Binding;
+    use narrative_gui::framework::input::Modifiers;
+
+    fn key_down(key: KeyCode) -> InputEvent {
+        InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        }
+    }
+
+    #[test]
+    fn test_help_sections_follow_bindings() {
+        let mut bindings = Bindings::default();
+        let sections = help_sections(&bindings);
+        let titles: Vec<&str> = sections.iter().map(|section| section.title).collect();
+        assert_eq!(titles, ["Reading", "Choices", "Menus"]);
+        assert_eq!(
+            sections[0].entries[0],
+            HelpEntry::new("Advance", "Enter, Space, Left Click, Pad South")
+        );
+
+        // Remapped keys show up
+        bindings.rebind(InputAction::Skip, Binding::key("K"));
+        let sections = help_sections(&bindings);
+        let skip = sections[0]
+            .entries
+            .iter()
+            .find(|entry| entry.label == "Skip")
+            .unwrap();
+        assert_eq!(skip.keys, "Pad North, K");
+    }
+
+    #[test]
+    fn test_help_overlay_is_modal() {
+        let mut help = HelpOverlayElement::new();
+        let bounds = Bounds::default();
+        assert!(!help.handle_event(&key_down(KeyCode::Space), bounds));
+
+        help.show(&Bindings::default());
+        assert!(help.is_visible());
+        assert_eq!(help.sections().len(), 3);
+
+        // Other input is swallowed without closing
+        assert!(help.handle_event(&key_down(KeyCode::Space), bounds));
+        assert!(help.is_visible());
+
+        assert!(help.handle_event(&InputEvent::CharInput { character: '?' }, bounds));
+        assert!(!help.is_visible());
+        assert!(help.sections().is_empty());
+
+        help.toggle(&Bindings::default());
+        assert!(help.handle_event(&key_down(KeyCode::Escape), bounds));
+        assert!(!help.is_visible());
+    }
+}
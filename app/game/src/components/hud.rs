@@ -0,0 +1,431 @@
+//! HUD widgets
+//!
+//! Shows flags and variables from the HUD manifest over the scene as bars,
+//! numbers and icon rows (affection meters, a day counter, collected keys).
+//! The game root syncs the widgets with the scenario runtime every frame, so
+//! they follow the executor's changes; a widget only asks for a repaint when
+//! its value or visibility changes, and bars ease to their new value.
+
+use narrative_core::{HudManifest, HudSource, HudWidgetDef, HudWidgetKind, VariableValue};
+use narrative_engine::runtime::ScenarioRuntime;
+use narrative_gui::framework::animation::AnimationContext;
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::InputEvent;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, common, font_size, radius, spacing};
+use narrative_gui::{Color, Point, Size};
+use std::any::Any;
+use std::collections::HashMap;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// A widget with the value it shows
+#[derive(Debug, Clone)]
+struct HudWidget {
+    /// Widget definition from the manifest
+    def: HudWidgetDef,
+    /// Current value of its flag or variable
+    value: f64,
+    /// Value drawn, easing toward `value` for bars
+    shown_value: f64,
+    /// Whether the widget is shown
+    visible: bool,
+}
+
+/// HUD overlay element
+pub struct HudElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Widgets, in drawing order
+    widgets: Vec<HudWidget>,
+    /// Icon paths mapped to loaded texture IDs
+    icon_textures: HashMap<String, u64>,
+    /// Animation context for global settings
+    animation_context: AnimationContext,
+}
+
+impl HudElement {
+    /// Bar size
+    const BAR_SIZE: Size = Size::new(160.0, 12.0);
+    /// Icon size in icon rows
+    const ICON_SIZE: f32 = 24.0;
+    /// Gap between icons
+    const ICON_GAP: f32 = 4.0;
+    /// Seconds a bar takes to fill from empty to full
+    const BAR_FILL_SECS: f64 = 0.5;
+
+    /// Create the HUD for a manifest's widgets, all at 0
+    pub fn new(manifest: &HudManifest) -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            widgets: manifest
+                .widgets
+                .iter()
+                .map(|def| HudWidget {
+                    def: def.clone(),
+                    value: 0.0,
+                    shown_value: 0.0,
+                    visible: false,
+                })
+                .collect(),
+            icon_textures: HashMap::new(),
+            animation_context: AnimationContext::default(),
+        }
+    }
+
+    /// Set the animation context
+    pub fn set_animation_context(&mut self, context: AnimationContext) {
+        self.animation_context = context;
+    }
+
+    /// Check whether the manifest had no widgets
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+
+    /// Icon paths of the icon rows, to be loaded as textures
+    pub fn icon_paths(&self) -> impl Iterator<Item = &str> {
+        self.widgets
+            .iter()
+            .filter_map(|widget| match &widget.def.kind {
+                HudWidgetKind::IconRow { icon, .. } => Some(icon.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Set the loaded icon textures, by icon path
+    pub fn set_icon_textures(&mut self, textures: HashMap<String, u64>) {
+        self.icon_textures = textures;
+    }
+
+    /// Take each widget's value and visibility from the runtime
+    ///
+    /// Returns: true if any widget changed
+    pub fn sync(&mut self, runtime: &ScenarioRuntime) -> bool {
+        let animate = self.animation_context.should_animate(None);
+        let mut changed = false;
+        for widget in &mut self.widgets {
+            let visible = runtime.hud_visibility().is_visible(&widget.def);
+            let value = source_value(&widget.def.source, runtime);
+            if visible != widget.visible || value != widget.value {
+                changed = true;
+            }
+            // A widget that appears starts at its value instead of easing to it
+            if !widget.visible || !animate || !matches!(widget.def.kind, HudWidgetKind::Bar { .. })
+            {
+                widget.shown_value = value;
+            }
+            widget.visible = visible;
+            widget.value = value;
+        }
+        changed
+    }
+
+    /// Visible widget definitions with the values drawn
+    pub fn visible_widgets(&self) -> impl Iterator<Item = (&HudWidgetDef, f64)> {
+        self.widgets
+            .iter()
+            .filter(|widget| widget.visible)
+            .map(|widget| (&widget.def, widget.shown_value))
+    }
+
+    /// Ease bars toward their values; returns true while any is moving
+    fn advance(&mut self, delta: f32) -> bool {
+        let mut moving = false;
+        for widget in &mut self.widgets {
+            let HudWidgetKind::Bar { max } = widget.def.kind else {
+                continue;
+            };
+            let step = max * f64::from(delta) / Self::BAR_FILL_SECS;
+            let remaining = widget.value - widget.shown_value;
+            if remaining.abs() <= step {
+                widget.shown_value = widget.value;
+            } else {
+                widget.shown_value += step.copysign(remaining);
+                moving = true;
+            }
+        }
+        moving
+    }
+
+    /// Fill color of a widget
+    fn widget_color(def: &HudWidgetDef) -> Color {
+        def.color.map_or(colors::ACCENT_PRIMARY, |[r, g, b, a]| {
+            Color::new(r, g, b, a)
+        })
+    }
+
+    /// Draw a label, returning the x position after it
+    fn paint_label(cx: &mut PaintContext, label: &str, origin: Point, height: f32) -> f32 {
+        cx.draw_text(
+            label,
+            Point::new(origin.x, origin.y + (height + font_size::MD * 0.7) / 2.0),
+            colors::TEXT_PRIMARY,
+            font_size::MD,
+        );
+        origin.x
+            + label.chars().count() as f32 * font_size::MD * common::CHAR_WIDTH_RATIO
+            + spacing::SM
+    }
+
+    fn paint_widget(&self, cx: &mut PaintContext, def: &HudWidgetDef, value: f64) {
+        let origin = Point::new(
+            cx.bounds.origin.x + def.position.0,
+            cx.bounds.origin.y + def.position.1,
+        );
+        let height = match def.kind {
+            HudWidgetKind::IconRow { .. } => Self::ICON_SIZE,
+            _ => font_size::MD,
+        };
+        let x = match &def.label {
+            Some(label) => Self::paint_label(cx, label, origin, height),
+            None => origin.x,
+        };
+        let color = Self::widget_color(def);
+
+        match &def.kind {
+            HudWidgetKind::Bar { max } => {
+                let track = Bounds {
+                    origin: Point::new(x, origin.y + (height - Self::BAR_SIZE.height) / 2.0),
+                    size: Self::BAR_SIZE,
+                };
+                let mut background = colors::BG_PANEL;
+                background.a = 0.8;
+                cx.fill_rounded_rect(track, background, radius::SM);
+                let ratio = (value / max).clamp(0.0, 1.0) as f32;
+                if ratio > 0.0 {
+                    let fill = Bounds {
+                        origin: track.origin,
+                        size: Size::new(track.size.width * ratio, track.size.height),
+                    };
+                    cx.fill_rounded_rect(fill, color, radius::SM);
+                }
+                cx.stroke_rect(track, colors::BORDER_LIGHT, common::BORDER_THICKNESS);
+            }
+            HudWidgetKind::Number => {
+                cx.draw_text(
+                    &format_value(value),
+                    Point::new(x, origin.y + (height + font_size::MD * 0.7) / 2.0),
+                    color,
+                    font_size::MD,
+                );
+            }
+            HudWidgetKind::IconRow { icon, max } => {
+                let Some(&texture_id) = self.icon_textures.get(icon) else {
+                    return;
+                };
+                let count = value.floor().clamp(0.0, f64::from(*max)) as u32;
+                for i in 0..count {
+                    let icon_bounds = Bounds {
+                        origin: Point::new(
+                            x + i as f32 * (Self::ICON_SIZE + Self::ICON_GAP),
+                            origin.y,
+                        ),
+                        size: Size::new(Self::ICON_SIZE, Self::ICON_SIZE),
+                    };
+                    cx.draw_texture(texture_id, icon_bounds, 1.0);
+                }
+            }
+        }
+    }
+}
+
+/// Numeric value of a flag or variable
+///
+/// Flags and bools count as 0 or 1; missing variables and strings as 0.
+fn source_value(source: &HudSource, runtime: &ScenarioRuntime) -> f64 {
+    match source {
+        HudSource::Flag(flag) => {
+            if runtime.flags().is_set(&flag.as_str().into()) {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        HudSource::Variable(variable) => match runtime.variables().get(&variable.as_str().into()) {
+            Some(VariableValue::Int(value)) => *value as f64,
+            Some(VariableValue::Float(value)) => *value,
+            Some(VariableValue::Bool(value)) => f64::from(u8::from(*value)),
+            Some(VariableValue::String(_)) | None => 0.0,
+        },
+    }
+}
+
+/// Format a number widget's value, without decimals for whole numbers
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+impl Default for HudElement {
+    fn default() -> Self {
+        Self::new(&HudManifest::default())
+    }
+}
+
+impl Element for HudElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Overlay the whole screen
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        for (def, value) in self.visible_widgets() {
+            self.paint_widget(cx, def, value);
+        }
+    }
+
+    fn handle_event(&mut self, _event: &InputEvent, _bounds: Bounds) -> bool {
+        false
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.advance(delta.as_secs_f32())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{Scenario, ScenarioCommand, ScenarioMetadata, Scene};
+
+    fn manifest() -> HudManifest {
+        HudManifest::new()
+            .add_widget(HudWidgetDef::new(
+                "affection",
+                HudSource::Variable("affection".to_string()),
+                HudWidgetKind::Bar { max: 100.0 },
+                (24.0, 24.0),
+            ))
+            .add_widget(
+                HudWidgetDef::new(
+                    "key",
+                    HudSource::Flag("has_key".to_string()),
+                    HudWidgetKind::Number,
+                    (24.0, 64.0),
+                )
+                .hidden(),
+            )
+    }
+
+    fn runtime(commands: Vec<ScenarioCommand>) -> ScenarioRuntime {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+        let mut scene = Scene::new("start", "Start");
+        for command in commands {
+            scene.add_command(command);
+        }
+        scene.add_command(ScenarioCommand::End);
+        scenario.add_scene("start", scene);
+        let mut runtime = ScenarioRuntime::new(scenario);
+        runtime.start().unwrap();
+        runtime
+    }
+
+    fn visible(hud: &HudElement) -> Vec<(&str, f64)> {
+        hud.visible_widgets()
+            .map(|(def, value)| (def.id.as_str(), value))
+            .collect()
+    }
+
+    #[test]
+    fn test_widgets_follow_runtime() {
+        let mut runtime = runtime(vec![
+            ScenarioCommand::SetVariable {
+                variable_name: "affection".to_string(),
+                value: VariableValue::Int(50),
+            },
+            ScenarioCommand::SetFlag {
+                flag_name: "has_key".to_string(),
+                value: true,
+            },
+            ScenarioCommand::SetHudVisible {
+                visible: true,
+                widgets: vec!["key".to_string()],
+            },
+        ]);
+        let mut hud = HudElement::new(&manifest());
+        assert!(hud.sync(&runtime));
+        assert_eq!(visible(&hud), [("affection", 0.0)]);
+        assert!(!hud.sync(&runtime));
+
+        for _ in 0..3 {
+            runtime.execute_current_command().unwrap();
+            runtime.advance_command();
+        }
+        assert!(hud.sync(&runtime));
+        assert_eq!(visible(&hud), [("affection", 0.0), ("key", 1.0)]);
+
+        // The bar eases to its new value
+        assert!(hud.advance(0.125));
+        assert_eq!(visible(&hud)[0], ("affection", 25.0));
+        assert!(!hud.advance(0.125));
+        assert_eq!(visible(&hud)[0], ("affection", 50.0));
+    }
+
+    #[test]
+    fn test_hidden_hud_and_instant_bars() {
+        let mut runtime = runtime(vec![
+            ScenarioCommand::SetVariable {
+                variable_name: "affection".to_string(),
+                value: VariableValue::Int(30),
+            },
+            ScenarioCommand::SetHudVisible {
+                visible: false,
+                widgets: Vec::new(),
+            },
+        ]);
+        let mut hud = HudElement::new(&manifest());
+        hud.set_animation_context(AnimationContext::disabled());
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+        hud.sync(&runtime);
+        assert_eq!(visible(&hud), [("affection", 30.0)]);
+
+        runtime.execute_current_command().unwrap();
+        runtime.advance_command();
+        assert!(hud.sync(&runtime));
+        assert!(visible(&hud).is_empty());
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(3.0), "3");
+        assert_eq!(format_value(-2.0), "-2");
+        assert_eq!(format_value(2.5), "2.5");
+    }
+}
@@ -0,0 +1,159 @@
+//! Loading screen with a progress bar
+//!
+//! Shown while the assets a game state needs are decoded in the background,
+//! so starting or loading a game does not freeze on a blank frame. Colors
+//! come from the UI theme.
+
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::InputEvent;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, common, font_size, radius, spacing};
+use narrative_gui::{Point, Size};
+use std::any::Any;
+use taffy::NodeId;
+
+/// Loading screen element
+pub struct LoadingScreenElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Loading progress (0.0 to 1.0)
+    progress: f32,
+    /// Asset being loaded, shown under the bar
+    task: String,
+}
+
+impl LoadingScreenElement {
+    /// Progress bar width relative to the screen width
+    const BAR_WIDTH_RATIO: f32 = 0.4;
+    /// Progress bar height
+    const BAR_HEIGHT: f32 = 8.0;
+
+    /// Create a loading screen at no progress
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            progress: 0.0,
+            task: String::new(),
+        }
+    }
+
+    /// Update the progress and the asset being loaded
+    ///
+    /// Returns true if the shown progress changed.
+    pub fn set_progress(&mut self, progress: f32, task: &str) -> bool {
+        let progress = progress.clamp(0.0, 1.0);
+        let changed = progress != self.progress || task != self.task;
+        self.progress = progress;
+        if task != self.task {
+            self.task = task.to_string();
+        }
+        changed
+    }
+
+    /// Loading progress (0.0 to 1.0)
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+}
+
+impl Default for LoadingScreenElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for LoadingScreenElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Cover the whole window
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        cx.fill_rect(cx.bounds, colors::BG_DARKEST);
+
+        let bar_width = cx.bounds.size.width * Self::BAR_WIDTH_RATIO;
+        let bar_origin = Point::new(
+            cx.bounds.origin.x + (cx.bounds.size.width - bar_width) / 2.0,
+            cx.bounds.origin.y + cx.bounds.size.height * 0.75,
+        );
+
+        let label = "Loading...";
+        let label_width = label.chars().count() as f32 * font_size::LG * common::CHAR_WIDTH_RATIO;
+        cx.draw_text(
+            label,
+            Point::new(
+                cx.bounds.origin.x + (cx.bounds.size.width - label_width) / 2.0,
+                bar_origin.y - spacing::MD,
+            ),
+            colors::TEXT_PRIMARY,
+            font_size::LG,
+        );
+
+        cx.fill_rounded_rect(
+            Bounds {
+                origin: bar_origin,
+                size: Size::new(bar_width, Self::BAR_HEIGHT),
+            },
+            colors::BG_ELEVATED,
+            radius::SM,
+        );
+        if self.progress > 0.0 {
+            cx.fill_rounded_rect(
+                Bounds {
+                    origin: bar_origin,
+                    size: Size::new(bar_width * self.progress, Self::BAR_HEIGHT),
+                },
+                colors::ACCENT_PRIMARY,
+                radius::SM,
+            );
+        }
+
+        if !self.task.is_empty() {
+            cx.draw_text(
+                &self.task,
+                Point::new(
+                    bar_origin.x,
+                    bar_origin.y + Self::BAR_HEIGHT + spacing::MD + font_size::SM,
+                ),
+                colors::TEXT_SECONDARY,
+                font_size::SM,
+            );
+        }
+    }
+
+    fn handle_event(&mut self, _event: &InputEvent, _bounds: Bounds) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -4,6 +4,7 @@
 //! All components implement the Element trait from narrative-gui.
 
 // Core game UI components
+pub mod achievement_list;
 pub mod backlog;
 pub mod cg_gallery;
 pub mod cg_viewer;
@@ -12,27 +13,44 @@ pub mod character_sprite;
 pub mod character_transition;
 pub mod choice_menu;
 pub mod confirm_dialog;
+pub mod dev_console;
 pub mod dialogue_box;
+pub mod ending_screen;
 pub mod game_root;
+pub mod help_overlay;
+pub mod hud;
+pub mod loading_screen;
+pub mod name_input;
 pub mod pause_menu;
 pub mod quick_menu;
 pub mod save_load_menu;
 pub mod save_slot_card;
 pub mod settings_menu;
+pub mod subtitle;
 pub mod title_screen;
+pub mod toast_manager;
 
 // Re-exports
+pub use achievement_list::{AchievementListAction, AchievementListElement};
 pub use backlog::BacklogElement;
 pub use cg_gallery::{CgGalleryAction, CgGalleryElement};
 pub use cg_viewer::{CgViewerAction, CgViewerElement};
 pub use character_sprite::CharacterSpriteElement;
-pub use choice_menu::ChoiceMenuElement;
+pub use choice_menu::{ChoiceMenuElement, ChoiceMenuStyle};
 pub use confirm_dialog::{ConfirmDialogElement, DialogResponse};
+pub use dev_console::{DevConsoleAction, DevConsoleElement, DevConsoleTab};
 pub use dialogue_box::DialogueBoxElement;
+pub use ending_screen::EndingScreenElement;
 pub use game_root::GameRootElement;
+pub use help_overlay::{HelpEntry, HelpOverlayElement, HelpSection};
+pub use hud::HudElement;
+pub use loading_screen::LoadingScreenElement;
+pub use name_input::NameInputElement;
 pub use pause_menu::{PauseMenuAction, PauseMenuElement};
-pub use quick_menu::{QuickMenuAction, QuickMenuElement};
+pub use quick_menu::{QuickMenuAction, QuickMenuElement, QuickMenuHandler};
 pub use save_load_menu::{SaveLoadMenuAction, SaveLoadMenuElement};
 pub use save_slot_card::SaveSlotCard;
 pub use settings_menu::SettingsMenuElement;
+pub use subtitle::{SubtitleElement, SubtitleStyle};
 pub use title_screen::{TitleScreenAction, TitleScreenElement};
+pub use toast_manager::{ToastManagerElement, ToastMessage, ToastQueue};
@@ -0,0 +1,333 @@
+//! Name entry UI component
+//!
+//! This component displays a modal panel for the `InputName` command with:
+//! - Prompt text
+//! - Text field prefilled (and selected) with the default name
+//! - OK button
+//!
+//! Enter or the OK button confirms the name; IME composition is supported
+//! through the text field.
+
+use narrative_engine::runtime::NameInputState;
+use narrative_gui::Point;
+use narrative_gui::components::common::TextInput;
+use narrative_gui::framework::element::{
+    Element, ElementId, LayoutContext, PaintContext, WindowOperation,
+};
+use narrative_gui::framework::input::InputEvent;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::colors;
+use std::any::Any;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Name entry element
+pub struct NameInputElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Prompt shown above the field
+    prompt: String,
+    /// Text field
+    input: TextInput,
+    /// Name confirmed by the player, not yet taken
+    confirmed: Option<String>,
+    /// Whether the OK button is hovered
+    button_hovered: bool,
+    /// Dirty flag to track if rendering needs update
+    dirty: bool,
+}
+
+impl NameInputElement {
+    /// Panel width
+    const PANEL_WIDTH: f32 = 560.0;
+    /// Panel height
+    const PANEL_HEIGHT: f32 = 260.0;
+    /// Padding inside the panel
+    const PANEL_PADDING: f32 = 40.0;
+    /// Text field height
+    const FIELD_HEIGHT: f32 = 48.0;
+    /// Text field font size
+    const FIELD_FONT_SIZE: f32 = 24.0;
+    /// Button width
+    const BUTTON_WIDTH: f32 = 150.0;
+    /// Button height
+    const BUTTON_HEIGHT: f32 = 50.0;
+    /// Corner radius of the panel and button
+    const CORNER_RADIUS: f32 = 8.0;
+    /// Prompt font size
+    const PROMPT_FONT_SIZE: f32 = 20.0;
+    /// Button font size
+    const BUTTON_FONT_SIZE: f32 = 18.0;
+    /// Background overlay alpha
+    const OVERLAY_ALPHA: f32 = 0.6;
+    /// Prompt used when the command has none
+    const DEFAULT_PROMPT: &'static str = "Enter your name";
+
+    /// Create the name entry for an `InputName` command
+    pub fn new(state: &NameInputState) -> Self {
+        let mut input = TextInput::new()
+            .with_text(state.default.clone())
+            .with_placeholder(state.default.clone())
+            .with_max_chars(state.max_length)
+            .with_font_size(Self::FIELD_FONT_SIZE)
+            .with_focus();
+        // Typing replaces the default name
+        input.select_all();
+
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            prompt: state
+                .prompt
+                .clone()
+                .unwrap_or_else(|| Self::DEFAULT_PROMPT.to_string()),
+            input,
+            confirmed: None,
+            button_hovered: false,
+            dirty: true,
+        }
+    }
+
+    /// Text currently in the field
+    pub fn text(&self) -> &str {
+        self.input.text()
+    }
+
+    /// Take the name confirmed by the player, if any
+    pub fn take_confirmed(&mut self) -> Option<String> {
+        self.confirmed.take()
+    }
+
+    /// Confirm the entered name and stop taking text input
+    fn confirm(&mut self) {
+        self.confirmed = Some(self.input.text().to_string());
+        self.input.set_focused(false);
+        self.dirty = true;
+    }
+
+    fn panel_bounds(container: Bounds) -> Bounds {
+        Bounds::new(
+            container.x() + (container.width() - Self::PANEL_WIDTH) / 2.0,
+            container.y() + (container.height() - Self::PANEL_HEIGHT) / 2.0,
+            Self::PANEL_WIDTH,
+            Self::PANEL_HEIGHT,
+        )
+    }
+
+    fn field_bounds(container: Bounds) -> Bounds {
+        let panel = Self::panel_bounds(container);
+        Bounds::new(
+            panel.x() + Self::PANEL_PADDING,
+            panel.y() + Self::PANEL_PADDING + Self::PROMPT_FONT_SIZE * 2.0,
+            Self::PANEL_WIDTH - Self::PANEL_PADDING * 2.0,
+            Self::FIELD_HEIGHT,
+        )
+    }
+
+    fn button_bounds(container: Bounds) -> Bounds {
+        let panel = Self::panel_bounds(container);
+        Bounds::new(
+            panel.x() + (Self::PANEL_WIDTH - Self::BUTTON_WIDTH) / 2.0,
+            panel.y() + Self::PANEL_HEIGHT - Self::PANEL_PADDING - Self::BUTTON_HEIGHT / 2.0,
+            Self::BUTTON_WIDTH,
+            Self::BUTTON_HEIGHT,
+        )
+    }
+}
+
+impl Element for NameInputElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Take up full available space (for overlay)
+        taffy::Style {
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let container = cx.bounds;
+        let overlay_color = narrative_gui::Color::new(0.0, 0.0, 0.0, Self::OVERLAY_ALPHA);
+        cx.fill_rect(container, overlay_color);
+
+        let panel = Self::panel_bounds(container);
+        cx.fill_rounded_rect(panel, colors::CARD_BG, Self::CORNER_RADIUS);
+        cx.stroke_rect(panel, colors::BORDER_LIGHT, 2.0);
+
+        cx.draw_text(
+            &self.prompt,
+            Point::new(
+                panel.x() + Self::PANEL_PADDING,
+                panel.y() + Self::PANEL_PADDING + Self::PROMPT_FONT_SIZE,
+            ),
+            colors::TEXT_PRIMARY,
+            Self::PROMPT_FONT_SIZE,
+        );
+
+        let mut field_cx = PaintContext {
+            bounds: Self::field_bounds(container),
+            clip_bounds: Some(panel),
+            commands: &mut *cx.commands,
+        };
+        self.input.paint(&mut field_cx);
+
+        let button = Self::button_bounds(container);
+        let bg_color = if self.button_hovered {
+            colors::ACCENT_PRIMARY
+        } else {
+            colors::BUTTON_PRIMARY
+        };
+        cx.fill_rounded_rect(button, bg_color, Self::CORNER_RADIUS);
+        let label = "OK";
+        let text_width = label.len() as f32 * Self::BUTTON_FONT_SIZE * 0.6;
+        cx.draw_text(
+            label,
+            Point::new(
+                button.x() + (Self::BUTTON_WIDTH - text_width) / 2.0,
+                button.y() + (Self::BUTTON_HEIGHT + Self::BUTTON_FONT_SIZE * 0.8) / 2.0,
+            ),
+            colors::BG_DARKEST,
+            Self::BUTTON_FONT_SIZE,
+        );
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        if self.confirmed.is_some() {
+            return false;
+        }
+
+        let button = Self::button_bounds(bounds);
+        match event {
+            InputEvent::MouseMove { position, .. } => {
+                let hovered = button.contains(*position);
+                if hovered != self.button_hovered {
+                    self.button_hovered = hovered;
+                    self.dirty = true;
+                }
+                false
+            }
+            InputEvent::MouseDown { position, .. } if button.contains(*position) => {
+                self.confirm();
+                true
+            }
+            // The field is the only input, so it keeps focus when clicking elsewhere
+            InputEvent::MouseDown { position, .. }
+                if !Self::field_bounds(bounds).contains(*position) =>
+            {
+                false
+            }
+            _ => {
+                let handled = self.input.handle_event(event, Self::field_bounds(bounds));
+                if self.input.take_submitted() {
+                    self.confirm();
+                }
+                self.dirty |= handled;
+                handled
+            }
+        }
+    }
+
+    fn take_window_operations(&mut self) -> Vec<WindowOperation> {
+        self.input.take_window_operations()
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        let caret_changed = self.input.tick(delta);
+        let was_dirty = self.dirty;
+        self.dirty = false;
+        was_dirty || caret_changed
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::ScenarioCommand;
+    use narrative_gui::framework::input::{KeyCode, Modifiers};
+
+    fn state() -> NameInputState {
+        NameInputState::from_command(&ScenarioCommand::InputName {
+            variable: "player_name".to_string(),
+            prompt: None,
+            default: "Alex".to_string(),
+            max_length: 6,
+        })
+        .unwrap()
+    }
+
+    fn bounds() -> Bounds {
+        Bounds::new(0.0, 0.0, 1280.0, 720.0)
+    }
+
+    fn key(element: &mut NameInputElement, key: KeyCode) -> bool {
+        element.handle_event(
+            &InputEvent::KeyDown {
+                key,
+                modifiers: Modifiers::default(),
+            },
+            bounds(),
+        )
+    }
+
+    #[test]
+    fn test_typing_replaces_default_name() {
+        let mut element = NameInputElement::new(&state());
+        assert_eq!(element.text(), "Alex");
+        assert_eq!(element.prompt, NameInputElement::DEFAULT_PROMPT);
+
+        for character in "Sakura".chars() {
+            element.handle_event(&InputEvent::CharInput { character }, bounds());
+        }
+        assert_eq!(element.text(), "Sakura");
+        assert_eq!(element.take_confirmed(), None);
+
+        assert!(key(&mut element, KeyCode::Enter));
+        assert_eq!(element.take_confirmed(), Some("Sakura".to_string()));
+        assert_eq!(element.take_confirmed(), None);
+    }
+
+    #[test]
+    fn test_escape_is_not_consumed() {
+        let mut element = NameInputElement::new(&state());
+        assert!(!key(&mut element, KeyCode::Escape));
+    }
+
+    #[test]
+    fn test_confirm_turns_ime_off() {
+        let mut element = NameInputElement::new(&state());
+        assert_eq!(
+            element.take_window_operations().first(),
+            Some(&WindowOperation::SetImeAllowed(true))
+        );
+
+        key(&mut element, KeyCode::Enter);
+        let operations = element.take_window_operations();
+        assert!(operations.contains(&WindowOperation::SetImeAllowed(false)));
+    }
+}
@@ -4,18 +4,29 @@
 //! - Resume
 //! - Save
 //! - Load
+//! - Last Choice (when enabled with `with_return_to_choice`)
 //! - Settings
 //! - Return to Title
 //!
 //! Supports arrow key navigation and Enter/Space for confirmation.
-
+//!
+//! When quick settings are enabled, a compact panel next to the buttons holds
+//! volume, text speed and textbox sliders that apply immediately. A preview of
+//! the textbox is drawn behind the menu while the panel is shown. The changed
+//! `UserSettings` are handed to the parent, which persists them when the menu closes.
+
+use narrative_core::TextSpeed;
+use narrative_core::config::{DialogueBoxConfig, UserSettings};
+use narrative_engine::AudioQueue;
 use narrative_gui::Point;
+use narrative_gui::components::common::Slider;
 use narrative_gui::framework::animation::AnimationContext;
 use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
 use narrative_gui::framework::input::{InputEvent, KeyCode};
 use narrative_gui::framework::layout::Bounds;
-use narrative_gui::theme::colors;
+use narrative_gui::theme::{colors, font_size};
 use std::any::Any;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use taffy::NodeId;
 
@@ -28,6 +39,8 @@ pub enum PauseMenuAction {
     Save,
     /// Load saved game
     Load,
+    /// Rewind to the most recent choice
+    ReturnToChoice,
     /// Open settings
     Settings,
     /// Return to title screen
@@ -45,6 +58,12 @@ struct MenuItem {
     enabled: bool,
 }
 
+/// Settings edited through the quick settings panel
+struct QuickSettingsState {
+    settings: UserSettings,
+    changed: bool,
+}
+
 /// Pause menu element that displays the in-game menu
 pub struct PauseMenuElement {
     /// Unique element ID
@@ -65,6 +84,10 @@ pub struct PauseMenuElement {
     animation_context: AnimationContext,
     /// Component-specific animation override (None = follow global)
     animations_enabled: Option<bool>,
+    /// Quick settings state (None = panel hidden)
+    quick_settings: Option<Arc<Mutex<QuickSettingsState>>>,
+    /// Quick settings sliders, painted inside the panel
+    sliders: Vec<Slider>,
 }
 
 impl PauseMenuElement {
@@ -84,6 +107,18 @@ impl PauseMenuElement {
     const TITLE_OFFSET_Y: f32 = 80.0;
     /// Background overlay alpha
     const OVERLAY_ALPHA: f32 = 0.7;
+    /// Quick settings panel width
+    const PANEL_WIDTH: f32 = 420.0;
+    /// Gap between the buttons and the quick settings panel
+    const PANEL_GAP: f32 = 48.0;
+    /// Quick settings panel inner padding
+    const PANEL_PADDING: f32 = 24.0;
+    /// Height reserved for the quick settings header
+    const PANEL_HEADER_HEIGHT: f32 = 40.0;
+    /// Height of each quick settings slider
+    const SLIDER_HEIGHT: f32 = 48.0;
+    /// Spacing between quick settings sliders
+    const SLIDER_SPACING: f32 = 12.0;
 
     /// Create a new pause menu element
     pub fn new() -> Self {
@@ -131,9 +166,148 @@ impl PauseMenuElement {
             button_bounds,
             animation_context: AnimationContext::default(),
             animations_enabled: None,
+            quick_settings: None,
+            sliders: Vec::new(),
         }
     }
 
+    /// Show the quick settings panel
+    ///
+    /// Volume changes are queued on the audio manager right away; all changes
+    /// are collected into `settings` and returned by `take_settings_if_changed`.
+    pub fn with_quick_settings(mut self, settings: UserSettings, audio_queue: AudioQueue) -> Self {
+        let audio = settings.audio.clone();
+        let text = settings.text.clone();
+        let state = Arc::new(Mutex::new(QuickSettingsState {
+            settings,
+            changed: false,
+        }));
+
+        let volume_slider = |label: &str,
+                             value: f32,
+                             apply: fn(&AudioQueue, f32),
+                             store: fn(&mut UserSettings, f32)| {
+            let audio_queue = audio_queue.clone();
+            let state_arc = Arc::clone(&state);
+            Slider::new(label, 0.0, 1.0)
+                .with_value(value)
+                .with_step(0.05)
+                .with_on_change(move |value| {
+                    apply(&audio_queue, value);
+                    if let Ok(mut state) = state_arc.lock() {
+                        store(&mut state.settings, value);
+                        state.changed = true;
+                    }
+                })
+        };
+
+        let music_slider = volume_slider(
+            "Music Volume",
+            audio.bgm_volume,
+            |audio, value| audio.set_music_volume(value),
+            |settings, value| settings.audio.bgm_volume = value,
+        );
+        let sound_slider = volume_slider(
+            "Sound Effects Volume",
+            audio.se_volume,
+            |audio, value| audio.set_sound_volume(value),
+            |settings, value| settings.audio.se_volume = value,
+        );
+        let voice_slider = volume_slider(
+            "Voice Volume",
+            audio.voice_volume,
+            |audio, value| audio.set_voice_volume(value),
+            |settings, value| settings.audio.voice_volume = value,
+        );
+
+        let state_arc = Arc::clone(&state);
+        let text_slider = Slider::new("Text Speed (characters/second)", 1.0, 200.0)
+            .with_value(text.speed.chars_per_second())
+            .with_step(1.0)
+            .with_on_change(move |value| {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.settings.text.speed = TextSpeed::from_chars_per_second(value);
+                    state.changed = true;
+                }
+            });
+
+        let state_arc = Arc::clone(&state);
+        let auto_slider = Slider::new("Auto-Play Speed (seconds)", 0.5, 10.0)
+            .with_value(text.auto_wait)
+            .with_step(0.5)
+            .with_on_change(move |value| {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.settings.text.auto_wait = value;
+                    state.changed = true;
+                }
+            });
+
+        let textbox = state
+            .lock()
+            .map(|state| state.settings.textbox.clone())
+            .unwrap_or_default();
+
+        let state_arc = Arc::clone(&state);
+        let opacity_slider = Slider::new("Textbox Opacity", 0.0, 1.0)
+            .with_value(textbox.opacity)
+            .with_step(0.05)
+            .with_on_change(move |value| {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.settings.textbox.opacity = value;
+                    state.changed = true;
+                }
+            });
+
+        let state_arc = Arc::clone(&state);
+        let position_slider = Slider::new("Textbox Position (bottom to center)", 0.0, 1.0)
+            .with_value(textbox.vertical_position)
+            .with_step(0.05)
+            .with_on_change(move |value| {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.settings.textbox.vertical_position = value;
+                    state.changed = true;
+                }
+            });
+
+        self.sliders = vec![
+            music_slider,
+            sound_slider,
+            voice_slider,
+            text_slider,
+            auto_slider,
+            opacity_slider,
+            position_slider,
+        ]
+        .into_iter()
+        .map(|slider| slider.with_animation_context(self.animation_context))
+        .collect();
+        self.quick_settings = Some(state);
+        self
+    }
+
+    /// Add the "Last Choice" item, which rewinds to the most recent choice
+    ///
+    /// The item is only added when `available` is true.
+    pub fn with_return_to_choice(mut self, available: bool) -> Self {
+        if available {
+            let index = self
+                .menu_items
+                .iter()
+                .position(|item| item.action == PauseMenuAction::Load)
+                .map_or(self.menu_items.len(), |index| index + 1);
+            self.menu_items.insert(
+                index,
+                MenuItem {
+                    label: "Last Choice",
+                    action: PauseMenuAction::ReturnToChoice,
+                    enabled: true,
+                },
+            );
+            self.button_bounds = vec![Bounds::default(); self.menu_items.len()];
+        }
+        self
+    }
+
     /// Set the animation context
     pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
         self.animation_context = context;
@@ -156,6 +330,17 @@ impl PauseMenuElement {
         self.action_confirmed = None;
     }
 
+    /// Return the quick settings if they changed since the last call (also clears the changed flag)
+    pub fn take_settings_if_changed(&self) -> Option<UserSettings> {
+        let mut state = self.quick_settings.as_ref()?.lock().ok()?;
+        if state.changed {
+            state.changed = false;
+            Some(state.settings.clone())
+        } else {
+            None
+        }
+    }
+
     /// Move selection up
     fn select_previous(&mut self) {
         if self.selected_index > 0 {
@@ -180,23 +365,161 @@ impl PauseMenuElement {
         }
     }
 
+    /// Total height of the button column
+    fn buttons_height(&self) -> f32 {
+        (Self::BUTTON_HEIGHT * self.menu_items.len() as f32)
+            + (Self::BUTTON_SPACING * (self.menu_items.len().saturating_sub(1)) as f32)
+    }
+
+    /// Total height of the quick settings panel
+    fn panel_height(&self) -> f32 {
+        Self::PANEL_HEADER_HEIGHT
+            + (Self::SLIDER_HEIGHT * self.sliders.len() as f32)
+            + (Self::SLIDER_SPACING * (self.sliders.len().saturating_sub(1)) as f32)
+            + Self::PANEL_PADDING * 2.0
+    }
+
     /// Calculate button bounds for layout
-    fn calculate_button_bounds(&mut self, container_bounds: Bounds) {
-        let total_height = (Self::BUTTON_HEIGHT * self.menu_items.len() as f32)
-            + (Self::BUTTON_SPACING * (self.menu_items.len().saturating_sub(1)) as f32);
+    ///
+    /// The button column and the quick settings panel (if shown) are centered together.
+    fn compute_button_bounds(&self, container_bounds: Bounds) -> Vec<Bounds> {
+        let total_height = self.buttons_height();
+        let total_width = if self.quick_settings.is_some() {
+            Self::BUTTON_WIDTH + Self::PANEL_GAP + Self::PANEL_WIDTH
+        } else {
+            Self::BUTTON_WIDTH
+        };
 
         // Center vertically
         let start_y =
             container_bounds.origin.y + (container_bounds.size.height - total_height) / 2.0;
-        let start_x =
-            container_bounds.origin.x + (container_bounds.size.width - Self::BUTTON_WIDTH) / 2.0;
-
-        for i in 0..self.menu_items.len() {
-            let y = start_y + (i as f32 * (Self::BUTTON_HEIGHT + Self::BUTTON_SPACING));
-            self.button_bounds[i] = Bounds {
-                origin: Point::new(start_x, y),
-                size: narrative_gui::Size::new(Self::BUTTON_WIDTH, Self::BUTTON_HEIGHT),
+        let start_x = container_bounds.origin.x + (container_bounds.size.width - total_width) / 2.0;
+
+        (0..self.menu_items.len())
+            .map(|i| {
+                let y = start_y + (i as f32 * (Self::BUTTON_HEIGHT + Self::BUTTON_SPACING));
+                Bounds {
+                    origin: Point::new(start_x, y),
+                    size: narrative_gui::Size::new(Self::BUTTON_WIDTH, Self::BUTTON_HEIGHT),
+                }
+            })
+            .collect()
+    }
+
+    /// Calculate the quick settings panel bounds, if the panel is shown
+    fn panel_bounds(&self, container_bounds: Bounds) -> Option<Bounds> {
+        self.quick_settings.as_ref()?;
+
+        let total_width = Self::BUTTON_WIDTH + Self::PANEL_GAP + Self::PANEL_WIDTH;
+        let height = self.panel_height();
+        let x = container_bounds.origin.x
+            + (container_bounds.size.width - total_width) / 2.0
+            + Self::BUTTON_WIDTH
+            + Self::PANEL_GAP;
+        let y = container_bounds.origin.y + (container_bounds.size.height - height) / 2.0;
+
+        Some(Bounds::new(x, y, Self::PANEL_WIDTH, height))
+    }
+
+    /// Calculate slider bounds inside the quick settings panel
+    fn slider_bounds(&self, container_bounds: Bounds) -> Vec<Bounds> {
+        let Some(panel) = self.panel_bounds(container_bounds) else {
+            return Vec::new();
+        };
+
+        let x = panel.x() + Self::PANEL_PADDING;
+        let width = panel.width() - Self::PANEL_PADDING * 2.0;
+        let start_y = panel.y() + Self::PANEL_PADDING + Self::PANEL_HEADER_HEIGHT;
+
+        (0..self.sliders.len())
+            .map(|i| {
+                let y = start_y + (i as f32 * (Self::SLIDER_HEIGHT + Self::SLIDER_SPACING));
+                Bounds::new(x, y, width, Self::SLIDER_HEIGHT)
+            })
+            .collect()
+    }
+
+    /// Forward a mouse event to the quick settings sliders
+    fn handle_slider_event(&mut self, event: &InputEvent, container_bounds: Bounds) -> bool {
+        let slider_bounds = self.slider_bounds(container_bounds);
+        let mut handled = false;
+        for (slider, bounds) in self.sliders.iter_mut().zip(slider_bounds) {
+            // Every slider sees the event so hover and drag state stay consistent
+            if slider.handle_event(event, bounds) {
+                handled = true;
+            }
+        }
+        if handled {
+            self.dirty = true;
+        }
+        handled
+    }
+
+    /// Paint a preview of the textbox with the current quick settings
+    fn paint_textbox_preview(&self, cx: &mut PaintContext) {
+        let Some(state) = &self.quick_settings else {
+            return;
+        };
+        let Ok(state) = state.lock() else {
+            return;
+        };
+        let config = state
+            .settings
+            .textbox
+            .apply_to(DialogueBoxConfig::default());
+        drop(state);
+
+        let container = cx.bounds;
+        let bottom = config.bottom_offset(container.height());
+        let preview = Bounds::new(
+            container.x(),
+            container.y() + container.height() - bottom - config.height,
+            container.width(),
+            config.height,
+        );
+        let background = config.background_color_with_opacity();
+        cx.fill_rect(
+            preview,
+            narrative_gui::Color::new(background.r, background.g, background.b, background.a),
+        );
+        cx.stroke_rect(preview, colors::BORDER_LIGHT, 1.0);
+        cx.draw_text(
+            "Textbox preview",
+            Point::new(
+                preview.x() + config.padding,
+                preview.y() + config.padding + font_size::LG,
+            ),
+            colors::TEXT_SECONDARY,
+            font_size::LG,
+        );
+    }
+
+    /// Paint the quick settings panel and its sliders
+    fn paint_quick_settings(&self, cx: &mut PaintContext) {
+        let container = cx.bounds;
+        let Some(panel) = self.panel_bounds(container) else {
+            return;
+        };
+
+        cx.fill_rounded_rect(panel, colors::CARD_BG, Self::CORNER_RADIUS);
+        cx.stroke_rect(panel, colors::BORDER_LIGHT, 1.0);
+        cx.draw_text(
+            "Quick Settings",
+            Point::new(
+                panel.x() + Self::PANEL_PADDING,
+                panel.y() + Self::PANEL_PADDING + font_size::LG,
+            ),
+            colors::TEXT_PRIMARY,
+            font_size::LG,
+        );
+
+        for (slider, bounds) in self.sliders.iter().zip(self.slider_bounds(container)) {
+            let mut slider_cx = PaintContext {
+                bounds,
+                clip_bounds: Some(panel),
+                commands: &mut *cx.commands,
             };
+            slider.paint(&mut slider_cx);
         }
     }
 }
@@ -242,6 +565,9 @@ impl Element for PauseMenuElement {
         let overlay_color = narrative_gui::Color::new(0.0, 0.0, 0.0, Self::OVERLAY_ALPHA);
         cx.fill_rect(cx.bounds, overlay_color);
 
+        // Show where the textbox will be, below the buttons and the panel
+        self.paint_textbox_preview(cx);
+
         // Draw title
         let title = "Pause Menu";
         // Rough estimate for centering (TODO: use proper text measurement)
@@ -256,21 +582,9 @@ impl Element for PauseMenuElement {
             Self::TITLE_FONT_SIZE,
         );
 
-        // Calculate button layout
-        let total_height = (Self::BUTTON_HEIGHT * self.menu_items.len() as f32)
-            + (Self::BUTTON_SPACING * (self.menu_items.len().saturating_sub(1)) as f32);
-
-        let start_y = cx.bounds.origin.y + (cx.bounds.size.height - total_height) / 2.0;
-        let start_x = cx.bounds.origin.x + (cx.bounds.size.width - Self::BUTTON_WIDTH) / 2.0;
-
         // Draw each menu button
-        for (i, item) in self.menu_items.iter().enumerate() {
-            let y = start_y + (i as f32 * (Self::BUTTON_HEIGHT + Self::BUTTON_SPACING));
-            let button_bounds = Bounds {
-                origin: Point::new(start_x, y),
-                size: narrative_gui::Size::new(Self::BUTTON_WIDTH, Self::BUTTON_HEIGHT),
-            };
-
+        let button_bounds = self.compute_button_bounds(cx.bounds);
+        for (i, (item, button_bounds)) in self.menu_items.iter().zip(button_bounds).enumerate() {
             // Determine button appearance
             let is_selected = i == self.selected_index;
             let bg_color = if is_selected {
@@ -306,11 +620,13 @@ impl Element for PauseMenuElement {
                 Self::FONT_SIZE,
             );
         }
+
+        self.paint_quick_settings(cx);
     }
 
     fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
         // Update button bounds for click detection
-        self.calculate_button_bounds(bounds);
+        self.button_bounds = self.compute_button_bounds(bounds);
 
         match event {
             InputEvent::KeyDown { key, .. } => match key {
@@ -334,7 +650,14 @@ impl Element for PauseMenuElement {
                 }
                 _ => false,
             },
+            InputEvent::MouseMove { .. } | InputEvent::MouseUp { .. } => {
+                self.handle_slider_event(event, bounds)
+            }
             InputEvent::MouseDown { position, .. } => {
+                if self.handle_slider_event(event, bounds) {
+                    return true;
+                }
+
                 // Check if click is on any button
                 for (i, button_bound) in self.button_bounds.iter().enumerate() {
                     if button_bound.contains(*position) {
@@ -456,4 +779,118 @@ mod tests {
         assert!(menu.handle_event(&event, bounds));
         assert_eq!(menu.confirmed_action(), Some(PauseMenuAction::Resume));
     }
+
+    #[test]
+    fn test_return_to_choice_item() {
+        let menu = PauseMenuElement::new().with_return_to_choice(false);
+        assert_eq!(menu.menu_items.len(), 5);
+
+        let mut menu = PauseMenuElement::new().with_return_to_choice(true);
+        assert_eq!(menu.menu_items.len(), 6);
+        assert_eq!(menu.button_bounds.len(), 6);
+
+        // Placed after Load (Resume, Save, Load, Last Choice)
+        for _ in 0..3 {
+            menu.select_next();
+        }
+        menu.confirm_selection();
+        assert_eq!(
+            menu.confirmed_action(),
+            Some(PauseMenuAction::ReturnToChoice)
+        );
+    }
+
+    fn menu_with_quick_settings() -> PauseMenuElement {
+        let mut menu = PauseMenuElement::new();
+        menu.quick_settings = Some(Arc::new(Mutex::new(QuickSettingsState {
+            settings: UserSettings::default(),
+            changed: false,
+        })));
+        menu.sliders = (0..7)
+            .map(|i| Slider::new(format!("Slider {}", i), 0.0, 1.0))
+            .collect();
+        menu
+    }
+
+    #[test]
+    fn test_quick_settings_hidden_by_default() {
+        let menu = PauseMenuElement::new();
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+
+        assert!(menu.take_settings_if_changed().is_none());
+        assert!(menu.panel_bounds(bounds).is_none());
+        assert!(menu.slider_bounds(bounds).is_empty());
+
+        // Buttons stay centered without the panel
+        let buttons = menu.compute_button_bounds(bounds);
+        assert_eq!(buttons.len(), 5);
+        assert_eq!(
+            buttons[0].x(),
+            (1280.0 - PauseMenuElement::BUTTON_WIDTH) / 2.0
+        );
+    }
+
+    #[test]
+    fn test_quick_settings_layout() {
+        let menu = menu_with_quick_settings();
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+
+        let buttons = menu.compute_button_bounds(bounds);
+        let panel = menu.panel_bounds(bounds).unwrap();
+        let sliders = menu.slider_bounds(bounds);
+
+        // Panel sits to the right of the buttons, both within the container
+        assert!(panel.x() >= buttons[0].x() + buttons[0].width());
+        assert!(buttons[0].x() >= 0.0);
+        assert!(panel.x() + panel.width() <= 1280.0);
+        assert!(panel.y() >= 0.0);
+        assert!(panel.y() + panel.height() <= 720.0);
+
+        // Sliders fit inside the panel without overlapping
+        assert_eq!(sliders.len(), 7);
+        for pair in sliders.windows(2) {
+            assert!(pair[0].y() + pair[0].height() <= pair[1].y());
+        }
+        let last = sliders[6];
+        assert!(last.y() + last.height() <= panel.y() + panel.height());
+    }
+
+    #[test]
+    fn test_quick_settings_slider_click_does_not_confirm() {
+        use narrative_gui::framework::input::{Modifiers, MouseButton};
+
+        let mut menu = menu_with_quick_settings();
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        let slider = menu.slider_bounds(bounds)[0];
+        let position = Point::new(
+            slider.x() + slider.width() * 0.75,
+            slider.y() + slider.height() - 10.0,
+        );
+
+        let event = InputEvent::MouseDown {
+            button: MouseButton::Left,
+            position,
+            modifiers: Modifiers::none(),
+        };
+        assert!(menu.handle_event(&event, bounds));
+        assert!(menu.confirmed_action().is_none());
+        assert!(menu.sliders[0].value() > 0.5);
+    }
+
+    #[test]
+    fn test_take_settings_if_changed() {
+        let menu = menu_with_quick_settings();
+        assert!(menu.take_settings_if_changed().is_none());
+
+        if let Some(state) = &menu.quick_settings {
+            let mut state = state.lock().unwrap();
+            state.settings.text.auto_wait = 4.0;
+            state.changed = true;
+        }
+
+        let settings = menu.take_settings_if_changed().unwrap();
+        assert_eq!(settings.text.auto_wait, 4.0);
+        // The changed flag is cleared
+        assert!(menu.take_settings_if_changed().is_none());
+    }
 }
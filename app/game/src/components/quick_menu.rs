@@ -1,14 +1,24 @@
 //! Quick menu UI component
 //!
-//! This component displays a horizontal quick access menu near the dialogue box with buttons for:
+//! This component displays a quick access menu near the dialogue box. Without a
+//! UI theme it shows a horizontal row of buttons for:
 //! - Skip mode toggle
 //! - Auto mode toggle
 //! - Backlog viewer
 //! - Quick save
 //! - Pause menu
 //!
-//! Buttons show their active/inactive state with visual feedback.
+//! A UI theme's `quick_menu` picks the buttons, their order, labels and icons,
+//! and lays them out as a row or a column. Entries that aren't built-in actions
+//! are passed to the game's [`QuickMenuHandler`].
+//!
+//! Buttons show their active/inactive state with visual feedback, and a button
+//! can be flashed briefly to confirm an action done from the keyboard (e.g. a
+//! quick save).
 
+use narrative_core::config::DialogueBoxConfig;
+use narrative_core::{QuickMenuOrientation, QuickMenuTheme};
+use narrative_engine::runtime::ScenarioRuntime;
 use narrative_gui::framework::animation::AnimationContext;
 use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
 use narrative_gui::framework::input::InputEvent;
@@ -16,32 +26,87 @@ use narrative_gui::framework::layout::Bounds;
 use narrative_gui::theme::colors;
 use narrative_gui::{Color, Point, Size};
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use taffy::NodeId;
 
+/// Handler for custom quick menu entries
+///
+/// Called with the entry's ID from the UI theme when its button is clicked.
+/// See [`GameRootElement::with_quick_menu_handler`](crate::components::GameRootElement::with_quick_menu_handler).
+pub type QuickMenuHandler = Box<dyn FnMut(&str, &mut ScenarioRuntime) + Send + Sync>;
+
 /// Quick menu button action
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum QuickMenuAction {
     /// Toggle skip mode
     ToggleSkip,
+    /// Skip straight to the next choice or unread line
+    SkipToChoice,
     /// Toggle auto mode
     ToggleAuto,
     /// Open backlog
     OpenBacklog,
     /// Quick save
     QuickSave,
+    /// Load the quick save
+    QuickLoad,
+    /// Open the save screen
+    Save,
+    /// Open the load screen
+    Load,
     /// Open pause menu
     OpenMenu,
+    /// Custom entry from the UI theme, with its ID
+    Custom(String),
+}
+
+impl QuickMenuAction {
+    /// Resolve a button ID from the UI theme
+    ///
+    /// IDs that aren't built-in actions become [`QuickMenuAction::Custom`].
+    pub fn from_id(id: &str) -> Self {
+        match id {
+            "skip" => Self::ToggleSkip,
+            "skip_to_choice" => Self::SkipToChoice,
+            "auto" => Self::ToggleAuto,
+            "backlog" => Self::OpenBacklog,
+            "quick_save" => Self::QuickSave,
+            "quick_load" => Self::QuickLoad,
+            "save" => Self::Save,
+            "load" => Self::Load,
+            "menu" => Self::OpenMenu,
+            _ => Self::Custom(id.to_string()),
+        }
+    }
+
+    /// Label shown when the theme doesn't set one
+    fn default_label(&self) -> &str {
+        match self {
+            Self::ToggleSkip => "Skip",
+            Self::SkipToChoice => "Next",
+            Self::ToggleAuto => "Auto",
+            Self::OpenBacklog => "Log",
+            Self::QuickSave => "Save",
+            Self::QuickLoad => "Load",
+            Self::Save => "Save",
+            Self::Load => "Load",
+            Self::OpenMenu => "Menu",
+            Self::Custom(id) => id,
+        }
+    }
 }
 
 /// Quick menu button state
 #[derive(Debug, Clone)]
 struct QuickMenuButton {
     /// Button label
-    label: &'static str,
+    label: String,
     /// Button action
     action: QuickMenuAction,
+    /// Icon texture ID
+    icon: Option<u64>,
     /// Whether this button is in active state (for toggles)
     is_active: bool,
     /// Whether this button is enabled
@@ -56,6 +121,8 @@ pub struct QuickMenuElement {
     layout_node: Option<NodeId>,
     /// Menu buttons
     buttons: Vec<QuickMenuButton>,
+    /// Row or column of buttons
+    orientation: QuickMenuOrientation,
     /// Currently hovered button index
     hovered_index: Option<usize>,
     /// Action to perform (set when button is clicked)
@@ -70,6 +137,10 @@ pub struct QuickMenuElement {
     skip_active: bool,
     /// Whether auto mode is active
     auto_active: bool,
+    /// Dialogue box the menu sits above
+    dialogue_box: DialogueBoxConfig,
+    /// Flashed action, with the seconds left of its flash
+    flash: Option<(QuickMenuAction, f32)>,
 }
 
 impl QuickMenuElement {
@@ -87,56 +158,70 @@ impl QuickMenuElement {
     const MENU_PADDING: f32 = 12.0;
     /// Background alpha
     const BG_ALPHA: f32 = 0.85;
-
-    /// Create a new quick menu element
+    /// Icon size
+    const ICON_SIZE: f32 = 20.0;
+    /// Gap between an icon and its label
+    const ICON_GAP: f32 = 6.0;
+    /// Duration of a button flash, in seconds
+    const FLASH_SECS: f32 = 0.6;
+
+    /// Create a new quick menu element with the built-in buttons
     pub fn new() -> Self {
-        let buttons = vec![
-            QuickMenuButton {
-                label: "Skip",
-                action: QuickMenuAction::ToggleSkip,
-                is_active: false,
-                enabled: true,
-            },
-            QuickMenuButton {
-                label: "Auto",
-                action: QuickMenuAction::ToggleAuto,
-                is_active: false,
-                enabled: true,
-            },
-            QuickMenuButton {
-                label: "Log",
-                action: QuickMenuAction::OpenBacklog,
-                is_active: false,
-                enabled: true,
-            },
-            QuickMenuButton {
-                label: "Save",
-                action: QuickMenuAction::QuickSave,
-                is_active: false,
-                enabled: true,
-            },
-            QuickMenuButton {
-                label: "Menu",
-                action: QuickMenuAction::OpenMenu,
-                is_active: false,
-                enabled: true,
-            },
-        ];
-
-        let button_bounds = Arc::new(Mutex::new(vec![Bounds::default(); buttons.len()]));
-
         Self {
             id: ElementId::new(),
             layout_node: None,
-            buttons,
+            buttons: Vec::new(),
+            orientation: QuickMenuOrientation::default(),
             hovered_index: None,
             pending_action: None,
             dirty: true,
-            button_bounds,
+            button_bounds: Arc::new(Mutex::new(Vec::new())),
             animation_context: AnimationContext::default(),
             skip_active: false,
             auto_active: false,
+            dialogue_box: DialogueBoxConfig::default(),
+            flash: None,
         }
+        .with_layout(&QuickMenuTheme::default(), &HashMap::new())
+    }
+
+    /// Set the buttons and placement from a UI theme's quick menu
+    ///
+    /// `icon_textures` maps icon paths to loaded texture IDs; buttons whose
+    /// icon isn't loaded show only their label.
+    pub fn with_layout(
+        mut self,
+        layout: &QuickMenuTheme,
+        icon_textures: &HashMap<String, u64>,
+    ) -> Self {
+        self.orientation = layout.orientation;
+        self.buttons = layout
+            .buttons
+            .iter()
+            .map(|def| {
+                let action = QuickMenuAction::from_id(&def.id);
+                QuickMenuButton {
+                    label: def
+                        .label
+                        .clone()
+                        .unwrap_or_else(|| action.default_label().to_string()),
+                    icon: def
+                        .icon
+                        .as_ref()
+                        .and_then(|path| icon_textures.get(path).copied()),
+                    is_active: match action {
+                        QuickMenuAction::ToggleSkip => self.skip_active,
+                        QuickMenuAction::ToggleAuto => self.auto_active,
+                        _ => false,
+                    },
+                    action,
+                    enabled: true,
+                }
+            })
+            .collect();
+        self.hovered_index = None;
+        self.dirty = true;
+        self
     }
 
     /// Set the animation context
@@ -145,13 +230,17 @@ impl QuickMenuElement {
         self
     }
 
+    /// Set the dialogue box configuration the menu is positioned above
+    pub fn with_dialogue_box_config(mut self, config: DialogueBoxConfig) -> Self {
+        self.dialogue_box = config;
+        self
+    }
+
     /// Update skip mode state
     pub fn set_skip_active(&mut self, active: bool) {
         if self.skip_active != active {
             self.skip_active = active;
-            if let Some(button) = self.buttons.first_mut() {
-                button.is_active = active;
-            }
+            self.set_button_active(&QuickMenuAction::ToggleSkip, active);
             self.dirty = true;
         }
     }
@@ -160,16 +249,46 @@ impl QuickMenuElement {
     pub fn set_auto_active(&mut self, active: bool) {
         if self.auto_active != active {
             self.auto_active = active;
-            if let Some(button) = self.buttons.get_mut(1) {
-                button.is_active = active;
-            }
+            self.set_button_active(&QuickMenuAction::ToggleAuto, active);
             self.dirty = true;
         }
     }
 
+    /// Flash the buttons for an action briefly
+    pub fn flash(&mut self, action: QuickMenuAction) {
+        self.flash = Some((action, Self::FLASH_SECS));
+        self.dirty = true;
+    }
+
+    /// How strongly a button is flashed (0.0 = not flashed)
+    fn flash_ratio(&self, button: &QuickMenuButton) -> f32 {
+        match &self.flash {
+            Some((action, remaining)) if *action == button.action => {
+                if self.animation_context.should_animate(None) {
+                    (remaining / Self::FLASH_SECS).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                }
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Show the buttons for an action as active or inactive
+    fn set_button_active(&mut self, action: &QuickMenuAction, active: bool) {
+        for button in self.buttons.iter_mut().filter(|b| &b.action == action) {
+            button.is_active = active;
+        }
+    }
+
     /// Get the pending action, if any
-    pub fn pending_action(&self) -> Option<QuickMenuAction> {
-        self.pending_action
+    pub fn pending_action(&self) -> Option<&QuickMenuAction> {
+        self.pending_action.as_ref()
+    }
+
+    /// Take the pending action, leaving none
+    pub fn take_pending_action(&mut self) -> Option<QuickMenuAction> {
+        self.pending_action.take()
     }
 
     /// Clear the pending action
@@ -177,28 +296,50 @@ impl QuickMenuElement {
         self.pending_action = None;
     }
 
-    /// Calculate button bounds for layout (used in tests)
-    #[cfg(test)]
-    fn calculate_button_bounds(&mut self, container_bounds: Bounds) {
-        let total_width = (Self::BUTTON_WIDTH * self.buttons.len() as f32)
-            + (Self::BUTTON_SPACING * (self.buttons.len().saturating_sub(1)) as f32);
-
-        // Right-align the menu
+    /// Button bounds inside the menu's bounds (absolute window coordinates)
+    ///
+    /// A row is aligned right; a column stacks the buttons top to bottom.
+    fn layout_buttons(&self, container_bounds: Bounds) -> Vec<Bounds> {
         let start_x = container_bounds.origin.x + container_bounds.size.width
-            - total_width
+            - self.buttons_extent(QuickMenuOrientation::Horizontal)
             - Self::MENU_PADDING;
         let start_y = container_bounds.origin.y + Self::MENU_PADDING;
+        let step = |i: usize, size: f32| i as f32 * (size + Self::BUTTON_SPACING);
 
-        if let Ok(mut bounds_vec) = self.button_bounds.lock() {
-            bounds_vec.clear();
-            for i in 0..self.buttons.len() {
-                let x = start_x + (i as f32 * (Self::BUTTON_WIDTH + Self::BUTTON_SPACING));
-                bounds_vec.push(Bounds {
-                    origin: Point::new(x, start_y),
+        (0..self.buttons.len())
+            .map(|i| {
+                let origin = match self.orientation {
+                    QuickMenuOrientation::Horizontal => {
+                        Point::new(start_x + step(i, Self::BUTTON_WIDTH), start_y)
+                    }
+                    QuickMenuOrientation::Vertical => {
+                        Point::new(start_x, start_y + step(i, Self::BUTTON_HEIGHT))
+                    }
+                };
+                Bounds {
+                    origin,
                     size: Size::new(Self::BUTTON_WIDTH, Self::BUTTON_HEIGHT),
-                });
+                }
+            })
+            .collect()
+    }
+
+    /// Width (row) or height (column) of the buttons, without padding
+    ///
+    /// A column is one button wide, so its width is a single button's.
+    fn buttons_extent(&self, axis: QuickMenuOrientation) -> f32 {
+        let count = match (self.orientation, axis) {
+            (QuickMenuOrientation::Horizontal, QuickMenuOrientation::Horizontal)
+            | (QuickMenuOrientation::Vertical, QuickMenuOrientation::Vertical) => {
+                self.buttons.len()
             }
-        }
+            _ => 1,
+        };
+        let size = match axis {
+            QuickMenuOrientation::Horizontal => Self::BUTTON_WIDTH,
+            QuickMenuOrientation::Vertical => Self::BUTTON_HEIGHT,
+        };
+        size * count as f32 + Self::BUTTON_SPACING * count.saturating_sub(1) as f32
     }
 
     /// Get button color based on state
@@ -249,31 +390,42 @@ impl Element for QuickMenuElement {
         self.layout_node = Some(node);
     }
 
-    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+    fn layout(&mut self, cx: &mut LayoutContext) -> taffy::Style {
         use taffy::prelude::*;
 
-        // Calculate total size needed
-        let _total_width = (Self::BUTTON_WIDTH * self.buttons.len() as f32)
-            + (Self::BUTTON_SPACING * (self.buttons.len().saturating_sub(1)) as f32)
-            + (Self::MENU_PADDING * 2.0);
-        let total_height = Self::BUTTON_HEIGHT + (Self::MENU_PADDING * 2.0);
+        let total_height =
+            self.buttons_extent(QuickMenuOrientation::Vertical) + (Self::MENU_PADDING * 2.0);
 
         // Position above dialogue box
-        // TODO: Consider moving these to DialogueBoxConfig or GameConfig for better maintainability
-        // Currently hardcoded to match DialogueBoxConfig::default().height (200px)
-        const DIALOGUE_BOX_HEIGHT: f32 = 200.0;
         const MENU_GAP: f32 = 8.0;
+        let bottom = self.dialogue_box.bottom_offset(cx.available_size.height)
+            + self.dialogue_box.height
+            + MENU_GAP;
+
+        // A row spans the full width for alignment; a column hugs the right edge
+        let (width, left) = match self.orientation {
+            QuickMenuOrientation::Horizontal => {
+                (Dimension::percent(1.0), LengthPercentageAuto::length(0.0))
+            }
+            QuickMenuOrientation::Vertical => (
+                Dimension::length(
+                    self.buttons_extent(QuickMenuOrientation::Horizontal)
+                        + (Self::MENU_PADDING * 2.0),
+                ),
+                LengthPercentageAuto::auto(),
+            ),
+        };
 
         taffy::Style {
             size: Size {
-                width: Dimension::percent(1.0), // Full width for alignment
+                width,
                 height: Dimension::length(total_height),
             },
             position: Position::Absolute,
             inset: Rect {
-                left: LengthPercentageAuto::length(0.0),
+                left,
                 right: LengthPercentageAuto::length(0.0),
-                bottom: LengthPercentageAuto::length(DIALOGUE_BOX_HEIGHT + MENU_GAP),
+                bottom: LengthPercentageAuto::length(bottom),
                 top: LengthPercentageAuto::auto(),
             },
             ..Default::default()
@@ -291,23 +443,12 @@ impl Element for QuickMenuElement {
         cx.fill_rounded_rect(cx.bounds, bg_color, Self::CORNER_RADIUS);
 
         // Calculate button positions (absolute window coordinates)
-        let total_width = (Self::BUTTON_WIDTH * self.buttons.len() as f32)
-            + (Self::BUTTON_SPACING * (self.buttons.len().saturating_sub(1)) as f32);
-
-        let start_x = cx.bounds.origin.x + cx.bounds.size.width - total_width - Self::MENU_PADDING;
-        let start_y = cx.bounds.origin.y + Self::MENU_PADDING;
+        let layout = self.layout_buttons(cx.bounds);
 
         // Store button bounds for event handling
         match self.button_bounds.lock() {
             Ok(mut bounds_vec) => {
-                bounds_vec.clear();
-                for i in 0..self.buttons.len() {
-                    let x = start_x + (i as f32 * (Self::BUTTON_WIDTH + Self::BUTTON_SPACING));
-                    bounds_vec.push(Bounds {
-                        origin: Point::new(x, start_y),
-                        size: Size::new(Self::BUTTON_WIDTH, Self::BUTTON_HEIGHT),
-                    });
-                }
+                bounds_vec.clone_from(&layout);
             }
             Err(e) => {
                 tracing::error!("Failed to lock button_bounds in paint: {:?}", e);
@@ -315,17 +456,11 @@ impl Element for QuickMenuElement {
         }
 
         // Draw each button
-        for (i, button) in self.buttons.iter().enumerate() {
+        for (i, (button, button_bounds)) in self.buttons.iter().zip(layout).enumerate() {
             if !button.enabled {
                 continue;
             }
 
-            let x = start_x + (i as f32 * (Self::BUTTON_WIDTH + Self::BUTTON_SPACING));
-            let button_bounds = Bounds {
-                origin: Point::new(x, start_y),
-                size: Size::new(Self::BUTTON_WIDTH, Self::BUTTON_HEIGHT),
-            };
-
             let is_hovered = self.hovered_index == Some(i);
             let bg_color = self.get_button_color(button, is_hovered);
             let text_color = self.get_text_color(button);
@@ -333,6 +468,14 @@ impl Element for QuickMenuElement {
             // Draw button background
             cx.fill_rounded_rect(button_bounds, bg_color, Self::CORNER_RADIUS);
 
+            // Fade the flash out over the background
+            let flash = self.flash_ratio(button);
+            if flash > 0.0 {
+                let mut flash_color = colors::ACCENT_PRIMARY;
+                flash_color.a = 0.6 * flash;
+                cx.fill_rounded_rect(button_bounds, flash_color, Self::CORNER_RADIUS);
+            }
+
             // Draw button border
             let border_color = if button.is_active {
                 colors::ACCENT_PRIMARY
@@ -341,14 +484,33 @@ impl Element for QuickMenuElement {
             };
             cx.stroke_rect(button_bounds, border_color, 1.0);
 
-            // Draw button text (centered)
-            let text_width = button.label.len() as f32 * Self::FONT_SIZE * 0.6;
-            let text_x = button_bounds.origin.x + (Self::BUTTON_WIDTH - text_width) / 2.0;
+            // Center the icon and label together
+            let text_width = button.label.chars().count() as f32 * Self::FONT_SIZE * 0.6;
+            let icon_width = match (button.icon, button.label.is_empty()) {
+                (None, _) => 0.0,
+                (Some(_), true) => Self::ICON_SIZE,
+                (Some(_), false) => Self::ICON_SIZE + Self::ICON_GAP,
+            };
+            let content_x =
+                button_bounds.origin.x + (Self::BUTTON_WIDTH - icon_width - text_width) / 2.0;
+
+            if let Some(texture_id) = button.icon {
+                let icon_bounds = Bounds {
+                    origin: Point::new(
+                        content_x,
+                        button_bounds.origin.y + (Self::BUTTON_HEIGHT - Self::ICON_SIZE) / 2.0,
+                    ),
+                    size: Size::new(Self::ICON_SIZE, Self::ICON_SIZE),
+                };
+                cx.draw_texture(texture_id, icon_bounds, 1.0);
+            }
+
+            let text_x = content_x + icon_width;
             let text_y =
                 button_bounds.origin.y + (Self::BUTTON_HEIGHT + Self::FONT_SIZE * 0.7) / 2.0;
 
             cx.draw_text(
-                button.label,
+                &button.label,
                 Point::new(text_x, text_y),
                 text_color,
                 Self::FONT_SIZE,
@@ -398,7 +560,7 @@ impl Element for QuickMenuElement {
                                 && let Some(button) = self.buttons.get(i)
                                 && button.enabled
                             {
-                                self.pending_action = Some(button.action);
+                                self.pending_action = Some(button.action.clone());
                                 self.dirty = true;
                                 return true;
                             }
@@ -416,11 +578,19 @@ impl Element for QuickMenuElement {
     }
 
     fn tick(&mut self, delta: Duration) -> bool {
-        let _ = delta;
+        // Repaint every frame while a flash fades out
+        let flashing = self.flash.is_some();
+        if let Some((_, remaining)) = &mut self.flash {
+            *remaining -= delta.as_secs_f32();
+            if *remaining <= 0.0 {
+                self.flash = None;
+            }
+        }
+
         // Reset dirty flag
         let was_dirty = self.dirty;
         self.dirty = false;
-        was_dirty
+        was_dirty || flashing
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -435,6 +605,7 @@ impl Element for QuickMenuElement {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use narrative_core::QuickMenuButtonDef;
 
     #[test]
     fn test_quick_menu_creation() {
@@ -494,7 +665,7 @@ mod tests {
 
         // Simulate button click by setting pending action
         menu.pending_action = Some(QuickMenuAction::QuickSave);
-        assert_eq!(menu.pending_action(), Some(QuickMenuAction::QuickSave));
+        assert_eq!(menu.pending_action(), Some(&QuickMenuAction::QuickSave));
 
         // Clear pending action
         menu.clear_pending_action();
@@ -502,14 +673,30 @@ mod tests {
     }
 
     #[test]
-    fn test_button_bounds_calculation() {
+    fn test_flash() {
         let mut menu = QuickMenuElement::new();
-        let container_bounds = Bounds::new(0.0, 0.0, 1280.0, 100.0);
+        let save = menu.buttons[3].clone();
+        assert_eq!(save.action, QuickMenuAction::QuickSave);
+        assert_eq!(menu.flash_ratio(&save), 0.0);
+
+        menu.flash(QuickMenuAction::QuickSave);
+        assert_eq!(menu.flash_ratio(&save), 1.0);
+        assert_eq!(menu.flash_ratio(&menu.buttons[0]), 0.0);
+
+        assert!(menu.tick(Duration::from_secs_f32(0.3)));
+        assert!(menu.flash_ratio(&save) < 1.0);
+        assert!(menu.tick(Duration::from_secs_f32(0.4)));
+        assert_eq!(menu.flash_ratio(&save), 0.0);
+        assert!(!menu.tick(Duration::from_secs_f32(0.1)));
+    }
 
-        menu.calculate_button_bounds(container_bounds);
+    #[test]
+    fn test_button_bounds_calculation() {
+        let menu = QuickMenuElement::new();
+        let container_bounds = Bounds::new(0.0, 0.0, 1280.0, 100.0);
 
         // Check that all button bounds are calculated
-        let bounds_vec = menu.button_bounds.lock().unwrap();
+        let bounds_vec = menu.layout_buttons(container_bounds);
         assert_eq!(bounds_vec.len(), 5);
 
         // Buttons should be right-aligned
@@ -519,4 +706,38 @@ mod tests {
 
         assert!((bounds_vec[0].origin.x - expected_start_x).abs() < 0.1);
     }
+
+    #[test]
+    fn test_themed_layout() {
+        let layout = QuickMenuTheme {
+            orientation: QuickMenuOrientation::Vertical,
+            buttons: vec![
+                QuickMenuButtonDef::new("load").with_icon("icons/load.png"),
+                QuickMenuButtonDef::new("auto").with_label("Auto Play"),
+                QuickMenuButtonDef::new("gallery"),
+            ],
+        };
+        let icons = HashMap::from([("icons/load.png".to_string(), 7)]);
+        let mut menu = QuickMenuElement::new().with_layout(&layout, &icons);
+
+        let labels: Vec<_> = menu.buttons.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels, vec!["Load", "Auto Play", "gallery"]);
+        assert_eq!(menu.buttons[0].icon, Some(7));
+        assert_eq!(
+            menu.buttons[2].action,
+            QuickMenuAction::Custom("gallery".to_string())
+        );
+
+        // Toggle state follows the action, not the button position
+        menu.set_auto_active(true);
+        assert!(menu.buttons[1].is_active);
+
+        // Buttons are stacked in one column
+        let bounds_vec = menu.layout_buttons(Bounds::new(0.0, 0.0, 104.0, 200.0));
+        assert_eq!(bounds_vec[0].origin.x, bounds_vec[2].origin.x);
+        assert_eq!(
+            bounds_vec[1].origin.y - bounds_vec[0].origin.y,
+            QuickMenuElement::BUTTON_HEIGHT + QuickMenuElement::BUTTON_SPACING
+        );
+    }
 }
@@ -1,17 +1,22 @@
 //! Save/Load menu element
 //!
-//! Main UI for saving and loading game progress.
+//! Main UI for saving and loading game progress. The slots of a page scroll
+//! below the header, and the selected slot shows a preview of the saved screen.
 
 use super::SaveSlotCard;
+use narrative_core::{SaveSlotConfig, SaveSlotKind, Scenario};
 use narrative_engine::runtime::LayoutMode;
-use narrative_engine::save::{SaveManager, SlotInfo, list_all_slots};
+use narrative_engine::save::{DisplaySnapshot, SaveManager, SlotInfo, list_all_slots_checked};
 use narrative_gui::framework::animation::AnimationContext;
-use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::element::{
+    Element, ElementId, LayoutContext, PaintContext, ScrollView,
+};
 use narrative_gui::framework::input::{InputEvent, KeyCode};
 use narrative_gui::framework::layout::Bounds;
 use narrative_gui::theme::{colors, font_size, spacing};
 use narrative_gui::{Color, Point};
 use std::any::Any;
+use std::ops::Range;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use taffy::NodeId;
@@ -23,6 +28,8 @@ pub enum SaveLoadMenuAction {
     SaveToSlot(usize),
     /// Load from selected slot
     LoadFromSlot(usize),
+    /// Restart a save that doesn't match the scenario from its scene-entry checkpoint
+    RestartFromCheckpoint(usize),
     /// Delete selected slot
     DeleteSlot(usize),
     /// Go back to previous menu
@@ -35,34 +42,46 @@ pub enum SaveLoadMenuAction {
     ToggleLayout,
 }
 
+/// A page of slots within one section of the menu
+#[derive(Debug, Clone, PartialEq)]
+struct SlotPage {
+    /// Section the slots belong to
+    kind: SaveSlotKind,
+    /// Slot numbers on the page
+    slots: Range<usize>,
+}
+
 /// Save/Load menu element
 pub struct SaveLoadMenuElement {
     /// Unique element ID
     id: ElementId,
     /// Taffy layout node
     layout_node: Option<NodeId>,
-    /// Save manager (unused in this element, operations are handled in GameRoot)
-    #[allow(dead_code)]
+    /// Save manager (used to list slots; save operations are handled in GameRoot)
     save_manager: Arc<Mutex<SaveManager>>,
     /// Current mode (Save or Load)
     is_save_mode: bool,
-    /// Current page (0-indexed)
+    /// Current page (0-indexed, across all sections)
     current_page: usize,
     /// Layout mode
     layout_mode: LayoutMode,
     /// Slots per page
     slots_per_page: usize,
+    /// Save slot layout (Auto / Quick / Manual sections)
+    slot_config: SaveSlotConfig,
+    /// Pages of all sections in display order
+    pages: Vec<SlotPage>,
     /// All slot information
     all_slots: Vec<SlotInfo>,
-    /// Total number of slots
-    total_slots: usize,
-    /// Selected slot index (global, across all pages)
+    /// Selected slot number
     selected_slot: usize,
+    /// Rendered preview of a slot's saved screen (slot, texture ID)
+    preview_texture: Option<(usize, u64)>,
     /// Confirmed action
     action_confirmed: Option<SaveLoadMenuAction>,
     /// Dirty flag
     dirty: bool,
-    /// Child elements (slot cards)
+    /// Child elements (a scroll view holding the slot cards)
     children: Vec<Box<dyn Element>>,
     /// Animation context
     animation_context: AnimationContext,
@@ -73,46 +92,51 @@ impl SaveLoadMenuElement {
     const SLOTS_PER_PAGE_LIST: usize = 6;
     /// Slots per page in Grid mode (3×3 grid)
     const SLOTS_PER_PAGE_GRID: usize = 9;
-    /// Total slots supported
-    const TOTAL_SLOTS: usize = 30;
-
-    /// Create a new save/load menu element
+    /// Space above the slots for the title and section tabs
+    const HEADER_HEIGHT: f32 = 110.0;
+    /// Space below the slots for compatibility warnings and instructions
+    const FOOTER_HEIGHT: f32 = 150.0;
+    /// Sections in display order
+    const SECTIONS: [SaveSlotKind; 3] = [
+        SaveSlotKind::Auto,
+        SaveSlotKind::Quick,
+        SaveSlotKind::Manual,
+    ];
+
+    /// Create a new save/load menu element with the default slot layout
     pub fn new(
         save_manager: Arc<Mutex<SaveManager>>,
         is_save_mode: bool,
         layout_mode: LayoutMode,
     ) -> Self {
-        let slots_per_page = match layout_mode {
-            LayoutMode::List => Self::SLOTS_PER_PAGE_LIST,
-            LayoutMode::Grid => Self::SLOTS_PER_PAGE_GRID,
-        };
-
-        // Load all slot information
-        let all_slots = match save_manager.lock() {
-            Ok(manager) => list_all_slots(&manager, Self::TOTAL_SLOTS),
-            Err(e) => {
-                tracing::error!("Failed to lock save_manager during initialization: {:?}", e);
-                // Return empty slots on error
-                (0..Self::TOTAL_SLOTS).map(SlotInfo::empty).collect()
-            }
-        };
+        let slot_config = SaveSlotConfig::default();
+        let all_slots = Self::load_slots(&save_manager, slot_config.total_slots(), None);
 
-        Self {
+        let mut menu = Self {
             id: ElementId::new(),
             layout_node: None,
             save_manager,
             is_save_mode,
             current_page: 0,
             layout_mode,
-            slots_per_page,
+            slots_per_page: Self::slots_per_page_for(layout_mode),
+            slot_config,
+            pages: Vec::new(),
             all_slots,
-            total_slots: Self::TOTAL_SLOTS,
             selected_slot: 0,
+            preview_texture: None,
             action_confirmed: None,
             dirty: true,
-            children: Vec::new(),
+            children: vec![Box::new(
+                ScrollView::new()
+                    .with_columns(Self::columns_for(layout_mode))
+                    .with_gap(spacing::MD),
+            )],
             animation_context: AnimationContext::default(),
-        }
+        };
+        menu.rebuild_pages();
+        menu.open_default_page();
+        menu
     }
 
     /// Set animation context
@@ -121,6 +145,32 @@ impl SaveLoadMenuElement {
         self
     }
 
+    /// Use a project-specific save slot layout
+    pub fn with_slot_config(mut self, slot_config: SaveSlotConfig) -> Self {
+        if slot_config != self.slot_config {
+            self.all_slots = Self::load_slots(&self.save_manager, slot_config.total_slots(), None);
+            self.slot_config = slot_config;
+            self.rebuild_pages();
+            self.open_default_page();
+            self.dirty = true;
+        }
+        self
+    }
+
+    /// Check saves against the running scenario
+    ///
+    /// Slots whose scene or position no longer exists get a warning badge and
+    /// load at the start of the nearest scene. Call after `with_slot_config`.
+    pub fn with_compatibility_check(mut self, scenario: &Scenario) -> Self {
+        self.all_slots = Self::load_slots(
+            &self.save_manager,
+            self.slot_config.total_slots(),
+            Some(scenario),
+        );
+        self.dirty = true;
+        self
+    }
+
     /// Get confirmed action
     pub fn confirmed_action(&self) -> Option<SaveLoadMenuAction> {
         self.action_confirmed
@@ -131,24 +181,156 @@ impl SaveLoadMenuElement {
         self.action_confirmed = None;
     }
 
-    /// Get current page slot range
-    fn current_page_slots(&self) -> Vec<&SlotInfo> {
-        let start = self.current_page * self.slots_per_page;
-        let end = (start + self.slots_per_page).min(self.total_slots);
-        self.all_slots[start..end].iter().collect()
+    /// Saved screen of the selected slot, if it should be previewed
+    pub fn preview_snapshot(&self) -> Option<&DisplaySnapshot> {
+        self.all_slots
+            .get(self.selected_slot)
+            .filter(|info| info.exists)
+            .map(|info| &info.display)
+            .filter(|snapshot| !snapshot.is_empty())
+    }
+
+    /// Selected slot, if it has a stored thumbnail to preview instead of
+    /// composing its snapshot
+    pub fn preview_thumbnail_slot(&self) -> Option<usize> {
+        self.all_slots
+            .get(self.selected_slot)
+            .filter(|info| info.exists && info.thumbnail_path.is_some())
+            .map(|info| info.slot)
+    }
+
+    /// Set the rendered preview of the selected slot
+    ///
+    /// Returns true if the texture changed.
+    pub fn set_preview_texture(&mut self, texture_id: Option<u64>) -> bool {
+        let preview = texture_id.map(|id| (self.selected_slot, id));
+        if self.preview_texture == preview {
+            return false;
+        }
+        self.preview_texture = preview;
+        self.dirty = true;
+        true
+    }
+
+    fn columns_for(layout_mode: LayoutMode) -> usize {
+        match layout_mode {
+            LayoutMode::List => 1,
+            LayoutMode::Grid => 3,
+        }
+    }
+
+    /// Region the slot cards scroll in
+    fn slots_viewport(bounds: Bounds) -> Bounds {
+        Bounds::new(
+            bounds.x() + spacing::XL,
+            bounds.y() + Self::HEADER_HEIGHT,
+            (bounds.width() - spacing::XL * 2.0).max(0.0),
+            (bounds.height() - Self::HEADER_HEIGHT - Self::FOOTER_HEIGHT).max(0.0),
+        )
+    }
+
+    fn scroll_view_mut(&mut self) -> Option<&mut ScrollView> {
+        self.children.first_mut()?.as_any_mut().downcast_mut()
+    }
+
+    /// Fit the scroll view to the current page and bring the selected slot into view
+    fn scroll_to_selected(&mut self) {
+        let columns = Self::columns_for(self.layout_mode);
+        // Cards have a margin on every side
+        let row_height = SaveSlotCard::card_size(self.layout_mode).1 + spacing::SM * 2.0;
+        let row_stride = row_height + spacing::MD;
+        let slots = self.current_page_slots();
+        let rows = slots.len().div_ceil(columns);
+        let row = self.selected_slot.saturating_sub(slots.start) / columns;
+
+        if let Some(scroll) = self.scroll_view_mut() {
+            scroll.set_columns(columns);
+            scroll.set_content_height((rows as f32 * row_stride - spacing::MD).max(0.0));
+            let top = row as f32 * row_stride;
+            scroll.scroll_to_show(top, top + row_height);
+        }
+    }
+
+    fn slots_per_page_for(layout_mode: LayoutMode) -> usize {
+        match layout_mode {
+            LayoutMode::List => Self::SLOTS_PER_PAGE_LIST,
+            LayoutMode::Grid => Self::SLOTS_PER_PAGE_GRID,
+        }
+    }
+
+    /// Load information for all slots
+    fn load_slots(
+        save_manager: &Arc<Mutex<SaveManager>>,
+        total_slots: usize,
+        scenario: Option<&Scenario>,
+    ) -> Vec<SlotInfo> {
+        match save_manager.lock() {
+            Ok(manager) => list_all_slots_checked(&manager, total_slots, scenario),
+            Err(e) => {
+                tracing::error!("Failed to lock save_manager during initialization: {:?}", e);
+                // Return empty slots on error
+                (0..total_slots).map(SlotInfo::empty).collect()
+            }
+        }
+    }
+
+    /// Split every section into pages
+    fn rebuild_pages(&mut self) {
+        self.pages.clear();
+        let per_page = self.slots_per_page.max(1);
+        for kind in Self::SECTIONS {
+            let section = self.slot_config.slots(kind);
+            let mut start = section.start;
+            while start < section.end {
+                let end = start.saturating_add(per_page).min(section.end);
+                self.pages.push(SlotPage {
+                    kind,
+                    slots: start..end,
+                });
+                start = end;
+            }
+        }
+    }
+
+    /// Open the first manual page in save mode, the first page in load mode
+    fn open_default_page(&mut self) {
+        self.current_page = if self.is_save_mode {
+            self.pages
+                .iter()
+                .position(|page| page.kind == SaveSlotKind::Manual)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        self.selected_slot = self.current_page_slots().start;
+        self.scroll_to_selected();
+    }
+
+    /// Slot numbers on the current page
+    fn current_page_slots(&self) -> Range<usize> {
+        self.pages
+            .get(self.current_page)
+            .map(|page| page.slots.clone())
+            .unwrap_or(0..0)
+    }
+
+    /// Section of the current page
+    fn current_section(&self) -> Option<SaveSlotKind> {
+        self.pages.get(self.current_page).map(|page| page.kind)
     }
 
     /// Calculate total pages
     fn total_pages(&self) -> usize {
-        self.total_slots.div_ceil(self.slots_per_page)
+        self.pages.len()
     }
 
     /// Navigate to next page
     fn next_page(&mut self) {
-        if self.current_page < self.total_pages() - 1 {
+        if self.current_page.saturating_add(1) < self.total_pages() {
             self.current_page += 1;
             // Reset selection to first slot of new page
-            self.selected_slot = self.current_page * self.slots_per_page;
+            self.selected_slot = self.current_page_slots().start;
+            self.scroll_to_selected();
             self.dirty = true;
         }
     }
@@ -158,7 +340,8 @@ impl SaveLoadMenuElement {
         if self.current_page > 0 {
             self.current_page -= 1;
             // Reset selection to first slot of new page
-            self.selected_slot = self.current_page * self.slots_per_page;
+            self.selected_slot = self.current_page_slots().start;
+            self.scroll_to_selected();
             self.dirty = true;
         }
     }
@@ -169,83 +352,116 @@ impl SaveLoadMenuElement {
             LayoutMode::List => LayoutMode::Grid,
             LayoutMode::Grid => LayoutMode::List,
         };
-        self.slots_per_page = match self.layout_mode {
-            LayoutMode::List => Self::SLOTS_PER_PAGE_LIST,
-            LayoutMode::Grid => Self::SLOTS_PER_PAGE_GRID,
-        };
+        self.slots_per_page = Self::slots_per_page_for(self.layout_mode);
+        self.rebuild_pages();
+        // Stay on the page holding the selected slot
+        self.current_page = self
+            .pages
+            .iter()
+            .position(|page| page.slots.contains(&self.selected_slot))
+            .unwrap_or(0);
+        self.scroll_to_selected();
         self.dirty = true;
     }
 
     /// Select next slot
     fn select_next(&mut self) {
-        let slots_in_page = self.current_page_slots().len();
-        let local_index = self.selected_slot % self.slots_per_page;
-        if local_index < slots_in_page.saturating_sub(1) {
+        if self.selected_slot.saturating_add(1) < self.current_page_slots().end {
             self.selected_slot += 1;
+            self.scroll_to_selected();
             self.dirty = true;
         }
     }
 
     /// Select previous slot
     fn select_previous(&mut self) {
-        let local_index = self.selected_slot % self.slots_per_page;
-        if local_index > 0 {
+        if self.selected_slot > self.current_page_slots().start {
             self.selected_slot -= 1;
+            self.scroll_to_selected();
             self.dirty = true;
         }
     }
 
     /// Confirm current selection
     fn confirm_selection(&mut self) {
-        let global_slot =
-            self.current_page * self.slots_per_page + (self.selected_slot % self.slots_per_page);
-
-        if global_slot >= self.all_slots.len() {
+        let slot = self.selected_slot;
+        let Some(slot_info) = self.all_slots.get(slot) else {
             return;
-        }
-
-        let slot_info = &self.all_slots[global_slot];
+        };
 
         if self.is_save_mode {
-            // Save mode: Always allow saving
-            self.action_confirmed = Some(SaveLoadMenuAction::SaveToSlot(global_slot));
+            // Save mode: reserved autosave/quick save slots can't be overwritten
+            if self.slot_config.is_protected(slot) {
+                return;
+            }
+            self.action_confirmed = Some(SaveLoadMenuAction::SaveToSlot(slot));
         } else {
             // Load mode: Only allow if slot exists
+            // (saves that don't match the scenario load at the nearest scene start)
             if slot_info.exists {
-                self.action_confirmed = Some(SaveLoadMenuAction::LoadFromSlot(global_slot));
+                self.action_confirmed = Some(SaveLoadMenuAction::LoadFromSlot(slot));
             }
         }
         self.dirty = true;
     }
 
+    /// Restart the selected save from its scene-entry checkpoint
+    ///
+    /// Only offered for saves that can't be restored exactly.
+    fn restart_from_checkpoint(&mut self) {
+        if self.is_save_mode {
+            return;
+        }
+        let slot = self.selected_slot;
+        if self
+            .all_slots
+            .get(slot)
+            .is_some_and(|info| info.has_issues() && info.has_checkpoint)
+        {
+            self.action_confirmed = Some(SaveLoadMenuAction::RestartFromCheckpoint(slot));
+            self.dirty = true;
+        }
+    }
+
     /// Delete selected slot
     fn delete_slot(&mut self) {
-        let global_slot =
-            self.current_page * self.slots_per_page + (self.selected_slot % self.slots_per_page);
-
-        if global_slot >= self.all_slots.len() {
+        let slot = self.selected_slot;
+        if self.slot_config.is_protected(slot) {
             return;
         }
 
-        let slot_info = &self.all_slots[global_slot];
-        if slot_info.exists {
-            self.action_confirmed = Some(SaveLoadMenuAction::DeleteSlot(global_slot));
+        if self.all_slots.get(slot).is_some_and(|info| info.exists) {
+            self.action_confirmed = Some(SaveLoadMenuAction::DeleteSlot(slot));
             self.dirty = true;
         }
     }
 
-    /// Rebuild children (slot cards)
-    fn rebuild_children(&mut self) {
-        self.children.clear();
-
-        // Clone slot information to avoid borrow checker issues
-        let start = self.current_page * self.slots_per_page;
-        let end = (start + self.slots_per_page).min(self.total_slots);
-        let slots: Vec<SlotInfo> = self.all_slots[start..end].to_vec();
+    /// Label of a slot card (numbered within its section)
+    fn slot_label(&self, slot: usize) -> String {
+        let number = self
+            .slot_config
+            .index_in_section(slot)
+            .unwrap_or(slot)
+            .saturating_add(1);
+        match (self.slot_config.kind_of(slot), self.layout_mode) {
+            (Some(SaveSlotKind::Manual) | None, LayoutMode::List) => format!("Slot {:02}", number),
+            (Some(SaveSlotKind::Manual) | None, LayoutMode::Grid) => format!("#{:02}", number),
+            (Some(kind), _) => format!("{} {:02}", kind.label(), number),
+        }
+    }
 
-        for (i, slot_info) in slots.iter().enumerate() {
-            let global_slot = self.current_page * self.slots_per_page + i;
-            let is_selected = global_slot == self.selected_slot;
+    /// Rebuild the slot cards in the scroll view
+    fn rebuild_children(&mut self) {
+        let mut cards: Vec<Box<dyn Element>> = Vec::new();
+        for slot in self.current_page_slots() {
+            let Some(slot_info) = self.all_slots.get(slot) else {
+                continue;
+            };
+            let is_selected = slot == self.selected_slot;
+            let thumbnail_texture = self
+                .preview_texture
+                .filter(|(preview_slot, _)| is_selected && *preview_slot == slot)
+                .map(|(_, texture_id)| texture_id);
 
             let card = SaveSlotCard::new(
                 slot_info.clone(),
@@ -253,9 +469,16 @@ impl SaveLoadMenuElement {
                 self.is_save_mode,
                 self.layout_mode,
             )
+            .with_label(self.slot_label(slot))
+            .with_protected(self.slot_config.is_protected(slot))
+            .with_thumbnail_texture(thumbnail_texture)
             .with_animation_context(self.animation_context);
 
-            self.children.push(Box::new(card));
+            cards.push(Box::new(card));
+        }
+
+        if let Some(scroll) = self.scroll_view_mut() {
+            scroll.set_children(cards);
         }
     }
 }
@@ -279,13 +502,17 @@ impl Element for SaveLoadMenuElement {
         Style {
             display: Display::Flex,
             flex_direction: FlexDirection::Column,
-            align_items: Some(AlignItems::Center),
+            align_items: Some(AlignItems::Stretch),
             size: Size {
                 width: Dimension::percent(1.0),
                 height: Dimension::percent(1.0),
             },
-            padding: Rect::length(spacing::XL),
-            gap: Size::length(spacing::MD),
+            padding: Rect {
+                left: LengthPercentage::length(spacing::XL),
+                right: LengthPercentage::length(spacing::XL),
+                top: LengthPercentage::length(Self::HEADER_HEIGHT),
+                bottom: LengthPercentage::length(Self::FOOTER_HEIGHT),
+            },
             ..Default::default()
         }
     }
@@ -321,8 +548,33 @@ impl Element for SaveLoadMenuElement {
             font_size::SM,
         );
 
+        // Section tabs (current section highlighted)
+        let mut section_x = cx.bounds.x() + spacing::XL;
+        let section_y = title_y + 50.0;
+        for kind in Self::SECTIONS {
+            if self.slot_config.slots(kind).is_empty() {
+                continue;
+            }
+            let color = if self.current_section() == Some(kind) {
+                colors::ACCENT_PRIMARY
+            } else {
+                colors::TEXT_SECONDARY
+            };
+            cx.draw_text(
+                kind.label(),
+                Point::new(section_x, section_y),
+                color,
+                font_size::MD,
+            );
+            section_x += 100.0;
+        }
+
         // Page info
-        let page_info = format!("Page {} / {}", self.current_page + 1, self.total_pages());
+        let page_info = format!(
+            "Page {} / {}",
+            self.current_page + 1,
+            self.total_pages().max(1)
+        );
         cx.draw_text(
             &page_info,
             Point::new(cx.bounds.x() + cx.bounds.width() / 2.0 - 50.0, title_y),
@@ -332,6 +584,38 @@ impl Element for SaveLoadMenuElement {
 
         // Instructions at bottom
         let instructions_y = cx.bounds.y() + cx.bounds.height() - 60.0;
+
+        // Compatibility warning for the selected slot
+        if let Some(slot_info) = self
+            .all_slots
+            .get(self.selected_slot)
+            .filter(|info| info.has_issues())
+        {
+            let mut lines: Vec<String> = slot_info.issues.iter().map(|i| i.to_string()).collect();
+            lines.push(
+                "This save was made with another version of the scenario. \
+                 [Enter] restarts at the beginning of the nearest scene."
+                    .to_string(),
+            );
+            if slot_info.has_checkpoint {
+                lines.push(
+                    "[R] restarts from the scene-entry checkpoint with the flags of that moment."
+                        .to_string(),
+                );
+            }
+            let line_height = 22.0;
+            let mut y = instructions_y - spacing::MD - line_height * lines.len() as f32;
+            for line in &lines {
+                cx.draw_text(
+                    line,
+                    Point::new(cx.bounds.x() + spacing::XL, y),
+                    colors::WARNING,
+                    font_size::SM,
+                );
+                y += line_height;
+            }
+        }
+
         cx.draw_text(
             "[↑↓] Select  [Enter] Confirm  [Delete] Delete  [←→] Page  [Esc] Back",
             Point::new(cx.bounds.x() + spacing::XL, instructions_y),
@@ -340,7 +624,11 @@ impl Element for SaveLoadMenuElement {
         );
     }
 
-    fn handle_event(&mut self, event: &InputEvent, _bounds: Bounds) -> bool {
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        if let Some(scroll) = self.scroll_view_mut() {
+            scroll.set_viewport(Self::slots_viewport(bounds));
+        }
+
         match event {
             InputEvent::KeyDown { key, .. } => match key {
                 KeyCode::Up => {
@@ -367,6 +655,10 @@ impl Element for SaveLoadMenuElement {
                     self.delete_slot();
                     true
                 }
+                KeyCode::R => {
+                    self.restart_from_checkpoint();
+                    true
+                }
                 KeyCode::Escape => {
                     self.action_confirmed = Some(SaveLoadMenuAction::Back);
                     true
@@ -377,10 +669,13 @@ impl Element for SaveLoadMenuElement {
                 }
                 _ => false,
             },
-            InputEvent::MouseDown { .. } => {
-                // TODO: Handle mouse clicks on slot cards
-                false
-            }
+            // TODO: Handle mouse clicks on slot cards
+            InputEvent::MouseScroll { .. }
+            | InputEvent::MouseDown { .. }
+            | InputEvent::MouseMove { .. }
+            | InputEvent::MouseUp { .. } => self
+                .scroll_view_mut()
+                .is_some_and(|scroll| scroll.handle_scroll_event(event)),
             _ => false,
         }
     }
@@ -421,3 +716,163 @@ impl Element for SaveLoadMenuElement {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::SceneId;
+    use narrative_engine::save::SaveIssue;
+    use narrative_gui::framework::input::Modifiers;
+    use std::path::PathBuf;
+
+    fn menu(is_save_mode: bool) -> SaveLoadMenuElement {
+        let manager = SaveManager::new(PathBuf::from("target/test_saves_missing"));
+        SaveLoadMenuElement::new(
+            Arc::new(Mutex::new(manager)),
+            is_save_mode,
+            LayoutMode::List,
+        )
+        .with_slot_config(SaveSlotConfig {
+            autosave_slots: 2,
+            quicksave_slots: 1,
+            manual_slots: 8,
+            ..Default::default()
+        })
+    }
+
+    fn scroll_view(menu: &SaveLoadMenuElement) -> Option<&ScrollView> {
+        menu.children.first()?.as_any().downcast_ref()
+    }
+
+    fn press(menu: &mut SaveLoadMenuElement, key: KeyCode) {
+        let event = InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        };
+        menu.handle_event(&event, Bounds::new(0.0, 0.0, 100.0, 100.0));
+    }
+
+    #[test]
+    fn test_pages_are_split_by_section() {
+        let menu = menu(false);
+        let pages: Vec<_> = menu
+            .pages
+            .iter()
+            .map(|page| (page.kind, page.slots.clone()))
+            .collect();
+        assert_eq!(
+            pages,
+            vec![
+                (SaveSlotKind::Auto, 0..2),
+                (SaveSlotKind::Quick, 2..3),
+                (SaveSlotKind::Manual, 3..9),
+                (SaveSlotKind::Manual, 9..11),
+            ]
+        );
+        // Load mode starts at the first section
+        assert_eq!(menu.current_section(), Some(SaveSlotKind::Auto));
+        assert_eq!(menu.slot_label(2), "Quick 01");
+        assert_eq!(menu.slot_label(4), "Slot 02");
+    }
+
+    #[test]
+    fn test_protected_slots_cannot_be_saved_or_deleted() {
+        let mut menu = menu(true);
+        // Save mode opens the manual section
+        assert_eq!(menu.current_section(), Some(SaveSlotKind::Manual));
+        press(&mut menu, KeyCode::Enter);
+        assert_eq!(
+            menu.confirmed_action(),
+            Some(SaveLoadMenuAction::SaveToSlot(3))
+        );
+        menu.reset_confirmation();
+
+        // Quick save section
+        press(&mut menu, KeyCode::Left);
+        assert_eq!(menu.current_section(), Some(SaveSlotKind::Quick));
+        if let Some(info) = menu.all_slots.get_mut(2) {
+            info.exists = true;
+        }
+        press(&mut menu, KeyCode::Enter);
+        press(&mut menu, KeyCode::Delete);
+        assert_eq!(menu.confirmed_action(), None);
+    }
+
+    #[test]
+    fn test_restart_from_checkpoint_only_for_incompatible_saves() {
+        let mut menu = menu(false);
+        if let Some(info) = menu.all_slots.get_mut(0) {
+            info.exists = true;
+            info.has_checkpoint = true;
+        }
+        press(&mut menu, KeyCode::R);
+        assert_eq!(menu.confirmed_action(), None);
+
+        if let Some(info) = menu.all_slots.get_mut(0) {
+            info.issues = vec![SaveIssue::MissingScene(SceneId::new("removed"))];
+        }
+        press(&mut menu, KeyCode::R);
+        assert_eq!(
+            menu.confirmed_action(),
+            Some(SaveLoadMenuAction::RestartFromCheckpoint(0))
+        );
+    }
+
+    #[test]
+    fn test_preview_follows_selected_slot() {
+        let mut menu = menu(false);
+        let slot = menu.selected_slot;
+        let snapshot = DisplaySnapshot {
+            background: Some("assets/backgrounds/room.png".to_string()),
+            ..Default::default()
+        };
+        if let Some(info) = menu.all_slots.get_mut(slot) {
+            info.display = snapshot.clone();
+        }
+        // Empty slots have nothing to preview
+        assert_eq!(menu.preview_snapshot(), None);
+
+        if let Some(info) = menu.all_slots.get_mut(slot) {
+            info.exists = true;
+        }
+        assert_eq!(menu.preview_snapshot(), Some(&snapshot));
+        assert!(menu.set_preview_texture(Some(3)));
+        assert!(!menu.set_preview_texture(Some(3)));
+
+        // The texture is only shown on the slot it was rendered for
+        press(&mut menu, KeyCode::Down);
+        assert_eq!(menu.preview_snapshot(), None);
+        assert!(menu.set_preview_texture(Some(3)));
+    }
+
+    #[test]
+    fn test_selection_scrolls_slots_into_view() {
+        let mut menu = menu(true);
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        let press = |menu: &mut SaveLoadMenuElement, key| {
+            let event = InputEvent::KeyDown {
+                key,
+                modifiers: Modifiers::none(),
+            };
+            menu.handle_event(&event, bounds);
+        };
+        let offset = |menu: &SaveLoadMenuElement| scroll_view(menu).map(ScrollView::offset);
+
+        // Six list cards don't fit between the header and the footer
+        assert_eq!(offset(&menu), Some(0.0));
+        for _ in 0..5 {
+            press(&mut menu, KeyCode::Down);
+        }
+        assert_eq!(menu.selected_slot, 8);
+        let scrolled = offset(&menu).unwrap_or_default();
+        assert!(scrolled > 0.0);
+        assert_eq!(
+            scroll_view(&menu).map(ScrollView::max_offset),
+            Some(scrolled)
+        );
+
+        // A new page starts at the top
+        press(&mut menu, KeyCode::Right);
+        assert_eq!(offset(&menu), Some(0.0));
+    }
+}
@@ -28,6 +28,12 @@ pub struct SaveSlotCard {
     is_save_mode: bool,
     /// Layout mode
     layout_mode: LayoutMode,
+    /// Label replacing the slot number (e.g. "Quick 01")
+    label: Option<String>,
+    /// Whether the slot is reserved for autosave or quick save
+    is_protected: bool,
+    /// Rendered preview of the saved screen
+    thumbnail_texture: Option<u64>,
     /// Animation context
     animation_context: AnimationContext,
 }
@@ -48,6 +54,9 @@ impl SaveSlotCard {
     const THUMBNAIL_WIDTH_GRID: f32 = 256.0;
     const THUMBNAIL_HEIGHT_GRID: f32 = 144.0;
 
+    /// Size of the compatibility warning badge
+    const BADGE_SIZE: f32 = 22.0;
+
     /// Create a new save slot card
     pub fn new(
         slot_info: SlotInfo,
@@ -62,21 +71,88 @@ impl SaveSlotCard {
             is_selected,
             is_save_mode,
             layout_mode,
+            label: None,
+            is_protected: false,
+            thumbnail_texture: None,
             animation_context: AnimationContext::default(),
         }
     }
 
+    /// Show a label instead of the slot number
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Mark the slot as reserved (can't be saved to or deleted manually)
+    pub fn with_protected(mut self, is_protected: bool) -> Self {
+        self.is_protected = is_protected;
+        self
+    }
+
+    /// Show a rendered preview of the saved screen as the thumbnail
+    pub fn with_thumbnail_texture(mut self, texture_id: Option<u64>) -> Self {
+        self.thumbnail_texture = texture_id;
+        self
+    }
+
+    /// Card size (width, height) in a layout mode, without the margin
+    pub fn card_size(layout_mode: LayoutMode) -> (f32, f32) {
+        match layout_mode {
+            LayoutMode::List => (Self::CARD_WIDTH_LIST, Self::CARD_HEIGHT_LIST),
+            LayoutMode::Grid => (Self::CARD_WIDTH_GRID, Self::CARD_HEIGHT_GRID),
+        }
+    }
+
+    /// Slot label in list layout
+    fn list_label(&self) -> String {
+        self.label
+            .clone()
+            .unwrap_or_else(|| format!("Slot {:02}", self.slot_info.slot + 1))
+    }
+
+    /// Slot label in grid layout
+    fn grid_label(&self) -> String {
+        self.label
+            .clone()
+            .unwrap_or_else(|| format!("#{:02}", self.slot_info.slot + 1))
+    }
+
     /// Set animation context
     pub fn with_animation_context(mut self, ctx: AnimationContext) -> Self {
         self.animation_context = ctx;
         self
     }
 
+    /// Paint a warning badge for saves that don't match the current scenario
+    fn paint_issue_badge(&self, cx: &mut PaintContext, bounds: Bounds) {
+        if !self.slot_info.has_issues() {
+            return;
+        }
+        let badge = Bounds {
+            origin: Point::new(
+                bounds.x() + bounds.width() - Self::BADGE_SIZE - spacing::SM,
+                bounds.y() + bounds.height() - Self::BADGE_SIZE - spacing::SM,
+            ),
+            size: Size::new(Self::BADGE_SIZE, Self::BADGE_SIZE),
+        };
+        cx.fill_rounded_rect(badge, colors::WARNING, radius::SM);
+        cx.draw_text(
+            "!",
+            Point::new(badge.x() + Self::BADGE_SIZE / 2.0 - 3.0, badge.y() + 2.0),
+            colors::BG_DARKEST,
+            font_size::MD,
+        );
+    }
+
     /// Paint thumbnail (or placeholder)
     fn paint_thumbnail(&self, cx: &mut PaintContext, bounds: Bounds) {
-        // For now, always draw placeholder
-        // TODO: Load actual thumbnail texture when available
-        self.paint_thumbnail_placeholder(cx, bounds);
+        match self.thumbnail_texture {
+            Some(texture_id) if self.slot_info.exists => {
+                cx.draw_texture(texture_id, bounds, 1.0);
+            }
+            _ => self.paint_thumbnail_placeholder(cx, bounds),
+        }
     }
 
     /// Paint thumbnail placeholder
@@ -116,7 +192,7 @@ impl SaveSlotCard {
 
         if self.slot_info.exists {
             // Slot number
-            let slot_text = format!("Slot {:02}", self.slot_info.slot + 1);
+            let slot_text = self.list_label();
             cx.draw_text(
                 &slot_text,
                 Point::new(info_x, info_y),
@@ -150,7 +226,7 @@ impl SaveSlotCard {
             );
         } else {
             // Empty slot
-            let empty_text = format!("Slot {:02} - Empty", self.slot_info.slot + 1);
+            let empty_text = format!("{} - Empty", self.list_label());
             cx.draw_text(
                 &empty_text,
                 Point::new(info_x, info_y + 40.0),
@@ -165,7 +241,14 @@ impl SaveSlotCard {
         let button_x = bounds.x() + bounds.width() - 120.0;
         let button_y = bounds.y() + spacing::MD;
 
-        if self.is_save_mode {
+        if self.is_save_mode && self.is_protected {
+            cx.draw_text(
+                "[Locked]",
+                Point::new(button_x, button_y),
+                colors::TEXT_SECONDARY,
+                font_size::MD,
+            );
+        } else if self.is_save_mode {
             cx.draw_text(
                 "[Save]",
                 Point::new(button_x, button_y),
@@ -179,12 +262,14 @@ impl SaveSlotCard {
                 colors::ACCENT_PRIMARY,
                 font_size::MD,
             );
-            cx.draw_text(
-                "[Delete]",
-                Point::new(button_x, button_y + 25.0),
-                colors::TEXT_SECONDARY,
-                font_size::SM,
-            );
+            if !self.is_protected {
+                cx.draw_text(
+                    "[Delete]",
+                    Point::new(button_x, button_y + 25.0),
+                    colors::TEXT_SECONDARY,
+                    font_size::SM,
+                );
+            }
         }
     }
 
@@ -206,7 +291,7 @@ impl SaveSlotCard {
         let info_y = thumbnail_bounds.y() + thumbnail_bounds.height() + spacing::SM;
 
         if self.slot_info.exists {
-            let slot_text = format!("#{:02}", self.slot_info.slot + 1);
+            let slot_text = self.grid_label();
             cx.draw_text(
                 &slot_text,
                 Point::new(bounds.x() + spacing::SM, info_y),
@@ -228,7 +313,7 @@ impl SaveSlotCard {
                 font_size::XS,
             );
         } else {
-            let empty_text = format!("#{:02} Empty", self.slot_info.slot + 1);
+            let empty_text = format!("{} Empty", self.grid_label());
             cx.draw_text(
                 &empty_text,
                 Point::new(bounds.x() + spacing::SM, info_y + 20.0),
@@ -255,10 +340,7 @@ impl Element for SaveSlotCard {
     fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
         use taffy::prelude::*;
 
-        let (width, height) = match self.layout_mode {
-            LayoutMode::List => (Self::CARD_WIDTH_LIST, Self::CARD_HEIGHT_LIST),
-            LayoutMode::Grid => (Self::CARD_WIDTH_GRID, Self::CARD_HEIGHT_GRID),
-        };
+        let (width, height) = Self::card_size(self.layout_mode);
 
         Style {
             display: Display::Flex,
@@ -287,6 +369,7 @@ impl Element for SaveSlotCard {
             LayoutMode::List => self.paint_list_layout(cx, bounds),
             LayoutMode::Grid => self.paint_grid_layout(cx, bounds),
         }
+        self.paint_issue_badge(cx, bounds);
 
         // Selection indicator
         if self.is_selected {
@@ -0,0 +1,952 @@
+//! Settings menu UI element
+//!
+//! Settings are grouped into tabs: Display, Audio, Text, Controls and
+//! Accessibility, and each setting's widget is generated from its entry in
+//! the settings schema (`narrative_core::config::SETTINGS_SCHEMA`). Tabs switch
+//! with a click, Q / E, Page Up / Page Down or Ctrl+Tab / Ctrl+Shift+Tab.
+//! Typing in the search field (click it or press Ctrl+F) lists the settings
+//! from every tab whose name contains the text.
+//!
+//! The Controls tab rebinds in-game actions: click an action, then press the
+//! key or mouse button to use. The new input replaces the action's bindings
+//! on the same device and is unbound from other actions; Escape cancels.
+//! Gamepad buttons are rebound in `settings.ron`.
+//!
+//! The Text tab also edits the player profile (name and pronouns) that new
+//! games receive as `player.*` variables.
+//!
+//! Settings are persisted in RON format to `assets/config/settings.ron`.
+
+mod registry;
+
+pub use narrative_core::config::SettingsCategory;
+
+use narrative_core::config::{
+    Binding, COMMON_RESOLUTIONS, InputAction, MouseBinding, SettingType, UserSettings,
+};
+use narrative_engine::AudioQueue;
+use narrative_gui::components::common::{
+    Button, ButtonVariant, DropdownItem, DropdownMenu, TextInput,
+};
+use narrative_gui::framework::animation::AnimationContext;
+use narrative_gui::framework::element::{
+    Element, ElementId, LayoutContext, PaintContext, WindowOperation,
+};
+use narrative_gui::framework::input::{InputEvent, KeyCode, Modifiers, MouseButton};
+use narrative_gui::framework::layout::{Bounds, Point};
+use narrative_gui::theme::{colors, common, font_size, radius, spacing, typography};
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Width of a settings row (slider, toggle or button)
+const ROW_WIDTH: f32 = 400.0;
+
+/// Height of a settings row
+const ROW_HEIGHT: f32 = 40.0;
+
+/// Width of the back button
+const BACK_BUTTON_WIDTH: f32 = 100.0;
+
+/// Vertical gap between settings rows
+const ROW_GAP: f32 = spacing::MD;
+
+/// Width of a tab button
+const TAB_WIDTH: f32 = 140.0;
+
+/// Height of the tab buttons and the search field
+const HEADER_ROW_HEIGHT: f32 = 36.0;
+
+/// Top of the tab bar, below the title
+const TAB_BAR_TOP: f32 = spacing::XXL + font_size::TITLE + spacing::LG;
+
+/// Top of the search field, below the tab bar
+const SEARCH_TOP: f32 = TAB_BAR_TOP + HEADER_ROW_HEIGHT + spacing::MD;
+
+/// Height of the title, tab bar and search field above the settings rows
+const HEADER_HEIGHT: f32 = SEARCH_TOP + HEADER_ROW_HEIGHT + spacing::LG;
+
+/// Longest search text accepted
+const MAX_SEARCH_LEN: usize = 40;
+
+/// Shared state for settings menu (single mutex reduces lock contention and complexity)
+struct SettingsState {
+    settings: UserSettings,
+    settings_changed: bool,
+    back_pressed: bool,
+    open_resolution_dropdown: bool,
+    /// Widgets show stale values (e.g. after a resolution or binding change)
+    rebuild_children: bool,
+    /// Action waiting for the key or mouse button to bind
+    capturing_binding: Option<InputAction>,
+    window_operations: Vec<WindowOperation>,
+}
+
+/// Settings menu element
+pub struct SettingsMenuElement {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    /// Shared state (single mutex for all state)
+    state: Arc<Mutex<SettingsState>>,
+    /// Audio command queue for real-time volume control
+    audio_queue: AudioQueue,
+    /// Child elements (the visible settings, then the back button)
+    children: Vec<Box<dyn Element>>,
+    /// Whether children need rebuilding
+    children_dirty: bool,
+    /// Selected tab
+    category: SettingsCategory,
+    /// Search text; when not blank, matching settings from every tab are shown
+    search: String,
+    /// Typing goes to the search field
+    search_focused: bool,
+    /// Animation context for global settings
+    animation_context: AnimationContext,
+    /// Component-specific animation override (None = follow global)
+    animations_enabled: Option<bool>,
+    /// Resolution dropdown menu
+    resolution_dropdown: DropdownMenu,
+    /// Child index of the resolution button, if it is shown
+    resolution_button_index: Option<usize>,
+    /// Resolution button bounds (for dropdown positioning)
+    resolution_button_bounds: Option<Bounds>,
+    /// Child index of the player name field, if it is shown
+    name_input_index: Option<usize>,
+}
+
+impl SettingsMenuElement {
+    /// Create a new settings menu
+    pub fn new(settings: UserSettings, audio_queue: AudioQueue) -> Self {
+        let state = Arc::new(Mutex::new(SettingsState {
+            settings,
+            settings_changed: false,
+            back_pressed: false,
+            open_resolution_dropdown: false,
+            rebuild_children: false,
+            capturing_binding: None,
+            window_operations: Vec::new(),
+        }));
+
+        // Setup resolution dropdown callback
+        let state_clone = Arc::clone(&state);
+        let resolution_dropdown = DropdownMenu::new().with_on_item_click(move |item_id| {
+            // Parse resolution from item_id (format: "WIDTHxHEIGHT")
+            if let Some((width_str, height_str)) = item_id.split_once('x')
+                && let (Ok(width), Ok(height)) =
+                    (width_str.parse::<u32>(), height_str.parse::<u32>())
+            {
+                tracing::info!(
+                    "Resolution changed to: {}x{} (applying immediately)",
+                    width,
+                    height
+                );
+                if let Ok(mut state) = state_clone.lock() {
+                    state.settings.display.resolution = (width, height);
+                    state.settings_changed = true;
+                    state.rebuild_children = true;
+                    state
+                        .window_operations
+                        .push(WindowOperation::Resize { width, height });
+                    tracing::debug!("Settings updated: resolution = {}x{}", width, height);
+                } else {
+                    tracing::warn!("Failed to lock state for resolution update");
+                }
+            }
+        });
+
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            state,
+            audio_queue,
+            children: Vec::new(),
+            children_dirty: true,
+            category: SettingsCategory::default(),
+            search: String::new(),
+            search_focused: false,
+            animation_context: AnimationContext::default(),
+            animations_enabled: None,
+            resolution_dropdown,
+            resolution_button_index: None,
+            resolution_button_bounds: None,
+            name_input_index: None,
+        }
+    }
+
+    /// Set the animation context
+    pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
+        self.animation_context = context;
+        self
+    }
+
+    /// Set component-specific animation override
+    pub fn with_animations_enabled(mut self, enabled: impl Into<Option<bool>>) -> Self {
+        self.animations_enabled = enabled.into();
+        self
+    }
+
+    /// Set the tab shown first
+    pub fn with_category(mut self, category: SettingsCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Selected tab
+    pub fn category(&self) -> SettingsCategory {
+        self.category
+    }
+
+    /// Search text
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    /// Check if settings have changed and return them if so (also clears the changed flag)
+    pub fn take_settings_if_changed(&self) -> Option<UserSettings> {
+        let mut state = self.state.lock().ok()?;
+        if state.settings_changed {
+            state.settings_changed = false;
+            Some(state.settings.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Check if back button was pressed (also clears the flag)
+    pub fn take_back_pressed(&self) -> bool {
+        let mut state = match self.state.lock() {
+            Ok(guard) => guard,
+            Err(e) => {
+                tracing::error!("Failed to lock state mutex: {}", e);
+                return false;
+            }
+        };
+        let result = state.back_pressed;
+        state.back_pressed = false;
+        result
+    }
+
+    /// Take window operations (called by parent to get queued operations)
+    pub fn take_window_operations(&mut self) -> Vec<WindowOperation> {
+        let mut operations = if let Ok(mut state) = self.state.lock() {
+            std::mem::take(&mut state.window_operations)
+        } else {
+            tracing::warn!("Failed to lock state for window operations");
+            Vec::new()
+        };
+        // IME on/off for the player name field
+        if let Some(input) = self.name_input_mut() {
+            operations.extend(input.take_window_operations());
+        }
+        operations
+    }
+
+    /// The player name field, if it is shown
+    fn name_input_mut(&mut self) -> Option<&mut TextInput> {
+        let index = self.name_input_index?;
+        self.children
+            .get_mut(index)?
+            .as_any_mut()
+            .downcast_mut::<TextInput>()
+    }
+
+    /// Copy the name typed in the player name field into the settings
+    fn sync_profile_name(&mut self) {
+        let Some(name) = self
+            .name_input_mut()
+            .map(|input| input.text().trim().to_string())
+        else {
+            return;
+        };
+        if let Ok(mut state) = self.state.lock()
+            && state.settings.profile.name != name
+        {
+            state.settings.profile.name = name;
+            state.settings_changed = true;
+        }
+    }
+
+    /// Send an event to the player name field while it has focus
+    ///
+    /// Keys and typed text go to the field, so Q / E and the other shortcuts
+    /// don't act on the menu; Escape only leaves the field.
+    fn handle_name_input_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        let field_bounds = self
+            .name_input_index
+            .and_then(|index| self.child_bounds(bounds).get(index).copied());
+        let Some(field_bounds) = field_bounds else {
+            return false;
+        };
+        let Some(input) = self.name_input_mut().filter(|input| input.is_focused()) else {
+            return false;
+        };
+
+        let handled = match event {
+            InputEvent::KeyDown {
+                key: KeyCode::Escape,
+                ..
+            } => {
+                input.set_focused(false);
+                true
+            }
+            InputEvent::KeyDown { .. } | InputEvent::CharInput { .. } => {
+                input.handle_event(event, field_bounds);
+                true
+            }
+            _ => input.handle_event(event, field_bounds),
+        };
+        self.sync_profile_name();
+        handled
+    }
+
+    /// Show a tab, clearing the search
+    fn select_category(&mut self, category: SettingsCategory) {
+        self.category = category;
+        self.search.clear();
+        self.search_focused = false;
+        self.resolution_dropdown.close();
+        self.children_dirty = true;
+    }
+
+    /// Replace the search text
+    fn set_search(&mut self, search: String) {
+        if search != self.search {
+            self.search = search;
+            self.resolution_dropdown.close();
+            self.children_dirty = true;
+        }
+    }
+
+    /// Build child elements
+    fn rebuild_children(&mut self) {
+        self.children.clear();
+        self.resolution_button_index = None;
+
+        for setting in registry::visible_settings(self.category, &self.search) {
+            if setting.kind == SettingType::Resolution {
+                self.resolution_button_index = Some(self.children.len());
+            }
+            let widget = registry::build_widget(self, setting);
+            self.children.push(widget);
+        }
+        for action in registry::visible_bindings(self.category, &self.search) {
+            let widget = registry::build_binding_widget(self, action);
+            self.children.push(widget);
+        }
+        self.name_input_index = None;
+        if registry::profile_visible(self.category, &self.search) {
+            self.name_input_index = Some(self.children.len());
+            let name_widget = registry::build_profile_name_widget(self);
+            self.children.push(name_widget);
+            let pronoun_widget = registry::build_pronoun_widget(self);
+            self.children.push(pronoun_widget);
+        }
+
+        // --- Back Button ---
+        let state_arc = Arc::clone(&self.state);
+        let back_button = Button::new("Back")
+            .with_variant(ButtonVariant::Secondary)
+            .with_on_click(move || {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.back_pressed = true;
+                }
+            });
+
+        self.children.push(Box::new(back_button));
+
+        self.children_dirty = false;
+    }
+
+    /// Bounds of each tab button, centered under the title
+    fn tab_bounds(bounds: Bounds) -> impl Iterator<Item = (SettingsCategory, Bounds)> {
+        let count = SettingsCategory::ALL.len() as f32;
+        let bar_width = TAB_WIDTH * count + spacing::SM * (count - 1.0);
+        let start_x = bounds.x() + (bounds.width() - bar_width) / 2.0;
+        let y = bounds.y() + TAB_BAR_TOP;
+        SettingsCategory::ALL
+            .into_iter()
+            .enumerate()
+            .map(move |(i, category)| {
+                let x = start_x + (TAB_WIDTH + spacing::SM) * i as f32;
+                (category, Bounds::new(x, y, TAB_WIDTH, HEADER_ROW_HEIGHT))
+            })
+    }
+
+    /// Bounds of the search field
+    fn search_bounds(bounds: Bounds) -> Bounds {
+        Bounds::new(
+            bounds.x() + (bounds.width() - ROW_WIDTH) / 2.0,
+            bounds.y() + SEARCH_TOP,
+            ROW_WIDTH,
+            HEADER_ROW_HEIGHT,
+        )
+    }
+
+    /// Bounds of each child: the settings rows, then the back button
+    ///
+    /// Mirrors the centered flex column built in `layout`.
+    fn child_bounds(&self, bounds: Bounds) -> Vec<Bounds> {
+        let rows = self.children.len();
+        if rows == 0 {
+            return Vec::new();
+        }
+
+        let content_x = bounds.x() + spacing::XXL;
+        let content_y = bounds.y() + HEADER_HEIGHT;
+        let content_width = bounds.width() - spacing::XXL * 2.0;
+        let content_height = bounds.height() - HEADER_HEIGHT - spacing::XXL;
+
+        let total_content_height =
+            ROW_HEIGHT * rows as f32 + ROW_GAP * rows.saturating_sub(1) as f32;
+
+        // When the rows don't fit, the flex layout shrinks every row by the
+        // same amount instead of letting them overflow, so do the same here
+        let shrink = (total_content_height - content_height).max(0.0) / rows as f32;
+        let row_height = ROW_HEIGHT - shrink;
+        let total_content_height = total_content_height.min(content_height);
+
+        // Center vertically in content area
+        let mut y = content_y + (content_height - total_content_height) / 2.0;
+        let back_button = rows - 1;
+        (0..rows)
+            .map(|i| {
+                let width = if i == back_button {
+                    BACK_BUTTON_WIDTH
+                } else {
+                    ROW_WIDTH
+                };
+                let row = Bounds::new(
+                    content_x + (content_width - width) / 2.0,
+                    y,
+                    width,
+                    row_height,
+                );
+                y += row_height + ROW_GAP;
+                row
+            })
+            .collect()
+    }
+
+    /// Action waiting for an input to bind, if any
+    pub fn capturing_binding(&self) -> Option<InputAction> {
+        self.state.lock().ok()?.capturing_binding
+    }
+
+    /// Bind the next key or mouse button press to the action being captured
+    ///
+    /// Returns false when no action is being captured. While capturing, every
+    /// event is consumed so the pressed input doesn't also act on the menu.
+    fn capture_binding(&mut self, event: &InputEvent) -> bool {
+        let Ok(mut state) = self.state.lock() else {
+            return false;
+        };
+        let Some(action) = state.capturing_binding else {
+            return false;
+        };
+
+        let binding = match event {
+            InputEvent::KeyDown {
+                key: KeyCode::Escape,
+                ..
+            } => None,
+            InputEvent::KeyDown { key, .. } if *key != KeyCode::Unknown => {
+                Some(Binding::key(key.name()))
+            }
+            InputEvent::MouseDown { button, .. } => match button {
+                MouseButton::Left => Some(Binding::Mouse(MouseBinding::Left)),
+                MouseButton::Right => Some(Binding::Mouse(MouseBinding::Right)),
+                MouseButton::Middle => Some(Binding::Mouse(MouseBinding::Middle)),
+                _ => return true,
+            },
+            _ => return true,
+        };
+
+        if let Some(binding) = binding {
+            tracing::info!("Bound {} to {:?}", binding.label(), action);
+            state.settings.bindings.rebind(action, binding);
+            state.settings_changed = true;
+        }
+        state.capturing_binding = None;
+        self.children_dirty = true;
+        true
+    }
+
+    /// Handle tab switching and search field keys
+    fn handle_key(&mut self, key: KeyCode, modifiers: Modifiers) -> bool {
+        if self.search_focused {
+            match key {
+                KeyCode::Backspace => {
+                    let mut search = self.search.clone();
+                    search.pop();
+                    self.set_search(search);
+                    return true;
+                }
+                KeyCode::Escape => {
+                    // Clear the search first; a second Escape leaves the menu
+                    self.search_focused = false;
+                    self.set_search(String::new());
+                    return true;
+                }
+                KeyCode::Enter => {
+                    self.search_focused = false;
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        match key {
+            KeyCode::F if modifiers.ctrl => {
+                self.search_focused = true;
+                true
+            }
+            KeyCode::Tab if modifiers.ctrl && modifiers.shift => {
+                self.select_category(self.category.previous());
+                true
+            }
+            KeyCode::Tab if modifiers.ctrl => {
+                self.select_category(self.category.next());
+                true
+            }
+            KeyCode::PageUp => {
+                self.select_category(self.category.previous());
+                true
+            }
+            KeyCode::PageDown => {
+                self.select_category(self.category.next());
+                true
+            }
+            // Q and E are typed into the search field while it has focus
+            KeyCode::Q if !self.search_focused => {
+                self.select_category(self.category.previous());
+                true
+            }
+            KeyCode::E if !self.search_focused => {
+                self.select_category(self.category.next());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Paint the tab bar
+    fn paint_tabs(&self, cx: &mut PaintContext) {
+        let searching = !self.search.trim().is_empty();
+        for (category, tab) in Self::tab_bounds(cx.bounds) {
+            let selected = category == self.category && !searching;
+            let (background, text_color) = if selected {
+                (colors::SIDEBAR_ITEM_ACTIVE, colors::TEXT_ACCENT)
+            } else {
+                (colors::BG_ELEVATED, colors::TEXT_SECONDARY)
+            };
+            cx.fill_rounded_rect(tab, background, radius::MD);
+
+            let label = category.label();
+            let text_x = tab.x()
+                + (tab.width() - label.len() as f32 * font_size::MD * common::CHAR_WIDTH_RATIO)
+                    / 2.0;
+            let text_y = tab.y() + tab.height() / 2.0 + font_size::MD * typography::BASELINE_OFFSET;
+            cx.draw_text(label, Point::new(text_x, text_y), text_color, font_size::MD);
+        }
+    }
+
+    /// Paint the search field
+    fn paint_search(&self, cx: &mut PaintContext) {
+        let field = Self::search_bounds(cx.bounds);
+        cx.fill_rounded_rect(field, colors::BG_PANEL, radius::MD);
+        let border = if self.search_focused {
+            colors::BORDER_ACCENT
+        } else {
+            colors::BORDER_LIGHT
+        };
+        cx.stroke_rect(field, border, common::BORDER_THICKNESS);
+
+        let (text, color) = if self.search_focused {
+            (format!("{}|", self.search), colors::TEXT_PRIMARY)
+        } else if self.search.is_empty() {
+            ("Search settings (Ctrl+F)".to_string(), colors::TEXT_MUTED)
+        } else {
+            (self.search.clone(), colors::TEXT_PRIMARY)
+        };
+        let text_y = field.y() + field.height() / 2.0 + font_size::MD * typography::BASELINE_OFFSET;
+        cx.draw_text(
+            &text,
+            Point::new(field.x() + spacing::SM, text_y),
+            color,
+            font_size::MD,
+        );
+    }
+}
+
+impl Element for SettingsMenuElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Rebuild children if needed
+        if self.children_dirty {
+            self.rebuild_children();
+        }
+
+        Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            justify_content: Some(JustifyContent::Center),
+            size: Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            gap: Size {
+                width: LengthPercentage::length(0.0),
+                height: LengthPercentage::length(ROW_GAP),
+            },
+            // The title, tab bar and search field are painted above the rows
+            padding: taffy::Rect {
+                left: LengthPercentage::length(spacing::XXL),
+                right: LengthPercentage::length(spacing::XXL),
+                top: LengthPercentage::length(HEADER_HEIGHT),
+                bottom: LengthPercentage::length(spacing::XXL),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let bounds = cx.bounds;
+
+        // Background
+        cx.fill_rect(bounds, colors::BG_DARKEST);
+
+        // Title
+        let title = "Settings";
+        let title_x =
+            bounds.x() + (bounds.width() - title.len() as f32 * font_size::TITLE * 0.6) / 2.0;
+        let title_y = bounds.y() + spacing::XXL + font_size::TITLE;
+        cx.draw_text(
+            title,
+            Point::new(title_x, title_y),
+            colors::TEXT_PRIMARY,
+            font_size::TITLE,
+        );
+
+        self.paint_tabs(cx);
+        self.paint_search(cx);
+
+        // Only the back button: nothing matches the search
+        if !self.children_dirty && self.children.len() <= 1 {
+            let message = format!("No settings match \"{}\"", self.search.trim());
+            let message_x = bounds.x()
+                + (bounds.width()
+                    - message.chars().count() as f32 * font_size::MD * common::CHAR_WIDTH_RATIO)
+                    / 2.0;
+            cx.draw_text(
+                &message,
+                Point::new(message_x, bounds.y() + HEADER_HEIGHT + font_size::MD),
+                colors::TEXT_MUTED,
+                font_size::MD,
+            );
+        }
+
+        // Paint dropdown overlay (must be last to render on top)
+        if self.resolution_dropdown.is_open() {
+            self.resolution_dropdown.paint_overlay(cx);
+        }
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        // Rebuild if needed
+        if self.children_dirty {
+            return false; // Will rebuild on next layout
+        }
+
+        // Check if dropdown should be opened
+        if let Ok(mut state) = self.state.lock()
+            && state.open_resolution_dropdown
+        {
+            state.open_resolution_dropdown = false;
+            if let Some(button_bounds) = self.resolution_button_bounds {
+                // Create dropdown items from common resolutions
+                let items: Vec<DropdownItem> = COMMON_RESOLUTIONS
+                    .iter()
+                    .map(|(width, height, label)| {
+                        DropdownItem::new(format!("{}x{}", width, height), *label)
+                    })
+                    .collect();
+
+                self.resolution_dropdown.open(button_bounds, items);
+            }
+        }
+
+        // A binding being captured takes the next key or mouse button
+        if self.capture_binding(event) {
+            return true;
+        }
+
+        // Handle dropdown events first (if open, it has priority)
+        if self.resolution_dropdown.is_open()
+            && self.resolution_dropdown.handle_event(event, bounds)
+        {
+            return true;
+        }
+
+        // The player name field takes keys and text while it has focus
+        if self.handle_name_input_event(event, bounds) {
+            return true;
+        }
+
+        // Tabs and the search field
+        let handled = match event {
+            InputEvent::KeyDown { key, modifiers } => self.handle_key(*key, *modifiers),
+            InputEvent::CharInput { character } if self.search_focused => {
+                if !character.is_control() && self.search.chars().count() < MAX_SEARCH_LEN {
+                    let mut search = self.search.clone();
+                    search.push(*character);
+                    self.set_search(search);
+                }
+                true
+            }
+            InputEvent::MouseDown { position, .. } => {
+                if let Some((category, _)) =
+                    Self::tab_bounds(bounds).find(|(_, tab)| tab.contains(*position))
+                {
+                    self.select_category(category);
+                    true
+                } else {
+                    self.search_focused = Self::search_bounds(bounds).contains(*position);
+                    self.search_focused
+                }
+            }
+            _ => false,
+        };
+        if handled {
+            return true;
+        }
+
+        // Forward events to children
+        let child_bounds = self.child_bounds(bounds);
+        self.resolution_button_bounds = self
+            .resolution_button_index
+            .and_then(|index| child_bounds.get(index).copied()); // Save for dropdown positioning
+
+        for (i, child_bounds) in child_bounds.iter().enumerate() {
+            if let Some(child) = self.children.get_mut(i)
+                && child.handle_event(event, *child_bounds)
+            {
+                self.sync_profile_name();
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        // A new tab or search needs a relayout
+        let mut needs_update = self.children_dirty;
+
+        // Check if a resolution or binding changed and children need rebuilding
+        if let Ok(mut state) = self.state.lock()
+            && state.rebuild_children
+        {
+            state.rebuild_children = false;
+            self.children_dirty = true;
+            needs_update = true;
+        }
+
+        for child in &mut self.children {
+            if child.tick(delta) {
+                needs_update = true;
+            }
+        }
+        needs_update
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn children(&self) -> &[Box<dyn Element>] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Element>] {
+        &mut self.children
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_engine::AudioManager;
+
+    fn menu() -> SettingsMenuElement {
+        let mut menu =
+            SettingsMenuElement::new(UserSettings::default(), AudioManager::disabled().queue());
+        menu.rebuild_children();
+        menu
+    }
+
+    fn key(menu: &mut SettingsMenuElement, key: KeyCode, modifiers: Modifiers) -> bool {
+        let handled = menu.handle_event(
+            &InputEvent::KeyDown { key, modifiers },
+            Bounds::new(0.0, 0.0, 1280.0, 720.0),
+        );
+        if menu.children_dirty {
+            menu.rebuild_children();
+        }
+        handled
+    }
+
+    fn type_text(menu: &mut SettingsMenuElement, text: &str) {
+        for character in text.chars() {
+            menu.handle_event(
+                &InputEvent::CharInput { character },
+                Bounds::new(0.0, 0.0, 1280.0, 720.0),
+            );
+            if menu.children_dirty {
+                menu.rebuild_children();
+            }
+        }
+    }
+
+    #[test]
+    fn test_tabs_show_their_settings() {
+        let mut menu = menu();
+        assert_eq!(menu.category(), SettingsCategory::Display);
+        // Fullscreen, resolution, animations, animation speed and back
+        assert_eq!(menu.children.len(), 5);
+        assert_eq!(menu.resolution_button_index, Some(1));
+
+        assert!(key(&mut menu, KeyCode::PageDown, Modifiers::none()));
+        assert_eq!(menu.category(), SettingsCategory::Audio);
+        assert_eq!(menu.children.len(), 5);
+        assert_eq!(menu.resolution_button_index, None);
+
+        let ctrl_shift = Modifiers {
+            ctrl: true,
+            shift: true,
+            ..Modifiers::none()
+        };
+        assert!(key(&mut menu, KeyCode::Tab, ctrl_shift));
+        assert!(key(&mut menu, KeyCode::Q, Modifiers::none()));
+        assert_eq!(menu.category(), SettingsCategory::Accessibility);
+    }
+
+    #[test]
+    fn test_search_filters_settings() {
+        let mut menu = menu();
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Modifiers::none()
+        };
+        assert!(key(&mut menu, KeyCode::F, ctrl));
+
+        // Q and E are typed rather than switching tabs
+        assert!(!key(&mut menu, KeyCode::E, Modifiers::none()));
+        type_text(&mut menu, "volume");
+        assert_eq!(menu.search(), "volume");
+        // Four volume sliders from the Audio tab, and back
+        assert_eq!(menu.children.len(), 5);
+
+        key(&mut menu, KeyCode::Backspace, Modifiers::none());
+        assert_eq!(menu.search(), "volum");
+
+        // Escape clears the search and shows the tab again
+        assert!(key(&mut menu, KeyCode::Escape, Modifiers::none()));
+        assert_eq!(menu.search(), "");
+        assert_eq!(menu.children.len(), 5);
+        assert_eq!(menu.category(), SettingsCategory::Display);
+
+        // Without focus, Escape is left for leaving the menu
+        assert!(!key(&mut menu, KeyCode::Escape, Modifiers::none()));
+    }
+
+    #[test]
+    fn test_rebind_action_from_controls_tab() {
+        let mut menu = menu().with_category(SettingsCategory::Controls);
+        menu.rebuild_children();
+        // Two skip toggles, a button per action, and back
+        assert_eq!(menu.children.len(), 2 + InputAction::ALL.len() + 1);
+
+        // Escape cancels without leaving the menu
+        menu.state.lock().unwrap().capturing_binding = Some(InputAction::Skip);
+        assert!(key(&mut menu, KeyCode::Escape, Modifiers::none()));
+        assert_eq!(menu.capturing_binding(), None);
+        assert!(menu.take_settings_if_changed().is_none());
+
+        // Q binds rather than switching tabs
+        menu.state.lock().unwrap().capturing_binding = Some(InputAction::Skip);
+        assert!(key(&mut menu, KeyCode::Q, Modifiers::none()));
+        assert_eq!(menu.category(), SettingsCategory::Controls);
+        let settings = menu.take_settings_if_changed().unwrap();
+        assert_eq!(
+            settings.bindings.action_for_key("Q"),
+            Some(InputAction::Skip)
+        );
+        assert_eq!(settings.bindings.action_for_key("S"), None);
+    }
+
+    #[test]
+    fn test_edit_player_profile_on_text_tab() {
+        let mut menu = menu().with_category(SettingsCategory::Text);
+        menu.rebuild_children();
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        let index = menu.name_input_index.unwrap();
+        let field = menu.child_bounds(bounds)[index];
+
+        assert!(menu.handle_event(
+            &InputEvent::MouseDown {
+                button: MouseButton::Left,
+                position: field.center(),
+                modifiers: Modifiers::none(),
+            },
+            bounds,
+        ));
+        // Q is typed into the name rather than switching tabs
+        assert!(key(&mut menu, KeyCode::Q, Modifiers::none()));
+        type_text(&mut menu, "Quinn");
+        assert_eq!(menu.category(), SettingsCategory::Text);
+        let settings = menu.take_settings_if_changed().unwrap();
+        assert_eq!(settings.profile.name, "Quinn");
+
+        // Escape leaves the field, not the menu
+        assert!(key(&mut menu, KeyCode::Escape, Modifiers::none()));
+        assert!(!key(&mut menu, KeyCode::Escape, Modifiers::none()));
+    }
+
+    #[test]
+    fn test_click_tab() {
+        let mut menu = menu();
+        let bounds = Bounds::new(0.0, 0.0, 1280.0, 720.0);
+        let (_, text_tab) = SettingsMenuElement::tab_bounds(bounds)
+            .find(|(category, _)| *category == SettingsCategory::Text)
+            .unwrap();
+
+        assert!(menu.handle_event(
+            &InputEvent::MouseDown {
+                button: narrative_gui::framework::input::MouseButton::Left,
+                position: text_tab.center(),
+                modifiers: Modifiers::none(),
+            },
+            bounds,
+        ));
+        assert_eq!(menu.category(), SettingsCategory::Text);
+    }
+}
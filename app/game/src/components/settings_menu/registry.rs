@@ -0,0 +1,303 @@
+//! Widgets for the settings declared in the settings schema
+//!
+//! Every setting in [`SETTINGS_SCHEMA`] gets a widget generated from its type:
+//! toggles become switches, ranges become sliders and the resolution becomes
+//! a button opening the resolution dropdown. The menu shows the settings of
+//! the selected tab, or every setting matching the search text.
+//!
+//! The Controls tab also lists a button per bindable action; clicking it
+//! waits for the key or mouse button to bind. The Text tab ends with the
+//! player profile: a name field and a button cycling the pronouns.
+
+use super::{ROW_WIDTH, SettingsMenuElement};
+use narrative_core::config::{
+    InputAction, SETTINGS_SCHEMA, SettingSchema, SettingType, SettingValue, SettingsCategory,
+    UserSettings,
+};
+use narrative_engine::AudioQueue;
+use narrative_gui::components::common::{
+    Button, ButtonVariant, Slider, TextInput, Toggle, ToggleStyle,
+};
+use narrative_gui::framework::element::Element;
+use narrative_gui::theme::font_size;
+use std::sync::Arc;
+
+/// Names of the player profile rows, matched by the search
+const PROFILE_LABELS: [&str; 2] = ["Player Name", "Pronouns"];
+
+/// Longest player name accepted
+const MAX_PLAYER_NAME_LEN: usize = 16;
+
+/// Settings to show: those matching `query` if it isn't blank, otherwise
+/// those on the `category` tab
+pub(super) fn visible_settings(
+    category: SettingsCategory,
+    query: &str,
+) -> impl Iterator<Item = &'static SettingSchema> {
+    let query = query.trim().to_string();
+    SETTINGS_SCHEMA.iter().filter(move |setting| {
+        if query.is_empty() {
+            setting.category == category
+        } else {
+            setting.matches(&query)
+        }
+    })
+}
+
+/// Actions whose bindings to show: those matching `query` if it isn't blank,
+/// otherwise all of them on the Controls tab
+pub(super) fn visible_bindings(
+    category: SettingsCategory,
+    query: &str,
+) -> impl Iterator<Item = InputAction> {
+    let query = query.trim().to_lowercase();
+    InputAction::ALL.into_iter().filter(move |action| {
+        if query.is_empty() {
+            category == SettingsCategory::Controls
+        } else {
+            action.label().to_lowercase().contains(&query)
+        }
+    })
+}
+
+/// Whether to show the player profile rows: if any matches `query` when it
+/// isn't blank, otherwise on the Text tab
+pub(super) fn profile_visible(category: SettingsCategory, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        category == SettingsCategory::Text
+    } else {
+        PROFILE_LABELS
+            .iter()
+            .any(|label| label.to_lowercase().contains(&query))
+    }
+}
+
+/// Build the field editing the player name
+///
+/// The menu copies the typed name into the settings (see
+/// `SettingsMenuElement::sync_profile_name`).
+pub(super) fn build_profile_name_widget(menu: &SettingsMenuElement) -> Box<dyn Element> {
+    let name = menu
+        .state
+        .lock()
+        .map(|state| state.settings.profile.name.clone())
+        .unwrap_or_default();
+    Box::new(
+        TextInput::new()
+            .with_text(name)
+            .with_placeholder(PROFILE_LABELS[0])
+            .with_max_chars(MAX_PLAYER_NAME_LEN)
+            .with_font_size(font_size::MD)
+            .with_width(ROW_WIDTH),
+    )
+}
+
+/// Build the button cycling the player's pronouns
+pub(super) fn build_pronoun_widget(menu: &SettingsMenuElement) -> Box<dyn Element> {
+    let pronoun = menu
+        .state
+        .lock()
+        .map(|state| state.settings.profile.pronoun)
+        .unwrap_or_default();
+    let state_arc = Arc::clone(&menu.state);
+    Box::new(
+        Button::new(format!("{}: {}", PROFILE_LABELS[1], pronoun.label()))
+            .with_variant(ButtonVariant::Secondary)
+            .with_width(ROW_WIDTH)
+            .with_on_click(move || {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.settings.profile.pronoun = state.settings.profile.pronoun.next();
+                    state.settings_changed = true;
+                    state.rebuild_children = true;
+                }
+            }),
+    )
+}
+
+/// Build the button rebinding an action
+pub(super) fn build_binding_widget(
+    menu: &SettingsMenuElement,
+    action: InputAction,
+) -> Box<dyn Element> {
+    let label = match menu.state.lock() {
+        Ok(state) if state.capturing_binding == Some(action) => format!(
+            "{}: press a key or mouse button (Esc cancels)",
+            action.label()
+        ),
+        Ok(state) => format!(
+            "{}: {}",
+            action.label(),
+            state.settings.bindings.describe(action)
+        ),
+        Err(_) => action.label().to_string(),
+    };
+    let state_arc = Arc::clone(&menu.state);
+    Box::new(
+        Button::new(label)
+            .with_variant(ButtonVariant::Secondary)
+            .with_width(ROW_WIDTH)
+            .with_on_click(move || {
+                if let Ok(mut state) = state_arc.lock() {
+                    state.capturing_binding = Some(action);
+                    state.rebuild_children = true;
+                }
+            }),
+    )
+}
+
+/// Build the widget editing a setting
+pub(super) fn build_widget(
+    menu: &SettingsMenuElement,
+    setting: &'static SettingSchema,
+) -> Box<dyn Element> {
+    let value = menu
+        .state
+        .lock()
+        .map(|state| setting.value(&state.settings))
+        .unwrap_or(setting.default);
+
+    match setting.kind {
+        SettingType::Toggle => Box::new(
+            Toggle::new(setting.label, value.as_bool().unwrap_or(false))
+                .with_style(ToggleStyle::Switch)
+                .with_width(ROW_WIDTH)
+                .with_on_change(store(menu, setting, SettingValue::Bool)),
+        ),
+        SettingType::Range { min, max, step } => {
+            let store = store(menu, setting, SettingValue::Number);
+            let live_apply = live_apply(setting.id);
+            let audio_queue = menu.audio_queue.clone();
+            Box::new(
+                Slider::new(setting.label, min, max)
+                    .with_value(value.as_number().unwrap_or(min))
+                    .with_step(step)
+                    .with_width(ROW_WIDTH)
+                    .with_on_change(move |value| {
+                        if let Some(apply) = live_apply {
+                            apply(&audio_queue, value);
+                        }
+                        store(value);
+                    }),
+            )
+        }
+        SettingType::Resolution => {
+            let mut display = UserSettings::default().display;
+            if let SettingValue::Resolution(width, height) = value {
+                display.resolution = (width, height);
+            }
+            let state_arc = Arc::clone(&menu.state);
+            Box::new(
+                Button::new(format!(
+                    "{}: {}",
+                    setting.label,
+                    display.resolution_display_name()
+                ))
+                .with_variant(ButtonVariant::Secondary)
+                .with_width(ROW_WIDTH)
+                .with_on_click(move || {
+                    if let Ok(mut state) = state_arc.lock() {
+                        state.open_resolution_dropdown = true;
+                    }
+                }),
+            )
+        }
+    }
+}
+
+/// Callback storing a widget's new value in the settings being edited
+fn store<T: 'static>(
+    menu: &SettingsMenuElement,
+    setting: &'static SettingSchema,
+    wrap: fn(T) -> SettingValue,
+) -> impl Fn(T) + Send + Sync + 'static {
+    let state_arc = Arc::clone(&menu.state);
+    move |value| {
+        if let Ok(mut state) = state_arc.lock() {
+            match setting.set_value(&mut state.settings, wrap(value)) {
+                Ok(()) => state.settings_changed = true,
+                Err(e) => tracing::warn!("Failed to change setting '{}': {}", setting.id, e),
+            }
+        }
+    }
+}
+
+/// Change applied as soon as a slider moves, for real-time feedback
+fn live_apply(id: &str) -> Option<fn(&AudioQueue, f32)> {
+    match id {
+        "audio.master_volume" => Some(AudioQueue::set_master_volume),
+        "audio.music_volume" => Some(AudioQueue::set_music_volume),
+        "audio.sound_volume" => Some(AudioQueue::set_sound_volume),
+        "audio.voice_volume" => Some(AudioQueue::set_voice_volume),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_tab_has_settings() {
+        for category in SettingsCategory::ALL {
+            assert!(
+                visible_settings(category, "").next().is_some(),
+                "{:?} tab is empty",
+                category
+            );
+        }
+    }
+
+    #[test]
+    fn test_search_matches_labels_across_tabs() {
+        let ids: Vec<_> = visible_settings(SettingsCategory::Display, " VOLUME ")
+            .map(|setting| setting.id)
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                "audio.master_volume",
+                "audio.music_volume",
+                "audio.sound_volume",
+                "audio.voice_volume"
+            ]
+        );
+        assert_eq!(
+            visible_settings(SettingsCategory::Audio, "xyzzy").count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_bindings_on_controls_tab_and_search() {
+        assert_eq!(
+            visible_bindings(SettingsCategory::Controls, "").count(),
+            InputAction::ALL.len()
+        );
+        assert_eq!(visible_bindings(SettingsCategory::Audio, "").count(), 0);
+        assert_eq!(
+            visible_bindings(SettingsCategory::Audio, "quick").collect::<Vec<_>>(),
+            [InputAction::QuickSave, InputAction::QuickLoad]
+        );
+    }
+
+    #[test]
+    fn test_profile_on_text_tab_and_search() {
+        assert!(profile_visible(SettingsCategory::Text, ""));
+        assert!(!profile_visible(SettingsCategory::Audio, ""));
+        assert!(profile_visible(SettingsCategory::Audio, "pronoun"));
+        assert!(!profile_visible(SettingsCategory::Text, "volume"));
+    }
+
+    #[test]
+    fn test_volume_sliders_apply_live() {
+        for setting in SETTINGS_SCHEMA {
+            assert_eq!(
+                live_apply(setting.id).is_some(),
+                setting.category == SettingsCategory::Audio,
+                "{}",
+                setting.id
+            );
+        }
+    }
+}
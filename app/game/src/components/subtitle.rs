@@ -0,0 +1,384 @@
+//! Subtitle overlay for narrated audio and long sound effects
+//!
+//! Shows the cues of a [`SubtitleTrack`] in time with playback, centered near
+//! the top of the scene so they don't collide with the dialogue box. Colors and
+//! sizes come from the UI theme (see [`SubtitleStyle::from_theme`]).
+
+use narrative_core::asset::ColorPalette;
+use narrative_core::{SubtitleTrack, UiThemeDef};
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::InputEvent;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, common, font_size, radius, spacing};
+use narrative_gui::{Color, Point, Size};
+use std::any::Any;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Subtitle appearance
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleStyle {
+    /// Font size
+    pub font_size: f32,
+    /// Text color
+    pub text_color: Color,
+    /// Background box color
+    pub background: Color,
+    /// Vertical position of the box top (0.0 = top, 1.0 = bottom)
+    pub vertical_position: f32,
+}
+
+impl Default for SubtitleStyle {
+    fn default() -> Self {
+        let mut background = colors::BG_DARKEST;
+        background.a = 0.75;
+        Self {
+            font_size: font_size::XL,
+            text_color: colors::TEXT_PRIMARY,
+            background,
+            vertical_position: 0.08,
+        }
+    }
+}
+
+impl SubtitleStyle {
+    /// Subtitle appearance for a UI theme
+    ///
+    /// The theme's subtitle settings win; unset colors come from its palette
+    /// (primary text, background) and anything else from the built-in look.
+    pub fn from_theme(theme: Option<&UiThemeDef>) -> Self {
+        let mut style = Self::default();
+        let Some(theme) = theme else {
+            return style;
+        };
+        if let Some(palette) = &theme.colors {
+            style.text_color = theme_color(palette.text_primary);
+            style.background = theme_color(palette.background);
+        }
+        if let Some(subtitles) = &theme.subtitles {
+            if let Some(font_size) = subtitles.font_size {
+                style.font_size = font_size;
+            }
+            if let Some(text_color) = subtitles.text_color {
+                style.text_color = theme_color(text_color);
+            }
+            if let Some(background) = subtitles.background {
+                style.background = theme_color(background);
+            }
+            if let Some(vertical_position) = subtitles.vertical_position {
+                style.vertical_position = vertical_position;
+            }
+        }
+        style
+    }
+}
+
+/// Convert a theme RGBA tuple (0-255) to a GUI color
+fn theme_color(rgba: (u8, u8, u8, u8)) -> Color {
+    let color = ColorPalette::to_color(rgba);
+    Color::new(color.r, color.g, color.b, color.a)
+}
+
+/// Subtitle overlay element
+pub struct SubtitleElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Track being played
+    track: Option<SubtitleTrack>,
+    /// Seconds since the track started
+    elapsed: f32,
+    /// Text of the active cues
+    text: Option<String>,
+    /// Whether subtitles are shown (accessibility setting)
+    enabled: bool,
+    /// Appearance
+    style: SubtitleStyle,
+}
+
+impl SubtitleElement {
+    /// Line height relative to font size
+    const LINE_HEIGHT: f32 = 1.4;
+
+    /// Create a subtitle overlay (enabled, nothing playing)
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            track: None,
+            elapsed: 0.0,
+            text: None,
+            enabled: true,
+            style: SubtitleStyle::default(),
+        }
+    }
+
+    /// Set whether subtitles are shown
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the subtitle appearance
+    pub fn with_style(mut self, style: SubtitleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Change the subtitle appearance (e.g. after a theme reload)
+    pub fn set_style(&mut self, style: SubtitleStyle) {
+        self.style = style;
+    }
+
+    /// Show or hide subtitles; disabling stops the current track
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.stop();
+        }
+    }
+
+    /// Whether subtitles are shown
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Start a track from the beginning, replacing the current one
+    ///
+    /// Ignored while subtitles are disabled.
+    pub fn play(&mut self, track: SubtitleTrack) {
+        if !self.enabled || track.is_empty() {
+            return;
+        }
+        self.text = track.text_at(0.0);
+        self.track = Some(track);
+        self.elapsed = 0.0;
+    }
+
+    /// Stop the current track
+    pub fn stop(&mut self) {
+        self.track = None;
+        self.text = None;
+        self.elapsed = 0.0;
+    }
+
+    /// Whether a track is playing
+    pub fn is_playing(&self) -> bool {
+        self.track.is_some()
+    }
+
+    /// Text currently shown
+    pub fn current_text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Advance playback; returns true if the shown text changed
+    fn advance(&mut self, delta: f32) -> bool {
+        let Some(track) = &self.track else {
+            return false;
+        };
+
+        self.elapsed += delta;
+        let text = track.text_at(self.elapsed);
+        let finished = self.elapsed >= track.duration();
+        let changed = text != self.text;
+
+        self.text = text;
+        if finished {
+            self.track = None;
+        }
+        changed
+    }
+}
+
+impl Default for SubtitleElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for SubtitleElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Overlay the whole scene
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let Some(text) = self.text.as_deref().filter(|_| self.enabled) else {
+            return;
+        };
+
+        let style = &self.style;
+        let line_height = style.font_size * Self::LINE_HEIGHT;
+        let lines: Vec<&str> = text.lines().collect();
+        let widest = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as f32
+            * style.font_size
+            * common::CHAR_WIDTH_RATIO;
+
+        let box_width = (widest + spacing::XL * 2.0).min(cx.bounds.size.width);
+        let box_height = lines.len() as f32 * line_height + spacing::MD * 2.0;
+        let box_bounds = Bounds {
+            origin: Point::new(
+                cx.bounds.origin.x + (cx.bounds.size.width - box_width) / 2.0,
+                cx.bounds.origin.y
+                    + (cx.bounds.size.height - box_height)
+                        * style.vertical_position.clamp(0.0, 1.0),
+            ),
+            size: Size::new(box_width, box_height),
+        };
+        cx.fill_rounded_rect(box_bounds, style.background, radius::MD);
+
+        for (i, line) in lines.iter().enumerate() {
+            let line_width =
+                line.chars().count() as f32 * style.font_size * common::CHAR_WIDTH_RATIO;
+            let position = Point::new(
+                box_bounds.origin.x + (box_width - line_width) / 2.0,
+                box_bounds.origin.y + spacing::MD + i as f32 * line_height + style.font_size,
+            );
+            cx.draw_text(line, position, style.text_color, style.font_size);
+        }
+    }
+
+    fn handle_event(&mut self, _event: &InputEvent, _bounds: Bounds) -> bool {
+        false
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.advance(delta.as_secs_f32())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::asset::{ButtonAssets, ChoiceAssets, DialogueBoxAssets, SubtitleTheme};
+
+    fn track() -> SubtitleTrack {
+        SubtitleTrack::parse_srt(
+            "1\n00:00:00,500 --> 00:00:01,500\nThunder rumbles\n\n2\n00:00:02,000 --> 00:00:03,000\nRain\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_subtitle_playback() {
+        let mut subtitles = SubtitleElement::new();
+        subtitles.play(track());
+        assert!(subtitles.is_playing());
+        assert_eq!(subtitles.current_text(), None);
+
+        assert!(subtitles.advance(0.5));
+        assert_eq!(subtitles.current_text(), Some("Thunder rumbles"));
+        assert!(!subtitles.advance(0.5));
+
+        assert!(subtitles.advance(0.6));
+        assert_eq!(subtitles.current_text(), None);
+
+        assert!(subtitles.advance(0.5));
+        assert_eq!(subtitles.current_text(), Some("Rain"));
+
+        assert!(subtitles.advance(1.0));
+        assert_eq!(subtitles.current_text(), None);
+        assert!(!subtitles.is_playing());
+    }
+
+    #[test]
+    fn test_subtitles_disabled() {
+        let mut subtitles = SubtitleElement::new().with_enabled(false);
+        subtitles.play(track());
+        assert!(!subtitles.is_playing());
+
+        subtitles.set_enabled(true);
+        subtitles.play(track());
+        assert!(subtitles.is_playing());
+
+        subtitles.set_enabled(false);
+        assert!(!subtitles.is_playing());
+        assert_eq!(subtitles.current_text(), None);
+    }
+
+    #[test]
+    fn test_style_from_theme() {
+        assert_eq!(SubtitleStyle::from_theme(None), SubtitleStyle::default());
+
+        let theme = UiThemeDef {
+            id: "dark".to_string(),
+            name: "Dark".to_string(),
+            dialogue_box: DialogueBoxAssets {
+                default: "box.png".to_string(),
+                variants: Default::default(),
+            },
+            buttons: ButtonAssets {
+                continue_idle: "a.png".to_string(),
+                continue_hover: "a.png".to_string(),
+                history_idle: "a.png".to_string(),
+                history_hover: "a.png".to_string(),
+                skip_idle: "a.png".to_string(),
+                skip_hover: "a.png".to_string(),
+                options_idle: "a.png".to_string(),
+                options_hover: "a.png".to_string(),
+            },
+            choices: ChoiceAssets {
+                idle: "a.png".to_string(),
+                hover: "a.png".to_string(),
+                disabled: "a.png".to_string(),
+            },
+            colors: Some(ColorPalette {
+                text_primary: (255, 255, 0, 255),
+                text_secondary: (200, 200, 200, 255),
+                accent: (0, 217, 192, 255),
+                background: (0, 0, 64, 200),
+            }),
+            fonts: None,
+            sounds: None,
+            quick_menu: None,
+            subtitles: Some(SubtitleTheme {
+                font_size: Some(30.0),
+                background: Some((0, 0, 0, 128)),
+                ..Default::default()
+            }),
+        };
+        let style = SubtitleStyle::from_theme(Some(&theme));
+        assert_eq!(style.font_size, 30.0);
+        assert_eq!(style.text_color, theme_color((255, 255, 0, 255)));
+        assert_eq!(style.background, theme_color((0, 0, 0, 128)));
+        assert_eq!(
+            style.vertical_position,
+            SubtitleStyle::default().vertical_position
+        );
+    }
+}
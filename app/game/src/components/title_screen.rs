@@ -4,6 +4,8 @@
 //! - New Game
 //! - Continue (only if save data exists)
 //! - Load
+//! - CG Gallery
+//! - Achievements (only if the game has any)
 //! - Settings
 //! - Exit
 //!
@@ -14,7 +16,7 @@ use narrative_gui::framework::animation::AnimationContext;
 use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
 use narrative_gui::framework::input::{InputEvent, KeyCode};
 use narrative_gui::framework::layout::Bounds;
-use narrative_gui::theme::colors;
+use narrative_gui::theme::{colors, font_size, spacing};
 use std::any::Any;
 use std::time::Duration;
 use taffy::NodeId;
@@ -30,6 +32,8 @@ pub enum TitleScreenAction {
     Load,
     /// Open CG Gallery
     CgGallery,
+    /// Open the achievement list
+    Achievements,
     /// Open settings
     Settings,
     /// Exit game
@@ -67,6 +71,10 @@ pub struct TitleScreenElement {
     animation_context: AnimationContext,
     /// Component-specific animation override (None = follow global)
     animations_enabled: Option<bool>,
+    /// Game title shown above the menu
+    title: String,
+    /// Version string shown in the bottom-right corner (None = hidden)
+    version: Option<String>,
 }
 
 impl TitleScreenElement {
@@ -136,9 +144,47 @@ impl TitleScreenElement {
             button_bounds,
             animation_context: AnimationContext::default(),
             animations_enabled: None,
+            title: "Narrative Novel".to_string(),
+            version: None,
         }
     }
 
+    /// Add the "Achievements" item after "CG Gallery"
+    ///
+    /// Games without achievements leave it out.
+    pub fn with_achievements(mut self, available: bool) -> Self {
+        if !available {
+            return self;
+        }
+        let index = self
+            .menu_items
+            .iter()
+            .position(|item| item.action == TitleScreenAction::CgGallery)
+            .map_or(self.menu_items.len(), |i| i + 1);
+        self.menu_items.insert(
+            index,
+            MenuItem {
+                label: "Achievements",
+                action: TitleScreenAction::Achievements,
+                enabled: true,
+            },
+        );
+        self.button_bounds = vec![Bounds::default(); self.menu_items.len()];
+        self
+    }
+
+    /// Set the game title shown above the menu
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the version string shown in the screen corner
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
     /// Set the animation context
     pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
         self.animation_context = context;
@@ -238,7 +284,7 @@ impl Element for TitleScreenElement {
 
     fn paint(&self, cx: &mut PaintContext) {
         // Draw title
-        let title = "Narrative Novel";
+        let title = self.title.as_str();
         // Rough estimate for centering (TODO: use proper text measurement)
         let title_width = title.len() as f32 * Self::TITLE_FONT_SIZE * 0.6;
         let title_x = cx.bounds.origin.x + (cx.bounds.size.width - title_width) / 2.0;
@@ -301,6 +347,21 @@ impl Element for TitleScreenElement {
                 Self::FONT_SIZE,
             );
         }
+
+        // Draw version in the bottom-right corner
+        if let Some(version) = &self.version {
+            let text = format!("v{}", version);
+            let text_width = text.len() as f32 * font_size::SM * 0.6;
+            cx.draw_text(
+                &text,
+                Point::new(
+                    cx.bounds.origin.x + cx.bounds.size.width - text_width - spacing::MD,
+                    cx.bounds.origin.y + cx.bounds.size.height - spacing::MD,
+                ),
+                colors::TEXT_SECONDARY,
+                font_size::SM,
+            );
+        }
     }
 
     fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
@@ -360,6 +421,19 @@ impl Element for TitleScreenElement {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_title_screen_title_and_version() {
+        let screen = TitleScreenElement::new(false);
+        assert_eq!(screen.title, "Narrative Novel");
+        assert!(screen.version.is_none());
+
+        let screen = TitleScreenElement::new(false)
+            .with_title("My Novel")
+            .with_version("1.2.0");
+        assert_eq!(screen.title, "My Novel");
+        assert_eq!(screen.version.as_deref(), Some("1.2.0"));
+    }
+
     #[test]
     fn test_title_screen_creation_without_continue() {
         let screen = TitleScreenElement::new(false);
@@ -379,6 +453,17 @@ mod tests {
         assert_eq!(screen.selected_index, 0);
     }
 
+    #[test]
+    fn test_achievements_item() {
+        let screen = TitleScreenElement::new(false).with_achievements(false);
+        assert_eq!(screen.menu_items.len(), 5);
+
+        let screen = TitleScreenElement::new(false).with_achievements(true);
+        assert_eq!(screen.menu_items.len(), 6);
+        assert_eq!(screen.button_bounds.len(), 6);
+        assert_eq!(screen.menu_items[3].action, TitleScreenAction::Achievements);
+    }
+
     #[test]
     fn test_selection_navigation() {
         let mut screen = TitleScreenElement::new(true);
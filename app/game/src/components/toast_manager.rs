@@ -0,0 +1,420 @@
+//! Toast notifications
+//!
+//! Short, non-blocking notifications (achievement unlocked, quick save done,
+//! save reminder) stacked at the top-right corner of the screen. Toasts are
+//! sent through a [`ToastQueue`], a cloneable handle that can be passed to
+//! any part of the game; the [`ToastManagerElement`] owned by the game root
+//! picks them up once per frame.
+
+use narrative_gui::components::{Icon, IconType};
+use narrative_gui::framework::animation::AnimationContext;
+use narrative_gui::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use narrative_gui::framework::input::InputEvent;
+use narrative_gui::framework::layout::Bounds;
+use narrative_gui::theme::{colors, font_size, icon_size, radius, spacing};
+use narrative_gui::{Point, Size};
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::time::Duration;
+use taffy::NodeId;
+
+/// A toast notification
+#[derive(Debug, Clone)]
+pub struct ToastMessage {
+    /// Icon drawn left of the text
+    pub icon: Option<IconType>,
+    /// Texture drawn left of the text instead of the icon, e.g. a save thumbnail
+    pub image: Option<u64>,
+    /// Small heading above the text
+    pub heading: Option<String>,
+    /// Notification text
+    pub text: String,
+    /// How long the toast stays on screen, fades included
+    pub duration: Duration,
+}
+
+impl ToastMessage {
+    /// Default time a toast stays on screen
+    pub const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+
+    /// Create a toast with text only
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            icon: None,
+            image: None,
+            heading: None,
+            text: text.into(),
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Set the icon
+    pub fn with_icon(mut self, icon: IconType) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the image, shown instead of the icon
+    pub fn with_image(mut self, texture_id: u64) -> Self {
+        self.image = Some(texture_id);
+        self
+    }
+
+    /// Set the heading
+    pub fn with_heading(mut self, heading: impl Into<String>) -> Self {
+        self.heading = Some(heading.into());
+        self
+    }
+
+    /// Set how long the toast stays on screen
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// Cloneable handle for sending toasts
+///
+/// Sending never blocks; toasts sent after the manager is dropped are discarded.
+#[derive(Debug, Clone)]
+pub struct ToastQueue {
+    sender: Sender<ToastMessage>,
+}
+
+impl ToastQueue {
+    /// Queue a toast
+    pub fn send(&self, toast: ToastMessage) {
+        if let Err(e) = self.sender.send(toast) {
+            tracing::debug!("Toast dropped, manager is gone: {:?}", e.0.text);
+        }
+    }
+
+    /// Queue a toast with text only
+    pub fn show(&self, text: impl Into<String>) {
+        self.send(ToastMessage::new(text));
+    }
+}
+
+/// Toast overlay element
+///
+/// Shows up to [`Self::MAX_VISIBLE`] toasts at once, newest at the bottom;
+/// further toasts wait until one expires.
+pub struct ToastManagerElement {
+    /// Unique element ID
+    id: ElementId,
+    /// Taffy layout node
+    layout_node: Option<NodeId>,
+    /// Handle given out to senders
+    queue: ToastQueue,
+    /// Toasts sent through the queue since the last frame
+    receiver: Mutex<Receiver<ToastMessage>>,
+    /// Toasts waiting for a free spot
+    waiting: VecDeque<ToastMessage>,
+    /// Toasts shown now, with the seconds they have been shown
+    shown: Vec<(ToastMessage, f32)>,
+    /// Animation context for global settings
+    animation_context: AnimationContext,
+}
+
+impl ToastManagerElement {
+    /// Most toasts on screen at once
+    pub const MAX_VISIBLE: usize = 3;
+    /// Slide and fade in/out duration, in seconds
+    const FADE_SECS: f32 = 0.3;
+    /// Toast card width
+    const WIDTH: f32 = 360.0;
+    /// Image size (16:9, like save thumbnails)
+    const IMAGE_SIZE: Size = Size::new(96.0, 54.0);
+
+    /// Create an empty toast overlay
+    pub fn new() -> Self {
+        let (sender, receiver) = channel();
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            queue: ToastQueue { sender },
+            receiver: Mutex::new(receiver),
+            waiting: VecDeque::new(),
+            shown: Vec::new(),
+            animation_context: AnimationContext::default(),
+        }
+    }
+
+    /// Set the animation context
+    pub fn set_animation_context(&mut self, context: AnimationContext) {
+        self.animation_context = context;
+    }
+
+    /// Handle for sending toasts from anywhere
+    pub fn queue(&self) -> ToastQueue {
+        self.queue.clone()
+    }
+
+    /// Queue a toast
+    pub fn push(&mut self, toast: ToastMessage) {
+        self.waiting.push_back(toast);
+        self.show_waiting();
+    }
+
+    /// Drop the shown and waiting toasts
+    pub fn clear(&mut self) {
+        self.take_sent();
+        self.waiting.clear();
+        self.shown.clear();
+    }
+
+    /// Toasts shown now, from top to bottom
+    pub fn shown(&self) -> impl Iterator<Item = &ToastMessage> {
+        self.shown.iter().map(|(toast, _)| toast)
+    }
+
+    /// Whether no toast is shown or waiting
+    pub fn is_empty(&self) -> bool {
+        self.shown.is_empty() && self.waiting.is_empty()
+    }
+
+    /// Move toasts sent through the queue to the waiting toasts
+    fn take_sent(&mut self) {
+        if let Ok(receiver) = self.receiver.get_mut() {
+            self.waiting.extend(receiver.try_iter());
+        }
+    }
+
+    /// Move waiting toasts to free spots on screen
+    fn show_waiting(&mut self) {
+        while self.shown.len() < Self::MAX_VISIBLE {
+            let Some(toast) = self.waiting.pop_front() else {
+                break;
+            };
+            self.shown.push((toast, 0.0));
+        }
+    }
+
+    /// Take sent toasts and advance the shown ones; returns true while any
+    /// is on screen
+    fn advance(&mut self, delta: f32) -> bool {
+        self.take_sent();
+        let visible = !self.shown.is_empty() || !self.waiting.is_empty();
+
+        for (_, elapsed) in &mut self.shown {
+            *elapsed += delta;
+        }
+        self.shown
+            .retain(|(toast, elapsed)| *elapsed < toast.duration.as_secs_f32());
+        self.show_waiting();
+        visible
+    }
+
+    /// How far a toast is faded in (0.0 = hidden, 1.0 = fully shown)
+    fn fade_ratio(&self, toast: &ToastMessage, elapsed: f32) -> f32 {
+        if !self.animation_context.should_animate(None) {
+            return 1.0;
+        }
+        let remaining = toast.duration.as_secs_f32() - elapsed;
+        (elapsed.min(remaining) / Self::FADE_SECS).clamp(0.0, 1.0)
+    }
+
+    /// Height of a toast's heading and text
+    fn text_height(toast: &ToastMessage) -> f32 {
+        let heading = if toast.heading.is_some() {
+            font_size::SM + spacing::SM
+        } else {
+            0.0
+        };
+        heading + font_size::LG + spacing::XS
+    }
+
+    /// Card height of a toast
+    fn card_height(toast: &ToastMessage) -> f32 {
+        let image = if toast.image.is_some() {
+            Self::IMAGE_SIZE.height
+        } else {
+            0.0
+        };
+        spacing::SM * 2.0 + Self::text_height(toast).max(image)
+    }
+
+    fn paint_toast(cx: &mut PaintContext, toast: &ToastMessage, card: Bounds, ratio: f32) {
+        let mut background = colors::BG_ELEVATED;
+        background.a = 0.95 * ratio;
+        cx.fill_rounded_rect(card, background, radius::MD);
+        let mut border = colors::BORDER_ACCENT;
+        border.a *= ratio;
+        cx.stroke_rect(card, border, 1.0);
+
+        let mut text_x = card.origin.x + spacing::MD;
+        if let Some(texture_id) = toast.image {
+            let image_bounds = Bounds {
+                origin: Point::new(
+                    text_x,
+                    card.origin.y + (card.size.height - Self::IMAGE_SIZE.height) / 2.0,
+                ),
+                size: Self::IMAGE_SIZE,
+            };
+            cx.draw_texture(texture_id, image_bounds, ratio);
+            text_x += Self::IMAGE_SIZE.width + spacing::SM;
+        } else if let Some(icon) = toast.icon {
+            let mut color = colors::TEXT_ACCENT;
+            color.a *= ratio;
+            let icon_bounds = Bounds {
+                origin: Point::new(
+                    text_x,
+                    card.origin.y + (card.size.height - icon_size::MD) / 2.0,
+                ),
+                size: Size::new(icon_size::MD, icon_size::MD),
+            };
+            let bounds = std::mem::replace(&mut cx.bounds, icon_bounds);
+            Icon::new(icon).with_color(color).paint(cx);
+            cx.bounds = bounds;
+            text_x += icon_size::MD + spacing::SM;
+        }
+
+        // Center the text next to a taller image
+        let mut y = card.origin.y + (card.size.height - Self::text_height(toast)) / 2.0;
+        if let Some(heading) = &toast.heading {
+            let mut color = colors::TEXT_ACCENT;
+            color.a *= ratio;
+            y += font_size::SM;
+            cx.draw_text(heading, Point::new(text_x, y), color, font_size::SM);
+            y += spacing::SM;
+        }
+        let mut color = colors::TEXT_PRIMARY;
+        color.a *= ratio;
+        cx.draw_text(
+            &toast.text,
+            Point::new(text_x, y + font_size::LG),
+            color,
+            font_size::LG,
+        );
+    }
+}
+
+impl Default for ToastManagerElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for ToastManagerElement {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Overlay the whole screen
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let mut y = cx.bounds.origin.y + spacing::LG;
+        for (toast, elapsed) in &self.shown {
+            let ratio = self.fade_ratio(toast, *elapsed);
+            let height = Self::card_height(toast);
+            let card = Bounds {
+                origin: Point::new(
+                    cx.bounds.origin.x + cx.bounds.size.width - (Self::WIDTH + spacing::LG) * ratio,
+                    y,
+                ),
+                size: Size::new(Self::WIDTH, height),
+            };
+            Self::paint_toast(cx, toast, card, ratio);
+            y += height + spacing::SM;
+        }
+    }
+
+    fn handle_event(&mut self, _event: &InputEvent, _bounds: Bounds) -> bool {
+        false
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.advance(delta.as_secs_f32())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(manager: &ToastManagerElement) -> Vec<&str> {
+        manager.shown().map(|toast| toast.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_toasts_stack_and_expire() {
+        let mut manager = ToastManagerElement::new();
+        assert!(!manager.advance(0.1));
+
+        let queue = manager.queue();
+        for text in ["First", "Second", "Third", "Fourth"] {
+            queue.show(text);
+        }
+        assert!(manager.advance(0.0));
+        assert_eq!(texts(&manager), ["First", "Second", "Third"]);
+
+        manager.push(ToastMessage::new("Long").with_duration(Duration::from_secs(10)));
+        assert!(manager.advance(ToastMessage::DEFAULT_DURATION.as_secs_f32()));
+        assert_eq!(texts(&manager), ["Fourth", "Long"]);
+
+        manager.advance(ToastMessage::DEFAULT_DURATION.as_secs_f32());
+        assert_eq!(texts(&manager), ["Long"]);
+
+        manager.clear();
+        assert!(manager.is_empty());
+        assert!(!manager.advance(0.1));
+    }
+
+    #[test]
+    fn test_image_fits_card() {
+        let text_only = ToastMessage::new("Quick 01").with_heading("Quick Saved");
+        let with_image = text_only.clone().with_image(7);
+        assert_eq!(with_image.image, Some(7));
+        assert!(
+            ToastManagerElement::card_height(&with_image)
+                >= ToastManagerElement::IMAGE_SIZE.height + spacing::SM * 2.0
+        );
+        assert!(
+            ToastManagerElement::card_height(&with_image)
+                > ToastManagerElement::card_height(&text_only)
+        );
+    }
+
+    #[test]
+    fn test_fade_ratio() {
+        let mut manager = ToastManagerElement::new();
+        let toast = ToastMessage::new("Saved");
+        let duration = toast.duration.as_secs_f32();
+        assert_eq!(manager.fade_ratio(&toast, 0.0), 0.0);
+        assert_eq!(manager.fade_ratio(&toast, 1.0), 1.0);
+        assert_eq!(manager.fade_ratio(&toast, duration), 0.0);
+
+        manager.set_animation_context(AnimationContext::disabled());
+        assert_eq!(manager.fade_ratio(&toast, 0.0), 1.0);
+    }
+}
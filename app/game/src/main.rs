@@ -3,11 +3,21 @@
 //! This is the main entry point for the Narrative Novel Engine application.
 
 use narrative_core::config::UserSettings;
-use narrative_engine::EngineConfig;
+use narrative_core::{GameConfig, GameMetadata, ProjectManifest, SaveSlotConfig, TextConfig};
+use narrative_engine::text::FontFallbackChain;
+use narrative_engine::{ConfigArgs, ConfigOverrides, EngineConfig};
 use narrative_game::components::GameRootElement;
-use narrative_gui::framework::{App, PresentMode, WindowOptions};
+use narrative_gui::framework::{App, GameMenuInfo, PresentMode, WindowOptions};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+/// Engine config loaded when no `--config` is given (optional)
+const ENGINE_CONFIG_PATH: &str = "assets/config/engine.ron";
 
 fn main() -> anyhow::Result<()> {
+    // Parse --config, --set/--<key>=<value> and --print-config
+    let args = ConfigArgs::from_env_args()?;
+
     // Initialize logging
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -16,10 +26,55 @@ fn main() -> anyhow::Result<()> {
         )
         .init();
 
-    tracing::info!("Starting Narrative Novel Engine");
+    // Load game metadata (title, version, icon), the save slot layout, the
+    // voice file naming convention and the text settings (font fallbacks)
+    let (metadata, save_slots, voice_resolver, text_config) =
+        match GameConfig::load_from_file("assets/config/game.ron") {
+            Ok(config) => {
+                let voice_resolver = config.paths.voice_resolver();
+                (config.game, config.saves, voice_resolver, config.text)
+            }
+            Err(e) => {
+                tracing::warn!("Could not load game config, using default metadata: {}", e);
+                (
+                    GameMetadata::new("Narrative Novel Engine"),
+                    SaveSlotConfig::default(),
+                    None,
+                    TextConfig::default(),
+                )
+            }
+        };
+    let window_title = metadata.get_window_title().to_string();
+
+    tracing::info!("Starting {} v{}", metadata.title, metadata.version);
+
+    // Load the project manifest for multi-scenario projects (optional)
+    let project_path = Path::new("assets/config/project.ron");
+    let project = if project_path.exists() {
+        match ProjectManifest::load_from_file(project_path) {
+            Ok(project) => {
+                tracing::info!("Loaded project with {} scenarios", project.scenarios.len());
+                Some(project)
+            }
+            Err(e) => {
+                tracing::warn!("Could not load project manifest: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Engine config layers: config file < game metadata and user settings
+    // < NARRATIVE_* environment variables < command-line flags
+    let mut config = match &args.config_path {
+        Some(path) => EngineConfig::load(path)?,
+        None => EngineConfig::load_or_default(ENGINE_CONFIG_PATH)?,
+    };
+    config.window.title = window_title;
 
     // Load user settings to get display resolution
-    let (width, height) = match UserSettings::load("assets/config/settings.ron") {
+    match UserSettings::load("assets/config/settings.ron") {
         Ok(settings) => {
             tracing::info!(
                 "Loaded display settings: resolution = {}x{}, fullscreen = {}",
@@ -27,38 +82,73 @@ fn main() -> anyhow::Result<()> {
                 settings.display.resolution.1,
                 settings.display.fullscreen
             );
-            settings.display.resolution
+            (config.window.width, config.window.height) = settings.display.resolution;
         }
         Err(e) => {
             tracing::warn!(
-                "Could not load user settings, using default resolution 1280x720: {}",
+                "Could not load user settings, using resolution {}x{}: {}",
+                config.window.width,
+                config.window.height,
                 e
             );
-            (1280, 720) // Default to 720p
         }
+    }
+
+    let mut overrides = ConfigOverrides::from_env();
+    overrides.extend(args.overrides);
+    config.apply_overrides(&overrides)?;
+
+    if args.print_config {
+        println!("{}", config.to_ron_string()?);
+        return Ok(());
+    }
+
+    // Native menu bar (Save/Load, Preferences, Toggle Fullscreen, About)
+    let menu_info = GameMenuInfo {
+        name: metadata.title.clone(),
+        version: metadata.version.clone(),
+        developer: metadata.developer.clone(),
     };
+    let (menu_sender, menu_receiver) = mpsc::channel();
+
+    let font_fallback = FontFallbackChain::from_text_config(&text_config, &config.asset_path);
 
     // Create and run GUI application
     // AudioManager is now initialized inside GameRootElement
-    App::new(WindowOptions {
-        title: "Narrative Novel Engine".to_string(),
-        width,
-        height,
+    let mut app = App::new(WindowOptions {
+        title: config.window.title.clone(),
+        width: config.window.width,
+        height: config.window.height,
         resizable: false, // Disable window resizing to maintain aspect ratio and layout
-        present_mode: PresentMode::VSync,
-        target_fps: 60,
+        present_mode: if config.graphics.vsync {
+            PresentMode::VSync
+        } else {
+            PresentMode::Immediate
+        },
+        target_fps: config.graphics.target_fps,
         show_fps_overlay: cfg!(debug_assertions),
+        icon: metadata.icon.as_ref().map(PathBuf::from),
         ..Default::default()
     })
     .with_root(move || {
-        // Create engine configuration with user-selected resolution
-        let mut config = EngineConfig::default();
-        config.window.title = "Narrative Novel Engine".to_string();
-        config.window.width = width;
-        config.window.height = height;
-
         // Create root element
-        Box::new(GameRootElement::new(config))
+        let mut root = GameRootElement::new(config)
+            .with_game_metadata(metadata)
+            .with_save_slots(save_slots)
+            .with_font_fallback(font_fallback)
+            .with_menu_commands(menu_receiver);
+        if let Some(project) = project {
+            root = root.with_project(project);
+        }
+        if let Some(voice_resolver) = voice_resolver {
+            root = root.with_voice_resolver(voice_resolver);
+        }
+        #[cfg(feature = "steam")]
+        match narrative_engine::steam::Steam::init() {
+            Ok(steam) => root = root.with_steam(steam),
+            Err(e) => tracing::warn!("Running without Steam: {}", e),
+        }
+        Box::new(root)
     })
     .on_window_created(|window| {
         // Load default game assets after window creation
@@ -77,8 +167,20 @@ fn main() -> anyhow::Result<()> {
                 // Continue anyway - game can run without textures
             }
         }
-    })
-    .run()?;
+    });
+
+    // Only Windows and macOS have native menu bar support
+    if cfg!(any(target_os = "windows", target_os = "macos")) {
+        app = app
+            .with_game_menu(&menu_info)
+            .with_menu_handler(move |menu_id| {
+                if menu_sender.send(menu_id).is_err() {
+                    tracing::warn!("Menu command dropped: game root is gone");
+                }
+            });
+    }
+
+    app.run()?;
 
     Ok(())
 }
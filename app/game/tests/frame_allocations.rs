@@ -0,0 +1,98 @@
+//! Frame Allocation Tests
+//!
+//! Counts heap allocations while a static dialogue frame is ticked, laid out
+//! and painted in a headless window. Once the first frames have filled the
+//! paint buffers and interned the text, repainting the same frame must not
+//! allocate.
+//!
+//! Run with: cargo test --test frame_allocations
+
+use narrative_core::config::DialogueBoxConfig;
+use narrative_game::components::DialogueBoxElement;
+use narrative_gui::framework::HeadlessWindow;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// System allocator that counts allocations made by the measuring thread
+struct CountingAllocator;
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record_allocation() {
+    // `try_with` so allocations during thread teardown are ignored
+    let _ = COUNTING.try_with(|counting| {
+        if counting.get() {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        }
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Count the allocations made by `f` on this thread
+fn count_allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.with(|count| count.set(0));
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[test]
+fn test_static_dialogue_frame_does_not_allocate() {
+    // The blinking click indicator animates every frame, so it is off to keep
+    // the frame static
+    let config = DialogueBoxConfig {
+        show_click_indicator: false,
+        ..Default::default()
+    };
+    let text = "The rain had stopped by the time we reached the station.";
+    let mut dialogue_box = DialogueBoxElement::new(config)
+        .with_speaker("Alice")
+        .with_text(text)
+        .with_visible_chars(text.chars().count());
+    dialogue_box.set_auto_mode_enabled(true);
+
+    let mut window = HeadlessWindow::new(1280, 720, Box::new(dialogue_box));
+
+    // Warm up: lay out the tree, size the buffers and intern the text
+    for _ in 0..3 {
+        window.frame().unwrap();
+    }
+    assert!(window.painted_text().contains(&text));
+
+    let allocations = count_allocations(|| {
+        for _ in 0..60 {
+            window.frame().unwrap();
+        }
+    });
+    assert_eq!(
+        allocations, 0,
+        "static frames allocated {allocations} times"
+    );
+}
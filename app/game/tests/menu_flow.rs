@@ -0,0 +1,65 @@
+//! Menu Flow Tests
+//!
+//! Drives `GameRootElement` in a headless window with synthetic input events
+//! and checks the menu state transitions:
+//! - Title screen → Settings → Back (mouse)
+//! - Title screen → Settings → Escape (keyboard)
+//!
+//! Run with: cargo test --test menu_flow
+
+use narrative_engine::EngineConfig;
+use narrative_engine::runtime::AppState;
+use narrative_game::components::GameRootElement;
+use narrative_gui::framework::HeadlessWindow;
+use narrative_gui::framework::input::KeyCode;
+use std::time::Duration;
+
+/// Longer than the loading screen so the title screen is showing
+const LOADING_TIME: Duration = Duration::from_millis(1500);
+
+fn app_state(window: &HeadlessWindow) -> &AppState {
+    window
+        .root_as::<GameRootElement>()
+        .expect("root should be a GameRootElement")
+        .app_state()
+}
+
+/// Open a headless 720p window and wait for the title screen
+fn title_screen() -> HeadlessWindow {
+    let root = GameRootElement::new(EngineConfig::default());
+    let mut window = HeadlessWindow::new(1280, 720, Box::new(root));
+
+    window.run_for(LOADING_TIME).unwrap();
+    assert!(app_state(&window).is_main_menu());
+    assert!(window.painted_text().contains(&"Settings"));
+
+    window
+}
+
+#[test]
+fn test_title_settings_back_with_mouse() {
+    let mut window = title_screen();
+
+    assert!(window.click_text("Settings"));
+    window.frame().unwrap();
+    assert!(matches!(app_state(&window), AppState::Settings(_)));
+    assert!(window.painted_text().contains(&"Back"));
+
+    assert!(window.click_text("Back"));
+    window.frame().unwrap();
+    assert!(app_state(&window).is_main_menu());
+    assert!(window.painted_text().contains(&"Settings"));
+}
+
+#[test]
+fn test_title_settings_back_with_keyboard() {
+    let mut window = title_screen();
+
+    assert!(window.press_key(KeyCode::Escape));
+    window.frame().unwrap();
+    assert!(matches!(app_state(&window), AppState::Settings(_)));
+
+    assert!(window.press_key(KeyCode::Escape));
+    window.frame().unwrap();
+    assert!(app_state(&window).is_main_menu());
+}
@@ -0,0 +1,243 @@
+//! Image button component for image choices and hotspots
+
+use crate::framework::element::{Element, ElementId, HitTestResult, LayoutContext, PaintContext};
+use crate::framework::hit_mask::HitMask;
+use crate::framework::input::InputEvent;
+use crate::framework::layout::{Bounds, Point};
+use std::any::Any;
+use std::sync::Arc;
+use taffy::NodeId;
+
+/// Opacity of the image while pressed
+const PRESSED_OPACITY: f32 = 0.8;
+
+/// Clickable image, optionally hit tested against its opacity mask
+///
+/// Without a hit mask the whole bounds are clickable. With one (see
+/// [`Renderer::load_texture_with_hit_mask`](crate::framework::Renderer::load_texture_with_hit_mask)),
+/// only the visible pixels of the image react to the mouse.
+pub struct ImageButton {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    texture_id: u64,
+    hover_texture_id: Option<u64>,
+    hit_mask: Option<Arc<HitMask>>,
+    width: Option<f32>,
+    height: Option<f32>,
+    is_hovered: bool,
+    is_pressed: bool,
+    on_click: Option<Box<dyn Fn() + Send + Sync>>,
+}
+
+impl ImageButton {
+    pub fn new(texture_id: u64) -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            texture_id,
+            hover_texture_id: None,
+            hit_mask: None,
+            width: None,
+            height: None,
+            is_hovered: false,
+            is_pressed: false,
+            on_click: None,
+        }
+    }
+
+    /// Set the texture shown while hovered
+    pub fn with_hover_texture(mut self, texture_id: u64) -> Self {
+        self.hover_texture_id = Some(texture_id);
+        self
+    }
+
+    /// Only react to the mouse over opaque pixels of the mask
+    pub fn with_hit_mask(mut self, mask: Arc<HitMask>) -> Self {
+        self.hit_mask = Some(mask);
+        self
+    }
+
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn with_height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn with_on_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_click = Some(Box::new(callback));
+        self
+    }
+
+    /// Check whether the button is hovered
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Check whether a point hits the button's image
+    fn hits(&self, point: Point, bounds: Bounds) -> bool {
+        match &self.hit_mask {
+            Some(mask) => mask.contains(point, bounds),
+            None => bounds.contains(point),
+        }
+    }
+}
+
+impl Element for ImageButton {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        Style {
+            size: taffy::Size {
+                width: self
+                    .width
+                    .map(Dimension::length)
+                    .unwrap_or(Dimension::auto()),
+                height: self
+                    .height
+                    .map(Dimension::length)
+                    .unwrap_or(Dimension::auto()),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let texture_id = match self.hover_texture_id {
+            Some(hover) if self.is_hovered => hover,
+            _ => self.texture_id,
+        };
+        let opacity = if self.is_pressed {
+            PRESSED_OPACITY
+        } else {
+            1.0
+        };
+
+        cx.draw_texture(texture_id, cx.bounds, opacity);
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        match event {
+            InputEvent::MouseMove { position, .. } => {
+                let was_hovered = self.is_hovered;
+                self.is_hovered = self.hits(*position, bounds);
+                was_hovered != self.is_hovered
+            }
+            InputEvent::MouseDown { position, .. } if self.hits(*position, bounds) => {
+                self.is_pressed = true;
+                true
+            }
+            InputEvent::MouseUp { position, .. } => {
+                let was_pressed = self.is_pressed;
+                self.is_pressed = false;
+                if was_pressed && self.hits(*position, bounds) {
+                    if let Some(ref callback) = self.on_click {
+                        callback();
+                    }
+                    true
+                } else {
+                    was_pressed
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn hit_test(&self, point: Point, bounds: Bounds) -> Option<HitTestResult> {
+        self.hits(point, bounds).then_some(HitTestResult {
+            element_id: self.id,
+            bounds,
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::hit_mask::HitMaskOptions;
+    use crate::framework::input::{Modifiers, MouseButton};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 2x2 texture with only the top-left pixel opaque
+    fn corner_mask() -> Arc<HitMask> {
+        let mut rgba = vec![0u8; 16];
+        rgba[..4].copy_from_slice(&[255, 255, 255, 255]);
+        Arc::new(HitMask::from_rgba(&rgba, 2, 2, &HitMaskOptions::default()).unwrap())
+    }
+
+    fn click(button: &mut ImageButton, position: Point, bounds: Bounds) -> bool {
+        let pressed = button.handle_event(
+            &InputEvent::MouseDown {
+                button: MouseButton::Left,
+                position,
+                modifiers: Modifiers::none(),
+            },
+            bounds,
+        );
+        let released = button.handle_event(
+            &InputEvent::MouseUp {
+                button: MouseButton::Left,
+                position,
+                modifiers: Modifiers::none(),
+            },
+            bounds,
+        );
+        pressed || released
+    }
+
+    #[test]
+    fn test_image_button_hit_mask() {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&clicks);
+        let mut button = ImageButton::new(1)
+            .with_hit_mask(corner_mask())
+            .with_on_click(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+
+        // Transparent pixels fall through
+        assert!(button.hit_test(Point::new(75.0, 75.0), bounds).is_none());
+        assert!(!click(&mut button, Point::new(75.0, 75.0), bounds));
+        assert_eq!(clicks.load(Ordering::SeqCst), 0);
+
+        assert!(button.hit_test(Point::new(25.0, 25.0), bounds).is_some());
+        assert!(click(&mut button, Point::new(25.0, 25.0), bounds));
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_image_button_without_mask_uses_bounds() {
+        let button = ImageButton::new(1);
+        let bounds = Bounds::new(0.0, 0.0, 100.0, 100.0);
+
+        assert!(button.hit_test(Point::new(75.0, 75.0), bounds).is_some());
+        assert!(button.hit_test(Point::new(150.0, 75.0), bounds).is_none());
+    }
+}
@@ -4,14 +4,20 @@ mod button;
 mod card;
 mod dropdown;
 mod icon;
+mod image_button;
 mod sidebar;
 mod slider;
+mod text_input;
+mod toast;
 mod toggle;
 
 pub use button::{Button, ButtonStyle, ButtonVariant};
 pub use card::{Card, CardStyle};
 pub use dropdown::{DropdownItem, DropdownMenu, DropdownState, MenuBarState, MenuDefinition};
 pub use icon::{Icon, IconType};
+pub use image_button::ImageButton;
 pub use sidebar::{Sidebar, SidebarItem};
 pub use slider::Slider;
+pub use text_input::TextInput;
+pub use toast::Toast;
 pub use toggle::{Toggle, ToggleStyle};
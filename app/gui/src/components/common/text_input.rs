@@ -0,0 +1,681 @@
+//! Text input component for single-line text entry
+//!
+//! A single-line text field with:
+//! - Caret movement (arrows, Home/End) and Shift+arrow selection
+//! - Backspace/Delete and Ctrl+A
+//! - IME composition (the composed text is shown at the caret until committed)
+//! - Optional placeholder and maximum length
+//!
+//! Text is entered through `CharInput` and `ImeCommit` events; Enter marks the
+//! entry as submitted (see [`TextInput::take_submitted`]).
+
+use crate::framework::element::{Element, ElementId, LayoutContext, PaintContext, WindowOperation};
+use crate::framework::input::{InputEvent, KeyCode};
+use crate::framework::layout::{Bounds, Point};
+use crate::theme::{colors, common, font_size, radius, spacing};
+use std::any::Any;
+use std::ops::Range;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Single-line text input field
+pub struct TextInput {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    /// Entered text
+    text: String,
+    /// Caret position (byte index into `text`)
+    caret: usize,
+    /// Other end of the selection (byte index), if any text is selected
+    anchor: Option<usize>,
+    /// IME composition text, shown at the caret until committed
+    preedit: String,
+    /// Text shown while the field is empty
+    placeholder: String,
+    /// Maximum text length in characters
+    max_chars: Option<usize>,
+    /// Font size of the text
+    font_size: f32,
+    /// Width in pixels (None = auto)
+    width: Option<f32>,
+    /// Whether the field receives keyboard input
+    focused: bool,
+    /// Whether Enter was pressed since the last `take_submitted`
+    submitted: bool,
+    /// Time since the caret blink phase started
+    blink_elapsed: Duration,
+    /// Window operations requested (IME on/off, IME position)
+    pending_operations: Vec<WindowOperation>,
+}
+
+impl TextInput {
+    /// Padding between the field border and the text
+    const PADDING: f32 = spacing::SM;
+    /// Caret line width
+    const CARET_WIDTH: f32 = 2.0;
+    /// Caret blink half-period
+    const BLINK_INTERVAL: Duration = Duration::from_millis(530);
+
+    /// Create an empty, unfocused text input
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            text: String::new(),
+            caret: 0,
+            anchor: None,
+            preedit: String::new(),
+            placeholder: String::new(),
+            max_chars: None,
+            font_size: font_size::LG,
+            width: None,
+            focused: false,
+            submitted: false,
+            blink_elapsed: Duration::ZERO,
+            pending_operations: Vec::new(),
+        }
+    }
+
+    /// Set the initial text (caret at the end)
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    /// Set the placeholder shown while the field is empty
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Limit the text to `max_chars` characters
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = Some(max_chars);
+        self.truncate_to_max();
+        self
+    }
+
+    /// Set the font size
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set custom width
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Start focused
+    pub fn with_focus(mut self) -> Self {
+        self.set_focused(true);
+        self
+    }
+
+    /// Get the entered text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text, moving the caret to the end
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.truncate_to_max();
+        self.caret = self.text.len();
+        self.anchor = None;
+        self.preedit.clear();
+    }
+
+    /// Caret position (byte index into the text)
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Selected byte range, if any text is selected
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.anchor
+            .filter(|&anchor| anchor != self.caret)
+            .map(|anchor| anchor.min(self.caret)..anchor.max(self.caret))
+    }
+
+    /// IME composition text not yet committed
+    pub fn preedit(&self) -> &str {
+        &self.preedit
+    }
+
+    /// Check if the field receives keyboard input
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Focus or unfocus the field, turning IME composition on or off
+    pub fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+        self.focused = focused;
+        self.preedit.clear();
+        self.blink_elapsed = Duration::ZERO;
+        self.pending_operations
+            .push(WindowOperation::SetImeAllowed(focused));
+    }
+
+    /// Check if Enter was pressed, clearing the flag
+    pub fn take_submitted(&mut self) -> bool {
+        std::mem::take(&mut self.submitted)
+    }
+
+    /// Insert text at the caret, replacing the selection
+    ///
+    /// Text past the maximum length is dropped.
+    pub fn insert_text(&mut self, text: &str) {
+        self.delete_selection();
+        let room = self.max_chars.map_or(usize::MAX, |max| {
+            max.saturating_sub(self.text.chars().count())
+        });
+        let inserted: String = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(room)
+            .collect();
+        self.text.insert_str(self.caret, &inserted);
+        self.caret += inserted.len();
+    }
+
+    /// Delete the selection, or the character before the caret
+    pub fn delete_backward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        let previous = self.previous_boundary(self.caret);
+        self.text.replace_range(previous..self.caret, "");
+        self.caret = previous;
+    }
+
+    /// Delete the selection, or the character after the caret
+    pub fn delete_forward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        let next = self.next_boundary(self.caret);
+        self.text.replace_range(self.caret..next, "");
+    }
+
+    /// Select the whole text
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.caret = self.text.len();
+    }
+
+    /// Move the caret to `position`, extending the selection if `extend`
+    fn move_caret(&mut self, position: usize, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.caret);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = position;
+    }
+
+    /// Remove the selected text; returns false if nothing was selected
+    fn delete_selection(&mut self) -> bool {
+        let Some(selection) = self.selection() else {
+            self.anchor = None;
+            return false;
+        };
+        self.caret = selection.start;
+        self.text.replace_range(selection, "");
+        self.anchor = None;
+        true
+    }
+
+    fn previous_boundary(&self, index: usize) -> usize {
+        self.text[..index]
+            .chars()
+            .next_back()
+            .map_or(0, |c| index - c.len_utf8())
+    }
+
+    fn next_boundary(&self, index: usize) -> usize {
+        self.text[index..]
+            .chars()
+            .next()
+            .map_or(self.text.len(), |c| index + c.len_utf8())
+    }
+
+    fn truncate_to_max(&mut self) {
+        if let Some(max) = self.max_chars
+            && let Some((end, _)) = self.text.char_indices().nth(max)
+        {
+            self.text.truncate(end);
+            self.caret = self.caret.min(end);
+        }
+    }
+
+    /// Estimated width of `text` (wide characters count double)
+    fn text_width(&self, text: &str) -> f32 {
+        text.chars()
+            .map(|c| {
+                if (c as u32) >= 0x1100 {
+                    self.font_size
+                } else {
+                    self.font_size * common::CHAR_WIDTH_RATIO
+                }
+            })
+            .sum()
+    }
+
+    /// Byte index of the character boundary nearest to `x`
+    fn index_at(&self, x: f32, bounds: Bounds) -> usize {
+        let mut left = bounds.x() + Self::PADDING;
+        for (index, c) in self.text.char_indices() {
+            let width = self.text_width(c.encode_utf8(&mut [0; 4]));
+            if x < left + width / 2.0 {
+                return index;
+            }
+            left += width;
+        }
+        self.text.len()
+    }
+
+    /// Area of the caret, used to place the IME candidate window
+    fn caret_bounds(&self, bounds: Bounds) -> Bounds {
+        let x = bounds.x()
+            + Self::PADDING
+            + self.text_width(&self.text[..self.caret])
+            + self.text_width(&self.preedit);
+        let height = self.font_size * 1.2;
+        Bounds::new(
+            x,
+            bounds.y() + (bounds.height() - height) / 2.0,
+            Self::CARET_WIDTH,
+            height,
+        )
+    }
+
+    /// Restart the caret blink and move the IME window to the caret
+    fn after_edit(&mut self, bounds: Bounds) {
+        self.blink_elapsed = Duration::ZERO;
+        self.pending_operations
+            .push(WindowOperation::SetImeCursorArea {
+                bounds: self.caret_bounds(bounds),
+            });
+    }
+
+    fn handle_key(&mut self, key: KeyCode, shift: bool, ctrl: bool) -> bool {
+        match key {
+            KeyCode::Left => self.move_caret(self.previous_boundary(self.caret), shift),
+            KeyCode::Right => self.move_caret(self.next_boundary(self.caret), shift),
+            KeyCode::Home | KeyCode::Up => self.move_caret(0, shift),
+            KeyCode::End | KeyCode::Down => self.move_caret(self.text.len(), shift),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::A if ctrl => self.select_all(),
+            KeyCode::Enter => self.submitted = true,
+            // Escape and Tab are left to the parent (cancel, focus change)
+            KeyCode::Escape | KeyCode::Tab => return false,
+            // Other keys arrive as character input
+            _ => {}
+        }
+        true
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for TextInput {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        Style {
+            size: taffy::Size {
+                width: self
+                    .width
+                    .map(Dimension::length)
+                    .unwrap_or(Dimension::auto()),
+                height: Dimension::length(self.font_size * 1.2 + Self::PADDING * 2.0),
+            },
+            min_size: taffy::Size {
+                width: Dimension::length(100.0),
+                height: Dimension::auto(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let bounds = cx.bounds;
+
+        cx.fill_rounded_rect(bounds, colors::BG_DARK, radius::SM);
+        let border_color = if self.focused {
+            colors::BORDER_ACCENT
+        } else {
+            colors::BORDER_LIGHT
+        };
+        cx.stroke_rect(bounds, border_color, common::BORDER_THICKNESS);
+
+        let text_x = bounds.x() + Self::PADDING;
+        let caret = self.caret_bounds(bounds);
+        let baseline = caret.y() + self.font_size;
+
+        cx.push_clip(bounds);
+
+        if let Some(selection) = self.selection() {
+            let start = text_x + self.text_width(&self.text[..selection.start]);
+            let width = self.text_width(&self.text[selection]);
+            cx.fill_rect(
+                Bounds::new(start, caret.y(), width, caret.height()),
+                colors::ACCENT_MUTED,
+            );
+        }
+
+        if self.text.is_empty() && self.preedit.is_empty() {
+            if !self.placeholder.is_empty() {
+                cx.draw_text(
+                    &self.placeholder,
+                    Point::new(text_x, baseline),
+                    colors::TEXT_MUTED,
+                    self.font_size,
+                );
+            }
+        } else {
+            let before = &self.text[..self.caret];
+            cx.draw_text(
+                before,
+                Point::new(text_x, baseline),
+                colors::TEXT_PRIMARY,
+                self.font_size,
+            );
+
+            // Composition text, underlined, between the text before and after the caret
+            let preedit_x = text_x + self.text_width(before);
+            if !self.preedit.is_empty() {
+                cx.draw_text(
+                    &self.preedit,
+                    Point::new(preedit_x, baseline),
+                    colors::TEXT_ACCENT,
+                    self.font_size,
+                );
+                cx.fill_rect(
+                    Bounds::new(
+                        preedit_x,
+                        caret.y() + caret.height() - 2.0,
+                        self.text_width(&self.preedit),
+                        common::BORDER_THICKNESS,
+                    ),
+                    colors::TEXT_ACCENT,
+                );
+            }
+
+            cx.draw_text(
+                &self.text[self.caret..],
+                Point::new(caret.x(), baseline),
+                colors::TEXT_PRIMARY,
+                self.font_size,
+            );
+        }
+
+        let caret_visible =
+            (self.blink_elapsed.as_millis() / Self::BLINK_INTERVAL.as_millis()).is_multiple_of(2);
+        if self.focused && caret_visible {
+            cx.fill_rect(caret, colors::TEXT_PRIMARY);
+        }
+
+        cx.pop_clip();
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, bounds: Bounds) -> bool {
+        match event {
+            InputEvent::MouseDown { position, .. } => {
+                let inside = bounds.contains(*position);
+                self.set_focused(inside);
+                if inside {
+                    let index = self.index_at(position.x, bounds);
+                    self.move_caret(index, false);
+                    self.after_edit(bounds);
+                }
+                inside
+            }
+            InputEvent::KeyDown { key, modifiers } if self.focused => {
+                // While composing, keys belong to the IME
+                if !self.preedit.is_empty() {
+                    return true;
+                }
+                let handled = self.handle_key(*key, modifiers.shift, modifiers.ctrl);
+                if handled {
+                    self.after_edit(bounds);
+                }
+                handled
+            }
+            InputEvent::CharInput { character } if self.focused => {
+                if self.preedit.is_empty() {
+                    self.insert_text(character.encode_utf8(&mut [0; 4]));
+                    self.after_edit(bounds);
+                }
+                true
+            }
+            InputEvent::ImePreedit { text, .. } if self.focused => {
+                if !text.is_empty() {
+                    self.delete_selection();
+                }
+                self.preedit = text.clone();
+                self.after_edit(bounds);
+                true
+            }
+            InputEvent::ImeCommit { text } if self.focused => {
+                self.preedit.clear();
+                self.insert_text(text);
+                self.after_edit(bounds);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn take_window_operations(&mut self) -> Vec<WindowOperation> {
+        std::mem::take(&mut self.pending_operations)
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        if !self.focused {
+            return false;
+        }
+        let phase = |elapsed: Duration| elapsed.as_millis() / Self::BLINK_INTERVAL.as_millis();
+        let before = phase(self.blink_elapsed);
+        self.blink_elapsed = self.blink_elapsed.saturating_add(delta);
+        phase(self.blink_elapsed) != before
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::input::{Modifiers, MouseButton};
+
+    fn bounds() -> Bounds {
+        Bounds::new(0.0, 0.0, 300.0, 40.0)
+    }
+
+    fn key(input: &mut TextInput, key: KeyCode, modifiers: Modifiers) -> bool {
+        input.handle_event(&InputEvent::KeyDown { key, modifiers }, bounds())
+    }
+
+    fn type_text(input: &mut TextInput, text: &str) {
+        for character in text.chars() {
+            input.handle_event(&InputEvent::CharInput { character }, bounds());
+        }
+    }
+
+    fn shift() -> Modifiers {
+        Modifiers {
+            shift: true,
+            ..Modifiers::default()
+        }
+    }
+
+    #[test]
+    fn test_typing_and_caret_movement() {
+        let mut input = TextInput::new().with_focus();
+        type_text(&mut input, "Alce");
+        key(&mut input, KeyCode::Left, Modifiers::default());
+        key(&mut input, KeyCode::Left, Modifiers::default());
+        type_text(&mut input, "i");
+        assert_eq!(input.text(), "Alice");
+        assert_eq!(input.caret(), 3);
+
+        key(&mut input, KeyCode::Home, Modifiers::default());
+        key(&mut input, KeyCode::Delete, Modifiers::default());
+        key(&mut input, KeyCode::End, Modifiers::default());
+        key(&mut input, KeyCode::Backspace, Modifiers::default());
+        assert_eq!(input.text(), "lic");
+    }
+
+    #[test]
+    fn test_unfocused_input_ignores_keys() {
+        let mut input = TextInput::new();
+        type_text(&mut input, "abc");
+        assert!(!key(&mut input, KeyCode::Backspace, Modifiers::default()));
+        assert_eq!(input.text(), "");
+    }
+
+    #[test]
+    fn test_selection_is_replaced() {
+        let mut input = TextInput::new().with_text("Hello world").with_focus();
+        for _ in 0..5 {
+            key(&mut input, KeyCode::Left, shift());
+        }
+        assert_eq!(input.selection(), Some(6..11));
+
+        type_text(&mut input, "there");
+        assert_eq!(input.text(), "Hello there");
+        assert_eq!(input.selection(), None);
+
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Modifiers::default()
+        };
+        key(&mut input, KeyCode::A, ctrl);
+        key(&mut input, KeyCode::Backspace, Modifiers::default());
+        assert_eq!(input.text(), "");
+    }
+
+    #[test]
+    fn test_multibyte_editing() {
+        let mut input = TextInput::new().with_text("さくら").with_focus();
+        key(&mut input, KeyCode::Left, Modifiers::default());
+        key(&mut input, KeyCode::Backspace, Modifiers::default());
+        assert_eq!(input.text(), "さら");
+        assert_eq!(input.caret(), "さ".len());
+    }
+
+    #[test]
+    fn test_max_chars() {
+        let mut input = TextInput::new().with_max_chars(4).with_focus();
+        type_text(&mut input, "Alexander");
+        assert_eq!(input.text(), "Alex");
+
+        let input = TextInput::new().with_text("さくらこ").with_max_chars(3);
+        assert_eq!(input.text(), "さくら");
+        assert_eq!(input.caret(), input.text().len());
+    }
+
+    #[test]
+    fn test_ime_composition() {
+        let mut input = TextInput::new().with_text("A").with_focus();
+        input.handle_event(
+            &InputEvent::ImePreedit {
+                text: "はな".to_string(),
+                cursor: Some((6, 6)),
+            },
+            bounds(),
+        );
+        assert_eq!(input.preedit(), "はな");
+        assert_eq!(input.text(), "A");
+
+        // Keys and raw characters go to the IME while composing
+        assert!(key(&mut input, KeyCode::Backspace, Modifiers::default()));
+        type_text(&mut input, "x");
+        assert_eq!(input.text(), "A");
+
+        input.handle_event(
+            &InputEvent::ImeCommit {
+                text: "花".to_string(),
+            },
+            bounds(),
+        );
+        assert_eq!(input.preedit(), "");
+        assert_eq!(input.text(), "A花");
+    }
+
+    #[test]
+    fn test_enter_submits() {
+        let mut input = TextInput::new().with_focus();
+        assert!(!input.take_submitted());
+        key(&mut input, KeyCode::Enter, Modifiers::default());
+        assert!(input.take_submitted());
+        assert!(!input.take_submitted());
+        assert!(!key(&mut input, KeyCode::Escape, Modifiers::default()));
+    }
+
+    #[test]
+    fn test_focus_toggles_ime() {
+        let mut input = TextInput::new();
+        input.handle_event(
+            &InputEvent::MouseDown {
+                button: MouseButton::Left,
+                position: Point::new(10.0, 10.0),
+                modifiers: Modifiers::default(),
+            },
+            bounds(),
+        );
+        assert!(input.is_focused());
+        let operations = input.take_window_operations();
+        assert_eq!(
+            operations.first(),
+            Some(&WindowOperation::SetImeAllowed(true))
+        );
+        assert!(matches!(
+            operations.last(),
+            Some(WindowOperation::SetImeCursorArea { .. })
+        ));
+
+        input.handle_event(
+            &InputEvent::MouseDown {
+                button: MouseButton::Left,
+                position: Point::new(10.0, 100.0),
+                modifiers: Modifiers::default(),
+            },
+            bounds(),
+        );
+        assert!(!input.is_focused());
+        assert_eq!(
+            input.take_window_operations(),
+            vec![WindowOperation::SetImeAllowed(false)]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Toast component for short, non-blocking notifications
+
+use crate::framework::animation::AnimationContext;
+use crate::framework::element::{Element, ElementId, LayoutContext, PaintContext};
+use crate::framework::input::InputEvent;
+use crate::framework::layout::{Bounds, Point, Size};
+use crate::framework::renderer::TextAlign;
+use crate::theme::{colors, font_size, radius, spacing};
+use std::any::Any;
+use std::time::Duration;
+use taffy::NodeId;
+
+/// Toast overlay that shows a message near the top of the screen for a while
+///
+/// The toast covers the whole screen but ignores input, so it never blocks
+/// the UI below it. It fades in and out unless animations are disabled.
+pub struct Toast {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    /// Message shown now
+    message: Option<String>,
+    /// Seconds the message has been shown
+    elapsed: f32,
+    /// Seconds a message stays on screen, fades included
+    duration: f32,
+    /// Animation context for global settings
+    animation_context: AnimationContext,
+}
+
+impl Toast {
+    /// Default time a message stays on screen, in seconds
+    pub const DEFAULT_DURATION_SECS: f32 = 4.0;
+    /// Fade in/out duration, in seconds
+    const FADE_SECS: f32 = 0.25;
+    /// Padding around the message
+    const PADDING: f32 = spacing::MD;
+
+    /// Create a hidden toast
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            message: None,
+            elapsed: 0.0,
+            duration: Self::DEFAULT_DURATION_SECS,
+            animation_context: AnimationContext::default(),
+        }
+    }
+
+    /// Set how long messages stay on screen
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration.as_secs_f32();
+        self
+    }
+
+    /// Set the animation context
+    pub fn with_animation_context(mut self, context: AnimationContext) -> Self {
+        self.animation_context = context;
+        self
+    }
+
+    /// Show a message, replacing the one shown now
+    pub fn show(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+        self.elapsed = 0.0;
+    }
+
+    /// Hide the message shown now
+    pub fn hide(&mut self) {
+        self.message = None;
+    }
+
+    /// Message shown now
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Whether a message is shown
+    pub fn is_visible(&self) -> bool {
+        self.message.is_some()
+    }
+
+    /// Opacity of the shown message (fades in, then out before it's hidden)
+    pub fn opacity(&self) -> f32 {
+        if self.message.is_none() {
+            return 0.0;
+        }
+        if !self.animation_context.should_animate(None) {
+            return 1.0;
+        }
+        let remaining = self.duration - self.elapsed;
+        (self.elapsed.min(remaining) / Self::FADE_SECS).clamp(0.0, 1.0)
+    }
+
+    /// Advance the shown message; returns true while one is on screen
+    fn advance(&mut self, delta: f32) -> bool {
+        if self.message.is_none() {
+            return false;
+        }
+        self.elapsed += delta;
+        if self.elapsed >= self.duration {
+            self.message = None;
+        }
+        true
+    }
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for Toast {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        // Overlay the whole screen
+        taffy::Style {
+            position: Position::Absolute,
+            size: taffy::geometry::Size {
+                width: Dimension::percent(1.0),
+                height: Dimension::percent(1.0),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        let Some(message) = &self.message else {
+            return;
+        };
+        let opacity = self.opacity();
+
+        let text_width = message.chars().count() as f32 * font_size::MD * 0.6;
+        let size = Size::new(
+            text_width + Self::PADDING * 2.0,
+            font_size::MD + Self::PADDING * 2.0,
+        );
+        let card = Bounds {
+            origin: Point::new(
+                cx.bounds.origin.x + (cx.bounds.size.width - size.width) / 2.0,
+                cx.bounds.origin.y + spacing::XL,
+            ),
+            size,
+        };
+
+        let mut background = colors::BG_ELEVATED;
+        background.a = 0.9 * opacity;
+        cx.fill_rounded_rect(card, background, radius::MD);
+
+        let mut text_color = colors::TEXT_PRIMARY;
+        text_color.a *= opacity;
+        cx.draw_text_aligned(
+            message,
+            Point::new(
+                card.origin.x + card.size.width / 2.0,
+                card.origin.y + Self::PADDING + font_size::MD * 0.8,
+            ),
+            text_color,
+            font_size::MD,
+            TextAlign::Center,
+        );
+    }
+
+    fn handle_event(&mut self, _event: &InputEvent, _bounds: Bounds) -> bool {
+        false
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.advance(delta.as_secs_f32())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_show_and_expire() {
+        let mut toast = Toast::new().with_duration(Duration::from_secs(2));
+        assert!(!toast.is_visible());
+        assert!(!toast.advance(0.1));
+
+        toast.show("Don't forget to save");
+        assert_eq!(toast.message(), Some("Don't forget to save"));
+        assert_eq!(toast.opacity(), 0.0);
+
+        assert!(toast.advance(1.0));
+        assert_eq!(toast.opacity(), 1.0);
+
+        toast.advance(1.0);
+        assert!(!toast.is_visible());
+    }
+
+    #[test]
+    fn test_toast_without_animations() {
+        let mut toast = Toast::new().with_animation_context(AnimationContext::disabled());
+        toast.show("Saved");
+        assert_eq!(toast.opacity(), 1.0);
+    }
+}
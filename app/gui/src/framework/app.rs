@@ -2,13 +2,16 @@
 
 use super::element::Element;
 use super::error::{FrameworkError, FrameworkResult};
-use super::menu::{AppMenu, MenuEventHandler, MenuId};
-use super::window::{Window, WindowOptions, convert_winit_event};
+use super::menu::{
+    AppMenu, GameMenu, GameMenuInfo, MenuEventHandler, MenuId, init_menu_for_window,
+};
+use super::window::{Window, WindowOptions, convert_winit_event, convert_winit_text_input};
+use std::path::Path;
 use std::sync::Arc;
 use winit::application::ApplicationHandler;
 use winit::event::WindowEvent;
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
-use winit::window::WindowId;
+use winit::window::{Icon, WindowId};
 
 /// Application context for global state
 pub struct AppContext {
@@ -39,7 +42,7 @@ pub struct App {
     window_options: WindowOptions,
     root_builder: Option<Box<dyn FnOnce() -> Box<dyn Element> + Send>>,
     modifiers: winit::keyboard::ModifiersState,
-    menu: Option<AppMenu>,
+    menu: Option<muda::Menu>,
     menu_event_handler: Option<MenuEventHandler>,
     on_menu_event: Option<MenuEventCallback>,
     on_window_created: Option<WindowInitCallback>,
@@ -71,7 +74,14 @@ impl App {
 
     /// Enable the native menu bar
     pub fn with_menu(mut self) -> Self {
-        self.menu = Some(AppMenu::new());
+        self.menu = Some(AppMenu::new().menu);
+        self.menu_event_handler = Some(MenuEventHandler::new());
+        self
+    }
+
+    /// Enable the native game menu bar (Save/Load, Preferences, Fullscreen, About)
+    pub fn with_game_menu(mut self, info: &GameMenuInfo) -> Self {
+        self.menu = Some(GameMenu::new(info).menu);
         self.menu_event_handler = Some(MenuEventHandler::new());
         self
     }
@@ -113,6 +123,20 @@ impl App {
     }
 }
 
+/// Load a window icon from an image file
+fn load_window_icon(path: &Path) -> FrameworkResult<Icon> {
+    let image = image::open(path).map_err(|e| {
+        FrameworkError::ResourceNotFound(format!("Failed to load icon '{}': {}", path.display(), e))
+    })?;
+    icon_from_image(image.to_rgba8())
+}
+
+fn icon_from_image(image: image::RgbaImage) -> FrameworkResult<Icon> {
+    let (width, height) = image.dimensions();
+    Icon::from_rgba(image.into_raw(), width, height)
+        .map_err(|e| FrameworkError::InvalidState(format!("Invalid icon image: {}", e)))
+}
+
 struct AppHandler {
     app: App,
     pending_init: bool,
@@ -131,7 +155,12 @@ impl ApplicationHandler for AppHandler {
                     self.app.window_options.height,
                 ))
                 .with_resizable(self.app.window_options.resizable)
-                .with_decorations(self.app.window_options.decorations);
+                .with_decorations(self.app.window_options.decorations)
+                .with_window_icon(self.app.window_options.icon.as_deref().and_then(|path| {
+                    load_window_icon(path)
+                        .inspect_err(|e| tracing::warn!("Window icon not set: {}", e))
+                        .ok()
+                }));
 
             let winit_window = match event_loop.create_window(window_attrs) {
                 Ok(w) => Arc::new(w),
@@ -161,7 +190,7 @@ impl ApplicationHandler for AppHandler {
 
                     // Initialize native menu bar if enabled
                     if let Some(ref menu) = self.app.menu {
-                        menu.init_for_window(&winit_window);
+                        init_menu_for_window(menu, &winit_window);
                         tracing::info!("Native menu bar initialized");
                     }
 
@@ -221,6 +250,9 @@ impl ApplicationHandler for AppHandler {
                 if let Some(input_event) = convert_winit_event(&event, &self.app.modifiers) {
                     window.handle_input(input_event);
                 }
+                for input_event in convert_winit_text_input(&event) {
+                    window.handle_input(input_event);
+                }
             }
         }
 
@@ -260,3 +292,20 @@ impl ApplicationHandler for AppHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_from_image() {
+        let image = image::RgbaImage::from_pixel(32, 32, image::Rgba([255, 0, 0, 255]));
+        assert!(icon_from_image(image).is_ok());
+    }
+
+    #[test]
+    fn test_load_window_icon_missing_file() {
+        let result = load_window_icon(Path::new("does/not/exist.png"));
+        assert!(matches!(result, Err(FrameworkError::ResourceNotFound(_))));
+    }
+}
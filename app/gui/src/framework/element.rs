@@ -5,9 +5,11 @@
 use super::Color;
 use super::input::InputEvent;
 use super::layout::{Bounds, Point, Size};
-use super::renderer::DrawCommand;
+use super::renderer::{CommandBuffer, DrawCommand, TextAlign, TextMarkup, TextSpacing};
 use crate::theme::{font_size, layout, timeline, typography};
+use narrative_core::TextSpan;
 use std::any::Any;
+use std::ops::Range;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
@@ -37,6 +39,12 @@ pub enum WindowOperation {
     SetDecorations(bool),
     /// Start dragging the window (for custom title bar)
     DragWindow,
+    /// Toggle between borderless fullscreen and windowed mode
+    ToggleFullscreen,
+    /// Enable or disable IME composition (on while a text field is focused)
+    SetImeAllowed(bool),
+    /// Tell the IME where the caret is, so its candidate window follows it
+    SetImeCursorArea { bounds: Bounds },
 }
 
 /// Unique identifier for elements
@@ -66,7 +74,7 @@ pub struct LayoutContext<'a> {
 pub struct PaintContext<'a> {
     pub bounds: Bounds,
     pub clip_bounds: Option<Bounds>,
-    pub commands: &'a mut Vec<DrawCommand>,
+    pub commands: &'a mut CommandBuffer,
 }
 
 impl<'a> PaintContext<'a> {
@@ -99,12 +107,80 @@ impl<'a> PaintContext<'a> {
     }
 
     /// Draw text at a position
+    ///
+    /// The text is interned, so painting the same text every frame doesn't allocate.
     pub fn draw_text(&mut self, text: &str, position: Point, color: Color, font_size: f32) {
+        self.draw_text_aligned(text, position, color, font_size, TextAlign::Left);
+    }
+
+    /// Draw text with `position` as its left edge, center or right edge
+    ///
+    /// Use [`TextAlign::start_of`] to start right-to-left text at the right.
+    pub fn draw_text_aligned(
+        &mut self,
+        text: &str,
+        position: Point,
+        color: Color,
+        font_size: f32,
+        align: TextAlign,
+    ) {
+        let text = self.commands.intern(text);
+        self.commands.push(DrawCommand::Text {
+            text,
+            position,
+            color,
+            font_size,
+            align,
+            markup: None,
+            spacing: TextSpacing::default(),
+            clip: None,
+        });
+    }
+
+    /// Draw text styled by markup spans (see [`narrative_core::markup`])
+    ///
+    /// `text` may be the revealed part of the line the spans were parsed
+    /// from. `time` drives the wave and shake effects.
+    pub fn draw_rich_text(
+        &mut self,
+        text: &str,
+        position: Point,
+        color: Color,
+        font_size: f32,
+        spans: Arc<[TextSpan]>,
+        time: f32,
+    ) {
+        self.draw_rich_text_aligned(
+            text,
+            position,
+            color,
+            font_size,
+            TextMarkup { spans, time },
+            TextAlign::Left,
+        );
+    }
+
+    /// Draw styled text with `position` as its left edge, center or right edge
+    pub fn draw_rich_text_aligned(
+        &mut self,
+        text: &str,
+        position: Point,
+        color: Color,
+        font_size: f32,
+        markup: TextMarkup,
+        align: TextAlign,
+    ) {
+        let text = self.commands.intern(text);
+        let markup = (!markup.spans.is_empty()).then_some(markup);
         self.commands.push(DrawCommand::Text {
-            text: text.to_string(),
+            text,
             position,
             color,
             font_size,
+            align,
+            markup,
+            spacing: TextSpacing::default(),
+            clip: None,
         });
     }
 
@@ -117,6 +193,45 @@ impl<'a> PaintContext<'a> {
         });
     }
 
+    /// Draw part of a texture with optional opacity
+    ///
+    /// `source` is the part drawn, in texture coordinates (0.0-1.0).
+    pub fn draw_texture_region(
+        &mut self,
+        texture_id: u64,
+        bounds: Bounds,
+        source: Bounds,
+        opacity: f32,
+    ) {
+        self.commands.push(DrawCommand::TextureRegion {
+            texture_id,
+            bounds,
+            source,
+            opacity,
+        });
+    }
+
+    /// Cut everything painted until [`pop_clip`](Self::pop_clip) to `bounds`
+    ///
+    /// Clip regions nest: content is cut to the intersection of all pushed
+    /// regions. Each pushed region must be popped.
+    pub fn push_clip(&mut self, bounds: Bounds) {
+        self.commands.push(DrawCommand::PushClip { bounds });
+    }
+
+    /// End the clip region started by the last [`push_clip`](Self::push_clip)
+    pub fn pop_clip(&mut self) {
+        self.commands.push(DrawCommand::PopClip);
+    }
+
+    /// Blur the backgrounds and sprites behind `bounds` (frosted glass)
+    ///
+    /// Paint the panel's tint and content afterwards; they are drawn on top.
+    pub fn draw_backdrop_blur(&mut self, bounds: Bounds, radius: f32) {
+        self.commands
+            .push(DrawCommand::BackdropBlur { bounds, radius });
+    }
+
     // Video frame drawing removed - was video-editing specific
     // /// Draw a video frame from RGBA data
     // /// Uses Arc to avoid cloning large frame buffers
@@ -203,6 +318,20 @@ pub trait Element: Send + Sync {
         &[]
     }
 
+    /// Offset subtracted from the children's layout positions when painting
+    ///
+    /// Scrolling containers return their scroll position.
+    fn scroll_offset(&self) -> Point {
+        Point::ZERO
+    }
+
+    /// Whether children are cut to this element's bounds when painting
+    ///
+    /// Children entirely outside the bounds are not painted at all.
+    fn clips_children(&self) -> bool {
+        false
+    }
+
     /// Get mutable children elements
     fn children_mut(&mut self) -> &mut [Box<dyn Element>] {
         &mut []
@@ -541,6 +670,395 @@ impl Element for Container {
     }
 }
 
+/// A vertically scrolling viewport
+///
+/// Scrolls with the mouse wheel and by dragging the scrollbar thumb, and draws
+/// the scrollbar just right of the viewport. Used two ways:
+///
+/// - As a container: children are laid out in a grid of `columns` columns and
+///   painted shifted by the scroll position and cut to the viewport. The owner
+///   sets the content height and passes the viewport with
+///   [`set_viewport`](Self::set_viewport) before forwarding events.
+/// - Embedded in an element that paints its own content: the element paints
+///   through [`paint_content`](Self::paint_content), asks which items are in
+///   view with [`visible_range`](Self::visible_range) or
+///   [`visible_items`](Self::visible_items) (so only those are painted), and
+///   paints the scrollbar with [`paint_scrollbar`](Self::paint_scrollbar).
+///
+/// Offsets and heights are in pixels; content coordinates start at the top
+/// of the content.
+pub struct ScrollView {
+    id: ElementId,
+    layout_node: Option<NodeId>,
+    children: Vec<Box<dyn Element>>,
+    /// Grid columns for children
+    columns: usize,
+    /// Gap between children, and between items of `visible_items`
+    gap: f32,
+    /// Viewport used for events, set by the owner
+    viewport: Bounds,
+    /// Total height of the content
+    content_height: f32,
+    /// Scroll position (0 = top)
+    offset: f32,
+    /// Pixels scrolled per wheel step
+    step: f32,
+    scrollbar_width: f32,
+    thumb_color: Color,
+    /// Grab point within the thumb while it is dragged
+    drag_grab: Option<f32>,
+}
+
+impl ScrollView {
+    /// Gap between the viewport and the scrollbar
+    const SCROLLBAR_GAP: f32 = 4.0;
+    /// Smallest thumb height, so long content keeps a grabbable thumb
+    const MIN_THUMB_HEIGHT: f32 = 24.0;
+
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::new(),
+            layout_node: None,
+            children: Vec::new(),
+            columns: 1,
+            gap: 0.0,
+            viewport: Bounds::ZERO,
+            content_height: 0.0,
+            offset: 0.0,
+            step: 40.0,
+            scrollbar_width: 8.0,
+            thumb_color: crate::theme::colors::ACCENT_PRIMARY,
+            drag_grab: None,
+        }
+    }
+
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the pixels scrolled per wheel step
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn with_scrollbar_width(mut self, width: f32) -> Self {
+        self.scrollbar_width = width;
+        self
+    }
+
+    pub fn with_thumb_color(mut self, color: Color) -> Self {
+        self.thumb_color = color;
+        self
+    }
+
+    pub fn with_child(mut self, child: Box<dyn Element>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn add_child(&mut self, child: Box<dyn Element>) {
+        self.children.push(child);
+    }
+
+    /// Change the number of grid columns, keeping the scroll position
+    pub fn set_columns(&mut self, columns: usize) {
+        self.columns = columns.max(1);
+    }
+
+    /// Replace the children, keeping the scroll position
+    pub fn set_children(&mut self, children: Vec<Box<dyn Element>>) {
+        self.children = children;
+    }
+
+    /// Set the viewport events are handled for
+    pub fn set_viewport(&mut self, viewport: Bounds) {
+        self.viewport = viewport;
+        self.offset = self.clamp_offset(self.offset, viewport);
+    }
+
+    pub fn viewport(&self) -> Bounds {
+        self.viewport
+    }
+
+    /// Set the total height of the content
+    pub fn set_content_height(&mut self, height: f32) {
+        self.content_height = height.max(0.0);
+        self.offset = self.clamp_offset(self.offset, self.viewport);
+    }
+
+    pub fn content_height(&self) -> f32 {
+        self.content_height
+    }
+
+    /// Current scroll position
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Largest scroll position for the viewport
+    pub fn max_offset(&self) -> f32 {
+        self.max_offset_for(self.viewport)
+    }
+
+    /// Whether the content is taller than the viewport
+    pub fn is_scrollable(&self) -> bool {
+        self.max_offset() > 0.0
+    }
+
+    /// Whether the scrollbar thumb is being dragged
+    pub fn is_dragging(&self) -> bool {
+        self.drag_grab.is_some()
+    }
+
+    /// Scroll by `delta` pixels (positive = down); returns true if the position changed
+    pub fn scroll_by(&mut self, delta: f32) -> bool {
+        self.scroll_to(self.offset + delta)
+    }
+
+    /// Scroll to a position; returns true if the position changed
+    pub fn scroll_to(&mut self, offset: f32) -> bool {
+        let offset = self.clamp_offset(offset, self.viewport);
+        if offset == self.offset {
+            return false;
+        }
+        self.offset = offset;
+        true
+    }
+
+    /// Scroll to the end of the content
+    pub fn scroll_to_end(&mut self) -> bool {
+        self.scroll_to(self.max_offset())
+    }
+
+    /// Scroll just enough to bring the content range `top..bottom` into view
+    ///
+    /// Returns true if the position changed.
+    pub fn scroll_to_show(&mut self, top: f32, bottom: f32) -> bool {
+        let height = self.viewport.height();
+        if height <= 0.0 {
+            return false;
+        }
+        if top < self.offset {
+            self.scroll_to(top)
+        } else if bottom > self.offset + height {
+            self.scroll_to(bottom - height)
+        } else {
+            false
+        }
+    }
+
+    /// Indices of uniform rows of height `row_height` (plus the gap) in view
+    ///
+    /// Rows partly in view are included.
+    pub fn visible_range(&self, viewport: Bounds, row_height: f32, count: usize) -> Range<usize> {
+        let stride = row_height + self.gap;
+        if stride <= 0.0 || count == 0 {
+            return 0..0;
+        }
+        let offset = self.offset_in(viewport);
+        let first = (offset / stride).floor().max(0.0) as usize;
+        let last = ((offset + viewport.height()) / stride).ceil().max(0.0) as usize;
+        first.min(count)..last.min(count)
+    }
+
+    /// Screen bounds of the items in view, for items of the given heights
+    ///
+    /// Items are stacked from the top of the viewport, `gap` apart, and span
+    /// its width. Items partly in view are included.
+    pub fn visible_items(
+        &self,
+        viewport: Bounds,
+        heights: impl IntoIterator<Item = f32>,
+    ) -> Vec<(usize, Bounds)> {
+        let mut visible = Vec::new();
+        let mut y = viewport.y() - self.offset_in(viewport);
+        for (index, height) in heights.into_iter().enumerate() {
+            if y >= viewport.bottom() {
+                break;
+            }
+            if y + height > viewport.y() {
+                visible.push((
+                    index,
+                    Bounds::new(viewport.x(), y, viewport.width(), height),
+                ));
+            }
+            y += height + self.gap;
+        }
+        visible
+    }
+
+    /// Paint content cut to `viewport`
+    ///
+    /// `paint` gets the scroll position; content at content position `y` is
+    /// drawn at `viewport.y() + y - offset`.
+    pub fn paint_content(
+        &self,
+        cx: &mut PaintContext,
+        viewport: Bounds,
+        paint: impl FnOnce(&mut PaintContext, f32),
+    ) {
+        cx.push_clip(viewport);
+        paint(cx, self.offset_in(viewport));
+        cx.pop_clip();
+    }
+
+    /// Paint the scrollbar thumb beside `viewport` if the content is scrollable
+    pub fn paint_scrollbar(&self, cx: &mut PaintContext, viewport: Bounds) {
+        if let Some(thumb) = self.thumb_bounds(viewport) {
+            cx.fill_rounded_rect(thumb, self.thumb_color, self.scrollbar_width / 2.0);
+        }
+    }
+
+    /// Bounds of the scrollbar thumb, or None if the content fits
+    pub fn thumb_bounds(&self, viewport: Bounds) -> Option<Bounds> {
+        let max_offset = self.max_offset_for(viewport);
+        if max_offset <= 0.0 {
+            return None;
+        }
+        let height = viewport.height();
+        let thumb_height = (height * height / self.content_height)
+            .max(Self::MIN_THUMB_HEIGHT)
+            .min(height);
+        let travel = height - thumb_height;
+        let y = viewport.y() + self.offset_in(viewport) / max_offset * travel;
+        Some(Bounds::new(
+            viewport.right() + Self::SCROLLBAR_GAP,
+            y,
+            self.scrollbar_width,
+            thumb_height,
+        ))
+    }
+
+    /// Handle wheel scrolling and thumb dragging for the viewport
+    ///
+    /// Returns true if the event was used. Keys are left to the owner.
+    pub fn handle_scroll_event(&mut self, event: &InputEvent) -> bool {
+        match event {
+            InputEvent::MouseScroll { delta, .. } => {
+                // Positive wheel delta scrolls up
+                self.scroll_by(-delta.y * self.step);
+                true
+            }
+            InputEvent::MouseDown { position, .. } => match self.thumb_bounds(self.viewport) {
+                Some(thumb) if thumb.contains(*position) => {
+                    self.drag_grab = Some(position.y - thumb.y());
+                    true
+                }
+                _ => false,
+            },
+            InputEvent::MouseMove { position, .. } => {
+                let (Some(grab), Some(thumb)) = (self.drag_grab, self.thumb_bounds(self.viewport))
+                else {
+                    return false;
+                };
+                let travel = self.viewport.height() - thumb.height();
+                if travel > 0.0 {
+                    let ratio = (position.y - grab - self.viewport.y()) / travel;
+                    self.scroll_to(ratio * self.max_offset());
+                }
+                true
+            }
+            InputEvent::MouseUp { .. } => self.drag_grab.take().is_some(),
+            _ => false,
+        }
+    }
+
+    fn max_offset_for(&self, viewport: Bounds) -> f32 {
+        (self.content_height - viewport.height()).max(0.0)
+    }
+
+    fn clamp_offset(&self, offset: f32, viewport: Bounds) -> f32 {
+        offset.clamp(0.0, self.max_offset_for(viewport))
+    }
+
+    /// Scroll position, kept within range for `viewport`
+    fn offset_in(&self, viewport: Bounds) -> f32 {
+        self.clamp_offset(self.offset, viewport)
+    }
+}
+
+impl Default for ScrollView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for ScrollView {
+    fn id(&self) -> ElementId {
+        self.id
+    }
+
+    fn layout_node(&self) -> Option<NodeId> {
+        self.layout_node
+    }
+
+    fn set_layout_node(&mut self, node: NodeId) {
+        self.layout_node = Some(node);
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutContext) -> taffy::Style {
+        use taffy::prelude::*;
+
+        Style {
+            display: Display::Grid,
+            grid_template_columns: vec![repeat(self.columns as u16, vec![auto()])],
+            justify_content: Some(JustifyContent::Center),
+            align_content: Some(AlignContent::Start),
+            gap: taffy::Size::length(self.gap),
+            flex_grow: 1.0,
+            min_size: taffy::Size {
+                width: Dimension::length(0.0),
+                height: Dimension::length(0.0),
+            },
+            overflow: taffy::Point {
+                x: taffy::Overflow::Visible,
+                y: taffy::Overflow::Scroll,
+            },
+            scrollbar_width: 0.0,
+            ..Default::default()
+        }
+    }
+
+    fn paint(&self, cx: &mut PaintContext) {
+        self.paint_scrollbar(cx, cx.bounds);
+    }
+
+    fn handle_event(&mut self, event: &InputEvent, _bounds: Bounds) -> bool {
+        self.handle_scroll_event(event)
+    }
+
+    fn children(&self) -> &[Box<dyn Element>] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Element>] {
+        &mut self.children
+    }
+
+    fn scroll_offset(&self) -> Point {
+        Point::new(0.0, self.offset)
+    }
+
+    fn clips_children(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /// A text element for displaying labels
 pub struct Text {
     id: ElementId,
@@ -656,3 +1174,87 @@ pub trait VideoElement: Element {
     /// Mark as redrawn
     fn mark_drawn(&mut self);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::input::{Modifiers, MouseButton};
+
+    /// 100px viewport over 400px of content
+    fn scroll_view() -> ScrollView {
+        let mut scroll = ScrollView::new().with_gap(10.0);
+        scroll.set_viewport(Bounds::new(0.0, 0.0, 200.0, 100.0));
+        scroll.set_content_height(400.0);
+        scroll
+    }
+
+    #[test]
+    fn test_scroll_view_clamps_offset() {
+        let mut scroll = scroll_view();
+        assert_eq!(scroll.max_offset(), 300.0);
+        assert!(scroll.scroll_by(500.0));
+        assert_eq!(scroll.offset(), 300.0);
+        assert!(!scroll.scroll_to_end());
+        assert!(scroll.scroll_to(-10.0));
+        assert_eq!(scroll.offset(), 0.0);
+
+        // Bring 150..190 into view at the bottom, then 20..60 at the top
+        assert!(scroll.scroll_to_show(150.0, 190.0));
+        assert_eq!(scroll.offset(), 90.0);
+        assert!(scroll.scroll_to_show(20.0, 60.0));
+        assert_eq!(scroll.offset(), 20.0);
+    }
+
+    #[test]
+    fn test_scroll_view_virtualization() {
+        let mut scroll = scroll_view();
+        scroll.scroll_to(75.0);
+        let viewport = scroll.viewport();
+
+        // Rows of 40 with a gap of 10 start every 50px
+        assert_eq!(scroll.visible_range(viewport, 40.0, 8), 1..4);
+        assert_eq!(scroll.visible_range(viewport, 40.0, 2), 1..2);
+
+        let items = scroll.visible_items(viewport, [40.0; 8]);
+        let indices: Vec<_> = items.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![1, 2, 3]);
+        assert_eq!(items[0].1, Bounds::new(0.0, -25.0, 200.0, 40.0));
+    }
+
+    #[test]
+    fn test_scroll_view_wheel_and_drag() {
+        let modifiers = Modifiers::none();
+        let mut scroll = scroll_view().with_step(20.0);
+
+        assert!(scroll.handle_scroll_event(&InputEvent::MouseScroll {
+            delta: Point::new(0.0, -2.0),
+            position: Point::new(50.0, 50.0),
+            modifiers,
+        }));
+        assert_eq!(scroll.offset(), 40.0);
+
+        let Some(thumb) = scroll.thumb_bounds(scroll.viewport()) else {
+            panic!("scrollable content should have a thumb");
+        };
+        let grab = Point::new(thumb.x() + 1.0, thumb.y() + 1.0);
+        assert!(scroll.handle_scroll_event(&InputEvent::MouseDown {
+            position: grab,
+            button: MouseButton::Left,
+            modifiers,
+        }));
+        assert!(scroll.is_dragging());
+
+        // Dragging the thumb to the bottom of the track scrolls to the end
+        assert!(scroll.handle_scroll_event(&InputEvent::MouseMove {
+            position: Point::new(grab.x, 100.0),
+            modifiers,
+        }));
+        assert_eq!(scroll.offset(), 300.0);
+        assert!(scroll.handle_scroll_event(&InputEvent::MouseUp {
+            position: grab,
+            button: MouseButton::Left,
+            modifiers,
+        }));
+        assert!(!scroll.is_dragging());
+    }
+}
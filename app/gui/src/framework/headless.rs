@@ -0,0 +1,281 @@
+//! Headless window for driving element trees without a GPU
+//!
+//! `HeadlessWindow` runs the same tick → layout → paint cycle as [`Window`](super::Window)
+//! but paints into a draw command list instead of a wgpu surface. Tests inject synthetic
+//! [`InputEvent`]s and inspect the root element or the painted text, so menu flows can be
+//! checked in CI without opening a window or clicking by hand.
+//!
+//! ```
+//! use narrative_gui::framework::HeadlessWindow;
+//! use narrative_gui::components::common::Button;
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicBool, Ordering};
+//!
+//! let clicked = Arc::new(AtomicBool::new(false));
+//! let flag = Arc::clone(&clicked);
+//! let button = Button::new("OK").with_on_click(move || flag.store(true, Ordering::SeqCst));
+//!
+//! let mut window = HeadlessWindow::new(1280, 720, Box::new(button));
+//! window.frame().unwrap();
+//! assert!(window.click_text("OK"));
+//! assert!(clicked.load(Ordering::SeqCst));
+//! ```
+
+use super::element::Element;
+use super::error::FrameworkResult;
+use super::input::{InputEvent, InputState, KeyCode, Modifiers, MouseButton};
+use super::layout::{Bounds, LayoutEngine, Point, Size};
+use super::renderer::{BatchBuilder, CommandBuffer, DrawCommand, ZLayer};
+use super::window::{build_layout_tree, paint_element_tree_batched, paint_overlay_tree_batched};
+use std::time::Duration;
+
+/// Frame time used by [`HeadlessWindow::frame`] (60 FPS)
+const FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+/// Window without a surface that records draw commands
+pub struct HeadlessWindow {
+    root: Box<dyn Element>,
+    size: Size,
+    layout_engine: LayoutEngine,
+    input_state: InputState,
+    /// Whether the next frame must lay out the tree
+    needs_layout: bool,
+    /// Commands painted by one element, reused across frames
+    paint_buffer: CommandBuffer,
+    /// Batched commands of the frame, reused across frames
+    batch: BatchBuilder,
+    draw_commands: Vec<DrawCommand>,
+}
+
+impl HeadlessWindow {
+    /// Create a headless window of the given size around a root element
+    pub fn new(width: u32, height: u32, root: Box<dyn Element>) -> Self {
+        Self {
+            root,
+            size: Size::new(width as f32, height as f32),
+            layout_engine: LayoutEngine::new(),
+            input_state: InputState::default(),
+            needs_layout: true,
+            paint_buffer: CommandBuffer::new(),
+            batch: BatchBuilder::new(),
+            draw_commands: Vec::new(),
+        }
+    }
+
+    /// Window bounds passed to the root element
+    pub fn bounds(&self) -> Bounds {
+        Bounds::new(0.0, 0.0, self.size.width, self.size.height)
+    }
+
+    /// Root element
+    pub fn root(&self) -> &dyn Element {
+        self.root.as_ref()
+    }
+
+    /// Root element as a concrete type
+    pub fn root_as<T: 'static>(&self) -> Option<&T> {
+        self.root.as_any().downcast_ref::<T>()
+    }
+
+    /// Root element as a mutable concrete type
+    pub fn root_as_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.root.as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Current input state (mouse position, pressed keys and buttons)
+    pub fn input_state(&self) -> &InputState {
+        &self.input_state
+    }
+
+    /// Dispatch an input event to the root element
+    ///
+    /// Returns whether the root element handled the event. A handled event
+    /// lays out the tree again on the next frame.
+    pub fn dispatch(&mut self, event: InputEvent) -> bool {
+        self.input_state.handle_event(&event);
+        let bounds = self.bounds();
+        let handled = self.root.handle_event(&event, bounds);
+        if handled {
+            self.needs_layout = true;
+        }
+        handled
+    }
+
+    /// Run one frame with the default 60 FPS frame time
+    pub fn frame(&mut self) -> FrameworkResult<()> {
+        self.advance(FRAME_TIME)
+    }
+
+    /// Run frames until `duration` has elapsed
+    pub fn run_for(&mut self, duration: Duration) -> FrameworkResult<()> {
+        let mut elapsed = Duration::ZERO;
+        while elapsed < duration {
+            self.frame()?;
+            elapsed += FRAME_TIME;
+        }
+        Ok(())
+    }
+
+    /// Run one frame: tick, layout and paint
+    ///
+    /// Like [`Window`](super::Window), the tree is laid out on the first frame
+    /// and whenever `tick` reports a change. The layout tree is then rebuilt
+    /// from scratch, so element trees that replace their children during
+    /// `tick` are always laid out correctly.
+    ///
+    /// Paint storage is reused between frames, so a frame that paints the same
+    /// content as the previous one doesn't allocate.
+    pub fn advance(&mut self, delta: Duration) -> FrameworkResult<()> {
+        if self.root.tick(delta) {
+            self.needs_layout = true;
+        }
+
+        if self.needs_layout {
+            self.layout_engine = LayoutEngine::new();
+            let root_node = build_layout_tree(self.root.as_mut(), &mut self.layout_engine, true)?;
+            self.layout_engine.compute_layout(root_node, self.size)?;
+            self.needs_layout = false;
+        }
+
+        let bounds = self.bounds();
+        paint_element_tree_batched(
+            self.root.as_ref(),
+            bounds,
+            &self.layout_engine,
+            &mut self.paint_buffer,
+            &mut self.batch,
+            ZLayer::DEFAULT,
+        );
+        paint_overlay_tree_batched(
+            self.root.as_ref(),
+            bounds,
+            &self.layout_engine,
+            &mut self.paint_buffer,
+            &mut self.batch,
+        );
+        self.paint_buffer.finish_frame();
+
+        self.batch.build_into(&mut self.draw_commands);
+        Ok(())
+    }
+
+    /// Draw commands painted by the last frame
+    pub fn draw_commands(&self) -> &[DrawCommand] {
+        &self.draw_commands
+    }
+
+    /// Text painted by the last frame, in draw order
+    pub fn painted_text(&self) -> Vec<&str> {
+        self.draw_commands
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Position of the topmost painted text matching `text` exactly
+    pub fn text_position(&self, text: &str) -> Option<Point> {
+        self.draw_commands
+            .iter()
+            .rev()
+            .find_map(|command| match command {
+                DrawCommand::Text {
+                    text: painted,
+                    position,
+                    ..
+                } if painted == text => Some(*position),
+                _ => None,
+            })
+    }
+
+    /// Move the mouse to a position
+    pub fn mouse_move(&mut self, position: Point) -> bool {
+        self.dispatch(InputEvent::MouseMove {
+            position,
+            modifiers: Modifiers::none(),
+        })
+    }
+
+    /// Left-click at a position (move, press and release)
+    ///
+    /// Returns whether the root element handled any of the events.
+    pub fn click(&mut self, position: Point) -> bool {
+        let moved = self.mouse_move(position);
+        let pressed = self.dispatch(InputEvent::MouseDown {
+            button: MouseButton::Left,
+            position,
+            modifiers: Modifiers::none(),
+        });
+        let released = self.dispatch(InputEvent::MouseUp {
+            button: MouseButton::Left,
+            position,
+            modifiers: Modifiers::none(),
+        });
+        moved || pressed || released
+    }
+
+    /// Left-click on the painted text matching `text`
+    ///
+    /// Returns `false` if the text was not painted by the last frame or the
+    /// click was not handled.
+    pub fn click_text(&mut self, text: &str) -> bool {
+        match self.text_position(text) {
+            Some(position) => self.click(position),
+            None => false,
+        }
+    }
+
+    /// Press and release a key
+    ///
+    /// Returns whether the root element handled the key press.
+    pub fn press_key(&mut self, key: KeyCode) -> bool {
+        let handled = self.dispatch(InputEvent::KeyDown {
+            key,
+            modifiers: Modifiers::none(),
+        });
+        self.dispatch(InputEvent::KeyUp {
+            key,
+            modifiers: Modifiers::none(),
+        });
+        handled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::common::Button;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_headless_frame_paints_text() {
+        let mut window = HeadlessWindow::new(1280, 720, Box::new(Button::new("Start")));
+        assert!(window.painted_text().is_empty());
+
+        window.frame().unwrap();
+        assert!(window.painted_text().contains(&"Start"));
+        assert!(window.text_position("Start").is_some());
+        assert!(window.text_position("Missing").is_none());
+    }
+
+    #[test]
+    fn test_headless_click_text() {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&clicks);
+        let button = Button::new("Start").with_on_click(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let mut window = HeadlessWindow::new(1280, 720, Box::new(button));
+
+        // Nothing painted yet, so there is nothing to click
+        assert!(!window.click_text("Start"));
+
+        window.frame().unwrap();
+        assert!(window.click_text("Start"));
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+        assert!(window.root_as::<Button>().is_some());
+    }
+}
@@ -0,0 +1,195 @@
+//! Opacity masks for pixel-accurate hit testing
+//!
+//! Image choices and hotspots are often irregularly shaped, so clicking their
+//! transparent corners should fall through. [`HitMask`] keeps a downscaled
+//! opacity mask of a texture, built once from its RGBA data, that hit testing
+//! can query without holding on to the full image.
+
+use super::layout::{Bounds, Point};
+
+/// Default longest side of a hit mask, in cells
+pub const DEFAULT_HIT_MASK_SIZE: u32 = 128;
+
+/// Options for building a [`HitMask`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitMaskOptions {
+    /// Pixels with an alpha at or below this value are transparent
+    pub alpha_threshold: u8,
+    /// Pixels of exactly this RGB color are transparent (for art without alpha)
+    pub color_key: Option<[u8; 3]>,
+    /// Longest side of the mask in cells; larger textures are downscaled
+    pub max_size: u32,
+}
+
+impl Default for HitMaskOptions {
+    fn default() -> Self {
+        Self {
+            alpha_threshold: 0,
+            color_key: None,
+            max_size: DEFAULT_HIT_MASK_SIZE,
+        }
+    }
+}
+
+impl HitMaskOptions {
+    pub fn with_alpha_threshold(mut self, threshold: u8) -> Self {
+        self.alpha_threshold = threshold;
+        self
+    }
+
+    pub fn with_color_key(mut self, color: [u8; 3]) -> Self {
+        self.color_key = Some(color);
+        self
+    }
+
+    pub fn with_max_size(mut self, max_size: u32) -> Self {
+        self.max_size = max_size.max(1);
+        self
+    }
+
+    fn is_opaque(&self, pixel: &[u8]) -> bool {
+        if pixel[3] <= self.alpha_threshold {
+            return false;
+        }
+        self.color_key.is_none_or(|key| pixel[..3] != key[..])
+    }
+}
+
+/// Downscaled opacity mask of a texture
+///
+/// Each cell covers a square block of source pixels and is opaque if any pixel
+/// in the block is, so downscaling never makes visible art unclickable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HitMask {
+    width: u32,
+    height: u32,
+    cells: Vec<bool>,
+}
+
+impl HitMask {
+    /// Build a mask from RGBA pixel data
+    ///
+    /// Returns `None` if the texture is empty or `rgba` is not `width * height * 4` bytes.
+    pub fn from_rgba(
+        rgba: &[u8],
+        width: u32,
+        height: u32,
+        options: &HitMaskOptions,
+    ) -> Option<Self> {
+        let pixel_count = (width as usize).checked_mul(height as usize)?;
+        if pixel_count == 0 || rgba.len() != pixel_count.checked_mul(4)? {
+            return None;
+        }
+
+        // Source pixels per cell along each axis
+        let scale = width.max(height).div_ceil(options.max_size.max(1));
+        let mask_width = width.div_ceil(scale);
+        let mask_height = height.div_ceil(scale);
+        let mut cells = vec![false; (mask_width * mask_height) as usize];
+
+        for (index, pixel) in rgba.chunks_exact(4).enumerate() {
+            if options.is_opaque(pixel) {
+                let x = index as u32 % width / scale;
+                let y = index as u32 / width / scale;
+                cells[(y * mask_width + x) as usize] = true;
+            }
+        }
+
+        Some(Self {
+            width: mask_width,
+            height: mask_height,
+            cells,
+        })
+    }
+
+    /// Mask size in cells
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Check whether the texture is opaque at normalized coordinates (0.0-1.0)
+    pub fn is_opaque_at(&self, u: f32, v: f32) -> bool {
+        if !(0.0..=1.0).contains(&u) || !(0.0..=1.0).contains(&v) {
+            return false;
+        }
+        let x = ((u * self.width as f32) as u32).min(self.width - 1);
+        let y = ((v * self.height as f32) as u32).min(self.height - 1);
+        self.cells[(y * self.width + x) as usize]
+    }
+
+    /// Check whether a point hits the texture drawn stretched over `bounds`
+    pub fn contains(&self, point: Point, bounds: Bounds) -> bool {
+        if !bounds.contains(point) || bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+            return false;
+        }
+        self.is_opaque_at(
+            (point.x - bounds.x()) / bounds.width(),
+            (point.y - bounds.y()) / bounds.height(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 4x4 texture: opaque red left half, transparent right half,
+    /// except a green top-right pixel
+    fn rgba() -> Vec<u8> {
+        let mut data = Vec::new();
+        for y in 0..4 {
+            for x in 0..4 {
+                let pixel = match (x, y) {
+                    (0..=1, _) => [255, 0, 0, 255],
+                    (3, 0) => [0, 255, 0, 255],
+                    _ => [0, 0, 0, 0],
+                };
+                data.extend_from_slice(&pixel);
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_hit_mask_alpha() {
+        let mask = HitMask::from_rgba(&rgba(), 4, 4, &HitMaskOptions::default()).unwrap();
+        let bounds = Bounds::new(100.0, 100.0, 40.0, 40.0);
+
+        assert_eq!(mask.size(), (4, 4));
+        assert!(mask.contains(Point::new(105.0, 135.0), bounds));
+        assert!(mask.contains(Point::new(135.0, 105.0), bounds));
+        assert!(!mask.contains(Point::new(135.0, 135.0), bounds));
+        assert!(!mask.contains(Point::new(95.0, 105.0), bounds));
+    }
+
+    #[test]
+    fn test_hit_mask_color_key_and_threshold() {
+        let options = HitMaskOptions::default().with_color_key([0, 255, 0]);
+        let mask = HitMask::from_rgba(&rgba(), 4, 4, &options).unwrap();
+        assert!(!mask.is_opaque_at(0.9, 0.1));
+        assert!(mask.is_opaque_at(0.1, 0.1));
+
+        let options = HitMaskOptions::default().with_alpha_threshold(255);
+        let mask = HitMask::from_rgba(&rgba(), 4, 4, &options).unwrap();
+        assert!(!mask.is_opaque_at(0.1, 0.1));
+    }
+
+    #[test]
+    fn test_hit_mask_downscale() {
+        let options = HitMaskOptions::default().with_max_size(2);
+        let mask = HitMask::from_rgba(&rgba(), 4, 4, &options).unwrap();
+
+        // The top-right cell keeps the single opaque pixel it covers
+        assert_eq!(mask.size(), (2, 2));
+        assert!(mask.is_opaque_at(0.9, 0.1));
+        assert!(!mask.is_opaque_at(0.9, 0.9));
+        assert!(mask.is_opaque_at(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_hit_mask_invalid_data() {
+        let options = HitMaskOptions::default();
+        assert!(HitMask::from_rgba(&[0; 12], 2, 2, &options).is_none());
+        assert!(HitMask::from_rgba(&[], 0, 0, &options).is_none());
+    }
+}
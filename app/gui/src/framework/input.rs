@@ -120,6 +120,13 @@ pub enum KeyCode {
     Unknown,
 }
 
+impl KeyCode {
+    /// Variant name (e.g. "Enter", "F5"), as stored in key bindings
+    pub fn name(self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 impl From<winit::keyboard::KeyCode> for KeyCode {
     fn from(key: winit::keyboard::KeyCode) -> Self {
         use winit::keyboard::KeyCode as WK;
@@ -269,6 +276,17 @@ pub enum InputEvent {
     /// Character input (for text input)
     CharInput { character: char },
 
+    /// IME composition text changed (empty when composition ends)
+    ///
+    /// `cursor` is the byte range of the IME cursor within `text`.
+    ImePreedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// IME committed composed text
+    ImeCommit { text: String },
+
     /// Window focused
     Focus,
 
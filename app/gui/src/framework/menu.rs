@@ -18,6 +18,7 @@ pub enum MenuId {
     NewProject,
     OpenProject,
     Save,
+    Load,
     SaveAs,
     Export,
     Settings,
@@ -62,6 +63,7 @@ impl MenuId {
             "new_project" => Some(Self::NewProject),
             "open_project" => Some(Self::OpenProject),
             "save" => Some(Self::Save),
+            "load" => Some(Self::Load),
             "save_as" => Some(Self::SaveAs),
             "export" => Some(Self::Export),
             "settings" => Some(Self::Settings),
@@ -319,10 +321,7 @@ impl AppMenu {
                         name: Some("Narrative".to_string()),
                         version: Some(env!("CARGO_PKG_VERSION").to_string()),
                         copyright: Some("Copyright (c) 2024 Narrative Contributors".to_string()),
-                        comments: Some(
-                            "A wgpu-based visual novel engine"
-                                .to_string(),
-                        ),
+                        comments: Some("A wgpu-based visual novel engine".to_string()),
                         ..Default::default()
                     }),
                 ),
@@ -351,59 +350,169 @@ impl AppMenu {
         }
     }
 
-    /// Initialize the menu for a window (Windows-specific)
-    #[cfg(target_os = "windows")]
+    /// Initialize the menu for a window
     pub fn init_for_window(&self, window: &winit::window::Window) {
-        use raw_window_handle::HasWindowHandle;
-        if let Ok(handle) = window.window_handle()
-            && let raw_window_handle::RawWindowHandle::Win32(win32_handle) = handle.as_raw()
-        {
-            let hwnd = win32_handle.hwnd.get() as *mut std::ffi::c_void;
-            // Safety: hwnd is obtained from a valid Window handle via raw-window-handle crate.
-            // The pointer is guaranteed to be valid for the lifetime of the window.
-            // This is safe because:
-            // 1. window_handle() returns Ok only for valid windows
-            // 2. Win32Handle contains a valid HWND
-            // 3. muda's init_for_hwnd performs validation
-            unsafe {
-                if let Err(e) = self.menu.init_for_hwnd(hwnd as isize) {
-                    tracing::error!("Failed to initialize menu for HWND: {}", e);
-                }
-            }
-        }
+        init_menu_for_window(&self.menu, window);
     }
+}
+
+impl Default for AppMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Game information shown in the game menu's About dialog
+#[derive(Debug, Clone, Default)]
+pub struct GameMenuInfo {
+    /// Game title
+    pub name: String,
+    /// Game version
+    pub version: String,
+    /// Developer/author name
+    pub developer: Option<String>,
+}
+
+/// Menu bar for game builds
+///
+/// Offers Save/Load, Preferences, Toggle Fullscreen and an About dialog built
+/// from the game's metadata.
+pub struct GameMenu {
+    pub menu: Menu,
+    pub game_menu: Submenu,
+    pub view_menu: Submenu,
+    pub help_menu: Submenu,
+}
+
+impl GameMenu {
+    /// Create a new game menu bar
+    pub fn new(info: &GameMenuInfo) -> Self {
+        let menu = Menu::new();
+
+        // Game menu
+        let game_menu = Submenu::new("Game", true);
+        game_menu
+            .append_items(&[
+                &MenuItem::with_id(
+                    "save",
+                    "Save...",
+                    true,
+                    Some(Accelerator::new(Some(Modifiers::CONTROL), Code::KeyS)),
+                ),
+                &MenuItem::with_id(
+                    "load",
+                    "Load...",
+                    true,
+                    Some(Accelerator::new(Some(Modifiers::CONTROL), Code::KeyO)),
+                ),
+                &PredefinedMenuItem::separator(),
+                &MenuItem::with_id(
+                    "settings",
+                    "Preferences...",
+                    true,
+                    Some(Accelerator::new(Some(Modifiers::CONTROL), Code::Comma)),
+                ),
+                &PredefinedMenuItem::separator(),
+                &MenuItem::with_id(
+                    "exit",
+                    "Exit",
+                    true,
+                    Some(Accelerator::new(Some(Modifiers::ALT), Code::F4)),
+                ),
+            ])
+            .map_err(|e| tracing::error!("Failed to create game menu items: {}", e))
+            .ok();
+
+        // View menu
+        let view_menu = Submenu::new("View", true);
+        view_menu
+            .append_items(&[&MenuItem::with_id(
+                "toggle_fullscreen",
+                "Toggle Fullscreen",
+                true,
+                Some(Accelerator::new(None, Code::F11)),
+            )])
+            .map_err(|e| tracing::error!("Failed to create view menu items: {}", e))
+            .ok();
+
+        // Help menu
+        let help_menu = Submenu::new("Help", true);
+        help_menu
+            .append_items(&[&PredefinedMenuItem::about(
+                Some(&format!("About {}", info.name)),
+                Some(AboutMetadata {
+                    name: Some(info.name.clone()),
+                    version: Some(info.version.clone()),
+                    authors: info.developer.clone().map(|developer| vec![developer]),
+                    ..Default::default()
+                }),
+            )])
+            .map_err(|e| tracing::error!("Failed to create help menu items: {}", e))
+            .ok();
 
-    /// Initialize the menu for a window (macOS-specific)
-    #[cfg(target_os = "macos")]
-    pub fn init_for_window(&self, _window: &winit::window::Window) {
-        // On macOS, menus are app-global, not per-window
-        if let Err(e) = self.menu.init_for_nsapp() {
-            tracing::error!("Failed to initialize menu for NSApp: {}", e);
+        menu.append_items(&[&game_menu, &view_menu, &help_menu])
+            .map_err(|e| tracing::error!("Failed to create menu bar: {}", e))
+            .ok();
+
+        Self {
+            menu,
+            game_menu,
+            view_menu,
+            help_menu,
         }
     }
 
-    /// Initialize the menu for a window (Linux-specific)
-    #[cfg(target_os = "linux")]
+    /// Initialize the menu for a window
     pub fn init_for_window(&self, window: &winit::window::Window) {
-        use raw_window_handle::HasWindowHandle;
-        // GTK menus need special handling
-        // For now, this is a placeholder
-        tracing::warn!("Native menu bar on Linux requires GTK integration");
+        init_menu_for_window(&self.menu, window);
     }
+}
 
-    /// Fallback for other platforms
-    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
-    pub fn init_for_window(&self, _window: &winit::window::Window) {
-        tracing::warn!("Native menu bar not supported on this platform");
+/// Initialize a menu bar for a window (Windows-specific)
+#[cfg(target_os = "windows")]
+pub(crate) fn init_menu_for_window(menu: &Menu, window: &winit::window::Window) {
+    use raw_window_handle::HasWindowHandle;
+    if let Ok(handle) = window.window_handle()
+        && let raw_window_handle::RawWindowHandle::Win32(win32_handle) = handle.as_raw()
+    {
+        let hwnd = win32_handle.hwnd.get() as *mut std::ffi::c_void;
+        // Safety: hwnd is obtained from a valid Window handle via raw-window-handle crate.
+        // The pointer is guaranteed to be valid for the lifetime of the window.
+        // This is safe because:
+        // 1. window_handle() returns Ok only for valid windows
+        // 2. Win32Handle contains a valid HWND
+        // 3. muda's init_for_hwnd performs validation
+        unsafe {
+            if let Err(e) = menu.init_for_hwnd(hwnd as isize) {
+                tracing::error!("Failed to initialize menu for HWND: {}", e);
+            }
+        }
     }
 }
 
-impl Default for AppMenu {
-    fn default() -> Self {
-        Self::new()
+/// Initialize a menu bar for a window (macOS-specific)
+#[cfg(target_os = "macos")]
+pub(crate) fn init_menu_for_window(menu: &Menu, _window: &winit::window::Window) {
+    // On macOS, menus are app-global, not per-window
+    if let Err(e) = menu.init_for_nsapp() {
+        tracing::error!("Failed to initialize menu for NSApp: {}", e);
     }
 }
 
+/// Initialize a menu bar for a window (Linux-specific)
+#[cfg(target_os = "linux")]
+pub(crate) fn init_menu_for_window(_menu: &Menu, _window: &winit::window::Window) {
+    // GTK menus need special handling
+    // For now, this is a placeholder
+    tracing::warn!("Native menu bar on Linux requires GTK integration");
+}
+
+/// Fallback for other platforms
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+pub(crate) fn init_menu_for_window(_menu: &Menu, _window: &winit::window::Window) {
+    tracing::warn!("Native menu bar not supported on this platform");
+}
+
 /// Menu event handler that processes menu item clicks
 pub struct MenuEventHandler {
     receiver: MenuEventReceiver,
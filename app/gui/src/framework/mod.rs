@@ -30,6 +30,8 @@ pub mod dev_assets;
 pub mod dirty;
 pub mod element;
 pub mod error;
+pub mod headless;
+pub mod hit_mask;
 pub mod input;
 pub mod layout;
 pub mod menu;
@@ -51,9 +53,11 @@ pub use element::{
     VideoElement, WindowOperation,
 };
 pub use error::{FrameworkError, FrameworkResult};
+pub use headless::HeadlessWindow;
+pub use hit_mask::{HitMask, HitMaskOptions};
 pub use input::{InputEvent, KeyCode, MouseButton};
 pub use layout::{Bounds, Point, Size};
-pub use menu::{AppMenu, MenuEventHandler, MenuId};
+pub use menu::{AppMenu, GameMenu, GameMenuInfo, MenuEventHandler, MenuId};
 pub use metrics::{FrameMetrics, FrameTiming, PerformanceStats};
 pub use reactive::{
     Effect, EffectId, ReactiveRuntime, RuntimeStats, Signal, SignalId, SubscriptionId,
@@ -63,8 +67,8 @@ pub use render_graph::{
     ExecutionOrder, GraphStats, PassContext, PassId, RenderGraph, RenderGraphError, RenderPass,
     Resource, ResourceAccess, ResourceId, ResourceType, ResourceUsage,
 };
-pub use renderer::{BatchBuilder, BatchStats, Renderer, ZLayer};
-pub use window::{PresentMode, Window, WindowContext, WindowOptions};
+pub use renderer::{BatchBuilder, BatchStats, CommandBuffer, Renderer, SharedString, ZLayer};
+pub use window::{DEFAULT_MAX_FRAME_DELTA, PresentMode, Window, WindowContext, WindowOptions};
 
 /// Color representation (RGBA, 0.0-1.0)
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -7,8 +7,9 @@
 //! - Z-order sorting for correct layering
 //! - Command type grouping to minimize pipeline switches
 //! - Draw call counting for metrics
+//! - Reusable storage: a builder kept across frames stops allocating
 
-use super::{DrawCommand, TextDraw};
+use super::{DrawCommand, SharedString, TextAlign, TextDraw, TextSpacing};
 use crate::framework::Color;
 use crate::framework::layout::{Bounds, Point};
 use std::sync::Arc;
@@ -31,6 +32,18 @@ impl ZLayer {
     pub const POPUP: ZLayer = ZLayer(2000);
     /// Debug/metrics overlay (always on top)
     pub const DEBUG: ZLayer = ZLayer(i32::MAX);
+
+    /// Name of the layer in GPU debug groups
+    pub fn debug_name(&self) -> &'static str {
+        match *self {
+            Self::BACKGROUND => "Background Layer",
+            Self::DEFAULT => "Default Layer",
+            Self::OVERLAY => "Overlay Layer",
+            Self::POPUP => "Popup Layer",
+            Self::DEBUG => "Debug Layer",
+            _ => "Custom Layer",
+        }
+    }
 }
 
 /// A draw command with z-order information
@@ -68,6 +81,7 @@ enum CommandType {
     Border,
     Text,
     Texture,
+    Backdrop,
     Clip,
 }
 
@@ -78,7 +92,10 @@ impl CommandType {
             DrawCommand::Rect { .. } => CommandType::Rect,
             DrawCommand::Border { .. } => CommandType::Border,
             DrawCommand::Text { .. } => CommandType::Text,
-            DrawCommand::Texture { .. } => CommandType::Texture,
+            DrawCommand::Texture { .. } | DrawCommand::TextureRegion { .. } => {
+                CommandType::Texture
+            }
+            DrawCommand::BackdropBlur { .. } => CommandType::Backdrop,
             DrawCommand::PushClip { .. } | DrawCommand::PopClip => CommandType::Clip,
         }
     }
@@ -125,6 +142,17 @@ impl BatchBuilder {
         self.current_order += 1;
     }
 
+    /// Add commands at a specific layer, in order
+    pub fn extend_at_layer(
+        &mut self,
+        commands: impl IntoIterator<Item = DrawCommand>,
+        layer: ZLayer,
+    ) {
+        for command in commands {
+            self.push_at_layer(command, layer);
+        }
+    }
+
     /// Add a rectangle at the default layer
     pub fn rect(&mut self, bounds: Bounds, color: Color, corner_radius: f32) {
         self.push(DrawCommand::Rect {
@@ -153,19 +181,29 @@ impl BatchBuilder {
     }
 
     /// Add text at the default layer
-    pub fn text(&mut self, text: String, position: Point, color: Color, font_size: f32) {
+    pub fn text(
+        &mut self,
+        text: impl Into<SharedString>,
+        position: Point,
+        color: Color,
+        font_size: f32,
+    ) {
         self.push(DrawCommand::Text {
-            text,
+            text: text.into(),
             position,
             color,
             font_size,
+            align: TextAlign::Left,
+            markup: None,
+            spacing: TextSpacing::default(),
+            clip: None,
         });
     }
 
     /// Add text at a specific layer
     pub fn text_at_layer(
         &mut self,
-        text: String,
+        text: impl Into<SharedString>,
         position: Point,
         color: Color,
         font_size: f32,
@@ -173,10 +211,14 @@ impl BatchBuilder {
     ) {
         self.push_at_layer(
             DrawCommand::Text {
-                text,
+                text: text.into(),
                 position,
                 color,
                 font_size,
+                align: TextAlign::Left,
+                markup: None,
+                spacing: TextSpacing::default(),
+                clip: None,
             },
             layer,
         );
@@ -212,19 +254,16 @@ impl BatchBuilder {
         self.commands.is_empty()
     }
 
-    /// Build the optimized command list and return statistics
+    /// Sort commands for optimal rendering order
     ///
-    /// This sorts commands for optimal rendering order:
     /// 1. First by z-layer (lower layers first)
     /// 2. Within each layer, group by command type to minimize pipeline switches
     /// 3. Within each type group, maintain original order for deterministic rendering
-    pub fn build(mut self) -> (Vec<DrawCommand>, BatchStats) {
-        if self.commands.is_empty() {
-            return (Vec::new(), BatchStats::default());
-        }
-
-        // Sort by layer, then by command type for batching, then by original order
-        self.commands.sort_by(|a, b| {
+    ///
+    /// The insertion order makes every key unique, so the unstable sort (which
+    /// sorts in place without a scratch buffer) is deterministic.
+    fn sort(&mut self) {
+        self.commands.sort_unstable_by(|a, b| {
             a.layer
                 .cmp(&b.layer)
                 .then_with(|| {
@@ -233,6 +272,31 @@ impl BatchBuilder {
                 })
                 .then_with(|| a.order.cmp(&b.order))
         });
+    }
+
+    /// Build the optimized command list and return statistics
+    ///
+    /// See [`build_into`](Self::build_into) for the ordering.
+    pub fn build(mut self) -> (Vec<DrawCommand>, BatchStats) {
+        let mut commands = Vec::with_capacity(self.commands.len());
+        let stats = self.build_into(&mut commands);
+        (commands, stats)
+    }
+
+    /// Build the optimized command list into `out`, leaving the builder empty
+    ///
+    /// `out` is cleared first. Both `out` and the builder keep their storage,
+    /// so building every frame into the same list doesn't allocate once the
+    /// command count is stable. Commands are sorted by layer, then by type,
+    /// then by insertion order.
+    pub fn build_into(&mut self, out: &mut Vec<DrawCommand>) -> BatchStats {
+        out.clear();
+        if self.commands.is_empty() {
+            self.clear();
+            return BatchStats::default();
+        }
+
+        self.sort();
 
         let mut stats = BatchStats::default();
         let mut last_type: Option<CommandType> = None;
@@ -270,36 +334,30 @@ impl BatchBuilder {
         }
         // video_count removed
 
-        let commands = self.commands.into_iter().map(|lc| lc.command).collect();
+        out.extend(self.commands.drain(..).map(|lc| lc.command));
+        self.current_order = 0;
 
-        (commands, stats)
+        stats
     }
 
     /// Build commands grouped by layer for proper z-order rendering
     ///
     /// Returns a Vec of (layer, commands) pairs sorted by layer.
     /// Each layer's commands are sorted by type then original order.
-    pub fn build_by_layer(mut self) -> (Vec<(ZLayer, Vec<DrawCommand>)>, BatchStats) {
+    /// The builder is left empty with its storage kept for the next frame.
+    pub fn build_by_layer(&mut self) -> (Vec<(ZLayer, Vec<DrawCommand>)>, BatchStats) {
         if self.commands.is_empty() {
+            self.clear();
             return (Vec::new(), BatchStats::default());
         }
 
-        // Sort by layer, then by command type for batching, then by original order
-        self.commands.sort_by(|a, b| {
-            a.layer
-                .cmp(&b.layer)
-                .then_with(|| {
-                    CommandType::from_command(&a.command)
-                        .cmp(&CommandType::from_command(&b.command))
-                })
-                .then_with(|| a.order.cmp(&b.order))
-        });
+        self.sort();
 
         let mut stats = BatchStats::default();
         let mut layers: Vec<(ZLayer, Vec<DrawCommand>)> = Vec::new();
         let mut current_layer: Option<ZLayer> = None;
 
-        for cmd in self.commands {
+        for cmd in self.commands.drain(..) {
             // Count by type
             match &cmd.command {
                 DrawCommand::Rect { .. } => stats.quad_count += 1,
@@ -319,6 +377,7 @@ impl BatchBuilder {
                 cmds.push(cmd.command);
             }
         }
+        self.current_order = 0;
 
         // Count draw calls per layer (each layer may have quads + text)
         for (_, cmds) in &layers {
@@ -422,12 +481,7 @@ mod tests {
     fn test_batch_builder_mixed_types() {
         let mut builder = BatchBuilder::new();
         builder.rect(Bounds::new(0.0, 0.0, 100.0, 100.0), Color::RED, 0.0);
-        builder.text(
-            "Hello".to_string(),
-            Point::new(10.0, 10.0),
-            Color::WHITE,
-            12.0,
-        );
+        builder.text("Hello", Point::new(10.0, 10.0), Color::WHITE, 12.0);
         builder.rect(Bounds::new(50.0, 50.0, 100.0, 100.0), Color::GREEN, 0.0);
 
         let (commands, stats) = builder.build();
@@ -476,6 +530,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_batch_builder_build_into_reuses_storage() {
+        let mut builder = BatchBuilder::with_capacity(4);
+        let mut commands = Vec::with_capacity(4);
+
+        for _ in 0..2 {
+            builder.text("Hello", Point::new(10.0, 10.0), Color::WHITE, 12.0);
+            builder.rect(Bounds::new(0.0, 0.0, 100.0, 100.0), Color::RED, 0.0);
+
+            let stats = builder.build_into(&mut commands);
+            assert_eq!(stats.draw_calls, 2);
+            assert!(builder.is_empty());
+            // Quads are grouped before text
+            assert!(matches!(commands[0], DrawCommand::Rect { .. }));
+            assert!(matches!(&commands[1], DrawCommand::Text { text, .. } if text == "Hello"));
+        }
+        assert_eq!(commands.capacity(), 4);
+    }
+
     #[test]
     fn test_layer_constants() {
         assert!(ZLayer::BACKGROUND < ZLayer::DEFAULT);
@@ -483,4 +556,10 @@ mod tests {
         assert!(ZLayer::OVERLAY < ZLayer::POPUP);
         assert!(ZLayer::POPUP < ZLayer::DEBUG);
     }
+
+    #[test]
+    fn test_layer_debug_names() {
+        assert_eq!(ZLayer::OVERLAY.debug_name(), "Overlay Layer");
+        assert_eq!(ZLayer(5).debug_name(), "Custom Layer");
+    }
 }
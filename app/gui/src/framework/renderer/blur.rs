@@ -0,0 +1,542 @@
+//! Backdrop blur renderer for frosted-glass panels
+
+use crate::framework::layout::Bounds;
+use bytemuck::{Pod, Zeroable};
+
+/// Backdrops are blurred at 1/DOWNSCALE of the screen resolution
+const DOWNSCALE: u32 = 4;
+
+/// Uniform data for one blur shader pass
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+struct BlurUniforms {
+    rect: [f32; 4],
+    texel: [f32; 2],
+    direction: [f32; 2],
+    radius: f32,
+    _padding: [f32; 3],
+}
+
+/// Screen region to draw blurred
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlurRegion {
+    pub bounds: Bounds,
+    /// Blur radius in screen pixels
+    pub radius: f32,
+}
+
+/// Offscreen render target with a bind group for sampling it
+struct BlurTarget {
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+/// Screen-sized offscreen targets
+struct BlurTargets {
+    screen_size: (u32, u32),
+    /// Full resolution texture layer
+    scene: BlurTarget,
+    /// Downscaled texture layer
+    down: BlurTarget,
+    /// Horizontally blurred regions
+    temp: BlurTarget,
+    /// Fully blurred regions
+    blurred: BlurTarget,
+}
+
+/// Renderer for `DrawCommand::BackdropBlur`
+///
+/// # Passes per frame
+///
+/// ```text
+/// [texture layer] ──render──> scene (full resolution)
+/// scene ──downscale──> down (1/4 resolution)
+/// for each region:
+///     down ──horizontal blur──> temp
+///     temp ──vertical blur──> blurred
+/// [main pass] blurred ──composite──> surface (after textures, before quads)
+/// ```
+///
+/// Only the texture layer (backgrounds, characters) is blurred: quads and text
+/// are always drawn on top of it, so panels put their own fill and content over
+/// the blurred region. Offscreen targets are created on first use and recreated
+/// when the screen size changes.
+pub struct BlurRenderer {
+    copy_pipeline: wgpu::RenderPipeline,
+    blur_pipeline: wgpu::RenderPipeline,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    uniform_bind_group_layout: wgpu::BindGroupLayout,
+    uniform_buffer: wgpu::Buffer,
+    uniform_bind_group: wgpu::BindGroup,
+    /// Number of uniform slots in `uniform_buffer`
+    uniform_capacity: u64,
+    /// Byte distance between uniform slots (dynamic offset alignment)
+    uniform_stride: u64,
+    sampler: wgpu::Sampler,
+    surface_format: wgpu::TextureFormat,
+    targets: Option<BlurTargets>,
+    /// Regions prepared for this frame, clipped to the screen
+    ///
+    /// Kept in command order (off-screen regions become empty) so callers can
+    /// composite a range of them per z-layer.
+    regions: Vec<BlurRegion>,
+}
+
+impl BlurRenderer {
+    pub fn new(device: &wgpu::Device, surface_format: wgpu::TextureFormat) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Blur Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/blur.wgsl").into()),
+        });
+
+        let uniform_size = std::mem::size_of::<BlurUniforms>() as u64;
+        let uniform_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Blur Uniform Bind Group Layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(uniform_size),
+                    },
+                    count: None,
+                }],
+            });
+
+        let texture_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Blur Texture Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Blur Pipeline Layout"),
+            bind_group_layouts: &[&uniform_bind_group_layout, &texture_bind_group_layout],
+            immediate_size: 0,
+        });
+
+        let create_pipeline = |label: &str, entry_point: &str| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: surface_format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview_mask: None,
+                cache: None,
+            })
+        };
+        let copy_pipeline = create_pipeline("Blur Copy Pipeline", "fs_copy");
+        let blur_pipeline = create_pipeline("Blur Pipeline", "fs_blur");
+
+        let alignment = u64::from(device.limits().min_uniform_buffer_offset_alignment);
+        let uniform_stride = uniform_size.div_ceil(alignment) * alignment;
+        let uniform_capacity = 16;
+        let (uniform_buffer, uniform_bind_group) = Self::create_uniform_buffer(
+            device,
+            &uniform_bind_group_layout,
+            uniform_stride * uniform_capacity,
+        );
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Blur Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Self {
+            copy_pipeline,
+            blur_pipeline,
+            texture_bind_group_layout,
+            uniform_bind_group_layout,
+            uniform_buffer,
+            uniform_bind_group,
+            uniform_capacity,
+            uniform_stride,
+            sampler,
+            surface_format,
+            targets: None,
+            regions: Vec::new(),
+        }
+    }
+
+    fn create_uniform_buffer(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        size: u64,
+    ) -> (wgpu::Buffer, wgpu::BindGroup) {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Blur Uniform Buffer"),
+            size,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Uniform Bind Group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(std::mem::size_of::<BlurUniforms>() as u64),
+                }),
+            }],
+        });
+        (buffer, bind_group)
+    }
+
+    fn create_target(&self, device: &wgpu::Device, size: (u32, u32), label: &str) -> BlurTarget {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width: size.0,
+                height: size.1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.surface_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(label),
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+        BlurTarget {
+            _texture: texture,
+            view,
+            bind_group,
+        }
+    }
+
+    /// Prepare the blur passes for this frame's regions
+    ///
+    /// This must be called BEFORE the render pass. Returns whether any region
+    /// needs drawing; if not, the other methods do nothing this frame.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        screen_size: (u32, u32),
+        regions: &[BlurRegion],
+    ) -> bool {
+        self.regions.clear();
+        if screen_size.0 == 0 || screen_size.1 == 0 {
+            return false;
+        }
+
+        let screen = Bounds::new(0.0, 0.0, screen_size.0 as f32, screen_size.1 as f32);
+        self.regions.extend(regions.iter().map(|region| BlurRegion {
+            bounds: region.bounds.intersection(&screen).unwrap_or(Bounds::ZERO),
+            radius: region.radius.max(0.0),
+        }));
+        if !self.regions.iter().any(is_visible) {
+            self.regions.clear();
+            return false;
+        }
+
+        if self
+            .targets
+            .as_ref()
+            .is_none_or(|targets| targets.screen_size != screen_size)
+        {
+            let down_size = (
+                screen_size.0.div_ceil(DOWNSCALE),
+                screen_size.1.div_ceil(DOWNSCALE),
+            );
+            self.targets = Some(BlurTargets {
+                screen_size,
+                scene: self.create_target(device, screen_size, "Blur Scene Target"),
+                down: self.create_target(device, down_size, "Blur Downscale Target"),
+                temp: self.create_target(device, down_size, "Blur Temp Target"),
+                blurred: self.create_target(device, down_size, "Blur Result Target"),
+            });
+        }
+
+        let uniforms = Self::pass_uniforms(screen_size, &self.regions);
+        let required = uniforms.len() as u64;
+        if required > self.uniform_capacity {
+            self.uniform_capacity = required.next_power_of_two();
+            (self.uniform_buffer, self.uniform_bind_group) = Self::create_uniform_buffer(
+                device,
+                &self.uniform_bind_group_layout,
+                self.uniform_stride * self.uniform_capacity,
+            );
+        }
+
+        let stride = self.uniform_stride as usize;
+        let mut data = vec![0u8; stride * uniforms.len()];
+        for (slot, uniform) in uniforms.iter().enumerate() {
+            let bytes = bytemuck::bytes_of(uniform);
+            data[slot * stride..slot * stride + bytes.len()].copy_from_slice(bytes);
+        }
+        queue.write_buffer(&self.uniform_buffer, 0, &data);
+
+        true
+    }
+
+    /// Uniforms for every pass of a frame, in slot order
+    ///
+    /// Slot 0 is the downscale; each region then takes three slots
+    /// (horizontal blur, vertical blur, composite).
+    fn pass_uniforms(screen_size: (u32, u32), regions: &[BlurRegion]) -> Vec<BlurUniforms> {
+        let (width, height) = (screen_size.0 as f32, screen_size.1 as f32);
+        let down_texel = [
+            1.0 / screen_size.0.div_ceil(DOWNSCALE) as f32,
+            1.0 / screen_size.1.div_ceil(DOWNSCALE) as f32,
+        ];
+        let normalized = |bounds: Bounds| {
+            let bounds = bounds
+                .intersection(&Bounds::new(0.0, 0.0, width, height))
+                .unwrap_or(Bounds::ZERO);
+            [
+                bounds.x() / width,
+                bounds.y() / height,
+                bounds.width() / width,
+                bounds.height() / height,
+            ]
+        };
+        let pass = |rect, direction, radius| BlurUniforms {
+            rect,
+            texel: down_texel,
+            direction,
+            radius,
+            _padding: [0.0; 3],
+        };
+
+        let mut uniforms = vec![pass([0.0, 0.0, 1.0, 1.0], [0.0, 0.0], 0.0)];
+        for region in regions {
+            let radius = region.radius / DOWNSCALE as f32;
+            // The vertical pass reads `radius` above and below the region,
+            // so the horizontal pass has to cover that band too
+            let band = Bounds::new(
+                region.bounds.x(),
+                region.bounds.y() - region.radius,
+                region.bounds.width(),
+                region.bounds.height() + region.radius * 2.0,
+            );
+            let rect = normalized(region.bounds);
+            uniforms.push(pass(normalized(band), [1.0, 0.0], radius));
+            uniforms.push(pass(rect, [0.0, 1.0], radius));
+            uniforms.push(pass(rect, [0.0, 0.0], 0.0));
+        }
+        uniforms
+    }
+
+    /// Offscreen target the texture layer is rendered into before blurring
+    pub fn scene_view(&self) -> Option<&wgpu::TextureView> {
+        if self.regions.is_empty() {
+            return None;
+        }
+        self.targets.as_ref().map(|targets| &targets.scene.view)
+    }
+
+    fn dynamic_offset(&self, slot: usize) -> u32 {
+        (slot as u64 * self.uniform_stride) as u32
+    }
+
+    fn draw_pass(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &BlurTarget,
+        source: &BlurTarget,
+        pipeline: &wgpu::RenderPipeline,
+        slot: usize,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Blur Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target.view,
+                resolve_target: None,
+                depth_slice: None,
+                ops: wgpu::Operations {
+                    load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &self.uniform_bind_group, &[self.dynamic_offset(slot)]);
+        render_pass.set_bind_group(1, &source.bind_group, &[]);
+        render_pass.draw(0..6, 0..1);
+    }
+
+    /// Downscale the scene target and blur every region
+    ///
+    /// Call after the texture layer has been rendered into [`scene_view`](Self::scene_view).
+    pub fn blur(&self, encoder: &mut wgpu::CommandEncoder) {
+        let Some(targets) = self.targets.as_ref() else {
+            return;
+        };
+        if self.regions.is_empty() {
+            return;
+        }
+
+        self.draw_pass(
+            encoder,
+            &targets.down,
+            &targets.scene,
+            &self.copy_pipeline,
+            0,
+            wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+        );
+
+        for (index, region) in self.regions.iter().enumerate() {
+            if !is_visible(region) {
+                continue;
+            }
+            let slot = 1 + index * 3;
+            self.draw_pass(
+                encoder,
+                &targets.temp,
+                &targets.down,
+                &self.blur_pipeline,
+                slot,
+                wgpu::LoadOp::Load,
+            );
+            self.draw_pass(
+                encoder,
+                &targets.blurred,
+                &targets.temp,
+                &self.blur_pipeline,
+                slot + 1,
+                wgpu::LoadOp::Load,
+            );
+        }
+    }
+
+    /// Draw the blurred regions into the current render pass
+    pub fn composite(&self, render_pass: &mut wgpu::RenderPass<'_>) {
+        self.composite_range(render_pass, 0..self.regions.len());
+    }
+
+    /// Draw the blurred regions in `regions` (indices in `prepare()` order)
+    pub fn composite_range(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        regions: std::ops::Range<usize>,
+    ) {
+        let Some(targets) = self.targets.as_ref() else {
+            return;
+        };
+        let Some(selected) = self.regions.get(regions.clone()) else {
+            return;
+        };
+        if !selected.iter().any(is_visible) {
+            return;
+        }
+
+        render_pass.set_pipeline(&self.copy_pipeline);
+        render_pass.set_bind_group(1, &targets.blurred.bind_group, &[]);
+        for (index, region) in regions.zip(selected) {
+            if !is_visible(region) {
+                continue;
+            }
+            let slot = 3 + index * 3;
+            render_pass.set_bind_group(0, &self.uniform_bind_group, &[self.dynamic_offset(slot)]);
+            render_pass.draw(0..6, 0..1);
+        }
+    }
+}
+
+/// Check whether a prepared region covers any pixels
+fn is_visible(region: &BlurRegion) -> bool {
+    region.bounds.width() > 0.0 && region.bounds.height() > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blur_uniforms_layout() {
+        // Must match the WGSL struct: vec4 + vec2 + vec2 + f32 + 3 padding floats
+        assert_eq!(std::mem::size_of::<BlurUniforms>(), 48);
+        assert_eq!(std::mem::align_of::<BlurUniforms>(), 4);
+    }
+
+    #[test]
+    fn test_blur_pass_uniforms() {
+        let regions = [BlurRegion {
+            bounds: Bounds::new(200.0, 100.0, 400.0, 200.0),
+            radius: 40.0,
+        }];
+        let uniforms = BlurRenderer::pass_uniforms((800, 400), &regions);
+
+        // Downscale + horizontal, vertical and composite per region
+        assert_eq!(uniforms.len(), 4);
+        assert_eq!(uniforms[0].rect, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(uniforms[0].texel, [1.0 / 200.0, 1.0 / 100.0]);
+
+        // Horizontal pass covers the band the vertical pass samples
+        assert_eq!(uniforms[1].rect, [0.25, 0.15, 0.5, 0.7]);
+        assert_eq!(uniforms[1].direction, [1.0, 0.0]);
+        assert_eq!(uniforms[1].radius, 10.0);
+
+        assert_eq!(uniforms[2].rect, [0.25, 0.25, 0.5, 0.5]);
+        assert_eq!(uniforms[2].direction, [0.0, 1.0]);
+        assert_eq!(uniforms[3].rect, [0.25, 0.25, 0.5, 0.5]);
+        assert_eq!(uniforms[3].radius, 0.0);
+    }
+}
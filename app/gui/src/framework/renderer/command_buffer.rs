@@ -0,0 +1,501 @@
+//! Pooled draw command storage
+//!
+//! Painting a frame produces the same commands over and over while a scene is
+//! static. [`CommandBuffer`] keeps its command storage between frames and
+//! interns painted text as [`SharedString`]s, so once a static frame has been
+//! painted, painting it again does not touch the heap.
+
+use super::DrawCommand;
+use crate::framework::layout::Bounds;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Immutable string that is cheap to clone
+///
+/// Either a `&'static str` or a reference-counted string. Cloning never
+/// allocates.
+#[derive(Clone)]
+pub enum SharedString {
+    /// String literal
+    Static(&'static str),
+    /// Reference-counted string
+    Shared(Arc<str>),
+}
+
+impl SharedString {
+    /// The string contents
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Static(text) => text,
+            Self::Shared(text) => text,
+        }
+    }
+}
+
+impl Deref for SharedString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SharedString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SharedString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for SharedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SharedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for SharedString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SharedString {}
+
+impl std::hash::Hash for SharedString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for SharedString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SharedString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<&'static str> for SharedString {
+    fn from(text: &'static str) -> Self {
+        Self::Static(text)
+    }
+}
+
+impl From<String> for SharedString {
+    fn from(text: String) -> Self {
+        Self::Shared(text.into())
+    }
+}
+
+impl From<Arc<str>> for SharedString {
+    fn from(text: Arc<str>) -> Self {
+        Self::Shared(text)
+    }
+}
+
+/// Interned strings of recent frames
+///
+/// Text painted in consecutive frames resolves to the same [`SharedString`],
+/// so only text that changed needs a new allocation. Strings that haven't
+/// been painted for a while are dropped by [`finish_frame`](Self::finish_frame).
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    /// Interned strings and the frame they were last used in
+    strings: HashMap<SharedString, u64>,
+    /// Current frame number
+    frame: u64,
+}
+
+impl StringInterner {
+    /// Frames an unused string is kept for
+    ///
+    /// Long enough for text that blinks or alternates between a few values.
+    const RETAIN_FRAMES: u64 = 60;
+
+    /// Create an empty interner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the shared copy of `text`, allocating only if it isn't interned
+    pub fn intern(&mut self, text: &str) -> SharedString {
+        let shared = match self.strings.get_key_value(text) {
+            Some((shared, _)) => shared.clone(),
+            None => SharedString::Shared(text.into()),
+        };
+        self.strings.insert(shared.clone(), self.frame);
+        shared
+    }
+
+    /// Number of interned strings
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Check if no strings are interned
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// End the frame, dropping strings unused for [`Self::RETAIN_FRAMES`]
+    pub fn finish_frame(&mut self) {
+        let frame = self.frame;
+        self.strings
+            .retain(|_, last_used| frame - *last_used < Self::RETAIN_FRAMES);
+        self.frame += 1;
+    }
+}
+
+/// Reusable list of draw commands for painting
+///
+/// Clearing keeps the allocated storage, and text is interned, so a buffer
+/// reused across frames stops allocating once the painted content is stable.
+///
+/// Clip regions are applied as commands are added (see [`push`](Self::push)),
+/// so the stored commands can be reordered freely by the batcher.
+#[derive(Debug, Default)]
+pub struct CommandBuffer {
+    /// Painted commands
+    commands: Vec<DrawCommand>,
+    /// Interned text of recent frames
+    strings: StringInterner,
+    /// Active clip regions, innermost last
+    clips: Vec<Bounds>,
+}
+
+impl CommandBuffer {
+    /// Create an empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create with pre-allocated command storage
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            commands: Vec::with_capacity(capacity),
+            strings: StringInterner::new(),
+            clips: Vec::new(),
+        }
+    }
+
+    /// Add a command
+    ///
+    /// `PushClip` and `PopClip` are not stored. While a clip region is active,
+    /// shapes and textures are cut to it (and dropped if nothing is left), and
+    /// text carries the region for the text renderer to cut its glyphs.
+    pub fn push(&mut self, command: DrawCommand) {
+        match command {
+            DrawCommand::PushClip { bounds } => {
+                let clip = match self.clips.last() {
+                    // Nothing of a clip region outside its parent is drawn
+                    Some(outer) => outer.intersection(&bounds).unwrap_or(Bounds::new(
+                        bounds.x(),
+                        bounds.y(),
+                        0.0,
+                        0.0,
+                    )),
+                    None => bounds,
+                };
+                self.clips.push(clip);
+            }
+            DrawCommand::PopClip => {
+                self.clips.pop();
+            }
+            command => match self.clips.last() {
+                Some(&clip) => self.push_clipped(command, clip),
+                None => self.commands.push(command),
+            },
+        }
+    }
+
+    /// Add a draw command cut to `clip`
+    fn push_clipped(&mut self, command: DrawCommand, clip: Bounds) {
+        match command {
+            DrawCommand::Rect {
+                bounds,
+                color,
+                corner_radius,
+            } => {
+                if let Some(bounds) = bounds.intersection(&clip) {
+                    self.commands.push(DrawCommand::Rect {
+                        bounds,
+                        color,
+                        corner_radius,
+                    });
+                }
+            }
+            DrawCommand::Border {
+                bounds,
+                color,
+                width,
+                corner_radius,
+            } => {
+                if is_inside(bounds, clip) {
+                    self.commands.push(DrawCommand::Border {
+                        bounds,
+                        color,
+                        width,
+                        corner_radius,
+                    });
+                    return;
+                }
+                // Cut each edge on its own
+                let edges = [
+                    Bounds::new(bounds.x(), bounds.y(), bounds.width(), width),
+                    Bounds::new(bounds.x(), bounds.bottom() - width, bounds.width(), width),
+                    Bounds::new(
+                        bounds.x(),
+                        bounds.y() + width,
+                        width,
+                        bounds.height() - 2.0 * width,
+                    ),
+                    Bounds::new(
+                        bounds.right() - width,
+                        bounds.y() + width,
+                        width,
+                        bounds.height() - 2.0 * width,
+                    ),
+                ];
+                for edge in edges {
+                    if let Some(bounds) = edge.intersection(&clip) {
+                        self.commands.push(DrawCommand::Rect {
+                            bounds,
+                            color,
+                            corner_radius: 0.0,
+                        });
+                    }
+                }
+            }
+            DrawCommand::Texture {
+                texture_id,
+                bounds,
+                opacity,
+            } if !is_inside(bounds, clip) => self.push_clipped(
+                DrawCommand::TextureRegion {
+                    texture_id,
+                    bounds,
+                    source: Bounds::new(0.0, 0.0, 1.0, 1.0),
+                    opacity,
+                },
+                clip,
+            ),
+            DrawCommand::TextureRegion {
+                texture_id,
+                bounds,
+                source,
+                opacity,
+            } if !is_inside(bounds, clip) => {
+                let Some(visible) = bounds.intersection(&clip) else {
+                    return;
+                };
+                // Keep the texture coordinates in step with the cut edges
+                let u = |x: f32| source.x() + (x - bounds.x()) / bounds.width() * source.width();
+                let v = |y: f32| source.y() + (y - bounds.y()) / bounds.height() * source.height();
+                self.commands.push(DrawCommand::TextureRegion {
+                    texture_id,
+                    bounds: visible,
+                    source: Bounds::new(
+                        u(visible.x()),
+                        v(visible.y()),
+                        u(visible.right()) - u(visible.x()),
+                        v(visible.bottom()) - v(visible.y()),
+                    ),
+                    opacity,
+                });
+            }
+            DrawCommand::Text {
+                text,
+                position,
+                color,
+                font_size,
+                align,
+                markup,
+                spacing,
+                clip: text_clip,
+            } => {
+                let clip = match text_clip {
+                    Some(text_clip) => text_clip.intersection(&clip).unwrap_or(Bounds::new(
+                        clip.x(),
+                        clip.y(),
+                        0.0,
+                        0.0,
+                    )),
+                    None => clip,
+                };
+                self.commands.push(DrawCommand::Text {
+                    text,
+                    position,
+                    color,
+                    font_size,
+                    align,
+                    markup,
+                    spacing,
+                    clip: Some(clip),
+                });
+            }
+            DrawCommand::BackdropBlur { bounds, radius } => {
+                if let Some(bounds) = bounds.intersection(&clip) {
+                    self.commands
+                        .push(DrawCommand::BackdropBlur { bounds, radius });
+                }
+            }
+            command => self.commands.push(command),
+        }
+    }
+
+    /// Get the shared copy of a painted string
+    pub fn intern(&mut self, text: &str) -> SharedString {
+        self.strings.intern(text)
+    }
+
+    /// Painted commands
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Number of commands
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Remove all commands, keeping the storage
+    pub fn clear(&mut self) {
+        self.commands.clear();
+        self.clips.clear();
+    }
+
+    /// Take out all commands, keeping the storage
+    ///
+    /// Open clip regions stay active for the commands added afterwards.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, DrawCommand> {
+        self.commands.drain(..)
+    }
+
+    /// End the frame, dropping text that hasn't been painted recently
+    ///
+    /// Clip regions left open are closed.
+    pub fn finish_frame(&mut self) {
+        self.strings.finish_frame();
+        self.clips.clear();
+    }
+}
+
+/// Whether `bounds` lies entirely within `clip`
+fn is_inside(bounds: Bounds, clip: Bounds) -> bool {
+    bounds.x() >= clip.x()
+        && bounds.y() >= clip.y()
+        && bounds.right() <= clip.right()
+        && bounds.bottom() <= clip.bottom()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::Color;
+    use crate::framework::layout::Point;
+    use crate::framework::renderer::{TextAlign, TextSpacing};
+
+    #[test]
+    fn test_intern_reuses_strings() {
+        let mut interner = StringInterner::new();
+        let first = interner.intern("Hello");
+        let second = interner.intern("Hello");
+
+        match (&first, &second) {
+            (SharedString::Shared(a), SharedString::Shared(b)) => assert!(Arc::ptr_eq(a, b)),
+            _ => panic!("interned strings should be shared"),
+        }
+        assert_eq!(first, "Hello");
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn test_unused_strings_are_dropped() {
+        let mut interner = StringInterner::new();
+        interner.intern("old");
+        for _ in 0..=StringInterner::RETAIN_FRAMES {
+            interner.intern("current");
+            interner.finish_frame();
+        }
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.intern("current"), "current");
+    }
+
+    #[test]
+    fn test_command_buffer_keeps_storage() {
+        let mut buffer = CommandBuffer::with_capacity(4);
+        let text = buffer.intern("Line");
+        buffer.push(DrawCommand::Text {
+            text,
+            position: Point::new(0.0, 0.0),
+            color: Color::WHITE,
+            font_size: 12.0,
+            align: TextAlign::Left,
+            markup: None,
+            spacing: TextSpacing::default(),
+            clip: None,
+        });
+        assert_eq!(buffer.len(), 1);
+
+        let capacity = buffer.commands.capacity();
+        assert_eq!(buffer.drain().count(), 1);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.commands.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_clip_regions_cut_commands() {
+        let rect = |x: f32, y: f32| DrawCommand::Rect {
+            bounds: Bounds::new(x, y, 100.0, 100.0),
+            color: Color::WHITE,
+            corner_radius: 0.0,
+        };
+        let mut buffer = CommandBuffer::new();
+        buffer.push(DrawCommand::PushClip {
+            bounds: Bounds::new(0.0, 0.0, 150.0, 150.0),
+        });
+        buffer.push(rect(100.0, 100.0));
+        // Entirely outside the clip region
+        buffer.push(rect(200.0, 0.0));
+        buffer.push(DrawCommand::PopClip);
+        buffer.push(rect(200.0, 0.0));
+
+        let bounds: Vec<_> = buffer
+            .drain()
+            .filter_map(|command| match command {
+                DrawCommand::Rect { bounds, .. } => Some(bounds),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            bounds,
+            vec![
+                Bounds::new(100.0, 100.0, 50.0, 50.0),
+                Bounds::new(200.0, 0.0, 100.0, 100.0),
+            ]
+        );
+    }
+}
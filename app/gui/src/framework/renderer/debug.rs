@@ -0,0 +1,95 @@
+//! GPU debug labels and markers
+//!
+//! With the `gpu-debug` feature, loaded textures are labelled with their asset
+//! path and each part of a frame is wrapped in a named debug group, so GPU
+//! captures (RenderDoc, Xcode, PIX) show what every resource and draw is.
+//! Without the feature the helpers do nothing and textures share one label.
+
+use std::borrow::Cow;
+
+/// Label of a texture loaded from `name` (usually its asset path)
+pub(crate) fn texture_label(name: &str) -> Cow<'static, str> {
+    #[cfg(feature = "gpu-debug")]
+    {
+        Cow::Owned(format!("Texture: {}", name))
+    }
+    #[cfg(not(feature = "gpu-debug"))]
+    {
+        let _ = name;
+        Cow::Borrowed("Loaded Texture")
+    }
+}
+
+/// Debug groups and markers on encoders and render passes
+pub(crate) trait DebugMarkers {
+    /// Open a named group; close it with [`end_debug_group`](Self::end_debug_group)
+    fn begin_debug_group(&mut self, label: &str);
+
+    /// Close the innermost open group
+    fn end_debug_group(&mut self);
+
+    /// Insert a single named marker
+    fn debug_marker(&mut self, label: &str);
+}
+
+impl DebugMarkers for wgpu::CommandEncoder {
+    fn begin_debug_group(&mut self, label: &str) {
+        #[cfg(feature = "gpu-debug")]
+        self.push_debug_group(label);
+        #[cfg(not(feature = "gpu-debug"))]
+        let _ = label;
+    }
+
+    fn end_debug_group(&mut self) {
+        #[cfg(feature = "gpu-debug")]
+        self.pop_debug_group();
+    }
+
+    fn debug_marker(&mut self, label: &str) {
+        #[cfg(feature = "gpu-debug")]
+        self.insert_debug_marker(label);
+        #[cfg(not(feature = "gpu-debug"))]
+        let _ = label;
+    }
+}
+
+impl DebugMarkers for wgpu::RenderPass<'_> {
+    fn begin_debug_group(&mut self, label: &str) {
+        #[cfg(feature = "gpu-debug")]
+        self.push_debug_group(label);
+        #[cfg(not(feature = "gpu-debug"))]
+        let _ = label;
+    }
+
+    fn end_debug_group(&mut self) {
+        #[cfg(feature = "gpu-debug")]
+        self.pop_debug_group();
+    }
+
+    fn debug_marker(&mut self, label: &str) {
+        #[cfg(feature = "gpu-debug")]
+        self.insert_debug_marker(label);
+        #[cfg(not(feature = "gpu-debug"))]
+        let _ = label;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "gpu-debug")]
+    #[test]
+    fn test_texture_label_names_asset() {
+        assert_eq!(
+            texture_label("assets/bg/room.png"),
+            "Texture: assets/bg/room.png"
+        );
+    }
+
+    #[cfg(not(feature = "gpu-debug"))]
+    #[test]
+    fn test_texture_label_is_shared_without_feature() {
+        assert_eq!(texture_label("assets/bg/room.png"), "Loaded Texture");
+    }
+}
@@ -6,23 +6,34 @@
 //! - `batch` module provides command sorting and grouping
 //! - Minimizes GPU pipeline state changes
 //! - Accurate draw call counting for metrics
+//! - `command_buffer` module pools paint storage and interns text between frames
+//! - `debug` module labels textures and frame sections for GPU captures (`gpu-debug` feature)
 
 mod batch;
+mod blur;
+mod command_buffer;
+mod debug;
 mod quad;
 mod text;
 mod texture;
 // Video rendering removed - was video-editing specific
 
 pub use batch::{BatchBuilder, BatchStats, LayeredCommand, ZLayer};
+pub use blur::{BlurRegion, BlurRenderer};
+pub use command_buffer::{CommandBuffer, SharedString, StringInterner};
 pub use quad::QuadRenderer;
-pub use text::{TextAlign, TextDraw, TextRenderer};
+pub use text::{TextAlign, TextDraw, TextMarkup, TextRenderer, TextSpacing};
 pub use texture::{TextureInstance, TextureRenderer};
 // Video rendering removed - was video-editing specific
 // pub use video::{VideoRenderer, VideoTexture};
 
 use super::Color;
+use super::hit_mask::{HitMask, HitMaskOptions};
 use super::layout::{Bounds, Point};
+use debug::{DebugMarkers, texture_label};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -46,10 +57,18 @@ pub enum DrawCommand {
 
     /// Draw text
     Text {
-        text: String,
+        text: SharedString,
         position: Point,
         color: Color,
         font_size: f32,
+        /// Which point of the text `position` is (see [`TextAlign`])
+        align: TextAlign,
+        /// Styled spans (color, bold, size, wave, shake), None for plain text
+        markup: Option<TextMarkup>,
+        /// Line, letter and paragraph spacing
+        spacing: TextSpacing,
+        /// Region the text is cut to (set by clip regions), None to draw it whole
+        clip: Option<Bounds>,
     },
 
     /// Draw a texture with opacity
@@ -59,6 +78,16 @@ pub enum DrawCommand {
         opacity: f32,
     },
 
+    /// Draw part of a texture with opacity
+    ///
+    /// `source` is in texture coordinates (0.0-1.0).
+    TextureRegion {
+        texture_id: u64,
+        bounds: Bounds,
+        source: Bounds,
+        opacity: f32,
+    },
+
     // VideoFrame removed - was video-editing specific
     // /// Draw a video frame (RGBA data)
     // /// Uses Arc to avoid cloning large frame buffers
@@ -68,7 +97,16 @@ pub enum DrawCommand {
     //     height: u32,
     //     bounds: Bounds,
     // },
+    /// Blur what the texture layer drew behind `bounds` (frosted glass)
+    ///
+    /// `radius` is in pixels. Quads and text are drawn on top of the blur, so a
+    /// panel paints its tint and content over it.
+    BackdropBlur { bounds: Bounds, radius: f32 },
+
     /// Push a clip region
+    ///
+    /// Commands painted until the matching `PopClip` are cut to `bounds` (and
+    /// to any enclosing clip region) when they are added to a [`CommandBuffer`].
     PushClip { bounds: Bounds },
 
     /// Pop a clip region
@@ -82,6 +120,8 @@ pub struct LoadedTexture {
     pub sampler: wgpu::Sampler,
     pub bind_group: wgpu::BindGroup,
     pub size: (u32, u32),
+    /// GPU debug label (the asset path with the `gpu-debug` feature)
+    pub label: Cow<'static, str>,
 }
 
 /// Error type for renderer operations
@@ -98,6 +138,12 @@ pub enum RendererError {
 
     #[error("Texture ID pool exhausted")]
     TextureIdPoolExhausted,
+
+    #[error("Invalid font data: {0}")]
+    InvalidFontData(String),
+
+    #[error("GPU readback failed: {0}")]
+    ReadbackError(String),
 }
 
 /// The main renderer that coordinates all rendering operations
@@ -108,11 +154,16 @@ pub struct Renderer {
     quad_renderer: QuadRenderer,
     text_renderer: TextRenderer,
     texture_renderer: TextureRenderer,
+    blur_renderer: BlurRenderer,
     // video_renderer removed - was video-editing specific
     // video_renderer: VideoRenderer,
     screen_size: (u32, u32),
+    // Offset added to everything drawn to the screen (screen shake)
+    camera_offset: [f32; 2],
     // Texture cache for loaded images
     textures: HashMap<u64, LoadedTexture>,
+    // Opacity masks for pixel-accurate hit testing, per texture
+    hit_masks: HashMap<u64, Arc<HitMask>>,
     next_texture_id: u64,
     // Cached video texture for preview - removed (was video-editing specific)
     // video_texture_cache: Option<(VideoTexture, wgpu::BindGroup)>,
@@ -130,6 +181,7 @@ impl Renderer {
         let quad_renderer = QuadRenderer::new(&device, surface_format);
         let text_renderer = TextRenderer::new(&device, &queue, surface_format, width, height);
         let texture_renderer = TextureRenderer::new(&device, surface_format);
+        let blur_renderer = BlurRenderer::new(&device, surface_format);
         // Video renderer removed - was video-editing specific
         // let video_renderer = VideoRenderer::new(&device, surface_format);
 
@@ -140,9 +192,12 @@ impl Renderer {
             quad_renderer,
             text_renderer,
             texture_renderer,
+            blur_renderer,
             // video_renderer removed
             screen_size: (width, height),
+            camera_offset: [0.0, 0.0],
             textures: HashMap::new(),
+            hit_masks: HashMap::new(),
             next_texture_id: 0,
             // video_texture_cache removed
         }
@@ -155,6 +210,20 @@ impl Renderer {
             .resize(&self.device, &self.queue, width, height);
     }
 
+    /// Offset everything drawn to the screen by `offset` pixels
+    ///
+    /// Used for screen shake. Offscreen renders
+    /// ([`render_to_texture`](Self::render_to_texture)) are not offset.
+    pub fn set_camera_offset(&mut self, offset: Point) {
+        self.camera_offset = [offset.x, offset.y];
+    }
+
+    /// Current camera offset
+    pub fn camera_offset(&self) -> Point {
+        let [x, y] = self.camera_offset;
+        Point::new(x, y)
+    }
+
     /// Render a frame
     pub fn render(
         &mut self,
@@ -165,6 +234,7 @@ impl Renderer {
         // Convert draw commands to quad instances and texture instances
         let mut quad_instances = Vec::new();
         let mut texture_instances: HashMap<u64, Vec<TextureInstance>> = HashMap::new();
+        let mut blur_regions = Vec::new();
         // video_frame_bounds removed - was video-editing specific
 
         for cmd in commands {
@@ -232,15 +302,21 @@ impl Renderer {
                     position,
                     color,
                     font_size,
+                    align,
+                    markup,
+                    spacing,
+                    clip,
                 } => {
                     self.text_renderer.queue_text(TextDraw {
                         text: text.clone(),
                         position: *position,
                         color: *color,
                         font_size: *font_size,
-                        line_height: None,
-                        align: TextAlign::Left,
+                        spacing: *spacing,
+                        align: *align,
                         max_width: None,
+                        markup: markup.clone(),
+                        clip: *clip,
                     });
                 }
                 DrawCommand::Texture {
@@ -252,12 +328,24 @@ impl Renderer {
                     texture_instances
                         .entry(*texture_id)
                         .or_default()
-                        .push(TextureInstance {
-                            position: [bounds.x(), bounds.y()],
-                            size: [bounds.width(), bounds.height()],
-                            opacity: *opacity,
-                            _padding: [0.0, 0.0, 0.0],
-                        });
+                        .push(TextureInstance::new(*bounds, *opacity));
+                }
+                DrawCommand::TextureRegion {
+                    texture_id,
+                    bounds,
+                    source,
+                    opacity,
+                } => {
+                    texture_instances
+                        .entry(*texture_id)
+                        .or_default()
+                        .push(TextureInstance::new(*bounds, *opacity).with_source(*source));
+                }
+                DrawCommand::BackdropBlur { bounds, radius } => {
+                    blur_regions.push(BlurRegion {
+                        bounds: *bounds,
+                        radius: *radius,
+                    });
                 }
                 // VideoFrame removed - was video-editing specific
                 DrawCommand::PushClip { bounds } => {
@@ -277,6 +365,7 @@ impl Renderer {
             &self.queue,
             self.screen_size.0,
             self.screen_size.1,
+            self.camera_offset,
         ) {
             tracing::error!("Failed to prepare text: {}", e);
         }
@@ -289,8 +378,13 @@ impl Renderer {
         // This avoids GPU synchronization issues that cause flickering
 
         // Prepare quad buffers
-        self.quad_renderer
-            .prepare(&self.device, &self.queue, &quad_instances, self.screen_size);
+        self.quad_renderer.prepare(
+            &self.device,
+            &self.queue,
+            &quad_instances,
+            self.screen_size,
+            self.camera_offset,
+        );
 
         // Prepare texture buffers
         for (texture_id, instances) in &texture_instances {
@@ -301,10 +395,16 @@ impl Renderer {
                     *texture_id,
                     instances,
                     self.screen_size,
+                    self.camera_offset,
                 );
             }
         }
 
+        // Prepare backdrop blur passes
+        let has_backdrop =
+            self.blur_renderer
+                .prepare(&self.device, &self.queue, self.screen_size, &blur_regions);
+
         // Video rendering removed - was video-editing specific
 
         // CRITICAL: Ensure all buffer writes are queued before creating the encoder
@@ -319,6 +419,14 @@ impl Renderer {
                 label: Some("GUI Render Encoder"),
             });
 
+        // Blur the texture layer offscreen before the main pass
+        if has_backdrop {
+            let textures = texture_instances
+                .iter()
+                .map(|(texture_id, instances)| (texture_id, 0..instances.len() as u32));
+            self.render_backdrop(&mut encoder, textures, clear_color);
+        }
+
         // Begin render pass
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -343,8 +451,10 @@ impl Renderer {
             // Render in proper Z-order: textures (background) → quads (UI) → text (foreground)
 
             // Textures first (backgrounds and characters)
+            render_pass.begin_debug_group("Textures");
             for texture_id in texture_instances.keys() {
                 if let Some(loaded_texture) = self.textures.get(texture_id) {
+                    render_pass.debug_marker(&loaded_texture.label);
                     self.texture_renderer.render(
                         &mut render_pass,
                         *texture_id,
@@ -352,19 +462,26 @@ impl Renderer {
                     );
                 }
             }
+            render_pass.end_debug_group();
+
+            // Blurred backdrops over the textures, under the UI
+            if has_backdrop {
+                render_pass.begin_debug_group("Backdrop Blur");
+                self.blur_renderer.composite(&mut render_pass);
+                render_pass.end_debug_group();
+            }
 
             // Quads second (UI elements like dialogue boxes)
             if !quad_instances.is_empty() {
+                render_pass.begin_debug_group("Quads");
                 self.quad_renderer.render(&mut render_pass);
+                render_pass.end_debug_group();
             }
 
             // Text last (always on top)
-            if let Err(e) = self
-                .text_renderer
-                .render(&mut render_pass, &self.device, &self.queue)
-            {
-                tracing::error!("Failed to render text: {}", e);
-            }
+            render_pass.begin_debug_group("Text");
+            self.text_renderer.render(&mut render_pass);
+            render_pass.end_debug_group();
         }
 
         // Submit commands
@@ -376,6 +493,22 @@ impl Renderer {
         self.text_renderer.measure_text(text, font_size)
     }
 
+    /// Use a font file for all text
+    ///
+    /// Replaces the font set by an earlier call, so a theme font can be
+    /// reloaded after it changes on disk. Returns the font's family name.
+    pub fn set_default_font(&mut self, path: &Path) -> Result<String, RendererError> {
+        let data = std::fs::read(path)?;
+        self.text_renderer
+            .set_default_font(data)
+            .ok_or_else(|| RendererError::InvalidFontData(path.display().to_string()))
+    }
+
+    /// Go back to the system font for all text
+    pub fn reset_default_font(&mut self) {
+        self.text_renderer.reset_default_font();
+    }
+
     /// Get the wgpu device
     pub fn device(&self) -> &wgpu::Device {
         &self.device
@@ -403,7 +536,60 @@ impl Renderer {
         let rgba = img.to_rgba8();
         let (width, height) = img.dimensions();
 
-        self.load_texture_from_bytes(&rgba, width, height)
+        self.upload_texture(&rgba, width, height, texture_label(&path.to_string_lossy()))
+    }
+
+    /// Load a texture from a file path and keep a hit mask for it
+    ///
+    /// Like [`load_texture_from_path`](Self::load_texture_from_path), but also builds
+    /// a downscaled opacity mask that [`hit_mask`](Self::hit_mask) returns, so
+    /// irregularly shaped image buttons ignore clicks on transparent pixels.
+    pub fn load_texture_with_hit_mask(
+        &mut self,
+        path: &Path,
+        options: &HitMaskOptions,
+    ) -> Result<u64, RendererError> {
+        use image::GenericImageView;
+
+        let img = image::open(path)?;
+        let rgba = img.to_rgba8();
+        let (width, height) = img.dimensions();
+
+        let id =
+            self.upload_texture(&rgba, width, height, texture_label(&path.to_string_lossy()))?;
+        self.insert_hit_mask(id, &rgba, width, height, options);
+        Ok(id)
+    }
+
+    /// Load a texture from raw RGBA bytes and keep a hit mask for it
+    pub fn load_texture_from_bytes_with_hit_mask(
+        &mut self,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+        options: &HitMaskOptions,
+    ) -> Result<u64, RendererError> {
+        let id = self.load_texture_from_bytes(rgba_data, width, height)?;
+        self.insert_hit_mask(id, rgba_data, width, height, options);
+        Ok(id)
+    }
+
+    fn insert_hit_mask(
+        &mut self,
+        id: u64,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+        options: &HitMaskOptions,
+    ) {
+        if let Some(mask) = HitMask::from_rgba(rgba_data, width, height, options) {
+            self.hit_masks.insert(id, Arc::new(mask));
+        }
+    }
+
+    /// Get the hit mask of a texture loaded with one
+    pub fn hit_mask(&self, texture_id: u64) -> Option<Arc<HitMask>> {
+        self.hit_masks.get(&texture_id).cloned()
     }
 
     /// Create a placeholder texture for graceful degradation
@@ -433,7 +619,7 @@ impl Renderer {
             }
         }
 
-        self.load_texture_from_bytes(&rgba_data, width, height)
+        self.upload_texture(&rgba_data, width, height, texture_label("placeholder"))
     }
 
     /// Load a texture from raw RGBA bytes
@@ -444,6 +630,17 @@ impl Renderer {
         rgba_data: &[u8],
         width: u32,
         height: u32,
+    ) -> Result<u64, RendererError> {
+        self.upload_texture(rgba_data, width, height, texture_label("raw RGBA"))
+    }
+
+    /// Create a texture from RGBA bytes and add it to the cache
+    fn upload_texture(
+        &mut self,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+        label: Cow<'static, str>,
     ) -> Result<u64, RendererError> {
         // Validate data size
         let expected_size = (width * height * 4) as usize;
@@ -459,7 +656,7 @@ impl Renderer {
 
         // Create texture
         let texture = self.device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("Loaded Texture"),
+            label: Some(&label),
             size: wgpu::Extent3d {
                 width,
                 height,
@@ -489,6 +686,16 @@ impl Renderer {
             },
         );
 
+        self.register_texture(texture, (width, height), label)
+    }
+
+    /// Add a GPU texture to the cache, creating its sampler and bind group
+    fn register_texture(
+        &mut self,
+        texture: wgpu::Texture,
+        (width, height): (u32, u32),
+        label: Cow<'static, str>,
+    ) -> Result<u64, RendererError> {
         // Create texture view
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
 
@@ -506,7 +713,7 @@ impl Renderer {
 
         // Create bind group
         let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Texture Bind Group"),
+            label: Some(&label),
             layout: self.texture_renderer.texture_bind_group_layout(),
             entries: &[
                 wgpu::BindGroupEntry {
@@ -536,6 +743,7 @@ impl Renderer {
                 sampler,
                 bind_group,
                 size: (width, height),
+                label,
             },
         );
 
@@ -554,29 +762,342 @@ impl Renderer {
     /// This frees GPU memory for the texture. Any subsequent DrawCommand::Texture
     /// using this texture_id will be ignored.
     pub fn remove_texture(&mut self, texture_id: u64) -> Option<LoadedTexture> {
+        self.hit_masks.remove(&texture_id);
         self.textures.remove(&texture_id)
     }
 
-    // Video renderer methods removed - were video-editing specific
-    // /// Get the video renderer
-    // pub fn video_renderer(&self) -> &VideoRenderer {
-    //     &self.video_renderer
-    // }
+    /// Render textured quads into a new offscreen texture
+    ///
+    /// Only `DrawCommand::Texture` and `DrawCommand::TextureRegion` commands are drawn, in command order, with
+    /// bounds in the target's pixel coordinates. Source textures are sampled
+    /// down to their bounds on the GPU, so large images can be composed into a
+    /// small target (e.g. save thumbnails) without resizing them on the CPU.
+    ///
+    /// Returns a texture ID usable with `DrawCommand::Texture` whose pixels can
+    /// be read back with [`read_texture`](Self::read_texture).
+    ///
+    /// # Errors
+    /// Returns an error if `width` or `height` is zero.
+    pub fn render_to_texture(
+        &mut self,
+        commands: &[DrawCommand],
+        width: u32,
+        height: u32,
+        clear_color: Color,
+    ) -> Result<u64, RendererError> {
+        if width == 0 || height == 0 {
+            return Err(RendererError::InvalidTextureData(format!(
+                "Invalid render target size {}x{}",
+                width, height
+            )));
+        }
+
+        // Group instances per texture, drawing textures in order of first use
+        let mut texture_instances: Vec<(u64, Vec<TextureInstance>)> = Vec::new();
+        for cmd in commands {
+            let (texture_id, instance) = match cmd {
+                DrawCommand::Texture {
+                    texture_id,
+                    bounds,
+                    opacity,
+                } => (texture_id, TextureInstance::new(*bounds, *opacity)),
+                DrawCommand::TextureRegion {
+                    texture_id,
+                    bounds,
+                    source,
+                    opacity,
+                } => (
+                    texture_id,
+                    TextureInstance::new(*bounds, *opacity).with_source(*source),
+                ),
+                _ => continue,
+            };
+            match texture_instances
+                .iter_mut()
+                .find(|(id, _)| id == texture_id)
+            {
+                Some((_, instances)) => instances.push(instance),
+                None => texture_instances.push((*texture_id, vec![instance])),
+            }
+        }
+
+        let label = texture_label("offscreen target");
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(&label),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.surface_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // The next frame prepares its own instances and screen size again
+        self.texture_renderer.begin_frame();
+        for (texture_id, instances) in &texture_instances {
+            if self.textures.contains_key(texture_id) {
+                self.texture_renderer.prepare(
+                    &self.device,
+                    &self.queue,
+                    *texture_id,
+                    instances,
+                    (width, height),
+                    [0.0, 0.0],
+                );
+            }
+        }
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Offscreen Render Encoder"),
+            });
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Offscreen Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    depth_slice: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(clear_color.into()),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+                multiview_mask: None,
+            });
+
+            for (texture_id, _) in &texture_instances {
+                if let Some(loaded_texture) = self.textures.get(texture_id) {
+                    render_pass.debug_marker(&loaded_texture.label);
+                    self.texture_renderer.render(
+                        &mut render_pass,
+                        *texture_id,
+                        &loaded_texture.bind_group,
+                    );
+                }
+            }
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        self.register_texture(texture, (width, height), label)
+    }
+
+    /// Read the pixels of a texture created by
+    /// [`render_to_texture`](Self::render_to_texture) as RGBA bytes
+    ///
+    /// Blocks until the GPU has finished rendering the texture.
+    ///
+    /// # Errors
+    /// Returns an error if the texture doesn't exist, wasn't rendered
+    /// offscreen, or can't be copied back from the GPU.
+    pub fn read_texture(&self, texture_id: u64) -> Result<Vec<u8>, RendererError> {
+        let loaded = self.textures.get(&texture_id).ok_or_else(|| {
+            RendererError::InvalidTextureData(format!("Unknown texture {}", texture_id))
+        })?;
+        let format = loaded.texture.format();
+        if !loaded
+            .texture
+            .usage()
+            .contains(wgpu::TextureUsages::COPY_SRC)
+            || format.block_copy_size(None) != Some(4)
+        {
+            return Err(RendererError::InvalidTextureData(format!(
+                "Texture {} can't be read back",
+                texture_id
+            )));
+        }
+
+        let (width, height) = loaded.size;
+        let row_bytes = width.saturating_mul(4);
+        let padded_row_bytes = row_bytes.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+            * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Texture Readback Buffer"),
+            size: u64::from(padded_row_bytes) * u64::from(height),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Texture Readback Encoder"),
+            });
+        encoder.copy_texture_to_buffer(
+            loaded.texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row_bytes),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|e| RendererError::ReadbackError(e.to_string()))?;
+        receiver
+            .recv()
+            .map_err(|e| RendererError::ReadbackError(e.to_string()))?
+            .map_err(|e| RendererError::ReadbackError(e.to_string()))?;
+
+        let bgra = matches!(
+            format,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+        let mut rgba = Vec::with_capacity(row_bytes as usize * height as usize);
+        {
+            let data = slice.get_mapped_range();
+            for row in data.chunks(padded_row_bytes as usize) {
+                let row = row.get(..row_bytes as usize).unwrap_or(row);
+                if bgra {
+                    for pixel in row.chunks_exact(4) {
+                        rgba.extend_from_slice(&[pixel[2], pixel[1], pixel[0], pixel[3]]);
+                    }
+                } else {
+                    rgba.extend_from_slice(row);
+                }
+            }
+        }
+        buffer.unmap();
+        Ok(rgba)
+    }
+
+    /// Upload a video frame, reusing the previous frame's texture
+    ///
+    /// Pass the texture ID returned for the previous frame, or `None` for the
+    /// first one. The texture is rewritten in place while the frame size stays
+    /// the same, and replaced when it changes.
+    ///
+    /// Returns the texture ID to draw the frame with.
+    pub fn upload_video_frame(
+        &mut self,
+        texture_id: Option<u64>,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+    ) -> Result<u64, RendererError> {
+        if let Some(id) = texture_id {
+            match self.textures.get(&id) {
+                Some(texture) if texture.size == (width, height) => {
+                    let expected_size = (width * height * 4) as usize;
+                    if rgba_data.len() != expected_size {
+                        return Err(RendererError::InvalidTextureData(format!(
+                            "Expected {} bytes for {}x{} video frame, got {} bytes",
+                            expected_size,
+                            width,
+                            height,
+                            rgba_data.len()
+                        )));
+                    }
+                    self.queue.write_texture(
+                        texture.texture.as_image_copy(),
+                        rgba_data,
+                        wgpu::TexelCopyBufferLayout {
+                            offset: 0,
+                            bytes_per_row: Some(4 * width),
+                            rows_per_image: Some(height),
+                        },
+                        wgpu::Extent3d {
+                            width,
+                            height,
+                            depth_or_array_layers: 1,
+                        },
+                    );
+                    return Ok(id);
+                }
+                _ => {
+                    self.remove_texture(id);
+                }
+            }
+        }
+        self.upload_texture(rgba_data, width, height, texture_label("video frame"))
+    }
+
+    /// Render the texture layer offscreen and blur it for `DrawCommand::BackdropBlur`
+    ///
+    /// `textures` are the prepared texture ids with the instance range to draw.
+    fn render_backdrop<'a>(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        textures: impl Iterator<Item = (&'a u64, Range<u32>)>,
+        clear_color: Color,
+    ) {
+        let Some(scene_view) = self.blur_renderer.scene_view() else {
+            return;
+        };
+
+        encoder.begin_debug_group("Backdrop");
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Backdrop Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: scene_view,
+                    resolve_target: None,
+                    depth_slice: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(clear_color.into()),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+                multiview_mask: None,
+            });
+
+            for (texture_id, instances) in textures {
+                if let Some(loaded_texture) = self.textures.get(texture_id) {
+                    render_pass.debug_marker(&loaded_texture.label);
+                    self.texture_renderer.render_range(
+                        &mut render_pass,
+                        *texture_id,
+                        &loaded_texture.bind_group,
+                        instances,
+                    );
+                }
+            }
+        }
 
-    // /// Create a video texture
-    // pub fn create_video_texture(&self, width: u32, height: u32) -> VideoTexture {
-    //     VideoTexture::new(&self.device, width, height)
-    // }
+        self.blur_renderer.blur(encoder);
+        encoder.end_debug_group();
+    }
 
     /// Render using a BatchBuilder for optimized draw call ordering
     ///
     /// Issue #250 Phase 2: Returns BatchStats for accurate draw call metrics.
     /// Commands are sorted by z-layer and grouped by type to minimize pipeline switches.
     /// Now uses layered rendering to ensure proper z-order (overlays render on top).
+    /// The batch is emptied and can be reused for the next frame.
     pub fn render_batched(
         &mut self,
         surface_view: &wgpu::TextureView,
-        batch: BatchBuilder,
+        batch: &mut BatchBuilder,
         clear_color: Color,
     ) -> BatchStats {
         let (layers, stats) = batch.build_by_layer();
@@ -586,17 +1107,39 @@ impl Renderer {
 
     /// Render commands grouped by layer for proper z-ordering
     ///
-    /// Each layer is rendered completely (quads then text) before moving to the next.
-    /// This ensures overlays (popups, dropdowns) render on top of lower layers.
+    /// Each layer is rendered completely (textures, backdrops, quads, then text)
+    /// before moving to the next. This ensures overlays (popups, dropdowns) and
+    /// their text render on top of lower layers.
+    ///
+    /// All layers share one instance buffer per renderer; each layer draws its
+    /// own range of instances from it.
     fn render_layered(
         &mut self,
         surface_view: &wgpu::TextureView,
         layers: &[(ZLayer, Vec<DrawCommand>)],
         clear_color: Color,
     ) {
-        // Type alias for layer data to satisfy clippy::type_complexity
-        // Use Vec instead of HashMap to preserve texture insertion order (Issue #120)
-        type LayerData = (Vec<quad::QuadInstance>, Vec<(u64, Vec<TextureInstance>)>);
+        /// Instances collected for one layer
+        ///
+        /// Use Vec instead of HashMap to preserve texture insertion order (Issue #120)
+        struct LayerData {
+            layer: ZLayer,
+            quads: Vec<quad::QuadInstance>,
+            textures: Vec<(u64, Vec<TextureInstance>)>,
+            /// Queued text draws of this layer
+            text_draws: Range<usize>,
+            /// Blur regions of this layer
+            blurs: Range<usize>,
+        }
+
+        /// Ranges of the merged instance buffers drawn for one layer
+        struct LayerDraws {
+            layer: ZLayer,
+            quads: Range<u32>,
+            textures: Vec<(u64, Range<u32>)>,
+            text_draws: Range<usize>,
+            blurs: Range<usize>,
+        }
 
         // Create a single command encoder for all layers
         // This prevents flickering caused by multiple queue.submit() calls per frame
@@ -608,11 +1151,14 @@ impl Renderer {
 
         // First, collect all layers' data and queue all text
         let mut layers_data: Vec<LayerData> = Vec::new();
+        let mut blur_regions = Vec::new();
 
-        for (_layer, commands) in layers {
+        for (layer, commands) in layers {
             // Collect quads and textures for this layer
             let mut quad_instances = Vec::new();
             let mut texture_instances: Vec<(u64, Vec<TextureInstance>)> = Vec::new();
+            let text_start = self.text_renderer.queued_count();
+            let blur_start = blur_regions.len();
 
             // Collect quads, textures, and queue text
             for cmd in commands {
@@ -676,29 +1222,39 @@ impl Renderer {
                         position,
                         color,
                         font_size,
+                        align,
+                        markup,
+                        spacing,
+                        clip,
                     } => {
                         self.text_renderer.queue_text(TextDraw {
                             text: text.clone(),
                             position: *position,
                             color: *color,
                             font_size: *font_size,
-                            line_height: None,
-                            align: TextAlign::Left,
+                            spacing: *spacing,
+                            align: *align,
                             max_width: None,
+                            markup: markup.clone(),
+                            clip: *clip,
                         });
                     }
                     DrawCommand::Texture {
                         texture_id,
                         bounds,
                         opacity,
+                    }
+                    | DrawCommand::TextureRegion {
+                        texture_id,
+                        bounds,
+                        opacity,
+                        ..
                     } => {
                         // Collect texture instances grouped by texture_id, preserving insertion order
-                        let instance = TextureInstance {
-                            position: [bounds.x(), bounds.y()],
-                            size: [bounds.width(), bounds.height()],
-                            opacity: *opacity,
-                            _padding: [0.0, 0.0, 0.0],
-                        };
+                        let mut instance = TextureInstance::new(*bounds, *opacity);
+                        if let DrawCommand::TextureRegion { source, .. } = cmd {
+                            instance = instance.with_source(*source);
+                        }
 
                         // Find existing entry or create new one
                         if let Some((_id, instances)) = texture_instances
@@ -710,6 +1266,12 @@ impl Renderer {
                             texture_instances.push((*texture_id, vec![instance]));
                         }
                     }
+                    DrawCommand::BackdropBlur { bounds, radius } => {
+                        blur_regions.push(BlurRegion {
+                            bounds: *bounds,
+                            radius: *radius,
+                        });
+                    }
                     // VideoFrame removed - was video-editing specific
                     DrawCommand::PushClip { .. } | DrawCommand::PopClip => {
                         // Not yet implemented
@@ -717,7 +1279,13 @@ impl Renderer {
                 }
             }
 
-            layers_data.push((quad_instances, texture_instances));
+            layers_data.push(LayerData {
+                layer: *layer,
+                quads: quad_instances,
+                textures: texture_instances,
+                text_draws: text_start..self.text_renderer.queued_count(),
+                blurs: blur_start..blur_regions.len(),
+            });
         }
 
         // Prepare text once for all layers (after all text has been queued)
@@ -726,6 +1294,7 @@ impl Renderer {
             &self.queue,
             self.screen_size.0,
             self.screen_size.1,
+            self.camera_offset,
         ) {
             tracing::error!("Failed to prepare text: {}", e);
         }
@@ -738,32 +1307,51 @@ impl Renderer {
         // Merge all layers' data to avoid buffer overwrites
         // This avoids GPU synchronization issues that cause flickering
 
-        // Merge all quads from all layers (maintains Z-order as layers are sorted)
-        let all_quads: Vec<quad::QuadInstance> = layers_data
-            .iter()
-            .flat_map(|(quads, _)| quads.iter())
-            .copied()
-            .collect();
-
-        // Merge all textures from all layers while preserving insertion order
+        // Merge all quads and textures from all layers, recording each layer's
+        // instance ranges (maintains Z-order as layers are sorted)
         // CRITICAL: Use Vec to maintain Z-order (HashMap randomizes iteration order!)
+        let mut all_quads: Vec<quad::QuadInstance> = Vec::new();
         let mut all_textures: Vec<(u64, Vec<TextureInstance>)> = Vec::new();
-        for (_quads, texture_instances) in &layers_data {
-            for (texture_id, instances) in texture_instances {
+        let mut layer_draws: Vec<LayerDraws> = Vec::with_capacity(layers_data.len());
+        for layer in layers_data {
+            let quad_start = all_quads.len() as u32;
+            all_quads.extend(layer.quads);
+
+            let mut textures = Vec::with_capacity(layer.textures.len());
+            for (texture_id, instances) in layer.textures {
                 // Find existing entry or create new one
-                if let Some((_id, existing_instances)) =
-                    all_textures.iter_mut().find(|(id, _)| id == texture_id)
-                {
-                    existing_instances.extend(instances.iter().copied());
-                } else {
-                    all_textures.push((*texture_id, instances.clone()));
-                }
+                let index = all_textures
+                    .iter()
+                    .position(|(id, _)| *id == texture_id)
+                    .unwrap_or_else(|| {
+                        all_textures.push((texture_id, Vec::new()));
+                        all_textures.len().saturating_sub(1)
+                    });
+                let Some((_id, existing)) = all_textures.get_mut(index) else {
+                    continue;
+                };
+                let start = existing.len() as u32;
+                existing.extend(instances);
+                textures.push((texture_id, start..existing.len() as u32));
             }
+
+            layer_draws.push(LayerDraws {
+                layer: layer.layer,
+                quads: quad_start..all_quads.len() as u32,
+                textures,
+                text_draws: layer.text_draws,
+                blurs: layer.blurs,
+            });
         }
 
         // Prepare quad buffers once for all layers
-        self.quad_renderer
-            .prepare(&self.device, &self.queue, &all_quads, self.screen_size);
+        self.quad_renderer.prepare(
+            &self.device,
+            &self.queue,
+            &all_quads,
+            self.screen_size,
+            self.camera_offset,
+        );
 
         // Prepare texture buffers once for all layers (in insertion order)
         for (texture_id, instances) in &all_textures {
@@ -774,10 +1362,16 @@ impl Renderer {
                     *texture_id,
                     instances,
                     self.screen_size,
+                    self.camera_offset,
                 );
             }
         }
 
+        // Prepare backdrop blur passes
+        let has_backdrop =
+            self.blur_renderer
+                .prepare(&self.device, &self.queue, self.screen_size, &blur_regions);
+
         // Video preparation removed - was video-editing specific
 
         // CRITICAL: Ensure all buffer writes are queued before creating the encoder
@@ -785,6 +1379,20 @@ impl Renderer {
         // the next queue.submit(), so explicit synchronization is not needed
         // (Issue #120 high-priority fix)
 
+        // Blur the texture layer offscreen before the main pass
+        // (textures of the layers up to the topmost backdrop)
+        if has_backdrop {
+            let backdrop_layers = layer_draws
+                .iter()
+                .rposition(|draws| !draws.blurs.is_empty())
+                .map_or(0, |index| index + 1);
+            let textures = layer_draws[..backdrop_layers]
+                .iter()
+                .flat_map(|draws| draws.textures.iter())
+                .map(|(texture_id, instances)| (texture_id, instances.clone()));
+            self.render_backdrop(&mut encoder, textures, clear_color);
+        }
+
         // Use a single render pass for all layers (prevents flickering)
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -806,29 +1414,39 @@ impl Renderer {
 
             // Video rendering removed - was video-editing specific
 
-            // Render in proper Z-order: textures (background) → quads (UI) → text (foreground)
-            // Textures first (backgrounds and characters) - in insertion order!
-            for (texture_id, _instances) in &all_textures {
-                if let Some(loaded_texture) = self.textures.get(texture_id) {
-                    self.texture_renderer.render(
-                        &mut render_pass,
-                        *texture_id,
-                        &loaded_texture.bind_group,
-                    );
+            // Render each layer in proper Z-order:
+            // textures (background) → backdrops → quads (UI) → text (foreground)
+            for draws in &layer_draws {
+                render_pass.begin_debug_group(draws.layer.debug_name());
+
+                // Textures first (backgrounds and characters) - in insertion order!
+                for (texture_id, instances) in &draws.textures {
+                    if let Some(loaded_texture) = self.textures.get(texture_id) {
+                        render_pass.debug_marker(&loaded_texture.label);
+                        self.texture_renderer.render_range(
+                            &mut render_pass,
+                            *texture_id,
+                            &loaded_texture.bind_group,
+                            instances.clone(),
+                        );
+                    }
                 }
-            }
 
-            // Quads second (UI elements like dialogue boxes)
-            if !all_quads.is_empty() {
-                self.quad_renderer.render(&mut render_pass);
-            }
+                // Blurred backdrops over the textures, under the UI
+                if has_backdrop {
+                    self.blur_renderer
+                        .composite_range(&mut render_pass, draws.blurs.clone());
+                }
 
-            // Render all text at the end (on top of all layers)
-            if let Err(e) = self
-                .text_renderer
-                .render(&mut render_pass, &self.device, &self.queue)
-            {
-                tracing::error!("Failed to render text: {}", e);
+                // Quads second (UI elements like dialogue boxes)
+                self.quad_renderer
+                    .render_range(&mut render_pass, draws.quads.clone());
+
+                // Text last, before the next layer's textures and quads
+                self.text_renderer
+                    .render_draws(&mut render_pass, draws.text_draws.clone());
+
+                render_pass.end_debug_group();
             }
         }
 
@@ -1017,6 +1635,132 @@ mod tests {
         assert_eq!(id3, id2 + 1);
     }
 
+    #[test]
+    #[ignore]
+    fn test_texture_hit_mask() {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .expect("Failed to find adapter");
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+                .expect("Failed to create device");
+
+        let mut renderer = Renderer::new_with_device_and_queue(
+            device,
+            queue,
+            (800, 600),
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+        );
+
+        let rgba_data = vec![255; 16];
+        let plain = renderer.load_texture_from_bytes(&rgba_data, 2, 2).unwrap();
+        let masked = renderer
+            .load_texture_from_bytes_with_hit_mask(&rgba_data, 2, 2, &HitMaskOptions::default())
+            .unwrap();
+
+        // Only textures loaded with a hit mask keep one
+        assert!(renderer.hit_mask(plain).is_none());
+        assert!(renderer.hit_mask(masked).is_some());
+
+        // Removing the texture drops its mask
+        renderer.remove_texture(masked);
+        assert!(renderer.hit_mask(masked).is_none());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_render_to_texture_downscales_on_gpu() {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .expect("Failed to find adapter");
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+                .expect("Failed to create device");
+
+        let mut renderer = Renderer::new_with_device_and_queue(
+            device,
+            queue,
+            (800, 600),
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+        );
+
+        // 64x64 red source drawn over the left half of an 8x4 target
+        let red = renderer
+            .load_texture_from_bytes(&[255, 0, 0, 255].repeat(64 * 64), 64, 64)
+            .unwrap();
+        let target = renderer
+            .render_to_texture(
+                &[DrawCommand::Texture {
+                    texture_id: red,
+                    bounds: Bounds::new(0.0, 0.0, 4.0, 4.0),
+                    opacity: 1.0,
+                }],
+                8,
+                4,
+                Color::BLACK,
+            )
+            .unwrap();
+        assert_eq!(renderer.get_texture_size(target), Some((8, 4)));
+
+        let pixels = renderer.read_texture(target).unwrap();
+        assert_eq!(pixels.len(), 8 * 4 * 4);
+        assert_eq!(&pixels[0..4], &[255, 0, 0, 255]);
+        assert_eq!(&pixels[28..32], &[0, 0, 0, 255]);
+
+        // Uploaded textures can't be read back
+        assert!(renderer.read_texture(red).is_err());
+        assert!(renderer.render_to_texture(&[], 0, 4, Color::BLACK).is_err());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_render_to_texture_draws_texture_region() {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .expect("Failed to find adapter");
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+                .expect("Failed to create device");
+
+        let mut renderer = Renderer::new_with_device_and_queue(
+            device,
+            queue,
+            (800, 600),
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+        );
+
+        // Left half red, right half green; only the green half is drawn
+        let mut rgba = Vec::new();
+        for _ in 0..4 {
+            rgba.extend([255, 0, 0, 255].repeat(2));
+            rgba.extend([0, 255, 0, 255].repeat(2));
+        }
+        let source = renderer.load_texture_from_bytes(&rgba, 4, 4).unwrap();
+        let target = renderer
+            .render_to_texture(
+                &[DrawCommand::TextureRegion {
+                    texture_id: source,
+                    bounds: Bounds::new(0.0, 0.0, 4.0, 4.0),
+                    source: Bounds::new(0.5, 0.0, 0.5, 1.0),
+                    opacity: 1.0,
+                }],
+                4,
+                4,
+                Color::BLACK,
+            )
+            .unwrap();
+
+        let pixels = renderer.read_texture(target).unwrap();
+        assert_eq!(&pixels[0..4], &[0, 255, 0, 255]);
+        assert_eq!(&pixels[12..16], &[0, 255, 0, 255]);
+    }
+
     impl Renderer {
         // Helper method for tests to create Renderer without a surface
         #[cfg(test)]
@@ -1035,6 +1779,7 @@ mod tests {
                 screen_size.1,
             );
             let texture_renderer = texture::TextureRenderer::new(&device, surface_format);
+            let blur_renderer = blur::BlurRenderer::new(&device, surface_format);
 
             Self {
                 device,
@@ -1042,9 +1787,12 @@ mod tests {
                 quad_renderer,
                 text_renderer,
                 texture_renderer,
+                blur_renderer,
                 surface_format,
                 screen_size,
+                camera_offset: [0.0, 0.0],
                 textures: HashMap::new(),
+                hit_masks: HashMap::new(),
                 next_texture_id: 0,
             }
         }
@@ -76,7 +76,7 @@ const QUAD_INDICES: &[u16] = &[0, 1, 2, 0, 2, 3];
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 struct QuadUniforms {
     screen_size: [f32; 2],
-    _padding: [f32; 2],
+    camera_offset: [f32; 2],
 }
 
 /// Renderer for quads (rectangles)
@@ -112,7 +112,7 @@ impl QuadRenderer {
             label: Some("Quad Uniform Buffer"),
             contents: bytemuck::cast_slice(&[QuadUniforms {
                 screen_size: [800.0, 600.0],
-                _padding: [0.0, 0.0],
+                camera_offset: [0.0, 0.0],
             }]),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
@@ -220,6 +220,7 @@ impl QuadRenderer {
         queue: &wgpu::Queue,
         instances: &[QuadInstance],
         screen_size: (u32, u32),
+        camera_offset: [f32; 2],
     ) {
         // Update uniform buffer only once per frame (shared across all quads)
         if !self.uniforms_updated {
@@ -228,7 +229,7 @@ impl QuadRenderer {
                 0,
                 bytemuck::cast_slice(&[QuadUniforms {
                     screen_size: [screen_size.0 as f32, screen_size.1 as f32],
-                    _padding: [0.0, 0.0],
+                    camera_offset,
                 }]),
             );
             self.uniforms_updated = true;
@@ -267,7 +268,18 @@ impl QuadRenderer {
     ///
     /// prepare() must be called before this
     pub fn render<'rp>(&self, render_pass: &mut wgpu::RenderPass<'rp>) {
-        if self.instance_count == 0 {
+        self.render_range(render_pass, 0..self.instance_count);
+    }
+
+    /// Render a range of the prepared quad instances
+    ///
+    /// Lets the renderer draw each z-layer's quads from the shared instance buffer.
+    pub fn render_range<'rp>(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'rp>,
+        instances: std::ops::Range<u32>,
+    ) {
+        if instances.is_empty() || instances.end > self.instance_count {
             return;
         }
 
@@ -278,7 +290,7 @@ impl QuadRenderer {
             render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
             render_pass.set_vertex_buffer(1, instance_buffer.slice(..));
             render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-            render_pass.draw_indexed(0..6, 0, 0..self.instance_count);
+            render_pass.draw_indexed(0..6, 0, instances);
         }
     }
 }
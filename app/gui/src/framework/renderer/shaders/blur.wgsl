@@ -0,0 +1,83 @@
+// Backdrop blur shader
+//
+// Every blur target covers the whole screen (at different resolutions), so the
+// normalized screen position doubles as the texture coordinate in all passes.
+
+struct Uniforms {
+    // Destination rect in normalized screen coordinates (x, y, width, height)
+    rect: vec4<f32>,
+    // Size of one source texel in normalized coordinates
+    texel: vec2<f32>,
+    // Blur axis (1,0) or (0,1)
+    direction: vec2<f32>,
+    // Blur radius in source texels
+    radius: f32,
+    _padding0: f32,
+    _padding1: vec2<f32>,
+}
+
+// Upper bound on taps per side; larger radii spread the taps further apart
+const MAX_TAPS: i32 = 32;
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+@group(1) @binding(0)
+var source: texture_2d<f32>;
+@group(1) @binding(1)
+var source_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    // Two triangles covering the unit quad
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+    );
+
+    let pos = uniforms.rect.xy + corners[index] * uniforms.rect.zw;
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(pos.x * 2.0 - 1.0, 1.0 - pos.y * 2.0, 0.0, 1.0);
+    out.uv = pos;
+    return out;
+}
+
+// Downscale and composite: plain filtered copy
+@fragment
+fn fs_copy(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSampleLevel(source, source_sampler, in.uv, 0.0);
+    return vec4<f32>(color.rgb, 1.0);
+}
+
+// Separable gaussian blur along `direction`
+@fragment
+fn fs_blur(in: VertexOutput) -> @location(0) vec4<f32> {
+    let taps = min(i32(ceil(uniforms.radius)), MAX_TAPS);
+    if (taps <= 0) {
+        return textureSampleLevel(source, source_sampler, in.uv, 0.0);
+    }
+
+    let spacing = uniforms.radius / f32(taps);
+    let step = uniforms.direction * uniforms.texel * spacing;
+    let sigma = f32(taps) * 0.5;
+
+    var color = vec4<f32>(0.0);
+    var total = 0.0;
+    for (var i = -taps; i <= taps; i = i + 1) {
+        let offset = f32(i);
+        let weight = exp(-(offset * offset) / (2.0 * sigma * sigma));
+        color = color + textureSampleLevel(source, source_sampler, in.uv + step * offset, 0.0) * weight;
+        total = total + weight;
+    }
+    return color / total;
+}
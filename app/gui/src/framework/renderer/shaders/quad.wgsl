@@ -2,7 +2,8 @@
 
 struct Uniforms {
     screen_size: vec2<f32>,
-    _padding: vec2<f32>,
+    // Offset added to everything drawn (screen shake)
+    camera_offset: vec2<f32>,
 }
 
 @group(0) @binding(0)
@@ -36,7 +37,7 @@ fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
     var out: VertexOutput;
 
     // Calculate pixel position
-    let pixel_pos = instance.quad_position + vertex.position * instance.quad_size;
+    let pixel_pos = instance.quad_position + vertex.position * instance.quad_size + uniforms.camera_offset;
 
     // Convert to clip space (-1 to 1)
     let clip_x = (pixel_pos.x / uniforms.screen_size.x) * 2.0 - 1.0;
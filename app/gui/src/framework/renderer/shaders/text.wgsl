@@ -2,7 +2,8 @@
 
 struct Uniforms {
     screen_size: vec2<f32>,
-    _padding: vec2<f32>,
+    // Offset added to everything drawn (screen shake)
+    camera_offset: vec2<f32>,
 }
 
 @group(0) @binding(0)
@@ -15,9 +16,15 @@ var glyph_texture: texture_2d<f32>;
 var glyph_sampler: sampler;
 
 struct VertexInput {
-    @location(0) position: vec2<f32>,
-    @location(1) uv: vec2<f32>,
-    @location(2) color: vec4<f32>,
+    @location(0) corner: vec2<f32>,
+}
+
+struct InstanceInput {
+    @location(1) position: vec2<f32>,
+    @location(2) size: vec2<f32>,
+    @location(3) uv_min: vec2<f32>,
+    @location(4) uv_max: vec2<f32>,
+    @location(5) color: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -27,16 +34,19 @@ struct VertexOutput {
 }
 
 @vertex
-fn vs_main(input: VertexInput) -> VertexOutput {
+fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
     var out: VertexOutput;
 
+    // Expand the unit quad to the glyph's pixel rect
+    let position = instance.position + vertex.corner * instance.size + uniforms.camera_offset;
+
     // Convert pixel position to clip space (-1 to 1)
-    let clip_x = (input.position.x / uniforms.screen_size.x) * 2.0 - 1.0;
-    let clip_y = 1.0 - (input.position.y / uniforms.screen_size.y) * 2.0;
+    let clip_x = (position.x / uniforms.screen_size.x) * 2.0 - 1.0;
+    let clip_y = 1.0 - (position.y / uniforms.screen_size.y) * 2.0;
 
     out.clip_position = vec4<f32>(clip_x, clip_y, 0.0, 1.0);
-    out.uv = input.uv;
-    out.color = input.color;
+    out.uv = mix(instance.uv_min, instance.uv_max, vertex.corner);
+    out.color = instance.color;
 
     return out;
 }
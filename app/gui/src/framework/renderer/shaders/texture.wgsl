@@ -2,7 +2,8 @@
 
 struct Uniforms {
     screen_size: vec2<f32>,
-    _padding: vec2<f32>,
+    // Offset added to everything drawn (screen shake)
+    camera_offset: vec2<f32>,
 }
 
 @group(0) @binding(0)
@@ -20,7 +21,10 @@ struct VertexInput {
 struct InstanceInput {
     @location(1) tex_position: vec2<f32>,
     @location(2) tex_size: vec2<f32>,
-    @location(3) opacity: f32,
+    // Part of the texture drawn, in texture coordinates
+    @location(3) uv_origin: vec2<f32>,
+    @location(4) uv_size: vec2<f32>,
+    @location(5) opacity: f32,
 }
 
 struct VertexOutput {
@@ -34,14 +38,14 @@ fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
     var out: VertexOutput;
 
     // Calculate pixel position
-    let pixel_pos = instance.tex_position + vertex.position * instance.tex_size;
+    let pixel_pos = instance.tex_position + vertex.position * instance.tex_size + uniforms.camera_offset;
 
     // Convert to clip space (-1 to 1)
     let clip_x = (pixel_pos.x / uniforms.screen_size.x) * 2.0 - 1.0;
     let clip_y = 1.0 - (pixel_pos.y / uniforms.screen_size.y) * 2.0;
 
     out.clip_position = vec4<f32>(clip_x, clip_y, 0.0, 1.0);
-    out.tex_coords = vertex.position; // Use unit quad positions as tex coords
+    out.tex_coords = instance.uv_origin + vertex.position * instance.uv_size;
     out.opacity = instance.opacity;
 
     return out;
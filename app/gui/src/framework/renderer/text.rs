@@ -4,18 +4,31 @@
 //! 1. Using cosmic-text for text shaping and layout
 //! 2. Using swash for glyph rasterization
 //! 3. Uploading glyph bitmaps to a wgpu texture atlas
-//! 4. Rendering text as textured quads
+//! 4. Rendering glyphs as instanced quads sampling the atlas
 //!
 //! Issue #250: Uses LRU cache for efficient glyph atlas management
+//!
+//! Glyph instances of every queued draw share one instance buffer, so the
+//! renderer can draw the text of each z-layer with a single instanced draw
+//! call, in the same layer pass as its quads and textures.
 
 use super::super::Color;
-use super::super::layout::Point;
-use cosmic_text::{Attrs, Buffer, FontSystem, Metrics, Shaping, SwashCache};
+use super::super::layout::{Bounds, Point};
+use super::SharedString;
+use cosmic_text::fontdb::{Family, ID, Source};
+use cosmic_text::{Align, Attrs, Buffer, FontSystem, Metrics, Shaping, SwashCache, Weight};
 use lru::LruCache;
+use narrative_core::markup::styled_runs;
+use narrative_core::{SpanStyle, TextDirection, TextSpan};
 use std::num::NonZeroUsize;
+use std::ops::Range;
+use std::sync::Arc;
 use wgpu::util::DeviceExt;
 
 /// Text alignment
+///
+/// Without a maximum width, the draw position is the left edge, center or
+/// right edge of the text. With one, lines are aligned within that width.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum TextAlign {
     #[default]
@@ -24,16 +37,77 @@ pub enum TextAlign {
     Right,
 }
 
+impl TextAlign {
+    /// Alignment at the edge `text` starts from: right for right-to-left text
+    pub fn start_of(text: &str) -> Self {
+        if TextDirection::Auto.is_rtl(text) {
+            Self::Right
+        } else {
+            Self::Left
+        }
+    }
+
+    /// Horizontal offset from the draw position to the left edge of a
+    /// paragraph `width` wide
+    fn anchor_offset(self, width: f32) -> f32 {
+        match self {
+            Self::Left => 0.0,
+            Self::Center => -width / 2.0,
+            Self::Right => -width,
+        }
+    }
+}
+
+impl From<TextAlign> for Align {
+    fn from(align: TextAlign) -> Self {
+        match align {
+            TextAlign::Left => Align::Left,
+            TextAlign::Center => Align::Center,
+            TextAlign::Right => Align::Right,
+        }
+    }
+}
+
+/// Styled spans of a text draw (see [`narrative_core::markup`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMarkup {
+    /// Styled runs, in characters of the full line (the drawn text may be a prefix)
+    pub spans: Arc<[TextSpan]>,
+    /// Seconds into the kinetic effects (wave, shake)
+    pub time: f32,
+}
+
+/// Line, letter and paragraph spacing of a text draw
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TextSpacing {
+    /// Line height in pixels (default: 1.2 times the font size)
+    pub line_height: Option<f32>,
+    /// Extra space after each letter in pixels
+    pub letter_spacing: f32,
+    /// Extra space between paragraphs (text between line breaks) in pixels
+    pub paragraph_spacing: f32,
+}
+
+impl TextSpacing {
+    /// Line height in pixels for text of `font_size`
+    pub fn line_height_for(&self, font_size: f32) -> f32 {
+        self.line_height.unwrap_or(font_size * 1.2)
+    }
+}
+
 /// A single text draw request
 #[derive(Debug, Clone)]
 pub struct TextDraw {
-    pub text: String,
+    pub text: SharedString,
     pub position: Point,
     pub color: Color,
     pub font_size: f32,
-    pub line_height: Option<f32>,
+    pub spacing: TextSpacing,
     pub align: TextAlign,
     pub max_width: Option<f32>,
+    pub markup: Option<TextMarkup>,
+    /// Glyphs are cut to this region
+    pub clip: Option<Bounds>,
 }
 
 /// Cached glyph data
@@ -63,15 +137,61 @@ struct GlyphInfo {
     offset_y: i32,
 }
 
-/// Vertex for text rendering
+/// Instance data for a single glyph quad
 #[repr(C)]
 #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
-struct TextVertex {
+struct GlyphInstance {
     position: [f32; 2],
-    uv: [f32; 2],
+    size: [f32; 2],
+    uv_min: [f32; 2],
+    uv_max: [f32; 2],
     color: [f32; 4],
 }
 
+impl GlyphInstance {
+    const ATTRIBS: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+        1 => Float32x2,  // position
+        2 => Float32x2,  // size
+        3 => Float32x2,  // uv_min
+        4 => Float32x2,  // uv_max
+        5 => Float32x4,  // color
+    ];
+
+    /// Cut the glyph quad to `clip`, keeping its texture coordinates in step
+    ///
+    /// Returns `None` if nothing of the glyph is inside.
+    fn clipped(self, clip: Bounds) -> Option<Self> {
+        let [x, y] = self.position;
+        let [width, height] = self.size;
+        let visible = Bounds::new(x, y, width, height).intersection(&clip)?;
+
+        let uv_width = self.uv_max[0] - self.uv_min[0];
+        let uv_height = self.uv_max[1] - self.uv_min[1];
+        let u = |px: f32| self.uv_min[0] + (px - x) / width * uv_width;
+        let v = |py: f32| self.uv_min[1] + (py - y) / height * uv_height;
+        Some(Self {
+            position: [visible.x(), visible.y()],
+            size: [visible.width(), visible.height()],
+            uv_min: [u(visible.x()), v(visible.y())],
+            uv_max: [u(visible.right()), v(visible.bottom())],
+            color: self.color,
+        })
+    }
+
+    fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<GlyphInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+// Unit quad corners (0,0) to (1,1), shared by all glyph instances
+const GLYPH_QUAD_VERTICES: &[[f32; 2]] = &[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+const GLYPH_QUAD_INDICES: &[u16] = &[0, 1, 2, 0, 2, 3];
+
 /// Text renderer using cosmic-text
 pub struct TextRenderer {
     font_system: FontSystem,
@@ -83,15 +203,70 @@ pub struct TextRenderer {
     sampler: wgpu::Sampler,
     uniform_buffer: wgpu::Buffer,
     pending_draws: Vec<TextDraw>,
-    vertices: Vec<TextVertex>,
-    indices: Vec<u32>,
-    /// Cached vertex buffer for reuse (buffer pooling for performance)
+    instances: Vec<GlyphInstance>,
+    /// First instance of each prepared draw, followed by the instance count
+    draw_offsets: Vec<u32>,
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    /// Cached instance buffer for reuse (buffer pooling for performance)
     /// See Issue #250 for GPU optimization tracking
-    vertex_buffer: Option<wgpu::Buffer>,
-    vertex_buffer_capacity: usize,
-    /// Cached index buffer for reuse
-    index_buffer: Option<wgpu::Buffer>,
-    index_buffer_capacity: usize,
+    instance_buffer: Option<wgpu::Buffer>,
+    instance_buffer_capacity: usize,
+    /// Faces loaded by [`set_default_font`](Self::set_default_font)
+    default_font_faces: Vec<ID>,
+    /// System sans-serif family, restored by [`reset_default_font`](Self::reset_default_font)
+    system_sans_serif: String,
+}
+
+/// Attributes of unstyled text of a draw
+fn base_attrs(draw: &TextDraw) -> Attrs<'static> {
+    let letter_spacing = draw.spacing.letter_spacing;
+    if letter_spacing != 0.0 && draw.font_size > 0.0 {
+        // cosmic-text tracks in ems
+        Attrs::new().letter_spacing(letter_spacing / draw.font_size)
+    } else {
+        Attrs::new()
+    }
+}
+
+/// Attributes of a styled span, tagged with `metadata` to find the span after layout
+fn span_attrs(draw: &TextDraw, style: &SpanStyle, metadata: usize) -> Attrs<'static> {
+    let mut attrs = base_attrs(draw).metadata(metadata);
+    if style.bold {
+        attrs = attrs.weight(Weight::BOLD);
+    }
+    if style.size.is_some() {
+        let line_height = draw.spacing.line_height_for(draw.font_size);
+        attrs = attrs.metrics(Metrics::new(
+            style.font_size(draw.font_size),
+            style.font_size(line_height),
+        ));
+    }
+    attrs
+}
+
+/// Character index where each line of a shaped buffer starts
+fn line_char_starts(buffer: &Buffer) -> Vec<usize> {
+    let mut starts = Vec::with_capacity(buffer.lines.len());
+    let mut start = 0usize;
+    for line in &buffer.lines {
+        starts.push(start);
+        start = start
+            .saturating_add(line.text().chars().count())
+            .saturating_add(line.ending().as_str().chars().count());
+    }
+    starts
+}
+
+/// Width of each paragraph (buffer line), the width of its longest visual line
+fn paragraph_widths(buffer: &Buffer) -> Vec<f32> {
+    let mut widths = vec![0.0_f32; buffer.lines.len()];
+    for run in buffer.layout_runs() {
+        if let Some(width) = widths.get_mut(run.line_i) {
+            *width = width.max(run.line_w);
+        }
+    }
+    widths
 }
 
 impl TextRenderer {
@@ -106,6 +281,7 @@ impl TextRenderer {
         height: u32,
     ) -> Self {
         let font_system = FontSystem::new();
+        let system_sans_serif = font_system.db().family_name(&Family::SansSerif).to_string();
         let swash_cache = SwashCache::new();
 
         // Create glyph atlas texture
@@ -215,15 +391,14 @@ impl TextRenderer {
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: Some("vs_main"),
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: std::mem::size_of::<TextVertex>() as wgpu::BufferAddress,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &wgpu::vertex_attr_array![
-                        0 => Float32x2,  // position
-                        1 => Float32x2,  // uv
-                        2 => Float32x4,  // color
-                    ],
-                }],
+                buffers: &[
+                    wgpu::VertexBufferLayout {
+                        array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![0 => Float32x2],
+                    },
+                    GlyphInstance::desc(),
+                ],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             },
             fragment: Some(wgpu::FragmentState {
@@ -246,6 +421,18 @@ impl TextRenderer {
             cache: None,
         });
 
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Text Vertex Buffer"),
+            contents: bytemuck::cast_slice(GLYPH_QUAD_VERTICES),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Text Index Buffer"),
+            contents: bytemuck::cast_slice(GLYPH_QUAD_INDICES),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
         // Issue #250: Use LRU cache with capacity for ~4000 glyphs
         // This is enough for common text rendering scenarios while avoiding atlas overflow
         // Note: 4096 is a non-zero constant, so this will always succeed
@@ -269,15 +456,53 @@ impl TextRenderer {
             sampler,
             uniform_buffer,
             pending_draws: Vec::new(),
-            vertices: Vec::new(),
-            indices: Vec::new(),
-            vertex_buffer: None,
-            vertex_buffer_capacity: 0,
-            index_buffer: None,
-            index_buffer_capacity: 0,
+            instances: Vec::new(),
+            draw_offsets: Vec::new(),
+            vertex_buffer,
+            index_buffer,
+            instance_buffer: None,
+            instance_buffer_capacity: 0,
+            default_font_faces: Vec::new(),
+            system_sans_serif,
         }
     }
 
+    /// Use a font file's data for all text
+    ///
+    /// Replaces the font set by an earlier call. Returns the family name of
+    /// the font, or `None` if the data holds no usable font face.
+    pub fn set_default_font(&mut self, data: Vec<u8>) -> Option<String> {
+        self.reset_default_font();
+
+        let db = self.font_system.db_mut();
+        let faces = db.load_font_source(Source::Binary(std::sync::Arc::new(data)));
+        let family = faces
+            .iter()
+            .find_map(|id| db.face(*id)?.families.first())
+            .map(|(name, _)| name.clone());
+        let Some(family) = family else {
+            for id in faces {
+                db.remove_face(id);
+            }
+            return None;
+        };
+        db.set_sans_serif_family(family.clone());
+        self.default_font_faces = faces.to_vec();
+        Some(family)
+    }
+
+    /// Go back to the system sans-serif font for all text
+    pub fn reset_default_font(&mut self) {
+        if self.default_font_faces.is_empty() {
+            return;
+        }
+        let db = self.font_system.db_mut();
+        for id in self.default_font_faces.drain(..) {
+            db.remove_face(id);
+        }
+        db.set_sans_serif_family(self.system_sans_serif.clone());
+    }
+
     /// Update viewport size
     pub fn resize(&mut self, _device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
         queue.write_buffer(
@@ -292,78 +517,185 @@ impl TextRenderer {
         self.pending_draws.push(draw);
     }
 
+    /// Number of draws queued since the last `prepare()`
+    ///
+    /// Draw indices returned by this are what [`render_draws`](Self::render_draws) takes.
+    pub fn queued_count(&self) -> usize {
+        self.pending_draws.len()
+    }
+
     /// Clear all queued text
     pub fn clear(&mut self) {
         self.pending_draws.clear();
-        self.vertices.clear();
-        self.indices.clear();
+        self.instances.clear();
+        self.draw_offsets.clear();
     }
 
     /// Prepare text for rendering
+    ///
+    /// Lays out every queued draw into glyph instances and uploads them.
+    /// This must be called BEFORE the render pass.
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         screen_width: u32,
         screen_height: u32,
+        camera_offset: [f32; 2],
     ) -> Result<(), String> {
         // Update uniform buffer
+        let [offset_x, offset_y] = camera_offset;
         queue.write_buffer(
             &self.uniform_buffer,
             0,
-            bytemuck::cast_slice(&[screen_width as f32, screen_height as f32, 0.0, 0.0]),
+            bytemuck::cast_slice(&[
+                screen_width as f32,
+                screen_height as f32,
+                offset_x,
+                offset_y,
+            ]),
         );
 
-        self.vertices.clear();
-        self.indices.clear();
+        self.instances.clear();
+        self.draw_offsets.clear();
 
         let draws = std::mem::take(&mut self.pending_draws);
 
         for draw in draws {
+            self.draw_offsets.push(self.instances.len() as u32);
             self.prepare_text_draw(device, queue, &draw)?;
         }
+        self.draw_offsets.push(self.instances.len() as u32);
+
+        self.upload_instances(device, queue);
 
         Ok(())
     }
 
+    fn upload_instances(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        if self.instances.is_empty() {
+            return;
+        }
+
+        // Reuse or create instance buffer (buffer pooling for performance)
+        let instance_count = self.instances.len();
+        if instance_count > self.instance_buffer_capacity {
+            let new_capacity = instance_count.max(256).next_power_of_two();
+            let buffer_size = new_capacity * std::mem::size_of::<GlyphInstance>();
+            self.instance_buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Text Instance Buffer"),
+                size: buffer_size as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+            self.instance_buffer_capacity = new_capacity;
+        }
+
+        if let Some(ref buffer) = self.instance_buffer {
+            queue.write_buffer(buffer, 0, bytemuck::cast_slice(&self.instances));
+        }
+    }
+
     fn prepare_text_draw(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         draw: &TextDraw,
     ) -> Result<(), String> {
-        let metrics = Metrics::new(
-            draw.font_size,
-            draw.line_height.unwrap_or(draw.font_size * 1.2),
-        );
+        let metrics = Metrics::new(draw.font_size, draw.spacing.line_height_for(draw.font_size));
         let mut buffer = Buffer::new(&mut self.font_system, metrics);
 
-        let width = draw.max_width.unwrap_or(f32::MAX);
-        buffer.set_size(&mut self.font_system, Some(width), None);
-
-        let attrs = Attrs::new();
-        buffer.set_text(
-            &mut self.font_system,
-            &draw.text,
-            &attrs,
-            Shaping::Advanced,
-            None,
-        );
+        // Without a maximum width, each paragraph is laid out as wide as its
+        // longest line and placed around the draw position below
+        buffer.set_size(&mut self.font_system, draw.max_width, None);
+        let align = Some(Align::from(draw.align));
+
+        let attrs = base_attrs(draw);
+        let spans = draw
+            .markup
+            .as_ref()
+            .map_or(&[][..], |markup| &markup.spans[..]);
+        if spans.is_empty() {
+            buffer.set_text(
+                &mut self.font_system,
+                draw.text.as_str(),
+                &attrs,
+                Shaping::Advanced,
+                align,
+            );
+        } else {
+            let text = draw.text.as_str();
+            let runs = styled_runs(text, spans)
+                .into_iter()
+                .map(|(range, span_index)| {
+                    let span_attrs = span_index
+                        .and_then(|index| {
+                            let span = spans.get(index)?;
+                            Some(span_attrs(draw, &span.style, index + 1))
+                        })
+                        .unwrap_or(attrs.clone());
+                    (&text[range], span_attrs)
+                });
+            buffer.set_rich_text(
+                &mut self.font_system,
+                runs,
+                &attrs,
+                Shaping::Advanced,
+                align,
+            );
+        }
         buffer.shape_until_scroll(&mut self.font_system, false);
 
-        let x = draw.position.x;
-        let mut y = draw.position.y;
+        // The first line sits at the draw position, the following ones one
+        // line height (plus paragraph spacing) apart
+        let first_line_y = buffer.layout_runs().next().map_or(0.0, |run| run.line_y);
+        let line_starts = line_char_starts(&buffer);
+        let paragraph_widths = if draw.max_width.is_none() {
+            paragraph_widths(&buffer)
+        } else {
+            Vec::new()
+        };
+        let time = draw.markup.as_ref().map_or(0.0, |markup| markup.time);
 
         for run in buffer.layout_runs() {
+            let line_start = line_starts.get(run.line_i).copied().unwrap_or(0);
+            let y = draw.position.y + run.line_y - first_line_y
+                + run.line_i as f32 * draw.spacing.paragraph_spacing;
+            let x = draw.position.x
+                + paragraph_widths
+                    .get(run.line_i)
+                    .map_or(0.0, |width| draw.align.anchor_offset(*width));
             for glyph in run.glyphs.iter() {
-                let physical_glyph = glyph.physical((x, y), 1.0);
+                // Metadata is the span index + 1 (0 for unstyled glyphs)
+                let style = glyph
+                    .metadata
+                    .checked_sub(1)
+                    .and_then(|index| spans.get(index))
+                    .map(|span| span.style);
+                let (dx, dy) = match style.and_then(|style| style.effect) {
+                    Some(effect) => {
+                        let char_index = line_start.saturating_add(
+                            run.text
+                                .get(..glyph.start)
+                                .map_or(0, |before| before.chars().count()),
+                        );
+                        effect.offset(char_index, time, glyph.font_size)
+                    }
+                    None => (0.0, 0.0),
+                };
+                let color = match style.and_then(|style| style.color) {
+                    Some(color) => [color.r, color.g, color.b, color.a * draw.color.a],
+                    None => draw.color.to_array(),
+                };
+
+                let physical_glyph = glyph.physical((x + dx, y + dy), 1.0);
 
                 // Get or create glyph in atlas
                 let glyph_info = self.get_or_create_glyph(
                     device,
                     queue,
                     physical_glyph.cache_key,
-                    draw.font_size as u32,
+                    glyph.font_size as u32,
                 )?;
 
                 if let Some(info) = glyph_info {
@@ -378,43 +710,20 @@ impl TextRenderer {
                     let u1 = (info.x + info.width) as f32 / self.glyph_cache.atlas_size as f32;
                     let v1 = (info.y + info.height) as f32 / self.glyph_cache.atlas_size as f32;
 
-                    let color = draw.color.to_array();
-                    let base_idx = self.vertices.len() as u32;
-
-                    self.vertices.extend_from_slice(&[
-                        TextVertex {
-                            position: [gx, gy],
-                            uv: [u0, v0],
-                            color,
-                        },
-                        TextVertex {
-                            position: [gx + gw, gy],
-                            uv: [u1, v0],
-                            color,
-                        },
-                        TextVertex {
-                            position: [gx + gw, gy + gh],
-                            uv: [u1, v1],
-                            color,
-                        },
-                        TextVertex {
-                            position: [gx, gy + gh],
-                            uv: [u0, v1],
-                            color,
-                        },
-                    ]);
-
-                    self.indices.extend_from_slice(&[
-                        base_idx,
-                        base_idx + 1,
-                        base_idx + 2,
-                        base_idx,
-                        base_idx + 2,
-                        base_idx + 3,
-                    ]);
+                    let instance = GlyphInstance {
+                        position: [gx, gy],
+                        size: [gw, gh],
+                        uv_min: [u0, v0],
+                        uv_max: [u1, v1],
+                        color,
+                    };
+                    let instance = match draw.clip {
+                        Some(clip) => instance.clipped(clip),
+                        None => Some(instance),
+                    };
+                    self.instances.extend(instance);
                 }
             }
-            y += run.line_y;
         }
 
         Ok(())
@@ -536,64 +845,49 @@ impl TextRenderer {
         Ok(Some(info))
     }
 
-    /// Render text
-    pub fn render<'rp>(
-        &mut self,
-        render_pass: &mut wgpu::RenderPass<'rp>,
-        device: &wgpu::Device,
-        queue: &wgpu::Queue,
-    ) -> Result<(), String> {
-        if self.vertices.is_empty() {
-            return Ok(());
-        }
+    /// Render all prepared text
+    ///
+    /// prepare() must be called before this
+    pub fn render<'rp>(&self, render_pass: &mut wgpu::RenderPass<'rp>) {
+        self.render_instances(render_pass, 0..self.instances.len() as u32);
+    }
 
-        // Reuse or create vertex buffer (buffer pooling for performance)
-        let vertex_count = self.vertices.len();
-        if vertex_count > self.vertex_buffer_capacity {
-            let new_capacity = vertex_count.max(256).next_power_of_two();
-            let buffer_size = new_capacity * std::mem::size_of::<TextVertex>();
-            self.vertex_buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Text Vertex Buffer"),
-                size: buffer_size as u64,
-                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            }));
-            self.vertex_buffer_capacity = new_capacity;
-        }
+    /// Render the prepared text of the queued draws in `draws`
+    ///
+    /// Lets the renderer draw each z-layer's text right after the layer's quads.
+    pub fn render_draws<'rp>(&self, render_pass: &mut wgpu::RenderPass<'rp>, draws: Range<usize>) {
+        self.render_instances(render_pass, self.instance_range(draws));
+    }
 
-        // Reuse or create index buffer
-        let index_count = self.indices.len();
-        if index_count > self.index_buffer_capacity {
-            let new_capacity = index_count.max(384).next_power_of_two();
-            let buffer_size = new_capacity * std::mem::size_of::<u32>();
-            self.index_buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Text Index Buffer"),
-                size: buffer_size as u64,
-                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            }));
-            self.index_buffer_capacity = new_capacity;
-        }
+    /// Glyph instances of the prepared draws in `draws`
+    fn instance_range(&self, draws: Range<usize>) -> Range<u32> {
+        let offset = |index: usize| {
+            self.draw_offsets
+                .get(index)
+                .or(self.draw_offsets.last())
+                .copied()
+                .unwrap_or(0)
+        };
+        offset(draws.start)..offset(draws.end)
+    }
 
-        // Write data to buffers
-        if let Some(ref buffer) = self.vertex_buffer {
-            queue.write_buffer(buffer, 0, bytemuck::cast_slice(&self.vertices));
-        }
-        if let Some(ref buffer) = self.index_buffer {
-            queue.write_buffer(buffer, 0, bytemuck::cast_slice(&self.indices));
+    fn render_instances<'rp>(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'rp>,
+        instances: Range<u32>,
+    ) {
+        if instances.is_empty() {
+            return;
         }
 
-        // Render
-        if let (Some(vertex_buffer), Some(index_buffer)) = (&self.vertex_buffer, &self.index_buffer)
-        {
+        if let Some(ref instance_buffer) = self.instance_buffer {
             render_pass.set_pipeline(&self.pipeline);
             render_pass.set_bind_group(0, &self.bind_group, &[]);
-            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
-            render_pass.draw_indexed(0..self.indices.len() as u32, 0, 0..1);
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.set_vertex_buffer(1, instance_buffer.slice(..));
+            render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.draw_indexed(0..GLYPH_QUAD_INDICES.len() as u32, 0, instances);
         }
-
-        Ok(())
     }
 
     /// Measure text dimensions
@@ -617,3 +911,92 @@ impl TextRenderer {
         (width, height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_clipping_keeps_uvs_in_step() {
+        let glyph = GlyphInstance {
+            position: [10.0, 10.0],
+            size: [20.0, 10.0],
+            uv_min: [0.0, 0.0],
+            uv_max: [0.2, 0.1],
+            color: [1.0; 4],
+        };
+
+        let clipped = glyph.clipped(Bounds::new(0.0, 15.0, 20.0, 100.0)).unwrap();
+        assert_eq!(clipped.position, [10.0, 15.0]);
+        assert_eq!(clipped.size, [10.0, 5.0]);
+        assert_eq!(clipped.uv_min, [0.0, 0.05]);
+        assert_eq!(clipped.uv_max, [0.1, 0.1]);
+
+        assert!(glyph.clipped(Bounds::new(0.0, 40.0, 100.0, 10.0)).is_none());
+    }
+
+    #[test]
+    fn test_glyph_instance_layout() {
+        // 4 * vec2<f32> + vec4<f32>, tightly packed for the instance buffer
+        assert_eq!(std::mem::size_of::<GlyphInstance>(), 48);
+        assert_eq!(std::mem::align_of::<GlyphInstance>(), 4);
+
+        let instances = [GlyphInstance {
+            position: [10.0, 20.0],
+            size: [8.0, 12.0],
+            uv_min: [0.0, 0.0],
+            uv_max: [0.25, 0.5],
+            color: [1.0, 1.0, 1.0, 1.0],
+        }];
+        let bytes: &[u8] = bytemuck::cast_slice(&instances);
+        assert_eq!(bytes.len(), 48);
+    }
+
+    #[test]
+    fn test_text_align_start_of() {
+        assert_eq!(TextAlign::start_of("Hello"), TextAlign::Left);
+        assert_eq!(TextAlign::start_of("שלום עולם"), TextAlign::Right);
+        assert_eq!(TextAlign::start_of("مرحبا Bob"), TextAlign::Right);
+        assert_eq!(TextAlign::start_of("Bob: مرحبا"), TextAlign::Left);
+        assert_eq!(TextAlign::start_of("42"), TextAlign::Left);
+    }
+
+    #[test]
+    fn test_text_align_anchor_offset() {
+        assert_eq!(TextAlign::Left.anchor_offset(100.0), 0.0);
+        assert_eq!(TextAlign::Center.anchor_offset(100.0), -50.0);
+        assert_eq!(TextAlign::Right.anchor_offset(100.0), -100.0);
+    }
+
+    #[test]
+    fn test_mixed_direction_paragraphs_stay_in_their_width() {
+        let mut font_system = FontSystem::new();
+        let mut buffer = Buffer::new(&mut font_system, Metrics::new(16.0, 20.0));
+        buffer.set_size(&mut font_system, None, None);
+        buffer.set_text(
+            &mut font_system,
+            "Hello שלום\nمرحبا world",
+            &Attrs::new(),
+            Shaping::Advanced,
+            Some(Align::Left),
+        );
+        buffer.shape_until_scroll(&mut font_system, false);
+
+        let widths = paragraph_widths(&buffer);
+        assert_eq!(widths.len(), 2);
+        for run in buffer.layout_runs() {
+            for glyph in run.glyphs.iter() {
+                // Right-to-left paragraphs are not pushed off the draw position
+                assert!(glyph.x >= -0.01, "glyph at {}", glyph.x);
+                assert!(glyph.x + glyph.w <= widths[run.line_i] + 0.01);
+            }
+        }
+    }
+
+    #[test]
+    fn test_glyph_quad_constants() {
+        assert_eq!(GLYPH_QUAD_INDICES, &[0, 1, 2, 0, 2, 3]);
+        assert_eq!(GLYPH_QUAD_VERTICES[0], [0.0, 0.0]);
+        assert_eq!(GLYPH_QUAD_VERTICES[2], [1.0, 1.0]);
+    }
+}
@@ -1,5 +1,6 @@
 //! Texture renderer for rendering images
 
+use crate::framework::layout::Bounds;
 use bytemuck::{Pod, Zeroable};
 use wgpu::util::DeviceExt;
 
@@ -9,17 +10,42 @@ use wgpu::util::DeviceExt;
 pub struct TextureInstance {
     pub position: [f32; 2],
     pub size: [f32; 2],
+    /// Top-left corner of the part of the texture drawn (0.0-1.0)
+    pub uv_origin: [f32; 2],
+    /// Size of the part of the texture drawn (0.0-1.0)
+    pub uv_size: [f32; 2],
     pub opacity: f32,
     pub _padding: [f32; 3],
 }
 
 impl TextureInstance {
-    const ATTRIBS: [wgpu::VertexAttribute; 3] = wgpu::vertex_attr_array![
+    const ATTRIBS: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
         1 => Float32x2,  // position
         2 => Float32x2,  // size
-        3 => Float32,    // opacity
+        3 => Float32x2,  // uv_origin
+        4 => Float32x2,  // uv_size
+        5 => Float32,    // opacity
     ];
 
+    /// Draw the whole texture stretched over `bounds`
+    pub fn new(bounds: Bounds, opacity: f32) -> Self {
+        Self {
+            position: [bounds.x(), bounds.y()],
+            size: [bounds.width(), bounds.height()],
+            uv_origin: [0.0, 0.0],
+            uv_size: [1.0, 1.0],
+            opacity,
+            _padding: [0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Draw only `source` of the texture, in texture coordinates (0.0-1.0)
+    pub fn with_source(mut self, source: Bounds) -> Self {
+        self.uv_origin = [source.x(), source.y()];
+        self.uv_size = [source.width(), source.height()];
+        self
+    }
+
     fn desc() -> wgpu::VertexBufferLayout<'static> {
         wgpu::VertexBufferLayout {
             array_stride: std::mem::size_of::<TextureInstance>() as wgpu::BufferAddress,
@@ -71,7 +97,7 @@ const TEXTURE_INDICES: &[u16] = &[0, 1, 2, 0, 2, 3];
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 struct TextureUniforms {
     screen_size: [f32; 2],
-    _padding: [f32; 2],
+    camera_offset: [f32; 2],
 }
 
 use std::collections::HashMap;
@@ -190,7 +216,7 @@ impl TextureRenderer {
             label: Some("Texture Uniform Buffer"),
             contents: bytemuck::cast_slice(&[TextureUniforms {
                 screen_size: [800.0, 600.0],
-                _padding: [0.0, 0.0],
+                camera_offset: [0.0, 0.0],
             }]),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
@@ -289,6 +315,7 @@ impl TextureRenderer {
         texture_id: u64,
         instances: &[TextureInstance],
         screen_size: (u32, u32),
+        camera_offset: [f32; 2],
     ) {
         // Update uniform buffer only once per frame (shared across all textures)
         if !self.uniforms_updated {
@@ -297,7 +324,7 @@ impl TextureRenderer {
                 0,
                 bytemuck::cast_slice(&[TextureUniforms {
                     screen_size: [screen_size.0 as f32, screen_size.1 as f32],
-                    _padding: [0.0, 0.0],
+                    camera_offset,
                 }]),
             );
             self.uniforms_updated = true;
@@ -357,8 +384,24 @@ impl TextureRenderer {
         let Some(data) = self.instance_data.get(&texture_id) else {
             return;
         };
+        self.render_range(render_pass, texture_id, texture_bind_group, 0..data.count);
+    }
 
-        if data.count == 0 {
+    /// Render a range of the prepared instances for a specific texture ID
+    ///
+    /// Lets the renderer draw each z-layer's sprites from the shared instance buffer.
+    pub fn render_range<'rp>(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'rp>,
+        texture_id: u64,
+        texture_bind_group: &'rp wgpu::BindGroup,
+        instances: std::ops::Range<u32>,
+    ) {
+        let Some(data) = self.instance_data.get(&texture_id) else {
+            return;
+        };
+
+        if instances.is_empty() || instances.end > data.count {
             return;
         }
 
@@ -368,7 +411,7 @@ impl TextureRenderer {
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
         render_pass.set_vertex_buffer(1, data.buffer.slice(..));
         render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-        render_pass.draw_indexed(0..6, 0, 0..data.count);
+        render_pass.draw_indexed(0..6, 0, instances);
     }
 
     /// Get the texture bind group layout for creating texture bind groups
@@ -386,8 +429,8 @@ mod tests {
         // Verify TextureInstance memory layout matches GPU expectations
         assert_eq!(
             std::mem::size_of::<TextureInstance>(),
-            32,
-            "TextureInstance size should be 32 bytes (2*f32 + 2*f32 + f32 + 3*f32 padding)"
+            48,
+            "TextureInstance size should be 48 bytes (4*vec2<f32> + f32 + 3*f32 padding)"
         );
 
         // Verify alignment
@@ -398,15 +441,13 @@ mod tests {
         );
 
         // Create instance and verify it can be safely cast to bytes
-        let instances = [TextureInstance {
-            position: [100.0, 200.0],
-            size: [256.0, 512.0],
-            opacity: 0.8,
-            _padding: [0.0, 0.0, 0.0],
-        }];
+        let instances = [TextureInstance::new(Bounds::new(100.0, 200.0, 256.0, 512.0), 0.8)
+            .with_source(Bounds::new(0.5, 0.0, 0.5, 1.0))];
+        assert_eq!(instances[0].uv_origin, [0.5, 0.0]);
+        assert_eq!(instances[0].uv_size, [0.5, 1.0]);
 
         let bytes: &[u8] = bytemuck::cast_slice(&instances);
-        assert_eq!(bytes.len(), 32, "Byte representation should be 32 bytes");
+        assert_eq!(bytes.len(), 48, "Byte representation should be 48 bytes");
     }
 
     #[test]
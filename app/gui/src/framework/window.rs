@@ -10,7 +10,8 @@ use super::error::{FrameworkError, FrameworkResult};
 use super::input::{InputEvent, InputState, Modifiers, MouseButton};
 use super::layout::{Bounds, LayoutEngine, Point, Size};
 use super::metrics::{FrameMetrics, PerformanceStats};
-use super::renderer::{BatchBuilder, DrawCommand, Renderer, ZLayer};
+use super::renderer::{BatchBuilder, CommandBuffer, DrawCommand, Renderer, ZLayer};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use winit::dpi::PhysicalSize;
@@ -45,6 +46,9 @@ impl From<PresentMode> for wgpu::PresentMode {
     }
 }
 
+/// Default [`WindowOptions::max_frame_delta`]
+pub const DEFAULT_MAX_FRAME_DELTA: Duration = Duration::from_millis(250);
+
 /// Options for creating a window
 #[derive(Debug, Clone)]
 pub struct WindowOptions {
@@ -59,6 +63,13 @@ pub struct WindowOptions {
     pub target_fps: u32,
     /// Show FPS overlay (Issue #250)
     pub show_fps_overlay: bool,
+    /// Window icon image file (None = platform default)
+    pub icon: Option<PathBuf>,
+    /// Longest frame delta passed to `Element::tick`
+    ///
+    /// Longer gaps (system sleep, minimized window) count as a single normal
+    /// frame, so timers and animations pause instead of jumping ahead.
+    pub max_frame_delta: Duration,
 }
 
 impl Default for WindowOptions {
@@ -72,6 +83,8 @@ impl Default for WindowOptions {
             present_mode: PresentMode::VSync,
             target_fps: 60,
             show_fps_overlay: cfg!(debug_assertions),
+            icon: None,
+            max_frame_delta: DEFAULT_MAX_FRAME_DELTA,
         }
     }
 }
@@ -101,12 +114,18 @@ pub struct Window {
     metrics: FrameMetrics,
     /// Dirty tracking for partial redraws (Issue #250)
     dirty_tracker: DirtyTracker,
+    /// Commands painted by one element, reused across frames
+    paint_buffer: CommandBuffer,
+    /// Batched commands of the frame, reused across frames
+    batch: BatchBuilder,
     /// Whether to show FPS overlay
     show_fps_overlay: bool,
     /// Frame pacing configuration (Issue #250 Phase 2)
     target_frame_time: Option<Duration>,
     /// Last frame end time for frame pacing
     last_frame_time: Instant,
+    /// Longest frame delta passed to tick
+    max_frame_delta: Duration,
     /// Current present mode
     present_mode: PresentMode,
 }
@@ -237,9 +256,12 @@ impl Window {
             needs_layout: true,
             metrics: FrameMetrics::new(),
             dirty_tracker: DirtyTracker::new(),
+            paint_buffer: CommandBuffer::with_capacity(64),
+            batch: BatchBuilder::with_capacity(256),
             show_fps_overlay: options.show_fps_overlay,
             target_frame_time,
             last_frame_time: Instant::now(),
+            max_frame_delta: options.max_frame_delta,
             present_mode: options.present_mode,
         })
     }
@@ -423,7 +445,11 @@ impl Window {
 
         // Calculate delta time for frame-rate independent animations
         let now = Instant::now();
-        let delta = now.duration_since(self.last_frame_time);
+        let delta = clamp_frame_delta(
+            now.duration_since(self.last_frame_time),
+            self.max_frame_delta,
+            self.target_frame_time,
+        );
         self.last_frame_time = now;
 
         // Call tick on root element for time-based updates
@@ -461,7 +487,8 @@ impl Window {
             .create_view(&wgpu::TextureViewDescriptor::default());
 
         // Issue #250 Phase 2: Use BatchBuilder for optimized command ordering
-        let mut batch = BatchBuilder::with_capacity(256);
+        // (kept across frames so its storage is reused)
+        let batch = &mut self.batch;
 
         if let Some(root) = &mut self.root_element {
             // Layout phase - only recalculate if needed (incremental layout optimization)
@@ -505,7 +532,8 @@ impl Window {
                 root.as_ref(),
                 window_bounds,
                 &self.layout_engine,
-                &mut batch,
+                &mut self.paint_buffer,
+                batch,
                 ZLayer::DEFAULT,
             );
 
@@ -514,8 +542,10 @@ impl Window {
                 root.as_ref(),
                 window_bounds,
                 &self.layout_engine,
-                &mut batch,
+                &mut self.paint_buffer,
+                batch,
             );
+            self.paint_buffer.finish_frame();
 
             // End paint timing (Issue #250)
             self.metrics.end_paint();
@@ -769,6 +799,28 @@ impl Window {
                         tracing::info!("Processing window set decorations: {}", enabled);
                         self.winit_window.set_decorations(enabled);
                     }
+                    WindowOperation::SetImeAllowed(allowed) => {
+                        tracing::debug!("Processing IME allowed: {}", allowed);
+                        self.winit_window.set_ime_allowed(allowed);
+                    }
+                    WindowOperation::SetImeCursorArea { bounds } => {
+                        self.winit_window.set_ime_cursor_area(
+                            winit::dpi::PhysicalPosition::new(bounds.origin.x, bounds.origin.y),
+                            winit::dpi::PhysicalSize::new(bounds.size.width, bounds.size.height),
+                        );
+                    }
+                    WindowOperation::ToggleFullscreen => {
+                        let is_fullscreen = self.winit_window.fullscreen().is_some();
+                        tracing::info!(
+                            "Processing window toggle fullscreen (currently: {})",
+                            is_fullscreen
+                        );
+                        self.winit_window.set_fullscreen(if is_fullscreen {
+                            None
+                        } else {
+                            Some(winit::window::Fullscreen::Borderless(None))
+                        });
+                    }
                     WindowOperation::DragWindow => {
                         tracing::debug!("Processing window drag");
                         if let Err(e) = self.winit_window.drag_window() {
@@ -851,14 +903,60 @@ pub fn convert_winit_event(
             Some(InputEvent::HoveredFile { path: path.clone() })
         }
         winit::event::WindowEvent::HoveredFileCancelled => Some(InputEvent::HoveredFileCancelled),
+        winit::event::WindowEvent::Ime(winit::event::Ime::Preedit(text, cursor)) => {
+            Some(InputEvent::ImePreedit {
+                text: text.clone(),
+                cursor: *cursor,
+            })
+        }
+        winit::event::WindowEvent::Ime(winit::event::Ime::Commit(text)) => {
+            Some(InputEvent::ImeCommit { text: text.clone() })
+        }
         _ => None,
     }
 }
 
+/// Convert the text typed by a key press into character input events
+///
+/// Sent alongside the `KeyDown` from [`convert_winit_event`]. Control
+/// characters (Backspace, Enter, Escape, ...) are left to the key event.
+pub fn convert_winit_text_input(event: &winit::event::WindowEvent) -> Vec<InputEvent> {
+    match event {
+        winit::event::WindowEvent::KeyboardInput { event, .. }
+            if event.state == winit::event::ElementState::Pressed =>
+        {
+            event
+                .text
+                .iter()
+                .flat_map(|text| text.chars())
+                .filter(|character| !character.is_control())
+                .map(|character| InputEvent::CharInput { character })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Clamp the time since the last frame to what elements may see as one frame
+///
+/// A gap longer than `max` means the app was suspended (system sleep, minimized
+/// window); it is replaced by one target frame so typewriter text, auto mode and
+/// animations resume where they were instead of skipping ahead.
+fn clamp_frame_delta(delta: Duration, max: Duration, target: Option<Duration>) -> Duration {
+    if delta <= max {
+        return delta;
+    }
+    tracing::debug!(
+        "Frame delta of {:?} after suspension, pausing timers",
+        delta
+    );
+    target.map_or(max, |target| target.min(max))
+}
+
 /// Recursively build the taffy layout tree from an element tree
 ///
 /// Issue #250: Supports incremental layout by reusing existing layout nodes
-fn build_layout_tree(
+pub(super) fn build_layout_tree(
     element: &mut dyn Element,
     engine: &mut LayoutEngine,
     force_rebuild: bool,
@@ -911,7 +1009,7 @@ fn paint_element_tree(
     element: &dyn Element,
     bounds: Bounds,
     engine: &LayoutEngine,
-    commands: &mut Vec<DrawCommand>,
+    commands: &mut CommandBuffer,
 ) {
     use super::element::PaintContext;
 
@@ -924,17 +1022,18 @@ fn paint_element_tree(
     element.paint(&mut paint_cx);
 
     // Paint children with their computed bounds
+    let clip = element.clips_children();
+    if clip {
+        commands.push(DrawCommand::PushClip { bounds });
+    }
     for child in element.children() {
         if let Some(child_node) = child.layout_node() {
             match engine.get_bounds(child_node) {
                 Ok(child_layout) => {
-                    // Child bounds are relative to parent, so offset by parent position
-                    let child_bounds = Bounds::new(
-                        bounds.x() + child_layout.x(),
-                        bounds.y() + child_layout.y(),
-                        child_layout.width(),
-                        child_layout.height(),
-                    );
+                    let child_bounds = child_bounds(element, bounds, child_layout);
+                    if clip && !child_bounds.intersects(&bounds) {
+                        continue;
+                    }
                     paint_element_tree(child.as_ref(), child_bounds, engine, commands);
                 }
                 Err(e) => {
@@ -943,49 +1042,74 @@ fn paint_element_tree(
             }
         }
     }
+    if clip {
+        commands.push(DrawCommand::PopClip);
+    }
+}
+
+/// Screen bounds of a child from its layout bounds
+///
+/// Child bounds are relative to the parent, so they are offset by the parent
+/// position, minus the parent's scroll position.
+fn child_bounds(parent: &dyn Element, parent_bounds: Bounds, child_layout: Bounds) -> Bounds {
+    let scroll = parent.scroll_offset();
+    Bounds::new(
+        parent_bounds.x() + child_layout.x() - scroll.x,
+        parent_bounds.y() + child_layout.y() - scroll.y,
+        child_layout.width(),
+        child_layout.height(),
+    )
 }
 
 /// Recursively paint an element tree using BatchBuilder for optimized draw ordering
 ///
 /// Issue #250 Phase 2: Supports z-layer based rendering for proper draw order optimization.
-/// Elements paint to a temporary Vec which is then added to the batch at the specified layer.
-fn paint_element_tree_batched(
+/// Elements paint to `commands` (a buffer reused across elements and frames), which is
+/// then drained into the batch at the specified layer.
+pub(super) fn paint_element_tree_batched(
     element: &dyn Element,
     bounds: Bounds,
     engine: &LayoutEngine,
+    commands: &mut CommandBuffer,
     batch: &mut BatchBuilder,
     layer: ZLayer,
 ) {
     use super::element::PaintContext;
 
-    // Collect commands for this element into a temporary Vec
-    let mut commands = Vec::new();
+    // Collect commands for this element
     let mut paint_cx = PaintContext {
         bounds,
         clip_bounds: None,
-        commands: &mut commands,
+        commands,
     };
     element.paint(&mut paint_cx);
 
     // Add collected commands to batch at the specified layer
-    for cmd in commands {
-        batch.push_at_layer(cmd, layer);
-    }
+    batch.extend_at_layer(commands.drain(), layer);
 
-    // Paint children with their computed bounds
+    // Paint children with their computed bounds (the clip region stays
+    // active in `commands` while the children are drained into the batch)
+    let clip = element.clips_children();
+    if clip {
+        commands.push(DrawCommand::PushClip { bounds });
+    }
     for child in element.children() {
         if let Some(child_node) = child.layout_node() {
             match engine.get_bounds(child_node) {
                 Ok(child_layout) => {
-                    // Child bounds are relative to parent, so offset by parent position
-                    let child_bounds = Bounds::new(
-                        bounds.x() + child_layout.x(),
-                        bounds.y() + child_layout.y(),
-                        child_layout.width(),
-                        child_layout.height(),
-                    );
+                    let child_bounds = child_bounds(element, bounds, child_layout);
+                    if clip && !child_bounds.intersects(&bounds) {
+                        continue;
+                    }
                     // Children inherit parent's layer by default
-                    paint_element_tree_batched(child.as_ref(), child_bounds, engine, batch, layer);
+                    paint_element_tree_batched(
+                        child.as_ref(),
+                        child_bounds,
+                        engine,
+                        commands,
+                        batch,
+                        layer,
+                    );
                 }
                 Err(e) => {
                     tracing::error!("Failed to get child bounds: {}", e);
@@ -993,46 +1117,48 @@ fn paint_element_tree_batched(
             }
         }
     }
+    if clip {
+        commands.push(DrawCommand::PopClip);
+    }
 }
 
 /// Paint overlay content (popups, dropdowns) at POPUP layer
 ///
 /// This collects overlay commands from all elements in the tree and adds them
 /// to the batch at the POPUP layer, ensuring they render on top of normal content.
-fn paint_overlay_tree_batched(
+pub(super) fn paint_overlay_tree_batched(
     element: &dyn Element,
     bounds: Bounds,
     engine: &LayoutEngine,
+    commands: &mut CommandBuffer,
     batch: &mut BatchBuilder,
 ) {
     use super::element::PaintContext;
 
     // Collect overlay commands for this element
-    let mut commands = Vec::new();
     let mut paint_cx = PaintContext {
         bounds,
         clip_bounds: None,
-        commands: &mut commands,
+        commands,
     };
     element.paint_overlay(&mut paint_cx);
 
     // Add collected overlay commands at POPUP layer
-    for cmd in commands {
-        batch.push_at_layer(cmd, ZLayer::POPUP);
-    }
+    batch.extend_at_layer(commands.drain(), ZLayer::POPUP);
 
     // Recursively collect overlay commands from children
     for child in element.children() {
         if let Some(child_node) = child.layout_node() {
             match engine.get_bounds(child_node) {
                 Ok(child_layout) => {
-                    let child_bounds = Bounds::new(
-                        bounds.x() + child_layout.x(),
-                        bounds.y() + child_layout.y(),
-                        child_layout.width(),
-                        child_layout.height(),
+                    let child_bounds = child_bounds(element, bounds, child_layout);
+                    paint_overlay_tree_batched(
+                        child.as_ref(),
+                        child_bounds,
+                        engine,
+                        commands,
+                        batch,
                     );
-                    paint_overlay_tree_batched(child.as_ref(), child_bounds, engine, batch);
                 }
                 Err(e) => {
                     tracing::error!("Failed to get child bounds for overlay: {}", e);
@@ -1041,3 +1167,24 @@ fn paint_overlay_tree_batched(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_frame_delta() {
+        let max = Duration::from_millis(250);
+        let frame = Duration::from_millis(16);
+        let target = Some(frame);
+
+        // Normal and slow frames pass through
+        let slow = Duration::from_millis(100);
+        assert_eq!(clamp_frame_delta(slow, max, target), slow);
+
+        // Gaps after suspension count as one target frame
+        let suspended = Duration::from_secs(3600);
+        assert_eq!(clamp_frame_delta(suspended, max, target), frame);
+        assert_eq!(clamp_frame_delta(suspended, max, None), max);
+    }
+}
@@ -28,8 +28,9 @@ pub mod theme;
 // Re-export framework types
 pub use framework::{
     Alignment, App, AppContext, AppMenu, Bounds, Color, Container, Element, ElementId,
-    FlexDirection, FrameworkError, FrameworkResult, InputEvent, MenuEventHandler, MenuId, Point,
-    PresentMode, Renderer, Size, Text, Window, WindowContext, WindowOperation, WindowOptions,
+    FlexDirection, FrameworkError, FrameworkResult, GameMenu, GameMenuInfo, InputEvent,
+    MenuEventHandler, MenuId, Point, PresentMode, Renderer, Size, Text, Window, WindowContext,
+    WindowOperation, WindowOptions,
 };
 
 use thiserror::Error;
@@ -111,6 +112,8 @@ impl From<GuiConfig> for WindowOptions {
             present_mode: PresentMode::VSync,
             target_fps: 60,
             show_fps_overlay: config.show_fps_overlay,
+            icon: None,
+            max_frame_delta: framework::DEFAULT_MAX_FRAME_DELTA,
         }
     }
 }
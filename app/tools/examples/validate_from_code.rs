@@ -66,6 +66,7 @@ fn main() -> anyhow::Result<()> {
     let strict_config = ValidationConfig {
         strict_mode: true,
         check_assets: true,
+        ..Default::default()
     };
 
     match scenario_validator::validate_file("assets/scenarios/chapter_01.toml", &strict_config) {
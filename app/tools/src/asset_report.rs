@@ -0,0 +1,758 @@
+//! Asset usage and orphan report
+//!
+//! Cross-references manifests and scenario commands against the media files
+//! under an asset root. Files nobody references are reported as unused,
+//! references to files that do not exist as missing, and sizes are totalled
+//! per category. Unused files can be deleted or moved to a quarantine
+//! directory.
+
+use crate::scenario_files::{ordered_scenes, relative_asset_path, visit_commands};
+use crate::voice_script::{self, VoiceScriptConfig};
+use anyhow::{Context, Result, bail};
+use narrative_core::{
+    BackgroundManifest, BgmManifest, CharacterDef, CharacterRegistry, Scenario, ScenarioCommand,
+    SeManifest, SpriteMode, UiThemeDef, UiThemeManifest,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const BACKGROUNDS_MANIFEST: &str = "manifests/backgrounds.ron";
+const BGM_MANIFEST: &str = "manifests/bgm.ron";
+const SE_MANIFEST: &str = "manifests/se.ron";
+const UI_THEMES_MANIFEST: &str = "manifests/ui_themes.ron";
+const CHARACTERS_MANIFEST: &str = "manifests/characters.ron";
+
+/// Category of a media file, decided by its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    Image,
+    Audio,
+    Font,
+    Video,
+}
+
+impl AssetKind {
+    /// Category for a file, or `None` if it is not a media file
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "png" | "jpg" | "jpeg" | "webp" | "bmp" | "gif" | "tga" => Some(Self::Image),
+            "ogg" | "wav" | "mp3" | "flac" | "opus" => Some(Self::Audio),
+            "ttf" | "otf" | "ttc" => Some(Self::Font),
+            "mp4" | "webm" | "mkv" | "avi" => Some(Self::Video),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Audio => "audio",
+            Self::Font => "font",
+            Self::Video => "video",
+        }
+    }
+}
+
+impl fmt::Display for AssetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A file path referenced by a manifest or scenario
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetReference {
+    /// Path relative to the asset root, using `/`
+    pub path: String,
+    /// Where the reference comes from
+    pub source: String,
+}
+
+/// A media file on disk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetFile {
+    /// Path relative to the asset root, using `/`
+    pub path: String,
+    pub kind: AssetKind,
+    pub bytes: u64,
+}
+
+/// File counts and sizes for one category
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct KindSummary {
+    pub files: usize,
+    pub bytes: u64,
+    pub unused_files: usize,
+    pub unused_bytes: u64,
+}
+
+/// All references found in manifests and scenarios
+#[derive(Debug, Default)]
+pub struct AssetReferences {
+    /// References that must exist on disk
+    pub explicit: Vec<AssetReference>,
+    /// Files that count as used but may legitimately be absent
+    /// (e.g. voice files that have not been recorded yet)
+    pub implicit: HashSet<String>,
+    /// Manifest IDs scenarios may use instead of file paths
+    pub ids: HashSet<String>,
+    /// Manifests or scenarios that could not be read
+    pub problems: Vec<String>,
+}
+
+impl AssetReferences {
+    /// Create an empty reference set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn add(&mut self, root_name: Option<&str>, path: &str, source: impl Into<String>) {
+        let path = normalize_reference(path, root_name);
+        if path.is_empty() {
+            return;
+        }
+        self.explicit.push(AssetReference {
+            path,
+            source: source.into(),
+        });
+    }
+
+    /// Add the body, outfit and accessory layers of a layered sprite
+    fn add_sprite_layers(
+        &mut self,
+        root_name: Option<&str>,
+        character: &CharacterDef,
+        source: &str,
+    ) {
+        let SpriteMode::Layered {
+            base,
+            outfits,
+            accessories,
+            ..
+        } = &character.sprite_mode
+        else {
+            return;
+        };
+        self.add(root_name, base, format!("{} base layer", source));
+        for (outfit, path) in outfits {
+            self.add(root_name, path, format!("{} outfit {}", source, outfit));
+        }
+        for path in accessories {
+            self.add(root_name, path, format!("{} accessory layer", source));
+        }
+    }
+
+    /// Add every file referenced by the manifests under `asset_root`
+    ///
+    /// Manifests that do not exist are skipped; manifests that fail to load
+    /// are recorded in `problems`.
+    pub fn collect_manifests(&mut self, asset_root: &Path) {
+        let root_name = root_name(asset_root);
+        let root_name = root_name.as_deref();
+
+        if let Some(manifest) = self.load_manifest(
+            asset_root,
+            BACKGROUNDS_MANIFEST,
+            BackgroundManifest::load_from_file,
+        ) {
+            for (id, background) in &manifest.backgrounds {
+                self.ids.insert(id.clone());
+                for (variant, path) in &background.variants {
+                    self.add(
+                        root_name,
+                        path,
+                        format!("{} {}.{}", BACKGROUNDS_MANIFEST, id, variant),
+                    );
+                }
+            }
+        }
+
+        if let Some(manifest) =
+            self.load_manifest(asset_root, BGM_MANIFEST, BgmManifest::load_from_file)
+        {
+            for (id, track) in &manifest.tracks {
+                self.ids.insert(id.clone());
+                self.add(
+                    root_name,
+                    &track.file_path,
+                    format!("{} {}", BGM_MANIFEST, id),
+                );
+            }
+        }
+
+        if let Some(manifest) =
+            self.load_manifest(asset_root, SE_MANIFEST, SeManifest::load_from_file)
+        {
+            for (id, sound) in &manifest.sounds {
+                self.ids.insert(id.clone());
+                self.add(
+                    root_name,
+                    &sound.file_path,
+                    format!("{} {}", SE_MANIFEST, id),
+                );
+            }
+            for (id, pool) in &manifest.pools {
+                self.ids.insert(id.clone());
+                for file in &pool.files {
+                    self.add(root_name, file, format!("{} pool {}", SE_MANIFEST, id));
+                }
+            }
+        }
+
+        if let Some(manifest) = self.load_manifest(
+            asset_root,
+            UI_THEMES_MANIFEST,
+            UiThemeManifest::load_from_file,
+        ) {
+            for (id, theme) in &manifest.themes {
+                for path in theme_paths(theme) {
+                    self.add(root_name, path, format!("{} {}", UI_THEMES_MANIFEST, id));
+                }
+            }
+        }
+
+        if asset_root.join(CHARACTERS_MANIFEST).is_file() {
+            let mut registry = CharacterRegistry::with_base_dir(asset_root);
+            match registry.load_from_manifest(CHARACTERS_MANIFEST) {
+                Ok(_) => {
+                    let mut ids = registry.character_ids();
+                    ids.sort_unstable();
+                    for id in ids {
+                        let Ok(character) = registry.get(id) else {
+                            continue;
+                        };
+                        for (expression, path) in &character.expressions {
+                            self.add(
+                                root_name,
+                                path,
+                                format!("{} {}.{}", CHARACTERS_MANIFEST, id, expression),
+                            );
+                        }
+                        self.add_sprite_layers(
+                            root_name,
+                            character,
+                            &format!("{} {}", CHARACTERS_MANIFEST, id),
+                        );
+                    }
+                }
+                Err(e) => self
+                    .problems
+                    .push(format!("Failed to load {}: {}", CHARACTERS_MANIFEST, e)),
+            }
+        }
+    }
+
+    fn load_manifest<T, E: fmt::Display>(
+        &mut self,
+        asset_root: &Path,
+        manifest: &str,
+        load: impl FnOnce(PathBuf) -> Result<T, E>,
+    ) -> Option<T> {
+        let path = asset_root.join(manifest);
+        if !path.is_file() {
+            return None;
+        }
+        match load(path) {
+            Ok(manifest) => Some(manifest),
+            Err(e) => {
+                self.problems
+                    .push(format!("Failed to load {}: {}", manifest, e));
+                None
+            }
+        }
+    }
+
+    /// Add every asset referenced by a scenario
+    ///
+    /// References that match a manifest ID are resolved through the manifest,
+    /// so manifests should be collected first. Expected voice files count as
+    /// used without being required to exist.
+    pub fn collect_scenario(&mut self, asset_root: &Path, name: &str, scenario: &Scenario) {
+        let root_name = root_name(asset_root);
+        let root_name = root_name.as_deref();
+
+        for character in &scenario.characters {
+            for (expression, path) in &character.expressions {
+                self.add(
+                    root_name,
+                    path,
+                    format!("{} character {}.{}", name, character.id, expression),
+                );
+            }
+            self.add_sprite_layers(
+                root_name,
+                character,
+                &format!("{} character {}", name, character.id),
+            );
+        }
+
+        for scene in ordered_scenes(scenario) {
+            let mut paths = Vec::new();
+            visit_commands(&scene.commands, &mut |command| match command {
+                ScenarioCommand::ShowBackground { asset, .. }
+                | ScenarioCommand::ShowCG { asset, .. }
+                | ScenarioCommand::PlayBgm { asset, .. }
+                | ScenarioCommand::PlaySe { asset, .. }
+                | ScenarioCommand::PlayVoice { asset, .. } => paths.push(asset.path()),
+                ScenarioCommand::ShowCharacter { sprite, .. }
+                | ScenarioCommand::ChangeSprite { sprite, .. } => {
+                    // Layered characters need no sprite; layered refs list each layer
+                    paths.extend(sprite.layers().filter(|path| !path.is_empty()))
+                }
+                _ => {}
+            });
+            for path in paths {
+                if !self.ids.contains(path) {
+                    self.add(root_name, path, format!("{} scene '{}'", name, scene.id));
+                }
+            }
+        }
+
+        let config = VoiceScriptConfig {
+            include_narrator: true,
+            ..Default::default()
+        };
+        for line in voice_script::collect_voice_lines(scenario, &config) {
+            self.implicit
+                .insert(normalize_reference(&line.voice_file, root_name));
+        }
+    }
+}
+
+/// Result of cross-referencing assets
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetReport {
+    /// Totals per category
+    pub kinds: BTreeMap<AssetKind, KindSummary>,
+    /// Files no manifest or scenario references
+    pub unused: Vec<AssetFile>,
+    /// References to files that do not exist
+    pub missing: Vec<AssetReference>,
+    /// Manifests or scenarios that could not be read
+    pub problems: Vec<String>,
+}
+
+impl AssetReport {
+    /// Compare references with the media files under `asset_root`
+    pub fn new(asset_root: &Path, references: &AssetReferences) -> Self {
+        let mut report = Self {
+            problems: references.problems.clone(),
+            ..Default::default()
+        };
+
+        let used: HashSet<&str> = references
+            .explicit
+            .iter()
+            .map(|reference| reference.path.as_str())
+            .chain(references.implicit.iter().map(String::as_str))
+            .collect();
+
+        for file in find_asset_files(asset_root) {
+            let summary = report.kinds.entry(file.kind).or_default();
+            summary.files = summary.files.saturating_add(1);
+            summary.bytes = summary.bytes.saturating_add(file.bytes);
+            if !used.contains(file.path.as_str()) {
+                summary.unused_files = summary.unused_files.saturating_add(1);
+                summary.unused_bytes = summary.unused_bytes.saturating_add(file.bytes);
+                report.unused.push(file);
+            }
+        }
+
+        let mut reported = HashSet::new();
+        for reference in &references.explicit {
+            if !asset_root.join(&reference.path).is_file()
+                && reported.insert((&reference.path, &reference.source))
+            {
+                report.missing.push(reference.clone());
+            }
+        }
+        report
+            .missing
+            .sort_by(|a, b| (&a.path, &a.source).cmp(&(&b.path, &b.source)));
+
+        report
+    }
+
+    /// Totals across all categories
+    pub fn total(&self) -> KindSummary {
+        self.kinds
+            .values()
+            .fold(KindSummary::default(), |total, kind| KindSummary {
+                files: total.files.saturating_add(kind.files),
+                bytes: total.bytes.saturating_add(kind.bytes),
+                unused_files: total.unused_files.saturating_add(kind.unused_files),
+                unused_bytes: total.unused_bytes.saturating_add(kind.unused_bytes),
+            })
+    }
+
+    /// Serialize as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// What to do with unused files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrphanAction {
+    /// Remove the files
+    Delete,
+    /// Move the files into this directory, keeping their relative paths
+    Quarantine(PathBuf),
+}
+
+/// Delete or quarantine the unused files of a report
+///
+/// Returns a description of each operation. With `dry_run` nothing is
+/// changed on disk and the descriptions say what would happen.
+///
+/// # Errors
+/// Refuses to run if the report has problems, since files referenced only by
+/// a scenario or manifest that could not be read look unused. `force`
+/// overrides this.
+pub fn apply_orphan_action(
+    asset_root: &Path,
+    report: &AssetReport,
+    action: &OrphanAction,
+    dry_run: bool,
+    force: bool,
+) -> Result<Vec<String>> {
+    if !report.problems.is_empty() && !force {
+        bail!(
+            "Refusing to clean up unused files: {} scenarios or manifests could not be read, \
+             so the assets they reference would be treated as unused",
+            report.problems.len()
+        );
+    }
+    let mut log = Vec::with_capacity(report.unused.len());
+    for file in &report.unused {
+        let source = asset_root.join(&file.path);
+        match action {
+            OrphanAction::Delete => {
+                if !dry_run {
+                    std::fs::remove_file(&source)
+                        .with_context(|| format!("Failed to delete '{}'", source.display()))?;
+                }
+                log.push(format!("delete {}", source.display()));
+            }
+            OrphanAction::Quarantine(dir) => {
+                let target = dir.join(&file.path);
+                if !dry_run {
+                    move_file(&source, &target)?;
+                }
+                log.push(format!("move {} -> {}", source.display(), target.display()));
+            }
+        }
+    }
+    Ok(log)
+}
+
+fn move_file(source: &Path, target: &Path) -> Result<()> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create '{}'", parent.display()))?;
+    }
+    if std::fs::rename(source, target).is_err() {
+        // rename fails across file systems; fall back to copy and delete
+        std::fs::copy(source, target).with_context(|| {
+            format!(
+                "Failed to move '{}' to '{}'",
+                source.display(),
+                target.display()
+            )
+        })?;
+        std::fs::remove_file(source)
+            .with_context(|| format!("Failed to delete '{}'", source.display()))?;
+    }
+    Ok(())
+}
+
+/// Media files under `asset_root`, sorted by path
+pub fn find_asset_files(asset_root: &Path) -> Vec<AssetFile> {
+    let mut files: Vec<AssetFile> = WalkDir::new(asset_root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let kind = AssetKind::from_path(entry.path())?;
+            let path = relative_asset_path(asset_root, entry.path())?;
+            let bytes = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            Some(AssetFile { path, kind, bytes })
+        })
+        .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+/// Normalize a referenced path to be relative to the asset root
+///
+/// Manifests are inconsistent about whether paths include the asset
+/// directory itself (`assets/bg/room.png` vs `bg/room.png`), so a leading
+/// `{root_name}/` is stripped.
+pub fn normalize_reference(reference: &str, root_name: Option<&str>) -> String {
+    let mut path = reference.trim().replace('\\', "/");
+    while let Some(rest) = path.strip_prefix("./") {
+        path = rest.to_string();
+    }
+    if let Some(name) = root_name
+        && let Some(rest) = path.strip_prefix(name).and_then(|p| p.strip_prefix('/'))
+    {
+        path = rest.to_string();
+    }
+    path
+}
+
+fn root_name(asset_root: &Path) -> Option<String> {
+    asset_root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+fn theme_paths(theme: &UiThemeDef) -> Vec<&str> {
+    let buttons = &theme.buttons;
+    let mut paths = vec![
+        theme.dialogue_box.default.as_str(),
+        &buttons.continue_idle,
+        &buttons.continue_hover,
+        &buttons.history_idle,
+        &buttons.history_hover,
+        &buttons.skip_idle,
+        &buttons.skip_hover,
+        &buttons.options_idle,
+        &buttons.options_hover,
+        &theme.choices.idle,
+        &theme.choices.hover,
+        &theme.choices.disabled,
+    ];
+    paths.extend(theme.dialogue_box.variants.values().map(String::as_str));
+    if let Some(fonts) = &theme.fonts {
+        paths.extend(
+            [&fonts.dialogue, &fonts.speaker, &fonts.ui]
+                .into_iter()
+                .flatten()
+                .map(String::as_str),
+        );
+    }
+    if let Some(sounds) = &theme.sounds {
+        paths.extend(
+            [&sounds.click, &sounds.hover, &sounds.advance]
+                .into_iter()
+                .flatten()
+                .map(String::as_str),
+        );
+    }
+    if let Some(quick_menu) = &theme.quick_menu {
+        paths.extend(
+            quick_menu
+                .buttons
+                .iter()
+                .filter_map(|button| button.icon.as_deref()),
+        );
+    }
+    paths
+}
+
+/// Format a byte count for display
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS.get(unit).copied().unwrap_or("B"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{AssetRef, ScenarioMetadata, Scene};
+
+    fn write(root: &Path, path: &str, contents: &[u8]) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    fn scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("ch1", "Chapter 1"), "intro");
+        let mut scene = Scene::new("intro", "Intro");
+        scene.add_command(ScenarioCommand::ShowBackground {
+            asset: AssetRef::new("bg.room"),
+            transition: Default::default(),
+        });
+        scene.add_command(ScenarioCommand::PlaySe {
+            asset: AssetRef::new("assets/se/missing.ogg"),
+            volume: 1.0,
+        });
+        scenario.add_scene("intro", scene);
+        scenario
+    }
+
+    #[test]
+    fn test_normalize_reference() {
+        assert_eq!(
+            normalize_reference("./assets/bg/room.png", Some("assets")),
+            "bg/room.png"
+        );
+        assert_eq!(
+            normalize_reference("bg\\room.png", Some("assets")),
+            "bg/room.png"
+        );
+        assert_eq!(
+            normalize_reference("assets_extra/a.png", Some("assets")),
+            "assets_extra/a.png"
+        );
+    }
+
+    #[test]
+    fn test_report_unused_and_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("assets");
+        write(
+            &root,
+            BACKGROUNDS_MANIFEST,
+            br#"(backgrounds: {"bg.room": (variants: {"day": "assets/bg/room.png"}, default_variant: "day")})"#,
+        );
+        write(&root, "bg/room.png", &[0; 10]);
+        write(&root, "bg/unused.png", &[0; 20]);
+        write(&root, "bgm/unused.ogg", &[0; 5]);
+
+        let mut references = AssetReferences::new();
+        references.collect_manifests(&root);
+        references.collect_scenario(&root, "ch1.toml", &scenario());
+        let report = AssetReport::new(&root, &references);
+
+        assert!(report.problems.is_empty());
+        let unused: Vec<&str> = report.unused.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(unused, ["bg/unused.png", "bgm/unused.ogg"]);
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].path, "se/missing.ogg");
+
+        let images = report.kinds[&AssetKind::Image];
+        assert_eq!((images.files, images.bytes), (2, 30));
+        assert_eq!((images.unused_files, images.unused_bytes), (1, 20));
+        assert_eq!(report.total().unused_bytes, 25);
+    }
+
+    #[test]
+    fn test_se_pool_files_are_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("assets");
+        write(
+            &root,
+            SE_MANIFEST,
+            br#"(sounds: {}, pools: {"footsteps": (id: "footsteps", files: ["assets/se/step1.ogg", "assets/se/step2.ogg"])})"#,
+        );
+        write(&root, "se/step1.ogg", &[0; 4]);
+
+        let mut scenario = Scenario::new(ScenarioMetadata::new("ch1", "Chapter 1"), "intro");
+        let mut scene = Scene::new("intro", "Intro");
+        scene.add_command(ScenarioCommand::PlaySe {
+            asset: AssetRef::new("footsteps"),
+            volume: 1.0,
+        });
+        scenario.add_scene("intro", scene);
+
+        let mut references = AssetReferences::new();
+        references.collect_manifests(&root);
+        references.collect_scenario(&root, "ch1.toml", &scenario);
+        let report = AssetReport::new(&root, &references);
+
+        assert!(report.problems.is_empty());
+        assert!(report.unused.is_empty());
+        let missing: Vec<&str> = report.missing.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(missing, ["se/step2.ogg"]);
+    }
+
+    #[test]
+    fn test_ui_theme_files_are_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("assets");
+        write(
+            &root,
+            UI_THEMES_MANIFEST,
+            br#"(themes: {"light": (
+                id: "light",
+                name: "Light",
+                dialogue_box: (default: "assets/ui/box.png"),
+                buttons: (
+                    continue_idle: "assets/ui/btn.png", continue_hover: "assets/ui/btn.png",
+                    history_idle: "assets/ui/btn.png", history_hover: "assets/ui/btn.png",
+                    skip_idle: "assets/ui/btn.png", skip_hover: "assets/ui/btn.png",
+                    options_idle: "assets/ui/btn.png", options_hover: "assets/ui/btn.png",
+                ),
+                choices: (idle: "assets/ui/btn.png", hover: "assets/ui/btn.png", disabled: "assets/ui/btn.png"),
+                quick_menu: Some((buttons: [
+                    (id: "save", icon: Some("assets/ui/icons/save.png")),
+                    (id: "load"),
+                    (id: "gallery", icon: Some("assets/ui/icons/gallery.png")),
+                ])),
+            )})"#,
+        );
+        write(&root, "ui/box.png", &[0; 4]);
+        write(&root, "ui/btn.png", &[0; 4]);
+        write(&root, "ui/icons/save.png", &[0; 4]);
+        write(&root, "ui/icons/gallery.png", &[0; 4]);
+        write(&root, "ui/icons/unused.png", &[0; 4]);
+
+        let mut references = AssetReferences::new();
+        references.collect_manifests(&root);
+        let report = AssetReport::new(&root, &references);
+
+        assert!(report.problems.is_empty(), "{:?}", report.problems);
+        let unused: Vec<&str> = report.unused.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(unused, ["ui/icons/unused.png"]);
+        assert!(report.missing.is_empty());
+    }
+
+    #[test]
+    fn test_quarantine_dry_run_and_move() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("assets");
+        let quarantine = dir.path().join("quarantine");
+        write(&root, "bg/unused.png", &[0; 4]);
+
+        let report = AssetReport::new(&root, &AssetReferences::new());
+        let action = OrphanAction::Quarantine(quarantine.clone());
+
+        let log = apply_orphan_action(&root, &report, &action, true, false).unwrap();
+        assert_eq!(log.len(), 1);
+        assert!(root.join("bg/unused.png").exists());
+
+        apply_orphan_action(&root, &report, &action, false, false).unwrap();
+        assert!(!root.join("bg/unused.png").exists());
+        assert!(quarantine.join("bg/unused.png").exists());
+    }
+
+    #[test]
+    fn test_cleanup_refused_when_files_could_not_be_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("assets");
+        write(&root, BACKGROUNDS_MANIFEST, b"(backgrounds: {");
+        write(&root, "bg/room.png", &[0; 4]);
+
+        let mut references = AssetReferences::new();
+        references.collect_manifests(&root);
+        let report = AssetReport::new(&root, &references);
+        assert_eq!(report.problems.len(), 1);
+
+        let error =
+            apply_orphan_action(&root, &report, &OrphanAction::Delete, false, false).unwrap_err();
+        assert!(error.to_string().starts_with("Refusing to clean up"));
+        assert!(root.join("bg/room.png").exists());
+
+        apply_orphan_action(&root, &report, &OrphanAction::Delete, false, true).unwrap();
+        assert!(!root.join("bg/room.png").exists());
+    }
+}
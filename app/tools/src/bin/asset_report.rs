@@ -0,0 +1,143 @@
+//! Asset Report CLI
+//!
+//! Reports unused and missing assets with sizes per category, and optionally
+//! deletes or quarantines unused files.
+
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use narrative_tools::asset_report::{
+    AssetReferences, AssetReport, OrphanAction, apply_orphan_action, format_bytes,
+};
+use narrative_tools::scenario_files::{find_scenario_files, load_scenario};
+use std::path::PathBuf;
+
+/// Report output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human-readable summary
+    Text,
+    /// Full JSON report
+    Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "asset-report",
+    about = "Report unused and missing assets and clean up orphaned files"
+)]
+struct Args {
+    /// Scenario files or directories (default: <ASSET_ROOT>/scenarios)
+    #[arg(value_name = "FILES_OR_DIRS")]
+    paths: Vec<PathBuf>,
+
+    /// Asset root containing manifests/ and the media files
+    #[arg(long, default_value = "assets")]
+    asset_root: PathBuf,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Delete unused files
+    #[arg(long, conflicts_with = "quarantine")]
+    delete: bool,
+
+    /// Move unused files into this directory, keeping their relative paths
+    #[arg(long, value_name = "DIR")]
+    quarantine: Option<PathBuf>,
+
+    /// Only print what --delete or --quarantine would do
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Delete or quarantine even if some scenarios or manifests could not be read
+    #[arg(long)]
+    force: bool,
+}
+
+fn main() -> Result<()> {
+    let mut args = Args::parse();
+    if !args.asset_root.is_dir() {
+        anyhow::bail!(
+            "Asset root '{}' is not a directory",
+            args.asset_root.display()
+        );
+    }
+    if args.paths.is_empty() {
+        args.paths.push(args.asset_root.join("scenarios"));
+    }
+
+    let mut references = AssetReferences::new();
+    references.collect_manifests(&args.asset_root);
+    for path in find_scenario_files(&args.paths) {
+        match load_scenario(&path) {
+            Ok(scenario) => {
+                let name = path.display().to_string();
+                references.collect_scenario(&args.asset_root, &name, &scenario);
+            }
+            Err(e) => references
+                .problems
+                .push(format!("Skipping {}: {:#}", path.display(), e)),
+        }
+    }
+    let report = AssetReport::new(&args.asset_root, &references);
+
+    match args.format {
+        Format::Text => print_summary(&report),
+        Format::Json => println!("{}", report.to_json()?),
+    }
+
+    let action = match (args.delete, args.quarantine) {
+        (true, _) => Some(OrphanAction::Delete),
+        (false, Some(dir)) => Some(OrphanAction::Quarantine(dir)),
+        (false, None) => None,
+    };
+    if let Some(action) = action {
+        let log =
+            apply_orphan_action(&args.asset_root, &report, &action, args.dry_run, args.force)?;
+        let prefix = if args.dry_run { "[dry run] " } else { "" };
+        for entry in &log {
+            eprintln!("{}{}", prefix, entry);
+        }
+        eprintln!("{}Processed {} unused files", prefix, log.len());
+    }
+
+    if !report.missing.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_summary(report: &AssetReport) {
+    for problem in &report.problems {
+        println!("⚠️  {}", problem);
+    }
+    for reference in &report.missing {
+        println!("❌ Missing {} ({})", reference.path, reference.source);
+    }
+    for file in &report.unused {
+        println!("🗑️  Unused {} ({})", file.path, format_bytes(file.bytes));
+    }
+
+    println!();
+    println!("📊 Asset Summary:");
+    for (kind, summary) in &report.kinds {
+        println!(
+            "   - {}: {} files, {} ({} unused, {})",
+            kind,
+            summary.files,
+            format_bytes(summary.bytes),
+            summary.unused_files,
+            format_bytes(summary.unused_bytes)
+        );
+    }
+    let total = report.total();
+    println!(
+        "   - Total: {} files, {} ({} unused, {})",
+        total.files,
+        format_bytes(total.bytes),
+        total.unused_files,
+        format_bytes(total.unused_bytes)
+    );
+    println!("   - Missing references: {}", report.missing.len());
+}
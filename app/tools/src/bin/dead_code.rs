@@ -0,0 +1,95 @@
+//! Dead Code CLI
+//!
+//! Reports unreachable scenes, flags that are set but never read or read but
+//! never set, and variables that are set but never read.
+
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use narrative_tools::dead_code::{DeadCodeAnalysis, DeadCodeReport};
+use narrative_tools::scenario_files::{find_scenario_files, load_scenario};
+use std::path::PathBuf;
+
+/// Report output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human-readable findings and summary
+    Text,
+    /// Full JSON report
+    Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "dead-code",
+    about = "Report unreachable scenes and unused flags and variables"
+)]
+struct Args {
+    /// Scenario files or directories (default: <ASSET_ROOT>/scenarios)
+    #[arg(value_name = "FILES_OR_DIRS")]
+    paths: Vec<PathBuf>,
+
+    /// Asset root containing manifests/
+    #[arg(long, default_value = "assets")]
+    asset_root: PathBuf,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+fn main() -> Result<()> {
+    let mut args = Args::parse();
+    if args.paths.is_empty() {
+        args.paths.push(args.asset_root.join("scenarios"));
+    }
+
+    let mut analysis = DeadCodeAnalysis::new();
+    if args.asset_root.is_dir() {
+        analysis.collect_manifests(&args.asset_root)?;
+    }
+    let mut failures = 0usize;
+    for path in find_scenario_files(&args.paths) {
+        match load_scenario(&path) {
+            Ok(scenario) => {
+                let source = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read '{}'", path.display()))?;
+                analysis.add_scenario(&path, &source, &scenario)?;
+            }
+            Err(e) => {
+                eprintln!("⚠️  Skipping {}: {:#}", path.display(), e);
+                failures += 1;
+            }
+        }
+    }
+    let report = analysis.report();
+
+    match args.format {
+        Format::Text => print_summary(&report),
+        Format::Json => println!("{}", report.to_json()?),
+    }
+
+    if failures > 0 || !report.findings.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_summary(report: &DeadCodeReport) {
+    for finding in &report.findings {
+        let files: Vec<String> = finding
+            .files
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect();
+        println!("⚠️  {} ({})", finding, files.join(", "));
+    }
+
+    println!();
+    println!("📊 Dead Code Summary:");
+    println!(
+        "   - Reachable scenes: {}/{}",
+        report.reachable_scenes, report.scenes
+    );
+    println!("   - Findings: {}", report.findings.len());
+    println!("   - Suppressed: {}", report.suppressed);
+}
@@ -0,0 +1,124 @@
+//! Refactor CLI
+//!
+//! Renames a scene, flag, variable or character ID across a project's
+//! scenarios and manifests, then re-runs the validator on the scenarios.
+
+use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+use narrative_tools::refactor::{RenameKind, RenamePlan};
+use narrative_tools::scenario_files::find_scenario_files;
+use narrative_tools::scenario_validator::{ValidationConfig, validate_files};
+use std::path::PathBuf;
+
+/// Kind of name to rename
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Kind {
+    Scene,
+    Flag,
+    Variable,
+    Character,
+}
+
+impl From<Kind> for RenameKind {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Scene => Self::Scene,
+            Kind::Flag => Self::Flag,
+            Kind::Variable => Self::Variable,
+            Kind::Character => Self::Character,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "refactor",
+    about = "Rename scenes, flags, variables and characters across a project"
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Rename an ID in every scenario and manifest that uses it
+    Rename {
+        /// What the ID names
+        #[arg(value_enum)]
+        kind: Kind,
+
+        /// Current ID
+        old: String,
+
+        /// New ID
+        new: String,
+
+        /// Scenario files or directories (default: <ASSET_ROOT>/scenarios)
+        #[arg(value_name = "FILES_OR_DIRS")]
+        paths: Vec<PathBuf>,
+
+        /// Asset root containing manifests/
+        #[arg(long, default_value = "assets")]
+        asset_root: PathBuf,
+
+        /// Only print the changes as a diff
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+fn main() -> Result<()> {
+    let Command::Rename {
+        kind,
+        old,
+        new,
+        mut paths,
+        asset_root,
+        dry_run,
+    } = Args::parse().command;
+    if paths.is_empty() {
+        paths.push(asset_root.join("scenarios"));
+    }
+    let asset_root = asset_root.is_dir().then_some(asset_root);
+
+    let plan = RenamePlan::new(kind.into(), &old, &new, &paths, asset_root.as_deref())?;
+    if plan.edits.is_empty() {
+        println!("⚠️  No references to {} '{}' found", plan.kind, old);
+        std::process::exit(1);
+    }
+
+    print!("{}", plan.diff());
+    println!();
+    println!(
+        "✏️  {} {} '{}' → '{}': {} references in {} files",
+        if dry_run { "Would rename" } else { "Renamed" },
+        plan.kind,
+        old,
+        new,
+        plan.renamed(),
+        plan.edits.len()
+    );
+    if dry_run {
+        return Ok(());
+    }
+    plan.apply()?;
+
+    println!("🔍 Validating scenario files...");
+    let results = validate_files(&find_scenario_files(&paths), &ValidationConfig::default())?;
+    let mut failed = false;
+    for result in &results {
+        for warning in &result.warnings {
+            println!("⚠️  {}: {}", result.file_path.display(), warning);
+        }
+        for error in &result.errors {
+            println!("❌ {}: {}", result.file_path.display(), error);
+        }
+        failed |= result.has_errors();
+    }
+    if failed {
+        std::process::exit(1);
+    }
+    println!("✅ {} scenario files valid", results.len());
+    Ok(())
+}
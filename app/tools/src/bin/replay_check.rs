@@ -0,0 +1,83 @@
+//! Replay Check CLI
+//!
+//! Plays a replay file back on its scenario without a window and checks that
+//! it ends in the recorded state.
+
+use anyhow::Result;
+use clap::Parser;
+use narrative_engine::app::{Replay, ScriptedInput};
+use narrative_tools::scenario_files::load_scenario;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "replay-check",
+    about = "Play back a replay file and check it ends in the recorded state"
+)]
+struct Args {
+    /// Replay file (e.g. saves/replays/chapter_01.ron)
+    replay: PathBuf,
+
+    /// Scenario file the replay was recorded on
+    scenario: PathBuf,
+
+    /// List the recorded inputs with their play time
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let replay = Replay::load(&args.replay)?;
+    let scenario = load_scenario(&args.scenario)?;
+
+    println!(
+        "🎬 Replay of '{}' ({} inputs, {}, engine {})",
+        replay.scenario_id,
+        replay.events.len(),
+        format_time(replay.play_time_secs),
+        replay.engine_version
+    );
+    if args.verbose {
+        for event in &replay.events {
+            let input = match &event.input {
+                ScriptedInput::Choose(index) => format!("choice {}", index + 1),
+                ScriptedInput::ChooseText(text) => format!("choice \"{}\"", text),
+                ScriptedInput::Name(name) => format!("name \"{}\"", name),
+            };
+            println!(
+                "   {:>8}  {:<20} {}",
+                format_time(event.time_secs),
+                event.scene,
+                input
+            );
+        }
+    }
+
+    match replay.verify(scenario) {
+        Ok(outcome) => {
+            println!(
+                "✅ Playback matches: ended in scene '{}' with {} flags and {} variables",
+                outcome.scene.as_deref().unwrap_or("<none>"),
+                outcome.flags.len(),
+                outcome.variables.len()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            println!("❌ {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Play time as `h:mm:ss` or `m:ss`
+fn format_time(secs: f64) -> String {
+    let total = secs.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
@@ -3,14 +3,58 @@
 //! Command-line interface for scenario file validation.
 
 use anyhow::Result;
-use narrative_tools::scenario_validator::{self, ValidationConfig};
+use narrative_core::VoiceResolver;
+use narrative_tools::scenario_validator::{
+    self, TextFitCheckConfig, ValidationConfig, ValidationReport, ValidationResult,
+    VoiceCheckConfig,
+};
+use narrative_tools::text_fit::{self, TextFitConfig};
+use narrative_tools::text_lint::{DEFAULT_CONFIG_FILE, TextLintConfig, TextLinter};
+use narrative_tools::validation_rules::{DEFAULT_RULES_FILE, RuleConfig};
 use std::env;
 use std::path::PathBuf;
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
+/// Exit code when every file passed
+const EXIT_OK: u8 = 0;
+/// Exit code when a file has errors (including warnings under `--deny-warnings`)
+const EXIT_FAILED: u8 = 1;
+/// Exit code for invalid arguments or configuration, or files that could not be validated
+const EXIT_USAGE: u8 = 2;
+
+/// Output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Human-readable results and summary
+    Text,
+    /// JSON report (one line per revalidation in watch mode)
+    Json,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => ExitCode::from(code),
+        Err(e) => {
+            eprintln!("❌ {:#}", e);
+            ExitCode::from(EXIT_USAGE)
+        }
+    }
+}
+
+fn run() -> Result<u8> {
     let args: Vec<String> = env::args().collect();
     let mut config = ValidationConfig::default();
     let mut paths_to_validate = Vec::new();
+    let mut lint = false;
+    let mut lint_config_path = None;
+    let mut voice_dir = None;
+    let mut fit = false;
+    let mut fit_config_path = None;
+    let mut asset_root = PathBuf::from("assets");
+    let mut format = Format::Text;
+    let mut watch = false;
+    let mut rules_path = None;
+    let mut deny_warnings = false;
 
     // Simple argument parsing
     let mut i = 1;
@@ -18,9 +62,66 @@ fn main() -> Result<()> {
         match args[i].as_str() {
             "--strict" | "-s" => config.strict_mode = true,
             "--no-assets" => config.check_assets = false,
+            "--lint" => lint = true,
+            "--fit" => fit = true,
+            "--watch" | "-w" => watch = true,
+            "--deny-warnings" | "-D" => deny_warnings = true,
+            "--config" => {
+                i += 1;
+                let Some(path) = args.get(i) else {
+                    eprintln!("--config requires a path");
+                    return Ok(EXIT_USAGE);
+                };
+                rules_path = Some(PathBuf::from(path));
+            }
+            "--format" => {
+                i += 1;
+                format = match args.get(i).map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    _ => {
+                        eprintln!("--format requires 'text' or 'json'");
+                        return Ok(EXIT_USAGE);
+                    }
+                };
+            }
+            "--lint-config" => {
+                i += 1;
+                let Some(path) = args.get(i) else {
+                    eprintln!("--lint-config requires a path");
+                    return Ok(EXIT_USAGE);
+                };
+                lint = true;
+                lint_config_path = Some(PathBuf::from(path));
+            }
+            "--fit-config" => {
+                i += 1;
+                let Some(path) = args.get(i) else {
+                    eprintln!("--fit-config requires a path");
+                    return Ok(EXIT_USAGE);
+                };
+                fit = true;
+                fit_config_path = Some(PathBuf::from(path));
+            }
+            "--voice-dir" => {
+                i += 1;
+                let Some(dir) = args.get(i) else {
+                    eprintln!("--voice-dir requires a directory");
+                    return Ok(EXIT_USAGE);
+                };
+                voice_dir = Some(dir.clone());
+            }
+            "--asset-root" => {
+                i += 1;
+                let Some(dir) = args.get(i) else {
+                    eprintln!("--asset-root requires a directory");
+                    return Ok(EXIT_USAGE);
+                };
+                asset_root = PathBuf::from(dir);
+            }
             "--help" | "-h" => {
                 print_help();
-                return Ok(());
+                return Ok(EXIT_OK);
             }
             path if !path.starts_with("--") => {
                 paths_to_validate.push(PathBuf::from(path));
@@ -28,74 +129,169 @@ fn main() -> Result<()> {
             _ => {
                 eprintln!("Unknown argument: {}", args[i]);
                 print_help();
-                std::process::exit(1);
+                return Ok(EXIT_USAGE);
             }
         }
         i += 1;
     }
 
+    // Fall back to the project validator config in the current directory
+    config.rules = match rules_path {
+        Some(path) => RuleConfig::load(path)?,
+        None if PathBuf::from(DEFAULT_RULES_FILE).is_file() => {
+            RuleConfig::load(DEFAULT_RULES_FILE)?
+        }
+        None => RuleConfig::default(),
+    };
+    config.rules.deny_warnings |= deny_warnings;
+
+    if lint {
+        // Fall back to the project lint config in the current directory
+        let lint_config = match lint_config_path {
+            Some(path) => TextLintConfig::load(path)?,
+            None if PathBuf::from(DEFAULT_CONFIG_FILE).is_file() => {
+                TextLintConfig::load(DEFAULT_CONFIG_FILE)?
+            }
+            None => TextLintConfig::default(),
+        };
+        config.text_lint = Some(TextLinter::new(lint_config)?);
+    }
+
+    if fit {
+        // Fall back to the project text fit config in the current directory
+        let fit_config = match fit_config_path {
+            Some(path) => TextFitConfig::load(path)?,
+            None if PathBuf::from(text_fit::DEFAULT_CONFIG_FILE).is_file() => {
+                TextFitConfig::load(text_fit::DEFAULT_CONFIG_FILE)?
+            }
+            None => TextFitConfig::default(),
+        };
+        config.text_fit = Some(TextFitCheckConfig::new(fit_config, &asset_root)?);
+    }
+
+    if let Some(voice_dir) = voice_dir {
+        config.voice_check = Some(VoiceCheckConfig {
+            resolver: VoiceResolver::new(voice_dir),
+            asset_root,
+        });
+    }
+
     // Default to validating scenarios directory
     if paths_to_validate.is_empty() {
         paths_to_validate.push(PathBuf::from("assets/scenarios"));
     }
 
-    println!("🔍 Validating scenario files...");
-    println!("📋 Configuration:");
-    println!("   - Strict mode: {}", config.strict_mode);
-    println!("   - Check assets: {}", config.check_assets);
-    println!();
+    if format == Format::Text {
+        println!("🔍 Validating scenario files...");
+        println!("📋 Configuration:");
+        println!("   - Strict mode: {}", config.strict_mode);
+        println!("   - Check assets: {}", config.check_assets);
+        println!("   - Text lint: {}", config.text_lint.is_some());
+        println!("   - Voice check: {}", config.voice_check.is_some());
+        println!("   - Text fit: {}", config.text_fit.is_some());
+        println!("   - Deny warnings: {}", config.rules.deny_warnings);
+        println!();
+    }
 
-    let mut all_results = Vec::new();
-    let mut total_errors = 0;
-    let mut total_warnings = 0;
-
-    for path in &paths_to_validate {
-        if path.is_dir() {
-            // Validate directory
-            match scenario_validator::validate_directory(path, &config) {
-                Ok(results) => {
-                    for result in results {
-                        total_errors += result.errors.len();
-                        total_warnings += result.warnings.len();
-                        print_validation_result(&result);
-                        all_results.push(result);
-                    }
-                }
-                Err(e) => {
-                    eprintln!("❌ Failed to validate directory {}: {}", path.display(), e);
-                }
+    let (results, all_validated) = validate_paths(&paths_to_validate, &config);
+    let report = ValidationReport::new(results);
+
+    if watch {
+        print_report(&report, format, true)?;
+        if format == Format::Text {
+            println!("👀 Watching for changes (Ctrl+C to stop)...");
+            println!();
+        }
+        scenario_validator::watch(&paths_to_validate, &config, |results| {
+            if let Err(e) = print_report(&ValidationReport::new(results), format, true) {
+                eprintln!("❌ Failed to print results: {}", e);
             }
-        } else {
-            // Validate single file
-            match scenario_validator::validate_file(path, &config) {
-                Ok(result) => {
-                    total_errors += result.errors.len();
-                    total_warnings += result.warnings.len();
-                    print_validation_result(&result);
-                    all_results.push(result);
-                }
-                Err(e) => {
-                    eprintln!("❌ Failed to validate file {}: {}", path.display(), e);
-                }
+        })?;
+        return Ok(EXIT_OK);
+    }
+
+    print_report(&report, format, false)?;
+    if report.total_errors > 0 {
+        return Ok(EXIT_FAILED);
+    }
+    if !all_validated {
+        return Ok(EXIT_USAGE);
+    }
+
+    if format == Format::Text {
+        println!("✅ All scenario files validated successfully!");
+    }
+    Ok(EXIT_OK)
+}
+
+/// Validate files and directories in argument order, reporting failures to stderr
+///
+/// Consecutive file arguments are validated together in parallel. Returns
+/// the results and whether every path could be validated.
+fn validate_paths(paths: &[PathBuf], config: &ValidationConfig) -> (Vec<ValidationResult>, bool) {
+    let mut all_results = Vec::new();
+    let mut files = Vec::new();
+    let mut all_validated = true;
+
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+        all_validated &= validate_file_batch(&mut files, config, &mut all_results);
+        match scenario_validator::validate_directory(path, config) {
+            Ok(results) => all_results.extend(results),
+            Err(e) => {
+                eprintln!("❌ Failed to validate directory {}: {}", path.display(), e);
+                all_validated = false;
             }
         }
     }
+    all_validated &= validate_file_batch(&mut files, config, &mut all_results);
 
-    // Summary
-    println!("📊 Validation Summary:");
-    println!("   - Files processed: {}", all_results.len());
-    println!(
-        "   - Files passed: {}",
-        all_results.iter().filter(|r| r.success).count()
-    );
-    println!("   - Total errors: {}", total_errors);
-    println!("   - Total warnings: {}", total_warnings);
+    (all_results, all_validated)
+}
 
-    if total_errors > 0 {
-        std::process::exit(1);
+/// Validate and clear a batch of files, returning false if it failed
+fn validate_file_batch(
+    files: &mut Vec<PathBuf>,
+    config: &ValidationConfig,
+    all_results: &mut Vec<ValidationResult>,
+) -> bool {
+    if files.is_empty() {
+        return true;
     }
+    let validated = match scenario_validator::validate_files(files, config) {
+        Ok(results) => {
+            all_results.extend(results);
+            true
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to validate files: {}", e);
+            false
+        }
+    };
+    files.clear();
+    validated
+}
+
+/// Print a report; JSON reports are single lines in watch mode
+fn print_report(report: &ValidationReport, format: Format, watch: bool) -> Result<()> {
+    match format {
+        Format::Json if watch => println!("{}", report.to_json_line()?),
+        Format::Json => println!("{}", report.to_json()?),
+        Format::Text => {
+            for result in &report.files {
+                print_validation_result(result);
+            }
 
-    println!("✅ All scenario files validated successfully!");
+            println!("📊 Validation Summary:");
+            println!("   - Files processed: {}", report.files.len());
+            println!("   - Files passed: {}", report.files_passed);
+            println!("   - Total errors: {}", report.total_errors);
+            println!("   - Total warnings: {}", report.total_warnings);
+        }
+    }
     Ok(())
 }
 
@@ -109,15 +305,52 @@ fn print_help() {
     println!("OPTIONS:");
     println!("    -s, --strict        Enable strict validation mode");
     println!("        --no-assets     Skip asset file validation");
+    println!(
+        "        --lint          Lint dialogue text (uses ./{} if present)",
+        DEFAULT_CONFIG_FILE
+    );
+    println!("        --lint-config <FILE>  Lint dialogue text with the given config");
+    println!("        --voice-dir <DIR>     Report character lines without a voice file in DIR");
+    println!(
+        "        --fit           Report text overflowing choice buttons or the dialogue box (uses ./{} if present)",
+        text_fit::DEFAULT_CONFIG_FILE
+    );
+    println!("        --fit-config <FILE>   Check text fit with the given fonts and UI sizes");
+    println!(
+        "        --asset-root <DIR>    Directory voice and font paths are relative to (default: assets)"
+    );
+    println!("    -D, --deny-warnings Report warnings as errors");
+    println!(
+        "        --config <FILE>       Rule levels config (uses ./{} if present)",
+        DEFAULT_RULES_FILE
+    );
+    println!("    -w, --watch         Revalidate files as they change");
+    println!("        --format <FORMAT>     Output format: text or json (default: text)");
     println!("    -h, --help          Show this help message");
     println!();
     println!("EXAMPLES:");
     println!("    scenario-validator                          # Validate assets/scenarios/");
     println!("    scenario-validator chapter_01.toml          # Validate specific file");
     println!("    scenario-validator --strict scenarios/      # Strict validation of directory");
+    println!("    scenario-validator --lint scenarios/        # Also lint dialogue text");
+    println!("    scenario-validator --voice-dir voice        # Report missing voice files");
+    println!("    scenario-validator --fit scenarios/ja/      # Report text that overflows the UI");
+    println!("    scenario-validator --watch --format json    # JSON line per change, for editors");
+    println!("    scenario-validator --deny-warnings          # Fail CI on any warning");
+    println!();
+    println!("EXIT CODES:");
+    println!("    {}  All files passed", EXIT_OK);
+    println!(
+        "    {}  Validation errors (warnings too with --deny-warnings)",
+        EXIT_FAILED
+    );
+    println!(
+        "    {}  Invalid arguments or config, or files could not be validated",
+        EXIT_USAGE
+    );
 }
 
-fn print_validation_result(result: &narrative_tools::scenario_validator::ValidationResult) {
+fn print_validation_result(result: &ValidationResult) {
     if result.success {
         println!("✅ {}", result.file_path.display());
     } else {
@@ -0,0 +1,149 @@
+//! Script Statistics CLI
+//!
+//! Reports word/character counts per scenario and speaker, choice and branch
+//! counts, and estimated reading time per route.
+
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use narrative_tools::scenario_files::{find_scenario_files, load_scenario};
+use narrative_tools::script_stats::{ReadingSpeed, ScenarioStats, ScriptReport};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+/// Report output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human-readable summary
+    Text,
+    /// Flat CSV table
+    Csv,
+    /// Full JSON report
+    Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "script-stats",
+    about = "Report word counts, choices and reading time for scenario files"
+)]
+struct Args {
+    /// Scenario files or directories (default: assets/scenarios)
+    #[arg(value_name = "FILES_OR_DIRS")]
+    paths: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Write the report to a file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Reading speed for space-separated text (words per minute)
+    #[arg(long, default_value_t = ReadingSpeed::default().words_per_minute)]
+    wpm: f64,
+
+    /// Reading speed for CJK text (characters per minute)
+    #[arg(long, default_value_t = ReadingSpeed::default().cjk_chars_per_minute)]
+    cpm: f64,
+}
+
+fn main() -> Result<()> {
+    let mut args = Args::parse();
+    if args.paths.is_empty() {
+        args.paths.push(PathBuf::from("assets/scenarios"));
+    }
+    let speed = ReadingSpeed {
+        words_per_minute: args.wpm,
+        cjk_chars_per_minute: args.cpm,
+    };
+
+    let mut scenarios = Vec::new();
+    let mut failures = 0usize;
+    for path in find_scenario_files(&args.paths) {
+        match load_scenario(&path) {
+            Ok(scenario) => scenarios.push(ScenarioStats::from_scenario(path, &scenario, &speed)),
+            Err(e) => {
+                eprintln!("⚠️  Skipping {}: {:#}", path.display(), e);
+                failures += 1;
+            }
+        }
+    }
+    let report = ScriptReport::new(scenarios, &speed);
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    };
+    match args.format {
+        Format::Text => print_summary(&mut out, &report)?,
+        Format::Csv => report.write_csv(&mut out)?,
+        Format::Json => writeln!(out, "{}", report.to_json()?)?,
+    }
+    out.flush()?;
+
+    if let Some(path) = &args.output {
+        eprintln!("📄 Report written to {}", path.display());
+    }
+    if failures > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_summary(out: &mut impl Write, report: &ScriptReport) -> Result<()> {
+    for scenario in &report.scenarios {
+        writeln!(
+            out,
+            "📖 {} ({}) - {}",
+            scenario.title,
+            scenario.id,
+            scenario.path.display()
+        )?;
+        writeln!(
+            out,
+            "   {} scenes, {} lines, {} words, {} characters",
+            scenario.scenes, scenario.text.lines, scenario.text.words, scenario.text.characters
+        )?;
+        writeln!(
+            out,
+            "   {} choices ({} options), {} conditional branches, ~{:.1} min",
+            scenario.choices, scenario.choice_options, scenario.branches, scenario.reading_minutes
+        )?;
+        for (speaker, stats) in &scenario.speakers {
+            writeln!(
+                out,
+                "   - {}: {} lines, {} words, {} characters",
+                speaker, stats.lines, stats.words, stats.characters
+            )?;
+        }
+        for route in &scenario.routes {
+            writeln!(
+                out,
+                "   ➜ {}: ~{:.1} min",
+                route.name(),
+                route.reading_minutes
+            )?;
+        }
+        if scenario.routes_truncated {
+            writeln!(out, "   (route list truncated)")?;
+        }
+        writeln!(out)?;
+    }
+
+    writeln!(out, "📊 Total:")?;
+    writeln!(out, "   - Scenarios: {}", report.scenarios.len())?;
+    writeln!(out, "   - Lines: {}", report.total.lines)?;
+    writeln!(out, "   - Words: {}", report.total.words)?;
+    writeln!(out, "   - Characters: {}", report.total.characters)?;
+    writeln!(out, "   - Reading time: ~{:.1} min", report.reading_minutes)?;
+    for (speaker, stats) in &report.speakers {
+        writeln!(
+            out,
+            "   - {}: {} lines, {} words, {} characters",
+            speaker, stats.lines, stats.words, stats.characters
+        )?;
+    }
+    Ok(())
+}
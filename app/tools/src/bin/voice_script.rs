@@ -0,0 +1,137 @@
+//! Voice Script CLI
+//!
+//! Exports per-character voice recording scripts and checks recorded voice
+//! files against them.
+
+use anyhow::Result;
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
+use narrative_tools::scenario_files::{find_scenario_files, load_scenario};
+use narrative_tools::voice_script::{
+    self, ExportFormat, VoiceLine, VoiceScriptConfig, check_voice_files, export_scripts,
+};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "voice-script",
+    about = "Export voice recording scripts and check voice files"
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Export one recording script per character
+    Export {
+        #[command(flatten)]
+        scenarios: ScenarioArgs,
+
+        /// Output directory
+        #[arg(short, long, default_value = "voice_script")]
+        output: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+    },
+    /// Report missing and orphaned voice files
+    Check {
+        #[command(flatten)]
+        scenarios: ScenarioArgs,
+
+        /// Directory voice file paths are relative to
+        #[arg(long, default_value = "assets")]
+        asset_root: PathBuf,
+    },
+}
+
+#[derive(Debug, ClapArgs)]
+struct ScenarioArgs {
+    /// Scenario files or directories (default: assets/scenarios)
+    #[arg(value_name = "FILES_OR_DIRS")]
+    paths: Vec<PathBuf>,
+
+    /// Treat narrator lines as voiced
+    #[arg(long)]
+    include_narrator: bool,
+
+    /// Extension of generated voice file names
+    #[arg(long, default_value = "ogg")]
+    extension: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Comma-separated values
+    Csv,
+    /// OpenDocument spreadsheet
+    Ods,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Command::Export {
+            scenarios,
+            output,
+            format,
+        } => {
+            let lines = collect_lines(scenarios)?;
+            let format = match format {
+                Format::Csv => ExportFormat::Csv,
+                Format::Ods => ExportFormat::Ods,
+            };
+            for path in export_scripts(&lines, &output, format)? {
+                println!("📄 {}", path.display());
+            }
+            println!("✅ Exported {} voiced lines", lines.len());
+        }
+        Command::Check {
+            scenarios,
+            asset_root,
+        } => {
+            let lines = collect_lines(scenarios)?;
+            let report = check_voice_files(&lines, &asset_root);
+
+            for line in &report.missing {
+                println!(
+                    "❌ Missing {} ({} {}: {})",
+                    line.voice_file, line.line_id, line.speaker, line.text
+                );
+            }
+            for file in &report.orphaned {
+                println!("⚠️  Orphaned {}", file);
+            }
+            println!();
+            println!("📊 Voice Check Summary:");
+            println!("   - Voiced lines: {}", lines.len());
+            println!("   - Missing files: {}", report.missing.len());
+            println!("   - Orphaned files: {}", report.orphaned.len());
+
+            if !report.missing.is_empty() {
+                std::process::exit(1);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn collect_lines(args: ScenarioArgs) -> Result<Vec<VoiceLine>> {
+    let mut paths = args.paths;
+    if paths.is_empty() {
+        paths.push(PathBuf::from("assets/scenarios"));
+    }
+    let config = VoiceScriptConfig {
+        extension: args.extension,
+        include_narrator: args.include_narrator,
+    };
+
+    let mut lines = Vec::new();
+    for path in find_scenario_files(&paths) {
+        let scenario = load_scenario(&path)?;
+        lines.extend(voice_script::collect_voice_lines(&scenario, &config));
+    }
+    Ok(lines)
+}
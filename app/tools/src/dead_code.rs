@@ -0,0 +1,834 @@
+//! Dead code analysis over the scene graph
+//!
+//! Builds the graph of jumps, choices, calls and scenario jumps across all
+//! analyzed scenarios and reports scenes no entry point leads to, flags that
+//! are set but never read or read but never set, and variables that are set
+//! but never read. Every scenario's start scene is an entry point. Flags and
+//! variables only count where they are used by a reachable scene, or by the
+//! achievement and HUD manifests.
+//!
+//! Intentional cases are suppressed with a comment in the scenario file:
+//!
+//! ```toml
+//! # dead-code: allow(unreachable_scene) debug_room
+//! # dead-code: allow(unread_flag, unset_flag)
+//! ```
+//!
+//! A suppression applies to the findings of the listed kinds that involve
+//! the file it is written in, limited to the listed names if there are any.
+//! Scenes allowed to be unreachable count as entry points, so what they use
+//! and lead to is not reported either. `Script` commands are scanned for
+//! `get_flag`, `set_flag`, `get_var`, `has_var`, `set_var` and `jump` calls
+//! with a string literal name.
+
+use crate::scenario_files::{ordered_scenes, visit_commands};
+use anyhow::{Context, Result, bail};
+use narrative_core::expression::Expression;
+use narrative_core::{
+    AchievementCondition, AchievementManifest, Condition, HudManifest, HudSource, Scenario,
+    ScenarioCommand, Scene, VariableOperation,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+const ACHIEVEMENTS_MANIFEST: &str = "manifests/achievements.ron";
+const HUD_MANIFEST: &str = "manifests/hud.ron";
+
+/// Comment prefix of a suppression, after the `#`
+const SUPPRESSION_PREFIX: &str = "dead-code:";
+
+/// Kind of dead code finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    /// Scene no entry point leads to
+    UnreachableScene,
+    /// Flag that is set but never read
+    UnreadFlag,
+    /// Flag that is read but never set
+    UnsetFlag,
+    /// Variable that is set but never read
+    UnusedVariable,
+}
+
+impl FindingKind {
+    /// All kinds, in report order
+    pub const ALL: [FindingKind; 4] = [
+        FindingKind::UnreachableScene,
+        FindingKind::UnreadFlag,
+        FindingKind::UnsetFlag,
+        FindingKind::UnusedVariable,
+    ];
+
+    /// Name used in suppression comments and reports
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::UnreachableScene => "unreachable_scene",
+            Self::UnreadFlag => "unread_flag",
+            Self::UnsetFlag => "unset_flag",
+            Self::UnusedVariable => "unused_variable",
+        }
+    }
+
+    /// Kind with the given name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A dead scene, flag or variable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    pub kind: FindingKind,
+    /// Scene, flag or variable name
+    pub name: String,
+    /// Scenario of an unreachable scene
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<String>,
+    /// Files defining or using the name
+    pub files: Vec<PathBuf>,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] ", self.kind)?;
+        match self.kind {
+            FindingKind::UnreachableScene => write!(
+                f,
+                "Scene '{}' of scenario '{}' is unreachable",
+                self.name,
+                self.scenario.as_deref().unwrap_or_default()
+            ),
+            FindingKind::UnreadFlag => write!(f, "Flag '{}' is set but never read", self.name),
+            FindingKind::UnsetFlag => write!(f, "Flag '{}' is read but never set", self.name),
+            FindingKind::UnusedVariable => {
+                write!(f, "Variable '{}' is set but never read", self.name)
+            }
+        }
+    }
+}
+
+/// A `# dead-code: allow(..)` comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    /// File the comment is in
+    pub file: PathBuf,
+    /// 1-based line of the comment
+    pub line: usize,
+    pub kinds: Vec<FindingKind>,
+    /// Names the suppression is limited to (all names if empty)
+    pub names: Vec<String>,
+}
+
+impl Suppression {
+    /// Whether the suppression covers a finding
+    pub fn matches(&self, finding: &Finding) -> bool {
+        self.kinds.contains(&finding.kind)
+            && (self.names.is_empty() || self.names.contains(&finding.name))
+            && finding.files.contains(&self.file)
+    }
+}
+
+/// Parse the suppression comments of a scenario file
+///
+/// # Errors
+/// Returns an error for a malformed comment or an unknown finding kind.
+pub fn parse_suppressions(file: &Path, source: &str) -> Result<Vec<Suppression>> {
+    let mut suppressions = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let Some(comment) = line.trim_start().strip_prefix('#') else {
+            continue;
+        };
+        let Some(directive) = comment.trim_start().strip_prefix(SUPPRESSION_PREFIX) else {
+            continue;
+        };
+        let line = index + 1;
+        let Some((kinds, names)) = directive
+            .trim_start()
+            .strip_prefix("allow(")
+            .and_then(|rest| rest.split_once(')'))
+        else {
+            bail!(
+                "{}:{}: expected '# {} allow(<kind>, ..) [names]'",
+                file.display(),
+                line,
+                SUPPRESSION_PREFIX
+            );
+        };
+        let kinds = kinds
+            .split(',')
+            .map(|kind| {
+                FindingKind::from_name(kind.trim()).with_context(|| {
+                    format!(
+                        "{}:{}: unknown dead code kind '{}'",
+                        file.display(),
+                        line,
+                        kind.trim()
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let names = names
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        suppressions.push(Suppression {
+            file: file.to_path_buf(),
+            line,
+            kinds,
+            names,
+        });
+    }
+    Ok(suppressions)
+}
+
+/// Scene in the graph: (scenario ID, scene ID)
+type SceneKey = (String, String);
+
+/// Where a scene can continue to
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Target {
+    /// Scene of the same scenario
+    Scene(String),
+    /// Scene of another scenario, or its start scene
+    Scenario(String, Option<String>),
+}
+
+/// Flags, variables and targets a scene uses
+#[derive(Debug, Clone, Default)]
+struct SceneUsage {
+    file: PathBuf,
+    targets: Vec<Target>,
+    flags_set: BTreeSet<String>,
+    flags_read: BTreeSet<String>,
+    variables_set: BTreeSet<String>,
+    variables_read: BTreeSet<String>,
+}
+
+impl SceneUsage {
+    fn new(file: &Path, scene: &Scene) -> Self {
+        let mut usage = Self {
+            file: file.to_path_buf(),
+            ..Default::default()
+        };
+        visit_commands(&scene.commands, &mut |command| usage.add_command(command));
+        usage
+    }
+
+    fn add_command(&mut self, command: &ScenarioCommand) {
+        match command {
+            ScenarioCommand::Dialogue { dialogue } => {
+                for text in std::iter::once(&dialogue.text).chain(dialogue.translations.values()) {
+                    self.variables_read.extend(placeholders(text));
+                }
+            }
+            ScenarioCommand::ShowChoice { choice } => {
+                for option in &choice.options {
+                    self.targets.push(Target::Scene(option.next_scene.clone()));
+                    self.flags_set.extend(option.flags_to_set.iter().cloned());
+                    for condition in option
+                        .conditions
+                        .iter()
+                        .chain(&option.condition)
+                        .chain(&option.disabled_condition)
+                    {
+                        self.add_condition(condition);
+                    }
+                }
+            }
+            ScenarioCommand::JumpToScene { scene_id } => {
+                self.targets.push(Target::Scene(scene_id.clone()));
+            }
+            ScenarioCommand::JumpToScenario { id, scene } => {
+                self.targets
+                    .push(Target::Scenario(id.clone(), scene.clone()));
+            }
+            ScenarioCommand::SetFlag { flag_name, .. } => {
+                self.flags_set.insert(flag_name.clone());
+            }
+            ScenarioCommand::SetVariable { variable_name, .. } => {
+                self.variables_set.insert(variable_name.clone());
+            }
+            ScenarioCommand::ModifyVariable {
+                variable_name,
+                operation,
+            } => {
+                self.variables_set.insert(variable_name.clone());
+                if let VariableOperation::Expression { expression } = operation {
+                    self.add_reads(expression);
+                }
+            }
+            ScenarioCommand::InputName { variable, .. } => {
+                self.variables_set.insert(variable.clone());
+            }
+            ScenarioCommand::Call {
+                scene_id,
+                return_scene,
+                args,
+                result,
+            } => {
+                self.targets.push(Target::Scene(scene_id.clone()));
+                self.targets.push(Target::Scene(return_scene.clone()));
+                for (name, expression) in args {
+                    self.variables_set.insert(name.clone());
+                    self.add_reads(expression);
+                }
+                self.variables_set.extend(result.clone());
+            }
+            ScenarioCommand::Return { value: Some(value) } => self.add_reads(value),
+            ScenarioCommand::If { condition, .. } => self.add_condition(condition),
+            ScenarioCommand::Script { source } => self.add_script(source),
+            _ => {}
+        }
+    }
+
+    fn add_condition(&mut self, condition: &Condition) {
+        match condition {
+            Condition::Flag { flag_name, .. } => {
+                self.flags_read.insert(flag_name.clone());
+            }
+            Condition::Variable { variable_name, .. } => {
+                self.variables_read.insert(variable_name.clone());
+            }
+            Condition::And { conditions } | Condition::Or { conditions } => {
+                for condition in conditions {
+                    self.add_condition(condition);
+                }
+            }
+            Condition::Not { condition } => self.add_condition(condition),
+            Condition::Expression { expression } => self.add_reads(expression),
+            Condition::True | Condition::False => {}
+        }
+    }
+
+    fn add_reads(&mut self, expression: &Expression) {
+        self.flags_read
+            .extend(expression.flags().into_iter().map(str::to_string));
+        self.variables_read
+            .extend(expression.variables().into_iter().map(str::to_string));
+    }
+
+    fn add_script(&mut self, source: &str) {
+        for (function, name) in script_calls(source) {
+            let names = match function {
+                "get_flag" => &mut self.flags_read,
+                "set_flag" => &mut self.flags_set,
+                "get_var" | "has_var" => &mut self.variables_read,
+                "set_var" => &mut self.variables_set,
+                _ => {
+                    self.targets.push(Target::Scene(name));
+                    continue;
+                }
+            };
+            names.insert(name);
+        }
+    }
+}
+
+/// Variable names of the `{$name}` placeholders in dialogue text
+fn placeholders(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find("{$") {
+        rest = &rest[open + 2..];
+        if let Some(close) = rest.find('}') {
+            names.push(rest[..close].to_string());
+            rest = &rest[close + 1..];
+        }
+    }
+    names
+}
+
+/// Script API calls with a string literal as their first argument
+fn script_calls(source: &str) -> Vec<(&'static str, String)> {
+    const FUNCTIONS: [&str; 6] = [
+        "get_flag", "set_flag", "get_var", "has_var", "set_var", "jump",
+    ];
+
+    let mut calls = Vec::new();
+    for function in FUNCTIONS {
+        for (start, _) in source.match_indices(function) {
+            // Skip longer identifiers ending in the function name
+            let preceded_by_ident = source[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if preceded_by_ident {
+                continue;
+            }
+            let Some(literal) = source[start + function.len()..]
+                .trim_start()
+                .strip_prefix('(')
+                .and_then(|rest| rest.trim_start().strip_prefix('"'))
+            else {
+                continue;
+            };
+            if let Some(end) = literal.find('"') {
+                calls.push((function, literal[..end].to_string()));
+            }
+        }
+    }
+    calls
+}
+
+/// Dead code analysis of a project's scenarios and manifests
+#[derive(Debug, Default)]
+pub struct DeadCodeAnalysis {
+    /// Scene usage by scenario and scene ID
+    scenes: BTreeMap<SceneKey, SceneUsage>,
+    /// Start scene by scenario ID
+    start_scenes: HashMap<String, String>,
+    /// Flags read by manifests, with the manifest path
+    manifest_flags: BTreeMap<String, BTreeSet<PathBuf>>,
+    /// Variables read by manifests, with the manifest path
+    manifest_variables: BTreeMap<String, BTreeSet<PathBuf>>,
+    suppressions: Vec<Suppression>,
+}
+
+impl DeadCodeAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a scenario and the suppression comments of its source text
+    ///
+    /// # Errors
+    /// Returns an error for a malformed suppression comment.
+    pub fn add_scenario(&mut self, path: &Path, source: &str, scenario: &Scenario) -> Result<()> {
+        self.suppressions.extend(parse_suppressions(path, source)?);
+        let id = &scenario.metadata.id;
+        self.start_scenes
+            .insert(id.clone(), scenario.start_scene.clone());
+        for scene in ordered_scenes(scenario) {
+            self.scenes
+                .insert((id.clone(), scene.id.clone()), SceneUsage::new(path, scene));
+        }
+        Ok(())
+    }
+
+    /// Add the flags and variables read by the manifests under an asset root
+    ///
+    /// Missing manifests are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if a manifest exists but cannot be loaded.
+    pub fn collect_manifests(&mut self, asset_root: &Path) -> Result<()> {
+        let path = asset_root.join(ACHIEVEMENTS_MANIFEST);
+        if path.is_file() {
+            let manifest = AchievementManifest::load_from_file(&path)
+                .with_context(|| format!("Failed to load {}", path.display()))?;
+            for condition in manifest.achievements.iter().flat_map(|a| &a.unlock) {
+                if let AchievementCondition::Flag(flag) = condition {
+                    add_use(&mut self.manifest_flags, flag, &path);
+                }
+            }
+        }
+
+        let path = asset_root.join(HUD_MANIFEST);
+        if path.is_file() {
+            let manifest = HudManifest::load_from_file(&path)
+                .with_context(|| format!("Failed to load {}", path.display()))?;
+            for widget in &manifest.widgets {
+                match &widget.source {
+                    HudSource::Flag(flag) => add_use(&mut self.manifest_flags, flag, &path),
+                    HudSource::Variable(variable) => {
+                        add_use(&mut self.manifest_variables, variable, &path)
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Scenes allowed to be unreachable by a suppression
+    fn allowed_scenes(&self) -> Vec<&SceneKey> {
+        self.scenes
+            .iter()
+            .filter(|(key, usage)| {
+                self.start_scenes.get(&key.0) != Some(&key.1)
+                    && self
+                        .suppressions
+                        .iter()
+                        .any(|suppression| suppression.matches(&unreachable_scene(key, usage)))
+            })
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Scenes reachable from the entry points
+    ///
+    /// Entry points are the start scene of every scenario and the scenes
+    /// allowed to be unreachable.
+    fn reachable_scenes(&self) -> HashSet<&SceneKey> {
+        let mut queue: VecDeque<&SceneKey> = self
+            .scenes
+            .keys()
+            .filter(|key| self.start_scenes.get(&key.0) == Some(&key.1))
+            .chain(self.allowed_scenes())
+            .collect();
+        let mut reachable: HashSet<&SceneKey> = queue.iter().copied().collect();
+
+        while let Some(key) = queue.pop_front() {
+            for target in &self.scenes[key].targets {
+                let target_key = match target {
+                    Target::Scene(scene) => (key.0.clone(), scene.clone()),
+                    Target::Scenario(scenario, scene) => {
+                        let Some(scene) = scene.as_ref().or(self.start_scenes.get(scenario)) else {
+                            continue;
+                        };
+                        (scenario.clone(), scene.clone())
+                    }
+                };
+                // Targets that do not exist are the validator's concern
+                if let Some((key, _)) = self.scenes.get_key_value(&target_key)
+                    && reachable.insert(key)
+                {
+                    queue.push_back(key);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Report the dead scenes, flags and variables
+    pub fn report(&self) -> DeadCodeReport {
+        let reachable = self.reachable_scenes();
+        let mut findings: Vec<Finding> = self
+            .scenes
+            .iter()
+            .filter(|(key, _)| !reachable.contains(key))
+            .map(|(key, usage)| unreachable_scene(key, usage))
+            .collect();
+
+        // Names used by reachable scenes, and every file using them
+        let mut flags_set = BTreeSet::new();
+        let mut flags_read: BTreeSet<&str> =
+            self.manifest_flags.keys().map(String::as_str).collect();
+        let mut variables_set = BTreeSet::new();
+        let mut variables_read: BTreeSet<&str> =
+            self.manifest_variables.keys().map(String::as_str).collect();
+        let mut flag_files = self.manifest_flags.clone();
+        let mut variable_files = self.manifest_variables.clone();
+        for (key, usage) in &self.scenes {
+            let live = reachable.contains(key);
+            for flag in usage.flags_set.iter().chain(&usage.flags_read) {
+                add_use(&mut flag_files, flag, &usage.file);
+            }
+            for variable in usage.variables_set.iter().chain(&usage.variables_read) {
+                add_use(&mut variable_files, variable, &usage.file);
+            }
+            if live {
+                flags_set.extend(usage.flags_set.iter().map(String::as_str));
+                flags_read.extend(usage.flags_read.iter().map(String::as_str));
+                variables_set.extend(usage.variables_set.iter().map(String::as_str));
+                variables_read.extend(usage.variables_read.iter().map(String::as_str));
+            }
+        }
+
+        let finding = |kind, name: &str, files: &BTreeMap<String, BTreeSet<PathBuf>>| Finding {
+            kind,
+            name: name.to_string(),
+            scenario: None,
+            files: files
+                .get(name)
+                .map(|files| files.iter().cloned().collect())
+                .unwrap_or_default(),
+        };
+        findings.extend(
+            flags_set
+                .difference(&flags_read)
+                .map(|flag| finding(FindingKind::UnreadFlag, flag, &flag_files)),
+        );
+        findings.extend(
+            flags_read
+                .difference(&flags_set)
+                .map(|flag| finding(FindingKind::UnsetFlag, flag, &flag_files)),
+        );
+        findings.extend(
+            variables_set
+                .difference(&variables_read)
+                .map(|variable| finding(FindingKind::UnusedVariable, variable, &variable_files)),
+        );
+
+        // Allowed scenes are entry points rather than findings
+        let total = findings.len() + self.allowed_scenes().len();
+        findings.retain(|finding| {
+            !self
+                .suppressions
+                .iter()
+                .any(|suppression| suppression.matches(finding))
+        });
+        findings
+            .sort_by(|a, b| (a.kind, &a.scenario, &a.name).cmp(&(b.kind, &b.scenario, &b.name)));
+        DeadCodeReport {
+            scenes: self.scenes.len(),
+            reachable_scenes: reachable.len(),
+            suppressed: total - findings.len(),
+            findings,
+        }
+    }
+}
+
+fn unreachable_scene(key: &SceneKey, usage: &SceneUsage) -> Finding {
+    Finding {
+        kind: FindingKind::UnreachableScene,
+        name: key.1.clone(),
+        scenario: Some(key.0.clone()),
+        files: vec![usage.file.clone()],
+    }
+}
+
+fn add_use(uses: &mut BTreeMap<String, BTreeSet<PathBuf>>, name: &str, path: &Path) {
+    uses.entry(name.to_string())
+        .or_default()
+        .insert(path.to_path_buf());
+}
+
+/// Result of a dead code analysis
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadCodeReport {
+    /// Findings not covered by a suppression, by kind and name
+    pub findings: Vec<Finding>,
+    /// Number of findings covered by a suppression
+    pub suppressed: usize,
+    pub scenes: usize,
+    pub reachable_scenes: usize,
+}
+
+impl DeadCodeReport {
+    /// Serialize the report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{Choice, ChoiceOption, Dialogue, ScenarioMetadata};
+
+    fn scenario(id: &str, scenes: Vec<(&str, Vec<ScenarioCommand>)>) -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new(id, id), scenes[0].0);
+        for (scene_id, commands) in scenes {
+            let mut scene = Scene::new(scene_id, scene_id);
+            for command in commands {
+                scene.add_command(command);
+            }
+            scenario.add_scene(scene_id, scene);
+        }
+        scenario
+    }
+
+    fn jump(scene_id: &str) -> ScenarioCommand {
+        ScenarioCommand::JumpToScene {
+            scene_id: scene_id.to_string(),
+        }
+    }
+
+    fn set_flag(flag_name: &str) -> ScenarioCommand {
+        ScenarioCommand::SetFlag {
+            flag_name: flag_name.to_string(),
+            value: true,
+        }
+    }
+
+    fn names(report: &DeadCodeReport, kind: FindingKind) -> Vec<&str> {
+        report
+            .findings
+            .iter()
+            .filter(|finding| finding.kind == kind)
+            .map(|finding| finding.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_reports_dead_scenes_flags_and_variables() {
+        let ch1 = scenario(
+            "ch1",
+            vec![
+                (
+                    "start",
+                    vec![
+                        set_flag("met_mia"),
+                        set_flag("debug_seen"),
+                        ScenarioCommand::SetVariable {
+                            variable_name: "gold".to_string(),
+                            value: narrative_core::VariableValue::Int(10),
+                        },
+                        ScenarioCommand::SetVariable {
+                            variable_name: "unused".to_string(),
+                            value: narrative_core::VariableValue::Int(0),
+                        },
+                        ScenarioCommand::ShowChoice {
+                            choice: Choice::new(vec![
+                                ChoiceOption::new("Shop", "shop").with_flag("chose_shop"),
+                                ChoiceOption::new("Leave", "outro").with_condition(
+                                    Condition::Expression {
+                                        expression: Expression::parse(
+                                            "flags.met_mia || flags.never_set",
+                                        )
+                                        .unwrap(),
+                                    },
+                                ),
+                            ]),
+                        },
+                    ],
+                ),
+                (
+                    "shop",
+                    vec![ScenarioCommand::Script {
+                        source: r#"if game.get_var("gold") > 5 { game.jump("outro"); }"#
+                            .to_string(),
+                    }],
+                ),
+                (
+                    "outro",
+                    vec![ScenarioCommand::JumpToScenario {
+                        id: "ch2".to_string(),
+                        scene: Some("late".to_string()),
+                    }],
+                ),
+                // Only an unreachable scene reads this flag
+                (
+                    "secret",
+                    vec![ScenarioCommand::If {
+                        condition: Condition::flag("debug_seen", true),
+                        then_commands: vec![jump("start")],
+                        else_commands: vec![],
+                    }],
+                ),
+            ],
+        );
+        let ch2 = scenario(
+            "ch2",
+            vec![
+                ("opening", vec![]),
+                (
+                    "late",
+                    vec![ScenarioCommand::Dialogue {
+                        dialogue: Dialogue::new(
+                            narrative_core::Speaker::Narrator,
+                            "Chose the shop? {$chose}",
+                        ),
+                    }],
+                ),
+                ("orphan", vec![]),
+            ],
+        );
+
+        let mut analysis = DeadCodeAnalysis::new();
+        analysis
+            .add_scenario(Path::new("ch1.toml"), "", &ch1)
+            .unwrap();
+        analysis
+            .add_scenario(Path::new("ch2.toml"), "", &ch2)
+            .unwrap();
+        let report = analysis.report();
+
+        assert_eq!(
+            names(&report, FindingKind::UnreachableScene),
+            ["secret", "orphan"]
+        );
+        assert_eq!(
+            names(&report, FindingKind::UnreadFlag),
+            ["chose_shop", "debug_seen"]
+        );
+        assert_eq!(names(&report, FindingKind::UnsetFlag), ["never_set"]);
+        assert_eq!(names(&report, FindingKind::UnusedVariable), ["unused"]);
+        assert_eq!((report.scenes, report.reachable_scenes), (7, 5));
+        assert_eq!(
+            report.findings[0].to_string(),
+            "[unreachable_scene] Scene 'secret' of scenario 'ch1' is unreachable"
+        );
+        assert_eq!(report.findings[0].files, [PathBuf::from("ch1.toml")]);
+    }
+
+    #[test]
+    fn test_suppressions_and_manifests() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifests = dir.path().join("manifests");
+        std::fs::create_dir_all(&manifests).unwrap();
+        std::fs::write(
+            manifests.join("achievements.ron"),
+            r#"(achievements: [(id: "friend", title: "Friend", unlock: [Flag("met_mia")])])"#,
+        )
+        .unwrap();
+
+        let ch1 = scenario(
+            "ch1",
+            vec![
+                (
+                    "start",
+                    vec![set_flag("met_mia"), set_flag("tutorial_done")],
+                ),
+                ("debug_room", vec![set_flag("debug"), jump("debug_exit")]),
+                ("debug_exit", vec![]),
+                ("cut", vec![]),
+            ],
+        );
+        let source = "\
+# dead-code: allow(unreachable_scene) debug_room
+# dead-code: allow(unread_flag)
+";
+        let mut analysis = DeadCodeAnalysis::new();
+        analysis.collect_manifests(dir.path()).unwrap();
+        analysis
+            .add_scenario(Path::new("ch1.toml"), source, &ch1)
+            .unwrap();
+        let report = analysis.report();
+
+        // debug_room is an entry point, so debug_exit is reachable
+        assert_eq!(names(&report, FindingKind::UnreachableScene), ["cut"]);
+        assert!(names(&report, FindingKind::UnreadFlag).is_empty());
+        assert_eq!(report.suppressed, 3);
+    }
+
+    #[test]
+    fn test_parse_suppressions() {
+        let path = Path::new("ch1.toml");
+        let suppressions = parse_suppressions(
+            path,
+            "[chapter]\n  #dead-code: allow(unread_flag, unset_flag) a, b\n",
+        )
+        .unwrap();
+        assert_eq!(
+            suppressions,
+            [Suppression {
+                file: path.to_path_buf(),
+                line: 2,
+                kinds: vec![FindingKind::UnreadFlag, FindingKind::UnsetFlag],
+                names: vec!["a".to_string(), "b".to_string()],
+            }]
+        );
+
+        let error = parse_suppressions(path, "# dead-code: allow(dead_flag)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "ch1.toml:1: unknown dead code kind 'dead_flag'"
+        );
+        assert!(parse_suppressions(path, "# dead-code: ignore everything").is_err());
+    }
+
+    #[test]
+    fn test_script_calls() {
+        let calls = script_calls(
+            r#"game.set_flag( "won", true); my_jump("x"); game.jump(name); game.get_var("hp")"#,
+        );
+        assert_eq!(
+            calls,
+            [
+                ("set_flag", "won".to_string()),
+                ("get_var", "hp".to_string())
+            ]
+        );
+    }
+}
@@ -5,7 +5,17 @@
 //!
 //! ## Modules
 //!
+//! - `asset_report` - Unused and missing asset report with orphan cleanup
+//! - `dead_code` - Unreachable scenes and unused flags and variables
+//! - `ods` - Minimal OpenDocument spreadsheet writer
+//! - `refactor` - Project-wide renames of scenes, flags, variables and characters
+//! - `scenario_files` - Scenario discovery and traversal shared by report tools
 //! - `scenario_validator` - Scenario file validation
+//! - `script_stats` - Word counts, choices and reading time reports
+//! - `text_fit` - Choice, name and dialogue text overflow checks against UI bounds
+//! - `text_lint` - Dialogue text style and spelling checks
+//! - `validation_rules` - Validator rules, severity levels and project config
+//! - `voice_script` - Voice recording scripts and voice file checks
 //! - `asset_optimizer` - Asset optimization utilities
 //! - `perf_analyzer` - Performance analysis tools
 //!
@@ -24,9 +34,22 @@
 //! # }
 //! ```
 
+pub mod asset_report;
+pub mod dead_code;
+pub mod ods;
+pub mod refactor;
+pub mod scenario_files;
 pub mod scenario_validator;
+pub mod script_stats;
+pub mod text_fit;
+pub mod text_lint;
+pub mod validation_rules;
+pub mod voice_script;
 
 // Re-export commonly used types
 pub use scenario_validator::{
     ValidationConfig, ValidationResult, validate_directory, validate_file,
 };
+pub use text_fit::{TextFitConfig, TextOverflow};
+pub use text_lint::{LintIssue, LintRule, TextLintConfig, TextLinter};
+pub use validation_rules::{RuleConfig, RuleLevel, ValidationRule};
@@ -0,0 +1,136 @@
+//! Minimal OpenDocument spreadsheet (.ods) writer
+//!
+//! Writes string-only sheets, which is all the report tools need. The
+//! output opens in LibreOffice Calc, Excel and Google Sheets.
+
+use anyhow::Result;
+use std::io::{Seek, Write};
+use zip::CompressionMethod;
+use zip::write::{SimpleFileOptions, ZipWriter};
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">
+ <manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+ <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>
+"#;
+
+/// A named sheet of string cells
+#[derive(Debug, Clone, Default)]
+pub struct Sheet {
+    /// Sheet (tab) name
+    pub name: String,
+    /// Rows of cells; the first row is usually the header
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Sheet {
+    /// Create an empty sheet
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Append a row
+    pub fn push_row<S: Into<String>>(&mut self, row: impl IntoIterator<Item = S>) {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+}
+
+/// Write sheets as an .ods document
+pub fn write_ods(writer: impl Write + Seek, sheets: &[Sheet]) -> Result<()> {
+    let mut zip = ZipWriter::new(writer);
+
+    // The mimetype entry must come first and be stored uncompressed
+    zip.start_file(
+        "mimetype",
+        SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+    )?;
+    zip.write_all(MIMETYPE.as_bytes())?;
+
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file("META-INF/manifest.xml", deflated)?;
+    zip.write_all(MANIFEST.as_bytes())?;
+
+    zip.start_file("content.xml", deflated)?;
+    zip.write_all(content_xml(sheets).as_bytes())?;
+
+    zip.finish()?;
+    Ok(())
+}
+
+fn content_xml(sheets: &[Sheet]) -> String {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" office:version="1.2"><office:body><office:spreadsheet>"#,
+    );
+    for sheet in sheets {
+        xml.push_str(&format!(
+            r#"<table:table table:name="{}">"#,
+            escape(&sheet.name)
+        ));
+        for row in &sheet.rows {
+            xml.push_str("<table:table-row>");
+            for cell in row {
+                xml.push_str(r#"<table:table-cell office:value-type="string">"#);
+                for line in cell.split('\n') {
+                    xml.push_str(&format!("<text:p>{}</text:p>", escape(line)));
+                }
+                xml.push_str("</table:table-cell>");
+            }
+            xml.push_str("</table:table-row>");
+        }
+        xml.push_str("</table:table>");
+    }
+    xml.push_str("</office:spreadsheet></office:body></office:document-content>\n");
+    xml
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn test_write_ods() {
+        let mut sheet = Sheet::new("alice");
+        sheet.push_row(["ID", "Text"]);
+        sheet.push_row(["ch1_001", "Tom & <Jerry>\nsecond"]);
+
+        let mut buffer = Cursor::new(Vec::new());
+        write_ods(&mut buffer, &[sheet]).unwrap();
+
+        let mut archive = zip::ZipArchive::new(buffer).unwrap();
+        assert_eq!(archive.by_index(0).unwrap().name(), "mimetype");
+
+        let mut content = String::new();
+        archive
+            .by_name("content.xml")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert!(content.contains(r#"table:name="alice""#));
+        assert!(
+            content.contains("<text:p>Tom &amp; &lt;Jerry&gt;</text:p><text:p>second</text:p>")
+        );
+    }
+}
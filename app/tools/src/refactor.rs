@@ -0,0 +1,540 @@
+//! Project-wide renames of scenes, flags, variables and characters
+//!
+//! Scenario files are edited through [`ScenarioDocument`], so comments and
+//! formatting outside the renamed values are kept. Under an asset root, the
+//! RON manifests are edited as text: `Scene(..)` and `Flag(..)` achievement
+//! conditions, `Flag(..)` and `Variable(..)` HUD sources, and the `id` of
+//! the character definitions listed in `manifests/characters.ron`.
+//!
+//! A [`RenamePlan`] holds the edited files until it is applied, so the
+//! changes can be reviewed as a diff first.
+
+use anyhow::{Context, Result, bail};
+use narrative_core::{CharacterManifest, ScenarioDocument};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+const ACHIEVEMENTS_MANIFEST: &str = "manifests/achievements.ron";
+const HUD_MANIFEST: &str = "manifests/hud.ron";
+const CHARACTERS_MANIFEST: &str = "manifests/characters.ron";
+
+/// Lines of unchanged text shown around each change in a diff
+const DIFF_CONTEXT: usize = 3;
+
+/// What a rename applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameKind {
+    Scene,
+    Flag,
+    Variable,
+    Character,
+}
+
+impl RenameKind {
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Scene => "scene",
+            Self::Flag => "flag",
+            Self::Variable => "variable",
+            Self::Character => "character",
+        }
+    }
+}
+
+impl fmt::Display for RenameKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A file changed by a rename
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEdit {
+    pub path: PathBuf,
+    pub original: String,
+    pub edited: String,
+    /// Number of values renamed in the file
+    pub renamed: usize,
+}
+
+impl FileEdit {
+    /// Unified diff of the edit
+    pub fn diff(&self) -> String {
+        unified_diff(
+            &self.path.display().to_string(),
+            &self.original,
+            &self.edited,
+        )
+    }
+}
+
+/// The edits of a rename across a project, not yet written
+#[derive(Debug, Clone)]
+pub struct RenamePlan {
+    pub kind: RenameKind,
+    pub old: String,
+    pub new: String,
+    /// Changed files, scenarios first, in path order
+    pub edits: Vec<FileEdit>,
+}
+
+impl RenamePlan {
+    /// Plan renaming `old` to `new` in the scenario files under `paths` and,
+    /// given an asset root, in its manifests
+    ///
+    /// # Errors
+    /// Returns an error if the new name is not a valid identifier, a file
+    /// cannot be read or parsed, or for scenes, if no scenario defines the
+    /// old scene or one already defines the new one.
+    pub fn new(
+        kind: RenameKind,
+        old: &str,
+        new: &str,
+        paths: &[PathBuf],
+        asset_root: Option<&Path>,
+    ) -> Result<Self> {
+        if !is_valid_name(new) {
+            bail!("'{}' is not a valid {} name", new, kind);
+        }
+        if old == new {
+            bail!("The old and new {} names are the same", kind);
+        }
+
+        let mut documents = Vec::new();
+        for path in crate::scenario_files::find_scenario_files(paths) {
+            let original = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let document = ScenarioDocument::parse(&original)
+                .with_context(|| format!("Failed to parse {}", path.display()))?;
+            documents.push((path, original, document));
+        }
+
+        if kind == RenameKind::Scene {
+            let defining = |id: &str| {
+                documents
+                    .iter()
+                    .find(|(_, _, document)| document.scene_ids().iter().any(|scene| scene == id))
+                    .map(|(path, _, _)| path)
+            };
+            if let Some(path) = defining(new) {
+                bail!("Scene '{}' already exists in {}", new, path.display());
+            }
+            if defining(old).is_none() {
+                bail!("Scene '{}' is not defined in any scenario file", old);
+            }
+        }
+
+        let mut edits = Vec::new();
+        for (path, original, mut document) in documents {
+            let renamed = match kind {
+                RenameKind::Scene => {
+                    let renamed = if document.scene_ids().iter().any(|scene| scene == old) {
+                        document.rename_scene(old, new)? + 1
+                    } else {
+                        document.rename_scene_references(old, new)
+                    };
+                    renamed + document.rename_scenario_jump_targets(old, new)
+                }
+                RenameKind::Flag => document.rename_flag(old, new),
+                RenameKind::Variable => document.rename_variable(old, new),
+                RenameKind::Character => document.rename_character(old, new),
+            };
+            if renamed > 0 {
+                edits.push(FileEdit {
+                    path,
+                    original,
+                    edited: document.to_string(),
+                    renamed,
+                });
+            }
+        }
+
+        if let Some(asset_root) = asset_root {
+            for path in manifest_files(kind, asset_root)? {
+                let Ok(original) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                let (edited, renamed) = rename_in_manifest(kind, &original, old, new);
+                if renamed > 0 {
+                    edits.push(FileEdit {
+                        path,
+                        original,
+                        edited,
+                        renamed,
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            kind,
+            old: old.to_string(),
+            new: new.to_string(),
+            edits,
+        })
+    }
+
+    /// Total number of values renamed
+    pub fn renamed(&self) -> usize {
+        self.edits.iter().map(|edit| edit.renamed).sum()
+    }
+
+    /// Unified diff of all edits
+    pub fn diff(&self) -> String {
+        self.edits.iter().map(FileEdit::diff).collect()
+    }
+
+    /// Write the edited files
+    ///
+    /// # Errors
+    /// Returns an error if a file cannot be written; files before it have
+    /// already been written.
+    pub fn apply(&self) -> Result<()> {
+        for edit in &self.edits {
+            std::fs::write(&edit.path, &edit.edited)
+                .with_context(|| format!("Failed to write {}", edit.path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a name can be used as a scene, flag, variable or character ID
+///
+/// Names must be usable in expressions: letters, digits, `_` and `.`, not
+/// starting with a digit or `.`, and not `true` or `false`.
+pub fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        && name != "true"
+        && name != "false"
+}
+
+/// Manifests under `asset_root` that can reference a name of this kind
+fn manifest_files(kind: RenameKind, asset_root: &Path) -> Result<Vec<PathBuf>> {
+    let files = match kind {
+        RenameKind::Scene => vec![asset_root.join(ACHIEVEMENTS_MANIFEST)],
+        RenameKind::Flag => vec![
+            asset_root.join(ACHIEVEMENTS_MANIFEST),
+            asset_root.join(HUD_MANIFEST),
+        ],
+        RenameKind::Variable => vec![asset_root.join(HUD_MANIFEST)],
+        RenameKind::Character => {
+            let manifest_path = asset_root.join(CHARACTERS_MANIFEST);
+            if !manifest_path.is_file() {
+                return Ok(Vec::new());
+            }
+            // Character files are relative to the manifest, as the registry
+            // loads them
+            let manifest = CharacterManifest::load_from_file(&manifest_path)
+                .with_context(|| format!("Failed to load {}", manifest_path.display()))?;
+            let manifest_dir = manifest_path.parent().unwrap_or(asset_root);
+            manifest
+                .characters
+                .iter()
+                .map(|path| manifest_dir.join(path))
+                .collect()
+        }
+    };
+    Ok(files)
+}
+
+/// Rename the references to a name in the text of a RON manifest
+fn rename_in_manifest(kind: RenameKind, text: &str, old: &str, new: &str) -> (String, usize) {
+    let patterns: &[&str] = match kind {
+        RenameKind::Scene => &["Scene(\"{}\")"],
+        RenameKind::Flag => &["Flag(\"{}\")"],
+        RenameKind::Variable => &["Variable(\"{}\")"],
+        RenameKind::Character => &["id: \"{}\""],
+    };
+    let mut edited = text.to_string();
+    let mut renamed = 0;
+    for pattern in patterns {
+        let from = pattern.replace("{}", old);
+        renamed += edited.matches(&from).count();
+        edited = edited.replace(&from, &pattern.replace("{}", new));
+    }
+    (edited, renamed)
+}
+
+/// A line of a diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Unified diff between two versions of a file, with `path` in the header
+pub fn unified_diff(path: &str, original: &str, edited: &str) -> String {
+    let old: Vec<&str> = original.lines().collect();
+    let new: Vec<&str> = edited.lines().collect();
+    let lines = diff_lines(&old, &new);
+    let is_change = |line: &DiffLine| !matches!(line, DiffLine::Same(_));
+
+    let mut diff = String::new();
+    let mut next = 0;
+    while let Some(first) = (next..lines.len()).find(|&i| is_change(&lines[i])) {
+        // Changes closer than twice the context share a hunk
+        let mut last = first;
+        while let Some(change) = (last + 1..lines.len())
+            .take(2 * DIFF_CONTEXT + 1)
+            .find(|&i| is_change(&lines[i]))
+        {
+            last = change;
+        }
+        let start = first.saturating_sub(DIFF_CONTEXT);
+        let end = (last + DIFF_CONTEXT + 1).min(lines.len());
+
+        let count = |lines: &[DiffLine], old_side: bool| {
+            lines
+                .iter()
+                .filter(|line| match line {
+                    DiffLine::Same(_) => true,
+                    DiffLine::Removed(_) => old_side,
+                    DiffLine::Added(_) => !old_side,
+                })
+                .count()
+        };
+        let (old_before, new_before) =
+            (count(&lines[..start], true), count(&lines[..start], false));
+        let (old_count, new_count) = (
+            count(&lines[start..end], true),
+            count(&lines[start..end], false),
+        );
+
+        if diff.is_empty() {
+            diff.push_str(&format!("--- {}\n+++ {}\n", path, path));
+        }
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_before + usize::from(old_count > 0),
+            old_count,
+            new_before + usize::from(new_count > 0),
+            new_count
+        ));
+        for line in &lines[start..end] {
+            let (sign, text) = match line {
+                DiffLine::Same(text) => (' ', text),
+                DiffLine::Removed(text) => ('-', text),
+                DiffLine::Added(text) => ('+', text),
+            };
+            diff.push(sign);
+            diff.push_str(text);
+            diff.push('\n');
+        }
+        next = end;
+    }
+    diff
+}
+
+/// Line-by-line differences, pairing changed lines when the line count is
+/// unchanged (as it is for renames) and using a longest common subsequence
+/// otherwise
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut lines: Vec<DiffLine> = old[..prefix].iter().map(|l| DiffLine::Same(l)).collect();
+    if old_middle.len() == new_middle.len() {
+        for (a, b) in old_middle.iter().zip(new_middle) {
+            if a == b {
+                lines.push(DiffLine::Same(a));
+            } else {
+                lines.push(DiffLine::Removed(a));
+                lines.push(DiffLine::Added(b));
+            }
+        }
+    } else {
+        // lengths[i][j]: common subsequence length of old_middle[i..], new_middle[j..]
+        let (n, m) = (old_middle.len(), new_middle.len());
+        let mut lengths = vec![vec![0u32; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lengths[i][j] = if old_middle[i] == new_middle[j] {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && old_middle[i] == new_middle[j] {
+                lines.push(DiffLine::Same(old_middle[i]));
+                i += 1;
+                j += 1;
+            } else if j == m || (i < n && lengths[i + 1][j] >= lengths[i][j + 1]) {
+                lines.push(DiffLine::Removed(old_middle[i]));
+                i += 1;
+            } else {
+                lines.push(DiffLine::Added(new_middle[j]));
+                j += 1;
+            }
+        }
+    }
+    lines.extend(old[old.len() - suffix..].iter().map(|l| DiffLine::Same(l)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, contents: &str) -> PathBuf {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    const PARK: &str = r#"[chapter]
+id = "chapter_01"
+title = "Chapter 1"
+
+[[scenes]]
+id = "park"
+title = "Park"
+
+[[scenes.commands]]
+type = "SetFlag"
+flag_name = "met_alice"  # first meeting
+value = true
+
+[[scenes.commands]]
+type = "JumpToScene"
+scene_id = "station"
+"#;
+
+    const STATION: &str = r#"[chapter]
+id = "chapter_02"
+title = "Chapter 2"
+
+[[scenes]]
+id = "station"
+title = "Station"
+
+[[scenes.commands]]
+type = "If"
+condition = "flags.met_alice"
+then_commands = [{ type = "JumpToScene", scene_id = "park" }]
+"#;
+
+    #[test]
+    fn test_rename_scene_across_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let park = write(dir.path(), "scenarios/park.toml", PARK);
+        write(dir.path(), "scenarios/station.toml", STATION);
+        write(
+            dir.path(),
+            ACHIEVEMENTS_MANIFEST,
+            "(achievements: [(id: \"trip\", unlock: [Scene(\"station\")])])",
+        );
+
+        let paths = [dir.path().join("scenarios")];
+        let plan = RenamePlan::new(
+            RenameKind::Scene,
+            "station",
+            "platform",
+            &paths,
+            Some(dir.path()),
+        )
+        .unwrap();
+        assert_eq!(plan.edits.len(), 3);
+        assert_eq!(plan.renamed(), 3);
+        assert_eq!(
+            plan.edits[0].diff(),
+            format!(
+                "--- {0}\n+++ {0}\n@@ -13,4 +13,4 @@\n \n [[scenes.commands]]\n \
+                 type = \"JumpToScene\"\n-scene_id = \"station\"\n+scene_id = \"platform\"\n",
+                park.display()
+            )
+        );
+
+        plan.apply().unwrap();
+        let achievements = std::fs::read_to_string(dir.path().join(ACHIEVEMENTS_MANIFEST));
+        assert!(achievements.unwrap().contains("Scene(\"platform\")"));
+        assert!(
+            RenamePlan::new(RenameKind::Scene, "station", "park", &paths, None).is_err(),
+            "the old scene no longer exists"
+        );
+        assert!(RenamePlan::new(RenameKind::Scene, "platform", "park", &paths, None).is_err());
+    }
+
+    #[test]
+    fn test_rename_flag_and_character() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "scenarios/park.toml", PARK);
+        write(dir.path(), "scenarios/station.toml", STATION);
+        write(
+            dir.path(),
+            HUD_MANIFEST,
+            "(widgets: [(source: Flag(\"met_alice\"))])",
+        );
+        write(
+            dir.path(),
+            CHARACTERS_MANIFEST,
+            "(characters: [\"characters/alice.ron\"])",
+        );
+        let alice = write(
+            dir.path(),
+            "manifests/characters/alice.ron",
+            "(\n    id: \"alice\",\n    name: \"Alice\",\n)\n",
+        );
+
+        let paths = [dir.path().join("scenarios")];
+        let plan = RenamePlan::new(
+            RenameKind::Flag,
+            "met_alice",
+            "knows_alice",
+            &paths,
+            Some(dir.path()),
+        )
+        .unwrap();
+        assert_eq!(plan.renamed(), 3);
+        assert!(
+            plan.edits[0]
+                .edited
+                .contains("flag_name = \"knows_alice\"  # first meeting")
+        );
+        assert!(
+            plan.edits[1]
+                .edited
+                .contains("condition = \"flags.knows_alice\"")
+        );
+
+        let plan = RenamePlan::new(
+            RenameKind::Character,
+            "alice",
+            "sakura",
+            &paths,
+            Some(dir.path()),
+        )
+        .unwrap();
+        assert_eq!(plan.edits.len(), 1);
+        assert_eq!(plan.edits[0].path, alice);
+        assert!(plan.edits[0].edited.contains("id: \"sakura\""));
+
+        assert!(RenamePlan::new(RenameKind::Variable, "gold", "2gold", &paths, None).is_err());
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        assert_eq!(unified_diff("a.txt", "same\n", "same\n"), "");
+        let original = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let edited = "1\nTWO\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n";
+        assert_eq!(
+            unified_diff("a.txt", original, edited),
+            "--- a.txt\n+++ a.txt\n@@ -1,5 +1,5 @@\n 1\n-2\n+TWO\n 3\n 4\n 5\n\
+             @@ -10,3 +10,4 @@\n 10\n 11\n 12\n+13\n"
+        );
+    }
+}
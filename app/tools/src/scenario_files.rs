@@ -0,0 +1,128 @@
+//! Scenario file discovery and traversal
+//!
+//! Shared helpers for report tools that work on scenarios as the engine
+//! loads them (including `[[scenes.dialogue]]` shorthand converted to commands).
+
+use anyhow::{Context, Result};
+use narrative_core::{Scenario, ScenarioCommand, Scene, Speaker};
+use narrative_engine::asset::AssetLoader;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Collect scenario files from a list of files and directories
+///
+/// Directories are searched recursively for `.toml` files. The result is
+/// sorted and free of duplicates.
+pub fn find_scenario_files(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            files.extend(
+                WalkDir::new(path)
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.into_path())
+                    .filter(|path| {
+                        path.is_file() && path.extension() == Some(std::ffi::OsStr::new("toml"))
+                    }),
+            );
+        } else {
+            files.push(path.clone());
+        }
+    }
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Load a scenario file the same way the engine does
+pub fn load_scenario(path: &Path) -> Result<Scenario> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("Invalid scenario path '{}'", path.display()))?;
+    let mut loader = AssetLoader::new(dir);
+    let scenario = loader
+        .load_scenario(file_name)
+        .with_context(|| format!("Failed to load scenario '{}'", path.display()))?;
+    Ok(scenario.clone())
+}
+
+/// Scenes in a stable order: the start scene first, then by ID
+pub fn ordered_scenes(scenario: &Scenario) -> Vec<&Scene> {
+    let mut scenes: Vec<&Scene> = scenario.scenes.values().collect();
+    scenes.sort_by(|a, b| {
+        (a.id != scenario.start_scene, &a.id).cmp(&(b.id != scenario.start_scene, &b.id))
+    });
+    scenes
+}
+
+/// Visit commands in order, descending into `If` branches
+pub fn visit_commands<'a>(
+    commands: &'a [ScenarioCommand],
+    visitor: &mut impl FnMut(&'a ScenarioCommand),
+) {
+    for command in commands {
+        visitor(command);
+        if let ScenarioCommand::If {
+            then_commands,
+            else_commands,
+            ..
+        } = command
+        {
+            visit_commands(then_commands, visitor);
+            visit_commands(else_commands, visitor);
+        }
+    }
+}
+
+/// Scene IDs a scene can continue to (jumps, calls and choice targets)
+pub fn scene_targets(scene: &Scene) -> Vec<&str> {
+    let mut targets: Vec<&str> = Vec::new();
+    visit_commands(&scene.commands, &mut |command| match command {
+        ScenarioCommand::JumpToScene { scene_id } => targets.push(scene_id),
+        ScenarioCommand::Call {
+            scene_id,
+            return_scene,
+            ..
+        } => {
+            targets.push(scene_id);
+            targets.push(return_scene);
+        }
+        ScenarioCommand::ShowChoice { choice } => {
+            targets.extend(
+                choice
+                    .options
+                    .iter()
+                    .map(|option| option.next_scene.as_str()),
+            );
+        }
+        _ => {}
+    });
+    let mut seen = std::collections::HashSet::new();
+    targets.retain(|target| seen.insert(*target));
+    targets
+}
+
+/// Path of `path` relative to `root`, with forward slashes
+///
+/// Asset references always use `/` regardless of platform.
+pub fn relative_asset_path(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    Some(
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+/// Name used for a speaker in reports
+pub fn speaker_name(speaker: &Speaker) -> &str {
+    match speaker {
+        Speaker::Character(id) => id,
+        Speaker::Narrator => "narrator",
+        Speaker::System => "system",
+    }
+}
@@ -3,11 +3,20 @@
 //! Provides validation functionality for TOML scenario files.
 //! Can be used both from CLI and from the editor.
 
+use crate::scenario_files;
+use crate::text_fit::{self, FontMeasure, TextFitConfig};
+use crate::text_lint::TextLinter;
+use crate::validation_rules::{RuleConfig, RuleLevel, ValidationRule};
 use anyhow::Result;
-use serde::Deserialize;
+use narrative_core::{ScenarioCommand, VoiceResolver};
+use notify::{RecursiveMode, Watcher};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, mpsc};
+use std::time::Duration;
 
 /// TOML scenario file structure
 #[derive(Debug, Deserialize)]
@@ -43,6 +52,25 @@ struct SceneInfo {
     dialogue: Vec<DialogueInfo>,
     #[serde(default)]
     choices: Vec<ChoiceInfo>,
+    #[serde(default)]
+    commands: Vec<CommandInfo>,
+}
+
+/// Scene command, with only the fields label checks need
+#[derive(Debug, Deserialize)]
+struct CommandInfo {
+    #[serde(rename = "type")]
+    kind: String,
+    /// Name of a `Label`
+    #[serde(default)]
+    name: Option<String>,
+    /// Target of a `JumpToLabel`
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default)]
+    then_commands: Vec<CommandInfo>,
+    #[serde(default)]
+    else_commands: Vec<CommandInfo>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -73,6 +101,51 @@ struct ScenarioSettings {
 pub struct ValidationConfig {
     pub strict_mode: bool,
     pub check_assets: bool,
+    /// Dialogue text lint pass (disabled when `None`)
+    pub text_lint: Option<TextLinter>,
+    /// Missing conventional voice file report (disabled when `None`)
+    pub voice_check: Option<VoiceCheckConfig>,
+    /// Text overflow check against UI bounds (disabled when `None`)
+    pub text_fit: Option<TextFitCheckConfig>,
+    /// Rule levels and `deny_warnings`
+    pub rules: RuleConfig,
+}
+
+/// Voice file check for lines without an explicit voice
+#[derive(Debug, Clone)]
+pub struct VoiceCheckConfig {
+    /// Resolver mapping dialogue lines to voice files
+    pub resolver: VoiceResolver,
+    /// Directory the resolved voice paths are relative to
+    pub asset_root: PathBuf,
+}
+
+/// Text overflow check against UI bounds
+#[derive(Clone)]
+pub struct TextFitCheckConfig {
+    /// Fonts and UI sizes
+    pub config: TextFitConfig,
+    /// Text measure, shared by the files validated in parallel
+    pub measure: Arc<Mutex<FontMeasure>>,
+}
+
+impl TextFitCheckConfig {
+    /// Create the check, loading the configured fonts relative to `asset_root`
+    pub fn new(config: TextFitConfig, asset_root: &Path) -> Result<Self> {
+        let measure = FontMeasure::new(&config.font_chain(asset_root))?;
+        Ok(Self {
+            config,
+            measure: Arc::new(Mutex::new(measure)),
+        })
+    }
+}
+
+impl std::fmt::Debug for TextFitCheckConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextFitCheckConfig")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for ValidationConfig {
@@ -80,16 +153,35 @@ impl Default for ValidationConfig {
         Self {
             strict_mode: false,
             check_assets: true,
+            text_lint: None,
+            voice_check: None,
+            text_fit: None,
+            rules: RuleConfig::default(),
         }
     }
 }
 
+/// A single validation finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// Rule that produced the finding (`None` for unreadable files)
+    pub rule: Option<ValidationRule>,
+    /// Level it was reported at (`Warn` or `Deny`)
+    pub level: RuleLevel,
+    /// Human-readable description
+    pub message: String,
+}
+
 /// Validation result for a single scenario
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ValidationResult {
     pub file_path: PathBuf,
+    /// Errors, prefixed with their rule (e.g. "[unknown_scene] ...")
     pub errors: Vec<String>,
+    /// Warnings, prefixed with their rule
     pub warnings: Vec<String>,
+    /// All findings with their rule and level
+    pub diagnostics: Vec<Diagnostic>,
     pub success: bool,
 }
 
@@ -99,17 +191,39 @@ impl ValidationResult {
             file_path,
             errors: Vec::new(),
             warnings: Vec::new(),
+            diagnostics: Vec::new(),
             success: true,
         }
     }
 
+    /// Record an error that is not tied to a rule, such as a parse error
     fn add_error(&mut self, error: String) {
-        self.errors.push(error);
+        self.errors.push(error.clone());
+        self.diagnostics.push(Diagnostic {
+            rule: None,
+            level: RuleLevel::Deny,
+            message: error,
+        });
         self.success = false;
     }
 
-    fn add_warning(&mut self, warning: String) {
-        self.warnings.push(warning);
+    /// Record a finding of `rule` at its configured level
+    fn report(&mut self, rules: &RuleConfig, rule: ValidationRule, message: String) {
+        let level = rules.level(rule);
+        let text = format!("[{}] {}", rule, message);
+        match level {
+            RuleLevel::Allow => return,
+            RuleLevel::Warn => self.warnings.push(text),
+            RuleLevel::Deny => {
+                self.errors.push(text);
+                self.success = false;
+            }
+        }
+        self.diagnostics.push(Diagnostic {
+            rule: Some(rule),
+            level,
+            message,
+        });
     }
 
     /// Check if validation was successful (no errors)
@@ -172,16 +286,34 @@ pub fn validate_file(
     validate_scenario_structure(&scenario, &mut result, config);
 
     // Validate character references
-    validate_character_references(&scenario, &mut result);
+    validate_character_references(&scenario, &mut result, config);
 
     // Validate scene flow
-    validate_scene_flow(&scenario, &mut result);
+    validate_scene_flow(&scenario, &mut result, config);
+
+    // Validate labels and label jumps
+    validate_labels(&scenario, &mut result, config);
 
     // Validate assets if enabled
     if config.check_assets {
         validate_assets(&scenario, &mut result);
     }
 
+    // Lint dialogue text if enabled
+    if let Some(linter) = &config.text_lint {
+        lint_text(file_path, &mut result, config, linter);
+    }
+
+    // Report character lines without a voice file if enabled
+    if let Some(voice_check) = &config.voice_check {
+        check_voice_files(file_path, &mut result, config, voice_check);
+    }
+
+    // Report text overflowing its UI element if enabled
+    if let Some(text_fit) = &config.text_fit {
+        check_text_fit(file_path, &mut result, config, text_fit);
+    }
+
     Ok(result)
 }
 
@@ -207,23 +339,137 @@ pub fn validate_directory(
     config: &ValidationConfig,
 ) -> Result<Vec<ValidationResult>> {
     let dir_path = dir_path.as_ref();
-    let mut results = Vec::new();
+    let mut files = Vec::new();
 
     if !dir_path.exists() {
         anyhow::bail!("Directory does not exist: {}", dir_path.display());
     }
 
     for entry in fs::read_dir(dir_path)? {
-        let entry = entry?;
-        let path = entry.path();
+        let path = entry?.path();
+        if is_scenario_file(&path) {
+            files.push(path);
+        }
+    }
+    files.sort();
 
-        if path.extension() == Some(std::ffi::OsStr::new("toml")) {
-            let result = validate_file(&path, config)?;
-            results.push(result);
+    validate_files(&files, config)
+}
+
+/// Validate scenario files in parallel
+///
+/// Results are in the order of `files`.
+pub fn validate_files(
+    files: &[PathBuf],
+    config: &ValidationConfig,
+) -> Result<Vec<ValidationResult>> {
+    files
+        .par_iter()
+        .map(|path| validate_file(path, config))
+        .collect()
+}
+
+/// Revalidate scenario files as they change
+///
+/// Watches `paths` (directories recursively) and calls `on_change` with the
+/// results for the `.toml` files changed since the last call. Changes
+/// arriving within a short window are batched, so an editor saving several
+/// files triggers one revalidation. Deleted files are not reported.
+///
+/// Blocks until the watcher stops.
+pub fn watch(
+    paths: &[PathBuf],
+    config: &ValidationConfig,
+    mut on_change: impl FnMut(Vec<ValidationResult>),
+) -> Result<()> {
+    /// Time to wait for further changes before revalidating
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // The receiver is gone once watching stops
+        let _ = tx.send(event);
+    })?;
+    for path in paths {
+        let mode = if path.is_dir() {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        watcher.watch(path, mode)?;
+    }
+
+    while let Ok(event) = rx.recv() {
+        let mut changed = Vec::new();
+        let mut event = Some(event);
+        while let Some(next) = event {
+            match next {
+                Ok(notify::Event { kind, paths, .. }) if !kind.is_access() => changed.extend(paths),
+                Ok(_) => {}
+                Err(e) => tracing::warn!("File watcher error: {}", e),
+            }
+            event = rx.recv_timeout(DEBOUNCE).ok();
+        }
+
+        let files = changed_scenario_files(changed);
+        if !files.is_empty() {
+            on_change(validate_files(&files, config)?);
         }
     }
 
-    Ok(results)
+    Ok(())
+}
+
+/// Existing scenario files among changed paths, sorted and deduplicated
+fn changed_scenario_files(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = paths
+        .into_iter()
+        .filter(|path| is_scenario_file(path) && path.is_file())
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+fn is_scenario_file(path: &Path) -> bool {
+    path.extension() == Some(std::ffi::OsStr::new("toml"))
+}
+
+/// Summary of a validation run, for machine-readable output
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    /// Results per file
+    pub files: Vec<ValidationResult>,
+    /// Number of files without errors
+    pub files_passed: usize,
+    /// Errors across all files
+    pub total_errors: usize,
+    /// Warnings across all files
+    pub total_warnings: usize,
+}
+
+impl ValidationReport {
+    /// Summarize validation results
+    pub fn new(files: Vec<ValidationResult>) -> Self {
+        Self {
+            files_passed: files.iter().filter(|result| result.success).count(),
+            total_errors: files.iter().map(|result| result.errors.len()).sum(),
+            total_warnings: files.iter().map(|result| result.warnings.len()).sum(),
+            files,
+        }
+    }
+
+    /// Serialize as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Serialize as JSON on a single line
+    ///
+    /// Used in watch mode, where each revalidation is one line of output.
+    pub fn to_json_line(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
 }
 
 fn validate_scenario_structure(
@@ -233,51 +479,95 @@ fn validate_scenario_structure(
 ) {
     // Check chapter info
     if scenario.chapter.id.is_empty() {
-        result.add_error("Chapter ID cannot be empty".to_string());
+        result.report(
+            &config.rules,
+            ValidationRule::EmptyField,
+            "Chapter ID cannot be empty".to_string(),
+        );
     }
     if scenario.chapter.title.is_empty() {
-        result.add_error("Chapter title cannot be empty".to_string());
+        result.report(
+            &config.rules,
+            ValidationRule::EmptyField,
+            "Chapter title cannot be empty".to_string(),
+        );
     }
 
     // Check characters
     if scenario.characters.is_empty() {
-        result.add_warning("No characters defined in scenario".to_string());
+        result.report(
+            &config.rules,
+            ValidationRule::NoCharacters,
+            "No characters defined in scenario".to_string(),
+        );
     }
 
     for character in &scenario.characters {
         if character.id.is_empty() {
-            result.add_error("Character ID cannot be empty".to_string());
+            result.report(
+                &config.rules,
+                ValidationRule::EmptyField,
+                "Character ID cannot be empty".to_string(),
+            );
         }
         if character.name.is_empty() {
-            result.add_error("Character name cannot be empty".to_string());
+            result.report(
+                &config.rules,
+                ValidationRule::EmptyField,
+                "Character name cannot be empty".to_string(),
+            );
         }
         if character.default_sprite.is_empty() {
-            result.add_error(format!(
-                "Default sprite path cannot be empty for character '{}'",
-                character.id
-            ));
+            result.report(
+                &config.rules,
+                ValidationRule::EmptyField,
+                format!(
+                    "Default sprite path cannot be empty for character '{}'",
+                    character.id
+                ),
+            );
         }
     }
 
     // Check scenes
     if scenario.scenes.is_empty() {
-        result.add_error("No scenes defined in scenario".to_string());
+        result.report(
+            &config.rules,
+            ValidationRule::NoScenes,
+            "No scenes defined in scenario".to_string(),
+        );
     }
 
     for scene in &scenario.scenes {
         if scene.id.is_empty() {
-            result.add_error("Scene ID cannot be empty".to_string());
+            result.report(
+                &config.rules,
+                ValidationRule::EmptyField,
+                "Scene ID cannot be empty".to_string(),
+            );
         }
         if scene.title.is_empty() && config.strict_mode {
-            result.add_warning(format!("Scene '{}' has no title", scene.id));
+            result.report(
+                &config.rules,
+                ValidationRule::MissingSceneTitle,
+                format!("Scene '{}' has no title", scene.id),
+            );
         }
         if scene.dialogue.is_empty() && scene.choices.is_empty() {
-            result.add_warning(format!("Scene '{}' has no dialogue or choices", scene.id));
+            result.report(
+                &config.rules,
+                ValidationRule::EmptyScene,
+                format!("Scene '{}' has no dialogue or choices", scene.id),
+            );
         }
     }
 }
 
-fn validate_character_references(scenario: &TomlScenario, result: &mut ValidationResult) {
+fn validate_character_references(
+    scenario: &TomlScenario,
+    result: &mut ValidationResult,
+    config: &ValidationConfig,
+) {
     let character_ids: Vec<&str> = scenario.characters.iter().map(|c| c.id.as_str()).collect();
     let character_names: Vec<&str> = scenario
         .characters
@@ -293,26 +583,38 @@ fn validate_character_references(scenario: &TomlScenario, result: &mut Validatio
                 && dialogue.speaker != "protagonist" // Allow special characters
                 && dialogue.speaker != "narrator"
             {
-                result.add_warning(format!(
-                    "Scene '{}': Speaker '{}' not found in character definitions",
-                    scene.id, dialogue.speaker
-                ));
+                result.report(
+                    &config.rules,
+                    ValidationRule::UnknownSpeaker,
+                    format!(
+                        "Scene '{}': Speaker '{}' not found in character definitions",
+                        scene.id, dialogue.speaker
+                    ),
+                );
             }
         }
     }
 }
 
-fn validate_scene_flow(scenario: &TomlScenario, result: &mut ValidationResult) {
+fn validate_scene_flow(
+    scenario: &TomlScenario,
+    result: &mut ValidationResult,
+    config: &ValidationConfig,
+) {
     let scene_ids: Vec<&str> = scenario.scenes.iter().map(|s| s.id.as_str()).collect();
 
     for scene in &scenario.scenes {
         // Check choice targets
         for choice in &scene.choices {
             if !scene_ids.contains(&choice.next_scene.as_str()) {
-                result.add_error(format!(
-                    "Scene '{}': Choice references non-existent scene '{}'",
-                    scene.id, choice.next_scene
-                ));
+                result.report(
+                    &config.rules,
+                    ValidationRule::UnknownScene,
+                    format!(
+                        "Scene '{}': Choice references non-existent scene '{}'",
+                        scene.id, choice.next_scene
+                    ),
+                );
             }
         }
     }
@@ -338,8 +640,179 @@ fn validate_scene_flow(scenario: &TomlScenario, result: &mut ValidationResult) {
             && !scenario.scenes.is_empty()
             && scene.id != scenario.scenes[0].id
         {
-            result.add_warning(format!("Scene '{}' may be unreachable", scene.id));
+            result.report(
+                &config.rules,
+                ValidationRule::UnreachableScene,
+                format!("Scene '{}' may be unreachable", scene.id),
+            );
+        }
+    }
+}
+
+fn validate_labels(
+    scenario: &TomlScenario,
+    result: &mut ValidationResult,
+    config: &ValidationConfig,
+) {
+    for scene in &scenario.scenes {
+        // Only top-level labels can be jumped to
+        let mut labels = HashSet::new();
+        for command in &scene.commands {
+            if command.kind == "Label"
+                && let Some(name) = &command.name
+                && !labels.insert(name.as_str())
+            {
+                result.report(
+                    &config.rules,
+                    ValidationRule::DuplicateLabel,
+                    format!(
+                        "Scene '{}': Label '{}' is defined more than once",
+                        scene.id, name
+                    ),
+                );
+            }
+        }
+
+        let mut pending: Vec<&CommandInfo> = scene.commands.iter().collect();
+        while let Some(command) = pending.pop() {
+            if command.kind == "JumpToLabel"
+                && let Some(label) = &command.label
+                && !labels.contains(label.as_str())
+            {
+                result.report(
+                    &config.rules,
+                    ValidationRule::UnknownLabel,
+                    format!(
+                        "Scene '{}': JumpToLabel references non-existent label '{}'",
+                        scene.id, label
+                    ),
+                );
+            }
+            pending.extend(&command.then_commands);
+            pending.extend(&command.else_commands);
+        }
+    }
+}
+
+fn lint_text(
+    file_path: &Path,
+    result: &mut ValidationResult,
+    config: &ValidationConfig,
+    linter: &TextLinter,
+) {
+    // Most text is in commands (including `If` branches), so use the full loader
+    let scenario = match scenario_files::load_scenario(file_path) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            result.report(
+                &config.rules,
+                ValidationRule::TextLint,
+                format!("Text lint skipped: {:#}", e),
+            );
+            return;
         }
+    };
+
+    let mut lines = Vec::new();
+    for scene in scenario_files::ordered_scenes(&scenario) {
+        let mut dialogue_count = 0usize;
+        let mut choice_count = 0usize;
+        scenario_files::visit_commands(&scene.commands, &mut |command| match command {
+            ScenarioCommand::Dialogue { dialogue } => {
+                dialogue_count = dialogue_count.saturating_add(1);
+                lines.push((
+                    format!("Scene '{}' dialogue {}", scene.id, dialogue_count),
+                    dialogue.text.as_str(),
+                ));
+            }
+            ScenarioCommand::ShowChoice { choice } => {
+                if let Some(prompt) = &choice.prompt {
+                    lines.push((
+                        format!("Scene '{}' choice prompt", scene.id),
+                        prompt.as_str(),
+                    ));
+                }
+                for option in &choice.options {
+                    choice_count = choice_count.saturating_add(1);
+                    lines.push((
+                        format!("Scene '{}' choice {}", scene.id, choice_count),
+                        option.text.as_str(),
+                    ));
+                }
+            }
+            _ => {}
+        });
+    }
+
+    for issue in linter.lint(lines) {
+        result.report(&config.rules, ValidationRule::TextLint, issue.to_string());
+    }
+}
+
+fn check_voice_files(
+    file_path: &Path,
+    result: &mut ValidationResult,
+    config: &ValidationConfig,
+    voice_check: &VoiceCheckConfig,
+) {
+    // Line numbers follow the engine's command order, so use the full loader
+    let scenario = match scenario_files::load_scenario(file_path) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            result.report(
+                &config.rules,
+                ValidationRule::MissingVoice,
+                format!("Voice check skipped: {:#}", e),
+            );
+            return;
+        }
+    };
+
+    for scene in scenario_files::ordered_scenes(&scenario) {
+        for voice in voice_check.resolver.conventional_lines(scene) {
+            if !voice_check.asset_root.join(&voice.path).is_file() {
+                result.report(
+                    &config.rules,
+                    ValidationRule::MissingVoice,
+                    format!(
+                        "Scene '{}' line {} ({}): no voice file '{}'",
+                        scene.id, voice.line, voice.character, voice.path
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn check_text_fit(
+    file_path: &Path,
+    result: &mut ValidationResult,
+    config: &ValidationConfig,
+    text_fit: &TextFitCheckConfig,
+) {
+    // Choices are commands in the engine's format, so use the full loader
+    let scenario = match scenario_files::load_scenario(file_path) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            result.report(
+                &config.rules,
+                ValidationRule::TextOverflow,
+                format!("Text fit check skipped: {:#}", e),
+            );
+            return;
+        }
+    };
+
+    let mut measure = text_fit
+        .measure
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    for overflow in text_fit::check_text_fit(&scenario, &text_fit.config, &mut *measure) {
+        result.report(
+            &config.rules,
+            ValidationRule::TextOverflow,
+            overflow.to_string(),
+        );
     }
 }
 
@@ -349,3 +822,230 @@ fn validate_assets(_scenario: &TomlScenario, _result: &mut ValidationResult) {
     // - Check if background images exist
     // - Check if audio files exist
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const VALID: &str = r#"
+[chapter]
+id = "ch1"
+title = "Chapter 1"
+
+[settings]
+
+[[characters]]
+id = "alice"
+name = "Alice"
+default_sprite = "alice.png"
+
+[[scenes]]
+id = "start"
+title = "Start"
+
+[[scenes.dialogue]]
+speaker = "alice"
+text = "Hello"
+"#;
+
+    fn write(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_validate_directory_in_file_order() {
+        let dir = TempDir::new().unwrap();
+        for i in (0..12).rev() {
+            write(&dir, &format!("ch{:02}.toml", i), VALID);
+        }
+        write(&dir, "broken.toml", "[chapter");
+        write(&dir, "notes.txt", "not a scenario");
+
+        let results = validate_directory(dir.path(), &ValidationConfig::default()).unwrap();
+        assert_eq!(results.len(), 13);
+        assert!(results[0].file_path.ends_with("broken.toml"));
+        assert!(results[0].has_errors());
+        assert!(results[1].file_path.ends_with("ch00.toml"));
+        assert!(results[12].file_path.ends_with("ch11.toml"));
+        assert!(results[1..].iter().all(|result| result.success));
+    }
+
+    #[test]
+    fn test_report_json() {
+        let dir = TempDir::new().unwrap();
+        let files = vec![
+            write(&dir, "ok.toml", VALID),
+            write(&dir, "broken.toml", "[chapter"),
+        ];
+        let report =
+            ValidationReport::new(validate_files(&files, &ValidationConfig::default()).unwrap());
+        assert_eq!(report.files_passed, 1);
+        assert_eq!(report.total_errors, 1);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["files"][1]["success"], false);
+        assert_eq!(json["total_errors"], 1);
+        assert!(!report.to_json_line().unwrap().contains('\n'));
+    }
+
+    #[test]
+    fn test_rule_levels() {
+        let dir = TempDir::new().unwrap();
+        // The second scene is unreachable, and "bob" is not a character
+        let path = write(
+            &dir,
+            "ch1.toml",
+            &format!(
+                "{}\n[[scenes]]\nid = \"extra\"\ntitle = \"Extra\"\n\n[[scenes.dialogue]]\nspeaker = \"bob\"\ntext = \"Hi\"\n",
+                VALID
+            ),
+        );
+
+        let mut config = ValidationConfig::default();
+        let result = validate_file(&path, &config).unwrap();
+        assert!(result.success);
+        assert_eq!(
+            result.warnings,
+            vec![
+                "[unknown_speaker] Scene 'extra': Speaker 'bob' not found in character definitions",
+                "[unreachable_scene] Scene 'extra' may be unreachable",
+            ]
+        );
+        assert_eq!(
+            result.diagnostics[1].rule,
+            Some(ValidationRule::UnreachableScene)
+        );
+
+        config
+            .rules
+            .rules
+            .insert(ValidationRule::UnreachableScene, RuleLevel::Allow);
+        let result = validate_file(&path, &config).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.diagnostics.len(), 1);
+
+        config.rules.deny_warnings = true;
+        let result = validate_file(&path, &config).unwrap();
+        assert!(!result.success);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.diagnostics[0].level, RuleLevel::Deny);
+    }
+
+    #[test]
+    fn test_labels() {
+        let dir = TempDir::new().unwrap();
+        let path = write(
+            &dir,
+            "ch1.toml",
+            &format!(
+                r#"{}
+[[scenes.commands]]
+type = "Label"
+name = "retry"
+
+[[scenes.commands]]
+type = "Label"
+name = "retry"
+
+[[scenes.commands]]
+type = "JumpToLabel"
+label = "retry"
+
+[[scenes.commands]]
+type = "If"
+condition = {{ type = "Flag", flag_name = "lost", expected = true }}
+
+[[scenes.commands.then_commands]]
+type = "JumpToLabel"
+label = "missing"
+"#,
+                VALID
+            ),
+        );
+
+        let result = validate_file(&path, &ValidationConfig::default()).unwrap();
+        assert!(!result.success);
+        assert_eq!(
+            result.errors,
+            vec![
+                "[unknown_label] Scene 'start': JumpToLabel references non-existent label 'missing'"
+            ]
+        );
+        assert_eq!(
+            result.warnings,
+            vec!["[duplicate_label] Scene 'start': Label 'retry' is defined more than once"]
+        );
+    }
+
+    #[test]
+    fn test_lint_command_text() {
+        let dir = TempDir::new().unwrap();
+        let path = write(
+            &dir,
+            "ch1.toml",
+            r#"
+characters = []
+
+[chapter]
+id = "ch1"
+title = "Chapter 1"
+
+[settings]
+
+[[scenes]]
+id = "start"
+title = "Start"
+
+[[scenes.commands]]
+type = "Dialogue"
+dialogue = { speaker = "Narrator", text = "Fine." }
+
+[[scenes.commands]]
+type = "If"
+condition = { type = "Flag", flag_name = "lost", expected = true }
+
+[[scenes.commands.then_commands]]
+type = "Dialogue"
+dialogue = { speaker = "Narrator", text = "Lost  again." }
+
+[[scenes.commands]]
+type = "ShowChoice"
+choice = { prompt = "Go  on?", options = [{ text = "Yes", next_scene = "start" }, { text = "No  way", next_scene = "start" }] }
+"#,
+        );
+
+        let config = ValidationConfig {
+            text_lint: Some(TextLinter::new(Default::default()).unwrap()),
+            ..Default::default()
+        };
+        let result = validate_file(&path, &config).unwrap();
+        let lint: Vec<&str> = result
+            .warnings
+            .iter()
+            .filter(|warning| warning.starts_with("[text_lint]"))
+            .map(String::as_str)
+            .collect();
+        assert_eq!(lint.len(), 3, "{:?}", result.diagnostics);
+        assert!(lint[0].contains("Scene 'start' dialogue 2"));
+        assert!(lint[1].contains("Scene 'start' choice prompt"));
+        assert!(lint[2].contains("Scene 'start' choice 2"));
+    }
+
+    #[test]
+    fn test_changed_scenario_files() {
+        let dir = TempDir::new().unwrap();
+        let scenario = write(&dir, "ch1.toml", VALID);
+        let other = write(&dir, "notes.txt", "");
+        let changed = changed_scenario_files(vec![
+            scenario.clone(),
+            other,
+            dir.path().join("deleted.toml"),
+            scenario.clone(),
+        ]);
+        assert_eq!(changed, vec![scenario]);
+    }
+}
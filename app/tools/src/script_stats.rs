@@ -0,0 +1,523 @@
+//! Script statistics module
+//!
+//! Counts words and characters per scenario and per speaker, choices and
+//! branches, and estimates the reading time of each route through a scenario.
+//! Reports can be written as CSV or JSON.
+//!
+//! Only dialogue text is counted; choice labels are reported separately as
+//! choice counts.
+
+use crate::scenario_files::{ordered_scenes, scene_targets, speaker_name, visit_commands};
+use anyhow::Result;
+use narrative_core::{Scenario, ScenarioCommand, Scene};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Maximum number of routes enumerated per scenario
+pub const MAX_ROUTES: usize = 1000;
+
+/// Reading speed used to estimate reading time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingSpeed {
+    /// Words per minute for space-separated scripts (English etc.)
+    pub words_per_minute: f64,
+    /// Characters per minute for CJK text
+    pub cjk_chars_per_minute: f64,
+}
+
+impl Default for ReadingSpeed {
+    fn default() -> Self {
+        Self {
+            words_per_minute: 250.0,
+            cjk_chars_per_minute: 500.0,
+        }
+    }
+}
+
+/// Text volume counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TextStats {
+    /// Number of dialogue lines
+    pub lines: usize,
+    /// Space-separated words (CJK text is not split into words)
+    pub words: usize,
+    /// Non-whitespace characters
+    pub characters: usize,
+    /// CJK characters (kana, kanji, hangul)
+    pub cjk_characters: usize,
+}
+
+impl TextStats {
+    /// Count a dialogue line
+    pub fn add_line(&mut self, text: &str) {
+        self.lines = self.lines.saturating_add(1);
+        self.words = self.words.saturating_add(
+            text.split_whitespace()
+                .filter(|token| token.chars().any(|c| c.is_alphanumeric() && !is_cjk(c)))
+                .count(),
+        );
+        self.characters = self
+            .characters
+            .saturating_add(text.chars().filter(|c| !c.is_whitespace()).count());
+        self.cjk_characters = self
+            .cjk_characters
+            .saturating_add(text.chars().filter(|c| is_cjk(*c)).count());
+    }
+
+    /// Add another set of counters
+    pub fn merge(&mut self, other: &TextStats) {
+        self.lines = self.lines.saturating_add(other.lines);
+        self.words = self.words.saturating_add(other.words);
+        self.characters = self.characters.saturating_add(other.characters);
+        self.cjk_characters = self.cjk_characters.saturating_add(other.cjk_characters);
+    }
+
+    /// Estimated reading time in minutes
+    pub fn reading_minutes(&self, speed: &ReadingSpeed) -> f64 {
+        let words = if speed.words_per_minute > 0.0 {
+            self.words as f64 / speed.words_per_minute
+        } else {
+            0.0
+        };
+        let cjk = if speed.cjk_chars_per_minute > 0.0 {
+            self.cjk_characters as f64 / speed.cjk_chars_per_minute
+        } else {
+            0.0
+        };
+        words + cjk
+    }
+}
+
+/// A path through the scene graph from the start scene to an ending
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RouteStats {
+    /// Scene IDs in visiting order
+    pub scenes: Vec<String>,
+    /// Text along the route
+    pub text: TextStats,
+    /// Estimated reading time in minutes
+    pub reading_minutes: f64,
+}
+
+impl RouteStats {
+    /// Route label ("start > a > ending")
+    pub fn name(&self) -> String {
+        self.scenes.join(" > ")
+    }
+}
+
+/// Statistics for one scenario file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScenarioStats {
+    /// Scenario file path
+    pub path: PathBuf,
+    /// Scenario ID
+    pub id: String,
+    /// Scenario title
+    pub title: String,
+    /// Number of scenes
+    pub scenes: usize,
+    /// All dialogue text
+    pub text: TextStats,
+    /// Dialogue text per speaker
+    pub speakers: BTreeMap<String, TextStats>,
+    /// Number of choice menus
+    pub choices: usize,
+    /// Number of choice options across all menus
+    pub choice_options: usize,
+    /// Number of conditional branches (`If` commands)
+    pub branches: usize,
+    /// Estimated reading time of the whole scenario in minutes
+    pub reading_minutes: f64,
+    /// Routes from the start scene
+    pub routes: Vec<RouteStats>,
+    /// Whether route enumeration stopped at [`MAX_ROUTES`]
+    pub routes_truncated: bool,
+}
+
+impl ScenarioStats {
+    /// Collect statistics for a loaded scenario
+    pub fn from_scenario(path: PathBuf, scenario: &Scenario, speed: &ReadingSpeed) -> Self {
+        let mut text = TextStats::default();
+        let mut speakers: BTreeMap<String, TextStats> = BTreeMap::new();
+        let mut scene_text: HashMap<&str, TextStats> = HashMap::new();
+        let mut choices = 0usize;
+        let mut choice_options = 0usize;
+        let mut branches = 0usize;
+
+        for scene in ordered_scenes(scenario) {
+            let mut this_scene = TextStats::default();
+            visit_commands(&scene.commands, &mut |command| match command {
+                ScenarioCommand::Dialogue { dialogue } => {
+                    this_scene.add_line(&dialogue.text);
+                    speakers
+                        .entry(speaker_name(&dialogue.speaker).to_string())
+                        .or_default()
+                        .add_line(&dialogue.text);
+                }
+                ScenarioCommand::ShowChoice { choice } => {
+                    choices = choices.saturating_add(1);
+                    choice_options = choice_options.saturating_add(choice.options.len());
+                }
+                ScenarioCommand::If { .. } => branches = branches.saturating_add(1),
+                _ => {}
+            });
+            text.merge(&this_scene);
+            scene_text.insert(scene.id.as_str(), this_scene);
+        }
+
+        let (routes, routes_truncated) = enumerate_routes(scenario);
+        let routes = routes
+            .into_iter()
+            .map(|scenes| {
+                let mut route_text = TextStats::default();
+                for id in &scenes {
+                    if let Some(stats) = scene_text.get(id.as_str()) {
+                        route_text.merge(stats);
+                    }
+                }
+                RouteStats {
+                    scenes,
+                    reading_minutes: route_text.reading_minutes(speed),
+                    text: route_text,
+                }
+            })
+            .collect();
+
+        Self {
+            path,
+            id: scenario.metadata.id.clone(),
+            title: scenario.metadata.title.clone(),
+            scenes: scenario.scenes.len(),
+            reading_minutes: text.reading_minutes(speed),
+            text,
+            speakers,
+            choices,
+            choice_options,
+            branches,
+            routes,
+            routes_truncated,
+        }
+    }
+}
+
+/// Statistics for a set of scenarios
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScriptReport {
+    /// Per-scenario statistics
+    pub scenarios: Vec<ScenarioStats>,
+    /// Totals across all scenarios
+    pub total: TextStats,
+    /// Totals per speaker across all scenarios
+    pub speakers: BTreeMap<String, TextStats>,
+    /// Estimated reading time of everything in minutes
+    pub reading_minutes: f64,
+}
+
+impl ScriptReport {
+    /// Build a report from per-scenario statistics
+    pub fn new(scenarios: Vec<ScenarioStats>, speed: &ReadingSpeed) -> Self {
+        let mut total = TextStats::default();
+        let mut speakers: BTreeMap<String, TextStats> = BTreeMap::new();
+        for scenario in &scenarios {
+            total.merge(&scenario.text);
+            for (speaker, stats) in &scenario.speakers {
+                speakers.entry(speaker.clone()).or_default().merge(stats);
+            }
+        }
+        Self {
+            scenarios,
+            reading_minutes: total.reading_minutes(speed),
+            total,
+            speakers,
+        }
+    }
+
+    /// Serialize the report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Write the report as a flat CSV table
+    ///
+    /// Each row is a scenario, speaker, route or total, distinguished by the
+    /// `scope` column.
+    pub fn write_csv(&self, writer: impl Write) -> Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        for scenario in &self.scenarios {
+            let mut row = CsvRow::new("scenario", &scenario.id, &scenario.title, &scenario.text);
+            row.scenes = Some(scenario.scenes);
+            row.choices = Some(scenario.choices);
+            row.choice_options = Some(scenario.choice_options);
+            row.branches = Some(scenario.branches);
+            row.reading_minutes = round_minutes(scenario.reading_minutes);
+            csv.serialize(row)?;
+
+            for (speaker, stats) in &scenario.speakers {
+                csv.serialize(CsvRow::new("speaker", &scenario.id, speaker, stats))?;
+            }
+            for route in &scenario.routes {
+                let name = route.name();
+                let mut row = CsvRow::new("route", &scenario.id, &name, &route.text);
+                row.scenes = Some(route.scenes.len());
+                row.reading_minutes = round_minutes(route.reading_minutes);
+                csv.serialize(row)?;
+            }
+        }
+        for (speaker, stats) in &self.speakers {
+            csv.serialize(CsvRow::new("speaker", "", speaker, stats))?;
+        }
+        let mut total = CsvRow::new("total", "", "", &self.total);
+        total.reading_minutes = round_minutes(self.reading_minutes);
+        csv.serialize(total)?;
+        csv.flush()?;
+        Ok(())
+    }
+}
+
+/// One row of the CSV report
+#[derive(Debug, Serialize)]
+struct CsvRow<'a> {
+    scope: &'a str,
+    scenario: &'a str,
+    name: &'a str,
+    lines: usize,
+    words: usize,
+    characters: usize,
+    cjk_characters: usize,
+    scenes: Option<usize>,
+    choices: Option<usize>,
+    choice_options: Option<usize>,
+    branches: Option<usize>,
+    reading_minutes: f64,
+}
+
+impl<'a> CsvRow<'a> {
+    fn new(scope: &'a str, scenario: &'a str, name: &'a str, text: &TextStats) -> Self {
+        Self {
+            scope,
+            scenario,
+            name,
+            lines: text.lines,
+            words: text.words,
+            characters: text.characters,
+            cjk_characters: text.cjk_characters,
+            scenes: None,
+            choices: None,
+            choice_options: None,
+            branches: None,
+            reading_minutes: 0.0,
+        }
+    }
+}
+
+fn round_minutes(minutes: f64) -> f64 {
+    (minutes * 10.0).round() / 10.0
+}
+
+/// Enumerate scene paths from the start scene to scenes without exits
+///
+/// Loops are cut when a scene would be visited twice on the same path.
+/// Returns the routes and whether enumeration stopped at [`MAX_ROUTES`].
+fn enumerate_routes(scenario: &Scenario) -> (Vec<Vec<String>>, bool) {
+    let mut routes = Vec::new();
+    let Some(start) = scenario.get_start_scene() else {
+        return (routes, false);
+    };
+    let mut path = Vec::new();
+    let truncated = !walk_routes(scenario, start, &mut path, &mut routes);
+    (routes, truncated)
+}
+
+/// Depth-first route walk; returns `false` once the route limit is reached
+fn walk_routes<'a>(
+    scenario: &'a Scenario,
+    scene: &'a Scene,
+    path: &mut Vec<&'a str>,
+    routes: &mut Vec<Vec<String>>,
+) -> bool {
+    path.push(scene.id.as_str());
+
+    let next: Vec<&Scene> = scene_targets(scene)
+        .into_iter()
+        .filter(|target| !path.contains(target))
+        .filter_map(|target| scenario.get_scene(target))
+        .collect();
+
+    let mut keep_going = true;
+    if next.is_empty() {
+        if routes.len() >= MAX_ROUTES {
+            keep_going = false;
+        } else {
+            routes.push(path.iter().map(|id| id.to_string()).collect());
+        }
+    } else {
+        for target in next {
+            if !walk_routes(scenario, target, path, routes) {
+                keep_going = false;
+                break;
+            }
+        }
+    }
+
+    path.pop();
+    keep_going
+}
+
+/// Whether a character belongs to a script read without word spacing
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{FF66}'..='\u{FF9F}' // Halfwidth Katakana
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{Choice, ChoiceOption, Condition, Dialogue, ScenarioMetadata};
+
+    fn scene(id: &str, commands: Vec<ScenarioCommand>) -> Scene {
+        let mut scene = Scene::new(id, id);
+        for command in commands {
+            scene.add_command(command);
+        }
+        scene
+    }
+
+    fn say(speaker: &str, text: &str) -> ScenarioCommand {
+        ScenarioCommand::Dialogue {
+            dialogue: Dialogue::new(speaker, text),
+        }
+    }
+
+    fn test_scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("ch1", "Chapter 1"), "start");
+        scenario.add_scene(
+            "start",
+            scene(
+                "start",
+                vec![
+                    say("alice", "Hello there, Bob."),
+                    say("narrator", "こんにちは"),
+                    ScenarioCommand::ShowChoice {
+                        choice: Choice::new(vec![
+                            ChoiceOption::new("Left", "left"),
+                            ChoiceOption::new("Right", "right"),
+                        ]),
+                    },
+                ],
+            ),
+        );
+        scenario.add_scene(
+            "left",
+            scene(
+                "left",
+                vec![
+                    ScenarioCommand::If {
+                        condition: Condition::flag("met_bob", true),
+                        then_commands: vec![say("bob", "Hi again")],
+                        else_commands: vec![],
+                    },
+                    ScenarioCommand::JumpToScene {
+                        scene_id: "end".to_string(),
+                    },
+                ],
+            ),
+        );
+        scenario.add_scene(
+            "right",
+            scene(
+                "right",
+                vec![
+                    say("bob", "Back to the start?"),
+                    ScenarioCommand::JumpToScene {
+                        scene_id: "start".to_string(),
+                    },
+                ],
+            ),
+        );
+        scenario.add_scene("end", scene("end", vec![ScenarioCommand::End]));
+        scenario
+    }
+
+    #[test]
+    fn test_text_stats_counts() {
+        let mut stats = TextStats::default();
+        stats.add_line("Hello, world!  ...");
+        stats.add_line("今日は 晴れ");
+        assert_eq!(stats.lines, 2);
+        assert_eq!(stats.words, 2);
+        assert_eq!(stats.characters, 20);
+        assert_eq!(stats.cjk_characters, 5);
+
+        let speed = ReadingSpeed {
+            words_per_minute: 2.0,
+            cjk_chars_per_minute: 5.0,
+        };
+        assert!((stats.reading_minutes(&speed) - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_scenario_stats() {
+        let stats = ScenarioStats::from_scenario(
+            PathBuf::from("ch1.toml"),
+            &test_scenario(),
+            &ReadingSpeed::default(),
+        );
+        assert_eq!(stats.scenes, 4);
+        assert_eq!(stats.text.lines, 4);
+        assert_eq!(stats.choices, 1);
+        assert_eq!(stats.choice_options, 2);
+        assert_eq!(stats.branches, 1);
+        assert_eq!(stats.speakers.get("bob").map(|s| s.lines), Some(2));
+        assert_eq!(
+            stats.speakers.get("narrator").map(|s| s.cjk_characters),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn test_routes_cut_loops() {
+        let stats = ScenarioStats::from_scenario(
+            PathBuf::from("ch1.toml"),
+            &test_scenario(),
+            &ReadingSpeed::default(),
+        );
+        let names: Vec<String> = stats.routes.iter().map(RouteStats::name).collect();
+        assert_eq!(names, vec!["start > left > end", "start > right"]);
+        assert!(!stats.routes_truncated);
+        assert_eq!(stats.routes[0].text.lines, 3);
+    }
+
+    #[test]
+    fn test_report_csv_and_json() {
+        let speed = ReadingSpeed::default();
+        let stats =
+            ScenarioStats::from_scenario(PathBuf::from("ch1.toml"), &test_scenario(), &speed);
+        let report = ScriptReport::new(vec![stats], &speed);
+        assert_eq!(report.total.lines, 4);
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut rows = csv.lines();
+        assert!(
+            rows.next()
+                .unwrap()
+                .starts_with("scope,scenario,name,lines")
+        );
+        assert!(csv.contains("route,ch1,start > left > end,3,"));
+        assert!(csv.lines().last().unwrap().starts_with("total,,,4,"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["total"]["lines"], 4);
+        assert_eq!(json["scenarios"][0]["routes"].as_array().unwrap().len(), 2);
+    }
+}
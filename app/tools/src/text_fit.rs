@@ -0,0 +1,413 @@
+//! Text fit check against UI bounds
+//!
+//! Lays out choice text, character names and dialogue text with the game's
+//! fonts and reports strings that overflow the choice buttons, the speaker
+//! name line or the dialogue box. Dialogue text is checked in every language
+//! it is translated to. Choice text and character names have no
+//! translations in the scenario format, so they are checked as written; run
+//! the check over each language's scenario files to cover those.
+//!
+//! Per-project settings (fonts and UI sizes) are read from a TOML file. The
+//! `dialogue_box` table takes the same keys as the game's dialogue box
+//! configuration:
+//!
+//! ```toml
+//! fonts = ["fonts/NotoSansJP-Regular.otf"]
+//! system_fonts = false
+//! screen_width = 1280.0
+//! choice_width = 560.0
+//!
+//! [dialogue_box]
+//! height = 180.0
+//! text_font_size = 22.0
+//! ```
+
+use crate::scenario_files;
+use anyhow::{Context, Result};
+use narrative_core::config::DialogueBoxConfig;
+use narrative_core::markup::RichText;
+use narrative_core::{Point, Scenario, ScenarioCommand, Size};
+use narrative_engine::text::{FontFallbackChain, FontManager, TextLayout, TextStyle};
+use narrative_game::components::ChoiceMenuElement;
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Default per-project text fit configuration file name
+pub const DEFAULT_CONFIG_FILE: &str = "text_fit.toml";
+
+/// Screen width the game's UI is laid out for
+const REFERENCE_SCREEN_WIDTH: f32 = 1280.0;
+
+/// Text fit configuration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TextFitConfig {
+    /// Font files, main font first, relative to the asset root
+    pub fonts: Vec<PathBuf>,
+    /// Fall back to the system fonts after `fonts`
+    pub system_fonts: bool,
+    /// Width of the screen the dialogue box spans
+    pub screen_width: f32,
+    /// Width of a choice button
+    pub choice_width: f32,
+    /// Font size of choice text
+    pub choice_font_size: f32,
+    /// Dialogue box sizes, padding and fonts
+    pub dialogue_box: DialogueBoxConfig,
+}
+
+impl Default for TextFitConfig {
+    fn default() -> Self {
+        Self {
+            fonts: Vec::new(),
+            system_fonts: true,
+            screen_width: REFERENCE_SCREEN_WIDTH,
+            choice_width: ChoiceMenuElement::BUTTON_WIDTH,
+            choice_font_size: ChoiceMenuElement::FONT_SIZE,
+            dialogue_box: DialogueBoxConfig::default(),
+        }
+    }
+}
+
+impl TextFitConfig {
+    /// Load a text fit configuration file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read text fit config '{}'", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse text fit config '{}'", path.display()))
+    }
+
+    /// Font fallback chain of the configured fonts, relative to `asset_root`
+    pub fn font_chain(&self, asset_root: &Path) -> FontFallbackChain {
+        FontFallbackChain {
+            fonts: self
+                .fonts
+                .iter()
+                .map(|font| asset_root.join(font))
+                .collect(),
+            system_fonts: self.system_fonts,
+        }
+    }
+
+    /// Width available to the speaker name and dialogue text
+    pub fn text_width(&self) -> f32 {
+        self.screen_width - self.dialogue_box.padding * 2.0
+    }
+
+    /// Height available to dialogue text
+    ///
+    /// A speaker name takes a line above the text.
+    pub fn text_height(&self, has_speaker: bool) -> f32 {
+        let box_config = &self.dialogue_box;
+        let speaker = if has_speaker {
+            box_config.speaker_font_size + box_config.padding * 0.5
+        } else {
+            0.0
+        };
+        box_config.height - box_config.padding * 2.0 - speaker
+    }
+
+    fn speaker_style(&self) -> TextStyle {
+        let font_size = self.dialogue_box.speaker_font_size;
+        TextStyle {
+            font_size,
+            line_height: font_size,
+            ..TextStyle::default()
+        }
+    }
+
+    fn dialogue_style(&self) -> TextStyle {
+        TextStyle {
+            font_size: self.dialogue_box.text_font_size,
+            line_height: self.dialogue_box.text_line_height(),
+            letter_spacing: self.dialogue_box.letter_spacing,
+            paragraph_spacing: self.dialogue_box.paragraph_spacing,
+            ..TextStyle::default()
+        }
+    }
+
+    fn choice_style(&self) -> TextStyle {
+        TextStyle {
+            font_size: self.choice_font_size,
+            line_height: self.choice_font_size,
+            ..TextStyle::default()
+        }
+    }
+}
+
+/// Text layout used by the check
+pub trait MeasureText {
+    /// Size of `text` laid out in `style`, wrapped at `max_width` if set
+    ///
+    /// `text` may contain dialogue markup.
+    fn measure(&mut self, text: &str, style: &TextStyle, max_width: Option<f32>) -> Size;
+}
+
+/// Measures text with the game's text layout and fonts
+pub struct FontMeasure {
+    fonts: FontManager,
+}
+
+impl FontMeasure {
+    /// Create a measure using a font fallback chain
+    pub fn new(chain: &FontFallbackChain) -> Result<Self> {
+        let fonts = FontManager::with_fallback_chain(chain).context("Failed to load fonts")?;
+        Ok(Self { fonts })
+    }
+}
+
+impl MeasureText for FontMeasure {
+    fn measure(&mut self, text: &str, style: &TextStyle, max_width: Option<f32>) -> Size {
+        let rich = RichText::parse(text);
+        let text: Arc<str> = Arc::from(rich.text.as_str());
+        let origin = Point::new(0.0, 0.0);
+        let layout = match max_width {
+            Some(width) => {
+                TextLayout::with_max_width(&mut self.fonts, text, origin, style.clone(), width)
+            }
+            None => TextLayout::new(&mut self.fonts, text, origin, style.clone()),
+        }
+        .with_spans(&mut self.fonts, rich.spans);
+        layout.calculate().unwrap_or(Size::new(0.0, 0.0))
+    }
+}
+
+/// A string that does not fit its UI element
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextOverflow {
+    /// Where the text came from (e.g. "Scene 'intro' choice 2")
+    pub location: String,
+    /// Language of the text (`None` = as written)
+    pub language: Option<String>,
+    /// Size of the text in pixels, along the overflowing axis
+    pub size: f32,
+    /// Space available in pixels
+    pub available: f32,
+}
+
+impl fmt::Display for TextOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.location)?;
+        if let Some(language) = &self.language {
+            write!(f, " [{}]", language)?;
+        }
+        write!(
+            f,
+            ": text needs {:.0}px but only {:.0}px fit",
+            self.size, self.available
+        )
+    }
+}
+
+/// Check the text of a scenario against the configured UI bounds
+///
+/// Findings are in scene order (start scene first), preceded by the
+/// character names.
+pub fn check_text_fit(
+    scenario: &Scenario,
+    config: &TextFitConfig,
+    measure: &mut impl MeasureText,
+) -> Vec<TextOverflow> {
+    let mut overflows = Vec::new();
+    let text_width = config.text_width();
+
+    let speaker_style = config.speaker_style();
+    for character in &scenario.characters {
+        let size = measure.measure(&character.name, &speaker_style, None);
+        if size.width > text_width {
+            overflows.push(TextOverflow {
+                location: format!("Character '{}' name", character.id),
+                language: None,
+                size: size.width,
+                available: text_width,
+            });
+        }
+    }
+
+    let dialogue_style = config.dialogue_style();
+    let choice_style = config.choice_style();
+    for scene in scenario_files::ordered_scenes(scenario) {
+        let mut line = 0usize;
+        let mut choice_number = 0usize;
+        for command in &scene.commands {
+            match command {
+                ScenarioCommand::Dialogue { dialogue } => {
+                    line = line.saturating_add(1);
+                    let available = config.text_height(dialogue.speaker.is_character());
+                    let mut languages: Vec<&String> = dialogue.translations.keys().collect();
+                    languages.sort();
+                    let texts = std::iter::once((None, dialogue.text.as_str())).chain(
+                        languages
+                            .into_iter()
+                            .map(|language| (Some(language), dialogue.text_in(Some(language)))),
+                    );
+                    for (language, text) in texts {
+                        let size = measure.measure(text, &dialogue_style, Some(text_width));
+                        if size.height > available {
+                            overflows.push(TextOverflow {
+                                location: format!("Scene '{}' dialogue {}", scene.id, line),
+                                language: language.cloned(),
+                                size: size.height,
+                                available,
+                            });
+                        }
+                    }
+                }
+                ScenarioCommand::ShowChoice { choice } => {
+                    for option in &choice.options {
+                        choice_number = choice_number.saturating_add(1);
+                        let size = measure.measure(&option.text, &choice_style, None);
+                        if size.width > config.choice_width {
+                            overflows.push(TextOverflow {
+                                location: format!("Scene '{}' choice {}", scene.id, choice_number),
+                                language: None,
+                                size: size.width,
+                                available: config.choice_width,
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    overflows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{CharacterDef, Choice, ChoiceOption, Dialogue, ScenarioMetadata, Scene};
+
+    /// Fixed-pitch measure: each character is half the font size wide
+    struct HalfEm;
+
+    impl MeasureText for HalfEm {
+        fn measure(&mut self, text: &str, style: &TextStyle, max_width: Option<f32>) -> Size {
+            let width = text.chars().count() as f32 * style.font_size * 0.5;
+            let lines = match max_width {
+                Some(max_width) => (width / max_width).ceil().max(1.0),
+                None => 1.0,
+            };
+            Size::new(
+                width.min(max_width.unwrap_or(width)),
+                lines * style.line_height,
+            )
+        }
+    }
+
+    fn scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("test", "Test"), "start");
+        scenario.characters.push(CharacterDef::new(
+            "herald",
+            "The Royal Herald of the Northern Provinces, Keeper of the Seal",
+            "normal",
+        ));
+        let mut scene = Scene::new("start", "Start");
+        scene.add_command(ScenarioCommand::Dialogue {
+            dialogue: Dialogue::character("herald", "Hear ye.")
+                .with_translation("de", "Hört her! ".repeat(40)),
+        });
+        scene.add_command(ScenarioCommand::ShowChoice {
+            choice: Choice::new(vec![
+                ChoiceOption::new("Bow", "start"),
+                ChoiceOption::new("Ignore the herald and walk away. ".repeat(3), "start"),
+            ]),
+        });
+        scenario.add_scene("start", scene);
+        scenario
+    }
+
+    #[test]
+    fn test_reports_overflowing_text() {
+        let config = TextFitConfig {
+            screen_width: 640.0,
+            ..TextFitConfig::default()
+        };
+        let overflows = check_text_fit(&scenario(), &config, &mut HalfEm);
+        let locations: Vec<(&str, Option<&str>)> = overflows
+            .iter()
+            .map(|overflow| (overflow.location.as_str(), overflow.language.as_deref()))
+            .collect();
+
+        assert_eq!(
+            locations,
+            vec![
+                ("Character 'herald' name", None),
+                ("Scene 'start' dialogue 1", Some("de")),
+                ("Scene 'start' choice 2", None),
+            ]
+        );
+        assert_eq!(overflows[2].available, ChoiceMenuElement::BUTTON_WIDTH);
+        assert!(
+            overflows[1]
+                .to_string()
+                .starts_with("Scene 'start' dialogue 1 [de]: text needs")
+        );
+    }
+
+    #[test]
+    fn test_larger_bounds_fit() {
+        let config = TextFitConfig {
+            screen_width: 3840.0,
+            choice_width: 1200.0,
+            dialogue_box: DialogueBoxConfig {
+                height: 600.0,
+                ..DialogueBoxConfig::default()
+            },
+            ..TextFitConfig::default()
+        };
+        assert!(check_text_fit(&scenario(), &config, &mut HalfEm).is_empty());
+    }
+
+    #[test]
+    fn test_config_load() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_CONFIG_FILE);
+        fs::write(
+            &path,
+            r#"
+fonts = ["fonts/main.ttf"]
+system_fonts = false
+choice_width = 480.0
+
+[dialogue_box]
+height = 150.0
+"#,
+        )
+        .unwrap();
+
+        let config = TextFitConfig::load(&path).unwrap();
+        assert_eq!(config.choice_width, 480.0);
+        assert_eq!(config.choice_font_size, ChoiceMenuElement::FONT_SIZE);
+        assert_eq!(config.dialogue_box.height, 150.0);
+        assert_eq!(
+            config.dialogue_box.padding,
+            DialogueBoxConfig::default().padding
+        );
+
+        let chain = config.font_chain(Path::new("assets"));
+        assert_eq!(chain.fonts, vec![PathBuf::from("assets/fonts/main.ttf")]);
+        assert!(!chain.system_fonts);
+
+        fs::write(&path, "choice_widht = 480.0\n").unwrap();
+        assert!(TextFitConfig::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_font_measure_wraps_long_text() {
+        let Ok(mut measure) = FontMeasure::new(&FontFallbackChain::default()) else {
+            return;
+        };
+        let style = TextStyle::default();
+        let short = measure.measure("Hello", &style, Some(200.0));
+        let long = measure.measure(&"Hello ".repeat(50), &style, Some(200.0));
+        assert!(long.height >= short.height);
+        assert!(long.width <= 200.0 + style.font_size);
+    }
+}
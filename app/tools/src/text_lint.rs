@@ -0,0 +1,736 @@
+//! Dialogue text lint module
+//!
+//! Style checks for dialogue and choice text: optional spell-checking with
+//! hunspell dictionaries, unknown or unclosed `[markup]` and `{typewriter}`
+//! tags, unbalanced brackets, double spaces, inconsistent ellipsis/dash
+//! usage, and over-long lines.
+//!
+//! Per-project settings (ignore lists, line length, preferred punctuation)
+//! are read from a TOML file:
+//!
+//! ```toml
+//! max_chars_per_line = 40
+//! ellipsis_style = "…"
+//! dictionary = "dictionaries/en_US.dic"
+//! ignore_words = ["Akari", "Yuki"]
+//! disabled_rules = ["dash"]
+//! ```
+
+use anyhow::{Context, Result};
+use narrative_engine::text::TypewriterEffect;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default per-project lint configuration file name
+pub const DEFAULT_CONFIG_FILE: &str = "text_lint.toml";
+
+/// Ellipsis spellings checked for consistency
+const ELLIPSIS_STYLES: [&str; 3] = ["…", "‥", "..."];
+
+/// Dash spellings checked for consistency
+const DASH_STYLES: [&str; 3] = ["—", "―", "--"];
+
+/// Bracket pairs that must be balanced within a line
+const BRACKET_PAIRS: [(char, char); 4] = [('「', '」'), ('『', '』'), ('（', '）'), ('(', ')')];
+
+/// Individual lint rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// Word not found in the dictionary
+    Spelling,
+    /// Unknown markup/typewriter tag, or a tag or bracket opened but not
+    /// closed (or the reverse)
+    UnclosedMarkup,
+    /// Two or more consecutive spaces
+    DoubleSpace,
+    /// Ellipsis spelled differently from the rest of the script
+    Ellipsis,
+    /// Dash spelled differently from the rest of the script
+    Dash,
+    /// Line longer than the configured character count
+    LineLength,
+}
+
+impl LintRule {
+    /// Name used in config files and reports
+    pub fn name(self) -> &'static str {
+        match self {
+            LintRule::Spelling => "spelling",
+            LintRule::UnclosedMarkup => "unclosed_markup",
+            LintRule::DoubleSpace => "double_space",
+            LintRule::Ellipsis => "ellipsis",
+            LintRule::Dash => "dash",
+            LintRule::LineLength => "line_length",
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Text lint configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TextLintConfig {
+    /// Maximum characters per displayed line (`None` disables the check)
+    pub max_chars_per_line: Option<usize>,
+    /// Required ellipsis spelling (`None` uses the most common one)
+    pub ellipsis_style: Option<String>,
+    /// Required dash spelling (`None` uses the most common one)
+    pub dash_style: Option<String>,
+    /// Hunspell `.dic` file; the `.aff` file next to it is loaded if present.
+    /// Spell-checking is skipped when unset.
+    pub dictionary: Option<PathBuf>,
+    /// Words accepted by the spell-checker (case-insensitive)
+    pub ignore_words: Vec<String>,
+    /// Rules that are not reported
+    pub disabled_rules: Vec<LintRule>,
+}
+
+impl TextLintConfig {
+    /// Load a lint configuration file
+    ///
+    /// A relative `dictionary` path is resolved against the config file's directory.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read lint config '{}'", path.display()))?;
+        let mut config: TextLintConfig = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse lint config '{}'", path.display()))?;
+
+        if let Some(dictionary) = &config.dictionary
+            && dictionary.is_relative()
+            && let Some(dir) = path.parent()
+        {
+            config.dictionary = Some(dir.join(dictionary));
+        }
+        Ok(config)
+    }
+
+    /// Check whether a rule is enabled
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        !self.disabled_rules.contains(&rule)
+    }
+}
+
+/// A single lint finding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// Rule that produced the issue
+    pub rule: LintRule,
+    /// Where the text came from (e.g. "Scene 'intro' dialogue 3")
+    pub location: String,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: [{}] {}", self.location, self.rule, self.message)
+    }
+}
+
+/// Dialogue text linter
+#[derive(Debug, Clone)]
+pub struct TextLinter {
+    config: TextLintConfig,
+    dictionary: Option<Dictionary>,
+    ignore_words: HashSet<String>,
+}
+
+impl TextLinter {
+    /// Create a linter, loading the configured dictionary
+    pub fn new(config: TextLintConfig) -> Result<Self> {
+        let dictionary = match &config.dictionary {
+            Some(path) if config.is_enabled(LintRule::Spelling) => Some(Dictionary::load(path)?),
+            _ => None,
+        };
+        let ignore_words = config
+            .ignore_words
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect();
+        Ok(Self {
+            config,
+            dictionary,
+            ignore_words,
+        })
+    }
+
+    /// Lint configuration
+    pub fn config(&self) -> &TextLintConfig {
+        &self.config
+    }
+
+    /// Lint a set of text lines given as `(location, text)` pairs
+    ///
+    /// Ellipsis and dash consistency is judged across all lines passed in,
+    /// so pass a whole scenario at once.
+    pub fn lint<'a>(&self, lines: impl IntoIterator<Item = (String, &'a str)>) -> Vec<LintIssue> {
+        let lines: Vec<(String, &str)> = lines.into_iter().collect();
+        let mut issues = Vec::new();
+
+        for (location, text) in &lines {
+            self.lint_line(location, text, &mut issues);
+        }
+
+        if self.config.is_enabled(LintRule::Ellipsis) {
+            check_consistency(
+                &lines,
+                LintRule::Ellipsis,
+                &ELLIPSIS_STYLES,
+                self.config.ellipsis_style.as_deref(),
+                &mut issues,
+            );
+        }
+        if self.config.is_enabled(LintRule::Dash) {
+            check_consistency(
+                &lines,
+                LintRule::Dash,
+                &DASH_STYLES,
+                self.config.dash_style.as_deref(),
+                &mut issues,
+            );
+        }
+
+        issues
+    }
+
+    fn lint_line(&self, location: &str, text: &str, issues: &mut Vec<LintIssue>) {
+        let mut report = |rule: LintRule, message: String| {
+            if self.config.is_enabled(rule) {
+                issues.push(LintIssue {
+                    rule,
+                    location: location.to_string(),
+                    message,
+                });
+            }
+        };
+
+        if text.contains("  ") {
+            report(
+                LintRule::DoubleSpace,
+                "Contains consecutive spaces".to_string(),
+            );
+        }
+
+        for problem in markup_problems(text) {
+            report(LintRule::UnclosedMarkup, problem);
+        }
+
+        if let Some(max) = self.config.max_chars_per_line {
+            for (i, line) in text.split('\n').enumerate() {
+                let count = line.chars().count();
+                if count > max {
+                    report(
+                        LintRule::LineLength,
+                        format!(
+                            "Line {} has {} characters (max {})",
+                            i.saturating_add(1),
+                            count,
+                            max
+                        ),
+                    );
+                }
+            }
+        }
+
+        if let Some(dictionary) = &self.dictionary {
+            for word in words(text) {
+                if !self.ignore_words.contains(&word.to_lowercase()) && !dictionary.check(word) {
+                    report(LintRule::Spelling, format!("Unknown word '{}'", word));
+                }
+            }
+        }
+    }
+}
+
+/// Report lines whose punctuation spelling differs from the expected one
+fn check_consistency(
+    lines: &[(String, &str)],
+    rule: LintRule,
+    styles: &[&str],
+    preferred: Option<&str>,
+    issues: &mut Vec<LintIssue>,
+) {
+    let line_styles: Vec<Vec<&str>> = lines
+        .iter()
+        .map(|(_, text)| used_styles(text, styles))
+        .collect();
+
+    let expected = match preferred {
+        Some(style) => style,
+        None => {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for style in line_styles.iter().flatten() {
+                *counts.entry(*style).or_default() += 1;
+            }
+            // Ties resolve to the earlier entry in `styles`
+            let Some(most_common) = styles
+                .iter()
+                .copied()
+                .max_by_key(|style| {
+                    (
+                        counts.get(style).copied().unwrap_or(0),
+                        std::cmp::Reverse(styles.iter().position(|s| s == style)),
+                    )
+                })
+                .filter(|style| counts.contains_key(style))
+            else {
+                return;
+            };
+            most_common
+        }
+    };
+
+    for ((location, _), used) in lines.iter().zip(&line_styles) {
+        for style in used.iter().filter(|style| **style != expected) {
+            issues.push(LintIssue {
+                rule,
+                location: location.clone(),
+                message: format!("Uses '{}' instead of '{}'", style, expected),
+            });
+        }
+    }
+}
+
+/// Styles occurring in `text`, matching longer spellings first
+fn used_styles<'s>(text: &str, styles: &[&'s str]) -> Vec<&'s str> {
+    let mut remaining = text.to_string();
+    let mut by_length: Vec<&str> = styles.to_vec();
+    by_length.sort_by_key(|style| std::cmp::Reverse(style.len()));
+
+    let mut used = Vec::new();
+    for style in by_length {
+        if remaining.contains(style) {
+            used.push(style);
+            remaining = remaining.replace(style, " ");
+        }
+    }
+    used
+}
+
+/// Find unknown, unmatched or unclosed markup and typewriter tags (checked
+/// with the engine's text parser) and unbalanced brackets
+fn markup_problems(text: &str) -> Vec<String> {
+    let mut problems: Vec<String> = TypewriterEffect::check_tags(text)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    for (open, close) in BRACKET_PAIRS {
+        let mut depth: i32 = 0;
+        for c in text.chars() {
+            if c == open {
+                depth = depth.saturating_add(1);
+            } else if c == close {
+                depth = depth.saturating_sub(1);
+                if depth < 0 {
+                    problems.push(format!("'{}' has no matching '{}'", close, open));
+                    depth = 0;
+                }
+            }
+        }
+        if depth > 0 {
+            problems.push(format!("'{}' is not closed with '{}'", open, close));
+        }
+    }
+
+    problems
+}
+
+/// Latin words in `text` (CJK text is not spell-checked)
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_ascii_alphabetic() || c == '\''))
+        .map(|word| word.trim_matches('\''))
+        .filter(|word| word.chars().count() > 1)
+}
+
+// ============================================================================
+// Hunspell dictionary (subset)
+// ============================================================================
+
+/// Flag encoding declared by the `FLAG` directive of an `.aff` file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagMode {
+    /// One character per flag (default)
+    Char,
+    /// Two characters per flag
+    Long,
+    /// Comma-separated decimal numbers
+    Num,
+}
+
+impl FlagMode {
+    fn parse(self, flags: &str) -> Vec<String> {
+        match self {
+            FlagMode::Char => flags.chars().map(String::from).collect(),
+            FlagMode::Long => flags
+                .chars()
+                .collect::<Vec<_>>()
+                .chunks(2)
+                .map(|chunk| chunk.iter().collect())
+                .collect(),
+            FlagMode::Num => flags
+                .split(',')
+                .map(|flag| flag.trim().to_string())
+                .filter(|flag| !flag.is_empty())
+                .collect(),
+        }
+    }
+}
+
+/// One element of an affix condition pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConditionToken {
+    Any,
+    Char(char),
+    Set { chars: Vec<char>, negated: bool },
+}
+
+impl ConditionToken {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            ConditionToken::Any => true,
+            ConditionToken::Char(expected) => *expected == c,
+            ConditionToken::Set { chars, negated } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+/// A prefix or suffix rule from an `.aff` file
+#[derive(Debug, Clone)]
+struct AffixRule {
+    flag: String,
+    cross_product: bool,
+    strip: String,
+    add: String,
+    condition: Vec<ConditionToken>,
+}
+
+impl AffixRule {
+    /// Undo this suffix on `word`, returning the stem
+    fn strip_suffix(&self, word: &str) -> Option<String> {
+        let base = word.strip_suffix(self.add.as_str())?;
+        if base.is_empty() {
+            return None;
+        }
+        let stem = format!("{}{}", base, self.strip);
+        let tail: Vec<char> = stem.chars().rev().take(self.condition.len()).collect();
+        let matches = tail.len() == self.condition.len()
+            && self
+                .condition
+                .iter()
+                .rev()
+                .zip(&tail)
+                .all(|(token, c)| token.matches(*c));
+        matches.then_some(stem)
+    }
+
+    /// Undo this prefix on `word`, returning the stem
+    fn strip_prefix(&self, word: &str) -> Option<String> {
+        let base = word.strip_prefix(self.add.as_str())?;
+        if base.is_empty() {
+            return None;
+        }
+        let stem = format!("{}{}", self.strip, base);
+        let head: Vec<char> = stem.chars().take(self.condition.len()).collect();
+        let matches = head.len() == self.condition.len()
+            && self
+                .condition
+                .iter()
+                .zip(&head)
+                .all(|(token, c)| token.matches(*c));
+        matches.then_some(stem)
+    }
+}
+
+/// Hunspell dictionary supporting stems with single-level prefix/suffix rules
+///
+/// Compounding, continuation classes and other advanced `.aff` features are
+/// ignored. Words that rely on them are reported as unknown and can be added
+/// to `ignore_words`.
+#[derive(Debug, Clone, Default)]
+struct Dictionary {
+    stems: HashMap<String, Vec<String>>,
+    prefixes: Vec<AffixRule>,
+    suffixes: Vec<AffixRule>,
+}
+
+impl Dictionary {
+    /// Load a `.dic` file and the `.aff` file next to it
+    fn load(dic_path: &Path) -> Result<Self> {
+        let aff_path = dic_path.with_extension("aff");
+        let aff = if aff_path.is_file() {
+            fs::read_to_string(&aff_path)
+                .with_context(|| format!("Failed to read '{}'", aff_path.display()))?
+        } else {
+            String::new()
+        };
+        let dic = fs::read_to_string(dic_path)
+            .with_context(|| format!("Failed to read dictionary '{}'", dic_path.display()))?;
+        Ok(Self::parse(&dic, &aff))
+    }
+
+    fn parse(dic: &str, aff: &str) -> Self {
+        let mut dictionary = Dictionary::default();
+        let mut flag_mode = FlagMode::Char;
+        // Cross-product setting per affix header ("SFX A Y 3")
+        let mut cross_products: HashMap<(String, String), bool> = HashMap::new();
+
+        for line in aff.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["FLAG", "long", ..] => flag_mode = FlagMode::Long,
+                ["FLAG", "num", ..] => flag_mode = FlagMode::Num,
+                [kind @ ("PFX" | "SFX"), flag, cross, count] if count.parse::<usize>().is_ok() => {
+                    cross_products.insert((kind.to_string(), flag.to_string()), *cross == "Y");
+                }
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let strip = if *strip == "0" { "" } else { strip };
+                    // Continuation flags after '/' are not supported
+                    let add = add.split('/').next().unwrap_or("");
+                    let add = if add == "0" { "" } else { add };
+                    let rule = AffixRule {
+                        flag: flag.to_string(),
+                        cross_product: cross_products
+                            .get(&(kind.to_string(), flag.to_string()))
+                            .copied()
+                            .unwrap_or(false),
+                        strip: strip.to_string(),
+                        add: add.to_string(),
+                        condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                    };
+                    if *kind == "PFX" {
+                        dictionary.prefixes.push(rule);
+                    } else {
+                        dictionary.suffixes.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // The first line of a .dic file is the approximate word count
+        for line in dic.lines().skip(1) {
+            let Some(entry) = line.split_whitespace().next() else {
+                continue;
+            };
+            let (word, flags) = match entry.split_once('/') {
+                Some((word, flags)) => (word, flag_mode.parse(flags)),
+                None => (entry, Vec::new()),
+            };
+            dictionary
+                .stems
+                .entry(word.to_string())
+                .or_default()
+                .extend(flags);
+        }
+
+        dictionary
+    }
+
+    /// Check a word, also accepting lowercase forms of capitalized words
+    fn check(&self, word: &str) -> bool {
+        self.check_exact(word) || {
+            let lower = word.to_lowercase();
+            lower != word && self.check_exact(&lower)
+        }
+    }
+
+    fn check_exact(&self, word: &str) -> bool {
+        if self.stems.contains_key(word) {
+            return true;
+        }
+        for suffix in &self.suffixes {
+            let Some(stem) = suffix.strip_suffix(word) else {
+                continue;
+            };
+            if self.has_flag(&stem, &suffix.flag) {
+                return true;
+            }
+            // Prefix + suffix combinations
+            if suffix.cross_product {
+                for prefix in self.prefixes.iter().filter(|p| p.cross_product) {
+                    if let Some(root) = prefix.strip_prefix(&stem)
+                        && self.has_flag(&root, &suffix.flag)
+                        && self.has_flag(&root, &prefix.flag)
+                    {
+                        return true;
+                    }
+                }
+            }
+        }
+        self.prefixes.iter().any(|prefix| {
+            prefix
+                .strip_prefix(word)
+                .is_some_and(|stem| self.has_flag(&stem, &prefix.flag))
+        })
+    }
+
+    fn has_flag(&self, stem: &str, flag: &str) -> bool {
+        self.stems
+            .get(stem)
+            .is_some_and(|flags| flags.iter().any(|f| f == flag))
+    }
+}
+
+/// Parse an affix condition such as `[^aeiou]y` or `.`
+fn parse_condition(pattern: &str) -> Vec<ConditionToken> {
+    if pattern == "." {
+        return Vec::new();
+    }
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => tokens.push(ConditionToken::Any),
+            '[' => {
+                let mut set = Vec::new();
+                let mut negated = false;
+                for (i, c) in chars.by_ref().enumerate() {
+                    match c {
+                        '^' if i == 0 => negated = true,
+                        ']' => break,
+                        c => set.push(c),
+                    }
+                }
+                tokens.push(ConditionToken::Set {
+                    chars: set,
+                    negated,
+                });
+            }
+            c => tokens.push(ConditionToken::Char(c)),
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8\n\
+        PFX U Y 1\n\
+        PFX U 0 un .\n\
+        SFX D Y 2\n\
+        SFX D y ied [^aeiou]y\n\
+        SFX D 0 ed [^y]\n";
+    const DIC: &str = "3\nhello\ncarry/D\nlock/DU\n";
+
+    fn linter(config: TextLintConfig) -> TextLinter {
+        let mut linter = TextLinter::new(config).unwrap();
+        linter.dictionary = Some(Dictionary::parse(DIC, AFF));
+        linter
+    }
+
+    fn lint_one(linter: &TextLinter, text: &str) -> Vec<LintRule> {
+        linter
+            .lint([("line".to_string(), text)])
+            .into_iter()
+            .map(|issue| issue.rule)
+            .collect()
+    }
+
+    #[test]
+    fn test_dictionary_affixes() {
+        let dictionary = Dictionary::parse(DIC, AFF);
+        assert!(dictionary.check("hello"));
+        assert!(dictionary.check("Hello"));
+        assert!(dictionary.check("carried"));
+        assert!(dictionary.check("locked"));
+        assert!(dictionary.check("unlocked"));
+        assert!(!dictionary.check("carryed"));
+        assert!(!dictionary.check("uncarried"));
+        assert!(!dictionary.check("helo"));
+    }
+
+    #[test]
+    fn test_spelling_respects_ignore_list() {
+        let linter = linter(TextLintConfig {
+            ignore_words: vec!["akari".to_string()],
+            ..Default::default()
+        });
+        assert!(lint_one(&linter, "Hello, Akari. Unlocked!").is_empty());
+        assert_eq!(lint_one(&linter, "Helo"), vec![LintRule::Spelling]);
+        // Non-Latin text is not spell-checked
+        assert!(lint_one(&linter, "こんにちは").is_empty());
+    }
+
+    #[test]
+    fn test_markup_and_brackets() {
+        assert!(
+            markup_problems("[b]bold[/b] {w=0.5}[color=#ff0000]{$name}[/color]「quote」")
+                .is_empty()
+        );
+        assert_eq!(markup_problems("[b]bold").len(), 1);
+        assert_eq!(markup_problems("bold[/b]").len(), 1);
+        assert_eq!(markup_problems("[bold]x").len(), 1);
+        assert_eq!(markup_problems("[b][wave]x[/b]").len(), 1);
+        assert_eq!(markup_problems("{wait=x}").len(), 1);
+        assert_eq!(markup_problems("「quote").len(), 1);
+        assert_eq!(markup_problems("quote)").len(), 1);
+    }
+
+    #[test]
+    fn test_double_space_and_line_length() {
+        let linter = TextLinter::new(TextLintConfig {
+            max_chars_per_line: Some(5),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(lint_one(&linter, "a  b"), vec![LintRule::DoubleSpace]);
+        assert_eq!(lint_one(&linter, "abc\nabcdef"), vec![LintRule::LineLength]);
+        assert!(lint_one(&linter, "あいうえお").is_empty());
+    }
+
+    #[test]
+    fn test_ellipsis_consistency() {
+        let linter = TextLinter::new(TextLintConfig::default()).unwrap();
+        let issues = linter.lint([
+            ("a".to_string(), "Well…"),
+            ("b".to_string(), "Hmm…"),
+            ("c".to_string(), "Wait..."),
+        ]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::Ellipsis);
+        assert_eq!(issues[0].location, "c");
+
+        let linter = TextLinter::new(TextLintConfig {
+            ellipsis_style: Some("...".to_string()),
+            disabled_rules: vec![LintRule::Dash],
+            ..Default::default()
+        })
+        .unwrap();
+        let issues = linter.lint([
+            ("a".to_string(), "Well…"),
+            ("b".to_string(), "No—yes--maybe"),
+        ]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location, "a");
+    }
+
+    #[test]
+    fn test_config_load_resolves_dictionary() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_CONFIG_FILE);
+        fs::write(
+            &path,
+            "max_chars_per_line = 30\ndictionary = \"dict/en.dic\"\ndisabled_rules = [\"double_space\"]\n",
+        )
+        .unwrap();
+
+        let config = TextLintConfig::load(&path).unwrap();
+        assert_eq!(config.max_chars_per_line, Some(30));
+        assert_eq!(config.dictionary, Some(dir.path().join("dict/en.dic")));
+        assert!(!config.is_enabled(LintRule::DoubleSpace));
+        assert!(TextLinter::new(config).is_err());
+    }
+}
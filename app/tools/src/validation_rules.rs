@@ -0,0 +1,218 @@
+//! Scenario validation rules and severity levels
+//!
+//! Each validator check belongs to a rule with a default level. Projects can
+//! change levels, turn rules off or treat all warnings as errors in a TOML
+//! file, similar to `clippy.toml`:
+//!
+//! ```toml
+//! deny_warnings = true
+//!
+//! [rules]
+//! unknown_speaker = "deny"
+//! unreachable_scene = "allow"
+//! ```
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Default per-project validator configuration file name
+pub const DEFAULT_RULES_FILE: &str = "scenario_validator.toml";
+
+/// Individual validation rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationRule {
+    /// Required ID, title, name or sprite left empty
+    EmptyField,
+    /// Scenario without scenes
+    NoScenes,
+    /// Scenario without character definitions
+    NoCharacters,
+    /// Scene without a title (strict mode only)
+    MissingSceneTitle,
+    /// Scene without dialogue or choices
+    EmptyScene,
+    /// Dialogue speaker not in the character definitions
+    UnknownSpeaker,
+    /// Choice leading to a scene that does not exist
+    UnknownScene,
+    /// Scene nothing leads to
+    UnreachableScene,
+    /// Dialogue text lint finding (see `--lint`)
+    TextLint,
+    /// Character line without a voice file (see `--voice-dir`)
+    MissingVoice,
+    /// Text wider or taller than its UI element (see `--fit`)
+    TextOverflow,
+    /// JumpToLabel to a label that is not in its scene
+    UnknownLabel,
+    /// Label defined more than once in a scene
+    DuplicateLabel,
+}
+
+impl ValidationRule {
+    /// All rules, in report order
+    pub const ALL: [ValidationRule; 13] = [
+        ValidationRule::EmptyField,
+        ValidationRule::NoScenes,
+        ValidationRule::NoCharacters,
+        ValidationRule::MissingSceneTitle,
+        ValidationRule::EmptyScene,
+        ValidationRule::UnknownSpeaker,
+        ValidationRule::UnknownScene,
+        ValidationRule::UnreachableScene,
+        ValidationRule::TextLint,
+        ValidationRule::MissingVoice,
+        ValidationRule::TextOverflow,
+        ValidationRule::UnknownLabel,
+        ValidationRule::DuplicateLabel,
+    ];
+
+    /// Name used in config files and reports
+    pub fn name(self) -> &'static str {
+        match self {
+            ValidationRule::EmptyField => "empty_field",
+            ValidationRule::NoScenes => "no_scenes",
+            ValidationRule::NoCharacters => "no_characters",
+            ValidationRule::MissingSceneTitle => "missing_scene_title",
+            ValidationRule::EmptyScene => "empty_scene",
+            ValidationRule::UnknownSpeaker => "unknown_speaker",
+            ValidationRule::UnknownScene => "unknown_scene",
+            ValidationRule::UnreachableScene => "unreachable_scene",
+            ValidationRule::TextLint => "text_lint",
+            ValidationRule::MissingVoice => "missing_voice",
+            ValidationRule::TextOverflow => "text_overflow",
+            ValidationRule::UnknownLabel => "unknown_label",
+            ValidationRule::DuplicateLabel => "duplicate_label",
+        }
+    }
+
+    /// Level used when the configuration does not set one
+    pub fn default_level(self) -> RuleLevel {
+        match self {
+            ValidationRule::EmptyField
+            | ValidationRule::NoScenes
+            | ValidationRule::UnknownScene
+            | ValidationRule::UnknownLabel => RuleLevel::Deny,
+            _ => RuleLevel::Warn,
+        }
+    }
+}
+
+impl fmt::Display for ValidationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How findings of a rule are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleLevel {
+    /// Not reported
+    Allow,
+    /// Reported as a warning
+    Warn,
+    /// Reported as an error
+    Deny,
+}
+
+/// Rule levels for a validation run
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleConfig {
+    /// Report every warning as an error
+    pub deny_warnings: bool,
+    /// Levels overriding the rule defaults
+    pub rules: HashMap<ValidationRule, RuleLevel>,
+}
+
+impl RuleConfig {
+    /// Load a validator configuration file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read validator config '{}'", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse validator config '{}'", path.display()))
+    }
+
+    /// Level findings of `rule` are reported at
+    pub fn level(&self, rule: ValidationRule) -> RuleLevel {
+        let level = self
+            .rules
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_level());
+        if level == RuleLevel::Warn && self.deny_warnings {
+            RuleLevel::Deny
+        } else {
+            level
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_levels() {
+        let config = RuleConfig::default();
+        assert_eq!(config.level(ValidationRule::UnknownScene), RuleLevel::Deny);
+        assert_eq!(
+            config.level(ValidationRule::UnknownSpeaker),
+            RuleLevel::Warn
+        );
+    }
+
+    #[test]
+    fn test_config_file_overrides_and_deny_warnings() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_RULES_FILE);
+        fs::write(
+            &path,
+            r#"
+deny_warnings = true
+
+[rules]
+unreachable_scene = "allow"
+unknown_scene = "warn"
+"#,
+        )
+        .unwrap();
+
+        let config = RuleConfig::load(&path).unwrap();
+        assert_eq!(
+            config.level(ValidationRule::UnreachableScene),
+            RuleLevel::Allow
+        );
+        // deny_warnings applies to configured warnings too
+        assert_eq!(config.level(ValidationRule::UnknownScene), RuleLevel::Deny);
+        assert_eq!(config.level(ValidationRule::EmptyScene), RuleLevel::Deny);
+    }
+
+    #[test]
+    fn test_config_file_rejects_unknown_rules() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(DEFAULT_RULES_FILE);
+        fs::write(&path, "[rules]\nno_such_rule = \"allow\"\n").unwrap();
+        assert!(RuleConfig::load(&path).is_err());
+
+        fs::write(&path, "[rules]\nempty_scene = \"loud\"\n").unwrap();
+        assert!(RuleConfig::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_rule_names_match_serde() {
+        for rule in ValidationRule::ALL {
+            let parsed: HashMap<ValidationRule, RuleLevel> =
+                toml::from_str(&format!("{} = \"warn\"", rule.name())).unwrap();
+            assert!(parsed.contains_key(&rule));
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Voice script module
+//!
+//! Builds voice recording scripts from scenarios and checks recorded voice
+//! files against them.
+//!
+//! Each voiced line gets an ID of the form `{scenario}_{scene}_{line:03}`,
+//! where `line` counts dialogue lines within the scene. The expected voice
+//! file is the path of a `PlayVoice` command directly preceding the line or,
+//! failing that, `{voice_prefix}{line_id}.{extension}` using the speaker's
+//! `voice_prefix` (default `voice/{speaker}/`).
+
+use crate::ods::{self, Sheet};
+use crate::scenario_files::{ordered_scenes, relative_asset_path, speaker_name, visit_commands};
+use anyhow::{Context, Result};
+use narrative_core::{Scenario, ScenarioCommand, Speaker};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Audio extensions considered voice files when looking for orphans
+const VOICE_EXTENSIONS: [&str; 4] = ["ogg", "wav", "mp3", "flac"];
+
+/// Column headers of exported scripts
+const HEADER: [&str; 6] = [
+    "line_id",
+    "scene",
+    "speaker",
+    "context",
+    "text",
+    "voice_file",
+];
+
+/// Voice script options
+#[derive(Debug, Clone)]
+pub struct VoiceScriptConfig {
+    /// Extension of generated voice file names
+    pub extension: String,
+    /// Whether narrator lines are voiced
+    pub include_narrator: bool,
+}
+
+impl Default for VoiceScriptConfig {
+    fn default() -> Self {
+        Self {
+            extension: "ogg".to_string(),
+            include_narrator: false,
+        }
+    }
+}
+
+/// One line of a voice recording script
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VoiceLine {
+    /// Stable line identifier
+    pub line_id: String,
+    /// Scene ID
+    pub scene: String,
+    /// Speaker ID
+    pub speaker: String,
+    /// Preceding line in the scene ("speaker: text"), for the actor's reference
+    pub context: String,
+    /// Dialogue text
+    pub text: String,
+    /// Expected voice file, relative to the asset root
+    pub voice_file: String,
+}
+
+/// Collect the voiced lines of a scenario in scene order
+pub fn collect_voice_lines(scenario: &Scenario, config: &VoiceScriptConfig) -> Vec<VoiceLine> {
+    let mut lines = Vec::new();
+    for scene in ordered_scenes(scenario) {
+        let mut line_number = 0usize;
+        let mut context = String::new();
+        let mut pending_voice: Option<String> = None;
+
+        visit_commands(&scene.commands, &mut |command| match command {
+            ScenarioCommand::PlayVoice { asset, .. } => {
+                pending_voice = Some(asset.path().to_string());
+            }
+            ScenarioCommand::Dialogue { dialogue } => {
+                line_number = line_number.saturating_add(1);
+                let speaker = speaker_name(&dialogue.speaker);
+                let explicit_voice = pending_voice.take();
+                let voiced = match &dialogue.speaker {
+                    Speaker::Character(_) => true,
+                    Speaker::Narrator => config.include_narrator,
+                    Speaker::System => false,
+                };
+
+                if voiced || explicit_voice.is_some() {
+                    let line_id =
+                        format!("{}_{}_{:03}", scenario.metadata.id, scene.id, line_number);
+                    let voice_file = explicit_voice.unwrap_or_else(|| {
+                        format!(
+                            "{}{}.{}",
+                            voice_prefix(scenario, speaker),
+                            line_id,
+                            config.extension
+                        )
+                    });
+                    lines.push(VoiceLine {
+                        line_id,
+                        scene: scene.id.clone(),
+                        speaker: speaker.to_string(),
+                        context: context.clone(),
+                        text: dialogue.text.clone(),
+                        voice_file,
+                    });
+                }
+                context = format!("{}: {}", speaker, dialogue.text);
+            }
+            _ => {}
+        });
+    }
+    lines
+}
+
+/// Voice directory of a speaker, always ending with `/`
+fn voice_prefix(scenario: &Scenario, speaker: &str) -> String {
+    let prefix = scenario
+        .characters
+        .iter()
+        .find(|character| character.id == speaker)
+        .and_then(|character| character.voice_prefix.clone())
+        .unwrap_or_else(|| format!("voice/{}/", speaker));
+    if prefix.is_empty() || prefix.ends_with('/') {
+        prefix
+    } else {
+        format!("{}/", prefix)
+    }
+}
+
+/// Group lines by speaker
+pub fn lines_by_speaker(lines: &[VoiceLine]) -> BTreeMap<&str, Vec<&VoiceLine>> {
+    let mut grouped: BTreeMap<&str, Vec<&VoiceLine>> = BTreeMap::new();
+    for line in lines {
+        grouped.entry(line.speaker.as_str()).or_default().push(line);
+    }
+    grouped
+}
+
+/// Export file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values
+    Csv,
+    /// OpenDocument spreadsheet
+    Ods,
+}
+
+impl ExportFormat {
+    /// File extension
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ods => "ods",
+        }
+    }
+}
+
+/// Write one script file per speaker into `output_dir`
+///
+/// Returns the written file paths.
+pub fn export_scripts(
+    lines: &[VoiceLine],
+    output_dir: &Path,
+    format: ExportFormat,
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create '{}'", output_dir.display()))?;
+
+    let mut written = Vec::new();
+    for (speaker, lines) in lines_by_speaker(lines) {
+        let path = output_dir.join(format!("{}.{}", speaker, format.extension()));
+        let file = BufWriter::new(
+            File::create(&path)
+                .with_context(|| format!("Failed to create '{}'", path.display()))?,
+        );
+        match format {
+            ExportFormat::Csv => write_csv(file, &lines)?,
+            ExportFormat::Ods => write_ods(file, speaker, &lines)?,
+        }
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Write lines as CSV
+pub fn write_csv(writer: impl Write, lines: &[&VoiceLine]) -> Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    for line in lines {
+        csv.serialize(line)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+fn write_ods(
+    writer: impl Write + std::io::Seek,
+    speaker: &str,
+    lines: &[&VoiceLine],
+) -> Result<()> {
+    let mut sheet = Sheet::new(speaker);
+    sheet.push_row(HEADER);
+    for line in lines {
+        sheet.push_row([
+            line.line_id.as_str(),
+            line.scene.as_str(),
+            line.speaker.as_str(),
+            line.context.as_str(),
+            line.text.as_str(),
+            line.voice_file.as_str(),
+        ]);
+    }
+    ods::write_ods(writer, &[sheet])
+}
+
+/// Result of comparing a voice script with files on disk
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VoiceCheckReport {
+    /// Lines whose voice file does not exist
+    pub missing: Vec<VoiceLine>,
+    /// Voice files not referenced by any line, relative to the asset root
+    pub orphaned: Vec<String>,
+}
+
+impl VoiceCheckReport {
+    /// Whether every line has a file and every file has a line
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.orphaned.is_empty()
+    }
+}
+
+/// Check voice files under `asset_root` against the script
+///
+/// Orphans are searched for in every directory that holds an expected
+/// voice file, so unrelated audio (BGM, SE) is never reported.
+pub fn check_voice_files(lines: &[VoiceLine], asset_root: &Path) -> VoiceCheckReport {
+    let missing = lines
+        .iter()
+        .filter(|line| !asset_root.join(&line.voice_file).is_file())
+        .cloned()
+        .collect();
+
+    let expected: HashSet<&str> = lines.iter().map(|line| line.voice_file.as_str()).collect();
+    let voice_dirs: BTreeSet<&str> = lines
+        .iter()
+        .filter_map(|line| line.voice_file.rsplit_once('/').map(|(dir, _)| dir))
+        .collect();
+
+    let mut orphaned = BTreeSet::new();
+    for dir in voice_dirs {
+        for entry in WalkDir::new(asset_root.join(dir))
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+        {
+            let is_audio = entry
+                .path()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| VOICE_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+            let Some(relative) = relative_asset_path(asset_root, entry.path()) else {
+                continue;
+            };
+            if is_audio && !expected.contains(relative.as_str()) {
+                orphaned.insert(relative);
+            }
+        }
+    }
+
+    VoiceCheckReport {
+        missing,
+        orphaned: orphaned.into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use narrative_core::{AssetRef, CharacterDef, Dialogue, ScenarioMetadata, Scene};
+
+    fn test_scenario() -> Scenario {
+        let mut scenario = Scenario::new(ScenarioMetadata::new("ch1", "Chapter 1"), "intro");
+        let mut alice = CharacterDef::new("alice", "Alice", "normal");
+        alice.voice_prefix = Some("audio/voice/alice".to_string());
+        scenario.add_character(alice);
+
+        let mut scene = Scene::new("intro", "Intro");
+        for command in [
+            ScenarioCommand::Dialogue {
+                dialogue: Dialogue::narrator("Morning."),
+            },
+            ScenarioCommand::Dialogue {
+                dialogue: Dialogue::character("alice", "Hi!"),
+            },
+            ScenarioCommand::PlayVoice {
+                asset: AssetRef::new("voice/bob/special.ogg"),
+                volume: 1.0,
+            },
+            ScenarioCommand::Dialogue {
+                dialogue: Dialogue::character("bob", "Hey."),
+            },
+        ] {
+            scene.add_command(command);
+        }
+        scenario.add_scene("intro", scene);
+        scenario
+    }
+
+    #[test]
+    fn test_collect_voice_lines() {
+        let lines = collect_voice_lines(&test_scenario(), &VoiceScriptConfig::default());
+        assert_eq!(lines.len(), 2);
+
+        let alice = &lines[0];
+        assert_eq!(alice.line_id, "ch1_intro_002");
+        assert_eq!(alice.context, "narrator: Morning.");
+        assert_eq!(alice.voice_file, "audio/voice/alice/ch1_intro_002.ogg");
+
+        let bob = &lines[1];
+        assert_eq!(bob.voice_file, "voice/bob/special.ogg");
+        assert_eq!(bob.context, "alice: Hi!");
+
+        let config = VoiceScriptConfig {
+            include_narrator: true,
+            ..Default::default()
+        };
+        let lines = collect_voice_lines(&test_scenario(), &config);
+        assert_eq!(lines[0].voice_file, "voice/narrator/ch1_intro_001.ogg");
+    }
+
+    #[test]
+    fn test_export_scripts_per_speaker() {
+        let dir = tempfile::tempdir().unwrap();
+        let lines = collect_voice_lines(&test_scenario(), &VoiceScriptConfig::default());
+
+        let written = export_scripts(&lines, dir.path(), ExportFormat::Csv).unwrap();
+        assert_eq!(written.len(), 2);
+        let alice = fs::read_to_string(dir.path().join("alice.csv")).unwrap();
+        assert!(alice.starts_with("line_id,scene,speaker,context,text,voice_file"));
+        assert!(alice.contains("ch1_intro_002,intro,alice,narrator: Morning.,Hi!,"));
+
+        let written = export_scripts(&lines, dir.path(), ExportFormat::Ods).unwrap();
+        assert!(written.iter().all(|path| path.is_file()));
+    }
+
+    #[test]
+    fn test_check_voice_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let lines = collect_voice_lines(&test_scenario(), &VoiceScriptConfig::default());
+
+        let alice_dir = dir.path().join("audio/voice/alice");
+        fs::create_dir_all(&alice_dir).unwrap();
+        fs::write(alice_dir.join("ch1_intro_002.ogg"), b"ogg").unwrap();
+        fs::write(alice_dir.join("ch1_intro_099.ogg"), b"ogg").unwrap();
+        fs::write(alice_dir.join("notes.txt"), b"txt").unwrap();
+
+        let report = check_voice_files(&lines, dir.path());
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].speaker, "bob");
+        assert_eq!(report.orphaned, vec!["audio/voice/alice/ch1_intro_099.ogg"]);
+        assert!(!report.is_clean());
+    }
+}